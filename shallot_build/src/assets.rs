@@ -0,0 +1,401 @@
+//! Asset pipeline - fingerprinted, pre-compressed static assets
+//!
+//! [`AssetPipeline::run`] copies everything under the `[assets]` directory
+//! into `<static output>/assets/`, adds a content hash to each filename
+//! (`css/site.css` → `css/site.1a2b3c4d.css`, the same scheme as the
+//! component CSS bundle) and writes `.gz` and `.br` copies of text assets
+//! next to the original for servers that serve pre-compressed files. With
+//! the `images` feature and `strip_metadata`, JPEG, PNG and WebP files are
+//! re-encoded without their EXIF and XMP blocks on the way (see
+//! [`crate::images`]).
+//!
+//! The mapping from original to hashed paths is written to
+//! [`MANIFEST_FILE`]. Templates resolve paths through [`asset_url`] after
+//! installing the manifest once at startup:
+//!
+//! ```ignore
+//! AssetManifest::load("dist/static/asset-manifest.json")?.install();
+//! html! { link rel="stylesheet" href=(asset_url("css/site.css")); }
+//! ```
+
+#[cfg(feature = "images")]
+use crate::images::{is_raster_image, strip_metadata};
+use crate::BuildError;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use shallot_foundation::content_hash;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Manifest written next to the static pages
+pub const MANIFEST_FILE: &str = "asset-manifest.json";
+
+/// URL prefix and output subdirectory for processed assets
+pub const ASSETS_PREFIX: &str = "assets";
+
+/// Extensions worth pre-compressing
+const TEXT_EXTENSIONS: &[&str] = &[
+    "css", "js", "mjs", "map", "json", "svg", "html", "xml", "txt", "wasm",
+];
+
+/// Files smaller than this are not compressed; the gzip and brotli framing
+/// would outweigh the savings
+const MIN_COMPRESS_SIZE: usize = 256;
+
+/// Original asset paths mapped to their published URLs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AssetManifest {
+    entries: BTreeMap<String, String>,
+}
+
+impl AssetManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `path` (relative to the assets directory) to `url`
+    pub fn insert(&mut self, path: impl Into<String>, url: impl Into<String>) {
+        self.entries.insert(path.into(), url.into());
+    }
+
+    /// Published URL of `path`, if the pipeline processed it
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.entries
+            .get(path.trim_start_matches('/'))
+            .map(String::as_str)
+    }
+
+    /// Published URL of `path`, falling back to its unhashed URL
+    pub fn url(&self, path: &str) -> String {
+        match self.get(path) {
+            Some(url) => url.to_string(),
+            None => format!("/{}/{}", ASSETS_PREFIX, path.trim_start_matches('/')),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(path, url)| (path.as_str(), url.as_str()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("string map serializes")
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(source)
+    }
+
+    /// Read a manifest written by [`AssetPipeline::run`]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BuildError> {
+        let source = fs::read_to_string(path)?;
+        Self::from_json(&source).map_err(|error| BuildError::StaticBuildFailed(error.to_string()))
+    }
+
+    /// Make this the manifest [`asset_url`] reads; only the first call
+    /// takes effect
+    pub fn install(self) -> bool {
+        INSTALLED.set(self).is_ok()
+    }
+}
+
+static INSTALLED: OnceLock<AssetManifest> = OnceLock::new();
+
+/// URL of an asset by its original path, e.g. `asset_url("css/site.css")`,
+/// using the manifest given to [`AssetManifest::install`]
+pub fn asset_url(path: &str) -> String {
+    match INSTALLED.get() {
+        Some(manifest) => manifest.url(path),
+        None => AssetManifest::new().url(path),
+    }
+}
+
+/// Copies, fingerprints and compresses one directory of assets
+#[derive(Debug, Clone)]
+pub struct AssetPipeline {
+    pub source: PathBuf,
+    /// Static output directory; assets go to its `assets/` subdirectory
+    pub output: PathBuf,
+    pub fingerprint: bool,
+    pub gzip: bool,
+    pub brotli: bool,
+    /// JPEG quality raster images are re-encoded at to drop their
+    /// metadata; `None` publishes them unchanged
+    #[cfg(feature = "images")]
+    pub strip_metadata: Option<u8>,
+}
+
+impl AssetPipeline {
+    pub fn new(source: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            source: source.into(),
+            output: output.into(),
+            fingerprint: true,
+            gzip: true,
+            brotli: true,
+            #[cfg(feature = "images")]
+            strip_metadata: None,
+        }
+    }
+
+    pub fn fingerprint(mut self, fingerprint: bool) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    pub fn brotli(mut self, brotli: bool) -> Self {
+        self.brotli = brotli;
+        self
+    }
+
+    #[cfg(feature = "images")]
+    pub fn strip_metadata(mut self, quality: Option<u8>) -> Self {
+        self.strip_metadata = quality;
+        self
+    }
+
+    /// Process every file under `source` and write the manifest
+    pub fn run(&self) -> Result<AssetManifest, BuildError> {
+        let mut manifest = AssetManifest::new();
+        let assets_dir = self.output.join(ASSETS_PREFIX);
+
+        for entry in walkdir::WalkDir::new(&self.source) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(&self.source)
+                .expect("walkdir yields paths under its root");
+            let bytes = fs::read(entry.path())?;
+            #[cfg(feature = "images")]
+            let bytes = match self.strip_metadata {
+                Some(quality) if is_raster_image(relative) => strip_metadata(&bytes, quality)?,
+                _ => bytes,
+            };
+
+            let published = if self.fingerprint {
+                fingerprinted_path(relative, &bytes)
+            } else {
+                relative.to_path_buf()
+            };
+            let target = assets_dir.join(&published);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, &bytes)?;
+
+            if is_text_asset(relative) && bytes.len() >= MIN_COMPRESS_SIZE {
+                if self.gzip {
+                    write_compressed(&target, ".gz", bytes.len(), gzip(&bytes))?;
+                }
+                if self.brotli {
+                    write_compressed(&target, ".br", bytes.len(), brotli(&bytes))?;
+                }
+            }
+
+            manifest.insert(
+                url_path(relative),
+                format!("/{}/{}", ASSETS_PREFIX, url_path(&published)),
+            );
+        }
+
+        fs::create_dir_all(&self.output)?;
+        fs::write(self.output.join(MANIFEST_FILE), manifest.to_json())?;
+        Ok(manifest)
+    }
+}
+
+/// Write `compressed` next to `target` with `suffix` added, unless it is
+/// no smaller than the original
+fn write_compressed(
+    target: &Path,
+    suffix: &str,
+    original_len: usize,
+    compressed: Vec<u8>,
+) -> Result<(), BuildError> {
+    if compressed.len() < original_len {
+        let mut path = target.as_os_str().to_owned();
+        path.push(suffix);
+        fs::write(path, compressed)?;
+    }
+    Ok(())
+}
+
+/// `dir/name.ext` → `dir/name.{hash}.ext`, with the short FNV-1a hash used
+/// for the component CSS bundle
+pub fn fingerprinted_path(path: &Path, bytes: &[u8]) -> PathBuf {
+    let hash = format!("{:08x}", content_hash(bytes) >> 32);
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{}.{}.{}", stem, hash, ext.to_string_lossy()),
+        None => format!("{}.{}", stem, hash),
+    };
+    path.with_file_name(name)
+}
+
+fn is_text_asset(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| TEXT_EXTENSIONS.contains(&ext))
+}
+
+pub(crate) fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Compress `data` into a gzip member (RFC 1952) at the best compression
+pub fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(data)
+        .and_then(|()| encoder.finish())
+        .expect("writing to a Vec cannot fail")
+}
+
+/// Compress `data` into a brotli stream (RFC 7932) at the highest quality
+pub fn brotli(data: &[u8]) -> Vec<u8> {
+    let params = brotli::enc::BrotliEncoderParams {
+        quality: 11,
+        ..Default::default()
+    };
+    let mut out = Vec::new();
+    brotli::BrotliCompress(&mut &data[..], &mut out, &params)
+        .expect("writing to a Vec cannot fail");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_fingerprinted_path() {
+        let path = fingerprinted_path(Path::new("css/site.css"), b"body {}");
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(path.starts_with("css"));
+        assert!(name.starts_with("site.") && name.ends_with(".css"));
+        assert_eq!(name.len(), "site.12345678.css".len());
+        assert_ne!(
+            path,
+            fingerprinted_path(Path::new("css/site.css"), b"body { }")
+        );
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let data = "body { color: red; }\n".repeat(100);
+        let compressed = gzip(data.as_bytes());
+        assert_eq!(&compressed[..3], &[0x1f, 0x8b, 8]);
+        assert!(compressed.len() < data.len() / 4);
+
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(&compressed[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, data.as_bytes());
+    }
+
+    #[test]
+    fn test_brotli_round_trip() {
+        let data = "body { margin: 0; }\n".repeat(100);
+        let compressed = brotli(data.as_bytes());
+        assert!(compressed.len() < data.len() / 4);
+
+        let mut decompressed = Vec::new();
+        ::brotli::BrotliDecompress(&mut &compressed[..], &mut decompressed).unwrap();
+        assert_eq!(decompressed, data.as_bytes());
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("assets");
+        fs::create_dir_all(source.join("css")).unwrap();
+        fs::write(
+            source.join("css/site.css"),
+            ".a { color: red; }\n".repeat(50),
+        )
+        .unwrap();
+        fs::write(source.join("logo.png"), [0u8; 300]).unwrap();
+
+        let output = dir.path().join("static");
+        let manifest = AssetPipeline::new(&source, &output).run().unwrap();
+        assert_eq!(manifest.len(), 2);
+
+        let css_url = manifest.get("css/site.css").unwrap();
+        assert!(css_url.starts_with("/assets/css/site."));
+        let css_file = output.join(css_url.trim_start_matches('/'));
+        assert!(css_file.exists());
+        assert!(PathBuf::from(format!("{}.gz", css_file.display())).exists());
+        assert!(PathBuf::from(format!("{}.br", css_file.display())).exists());
+
+        let png_file = output.join(manifest.get("logo.png").unwrap().trim_start_matches('/'));
+        assert!(!PathBuf::from(format!("{}.gz", png_file.display())).exists());
+        assert!(!PathBuf::from(format!("{}.br", png_file.display())).exists());
+
+        let plain = dir.path().join("plain");
+        AssetPipeline::new(&source, &plain)
+            .fingerprint(false)
+            .brotli(false)
+            .run()
+            .unwrap();
+        assert!(plain.join("assets/css/site.css.gz").exists());
+        assert!(!plain.join("assets/css/site.css.br").exists());
+
+        let written = AssetManifest::load(output.join(MANIFEST_FILE)).unwrap();
+        assert_eq!(written, manifest);
+        assert_eq!(manifest.url("/css/site.css"), css_url);
+        assert_eq!(manifest.url("missing.js"), "/assets/missing.js");
+    }
+
+    #[test]
+    fn test_static_build_runs_pipeline() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("assets")).unwrap();
+        fs::write(dir.path().join("assets/app.js"), "export {};").unwrap();
+
+        let mut config = crate::BuildConfig::production_default("site", "0.1.0");
+        config.output_dir = dir.path().join("dist");
+        config.assets.dir = dir.path().join("assets");
+        crate::BuildSystem::new(config)
+            .build_target(crate::BuildTarget::StaticSite)
+            .unwrap();
+
+        let manifest =
+            AssetManifest::load(dir.path().join("dist/static").join(MANIFEST_FILE)).unwrap();
+        assert!(manifest.get("app.js").unwrap().starts_with("/assets/app."));
+    }
+
+    #[test]
+    fn test_asset_url_fallback() {
+        assert!(asset_url("app.js").ends_with("app.js"));
+    }
+}
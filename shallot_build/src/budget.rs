@@ -0,0 +1,255 @@
+//! Size budgets - fail static builds that outgrow their limits
+//!
+//! The `[budgets]` config sets byte limits the static output must stay
+//! under: each HTML page, each stylesheet, and everything in the output
+//! directory together (pre-compressed `.gz` copies included). The check
+//! runs after pages and assets are written, so the sizes are the ones that
+//! get deployed; a failure lists the biggest offenders first.
+
+use crate::{BudgetOptions, BuildError, BuildSystem};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Offenders listed per violation before the rest are summarised
+const MAX_LISTED: usize = 5;
+
+/// Which limit a [`BudgetViolation`] broke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetKind {
+    /// `max_page_html`, per `.html` file
+    PageHtml,
+    /// `max_css`, per `.css` file
+    Css,
+    /// `max_total`, the whole output directory
+    Total,
+}
+
+/// One broken limit and the files behind it, largest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetViolation {
+    pub kind: BudgetKind,
+    pub limit: u64,
+    /// Size of the largest offending file, or the total for
+    /// [`BudgetKind::Total`]
+    pub actual: u64,
+    /// Files relative to the output directory with their sizes; for
+    /// [`BudgetKind::Total`] the largest files overall
+    pub offenders: Vec<(PathBuf, u64)>,
+}
+
+/// Every budget a build broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetReport {
+    pub violations: Vec<BudgetViolation>,
+}
+
+impl BudgetKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            BudgetKind::PageHtml => "page HTML",
+            BudgetKind::Css => "CSS",
+            BudgetKind::Total => "total output",
+        }
+    }
+}
+
+impl fmt::Display for BudgetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for violation in &self.violations {
+            match violation.kind {
+                BudgetKind::Total => writeln!(
+                    f,
+                    "{} is {}, over the {} budget; largest files:",
+                    violation.kind.description(),
+                    format_size(violation.actual),
+                    format_size(violation.limit)
+                )?,
+                kind => writeln!(
+                    f,
+                    "{} over the {} budget in {} file(s):",
+                    kind.description(),
+                    format_size(violation.limit),
+                    violation.offenders.len()
+                )?,
+            }
+
+            for (path, size) in violation.offenders.iter().take(MAX_LISTED) {
+                write!(f, "  {} {}", path.display(), format_size(*size))?;
+                if violation.kind != BudgetKind::Total {
+                    write!(f, " (+{})", format_size(size - violation.limit))?;
+                }
+                writeln!(f)?;
+            }
+            if violation.offenders.len() > MAX_LISTED {
+                writeln!(
+                    f,
+                    "  ...and {} more",
+                    violation.offenders.len() - MAX_LISTED
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BuildSystem {
+    /// Check the files under `output_dir` against the `[budgets]` limits
+    pub fn check_budgets(&self, output_dir: &Path) -> Result<(), BuildError> {
+        self.config.budgets.check(output_dir)
+    }
+}
+
+impl BudgetOptions {
+    /// Check the files under `output_dir` against these limits
+    pub fn check(&self, output_dir: &Path) -> Result<(), BuildError> {
+        if *self == BudgetOptions::default() {
+            return Ok(());
+        }
+
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(output_dir) {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                let path = entry.path();
+                let relative = path.strip_prefix(output_dir).unwrap_or(path);
+                files.push((relative.to_path_buf(), entry.metadata()?.len()));
+            }
+        }
+        files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let per_file = [
+            (BudgetKind::PageHtml, "html", self.max_page_html),
+            (BudgetKind::Css, "css", self.max_css),
+        ];
+        let mut violations: Vec<BudgetViolation> = per_file
+            .into_iter()
+            .filter_map(|(kind, extension, limit)| {
+                let limit = limit?;
+                let offenders: Vec<(PathBuf, u64)> = files
+                    .iter()
+                    .filter(|(path, size)| {
+                        *size > limit && path.extension().is_some_and(|ext| ext == extension)
+                    })
+                    .cloned()
+                    .collect();
+                let actual = offenders.first()?.1;
+                Some(BudgetViolation {
+                    kind,
+                    limit,
+                    actual,
+                    offenders,
+                })
+            })
+            .collect();
+
+        let total: u64 = files.iter().map(|(_, size)| size).sum();
+        if let Some(limit) = self.max_total.filter(|limit| total > *limit) {
+            violations.push(BudgetViolation {
+                kind: BudgetKind::Total,
+                limit,
+                actual: total,
+                offenders: files.into_iter().take(MAX_LISTED).collect(),
+            });
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(BuildError::BudgetExceeded(BudgetReport { violations }))
+        }
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.2} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BudgetOptions, BuildConfig, BuildTarget};
+    use std::fs;
+
+    fn system(root: &Path, budgets: BudgetOptions) -> BuildSystem {
+        let mut config = BuildConfig::production_default("site", "0.1.0");
+        config.targets = vec![BuildTarget::StaticSite];
+        config.output_dir = root.join("dist");
+        config.assets.dir = root.join("assets");
+        config.budgets = budgets;
+        BuildSystem::new(config)
+    }
+
+    fn write_output(root: &Path) -> PathBuf {
+        let out = root.join("out");
+        fs::create_dir_all(out.join("blog")).unwrap();
+        fs::write(out.join("index.html"), "x".repeat(300)).unwrap();
+        fs::write(out.join("blog/post.html"), "x".repeat(2000)).unwrap();
+        fs::write(out.join("about.html"), "x".repeat(100)).unwrap();
+        fs::write(out.join("styles.css"), "x".repeat(500)).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_within_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = write_output(dir.path());
+        let budgets = BudgetOptions {
+            max_page_html: Some(2000),
+            max_css: Some(500),
+            max_total: Some(2900),
+        };
+        assert!(system(dir.path(), budgets).check_budgets(&out).is_ok());
+        assert!(system(dir.path(), BudgetOptions::default())
+            .check_budgets(&out)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_budget_violations_list_largest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = write_output(dir.path());
+        let budgets = BudgetOptions {
+            max_page_html: Some(200),
+            max_css: None,
+            max_total: Some(1024),
+        };
+
+        let Err(BuildError::BudgetExceeded(report)) =
+            system(dir.path(), budgets).check_budgets(&out)
+        else {
+            panic!("expected a budget failure");
+        };
+        let pages = &report.violations[0];
+        assert_eq!(pages.kind, BudgetKind::PageHtml);
+        assert_eq!(pages.actual, 2000);
+        assert_eq!(
+            pages.offenders,
+            vec![
+                (PathBuf::from("blog/post.html"), 2000),
+                (PathBuf::from("index.html"), 300),
+            ]
+        );
+        assert_eq!(report.violations[1].kind, BudgetKind::Total);
+        assert_eq!(report.violations[1].actual, 2900);
+
+        let message = report.to_string();
+        assert!(message.contains("page HTML over the 200 B budget in 2 file(s):"));
+        assert!(message.contains("  blog/post.html 2.0 KB (+1.8 KB)"));
+        assert!(message.contains("total output is 2.8 KB, over the 1.0 KB budget"));
+    }
+
+    #[test]
+    fn test_static_build_enforces_budgets() {
+        let dir = tempfile::tempdir().unwrap();
+        let budgets = BudgetOptions {
+            max_css: Some(16),
+            ..BudgetOptions::default()
+        };
+        let result = system(dir.path(), budgets).build_target(BuildTarget::StaticSite);
+        assert!(matches!(result, Err(BuildError::BudgetExceeded(_))));
+    }
+}
@@ -0,0 +1,821 @@
+//! Build config files
+//!
+//! [`BuildConfig::from_file`] reads a `shallot.toml` (or a `.json` file with
+//! the same fields), applies `SHALLOT_*` environment overrides and
+//! validates the result:
+//!
+//! ```toml
+//! name = "my-site"
+//! version = "0.1.0"
+//! targets = ["static", "wasm"]   # wasm | native | ssr | static
+//! features = []
+//! optimization = "release"       # development | release | size | speed
+//! output_dir = "dist"
+//! jobs = 4                       # worker threads; all cores when unset
+//!
+//! [wasm]
+//! target = "web"                 # wasm-pack --target
+//!
+//! [static]
+//! title = "My Site"
+//! generator = "my-site"          # bin that renders the pages, see below
+//! app_js = false
+//!
+//! [ssr]
+//! features = ["ssr"]
+//!
+//! [watch]                        # shallot build --watch
+//! paths = ["src", "content", "assets"]
+//! interval_ms = 500
+//! live_reload = true
+//!
+//! [assets]                       # copied to <output>/static/assets
+//! dir = "assets"
+//! fingerprint = true
+//! gzip = true
+//! brotli = true
+//!
+//! [images]                       # needs the `images` feature
+//! widths = [480, 960, 1440, 1920] # srcset widths, never upscaled
+//! formats = ["avif", "webp"]     # avif | webp
+//! quality = 80
+//! strip_metadata = true          # drop EXIF/XMP from published images
+//!
+//! [content]                      # Markdown/TOML content collections
+//! dir = "content"
+//! drafts = false                 # build drafts and scheduled entries too
+//!
+//! [budgets]                      # byte limits; static builds fail over them
+//! max_page_html = 51200          # each .html file
+//! max_css = 30720                # each .css file
+//! max_total = 1048576            # the whole static output
+//! ```
+//!
+//! With `generator` set, static builds run `cargo run --bin <generator>`
+//! with [`crate::SITE_DIR_VAR`] pointing at `<output>/static` and publish
+//! whatever the binary writes there; without it a starter page is written.
+
+use crate::{BuildConfig, BuildTarget, ImageFormat, OptimizationLevel};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config file name looked up by the CLI
+pub const CONFIG_FILE: &str = "shallot.toml";
+
+/// `wasm-pack --target` values
+pub const WASM_PACK_TARGETS: &[&str] = &["web", "bundler", "nodejs", "no-modules", "deno"];
+
+/// How an environment override is read
+#[derive(Clone, Copy)]
+enum EnvValue {
+    Text,
+    /// Comma-separated list
+    List,
+    Number,
+    /// `true`/`false` or `1`/`0`
+    Bool,
+}
+
+/// Environment variables that override config keys: variable, table, key
+/// and value kind
+const ENV_OVERRIDES: &[(&str, &str, &str, EnvValue)] = &[
+    ("SHALLOT_NAME", "", "name", EnvValue::Text),
+    ("SHALLOT_VERSION", "", "version", EnvValue::Text),
+    ("SHALLOT_TARGETS", "", "targets", EnvValue::List),
+    ("SHALLOT_FEATURES", "", "features", EnvValue::List),
+    ("SHALLOT_OPTIMIZATION", "", "optimization", EnvValue::Text),
+    ("SHALLOT_OUTPUT_DIR", "", "output_dir", EnvValue::Text),
+    ("SHALLOT_JOBS", "", "jobs", EnvValue::Number),
+    ("SHALLOT_WASM_TARGET", "wasm", "target", EnvValue::Text),
+    ("SHALLOT_STATIC_TITLE", "static", "title", EnvValue::Text),
+    (
+        "SHALLOT_CONTENT_DRAFTS",
+        "content",
+        "drafts",
+        EnvValue::Bool,
+    ),
+];
+
+/// `[wasm]` options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WasmOptions {
+    /// `wasm-pack --target`
+    pub target: String,
+    /// Name of the generated package; the config name when unset
+    pub out_name: Option<String>,
+}
+
+impl Default for WasmOptions {
+    fn default() -> Self {
+        Self {
+            target: "web".to_string(),
+            out_name: None,
+        }
+    }
+}
+
+/// `[static]` options
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaticOptions {
+    /// Page title; the config name when unset
+    pub title: Option<String>,
+    /// Binary target that renders the site into [`crate::SITE_DIR_VAR`]
+    pub generator: Option<String>,
+    /// Whether to emit and load `app.js` on the starter page
+    pub app_js: bool,
+}
+
+/// `[ssr]` options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SsrOptions {
+    /// Cargo features enabled on top of the config's `features`
+    pub features: Vec<String>,
+}
+
+impl Default for SsrOptions {
+    fn default() -> Self {
+        Self {
+            features: vec!["ssr".to_string()],
+        }
+    }
+}
+
+/// `[watch]` options for [`crate::BuildSystem::watch`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchOptions {
+    /// Files and directories to watch
+    pub paths: Vec<PathBuf>,
+    /// How long to gather a burst of file events before rebuilding
+    pub interval_ms: u64,
+    /// Write a self-refreshing `dev.html` next to the static pages
+    pub live_reload: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            paths: vec![
+                PathBuf::from("src"),
+                PathBuf::from("content"),
+                PathBuf::from("assets"),
+            ],
+            interval_ms: 500,
+            live_reload: true,
+        }
+    }
+}
+
+/// `[assets]` options for [`crate::AssetPipeline`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AssetOptions {
+    /// Source directory; skipped when it does not exist
+    pub dir: PathBuf,
+    /// Add content hashes to filenames
+    pub fingerprint: bool,
+    /// Write `.gz` copies of text assets
+    pub gzip: bool,
+    /// Write `.br` copies of text assets
+    pub brotli: bool,
+}
+
+impl Default for AssetOptions {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("assets"),
+            fingerprint: true,
+            gzip: true,
+            brotli: true,
+        }
+    }
+}
+
+/// `[content]` options for [`crate::Collection`]s
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContentOptions {
+    /// Directory of content collections, one per subdirectory
+    pub dir: PathBuf,
+    /// Build drafts and entries whose `publish_date` has not come yet
+    pub drafts: bool,
+}
+
+impl Default for ContentOptions {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("content"),
+            drafts: false,
+        }
+    }
+}
+
+/// `[images]` options for [`crate::ImagePipeline`]; ignored without the
+/// `images` feature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ImageOptions {
+    /// Variant widths in pixels; widths at or past an image's own width
+    /// are skipped and the original width is always included
+    pub widths: Vec<u32>,
+    /// Formats every raster image is converted to, best first
+    pub formats: Vec<ImageFormat>,
+    /// Encoder quality, 1-100, for AVIF variants and re-encoded JPEGs;
+    /// WebP variants are lossless
+    pub quality: u8,
+    /// Re-encode published originals without their EXIF and XMP metadata
+    pub strip_metadata: bool,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            widths: vec![480, 960, 1440, 1920],
+            formats: vec![ImageFormat::Avif, ImageFormat::Webp],
+            quality: 80,
+            strip_metadata: true,
+        }
+    }
+}
+
+/// `[budgets]` size limits, in bytes, checked by
+/// [`crate::BuildSystem::check_budgets`]; unset limits are not checked
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BudgetOptions {
+    /// Largest size of any one HTML page
+    pub max_page_html: Option<u64>,
+    /// Largest size of any one stylesheet
+    pub max_css: Option<u64>,
+    /// Largest size of the whole static output directory
+    pub max_total: Option<u64>,
+}
+
+impl BudgetOptions {
+    fn limits(&self) -> [(&'static str, Option<u64>); 3] {
+        [
+            ("max_page_html", self.max_page_html),
+            ("max_css", self.max_css),
+            ("max_total", self.max_total),
+        ]
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Line {line}: {message}")]
+    Syntax { line: usize, message: String },
+
+    #[error("Invalid config: {0}")]
+    Schema(String),
+
+    #[error("`{field}`: {message}")]
+    Invalid { field: String, message: String },
+
+    #[error("{0} already exists")]
+    AlreadyExists(PathBuf),
+}
+
+fn invalid(field: &str, message: impl Into<String>) -> ConfigError {
+    ConfigError::Invalid {
+        field: field.to_string(),
+        message: message.into(),
+    }
+}
+
+impl BuildConfig {
+    /// Read a `.toml` or `.json` config, apply `SHALLOT_*` environment
+    /// overrides and validate it
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)?;
+        let value = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&source).map_err(|error| ConfigError::Schema(error.to_string()))?
+        } else {
+            parse_toml(&source)?
+        };
+        Self::from_value(value, std::env::vars())
+    }
+
+    /// Parse and validate a TOML config without environment overrides
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        Self::from_toml_with_env(source, std::iter::empty())
+    }
+
+    /// Parse a TOML config, apply overrides from `env` (`SHALLOT_*`
+    /// name/value pairs) and validate it
+    pub fn from_toml_with_env<I>(source: &str, env: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self::from_value(parse_toml(source)?, env)
+    }
+
+    fn from_value<I>(mut value: Value, env: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        apply_env(&mut value, env);
+        let config: Self = serde_json::from_value(value)
+            .map_err(|error| ConfigError::Schema(error.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check the values serde cannot: names, version, targets and options
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        };
+
+        if !is_name(&self.name) {
+            return Err(invalid(
+                "name",
+                "use letters, digits, `-` and `_` only, e.g. \"my-site\"",
+            ));
+        }
+        let release = self.version.split(['-', '+']).next().unwrap_or_default();
+        let parts: Vec<&str> = release.split('.').collect();
+        if parts.len() != 3
+            || parts
+                .iter()
+                .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(invalid(
+                "version",
+                "expected MAJOR.MINOR.PATCH, e.g. \"0.1.0\"",
+            ));
+        }
+        if self.targets.is_empty() {
+            return Err(invalid(
+                "targets",
+                "list at least one of wasm, native, ssr, static",
+            ));
+        }
+        for (i, target) in self.targets.iter().enumerate() {
+            if self.targets[..i].contains(target) {
+                return Err(invalid(
+                    "targets",
+                    format!("`{}` is listed twice", target.as_str()),
+                ));
+            }
+        }
+        let mut features = self.features.iter().chain(&self.ssr.features);
+        if let Some(feature) = features.find(|feature| !is_name(feature)) {
+            return Err(invalid(
+                "features",
+                format!("`{}` is not a valid feature name", feature),
+            ));
+        }
+        if self.output_dir.as_os_str().is_empty() {
+            return Err(invalid("output_dir", "must not be empty"));
+        }
+        if !WASM_PACK_TARGETS.contains(&self.wasm.target.as_str()) {
+            return Err(invalid(
+                "wasm.target",
+                format!("expected one of {}", WASM_PACK_TARGETS.join(", ")),
+            ));
+        }
+        if self
+            .wasm
+            .out_name
+            .as_deref()
+            .is_some_and(|name| !is_name(name))
+        {
+            return Err(invalid(
+                "wasm.out_name",
+                "use letters, digits, `-` and `_` only",
+            ));
+        }
+        if self
+            .static_site
+            .generator
+            .as_deref()
+            .is_some_and(|bin| !is_name(bin))
+        {
+            return Err(invalid(
+                "static.generator",
+                "expected the name of a binary target, e.g. \"my-site\"",
+            ));
+        }
+        if self.watch.interval_ms == 0 {
+            return Err(invalid("watch.interval_ms", "must be at least 1"));
+        }
+        if self.jobs == Some(0) {
+            return Err(invalid(
+                "jobs",
+                "must be at least 1; leave it out to use every core",
+            ));
+        }
+        if self.images.widths.is_empty() || self.images.widths.contains(&0) {
+            return Err(invalid(
+                "images.widths",
+                "list one or more widths in pixels, e.g. [480, 960]",
+            ));
+        }
+        if !(1..=100).contains(&self.images.quality) {
+            return Err(invalid("images.quality", "must be between 1 and 100"));
+        }
+        for (key, limit) in self.budgets.limits() {
+            if limit == Some(0) {
+                return Err(invalid(
+                    &format!("budgets.{}", key),
+                    "must be at least 1 byte; leave it out for no limit",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The config as a `shallot.toml` file
+    pub fn to_toml(&self) -> String {
+        let list = |items: Vec<&str>| {
+            let items: Vec<String> = items.into_iter().map(toml_string).collect();
+            format!("[{}]", items.join(", "))
+        };
+
+        let mut toml = String::from("# Shallot build config, read by `shallot build`\n");
+        toml.push_str("# SHALLOT_* environment variables override these values\n");
+        toml.push_str(&format!("name = {}\n", toml_string(&self.name)));
+        toml.push_str(&format!("version = {}\n", toml_string(&self.version)));
+        toml.push_str(&format!(
+            "targets = {}\n",
+            list(self.targets.iter().map(BuildTarget::as_str).collect())
+        ));
+        toml.push_str(&format!(
+            "features = {}\n",
+            list(self.features.iter().map(String::as_str).collect())
+        ));
+        toml.push_str(&format!(
+            "optimization = {}\n",
+            toml_string(self.optimization.as_str())
+        ));
+        toml.push_str(&format!(
+            "output_dir = {}\n",
+            toml_string(&self.output_dir.to_string_lossy())
+        ));
+        if let Some(jobs) = self.jobs {
+            toml.push_str(&format!("jobs = {}\n", jobs));
+        }
+
+        toml.push_str("\n[wasm]\n");
+        toml.push_str(&format!("target = {}\n", toml_string(&self.wasm.target)));
+        if let Some(out_name) = &self.wasm.out_name {
+            toml.push_str(&format!("out_name = {}\n", toml_string(out_name)));
+        }
+
+        toml.push_str("\n[static]\n");
+        if let Some(title) = &self.static_site.title {
+            toml.push_str(&format!("title = {}\n", toml_string(title)));
+        }
+        if let Some(generator) = &self.static_site.generator {
+            toml.push_str(&format!("generator = {}\n", toml_string(generator)));
+        }
+        toml.push_str(&format!("app_js = {}\n", self.static_site.app_js));
+
+        toml.push_str("\n[ssr]\n");
+        toml.push_str(&format!(
+            "features = {}\n",
+            list(self.ssr.features.iter().map(String::as_str).collect())
+        ));
+
+        let paths: Vec<String> = self
+            .watch
+            .paths
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect();
+        toml.push_str("\n[watch]\n");
+        toml.push_str(&format!(
+            "paths = {}\n",
+            list(paths.iter().map(String::as_str).collect())
+        ));
+        toml.push_str(&format!("interval_ms = {}\n", self.watch.interval_ms));
+        toml.push_str(&format!("live_reload = {}\n", self.watch.live_reload));
+
+        toml.push_str("\n[assets]\n");
+        toml.push_str(&format!(
+            "dir = {}\n",
+            toml_string(&self.assets.dir.to_string_lossy())
+        ));
+        toml.push_str(&format!("fingerprint = {}\n", self.assets.fingerprint));
+        toml.push_str(&format!("gzip = {}\n", self.assets.gzip));
+        toml.push_str(&format!("brotli = {}\n", self.assets.brotli));
+
+        toml.push_str("\n[content]\n");
+        toml.push_str(&format!(
+            "dir = {}\n",
+            toml_string(&self.content.dir.to_string_lossy())
+        ));
+        toml.push_str(&format!("drafts = {}\n", self.content.drafts));
+
+        let widths: Vec<String> = self.images.widths.iter().map(u32::to_string).collect();
+        toml.push_str("\n[images]\n");
+        toml.push_str(&format!("widths = [{}]\n", widths.join(", ")));
+        toml.push_str(&format!(
+            "formats = {}\n",
+            list(
+                self.images
+                    .formats
+                    .iter()
+                    .map(ImageFormat::as_str)
+                    .collect()
+            )
+        ));
+        toml.push_str(&format!("quality = {}\n", self.images.quality));
+        toml.push_str(&format!(
+            "strip_metadata = {}\n",
+            self.images.strip_metadata
+        ));
+
+        if self.budgets != BudgetOptions::default() {
+            toml.push_str("\n[budgets]\n");
+            for (key, limit) in self.budgets.limits() {
+                if let Some(limit) = limit {
+                    toml.push_str(&format!("{} = {}\n", key, limit));
+                }
+            }
+        }
+        toml
+    }
+
+    /// Write a starter config to `path`, named after its directory, and
+    /// return it; an existing file is left alone
+    pub fn write_default(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(ConfigError::AlreadyExists(path.to_path_buf()));
+        }
+
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => std::env::current_dir()?,
+        };
+        let name: String = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let name = match name.trim_matches('-') {
+            "" => "shallot-site",
+            name => name,
+        };
+
+        let mut config = Self::production_default(name, "0.1.0");
+        config.targets = vec![BuildTarget::StaticSite];
+        config.features = Vec::new();
+        config.optimization = OptimizationLevel::Release;
+        fs::write(path, config.to_toml())?;
+        Ok(config)
+    }
+}
+
+fn apply_env<I>(value: &mut Value, env: I)
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let Value::Object(root) = value else {
+        return;
+    };
+    for (var, raw) in env {
+        let Some(&(_, table, key, kind)) = ENV_OVERRIDES.iter().find(|(name, ..)| *name == var)
+        else {
+            continue;
+        };
+        let value = match kind {
+            EnvValue::Text => Value::String(raw),
+            EnvValue::List => Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| Value::String(item.to_string()))
+                    .collect(),
+            ),
+            // Left as a string when not a number, so serde reports it
+            EnvValue::Number => match raw.trim().parse::<u64>() {
+                Ok(number) => Value::from(number),
+                Err(_) => Value::String(raw),
+            },
+            EnvValue::Bool => match raw.trim() {
+                "true" | "1" => Value::Bool(true),
+                "false" | "0" => Value::Bool(false),
+                _ => Value::String(raw),
+            },
+        };
+
+        let map = if table.is_empty() {
+            &mut *root
+        } else {
+            match root
+                .entry(table)
+                .or_insert_with(|| Value::Object(Map::new()))
+            {
+                Value::Object(map) => map,
+                _ => continue,
+            }
+        };
+        map.insert(key.to_string(), value);
+    }
+}
+
+/// Parse TOML into a JSON value for serde; dates and times become strings
+pub(crate) fn parse_toml(source: &str) -> Result<Value, ConfigError> {
+    let table: toml::Table = source.parse().map_err(|error: toml::de::Error| {
+        let offset = error.span().map_or(0, |span| span.start);
+        ConfigError::Syntax {
+            line: source[..offset].matches('\n').count() + 1,
+            message: error.message().to_string(),
+        }
+    })?;
+    Ok(to_json(toml::Value::Table(table)))
+}
+
+fn to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(text) => Value::String(text),
+        toml::Value::Integer(number) => Value::from(number),
+        toml::Value::Float(number) => Value::from(number),
+        toml::Value::Boolean(flag) => Value::Bool(flag),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn toml_string(text: &str) -> String {
+    toml::Value::String(text.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+# Site build
+name = "my-site"
+version = "1.2.0"
+targets = ["static", "wasm"]  # deploy both
+features = ["fast"]
+optimization = "size"
+output_dir = "public"
+
+[wasm]
+target = "bundler"
+
+[static]
+title = "My \"Site\" #1"
+generator = "site-gen"
+app_js = true
+
+[content]
+drafts = true
+
+[images]
+widths = [640, 1280]
+formats = ["webp"]
+
+[budgets]
+max_page_html = 51200
+max_total = 1048576
+"#;
+
+    #[test]
+    fn test_from_toml() {
+        let config = BuildConfig::from_toml(SAMPLE).unwrap();
+        assert_eq!(config.name, "my-site");
+        assert_eq!(
+            config.targets,
+            vec![BuildTarget::StaticSite, BuildTarget::WebAssembly]
+        );
+        assert_eq!(config.optimization, OptimizationLevel::SizeOptimized);
+        assert_eq!(config.output_dir, PathBuf::from("public"));
+        assert_eq!(config.wasm.target, "bundler");
+        assert_eq!(config.static_site.title.as_deref(), Some("My \"Site\" #1"));
+        assert_eq!(config.static_site.generator.as_deref(), Some("site-gen"));
+        assert!(config.static_site.app_js);
+        assert_eq!(config.ssr, SsrOptions::default());
+        assert_eq!(config.budgets.max_page_html, Some(51200));
+        assert_eq!(config.budgets.max_css, None);
+        assert_eq!(config.images.widths, vec![640, 1280]);
+        assert_eq!(config.images.formats, vec![ImageFormat::Webp]);
+        assert_eq!(config.images.quality, 80);
+        assert!(config.content.drafts);
+        assert_eq!(config.content.dir, PathBuf::from("content"));
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let env = [
+            ("SHALLOT_TARGETS", "ssr, native"),
+            ("SHALLOT_OUTPUT_DIR", "build"),
+            ("SHALLOT_WASM_TARGET", "web"),
+            ("SHALLOT_JOBS", "3"),
+            ("SHALLOT_CONTENT_DRAFTS", "0"),
+            ("PATH", "/usr/bin"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+        let config = BuildConfig::from_toml_with_env(SAMPLE, env).unwrap();
+        assert_eq!(
+            config.targets,
+            vec![BuildTarget::ServerSideRendering, BuildTarget::NativeDesktop]
+        );
+        assert_eq!(config.output_dir, PathBuf::from("build"));
+        assert_eq!(config.wasm.target, "web");
+        assert_eq!(config.jobs, Some(3));
+        assert!(!config.content.drafts);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = BuildConfig::from_toml("name = \"a\"\nname = \"b\"").unwrap_err();
+        assert!(
+            matches!(error, ConfigError::Syntax { line: 2, ref message } if message.contains("duplicate key"))
+        );
+
+        let error = BuildConfig::from_toml("name = \"unterminated").unwrap_err();
+        assert!(matches!(error, ConfigError::Syntax { line: 1, .. }));
+
+        let typo = SAMPLE.replace("optimization", "optimisation");
+        let error = BuildConfig::from_toml(&typo).unwrap_err();
+        assert!(error.to_string().contains("unknown field `optimisation`"));
+
+        let error = BuildConfig::from_toml(&SAMPLE.replace("1.2.0", "1.2")).unwrap_err();
+        assert!(matches!(error, ConfigError::Invalid { ref field, .. } if field == "version"));
+
+        let error =
+            BuildConfig::from_toml(&SAMPLE.replace("\"bundler\"", "\"browser\"")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`wasm.target`: expected one of web, bundler, nodejs, no-modules, deno"
+        );
+
+        let error = BuildConfig::from_toml(&SAMPLE.replace("site-gen", "../gen")).unwrap_err();
+        assert!(
+            matches!(error, ConfigError::Invalid { ref field, .. } if field == "static.generator")
+        );
+
+        let error = BuildConfig::from_toml(&SAMPLE.replace("[640, 1280]", "[]")).unwrap_err();
+        assert!(
+            matches!(error, ConfigError::Invalid { ref field, .. } if field == "images.widths")
+        );
+
+        let error = BuildConfig::from_toml(&SAMPLE.replace("51200", "0")).unwrap_err();
+        assert!(
+            matches!(error, ConfigError::Invalid { ref field, .. } if field == "budgets.max_page_html")
+        );
+    }
+
+    #[test]
+    fn test_to_toml_round_trip() {
+        let config = BuildConfig::from_toml(SAMPLE).unwrap();
+        let again = BuildConfig::from_toml(&config.to_toml()).unwrap();
+        assert_eq!(again.to_toml(), config.to_toml());
+        assert_eq!(again.targets, config.targets);
+        assert_eq!(again.static_site, config.static_site);
+        assert_eq!(again.watch, WatchOptions::default());
+        assert_eq!(again.assets, AssetOptions::default());
+        assert_eq!(again.budgets, config.budgets);
+        assert_eq!(again.images, config.images);
+    }
+
+    #[test]
+    fn test_write_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blog").join(CONFIG_FILE);
+        fs::create_dir(path.parent().unwrap()).unwrap();
+
+        let written = BuildConfig::write_default(&path).unwrap();
+        assert_eq!(written.name, "blog");
+        let loaded = BuildConfig::from_file(&path).unwrap();
+        assert_eq!(loaded.targets, vec![BuildTarget::StaticSite]);
+        assert!(!loaded.static_site.app_js);
+        assert!(matches!(
+            BuildConfig::write_default(&path),
+            Err(ConfigError::AlreadyExists(_))
+        ));
+    }
+}
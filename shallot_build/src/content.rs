@@ -0,0 +1,631 @@
+//! Content collections - typed Markdown and TOML entries
+//!
+//! [`Collection::load`] reads every `.md` and `.toml` file in a directory
+//! into a [`ContentEntry`]. A Markdown file may open with TOML front matter
+//! between `+++` lines; a `.toml` file is all front matter. The front matter
+//! is deserialized into the collection's own serde type, so a missing
+//! required field or a wrong type fails the load with the file it came
+//! from, and the Markdown body is rendered with [`markdown_to_html`].
+//! Entries are keyed by slug: the file stem, or a `slug` front matter key.
+//!
+//! Two more keys control publishing: `draft = true` holds an entry back, and
+//! a `publish_date = 2026-01-02` in the future schedules it. Production
+//! builds keep only published entries with [`Collection::for_build`];
+//! `[content] drafts = true` (`shallot build --drafts`) previews everything,
+//! and [`ContentReport`] lists what is still waiting.
+//!
+//! Collections sort and filter like iterators and hand their entries to
+//! page templates, e.g. `shallot_components`' `SiteBuilder::collection`
+//! with [`ContentEntry::params`] filling a `/blog/{slug}` route.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::Deserialize;
+//! use shallot_build::content::{Collection, ContentEntry};
+//! use shallot_build::CalendarDate;
+//!
+//! #[derive(Deserialize)]
+//! struct Post {
+//!     title: String,
+//!     date: String,
+//!     #[serde(default)]
+//!     draft: bool,
+//! }
+//!
+//! let first = ContentEntry::<Post>::parse(
+//!     "first.md",
+//!     "+++\ntitle = \"First\"\ndate = 2026-01-02\n+++\nHello *world*\n",
+//! )
+//! .unwrap();
+//! let draft = ContentEntry::<Post>::parse(
+//!     "draft.md",
+//!     "+++\ntitle = \"WIP\"\ndate = 2026-03-01\ndraft = true\n+++\n",
+//! )
+//! .unwrap();
+//!
+//! let posts = Collection::new(vec![first, draft])
+//!     .published(CalendarDate::today())
+//!     .sort_by_key(|post| std::cmp::Reverse(post.data.date.clone()));
+//! assert_eq!(posts.len(), 1);
+//! assert_eq!(posts.get("first").unwrap().html, "<p>Hello <em>world</em></p>\n");
+//! assert!(ContentEntry::<Post>::parse("bad.md", "+++\ntitle = \"x\"\n+++\n").is_err());
+//! ```
+//!
+//! TOML dates and times reach serde as strings, so `date = 2026-01-02`
+//! fills a `String` field.
+
+use crate::config::{parse_toml, ConfigError, ContentOptions};
+use crate::markdown::markdown_to_html;
+use crate::{BuildError, BuildSystem};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use shallot_foundation::CalendarDate;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Delimiter line around TOML front matter in Markdown files
+pub const FRONT_MATTER_DELIMITER: &str = "+++";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ContentError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{}: {message}", path.display())]
+    FrontMatter { path: PathBuf, message: String },
+
+    #[error("{}: front matter has no closing `+++`", .0.display())]
+    UnclosedFrontMatter(PathBuf),
+
+    #[error("Slug `{slug}` is used by both {} and {}", first.display(), second.display())]
+    DuplicateSlug {
+        slug: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+}
+
+/// One content file: its slug, typed front matter and rendered body
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEntry<T> {
+    pub slug: String,
+    pub path: PathBuf,
+    pub data: T,
+    /// Markdown source after the front matter; empty for `.toml` files
+    pub body: String,
+    /// `body` rendered to HTML
+    pub html: String,
+    /// `draft = true`: left out of production builds
+    pub draft: bool,
+    /// `publish_date`: left out of production builds until this date
+    pub publish_date: Option<CalendarDate>,
+}
+
+impl<T: DeserializeOwned> ContentEntry<T> {
+    /// Read and parse one `.md` or `.toml` file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ContentError> {
+        let path = path.as_ref();
+        Self::parse(path, &fs::read_to_string(path)?)
+    }
+
+    /// Parse `source` as the contents of `path`; `.toml` paths are read as
+    /// front matter only
+    pub fn parse(path: impl AsRef<Path>, source: &str) -> Result<Self, ContentError> {
+        let path = path.as_ref();
+        let (front_matter, body, offset) = if path.extension().is_some_and(|ext| ext == "toml") {
+            (source, "", 0)
+        } else {
+            split_front_matter(path, source)?
+        };
+
+        let front_matter_error = |message: String| ContentError::FrontMatter {
+            path: path.to_path_buf(),
+            message,
+        };
+        let value = parse_toml(front_matter).map_err(|error| {
+            front_matter_error(match error {
+                ConfigError::Syntax { line, message } => {
+                    format!("Line {}: {}", line + offset, message)
+                }
+                error => error.to_string(),
+            })
+        })?;
+
+        let slug = match value.get("slug") {
+            Some(Value::String(slug)) if is_valid_slug(slug) => slug.clone(),
+            Some(_) => {
+                return Err(front_matter_error(
+                    "`slug` must be a non-empty string without `/`".to_string(),
+                ))
+            }
+            None => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        };
+        let draft = match value.get("draft") {
+            None => false,
+            Some(Value::Bool(draft)) => *draft,
+            Some(_) => return Err(front_matter_error("`draft` must be a boolean".to_string())),
+        };
+        let publish_date = match value.get("publish_date") {
+            None => None,
+            Some(Value::String(date)) => Some(parse_publish_date(date).ok_or_else(|| {
+                front_matter_error(format!(
+                    "`publish_date` {:?} is not a YYYY-MM-DD date",
+                    date
+                ))
+            })?),
+            Some(_) => {
+                return Err(front_matter_error(
+                    "`publish_date` must be a date like 2026-01-02".to_string(),
+                ))
+            }
+        };
+        let data =
+            serde_json::from_value(value).map_err(|error| front_matter_error(error.to_string()))?;
+
+        Ok(Self {
+            slug,
+            path: path.to_path_buf(),
+            data,
+            body: body.to_string(),
+            html: markdown_to_html(body),
+            draft,
+            publish_date,
+        })
+    }
+}
+
+impl<T> ContentEntry<T> {
+    /// Route parameters for the entry, `slug` for patterns like
+    /// `/blog/{slug}`
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("slug", self.slug.clone())]
+    }
+
+    /// Not a draft, and its publish date, if any, has come
+    pub fn is_published(&self, today: CalendarDate) -> bool {
+        !self.draft && self.publish_date.is_none_or(|date| date <= today)
+    }
+
+    /// Not a draft, but dated after `today`
+    pub fn is_scheduled(&self, today: CalendarDate) -> bool {
+        !self.draft && self.publish_date.is_some_and(|date| date > today)
+    }
+}
+
+/// The entries of one content directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collection<T> {
+    entries: Vec<ContentEntry<T>>,
+}
+
+impl<T: DeserializeOwned> Collection<T> {
+    /// Load every `.md`, `.markdown` and `.toml` file directly inside
+    /// `dir`, sorted by slug
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, ContentError> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_content = path
+                .extension()
+                .is_some_and(|ext| ext == "md" || ext == "markdown" || ext == "toml");
+            if is_content && path.is_file() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let entries = paths
+            .into_iter()
+            .map(ContentEntry::load)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut seen: HashMap<&str, &Path> = HashMap::new();
+        for entry in &entries {
+            if let Some(first) = seen.insert(&entry.slug, &entry.path) {
+                return Err(ContentError::DuplicateSlug {
+                    slug: entry.slug.clone(),
+                    first: first.to_path_buf(),
+                    second: entry.path.clone(),
+                });
+            }
+        }
+
+        Ok(Self::new(entries).sort_by(|a, b| a.slug.cmp(&b.slug)))
+    }
+}
+
+impl<T> Collection<T> {
+    pub fn new(entries: Vec<ContentEntry<T>>) -> Self {
+        Self { entries }
+    }
+
+    /// Drop drafts and entries scheduled after `today`
+    pub fn published(self, today: CalendarDate) -> Self {
+        self.filter(|entry| entry.is_published(today))
+    }
+
+    /// Everything when `options.drafts` is set, otherwise only what is
+    /// published today
+    pub fn for_build(self, options: &ContentOptions) -> Self {
+        if options.drafts {
+            self
+        } else {
+            self.published(CalendarDate::today())
+        }
+    }
+
+    /// Entries scheduled after `today`, soonest first
+    pub fn upcoming(&self, today: CalendarDate) -> Vec<&ContentEntry<T>> {
+        let mut upcoming: Vec<&ContentEntry<T>> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.is_scheduled(today))
+            .collect();
+        upcoming.sort_by_key(|entry| entry.publish_date);
+        upcoming
+    }
+
+    /// Keep the entries `keep` returns true for
+    pub fn filter(mut self, keep: impl FnMut(&ContentEntry<T>) -> bool) -> Self {
+        self.entries.retain(keep);
+        self
+    }
+
+    pub fn sort_by(
+        mut self,
+        compare: impl FnMut(&ContentEntry<T>, &ContentEntry<T>) -> Ordering,
+    ) -> Self {
+        self.entries.sort_by(compare);
+        self
+    }
+
+    pub fn sort_by_key<K: Ord>(mut self, key: impl FnMut(&ContentEntry<T>) -> K) -> Self {
+        self.entries.sort_by_key(key);
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.entries.reverse();
+        self
+    }
+
+    /// The first `count` entries
+    pub fn take(mut self, count: usize) -> Self {
+        self.entries.truncate(count);
+        self
+    }
+
+    pub fn get(&self, slug: &str) -> Option<&ContentEntry<T>> {
+        self.entries.iter().find(|entry| entry.slug == slug)
+    }
+
+    pub fn entries(&self) -> &[ContentEntry<T>] {
+        &self.entries
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ContentEntry<T>> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Collection<T> {
+    type Item = &'a ContentEntry<T>;
+    type IntoIter = std::slice::Iter<'a, ContentEntry<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<T> IntoIterator for Collection<T> {
+    type Item = ContentEntry<T>;
+    type IntoIter = std::vec::IntoIter<ContentEntry<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+/// Scheduled and draft entries under a content directory
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentReport {
+    /// Entries with a future `publish_date`, soonest first
+    pub upcoming: Vec<(PathBuf, CalendarDate)>,
+    pub drafts: Vec<PathBuf>,
+    /// Whether the build includes drafts and upcoming entries
+    pub drafts_included: bool,
+}
+
+impl ContentReport {
+    /// Scan every content file below `options.dir`; a missing directory
+    /// gives an empty report
+    pub fn scan(options: &ContentOptions, today: CalendarDate) -> Result<Self, ContentError> {
+        let mut report = Self {
+            drafts_included: options.drafts,
+            ..Self::default()
+        };
+        if !options.dir.is_dir() {
+            return Ok(report);
+        }
+
+        for file in walkdir::WalkDir::new(&options.dir).sort_by_file_name() {
+            let file = file.map_err(std::io::Error::from)?;
+            let is_content = file
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "md" || ext == "markdown" || ext == "toml");
+            if !file.file_type().is_file() || !is_content {
+                continue;
+            }
+
+            let entry = ContentEntry::<Value>::load(file.path())?;
+            if entry.draft {
+                report.drafts.push(entry.path);
+            } else if let Some(date) = entry.publish_date.filter(|_| entry.is_scheduled(today)) {
+                report.upcoming.push((entry.path, date));
+            }
+        }
+        report.upcoming.sort_by_key(|(_, date)| *date);
+        Ok(report)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upcoming.is_empty() && self.drafts.is_empty()
+    }
+}
+
+impl fmt::Display for ContentReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.drafts_included {
+            "included, --drafts"
+        } else {
+            "not built; use --drafts to preview"
+        };
+        if !self.upcoming.is_empty() {
+            writeln!(f, "Upcoming ({}):", status)?;
+            for (path, date) in &self.upcoming {
+                writeln!(f, "  {}  {}", date, path.display())?;
+            }
+        }
+        if !self.drafts.is_empty() {
+            writeln!(f, "Drafts ({}):", status)?;
+            for path in &self.drafts {
+                writeln!(f, "  {}", path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BuildSystem {
+    /// Drafts and upcoming entries under the `[content]` directory
+    pub fn content_report(&self) -> Result<ContentReport, BuildError> {
+        Ok(ContentReport::scan(
+            &self.config.content,
+            CalendarDate::today(),
+        )?)
+    }
+}
+
+/// Split a Markdown file into front matter, body and the number of lines
+/// before the front matter
+fn split_front_matter<'s>(
+    path: &Path,
+    source: &'s str,
+) -> Result<(&'s str, &'s str, usize), ContentError> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    let Some(rest) = source
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(|rest| {
+            rest.strip_prefix("\r\n")
+                .or_else(|| rest.strip_prefix('\n'))
+        })
+    else {
+        return Ok(("", source, 0));
+    };
+
+    let mut end = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Ok((&rest[..end], &rest[end + line.len()..], 1));
+        }
+        end += line.len();
+    }
+    Err(ContentError::UnclosedFrontMatter(path.to_path_buf()))
+}
+
+/// `YYYY-MM-DD`, ignoring any time after a `T` or space
+fn parse_publish_date(text: &str) -> Option<CalendarDate> {
+    CalendarDate::parse(text.trim().split(['T', ' ']).next()?)
+}
+
+fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty() && !slug.contains('/') && slug != "." && slug != ".."
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, Deserialize, PartialEq)]
+    struct Post {
+        title: String,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_parse_front_matter() {
+        let entry = ContentEntry::<Post>::parse(
+            "posts/hello.md",
+            "+++\ntitle = \"Hello\"\ntags = [\"rust\", \"css\"]\n+++\n# Hi\n",
+        )
+        .unwrap();
+        assert_eq!(entry.slug, "hello");
+        assert_eq!(entry.data.tags, vec!["rust", "css"]);
+        assert_eq!(entry.body, "# Hi\n");
+        assert_eq!(entry.html, "<h1 id=\"hi\">Hi</h1>\n");
+        assert_eq!(entry.params(), vec![("slug", "hello".to_string())]);
+
+        let renamed = ContentEntry::<Post>::parse(
+            "posts/2026-01-01-hello.md",
+            "+++\ntitle = \"Hello\"\nslug = \"hello\"\n+++\n",
+        )
+        .unwrap();
+        assert_eq!(renamed.slug, "hello");
+    }
+
+    #[test]
+    fn test_front_matter_errors() {
+        let missing = ContentEntry::<Post>::parse("a.md", "+++\ntags = []\n+++\nBody");
+        assert_eq!(
+            missing.unwrap_err().to_string(),
+            "a.md: missing field `title`"
+        );
+
+        let syntax = ContentEntry::<Post>::parse("a.md", "+++\ntitle = \"x\"\ntags\n+++\n");
+        assert!(syntax
+            .unwrap_err()
+            .to_string()
+            .starts_with("a.md: Line 3: "));
+
+        assert!(matches!(
+            ContentEntry::<Post>::parse("a.md", "+++\ntitle = \"x\"\n"),
+            Err(ContentError::UnclosedFrontMatter(_))
+        ));
+        assert!(matches!(
+            ContentEntry::<Post>::parse("a.md", "+++\ntitle = \"x\"\nslug = \"a/b\"\n+++\n"),
+            Err(ContentError::FrontMatter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_publish_dates() {
+        assert_eq!(
+            parse_publish_date("2024-02-29T09:30:00Z"),
+            Some(CalendarDate::new(2024, 2, 29))
+        );
+        assert_eq!(
+            parse_publish_date("2024-02-29 09:30"),
+            Some(CalendarDate::new(2024, 2, 29))
+        );
+        assert_eq!(parse_publish_date("2023-02-29"), None);
+
+        let bad = ContentEntry::<Post>::parse(
+            "a.md",
+            "+++\ntitle = \"x\"\npublish_date = \"soon\"\n+++\n",
+        );
+        assert!(matches!(bad, Err(ContentError::FrontMatter { .. })));
+    }
+
+    #[test]
+    fn test_drafts_and_scheduling() {
+        let today = CalendarDate::new(2026, 6, 1);
+        let entry = |name: &str, extra: &str| {
+            ContentEntry::<Post>::parse(name, &format!("+++\ntitle = \"t\"\n{}\n+++\n", extra))
+                .unwrap()
+        };
+        let posts = Collection::new(vec![
+            entry("live.md", ""),
+            entry("dated.md", "publish_date = 2026-06-01"),
+            entry("later.md", "publish_date = 2026-09-01T08:00:00Z"),
+            entry("soon.md", "publish_date = 2026-07-01"),
+            entry("draft.md", "draft = true"),
+        ]);
+
+        let upcoming: Vec<&str> = posts
+            .upcoming(today)
+            .iter()
+            .map(|post| post.slug.as_str())
+            .collect();
+        assert_eq!(upcoming, vec!["soon", "later"]);
+
+        let drafts = ContentOptions {
+            drafts: true,
+            ..ContentOptions::default()
+        };
+        assert_eq!(posts.clone().for_build(&drafts).len(), 5);
+        let live: Vec<String> = posts
+            .published(today)
+            .into_iter()
+            .map(|post| post.slug)
+            .collect();
+        assert_eq!(live, vec!["live", "dated"]);
+    }
+
+    #[test]
+    fn test_content_report() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("blog")).unwrap();
+        fs::write(dir.path().join("blog/wip.md"), "+++\ndraft = true\n+++\n").unwrap();
+        fs::write(
+            dir.path().join("blog/launch.md"),
+            "+++\npublish_date = 2099-01-01\n+++\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("about.md"), "About").unwrap();
+
+        let options = ContentOptions {
+            dir: dir.path().to_path_buf(),
+            drafts: false,
+        };
+        let report = ContentReport::scan(&options, CalendarDate::today()).unwrap();
+        assert_eq!(report.drafts, vec![dir.path().join("blog/wip.md")]);
+        assert_eq!(report.upcoming.len(), 1);
+        let text = report.to_string();
+        assert!(text.starts_with("Upcoming (not built; use --drafts to preview):\n  2099-01-01  "));
+        assert!(text.contains("Drafts (not built; use --drafts to preview):"));
+
+        let missing = ContentOptions {
+            dir: dir.path().join("missing"),
+            drafts: false,
+        };
+        assert!(ContentReport::scan(&missing, CalendarDate::today())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_load_collection() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("b.md"), "+++\ntitle = \"B\"\n+++\nSecond").unwrap();
+        fs::write(dir.path().join("a.toml"), "title = \"A\"\ntags = [\"x\"]").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+        fs::create_dir(dir.path().join("drafts")).unwrap();
+
+        let posts = Collection::<Post>::load(dir.path()).unwrap();
+        let slugs: Vec<&str> = posts.iter().map(|post| post.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["a", "b"]);
+        assert_eq!(posts.get("a").unwrap().html, "");
+        assert_eq!(posts.get("b").unwrap().html, "<p>Second</p>\n");
+
+        let tagged = posts.filter(|post| !post.data.tags.is_empty());
+        assert_eq!(tagged.len(), 1);
+
+        fs::write(
+            dir.path().join("c.md"),
+            "+++\ntitle = \"C\"\nslug = \"b\"\n+++\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            Collection::<Post>::load(dir.path()),
+            Err(ContentError::DuplicateSlug { slug, .. }) if slug == "b"
+        ));
+    }
+}
@@ -0,0 +1,553 @@
+//! Image optimization - responsive variants for the asset pipeline
+//!
+//! With the `images` feature, [`ImagePipeline`] turns every JPEG, PNG and
+//! WebP under the `[assets]` directory into resized copies at the
+//! `[images]` widths in each configured format (`img/hero-960w.avif`), and
+//! writes [`IMAGE_MANIFEST_FILE`] with a `srcset` per format for
+//! `<picture>` markup. Widths past the source image's own are skipped, and
+//! its own width is always included, so nothing is upscaled.
+//!
+//! Decoding and encoding go through an [`ImageEncoder`]; [`RasterEncoder`]
+//! uses the `image` crate, like the components' `ImageResizer`. Variants
+//! are written fresh, so they never carry the source's EXIF or XMP
+//! metadata, and [`strip_metadata`] re-encodes published originals the
+//! same way.
+//!
+//! ```ignore
+//! let images = ImageManifest::load("dist/static/image-manifest.json")?;
+//! let hero = images.get("img/hero.jpg").unwrap();
+//! let mut image = ResponsiveImage::new(&hero.src, "Hero")
+//!     .width(hero.width)
+//!     .height(hero.height)
+//!     .sizes("100vw");
+//! for set in &hero.sources {
+//!     image = image.source_with_type(&set.srcset, &set.mime_type);
+//! }
+//! ```
+
+use crate::BuildError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[cfg(feature = "images")]
+use crate::assets::{fingerprinted_path, url_path, ASSETS_PREFIX};
+#[cfg(feature = "images")]
+use crate::{AssetManifest, ImageOptions};
+#[cfg(feature = "images")]
+use image::{DynamicImage, ImageDecoder, ImageReader};
+#[cfg(feature = "images")]
+use std::io::{BufRead, Cursor, Seek};
+#[cfg(feature = "images")]
+use std::path::PathBuf;
+
+/// Image manifest written next to the asset manifest
+pub const IMAGE_MANIFEST_FILE: &str = "image-manifest.json";
+
+/// Extensions of the raster images that get variants and metadata stripping
+#[cfg(feature = "images")]
+const RASTER_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp"];
+
+/// Formats responsive variants are encoded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Avif,
+    Webp,
+}
+
+impl ImageFormat {
+    /// Config name and file extension
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "avif",
+            ImageFormat::Webp => "webp",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "image/avif",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+/// One format's variants as a `srcset` value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageSourceSet {
+    #[serde(rename = "type")]
+    pub mime_type: String,
+    /// `url 480w, url 960w, ...`
+    pub srcset: String,
+}
+
+/// Everything needed to render one source image responsively
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageEntry {
+    /// Intrinsic size of the source, for `width`/`height` attributes
+    pub width: u32,
+    pub height: u32,
+    /// Published URL of the original, the `<img src>` fallback
+    pub src: String,
+    /// Variants per format, in the configured order
+    pub sources: Vec<ImageSourceSet>,
+}
+
+impl ImageEntry {
+    /// The `srcset` for `format`, if variants were made in it
+    pub fn srcset(&self, format: ImageFormat) -> Option<&str> {
+        self.sources
+            .iter()
+            .find(|set| set.mime_type == format.mime_type())
+            .map(|set| set.srcset.as_str())
+    }
+}
+
+/// Source image paths, relative to the assets directory, mapped to their
+/// variants
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ImageManifest {
+    entries: BTreeMap<String, ImageEntry>,
+}
+
+impl ImageManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, path: impl Into<String>, entry: ImageEntry) {
+        self.entries.insert(path.into(), entry);
+    }
+
+    pub fn get(&self, path: &str) -> Option<&ImageEntry> {
+        self.entries.get(path.trim_start_matches('/'))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ImageEntry)> {
+        self.entries
+            .iter()
+            .map(|(path, entry)| (path.as_str(), entry))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("image manifest serializes")
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(source)
+    }
+
+    /// Read a manifest written by the image pipeline
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BuildError> {
+        let source = fs::read_to_string(path)?;
+        Self::from_json(&source).map_err(|error| BuildError::StaticBuildFailed(error.to_string()))
+    }
+}
+
+/// Whether `path` is a raster image the pipeline processes
+#[cfg(feature = "images")]
+pub(crate) fn is_raster_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| RASTER_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// Resizes and converts source images for [`ImagePipeline`]
+#[cfg(feature = "images")]
+pub trait ImageEncoder {
+    /// `source` scaled to `width` pixels wide, keeping its aspect ratio,
+    /// encoded as `format` at `quality` (1-100)
+    fn encode(
+        &self,
+        source: &Path,
+        width: u32,
+        format: ImageFormat,
+        quality: u8,
+    ) -> Result<Vec<u8>, BuildError>;
+}
+
+/// AVIF encoder speed, from 1 (smallest files) to 10 (fastest)
+#[cfg(feature = "images")]
+const AVIF_SPEED: u8 = 6;
+
+/// [`ImageEncoder`] backed by the `image` crate. EXIF orientation is
+/// applied before resizing; WebP output is lossless, so `quality` only
+/// affects AVIF.
+#[cfg(feature = "images")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RasterEncoder;
+
+#[cfg(feature = "images")]
+impl RasterEncoder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "images")]
+impl ImageEncoder for RasterEncoder {
+    fn encode(
+        &self,
+        source: &Path,
+        width: u32,
+        format: ImageFormat,
+        quality: u8,
+    ) -> Result<Vec<u8>, BuildError> {
+        use image::codecs::{avif::AvifEncoder, webp::WebPEncoder};
+        use image::imageops::FilterType;
+
+        let image = decode_oriented(ImageReader::open(source)?)?;
+        let height =
+            (u64::from(image.height()) * u64::from(width) / u64::from(image.width())).max(1) as u32;
+        let image = DynamicImage::ImageRgba8(
+            image
+                .resize_exact(width, height, FilterType::Lanczos3)
+                .to_rgba8(),
+        );
+
+        let mut bytes = Vec::new();
+        match format {
+            ImageFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+                &mut bytes, AVIF_SPEED, quality,
+            ))?,
+            ImageFormat::Webp => image.write_with_encoder(WebPEncoder::new_lossless(&mut bytes))?,
+        }
+        Ok(bytes)
+    }
+}
+
+/// Decode an image, turned upright by its EXIF orientation
+#[cfg(feature = "images")]
+fn decode_oriented<R: BufRead + Seek>(reader: ImageReader<R>) -> Result<DynamicImage, BuildError> {
+    let mut decoder = reader.with_guessed_format()?.into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// Upright width and height of the image at `path`, or `None` when it does
+/// not decode
+#[cfg(feature = "images")]
+fn oriented_dimensions(path: &Path) -> Option<(u32, u32)> {
+    use image::metadata::Orientation;
+
+    let mut decoder = ImageReader::open(path)
+        .ok()?
+        .with_guessed_format()
+        .ok()?
+        .into_decoder()
+        .ok()?;
+    let (width, height) = decoder.dimensions();
+    match decoder.orientation().ok()? {
+        Orientation::Rotate90
+        | Orientation::Rotate270
+        | Orientation::Rotate90FlipH
+        | Orientation::Rotate270FlipH => Some((height, width)),
+        _ => Some((width, height)),
+    }
+}
+
+/// Re-encode a JPEG, PNG or WebP image without its EXIF, XMP and text
+/// metadata, upright by its EXIF orientation. JPEGs are re-encoded at
+/// `quality`, PNG and WebP losslessly; anything else is returned unchanged.
+#[cfg(feature = "images")]
+pub fn strip_metadata(bytes: &[u8], quality: u8) -> Result<Vec<u8>, BuildError> {
+    use image::codecs::{jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder};
+
+    let reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+    let Some(format) = reader.format() else {
+        return Ok(bytes.to_vec());
+    };
+    let mut out = Vec::new();
+    match format {
+        image::ImageFormat::Jpeg => {
+            let image = DynamicImage::ImageRgb8(decode_oriented(reader)?.to_rgb8());
+            image.write_with_encoder(JpegEncoder::new_with_quality(&mut out, quality))?;
+        }
+        image::ImageFormat::Png => {
+            decode_oriented(reader)?.write_with_encoder(PngEncoder::new(&mut out))?;
+        }
+        image::ImageFormat::WebP => {
+            let image = DynamicImage::ImageRgba8(decode_oriented(reader)?.to_rgba8());
+            image.write_with_encoder(WebPEncoder::new_lossless(&mut out))?;
+        }
+        _ => return Ok(bytes.to_vec()),
+    }
+    Ok(out)
+}
+
+/// Writes responsive variants of one directory's raster images
+#[cfg(feature = "images")]
+#[derive(Debug, Clone)]
+pub struct ImagePipeline {
+    pub source: PathBuf,
+    /// Static output directory; variants go to its `assets/` subdirectory
+    /// next to the originals
+    pub output: PathBuf,
+    pub options: ImageOptions,
+    pub fingerprint: bool,
+}
+
+#[cfg(feature = "images")]
+impl ImagePipeline {
+    pub fn new(source: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            source: source.into(),
+            output: output.into(),
+            options: ImageOptions::default(),
+            fingerprint: true,
+        }
+    }
+
+    pub fn options(mut self, options: ImageOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn fingerprint(mut self, fingerprint: bool) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    /// Encode every raster image under `source` and write the image
+    /// manifest; `assets` supplies each original's published URL
+    pub fn run(
+        &self,
+        assets: &AssetManifest,
+        encoder: &impl ImageEncoder,
+    ) -> Result<ImageManifest, BuildError> {
+        let mut manifest = ImageManifest::new();
+        let assets_dir = self.output.join(ASSETS_PREFIX);
+
+        for entry in walkdir::WalkDir::new(&self.source) {
+            let entry = entry?;
+            if !entry.file_type().is_file() || !is_raster_image(entry.path()) {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(&self.source)
+                .expect("walkdir yields paths under its root");
+            let Some((width, height)) = oriented_dimensions(entry.path()) else {
+                continue;
+            };
+
+            let mut widths: Vec<u32> = self
+                .options
+                .widths
+                .iter()
+                .copied()
+                .filter(|&candidate| candidate < width)
+                .chain([width])
+                .collect();
+            widths.sort_unstable();
+            widths.dedup();
+
+            let stem = relative
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let mut sources = Vec::new();
+            for &format in &self.options.formats {
+                let mut srcset = Vec::new();
+                for &variant_width in &widths {
+                    let bytes = encoder.encode(
+                        entry.path(),
+                        variant_width,
+                        format,
+                        self.options.quality,
+                    )?;
+
+                    let name = relative.with_file_name(format!(
+                        "{}-{}w.{}",
+                        stem,
+                        variant_width,
+                        format.as_str()
+                    ));
+                    let published = if self.fingerprint {
+                        fingerprinted_path(&name, &bytes)
+                    } else {
+                        name
+                    };
+                    let target = assets_dir.join(&published);
+                    if let Some(parent) = target.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&target, &bytes)?;
+                    srcset.push(format!(
+                        "/{}/{} {}w",
+                        ASSETS_PREFIX,
+                        url_path(&published),
+                        variant_width
+                    ));
+                }
+                sources.push(ImageSourceSet {
+                    mime_type: format.mime_type().to_string(),
+                    srcset: srcset.join(", "),
+                });
+            }
+
+            let path = url_path(relative);
+            manifest.insert(
+                path.clone(),
+                ImageEntry {
+                    width,
+                    height,
+                    src: assets.url(&path),
+                    sources,
+                },
+            );
+        }
+
+        fs::create_dir_all(&self.output)?;
+        fs::write(self.output.join(IMAGE_MANIFEST_FILE), manifest.to_json())?;
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A JPEG of `width` x `height` carrying EXIF orientation 6 (rotate 90°)
+    #[cfg(feature = "images")]
+    fn rotated_jpeg(width: u32, height: u32) -> Vec<u8> {
+        let mut jpeg = Vec::new();
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut Cursor::new(&mut jpeg), image::ImageFormat::Jpeg)
+            .unwrap();
+        let mut exif = vec![0xff, 0xe1, 0, 34];
+        exif.extend_from_slice(b"Exif\0\0MM\0\x2a\0\0\0\x08");
+        exif.extend_from_slice(&[0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0]);
+        exif.extend_from_slice(&[0, 0, 0, 0]);
+        jpeg.splice(2..2, exif);
+        jpeg
+    }
+
+    #[cfg(feature = "images")]
+    #[test]
+    fn test_raster_encoder_resizes_upright() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("photo.jpg");
+        fs::write(&source, rotated_jpeg(40, 20)).unwrap();
+        assert_eq!(oriented_dimensions(&source), Some((20, 40)));
+
+        let webp = RasterEncoder
+            .encode(&source, 10, ImageFormat::Webp, 80)
+            .unwrap();
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (10, 20));
+
+        let avif = RasterEncoder
+            .encode(&source, 10, ImageFormat::Avif, 80)
+            .unwrap();
+        assert_eq!(&avif[4..12], b"ftypavif");
+    }
+
+    #[cfg(feature = "images")]
+    #[test]
+    fn test_strip_metadata_reencodes() {
+        let stripped = strip_metadata(&rotated_jpeg(40, 20), 80).unwrap();
+        assert!(!stripped.windows(4).any(|w| w == b"Exif"));
+        let decoded = image::load_from_memory(&stripped).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (20, 40));
+
+        assert_eq!(strip_metadata(b"plain text", 80).unwrap(), b"plain text");
+    }
+
+    #[test]
+    fn test_manifest_json_round_trip() {
+        let mut manifest = ImageManifest::new();
+        manifest.insert(
+            "img/hero.jpg",
+            ImageEntry {
+                width: 960,
+                height: 540,
+                src: "/assets/img/hero.jpg".to_string(),
+                sources: vec![ImageSourceSet {
+                    mime_type: "image/webp".to_string(),
+                    srcset: "/assets/img/hero-480w.webp 480w".to_string(),
+                }],
+            },
+        );
+
+        let json = manifest.to_json();
+        assert!(json.contains(r#""type": "image/webp""#));
+        let parsed = ImageManifest::from_json(&json).unwrap();
+        let hero = parsed.get("/img/hero.jpg").unwrap();
+        assert_eq!(
+            hero.srcset(ImageFormat::Webp),
+            Some("/assets/img/hero-480w.webp 480w")
+        );
+        assert_eq!(hero.srcset(ImageFormat::Avif), None);
+    }
+
+    #[cfg(feature = "images")]
+    struct LabelEncoder;
+
+    #[cfg(feature = "images")]
+    impl ImageEncoder for LabelEncoder {
+        fn encode(
+            &self,
+            _source: &Path,
+            width: u32,
+            format: ImageFormat,
+            _quality: u8,
+        ) -> Result<Vec<u8>, BuildError> {
+            Ok(format!("{}@{}", format.as_str(), width).into_bytes())
+        }
+    }
+
+    #[cfg(feature = "images")]
+    #[test]
+    fn test_image_pipeline_variants() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("assets");
+        fs::create_dir_all(source.join("img")).unwrap();
+        DynamicImage::new_rgb8(1000, 500)
+            .save(source.join("img/hero.png"))
+            .unwrap();
+        fs::write(source.join("img/logo.svg"), "<svg/>").unwrap();
+
+        let output = dir.path().join("static");
+        let mut assets = AssetManifest::new();
+        assets.insert("img/hero.png", "/assets/img/hero.0badf00d.png");
+        let images = ImagePipeline::new(&source, &output)
+            .options(ImageOptions {
+                widths: vec![480, 960, 1440],
+                ..ImageOptions::default()
+            })
+            .fingerprint(false)
+            .run(&assets, &LabelEncoder)
+            .unwrap();
+
+        assert_eq!(images.len(), 1);
+        let hero = images.get("img/hero.png").unwrap();
+        assert_eq!((hero.width, hero.height), (1000, 500));
+        assert_eq!(hero.src, "/assets/img/hero.0badf00d.png");
+        assert_eq!(
+            hero.srcset(ImageFormat::Avif),
+            Some("/assets/img/hero-480w.avif 480w, /assets/img/hero-960w.avif 960w, /assets/img/hero-1000w.avif 1000w")
+        );
+        assert_eq!(
+            fs::read_to_string(output.join("assets/img/hero-960w.webp")).unwrap(),
+            "webp@960"
+        );
+        assert_eq!(
+            ImageManifest::load(output.join(IMAGE_MANIFEST_FILE)).unwrap(),
+            images
+        );
+    }
+}
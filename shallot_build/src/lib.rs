@@ -1,31 +1,90 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::fs;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub mod assets;
+pub mod budget;
+pub mod config;
+pub mod content;
+pub mod images;
+pub mod markdown;
+pub mod parallel;
+pub mod watch;
+
+pub use assets::{asset_url, AssetManifest, AssetPipeline, MANIFEST_FILE};
+pub use budget::{BudgetKind, BudgetReport, BudgetViolation};
+pub use content::{
+    Collection, ContentEntry, ContentError, ContentReport, FRONT_MATTER_DELIMITER,
+};
+pub use shallot_foundation::CalendarDate;
+pub use markdown::markdown_to_html;
+pub use parallel::Page;
+#[cfg(feature = "images")]
+pub use images::{strip_metadata, ImageEncoder, ImagePipeline, RasterEncoder};
+pub use images::{ImageEntry, ImageFormat, ImageManifest, ImageSourceSet, IMAGE_MANIFEST_FILE};
+pub use config::{
+    AssetOptions, BudgetOptions, ConfigError, ContentOptions, ImageOptions, SsrOptions, StaticOptions, WasmOptions, WatchOptions, CONFIG_FILE,
+};
+/// Environment variable telling a `[static] generator` binary where to
+/// write the site
+pub const SITE_DIR_VAR: &str = "SHALLOT_SITE_DIR";
+
+pub use watch::{Change, ChangeKind, RebuildScope, WatchEvent, DEV_PAGE};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BuildConfig {
     pub name: String,
     pub version: String,
     pub targets: Vec<BuildTarget>,
+    #[serde(default)]
     pub features: Vec<String>,
     pub optimization: OptimizationLevel,
     pub output_dir: PathBuf,
+    /// Worker threads for targets and pages; every core when unset
+    #[serde(default)]
+    pub jobs: Option<usize>,
+    #[serde(default)]
+    pub wasm: WasmOptions,
+    #[serde(default, rename = "static")]
+    pub static_site: StaticOptions,
+    #[serde(default)]
+    pub ssr: SsrOptions,
+    #[serde(default)]
+    pub watch: WatchOptions,
+    #[serde(default)]
+    pub assets: AssetOptions,
+    #[serde(default)]
+    pub content: ContentOptions,
+    #[serde(default)]
+    pub images: ImageOptions,
+    #[serde(default)]
+    pub budgets: BudgetOptions,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildTarget {
+    #[serde(alias = "wasm")]
     WebAssembly,
+    #[serde(alias = "native")]
     NativeDesktop,
+    #[serde(alias = "ssr")]
     ServerSideRendering,
+    #[serde(alias = "static")]
     StaticSite,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OptimizationLevel {
+    #[serde(alias = "development")]
     Development,
+    #[serde(alias = "release")]
     Release,
+    #[serde(alias = "size")]
     SizeOptimized,
+    #[serde(alias = "speed")]
     SpeedOptimized,
 }
 
@@ -50,6 +109,15 @@ impl BuildTarget {
 }
 
 impl OptimizationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptimizationLevel::Development => "development",
+            OptimizationLevel::Release => "release",
+            OptimizationLevel::SizeOptimized => "size",
+            OptimizationLevel::SpeedOptimized => "speed",
+        }
+    }
+
     pub fn cargo_profile(&self) -> &'static str {
         match self {
             OptimizationLevel::Development => "dev",
@@ -79,17 +147,18 @@ impl BuildSystem {
         Self { config }
     }
 
+    /// Build every target on the `jobs` thread pool; results keep the
+    /// order of `targets`, and the first failing target's error is returned
     pub fn build_all(&self) -> Result<Vec<BuildResult>, BuildError> {
-        let mut results = Vec::new();
-
-        for target in &self.config.targets {
-            match self.build_target(*target) {
-                Ok(result) => results.push(result),
-                Err(error) => return Err(error),
-            }
-        }
-
-        Ok(results)
+        let results: Vec<_> = self.thread_pool()?.install(|| {
+            self.config
+                .targets
+                .par_iter()
+                .map(|target| self.build_target(*target))
+                .collect()
+        });
+
+        results.into_iter().collect()
     }
 
     pub fn build_target(&self, target: BuildTarget) -> Result<BuildResult, BuildError> {
@@ -113,11 +182,11 @@ impl BuildSystem {
         let mut cmd = Command::new("wasm-pack");
         cmd.arg("build")
             .arg("--target")
-            .arg("web")
+            .arg(&self.config.wasm.target)
             .arg("--out-dir")
             .arg(&output_dir)
             .arg("--name")
-            .arg(&self.config.name);
+            .arg(self.config.wasm.out_name.as_ref().unwrap_or(&self.config.name));
 
         match self.config.optimization {
             OptimizationLevel::Development => {
@@ -224,7 +293,7 @@ impl BuildSystem {
 
         // Add SSR-specific features
         let mut features = self.config.features.clone();
-        features.push("ssr".to_string());
+        features.extend(self.config.ssr.features.iter().cloned());
         cmd.arg("--features")
             .arg(features.join(" "));
 
@@ -249,14 +318,74 @@ impl BuildSystem {
         let output_dir = self.config.output_dir.join("static");
         fs::create_dir_all(&output_dir)?;
 
-        // Generate static HTML, CSS, and JS files
-        self.generate_static_files(&output_dir)?;
+        // Render the project's pages, or a starter page without a generator
+        match &self.config.static_site.generator {
+            Some(bin) => self.run_generator(bin, &output_dir)?,
+            None => self.generate_static_files(&output_dir)?,
+        }
+        self.process_assets(&output_dir)?;
+        self.check_budgets(&output_dir)?;
+
+        self.static_result(output_dir)
+    }
+
+    /// Run the project's `[static] generator` binary with [`SITE_DIR_VAR`]
+    /// set to `output_dir`
+    fn run_generator(&self, bin: &str, output_dir: &Path) -> Result<(), BuildError> {
+        let mut cmd = Command::new("cargo");
+        cmd.arg("run")
+            .arg("--quiet")
+            .arg("--bin")
+            .arg(bin)
+            .arg("--profile")
+            .arg(self.config.optimization.cargo_profile())
+            .env(SITE_DIR_VAR, output_dir);
+        if !self.config.features.is_empty() {
+            cmd.arg("--features").arg(self.config.features.join(" "));
+        }
+
+        let output = cmd.output()?;
+        if !output.status.success() {
+            return Err(BuildError::StaticBuildFailed(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Copy, fingerprint and compress the `[assets]` directory, if present,
+    /// and with the `images` feature write its responsive image variants
+    fn process_assets(&self, output_dir: &Path) -> Result<Option<AssetManifest>, BuildError> {
+        let options = &self.config.assets;
+        if !options.dir.is_dir() {
+            return Ok(None);
+        }
+
+        let pipeline = AssetPipeline::new(&options.dir, output_dir)
+            .fingerprint(options.fingerprint)
+            .gzip(options.gzip)
+            .brotli(options.brotli);
+        #[cfg(feature = "images")]
+        let pipeline = pipeline.strip_metadata(
+            self.config.images.strip_metadata.then_some(self.config.images.quality),
+        );
+        let manifest = pipeline.run()?;
+
+        #[cfg(feature = "images")]
+        ImagePipeline::new(&options.dir, output_dir)
+            .options(self.config.images.clone())
+            .fingerprint(options.fingerprint)
+            .run(&manifest, &RasterEncoder)?;
+
+        Ok(Some(manifest))
+    }
 
+    fn static_result(&self, output_dir: PathBuf) -> Result<BuildResult, BuildError> {
         Ok(BuildResult {
             target: BuildTarget::StaticSite,
-            output_dir: output_dir.clone(),
             artifacts: self.find_static_artifacts(&output_dir)?,
             size: self.calculate_directory_size(&output_dir)?,
+            output_dir,
             optimization: self.config.optimization,
         })
     }
@@ -346,6 +475,23 @@ impl BuildSystem {
     }
 
     fn generate_static_files(&self, output_dir: &PathBuf) -> Result<(), BuildError> {
+        self.generate_static_pages(output_dir)?;
+        self.generate_static_styles(output_dir)?;
+
+        Ok(())
+    }
+
+    fn generate_static_pages(&self, output_dir: &Path) -> Result<(), BuildError> {
+        let title = self.config.static_site.title.as_ref().unwrap_or(&self.config.name)
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let script = if self.config.static_site.app_js {
+            "\n    <script src=\"app.js\"></script>"
+        } else {
+            ""
+        };
+
         // Generate index.html
         let index_html = format!(r#"<!DOCTYPE html>
 <html lang="en">
@@ -359,13 +505,25 @@ impl BuildSystem {
     <div id="app">
         <h1>{} Component Library</h1>
         <p>Static site generated successfully!</p>
-    </div>
-    <script src="app.js"></script>
+    </div>{}
 </body>
-</html>"#, self.config.name, self.config.name);
+</html>"#, title, title, script);
 
         fs::write(output_dir.join("index.html"), index_html)?;
 
+        // Generate app.js
+        if self.config.static_site.app_js {
+            let app_js = r#"
+console.log('Shallot.rs Component Library loaded successfully!');
+"#;
+
+            fs::write(output_dir.join("app.js"), app_js)?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_static_styles(&self, output_dir: &Path) -> Result<(), BuildError> {
         // Generate styles.css
         let styles_css = r#"
 :root {
@@ -399,13 +557,6 @@ h1 {
 
         fs::write(output_dir.join("styles.css"), styles_css)?;
 
-        // Generate app.js
-        let app_js = r#"
-console.log('Shallot.rs Component Library loaded successfully!');
-"#;
-
-        fs::write(output_dir.join("app.js"), app_js)?;
-
         Ok(())
     }
 
@@ -492,6 +643,22 @@ pub enum BuildError {
     
     #[error("Walk directory error: {0}")]
     WalkDir(#[from] walkdir::Error),
+
+    #[error("Could not start build threads: {0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+
+    #[error("Size budget exceeded:\n{0}")]
+    BudgetExceeded(BudgetReport),
+
+    #[error("Content error: {0}")]
+    Content(#[from] ContentError),
+
+    #[error("Watch error: {0}")]
+    Watch(#[from] notify::Error),
+
+    #[cfg(feature = "images")]
+    #[error("Image error: {0}")]
+    Image(#[from] image::ImageError),
 }
 
 // Default configurations for different scenarios
@@ -504,6 +671,15 @@ impl BuildConfig {
             features: vec!["dev".to_string()],
             optimization: OptimizationLevel::Development,
             output_dir: PathBuf::from("target/build"),
+            jobs: None,
+            wasm: WasmOptions::default(),
+            static_site: StaticOptions::default(),
+            ssr: SsrOptions::default(),
+            watch: WatchOptions::default(),
+            assets: AssetOptions::default(),
+            content: ContentOptions::default(),
+            images: ImageOptions::default(),
+            budgets: BudgetOptions::default(),
         }
     }
 
@@ -520,6 +696,15 @@ impl BuildConfig {
             features: vec!["prod".to_string()],
             optimization: OptimizationLevel::Release,
             output_dir: PathBuf::from("dist"),
+            jobs: None,
+            wasm: WasmOptions::default(),
+            static_site: StaticOptions::default(),
+            ssr: SsrOptions::default(),
+            watch: WatchOptions::default(),
+            assets: AssetOptions::default(),
+            content: ContentOptions::default(),
+            images: ImageOptions::default(),
+            budgets: BudgetOptions::default(),
         }
     }
 
@@ -531,6 +716,15 @@ impl BuildConfig {
             features: vec!["size-opt".to_string()],
             optimization: OptimizationLevel::SizeOptimized,
             output_dir: PathBuf::from("dist/minimal"),
+            jobs: None,
+            wasm: WasmOptions::default(),
+            static_site: StaticOptions::default(),
+            ssr: SsrOptions::default(),
+            watch: WatchOptions::default(),
+            assets: AssetOptions::default(),
+            content: ContentOptions::default(),
+            images: ImageOptions::default(),
+            budgets: BudgetOptions::default(),
         }
     }
 }
@@ -0,0 +1,175 @@
+//! Markdown rendering for content collections
+//!
+//! [`markdown_to_html`] renders CommonMark through `pulldown-cmark`, with
+//! tables, footnotes, strikethrough and task lists switched on. Headings
+//! get ids for anchor links. Raw HTML is escaped rather than passed
+//! through, and links and images with `javascript:`, `vbscript:` or
+//! `data:` URLs render as their text, so content files cannot inject
+//! markup.
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+
+/// Render Markdown source to an HTML fragment
+pub fn markdown_to_html(source: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+
+    let mut events = Vec::new();
+    // Whether each open link or image was kept, to drop the matching end
+    let mut kept = Vec::new();
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Html(raw) | Event::InlineHtml(raw) => events.push(Event::Text(raw)),
+            Event::Start(Tag::Link { ref dest_url, .. } | Tag::Image { ref dest_url, .. }) => {
+                let safe = is_safe_url(dest_url);
+                kept.push(safe);
+                if safe {
+                    events.push(event);
+                }
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                if kept.pop().unwrap_or(true) {
+                    events.push(event);
+                }
+            }
+            event => events.push(event),
+        }
+    }
+    add_heading_ids(&mut events);
+
+    let mut out = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut out, events.into_iter());
+    out
+}
+
+/// Give every heading an id from its text, adding `-1`, `-2` to repeats
+fn add_heading_ids(events: &mut [Event<'_>]) {
+    let mut ids: HashMap<String, usize> = HashMap::new();
+    for i in 0..events.len() {
+        if !matches!(events[i], Event::Start(Tag::Heading { id: None, .. })) {
+            continue;
+        }
+        let text: String = events[i + 1..]
+            .iter()
+            .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+            .filter_map(|event| match event {
+                Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                _ => None,
+            })
+            .collect();
+        let base = slugify(&text);
+        let count = ids.entry(base.clone()).or_insert(0);
+        let id = if *count == 0 {
+            base
+        } else {
+            format!("{}-{}", base, count)
+        };
+        *count += 1;
+        if let Event::Start(Tag::Heading { id: slot, .. }) = &mut events[i] {
+            *slot = Some(CowStr::from(id));
+        }
+    }
+}
+
+/// Heading text as an anchor id: lowercase words joined by hyphens
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    match slug.trim_end_matches('-') {
+        "" => "section".to_string(),
+        slug => slug.to_string(),
+    }
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let scheme: String = url
+        .chars()
+        .take_while(|&c| c != ':' && c != '/')
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    !(url.contains(':') && matches!(scheme.as_str(), "javascript" | "vbscript" | "data"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let html = markdown_to_html(
+            "# Hello *world*\n\nFirst line\nsecond line  \nbroken\n\n> quoted\n> text\n\n---\n\n```rust\nfn main() {}\nlet x = a < b;\n```\n\n## Hello world\n",
+        );
+        assert_eq!(
+            html,
+            "<h1 id=\"hello-world\">Hello <em>world</em></h1>\n\
+             <p>First line\nsecond line<br />\nbroken</p>\n\
+             <blockquote>\n<p>quoted\ntext</p>\n</blockquote>\n\
+             <hr />\n\
+             <pre><code class=\"language-rust\">fn main() {}\nlet x = a &lt; b;\n</code></pre>\n\
+             <h2 id=\"hello-world-1\">Hello world</h2>\n"
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let html = markdown_to_html(
+            "- one\n- two\n  - nested\n    - deeper\n- three\n\n3. third\n4. fourth\n\nAfter",
+        );
+        assert_eq!(
+            html,
+            "<ul>\n<li>one</li>\n<li>two\n<ul>\n<li>nested\n<ul>\n<li>deeper</li>\n</ul>\n</li>\n</ul>\n</li>\n<li>three</li>\n</ul>\n\
+             <ol start=\"3\">\n<li>third</li>\n<li>fourth</li>\n</ol>\n\
+             <p>After</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_inline() {
+        let html = markdown_to_html(
+            "**Bold** and __strong__, `a < b`, snake_case_name, [docs](/docs \"Docs\"), \
+             ![A *logo*](/logo.png), <https://shallot.rs> and \\*not em\\*",
+        );
+        assert_eq!(
+            html,
+            "<p><strong>Bold</strong> and <strong>strong</strong>, <code>a &lt; b</code>, \
+             snake_case_name, <a href=\"/docs\" title=\"Docs\">docs</a>, \
+             <img src=\"/logo.png\" alt=\"A logo\" />, \
+             <a href=\"https://shallot.rs\">https://shallot.rs</a> and *not em*</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_tables_and_reference_links() {
+        let html = markdown_to_html(
+            "| Name | Size |\n| ---- | ---: |\n| [a][docs] | 1 |\n\n[docs]: /docs \"Docs\"\n",
+        );
+        assert!(html.starts_with(
+            "<table><thead><tr><th>Name</th><th style=\"text-align: right\">Size</th>"
+        ));
+        assert!(html.contains("<td><a href=\"/docs\" title=\"Docs\">a</a></td>"));
+    }
+
+    #[test]
+    fn test_raw_html_and_scripts_are_escaped() {
+        let html = markdown_to_html(
+            "<div onclick=\"x()\">\n\n<script>alert(1)</script>\n\n\
+             Links [x](javascript:alert(1)) ![y](data:image/svg+xml,z)",
+        );
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<div"));
+        assert!(!html.contains("href=\"javascript"));
+        assert!(!html.contains("<img"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("<p>Links x y</p>"));
+    }
+}
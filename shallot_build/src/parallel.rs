@@ -0,0 +1,127 @@
+//! Parallel builds - the `jobs` thread pool and page rendering
+//!
+//! [`BuildSystem::build_all`] builds its targets on this pool, and
+//! [`BuildSystem::render_pages`] renders and writes site pages on it. Both
+//! return results in input order whatever order the work finishes in, so
+//! reports and manifests are the same on every run. `jobs = 1` builds
+//! serially.
+
+use crate::{BuildError, BuildSystem};
+use rayon::prelude::*;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// A rendered page and where it goes, relative to the static output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub path: PathBuf,
+    pub html: String,
+}
+
+impl Page {
+    pub fn new(path: impl Into<PathBuf>, html: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            html: html.into(),
+        }
+    }
+}
+
+impl BuildSystem {
+    /// Thread pool sized by the config's `jobs`
+    pub fn thread_pool(&self) -> Result<rayon::ThreadPool, BuildError> {
+        let mut builder = rayon::ThreadPoolBuilder::new();
+        if let Some(jobs) = self.config.jobs {
+            builder = builder.num_threads(jobs);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Render `items` in parallel and write each [`Page`] under the static
+    /// output directory; returns the written paths in item order
+    pub fn render_pages<T, F>(&self, items: &[T], render: F) -> Result<Vec<PathBuf>, BuildError>
+    where
+        T: Sync,
+        F: Fn(&T) -> Page + Sync,
+    {
+        let output_dir = self.config.output_dir.join("static");
+        let written: Vec<Result<PathBuf, BuildError>> = self.thread_pool()?.install(|| {
+            items
+                .par_iter()
+                .map(|item| write_page(&output_dir, render(item)))
+                .collect()
+        });
+
+        written.into_iter().collect()
+    }
+}
+
+fn write_page(output_dir: &Path, page: Page) -> Result<PathBuf, BuildError> {
+    let escapes = page
+        .path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+    if escapes || page.path.as_os_str().is_empty() {
+        return Err(BuildError::StaticBuildFailed(format!(
+            "page path {} must be relative to the output directory",
+            page.path.display()
+        )));
+    }
+
+    let path = output_dir.join(&page.path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, page.html)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BuildConfig, BuildTarget};
+
+    fn system(root: &Path, jobs: Option<usize>) -> BuildSystem {
+        let mut config = BuildConfig::production_default("site", "0.1.0");
+        config.targets = vec![BuildTarget::StaticSite];
+        config.output_dir = root.join("dist");
+        config.assets.dir = root.join("assets");
+        config.jobs = jobs;
+        BuildSystem::new(config)
+    }
+
+    #[test]
+    fn test_render_pages_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let system = system(dir.path(), Some(4));
+        let slugs: Vec<String> = (0..50).map(|i| format!("post-{}", i)).collect();
+
+        let written = system
+            .render_pages(&slugs, |slug| {
+                Page::new(
+                    format!("blog/{}/index.html", slug),
+                    format!("<h1>{}</h1>", slug),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(written.len(), 50);
+        assert!(written[7].ends_with("blog/post-7/index.html"));
+        assert_eq!(fs::read_to_string(&written[7]).unwrap(), "<h1>post-7</h1>");
+    }
+
+    #[test]
+    fn test_render_pages_rejects_escaping_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let result =
+            system(dir.path(), Some(1)).render_pages(&["x"], |_| Page::new("../outside.html", ""));
+        assert!(matches!(result, Err(BuildError::StaticBuildFailed(_))));
+    }
+
+    #[test]
+    fn test_thread_pool_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = system(dir.path(), Some(3)).thread_pool().unwrap();
+        assert_eq!(pool.current_num_threads(), 3);
+    }
+}
@@ -0,0 +1,337 @@
+//! Watch mode - rebuild when sources change
+//!
+//! [`BuildSystem::watch_with`] subscribes to file events on the `[watch]`
+//! paths through `notify`, gathers each burst of events for `interval_ms`
+//! and rebuilds only what the changes can affect: stylesheets re-emit
+//! `styles.css`, content files re-render the static pages, anything else
+//! (Rust sources, the config) runs every target again. Partial rebuilds
+//! also re-run the asset pipeline, so edited assets get fresh hashes.
+//! Every rebuild is handed to the caller as a [`WatchEvent`]; `shallot
+//! build --watch` prints them.
+//!
+//! With `live_reload`, each static rebuild also writes [`DEV_PAGE`], a copy
+//! of `index.html` that refreshes itself with `<meta http-equiv="refresh">`,
+//! so `shallot preview` shows edits without any JavaScript.
+
+use crate::{BuildError, BuildResult, BuildSystem, BuildTarget};
+use notify::event::{EventKind, ModifyKind, RenameMode};
+use notify::{RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Self-refreshing copy of the index page written in live-reload mode
+pub const DEV_PAGE: &str = "dev.html";
+
+/// Extensions treated as page content rather than code
+const CONTENT_EXTENSIONS: &[&str] = &["html", "md", "markdown", "txt", "json", "svg"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// One file changed on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub path: PathBuf,
+    pub kind: ChangeKind,
+}
+
+/// How much a set of changes needs rebuilt, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RebuildScope {
+    /// Only the static stylesheet
+    Styles,
+    /// The static pages
+    Pages,
+    /// Every configured target
+    Full,
+}
+
+/// What [`BuildSystem::watch_with`] did after a batch of changes
+#[derive(Debug)]
+pub struct WatchEvent {
+    pub changes: Vec<Change>,
+    pub scope: RebuildScope,
+    pub result: Result<Vec<BuildResult>, BuildError>,
+}
+
+impl Change {
+    /// Merge the file changes in a burst of `notify` events into one change
+    /// per path, sorted by path. Access events are ignored, and a file
+    /// added then modified stays added.
+    pub fn from_events(events: impl IntoIterator<Item = notify::Event>) -> Vec<Change> {
+        let mut kinds: BTreeMap<PathBuf, ChangeKind> = BTreeMap::new();
+        for event in events {
+            let kind = match event.kind {
+                EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                    ChangeKind::Added
+                }
+                EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                    ChangeKind::Removed
+                }
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                    if let [from, to, ..] = &event.paths[..] {
+                        kinds.insert(from.clone(), ChangeKind::Removed);
+                        kinds.insert(to.clone(), ChangeKind::Added);
+                    }
+                    continue;
+                }
+                EventKind::Modify(_) | EventKind::Any | EventKind::Other => ChangeKind::Modified,
+                EventKind::Access(_) => continue,
+            };
+            for path in event.paths {
+                let merged = match (kinds.get(&path), kind) {
+                    (Some(ChangeKind::Added), ChangeKind::Modified) => ChangeKind::Added,
+                    _ => kind,
+                };
+                kinds.insert(path, merged);
+            }
+        }
+        kinds
+            .into_iter()
+            .filter(|(path, kind)| *kind == ChangeKind::Removed || !path.is_dir())
+            .map(|(path, kind)| Change { path, kind })
+            .collect()
+    }
+}
+
+impl RebuildScope {
+    /// Scope a change to `path` needs, by extension
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("css") => RebuildScope::Styles,
+            Some(ext) if CONTENT_EXTENSIONS.contains(&ext) => RebuildScope::Pages,
+            _ => RebuildScope::Full,
+        }
+    }
+
+    /// Widest scope any of `changes` needs; `None` when there are none
+    pub fn for_changes(changes: &[Change]) -> Option<Self> {
+        changes
+            .iter()
+            .map(|change| Self::for_path(&change.path))
+            .max()
+    }
+}
+
+impl BuildSystem {
+    /// Build once, then rebuild on every change, calling `on_rebuild` after
+    /// each rebuild; stops when it returns `false`. Failed rebuilds are
+    /// reported through the event rather than ending the watch. Paths that
+    /// do not exist when the watch starts are not watched.
+    pub fn watch_with(
+        &self,
+        mut on_rebuild: impl FnMut(&WatchEvent) -> bool,
+    ) -> Result<(), BuildError> {
+        let interval = Duration::from_millis(self.config.watch.interval_ms);
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        for path in self.config.watch.paths.iter().filter(|path| path.exists()) {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        }
+
+        self.rebuild(RebuildScope::Full)?;
+
+        // Ends when the watcher, and with it the sender, is dropped
+        while let Ok(first) = events.recv() {
+            let mut batch = vec![first?];
+            let deadline = Instant::now() + interval;
+            while let Ok(event) =
+                events.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            {
+                batch.push(event?);
+            }
+
+            let changes: Vec<Change> = Change::from_events(batch)
+                .into_iter()
+                .filter(|change| !change.path.starts_with(&self.config.output_dir))
+                .collect();
+            let Some(scope) = RebuildScope::for_changes(&changes) else {
+                continue;
+            };
+            let event = WatchEvent {
+                changes,
+                scope,
+                result: self.rebuild(scope),
+            };
+            if !on_rebuild(&event) {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    /// Rebuild what `scope` covers. Partial scopes only touch the static
+    /// site target; other targets are rebuilt on [`RebuildScope::Full`].
+    pub fn rebuild(&self, scope: RebuildScope) -> Result<Vec<BuildResult>, BuildError> {
+        let has_static = self.config.targets.contains(&BuildTarget::StaticSite);
+        let results = match scope {
+            RebuildScope::Full => self.build_all()?,
+            _ if !has_static => Vec::new(),
+            RebuildScope::Styles | RebuildScope::Pages => {
+                let output_dir = self.config.output_dir.join("static");
+                fs::create_dir_all(&output_dir)?;
+                if scope == RebuildScope::Styles {
+                    self.generate_static_styles(&output_dir)?;
+                } else {
+                    self.generate_static_pages(&output_dir)?;
+                }
+                self.process_assets(&output_dir)?;
+                self.check_budgets(&output_dir)?;
+                vec![self.static_result(output_dir)?]
+            }
+        };
+
+        if has_static && self.config.watch.live_reload {
+            self.write_dev_page()?;
+        }
+        Ok(results)
+    }
+
+    fn write_dev_page(&self) -> Result<(), BuildError> {
+        let output_dir = self.config.output_dir.join("static");
+        let index_path = output_dir.join("index.html");
+        if !index_path.exists() {
+            return Ok(());
+        }
+        let index = fs::read_to_string(index_path)?;
+        let refresh = self.config.watch.interval_ms.div_ceil(1000).max(1);
+        let meta = format!("<meta http-equiv=\"refresh\" content=\"{}\">", refresh);
+        let page = match index.find("<head>") {
+            Some(at) => {
+                let at = at + "<head>".len();
+                format!("{}\n    {}{}", &index[..at], meta, &index[at..])
+            }
+            None => format!("{}\n{}", meta, index),
+        };
+        fs::write(output_dir.join(DEV_PAGE), page)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildConfig;
+
+    fn static_config(root: &Path) -> BuildConfig {
+        let mut config = BuildConfig::production_default("site", "0.1.0");
+        config.targets = vec![BuildTarget::StaticSite];
+        config.output_dir = root.join("dist");
+        config.watch.paths = vec![root.join("src")];
+        config.assets.dir = root.join("assets");
+        config.watch.interval_ms = 10;
+        config
+    }
+
+    #[test]
+    fn test_changes_from_events() {
+        use notify::event::{CreateKind, DataChange, RemoveKind};
+
+        let event = |kind: EventKind, paths: &[&str]| {
+            paths.iter().fold(notify::Event::new(kind), |event, path| {
+                event.add_path(PathBuf::from(path))
+            })
+        };
+        let changes = Change::from_events([
+            event(EventKind::Create(CreateKind::File), &["new.md"]),
+            event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["new.md", "site.css"],
+            ),
+            event(EventKind::Remove(RemoveKind::File), &["old.rs"]),
+            event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)),
+                &["a.txt", "b.txt"],
+            ),
+            event(
+                EventKind::Access(notify::event::AccessKind::Any),
+                &["read.md"],
+            ),
+        ]);
+
+        let kinds: Vec<(&str, ChangeKind)> = changes
+            .iter()
+            .map(|change| (change.path.to_str().unwrap(), change.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("a.txt", ChangeKind::Removed),
+                ("b.txt", ChangeKind::Added),
+                ("new.md", ChangeKind::Added),
+                ("old.rs", ChangeKind::Removed),
+                ("site.css", ChangeKind::Modified),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rebuild_scope() {
+        assert_eq!(
+            RebuildScope::for_path(Path::new("theme.css")),
+            RebuildScope::Styles
+        );
+        assert_eq!(
+            RebuildScope::for_path(Path::new("posts/hello.md")),
+            RebuildScope::Pages
+        );
+        assert_eq!(
+            RebuildScope::for_path(Path::new("src/main.rs")),
+            RebuildScope::Full
+        );
+
+        let change = |path: &str| Change {
+            path: PathBuf::from(path),
+            kind: ChangeKind::Modified,
+        };
+        assert_eq!(
+            RebuildScope::for_changes(&[change("a.css"), change("b.md")]),
+            Some(RebuildScope::Pages)
+        );
+        assert_eq!(RebuildScope::for_changes(&[]), None);
+    }
+
+    #[test]
+    fn test_rebuild_styles_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let system = BuildSystem::new(static_config(dir.path()));
+        let results = system.rebuild(RebuildScope::Styles).unwrap();
+
+        let static_dir = dir.path().join("dist/static");
+        assert_eq!(results[0].target, BuildTarget::StaticSite);
+        assert!(static_dir.join("styles.css").exists());
+        assert!(!static_dir.join("index.html").exists());
+    }
+
+    #[test]
+    fn test_watch_rebuilds_on_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        let system = BuildSystem::new(static_config(dir.path()));
+
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            fs::write(src.join("site.css"), "body {}").unwrap();
+        });
+        let mut scopes = Vec::new();
+        system
+            .watch_with(|event| {
+                scopes.push(event.scope);
+                false
+            })
+            .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(scopes, vec![RebuildScope::Styles]);
+        let dev = fs::read_to_string(dir.path().join("dist/static").join(DEV_PAGE)).unwrap();
+        assert!(dev.contains(r#"<meta http-equiv="refresh" content="1">"#));
+    }
+}
@@ -0,0 +1,787 @@
+//! `shallot audit` - a report card for a built site
+//!
+//! Every `.html` page in the audited directory gets four groups of checks,
+//! each scored 0-100 like a Lighthouse category:
+//!
+//! - accessibility: page language, image alt text, a `main` landmark and
+//!   [`focus_order_audit`]
+//! - SEO: a title, meta description, viewport and canonical link in the head
+//! - links: local `href`/`src` targets exist and `#fragment` links have a
+//!   matching id on the page
+//! - size: the `[budgets]` page HTML limit, when one is set
+//!
+//! Site-wide budget failures (stylesheets, total output) are reported once
+//! for the whole site. [`render_report`] turns the results into an HTML
+//! page built from shallot components.
+
+use crate::CliError;
+use maud::{html, PreEscaped, Render, DOCTYPE};
+use shallot_build::{BudgetOptions, BudgetReport, BuildError, DEV_PAGE};
+use shallot_components::progress_circle::{ProgressCircleSize, ProgressCircleVariant};
+use shallot_components::{
+    alert_css, badge_css, card_css, component_base_css, focus_order_audit, progress_circle_css,
+    table_css, Alert, AlertKind, Badge, Card, ComponentColor, FocusIssueKind, ProgressCircle,
+    Table, TableColumn, TableRow, Thresholds,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File `shallot audit` writes its report to when `--output` is not given
+pub const REPORT_FILE: &str = "audit.html";
+
+/// A group of checks with its own score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Accessibility,
+    Seo,
+    Links,
+    Size,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [
+        Category::Accessibility,
+        Category::Seo,
+        Category::Links,
+        Category::Size,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::Accessibility => "Accessibility",
+            Category::Seo => "SEO",
+            Category::Links => "Links",
+            Category::Size => "Size",
+        }
+    }
+}
+
+/// One check run against one page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub category: Category,
+    pub name: &'static str,
+    /// What failed, one entry per offending element; empty when it passed
+    pub failures: Vec<String>,
+}
+
+impl Check {
+    fn new(category: Category, name: &'static str, failures: Vec<String>) -> Self {
+        Self {
+            category,
+            name,
+            failures,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Every check run against one page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageAudit {
+    /// Relative to the audited directory
+    pub path: PathBuf,
+    pub checks: Vec<Check>,
+}
+
+impl PageAudit {
+    /// Percentage of the `category` checks that passed; `None` when none ran
+    pub fn score(&self, category: Category) -> Option<u8> {
+        let checks: Vec<&Check> = self
+            .checks
+            .iter()
+            .filter(|check| check.category == category)
+            .collect();
+        let passed = checks.iter().filter(|check| check.passed()).count();
+        percentage(passed, checks.len())
+    }
+
+    /// Mean of the category scores
+    pub fn overall(&self) -> u8 {
+        mean(
+            Category::ALL
+                .iter()
+                .filter_map(|category| self.score(*category)),
+        )
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|check| !check.passed())
+    }
+}
+
+/// The audit of a whole build output directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiteAudit {
+    pub pages: Vec<PageAudit>,
+    /// Every `[budgets]` limit the output breaks, if any
+    pub budgets: Option<BudgetReport>,
+}
+
+impl SiteAudit {
+    /// Mean `category` score over the pages that ran its checks
+    pub fn score(&self, category: Category) -> Option<u8> {
+        let scores: Vec<u8> = self
+            .pages
+            .iter()
+            .filter_map(|page| page.score(category))
+            .collect();
+        (!scores.is_empty()).then(|| mean(scores.into_iter()))
+    }
+
+    /// Mean of the site's category scores
+    pub fn overall(&self) -> u8 {
+        mean(
+            Category::ALL
+                .iter()
+                .filter_map(|category| self.score(*category)),
+        )
+    }
+
+    /// Plain-text scores for the terminal, one line per page
+    pub fn summary(&self) -> String {
+        let scores = |score: &dyn Fn(Category) -> Option<u8>| {
+            Category::ALL
+                .iter()
+                .map(|category| match score(*category) {
+                    Some(value) => format!("{} {:>3}", category.label(), value),
+                    None => format!("{}   -", category.label()),
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+        };
+
+        let mut summary = String::new();
+        for page in &self.pages {
+            summary.push_str(&format!(
+                "{:>3}  {}  {}\n",
+                page.overall(),
+                scores(&|category| page.score(category)),
+                page.path.display()
+            ));
+        }
+        summary.push_str(&format!(
+            "{:>3}  {}  ({} page(s))\n",
+            self.overall(),
+            scores(&|category| self.score(category)),
+            self.pages.len()
+        ));
+        if let Some(report) = &self.budgets {
+            summary.push_str(&report.to_string());
+        }
+        summary
+    }
+}
+
+/// Audit every page under `dir`, checking sizes against `budgets`
+pub fn audit_site(dir: &Path, budgets: &BudgetOptions) -> Result<SiteAudit, CliError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        collect_pages(&entry?.path(), &mut paths)?;
+    }
+    paths.sort();
+
+    let mut pages = Vec::new();
+    for path in paths {
+        let relative = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+        if relative == Path::new(DEV_PAGE) {
+            continue;
+        }
+        let page = fs::read_to_string(&path)?;
+        pages.push(audit_page(dir, &relative, &page, budgets.max_page_html));
+    }
+
+    let budgets = match budgets.check(dir) {
+        Ok(()) => None,
+        Err(BuildError::BudgetExceeded(report)) => Some(report),
+        Err(error) => return Err(error.into()),
+    };
+    Ok(SiteAudit { pages, budgets })
+}
+
+fn collect_pages(path: &Path, pages: &mut Vec<PathBuf>) -> Result<(), CliError> {
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            collect_pages(&entry?.path(), pages)?;
+        }
+    } else if path.extension().is_some_and(|ext| ext == "html") {
+        pages.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Run every check against one page; `page` is relative to `root`, which
+/// local links are resolved against
+pub fn audit_page(root: &Path, page: &Path, source: &str, max_html: Option<u64>) -> PageAudit {
+    let tags = start_tags(source);
+    let find = |name: &str| tags.iter().find(|tag| tag.name == name);
+    let meta = |name: &str| {
+        tags.iter()
+            .find(|tag| tag.name == "meta" && tag.attr("name") == Some(name))
+            .and_then(|tag| tag.attr("content"))
+            .is_some_and(|content| !content.trim().is_empty())
+    };
+    let failed_if = |missing: bool, failure: &str| {
+        if missing {
+            vec![failure.to_string()]
+        } else {
+            Vec::new()
+        }
+    };
+
+    let mut checks = vec![
+        Check::new(
+            Category::Accessibility,
+            "Document has a lang attribute",
+            failed_if(
+                find("html")
+                    .and_then(|tag| tag.attr("lang"))
+                    .is_none_or(|lang| lang.trim().is_empty()),
+                "<html> has no lang",
+            ),
+        ),
+        Check::new(
+            Category::Accessibility,
+            "Images have alt text",
+            tags.iter()
+                .filter(|tag| tag.name == "img" && tag.attr("alt").is_none())
+                .map(|tag| format!("<img src=\"{}\">", tag.attr("src").unwrap_or_default()))
+                .collect(),
+        ),
+        Check::new(
+            Category::Accessibility,
+            "Page has a main landmark",
+            failed_if(
+                !tags
+                    .iter()
+                    .any(|tag| tag.name == "main" || tag.attr("role") == Some("main")),
+                "no <main> or role=\"main\"",
+            ),
+        ),
+        Check::new(
+            Category::Accessibility,
+            "Tab order is keyboard friendly",
+            focus_order_audit(source)
+                .into_iter()
+                .map(|issue| match issue.kind {
+                    FocusIssueKind::PositiveTabindex => {
+                        format!("{} has a positive tabindex", issue.element)
+                    }
+                    FocusIssueKind::Unreachable => {
+                        format!("{} cannot be reached with Tab", issue.element)
+                    }
+                })
+                .collect(),
+        ),
+        Check::new(
+            Category::Seo,
+            "Head has a title",
+            failed_if(
+                element_text(source, "title").is_none_or(|title| title.trim().is_empty()),
+                "no <title> or an empty one",
+            ),
+        ),
+        Check::new(
+            Category::Seo,
+            "Head has a meta description",
+            failed_if(!meta("description"), "no <meta name=\"description\">"),
+        ),
+        Check::new(
+            Category::Seo,
+            "Head has a viewport meta tag",
+            failed_if(!meta("viewport"), "no <meta name=\"viewport\">"),
+        ),
+        Check::new(
+            Category::Seo,
+            "Head has a canonical link",
+            failed_if(
+                !tags.iter().any(|tag| {
+                    tag.name == "link"
+                        && tag.attr("rel") == Some("canonical")
+                        && tag.attr("href").is_some()
+                }),
+                "no <link rel=\"canonical\">",
+            ),
+        ),
+    ];
+
+    let urls: Vec<&str> = tags
+        .iter()
+        .flat_map(|tag| [tag.attr("href"), tag.attr("src")])
+        .flatten()
+        .collect();
+    checks.push(Check::new(
+        Category::Links,
+        "Local links resolve",
+        urls.iter()
+            .filter(|url| is_local(url) && !link_exists(root, page, url))
+            .map(|url| format!("{} is missing", url))
+            .collect(),
+    ));
+    let ids: Vec<&str> = tags
+        .iter()
+        .filter_map(|tag| {
+            tag.attr("id")
+                .or_else(|| tag.attr("name").filter(|_| tag.name == "a"))
+        })
+        .collect();
+    checks.push(Check::new(
+        Category::Links,
+        "Fragment links have targets",
+        urls.iter()
+            .filter_map(|url| url.strip_prefix('#'))
+            .filter(|id| !id.is_empty() && *id != "top" && !ids.contains(id))
+            .map(|id| format!("no element with id=\"{}\"", id))
+            .collect(),
+    ));
+
+    if let Some(limit) = max_html {
+        let size = source.len() as u64;
+        let failures = if size > limit {
+            vec![format!(
+                "{} bytes, {} over the {} byte limit",
+                size,
+                size - limit,
+                limit
+            )]
+        } else {
+            Vec::new()
+        };
+        checks.push(Check::new(
+            Category::Size,
+            "HTML is within the page budget",
+            failures,
+        ));
+    }
+
+    PageAudit {
+        path: page.to_path_buf(),
+        checks,
+    }
+}
+
+/// Whether `url` points at a file in the site rather than another origin
+/// or a place on the same page
+fn is_local(url: &str) -> bool {
+    let url = url.trim();
+    !(url.is_empty() || url.starts_with('#') || url.starts_with("//") || url.contains(':'))
+}
+
+/// Whether the local `url` on `page` names a file under `root`; a
+/// directory URL needs an `index.html`
+fn link_exists(root: &Path, page: &Path, url: &str) -> bool {
+    let path = url.trim().split(['?', '#']).next().unwrap_or_default();
+    if path.is_empty() {
+        return true;
+    }
+
+    let mut resolved = match path.strip_prefix('/') {
+        Some(_) => PathBuf::new(),
+        None => page.parent().map(Path::to_path_buf).unwrap_or_default(),
+    };
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if !resolved.pop() {
+                    return false;
+                }
+            }
+            part => resolved.push(part),
+        }
+    }
+
+    let target = root.join(resolved);
+    if path.ends_with('/') || target.is_dir() {
+        target.join("index.html").is_file()
+    } else {
+        target.is_file()
+    }
+}
+
+/// Render the audit as a standalone HTML page
+pub fn render_report(audit: &SiteAudit) -> String {
+    let thresholds = || {
+        Thresholds::new(ProgressCircleVariant::Success)
+            .below(50.0, ProgressCircleVariant::Danger)
+            .below(90.0, ProgressCircleVariant::Warning)
+    };
+    let badge = |score: Option<u8>| match score {
+        Some(score) => Badge::new(score.to_string())
+            .color(score_color(score))
+            .render(),
+        None => html! { span class="sh-audit__na" { "n/a" } },
+    };
+
+    let mut columns = vec![column("page", "Page")];
+    columns.extend(
+        Category::ALL
+            .iter()
+            .map(|category| column(category.label(), category.label())),
+    );
+    columns.push(column("overall", "Overall"));
+    let rows = audit
+        .pages
+        .iter()
+        .map(|page| {
+            let mut cells = vec![html! { code { (page.path.display()) } }];
+            cells.extend(
+                Category::ALL
+                    .iter()
+                    .map(|category| badge(page.score(*category))),
+            );
+            cells.push(badge(Some(page.overall())));
+            TableRow {
+                cells,
+                id: None,
+                selected: false,
+                disabled: false,
+                href: None,
+            }
+        })
+        .collect();
+    let table = Table::new(columns)
+        .rows(rows)
+        .caption("Scores per page")
+        .striped(true);
+
+    let page = html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                meta name="description" content="Accessibility, SEO, link and size scores for a Shallot site";
+                title { "Site audit" }
+                style { (PreEscaped(report_css())) }
+            }
+            body {
+                main class="sh-audit" {
+                    h1 { "Site audit" }
+                    p { (audit.pages.len()) " page(s), overall score " (audit.overall()) }
+
+                    div class="sh-audit__scores" {
+                        @for category in Category::ALL {
+                            @if let Some(score) = audit.score(category) {
+                                figure class="sh-audit__score" {
+                                    (ProgressCircle::new(score)
+                                        .meter(true)
+                                        .thresholds(thresholds())
+                                        .size(ProgressCircleSize::Lg)
+                                        .label(category.label()))
+                                    figcaption { (category.label()) }
+                                }
+                            }
+                        }
+                    }
+
+                    @if let Some(report) = &audit.budgets {
+                        (Alert::new(
+                            AlertKind::Error,
+                            "Size budgets exceeded",
+                            format!("{} limit(s) broken, largest files first", report.violations.len()),
+                        )
+                        .render())
+                        pre class="sh-audit__budgets" { (report) }
+                    }
+
+                    (table)
+
+                    @for page in audit.pages.iter().filter(|page| page.failed().next().is_some()) {
+                        (Card::new(html! {
+                            ul class="sh-audit__failures" {
+                                @for check in page.failed() {
+                                    li {
+                                        strong { (check.category.label()) ": " (check.name) }
+                                        ul {
+                                            @for failure in &check.failures {
+                                                li { code { (failure) } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        })
+                        .title(page.path.display().to_string())
+                        .subtitle(format!("Overall {}", page.overall())))
+                    }
+                }
+            }
+        }
+    };
+    page.into_string()
+}
+
+fn column<'a>(key: &'a str, label: &'a str) -> TableColumn<'a> {
+    TableColumn {
+        key,
+        label,
+        sortable: false,
+        width: None,
+        align: Default::default(),
+    }
+}
+
+fn score_color(score: u8) -> ComponentColor {
+    match score {
+        0..=49 => ComponentColor::Error,
+        50..=89 => ComponentColor::Warning,
+        _ => ComponentColor::Success,
+    }
+}
+
+fn report_css() -> String {
+    let mut css = component_base_css();
+    for component_css in [
+        alert_css(),
+        badge_css(),
+        card_css(),
+        progress_circle_css(),
+        table_css(),
+    ] {
+        css.push('\n');
+        css.push_str(&component_css);
+    }
+    css.push_str(
+        r#"
+.sh-audit {
+    max-width: 64rem;
+    margin: 0 auto;
+    padding: 2rem 1rem;
+    display: grid;
+    gap: 1.5rem;
+    font-family: system-ui, sans-serif;
+}
+
+.sh-audit__scores {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 2rem;
+}
+
+.sh-audit__score {
+    margin: 0;
+    display: grid;
+    justify-items: center;
+    gap: 0.5rem;
+}
+
+.sh-audit__budgets {
+    margin: 0;
+    overflow-x: auto;
+}
+
+.sh-audit__failures ul {
+    margin: 0.25rem 0 0.75rem;
+}
+"#,
+    );
+    css
+}
+
+fn percentage(passed: usize, total: usize) -> Option<u8> {
+    (total > 0).then(|| ((passed * 100 + total / 2) / total) as u8)
+}
+
+fn mean(scores: impl Iterator<Item = u8>) -> u8 {
+    let (sum, count) = scores.fold((0usize, 0usize), |(sum, count), score| {
+        (sum + usize::from(score), count + 1)
+    });
+    percentage(sum, count * 100).unwrap_or(100)
+}
+
+struct StartTag<'h> {
+    name: String,
+    attrs: Vec<(String, &'h str)>,
+}
+
+impl<'h> StartTag<'h> {
+    fn attr(&self, name: &str) -> Option<&'h str> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Opening tags in document order with their attributes, skipping
+/// comments, doctypes and closing tags
+fn start_tags(html: &str) -> Vec<StartTag<'_>> {
+    let bytes = html.as_bytes();
+    let mut tags = Vec::new();
+    let mut i = 0;
+    while let Some(offset) = html[i..].find('<') {
+        i += offset + 1;
+        if html[i..].starts_with("!--") {
+            i = html[i..].find("-->").map_or(html.len(), |end| i + end + 3);
+            continue;
+        }
+        if !bytes.get(i).is_some_and(u8::is_ascii_alphabetic) {
+            continue;
+        }
+        let word_end = |from: usize, stops: &[u8]| {
+            (from..bytes.len())
+                .find(|&j| bytes[j].is_ascii_whitespace() || stops.contains(&bytes[j]))
+                .unwrap_or(bytes.len())
+        };
+        let name_end = word_end(i, b">/");
+        let mut tag = StartTag {
+            name: html[i..name_end].to_ascii_lowercase(),
+            attrs: Vec::new(),
+        };
+        i = name_end;
+
+        loop {
+            while bytes
+                .get(i)
+                .is_some_and(|b| b.is_ascii_whitespace() || *b == b'/')
+            {
+                i += 1;
+            }
+            if i >= bytes.len() || bytes[i] == b'>' {
+                i += 1;
+                break;
+            }
+            let attr_end = word_end(i, b"=>/");
+            let attr = html[i..attr_end].to_ascii_lowercase();
+            i = attr_end;
+            let mut value = "";
+            if bytes.get(i) == Some(&b'=') {
+                i += 1;
+                match bytes.get(i) {
+                    Some(&quote @ (b'"' | b'\'')) => {
+                        let end = html[i + 1..]
+                            .find(quote as char)
+                            .map_or(html.len(), |end| i + 1 + end);
+                        value = &html[i + 1..end];
+                        i = (end + 1).min(html.len());
+                    }
+                    _ => {
+                        let end = word_end(i, b">");
+                        value = &html[i..end];
+                        i = end;
+                    }
+                }
+            }
+            tag.attrs.push((attr, value));
+        }
+        tags.push(tag);
+    }
+    tags
+}
+
+/// Text inside the first `<name>` element, without nested markup handling
+fn element_text<'h>(html: &'h str, name: &str) -> Option<&'h str> {
+    let lower = html.to_ascii_lowercase();
+    let open = lower.find(&format!("<{}", name))?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find(&format!("</{}", name))?;
+    Some(&html[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOOD: &str = r##"<!DOCTYPE html>
+<html lang="en"><head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width">
+<meta name="description" content="A good page">
+<title>Good</title>
+<link rel="canonical" href="https://example.com/">
+<link rel="stylesheet" href="styles.css?v=2">
+</head><body>
+<a href="#main">Skip</a>
+<main id="main"><img src="/img/logo.png" alt=""><a href="blog/">Blog</a>
+<a href="https://example.com">Out</a></main>
+</body></html>"##;
+
+    fn site() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("blog")).unwrap();
+        fs::create_dir_all(dir.path().join("img")).unwrap();
+        fs::write(dir.path().join("index.html"), GOOD).unwrap();
+        fs::write(dir.path().join("styles.css"), "body {}").unwrap();
+        fs::write(dir.path().join("img/logo.png"), "png").unwrap();
+        fs::write(
+            dir.path().join("blog/index.html"),
+            r##"<html><body><img src="../img/missing.png"><a href="#nowhere" tabindex="2">x</a></body></html>"##,
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_good_page_scores_100() {
+        let dir = site();
+        let audit = audit_page(dir.path(), Path::new("index.html"), GOOD, Some(10_000));
+        assert_eq!(
+            audit.failed().map(|check| check.name).collect::<Vec<_>>(),
+            Vec::<&str>::new()
+        );
+        assert_eq!(audit.overall(), 100);
+        assert_eq!(audit.score(Category::Size), Some(100));
+    }
+
+    #[test]
+    fn test_failing_page() {
+        let dir = site();
+        let audit = audit_site(dir.path(), &BudgetOptions::default()).unwrap();
+        assert_eq!(audit.pages.len(), 2);
+        let blog = &audit.pages[0];
+        assert_eq!(blog.path, Path::new("blog/index.html"));
+        assert_eq!(blog.score(Category::Accessibility), Some(0));
+        assert_eq!(blog.score(Category::Seo), Some(0));
+        assert_eq!(blog.score(Category::Links), Some(0));
+        assert_eq!(blog.score(Category::Size), None);
+        assert_eq!(blog.overall(), 0);
+
+        let failures: Vec<&String> = blog.failed().flat_map(|check| &check.failures).collect();
+        assert!(failures.contains(&&"../img/missing.png is missing".to_string()));
+        assert!(failures.contains(&&"no element with id=\"nowhere\"".to_string()));
+        assert!(failures.contains(&&"a has a positive tabindex".to_string()));
+        assert_eq!(audit.score(Category::Links), Some(50));
+        assert!(audit.budgets.is_none());
+    }
+
+    #[test]
+    fn test_link_resolution() {
+        let dir = site();
+        let root = dir.path();
+        let blog = Path::new("blog/index.html");
+        assert!(link_exists(root, blog, "../styles.css"));
+        assert!(link_exists(root, blog, "/img/logo.png#x"));
+        assert!(link_exists(root, blog, "./"));
+        assert!(!link_exists(root, blog, "../../outside.html"));
+        assert!(!link_exists(root, Path::new("index.html"), "img/"));
+        assert!(!is_local("mailto:team@example.com"));
+        assert!(!is_local("//cdn.example.com/a.js"));
+    }
+
+    #[test]
+    fn test_report_includes_budgets_and_failures() {
+        let dir = site();
+        let budgets = BudgetOptions {
+            max_page_html: Some(200),
+            ..BudgetOptions::default()
+        };
+        let audit = audit_site(dir.path(), &budgets).unwrap();
+        assert_eq!(audit.pages[1].score(Category::Size), Some(0));
+        assert!(audit.budgets.is_some());
+
+        let report = render_report(&audit);
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("Size budgets exceeded"));
+        assert!(report.contains("<code>blog/index.html</code>"));
+        assert!(report.contains("Images have alt text"));
+        assert!(report.contains(r#"role="meter""#));
+        assert!(audit.summary().contains("(2 page(s))"));
+
+        let report_audit = audit_page(dir.path(), Path::new(REPORT_FILE), &report, None);
+        assert_eq!(report_audit.score(Category::Accessibility), Some(100));
+    }
+}
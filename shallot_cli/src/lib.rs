@@ -0,0 +1,479 @@
+//! Shallot CLI - scaffold, build and preview sites without build glue
+//!
+//! ```text
+//! shallot new <name>                  Create a site project in ./<name>
+//! shallot init                        Write a default shallot.toml here
+//! shallot build [--config <file>] [--watch] [--jobs N] [--drafts]
+//!                                     Run the build targets in shallot.toml
+//! shallot preview [<dir>] [--port N]  Serve a build output directory locally
+//! shallot audit [<dir>] [--config <file>] [--output <file>]
+//!                                     Score built pages and write an HTML report
+//! shallot css [<component>...] [--motion full|reduced|none] [--output <file>]
+//!                                     Write a component CSS bundle
+//! ```
+
+pub mod audit;
+pub mod preview;
+pub mod scaffold;
+
+use shallot_build::{
+    BudgetOptions, BuildConfig, BuildError, BuildSystem, ConfigError, CONFIG_FILE,
+};
+use shallot_components::component::component_base_css;
+use shallot_components::css_cache::cached;
+use shallot_components::{all_component_css_for, MotionConfig};
+use std::fs;
+use std::path::PathBuf;
+
+/// Config file read by `shallot build` when `--config` is not given
+pub const DEFAULT_CONFIG: &str = CONFIG_FILE;
+
+/// Directory served by `shallot preview` when none is given
+pub const DEFAULT_PREVIEW_DIR: &str = "dist/static";
+
+pub const DEFAULT_PORT: u16 = 8000;
+
+pub const USAGE: &str = "\
+Usage: shallot <command> [options]
+
+Commands:
+  new <name>                  Create a site project in ./<name>
+  init                        Write a default shallot.toml in this directory
+  build [--config <file>] [--watch] [--jobs N] [--drafts]
+                              Run the build targets in shallot.toml on N
+                              threads, and again on every change with --watch;
+                              --drafts includes drafts and scheduled content
+  preview [<dir>] [--port N]  Serve a build output directory locally
+  audit [<dir>] [--config <file>] [--output <file>]
+                              Check built pages for accessibility, SEO, broken
+                              links and size budgets; writes audit.html
+  css [<component>...] [--motion full|reduced|none] [--output <file>]
+                              Write a component CSS bundle (all components by default)
+  help                        Show this message";
+
+/// A parsed command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    New {
+        name: String,
+    },
+    Init,
+    Build {
+        config: PathBuf,
+        watch: bool,
+        jobs: Option<usize>,
+        drafts: bool,
+    },
+    Preview {
+        dir: PathBuf,
+        port: u16,
+    },
+    Audit {
+        dir: PathBuf,
+        /// Budgets come from here, or from `shallot.toml` when it exists
+        config: Option<PathBuf>,
+        output: PathBuf,
+    },
+    Css {
+        components: Vec<String>,
+        motion: Option<MotionConfig>,
+        output: Option<PathBuf>,
+    },
+    Help,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("{0}\n\n{USAGE}")]
+    Usage(String),
+
+    #[error("Unknown component: {0}")]
+    UnknownComponent(String),
+
+    #[error("Invalid project name: {0}")]
+    InvalidName(String),
+
+    #[error("{0} already exists and is not empty")]
+    DirectoryNotEmpty(PathBuf),
+
+    #[error("{path}: {source}")]
+    Config { path: PathBuf, source: ConfigError },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Build(#[from] BuildError),
+}
+
+fn usage(message: impl Into<String>) -> CliError {
+    CliError::Usage(message.into())
+}
+
+/// Parse the arguments that follow the program name
+pub fn parse_args<I, S>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut args = args.into_iter().map(Into::into);
+    let Some(command) = args.next() else {
+        return Err(usage("No command given"));
+    };
+    let value = |args: &mut dyn Iterator<Item = String>, flag: &str| {
+        args.next()
+            .ok_or_else(|| usage(format!("{} needs a value", flag)))
+    };
+
+    match command.as_str() {
+        "new" => {
+            let name = args
+                .next()
+                .ok_or_else(|| usage("new needs a project name"))?;
+            if let Some(extra) = args.next() {
+                return Err(usage(format!("Unexpected argument: {}", extra)));
+            }
+            Ok(Command::New { name })
+        }
+        "init" => match args.next() {
+            Some(extra) => Err(usage(format!("Unexpected argument: {}", extra))),
+            None => Ok(Command::Init),
+        },
+        "build" => {
+            let mut config = PathBuf::from(DEFAULT_CONFIG);
+            let mut watch = false;
+            let mut jobs = None;
+            let mut drafts = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--config" | "-c" => config = value(&mut args, &arg)?.into(),
+                    "--watch" | "-w" => watch = true,
+                    "--drafts" => drafts = true,
+                    "--jobs" | "-j" => {
+                        let raw = value(&mut args, &arg)?;
+                        match raw.parse() {
+                            Ok(count) if count > 0 => jobs = Some(count),
+                            _ => return Err(usage(format!("Invalid job count: {}", raw))),
+                        }
+                    }
+                    _ => return Err(usage(format!("Unexpected argument: {}", arg))),
+                }
+            }
+            Ok(Command::Build {
+                config,
+                watch,
+                jobs,
+                drafts,
+            })
+        }
+        "preview" => {
+            let mut dir = None;
+            let mut port = DEFAULT_PORT;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--port" | "-p" => {
+                        let raw = value(&mut args, &arg)?;
+                        port = raw
+                            .parse()
+                            .map_err(|_| usage(format!("Invalid port: {}", raw)))?;
+                    }
+                    _ if arg.starts_with('-') => {
+                        return Err(usage(format!("Unknown option: {}", arg)))
+                    }
+                    _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+                    _ => return Err(usage(format!("Unexpected argument: {}", arg))),
+                }
+            }
+            Ok(Command::Preview {
+                dir: dir.unwrap_or_else(|| PathBuf::from(DEFAULT_PREVIEW_DIR)),
+                port,
+            })
+        }
+        "audit" => {
+            let mut dir = None;
+            let mut config = None;
+            let mut output = PathBuf::from(audit::REPORT_FILE);
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--config" | "-c" => config = Some(value(&mut args, &arg)?.into()),
+                    "--output" | "-o" => output = value(&mut args, &arg)?.into(),
+                    _ if arg.starts_with('-') => {
+                        return Err(usage(format!("Unknown option: {}", arg)))
+                    }
+                    _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+                    _ => return Err(usage(format!("Unexpected argument: {}", arg))),
+                }
+            }
+            Ok(Command::Audit {
+                dir: dir.unwrap_or_else(|| PathBuf::from(DEFAULT_PREVIEW_DIR)),
+                config,
+                output,
+            })
+        }
+        "css" => {
+            let mut components = Vec::new();
+            let mut motion = None;
+            let mut output = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--motion" | "-m" => {
+                        let raw = value(&mut args, &arg)?;
+                        motion = Some(parse_motion(&raw)?);
+                    }
+                    "--output" | "-o" => output = Some(value(&mut args, &arg)?.into()),
+                    _ if arg.starts_with('-') => {
+                        return Err(usage(format!("Unknown option: {}", arg)))
+                    }
+                    _ => components.push(arg),
+                }
+            }
+            if motion.is_some() && !components.is_empty() {
+                return Err(usage("--motion applies to the full bundle only"));
+            }
+            Ok(Command::Css {
+                components,
+                motion,
+                output,
+            })
+        }
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        _ => Err(usage(format!("Unknown command: {}", command))),
+    }
+}
+
+fn parse_motion(raw: &str) -> Result<MotionConfig, CliError> {
+    match raw {
+        "full" => Ok(MotionConfig::Full),
+        "reduced" => Ok(MotionConfig::Reduced),
+        "none" => Ok(MotionConfig::None),
+        _ => Err(usage(format!("Unknown motion level: {}", raw))),
+    }
+}
+
+/// Run a parsed command
+pub fn run(command: Command) -> Result<(), CliError> {
+    match command {
+        Command::New { name } => {
+            let root = scaffold::create(&name, ".")?;
+            println!("Created {}", root.display());
+            println!("Next: cd {} && shallot build && shallot preview", name);
+            Ok(())
+        }
+        Command::Init => {
+            let path = PathBuf::from(DEFAULT_CONFIG);
+            BuildConfig::write_default(&path).map_err(|source| CliError::Config {
+                path: path.clone(),
+                source,
+            })?;
+            println!("Created {}", path.display());
+            Ok(())
+        }
+        Command::Build {
+            config,
+            watch,
+            jobs,
+            drafts,
+        } => {
+            let mut config = load_config(&config)?;
+            if jobs.is_some() {
+                config.jobs = jobs;
+            }
+            if drafts {
+                config.content.drafts = true;
+            }
+            let system = BuildSystem::new(config);
+
+            if watch {
+                println!("Watching for changes; press Ctrl-C to stop");
+                Ok(system.watch_with(|event| {
+                    match &event.result {
+                        Ok(results) => println!(
+                            "Rebuilt {:?} ({} target(s)) after {} change(s)",
+                            event.scope,
+                            results.len(),
+                            event.changes.len()
+                        ),
+                        Err(error) => eprintln!("Rebuild failed: {}", error),
+                    }
+                    true
+                })?)
+            } else {
+                let results = system.build_all()?;
+                print!("{}", system.generate_build_report(&results));
+                print!("{}", system.content_report()?);
+                Ok(())
+            }
+        }
+        Command::Preview { dir, port } => preview::serve(&dir, port),
+        Command::Audit {
+            dir,
+            config,
+            output,
+        } => {
+            let default_config = PathBuf::from(DEFAULT_CONFIG);
+            let budgets = match config {
+                Some(path) => load_config(&path)?.budgets,
+                None if default_config.exists() => load_config(&default_config)?.budgets,
+                None => BudgetOptions::default(),
+            };
+            let report = audit::audit_site(&dir, &budgets)?;
+            print!("{}", report.summary());
+            fs::write(&output, audit::render_report(&report))?;
+            println!("Wrote {}", output.display());
+            Ok(())
+        }
+        Command::Css {
+            components,
+            motion,
+            output,
+        } => {
+            let css = css_bundle(&components, motion.unwrap_or_default())?;
+            match output {
+                Some(path) => fs::write(path, css)?,
+                None => print!("{}", css),
+            }
+            Ok(())
+        }
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    }
+}
+
+/// Read a build config, with `SHALLOT_*` environment overrides applied
+pub fn load_config(path: &std::path::Path) -> Result<BuildConfig, CliError> {
+    BuildConfig::from_file(path).map_err(|source| CliError::Config {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// CSS for the named components on top of the base styles, or every
+/// component at `motion` when no names are given
+pub fn css_bundle(components: &[String], motion: MotionConfig) -> Result<String, CliError> {
+    if components.is_empty() {
+        return Ok(all_component_css_for(motion));
+    }
+
+    let mut css = component_base_css();
+    for name in components {
+        let component_css =
+            cached::by_name(name).ok_or_else(|| CliError::UnknownComponent(name.clone()))?;
+        css.push('\n');
+        css.push_str(component_css);
+    }
+    Ok(css)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse_args(["new", "blog"]).unwrap(),
+            Command::New {
+                name: "blog".into()
+            }
+        );
+        assert_eq!(
+            parse_args(["build"]).unwrap(),
+            Command::Build {
+                config: PathBuf::from(DEFAULT_CONFIG),
+                watch: false,
+                jobs: None,
+                drafts: false,
+            }
+        );
+        assert_eq!(
+            parse_args(["build", "--watch", "-c", "site.toml", "-j", "4", "--drafts"]).unwrap(),
+            Command::Build {
+                config: PathBuf::from("site.toml"),
+                watch: true,
+                jobs: Some(4),
+                drafts: true,
+            }
+        );
+        assert_eq!(
+            parse_args(["preview", "out", "--port", "3000"]).unwrap(),
+            Command::Preview {
+                dir: PathBuf::from("out"),
+                port: 3000
+            }
+        );
+        assert_eq!(
+            parse_args(["audit", "-o", "report.html"]).unwrap(),
+            Command::Audit {
+                dir: PathBuf::from(DEFAULT_PREVIEW_DIR),
+                config: None,
+                output: PathBuf::from("report.html"),
+            }
+        );
+        assert_eq!(
+            parse_args(["css", "button", "card", "-o", "site.css"]).unwrap(),
+            Command::Css {
+                components: vec!["button".into(), "card".into()],
+                motion: None,
+                output: Some(PathBuf::from("site.css")),
+            }
+        );
+        assert_eq!(parse_args(["init"]).unwrap(), Command::Init);
+        assert_eq!(parse_args(["--help"]).unwrap(), Command::Help);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            parse_args(Vec::<String>::new()),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(parse_args(["deploy"]), Err(CliError::Usage(_))));
+        assert!(matches!(
+            parse_args(["build", "--config"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(["build", "--jobs", "0"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(["preview", "--port", "http"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(["css", "button", "--motion", "reduced"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_css_bundle() {
+        let css = css_bundle(&["button".into()], MotionConfig::Full).unwrap();
+        assert!(css.starts_with(&component_base_css()));
+        assert!(css.contains(cached::button_css()));
+        assert!(!css.contains(cached::card_css()));
+
+        assert_eq!(
+            css_bundle(&[], MotionConfig::Reduced).unwrap(),
+            all_component_css_for(MotionConfig::Reduced)
+        );
+        assert!(matches!(
+            css_bundle(&["nope".into()], MotionConfig::Full),
+            Err(CliError::UnknownComponent(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_CONFIG);
+        let config = BuildConfig::production_default("site", "0.1.0");
+        fs::write(&path, config.to_toml()).unwrap();
+        assert_eq!(load_config(&path).unwrap().name, "site");
+
+        fs::write(&path, "name = ").unwrap();
+        assert!(matches!(load_config(&path), Err(CliError::Config { .. })));
+    }
+}
@@ -0,0 +1,14 @@
+//! The `shallot` command
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let result = shallot_cli::parse_args(std::env::args().skip(1)).and_then(shallot_cli::run);
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
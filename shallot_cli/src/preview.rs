@@ -0,0 +1,142 @@
+//! `shallot preview` - a local static file server for build output
+//!
+//! Serves one request at a time on `127.0.0.1`; it is for looking at a
+//! build, not for production.
+
+use crate::CliError;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+
+/// Serve `dir` on `http://127.0.0.1:<port>` until the process is stopped
+pub fn serve(dir: &Path, port: u16) -> Result<(), CliError> {
+    if !dir.is_dir() {
+        return Err(CliError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!(
+                "{} is not a directory; run `shallot build` first",
+                dir.display()
+            ),
+        )));
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("Serving {} at http://127.0.0.1:{}/", dir.display(), port);
+
+    for stream in listener.incoming() {
+        if let Err(error) = stream.and_then(|stream| handle(stream, dir)) {
+            eprintln!("preview: {}", error);
+        }
+    }
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, root: &Path) -> std::io::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or("/"));
+
+    let (status, content_type, body) = if method != "GET" && method != "HEAD" {
+        (
+            "405 Method Not Allowed",
+            "text/plain",
+            b"Method Not Allowed".to_vec(),
+        )
+    } else {
+        match resolve(root, target).and_then(|path| fs::read(&path).ok().map(|body| (path, body))) {
+            Some((path, body)) => ("200 OK", content_type(&path), body),
+            None => ("404 Not Found", "text/plain", b"Not Found".to_vec()),
+        }
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    if method != "HEAD" {
+        stream.write_all(&body)?;
+    }
+    stream.flush()
+}
+
+/// File under `root` for a request target; `None` for targets that would
+/// leave `root`. Directories resolve to their `index.html` and extensionless
+/// paths fall back to `<path>.html`.
+pub fn resolve(root: &Path, target: &str) -> Option<PathBuf> {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let mut file = root.to_path_buf();
+    for component in Path::new(path.trim_start_matches('/')).components() {
+        match component {
+            Component::Normal(part) => file.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+
+    if file.is_dir() {
+        file.push("index.html");
+    } else if !file.exists() && file.extension().is_none() {
+        file.set_extension("html");
+    }
+    Some(file)
+}
+
+/// `Content-Type` for a file, by extension
+pub fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json") | Some("webmanifest") => "application/json",
+        Some("xml") => "application/xml",
+        Some("txt") => "text/plain; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        Some("ico") => "image/x-icon",
+        Some("woff2") => "font/woff2",
+        Some("wasm") => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join("docs")).unwrap();
+        fs::write(root.join("about.html"), "").unwrap();
+
+        assert_eq!(resolve(root, "/"), Some(root.join("index.html")));
+        assert_eq!(
+            resolve(root, "/docs/?q=1"),
+            Some(root.join("docs/index.html"))
+        );
+        assert_eq!(resolve(root, "/about"), Some(root.join("about.html")));
+        assert_eq!(resolve(root, "/styles.css"), Some(root.join("styles.css")));
+        assert_eq!(resolve(root, "/../secret"), None);
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(
+            content_type(Path::new("index.html")),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(content_type(Path::new("app.wasm")), "application/wasm");
+        assert_eq!(
+            content_type(Path::new("LICENSE")),
+            "application/octet-stream"
+        );
+    }
+}
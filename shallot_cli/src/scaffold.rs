@@ -0,0 +1,161 @@
+//! `shallot new` - project scaffolding
+//!
+//! A new project is a small binary crate whose `main` renders its pages
+//! with shallot components, plus a `shallot.toml` build config naming it
+//! as the `[static] generator`, so `shallot build` runs it and writes the
+//! site to `dist/static`.
+
+use crate::{CliError, DEFAULT_CONFIG};
+use shallot_build::{BuildConfig, BuildTarget, SITE_DIR_VAR};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory the scaffolded site generator writes its pages to when run
+/// outside `shallot build`
+pub const SITE_DIR: &str = "dist/static";
+
+/// Whether `name` works as both a directory and a crate name
+pub fn is_valid_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Create the project `name` inside `parent`, returning its directory
+pub fn create(name: &str, parent: impl AsRef<Path>) -> Result<PathBuf, CliError> {
+    if !is_valid_name(name) {
+        return Err(CliError::InvalidName(name.to_string()));
+    }
+
+    let root = parent.as_ref().join(name);
+    if root.exists() && fs::read_dir(&root)?.next().is_some() {
+        return Err(CliError::DirectoryNotEmpty(root));
+    }
+    fs::create_dir_all(root.join("src"))?;
+
+    let mut config = BuildConfig::production_default(name, "0.1.0");
+    config.targets = vec![BuildTarget::StaticSite];
+    config.features = Vec::new();
+    config.static_site.generator = Some(name.to_string());
+
+    fs::write(root.join("Cargo.toml"), cargo_toml(name))?;
+    fs::write(root.join(DEFAULT_CONFIG), config.to_toml())?;
+    fs::write(root.join("src/main.rs"), main_rs(name))?;
+    fs::write(root.join(".gitignore"), "/target\n/dist\n")?;
+
+    Ok(root)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+maud = "0.26"
+shallot_components = {{ git = "{repository}", version = "{version}" }}
+"#,
+        repository = env!("CARGO_PKG_REPOSITORY"),
+        version = env!("CARGO_PKG_VERSION"),
+    )
+}
+
+fn main_rs(name: &str) -> String {
+    format!(
+        r#"//! Renders the {name} site into ${SITE_DIR_VAR}, or {SITE_DIR}
+
+use maud::{{html, Markup, Render, DOCTYPE}};
+use shallot_components::{{all_component_css, Button, Card}};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn page(title: &str, body: Markup) -> Markup {{
+    html! {{
+        (DOCTYPE)
+        html lang="en" {{
+            head {{
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title {{ (title) }}
+                link rel="stylesheet" href="shallot.css";
+            }}
+            body {{ (body) }}
+        }}
+    }}
+}}
+
+fn main() {{
+    let out = env::var_os("{SITE_DIR_VAR}")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("{SITE_DIR}"));
+    fs::create_dir_all(&out).expect("Failed to create output directory");
+
+    let home = page(
+        "{name}",
+        html! {{
+            main {{
+                (Card::new(html! {{
+                    p {{ "Built with Shallot: zero JavaScript, all HTML and CSS." }}
+                    (Button::new("Read the docs").href("https://shallot.rs").render())
+                }})
+                .title("{name}")
+                .render())
+            }}
+        }},
+    );
+
+    fs::write(out.join("index.html"), home.into_string()).expect("Failed to write index.html");
+    fs::write(out.join("shallot.css"), all_component_css()).expect("Failed to write shallot.css");
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        assert!(is_valid_name("my-site"));
+        assert!(is_valid_name("blog_2"));
+        assert!(!is_valid_name("2fast"));
+        assert!(!is_valid_name("../site"));
+        assert!(!is_valid_name(""));
+    }
+
+    #[test]
+    fn test_create_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = create("my-site", dir.path()).unwrap();
+
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(r#"name = "my-site""#));
+        assert!(manifest.contains(&format!(r#"version = "{}""#, env!("CARGO_PKG_VERSION"))));
+        let main = fs::read_to_string(root.join("src/main.rs")).unwrap();
+        assert!(main.contains(r#"env::var_os("SHALLOT_SITE_DIR")"#));
+        assert!(main.contains(r#"PathBuf::from("dist/static")"#));
+
+        let config = crate::load_config(&root.join(DEFAULT_CONFIG)).unwrap();
+        assert_eq!(config.name, "my-site");
+        assert!(matches!(config.targets[..], [BuildTarget::StaticSite]));
+        assert_eq!(config.static_site.generator.as_deref(), Some("my-site"));
+        assert_eq!(
+            config.output_dir.join("static"),
+            PathBuf::from(crate::DEFAULT_PREVIEW_DIR)
+        );
+
+        assert!(matches!(
+            create("my-site", dir.path()),
+            Err(CliError::DirectoryNotEmpty(_))
+        ));
+        assert!(matches!(
+            create("bad/name", dir.path()),
+            Err(CliError::InvalidName(_))
+        ));
+    }
+}
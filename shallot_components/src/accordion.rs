@@ -1,24 +1,68 @@
 //! Accordion Component
 //!
 //! Collapsible content panels for organizing information.
+//!
+//! Items render as native `<details>`/`<summary>` pairs, so opening and
+//! closing works without JavaScript and survives find-in-page. Unless
+//! [`Accordion::allow_multiple`] is set, every item shares one `name`
+//! attribute and the browser keeps at most one of them open at a time.
 
 use crate::component::ComponentSize;
 use maud::{html, Markup, Render};
+use shallot_foundation::ClassList;
+use std::borrow::Cow;
 
 pub struct AccordionItem<'a> {
-    pub id: &'a str,
-    pub title: &'a str,
+    pub id: Cow<'a, str>,
+    pub title: Cow<'a, str>,
     pub content: Markup,
     pub open: bool,
     pub disabled: bool,
+    pub nested: Option<Box<Accordion<'a>>>,
+}
+
+impl<'a> AccordionItem<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        content: Markup,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content,
+            open: false,
+            disabled: false,
+            nested: None,
+        }
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Render another accordion below this item's content
+    pub fn nested(mut self, accordion: Accordion<'a>) -> Self {
+        self.nested = Some(Box::new(accordion));
+        self
+    }
 }
 
 pub struct Accordion<'a> {
     items: Vec<AccordionItem<'a>>,
     size: ComponentSize,
     variant: AccordionVariant,
+    marker: AccordionMarker,
     allow_multiple: bool,
     bordered: bool,
+    group: Option<Cow<'a, str>>,
+    default_open: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -30,14 +74,30 @@ pub enum AccordionVariant {
     Minimal,
 }
 
+/// Summary marker shown next to each item title
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccordionMarker {
+    /// Chevron on the trailing edge that flips when open
+    #[default]
+    Chevron,
+    /// Plus sign that turns into a minus when open
+    PlusMinus,
+    /// Caret on the leading edge that rotates a quarter turn
+    Caret,
+    None,
+}
+
 impl<'a> Accordion<'a> {
     pub fn new(items: Vec<AccordionItem<'a>>) -> Self {
         Self {
             items,
             size: ComponentSize::Md,
             variant: AccordionVariant::Default,
+            marker: AccordionMarker::default(),
             allow_multiple: false,
             bordered: false,
+            group: None,
+            default_open: None,
         }
     }
 
@@ -51,6 +111,11 @@ impl<'a> Accordion<'a> {
         self
     }
 
+    pub fn marker(mut self, marker: AccordionMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
     pub fn allow_multiple(mut self, allow: bool) -> Self {
         self.allow_multiple = allow;
         self
@@ -61,17 +126,62 @@ impl<'a> Accordion<'a> {
         self
     }
 
-    fn build_classes(&self) -> String {
-        let mut classes = vec!["sh-accordion".to_string()];
+    /// Name shared by the `<details>` elements of an exclusive accordion.
+    ///
+    /// Defaults to `sh-accordion-{first item id}`; set it explicitly when two
+    /// accordions on one page start with the same item id.
+    pub fn group(mut self, group: impl Into<Cow<'a, str>>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
 
-        classes.push(format!("sh-accordion--{}", self.size.class_suffix()));
-        classes.push(format!("sh-accordion--{}", self.variant_class()));
+    /// Open the item with this id on first render
+    pub fn default_open(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.default_open = Some(id.into());
+        self
+    }
 
-        if self.bordered {
-            classes.push("sh-accordion--bordered".to_string());
+    fn group_name(&self) -> Option<Cow<'_, str>> {
+        if self.allow_multiple {
+            return None;
         }
+        match &self.group {
+            Some(group) => Some(Cow::Borrowed(group.as_ref())),
+            None => self
+                .items
+                .first()
+                .map(|item| Cow::Owned(format!("sh-accordion-{}", item.id))),
+        }
+    }
+
+    /// Which items start open. An exclusive accordion opens at most one,
+    /// preferring [`Accordion::default_open`] over the first `open` item.
+    fn open_states(&self) -> Vec<bool> {
+        let requested = |item: &AccordionItem| {
+            !item.disabled && (item.open || self.default_open.as_deref() == Some(item.id.as_ref()))
+        };
+
+        if self.allow_multiple {
+            return self.items.iter().map(requested).collect();
+        }
+
+        let chosen = self
+            .default_open
+            .as_deref()
+            .and_then(|id| self.items.iter().position(|i| i.id == id && !i.disabled))
+            .or_else(|| self.items.iter().position(|i| i.open && !i.disabled));
+        (0..self.items.len())
+            .map(|idx| Some(idx) == chosen)
+            .collect()
+    }
 
-        classes.join(" ")
+    fn build_classes(&self) -> String {
+        ClassList::new("sh-accordion")
+            .modifier("sh-accordion", self.size.class_suffix())
+            .modifier("sh-accordion", self.variant_class())
+            .modifier("sh-accordion", self.marker_class())
+            .push_if("sh-accordion--bordered", self.bordered)
+            .build()
     }
 
     fn variant_class(&self) -> &'static str {
@@ -82,49 +192,81 @@ impl<'a> Accordion<'a> {
             AccordionVariant::Minimal => "minimal",
         }
     }
+
+    fn marker_class(&self) -> &'static str {
+        match self.marker {
+            AccordionMarker::Chevron => "marker-chevron",
+            AccordionMarker::PlusMinus => "marker-plus",
+            AccordionMarker::Caret => "marker-caret",
+            AccordionMarker::None => "marker-none",
+        }
+    }
+
+    fn render_marker(&self) -> Markup {
+        html! {
+            @match self.marker {
+                AccordionMarker::Chevron => {
+                    span class="sh-accordion-icon" aria-hidden="true" {
+                        svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                            polyline points="6 9 12 15 18 9";
+                        }
+                    }
+                }
+                AccordionMarker::PlusMinus | AccordionMarker::Caret => {
+                    span class="sh-accordion-icon" aria-hidden="true" {}
+                }
+                AccordionMarker::None => {}
+            }
+        }
+    }
 }
 
 impl<'a> Render for Accordion<'a> {
     fn render(&self) -> Markup {
         let accordion_class = self.build_classes();
+        let group = self.group_name();
+        let open_states = self.open_states();
 
         html! {
-            div class=(accordion_class) role="region" {
-                @for item in &self.items {
+            div class=(accordion_class) {
+                @for (item, open) in self.items.iter().zip(open_states) {
                     @let item_id = format!("sh-accordion-{}", item.id);
+                    @let header_id = format!("sh-accordion-header-{}", item.id);
                     @let content_id = format!("sh-accordion-content-{}", item.id);
-                    @let item_classes = {
-                        let mut cls = vec!["sh-accordion-item"];
-                        if item.open { cls.push("sh-accordion-item--open"); }
-                        if item.disabled { cls.push("sh-accordion-item--disabled"); }
-                        cls.join(" ")
-                    };
+                    @let item_classes = ClassList::new("sh-accordion-item")
+                        .push_if("sh-accordion-item--open", open)
+                        .push_if("sh-accordion-item--disabled", item.disabled)
+                        .push_if("sh-accordion-item--has-nested", item.nested.is_some())
+                        .build();
 
                     details
                         class=(item_classes)
                         id=(item_id)
-                        open?[item.open]
+                        name=[group.as_deref()]
+                        open?[open]
                     {
                         summary
                             class="sh-accordion-header"
-                            aria-expanded=(if item.open { "true" } else { "false" })
-                            aria-controls=(content_id)
+                            id=(header_id)
+                            aria-disabled=[item.disabled.then_some("true")]
+                            tabindex=[item.disabled.then_some("-1")]
                         {
                             span class="sh-accordion-title" { (item.title) }
-                            span class="sh-accordion-icon" aria-hidden="true" {
-                                svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                    polyline points="6 9 12 15 18 9";
-                                }
-                            }
+                            (self.render_marker())
                         }
 
                         div
                             class="sh-accordion-content"
                             id=(content_id)
                             role="region"
-                            aria-labelledby=(item_id)
+                            aria-labelledby=(header_id)
                         {
                             (item.content.clone())
+                            @if let Some(nested) = &item.nested {
+                                div class="sh-accordion-nested" {
+                                    (nested.render())
+                                }
+                            }
                         }
                     }
                 }
@@ -170,7 +312,7 @@ pub fn accordion_css() -> String {
     background: var(--sh-surface-hover, #f9fafb);
 }
 
-.sh-accordion-item[open] .sh-accordion-icon {
+.sh-accordion-item[open] > .sh-accordion-header .sh-accordion-icon {
     transform: rotate(180deg);
 }
 
@@ -273,6 +415,94 @@ pub fn accordion_css() -> String {
 .sh-accordion--bordered .sh-accordion-item:last-child {
     border-bottom: none;
 }
+
+/* Markers */
+.sh-accordion--marker-plus .sh-accordion-icon,
+.sh-accordion--marker-caret .sh-accordion-icon {
+    position: relative;
+    width: 1rem;
+    height: 1rem;
+}
+
+.sh-accordion--marker-plus .sh-accordion-icon::before,
+.sh-accordion--marker-plus .sh-accordion-icon::after {
+    content: '';
+    position: absolute;
+    top: 50%;
+    left: 0;
+    width: 100%;
+    height: 2px;
+    margin-top: -1px;
+    border-radius: 1px;
+    background: currentColor;
+    transition: transform 0.2s ease;
+}
+
+.sh-accordion--marker-plus .sh-accordion-icon::after {
+    transform: rotate(90deg);
+}
+
+.sh-accordion--marker-plus .sh-accordion-item[open] > .sh-accordion-header .sh-accordion-icon {
+    transform: none;
+}
+
+.sh-accordion--marker-plus .sh-accordion-item[open] > .sh-accordion-header .sh-accordion-icon::after {
+    transform: rotate(0deg);
+}
+
+.sh-accordion--marker-caret .sh-accordion-header {
+    flex-direction: row-reverse;
+    justify-content: flex-end;
+    gap: 0.75rem;
+}
+
+.sh-accordion--marker-caret .sh-accordion-icon::before {
+    content: '';
+    position: absolute;
+    inset: 0;
+    margin: auto;
+    width: 0;
+    height: 0;
+    border-top: 0.3125rem solid transparent;
+    border-bottom: 0.3125rem solid transparent;
+    border-left: 0.375rem solid currentColor;
+}
+
+.sh-accordion--marker-caret .sh-accordion-item[open] > .sh-accordion-header .sh-accordion-icon {
+    transform: rotate(90deg);
+}
+
+.sh-accordion-header:focus-visible {
+    outline: 2px solid var(--sh-accent, #3b82f6);
+    outline-offset: -2px;
+}
+
+/* Nested accordions */
+.sh-accordion-nested {
+    margin-top: 0.75rem;
+}
+
+.sh-accordion-nested .sh-accordion-item {
+    background: transparent;
+}
+
+.sh-accordion-nested .sh-accordion-header {
+    padding-left: 0.75rem;
+    padding-right: 0.75rem;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-accordion-header,
+    .sh-accordion-icon,
+    .sh-accordion-icon::before,
+    .sh-accordion-icon::after {
+        transition: none;
+    }
+
+    .sh-accordion-content {
+        animation: none;
+    }
+}
 "#
     .to_string()
 }
@@ -281,15 +511,21 @@ pub fn accordion_css() -> String {
 mod tests {
     use super::*;
 
+    fn faq() -> Vec<AccordionItem<'static>> {
+        vec![
+            AccordionItem::new("shipping", "Shipping", html! { p { "Ships in 2 days" } }),
+            AccordionItem::new("returns", "Returns", html! { p { "30 day returns" } }),
+            AccordionItem::new("legacy", "Legacy", html! {}).disabled(true),
+        ]
+    }
+
     #[test]
     fn test_accordion_creation() {
-        let items = vec![AccordionItem {
-            id: "item1",
-            title: "First Section",
-            content: html! { p { "Content here" } },
-            open: true,
-            disabled: false,
-        }];
+        let items =
+            vec![
+                AccordionItem::new("item1", "First Section", html! { p { "Content here" } })
+                    .open(true),
+            ];
 
         let accordion = Accordion::new(items).variant(AccordionVariant::Bordered);
 
@@ -305,5 +541,87 @@ mod tests {
         let classes = accordion.build_classes();
         assert!(classes.contains("sh-accordion--lg"));
         assert!(classes.contains("sh-accordion--bordered"));
+        assert!(classes.contains("sh-accordion--marker-chevron"));
+    }
+
+    #[test]
+    fn test_exclusive_accordion_shares_name() {
+        let html = Accordion::new(faq()).render().into_string();
+        assert_eq!(html.matches(r#"name="sh-accordion-shipping""#).count(), 3);
+
+        let html = Accordion::new(faq()).group("faq").render().into_string();
+        assert_eq!(html.matches(r#"name="faq""#).count(), 3);
+    }
+
+    #[test]
+    fn test_allow_multiple_omits_name() {
+        let html = Accordion::new(faq())
+            .allow_multiple(true)
+            .group("faq")
+            .render()
+            .into_string();
+        assert!(!html.contains("name="));
+    }
+
+    #[test]
+    fn test_exclusive_opens_single_item() {
+        let items = faq().into_iter().map(|item| item.open(true)).collect();
+        let accordion = Accordion::new(items);
+        assert_eq!(accordion.open_states(), vec![true, false, false]);
+
+        let accordion = Accordion::new(faq()).default_open("returns");
+        assert_eq!(accordion.open_states(), vec![false, true, false]);
+        assert!(accordion
+            .render()
+            .into_string()
+            .contains("sh-accordion-item--open"));
+    }
+
+    #[test]
+    fn test_multiple_keeps_every_open_item() {
+        let items = faq().into_iter().map(|item| item.open(true)).collect();
+        let accordion = Accordion::new(items).allow_multiple(true);
+        // Disabled items never start open
+        assert_eq!(accordion.open_states(), vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_accordion_aria() {
+        let html = Accordion::new(faq()).render().into_string();
+
+        assert!(!html.contains("aria-expanded"));
+        assert!(html.contains(r#"id="sh-accordion-header-returns""#));
+        assert!(html.contains(r#"aria-labelledby="sh-accordion-header-returns""#));
+        assert!(html.contains(r#"aria-disabled="true" tabindex="-1""#));
+    }
+
+    #[test]
+    fn test_nested_accordion() {
+        let inner = Accordion::new(vec![AccordionItem::new("inner", "Inner", html! { "Deep" })]);
+        let outer = Accordion::new(vec![
+            AccordionItem::new("outer", "Outer", html! { "Top" }).nested(inner)
+        ]);
+        let html = outer.render().into_string();
+
+        assert!(html.contains("sh-accordion-item--has-nested"));
+        assert!(html.contains("sh-accordion-nested"));
+        assert!(html.contains(r#"name="sh-accordion-inner""#));
+        assert!(html.contains(r#"name="sh-accordion-outer""#));
+    }
+
+    #[test]
+    fn test_marker_variants() {
+        let html = Accordion::new(faq())
+            .marker(AccordionMarker::PlusMinus)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-accordion--marker-plus"));
+        assert!(!html.contains("<svg"));
+
+        let html = Accordion::new(faq())
+            .marker(AccordionMarker::None)
+            .render()
+            .into_string();
+        assert!(!html.contains("sh-accordion-icon"));
     }
 }
@@ -0,0 +1,970 @@
+//! AddressForm Component - Postal address fields laid out per country
+//!
+//! Renders a `fieldset` of address fields to place inside a [`Form`]. Each
+//! [`AddressCountry`] sets the field order and labels, whether the region
+//! is a select (US states) or free text (UK counties), and the postal code
+//! pattern.
+//!
+//! Without JavaScript the layout cannot change as a different country is
+//! picked, so the country select is followed by an "Update" button that
+//! submits with `formnovalidate`. When [`AddressForm::is_country_update`]
+//! is true for a submission, re-render the form for the new country
+//! instead of processing it.
+//!
+//! [`Form`]: crate::form::Form
+
+use crate::form::FormData;
+use crate::input::{Input, Select, SelectOption};
+use maud::{html, Markup, Render};
+use AddressField::{City, Line1, Line2, PostalCode, Region};
+
+/// One field of an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressField {
+    Line1,
+    Line2,
+    City,
+    Region,
+    PostalCode,
+    Country,
+}
+
+impl AddressField {
+    /// Form field suffix
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressField::Line1 => "line1",
+            AddressField::Line2 => "line2",
+            AddressField::City => "city",
+            AddressField::Region => "region",
+            AddressField::PostalCode => "postal_code",
+            AddressField::Country => "country",
+        }
+    }
+
+    /// Country-neutral label, used in error messages
+    pub fn label(&self) -> &'static str {
+        match self {
+            AddressField::Line1 => "Address line 1",
+            AddressField::Line2 => "Address line 2",
+            AddressField::City => "City",
+            AddressField::Region => "Region",
+            AddressField::PostalCode => "Postal code",
+            AddressField::Country => "Country",
+        }
+    }
+
+    fn autocomplete(&self) -> &'static str {
+        match self {
+            AddressField::Line1 => "address-line1",
+            AddressField::Line2 => "address-line2",
+            AddressField::City => "address-level2",
+            AddressField::Region => "address-level1",
+            AddressField::PostalCode => "postal-code",
+            AddressField::Country => "country",
+        }
+    }
+}
+
+/// Address conventions for one country
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressCountry {
+    /// ISO 3166-1 alpha-2 code
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Rows of fields, top to bottom; fields in a row sit side by side
+    pub layout: &'static [&'static [AddressField]],
+    pub city_label: &'static str,
+    pub region_label: &'static str,
+    /// `(code, name)` pairs for a region select, or `None` for free text
+    pub regions: Option<&'static [(&'static str, &'static str)]>,
+    pub region_required: bool,
+    pub postal_label: &'static str,
+    /// HTML `pattern` for the postal code
+    pub postal_pattern: &'static str,
+    pub postal_example: &'static str,
+}
+
+impl AddressCountry {
+    /// Look up a country by ISO code (case-insensitive)
+    pub fn find(code: &str) -> Option<&'static AddressCountry> {
+        ADDRESS_COUNTRIES
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Whether the layout includes `field`
+    pub fn has_field(&self, field: AddressField) -> bool {
+        field == AddressField::Country || self.layout.iter().any(|row| row.contains(&field))
+    }
+
+    /// Whether `postal_code` matches [`postal_pattern`](Self::postal_pattern)
+    pub fn is_valid_postal_code(&self, postal_code: &str) -> bool {
+        regex::Regex::new(&format!("^(?:{})$", self.postal_pattern))
+            .map(|re| re.is_match(postal_code))
+            .unwrap_or(false)
+    }
+
+    /// Region code for a submitted value, matching either code or name
+    fn region_code(&self, value: &str) -> Option<&'static str> {
+        self.regions?
+            .iter()
+            .find(|(code, name)| {
+                code.eq_ignore_ascii_case(value) || name.eq_ignore_ascii_case(value)
+            })
+            .map(|(code, _)| *code)
+    }
+}
+
+const US_STATES: &[(&str, &str)] = &[
+    ("AL", "Alabama"),
+    ("AK", "Alaska"),
+    ("AZ", "Arizona"),
+    ("AR", "Arkansas"),
+    ("CA", "California"),
+    ("CO", "Colorado"),
+    ("CT", "Connecticut"),
+    ("DE", "Delaware"),
+    ("DC", "District of Columbia"),
+    ("FL", "Florida"),
+    ("GA", "Georgia"),
+    ("HI", "Hawaii"),
+    ("ID", "Idaho"),
+    ("IL", "Illinois"),
+    ("IN", "Indiana"),
+    ("IA", "Iowa"),
+    ("KS", "Kansas"),
+    ("KY", "Kentucky"),
+    ("LA", "Louisiana"),
+    ("ME", "Maine"),
+    ("MD", "Maryland"),
+    ("MA", "Massachusetts"),
+    ("MI", "Michigan"),
+    ("MN", "Minnesota"),
+    ("MS", "Mississippi"),
+    ("MO", "Missouri"),
+    ("MT", "Montana"),
+    ("NE", "Nebraska"),
+    ("NV", "Nevada"),
+    ("NH", "New Hampshire"),
+    ("NJ", "New Jersey"),
+    ("NM", "New Mexico"),
+    ("NY", "New York"),
+    ("NC", "North Carolina"),
+    ("ND", "North Dakota"),
+    ("OH", "Ohio"),
+    ("OK", "Oklahoma"),
+    ("OR", "Oregon"),
+    ("PA", "Pennsylvania"),
+    ("RI", "Rhode Island"),
+    ("SC", "South Carolina"),
+    ("SD", "South Dakota"),
+    ("TN", "Tennessee"),
+    ("TX", "Texas"),
+    ("UT", "Utah"),
+    ("VT", "Vermont"),
+    ("VA", "Virginia"),
+    ("WA", "Washington"),
+    ("WV", "West Virginia"),
+    ("WI", "Wisconsin"),
+    ("WY", "Wyoming"),
+];
+
+const CA_PROVINCES: &[(&str, &str)] = &[
+    ("AB", "Alberta"),
+    ("BC", "British Columbia"),
+    ("MB", "Manitoba"),
+    ("NB", "New Brunswick"),
+    ("NL", "Newfoundland and Labrador"),
+    ("NS", "Nova Scotia"),
+    ("NT", "Northwest Territories"),
+    ("NU", "Nunavut"),
+    ("ON", "Ontario"),
+    ("PE", "Prince Edward Island"),
+    ("QC", "Quebec"),
+    ("SK", "Saskatchewan"),
+    ("YT", "Yukon"),
+];
+
+const AU_STATES: &[(&str, &str)] = &[
+    ("ACT", "Australian Capital Territory"),
+    ("NSW", "New South Wales"),
+    ("NT", "Northern Territory"),
+    ("QLD", "Queensland"),
+    ("SA", "South Australia"),
+    ("TAS", "Tasmania"),
+    ("VIC", "Victoria"),
+    ("WA", "Western Australia"),
+];
+
+const STREET_CITY_REGION_POSTAL: &[&[AddressField]] =
+    &[&[Line1], &[Line2], &[City], &[Region, PostalCode]];
+const STREET_CITY_REGION_THEN_POSTAL: &[&[AddressField]] =
+    &[&[Line1], &[Line2], &[City], &[Region], &[PostalCode]];
+const STREET_POSTAL_CITY: &[&[AddressField]] = &[&[Line1], &[Line2], &[PostalCode, City]];
+
+/// Countries offered by default, in select order
+pub const ADDRESS_COUNTRIES: &[AddressCountry] = &[
+    AddressCountry {
+        code: "US",
+        name: "United States",
+        layout: STREET_CITY_REGION_POSTAL,
+        city_label: "City",
+        region_label: "State",
+        regions: Some(US_STATES),
+        region_required: true,
+        postal_label: "ZIP code",
+        postal_pattern: r"\d{5}(-\d{4})?",
+        postal_example: "94103",
+    },
+    AddressCountry {
+        code: "CA",
+        name: "Canada",
+        layout: STREET_CITY_REGION_POSTAL,
+        city_label: "City",
+        region_label: "Province",
+        regions: Some(CA_PROVINCES),
+        region_required: true,
+        postal_label: "Postal code",
+        postal_pattern: r"[A-Za-z]\d[A-Za-z] ?\d[A-Za-z]\d",
+        postal_example: "K1A 0B1",
+    },
+    AddressCountry {
+        code: "GB",
+        name: "United Kingdom",
+        layout: STREET_CITY_REGION_THEN_POSTAL,
+        city_label: "Town or city",
+        region_label: "County (optional)",
+        regions: None,
+        region_required: false,
+        postal_label: "Postcode",
+        postal_pattern: r"[A-Za-z]{1,2}\d[A-Za-z\d]? ?\d[A-Za-z]{2}",
+        postal_example: "SW1A 1AA",
+    },
+    AddressCountry {
+        code: "IE",
+        name: "Ireland",
+        layout: STREET_CITY_REGION_THEN_POSTAL,
+        city_label: "Town or city",
+        region_label: "County",
+        regions: None,
+        region_required: true,
+        postal_label: "Eircode",
+        postal_pattern: r"[A-Za-z]\d[\dWw] ?[A-Za-z\d]{4}",
+        postal_example: "D02 X285",
+    },
+    AddressCountry {
+        code: "DE",
+        name: "Germany",
+        layout: STREET_POSTAL_CITY,
+        city_label: "City",
+        region_label: "State",
+        regions: None,
+        region_required: false,
+        postal_label: "Postal code",
+        postal_pattern: r"\d{5}",
+        postal_example: "10115",
+    },
+    AddressCountry {
+        code: "FR",
+        name: "France",
+        layout: STREET_POSTAL_CITY,
+        city_label: "City",
+        region_label: "Region",
+        regions: None,
+        region_required: false,
+        postal_label: "Postal code",
+        postal_pattern: r"\d{5}",
+        postal_example: "75008",
+    },
+    AddressCountry {
+        code: "AU",
+        name: "Australia",
+        layout: STREET_CITY_REGION_POSTAL,
+        city_label: "Suburb",
+        region_label: "State",
+        regions: Some(AU_STATES),
+        region_required: true,
+        postal_label: "Postcode",
+        postal_pattern: r"\d{4}",
+        postal_example: "2000",
+    },
+    AddressCountry {
+        code: "JP",
+        name: "Japan",
+        layout: &[&[PostalCode], &[Region, City], &[Line1], &[Line2]],
+        city_label: "City",
+        region_label: "Prefecture",
+        regions: None,
+        region_required: true,
+        postal_label: "Postal code",
+        postal_pattern: r"\d{3}-?\d{4}",
+        postal_example: "100-0001",
+    },
+];
+
+/// Why a submitted address was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    Missing(AddressField),
+    UnknownCountry(String),
+    UnknownRegion,
+    InvalidPostalCode,
+}
+
+impl AddressError {
+    /// The field the error belongs to
+    pub fn field(&self) -> AddressField {
+        match self {
+            AddressError::Missing(field) => *field,
+            AddressError::UnknownCountry(_) => AddressField::Country,
+            AddressError::UnknownRegion => AddressField::Region,
+            AddressError::InvalidPostalCode => AddressField::PostalCode,
+        }
+    }
+}
+
+impl std::fmt::Display for AddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressError::Missing(field) => write!(f, "{} is required", field.label()),
+            AddressError::UnknownCountry(code) => write!(f, "unsupported country: {}", code),
+            AddressError::UnknownRegion => write!(f, "Please choose a region from the list"),
+            AddressError::InvalidPostalCode => write!(f, "Please enter a valid postal code"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// A postal address as submitted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Address {
+    pub line1: String,
+    pub line2: String,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+    /// ISO 3166-1 alpha-2 code
+    pub country: String,
+}
+
+impl Address {
+    /// Read the fields an [`AddressForm`] with the same `prefix` submits
+    pub fn from_form(data: &FormData, prefix: &str) -> Self {
+        let get = |field: AddressField| {
+            data.get(&AddressForm::field_name(prefix, field.as_str()))
+                .unwrap_or_default()
+                .to_string()
+        };
+        Self {
+            line1: get(Line1),
+            line2: get(Line2),
+            city: get(City),
+            region: get(Region),
+            postal_code: get(PostalCode),
+            country: get(AddressField::Country),
+        }
+    }
+
+    /// Conventions for [`country`](Self::country)
+    pub fn country_info(&self) -> Option<&'static AddressCountry> {
+        AddressCountry::find(&self.country)
+    }
+
+    fn value(&self, field: AddressField) -> &str {
+        match field {
+            Line1 => &self.line1,
+            Line2 => &self.line2,
+            City => &self.city,
+            Region => &self.region,
+            PostalCode => &self.postal_code,
+            AddressField::Country => &self.country,
+        }
+    }
+
+    /// Check the address against its country's rules
+    pub fn validate(&self) -> Result<(), Vec<AddressError>> {
+        let Some(country) = self.country_info() else {
+            return Err(vec![if self.country.trim().is_empty() {
+                AddressError::Missing(AddressField::Country)
+            } else {
+                AddressError::UnknownCountry(self.country.clone())
+            }]);
+        };
+
+        let mut errors = Vec::new();
+        for field in [Line1, City] {
+            if self.value(field).trim().is_empty() {
+                errors.push(AddressError::Missing(field));
+            }
+        }
+        let region = self.region.trim();
+        if country.has_field(Region) {
+            if region.is_empty() {
+                if country.region_required {
+                    errors.push(AddressError::Missing(Region));
+                }
+            } else if country.regions.is_some() && country.region_code(region).is_none() {
+                errors.push(AddressError::UnknownRegion);
+            }
+        }
+        let postal_code = self.postal_code.trim();
+        if postal_code.is_empty() {
+            errors.push(AddressError::Missing(PostalCode));
+        } else if !country.is_valid_postal_code(postal_code) {
+            errors.push(AddressError::InvalidPostalCode);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Trim every field, uppercase codes, store regions from a select as
+    /// their code, and space UK, Irish and Canadian postcodes
+    pub fn normalize(self) -> Self {
+        let country = self.country.trim().to_uppercase();
+        let info = AddressCountry::find(&country);
+        let region = self.region.trim();
+        let region = info
+            .and_then(|info| info.region_code(region))
+            .map_or_else(|| region.to_string(), str::to_string);
+
+        let mut postal_code: String = self
+            .postal_code
+            .split_whitespace()
+            .collect::<String>()
+            .to_uppercase();
+        let inward = match country.as_str() {
+            "GB" | "CA" => 3,
+            "IE" => 4,
+            _ => 0,
+        };
+        if inward > 0 && postal_code.len() > inward && postal_code.is_ascii() {
+            postal_code.insert(postal_code.len() - inward, ' ');
+        }
+
+        Self {
+            line1: self.line1.trim().to_string(),
+            line2: self.line2.trim().to_string(),
+            city: self.city.trim().to_string(),
+            region,
+            postal_code,
+            country,
+        }
+    }
+
+    /// Lines for a shipping label, following the country's layout
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = match self.country_info() {
+            Some(country) => country
+                .layout
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|field| self.value(*field).trim())
+                        .filter(|value| !value.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .filter(|line| !line.is_empty())
+                .collect(),
+            None => [
+                &self.line1,
+                &self.line2,
+                &self.city,
+                &self.region,
+                &self.postal_code,
+            ]
+            .iter()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect(),
+        };
+        match self.country_info() {
+            Some(country) => lines.push(country.name.to_string()),
+            None if !self.country.is_empty() => lines.push(self.country.clone()),
+            None => {}
+        }
+        lines
+    }
+}
+
+/// AddressForm component
+pub struct AddressForm<'a> {
+    prefix: &'a str,
+    legend: &'a str,
+    address: Option<&'a Address>,
+    country: &'a str,
+    countries: &'a [AddressCountry],
+    errors: &'a [AddressError],
+    disabled: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> AddressForm<'a> {
+    /// Create a new AddressForm
+    pub fn new() -> Self {
+        Self {
+            prefix: "",
+            legend: "Address",
+            address: None,
+            country: "US",
+            countries: ADDRESS_COUNTRIES,
+            errors: &[],
+            disabled: false,
+            class: None,
+        }
+    }
+
+    /// Prefix field names, e.g. "shipping" gives `shipping_city`
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Set the fieldset legend
+    pub fn legend(mut self, legend: &'a str) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Fill the fields; its country takes precedence over [`country`](Self::country)
+    pub fn address(mut self, address: &'a Address) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Country to lay out for when no address is set (default: "US")
+    pub fn country(mut self, code: &'a str) -> Self {
+        self.country = code;
+        self
+    }
+
+    /// Restrict the countries offered
+    pub fn countries(mut self, countries: &'a [AddressCountry]) -> Self {
+        self.countries = countries;
+        self
+    }
+
+    /// Show errors from [`Address::validate`] next to their fields
+    pub fn errors(mut self, errors: &'a [AddressError]) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Add custom class
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Name of a field for the given prefix
+    pub fn field_name(prefix: &str, field: &str) -> String {
+        if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}_{}", prefix, field)
+        }
+    }
+
+    /// Whether a submission came from the "Update" button next to the
+    /// country select rather than the form's own submit button
+    pub fn is_country_update(data: &FormData, prefix: &str) -> bool {
+        data.get(&Self::field_name(prefix, "update")).is_some()
+    }
+
+    fn selected_country(&self) -> Option<&'a AddressCountry> {
+        let code = self
+            .address
+            .map(|address| address.country.as_str())
+            .filter(|code| !code.is_empty())
+            .unwrap_or(self.country);
+        self.countries
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(code))
+            .or(self.countries.first())
+    }
+
+    fn error_for(&self, field: AddressField) -> Option<String> {
+        self.errors
+            .iter()
+            .find(|error| error.field() == field)
+            .map(|error| error.to_string())
+    }
+
+    fn render_field(&self, country: &AddressCountry, field: AddressField) -> Markup {
+        let name = Self::field_name(self.prefix, field.as_str());
+        let value = self.address.map(|address| address.value(field));
+        let error = self.error_for(field);
+
+        if field == Region {
+            if let Some(regions) = country.regions {
+                let selected = value.and_then(|value| country.region_code(value));
+                let options = regions
+                    .iter()
+                    .map(|&(code, label)| SelectOption {
+                        label,
+                        value: code,
+                        selected: selected == Some(code),
+                        disabled: false,
+                    })
+                    .collect();
+                let mut select = Select::new(&name, options)
+                    .label(country.region_label)
+                    .placeholder("Select…")
+                    .required(country.region_required)
+                    .disabled(self.disabled);
+                if let Some(error) = &error {
+                    select = select.error(error);
+                }
+                return select.render();
+            }
+        }
+
+        let (label, required) = match field {
+            Line1 => ("Address line 1", true),
+            Line2 => ("Apartment, suite, etc. (optional)", false),
+            City => (country.city_label, true),
+            Region => (country.region_label, country.region_required),
+            _ => (country.postal_label, true),
+        };
+        let mut input = Input::new(&name)
+            .label(label)
+            .autocomplete(field.autocomplete())
+            .required(required)
+            .disabled(self.disabled);
+        if field == PostalCode {
+            input = input
+                .pattern(country.postal_pattern)
+                .placeholder(country.postal_example);
+        }
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            input = input.value(value);
+        }
+        if let Some(error) = &error {
+            input = input.error(error);
+        }
+        input.render()
+    }
+}
+
+impl<'a> Default for AddressForm<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Render for AddressForm<'a> {
+    fn render(&self) -> Markup {
+        let Some(country) = self.selected_country() else {
+            return html! {};
+        };
+        let classes = match self.class {
+            Some(custom) => format!(
+                "sh-address sh-address--{} {}",
+                country.code.to_lowercase(),
+                custom
+            ),
+            None => format!("sh-address sh-address--{}", country.code.to_lowercase()),
+        };
+        let country_name = Self::field_name(self.prefix, AddressField::Country.as_str());
+        let country_options = self
+            .countries
+            .iter()
+            .map(|option| SelectOption {
+                label: option.name,
+                value: option.code,
+                selected: option.code == country.code,
+                disabled: false,
+            })
+            .collect();
+        let country_error = self.error_for(AddressField::Country);
+        let mut country_select = Select::new(&country_name, country_options)
+            .label("Country")
+            .required(true)
+            .disabled(self.disabled);
+        if let Some(error) = &country_error {
+            country_select = country_select.error(error);
+        }
+
+        html! {
+            fieldset class=(classes) disabled?[self.disabled] {
+                legend class="sh-address__legend" { (self.legend) }
+                div class="sh-address__country" {
+                    (country_select)
+                    button
+                        class="sh-btn sh-btn--secondary sh-btn--sm sh-address__update"
+                        type="submit"
+                        name=(Self::field_name(self.prefix, "update"))
+                        value="1"
+                        formnovalidate {
+                        "Update"
+                        span class="sh-visually-hidden" { " address fields for this country" }
+                    }
+                }
+                @for row in country.layout {
+                    div class="sh-address__row" {
+                        @for field in row.iter() {
+                            (self.render_field(country, *field))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for AddressForm component
+pub fn address_form_css() -> String {
+    r#"
+.sh-address {
+    display: flex;
+    flex-direction: column;
+    gap: 1rem;
+    min-width: 0;
+    margin: 0;
+    padding: 0;
+    border: none;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-address__legend {
+    margin-bottom: 0.5rem;
+    padding: 0;
+    font-size: 1rem;
+    font-weight: 600;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-address__country {
+    display: flex;
+    align-items: flex-end;
+    gap: 0.75rem;
+}
+
+.sh-address__country > .sh-select-wrapper {
+    flex: 1 1 auto;
+}
+
+.sh-address__update {
+    flex: 0 0 auto;
+}
+
+.sh-address__row {
+    display: grid;
+    grid-auto-flow: column;
+    grid-auto-columns: 1fr;
+    gap: 1rem;
+}
+
+/* Postal code before city: keep the postal field narrow */
+.sh-address--de .sh-address__row:has(> :nth-child(2)),
+.sh-address--fr .sh-address__row:has(> :nth-child(2)) {
+    grid-template-columns: 10rem 1fr;
+    grid-auto-flow: row;
+}
+
+@media (max-width: 480px) {
+    .sh-address__row,
+    .sh-address--de .sh-address__row:has(> :nth-child(2)),
+    .sh-address--fr .sh-address__row:has(> :nth-child(2)) {
+        grid-auto-flow: row;
+        grid-template-columns: 1fr;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us_address() -> Address {
+        Address {
+            line1: "1 Market St".to_string(),
+            line2: String::new(),
+            city: "San Francisco".to_string(),
+            region: "California".to_string(),
+            postal_code: "94103".to_string(),
+            country: "US".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_postal_patterns() {
+        let check = |code: &str, postal: &str| {
+            AddressCountry::find(code)
+                .unwrap()
+                .is_valid_postal_code(postal)
+        };
+        assert!(check("US", "94103"));
+        assert!(check("US", "94103-1234"));
+        assert!(!check("US", "9410"));
+        assert!(check("GB", "SW1A 1AA"));
+        assert!(check("GB", "m11ae"));
+        assert!(!check("GB", "12345"));
+        assert!(check("CA", "K1A 0B1"));
+        assert!(check("IE", "D02 X285"));
+        assert!(check("JP", "100-0001"));
+        for country in ADDRESS_COUNTRIES {
+            assert!(
+                country.is_valid_postal_code(country.postal_example),
+                "{}",
+                country.code
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(us_address().validate(), Ok(()));
+
+        let mut bad = us_address();
+        bad.city = " ".to_string();
+        bad.region = "Narnia".to_string();
+        bad.postal_code = "ABC".to_string();
+        assert_eq!(
+            bad.validate(),
+            Err(vec![
+                AddressError::Missing(City),
+                AddressError::UnknownRegion,
+                AddressError::InvalidPostalCode,
+            ])
+        );
+
+        // UK counties are optional free text
+        let uk = Address {
+            line1: "10 Downing St".to_string(),
+            city: "London".to_string(),
+            postal_code: "SW1A 2AA".to_string(),
+            country: "GB".to_string(),
+            ..Address::default()
+        };
+        assert_eq!(uk.validate(), Ok(()));
+
+        let unknown = Address {
+            country: "XX".to_string(),
+            ..Address::default()
+        };
+        assert_eq!(
+            unknown.validate(),
+            Err(vec![AddressError::UnknownCountry("XX".to_string())])
+        );
+        assert_eq!(
+            Address::default().validate(),
+            Err(vec![AddressError::Missing(AddressField::Country)])
+        );
+    }
+
+    #[test]
+    fn test_normalize_and_lines() {
+        let address = us_address().normalize();
+        assert_eq!(address.region, "CA");
+        assert_eq!(
+            address.lines(),
+            vec!["1 Market St", "San Francisco", "CA 94103", "United States"]
+        );
+
+        let uk = Address {
+            line1: " 221B Baker St ".to_string(),
+            city: "London".to_string(),
+            postal_code: "nw16xe".to_string(),
+            country: "gb".to_string(),
+            ..Address::default()
+        }
+        .normalize();
+        assert_eq!(uk.postal_code, "NW1 6XE");
+        assert_eq!(uk.country, "GB");
+        assert_eq!(
+            uk.lines(),
+            vec!["221B Baker St", "London", "NW1 6XE", "United Kingdom"]
+        );
+
+        let de = Address {
+            line1: "Unter den Linden 1".to_string(),
+            city: "Berlin".to_string(),
+            postal_code: "10117".to_string(),
+            country: "DE".to_string(),
+            ..Address::default()
+        };
+        assert_eq!(de.lines()[1], "10117 Berlin");
+    }
+
+    #[test]
+    fn test_from_form() {
+        let data = FormData::from_pairs([
+            ("shipping_line1", "1 Market St"),
+            ("shipping_city", "San Francisco"),
+            ("shipping_region", "CA"),
+            ("shipping_postal_code", "94103"),
+            ("shipping_country", "US"),
+        ]);
+        let address = Address::from_form(&data, "shipping");
+        assert_eq!(address.city, "San Francisco");
+        assert_eq!(address.line2, "");
+        assert!(address.validate().is_ok());
+        assert!(!AddressForm::is_country_update(&data, "shipping"));
+
+        let update = FormData::from_pairs([("shipping_update", "1")]);
+        assert!(AddressForm::is_country_update(&update, "shipping"));
+    }
+
+    #[test]
+    fn test_render_us_layout() {
+        let address = us_address();
+        let html = AddressForm::new()
+            .prefix("shipping")
+            .address(&address)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-address--us"));
+        assert!(html.contains(r#"name="shipping_region""#));
+        assert!(html.contains(r#"<option value="CA" selected>California</option>"#));
+        assert!(html.contains(r#"<option value="US" selected>United States</option>"#));
+        assert!(html.contains("ZIP code"));
+        assert!(html.contains(r#"pattern="\d{5}(-\d{4})?""#));
+        assert!(html.contains(r#"autocomplete="postal-code""#));
+        assert!(html.contains(r#"name="shipping_update" value="1" formnovalidate"#));
+        let city = html.find("shipping_city").unwrap();
+        let postal = html.find("shipping_postal_code").unwrap();
+        assert!(city < postal);
+    }
+
+    #[test]
+    fn test_render_country_layouts() {
+        let uk = AddressForm::new().country("GB").render().into_string();
+        assert!(uk.contains("County (optional)"));
+        assert!(uk.contains(r#"type="text" name="region""#));
+        assert!(uk.contains("Postcode"));
+
+        let de = AddressForm::new().country("DE").render().into_string();
+        assert!(!de.contains(r#"name="region""#));
+        assert!(de.find(r#"name="postal_code""#).unwrap() < de.find(r#"name="city""#).unwrap());
+    }
+
+    #[test]
+    fn test_render_errors() {
+        let errors = [AddressError::InvalidPostalCode];
+        let html = AddressForm::new().errors(&errors).render().into_string();
+        assert!(html.contains("Please enter a valid postal code"));
+        assert!(html.contains("sh-input-wrapper--error"));
+    }
+
+    #[test]
+    fn test_address_form_css() {
+        let css = address_form_css();
+        assert!(css.contains(".sh-address__row"));
+        assert!(css.contains(".sh-address__country"));
+    }
+}
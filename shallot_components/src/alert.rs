@@ -1,4 +1,5 @@
 use maud::{html, Markup};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AlertKind {
@@ -10,16 +11,20 @@ pub enum AlertKind {
 
 pub struct Alert<'a> {
     pub kind: AlertKind,
-    pub title: &'a str,
-    pub message: &'a str,
+    pub title: Cow<'a, str>,
+    pub message: Cow<'a, str>,
 }
 
 impl<'a> Alert<'a> {
-    pub fn new(kind: AlertKind, title: &'a str, message: &'a str) -> Self {
+    pub fn new(
+        kind: AlertKind,
+        title: impl Into<Cow<'a, str>>,
+        message: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             kind,
-            title,
-            message,
+            title: title.into(),
+            message: message.into(),
         }
     }
 
@@ -97,6 +102,15 @@ mod tests {
         assert_eq!(alert.message, "Message");
     }
 
+    #[test]
+    fn test_alert_owned_text() {
+        let name = String::from("deploy");
+        let alert = Alert::new(AlertKind::Error, format!("{} failed", name), "Retrying");
+        assert!(matches!(alert.title, Cow::Owned(_)));
+        assert!(matches!(alert.message, Cow::Borrowed(_)));
+        assert!(alert.render().into_string().contains("deploy failed"));
+    }
+
     #[test]
     fn test_alert_kind_values() {
         assert_eq!(AlertKind::Info as u8, 0);
@@ -7,6 +7,8 @@
 //! - TypingText: Typewriter effect
 
 use crate::component::Component;
+use crate::counter::{render_count, CountAnimation, CountStart, NumberFormat};
+use crate::scroll_reveal::Easing;
 use maud::{html, Markup, Render};
 
 /// Animated gradient text with flowing colors
@@ -185,12 +187,22 @@ impl Render for ShimmerText {
 }
 
 /// Number ticker/counter animation
+///
+/// Counts up with the `sh-count` rules from
+/// [`counter_css`](crate::counter::counter_css); without them the final
+/// value is shown.
 pub struct NumberTicker {
     value: f64,
     prefix: String,
     suffix: String,
     decimals: u8,
+    separator: bool,
+    percent: bool,
     duration: f32,
+    delay: f32,
+    easing: Easing,
+    start: CountStart,
+    animated: bool,
     font_size: String,
     custom_class: Option<String>,
 }
@@ -202,7 +214,13 @@ impl NumberTicker {
             prefix: String::new(),
             suffix: String::new(),
             decimals: 0,
+            separator: false,
+            percent: false,
             duration: 2.0,
+            delay: 0.0,
+            easing: Easing::EaseOutCubic,
+            start: CountStart::Load,
+            animated: true,
             font_size: "2rem".to_string(),
             custom_class: None,
         }
@@ -223,11 +241,54 @@ impl NumberTicker {
         self
     }
 
+    /// Group thousands with commas
+    pub fn separator(mut self, separator: bool) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Show a ratio as a percentage (`0.42` -> `42%`), with a `%` suffix
+    /// unless one is set
+    pub fn percent(mut self) -> Self {
+        self.percent = true;
+        if self.suffix.is_empty() {
+            self.suffix = "%".to_string();
+        }
+        self
+    }
+
     pub fn duration(mut self, duration: f32) -> Self {
         self.duration = duration;
         self
     }
 
+    /// Seconds before counting starts
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn start(mut self, start: CountStart) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Count up while scrolling into view, see [`CountStart::Scroll`]
+    pub fn on_scroll(self, threshold: f32) -> Self {
+        self.start(CountStart::Scroll(threshold))
+    }
+
+    /// Render the final value without counting up
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
     pub fn font_size(mut self, size: impl Into<String>) -> Self {
         self.font_size = size.into();
         self
@@ -238,15 +299,20 @@ impl NumberTicker {
         self
     }
 
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat {
+            decimals: self.decimals,
+            separator: self.separator,
+            percent: self.percent,
+        }
+    }
+
     fn format_value(&self) -> String {
-        format!("{:.prec$}", self.value, prec = self.decimals as usize)
+        self.number_format().format(self.value)
     }
 
     fn build_style(&self) -> String {
-        format!(
-            "--ticker-duration: {}s; --ticker-font-size: {}; --ticker-value: {};",
-            self.duration, self.font_size, self.value
-        )
+        format!("--ticker-font-size: {};", self.font_size)
     }
 }
 
@@ -257,6 +323,12 @@ impl Render for NumberTicker {
             self.custom_class.as_deref().unwrap_or("")
         );
         let value_str = self.format_value();
+        let animation = self.animated.then_some(CountAnimation {
+            duration: self.duration,
+            delay: self.delay,
+            easing: self.easing,
+            start: self.start,
+        });
 
         html! {
             span
@@ -268,7 +340,9 @@ impl Render for NumberTicker {
                 aria-label=(format!("{}{}{}", self.prefix, value_str, self.suffix))
             {
                 span class="sh-number-ticker__prefix" aria-hidden="true" { (self.prefix) }
-                span class="sh-number-ticker__value" { (value_str) }
+                span class="sh-number-ticker__value" {
+                    (render_count(self.value, self.number_format(), animation.as_ref()))
+                }
                 span class="sh-number-ticker__suffix" aria-hidden="true" { (self.suffix) }
             }
         }
@@ -483,6 +557,32 @@ mod tests {
         assert_eq!(ticker.decimals, 2);
     }
 
+    #[test]
+    fn test_number_ticker_count_up() {
+        let html = NumberTicker::new(0.125)
+            .percent()
+            .decimals(1)
+            .easing(Easing::EaseOut)
+            .delay(0.5)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"aria-label="12.5%""#));
+        assert!(
+            html.contains(r#"<span class="sh-number-ticker__suffix" aria-hidden="true">%</span>"#)
+        );
+        assert!(html.contains("--sh-count-easing: ease-out; --sh-count-delay: 0.5s;"));
+        assert!(html.contains(r#"--sh-count-to: 125;"#));
+
+        let still = NumberTicker::new(1200.0)
+            .separator(true)
+            .animated(false)
+            .render()
+            .into_string();
+        assert!(still.contains(
+            r#"<span class="sh-count"><span class="sh-count__static">1,200</span></span>"#
+        ));
+    }
+
     #[test]
     fn test_word_rotate() {
         let rotate = WordRotate::new(vec!["Design", "Build", "Ship", "Repeat"]).duration(3.0);
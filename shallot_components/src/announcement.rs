@@ -0,0 +1,401 @@
+//! Announcement Component - Top-of-page bar dismissed with a plain link
+//!
+//! The dismiss link points back at the current page with
+//! `?dismiss={id}`. Before rendering, pass the request's `Cookie` header and
+//! query string to [`DismissedAnnouncements::from_request`]: it records the
+//! id and returns the `Set-Cookie` value to send, so the bar disappears on
+//! this response and stays hidden on later ones.
+//!
+//! ```ignore
+//! let (dismissed, set_cookie) =
+//!     DismissedAnnouncements::from_request(cookie_header, query, 90, true);
+//! if let Some(cookie) = set_cookie {
+//!     response.header("Set-Cookie", cookie);
+//! }
+//! Announcement::new("maintenance-0612", "Scheduled maintenance on June 12")
+//!     .dismiss_action("/pricing")
+//!     .dismissed(&dismissed)
+//! ```
+
+use crate::promo::{cookie_ids, ids_set_cookie, sanitize_id};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Cookie holding the ids of dismissed announcements
+pub const ANNOUNCEMENT_COOKIE: &str = "sh_announcements_dismissed";
+
+/// Query parameter carrying the announcement id on dismiss links
+pub const ANNOUNCEMENT_DISMISS_PARAM: &str = "dismiss";
+
+/// Announcement ids a visitor has dismissed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DismissedAnnouncements {
+    pub ids: Vec<String>,
+}
+
+impl DismissedAnnouncements {
+    /// Read the ids from a `Cookie` request header
+    pub fn from_cookie_header(header: &str) -> Self {
+        Self {
+            ids: cookie_ids(header, ANNOUNCEMENT_COOKIE),
+        }
+    }
+
+    /// Read the ids from the cookie and apply a `?dismiss=` parameter in
+    /// `query` (with or without the leading `?`). Returns the `Set-Cookie`
+    /// value to send when the query dismissed something new.
+    pub fn from_request(
+        cookie_header: &str,
+        query: &str,
+        max_age_days: u32,
+        secure: bool,
+    ) -> (Self, Option<String>) {
+        let dismissed = Self::from_cookie_header(cookie_header);
+        match dismiss_param(query) {
+            Some(id) if !dismissed.contains(&id) => {
+                let dismissed = dismissed.dismiss(&id);
+                let cookie = dismissed.set_cookie(max_age_days, secure);
+                (dismissed, Some(cookie))
+            }
+            _ => (dismissed, None),
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        let id = sanitize_id(id);
+        self.ids.contains(&id)
+    }
+
+    /// Add `id`, ignoring duplicates
+    pub fn dismiss(mut self, id: &str) -> Self {
+        let id = sanitize_id(id);
+        if !id.is_empty() && !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+        self
+    }
+
+    /// `Set-Cookie` header value storing the ids
+    pub fn set_cookie(&self, max_age_days: u32, secure: bool) -> String {
+        ids_set_cookie(ANNOUNCEMENT_COOKIE, &self.ids, max_age_days, secure)
+    }
+}
+
+/// The sanitized `dismiss` value in a query string, if any
+pub fn dismiss_param(query: &str) -> Option<String> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            (name == ANNOUNCEMENT_DISMISS_PARAM).then(|| sanitize_id(value))
+        })
+        .filter(|id| !id.is_empty())
+}
+
+/// How urgent an announcement is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnnouncementSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Critical,
+}
+
+impl AnnouncementSeverity {
+    fn modifier(&self) -> &'static str {
+        match self {
+            AnnouncementSeverity::Info => "info",
+            AnnouncementSeverity::Success => "success",
+            AnnouncementSeverity::Warning => "warning",
+            AnnouncementSeverity::Critical => "critical",
+        }
+    }
+
+    /// Prefix read by screen readers, since the color carries the severity
+    fn prefix(&self) -> Option<&'static str> {
+        match self {
+            AnnouncementSeverity::Warning => Some("Warning: "),
+            AnnouncementSeverity::Critical => Some("Important: "),
+            _ => None,
+        }
+    }
+}
+
+/// Site-wide announcement bar
+pub struct Announcement<'a> {
+    pub id: Cow<'a, str>,
+    pub message: Cow<'a, str>,
+    pub link: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    pub severity: AnnouncementSeverity,
+    pub dismiss_action: Option<Cow<'a, str>>,
+    pub dismissed: bool,
+    pub sticky: bool,
+}
+
+impl<'a> Announcement<'a> {
+    /// `id` identifies the announcement in the dismissal cookie
+    pub fn new(id: impl Into<Cow<'a, str>>, message: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            message: message.into(),
+            link: None,
+            severity: AnnouncementSeverity::default(),
+            dismiss_action: None,
+            dismissed: false,
+            sticky: false,
+        }
+    }
+
+    /// Call-to-action link
+    pub fn link(mut self, label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        self.link = Some((label.into(), href.into()));
+        self
+    }
+
+    pub fn severity(mut self, severity: AnnouncementSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Page the dismiss link points to, usually the current path;
+    /// `?dismiss={id}` is appended
+    pub fn dismiss_action(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.dismiss_action = Some(path.into());
+        self
+    }
+
+    /// Hide the bar when `dismissed` contains its id
+    pub fn dismissed(mut self, dismissed: &DismissedAnnouncements) -> Self {
+        self.dismissed = dismissed.contains(&self.id);
+        self
+    }
+
+    /// Keep the bar at the top of the viewport while scrolling
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        self.sticky = sticky;
+        self
+    }
+
+    fn dismiss_href(&self) -> Option<String> {
+        let path = self.dismiss_action.as_ref()?;
+        let separator = if path.contains('?') { '&' } else { '?' };
+        Some(format!(
+            "{}{}{}={}",
+            path,
+            separator,
+            ANNOUNCEMENT_DISMISS_PARAM,
+            sanitize_id(&self.id)
+        ))
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = format!(
+            "sh-announcement sh-announcement--{}",
+            self.severity.modifier()
+        );
+        if self.sticky {
+            classes.push_str(" sh-announcement--sticky");
+        }
+        classes
+    }
+}
+
+impl<'a> Render for Announcement<'a> {
+    fn render(&self) -> Markup {
+        if self.dismissed {
+            return html! {};
+        }
+
+        html! {
+            aside
+                class=(self.build_classes())
+                id={ "sh-announcement-" (sanitize_id(&self.id)) }
+                aria-label="Announcement" {
+                p class="sh-announcement__message" {
+                    @if let Some(prefix) = self.severity.prefix() {
+                        span class="sh-visually-hidden" { (prefix) }
+                    }
+                    (self.message)
+                }
+                @if let Some((label, href)) = &self.link {
+                    a class="sh-announcement__link" href=(href) {
+                        (label)
+                        span aria-hidden="true" { " →" }
+                    }
+                }
+                @if let Some(href) = self.dismiss_href() {
+                    a class="sh-announcement__dismiss" href=(href) rel="nofollow" {
+                        span aria-hidden="true" { "×" }
+                        span class="sh-visually-hidden" { "Dismiss announcement" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the announcement bar
+pub fn announcement_css() -> String {
+    r#"
+.sh-announcement {
+    position: relative;
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: center;
+    gap: 0.25rem 1rem;
+    padding: 0.625rem 3rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 0.875rem;
+    text-align: center;
+}
+
+.sh-announcement--sticky {
+    position: sticky;
+    top: 0;
+    z-index: 50;
+}
+
+.sh-announcement--info {
+    background: var(--sh-primary, #3b82f6);
+    color: #fff;
+}
+
+.sh-announcement--success {
+    background: var(--sh-success, #22c55e);
+    color: #fff;
+}
+
+.sh-announcement--warning {
+    background: var(--sh-warning, #f59e0b);
+    color: #1f2937;
+}
+
+.sh-announcement--critical {
+    background: var(--sh-error, #ef4444);
+    color: #fff;
+    font-weight: 500;
+}
+
+.sh-announcement__message {
+    margin: 0;
+}
+
+.sh-announcement__link {
+    color: inherit;
+    font-weight: 600;
+    text-decoration: underline;
+    text-underline-offset: 0.2em;
+    white-space: nowrap;
+}
+
+.sh-announcement__dismiss {
+    position: absolute;
+    top: 50%;
+    right: 0.75rem;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    width: 2rem;
+    height: 2rem;
+    margin-top: -1rem;
+    border-radius: 9999px;
+    color: inherit;
+    font-size: 1.25rem;
+    line-height: 1;
+    text-decoration: none;
+}
+
+.sh-announcement__dismiss:hover,
+.sh-announcement__dismiss:focus-visible {
+    background: rgba(0, 0, 0, 0.1);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dismiss_param() {
+        assert_eq!(dismiss_param("?dismiss=launch"), Some("launch".to_string()));
+        assert_eq!(
+            dismiss_param("page=2&dismiss=a%3Bb"),
+            Some("a3Bb".to_string())
+        );
+        assert_eq!(dismiss_param("dismiss="), None);
+        assert_eq!(dismiss_param(""), None);
+    }
+
+    #[test]
+    fn test_dismissed_from_request() {
+        let (dismissed, cookie) = DismissedAnnouncements::from_request(
+            "sh_announcements_dismissed=old",
+            "?dismiss=launch",
+            30,
+            false,
+        );
+        assert!(dismissed.contains("launch"));
+        assert!(dismissed.contains("old"));
+        assert_eq!(
+            cookie.as_deref(),
+            Some("sh_announcements_dismissed=old.launch; Path=/; Max-Age=2592000; SameSite=Lax")
+        );
+
+        // Already dismissed or no parameter: nothing to set
+        let (_, cookie) = DismissedAnnouncements::from_request(
+            "sh_announcements_dismissed=launch",
+            "dismiss=launch",
+            30,
+            false,
+        );
+        assert!(cookie.is_none());
+        let (dismissed, cookie) = DismissedAnnouncements::from_request("", "", 30, false);
+        assert!(dismissed.ids.is_empty());
+        assert!(cookie.is_none());
+    }
+
+    #[test]
+    fn test_announcement_render() {
+        let html = Announcement::new("launch", "Version 2 is here")
+            .link("Read the notes", "/changelog")
+            .severity(AnnouncementSeverity::Critical)
+            .dismiss_action("/pricing")
+            .sticky(true)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-announcement--critical sh-announcement--sticky"));
+        assert!(html.contains(r#"id="sh-announcement-launch""#));
+        assert!(html.contains("Important: "));
+        assert!(html.contains(r#"href="/changelog""#));
+        assert!(html.contains(r#"href="/pricing?dismiss=launch""#));
+    }
+
+    #[test]
+    fn test_announcement_hidden() {
+        let dismissed = DismissedAnnouncements::default().dismiss("launch");
+        let html = Announcement::new("launch", "Hi")
+            .dismissed(&dismissed)
+            .render()
+            .into_string();
+        assert_eq!(html, "");
+
+        let html = Announcement::new("x", "Hi")
+            .dismiss_action("/docs?page=2")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"href="/docs?page=2&amp;dismiss=x""#));
+        assert!(!html.contains("sh-visually-hidden\">Warning"));
+    }
+
+    #[test]
+    fn test_announcement_css() {
+        let css = announcement_css();
+        assert!(css.contains(".sh-announcement--warning"));
+        assert!(css.contains("position: sticky"));
+    }
+}
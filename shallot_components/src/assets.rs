@@ -0,0 +1,458 @@
+//! Site Assets - Favicons and Web App Manifest
+//!
+//! [`Manifest`] produces `site.webmanifest`, the theme-color metadata and
+//! the `<link rel="icon">` head fragment from one description of the site's
+//! icons. Generating the PNG sizes from a source logo goes through an
+//! [`IconResizer`], like `og_image`'s rasterizer; with the `image` feature
+//! enabled, [`ImageResizer`] resizes a raster logo and
+//! [`SiteBuilder::icons`](crate::site::SiteBuilder::icons) writes the
+//! standard sizes alongside the site's pages.
+
+use maud::{html, Markup};
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Preferred display mode of an installed web app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DisplayMode {
+    Fullscreen,
+    Standalone,
+    MinimalUi,
+    #[default]
+    Browser,
+}
+
+/// What an icon may be used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconPurpose {
+    #[default]
+    Any,
+    /// Full-bleed icon with a safe zone, cropped by the platform
+    Maskable,
+    Monochrome,
+}
+
+/// A standard raster icon the site should ship
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IconSpec {
+    pub file_name: &'static str,
+    pub size: u32,
+    pub purpose: IconPurpose,
+}
+
+/// The icon set browsers and platforms ask for
+pub const STANDARD_ICONS: [IconSpec; 6] = [
+    IconSpec {
+        file_name: "favicon-16x16.png",
+        size: 16,
+        purpose: IconPurpose::Any,
+    },
+    IconSpec {
+        file_name: "favicon-32x32.png",
+        size: 32,
+        purpose: IconPurpose::Any,
+    },
+    IconSpec {
+        file_name: "apple-touch-icon.png",
+        size: 180,
+        purpose: IconPurpose::Any,
+    },
+    IconSpec {
+        file_name: "icon-192.png",
+        size: 192,
+        purpose: IconPurpose::Any,
+    },
+    IconSpec {
+        file_name: "icon-512.png",
+        size: 512,
+        purpose: IconPurpose::Any,
+    },
+    IconSpec {
+        file_name: "icon-maskable-512.png",
+        size: 512,
+        purpose: IconPurpose::Maskable,
+    },
+];
+
+/// Resizes a source logo into a square PNG of `size` pixels
+pub trait IconResizer {
+    type Error: From<io::Error>;
+
+    fn resize(
+        &self,
+        source: &Path,
+        size: u32,
+        purpose: IconPurpose,
+    ) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// [`IconResizer`] backed by the `image` crate, for PNG, JPEG and WebP logos
+///
+/// The logo is scaled to fit and centered on a transparent square; maskable
+/// icons shrink it into the central 80% safe zone over an opaque background.
+#[cfg(feature = "image")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageResizer {
+    maskable_background: [u8; 4],
+}
+
+#[cfg(feature = "image")]
+impl ImageResizer {
+    pub fn new() -> Self {
+        Self {
+            maskable_background: [255, 255, 255, 255],
+        }
+    }
+
+    /// Fill behind maskable icons, as RGBA
+    pub fn maskable_background(mut self, rgba: [u8; 4]) -> Self {
+        self.maskable_background = rgba;
+        self
+    }
+}
+
+#[cfg(feature = "image")]
+impl Default for ImageResizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "image")]
+impl IconResizer for ImageResizer {
+    type Error = io::Error;
+
+    fn resize(&self, source: &Path, size: u32, purpose: IconPurpose) -> io::Result<Vec<u8>> {
+        use image::{imageops, ImageFormat, Rgba, RgbaImage};
+
+        let logo = image::open(source).map_err(io::Error::other)?;
+        let (fill, inner) = match purpose {
+            IconPurpose::Maskable => (Rgba(self.maskable_background), size * 4 / 5),
+            _ => (Rgba([0, 0, 0, 0]), size),
+        };
+        let logo = logo
+            .resize(inner.max(1), inner.max(1), imageops::FilterType::Lanczos3)
+            .to_rgba8();
+
+        let mut icon = RgbaImage::from_pixel(size, size, fill);
+        let x = (size - logo.width()) / 2;
+        let y = (size - logo.height()) / 2;
+        imageops::overlay(&mut icon, &logo, x.into(), y.into());
+
+        let mut png = io::Cursor::new(Vec::new());
+        icon.write_to(&mut png, ImageFormat::Png)
+            .map_err(io::Error::other)?;
+        Ok(png.into_inner())
+    }
+}
+
+/// Manifest `icons` entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ManifestIcon {
+    pub src: String,
+    pub sizes: String,
+    #[serde(rename = "type")]
+    pub mime_type: String,
+    pub purpose: IconPurpose,
+}
+
+/// `site.webmanifest` builder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Manifest {
+    pub name: String,
+    pub short_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub start_url: String,
+    pub scope: String,
+    pub display: DisplayMode,
+    pub background_color: String,
+    pub theme_color: String,
+    #[serde(skip)]
+    pub theme_color_dark: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<ManifestIcon>,
+    #[serde(skip)]
+    pub icon_base: String,
+    #[serde(skip)]
+    pub svg_icon: Option<String>,
+}
+
+impl Manifest {
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            short_name: name.clone(),
+            name,
+            description: None,
+            start_url: "/".to_string(),
+            scope: "/".to_string(),
+            display: DisplayMode::default(),
+            background_color: "#ffffff".to_string(),
+            theme_color: "#ffffff".to_string(),
+            theme_color_dark: None,
+            icons: Vec::new(),
+            icon_base: "/".to_string(),
+            svg_icon: None,
+        }
+    }
+
+    /// Home screen label (defaults to the name)
+    pub fn short_name(mut self, short_name: impl Into<String>) -> Self {
+        self.short_name = short_name.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn start_url(mut self, start_url: impl Into<String>) -> Self {
+        self.start_url = start_url.into();
+        self
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    pub fn display(mut self, display: DisplayMode) -> Self {
+        self.display = display;
+        self
+    }
+
+    pub fn background_color(mut self, color: impl Into<String>) -> Self {
+        self.background_color = color.into();
+        self
+    }
+
+    pub fn theme_color(mut self, color: impl Into<String>) -> Self {
+        self.theme_color = color.into();
+        self
+    }
+
+    /// Theme color under `prefers-color-scheme: dark` (head metadata only)
+    pub fn theme_color_dark(mut self, color: impl Into<String>) -> Self {
+        self.theme_color_dark = Some(color.into());
+        self
+    }
+
+    /// Scalable favicon, linked ahead of the PNG fallbacks
+    pub fn svg_icon(mut self, href: impl Into<String>) -> Self {
+        self.svg_icon = Some(href.into());
+        self
+    }
+
+    /// Add a custom manifest icon
+    pub fn icon(mut self, icon: ManifestIcon) -> Self {
+        self.icons.push(icon);
+        self
+    }
+
+    /// Reference the [`STANDARD_ICONS`] set, served from `base` (e.g. `/icons/`)
+    pub fn standard_icons(mut self, base: impl Into<String>) -> Self {
+        let mut base = base.into();
+        if !base.ends_with('/') {
+            base.push('/');
+        }
+        let previous = std::mem::take(&mut self.icon_base);
+        self.icons.retain(|icon| !is_standard(&icon.src, &previous));
+        self.icons.extend(
+            STANDARD_ICONS
+                .iter()
+                // Only the install sizes belong in the manifest
+                .filter(|spec| spec.size >= 192)
+                .map(|spec| ManifestIcon {
+                    src: format!("{}{}", base, spec.file_name),
+                    sizes: format!("{0}x{0}", spec.size),
+                    mime_type: "image/png".to_string(),
+                    purpose: spec.purpose,
+                }),
+        );
+        self.icon_base = base;
+        self
+    }
+
+    /// Serialize as `site.webmanifest`
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// `<head>` fragment: manifest link, theme colors and favicon links
+    pub fn head_tags(&self, manifest_href: &str) -> Markup {
+        let base = &self.icon_base;
+        html! {
+            link rel="manifest" href=(manifest_href);
+            @if let Some(dark) = &self.theme_color_dark {
+                meta name="theme-color" media="(prefers-color-scheme: light)" content=(self.theme_color);
+                meta name="theme-color" media="(prefers-color-scheme: dark)" content=(dark);
+            } @else {
+                meta name="theme-color" content=(self.theme_color);
+            }
+            @if let Some(svg) = &self.svg_icon {
+                link rel="icon" type="image/svg+xml" href=(svg);
+            }
+            link rel="icon" type="image/png" sizes="32x32" href={ (base) "favicon-32x32.png" };
+            link rel="icon" type="image/png" sizes="16x16" href={ (base) "favicon-16x16.png" };
+            link rel="apple-touch-icon" sizes="180x180" href={ (base) "apple-touch-icon.png" };
+        }
+    }
+
+    /// Write `site.webmanifest` into an output directory
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = dir.as_ref().join("site.webmanifest");
+        fs::create_dir_all(dir.as_ref())?;
+        fs::write(&path, self.to_json())?;
+        Ok(path)
+    }
+}
+
+fn is_standard(src: &str, base: &str) -> bool {
+    STANDARD_ICONS
+        .iter()
+        .any(|spec| src == format!("{}{}", base, spec.file_name))
+}
+
+/// Resize `source` into every [`STANDARD_ICONS`] size inside `dir`
+pub fn write_icons<R: IconResizer>(
+    source: impl AsRef<Path>,
+    dir: impl AsRef<Path>,
+    resizer: &R,
+) -> Result<Vec<PathBuf>, R::Error> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+    STANDARD_ICONS
+        .iter()
+        .map(|spec| {
+            let bytes = resizer.resize(source.as_ref(), spec.size, spec.purpose)?;
+            let path = dir.join(spec.file_name);
+            fs::write(&path, bytes)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeResizer;
+
+    impl IconResizer for FakeResizer {
+        type Error = io::Error;
+
+        fn resize(&self, _: &Path, size: u32, purpose: IconPurpose) -> io::Result<Vec<u8>> {
+            Ok(format!("{}-{:?}", size, purpose).into_bytes())
+        }
+    }
+
+    #[test]
+    fn test_manifest_json() {
+        let json: serde_json::Value = serde_json::from_str(
+            &Manifest::new("Shallot Components")
+                .short_name("Shallot")
+                .display(DisplayMode::MinimalUi)
+                .theme_color("#7c3aed")
+                .theme_color_dark("#1e1b4b")
+                .standard_icons("/icons")
+                .to_json(),
+        )
+        .unwrap();
+
+        assert_eq!(json["name"], "Shallot Components");
+        assert_eq!(json["short_name"], "Shallot");
+        assert_eq!(json["display"], "minimal-ui");
+        assert_eq!(json["theme_color"], "#7c3aed");
+        assert!(json.get("theme_color_dark").is_none());
+        assert!(json.get("description").is_none());
+        assert_eq!(json["icons"].as_array().unwrap().len(), 3);
+        assert_eq!(json["icons"][0]["src"], "/icons/icon-192.png");
+        assert_eq!(json["icons"][0]["type"], "image/png");
+        assert_eq!(json["icons"][2]["purpose"], "maskable");
+    }
+
+    #[test]
+    fn test_standard_icons_is_idempotent() {
+        let manifest = Manifest::new("Site")
+            .standard_icons("/")
+            .standard_icons("/")
+            .icon(ManifestIcon {
+                src: "/custom.png".into(),
+                sizes: "48x48".into(),
+                mime_type: "image/png".into(),
+                purpose: IconPurpose::Monochrome,
+            });
+        assert_eq!(manifest.icons.len(), 4);
+    }
+
+    #[test]
+    fn test_head_tags() {
+        let head = Manifest::new("Site")
+            .theme_color("#fff")
+            .theme_color_dark("#000")
+            .svg_icon("/favicon.svg")
+            .standard_icons("/icons/")
+            .head_tags("/site.webmanifest")
+            .into_string();
+
+        assert!(head.contains(r#"<link rel="manifest" href="/site.webmanifest">"#));
+        assert!(head.contains(r##"media="(prefers-color-scheme: dark)" content="#000""##));
+        assert!(head.contains(r#"<link rel="icon" type="image/svg+xml" href="/favicon.svg">"#));
+        assert!(head.contains(r#"sizes="32x32" href="/icons/favicon-32x32.png""#));
+        assert!(head.contains(
+            r#"<link rel="apple-touch-icon" sizes="180x180" href="/icons/apple-touch-icon.png">"#
+        ));
+
+        let plain = Manifest::new("Site").head_tags("/m.json").into_string();
+        assert!(plain.contains(r##"<meta name="theme-color" content="#ffffff">"##));
+        assert!(!plain.contains("image/svg+xml"));
+    }
+
+    #[test]
+    fn test_write_icons_and_manifest() {
+        let dir = std::env::temp_dir().join(format!("shallot-assets-{}", std::process::id()));
+        let written = write_icons("logo.svg", &dir, &FakeResizer).unwrap();
+
+        assert_eq!(written.len(), STANDARD_ICONS.len());
+        assert_eq!(
+            fs::read_to_string(dir.join("icon-maskable-512.png")).unwrap(),
+            "512-Maskable"
+        );
+        let manifest = Manifest::new("Site").write_to(&dir).unwrap();
+        assert!(fs::read_to_string(manifest)
+            .unwrap()
+            .contains("\"start_url\": \"/\""));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_image_resizer() {
+        let dir = std::env::temp_dir().join(format!("shallot-resize-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("logo.png");
+        image::RgbaImage::from_pixel(64, 32, image::Rgba([255, 0, 0, 255]))
+            .save(&source)
+            .unwrap();
+
+        let resizer = ImageResizer::new().maskable_background([0, 0, 0, 255]);
+        let any = image::load_from_memory(&resizer.resize(&source, 32, IconPurpose::Any).unwrap())
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(any.dimensions(), (32, 32));
+        assert_eq!(any.get_pixel(0, 0).0, [0, 0, 0, 0]);
+        assert_eq!(any.get_pixel(16, 16).0, [255, 0, 0, 255]);
+
+        let maskable = resizer.resize(&source, 40, IconPurpose::Maskable).unwrap();
+        let maskable = image::load_from_memory(&maskable).unwrap().to_rgba8();
+        assert_eq!(maskable.get_pixel(0, 0).0, [0, 0, 0, 255]);
+        assert_eq!(maskable.get_pixel(20, 20).0, [255, 0, 0, 255]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1047 @@
+//! Auth Pages - Login, registration, password reset and two-factor forms
+//!
+//! Each form posts to a server handler and is re-rendered with the outcome:
+//! a form-level error or notice shows as an [`Alert`], field errors sit
+//! under their [`Input`], and submitted values can be echoed back. The CSRF
+//! slot goes through [`Form::csrf_field`], and [`AuthLayout`] picks between
+//! a centered card, a split page with a brand panel, or the bare form for
+//! embedding.
+
+use crate::alert::{Alert, AlertKind};
+use crate::button::{Button, ButtonVariant};
+use crate::form::Form;
+use crate::input::{Checkbox, Input, InputType};
+use crate::otp_input::OtpInput;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Page arrangement around an auth form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthLayout {
+    /// Card centered on the page
+    #[default]
+    Centered,
+    /// Brand panel beside the form, stacking on narrow screens
+    Split,
+    /// Just the card contents, for embedding in an existing page
+    Plain,
+}
+
+impl AuthLayout {
+    fn modifier(&self) -> &'static str {
+        match self {
+            AuthLayout::Centered => "centered",
+            AuthLayout::Split => "split",
+            AuthLayout::Plain => "plain",
+        }
+    }
+}
+
+/// Third-party sign-in option
+#[derive(Debug, Clone)]
+pub struct OAuthProvider<'a> {
+    pub name: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+}
+
+impl<'a> OAuthProvider<'a> {
+    /// `href` starts the provider's authorization flow
+    pub fn new(name: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            href: href.into(),
+        }
+    }
+}
+
+/// Settings shared by every auth form
+#[derive(Debug, Clone)]
+pub struct AuthOptions<'a> {
+    pub action: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub subtitle: Option<Cow<'a, str>>,
+    pub error: Option<Cow<'a, str>>,
+    pub notice: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    pub field_errors: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    pub csrf: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    pub hidden: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    pub layout: AuthLayout,
+    pub brand: Option<Markup>,
+    pub aside: Option<Markup>,
+    pub oauth: Vec<OAuthProvider<'a>>,
+}
+
+impl<'a> AuthOptions<'a> {
+    fn new(action: Cow<'a, str>, title: &'static str) -> Self {
+        Self {
+            action,
+            title: Cow::Borrowed(title),
+            subtitle: None,
+            error: None,
+            notice: None,
+            field_errors: Vec::new(),
+            csrf: None,
+            hidden: Vec::new(),
+            layout: AuthLayout::default(),
+            brand: None,
+            aside: None,
+            oauth: Vec::new(),
+        }
+    }
+
+    fn field_error(&self, field: &str) -> Option<&str> {
+        self.field_errors
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, message)| message.as_ref())
+    }
+
+    /// The posting form with the CSRF token and hidden fields attached
+    fn form(&self, fields: Markup, submit_label: &str) -> Markup {
+        let mut form = Form::new()
+            .action(&self.action)
+            .method("post")
+            .class("sh-auth__form")
+            .aria_label(&self.title);
+        if let Some((name, token)) = &self.csrf {
+            form = form.csrf_field(name, token);
+        }
+        for (name, value) in &self.hidden {
+            form = form.hidden(name, value);
+        }
+        form.fields(fields)
+            .submit_button(submit_button(submit_label))
+            .render()
+    }
+
+    fn render_oauth(&self) -> Markup {
+        html! {
+            @if !self.oauth.is_empty() {
+                div class="sh-auth__oauth" {
+                    @for provider in &self.oauth {
+                        (Button::new(format!("Continue with {}", provider.name))
+                            .variant(ButtonVariant::Secondary)
+                            .href(provider.href.as_ref())
+                            .render())
+                    }
+                }
+                div class="sh-auth__divider" role="separator" { span { "or" } }
+            }
+        }
+    }
+
+    /// Wrap the form in the chosen layout
+    fn render_page(&self, error_title: &str, body: Markup, footer: Markup) -> Markup {
+        let card = html! {
+            @if let Some(brand) = &self.brand {
+                div class="sh-auth__brand" { (brand) }
+            }
+            div class="sh-auth__header" {
+                h1 class="sh-auth__title" { (self.title) }
+                @if let Some(subtitle) = &self.subtitle {
+                    p class="sh-auth__subtitle" { (subtitle) }
+                }
+            }
+            @if let Some(error) = &self.error {
+                (Alert::new(AlertKind::Error, error_title, error.as_ref()).render())
+            }
+            @if let Some((title, message)) = &self.notice {
+                (Alert::new(AlertKind::Success, title.as_ref(), message.as_ref()).render())
+            }
+            (body)
+            div class="sh-auth__footer" { (footer) }
+        };
+
+        html! {
+            div class={ "sh-auth sh-auth--" (self.layout.modifier()) } {
+                @match self.layout {
+                    AuthLayout::Plain => { (card) },
+                    AuthLayout::Centered => {
+                        div class="sh-auth__card" { (card) }
+                    },
+                    AuthLayout::Split => {
+                        div class="sh-auth__aside" {
+                            @if let Some(aside) = &self.aside { (aside) }
+                        }
+                        div class="sh-auth__panel" {
+                            div class="sh-auth__card" { (card) }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn submit_button(label: &str) -> Markup {
+    html! {
+        button class="sh-btn sh-btn--primary sh-btn--md sh-auth__submit" type="submit" {
+            (label)
+        }
+    }
+}
+
+fn footer_link(prompt: &str, label: &str, href: Option<&str>) -> Markup {
+    html! {
+        @if let Some(href) = href {
+            p class="sh-auth__switch" {
+                (prompt) " "
+                a class="sh-auth__link" href=(href) { (label) }
+            }
+        }
+    }
+}
+
+/// Builders every auth form shares, writing into its [`AuthOptions`]
+macro_rules! auth_options_builders {
+    ($form:ident) => {
+        impl<'a> $form<'a> {
+            pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+                self.options.title = title.into();
+                self
+            }
+
+            pub fn subtitle(mut self, subtitle: impl Into<Cow<'a, str>>) -> Self {
+                self.options.subtitle = Some(subtitle.into());
+                self
+            }
+
+            /// Form-level error from the last submission
+            pub fn error(mut self, message: impl Into<Cow<'a, str>>) -> Self {
+                self.options.error = Some(message.into());
+                self
+            }
+
+            /// Success message, e.g. after a password reset
+            pub fn notice(
+                mut self,
+                title: impl Into<Cow<'a, str>>,
+                message: impl Into<Cow<'a, str>>,
+            ) -> Self {
+                self.options.notice = Some((title.into(), message.into()));
+                self
+            }
+
+            /// Error shown under one field, keyed by the field name
+            pub fn field_error(
+                mut self,
+                field: impl Into<Cow<'a, str>>,
+                message: impl Into<Cow<'a, str>>,
+            ) -> Self {
+                self.options
+                    .field_errors
+                    .push((field.into(), message.into()));
+                self
+            }
+
+            /// CSRF token, submitted as `csrf_token`
+            pub fn csrf_token(self, token: impl Into<Cow<'a, str>>) -> Self {
+                self.csrf_field("csrf_token", token)
+            }
+
+            /// CSRF token under the field name a middleware expects
+            pub fn csrf_field(
+                mut self,
+                name: impl Into<Cow<'a, str>>,
+                token: impl Into<Cow<'a, str>>,
+            ) -> Self {
+                self.options.csrf = Some((name.into(), token.into()));
+                self
+            }
+
+            /// Extra hidden field, e.g. a `next` redirect
+            pub fn hidden(
+                mut self,
+                name: impl Into<Cow<'a, str>>,
+                value: impl Into<Cow<'a, str>>,
+            ) -> Self {
+                self.options.hidden.push((name.into(), value.into()));
+                self
+            }
+
+            pub fn layout(mut self, layout: AuthLayout) -> Self {
+                self.options.layout = layout;
+                self
+            }
+
+            /// Logo or product name above the title
+            pub fn brand(mut self, brand: Markup) -> Self {
+                self.options.brand = Some(brand);
+                self
+            }
+
+            /// Content of the brand panel in [`AuthLayout::Split`]
+            pub fn aside(mut self, aside: Markup) -> Self {
+                self.options.aside = Some(aside);
+                self
+            }
+        }
+    };
+}
+
+/// Sign-in form
+#[derive(Debug, Clone)]
+pub struct LoginForm<'a> {
+    pub options: AuthOptions<'a>,
+    pub username: bool,
+    pub identifier: Option<Cow<'a, str>>,
+    pub remember_me: bool,
+    pub forgot_href: Option<Cow<'a, str>>,
+    pub register_href: Option<Cow<'a, str>>,
+}
+
+impl<'a> LoginForm<'a> {
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            options: AuthOptions::new(action.into(), "Sign in"),
+            username: false,
+            identifier: None,
+            remember_me: true,
+            forgot_href: None,
+            register_href: None,
+        }
+    }
+
+    /// Ask for a username instead of an email address
+    pub fn username(mut self, username: bool) -> Self {
+        self.username = username;
+        self
+    }
+
+    /// Echo the submitted email or username after a failed attempt
+    pub fn identifier(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    pub fn remember_me(mut self, show: bool) -> Self {
+        self.remember_me = show;
+        self
+    }
+
+    pub fn forgot_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.forgot_href = Some(href.into());
+        self
+    }
+
+    pub fn register_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.register_href = Some(href.into());
+        self
+    }
+
+    pub fn oauth(mut self, provider: OAuthProvider<'a>) -> Self {
+        self.options.oauth.push(provider);
+        self
+    }
+}
+
+auth_options_builders!(LoginForm);
+
+impl<'a> Render for LoginForm<'a> {
+    fn render(&self) -> Markup {
+        let o = &self.options;
+        let (name, label, kind, autocomplete) = if self.username {
+            ("username", "Username", InputType::Text, "username")
+        } else {
+            ("email", "Email", InputType::Email, "email")
+        };
+
+        let mut identifier = Input::new(name)
+            .type_(kind)
+            .label(label)
+            .autocomplete(autocomplete)
+            .required(true);
+        if let Some(value) = self.identifier.as_deref() {
+            identifier = identifier.value(value);
+        }
+        if let Some(error) = o.field_error(name) {
+            identifier = identifier.error(error);
+        }
+        let mut password = Input::password("password")
+            .label("Password")
+            .autocomplete("current-password")
+            .required(true);
+        if let Some(error) = o.field_error("password") {
+            password = password.error(error);
+        }
+
+        let fields = html! {
+            (identifier)
+            (password)
+            @if self.remember_me || self.forgot_href.is_some() {
+                div class="sh-auth__row" {
+                    @if self.remember_me {
+                        (Checkbox::new("Remember me", "remember"))
+                    }
+                    @if let Some(href) = &self.forgot_href {
+                        a class="sh-auth__link" href=(href) { "Forgot password?" }
+                    }
+                }
+            }
+        };
+
+        let body = html! {
+            (o.render_oauth())
+            (o.form(fields, "Sign in"))
+        };
+        let footer = footer_link(
+            "Don't have an account?",
+            "Sign up",
+            self.register_href.as_deref(),
+        );
+        o.render_page("Sign-in failed", body, footer)
+    }
+}
+
+/// Account registration form
+#[derive(Debug, Clone)]
+pub struct RegisterForm<'a> {
+    pub options: AuthOptions<'a>,
+    pub name_field: bool,
+    pub name: Option<Cow<'a, str>>,
+    pub email: Option<Cow<'a, str>>,
+    pub confirm_password: bool,
+    pub password_hint: Option<Cow<'a, str>>,
+    pub terms: Option<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+    pub login_href: Option<Cow<'a, str>>,
+}
+
+impl<'a> RegisterForm<'a> {
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            options: AuthOptions::new(action.into(), "Create an account"),
+            name_field: true,
+            name: None,
+            email: None,
+            confirm_password: true,
+            password_hint: None,
+            terms: None,
+            login_href: None,
+        }
+    }
+
+    /// Ask for a display name
+    pub fn name_field(mut self, show: bool) -> Self {
+        self.name_field = show;
+        self
+    }
+
+    /// Echo submitted values after a failed attempt
+    pub fn values(mut self, name: impl Into<Cow<'a, str>>, email: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn confirm_password(mut self, confirm: bool) -> Self {
+        self.confirm_password = confirm;
+        self
+    }
+
+    /// Password rules shown under the password field
+    pub fn password_hint(mut self, hint: impl Into<Cow<'a, str>>) -> Self {
+        self.password_hint = Some(hint.into());
+        self
+    }
+
+    /// Require accepting the terms (submitted as `terms=on`), with an
+    /// optional link to read them
+    pub fn terms(mut self, label: impl Into<Cow<'a, str>>, href: Option<Cow<'a, str>>) -> Self {
+        self.terms = Some((label.into(), href));
+        self
+    }
+
+    pub fn login_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.login_href = Some(href.into());
+        self
+    }
+
+    pub fn oauth(mut self, provider: OAuthProvider<'a>) -> Self {
+        self.options.oauth.push(provider);
+        self
+    }
+}
+
+auth_options_builders!(RegisterForm);
+
+impl<'a> Render for RegisterForm<'a> {
+    fn render(&self) -> Markup {
+        let o = &self.options;
+
+        let mut name = Input::new("name")
+            .label("Name")
+            .autocomplete("name")
+            .required(true);
+        if let Some(value) = self.name.as_deref() {
+            name = name.value(value);
+        }
+        if let Some(error) = o.field_error("name") {
+            name = name.error(error);
+        }
+        let mut email = Input::email("email")
+            .label("Email")
+            .autocomplete("email")
+            .required(true);
+        if let Some(value) = self.email.as_deref() {
+            email = email.value(value);
+        }
+        if let Some(error) = o.field_error("email") {
+            email = email.error(error);
+        }
+        let mut password = Input::password("password")
+            .label("Password")
+            .autocomplete("new-password")
+            .required(true);
+        if let Some(hint) = self.password_hint.as_deref() {
+            password = password.helper_text(hint);
+        }
+        if let Some(error) = o.field_error("password") {
+            password = password.error(error);
+        }
+        let mut confirm = Input::password("password_confirmation")
+            .label("Confirm password")
+            .autocomplete("new-password")
+            .required(true);
+        if let Some(error) = o.field_error("password_confirmation") {
+            confirm = confirm.error(error);
+        }
+
+        let fields = html! {
+            @if self.name_field {
+                (name)
+            }
+            (email)
+            (password)
+            @if self.confirm_password {
+                (confirm)
+            }
+            @if let Some((label, href)) = &self.terms {
+                div class="sh-auth__row" {
+                    (Checkbox::new(label, "terms").required(true))
+                    @if let Some(href) = href {
+                        a class="sh-auth__link" href=(href) target="_blank" rel="noopener" {
+                            "Read the terms"
+                        }
+                    }
+                }
+                @if let Some(error) = o.field_error("terms") {
+                    p class="sh-auth__field-error" { (error) }
+                }
+            }
+        };
+
+        let body = html! {
+            (o.render_oauth())
+            (o.form(fields, "Create account"))
+        };
+        let footer = footer_link(
+            "Already have an account?",
+            "Sign in",
+            self.login_href.as_deref(),
+        );
+        o.render_page("Couldn't create your account", body, footer)
+    }
+}
+
+/// Password reset request form
+#[derive(Debug, Clone)]
+pub struct ForgotPasswordForm<'a> {
+    pub options: AuthOptions<'a>,
+    pub email: Option<Cow<'a, str>>,
+    pub sent: bool,
+    pub login_href: Option<Cow<'a, str>>,
+}
+
+impl<'a> ForgotPasswordForm<'a> {
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        let mut options = AuthOptions::new(action.into(), "Reset your password");
+        options.subtitle = Some(Cow::Borrowed(
+            "Enter your email and we'll send you a link to choose a new password.",
+        ));
+        Self {
+            options,
+            email: None,
+            sent: false,
+            login_href: None,
+        }
+    }
+
+    pub fn email(mut self, email: impl Into<Cow<'a, str>>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Replace the form with a confirmation once the link has been sent
+    pub fn sent(mut self, sent: bool) -> Self {
+        self.sent = sent;
+        self
+    }
+
+    pub fn login_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.login_href = Some(href.into());
+        self
+    }
+}
+
+auth_options_builders!(ForgotPasswordForm);
+
+impl<'a> Render for ForgotPasswordForm<'a> {
+    fn render(&self) -> Markup {
+        let o = &self.options;
+
+        let body = if self.sent {
+            let message = match self.email.as_deref() {
+                Some(email) => format!(
+                    "If an account exists for {}, a reset link is on its way.",
+                    email
+                ),
+                None => {
+                    "If an account exists for that address, a reset link is on its way.".to_string()
+                }
+            };
+            Alert::new(AlertKind::Success, "Check your email", message).render()
+        } else {
+            let mut email = Input::email("email")
+                .label("Email")
+                .autocomplete("email")
+                .required(true);
+            if let Some(value) = self.email.as_deref() {
+                email = email.value(value);
+            }
+            if let Some(error) = o.field_error("email") {
+                email = email.error(error);
+            }
+            let fields = html! {
+                (email)
+            };
+            o.form(fields, "Send reset link")
+        };
+        let footer = footer_link(
+            "Remembered it?",
+            "Back to sign in",
+            self.login_href.as_deref(),
+        );
+        o.render_page("Couldn't send the reset link", body, footer)
+    }
+}
+
+/// One-time code step after sign-in
+#[derive(Debug, Clone)]
+pub struct TwoFactorForm<'a> {
+    pub options: AuthOptions<'a>,
+    pub digits: usize,
+    pub remember_device: bool,
+    pub recovery_href: Option<Cow<'a, str>>,
+}
+
+impl<'a> TwoFactorForm<'a> {
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        let mut options = AuthOptions::new(action.into(), "Two-factor authentication");
+        options.subtitle = Some(Cow::Borrowed("Enter the code from your authenticator app."));
+        Self {
+            options,
+            digits: 6,
+            remember_device: false,
+            recovery_href: None,
+        }
+    }
+
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Offer to skip the code on this device next time (`remember_device=on`)
+    pub fn remember_device(mut self, show: bool) -> Self {
+        self.remember_device = show;
+        self
+    }
+
+    /// Link to sign in with a recovery code instead
+    pub fn recovery_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.recovery_href = Some(href.into());
+        self
+    }
+
+    /// Join the per-digit `code[0]`, `code[1]`, ... fields of a submission
+    pub fn code_from_form<'f, I>(pairs: I) -> String
+    where
+        I: IntoIterator<Item = (&'f str, &'f str)>,
+    {
+        let mut digits: Vec<(usize, &str)> = pairs
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let index = name.strip_prefix("code[")?.strip_suffix(']')?;
+                Some((index.parse().ok()?, value))
+            })
+            .collect();
+        digits.sort_by_key(|(index, _)| *index);
+        digits.into_iter().map(|(_, value)| value.trim()).collect()
+    }
+}
+
+auth_options_builders!(TwoFactorForm);
+
+impl<'a> Render for TwoFactorForm<'a> {
+    fn render(&self) -> Markup {
+        let o = &self.options;
+        let fields = html! {
+            (OtpInput::new("sh-auth-code", "code")
+                .length(self.digits)
+                .label("Authentication code")
+                .required(true))
+            @if let Some(error) = o.field_error("code") {
+                p class="sh-auth__field-error" { (error) }
+            }
+            @if self.remember_device {
+                (Checkbox::new("Trust this device for 30 days", "remember_device"))
+            }
+        };
+        let body = o.form(fields, "Verify");
+        let footer = footer_link(
+            "Lost access to your device?",
+            "Use a recovery code",
+            self.recovery_href.as_deref(),
+        );
+        o.render_page("Verification failed", body, footer)
+    }
+}
+
+/// Generate CSS for the auth page layouts
+pub fn auth_pages_css() -> String {
+    r#"
+.sh-auth {
+    color: var(--sh-text, #111827);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-auth--centered {
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    min-height: 100vh;
+    padding: 2rem 1rem;
+    background: var(--sh-surface-alt, #f9fafb);
+}
+
+.sh-auth--split {
+    display: grid;
+    grid-template-columns: minmax(0, 1fr) minmax(0, 1fr);
+    min-height: 100vh;
+}
+
+.sh-auth__aside {
+    display: flex;
+    flex-direction: column;
+    justify-content: center;
+    padding: 3rem;
+    background: var(--sh-primary, #3b82f6);
+    color: #ffffff;
+}
+
+.sh-auth__panel {
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    padding: 2rem 1rem;
+    background: var(--sh-surface, #ffffff);
+}
+
+.sh-auth__card,
+.sh-auth--plain {
+    display: flex;
+    flex-direction: column;
+    gap: 1.25rem;
+    width: 100%;
+    max-width: 26rem;
+}
+
+.sh-auth--centered .sh-auth__card {
+    padding: 2rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    background: var(--sh-surface, #ffffff);
+    box-shadow: 0 8px 24px rgba(0, 0, 0, 0.06);
+}
+
+.sh-auth__brand {
+    display: flex;
+    justify-content: center;
+}
+
+.sh-auth__header {
+    text-align: center;
+}
+
+.sh-auth__title {
+    margin: 0;
+    font-size: 1.5rem;
+    font-weight: 700;
+}
+
+.sh-auth__subtitle {
+    margin: 0.375rem 0 0;
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.9375rem;
+}
+
+.sh-auth__oauth {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+}
+
+.sh-auth__oauth .sh-btn {
+    justify-content: center;
+    width: 100%;
+}
+
+.sh-auth__divider {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.8125rem;
+    text-transform: uppercase;
+}
+
+.sh-auth__divider::before,
+.sh-auth__divider::after {
+    content: "";
+    flex: 1;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-auth__row {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: 1rem;
+    font-size: 0.875rem;
+}
+
+.sh-auth__link {
+    color: var(--sh-primary, #3b82f6);
+    font-weight: 500;
+    text-decoration: none;
+}
+
+.sh-auth__link:hover {
+    text-decoration: underline;
+}
+
+.sh-auth__field-error {
+    margin: -0.5rem 0 0;
+    color: var(--sh-error, #ef4444);
+    font-size: 0.875rem;
+}
+
+.sh-auth__form .sh-form__actions {
+    justify-content: stretch;
+}
+
+.sh-auth__submit {
+    width: 100%;
+    justify-content: center;
+}
+
+.sh-auth__footer:empty {
+    display: none;
+}
+
+.sh-auth__switch {
+    margin: 0;
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.875rem;
+    text-align: center;
+}
+
+@media (max-width: 768px) {
+    .sh-auth--split {
+        grid-template-columns: 1fr;
+        grid-template-rows: auto 1fr;
+    }
+
+    .sh-auth__aside {
+        padding: 1.5rem;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_login_form_fields() {
+        let html = LoginForm::new("/login")
+            .forgot_href("/forgot")
+            .register_href("/register")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"action="/login" method="post""#));
+        assert!(html.contains(r#"type="email" name="email""#));
+        assert!(html.contains(r#"autocomplete="current-password""#));
+        assert!(html.contains(r#"name="remember""#));
+        assert!(html.contains(r#"href="/forgot""#));
+        assert!(html.contains(r#"href="/register">Sign up</a>"#));
+        assert!(html.contains(r#"<h1 class="sh-auth__title">Sign in</h1>"#));
+        assert!(html.contains(r#"type="submit""#));
+    }
+
+    #[test]
+    fn test_login_errors_and_echo() {
+        let html = LoginForm::new("/login")
+            .username(true)
+            .identifier("ada")
+            .error("Wrong username or password.")
+            .field_error("password", "Password is required")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="username""#));
+        assert!(html.contains(r#"value="ada""#));
+        assert!(html.contains("sh-alert--error"));
+        assert!(html.contains("Sign-in failed"));
+        assert!(html.contains("Wrong username or password."));
+        assert!(html.contains("Password is required"));
+        assert!(html.contains(r#"aria-invalid="true""#));
+    }
+
+    #[test]
+    fn test_csrf_and_hidden_fields() {
+        let html = LoginForm::new("/login")
+            .csrf_token("abc123")
+            .hidden("next", "/dashboard")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<input type="hidden" name="csrf_token" value="abc123">"#));
+        assert!(html.contains(r#"<input type="hidden" name="next" value="/dashboard">"#));
+
+        let html = TwoFactorForm::new("/2fa")
+            .csrf_field("authenticity_token", "t")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="authenticity_token" value="t""#));
+    }
+
+    #[test]
+    fn test_oauth_row() {
+        let html = RegisterForm::new("/register")
+            .oauth(OAuthProvider::new("GitHub", "/auth/github"))
+            .oauth(OAuthProvider::new("Google", "/auth/google"))
+            .render()
+            .into_string();
+        assert!(html.contains("Continue with GitHub"));
+        assert!(html.contains(r#"href="/auth/google""#));
+        assert!(html.contains("sh-auth__divider"));
+        assert!(!LoginForm::new("/l")
+            .render()
+            .into_string()
+            .contains("sh-auth__oauth"));
+    }
+
+    #[test]
+    fn test_register_form() {
+        let html = RegisterForm::new("/register")
+            .values("Ada", "ada@example.com")
+            .password_hint("At least 12 characters")
+            .terms("I agree to the terms", Some("/terms".into()))
+            .field_error("email", "Email already registered")
+            .login_href("/login")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"value="ada@example.com""#));
+        assert!(html.contains(r#"name="password_confirmation""#));
+        assert!(html.contains(r#"autocomplete="new-password""#));
+        assert!(html.contains("At least 12 characters"));
+        assert!(html.contains(r#"name="terms""#));
+        assert!(html.contains("Email already registered"));
+
+        let html = RegisterForm::new("/register")
+            .name_field(false)
+            .confirm_password(false)
+            .render()
+            .into_string();
+        assert!(!html.contains(r#"name="name""#));
+        assert!(!html.contains("password_confirmation"));
+    }
+
+    #[test]
+    fn test_forgot_password_sent() {
+        let form = ForgotPasswordForm::new("/forgot").email("ada@example.com");
+        let html = form.clone().render().into_string();
+        assert!(html.contains("Send reset link"));
+
+        let html = form.sent(true).render().into_string();
+        assert!(!html.contains("<form"));
+        assert!(html.contains("sh-alert--success"));
+        assert!(html.contains("ada@example.com"));
+    }
+
+    #[test]
+    fn test_two_factor_form() {
+        let html = TwoFactorForm::new("/2fa")
+            .digits(8)
+            .remember_device(true)
+            .recovery_href("/2fa/recovery")
+            .field_error("code", "That code has expired")
+            .render()
+            .into_string();
+        assert_eq!(html.matches(r#"class="sh-otp-input__field""#).count(), 8);
+        assert!(html.contains(r#"name="code[7]""#));
+        assert!(html.contains(r#"name="remember_device""#));
+        assert!(html.contains("That code has expired"));
+        assert!(html.contains(r#"href="/2fa/recovery""#));
+    }
+
+    #[test]
+    fn test_code_from_form() {
+        let code = TwoFactorForm::code_from_form([
+            ("code[1]", "2"),
+            ("csrf_token", "x"),
+            ("code[0]", "1"),
+            ("code[2]", " 3"),
+        ]);
+        assert_eq!(code, "123");
+    }
+
+    #[test]
+    fn test_layouts() {
+        let centered = LoginForm::new("/l").render().into_string();
+        assert!(centered
+            .contains(r#"<div class="sh-auth sh-auth--centered"><div class="sh-auth__card">"#));
+
+        let split = LoginForm::new("/l")
+            .layout(AuthLayout::Split)
+            .aside(html! { p { "Welcome back" } })
+            .render()
+            .into_string();
+        assert!(split.contains(r#"<div class="sh-auth__aside"><p>Welcome back</p></div>"#));
+        assert!(split.contains("sh-auth__panel"));
+
+        let plain = LoginForm::new("/l")
+            .layout(AuthLayout::Plain)
+            .render()
+            .into_string();
+        assert!(!plain.contains("sh-auth__card"));
+    }
+
+    #[test]
+    fn test_notice() {
+        let html = LoginForm::new("/l")
+            .notice("Password updated", "Sign in with your new password.")
+            .render()
+            .into_string();
+        assert!(html.contains("sh-alert--success"));
+        assert!(html.contains("Password updated"));
+    }
+
+    #[test]
+    fn test_auth_pages_css() {
+        let css = auth_pages_css();
+        assert!(css.contains(".sh-auth--split"));
+        assert!(css.contains(".sh-auth__divider"));
+    }
+}
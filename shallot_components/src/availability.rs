@@ -0,0 +1,439 @@
+//! Availability Picker - weekly grid of bookable time slots
+//!
+//! The booking widget without a script: each day is a column of slots, and
+//! each free slot is a radio button (or a checkbox with
+//! [`AvailabilityPicker::multiple`]) posting the day key and time, e.g.
+//! `2026-10-19T09:30`, under the picker's name. Busy slots show but can't be
+//! chosen. On narrow screens the days stack and their slots wrap.
+//!
+//! [`AvailabilityPicker::selection`] reads the choice back and refuses slots
+//! that are busy or were never offered.
+
+use crate::form::{FormData, ValidationError};
+use crate::time_picker::{Time, TimeFormat, TimeRange};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// A column of the grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvailabilityDay<'a> {
+    /// Submitted before the time, usually an ISO date like `2026-10-19`
+    pub key: Cow<'a, str>,
+    /// Column heading, e.g. `Mon 19`
+    pub label: Cow<'a, str>,
+}
+
+/// A chosen slot
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AvailabilitySlot {
+    pub day: String,
+    pub time: Time,
+}
+
+impl AvailabilitySlot {
+    /// Read a submitted `dayTHH:MM` value
+    pub fn parse(value: &str) -> Option<Self> {
+        let (day, time) = value.rsplit_once('T')?;
+        Some(Self {
+            day: day.to_string(),
+            time: time.parse().ok()?,
+        })
+    }
+
+    /// The submitted form value
+    pub fn value(&self) -> String {
+        format!("{}T{}", self.day, self.time)
+    }
+}
+
+/// Weekly time grid of selectable slots
+#[derive(Debug, Clone)]
+pub struct AvailabilityPicker<'a> {
+    pub name: Cow<'a, str>,
+    pub label: Cow<'a, str>,
+    pub days: Vec<AvailabilityDay<'a>>,
+    /// Slot start times, the rows of the grid
+    pub times: Vec<Time>,
+    /// Slots that can't be booked, as `(day key, time)`
+    pub busy: Vec<(Cow<'a, str>, Time)>,
+    /// Values checked when rendered
+    pub selected: Vec<Cow<'a, str>>,
+    /// Checkboxes rather than radio buttons
+    pub multiple: bool,
+    pub required: bool,
+    /// Shown as "Times in …" above the grid
+    pub timezone: Option<Cow<'a, str>>,
+    pub format: TimeFormat,
+}
+
+impl<'a> AvailabilityPicker<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: Cow::Borrowed("Choose a time"),
+            days: Vec::new(),
+            times: Vec::new(),
+            busy: Vec::new(),
+            selected: Vec::new(),
+            multiple: false,
+            required: false,
+            timezone: None,
+            format: TimeFormat::Hour24,
+        }
+    }
+
+    /// Accessible name of the grid (default `Choose a time`)
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Add a day column
+    pub fn day(mut self, key: impl Into<Cow<'a, str>>, label: impl Into<Cow<'a, str>>) -> Self {
+        self.days.push(AvailabilityDay {
+            key: key.into(),
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Offer slots every `step` minutes across `hours`, e.g. 09:00-17:00
+    pub fn hours(mut self, hours: TimeRange, step: u32) -> Self {
+        self.times = hours.slots(step);
+        self
+    }
+
+    /// Offer a slot starting at `time`
+    pub fn slot(mut self, time: Time) -> Self {
+        if !self.times.contains(&time) {
+            self.times.push(time);
+            self.times.sort();
+        }
+        self
+    }
+
+    /// Mark one slot as taken
+    pub fn busy(mut self, day: impl Into<Cow<'a, str>>, time: Time) -> Self {
+        self.busy.push((day.into(), time));
+        self
+    }
+
+    /// Mark every slot starting within `range` on `day` as taken
+    pub fn busy_range(mut self, day: impl Into<Cow<'a, str>>, range: TimeRange) -> Self {
+        let day = day.into();
+        for time in self.times.iter().filter(|t| range.contains(**t)) {
+            self.busy.push((day.clone(), *time));
+        }
+        self
+    }
+
+    /// Check the slot with this value, e.g. to repopulate after an error
+    pub fn selected(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.selected.push(value.into());
+        self
+    }
+
+    /// Let several slots be chosen
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Name the time zone the slots are in, e.g. `Europe/Paris`
+    pub fn timezone(mut self, timezone: impl Into<Cow<'a, str>>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn format(mut self, format: TimeFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    fn is_busy(&self, day: &str, time: Time) -> bool {
+        self.busy.iter().any(|(d, t)| d == day && *t == time)
+    }
+
+    fn is_offered(&self, slot: &AvailabilitySlot) -> bool {
+        self.days.iter().any(|d| d.key == slot.day)
+            && self.times.contains(&slot.time)
+            && !self.is_busy(&slot.day, slot.time)
+    }
+
+    /// The slots chosen in a submission, refusing busy or unknown ones
+    pub fn selection(&self, data: &FormData) -> Result<Vec<AvailabilitySlot>, ValidationError> {
+        let values = data.get_all(&self.name);
+        let values: Vec<&str> = values.into_iter().filter(|v| !v.is_empty()).collect();
+        if values.is_empty() && self.required {
+            return Err(ValidationError::new(
+                self.name.as_ref(),
+                "Choose a time",
+                "required",
+            ));
+        }
+        if values.len() > 1 && !self.multiple {
+            return Err(ValidationError::new(
+                self.name.as_ref(),
+                "Choose one time",
+                "slot",
+            ));
+        }
+        values
+            .into_iter()
+            .map(|value| {
+                AvailabilitySlot::parse(value)
+                    .filter(|slot| self.is_offered(slot))
+                    .ok_or_else(|| {
+                        ValidationError::new(
+                            self.name.as_ref(),
+                            "That time is no longer available",
+                            "slot",
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+impl<'a> Render for AvailabilityPicker<'a> {
+    fn render(&self) -> Markup {
+        let input_type = if self.multiple { "checkbox" } else { "radio" };
+
+        html! {
+            fieldset class="sh-availability" {
+                legend class="sh-availability__legend" { (self.label) }
+                @if let Some(timezone) = &self.timezone {
+                    p class="sh-availability__timezone" {
+                        "Times in " (timezone.replace('_', " "))
+                    }
+                }
+                div class="sh-availability__grid" {
+                    @for day in &self.days {
+                        div class="sh-availability__day" role="group" aria-label=(day.label) {
+                            p class="sh-availability__day-label" aria-hidden="true" { (day.label) }
+                            @if self.times.is_empty() {
+                                p class="sh-availability__empty" { "No times" }
+                            }
+                            ul class="sh-availability__slots" {
+                                @for time in &self.times {
+                                    @let value = format!("{}T{}", day.key, time);
+                                    @let busy = self.is_busy(&day.key, *time);
+                                    @let checked = self.selected.iter().any(|s| *s == value);
+                                    li {
+                                        label class=(if busy { "sh-availability__slot sh-availability__slot--busy" } else { "sh-availability__slot" }) {
+                                            input
+                                                type=(input_type)
+                                                class="sh-availability__input"
+                                                name=(self.name)
+                                                value=(value)
+                                                checked?[checked && !busy]
+                                                disabled?[busy]
+                                                required?[self.required && !self.multiple];
+                                            span class="sh-availability__time" { (time.format(self.format)) }
+                                            @if busy {
+                                                span class="sh-availability__busy" { " Busy" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the availability picker
+pub fn availability_css() -> String {
+    r#"
+.sh-availability {
+    margin: 0;
+    padding: 0;
+    border: none;
+    min-width: 0;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-availability__legend {
+    padding: 0;
+    margin-bottom: 0.5rem;
+    font-weight: 600;
+}
+
+.sh-availability__timezone {
+    margin: 0 0 0.75rem;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-availability__grid {
+    display: grid;
+    grid-template-columns: repeat(auto-fit, minmax(6.5rem, 1fr));
+    gap: 0.75rem;
+}
+
+.sh-availability__day-label {
+    margin: 0 0 0.5rem;
+    font-size: 0.875rem;
+    font-weight: 600;
+    text-align: center;
+}
+
+.sh-availability__empty {
+    margin: 0;
+    font-size: 0.875rem;
+    text-align: center;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-availability__slots {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-availability__slot {
+    position: relative;
+    display: flex;
+    justify-content: center;
+    gap: 0.25rem;
+    padding: 0.5rem;
+    border: 1px solid var(--sh-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font-size: 0.875rem;
+    font-variant-numeric: tabular-nums;
+    cursor: pointer;
+    transition: background 0.15s, border-color 0.15s;
+}
+
+.sh-availability__input {
+    position: absolute;
+    opacity: 0;
+    pointer-events: none;
+}
+
+.sh-availability__slot:hover {
+    border-color: var(--sh-primary, #3b82f6);
+}
+
+.sh-availability__slot:has(:checked) {
+    background: var(--sh-primary, #3b82f6);
+    border-color: var(--sh-primary, #3b82f6);
+    color: var(--sh-primary-contrast, #ffffff);
+}
+
+.sh-availability__slot:has(:focus-visible) {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 2px;
+}
+
+.sh-availability__slot--busy {
+    cursor: not-allowed;
+    color: var(--sh-text-muted, #6b7280);
+    background: var(--sh-surface-2, #f3f4f6);
+    text-decoration: line-through;
+}
+
+.sh-availability__slot--busy:hover {
+    border-color: var(--sh-border, #d1d5db);
+}
+
+.sh-availability__busy {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    overflow: hidden;
+    clip: rect(0 0 0 0);
+    white-space: nowrap;
+}
+
+@media (max-width: 640px) {
+    .sh-availability__grid {
+        grid-template-columns: 1fr;
+    }
+
+    .sh-availability__day-label {
+        text-align: start;
+    }
+
+    .sh-availability__slots {
+        flex-direction: row;
+        flex-wrap: wrap;
+    }
+
+    .sh-availability__slot {
+        min-width: 4.5rem;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picker() -> AvailabilityPicker<'static> {
+        AvailabilityPicker::new("slot")
+            .day("2026-10-19", "Mon 19")
+            .day("2026-10-20", "Tue 20")
+            .hours("09:00-11:00".parse().unwrap(), 30)
+            .busy_range("2026-10-20", "09:00-10:00".parse().unwrap())
+    }
+
+    #[test]
+    fn test_availability_renders_slots() {
+        let html = picker()
+            .timezone("America/New_York")
+            .format(TimeFormat::Hour12)
+            .selected("2026-10-19T10:30")
+            .render()
+            .into_string();
+        assert_eq!(html.matches(r#"type="radio""#).count(), 8);
+        assert!(html.contains("Times in America/New York"));
+        assert!(html.contains(r#"value="2026-10-19T10:30" checked"#));
+        assert!(html.contains(r#"value="2026-10-20T09:30" disabled"#));
+        assert_eq!(html.matches("sh-availability__slot--busy").count(), 2);
+        assert!(html.contains(">10:30 AM<"));
+    }
+
+    #[test]
+    fn test_availability_selection() {
+        let picker = picker().required(true);
+        let data = FormData::parse_urlencoded("slot=2026-10-20T10%3A00");
+        let chosen = picker.selection(&data).unwrap();
+        assert_eq!(chosen[0].time, Time::new(10, 0).unwrap());
+        assert_eq!(chosen[0].value(), "2026-10-20T10:00");
+
+        for taken in [
+            "slot=2026-10-20T09%3A00",
+            "slot=2026-10-19T12%3A00",
+            "slot=junk",
+        ] {
+            let data = FormData::parse_urlencoded(taken);
+            assert_eq!(picker.selection(&data).unwrap_err().code, "slot");
+        }
+        let none = picker.selection(&FormData::new()).unwrap_err();
+        assert_eq!(none.code, "required");
+
+        let two = FormData::parse_urlencoded("slot=2026-10-19T09%3A00&slot=2026-10-19T09%3A30");
+        assert!(picker.selection(&two).is_err());
+        let multiple = picker.multiple(true);
+        assert_eq!(multiple.selection(&two).unwrap().len(), 2);
+        assert!(multiple
+            .render()
+            .into_string()
+            .contains(r#"type="checkbox""#));
+    }
+}
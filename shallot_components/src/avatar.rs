@@ -2,8 +2,22 @@
 //!
 //! User avatar display with image, fallback initials, and status indicator.
 //! Supports groups, sizes, and shapes.
+//!
+//! # Example
+//! ```
+//! use maud::Render;
+//! use shallot_components::avatar::{Avatar, AvatarStatus};
+//! use shallot_components::component::ComponentSize;
+//!
+//! let avatar = Avatar::new("Ada Lovelace")
+//!     .initials("AL")
+//!     .size(ComponentSize::Lg)
+//!     .status(AvatarStatus::Online)
+//!     .render();
+//! ```
 
 use crate::component::{Component, ComponentColor, ComponentSize};
+use crate::skeleton::{Skeleton, SkeletonText};
 use maud::{html, Markup, Render};
 
 /// Avatar component for user/profile display
@@ -161,6 +175,28 @@ impl<'a> Avatar<'a> {
         classes.join(" ")
     }
 
+    /// Render a loading placeholder with this avatar's size and shape
+    pub fn skeleton(&self) -> Markup {
+        html! {
+            div class=(self.build_classes()) aria-hidden="true" {
+                (Skeleton::fill().render())
+            }
+        }
+    }
+
+    /// Render a loading placeholder for an avatar followed by lines of text,
+    /// as used in list rows and comment headers
+    pub fn skeleton_with_text(&self, lines: u8) -> Markup {
+        html! {
+            div class="sh-skeleton-avatar-text" aria-busy="true" {
+                (self.skeleton())
+                div class="sh-skeleton-avatar-text__lines" {
+                    (SkeletonText::new(lines).render())
+                }
+            }
+        }
+    }
+
     fn get_initials(&self) -> String {
         self.initials.map(|s| s.to_string()).unwrap_or_else(|| {
             // Generate initials from alt text
@@ -435,6 +471,19 @@ pub fn avatar_css() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_avatar_skeleton() {
+        let avatar = Avatar::new("Jane Doe").size(ComponentSize::Lg);
+        let html = avatar.skeleton().into_string();
+        assert!(html.contains("sh-avatar--lg"));
+        assert!(html.contains("sh-skeleton"));
+        assert!(!html.contains("JD"));
+
+        let row = avatar.skeleton_with_text(2).into_string();
+        assert!(row.contains("sh-skeleton-avatar-text"));
+        assert!(row.contains("sh-skeleton-text"));
+    }
+
     #[test]
     fn test_avatar_creation() {
         let avatar = Avatar::new("John Doe")
@@ -7,12 +7,13 @@ use crate::component::{
     Component, ComponentColor, ComponentShape, ComponentSize,
 };
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 use shallot_foundation::Icon;
 
 /// Badge component for status indicators and labels
 pub struct Badge<'a> {
     /// Badge text content
-    label: &'a str,
+    label: Cow<'a, str>,
     /// Size variant
     size: ComponentSize,
     /// Color variant
@@ -28,7 +29,7 @@ pub struct Badge<'a> {
     /// Whether the badge is dismissible
     dismissible: bool,
     /// Custom CSS class
-    custom_class: Option<&'a str>,
+    custom_class: Option<Cow<'a, str>>,
 }
 
 /// Badge visual variants
@@ -51,9 +52,9 @@ pub enum IconPosition {
 
 impl<'a> Badge<'a> {
     /// Create a new badge with the given label
-    pub fn new(label: &'a str) -> Self {
+    pub fn new(label: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            label,
+            label: label.into(),
             size: ComponentSize::Md,
             color: ComponentColor::Primary,
             variant: BadgeVariant::Soft,
@@ -108,8 +109,8 @@ impl<'a> Badge<'a> {
     }
 
     /// Add custom CSS class
-    pub fn custom_class(mut self, class: &'a str) -> Self {
-        self.custom_class = Some(class);
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
         self
     }
 
@@ -154,7 +155,7 @@ impl<'a> Badge<'a> {
         }
 
         // Custom class
-        if let Some(custom) = self.custom_class {
+        if let Some(custom) = &self.custom_class {
             classes.push(custom.to_string());
         }
 
@@ -569,6 +570,18 @@ pub fn badge_css() -> String {
 .sh-count-badge--primary { background: var(--sh-accent); }
 .sh-count-badge--success { background: var(--sh-success); }
 .sh-count-badge--warning { background: var(--sh-warning); }
+
+/* Forced colors: solid and soft badges are only a tinted background */
+@media (forced-colors: active) {
+  .sh-badge,
+  .sh-count-badge {
+    border: 1px solid CanvasText;
+  }
+
+  .sh-status-dot {
+    background: CanvasText;
+  }
+}
 "#
     .to_string()
 }
@@ -605,4 +618,10 @@ mod tests {
         let badge2 = CountBadge::new(50).max(99);
         assert_eq!(badge2.format_count(), "50");
     }
+
+    #[test]
+    fn test_badge_css_forced_colors() {
+        let css = badge_css();
+        assert_eq!(shallot_testing::accessibility::forced_colors_issues(&css), vec![]);
+    }
 }
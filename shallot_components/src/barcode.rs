@@ -0,0 +1,690 @@
+//! Barcode Component - Code 128 and EAN-13 as inline SVG
+//!
+//! Linear barcodes for receipts, tickets and inventory pages, rendered
+//! server-side with the human-readable text below the bars. Code 128 picks
+//! subsets A/B/C automatically (digit runs are packed in pairs); EAN-13
+//! computes or validates the check digit.
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Symbology to encode with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarcodeFormat {
+    /// Any ASCII text
+    #[default]
+    Code128,
+    /// 12 digits (check digit appended) or 13 digits (check digit verified)
+    Ean13,
+}
+
+impl BarcodeFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            BarcodeFormat::Code128 => "Code 128",
+            BarcodeFormat::Ean13 => "EAN-13",
+        }
+    }
+}
+
+/// Reason a payload could not be encoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BarcodeError {
+    Empty,
+    /// Character outside the symbology's alphabet
+    InvalidCharacter(char),
+    /// EAN-13 needs 12 or 13 digits
+    InvalidLength(usize),
+    InvalidCheckDigit {
+        expected: u8,
+        found: u8,
+    },
+}
+
+impl std::fmt::Display for BarcodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Barcode data is empty"),
+            Self::InvalidCharacter(c) => write!(f, "Cannot encode {:?}", c),
+            Self::InvalidLength(n) => write!(f, "EAN-13 needs 12 or 13 digits, got {}", n),
+            Self::InvalidCheckDigit { expected, found } => {
+                write!(f, "Check digit should be {}, not {}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BarcodeError {}
+
+/// Bar/space widths for Code 128 values 0-105, each 11 modules wide
+const CODE128_PATTERNS: [&str; 106] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312", "132212",
+    "221213", "221312", "231212", "112232", "122132", "122231", "113222", "123122", "123221",
+    "223211", "221132", "221231", "213212", "223112", "312131", "311222", "321122", "321221",
+    "312212", "322112", "322211", "212123", "212321", "232121", "111323", "131123", "131321",
+    "112313", "132113", "132311", "211313", "231113", "231311", "112133", "112331", "132131",
+    "113123", "113321", "133121", "313121", "211331", "231131", "213113", "213311", "213131",
+    "311123", "311321", "331121", "312113", "312311", "332111", "314111", "221411", "431111",
+    "111224", "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111", "111242",
+    "121142", "121241", "114212", "124112", "124211", "411212", "421112", "421211", "212141",
+    "214121", "412121", "111143", "111341", "131141", "114113", "114311", "411113", "411311",
+    "113141", "114131", "311141", "411131", "211412", "211214", "211232",
+];
+const CODE128_STOP: &str = "2331112";
+
+const CODE_C: u8 = 99;
+const CODE_B: u8 = 100;
+const CODE_A: u8 = 101;
+const START_A: u8 = 103;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CodeSet {
+    A,
+    B,
+    C,
+}
+
+impl CodeSet {
+    fn start(self) -> u8 {
+        START_A + self as u8
+    }
+
+    fn switch(self) -> u8 {
+        match self {
+            CodeSet::A => CODE_A,
+            CodeSet::B => CODE_B,
+            CodeSet::C => CODE_C,
+        }
+    }
+}
+
+/// Code 128 symbol values: start code, data, check value and stop (106)
+pub fn code128_values(data: &str) -> Result<Vec<u8>, BarcodeError> {
+    if data.is_empty() {
+        return Err(BarcodeError::Empty);
+    }
+    if let Some(c) = data.chars().find(|c| !c.is_ascii()) {
+        return Err(BarcodeError::InvalidCharacter(c));
+    }
+
+    let bytes = data.as_bytes();
+    let mut values = Vec::with_capacity(bytes.len() + 4);
+    let mut set: Option<CodeSet> = None;
+    let switch_to = |values: &mut Vec<u8>, set: &mut Option<CodeSet>, next: CodeSet| {
+        if *set != Some(next) {
+            values.push(if set.is_some() {
+                next.switch()
+            } else {
+                next.start()
+            });
+            *set = Some(next);
+        }
+    };
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut run = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let at_edge = i == 0 || i + run == bytes.len();
+        let pack = (run == bytes.len() && run % 2 == 0) || run >= if at_edge { 4 } else { 6 };
+
+        if pack && set != Some(CodeSet::C) {
+            if run % 2 == 1 {
+                match set {
+                    // Leave the odd digit for the subset that follows
+                    None => run -= 1,
+                    // Finish the odd digit in the current subset first
+                    Some(current) => {
+                        values.push(code128_value(current, bytes[i]));
+                        i += 1;
+                        run -= 1;
+                    }
+                }
+            }
+            switch_to(&mut values, &mut set, CodeSet::C);
+        }
+
+        if set == Some(CodeSet::C) && run >= 2 {
+            for pair in bytes[i..i + run - run % 2].chunks(2) {
+                values.push((pair[0] - b'0') * 10 + pair[1] - b'0');
+            }
+            i += run - run % 2;
+            continue;
+        }
+
+        let byte = bytes[i];
+        let next = match (byte, set) {
+            (0..=31, _) => CodeSet::A,
+            (96..=127, _) => CodeSet::B,
+            (_, Some(CodeSet::A)) => CodeSet::A,
+            _ => CodeSet::B,
+        };
+        switch_to(&mut values, &mut set, next);
+        values.push(code128_value(next, byte));
+        i += 1;
+    }
+
+    let check = values
+        .iter()
+        .enumerate()
+        .map(|(k, &v)| k.max(1) as u32 * v as u32)
+        .sum::<u32>()
+        % 103;
+    values.push(check as u8);
+    values.push(106);
+    Ok(values)
+}
+
+fn code128_value(set: CodeSet, byte: u8) -> u8 {
+    match (set, byte) {
+        (CodeSet::A, 0..=31) => byte + 64,
+        _ => byte - 32,
+    }
+}
+
+fn push_widths(bars: &mut Vec<bool>, widths: &str) {
+    for (k, w) in widths.bytes().enumerate() {
+        for _ in 0..(w - b'0') {
+            bars.push(k % 2 == 0);
+        }
+    }
+}
+
+/// Code 128 modules, `true` for a bar
+pub fn encode_code128(data: &str) -> Result<Vec<bool>, BarcodeError> {
+    let mut bars = Vec::new();
+    for value in code128_values(data)? {
+        match CODE128_PATTERNS.get(value as usize) {
+            Some(widths) => push_widths(&mut bars, widths),
+            None => push_widths(&mut bars, CODE128_STOP),
+        }
+    }
+    Ok(bars)
+}
+
+const EAN_L: [&str; 10] = [
+    "0001101", "0011001", "0010011", "0111101", "0100011", "0110001", "0101111", "0111011",
+    "0110111", "0001011",
+];
+const EAN_G: [&str; 10] = [
+    "0100111", "0110011", "0011011", "0100001", "0011101", "0111001", "0000101", "0010001",
+    "0001001", "0010111",
+];
+const EAN_R: [&str; 10] = [
+    "1110010", "1100110", "1101100", "1000010", "1011100", "1001110", "1010000", "1000100",
+    "1001000", "1110100",
+];
+/// Left-half parity (L or G) selected by the leading digit
+const EAN_PARITY: [&str; 10] = [
+    "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL",
+    "LGGLGL",
+];
+
+/// EAN-13 check digit over the first 12 digits
+pub fn ean13_check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .take(12)
+        .enumerate()
+        .map(|(i, &d)| d as u32 * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Validate 12 or 13 digits and return all 13
+pub fn ean13_digits(data: &str) -> Result<Vec<u8>, BarcodeError> {
+    if data.is_empty() {
+        return Err(BarcodeError::Empty);
+    }
+    if let Some(c) = data.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(BarcodeError::InvalidCharacter(c));
+    }
+    let mut digits: Vec<u8> = data.bytes().map(|b| b - b'0').collect();
+    let expected = match digits.len() {
+        12 | 13 => ean13_check_digit(&digits),
+        n => return Err(BarcodeError::InvalidLength(n)),
+    };
+    match digits.get(12) {
+        Some(&found) if found != expected => {
+            return Err(BarcodeError::InvalidCheckDigit { expected, found })
+        }
+        Some(_) => {}
+        None => digits.push(expected),
+    }
+    Ok(digits)
+}
+
+/// EAN-13 modules (95 wide) with the guard bar positions
+fn encode_ean13_with_guards(digits: &[u8]) -> (Vec<bool>, Vec<bool>) {
+    let mut bars = Vec::with_capacity(95);
+    let mut guards = Vec::with_capacity(95);
+    let mut push = |pattern: &str, guard: bool| {
+        for b in pattern.bytes() {
+            bars.push(b == b'1');
+            guards.push(guard);
+        }
+    };
+
+    push("101", true);
+    let parity = EAN_PARITY[digits[0] as usize].as_bytes();
+    for (k, &d) in digits[1..7].iter().enumerate() {
+        let table = if parity[k] == b'L' { &EAN_L } else { &EAN_G };
+        push(table[d as usize], false);
+    }
+    push("01010", true);
+    for &d in &digits[7..13] {
+        push(EAN_R[d as usize], false);
+    }
+    push("101", true);
+    (bars, guards)
+}
+
+/// EAN-13 modules, `true` for a bar
+pub fn encode_ean13(data: &str) -> Result<Vec<bool>, BarcodeError> {
+    Ok(encode_ean13_with_guards(&ean13_digits(data)?).0)
+}
+
+struct Encoded {
+    bars: Vec<bool>,
+    guards: Vec<bool>,
+    digits: Vec<u8>,
+}
+
+/// SVG barcode with human-readable text
+#[derive(Debug, Clone)]
+pub struct Barcode<'a> {
+    data: Cow<'a, str>,
+    format: BarcodeFormat,
+    module_width: u32,
+    height: u32,
+    color: Cow<'a, str>,
+    background: Cow<'a, str>,
+    show_text: bool,
+    label: Option<Cow<'a, str>>,
+    custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> Barcode<'a> {
+    pub fn new(data: impl Into<Cow<'a, str>>, format: BarcodeFormat) -> Self {
+        Self {
+            data: data.into(),
+            format,
+            module_width: 2,
+            height: 60,
+            color: Cow::Borrowed("#000000"),
+            background: Cow::Borrowed("#ffffff"),
+            show_text: true,
+            label: None,
+            custom_class: None,
+        }
+    }
+
+    pub fn code128(data: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(data, BarcodeFormat::Code128)
+    }
+
+    pub fn ean13(data: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(data, BarcodeFormat::Ean13)
+    }
+
+    /// Narrowest bar width in CSS pixels
+    pub fn module_width(mut self, px: u32) -> Self {
+        self.module_width = px.max(1);
+        self
+    }
+
+    /// Bar height in modules
+    pub fn height(mut self, modules: u32) -> Self {
+        self.height = modules.max(1);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Cow<'a, str>>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn background(mut self, background: impl Into<Cow<'a, str>>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Print the human-readable text below the bars
+    pub fn show_text(mut self, show: bool) -> Self {
+        self.show_text = show;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn encode(&self) -> Result<Encoded, BarcodeError> {
+        match self.format {
+            BarcodeFormat::Code128 => {
+                let bars = encode_code128(&self.data)?;
+                Ok(Encoded {
+                    guards: vec![false; bars.len()],
+                    bars,
+                    digits: Vec::new(),
+                })
+            }
+            BarcodeFormat::Ean13 => {
+                let digits = ean13_digits(&self.data)?;
+                let (bars, guards) = encode_ean13_with_guards(&digits);
+                Ok(Encoded {
+                    bars,
+                    guards,
+                    digits,
+                })
+            }
+        }
+    }
+
+    /// Modules of the encoded symbol, `true` for a bar
+    pub fn bars(&self) -> Result<Vec<bool>, BarcodeError> {
+        self.encode().map(|encoded| encoded.bars)
+    }
+
+    /// Quiet zones in modules (left, right)
+    fn quiet_zone(&self) -> (usize, usize) {
+        match self.format {
+            BarcodeFormat::Code128 => (10, 10),
+            BarcodeFormat::Ean13 => (11, 7),
+        }
+    }
+
+    fn svg(&self, encoded: &Encoded) -> Markup {
+        let (left, right) = self.quiet_zone();
+        let width = left + encoded.bars.len() + right;
+        let bar_height = self.height as usize;
+        let text_height = if self.show_text { 11 } else { 0 };
+        let height = bar_height + text_height;
+        let baseline = bar_height + 9;
+
+        let mut d = String::new();
+        let mut x = 0;
+        while x < encoded.bars.len() {
+            if !encoded.bars[x] {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < encoded.bars.len() && encoded.bars[x] {
+                x += 1;
+            }
+            let h = if self.show_text && encoded.guards[start] {
+                bar_height + 5
+            } else {
+                bar_height
+            };
+            let _ = write!(d, "M{} 0h{}v{}h-{}z", start + left, x - start, h, x - start);
+        }
+
+        let text = match self.format {
+            BarcodeFormat::Code128 => self.data.to_string(),
+            BarcodeFormat::Ean13 => encoded.digits.iter().map(|d| d.to_string()).collect(),
+        };
+        let label = match &self.label {
+            Some(label) => label.to_string(),
+            None => format!("{} barcode {}", self.format.name(), text),
+        };
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                class="sh-barcode__svg"
+                viewBox=(format!("0 0 {} {}", width, height))
+                width=(width * self.module_width as usize)
+                height=(height * self.module_width as usize)
+                role="img"
+                aria-label=(label)
+                shape-rendering="crispEdges"
+            {
+                rect width=(width) height=(height) fill=(self.background) {}
+                path class="sh-barcode__bars" d=(d) fill=(self.color) {}
+                @if self.show_text {
+                    g
+                        class="sh-barcode__text"
+                        fill=(self.color)
+                        font-family="ui-monospace, Menlo, Consolas, monospace"
+                        font-size="9"
+                        text-anchor="middle"
+                        aria-hidden="true"
+                    {
+                        @if self.format == BarcodeFormat::Ean13 {
+                            text x=(left - 4) y=(baseline) { (&text[..1]) }
+                            text x=(left + 3 + 21) y=(baseline) { (&text[1..7]) }
+                            text x=(left + 50 + 21) y=(baseline) { (&text[7..]) }
+                        } @else {
+                            text x=(left + encoded.bars.len() / 2) y=(baseline) { (text) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Standalone SVG document for print or download
+    pub fn to_svg(&self) -> Result<String, BarcodeError> {
+        Ok(self.svg(&self.encode()?).into_string())
+    }
+}
+
+impl<'a> Render for Barcode<'a> {
+    fn render(&self) -> Markup {
+        let mut classes = vec!["sh-barcode"];
+        if let Some(class) = &self.custom_class {
+            classes.push(class);
+        }
+
+        match self.encode() {
+            Ok(encoded) => html! {
+                div class=(classes.join(" ")) {
+                    (self.svg(&encoded))
+                }
+            },
+            // Unencodable input: still show the reader the value
+            Err(err) => html! {
+                div class={ (classes.join(" ")) " sh-barcode--fallback" } title=(err) {
+                    code class="sh-barcode__value" { (self.data) }
+                }
+            },
+        }
+    }
+}
+
+pub fn barcode_css() -> String {
+    r#"
+.sh-barcode {
+    display: inline-block;
+    max-width: 100%;
+    line-height: 0;
+}
+
+.sh-barcode__svg {
+    display: block;
+    max-width: 100%;
+    height: auto;
+}
+
+.sh-barcode--fallback {
+    line-height: 1.4;
+    padding: 0.5rem 0.75rem;
+    border: 1px dashed var(--sh-color-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.5rem);
+}
+
+.sh-barcode__value {
+    font-family: var(--sh-font-mono, ui-monospace, monospace);
+    font-size: 0.75rem;
+}
+
+@media print {
+    .sh-barcode {
+        break-inside: avoid;
+    }
+
+    .sh-barcode__svg {
+        -webkit-print-color-adjust: exact;
+        print-color-adjust: exact;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code128_patterns_are_eleven_modules() {
+        for widths in CODE128_PATTERNS {
+            assert_eq!(widths.bytes().map(|w| (w - b'0') as u32).sum::<u32>(), 11);
+        }
+        assert_eq!(
+            CODE128_STOP.bytes().map(|w| (w - b'0') as u32).sum::<u32>(),
+            13
+        );
+    }
+
+    #[test]
+    fn test_code128_subset_b_checksum() {
+        // Start B, P J J 1 2 3 C, check, stop
+        assert_eq!(
+            code128_values("PJJ123C").unwrap(),
+            vec![104, 48, 42, 42, 17, 18, 19, 35, 55, 106]
+        );
+    }
+
+    #[test]
+    fn test_code128_packs_digits_in_subset_c() {
+        // All-digit payloads start in C
+        assert_eq!(code128_values("123456").unwrap()[..4], [105, 12, 34, 56]);
+        // Odd leading run: pairs in C, then the last digit in B
+        assert_eq!(
+            code128_values("12345").unwrap()[..5],
+            [105, 12, 34, CODE_B, 21]
+        );
+        // A long inner run switches to C and back
+        let values = code128_values("AB123456Z").unwrap();
+        assert_eq!(values[..8], [104, 33, 34, CODE_C, 12, 34, 56, CODE_B]);
+        // Short inner runs stay in B
+        assert_eq!(code128_values("A12B").unwrap()[..5], [104, 33, 17, 18, 34]);
+    }
+
+    #[test]
+    fn test_code128_control_characters_use_subset_a() {
+        assert_eq!(code128_values("\tA").unwrap()[..3], [103, 73, 33]);
+        assert_eq!(
+            code128_values("A\tB").unwrap()[..5],
+            [104, 33, CODE_A, 73, 34]
+        );
+        assert_eq!(
+            code128_values("é"),
+            Err(BarcodeError::InvalidCharacter('é'))
+        );
+        assert_eq!(code128_values(""), Err(BarcodeError::Empty));
+    }
+
+    #[test]
+    fn test_code128_modules() {
+        let bars = encode_code128("Hi").unwrap();
+        // start + 2 data + check = 4 * 11, stop = 13
+        assert_eq!(bars.len(), 57);
+        assert!(bars[0] && bars[1] && !bars[2]);
+        assert!(bars[bars.len() - 1] && bars[bars.len() - 2]);
+    }
+
+    #[test]
+    fn test_ean13_check_digit() {
+        assert_eq!(ean13_digits("400638133393").unwrap()[12], 1);
+        assert!(ean13_digits("4006381333931").is_ok());
+        assert_eq!(
+            ean13_digits("4006381333932"),
+            Err(BarcodeError::InvalidCheckDigit {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(ean13_digits("12345"), Err(BarcodeError::InvalidLength(5)));
+        assert_eq!(
+            ean13_digits("40063813339x"),
+            Err(BarcodeError::InvalidCharacter('x'))
+        );
+    }
+
+    #[test]
+    fn test_ean13_modules() {
+        let bars = encode_ean13("4006381333931").unwrap();
+        assert_eq!(bars.len(), 95);
+        let bits = |range: std::ops::Range<usize>| -> String {
+            bars[range]
+                .iter()
+                .map(|&b| if b { '1' } else { '0' })
+                .collect()
+        };
+        assert_eq!(bits(0..3), "101");
+        assert_eq!(bits(45..50), "01010");
+        assert_eq!(bits(92..95), "101");
+        // Leading 4 selects LGLLGG: the first left digit (0) uses L
+        assert_eq!(bits(3..10), EAN_L[0]);
+        assert_eq!(bits(10..17), EAN_G[0]);
+        assert_eq!(bits(88..92), &EAN_R[1][3..]);
+    }
+
+    #[test]
+    fn test_barcode_render() {
+        let html = Barcode::ean13("400638133393")
+            .module_width(3)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"class="sh-barcode""#));
+        assert!(html.contains(r#"viewBox="0 0 113 71""#));
+        assert!(html.contains(r#"width="339""#));
+        assert!(html.contains(r#"aria-label="EAN-13 barcode 4006381333931""#));
+        // Guard bars extend into the text area
+        assert!(html.contains("M11 0h1v65h-1z"));
+        assert!(html.contains(">4</text>"));
+        assert!(html.contains(">006381</text>"));
+        assert!(html.contains(">333931</text>"));
+    }
+
+    #[test]
+    fn test_barcode_without_text() {
+        let svg = Barcode::code128("TICKET-0042")
+            .height(40)
+            .show_text(false)
+            .color("#1f2937")
+            .label("Ticket 42")
+            .to_svg()
+            .unwrap();
+        assert!(!svg.contains("<text"));
+        assert!(svg.contains(r#"aria-label="Ticket 42""#));
+        assert!(svg.contains(r##"fill="#1f2937""##));
+        assert!(svg.contains("M10 0h2v40h-2z"));
+    }
+
+    #[test]
+    fn test_barcode_fallback() {
+        let html = Barcode::ean13("not-a-number").render().into_string();
+        assert!(html.contains("sh-barcode--fallback"));
+        assert!(html.contains("not-a-number"));
+        assert!(!html.contains("<svg"));
+    }
+
+    #[test]
+    fn test_barcode_css() {
+        let css = barcode_css();
+        assert!(css.contains(".sh-barcode"));
+        assert!(css.contains("print-color-adjust"));
+    }
+}
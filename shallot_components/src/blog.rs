@@ -0,0 +1,751 @@
+//! Blog Components - Article header, author bio and related posts
+//!
+//! Reading time is computed when the page is rendered, from the article
+//! text or HTML passed to [`ArticleHeader::content`]. Dates are ISO 8601
+//! (`2026-03-14`) and render in `<time>` elements.
+//!
+//! Give a [`RelatedPost`] and the matching [`ArticleHeader`] the same
+//! view transition name and the card morphs into the header on navigation
+//! (see [`crate::view_transitions`]).
+
+use crate::avatar::Avatar;
+use crate::card::Card;
+use crate::component::ComponentSize;
+use crate::datetime::DateTime;
+use crate::view_transitions::view_transition_style;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Average adult silent reading speed
+pub const WORDS_PER_MINUTE: usize = 230;
+
+/// Count words in text or HTML; tags and their attributes are skipped
+pub fn word_count(content: &str) -> usize {
+    let mut count = 0;
+    let mut in_tag = false;
+    let mut in_word = false;
+    for c in content.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                in_word = false;
+            }
+            '>' if in_tag => in_tag = false,
+            _ if in_tag => {}
+            c if c.is_alphanumeric() => {
+                if !in_word {
+                    count += 1;
+                    in_word = true;
+                }
+            }
+            '\'' | '’' | '-' if in_word => {}
+            _ => in_word = false,
+        }
+    }
+    count
+}
+
+/// Whole minutes needed to read `content`, at least one
+pub fn reading_time(content: &str) -> u32 {
+    word_count(content).div_ceil(WORDS_PER_MINUTE).max(1) as u32
+}
+
+/// `<time>` for an ISO date, or the raw text when it does not parse
+fn render_date(class: &str, iso: &str) -> Markup {
+    html! {
+        @if let Some(date) = DateTime::parse(iso) {
+            (date.show_time(false).class(class))
+        } @else {
+            span class=(class) { (iso) }
+        }
+    }
+}
+
+/// Byline of an article: who wrote it and where to read more
+#[derive(Debug, Clone)]
+pub struct ArticleAuthor<'a> {
+    pub name: Cow<'a, str>,
+    pub avatar: Option<&'a str>,
+    pub role: Option<Cow<'a, str>>,
+    pub href: Option<Cow<'a, str>>,
+}
+
+impl<'a> ArticleAuthor<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            avatar: None,
+            role: None,
+            href: None,
+        }
+    }
+
+    pub fn avatar(mut self, src: &'a str) -> Self {
+        self.avatar = Some(src);
+        self
+    }
+
+    /// Short description, e.g. "Staff engineer"
+    pub fn role(mut self, role: impl Into<Cow<'a, str>>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Author page, linked from the name
+    pub fn href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    fn render_avatar(&self, size: ComponentSize) -> Markup {
+        let mut avatar = Avatar::new(&self.name).size(size);
+        if let Some(src) = self.avatar {
+            avatar = avatar.src(src);
+        }
+        avatar.render()
+    }
+
+    fn render_name(&self, class: &str) -> Markup {
+        html! {
+            @if let Some(href) = &self.href {
+                a class=(class) href=(href) rel="author" { (self.name) }
+            } @else {
+                span class=(class) { (self.name) }
+            }
+        }
+    }
+}
+
+/// Title block at the top of a blog post
+#[derive(Debug, Clone)]
+pub struct ArticleHeader<'a> {
+    pub title: Cow<'a, str>,
+    pub subtitle: Option<Cow<'a, str>>,
+    pub category: Option<Cow<'a, str>>,
+    pub category_href: Option<Cow<'a, str>>,
+    pub author: Option<ArticleAuthor<'a>>,
+    pub published: Option<Cow<'a, str>>,
+    pub updated: Option<Cow<'a, str>>,
+    pub reading_minutes: Option<u32>,
+    pub view_transition_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> ArticleHeader<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            subtitle: None,
+            category: None,
+            category_href: None,
+            author: None,
+            published: None,
+            updated: None,
+            reading_minutes: None,
+            view_transition_name: None,
+        }
+    }
+
+    pub fn subtitle(mut self, subtitle: impl Into<Cow<'a, str>>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Label shown above the title
+    pub fn category(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.category = Some(label.into());
+        self
+    }
+
+    /// Archive page the category label links to
+    pub fn category_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.category_href = Some(href.into());
+        self
+    }
+
+    pub fn author(mut self, author: ArticleAuthor<'a>) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    pub fn published(mut self, iso: impl Into<Cow<'a, str>>) -> Self {
+        self.published = Some(iso.into());
+        self
+    }
+
+    /// Last revision; shown only when it differs from the publish date
+    pub fn updated(mut self, iso: impl Into<Cow<'a, str>>) -> Self {
+        self.updated = Some(iso.into());
+        self
+    }
+
+    /// Compute the reading time from the article text or HTML
+    pub fn content(mut self, content: &str) -> Self {
+        self.reading_minutes = Some(reading_time(content));
+        self
+    }
+
+    /// Set the reading time directly
+    pub fn reading_minutes(mut self, minutes: u32) -> Self {
+        self.reading_minutes = Some(minutes.max(1));
+        self
+    }
+
+    /// Morph the title from a [`RelatedPost`] card with the same name
+    pub fn view_transition_name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.view_transition_name = Some(name.into());
+        self
+    }
+
+    fn show_updated(&self) -> Option<&str> {
+        let updated = self.updated.as_deref()?;
+        (self.published.as_deref() != Some(updated)).then_some(updated)
+    }
+}
+
+impl<'a> Render for ArticleHeader<'a> {
+    fn render(&self) -> Markup {
+        let style = self
+            .view_transition_name
+            .as_deref()
+            .map(view_transition_style);
+        let has_meta = self.author.is_some()
+            || self.published.is_some()
+            || self.show_updated().is_some()
+            || self.reading_minutes.is_some();
+
+        html! {
+            header class="sh-article-header" {
+                @if let Some(label) = &self.category {
+                    @if let Some(href) = &self.category_href {
+                        a class="sh-article-header__category" href=(href) { (label) }
+                    } @else {
+                        span class="sh-article-header__category" { (label) }
+                    }
+                }
+                h1 class="sh-article-header__title" style=[style] { (self.title) }
+                @if let Some(subtitle) = &self.subtitle {
+                    p class="sh-article-header__subtitle" { (subtitle) }
+                }
+                @if has_meta {
+                    div class="sh-article-header__meta" {
+                        @if let Some(author) = &self.author {
+                            (author.render_avatar(ComponentSize::Md))
+                            div class="sh-article-header__byline" {
+                                (author.render_name("sh-article-header__author"))
+                                @if let Some(role) = &author.role {
+                                    span class="sh-article-header__role" { (role) }
+                                }
+                            }
+                        }
+                        ul class="sh-article-header__facts" {
+                            @if let Some(published) = &self.published {
+                                li {
+                                    span class="sh-visually-hidden" { "Published " }
+                                    (render_date("sh-article-header__date", published))
+                                }
+                            }
+                            @if let Some(updated) = self.show_updated() {
+                                li {
+                                    "Updated "
+                                    (render_date("sh-article-header__date", updated))
+                                }
+                            }
+                            @if let Some(minutes) = self.reading_minutes {
+                                li class="sh-article-header__reading-time" {
+                                    (minutes) " min read"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// "About the author" card shown after an article
+#[derive(Debug, Clone)]
+pub struct AuthorBio<'a> {
+    pub author: ArticleAuthor<'a>,
+    pub bio: Cow<'a, str>,
+    pub links: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    pub heading: Cow<'a, str>,
+}
+
+impl<'a> AuthorBio<'a> {
+    pub fn new(author: ArticleAuthor<'a>, bio: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            author,
+            bio: bio.into(),
+            links: Vec::new(),
+            heading: Cow::Borrowed("About the author"),
+        }
+    }
+
+    /// Profile link, e.g. ("Mastodon", "https://…")
+    pub fn link(mut self, label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        self.links.push((label.into(), href.into()));
+        self
+    }
+
+    pub fn heading(mut self, heading: impl Into<Cow<'a, str>>) -> Self {
+        self.heading = heading.into();
+        self
+    }
+}
+
+impl<'a> Render for AuthorBio<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            aside class="sh-author-bio" aria-label=(self.heading) {
+                (self.author.render_avatar(ComponentSize::Lg))
+                div class="sh-author-bio__content" {
+                    p class="sh-author-bio__heading" { (self.heading) }
+                    (self.author.render_name("sh-author-bio__name"))
+                    @if let Some(role) = &self.author.role {
+                        span class="sh-author-bio__role" { (role) }
+                    }
+                    p class="sh-author-bio__bio" { (self.bio) }
+                    @if !self.links.is_empty() {
+                        ul class="sh-author-bio__links" {
+                            @for (label, href) in &self.links {
+                                li { a href=(href) rel="me" { (label) } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Teaser of another post
+#[derive(Debug, Clone)]
+pub struct RelatedPost<'a> {
+    pub title: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+    pub excerpt: Option<Cow<'a, str>>,
+    pub image: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    pub date: Option<Cow<'a, str>>,
+    pub reading_minutes: Option<u32>,
+    pub view_transition_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> RelatedPost<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            href: href.into(),
+            excerpt: None,
+            image: None,
+            date: None,
+            reading_minutes: None,
+            view_transition_name: None,
+        }
+    }
+
+    pub fn excerpt(mut self, excerpt: impl Into<Cow<'a, str>>) -> Self {
+        self.excerpt = Some(excerpt.into());
+        self
+    }
+
+    pub fn image(mut self, src: impl Into<Cow<'a, str>>, alt: impl Into<Cow<'a, str>>) -> Self {
+        self.image = Some((src.into(), alt.into()));
+        self
+    }
+
+    pub fn date(mut self, iso: impl Into<Cow<'a, str>>) -> Self {
+        self.date = Some(iso.into());
+        self
+    }
+
+    pub fn reading_minutes(mut self, minutes: u32) -> Self {
+        self.reading_minutes = Some(minutes.max(1));
+        self
+    }
+
+    /// Morph this card into the [`ArticleHeader`] with the same name
+    pub fn view_transition_name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.view_transition_name = Some(name.into());
+        self
+    }
+
+    fn to_card(&self) -> Card {
+        let body = html! {
+            @if let Some(excerpt) = &self.excerpt {
+                p class="sh-related-posts__excerpt" { (excerpt) }
+            }
+            @if self.date.is_some() || self.reading_minutes.is_some() {
+                p class="sh-related-posts__meta" {
+                    @if let Some(date) = &self.date {
+                        (render_date("sh-related-posts__date", date))
+                    }
+                    @if self.date.is_some() && self.reading_minutes.is_some() {
+                        span aria-hidden="true" { " · " }
+                    }
+                    @if let Some(minutes) = self.reading_minutes {
+                        span { (minutes) " min read" }
+                    }
+                }
+            }
+        };
+        let mut card = Card::new(body)
+            .title(self.title.as_ref())
+            .href(self.href.as_ref())
+            .custom_class("sh-related-posts__card");
+        if let Some((src, alt)) = &self.image {
+            card = card.cover_image(src.as_ref(), alt.as_ref());
+        }
+        if let Some(name) = &self.view_transition_name {
+            card = card.view_transition_name(name.as_ref());
+        }
+        card
+    }
+}
+
+/// Grid of [`RelatedPost`] cards
+#[derive(Debug, Clone)]
+pub struct RelatedPosts<'a> {
+    pub posts: Vec<RelatedPost<'a>>,
+    pub title: Cow<'a, str>,
+    pub columns: u8,
+}
+
+impl<'a> RelatedPosts<'a> {
+    pub fn new(posts: Vec<RelatedPost<'a>>) -> Self {
+        Self {
+            posts,
+            title: Cow::Borrowed("Related posts"),
+            columns: 3,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Columns on wide screens (1-4); narrower screens wrap to fewer
+    pub fn columns(mut self, columns: u8) -> Self {
+        self.columns = columns.clamp(1, 4);
+        self
+    }
+}
+
+impl<'a> Render for RelatedPosts<'a> {
+    fn render(&self) -> Markup {
+        if self.posts.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            section class="sh-related-posts" aria-label=(self.title) {
+                h2 class="sh-related-posts__title" { (self.title) }
+                ul
+                    class="sh-related-posts__grid"
+                    style=(format!("--sh-related-columns: {};", self.columns)) {
+                    @for post in &self.posts {
+                        li { (post.to_card()) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for blog components
+pub fn blog_css() -> String {
+    r#"
+.sh-article-header {
+    display: flex;
+    flex-direction: column;
+    gap: 0.75rem;
+    max-width: 48rem;
+    margin: 0 auto 2rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-article-header__category {
+    align-self: flex-start;
+    font-size: 0.8125rem;
+    font-weight: 600;
+    letter-spacing: 0.05em;
+    text-transform: uppercase;
+    color: var(--sh-primary, #3b82f6);
+    text-decoration: none;
+}
+
+.sh-article-header__title {
+    margin: 0;
+    font-size: clamp(2rem, 5vw, 3rem);
+    font-weight: 800;
+    line-height: 1.15;
+    letter-spacing: -0.02em;
+}
+
+.sh-article-header__subtitle {
+    margin: 0;
+    font-size: 1.25rem;
+    line-height: 1.5;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-article-header__meta {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.75rem 1rem;
+    margin-top: 0.5rem;
+    font-size: 0.875rem;
+}
+
+.sh-article-header__byline {
+    display: flex;
+    flex-direction: column;
+}
+
+.sh-article-header__author {
+    font-weight: 600;
+    color: var(--sh-text, #1f2937);
+    text-decoration: none;
+}
+
+a.sh-article-header__author:hover {
+    text-decoration: underline;
+}
+
+.sh-article-header__role {
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-article-header__facts {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 0.25rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-article-header__facts li + li::before {
+    content: "·";
+    margin-right: 0.25rem;
+}
+
+.sh-article-header__byline + .sh-article-header__facts {
+    margin-left: auto;
+}
+
+/* Author bio */
+.sh-author-bio {
+    display: flex;
+    gap: 1.25rem;
+    padding: 1.5rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.75rem);
+    background: var(--sh-surface, #fff);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+@media (max-width: 480px) {
+    .sh-author-bio {
+        flex-direction: column;
+    }
+}
+
+.sh-author-bio__content {
+    display: flex;
+    flex-direction: column;
+    gap: 0.25rem;
+    min-width: 0;
+}
+
+.sh-author-bio__heading {
+    margin: 0;
+    font-size: 0.75rem;
+    font-weight: 600;
+    letter-spacing: 0.05em;
+    text-transform: uppercase;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-author-bio__name {
+    font-size: 1.125rem;
+    font-weight: 700;
+    color: inherit;
+    text-decoration: none;
+}
+
+.sh-author-bio__role {
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-author-bio__bio {
+    margin: 0.5rem 0 0;
+    line-height: 1.6;
+}
+
+.sh-author-bio__links {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 1rem;
+    margin: 0.5rem 0 0;
+    padding: 0;
+    list-style: none;
+    font-size: 0.875rem;
+}
+
+.sh-author-bio__links a {
+    color: var(--sh-primary, #3b82f6);
+}
+
+/* Related posts */
+.sh-related-posts {
+    display: flex;
+    flex-direction: column;
+    gap: 1.25rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-related-posts__title {
+    margin: 0;
+    font-size: 1.5rem;
+    font-weight: 700;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-related-posts__grid {
+    display: grid;
+    grid-template-columns: repeat(auto-fill, minmax(max(16rem, calc((100% - 3rem) / var(--sh-related-columns, 3))), 1fr));
+    gap: 1.5rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-related-posts__card {
+    height: 100%;
+    text-decoration: none;
+    color: inherit;
+}
+
+.sh-related-posts__excerpt {
+    margin: 0 0 0.75rem;
+    line-height: 1.5;
+    color: var(--sh-text-muted, #6b7280);
+    display: -webkit-box;
+    -webkit-line-clamp: 3;
+    -webkit-box-orient: vertical;
+    overflow: hidden;
+}
+
+.sh-related-posts__meta {
+    margin: 0;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_count_and_reading_time() {
+        assert_eq!(word_count("Hello, world! It's a well-known fact."), 6);
+        assert_eq!(word_count(r#"<p class="lead">Two <em>words</em></p>"#), 2);
+        assert_eq!(reading_time(""), 1);
+        assert_eq!(reading_time(&"word ".repeat(230)), 1);
+        assert_eq!(reading_time(&"word ".repeat(231)), 2);
+        assert_eq!(reading_time(&"word ".repeat(1000)), 5);
+    }
+
+    #[test]
+    fn test_article_header_render() {
+        let html = ArticleHeader::new("Zero-JS forms")
+            .subtitle("Validation without scripts")
+            .category("Guides")
+            .category_href("/blog/guides")
+            .author(
+                ArticleAuthor::new("Ada Park")
+                    .role("Staff engineer")
+                    .href("/authors/ada"),
+            )
+            .published("2026-03-14")
+            .updated("2026-04-02")
+            .content(&"word ".repeat(700))
+            .view_transition_name("post-zero-js-forms")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<h1 class="sh-article-header__title" style="view-transition-name: post-zero-js-forms"#));
+        assert!(html.contains(r#"href="/blog/guides""#));
+        assert!(html.contains(r#"href="/authors/ada" rel="author""#));
+        assert!(html.contains(r#"datetime="2026-03-14">Mar 14, 2026</time>"#));
+        assert!(html.contains("Updated "));
+        assert!(html.contains("4 min read"));
+    }
+
+    #[test]
+    fn test_article_header_minimal() {
+        let html = ArticleHeader::new("Notes")
+            .published("2026-03-14")
+            .updated("2026-03-14")
+            .render()
+            .into_string();
+        assert!(!html.contains("Updated"));
+        assert!(!html.contains("min read"));
+        assert!(!html.contains("sh-article-header__category"));
+
+        let bare = ArticleHeader::new("Notes").render().into_string();
+        assert!(!bare.contains("sh-article-header__meta"));
+    }
+
+    #[test]
+    fn test_author_bio_render() {
+        let html = AuthorBio::new(ArticleAuthor::new("Ada Park"), "Writes about forms.")
+            .link("Mastodon", "https://hachyderm.io/@ada")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"aria-label="About the author""#));
+        assert!(html.contains("Writes about forms."));
+        assert!(html.contains(r#"href="https://hachyderm.io/@ada" rel="me""#));
+        assert!(html.contains("sh-avatar"));
+    }
+
+    #[test]
+    fn test_related_posts_render() {
+        let html = RelatedPosts::new(vec![
+            RelatedPost::new("Styling inputs", "/blog/inputs")
+                .excerpt("A tour of :user-invalid")
+                .date("2026-02-01")
+                .reading_minutes(6)
+                .image("/img/inputs.png", "Form fields"),
+            RelatedPost::new("Dialogs", "/blog/dialogs"),
+        ])
+        .columns(2)
+        .render()
+        .into_string();
+        assert!(html.contains("--sh-related-columns: 2;"));
+        assert!(html.contains(r#"href="/blog/inputs""#));
+        assert!(html.contains("sh-card__cover-image"));
+        assert!(html.contains("Feb 1, 2026"));
+        assert!(html.contains("6 min read"));
+        assert_eq!(html.matches("<li>").count(), 2);
+
+        assert_eq!(RelatedPosts::new(vec![]).render().into_string(), "");
+    }
+
+    #[test]
+    fn test_blog_css() {
+        let css = blog_css();
+        assert!(css.contains(".sh-article-header__title"));
+        assert!(css.contains(".sh-author-bio"));
+        assert!(css.contains("--sh-related-columns"));
+    }
+}
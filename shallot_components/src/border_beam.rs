@@ -2,6 +2,7 @@
 //!
 //! An animated border beam effect that creates a moving gradient around an element's border.
 
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 /// BorderBeam variant for styling
@@ -138,7 +139,13 @@ impl Render for BorderBeam {
 
 /// Generate border beam CSS
 pub fn border_beam_css() -> String {
-    r#"
+    border_beam_css_for(MotionConfig::default())
+}
+
+/// BorderBeam CSS at the given motion level
+pub fn border_beam_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-border-beam {
     --sh-beam-dur: 2.5s;
     --sh-beam-from: #ff80b5;
@@ -255,8 +262,19 @@ pub fn border_beam_css() -> String {
         background-position: 100% 0, 120% 0, 140% 0, 160% 0, 180% 0, 200% 0, 0 0, 0 0;
     }
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-border-beam__beam,
+.sh-border-beam--rainbow .sh-border-beam__beam {
+    animation: none;
+}
+"#,
+        off: r#"
+.sh-border-beam__beam {
+    display: none;
+}
+"#,
+    })
 }
 
 #[cfg(test)]
@@ -341,4 +359,13 @@ mod tests {
         assert!(css.contains(".sh-border-beam"));
         assert!(css.contains("@keyframes border-beam-spin"));
     }
+
+    #[test]
+    fn test_border_beam_motion() {
+        let css = border_beam_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes"));
+        assert!(
+            css.contains(".sh-border-beam--rainbow .sh-border-beam__beam {\n    animation: none;")
+        );
+    }
 }
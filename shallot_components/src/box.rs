@@ -20,6 +20,7 @@
 //! ```
 
 use maud::{html, Markup};
+use shallot_foundation::{ClassList, SpacingScale};
 
 /// Display property variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -123,6 +124,7 @@ pub enum SizeValue {
     MinContent,
     MaxContent,
     FitContent,
+    Token(SpacingToken),
 }
 
 impl SizeValue {
@@ -137,6 +139,64 @@ impl SizeValue {
             Self::MinContent => "min-content".to_string(),
             Self::MaxContent => "max-content".to_string(),
             Self::FitContent => "fit-content".to_string(),
+            Self::Token(token) => token.css_value().to_string(),
+        }
+    }
+}
+
+/// Step on the theme's spacing scale, resolving to `var(--sh-space-N)`.
+///
+/// Each step is a multiple of `--sh-space-unit` (4px by default, so `S4` is
+/// 16px); see [`SpacingScale::token_css`](shallot_foundation::SpacingScale::token_css).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpacingToken {
+    S1,
+    S2,
+    S3,
+    S4,
+    S5,
+    S6,
+    S7,
+    S8,
+    S9,
+    S10,
+    S11,
+    S12,
+}
+
+impl SpacingToken {
+    /// Position on the scale, 1 to 12
+    pub const fn index(&self) -> u8 {
+        match self {
+            Self::S1 => 1,
+            Self::S2 => 2,
+            Self::S3 => 3,
+            Self::S4 => 4,
+            Self::S5 => 5,
+            Self::S6 => 6,
+            Self::S7 => 7,
+            Self::S8 => 8,
+            Self::S9 => 9,
+            Self::S10 => 10,
+            Self::S11 => 11,
+            Self::S12 => 12,
+        }
+    }
+
+    pub const fn css_value(&self) -> &'static str {
+        match self {
+            Self::S1 => "var(--sh-space-1)",
+            Self::S2 => "var(--sh-space-2)",
+            Self::S3 => "var(--sh-space-3)",
+            Self::S4 => "var(--sh-space-4)",
+            Self::S5 => "var(--sh-space-5)",
+            Self::S6 => "var(--sh-space-6)",
+            Self::S7 => "var(--sh-space-7)",
+            Self::S8 => "var(--sh-space-8)",
+            Self::S9 => "var(--sh-space-9)",
+            Self::S10 => "var(--sh-space-10)",
+            Self::S11 => "var(--sh-space-11)",
+            Self::S12 => "var(--sh-space-12)",
         }
     }
 }
@@ -148,6 +208,7 @@ pub enum SpacingValue {
     Px(u32),
     Rem(f32),
     Auto,
+    Token(SpacingToken),
 }
 
 impl SpacingValue {
@@ -157,6 +218,7 @@ impl SpacingValue {
             Self::Px(v) => format!("{}px", v),
             Self::Rem(v) => format!("{}rem", v),
             Self::Auto => "auto".to_string(),
+            Self::Token(token) => token.css_value().to_string(),
         }
     }
 }
@@ -731,9 +793,10 @@ impl Box {
 
     /// Build class attribute
     fn build_class(&self) -> String {
-        let mut classes = vec!["sh-box".to_string()];
-        classes.extend(self.classes.clone());
-        classes.join(" ")
+        self.classes
+            .iter()
+            .fold(ClassList::new("sh-box"), |list, class| list.push(class))
+            .build()
     }
 
     /// Render the box with children
@@ -850,6 +913,18 @@ impl From<f32> for SpacingValue {
     }
 }
 
+impl From<SpacingToken> for SpacingValue {
+    fn from(token: SpacingToken) -> Self {
+        SpacingValue::Token(token)
+    }
+}
+
+impl From<SpacingToken> for SizeValue {
+    fn from(token: SpacingToken) -> Self {
+        SizeValue::Token(token)
+    }
+}
+
 /// Center component - Flexbox/Grid centered content
 #[derive(Debug, Clone)]
 pub struct Center {
@@ -1045,7 +1120,9 @@ impl Spacer {
 
 /// Generate CSS for box components
 pub fn box_css() -> String {
-    r#"
+    let mut css = SpacingScale::default().token_css(":root");
+    css.push_str(
+        r#"
 /* Box Component Styles */
 .sh-box {
     box-sizing: border-box;
@@ -1060,8 +1137,9 @@ pub fn box_css() -> String {
 .sh-center {
     display: flex;
 }
-"#
-    .to_string()
+"#,
+    );
+    css
 }
 
 #[cfg(test)]
@@ -1101,6 +1179,24 @@ mod tests {
         assert_eq!(rem.css_value(), "1rem");
     }
 
+    #[test]
+    fn test_spacing_tokens() {
+        let token: SpacingValue = SpacingToken::S4.into();
+        assert_eq!(token.css_value(), "var(--sh-space-4)");
+
+        let html = Box::new()
+            .padding(SpacingToken::S4)
+            .width(SpacingToken::S12)
+            .render(html! { "Content" })
+            .into_string();
+        assert!(html.contains("padding:var(--sh-space-4);"));
+        assert!(html.contains("width:var(--sh-space-12);"));
+
+        let css = box_css();
+        assert!(css.contains("--sh-space-unit: 0.25rem;"));
+        assert!(css.contains("--sh-space-12: calc(var(--sh-space-unit) * 12);"));
+    }
+
     #[test]
     fn test_display_variants() {
         assert_eq!(Display::Flex.css_value(), "flex");
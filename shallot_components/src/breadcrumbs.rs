@@ -1,8 +1,26 @@
+use crate::route::RouteContext;
 use maud::{html, Markup};
+use std::borrow::Cow;
 
 pub struct BreadcrumbItem<'a> {
-    pub label: &'a str,
-    pub href: Option<&'a str>,
+    pub label: Cow<'a, str>,
+    pub href: Option<Cow<'a, str>>,
+}
+
+impl<'a> BreadcrumbItem<'a> {
+    /// Create a breadcrumb item; without an href it renders as the current page
+    pub fn new(label: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+        }
+    }
+
+    /// Link the item to a page
+    pub fn href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
 }
 
 pub struct Breadcrumbs<'a> {
@@ -14,13 +32,31 @@ impl<'a> Breadcrumbs<'a> {
         Self { items }
     }
 
+    /// Build the trail from the route's breadcrumbs; the step pointing at
+    /// the current page renders as unlinked text with `aria-current`
+    pub fn from_route(route: &RouteContext<'a>) -> Self {
+        let items = route
+            .breadcrumbs
+            .iter()
+            .map(|(label, href)| {
+                let item = BreadcrumbItem::new(label.clone());
+                if route.is_current(href) {
+                    item
+                } else {
+                    item.href(href.clone())
+                }
+            })
+            .collect();
+        Self { items }
+    }
+
     pub fn render(self) -> Markup {
         html! {
             nav aria-label="Breadcrumb" {
                 ol class="sh-bc" {
                     @for (idx, it) in self.items.iter().enumerate() {
                         li class="sh-bc__item" {
-                            @if let Some(href) = it.href {
+                            @if let Some(href) = &it.href {
                                 a href=(href) { (it.label) }
                             } @else {
                                 span aria-current="page" { (it.label) }
@@ -87,14 +123,8 @@ mod tests {
     #[test]
     fn test_breadcrumbs_creation() {
         let items = vec![
-            BreadcrumbItem {
-                label: "Home",
-                href: Some("/"),
-            },
-            BreadcrumbItem {
-                label: "Current",
-                href: None,
-            },
+            BreadcrumbItem::new("Home").href("/"),
+            BreadcrumbItem::new("Current"),
         ];
         let bc = Breadcrumbs::new(items);
         assert_eq!(bc.items.len(), 2);
@@ -103,14 +133,8 @@ mod tests {
     #[test]
     fn test_breadcrumbs_render() {
         let items = vec![
-            BreadcrumbItem {
-                label: "Home",
-                href: Some("/"),
-            },
-            BreadcrumbItem {
-                label: "Page",
-                href: None,
-            },
+            BreadcrumbItem::new("Home").href("/"),
+            BreadcrumbItem::new("Page"),
         ];
         let bc = Breadcrumbs::new(items);
         let html = bc.render().into_string();
@@ -119,6 +143,18 @@ mod tests {
         assert!(html.contains("aria-current=\"page\""));
     }
 
+    #[test]
+    fn test_breadcrumbs_from_route() {
+        let route = RouteContext::new("/docs/components")
+            .breadcrumb("Home", "/")
+            .breadcrumb("Docs", "/docs")
+            .breadcrumb("Components", "/docs/components/");
+        let html = Breadcrumbs::from_route(&route).render().into_string();
+        assert!(html.contains(r#"<a href="/docs">Docs</a>"#));
+        assert!(html.contains(r#"<span aria-current="page">Components</span>"#));
+        assert_eq!(html.matches("aria-current").count(), 1);
+    }
+
     #[test]
     fn test_breadcrumbs_css() {
         let css = breadcrumbs_css();
@@ -1,4 +1,9 @@
+use crate::htmx::HxAttrs;
+#[cfg(feature = "htmx")]
+use crate::htmx::HxSwap;
 use maud::{html, Markup};
+use shallot_foundation::ClassList;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonVariant {
@@ -16,22 +21,26 @@ pub enum ButtonSize {
 }
 
 pub struct Button<'a> {
-    pub label: &'a str,
+    pub label: Cow<'a, str>,
     pub variant: ButtonVariant,
     pub size: ButtonSize,
     pub disabled: bool,
-    pub href: Option<&'a str>,
+    pub href: Option<Cow<'a, str>>,
+    hx: HxAttrs<'a>,
+    turbo_frame: Option<Cow<'a, str>>,
 }
 
 impl<'a> Button<'a> {
     /// Create a new button with the given label
-    pub fn new(label: &'a str) -> Self {
+    pub fn new(label: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            label,
+            label: label.into(),
             variant: ButtonVariant::Primary,
             size: ButtonSize::Md,
             disabled: false,
             href: None,
+            hx: HxAttrs::default(),
+            turbo_frame: None,
         }
     }
 
@@ -54,36 +63,79 @@ impl<'a> Button<'a> {
     }
 
     /// Set the button href (renders as link if set)
-    pub fn href(mut self, href: &'a str) -> Self {
-        self.href = Some(href);
+    pub fn href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Fetch `url` with HTMX on click
+    #[cfg(feature = "htmx")]
+    pub fn hx_get(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+        self.hx.get = Some(url.into());
+        self
+    }
+
+    /// Post to `url` with HTMX on click
+    #[cfg(feature = "htmx")]
+    pub fn hx_post(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+        self.hx.post = Some(url.into());
+        self
+    }
+
+    /// Element the HTMX response is swapped into
+    #[cfg(feature = "htmx")]
+    pub fn hx_target(mut self, selector: impl Into<Cow<'a, str>>) -> Self {
+        self.hx.target = Some(selector.into());
+        self
+    }
+
+    /// How the HTMX response is swapped in
+    #[cfg(feature = "htmx")]
+    pub fn hx_swap(mut self, swap: HxSwap) -> Self {
+        self.hx.swap = Some(swap);
+        self
+    }
+
+    /// Load the link's page into the Turbo frame with id `frame`
+    #[cfg(feature = "turbo")]
+    pub fn turbo_frame(mut self, frame: impl Into<Cow<'a, str>>) -> Self {
+        self.turbo_frame = Some(frame.into());
         self
     }
 
     pub fn render(self) -> Markup {
-        let variant_class = match self.variant {
-            ButtonVariant::Primary => "sh-btn sh-btn--primary",
-            ButtonVariant::Secondary => "sh-btn sh-btn--secondary",
-            ButtonVariant::Ghost => "sh-btn sh-btn--ghost",
-            ButtonVariant::Danger => "sh-btn sh-btn--danger",
+        let variant = match self.variant {
+            ButtonVariant::Primary => "primary",
+            ButtonVariant::Secondary => "secondary",
+            ButtonVariant::Ghost => "ghost",
+            ButtonVariant::Danger => "danger",
         };
 
-        let size_class = match self.size {
-            ButtonSize::Sm => "sh-btn--sm",
-            ButtonSize::Md => "sh-btn--md",
-            ButtonSize::Lg => "sh-btn--lg",
+        let size = match self.size {
+            ButtonSize::Sm => "sm",
+            ButtonSize::Md => "md",
+            ButtonSize::Lg => "lg",
         };
 
-        let class = format!("{} {}", variant_class, size_class);
+        let class = ClassList::new("sh-btn")
+            .modifier("sh-btn", variant)
+            .modifier("sh-btn", size)
+            .build();
 
         if let Some(href) = self.href {
             let disabled = self.disabled;
             html! {
                 a
                     class=(class)
-                    href=(if disabled { "#" } else { href })
+                    href=(if disabled { "#" } else { href.as_ref() })
                     role="button"
                     aria-disabled?[disabled]
                     tabindex=(if disabled { "-1" } else { "0" })
+                    hx-get=[self.hx.get().filter(|_| !disabled)]
+                    hx-post=[self.hx.post().filter(|_| !disabled)]
+                    hx-target=[self.hx.target()]
+                    hx-swap=[self.hx.swap()]
+                    data-turbo-frame=[self.turbo_frame.as_deref()]
                 {
                     (self.label)
                 }
@@ -94,6 +146,10 @@ impl<'a> Button<'a> {
                     type="button"
                     class=(class)
                     disabled?[self.disabled]
+                    hx-get=[self.hx.get()]
+                    hx-post=[self.hx.post()]
+                    hx-target=[self.hx.target()]
+                    hx-swap=[self.hx.swap()]
                 {
                     (self.label)
                 }
@@ -174,6 +230,28 @@ pub fn button_css() -> String {
     opacity: 0.5;
     cursor: not-allowed;
 }
+
+/* Forced colors: variants are told apart by background, which the
+   high-contrast palette replaces, so draw every button's edge */
+@media (forced-colors: active) {
+    .sh-btn {
+        border: 1px solid ButtonText;
+    }
+
+    .sh-btn:hover {
+        border-color: Highlight;
+    }
+
+    .sh-btn:focus-visible {
+        outline: 2px solid Highlight;
+        outline-offset: 2px;
+    }
+
+    .sh-btn:disabled {
+        border-color: GrayText;
+        color: GrayText;
+    }
+}
 "#
     .to_string()
 }
@@ -202,6 +280,51 @@ mod tests {
         assert_eq!(button.size, ButtonSize::Lg);
     }
 
+    #[test]
+    fn test_button_classes() {
+        let html = Button::new("Test")
+            .variant(ButtonVariant::Danger)
+            .size(ButtonSize::Lg)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"class="sh-btn sh-btn--danger sh-btn--lg""#));
+        assert!(!html.contains("hx-"));
+    }
+
+    #[cfg(feature = "turbo")]
+    #[test]
+    fn test_button_turbo_frame() {
+        let html = Button::new("Edit")
+            .href("/posts/1/edit")
+            .turbo_frame("post-1")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"data-turbo-frame="post-1""#));
+    }
+
+    #[cfg(feature = "htmx")]
+    #[test]
+    fn test_button_htmx() {
+        let html = Button::new("Load more")
+            .href("/posts?page=2")
+            .hx_get("/posts/partial?page=2")
+            .hx_target("#posts")
+            .hx_swap(HxSwap::BeforeEnd)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"href="/posts?page=2""#));
+        assert!(html.contains(
+            r##"hx-get="/posts/partial?page=2" hx-target="#posts" hx-swap="beforeend""##
+        ));
+
+        let html = Button::new("Delete")
+            .hx_post("/items/1/delete")
+            .disabled(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"hx-post="/items/1/delete""#));
+    }
+
     #[test]
     fn test_button_disabled() {
         let button = Button::new("Test").disabled(true);
@@ -214,4 +337,20 @@ mod tests {
         assert!(css.contains(".sh-btn"));
         assert!(css.contains(".sh-btn--primary"));
     }
+
+    #[test]
+    fn test_button_css_forced_colors() {
+        use shallot_testing::accessibility::{forced_colors_issues, ForcedColorsReason};
+
+        let css = button_css();
+        assert_eq!(forced_colors_issues(&css), vec![]);
+
+        // Without the override, filled variants lose their edge
+        let unpatched = &css[..css.find("@media (forced-colors: active)").unwrap()];
+        let issues = forced_colors_issues(unpatched);
+        assert!(issues
+            .iter()
+            .any(|issue| issue.selector == ".sh-btn--primary"
+                && issue.reason == ForcedColorsReason::BackgroundOnly));
+    }
 }
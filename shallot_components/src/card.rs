@@ -1,7 +1,10 @@
 use crate::component::{
     AriaAttrs, Component, ComponentSize,
 };
+use crate::skeleton::{Skeleton, SkeletonText};
+use crate::view_transitions::view_transition_style;
 use maud::{html, Markup, Render};
+use shallot_foundation::{ClassList, Elevation};
 
 /// Enhanced Card component with comprehensive styling options
 pub struct Card {
@@ -35,6 +38,8 @@ pub struct Card {
     cover_image: Option<String>,
     /// Card cover image alt text
     cover_alt: Option<String>,
+    /// Shared element name for cross-page view transitions
+    view_transition_name: Option<String>,
 }
 
 /// Card visual variants
@@ -58,29 +63,6 @@ impl CardVariant {
     }
 }
 
-/// Card elevation levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum Elevation {
-    None,
-    Low,
-    #[default]
-    Default,
-    High,
-    Higher,
-}
-
-impl Elevation {
-    fn class_suffix(&self) -> &'static str {
-        match self {
-            Elevation::None => "none",
-            Elevation::Low => "low",
-            Elevation::Default => "default",
-            Elevation::High => "high",
-            Elevation::Higher => "higher",
-        }
-    }
-}
-
 impl Card {
     /// Create a new card with the given body content
     pub fn new(body: Markup) -> Self {
@@ -100,6 +82,7 @@ impl Card {
             divided: false,
             cover_image: None,
             cover_alt: None,
+            view_transition_name: None,
         }
     }
 
@@ -183,30 +166,26 @@ impl Card {
         self
     }
 
+    /// Morph this card into the element with the same name on the next page
+    /// (see [`crate::view_transitions`])
+    pub fn view_transition_name(mut self, name: impl Into<String>) -> Self {
+        self.view_transition_name = Some(name.into());
+        self
+    }
+
     /// Build the CSS class string
     fn build_classes(&self) -> String {
-        let mut classes = vec!["sh-card".to_string()];
-
-        classes.push(format!("sh-card--{}", self.variant.class_suffix()));
-        classes.push(format!("sh-card--{}", self.size.class_suffix()));
-        classes.push(format!(
-            "sh-card--elevation-{}",
-            self.elevation.class_suffix()
-        ));
-
-        if self.clickable {
-            classes.push("sh-card--clickable".to_string());
-        }
-
-        if self.divided {
-            classes.push("sh-card--divided".to_string());
-        }
-
-        if let Some(custom) = &self.custom_class {
-            classes.push(custom.clone());
-        }
-
-        classes.join(" ")
+        ClassList::new("sh-card")
+            .modifier("sh-card", self.variant.class_suffix())
+            .modifier("sh-card", self.size.class_suffix())
+            .modifier(
+                "sh-card",
+                format_args!("elevation-{}", self.elevation.name()),
+            )
+            .push_if("sh-card--clickable", self.clickable)
+            .push_if("sh-card--divided", self.divided)
+            .push_opt(self.custom_class.as_deref())
+            .build()
     }
 
     /// Render the header section
@@ -246,6 +225,44 @@ impl Card {
             }
         })
     }
+
+    /// Render a loading placeholder with this card's classes and sections,
+    /// so swapping in the real card causes no layout shift
+    pub fn skeleton(&self) -> Markup {
+        let class = ClassList::new(&self.build_classes())
+            .push("sh-card--skeleton")
+            .build();
+        let has_header = self.header.is_some() || self.title.is_some() || self.subtitle.is_some();
+        let has_top = has_header || self.cover_image.is_some();
+
+        html! {
+            div class=(class) role="article" aria-busy="true" aria-label="Loading" {
+                @if self.cover_image.is_some() {
+                    div class="sh-card__cover" { (Skeleton::fill().render()) }
+                }
+                @if has_header {
+                    div class="sh-card__header" {
+                        h3 class="sh-card__title" { (Skeleton::inline("60%").render()) }
+                        @if self.subtitle.is_some() {
+                            p class="sh-card__subtitle" { (Skeleton::inline("40%").render()) }
+                        }
+                    }
+                }
+                @if self.divided && has_top {
+                    div class="sh-card__divider" {}
+                }
+                div class="sh-card__body" {
+                    (SkeletonText::new(3).render())
+                }
+                @if self.divided && self.footer.is_some() {
+                    div class="sh-card__divider" {}
+                }
+                @if self.footer.is_some() {
+                    div class="sh-card__footer" { (Skeleton::inline("30%").render()) }
+                }
+            }
+        }
+    }
 }
 
 impl Render for Card {
@@ -253,6 +270,7 @@ impl Render for Card {
         let class = self.build_classes();
         let header = self.render_header();
         let cover = self.render_cover();
+        let style = self.view_transition_name.as_deref().map(view_transition_style);
 
         let content = html! {
             @if let Some(ref cover) = cover {
@@ -279,19 +297,19 @@ impl Render for Card {
 
         if let Some(href) = &self.href {
             html! {
-                a class=(class) href=(href) role="article" {
+                a class=(class) href=(href) role="article" style=[style] {
                     (content)
                 }
             }
         } else if self.clickable {
             html! {
-                div class=(class) role="button" tabindex="0" {
+                div class=(class) role="button" tabindex="0" style=[style] {
                     (content)
                 }
             }
         } else {
             html! {
-                div class=(class) role="article" {
+                div class=(class) role="article" style=[style] {
                     (content)
                 }
             }
@@ -373,7 +391,7 @@ pub fn card_css() -> String {
 /* Variant styles */
 .sh-card--default {
   border: 1px solid var(--sh-border);
-  box-shadow: var(--sh-shadow-sm);
+  box-shadow: var(--sh-elevation-1);
 }
 
 .sh-card--outlined {
@@ -389,19 +407,16 @@ pub fn card_css() -> String {
 .sh-card--elevated {
   border: none;
   background: var(--sh-surface);
-  box-shadow: var(--sh-shadow-md);
+  box-shadow: var(--sh-elevation-2);
 }
 
 /* Elevation levels */
-.sh-card--elevation-none { box-shadow: none; }
-.sh-card--elevation-low { box-shadow: var(--sh-shadow-sm); }
-.sh-card--elevation-default { box-shadow: var(--sh-shadow-md); }
-.sh-card--elevation-high { box-shadow: var(--sh-shadow-xl); }
-.sh-card--elevation-higher {
-  box-shadow: 
-    0 20px 25px -5px rgba(0, 0, 0, 0.1),
-    0 10px 10px -5px rgba(0, 0, 0, 0.04);
-}
+.sh-card--elevation-none { box-shadow: var(--sh-elevation-0); }
+.sh-card--elevation-low { box-shadow: var(--sh-elevation-1); }
+.sh-card--elevation-default { box-shadow: var(--sh-elevation-2); }
+.sh-card--elevation-high { box-shadow: var(--sh-elevation-3); }
+.sh-card--elevation-higher { box-shadow: var(--sh-elevation-4); }
+.sh-card--elevation-highest { box-shadow: var(--sh-elevation-5); }
 
 /* Clickable card */
 .sh-card--clickable {
@@ -412,7 +427,7 @@ pub fn card_css() -> String {
 
 .sh-card--clickable:hover {
   transform: translateY(-2px);
-  box-shadow: var(--sh-shadow-lg);
+  box-shadow: var(--sh-elevation-4);
 }
 
 .sh-card--clickable:active {
@@ -532,6 +547,23 @@ pub fn card_css() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_card_skeleton() {
+        let card = Card::new(html! { "Body" })
+            .title("Title")
+            .cover_image("/cover.jpg", "Cover")
+            .footer(html! { "Footer" })
+            .variant(CardVariant::Outlined);
+        let html = card.skeleton().into_string();
+        assert!(html.contains("sh-card--outlined"));
+        assert!(html.contains("sh-card--skeleton"));
+        assert!(html.contains("sh-card__cover"));
+        assert!(html.contains("sh-card__title"));
+        assert!(html.contains("sh-card__footer"));
+        assert!(!html.contains("sh-card__subtitle"));
+        assert!(!html.contains("Body"));
+    }
+
     #[test]
     fn test_card_creation() {
         let body = html! { p { "Test content" } };
@@ -572,5 +604,21 @@ mod tests {
         let css = card_css();
         assert!(css.contains(".sh-card"));
         assert!(css.contains(".sh-card--clickable:hover"));
+        assert!(
+            css.contains(".sh-card--elevation-high { box-shadow: var(--sh-elevation-3); }")
+        );
+    }
+
+    #[test]
+    fn test_card_view_transition_name() {
+        let html = Card::new(html! { "Body" })
+            .href("/products/42")
+            .view_transition_name("product-42")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"style="view-transition-name: product-42;""#));
+
+        let plain = Card::new(html! { "Body" }).render().into_string();
+        assert!(!plain.contains("view-transition-name"));
     }
 }
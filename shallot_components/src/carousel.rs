@@ -1,6 +1,7 @@
 //! Carousel Component - Image/content slider with navigation
 
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 
 /// Carousel animation type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -21,10 +22,47 @@ pub enum CarouselIndicatorStyle {
     None,
 }
 
+/// Indicator `:target` rules and autoplay keyframes are generated for
+/// carousels of up to this many slides
+pub const MAX_CSS_SLIDES: usize = 12;
+
+/// A single carousel slide with an optional caption
+#[derive(Debug, Clone)]
+pub struct CarouselSlide<'a> {
+    pub content: Markup,
+    pub caption: Option<Cow<'a, str>>,
+}
+
+impl<'a> CarouselSlide<'a> {
+    pub fn new(content: Markup) -> Self {
+        Self {
+            content,
+            caption: None,
+        }
+    }
+
+    pub fn caption(mut self, caption: impl Into<Cow<'a, str>>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+}
+
+impl<'a> From<Markup> for CarouselSlide<'a> {
+    fn from(content: Markup) -> Self {
+        Self::new(content)
+    }
+}
+
 /// Carousel Component
+///
+/// Slides sit in a horizontal scroll-snap track. Every slide has an id
+/// (`{id}-slide-{n}`), so the previous/next arrows and the indicators are
+/// plain anchors and the `:target` slide drives the active indicator. The
+/// server-side `active_index` is highlighted until a fragment is targeted.
 #[derive(Debug, Clone)]
 pub struct Carousel<'a> {
-    pub items: Vec<Markup>,
+    pub items: Vec<CarouselSlide<'a>>,
+    pub id: Cow<'a, str>,
     pub animation: CarouselAnimation,
     pub indicator_style: CarouselIndicatorStyle,
     pub autoplay: bool,
@@ -38,6 +76,7 @@ impl<'a> Default for Carousel<'a> {
     fn default() -> Self {
         Self {
             items: Vec::new(),
+            id: Cow::Borrowed("sh-carousel"),
             animation: CarouselAnimation::default(),
             indicator_style: CarouselIndicatorStyle::default(),
             autoplay: false,
@@ -51,12 +90,24 @@ impl<'a> Default for Carousel<'a> {
 
 impl<'a> Carousel<'a> {
     pub fn new(items: Vec<Markup>) -> Self {
+        Self::from_slides(items.into_iter().map(CarouselSlide::from).collect())
+    }
+
+    /// Create a carousel from slides carrying captions
+    pub fn from_slides(items: Vec<CarouselSlide<'a>>) -> Self {
         Self {
             items,
             ..Self::default()
         }
     }
 
+    /// Set the element id; slide ids are derived from it, so it must be
+    /// unique when several carousels share a page
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
     pub fn animation(mut self, animation: CarouselAnimation) -> Self {
         self.animation = animation;
         self
@@ -67,6 +118,9 @@ impl<'a> Carousel<'a> {
         self
     }
 
+    /// Advance slides with a CSS animation; paused on hover and focus, and
+    /// disabled for `prefers-reduced-motion` or more than
+    /// [`MAX_CSS_SLIDES`] slides
     pub fn autoplay(mut self, autoplay: bool) -> Self {
         self.autoplay = autoplay;
         self
@@ -92,24 +146,36 @@ impl<'a> Carousel<'a> {
         self
     }
 
+    /// Id of the slide at `index`
+    pub fn slide_id(&self, index: usize) -> String {
+        format!("{}-slide-{}", self.id, index + 1)
+    }
+
+    fn autoplays(&self) -> bool {
+        self.autoplay
+            && self.animation != CarouselAnimation::None
+            && (2..=MAX_CSS_SLIDES).contains(&self.items.len())
+    }
+
     fn build_classes(&self) -> String {
-        let mut classes = vec!["sh-carousel"];
+        let mut classes = vec!["sh-carousel".to_string()];
 
         match self.animation {
-            CarouselAnimation::None => classes.push("sh-carousel--no-animation"),
-            CarouselAnimation::Slide => classes.push("sh-carousel--slide"),
-            CarouselAnimation::Fade => classes.push("sh-carousel--fade"),
+            CarouselAnimation::None => classes.push("sh-carousel--no-animation".to_string()),
+            CarouselAnimation::Slide => classes.push("sh-carousel--slide".to_string()),
+            CarouselAnimation::Fade => classes.push("sh-carousel--fade".to_string()),
         }
 
         match self.indicator_style {
-            CarouselIndicatorStyle::Dots => classes.push("sh-carousel--dots"),
-            CarouselIndicatorStyle::Lines => classes.push("sh-carousel--lines"),
-            CarouselIndicatorStyle::Numbers => classes.push("sh-carousel--numbers"),
-            CarouselIndicatorStyle::None => classes.push("sh-carousel--no-indicators"),
+            CarouselIndicatorStyle::Dots => classes.push("sh-carousel--dots".to_string()),
+            CarouselIndicatorStyle::Lines => classes.push("sh-carousel--lines".to_string()),
+            CarouselIndicatorStyle::Numbers => classes.push("sh-carousel--numbers".to_string()),
+            CarouselIndicatorStyle::None => classes.push("sh-carousel--no-indicators".to_string()),
         }
 
-        if self.autoplay {
-            classes.push("sh-carousel--autoplay");
+        if self.autoplays() {
+            classes.push("sh-carousel--autoplay".to_string());
+            classes.push(format!("sh-carousel--autoplay-{}", self.items.len()));
         }
 
         classes.join(" ")
@@ -119,21 +185,15 @@ impl<'a> Carousel<'a> {
 impl<'a> Render for Carousel<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
-        let style = format!("--sh-carousel-interval: {}ms;", self.autoplay_interval_ms);
         let item_count = self.items.len();
-
-        // Pre-compute aria-hidden values
-        let aria_hidden: Vec<&'static str> = self.items.iter().enumerate().map(|(idx, _)| {
-            if idx == self.active_index { "false" } else { "true" }
-        }).collect();
-
-        // Pre-compute aria-selected values for indicators
-        let aria_selected_indicators: Vec<&'static str> = (0..item_count).map(|idx| {
-            if idx == self.active_index { "true" } else { "false" }
-        }).collect();
+        let style = format!(
+            "--sh-carousel-interval: {}ms; --sh-carousel-count: {};",
+            self.autoplay_interval_ms, item_count
+        );
 
         html! {
             div
+                id=(self.id)
                 class=(classes)
                 style=(style)
                 role="region"
@@ -142,46 +202,50 @@ impl<'a> Render for Carousel<'a> {
             {
                 div class="sh-carousel__viewport" {
                     div class="sh-carousel__track" {
-                        @for (idx, item) in self.items.iter().enumerate() {
+                        @for (idx, slide) in self.items.iter().enumerate() {
                             div
-                                class="sh-carousel__item"
+                                id=(self.slide_id(idx))
+                                class=(if idx == self.active_index { "sh-carousel__item sh-carousel__item--active" } else { "sh-carousel__item" })
+                                style=(format!("--sh-carousel-index: {};", idx))
                                 role="group"
                                 aria-roledescription="slide"
-                                aria-label={(format!("Slide {} of {}", idx + 1, item_count))}
-                                aria-hidden=(aria_hidden[idx])
+                                aria-label=(format!("Slide {} of {}", idx + 1, item_count))
                             {
-                                (item)
+                                div class="sh-carousel__content" { (slide.content) }
+
+                                @if let Some(caption) = &slide.caption {
+                                    p class="sh-carousel__caption" { (caption) }
+                                }
+
+                                @if self.show_arrows && item_count > 1 {
+                                    a
+                                        class="sh-carousel__arrow sh-carousel__arrow--prev"
+                                        href=(format!("#{}", self.slide_id((idx + item_count - 1) % item_count)))
+                                        aria-label="Previous slide"
+                                    {
+                                        span class="sh-carousel__arrow-icon" aria-hidden="true" { "‹" }
+                                    }
+                                    a
+                                        class="sh-carousel__arrow sh-carousel__arrow--next"
+                                        href=(format!("#{}", self.slide_id((idx + 1) % item_count)))
+                                        aria-label="Next slide"
+                                    {
+                                        span class="sh-carousel__arrow-icon" aria-hidden="true" { "›" }
+                                    }
+                                }
                             }
                         }
                     }
                 }
 
-                @if self.show_arrows && item_count > 1 {
-                    button
-                        class="sh-carousel__arrow sh-carousel__arrow--prev"
-                        type="button"
-                        aria-label="Previous slide"
-                    {
-                        span class="sh-carousel__arrow-icon" { "‹" }
-                    }
-                    button
-                        class="sh-carousel__arrow sh-carousel__arrow--next"
-                        type="button"
-                        aria-label="Next slide"
-                    {
-                        span class="sh-carousel__arrow-icon" { "›" }
-                    }
-                }
-
                 @if self.indicator_style != CarouselIndicatorStyle::None && item_count > 1 {
-                    div class="sh-carousel__indicators" role="tablist" {
+                    nav class="sh-carousel__indicators" aria-label="Choose slide" {
                         @for idx in 0..item_count {
-                            button
+                            a
                                 class="sh-carousel__indicator"
-                                type="button"
-                                role="tab"
-                                aria-selected=(aria_selected_indicators[idx])
-                                aria-label={(format!("Go to slide {}", idx + 1))}
+                                href=(format!("#{}", self.slide_id(idx)))
+                                aria-label=(format!("Go to slide {}", idx + 1))
+                                aria-current=[(idx == self.active_index).then_some("true")]
                             {
                                 @if self.indicator_style == CarouselIndicatorStyle::Numbers {
                                     (idx + 1)
@@ -195,9 +259,56 @@ impl<'a> Render for Carousel<'a> {
     }
 }
 
+/// `:target` rules linking each slide to its indicator
+fn target_indicator_css() -> String {
+    let selectors: Vec<String> = (1..=MAX_CSS_SLIDES)
+        .map(|n| {
+            format!(
+                ".sh-carousel:has(.sh-carousel__item:nth-child({n}):target) .sh-carousel__indicator:nth-child({n})"
+            )
+        })
+        .collect();
+    format!(
+        "{} {{\n    background: white;\n    transform: scale(1.2);\n}}\n",
+        selectors.join(",\n")
+    )
+}
+
+/// Keyframes stepping through `count` slides: each slide holds for most of
+/// its slot, then slides (or fades) on to the next
+fn autoplay_keyframes_css(count: usize) -> String {
+    let slot = 100.0 / count as f64;
+    let fade_in = slot * 0.15;
+
+    let mut css = format!("@keyframes sh-carousel-autoplay-{count} {{\n");
+    for i in 0..count {
+        let start = slot * i as f64;
+        css.push_str(&format!(
+            "    {:.2}%, {:.2}% {{ transform: translateX(-{}%); }}\n",
+            start,
+            start + slot * 0.85,
+            i * 100
+        ));
+    }
+    css.push_str("    100% { transform: translateX(0); }\n}\n");
+
+    css.push_str(&format!(
+        "@keyframes sh-carousel-fade-{count} {{\n    0% {{ opacity: 0; }}\n    {:.2}%, {:.2}% {{ opacity: 1; }}\n    {:.2}%, 100% {{ opacity: 0; }}\n}}\n",
+        fade_in,
+        slot,
+        slot + fade_in
+    ));
+
+    css.push_str(&format!(
+        ".sh-carousel--autoplay-{count} .sh-carousel__track {{ animation-name: sh-carousel-autoplay-{count}; }}\n\
+         .sh-carousel--fade.sh-carousel--autoplay-{count} .sh-carousel__item {{ animation-name: sh-carousel-fade-{count}; }}\n"
+    ));
+    css
+}
+
 /// Generate CSS for carousel components
 pub fn carousel_css() -> String {
-    r#"
+    let mut css = r#"
 /* Carousel Component Styles */
 .sh-carousel {
     position: relative;
@@ -210,38 +321,76 @@ pub fn carousel_css() -> String {
     width: 100%;
 }
 
+/* Scroll-snap track: anchors to slide ids scroll the track, not the page layout */
 .sh-carousel__track {
     display: flex;
-    transition: transform 0.5s ease;
+    overflow-x: auto;
+    scroll-snap-type: x mandatory;
+    scroll-behavior: smooth;
+    overscroll-behavior-x: contain;
+    scrollbar-width: none;
 }
 
-.sh-carousel--no-animation .sh-carousel__track {
-    transition: none;
+.sh-carousel__track::-webkit-scrollbar {
+    display: none;
 }
 
-.sh-carousel--fade .sh-carousel__track {
-    display: block;
+.sh-carousel--no-animation .sh-carousel__track {
+    scroll-behavior: auto;
 }
 
 .sh-carousel__item {
+    position: relative;
     flex: 0 0 100%;
     min-width: 100%;
+    scroll-snap-align: start;
+    scroll-snap-stop: always;
 }
 
-.sh-carousel--fade .sh-carousel__item {
+.sh-carousel__content {
+    width: 100%;
+}
+
+.sh-carousel__caption {
     position: absolute;
-    top: 0;
     left: 0;
-    width: 100%;
+    right: 0;
+    bottom: 2.5rem;
+    margin: 0 auto;
+    max-width: 80%;
+    padding: 0.5rem 1rem;
+    text-align: center;
+    color: white;
+    background: rgba(0, 0, 0, 0.55);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font-size: 0.875rem;
+}
+
+/* Fade: slides share one grid cell; the targeted (or active) slide is shown */
+.sh-carousel--fade .sh-carousel__track {
+    display: grid;
+    overflow: hidden;
+}
+
+.sh-carousel--fade .sh-carousel__item {
+    grid-area: 1 / 1;
     opacity: 0;
-    transition: opacity 0.5s ease;
+    visibility: hidden;
+    transition: opacity 0.5s ease, visibility 0.5s;
 }
 
-.sh-carousel--fade .sh-carousel__item:first-child {
-    position: relative;
+.sh-carousel--fade .sh-carousel__item--active,
+.sh-carousel--fade .sh-carousel__item:target {
+    opacity: 1;
+    visibility: visible;
+}
+
+.sh-carousel--fade:has(.sh-carousel__item:target) .sh-carousel__item--active:not(:target) {
+    opacity: 0;
+    visibility: hidden;
 }
 
-/* Arrows */
+/* Arrows (rendered inside each slide, linking to its neighbours) */
 .sh-carousel__arrow {
     position: absolute;
     top: 50%;
@@ -253,14 +402,14 @@ pub fn carousel_css() -> String {
     justify-content: center;
     background: rgba(0, 0, 0, 0.5);
     color: white;
-    border: none;
     border-radius: 50%;
-    cursor: pointer;
+    text-decoration: none;
     z-index: 10;
     transition: background 0.2s;
 }
 
-.sh-carousel__arrow:hover {
+.sh-carousel__arrow:hover,
+.sh-carousel__arrow:focus-visible {
     background: rgba(0, 0, 0, 0.7);
 }
 
@@ -287,23 +436,29 @@ pub fn carousel_css() -> String {
     bottom: 0;
     left: 0;
     right: 0;
+    z-index: 10;
 }
 
 .sh-carousel__indicator {
+    display: block;
     width: 10px;
     height: 10px;
     border-radius: 50%;
     background: rgba(255, 255, 255, 0.5);
-    border: none;
-    cursor: pointer;
     transition: background 0.2s, transform 0.2s;
 }
 
-.sh-carousel__indicator[aria-selected="true"] {
+.sh-carousel__indicator[aria-current="true"] {
     background: white;
     transform: scale(1.2);
 }
 
+/* Once a slide is targeted, it owns the active indicator */
+.sh-carousel:has(.sh-carousel__item:target) .sh-carousel__indicator[aria-current="true"] {
+    background: rgba(255, 255, 255, 0.5);
+    transform: none;
+}
+
 /* Lines style */
 .sh-carousel--lines .sh-carousel__indicator {
     width: 24px;
@@ -319,29 +474,76 @@ pub fn carousel_css() -> String {
     font-size: 0.75rem;
     background: rgba(0, 0, 0, 0.5);
     color: white;
+    text-decoration: none;
     border-radius: 0.25rem;
 }
 
-/* Autoplay animation */
+/* Autoplay: the track steps through the slides; hover, focus or a
+   targeted slide hands control back to the visitor */
 .sh-carousel--autoplay .sh-carousel__track {
-    animation: carousel-slide var(--sh-carousel-interval, 5s) infinite;
+    overflow: visible;
+    animation-duration: calc(var(--sh-carousel-interval, 5000ms) * var(--sh-carousel-count, 1));
+    animation-timing-function: ease-in-out;
+    animation-iteration-count: infinite;
+}
+
+.sh-carousel--fade.sh-carousel--autoplay .sh-carousel__track {
+    animation: none;
+}
+
+.sh-carousel--fade.sh-carousel--autoplay .sh-carousel__item {
+    visibility: visible;
+    animation-duration: calc(var(--sh-carousel-interval, 5000ms) * var(--sh-carousel-count, 1));
+    animation-delay: calc(var(--sh-carousel-interval, 5000ms) * var(--sh-carousel-index, 0));
+    animation-iteration-count: infinite;
+    animation-fill-mode: backwards;
+}
+
+.sh-carousel--autoplay:hover .sh-carousel__track,
+.sh-carousel--autoplay:focus-within .sh-carousel__track,
+.sh-carousel--autoplay:hover .sh-carousel__item,
+.sh-carousel--autoplay:focus-within .sh-carousel__item {
+    animation-play-state: paused;
+}
+
+.sh-carousel--autoplay:has(.sh-carousel__item:target) .sh-carousel__track,
+.sh-carousel--autoplay:has(.sh-carousel__item:target) .sh-carousel__item {
+    animation: none;
+}
+
+.sh-carousel--autoplay:has(.sh-carousel__item:target) .sh-carousel__track {
+    overflow-x: auto;
 }
 
 /* Reduced motion */
 @media (prefers-reduced-motion: reduce) {
-    .sh-carousel__track,
+    .sh-carousel__track {
+        scroll-behavior: auto;
+    }
+
     .sh-carousel--fade .sh-carousel__item,
     .sh-carousel__arrow,
     .sh-carousel__indicator {
         transition: none;
     }
 
-    .sh-carousel--autoplay .sh-carousel__track {
+    .sh-carousel--autoplay .sh-carousel__track,
+    .sh-carousel--autoplay .sh-carousel__item {
         animation: none;
     }
+
+    .sh-carousel--autoplay .sh-carousel__track {
+        overflow-x: auto;
+    }
 }
 "#
-    .to_string()
+    .to_string();
+
+    css.push_str(&target_indicator_css());
+    for count in 2..=MAX_CSS_SLIDES {
+        css.push_str(&autoplay_keyframes_css(count));
+    }
+    css
 }
 
 #[cfg(test)]
@@ -389,6 +591,65 @@ mod tests {
         assert!(!carousel.show_arrows);
     }
 
+    #[test]
+    fn test_carousel_slide_navigation() {
+        let html = Carousel::new(vec![html! { "A" }, html! { "B" }, html! { "C" }])
+            .id("hero")
+            .active_index(1)
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"id="hero-slide-1""#));
+        assert!(html.contains(r#"id="hero-slide-3""#));
+        // First slide wraps back to the last one
+        assert!(html.contains(r##"href="#hero-slide-3" aria-label="Previous slide""##));
+        assert!(html.contains(r##"href="#hero-slide-2" aria-label="Next slide""##));
+        assert!(html.contains(r##"href="#hero-slide-2" aria-label="Go to slide 2" aria-current="true""##));
+        assert_eq!(html.matches("aria-current").count(), 1);
+        assert!(html.contains("sh-carousel__item sh-carousel__item--active"));
+    }
+
+    #[test]
+    fn test_carousel_captions() {
+        let html = Carousel::from_slides(vec![
+            CarouselSlide::new(html! { "A" }).caption("Sunrise"),
+            html! { "B" }.into(),
+        ])
+        .render()
+        .into_string();
+
+        assert!(html.contains(r#"<p class="sh-carousel__caption">Sunrise</p>"#));
+        assert_eq!(html.matches("sh-carousel__caption").count(), 1);
+    }
+
+    #[test]
+    fn test_carousel_autoplay_classes() {
+        let slides = |n| (0..n).map(|_| html! { "x" }).collect::<Vec<_>>();
+
+        let html = Carousel::new(slides(3)).autoplay(true).render().into_string();
+        assert!(html.contains("sh-carousel--autoplay sh-carousel--autoplay-3"));
+        assert!(html.contains("--sh-carousel-count: 3;"));
+
+        let single = Carousel::new(slides(1)).autoplay(true).render().into_string();
+        assert!(!single.contains("sh-carousel--autoplay"));
+
+        let too_many = Carousel::new(slides(MAX_CSS_SLIDES + 1)).autoplay(true);
+        assert!(!too_many.build_classes().contains("sh-carousel--autoplay"));
+
+        let static_carousel = Carousel::new(slides(3))
+            .autoplay(true)
+            .animation(CarouselAnimation::None);
+        assert!(!static_carousel.build_classes().contains("sh-carousel--autoplay"));
+    }
+
+    #[test]
+    fn test_autoplay_keyframes() {
+        let css = autoplay_keyframes_css(4);
+        assert!(css.contains("@keyframes sh-carousel-autoplay-4"));
+        assert!(css.contains("translateX(-300%)"));
+        assert!(css.contains("@keyframes sh-carousel-fade-4"));
+    }
+
     #[test]
     fn test_carousel_css() {
         let css = carousel_css();
@@ -396,5 +657,10 @@ mod tests {
         assert!(css.contains(".sh-carousel--fade"));
         assert!(css.contains(".sh-carousel__arrow"));
         assert!(css.contains(".sh-carousel__indicator"));
+        assert!(css.contains("scroll-snap-type: x mandatory"));
+        assert!(css.contains(":target"));
+        assert!(css.contains("animation-play-state: paused"));
+        assert!(css.contains("prefers-reduced-motion"));
+        assert!(css.contains(&format!("sh-carousel-autoplay-{}", MAX_CSS_SLIDES)));
     }
 }
@@ -0,0 +1,919 @@
+//! Changelog Component - Versioned release notes
+//!
+//! Releases are listed newest first. Each gets a semver badge, the size of
+//! the bump relative to the release below it, and changes tagged by kind in
+//! Keep a Changelog order with breaking changes on top. Releases past the
+//! first few collapse into `details` elements, and [`Changelog::to_feed`]
+//! turns the same data into a [`Feed`] so release pages can offer RSS.
+
+use crate::badge::{Badge, BadgeVariant};
+use crate::component::{ComponentColor, ComponentSize};
+use crate::feed::{Feed, FeedFormat, FeedItem};
+use crate::datetime::DateTime;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+use std::fmt;
+
+/// Error returned when a version string is not `MAJOR.MINOR.PATCH[-PRE][+BUILD]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemVerError {
+    pub input: String,
+}
+
+impl fmt::Display for SemVerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid semantic version: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for SemVerError {}
+
+/// A parsed semantic version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemVer {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+    pub build: Option<String>,
+}
+
+impl SemVer {
+    /// Parse a version, accepting an optional leading `v`
+    pub fn parse(input: &str) -> Result<Self, SemVerError> {
+        let err = || SemVerError {
+            input: input.to_string(),
+        };
+        let trimmed = input.trim();
+        let trimmed = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+        let (rest, build) = match trimmed.split_once('+') {
+            Some((rest, build)) => (rest, Some(build.to_string())),
+            None => (trimmed, None),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (rest, None),
+        };
+        if pre.as_deref() == Some("") || build.as_deref() == Some("") {
+            return Err(err());
+        }
+
+        let mut parts = core.split('.');
+        let mut next = || -> Result<u64, SemVerError> {
+            let part = parts.next().ok_or_else(err)?;
+            if part.is_empty() || (part.len() > 1 && part.starts_with('0')) {
+                return Err(err());
+            }
+            part.parse().map_err(|_| err())
+        };
+        let (major, minor, patch) = (next()?, next()?, next()?);
+        if parts.next().is_some() {
+            return Err(err());
+        }
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+
+    /// How this version moved on from `previous`
+    pub fn bump_from(&self, previous: &SemVer) -> VersionBump {
+        if self.pre.is_some() {
+            VersionBump::Prerelease
+        } else if self.major != previous.major {
+            VersionBump::Major
+        } else if self.minor != previous.minor {
+            VersionBump::Minor
+        } else {
+            VersionBump::Patch
+        }
+    }
+}
+
+impl fmt::Display for SemVer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
+        }
+        Ok(())
+    }
+}
+
+/// Size of a release relative to the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+    Prerelease,
+}
+
+impl VersionBump {
+    fn label(&self) -> &'static str {
+        match self {
+            VersionBump::Major => "Major",
+            VersionBump::Minor => "Minor",
+            VersionBump::Patch => "Patch",
+            VersionBump::Prerelease => "Pre-release",
+        }
+    }
+
+    fn color(&self) -> ComponentColor {
+        match self {
+            VersionBump::Major => ComponentColor::Warning,
+            VersionBump::Minor => ComponentColor::Info,
+            VersionBump::Patch => ComponentColor::Neutral,
+            VersionBump::Prerelease => ComponentColor::Secondary,
+        }
+    }
+}
+
+/// Kind of change, in the order changes are listed within a release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChangeKind {
+    Breaking,
+    Security,
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+}
+
+impl ChangeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeKind::Breaking => "Breaking",
+            ChangeKind::Security => "Security",
+            ChangeKind::Added => "Added",
+            ChangeKind::Changed => "Changed",
+            ChangeKind::Deprecated => "Deprecated",
+            ChangeKind::Removed => "Removed",
+            ChangeKind::Fixed => "Fixed",
+        }
+    }
+
+    fn modifier(&self) -> &'static str {
+        match self {
+            ChangeKind::Breaking => "breaking",
+            ChangeKind::Security => "security",
+            ChangeKind::Added => "added",
+            ChangeKind::Changed => "changed",
+            ChangeKind::Deprecated => "deprecated",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Fixed => "fixed",
+        }
+    }
+}
+
+/// One entry in a release
+#[derive(Debug, Clone)]
+pub struct Change<'a> {
+    pub kind: ChangeKind,
+    pub text: Cow<'a, str>,
+    pub scope: Option<Cow<'a, str>>,
+    pub reference: Option<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> Change<'a> {
+    pub fn new(kind: ChangeKind, text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            scope: None,
+            reference: None,
+        }
+    }
+
+    pub fn added(text: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(ChangeKind::Added, text)
+    }
+
+    pub fn fixed(text: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(ChangeKind::Fixed, text)
+    }
+
+    pub fn breaking(text: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(ChangeKind::Breaking, text)
+    }
+
+    /// Area of the product the change touches, shown before the text
+    pub fn scope(mut self, scope: impl Into<Cow<'a, str>>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Link to the issue or pull request, e.g. `("#42", url)`
+    pub fn reference(
+        mut self,
+        label: impl Into<Cow<'a, str>>,
+        href: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.reference = Some((label.into(), href.into()));
+        self
+    }
+}
+
+/// A released (or upcoming) version
+#[derive(Debug, Clone)]
+pub struct Release<'a> {
+    pub version: Cow<'a, str>,
+    pub date: Option<Cow<'a, str>>,
+    pub title: Option<Cow<'a, str>>,
+    pub summary: Option<Cow<'a, str>>,
+    pub changes: Vec<Change<'a>>,
+    pub yanked: bool,
+}
+
+impl<'a> Release<'a> {
+    /// `version` may be a semver string or a label such as `Unreleased`
+    pub fn new(version: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            version: version.into(),
+            date: None,
+            title: None,
+            summary: None,
+            changes: Vec::new(),
+            yanked: false,
+        }
+    }
+
+    /// Release date as `YYYY-MM-DD`
+    pub fn date(mut self, date: impl Into<Cow<'a, str>>) -> Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn summary(mut self, summary: impl Into<Cow<'a, str>>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    pub fn change(mut self, change: Change<'a>) -> Self {
+        self.changes.push(change);
+        self
+    }
+
+    pub fn changes(mut self, changes: impl IntoIterator<Item = Change<'a>>) -> Self {
+        self.changes.extend(changes);
+        self
+    }
+
+    /// Mark a release that was pulled after publishing
+    pub fn yanked(mut self, yanked: bool) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    pub fn semver(&self) -> Option<SemVer> {
+        SemVer::parse(&self.version).ok()
+    }
+
+    /// Version as displayed: `v1.2.0`, or the label unchanged
+    pub fn display_version(&self) -> String {
+        match self.semver() {
+            Some(version) => format!("v{}", version),
+            None => self.version.to_string(),
+        }
+    }
+
+    /// Fragment id for the release heading, e.g. `v1-2-0`
+    pub fn anchor(&self) -> String {
+        let mut slug = String::new();
+        for c in self.display_version().chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        slug.trim_matches('-').to_string()
+    }
+
+    fn has_breaking(&self) -> bool {
+        self.changes.iter().any(|c| c.kind == ChangeKind::Breaking)
+    }
+
+    fn sorted_changes(&self) -> Vec<&Change<'a>> {
+        let mut changes: Vec<&Change<'a>> = self.changes.iter().collect();
+        changes.sort_by_key(|c| c.kind);
+        changes
+    }
+
+    fn render_date(&self) -> Markup {
+        html! {
+            @if let Some(date) = &self.date {
+                @if let Some(parsed) = DateTime::parse(date) {
+                    (parsed.show_time(false).class("sh-changelog__date"))
+                } @else {
+                    span class="sh-changelog__date" { (date) }
+                }
+            }
+        }
+    }
+
+    fn render_body(&self) -> Markup {
+        html! {
+            @if let Some(summary) = &self.summary {
+                p class="sh-changelog__summary" { (summary) }
+            }
+            @if !self.changes.is_empty() {
+                ul class="sh-changelog__changes" {
+                    @for change in self.sorted_changes() {
+                        li class={ "sh-changelog__change sh-changelog__change--" (change.kind.modifier()) } {
+                            span class={ "sh-changelog__tag sh-changelog__tag--" (change.kind.modifier()) } {
+                                (change.kind.label())
+                            }
+                            span class="sh-changelog__text" {
+                                @if let Some(scope) = &change.scope {
+                                    strong class="sh-changelog__scope" { (scope) ":" }
+                                    " "
+                                }
+                                (change.text)
+                                @if let Some((label, href)) = &change.reference {
+                                    " "
+                                    a class="sh-changelog__ref" href=(href) { "(" (label) ")" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Release notes page section
+#[derive(Debug, Clone)]
+pub struct Changelog<'a> {
+    pub title: Cow<'a, str>,
+    pub releases: Vec<Release<'a>>,
+    pub expanded: usize,
+    pub feed_url: Option<Cow<'a, str>>,
+    pub heading_level: u8,
+}
+
+impl<'a> Changelog<'a> {
+    pub fn new(releases: Vec<Release<'a>>) -> Self {
+        Self {
+            title: Cow::Borrowed("Changelog"),
+            releases,
+            expanded: 3,
+            feed_url: None,
+            heading_level: 2,
+        }
+    }
+
+    pub fn release(mut self, release: Release<'a>) -> Self {
+        self.releases.push(release);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Number of newest releases shown open; the rest collapse
+    pub fn expanded(mut self, count: usize) -> Self {
+        self.expanded = count;
+        self
+    }
+
+    /// URL of the RSS feed built with [`Changelog::to_feed`]; adds a
+    /// subscribe link to the header
+    pub fn feed_url(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+        self.feed_url = Some(url.into());
+        self
+    }
+
+    /// Heading level of the changelog title (2-5); releases sit one below
+    pub fn heading_level(mut self, level: u8) -> Self {
+        self.heading_level = level.clamp(2, 5);
+        self
+    }
+
+    /// `<link rel="alternate">` for the page `<head>`, if a feed is set
+    pub fn feed_link(&self) -> Option<Markup> {
+        self.feed_url.as_ref().map(|url| {
+            html! {
+                link rel="alternate" type=(FeedFormat::Rss.content_type()) title=(self.title) href=(url);
+            }
+        })
+    }
+
+    /// Build a feed with one item per dated release, linking to the
+    /// release anchors on `page_url`
+    pub fn to_feed(&self, page_url: &str, feed_url: &str) -> Feed {
+        let items = self.releases.iter().filter_map(|release| {
+            let date = release.date.as_deref()?;
+            let title = match &release.title {
+                Some(title) => format!("{}: {}", release.display_version(), title),
+                None => release.display_version(),
+            };
+            let mut item = FeedItem::new(title, format!("{}#{}", page_url, release.anchor()), date)
+                .content_html(release.render_body().into_string());
+            if let Some(summary) = &release.summary {
+                item = item.summary(summary.as_ref());
+            }
+            let mut kinds: Vec<ChangeKind> = release.changes.iter().map(|c| c.kind).collect();
+            kinds.sort();
+            kinds.dedup();
+            for kind in kinds {
+                item = item.category(kind.label());
+            }
+            Some(item)
+        });
+        Feed::new(self.title.as_ref(), page_url, feed_url).items(items)
+    }
+
+    /// Bump of each release relative to the next older semver release
+    fn bumps(&self) -> Vec<Option<VersionBump>> {
+        let versions: Vec<Option<SemVer>> = self.releases.iter().map(Release::semver).collect();
+        (0..versions.len())
+            .map(|i| {
+                let current = versions[i].as_ref()?;
+                let previous = versions[i + 1..].iter().flatten().next()?;
+                Some(current.bump_from(previous))
+            })
+            .collect()
+    }
+
+    fn render_release(&self, release: &Release<'a>, bump: Option<VersionBump>) -> Markup {
+        let level = self.heading_level + 1;
+        let anchor = release.anchor();
+        let header = html! {
+            a class="sh-changelog__anchor" href={ "#" (anchor) } {
+                (release.display_version())
+            }
+            @if let Some(title) = &release.title {
+                span class="sh-changelog__release-title" { (title) }
+            }
+        };
+        let meta = html! {
+            span class="sh-changelog__meta" {
+                @if let Some(bump) = bump {
+                    (Badge::new(bump.label())
+                        .size(ComponentSize::Sm)
+                        .color(bump.color())
+                        .variant(BadgeVariant::Soft))
+                }
+                @if release.has_breaking() && bump != Some(VersionBump::Major) {
+                    (Badge::new("Breaking")
+                        .size(ComponentSize::Sm)
+                        .color(ComponentColor::Error)
+                        .variant(BadgeVariant::Soft))
+                }
+                @if release.yanked {
+                    (Badge::new("Yanked")
+                        .size(ComponentSize::Sm)
+                        .color(ComponentColor::Error)
+                        .variant(BadgeVariant::Outline))
+                }
+                (release.render_date())
+            }
+        };
+
+        html! {
+            div class="sh-changelog__release-header" {
+                @match level {
+                    3 => h3 class="sh-changelog__version" { (header) },
+                    4 => h4 class="sh-changelog__version" { (header) },
+                    5 => h5 class="sh-changelog__version" { (header) },
+                    _ => h6 class="sh-changelog__version" { (header) },
+                }
+                (meta)
+            }
+            (release.render_body())
+        }
+    }
+}
+
+impl<'a> Render for Changelog<'a> {
+    fn render(&self) -> Markup {
+        let bumps = self.bumps();
+        let split = self.expanded.min(self.releases.len());
+        let (recent, older) = self.releases.split_at(split);
+
+        html! {
+            section class="sh-changelog" aria-label=(self.title) {
+                div class="sh-changelog__header" {
+                    @match self.heading_level {
+                        2 => h2 class="sh-changelog__title" { (self.title) },
+                        3 => h3 class="sh-changelog__title" { (self.title) },
+                        4 => h4 class="sh-changelog__title" { (self.title) },
+                        _ => h5 class="sh-changelog__title" { (self.title) },
+                    }
+                    @if let Some(url) = &self.feed_url {
+                        a class="sh-changelog__feed" href=(url) type=(FeedFormat::Rss.content_type()) {
+                            svg class="sh-changelog__feed-icon" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" aria-hidden="true" {
+                                path d="M4 11a9 9 0 0 1 9 9M4 4a16 16 0 0 1 16 16" {}
+                                circle cx="5" cy="19" r="1" {}
+                            }
+                            "RSS"
+                        }
+                    }
+                }
+                @for (i, release) in recent.iter().enumerate() {
+                    article
+                        class={ "sh-changelog__release" @if release.yanked { " sh-changelog__release--yanked" } }
+                        id=(release.anchor()) {
+                        (self.render_release(release, bumps[i]))
+                    }
+                }
+                @if !older.is_empty() {
+                    div class="sh-changelog__older" {
+                        p class="sh-changelog__older-label" {
+                            "Older versions (" (older.len()) ")"
+                        }
+                        @for (i, release) in older.iter().enumerate() {
+                            details
+                                class={ "sh-changelog__release sh-changelog__release--collapsed" @if release.yanked { " sh-changelog__release--yanked" } }
+                                id=(release.anchor()) {
+                                summary class="sh-changelog__toggle" {
+                                    span class="sh-changelog__toggle-version" { (release.display_version()) }
+                                    (release.render_date())
+                                }
+                                (self.render_release(release, bumps[split + i]))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the changelog component
+pub fn changelog_css() -> String {
+    r#"
+.sh-changelog {
+    display: flex;
+    flex-direction: column;
+    gap: 1.5rem;
+    color: var(--sh-text, #111827);
+}
+
+.sh-changelog__header {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: 1rem;
+}
+
+.sh-changelog__title {
+    margin: 0;
+}
+
+.sh-changelog__feed {
+    display: inline-flex;
+    align-items: center;
+    gap: 0.375rem;
+    padding: 0.25rem 0.625rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.8125rem;
+    text-decoration: none;
+}
+
+.sh-changelog__feed:hover {
+    color: var(--sh-accent, #f97316);
+    border-color: currentColor;
+}
+
+.sh-changelog__release {
+    padding-bottom: 1.5rem;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    scroll-margin-top: 1rem;
+}
+
+.sh-changelog__release:target {
+    border-left: 3px solid var(--sh-primary, #3b82f6);
+    padding-left: 1rem;
+}
+
+.sh-changelog__release-header {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: baseline;
+    gap: 0.5rem 1rem;
+    margin-bottom: 0.75rem;
+}
+
+.sh-changelog__version {
+    display: flex;
+    align-items: baseline;
+    gap: 0.5rem;
+    margin: 0;
+    font-size: 1.25rem;
+}
+
+.sh-changelog__anchor {
+    color: inherit;
+    font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace;
+    text-decoration: none;
+}
+
+.sh-changelog__anchor:hover {
+    text-decoration: underline;
+}
+
+.sh-changelog__release-title {
+    font-weight: 500;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-changelog__release--yanked .sh-changelog__anchor {
+    text-decoration: line-through;
+}
+
+.sh-changelog__meta {
+    display: inline-flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-changelog__date {
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.875rem;
+}
+
+.sh-changelog__summary {
+    margin: 0 0 0.75rem;
+}
+
+.sh-changelog__changes {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-changelog__change {
+    display: flex;
+    align-items: baseline;
+    gap: 0.75rem;
+}
+
+.sh-changelog__tag {
+    flex: none;
+    min-width: 6.5rem;
+    padding: 0.0625rem 0.5rem;
+    border-radius: 999px;
+    font-size: 0.75rem;
+    font-weight: 600;
+    text-align: center;
+    text-transform: uppercase;
+    letter-spacing: 0.03em;
+    --sh-changelog-tag: var(--sh-text-muted, #6b7280);
+    color: var(--sh-changelog-tag);
+    background: color-mix(in srgb, var(--sh-changelog-tag) 14%, transparent);
+}
+
+.sh-changelog__tag--breaking { --sh-changelog-tag: var(--sh-error, #ef4444); }
+.sh-changelog__tag--security { --sh-changelog-tag: #b45309; }
+.sh-changelog__tag--added { --sh-changelog-tag: #15803d; }
+.sh-changelog__tag--changed { --sh-changelog-tag: var(--sh-primary, #3b82f6); }
+.sh-changelog__tag--deprecated { --sh-changelog-tag: #a16207; }
+.sh-changelog__tag--removed { --sh-changelog-tag: #9f1239; }
+.sh-changelog__tag--fixed { --sh-changelog-tag: #7c3aed; }
+
+.sh-changelog__scope {
+    font-weight: 600;
+}
+
+.sh-changelog__ref {
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.875rem;
+}
+
+.sh-changelog__older {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+}
+
+.sh-changelog__older-label {
+    margin: 0;
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.875rem;
+    font-weight: 600;
+}
+
+.sh-changelog__release--collapsed {
+    padding-bottom: 0.5rem;
+}
+
+.sh-changelog__toggle {
+    display: flex;
+    align-items: baseline;
+    gap: 1rem;
+    padding: 0.5rem 0;
+    cursor: pointer;
+    list-style: none;
+}
+
+.sh-changelog__toggle::-webkit-details-marker {
+    display: none;
+}
+
+.sh-changelog__toggle::before {
+    content: "\25B8";
+    color: var(--sh-text-muted, #6b7280);
+    transition: transform 0.15s ease;
+}
+
+.sh-changelog__release--collapsed[open] > .sh-changelog__toggle::before {
+    transform: rotate(90deg);
+}
+
+.sh-changelog__toggle-version {
+    font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace;
+    font-weight: 600;
+}
+
+.sh-changelog__release--collapsed[open] > .sh-changelog__toggle .sh-changelog__toggle-version,
+.sh-changelog__release--collapsed[open] > .sh-changelog__toggle .sh-changelog__date {
+    display: none;
+}
+
+.sh-changelog__toggle:focus-visible {
+    outline: 2px solid var(--sh-accent, #3b82f6);
+    outline-offset: 2px;
+}
+
+@media (max-width: 640px) {
+    .sh-changelog__change {
+        flex-direction: column;
+        gap: 0.25rem;
+    }
+
+    .sh-changelog__tag {
+        min-width: 0;
+    }
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-changelog__toggle::before {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Changelog<'static> {
+        Changelog::new(vec![
+            Release::new("Unreleased").change(Change::added("Dark mode")),
+            Release::new("2.0.0")
+                .date("2024-05-01")
+                .change(Change::fixed("Crash on empty input"))
+                .change(Change::breaking("Drop the legacy API").scope("api")),
+            Release::new("v1.4.0")
+                .date("2024-03-12")
+                .change(Change::added("Export to CSV").reference("#42", "/pull/42")),
+            Release::new("1.3.1").date("2024-02-02").yanked(true),
+            Release::new("1.3.0-rc.1").date("2024-01-20"),
+        ])
+    }
+
+    #[test]
+    fn test_semver_parse() {
+        let v = SemVer::parse("v1.2.3-beta.1+build.5").unwrap();
+        assert_eq!((v.major, v.minor, v.patch), (1, 2, 3));
+        assert_eq!(v.pre.as_deref(), Some("beta.1"));
+        assert_eq!(v.build.as_deref(), Some("build.5"));
+        assert_eq!(v.to_string(), "1.2.3-beta.1+build.5");
+
+        assert!(SemVer::parse("1.2").is_err());
+        assert!(SemVer::parse("1.02.3").is_err());
+        assert!(SemVer::parse("1.2.3.4").is_err());
+        assert!(SemVer::parse("1.2.3-").is_err());
+        assert_eq!(
+            SemVer::parse("next").unwrap_err().to_string(),
+            "invalid semantic version: \"next\""
+        );
+    }
+
+    #[test]
+    fn test_bumps() {
+        let bumps = sample().bumps();
+        assert_eq!(
+            bumps,
+            vec![
+                None,
+                Some(VersionBump::Major),
+                Some(VersionBump::Minor),
+                Some(VersionBump::Patch),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prerelease_bump() {
+        let rc = SemVer::parse("2.0.0-rc.1").unwrap();
+        let prev = SemVer::parse("1.9.0").unwrap();
+        assert_eq!(rc.bump_from(&prev), VersionBump::Prerelease);
+    }
+
+    #[test]
+    fn test_anchor_and_display_version() {
+        let release = Release::new("1.4.0-rc.1");
+        assert_eq!(release.display_version(), "v1.4.0-rc.1");
+        assert_eq!(release.anchor(), "v1-4-0-rc-1");
+        assert_eq!(Release::new("Unreleased").anchor(), "unreleased");
+    }
+
+    #[test]
+    fn test_changes_sorted_breaking_first() {
+        let html = sample().render().into_string();
+        let breaking = html.find("sh-changelog__tag--breaking").unwrap();
+        let fixed = html.find("sh-changelog__tag--fixed").unwrap();
+        assert!(breaking < fixed);
+        assert!(html.contains(r#"<strong class="sh-changelog__scope">api:</strong>"#));
+        assert!(html.contains(r##"<a class="sh-changelog__ref" href="/pull/42">(#42)</a>"##));
+    }
+
+    #[test]
+    fn test_render_dates_and_badges() {
+        let html = sample().render().into_string();
+        assert!(html.contains(
+            r#"<time class="sh-changelog__date" datetime="2024-05-01">May 1, 2024</time>"#
+        ));
+        assert!(html.contains(r##"href="#v2-0-0""##));
+        assert!(html.contains("Major"));
+        assert!(html.contains("Minor"));
+        assert!(html.contains("Yanked"));
+        assert!(html.contains("sh-changelog__release--yanked"));
+    }
+
+    #[test]
+    fn test_older_versions_collapse() {
+        let html = sample().render().into_string();
+        assert_eq!(html.matches("<article").count(), 3);
+        assert!(html.contains("Older versions (2)"));
+        assert!(html.contains(r#"<details class="sh-changelog__release sh-changelog__release--collapsed sh-changelog__release--yanked" id="v1-3-1">"#));
+        assert!(!html.contains("<details open"));
+
+        let html = sample().expanded(10).render().into_string();
+        assert!(!html.contains("sh-changelog__older"));
+    }
+
+    #[test]
+    fn test_feed_hook() {
+        let log = sample().feed_url("/changelog.xml");
+        let html = log.render().into_string();
+        assert!(html.contains(
+            r#"<a class="sh-changelog__feed" href="/changelog.xml" type="application/rss+xml">"#
+        ));
+        let link = log.feed_link().unwrap().into_string();
+        assert!(link.contains(r#"rel="alternate""#));
+        assert!(Changelog::new(vec![]).feed_link().is_none());
+
+        let feed = log.to_feed(
+            "https://example.com/changelog",
+            "https://example.com/changelog.xml",
+        );
+        assert_eq!(feed.items.len(), 4);
+        assert_eq!(feed.items[0].title, "v2.0.0");
+        assert_eq!(feed.items[0].url, "https://example.com/changelog#v2-0-0");
+        assert_eq!(feed.items[0].categories, vec!["Breaking", "Fixed"]);
+        assert!(feed.to_rss().contains("<pubDate>Wed, 01 May 2024"));
+    }
+
+    #[test]
+    fn test_heading_levels() {
+        let html = sample().heading_level(3).render().into_string();
+        assert!(html.contains(r#"<h3 class="sh-changelog__title">"#));
+        assert!(html.contains(r#"<h4 class="sh-changelog__version">"#));
+    }
+
+    #[test]
+    fn test_changelog_css() {
+        let css = changelog_css();
+        assert!(css.contains(".sh-changelog__tag--breaking"));
+        assert!(css.contains(".sh-changelog__release--collapsed[open]"));
+    }
+}
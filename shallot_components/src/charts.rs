@@ -493,10 +493,122 @@ impl BarChart {
     }
 }
 
-/// Gauge chart - circular progress indicator
+/// Color bands picked by value, e.g. green below 70, amber below 90 and
+/// red above
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thresholds<C> {
+    /// Upper limits (exclusive) with their colors, ascending
+    bands: Vec<(f64, C)>,
+    above: C,
+}
+
+impl<C: Copy> Thresholds<C> {
+    /// `above` is used for values past every limit
+    pub fn new(above: C) -> Self {
+        Self {
+            bands: Vec::new(),
+            above,
+        }
+    }
+
+    /// Use `color` for values below `limit` not covered by a lower band
+    pub fn below(mut self, limit: f64, color: C) -> Self {
+        let index = self.bands.partition_point(|(l, _)| *l < limit);
+        self.bands.insert(index, (limit, color));
+        self
+    }
+
+    pub fn color_for(&self, value: f64) -> C {
+        self.bands
+            .iter()
+            .find(|(limit, _)| value < *limit)
+            .map_or(self.above, |(_, color)| *color)
+    }
+
+    /// Bands as `(start, end, color)` clipped to `min..max`
+    pub fn bands(&self, min: f64, max: f64) -> Vec<(f64, f64, C)> {
+        let mut start = min;
+        let mut out = Vec::new();
+        for (limit, color) in &self.bands {
+            let end = limit.clamp(min, max);
+            if end > start {
+                out.push((start, end, *color));
+                start = end;
+            }
+        }
+        if max > start {
+            out.push((start, max, self.above));
+        }
+        out
+    }
+}
+
+impl Thresholds<ChartColor> {
+    /// Success below `warning`, Warning below `critical`, Error above
+    pub fn traffic_light(warning: f64, critical: f64) -> Self {
+        Self::new(ChartColor::Error)
+            .below(warning, ChartColor::Success)
+            .below(critical, ChartColor::Warning)
+    }
+}
+
+/// How a meter's center label shows its value
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MeterFormat {
+    /// Position within the range, e.g. "75%"
+    #[default]
+    Percent,
+    /// The raw value with fixed decimals and a unit, e.g. "3.2 GB"
+    Value { decimals: u8, unit: &'static str },
+    /// Value over maximum, e.g. "42 / 50"
+    Fraction,
+    /// Fixed text
+    Text(String),
+}
+
+impl MeterFormat {
+    pub fn format(&self, value: f64, min: f64, max: f64) -> String {
+        match self {
+            MeterFormat::Percent => {
+                format!("{:.0}%", normalize(value, min, max) * 100.0)
+            }
+            MeterFormat::Value { decimals, unit } => {
+                let number = format!("{:.*}", usize::from(*decimals), value);
+                if unit.is_empty() {
+                    number
+                } else {
+                    format!("{} {}", number, unit)
+                }
+            }
+            MeterFormat::Fraction => format!("{} / {}", value, max),
+            MeterFormat::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// Position of `value` in `min..max` as 0.0-1.0
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Degrees swept clockwise from `start` to `end`, in `(0, 360]`
+pub(crate) fn arc_sweep(start: f64, end: f64) -> f64 {
+    let sweep = (end - start).rem_euclid(360.0);
+    if sweep == 0.0 {
+        360.0
+    } else {
+        sweep
+    }
+}
+
+/// Gauge chart - circular or arc-shaped meter
 #[derive(Debug, Clone)]
 pub struct Gauge {
-    value: f64, // 0.0 to 100.0
+    value: f64, // clamped to min..max
     min: f64,
     max: f64,
     size: u16,
@@ -506,14 +618,27 @@ pub struct Gauge {
     show_value: bool,
     animate: bool,
     animation_duration: u16,
+    /// Degrees clockwise from 12 o'clock
+    start_angle: f64,
+    end_angle: f64,
+    thresholds: Option<Thresholds<ChartColor>>,
+    show_bands: bool,
+    format: MeterFormat,
+    label: Option<String>,
 }
 
 impl Gauge {
+    /// Gauge over the default 0-100 range
     pub fn new(value: f64) -> Self {
+        Self::with_range(value, 0.0, 100.0)
+    }
+
+    /// Gauge over `min..max`
+    pub fn with_range(value: f64, min: f64, max: f64) -> Self {
         Self {
-            value: value.clamp(0.0, 100.0),
-            min: 0.0,
-            max: 100.0,
+            value: value.clamp(min.min(max), max.max(min)),
+            min,
+            max,
             size: 100,
             stroke_width: 8,
             color: ChartColor::Primary,
@@ -521,12 +646,21 @@ impl Gauge {
             show_value: true,
             animate: true,
             animation_duration: 1000,
+            start_angle: 0.0,
+            end_angle: 360.0,
+            thresholds: None,
+            show_bands: false,
+            format: MeterFormat::default(),
+            label: None,
         }
     }
 
+    /// Change the range; the value is clamped to it, so use
+    /// [`Gauge::with_range`] for values outside 0-100
     pub fn range(mut self, min: f64, max: f64) -> Self {
         self.min = min;
         self.max = max;
+        self.value = self.value.clamp(min.min(max), max.max(min));
         self
     }
 
@@ -535,6 +669,11 @@ impl Gauge {
         self
     }
 
+    pub fn stroke_width(mut self, width: u8) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
     pub fn color(mut self, color: ChartColor) -> Self {
         self.color = color;
         self
@@ -545,38 +684,103 @@ impl Gauge {
         self
     }
 
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    /// Draw an arc from `start` to `end`, in degrees clockwise from
+    /// 12 o'clock; `(-135.0, 135.0)` gives a speedometer
+    pub fn angles(mut self, start: f64, end: f64) -> Self {
+        self.start_angle = start;
+        self.end_angle = end;
+        self
+    }
+
+    /// Color the value by threshold instead of [`Gauge::color`]
+    pub fn thresholds(mut self, thresholds: Thresholds<ChartColor>) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    /// Tint the track with the threshold bands
+    pub fn show_bands(mut self, show: bool) -> Self {
+        self.show_bands = show;
+        self
+    }
+
+    pub fn format(mut self, format: MeterFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Accessible name, e.g. "CPU usage"
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The center label text
+    pub fn value_text(&self) -> String {
+        self.format.format(self.value, self.min, self.max)
+    }
+
+    fn current_color(&self) -> ChartColor {
+        self.thresholds
+            .as_ref()
+            .map_or(self.color, |t| t.color_for(self.value))
+    }
+
     pub fn render(self) -> Markup {
-        let normalized_value = ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
-        let percentage = normalized_value * 100.0;
+        let normalized_value = normalize(self.value, self.min, self.max);
 
         let radius = (self.size as f64 - self.stroke_width as f64) / 2.0;
         let center = self.size as f64 / 2.0;
         let circumference = 2.0 * std::f64::consts::PI * radius;
-        let stroke_dashoffset = circumference * (1.0 - normalized_value);
-
-        let color = self.color.css_value();
+        let sweep = arc_sweep(self.start_angle, self.end_angle);
+        let arc_length = circumference * sweep / 360.0;
+        let stroke_dashoffset = arc_length * (1.0 - normalized_value);
+        // SVG angles start at 3 o'clock
+        let rotate = format!("rotate({} {} {})", self.start_angle - 90.0, center, center);
+        let dasharray = format!("{} {}", arc_length, circumference);
+
+        let color = self.current_color().css_value();
+        let value_text = self.value_text();
+        let bands = match (&self.thresholds, self.show_bands) {
+            (Some(thresholds), true) => thresholds.bands(self.min, self.max),
+            _ => Vec::new(),
+        };
+        let span = self.max - self.min;
 
         let animation_style = if self.animate {
             format!(
                 "--gauge-circumference:{};--gauge-offset:{};--gauge-animation-duration:{}ms;",
-                circumference, stroke_dashoffset, self.animation_duration
+                arc_length, stroke_dashoffset, self.animation_duration
             )
         } else {
             String::new()
         };
 
         html! {
-            div class="sh-gauge" style=(format!("width:{}px;height:{}px;", self.size, self.size)) {
+            div
+                class="sh-gauge"
+                style=(format!("width:{}px;height:{}px;", self.size, self.size))
+                role="meter"
+                aria-valuenow=(self.value)
+                aria-valuemin=(self.min)
+                aria-valuemax=(self.max)
+                aria-valuetext=(value_text)
+                aria-label=[self.label.as_deref()]
+            {
                 svg
                     class=(if self.animate { "sh-gauge--animated" } else { "" })
                     style=(animation_style)
                     width=(self.size)
                     height=(self.size)
                     viewBox=(format!("0 0 {} {}", self.size, self.size))
-                    role="img"
-                    aria-label=(format!("Gauge showing {:.0}%", percentage))
+                    aria-hidden="true"
                 {
-                    // Background circle
+                    // Background arc
                     circle
                         class="sh-gauge-bg"
                         cx=(center)
@@ -585,9 +789,28 @@ impl Gauge {
                         fill="none"
                         stroke=(self.background_color)
                         stroke-width=(self.stroke_width)
+                        stroke-dasharray=(dasharray)
+                        transform=(rotate)
                     {}
 
-                    // Progress circle
+                    @for (start, end, band_color) in &bands {
+                        @let band_start = arc_length * (start - self.min) / span;
+                        @let band_length = arc_length * (end - start) / span;
+                        circle
+                            class="sh-gauge-band"
+                            cx=(center)
+                            cy=(center)
+                            r=(radius)
+                            fill="none"
+                            stroke=(band_color.css_value())
+                            stroke-width=(self.stroke_width)
+                            stroke-dasharray=(format!("{} {}", band_length, circumference))
+                            stroke-dashoffset=(-band_start)
+                            transform=(rotate)
+                        {}
+                    }
+
+                    // Progress arc
                     circle
                         class="sh-gauge-progress"
                         cx=(center)
@@ -597,15 +820,15 @@ impl Gauge {
                         stroke=(color)
                         stroke-width=(self.stroke_width)
                         stroke-linecap="round"
-                        stroke-dasharray=(circumference)
-                        stroke-dashoffset=(if self.animate { circumference } else { stroke_dashoffset })
-                        transform=(format!("rotate(-90 {} {})", center, center))
+                        stroke-dasharray=(dasharray)
+                        stroke-dashoffset=(if self.animate { arc_length } else { stroke_dashoffset })
+                        transform=(rotate)
                     {}
                 }
 
                 @if self.show_value {
                     div class="sh-gauge-value" {
-                        (format!("{:.0}%", percentage))
+                        (value_text)
                     }
                 }
             }
@@ -677,8 +900,8 @@ pub fn charts_css() -> String {
     justify-content: center;
 }
 
-.sh-gauge svg {
-    transform: rotate(-90deg);
+.sh-gauge-band {
+    opacity: 0.3;
 }
 
 .sh-gauge--animated .sh-gauge-progress {
@@ -776,4 +999,75 @@ mod tests {
         let gauge = Gauge::new(-10.0); // Under 0
         assert_eq!(gauge.value, 0.0);
     }
+
+    #[test]
+    fn test_thresholds() {
+        let thresholds = Thresholds::traffic_light(70.0, 90.0);
+        assert_eq!(thresholds.color_for(50.0), ChartColor::Success);
+        assert_eq!(thresholds.color_for(70.0), ChartColor::Warning);
+        assert_eq!(thresholds.color_for(95.0), ChartColor::Error);
+        assert_eq!(
+            thresholds.bands(0.0, 100.0),
+            vec![
+                (0.0, 70.0, ChartColor::Success),
+                (70.0, 90.0, ChartColor::Warning),
+                (90.0, 100.0, ChartColor::Error),
+            ]
+        );
+        // Limits outside the range are dropped
+        assert_eq!(
+            thresholds.bands(80.0, 85.0),
+            vec![(80.0, 85.0, ChartColor::Warning)]
+        );
+    }
+
+    #[test]
+    fn test_meter_format() {
+        assert_eq!(MeterFormat::Percent.format(150.0, 100.0, 200.0), "50%");
+        let gb = MeterFormat::Value {
+            decimals: 1,
+            unit: "GB",
+        };
+        assert_eq!(gb.format(3.25, 0.0, 8.0), "3.2 GB");
+        assert_eq!(MeterFormat::Fraction.format(42.0, 0.0, 50.0), "42 / 50");
+        assert_eq!(MeterFormat::Text("OK".into()).format(1.0, 0.0, 1.0), "OK");
+        assert_eq!(arc_sweep(-135.0, 135.0), 270.0);
+        assert_eq!(arc_sweep(0.0, 360.0), 360.0);
+    }
+
+    #[test]
+    fn test_gauge_range_and_meter() {
+        let gauge = Gauge::with_range(450.0, 0.0, 500.0)
+            .label("Requests per second")
+            .format(MeterFormat::Fraction)
+            .thresholds(Thresholds::traffic_light(350.0, 450.0));
+        assert_eq!(gauge.value, 450.0);
+        assert_eq!(gauge.value_text(), "450 / 500");
+
+        let html = gauge.render().into_string();
+        assert!(html.contains(r#"role="meter""#));
+        assert!(html.contains(r#"aria-valuenow="450""#));
+        assert!(html.contains(r#"aria-valuemax="500""#));
+        assert!(html.contains(r#"aria-valuetext="450 / 500""#));
+        assert!(html.contains(r#"aria-label="Requests per second""#));
+        assert!(html.contains(r#"stroke="var(--sh-error)""#));
+        assert!(!html.contains("sh-gauge-band"));
+    }
+
+    #[test]
+    fn test_gauge_arc_and_bands() {
+        let html = Gauge::new(50.0)
+            .size(100)
+            .stroke_width(10)
+            .angles(-135.0, 135.0)
+            .thresholds(Thresholds::traffic_light(70.0, 90.0))
+            .show_bands(true)
+            .animate(false)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"transform="rotate(-225 50 50)""#));
+        assert_eq!(html.matches("sh-gauge-band").count(), 3);
+        assert!(html.contains(r#"stroke="var(--sh-success)""#));
+        assert!(html.contains(">50%</div>"));
+    }
 }
@@ -0,0 +1,814 @@
+//! Chat Components - Message bubbles, threads and a composer
+//!
+//! [`MessageList`] groups consecutive messages from the same sender and
+//! inserts day separators; [`ChatInput`] is a plain form post, so support
+//! widgets and inboxes render per request without a client runtime.
+
+use crate::avatar::Avatar;
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+
+/// Which side of the thread a message sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageDirection {
+    #[default]
+    Incoming,
+    Outgoing,
+}
+
+impl MessageDirection {
+    fn modifier(&self) -> &'static str {
+        match self {
+            MessageDirection::Incoming => "incoming",
+            MessageDirection::Outgoing => "outgoing",
+        }
+    }
+}
+
+/// Delivery state of an outgoing message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptStatus {
+    Sending,
+    Sent,
+    Delivered,
+    Read,
+}
+
+impl ReceiptStatus {
+    fn modifier(&self) -> &'static str {
+        match self {
+            ReceiptStatus::Sending => "sending",
+            ReceiptStatus::Sent => "sent",
+            ReceiptStatus::Delivered => "delivered",
+            ReceiptStatus::Read => "read",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReceiptStatus::Sending => "Sending",
+            ReceiptStatus::Sent => "Sent",
+            ReceiptStatus::Delivered => "Delivered",
+            ReceiptStatus::Read => "Read",
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            ReceiptStatus::Sending => "○",
+            ReceiptStatus::Sent => "✓",
+            ReceiptStatus::Delivered | ReceiptStatus::Read => "✓✓",
+        }
+    }
+}
+
+/// Place of a bubble within a run of messages from one sender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupPosition {
+    #[default]
+    Single,
+    First,
+    Middle,
+    Last,
+}
+
+impl GroupPosition {
+    fn modifier(&self) -> &'static str {
+        match self {
+            GroupPosition::Single => "single",
+            GroupPosition::First => "first",
+            GroupPosition::Middle => "middle",
+            GroupPosition::Last => "last",
+        }
+    }
+
+    fn starts(&self) -> bool {
+        matches!(self, GroupPosition::Single | GroupPosition::First)
+    }
+
+    fn ends(&self) -> bool {
+        matches!(self, GroupPosition::Single | GroupPosition::Last)
+    }
+}
+
+/// `2024-03-13T09:05:00Z` -> (date, minutes since midnight)
+fn parse_timestamp(timestamp: &str) -> Option<(CalendarDate, Option<u32>)> {
+    let date = CalendarDate::parse(timestamp.get(..10)?)?;
+    let minutes = timestamp.get(11..16).and_then(|clock| {
+        let (h, m) = clock.split_once(':')?;
+        Some(h.parse::<u32>().ok()? * 60 + m.parse::<u32>().ok()?)
+    });
+    Some((date, minutes))
+}
+
+fn day_label(date: CalendarDate, today: Option<CalendarDate>) -> String {
+    if let Some(today) = today {
+        match today.days_since_epoch() - date.days_since_epoch() {
+            0 => return "Today".to_string(),
+            1 => return "Yesterday".to_string(),
+            _ => {}
+        }
+    }
+    format!("{} {}, {}", date.month_abbreviation(), date.day, date.year)
+}
+
+/// Single chat message
+#[derive(Clone)]
+pub struct MessageBubble<'a> {
+    pub id: Option<Cow<'a, str>>,
+    pub sender: Cow<'a, str>,
+    pub body: Cow<'a, str>,
+    /// ISO 8601 timestamp, e.g. `2024-03-13T09:05:00Z`
+    pub timestamp: Option<Cow<'a, str>>,
+    pub direction: MessageDirection,
+    pub receipt: Option<ReceiptStatus>,
+    pub avatar: Option<Avatar<'a>>,
+    pub position: GroupPosition,
+}
+
+impl<'a> MessageBubble<'a> {
+    pub fn new(sender: impl Into<Cow<'a, str>>, body: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: None,
+            sender: sender.into(),
+            body: body.into(),
+            timestamp: None,
+            direction: MessageDirection::default(),
+            receipt: None,
+            avatar: None,
+            position: GroupPosition::default(),
+        }
+    }
+
+    /// Anchor id, for linking to a message
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Mark as sent by the current user
+    pub fn outgoing(mut self) -> Self {
+        self.direction = MessageDirection::Outgoing;
+        self
+    }
+
+    pub fn direction(mut self, direction: MessageDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn receipt(mut self, receipt: ReceiptStatus) -> Self {
+        self.receipt = Some(receipt);
+        self
+    }
+
+    /// Shown beside the last bubble of an incoming run
+    pub fn avatar(mut self, avatar: Avatar<'a>) -> Self {
+        self.avatar = Some(avatar);
+        self
+    }
+
+    pub fn position(mut self, position: GroupPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    fn clock(&self) -> Option<String> {
+        let minutes = parse_timestamp(self.timestamp.as_deref()?)?.1?;
+        Some(format!("{:02}:{:02}", minutes / 60, minutes % 60))
+    }
+}
+
+impl<'a> Render for MessageBubble<'a> {
+    fn render(&self) -> Markup {
+        let incoming = self.direction == MessageDirection::Incoming;
+        let class = format!(
+            "sh-chat-message sh-chat-message--{} sh-chat-message--{}",
+            self.direction.modifier(),
+            self.position.modifier()
+        );
+        let show_meta =
+            self.position.ends() && (self.timestamp.is_some() || self.receipt.is_some());
+
+        html! {
+            div class=(class) id=[self.id.as_deref()] {
+                @if incoming {
+                    @if let (true, Some(avatar)) = (self.position.ends(), &self.avatar) {
+                        div class="sh-chat-message__avatar" { (avatar) }
+                    } @else if self.avatar.is_some() {
+                        div class="sh-chat-message__avatar" aria-hidden="true" {}
+                    }
+                }
+                div class="sh-chat-message__content" {
+                    @if incoming && self.position.starts() {
+                        div class="sh-chat-message__sender" { (self.sender) }
+                    } @else {
+                        span class="sh-visually-hidden" { (self.sender) ": " }
+                    }
+                    div class="sh-chat-message__bubble" { (self.body) }
+                    @if show_meta {
+                        div class="sh-chat-message__meta" {
+                            @if let Some(timestamp) = &self.timestamp {
+                                time datetime=(timestamp) {
+                                    (self.clock().unwrap_or_else(|| timestamp.to_string()))
+                                }
+                            }
+                            @if let Some(receipt) = self.receipt {
+                                span
+                                    class={ "sh-chat-message__receipt sh-chat-message__receipt--" (receipt.modifier()) }
+                                    title=(receipt.label())
+                                {
+                                    span aria-hidden="true" { (receipt.icon()) }
+                                    span class="sh-visually-hidden" { (receipt.label()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Scrollable thread with grouping and day separators
+#[derive(Clone)]
+pub struct MessageList<'a> {
+    pub messages: Vec<MessageBubble<'a>>,
+    /// Reference day for "Today" / "Yesterday" separators
+    pub today: Option<CalendarDate>,
+    /// Longest pause, in minutes, that still continues a group
+    pub group_window: u32,
+    pub label: Cow<'a, str>,
+    pub custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageList<'a> {
+    pub fn new(messages: Vec<MessageBubble<'a>>) -> Self {
+        Self {
+            messages,
+            today: None,
+            group_window: 5,
+            label: Cow::Borrowed("Messages"),
+            custom_class: None,
+        }
+    }
+
+    pub fn message(mut self, message: MessageBubble<'a>) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    pub fn today(mut self, today: CalendarDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    pub fn group_window(mut self, minutes: u32) -> Self {
+        self.group_window = minutes;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn continues(&self, previous: &MessageBubble<'a>, next: &MessageBubble<'a>) -> bool {
+        if previous.sender != next.sender || previous.direction != next.direction {
+            return false;
+        }
+        let when = |m: &MessageBubble<'a>| m.timestamp.as_deref().and_then(parse_timestamp);
+        match (when(previous), when(next)) {
+            (Some((d1, t1)), Some((d2, t2))) => {
+                d1 == d2
+                    && match (t1, t2) {
+                        (Some(t1), Some(t2)) => t2.abs_diff(t1) <= self.group_window,
+                        _ => true,
+                    }
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn positions(&self) -> Vec<GroupPosition> {
+        let n = self.messages.len();
+        (0..n)
+            .map(|i| {
+                let after = i > 0 && self.continues(&self.messages[i - 1], &self.messages[i]);
+                let before = i + 1 < n && self.continues(&self.messages[i], &self.messages[i + 1]);
+                match (after, before) {
+                    (false, false) => GroupPosition::Single,
+                    (false, true) => GroupPosition::First,
+                    (true, true) => GroupPosition::Middle,
+                    (true, false) => GroupPosition::Last,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a> Render for MessageList<'a> {
+    fn render(&self) -> Markup {
+        let class = match &self.custom_class {
+            Some(custom) => format!("sh-chat-list {}", custom),
+            None => "sh-chat-list".to_string(),
+        };
+        let mut day = None;
+
+        html! {
+            div class=(class) role="log" aria-live="polite" aria-label=(self.label) {
+                @for (message, position) in self.messages.iter().zip(self.positions()) {
+                    @let date = message
+                        .timestamp
+                        .as_deref()
+                        .and_then(parse_timestamp)
+                        .map(|(date, _)| date);
+                    @if let Some(date) = date.filter(|d| day != Some(*d)) {
+                        @let _ = day.replace(date);
+                        div class="sh-chat-list__separator" role="separator" {
+                            span { (day_label(date, self.today)) }
+                        }
+                    }
+                    (message.clone().position(position))
+                }
+            }
+        }
+    }
+}
+
+/// Message composer posting to a server endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatInput<'a> {
+    pub action: Cow<'a, str>,
+    pub id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub label: Cow<'a, str>,
+    pub placeholder: Cow<'a, str>,
+    pub send_label: Cow<'a, str>,
+    pub max_length: Option<u32>,
+    /// Adds a file picker and switches to multipart encoding
+    pub attachments: bool,
+    pub disabled: bool,
+    pub hidden: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> ChatInput<'a> {
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            action: action.into(),
+            id: Cow::Borrowed("sh-chat-input"),
+            name: Cow::Borrowed("message"),
+            label: Cow::Borrowed("Message"),
+            placeholder: Cow::Borrowed("Type a message…"),
+            send_label: Cow::Borrowed("Send"),
+            max_length: None,
+            attachments: false,
+            disabled: false,
+            hidden: Vec::new(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Form field name of the message text
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'a, str>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn send_label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.send_label = label.into();
+        self
+    }
+
+    pub fn max_length(mut self, max: u32) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    pub fn attachments(mut self, attachments: bool) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Extra hidden field, e.g. a thread id or CSRF token
+    pub fn hidden(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        self.hidden.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl<'a> Render for ChatInput<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            form
+                class="sh-chat-input"
+                action=(self.action)
+                method="post"
+                enctype=[self.attachments.then_some("multipart/form-data")]
+            {
+                @for (name, value) in &self.hidden {
+                    input type="hidden" name=(name) value=(value);
+                }
+                label class="sh-visually-hidden" for=(self.id) { (self.label) }
+                @if self.attachments {
+                    label class="sh-chat-input__attach" title="Attach a file" {
+                        input class="sh-visually-hidden" type="file" name="attachment" disabled[self.disabled];
+                        svg viewBox="0 0 24 24" width="20" height="20" aria-hidden="true" {
+                            path
+                                d="M21 11.5l-8.6 8.6a5 5 0 01-7.1-7.1l8.6-8.6a3.5 3.5 0 015 5l-8.6 8.6a2 2 0 01-2.8-2.8l7.9-7.9"
+                                fill="none"
+                                stroke="currentColor"
+                                stroke-width="2"
+                                stroke-linecap="round"
+                            {}
+                        }
+                        span class="sh-visually-hidden" { "Attach a file" }
+                    }
+                }
+                textarea
+                    class="sh-chat-input__field"
+                    id=(self.id)
+                    name=(self.name)
+                    rows="1"
+                    placeholder=(self.placeholder)
+                    maxlength=[self.max_length]
+                    required
+                    disabled[self.disabled]
+                {}
+                button class="sh-chat-input__send" type="submit" disabled[self.disabled] {
+                    svg viewBox="0 0 24 24" width="20" height="20" aria-hidden="true" {
+                        path d="M3 20.5l18-8.5L3 3.5l2.5 8.5L3 20.5zM5.5 12H13" fill="none" stroke="currentColor" stroke-width="2" stroke-linejoin="round" {}
+                    }
+                    span class="sh-visually-hidden" { (self.send_label) }
+                }
+            }
+        }
+    }
+}
+
+pub fn chat_css() -> String {
+    r#"
+.sh-chat-list {
+    display: flex;
+    flex-direction: column;
+    gap: 2px;
+    padding: 1rem;
+    overflow-y: auto;
+    overscroll-behavior: contain;
+}
+
+.sh-chat-list__separator {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    margin: 1rem 0 0.5rem;
+    font-size: 0.75rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-chat-list__separator::before,
+.sh-chat-list__separator::after {
+    content: '';
+    flex: 1;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-chat-message {
+    display: flex;
+    align-items: flex-end;
+    gap: 0.5rem;
+    max-width: min(80%, 36rem);
+}
+
+.sh-chat-message--single,
+.sh-chat-message--first {
+    margin-top: 0.5rem;
+}
+
+.sh-chat-message--outgoing {
+    align-self: flex-end;
+    flex-direction: row-reverse;
+}
+
+.sh-chat-message__avatar {
+    flex: 0 0 2rem;
+    width: 2rem;
+}
+
+.sh-chat-message__content {
+    display: flex;
+    flex-direction: column;
+    min-width: 0;
+}
+
+.sh-chat-message--outgoing .sh-chat-message__content {
+    align-items: flex-end;
+}
+
+.sh-chat-message__sender {
+    margin: 0 0.75rem 0.125rem;
+    font-size: 0.75rem;
+    font-weight: 600;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-chat-message__bubble {
+    padding: 0.5rem 0.875rem;
+    border-radius: 1.125rem;
+    background: var(--sh-surface-alt, #f3f4f6);
+    color: var(--sh-text, #111827);
+    line-height: 1.4;
+    white-space: pre-wrap;
+    overflow-wrap: anywhere;
+}
+
+.sh-chat-message--outgoing .sh-chat-message__bubble {
+    background: var(--sh-primary, #8b5cf6);
+    color: var(--sh-on-primary, #ffffff);
+}
+
+.sh-chat-message--incoming.sh-chat-message--first .sh-chat-message__bubble,
+.sh-chat-message--incoming.sh-chat-message--middle .sh-chat-message__bubble {
+    border-bottom-left-radius: 0.375rem;
+}
+
+.sh-chat-message--incoming.sh-chat-message--middle .sh-chat-message__bubble,
+.sh-chat-message--incoming.sh-chat-message--last .sh-chat-message__bubble {
+    border-top-left-radius: 0.375rem;
+}
+
+.sh-chat-message--outgoing.sh-chat-message--first .sh-chat-message__bubble,
+.sh-chat-message--outgoing.sh-chat-message--middle .sh-chat-message__bubble {
+    border-bottom-right-radius: 0.375rem;
+}
+
+.sh-chat-message--outgoing.sh-chat-message--middle .sh-chat-message__bubble,
+.sh-chat-message--outgoing.sh-chat-message--last .sh-chat-message__bubble {
+    border-top-right-radius: 0.375rem;
+}
+
+.sh-chat-message__meta {
+    display: flex;
+    gap: 0.25rem;
+    margin: 0.125rem 0.75rem 0;
+    font-size: 0.6875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-chat-message__receipt--read {
+    color: var(--sh-primary, #8b5cf6);
+}
+
+.sh-chat-input {
+    display: flex;
+    align-items: flex-end;
+    gap: 0.5rem;
+    padding: 0.5rem;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+    background: var(--sh-surface, #ffffff);
+}
+
+.sh-chat-input__field {
+    flex: 1;
+    min-height: 2.5rem;
+    max-height: 10rem;
+    padding: 0.5rem 0.875rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: 1.25rem;
+    background: var(--sh-surface, #ffffff);
+    color: var(--sh-text, #111827);
+    font: inherit;
+    resize: none;
+    field-sizing: content;
+}
+
+.sh-chat-input__field:focus-visible {
+    outline: 2px solid var(--sh-primary, #8b5cf6);
+    outline-offset: 1px;
+}
+
+.sh-chat-input__attach,
+.sh-chat-input__send {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    flex: 0 0 2.5rem;
+    height: 2.5rem;
+    border: 0;
+    border-radius: 50%;
+    cursor: pointer;
+}
+
+.sh-chat-input__attach {
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-chat-input__attach:focus-within {
+    outline: 2px solid var(--sh-primary, #8b5cf6);
+}
+
+.sh-chat-input__send {
+    background: var(--sh-primary, #8b5cf6);
+    color: var(--sh-on-primary, #ffffff);
+}
+
+.sh-chat-input__send:disabled {
+    opacity: 0.5;
+    cursor: not-allowed;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread() -> MessageList<'static> {
+        MessageList::new(vec![
+            MessageBubble::new("Ana", "Hi!").timestamp("2024-03-12T18:00:00Z"),
+            MessageBubble::new("Ana", "Are you there?")
+                .timestamp("2024-03-13T09:00:00Z")
+                .avatar(Avatar::new("Ana Lopez")),
+            MessageBubble::new("Ana", "Order #42 is late")
+                .timestamp("2024-03-13T09:03:00Z")
+                .avatar(Avatar::new("Ana Lopez")),
+            MessageBubble::new("Ana", "Hello?")
+                .timestamp("2024-03-13T09:04:00Z")
+                .avatar(Avatar::new("Ana Lopez")),
+            MessageBubble::new("Support", "Looking into it")
+                .outgoing()
+                .timestamp("2024-03-13T09:30:00Z")
+                .receipt(ReceiptStatus::Read),
+        ])
+        .today(CalendarDate::new(2024, 3, 13))
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(
+            parse_timestamp("2024-03-13T09:05:00Z"),
+            Some((CalendarDate::new(2024, 3, 13), Some(545)))
+        );
+        assert_eq!(
+            parse_timestamp("2024-03-13"),
+            Some((CalendarDate::new(2024, 3, 13), None))
+        );
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn test_grouping_positions() {
+        use GroupPosition::*;
+        assert_eq!(
+            thread().positions(),
+            vec![Single, First, Middle, Last, Single]
+        );
+        // A wider window doesn't join messages across days
+        assert_eq!(
+            thread().group_window(60 * 24).positions(),
+            vec![Single, First, Middle, Last, Single]
+        );
+        assert_eq!(
+            thread().group_window(2).positions(),
+            vec![Single, Single, First, Last, Single]
+        );
+    }
+
+    #[test]
+    fn test_day_labels() {
+        let today = Some(CalendarDate::new(2024, 3, 13));
+        assert_eq!(day_label(CalendarDate::new(2024, 3, 13), today), "Today");
+        assert_eq!(
+            day_label(CalendarDate::new(2024, 3, 12), today),
+            "Yesterday"
+        );
+        assert_eq!(
+            day_label(CalendarDate::new(2024, 3, 1), today),
+            "Mar 1, 2024"
+        );
+        assert_eq!(
+            day_label(CalendarDate::new(2024, 3, 13), None),
+            "Mar 13, 2024"
+        );
+    }
+
+    #[test]
+    fn test_message_list_render() {
+        let html = thread().render().into_string();
+
+        assert!(html.starts_with(
+            r#"<div class="sh-chat-list" role="log" aria-live="polite" aria-label="Messages">"#
+        ));
+        assert_eq!(
+            html.matches(r#"class="sh-chat-list__separator""#).count(),
+            2
+        );
+        assert!(html.contains("<span>Yesterday</span>"));
+        assert!(html.contains("<span>Today</span>"));
+        assert!(html.contains("sh-chat-message sh-chat-message--incoming sh-chat-message--middle"));
+        // Only the first bubble of a run names the sender visibly
+        assert_eq!(
+            html.matches(r#"<div class="sh-chat-message__sender">Ana</div>"#)
+                .count(),
+            2
+        );
+        assert!(html.contains(r#"<span class="sh-visually-hidden">Ana: </span>"#));
+        // The avatar sits on the last bubble; earlier ones keep a spacer
+        assert_eq!(
+            html.matches(r#"role="img" aria-label="Ana Lopez""#).count(),
+            1
+        );
+        assert_eq!(
+            html.matches(r#"<div class="sh-chat-message__avatar" aria-hidden="true"></div>"#)
+                .count(),
+            2
+        );
+        assert!(html.contains(r#"<time datetime="2024-03-13T09:04:00Z">09:04</time>"#));
+        assert!(!html.contains(">09:03</time>"));
+    }
+
+    #[test]
+    fn test_bubble_receipt() {
+        let html = MessageBubble::new("Me", "Sent!")
+            .outgoing()
+            .id("m-7")
+            .receipt(ReceiptStatus::Delivered)
+            .render()
+            .into_string();
+
+        assert!(html.starts_with(
+            r#"<div class="sh-chat-message sh-chat-message--outgoing sh-chat-message--single" id="m-7">"#
+        ));
+        assert!(html.contains(
+            r#"class="sh-chat-message__receipt sh-chat-message__receipt--delivered" title="Delivered""#
+        ));
+        assert!(html.contains("✓✓"));
+        assert!(!html.contains("sh-chat-message__sender"));
+        assert!(!html.contains("<time"));
+    }
+
+    #[test]
+    fn test_chat_input() {
+        let html = ChatInput::new("/support/42/messages")
+            .hidden("thread", "42")
+            .max_length(2000)
+            .attachments(true)
+            .render()
+            .into_string();
+
+        assert!(html.starts_with(
+            r#"<form class="sh-chat-input" action="/support/42/messages" method="post" enctype="multipart/form-data">"#
+        ));
+        assert!(html.contains(r#"<input type="hidden" name="thread" value="42">"#));
+        assert!(html
+            .contains(r#"<label class="sh-visually-hidden" for="sh-chat-input">Message</label>"#));
+        assert!(html.contains(
+            r#"name="message" rows="1" placeholder="Type a message…" maxlength="2000" required>"#
+        ));
+        assert!(html.contains(r#"type="file" name="attachment""#));
+        assert!(html.contains(r#"<span class="sh-visually-hidden">Send</span>"#));
+
+        let plain = ChatInput::new("/m").disabled(true).render().into_string();
+        assert!(!plain.contains("enctype"));
+        assert!(plain.contains("required disabled"));
+    }
+
+    #[test]
+    fn test_chat_css() {
+        let css = chat_css();
+        assert!(css.contains(".sh-chat-message--outgoing"));
+        assert!(css.contains(".sh-chat-input__send"));
+    }
+}
@@ -0,0 +1,1235 @@
+//! Checkout Components - Server-posted cart, shipping, summary and payment
+//!
+//! Builds on [`CartItem`] from the product card module:
+//! - [`Order`]: the typed model. It holds line items, the chosen shipping
+//!   method, discounts and tax rates, and computes the totals.
+//! - [`CartTable`]: one small form per row for quantity updates and removal,
+//!   read back with [`CartUpdate::from_form`].
+//! - [`ShippingOptions`]: a radio group of [`ShippingMethod`]s with prices.
+//! - [`OrderSummary`]: subtotal, discount, shipping, tax and total lines.
+//! - [`PaymentSection`]: a slot for the payment provider's fields.
+//! - [`Checkout`]: composes the above into one form next to the summary.
+//!
+//! Every interaction is a plain form post, so no JavaScript is needed.
+//!
+//! # Example
+//! ```
+//! use shallot_components::checkout::{Checkout, Order, ShippingMethod};
+//! use shallot_components::product_card::CartItem;
+//! use maud::Render;
+//!
+//! let methods = [ShippingMethod::new("standard", "Standard", 4.99)];
+//! let order = Order::new(vec![CartItem::new("Widget", 2, 19.99).id("sku-1")])
+//!     .tax("VAT", 0.2)
+//!     .with_shipping(methods[0].clone());
+//! assert_eq!(order.total(), 52.97);
+//!
+//! let page = Checkout::new("/checkout", &order)
+//!     .shipping_methods(&methods)
+//!     .render();
+//! ```
+
+use crate::alert::{Alert, AlertKind};
+use crate::form::{Form, FormData, FormVariant};
+use crate::product_card::{CartItem, CartSummary};
+use maud::{html, Markup, Render};
+
+/// Field carrying the line item key in cart row forms
+pub const CART_ITEM_FIELD: &str = "item";
+/// Field carrying the new quantity in cart row forms
+pub const CART_QUANTITY_FIELD: &str = "quantity";
+/// Field set by the Update / Remove buttons of cart row forms
+pub const CART_ACTION_FIELD: &str = "cart_action";
+/// Field carrying the chosen shipping method id
+pub const SHIPPING_METHOD_FIELD: &str = "shipping_method";
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+fn format_money(currency: &str, amount: f64) -> String {
+    if amount < 0.0 {
+        format!("−{}{:.2}", currency, -amount)
+    } else {
+        format!("{}{:.2}", currency, amount)
+    }
+}
+
+/// A delivery option with its price
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippingMethod {
+    pub id: String,
+    pub label: String,
+    pub price: f64,
+    /// Delivery estimate, e.g. "3–5 business days"
+    pub estimate: Option<String>,
+}
+
+impl ShippingMethod {
+    pub fn new(id: impl Into<String>, label: impl Into<String>, price: f64) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            price,
+            estimate: None,
+        }
+    }
+
+    pub fn estimate(mut self, estimate: impl Into<String>) -> Self {
+        self.estimate = Some(estimate.into());
+        self
+    }
+}
+
+/// A fixed amount taken off the subtotal, e.g. a coupon
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discount {
+    pub label: String,
+    pub amount: f64,
+}
+
+/// A tax applied to the discounted subtotal
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxRate {
+    pub label: String,
+    /// Fraction, so 20% is `0.2`
+    pub rate: f64,
+}
+
+/// A change posted by a [`CartTable`] row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CartUpdate {
+    SetQuantity { item: String, quantity: u32 },
+    Remove { item: String },
+}
+
+impl CartUpdate {
+    /// Read a cart row submission; `None` if it is not one. A quantity
+    /// of zero counts as removal.
+    pub fn from_form(data: &FormData) -> Option<Self> {
+        let item = data.get(CART_ITEM_FIELD)?.to_string();
+        if data.get(CART_ACTION_FIELD) == Some("remove") {
+            return Some(CartUpdate::Remove { item });
+        }
+        let quantity = data.get(CART_QUANTITY_FIELD)?.trim().parse().ok()?;
+        Some(if quantity == 0 {
+            CartUpdate::Remove { item }
+        } else {
+            CartUpdate::SetQuantity { item, quantity }
+        })
+    }
+}
+
+/// Why an order could not be updated or placed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckoutError {
+    EmptyCart,
+    UnknownItem(String),
+    UnknownShippingMethod(String),
+    MissingShipping,
+}
+
+impl std::fmt::Display for CheckoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckoutError::EmptyCart => write!(f, "Your cart is empty"),
+            CheckoutError::UnknownItem(item) => write!(f, "unknown cart item: {}", item),
+            CheckoutError::UnknownShippingMethod(id) => {
+                write!(f, "unknown shipping method: {}", id)
+            }
+            CheckoutError::MissingShipping => write!(f, "Please choose a shipping method"),
+        }
+    }
+}
+
+impl std::error::Error for CheckoutError {}
+
+/// An order being checked out
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    pub items: Vec<CartItem>,
+    pub shipping: Option<ShippingMethod>,
+    pub discounts: Vec<Discount>,
+    pub taxes: Vec<TaxRate>,
+    /// Currency symbol placed before amounts
+    pub currency: String,
+}
+
+impl Order {
+    pub fn new(items: Vec<CartItem>) -> Self {
+        Self {
+            items,
+            shipping: None,
+            discounts: Vec::new(),
+            taxes: Vec::new(),
+            currency: "$".to_string(),
+        }
+    }
+
+    pub fn currency(mut self, symbol: impl Into<String>) -> Self {
+        self.currency = symbol.into();
+        self
+    }
+
+    pub fn with_shipping(mut self, method: ShippingMethod) -> Self {
+        self.shipping = Some(method);
+        self
+    }
+
+    pub fn discount(mut self, label: impl Into<String>, amount: f64) -> Self {
+        self.discounts.push(Discount {
+            label: label.into(),
+            amount,
+        });
+        self
+    }
+
+    pub fn tax(mut self, label: impl Into<String>, rate: f64) -> Self {
+        self.taxes.push(TaxRate {
+            label: label.into(),
+            rate,
+        });
+        self
+    }
+
+    /// Key posted for the item at `index`: its id, or the index itself
+    pub fn item_key(&self, index: usize) -> String {
+        self.items
+            .get(index)
+            .and_then(|item| item.id.clone())
+            .unwrap_or_else(|| index.to_string())
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        (0..self.items.len()).find(|index| self.item_key(*index) == key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Total number of units across all lines
+    pub fn item_count(&self) -> u32 {
+        self.items.iter().map(|item| item.quantity).sum()
+    }
+
+    pub fn subtotal(&self) -> f64 {
+        round_cents(self.items.iter().map(CartItem::total).sum())
+    }
+
+    /// Sum of discounts, never more than the subtotal
+    pub fn discount_total(&self) -> f64 {
+        let total: f64 = self.discounts.iter().map(|d| d.amount).sum();
+        round_cents(total.min(self.subtotal()))
+    }
+
+    pub fn shipping_total(&self) -> f64 {
+        self.shipping.as_ref().map_or(0.0, |method| method.price)
+    }
+
+    /// Each tax with its amount, rounded to cents
+    pub fn tax_lines(&self) -> Vec<(&str, f64)> {
+        let taxable = self.subtotal() - self.discount_total();
+        self.taxes
+            .iter()
+            .map(|tax| (tax.label.as_str(), round_cents(taxable * tax.rate)))
+            .collect()
+    }
+
+    pub fn tax_total(&self) -> f64 {
+        round_cents(self.tax_lines().iter().map(|(_, amount)| amount).sum())
+    }
+
+    pub fn total(&self) -> f64 {
+        round_cents(
+            self.subtotal() - self.discount_total() + self.shipping_total() + self.tax_total(),
+        )
+    }
+
+    /// Apply a change posted by a [`CartTable`] row
+    pub fn apply(&mut self, update: &CartUpdate) -> Result<(), CheckoutError> {
+        match update {
+            CartUpdate::SetQuantity { item, quantity } => {
+                let index = self
+                    .position(item)
+                    .ok_or_else(|| CheckoutError::UnknownItem(item.clone()))?;
+                self.items[index].quantity = *quantity;
+            }
+            CartUpdate::Remove { item } => {
+                let index = self
+                    .position(item)
+                    .ok_or_else(|| CheckoutError::UnknownItem(item.clone()))?;
+                self.items.remove(index);
+            }
+        }
+        Ok(())
+    }
+
+    /// Choose the shipping method `id` from the offered `methods`
+    pub fn select_shipping(
+        &mut self,
+        methods: &[ShippingMethod],
+        id: &str,
+    ) -> Result<(), CheckoutError> {
+        let method = methods
+            .iter()
+            .find(|method| method.id == id)
+            .ok_or_else(|| CheckoutError::UnknownShippingMethod(id.to_string()))?;
+        self.shipping = Some(method.clone());
+        Ok(())
+    }
+
+    /// Check the order can be placed
+    pub fn validate(&self) -> Result<(), CheckoutError> {
+        if self.is_empty() {
+            return Err(CheckoutError::EmptyCart);
+        }
+        if self.shipping.is_none() {
+            return Err(CheckoutError::MissingShipping);
+        }
+        Ok(())
+    }
+
+    /// The compact product card summary for this order
+    pub fn to_cart_summary(&self) -> CartSummary {
+        let mut summary = CartSummary::new(self.items.clone()).currency(self.currency.clone());
+        if !self.discounts.is_empty() {
+            summary = summary.discount(self.discount_total());
+        }
+        if let Some(method) = &self.shipping {
+            summary = summary.shipping(method.price);
+        }
+        if !self.taxes.is_empty() {
+            summary = summary.tax(self.tax_total());
+        }
+        summary
+    }
+}
+
+/// Cart lines with a quantity form per row
+pub struct CartTable<'a> {
+    order: &'a Order,
+    action: &'a str,
+    csrf: Option<&'a str>,
+    max_quantity: u32,
+    caption: &'a str,
+}
+
+impl<'a> CartTable<'a> {
+    /// Rows post [`CartUpdate`]s to `action`
+    pub fn new(order: &'a Order, action: &'a str) -> Self {
+        Self {
+            order,
+            action,
+            csrf: None,
+            max_quantity: 99,
+            caption: "Shopping cart",
+        }
+    }
+
+    pub fn csrf_token(mut self, token: &'a str) -> Self {
+        self.csrf = Some(token);
+        self
+    }
+
+    /// Largest quantity the input accepts (default: 99)
+    pub fn max_quantity(mut self, max: u32) -> Self {
+        self.max_quantity = max;
+        self
+    }
+
+    pub fn caption(mut self, caption: &'a str) -> Self {
+        self.caption = caption;
+        self
+    }
+
+    fn render_row_form(&self, key: &str, item: &CartItem) -> Markup {
+        let quantity_id = format!("sh-checkout-qty-{}", key);
+        let mut form = Form::new()
+            .action(self.action)
+            .method("post")
+            .variant(FormVariant::Inline)
+            .class("sh-checkout-cart__form")
+            .hidden(CART_ITEM_FIELD, key);
+        if let Some(token) = self.csrf {
+            form = form.csrf_token(token);
+        }
+        form.fields(html! {
+            label class="sh-visually-hidden" for=(quantity_id) {
+                "Quantity for " (item.name)
+            }
+            input
+                class="sh-checkout-cart__quantity"
+                type="number"
+                id=(quantity_id)
+                name=(CART_QUANTITY_FIELD)
+                value=(item.quantity)
+                min="1"
+                max=(self.max_quantity)
+                inputmode="numeric";
+            button class="sh-btn sh-btn--secondary sh-btn--sm" type="submit" name=(CART_ACTION_FIELD) value="update" {
+                "Update"
+            }
+            button class="sh-btn sh-btn--ghost sh-btn--sm" type="submit" name=(CART_ACTION_FIELD) value="remove" formnovalidate {
+                "Remove"
+                span class="sh-visually-hidden" { " " (item.name) }
+            }
+        })
+        .render()
+    }
+}
+
+impl<'a> Render for CartTable<'a> {
+    fn render(&self) -> Markup {
+        let currency = self.order.currency.as_str();
+        if self.order.is_empty() {
+            return html! {
+                p class="sh-checkout-cart__empty" { "Your cart is empty" }
+            };
+        }
+
+        html! {
+            table class="sh-checkout-cart" {
+                caption class="sh-checkout-cart__caption" { (self.caption) }
+                thead {
+                    tr {
+                        th scope="col" { "Item" }
+                        th scope="col" class="sh-checkout-cart__num" { "Price" }
+                        th scope="col" { "Quantity" }
+                        th scope="col" class="sh-checkout-cart__num" { "Total" }
+                    }
+                }
+                tbody {
+                    @for (index, item) in self.order.items.iter().enumerate() {
+                        @let key = self.order.item_key(index);
+                        tr class="sh-checkout-cart__row" {
+                            th scope="row" class="sh-checkout-cart__item" {
+                                @if let Some(src) = &item.image {
+                                    img class="sh-checkout-cart__image" src=(src) alt="" width="48" height="48";
+                                }
+                                span { (item.name) }
+                            }
+                            td class="sh-checkout-cart__num" data-label="Price" {
+                                (format_money(currency, item.price))
+                            }
+                            td data-label="Quantity" { (self.render_row_form(&key, item)) }
+                            td class="sh-checkout-cart__num" data-label="Total" {
+                                (format_money(currency, item.total()))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Radio group of shipping methods with prices
+pub struct ShippingOptions<'a> {
+    methods: &'a [ShippingMethod],
+    selected: Option<&'a str>,
+    currency: &'a str,
+    legend: &'a str,
+}
+
+impl<'a> ShippingOptions<'a> {
+    pub fn new(methods: &'a [ShippingMethod]) -> Self {
+        Self {
+            methods,
+            selected: None,
+            currency: "$",
+            legend: "Shipping method",
+        }
+    }
+
+    /// Check the method with this id (default: the first)
+    pub fn selected(mut self, id: &'a str) -> Self {
+        self.selected = Some(id);
+        self
+    }
+
+    pub fn currency(mut self, symbol: &'a str) -> Self {
+        self.currency = symbol;
+        self
+    }
+
+    pub fn legend(mut self, legend: &'a str) -> Self {
+        self.legend = legend;
+        self
+    }
+}
+
+impl<'a> Render for ShippingOptions<'a> {
+    fn render(&self) -> Markup {
+        let selected = self
+            .selected
+            .filter(|id| self.methods.iter().any(|method| method.id == *id))
+            .or(self.methods.first().map(|method| method.id.as_str()));
+
+        html! {
+            fieldset class="sh-checkout-shipping" {
+                legend class="sh-checkout__legend" { (self.legend) }
+                @for method in self.methods {
+                    @let id = format!("sh-shipping-{}", method.id);
+                    label class="sh-checkout-shipping__option" for=(id) {
+                        input
+                            class="sh-checkout-shipping__radio"
+                            type="radio"
+                            id=(id)
+                            name=(SHIPPING_METHOD_FIELD)
+                            value=(method.id)
+                            checked?[selected == Some(method.id.as_str())]
+                            required;
+                        span class="sh-checkout-shipping__text" {
+                            span class="sh-checkout-shipping__label" { (method.label) }
+                            @if let Some(estimate) = &method.estimate {
+                                span class="sh-checkout-shipping__estimate" { (estimate) }
+                            }
+                        }
+                        span class="sh-checkout-shipping__price" {
+                            @if method.price == 0.0 {
+                                "Free"
+                            } @else {
+                                (format_money(self.currency, method.price))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Totals for an [`Order`]
+pub struct OrderSummary<'a> {
+    order: &'a Order,
+    title: &'a str,
+    show_items: bool,
+}
+
+impl<'a> OrderSummary<'a> {
+    pub fn new(order: &'a Order) -> Self {
+        Self {
+            order,
+            title: "Order summary",
+            show_items: true,
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// List the line items above the totals (default: true)
+    pub fn show_items(mut self, show: bool) -> Self {
+        self.show_items = show;
+        self
+    }
+}
+
+impl<'a> Render for OrderSummary<'a> {
+    fn render(&self) -> Markup {
+        let order = self.order;
+        let currency = order.currency.as_str();
+
+        html! {
+            section class="sh-checkout-summary" aria-label=(self.title) {
+                h2 class="sh-checkout-summary__title" { (self.title) }
+                @if self.show_items && !order.is_empty() {
+                    ul class="sh-checkout-summary__items" {
+                        @for item in &order.items {
+                            li class="sh-checkout-summary__item" {
+                                span { (item.name) " × " (item.quantity) }
+                                span { (format_money(currency, item.total())) }
+                            }
+                        }
+                    }
+                }
+                dl class="sh-checkout-summary__lines" {
+                    div class="sh-checkout-summary__line" {
+                        dt { "Subtotal" }
+                        dd { (format_money(currency, order.subtotal())) }
+                    }
+                    @for discount in &order.discounts {
+                        div class="sh-checkout-summary__line sh-checkout-summary__line--discount" {
+                            dt { (discount.label) }
+                            dd { (format_money(currency, -discount.amount)) }
+                        }
+                    }
+                    div class="sh-checkout-summary__line" {
+                        dt {
+                            "Shipping"
+                            @if let Some(method) = &order.shipping {
+                                span class="sh-checkout-summary__note" { " (" (method.label) ")" }
+                            }
+                        }
+                        dd {
+                            @match &order.shipping {
+                                Some(method) if method.price == 0.0 => "Free",
+                                Some(method) => (format_money(currency, method.price)),
+                                None => span class="sh-checkout-summary__note" { "Calculated at next step" },
+                            }
+                        }
+                    }
+                    @for (label, amount) in order.tax_lines() {
+                        div class="sh-checkout-summary__line" {
+                            dt { (label) }
+                            dd { (format_money(currency, amount)) }
+                        }
+                    }
+                    div class="sh-checkout-summary__line sh-checkout-summary__line--total" {
+                        dt { "Total" }
+                        dd { (format_money(currency, order.total())) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Slot for a payment provider's fields
+pub struct PaymentSection<'a> {
+    legend: &'a str,
+    description: Option<&'a str>,
+    content: Option<Markup>,
+}
+
+impl<'a> PaymentSection<'a> {
+    pub fn new() -> Self {
+        Self {
+            legend: "Payment",
+            description: None,
+            content: None,
+        }
+    }
+
+    pub fn legend(mut self, legend: &'a str) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Fields submitted with the checkout form, e.g. a `CreditCardInput`
+    pub fn content(mut self, content: Markup) -> Self {
+        self.content = Some(content);
+        self
+    }
+}
+
+impl<'a> Default for PaymentSection<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Render for PaymentSection<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            fieldset class="sh-checkout-payment" {
+                legend class="sh-checkout__legend" { (self.legend) }
+                @if let Some(description) = self.description {
+                    p class="sh-checkout-payment__description" { (description) }
+                }
+                div class="sh-checkout-payment__slot" {
+                    @if let Some(content) = &self.content {
+                        (content)
+                    } @else {
+                        p class="sh-checkout-payment__empty" { "No payment methods are available." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checkout page: address, shipping and payment in one form, with the
+/// order summary alongside
+pub struct Checkout<'a> {
+    action: &'a str,
+    order: &'a Order,
+    csrf: Option<&'a str>,
+    address: Option<Markup>,
+    shipping_methods: &'a [ShippingMethod],
+    payment: Option<PaymentSection<'a>>,
+    error: Option<&'a str>,
+    submit_label: &'a str,
+}
+
+impl<'a> Checkout<'a> {
+    pub fn new(action: &'a str, order: &'a Order) -> Self {
+        Self {
+            action,
+            order,
+            csrf: None,
+            address: None,
+            shipping_methods: &[],
+            payment: None,
+            error: None,
+            submit_label: "Place order",
+        }
+    }
+
+    pub fn csrf_token(mut self, token: &'a str) -> Self {
+        self.csrf = Some(token);
+        self
+    }
+
+    /// Address fields, typically an `AddressForm`
+    pub fn address(mut self, address: impl Render) -> Self {
+        self.address = Some(address.render());
+        self
+    }
+
+    pub fn shipping_methods(mut self, methods: &'a [ShippingMethod]) -> Self {
+        self.shipping_methods = methods;
+        self
+    }
+
+    pub fn payment(mut self, payment: PaymentSection<'a>) -> Self {
+        self.payment = Some(payment);
+        self
+    }
+
+    /// Error shown above the form, e.g. from [`Order::validate`]
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn submit_label(mut self, label: &'a str) -> Self {
+        self.submit_label = label;
+        self
+    }
+}
+
+impl<'a> Render for Checkout<'a> {
+    fn render(&self) -> Markup {
+        let order = self.order;
+        let mut shipping = ShippingOptions::new(self.shipping_methods).currency(&order.currency);
+        if let Some(method) = &order.shipping {
+            shipping = shipping.selected(&method.id);
+        }
+        let mut form = Form::new()
+            .action(self.action)
+            .method("post")
+            .class("sh-checkout__form");
+        if let Some(token) = self.csrf {
+            form = form.csrf_token(token);
+        }
+        let form = form
+            .fields(html! {
+                @if let Some(address) = &self.address {
+                    (address)
+                }
+                @if !self.shipping_methods.is_empty() {
+                    (shipping)
+                }
+                @if let Some(payment) = &self.payment {
+                    (payment)
+                }
+            })
+            .submit_button(html! {
+                button class="sh-btn sh-btn--primary sh-btn--md sh-checkout__submit" type="submit" disabled?[order.is_empty()] {
+                    (self.submit_label) " · " (format_money(&order.currency, order.total()))
+                }
+            });
+
+        html! {
+            div class="sh-checkout" {
+                div class="sh-checkout__main" {
+                    @if let Some(error) = self.error {
+                        (Alert::new(AlertKind::Error, "Unable to place order", error).render())
+                    }
+                    (form.render())
+                }
+                aside class="sh-checkout__aside" {
+                    (OrderSummary::new(order))
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for checkout components
+pub fn checkout_css() -> String {
+    r#"
+.sh-checkout {
+    display: grid;
+    grid-template-columns: minmax(0, 1fr) minmax(16rem, 22rem);
+    gap: 2rem;
+    align-items: start;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-checkout__form {
+    display: flex;
+    flex-direction: column;
+    gap: 1.5rem;
+}
+
+.sh-checkout__aside {
+    position: sticky;
+    top: 1rem;
+}
+
+.sh-checkout__legend {
+    margin-bottom: 0.75rem;
+    padding: 0;
+    font-size: 1rem;
+    font-weight: 600;
+}
+
+.sh-checkout__submit {
+    width: 100%;
+}
+
+/* Cart table */
+.sh-checkout-cart {
+    width: 100%;
+    border-collapse: collapse;
+    font-size: 0.9375rem;
+}
+
+.sh-checkout-cart__caption {
+    margin-bottom: 0.75rem;
+    font-size: 1.125rem;
+    font-weight: 600;
+    text-align: left;
+}
+
+.sh-checkout-cart th,
+.sh-checkout-cart td {
+    padding: 0.75rem 0.5rem;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    text-align: left;
+    vertical-align: middle;
+}
+
+.sh-checkout-cart thead th {
+    font-size: 0.8125rem;
+    font-weight: 500;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-checkout-cart .sh-checkout-cart__num {
+    text-align: right;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-checkout-cart__item {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    font-weight: 500;
+}
+
+.sh-checkout-cart__image {
+    width: 3rem;
+    height: 3rem;
+    object-fit: cover;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface-alt, #f3f4f6);
+}
+
+.sh-checkout-cart__form {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-checkout-cart__quantity {
+    width: 4rem;
+    height: 2rem;
+    padding: 0 0.5rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    color: inherit;
+}
+
+.sh-checkout-cart__empty {
+    padding: 2rem;
+    text-align: center;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+/* Shipping options */
+.sh-checkout-shipping {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0;
+    border: none;
+}
+
+.sh-checkout-shipping__option {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    padding: 0.875rem 1rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    cursor: pointer;
+    transition: border-color 0.2s ease;
+}
+
+.sh-checkout-shipping__option:has(:checked) {
+    border-color: var(--sh-primary, #3b82f6);
+    box-shadow: 0 0 0 1px var(--sh-primary, #3b82f6);
+}
+
+.sh-checkout-shipping__radio {
+    accent-color: var(--sh-primary, #3b82f6);
+}
+
+.sh-checkout-shipping__text {
+    display: flex;
+    flex: 1;
+    flex-direction: column;
+}
+
+.sh-checkout-shipping__label {
+    font-weight: 500;
+}
+
+.sh-checkout-shipping__estimate {
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-checkout-shipping__price {
+    font-weight: 500;
+    font-variant-numeric: tabular-nums;
+}
+
+/* Order summary */
+.sh-checkout-summary {
+    padding: 1.25rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface-alt, #f9fafb);
+}
+
+.sh-checkout-summary__title {
+    margin: 0 0 1rem;
+    font-size: 1.125rem;
+    font-weight: 600;
+}
+
+.sh-checkout-summary__items {
+    margin: 0 0 1rem;
+    padding: 0 0 1rem;
+    list-style: none;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-checkout-summary__item,
+.sh-checkout-summary__line {
+    display: flex;
+    justify-content: space-between;
+    gap: 1rem;
+    font-size: 0.875rem;
+}
+
+.sh-checkout-summary__item + .sh-checkout-summary__item {
+    margin-top: 0.5rem;
+}
+
+.sh-checkout-summary__lines {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+    margin: 0;
+}
+
+.sh-checkout-summary__lines dd {
+    margin: 0;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-checkout-summary__note {
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-checkout-summary__line--discount {
+    color: var(--sh-success, #10b981);
+}
+
+.sh-checkout-summary__line--total {
+    margin-top: 0.5rem;
+    padding-top: 0.75rem;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+    font-size: 1.0625rem;
+    font-weight: 600;
+}
+
+/* Payment */
+.sh-checkout-payment {
+    margin: 0;
+    padding: 0;
+    border: none;
+}
+
+.sh-checkout-payment__description,
+.sh-checkout-payment__empty {
+    margin: 0 0 0.75rem;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+@media (max-width: 768px) {
+    .sh-checkout {
+        grid-template-columns: 1fr;
+    }
+
+    .sh-checkout__aside {
+        position: static;
+        order: -1;
+    }
+
+    .sh-checkout-cart thead {
+        display: none;
+    }
+
+    .sh-checkout-cart tr {
+        display: grid;
+        grid-template-columns: 1fr auto;
+        border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    }
+
+    .sh-checkout-cart th,
+    .sh-checkout-cart td {
+        border-bottom: none;
+    }
+
+    .sh-checkout-cart td[data-label="Price"] {
+        display: none;
+    }
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-checkout-shipping__option {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn methods() -> Vec<ShippingMethod> {
+        vec![
+            ShippingMethod::new("standard", "Standard", 0.0).estimate("3–5 business days"),
+            ShippingMethod::new("express", "Express", 12.5),
+        ]
+    }
+
+    fn order() -> Order {
+        Order::new(vec![
+            CartItem::new("Widget", 2, 19.99).id("sku-1"),
+            CartItem::new("Gadget", 1, 5.0),
+        ])
+    }
+
+    #[test]
+    fn test_order_totals() {
+        let order = order()
+            .discount("WELCOME10", 10.0)
+            .tax("VAT", 0.2)
+            .with_shipping(methods()[1].clone());
+        assert_eq!(order.item_count(), 3);
+        assert_eq!(order.subtotal(), 44.98);
+        assert_eq!(order.discount_total(), 10.0);
+        assert_eq!(order.tax_lines(), vec![("VAT", 7.0)]);
+        assert_eq!(order.total(), 54.48);
+
+        // Discounts are capped at the subtotal
+        let free = Order::new(vec![CartItem::new("Sticker", 1, 2.0)]).discount("Gift", 5.0);
+        assert_eq!(free.discount_total(), 2.0);
+        assert_eq!(free.total(), 0.0);
+    }
+
+    #[test]
+    fn test_cart_update_from_form() {
+        let set = FormData::from_pairs([
+            ("item", "sku-1"),
+            ("quantity", "3"),
+            ("cart_action", "update"),
+        ]);
+        assert_eq!(
+            CartUpdate::from_form(&set),
+            Some(CartUpdate::SetQuantity {
+                item: "sku-1".to_string(),
+                quantity: 3
+            })
+        );
+        let remove =
+            FormData::from_pairs([("item", "1"), ("quantity", "3"), ("cart_action", "remove")]);
+        assert_eq!(
+            CartUpdate::from_form(&remove),
+            Some(CartUpdate::Remove {
+                item: "1".to_string()
+            })
+        );
+        let zero = FormData::from_pairs([("item", "1"), ("quantity", "0")]);
+        assert!(matches!(
+            CartUpdate::from_form(&zero),
+            Some(CartUpdate::Remove { .. })
+        ));
+        assert_eq!(
+            CartUpdate::from_form(&FormData::from_pairs([("quantity", "2")])),
+            None
+        );
+        assert_eq!(
+            CartUpdate::from_form(&FormData::from_pairs([("item", "1"), ("quantity", "x")])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_order_apply_and_shipping() {
+        let mut order = order();
+        order
+            .apply(&CartUpdate::SetQuantity {
+                item: "sku-1".to_string(),
+                quantity: 5,
+            })
+            .unwrap();
+        assert_eq!(order.items[0].quantity(), 5);
+        // Items without an id are keyed by position
+        order
+            .apply(&CartUpdate::Remove {
+                item: "1".to_string(),
+            })
+            .unwrap();
+        assert_eq!(order.items.len(), 1);
+        assert_eq!(
+            order.apply(&CartUpdate::Remove {
+                item: "nope".to_string()
+            }),
+            Err(CheckoutError::UnknownItem("nope".to_string()))
+        );
+
+        assert_eq!(order.validate(), Err(CheckoutError::MissingShipping));
+        assert_eq!(
+            order.select_shipping(&methods(), "drone"),
+            Err(CheckoutError::UnknownShippingMethod("drone".to_string()))
+        );
+        order.select_shipping(&methods(), "express").unwrap();
+        assert_eq!(order.shipping_total(), 12.5);
+        assert_eq!(order.validate(), Ok(()));
+        assert_eq!(
+            Order::new(Vec::new()).validate(),
+            Err(CheckoutError::EmptyCart)
+        );
+    }
+
+    #[test]
+    fn test_to_cart_summary() {
+        let order = order()
+            .discount("Sale", 4.98)
+            .with_shipping(methods()[1].clone());
+        let html = order.to_cart_summary().render().into_string();
+        assert!(html.contains("−$4.98"));
+        assert!(html.contains("$52.50"));
+    }
+
+    #[test]
+    fn test_cart_table() {
+        let order = order();
+        let html = CartTable::new(&order, "/cart")
+            .csrf_token("tok")
+            .render()
+            .into_string();
+        assert_eq!(html.matches(r#"action="/cart""#).count(), 2);
+        assert!(html.contains(r#"name="item" value="sku-1""#));
+        assert!(html.contains(r#"name="item" value="1""#));
+        assert!(html.contains(r#"name="csrf_token" value="tok""#));
+        assert!(html.contains(r#"name="quantity" value="2" min="1" max="99""#));
+        assert!(html.contains(r#"name="cart_action" value="remove" formnovalidate"#));
+        assert!(html.contains("$39.98"));
+
+        let empty = Order::new(Vec::new());
+        let html = CartTable::new(&empty, "/cart").render().into_string();
+        assert!(html.contains("Your cart is empty"));
+        assert!(!html.contains("<table"));
+    }
+
+    #[test]
+    fn test_shipping_options() {
+        let methods = methods();
+        let html = ShippingOptions::new(&methods).render().into_string();
+        assert!(html.contains(r#"value="standard" checked"#));
+        assert!(html.contains("Free"));
+        assert!(html.contains("3–5 business days"));
+        assert!(html.contains("$12.50"));
+
+        let html = ShippingOptions::new(&methods)
+            .selected("express")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"value="express" checked"#));
+        assert!(!html.contains(r#"value="standard" checked"#));
+    }
+
+    #[test]
+    fn test_order_summary() {
+        let order = order().discount("WELCOME10", 10.0).tax("VAT", 0.2);
+        let html = OrderSummary::new(&order).render().into_string();
+        assert!(html.contains("Widget × 2"));
+        assert!(html.contains("WELCOME10"));
+        assert!(html.contains("−$10.00"));
+        assert!(html.contains("Calculated at next step"));
+        assert!(html.contains("$41.98"));
+
+        let html = OrderSummary::new(&order)
+            .show_items(false)
+            .render()
+            .into_string();
+        assert!(!html.contains("sh-checkout-summary__items"));
+    }
+
+    #[test]
+    fn test_checkout() {
+        let methods = methods();
+        let order = order().with_shipping(methods[1].clone());
+        let html = Checkout::new("/checkout", &order)
+            .csrf_token("tok")
+            .address(html! { fieldset class="address" {} })
+            .shipping_methods(&methods)
+            .payment(PaymentSection::new().content(html! { input name="card"; }))
+            .error("Card declined")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"action="/checkout" method="post""#));
+        assert!(html.contains(r#"<fieldset class="address">"#));
+        assert!(html.contains(r#"value="express" checked"#));
+        assert!(html.contains(r#"name="card""#));
+        assert!(html.contains("Card declined"));
+        assert!(html.contains("Place order · $57.48"));
+        assert!(html.contains("sh-checkout__aside"));
+
+        let empty = Order::new(Vec::new());
+        let html = Checkout::new("/checkout", &empty).render().into_string();
+        assert!(html.contains("type=\"submit\" disabled"));
+        assert!(!html.contains("sh-checkout-shipping"));
+    }
+
+    #[test]
+    fn test_checkout_css() {
+        let css = checkout_css();
+        assert!(css.contains(".sh-checkout-cart__form"));
+        assert!(css.contains(".sh-checkout-shipping__option:has(:checked)"));
+    }
+}
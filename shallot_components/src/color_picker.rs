@@ -1,15 +1,73 @@
 //! Color Picker Component
 //!
 //! Color selection input with swatches and custom color input.
+//!
+//! Swatches are radio inputs named after the picker, so a plain form post
+//! submits the chosen color. With [`ColorPicker::allow_custom`] an "Other"
+//! radio reveals the native color input (named `{name}_custom`) through CSS
+//! alone; read the result back with [`ColorPicker::value_from_form`].
 
 use crate::component::ComponentSize;
+use crate::form::FormData;
 use maud::{html, Markup, Render};
+use shallot_foundation::color::{hex_to_rgb, rgb_to_hex};
+
+/// Radio value submitted when the "Other" option is chosen
+pub const COLOR_PICKER_CUSTOM_VALUE: &str = "other";
+
+/// Normalize `#rgb` / `#rrggbb` (with or without `#`) to lowercase `#rrggbb`
+pub fn normalize_hex_color(raw: &str) -> Option<String> {
+    let hex = raw.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let (r, g, b) = hex_to_rgb(hex)?;
+    Some(rgb_to_hex(r, g, b))
+}
+
+/// Named group of swatches, e.g. brand colors or grays
+#[derive(Debug, Clone)]
+pub struct ColorPalette<'a> {
+    pub label: &'a str,
+    pub colors: Vec<ColorSwatch<'a>>,
+}
+
+impl<'a> ColorPalette<'a> {
+    pub fn new(label: &'a str, colors: Vec<&'a str>) -> Self {
+        Self {
+            label,
+            colors: colors.into_iter().map(ColorSwatch::new).collect(),
+        }
+    }
+
+    /// Add a swatch with a readable name, announced instead of the hex value
+    pub fn swatch(mut self, value: &'a str, label: &'a str) -> Self {
+        self.colors.push(ColorSwatch::new(value).label(label));
+        self
+    }
+
+    /// Neutral ramp from white to black
+    pub fn grays() -> Self {
+        Self::new("Grays", Vec::new())
+            .swatch("#ffffff", "White")
+            .swatch("#f3f4f6", "Gray 100")
+            .swatch("#d1d5db", "Gray 300")
+            .swatch("#9ca3af", "Gray 400")
+            .swatch("#6b7280", "Gray 500")
+            .swatch("#374151", "Gray 700")
+            .swatch("#111827", "Gray 900")
+            .swatch("#000000", "Black")
+    }
+}
 
 pub struct ColorPicker<'a> {
     name: &'a str,
     value: &'a str,
     label: Option<&'a str>,
     swatches: Vec<&'a str>,
+    palettes: Vec<ColorPalette<'a>>,
+    recent: Vec<&'a str>,
+    allow_custom: bool,
     size: ComponentSize,
     show_input: bool,
     disabled: bool,
@@ -22,6 +80,9 @@ impl<'a> ColorPicker<'a> {
             value,
             label: None,
             swatches: Vec::new(),
+            palettes: Vec::new(),
+            recent: Vec::new(),
+            allow_custom: false,
             size: ComponentSize::Md,
             show_input: false,
             disabled: false,
@@ -33,16 +94,35 @@ impl<'a> ColorPicker<'a> {
         self
     }
 
+    /// Ungrouped swatches, shown before any palettes
     pub fn swatches(mut self, swatches: Vec<&'a str>) -> Self {
         self.swatches = swatches;
         self
     }
 
+    pub fn palette(mut self, palette: ColorPalette<'a>) -> Self {
+        self.palettes.push(palette);
+        self
+    }
+
+    /// Recently used colors, shown first under "Recent"
+    pub fn recent(mut self, colors: Vec<&'a str>) -> Self {
+        self.recent = colors;
+        self
+    }
+
+    /// Add an "Other" option revealing the native color input
+    pub fn allow_custom(mut self, allow: bool) -> Self {
+        self.allow_custom = allow;
+        self
+    }
+
     pub fn size(mut self, size: ComponentSize) -> Self {
         self.size = size;
         self
     }
 
+    /// Show a hex text field (named `{name}_hex`) next to the native input
     pub fn show_input(mut self, show: bool) -> Self {
         self.show_input = show;
         self
@@ -52,63 +132,188 @@ impl<'a> ColorPicker<'a> {
         self.disabled = disabled;
         self
     }
+
+    /// Resolve the color submitted by a picker named `name`.
+    ///
+    /// A swatch radio wins; for "Other" the hex field is preferred over the
+    /// native input. Returns a normalized `#rrggbb`, or `None` when nothing
+    /// valid was submitted.
+    pub fn value_from_form(form: &FormData, name: &str) -> Option<String> {
+        let choice = form.get(name)?;
+        if choice != COLOR_PICKER_CUSTOM_VALUE {
+            return normalize_hex_color(choice);
+        }
+        form.get(&format!("{}_hex", name))
+            .and_then(normalize_hex_color)
+            .or_else(|| {
+                form.get(&format!("{}_custom", name))
+                    .and_then(normalize_hex_color)
+            })
+    }
+
+    fn groups(&self) -> Vec<(Option<&'a str>, Vec<ColorSwatch<'a>>)> {
+        let mut groups = Vec::new();
+        if !self.recent.is_empty() {
+            groups.push((
+                Some("Recent"),
+                self.recent.iter().copied().map(ColorSwatch::new).collect(),
+            ));
+        }
+        if !self.swatches.is_empty() {
+            groups.push((
+                None,
+                self.swatches
+                    .iter()
+                    .copied()
+                    .map(ColorSwatch::new)
+                    .collect(),
+            ));
+        }
+        for palette in &self.palettes {
+            groups.push((Some(palette.label), palette.colors.clone()));
+        }
+        groups
+    }
+
+    fn native_value(&self) -> String {
+        normalize_hex_color(self.value).unwrap_or_else(|| "#000000".to_string())
+    }
+
+    fn render_native(&self, id: &str, name: &str) -> Markup {
+        html! {
+            div class="sh-color-picker__preview" {
+                input
+                    type="color"
+                    class="sh-color-picker__native"
+                    id=(id)
+                    name=(name)
+                    value=(self.native_value())
+                    disabled?[self.disabled]
+                    aria-label="Color value";
+
+                div class="sh-color-picker__swatch" style=(format!("background-color: {}", self.value)) aria-hidden="true" {}
+            }
+            @if self.show_input {
+                input
+                    type="text"
+                    class="sh-color-picker__input"
+                    name=(format!("{}_hex", self.name))
+                    value=(self.value)
+                    placeholder="#000000"
+                    pattern="#?([0-9a-fA-F]{3}|[0-9a-fA-F]{6})"
+                    disabled?[self.disabled]
+                    aria-label="Color hex value";
+            }
+        }
+    }
+
+    fn render_swatch(&self, id: &str, swatch: &ColorSwatch<'a>, checked: bool) -> Markup {
+        html! {
+            label class="sh-color-swatch" for=(id) title=(swatch.label.unwrap_or(swatch.value)) {
+                input
+                    type="radio"
+                    class="sh-color-swatch__input"
+                    id=(id)
+                    name=(self.name)
+                    value=(swatch.value)
+                    checked?[checked]
+                    disabled?[self.disabled];
+                span class="sh-color-swatch__chip" style=(format!("background-color: {}", swatch.value)) aria-hidden="true" {}
+                span class="sh-visually-hidden" { (swatch.label.unwrap_or(swatch.value)) }
+            }
+        }
+    }
+}
+
+fn same_color(a: &str, b: &str) -> bool {
+    match (normalize_hex_color(a), normalize_hex_color(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.eq_ignore_ascii_case(b),
+    }
 }
 
 impl<'a> Render for ColorPicker<'a> {
     fn render(&self) -> Markup {
         let input_id = format!("sh-color-{}", self.name);
         let size_class = format!("sh-color-picker--{}", self.size.class_suffix());
+        let groups = self.groups();
+
+        // Without swatches the native input carries the value directly
+        if groups.is_empty() {
+            return html! {
+                div
+                    class={(format!("sh-color-picker {}", size_class))}
+                    role="group"
+                    aria-label=(self.label.unwrap_or("Color picker"))
+                {
+                    @if let Some(label) = self.label {
+                        label class="sh-color-picker__label" for=(input_id) {
+                            (label)
+                        }
+                    }
+                    (self.render_native(&input_id, self.name))
+                }
+            };
+        }
+
+        // Only the first matching swatch is checked, so duplicates between
+        // recent colors and palettes don't fight over the selection
+        let selected = groups.iter().enumerate().find_map(|(g, (_, colors))| {
+            colors
+                .iter()
+                .position(|swatch| same_color(swatch.value, self.value))
+                .map(|i| (g, i))
+        });
+        let custom_checked = self.allow_custom && selected.is_none();
 
         html! {
-            div
-                class={(format!("sh-color-picker {}", size_class))}
-                role="group"
-                aria-label=(self.label.unwrap_or("Color picker"))
+            fieldset
+                class={(format!("sh-color-picker sh-color-picker--swatches {}", size_class))}
+                aria-label=[self.label.is_none().then_some("Color picker")]
+                disabled?[self.disabled]
             {
                 @if let Some(label) = self.label {
-                    label class="sh-color-picker__label" for=(input_id) {
-                        (label)
-                    }
+                    legend class="sh-color-picker__label" { (label) }
                 }
 
-                div class="sh-color-picker__preview" {
-                    input
-                        type="color"
-                        class="sh-color-picker__native"
-                        id=(input_id)
-                        name=(self.name)
-                        value=(self.value)
-                        disabled?[self.disabled]
-                        aria-label="Color value";
-
-                    div class="sh-color-picker__swatch" style=(format!("background-color: {}", self.value)) aria-hidden="true" {}
-                }
-
-                @if !self.swatches.is_empty() {
-                    div class="sh-color-picker__swatches" role="listbox" aria-label="Color swatches" {
-                        @for color in &self.swatches {
-                            @let is_selected = *color == self.value;
-                            button
-                                type="button"
-                                class={(format!("sh-color-swatch {}", if is_selected { "sh-color-swatch--selected" } else { "" }))}
-                                style=(format!("background-color: {}", color))
-                                data-color=(color)
-                                disabled?[self.disabled]
-                                aria-pressed=(if is_selected { "true" } else { "false" })
-                                aria-label=(format!("Select color {}", color))
-                            {}
+                @for (g, (group_label, colors)) in groups.iter().enumerate() {
+                    div class="sh-color-picker__palette" {
+                        @if let Some(group_label) = group_label {
+                            span class="sh-color-picker__palette-label" id=(format!("{}-group-{}", input_id, g)) {
+                                (group_label)
+                            }
+                        }
+                        div
+                            class="sh-color-picker__swatches"
+                            role="group"
+                            aria-labelledby=[group_label.map(|_| format!("{}-group-{}", input_id, g))]
+                        {
+                            @for (i, swatch) in colors.iter().enumerate() {
+                                (self.render_swatch(&format!("{}-{}-{}", input_id, g, i), swatch, selected == Some((g, i))))
+                            }
                         }
                     }
                 }
 
-                @if self.show_input {
-                    input
-                        type="text"
-                        class="sh-color-picker__input"
-                        value=(self.value)
-                        placeholder="#000000"
-                        disabled?[self.disabled]
-                        aria-label="Color hex value";
+                @if self.allow_custom {
+                    @let other_id = format!("{}-other", input_id);
+                    div class="sh-color-picker__other" {
+                        label class="sh-color-swatch sh-color-swatch--other" for=(other_id) {
+                            input
+                                type="radio"
+                                class="sh-color-swatch__input sh-color-picker__other-input"
+                                id=(other_id)
+                                name=(self.name)
+                                value=(COLOR_PICKER_CUSTOM_VALUE)
+                                checked?[custom_checked]
+                                disabled?[self.disabled];
+                            span class="sh-color-swatch__chip" aria-hidden="true" { "+" }
+                            span class="sh-color-swatch__text" { "Other" }
+                        }
+                        div class="sh-color-picker__custom" {
+                            (self.render_native(&format!("{}-custom", input_id), &format!("{}_custom", self.name)))
+                        }
+                    }
                 }
             }
         }
@@ -122,12 +327,33 @@ pub struct ColorSwatches<'a> {
     size: ComponentSize,
 }
 
+#[derive(Debug, Clone)]
 pub struct ColorSwatch<'a> {
     pub value: &'a str,
     pub label: Option<&'a str>,
     pub selected: bool,
 }
 
+impl<'a> ColorSwatch<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            label: None,
+            selected: false,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
 impl<'a> ColorSwatches<'a> {
     pub fn new(name: &'a str, colors: Vec<ColorSwatch<'a>>) -> Self {
         Self {
@@ -227,23 +453,101 @@ pub fn color_picker_css() -> String {
     gap: 0.375rem;
 }
 
+.sh-color-picker--swatches {
+    min-width: 0;
+    margin: 0;
+    padding: 0;
+    border: 0;
+}
+
+.sh-color-picker--swatches > .sh-color-picker__label {
+    padding: 0;
+    margin-bottom: 0.75rem;
+}
+
+.sh-color-picker__palette {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+}
+
+.sh-color-picker__palette-label {
+    font-size: 0.75rem;
+    font-weight: 500;
+    color: var(--sh-text-muted, #6b7280);
+}
+
 .sh-color-swatch {
+    position: relative;
+    display: inline-flex;
+    align-items: center;
+    gap: 0.5rem;
+    cursor: pointer;
+}
+
+.sh-color-swatch__input {
+    position: absolute;
+    opacity: 0;
+    width: 1px;
+    height: 1px;
+    margin: 0;
+}
+
+.sh-color-swatch__chip {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
     width: 1.5rem;
     height: 1.5rem;
-    padding: 0;
-    border: 2px solid transparent;
+    border: 1px solid color-mix(in srgb, var(--sh-text, #1f2937) 15%, transparent);
     border-radius: var(--sh-radius-sm, 0.25rem);
-    cursor: pointer;
-    transition: all 0.15s ease;
+    transition: transform 0.15s ease, box-shadow 0.15s ease;
 }
 
-.sh-color-swatch:hover {
+.sh-color-swatch:hover .sh-color-swatch__chip {
     transform: scale(1.1);
 }
 
-.sh-color-swatch--selected {
-    border-color: var(--sh-text, #1f2937);
-    box-shadow: 0 0 0 2px var(--sh-surface, #fff);
+.sh-color-swatch__input:checked + .sh-color-swatch__chip {
+    box-shadow: 0 0 0 2px var(--sh-surface, #fff), 0 0 0 4px var(--sh-text, #1f2937);
+}
+
+.sh-color-swatch__input:focus-visible + .sh-color-swatch__chip {
+    box-shadow: 0 0 0 2px var(--sh-surface, #fff), 0 0 0 4px var(--sh-accent, #3b82f6);
+}
+
+.sh-color-swatch__input:disabled + .sh-color-swatch__chip {
+    opacity: 0.5;
+    cursor: not-allowed;
+}
+
+.sh-color-swatch--other .sh-color-swatch__chip {
+    font-size: 1rem;
+    color: var(--sh-text-muted, #6b7280);
+    border-style: dashed;
+    background: var(--sh-surface, #fff);
+}
+
+.sh-color-swatch__text {
+    font-size: 0.875rem;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-color-picker__other {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.75rem;
+}
+
+.sh-color-picker__custom {
+    display: none;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-color-picker__other:has(.sh-color-picker__other-input:checked) .sh-color-picker__custom {
+    display: flex;
 }
 
 .sh-color-picker__input {
@@ -273,6 +577,16 @@ pub fn color_picker_css() -> String {
     height: 4rem;
 }
 
+.sh-color-picker--sm .sh-color-swatch__chip {
+    width: 1.25rem;
+    height: 1.25rem;
+}
+
+.sh-color-picker--lg .sh-color-swatch__chip {
+    width: 2rem;
+    height: 2rem;
+}
+
 /* Color swatches grid */
 .sh-color-swatches {
     display: grid;
@@ -366,4 +680,109 @@ mod tests {
         assert_eq!(swatches.columns, 4);
         assert_eq!(swatches.colors.len(), 2);
     }
+
+    #[test]
+    fn test_normalize_hex_color() {
+        assert_eq!(normalize_hex_color("#3B82F6"), Some("#3b82f6".to_string()));
+        assert_eq!(normalize_hex_color(" fff "), Some("#ffffff".to_string()));
+        assert_eq!(normalize_hex_color("#12345"), None);
+        assert_eq!(normalize_hex_color("red"), None);
+        assert_eq!(normalize_hex_color("#éa"), None);
+    }
+
+    #[test]
+    fn test_color_picker_swatch_radios() {
+        let html = ColorPicker::new("accent", "#10B981")
+            .label("Accent")
+            .recent(vec!["#10b981"])
+            .palette(ColorPalette::new("Brand", vec!["#3b82f6", "#10b981"]))
+            .palette(ColorPalette::grays())
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<legend class="sh-color-picker__label">Accent</legend>"#));
+        assert!(html.contains(r##"type="radio" class="sh-color-swatch__input" id="sh-color-accent-0-0" name="accent" value="#10b981" checked"##));
+        // The duplicate in the brand palette is not checked as well
+        assert_eq!(html.matches(" checked").count(), 1);
+        assert!(html.contains(">Recent</span>"));
+        assert!(html.contains(">Gray 500</span>"));
+        assert!(!html.contains(r#"type="color""#));
+        assert!(!html.contains("sh-color-picker__other"));
+    }
+
+    #[test]
+    fn test_color_picker_custom_option() {
+        let html = ColorPicker::new("accent", "#abc")
+            .swatches(vec!["#3b82f6"])
+            .allow_custom(true)
+            .show_input(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"value="other" checked"#));
+        assert!(html.contains(r##"name="accent_custom" value="#aabbcc""##));
+        assert!(html.contains(r#"name="accent_hex""#));
+        assert!(html.contains(r#"aria-label="Color picker""#));
+
+        let html = ColorPicker::new("accent", "#3b82f6")
+            .swatches(vec!["#3b82f6"])
+            .allow_custom(true)
+            .render()
+            .into_string();
+        assert!(!html.contains(r#"value="other" checked"#));
+    }
+
+    #[test]
+    fn test_color_picker_native_only() {
+        let html = ColorPicker::new("bg", "#ffffff")
+            .label("Background")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<label class="sh-color-picker__label" for="sh-color-bg">"#));
+        assert!(html.contains(
+            r#"type="color" class="sh-color-picker__native" id="sh-color-bg" name="bg""#
+        ));
+        assert!(!html.contains("radio"));
+    }
+
+    #[test]
+    fn test_value_from_form() {
+        let form = FormData::from_pairs([("accent", "#3B82F6")]);
+        assert_eq!(
+            ColorPicker::value_from_form(&form, "accent"),
+            Some("#3b82f6".to_string())
+        );
+
+        let form = FormData::from_pairs([
+            ("accent", "other"),
+            ("accent_custom", "#112233"),
+            ("accent_hex", "f00"),
+        ]);
+        assert_eq!(
+            ColorPicker::value_from_form(&form, "accent"),
+            Some("#ff0000".to_string())
+        );
+
+        let form = FormData::from_pairs([
+            ("accent", "other"),
+            ("accent_custom", "#112233"),
+            ("accent_hex", ""),
+        ]);
+        assert_eq!(
+            ColorPicker::value_from_form(&form, "accent"),
+            Some("#112233".to_string())
+        );
+
+        let form = FormData::from_pairs([("accent", "javascript:")]);
+        assert_eq!(ColorPicker::value_from_form(&form, "accent"), None);
+        assert_eq!(
+            ColorPicker::value_from_form(&FormData::default(), "accent"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_color_picker_css() {
+        let css = color_picker_css();
+        assert!(css.contains(".sh-color-swatch__input:checked + .sh-color-swatch__chip"));
+        assert!(css.contains(":has(.sh-color-picker__other-input:checked)"));
+    }
 }
@@ -0,0 +1,495 @@
+//! Comments Component - Nested discussion threads
+//!
+//! Renders a comment tree with depth-based indentation, replies that
+//! collapse through `<details>`, permalink anchors and vote counts. Each
+//! comment can carry an inline reply [`Form`] posting to a configurable
+//! action URL with the parent id in a hidden field, so threads work
+//! without client-side code.
+
+use crate::avatar::Avatar;
+use crate::form::{Form, FormVariant};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// One comment and its replies
+#[derive(Clone)]
+pub struct Comment<'a> {
+    pub id: Cow<'a, str>,
+    pub author: Cow<'a, str>,
+    pub body: Cow<'a, str>,
+    pub avatar: Option<Avatar<'a>>,
+    /// Machine-readable time for the `datetime` attribute
+    pub datetime: Option<Cow<'a, str>>,
+    /// Display text for the time, e.g. "3 hours ago"
+    pub time_label: Option<Cow<'a, str>>,
+    pub votes: Option<i64>,
+    pub replies: Vec<Comment<'a>>,
+}
+
+impl<'a> Comment<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        author: impl Into<Cow<'a, str>>,
+        body: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            author: author.into(),
+            body: body.into(),
+            avatar: None,
+            datetime: None,
+            time_label: None,
+            votes: None,
+            replies: Vec::new(),
+        }
+    }
+
+    pub fn avatar(mut self, avatar: Avatar<'a>) -> Self {
+        self.avatar = Some(avatar);
+        self
+    }
+
+    pub fn timestamp(
+        mut self,
+        datetime: impl Into<Cow<'a, str>>,
+        label: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.datetime = Some(datetime.into());
+        self.time_label = Some(label.into());
+        self
+    }
+
+    pub fn votes(mut self, votes: i64) -> Self {
+        self.votes = Some(votes);
+        self
+    }
+
+    pub fn reply(mut self, reply: Comment<'a>) -> Self {
+        self.replies.push(reply);
+        self
+    }
+
+    pub fn replies(mut self, replies: Vec<Comment<'a>>) -> Self {
+        self.replies = replies;
+        self
+    }
+
+    /// Replies at every depth below this comment
+    pub fn descendant_count(&self) -> usize {
+        self.replies
+            .iter()
+            .map(|reply| 1 + reply.descendant_count())
+            .sum()
+    }
+}
+
+/// Comment tree
+#[derive(Clone)]
+pub struct CommentThread<'a> {
+    pub comments: Vec<Comment<'a>>,
+    /// Where reply forms post; `None` hides them
+    pub reply_action: Option<Cow<'a, str>>,
+    /// Name of the hidden field carrying the parent comment id
+    pub parent_field: Cow<'a, str>,
+    /// Name of the reply textarea
+    pub body_field: Cow<'a, str>,
+    /// Depth after which replies stop indenting further
+    pub max_depth: usize,
+    /// Depth from which reply lists start collapsed
+    pub collapse_depth: Option<usize>,
+    /// Prefix for comment anchors, e.g. `comment-` gives `#comment-42`
+    pub anchor_prefix: Cow<'a, str>,
+    pub label: Cow<'a, str>,
+    pub custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> CommentThread<'a> {
+    pub fn new(comments: Vec<Comment<'a>>) -> Self {
+        Self {
+            comments,
+            reply_action: None,
+            parent_field: Cow::Borrowed("parent_id"),
+            body_field: Cow::Borrowed("body"),
+            max_depth: 6,
+            collapse_depth: None,
+            anchor_prefix: Cow::Borrowed("comment-"),
+            label: Cow::Borrowed("Comments"),
+            custom_class: None,
+        }
+    }
+
+    /// Show a reply form under each comment, posting to `action`
+    pub fn reply_action(mut self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.reply_action = Some(action.into());
+        self
+    }
+
+    pub fn parent_field(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.parent_field = name.into();
+        self
+    }
+
+    pub fn body_field(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.body_field = name.into();
+        self
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth.max(1);
+        self
+    }
+
+    /// Collapse reply lists nested `depth` levels or deeper
+    pub fn collapse_depth(mut self, depth: usize) -> Self {
+        self.collapse_depth = Some(depth);
+        self
+    }
+
+    pub fn anchor_prefix(mut self, prefix: impl Into<Cow<'a, str>>) -> Self {
+        self.anchor_prefix = prefix.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn anchor(&self, comment: &Comment<'a>) -> String {
+        format!("{}{}", self.anchor_prefix, comment.id)
+    }
+
+    fn reply_form(&self, action: &str, comment: &Comment<'a>) -> Markup {
+        let field_id = format!("{}reply-{}", self.anchor_prefix, comment.id);
+        let aria_label = format!("Reply to {}", comment.author);
+        Form::new()
+            .action(action)
+            .method("post")
+            .variant(FormVariant::Compact)
+            .class("sh-comment__reply-form")
+            .aria_label(&aria_label)
+            .hidden(&self.parent_field, &comment.id)
+            .fields(html! {
+                label class="sh-visually-hidden" for=(field_id) { (aria_label) }
+                textarea
+                    class="sh-comment__reply-field"
+                    id=(field_id)
+                    name=(self.body_field)
+                    rows="3"
+                    required
+                {}
+            })
+            .submit_button(html! {
+                button class="sh-btn sh-btn--primary sh-btn--sm" type="submit" { "Post reply" }
+            })
+            .render()
+    }
+
+    fn comment(&self, comment: &Comment<'a>, depth: usize) -> Markup {
+        let anchor = self.anchor(comment);
+        let capped = depth + 1 >= self.max_depth;
+        let class = if capped {
+            "sh-comment sh-comment--capped"
+        } else {
+            "sh-comment"
+        };
+        let open = self
+            .collapse_depth
+            .is_none_or(|collapse| depth + 1 < collapse);
+        let reply_count = comment.descendant_count();
+
+        html! {
+            li class=(class) id=(anchor) data-depth=(depth) {
+                article class="sh-comment__main" aria-labelledby={ (anchor) "-author" } {
+                    header class="sh-comment__header" {
+                        @if let Some(avatar) = &comment.avatar {
+                            div class="sh-comment__avatar" { (avatar) }
+                        }
+                        span class="sh-comment__author" id={ (anchor) "-author" } { (comment.author) }
+                        a class="sh-comment__permalink" href={ "#" (anchor) } {
+                            @match (&comment.datetime, &comment.time_label) {
+                                (Some(datetime), Some(label)) => time datetime=(datetime) { (label) },
+                                _ => "Link",
+                            }
+                        }
+                    }
+                    div class="sh-comment__body" {
+                        @for paragraph in comment.body.split("\n\n").map(str::trim).filter(|p| !p.is_empty()) {
+                            p { (paragraph) }
+                        }
+                    }
+                    footer class="sh-comment__actions" {
+                        @if let Some(votes) = comment.votes {
+                            span class="sh-comment__votes" {
+                                (votes)
+                                span class="sh-visually-hidden" {
+                                    @if votes.abs() == 1 { " point" } @else { " points" }
+                                }
+                            }
+                        }
+                        @if let Some(action) = &self.reply_action {
+                            details class="sh-comment__reply" {
+                                summary class="sh-comment__reply-toggle" { "Reply" }
+                                (self.reply_form(action, comment))
+                            }
+                        }
+                    }
+                }
+                @if reply_count > 0 {
+                    details class="sh-comment__replies" open[open] {
+                        summary class="sh-comment__replies-toggle" {
+                            (reply_count) @if reply_count == 1 { " reply" } @else { " replies" }
+                        }
+                        ol class="sh-comments__list" role="list" {
+                            @for reply in &comment.replies {
+                                (self.comment(reply, depth + 1))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for CommentThread<'a> {
+    fn render(&self) -> Markup {
+        let class = match &self.custom_class {
+            Some(custom) => format!("sh-comments {}", custom),
+            None => "sh-comments".to_string(),
+        };
+        html! {
+            section class=(class) aria-label=(self.label) {
+                ol class="sh-comments__list" role="list" {
+                    @for comment in &self.comments {
+                        (self.comment(comment, 0))
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn comments_css() -> String {
+    r#"
+.sh-comments {
+    --sh-comment-indent: 1.5rem;
+    color: var(--sh-text, #111827);
+}
+
+.sh-comments__list {
+    list-style: none;
+    margin: 0;
+    padding: 0;
+}
+
+.sh-comment__replies > .sh-comments__list {
+    margin-inline-start: 0.75rem;
+    padding-inline-start: calc(var(--sh-comment-indent) - 0.75rem);
+    border-inline-start: 2px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-comment--capped > .sh-comment__replies > .sh-comments__list {
+    margin-inline-start: 0;
+    padding-inline-start: 0;
+    border-inline-start: 0;
+}
+
+.sh-comment:target > .sh-comment__main {
+    background: var(--sh-surface-alt, #f5f3ff);
+    box-shadow: 0 0 0 2px var(--sh-primary, #8b5cf6);
+}
+
+.sh-comment__main {
+    padding: 0.75rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    scroll-margin-top: 4rem;
+}
+
+.sh-comment__header {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    font-size: 0.875rem;
+}
+
+.sh-comment__author {
+    font-weight: 600;
+}
+
+.sh-comment__permalink {
+    color: var(--sh-text-muted, #6b7280);
+    text-decoration: none;
+}
+
+.sh-comment__permalink:hover,
+.sh-comment__permalink:focus-visible {
+    text-decoration: underline;
+}
+
+.sh-comment__body {
+    margin-top: 0.375rem;
+    line-height: 1.6;
+    overflow-wrap: anywhere;
+}
+
+.sh-comment__body p {
+    margin: 0 0 0.5rem;
+}
+
+.sh-comment__actions {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: baseline;
+    gap: 0.75rem;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-comment__votes {
+    font-weight: 600;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-comment__reply {
+    flex-basis: 100%;
+}
+
+.sh-comment__reply-toggle,
+.sh-comment__replies-toggle {
+    display: inline-block;
+    cursor: pointer;
+    font-weight: 500;
+    color: var(--sh-primary, #8b5cf6);
+    list-style: none;
+}
+
+.sh-comment__reply-toggle::-webkit-details-marker,
+.sh-comment__replies-toggle::-webkit-details-marker {
+    display: none;
+}
+
+.sh-comment__replies-toggle {
+    margin: 0 0 0.25rem 0.75rem;
+    font-size: 0.8125rem;
+}
+
+.sh-comment__replies-toggle::before {
+    content: '▸ ';
+}
+
+.sh-comment__replies[open] > .sh-comment__replies-toggle::before {
+    content: '▾ ';
+}
+
+.sh-comment__reply-form {
+    margin-top: 0.5rem;
+}
+
+.sh-comment__reply-field {
+    width: 100%;
+    padding: 0.5rem 0.75rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #ffffff);
+    color: inherit;
+    font: inherit;
+    resize: vertical;
+}
+
+.sh-comment__reply-field:focus-visible {
+    outline: 2px solid var(--sh-primary, #8b5cf6);
+    outline-offset: 1px;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread() -> CommentThread<'static> {
+        CommentThread::new(vec![Comment::new("1", "Ana", "First!\n\nSecond paragraph")
+            .timestamp("2024-03-13T09:00:00Z", "2 hours ago")
+            .votes(12)
+            .reply(
+                Comment::new("2", "Ben", "Reply")
+                    .votes(1)
+                    .reply(Comment::new("3", "Cy", "Deeper")),
+            )])
+    }
+
+    #[test]
+    fn test_descendant_count() {
+        assert_eq!(thread().comments[0].descendant_count(), 2);
+        assert_eq!(Comment::new("x", "X", "x").descendant_count(), 0);
+    }
+
+    #[test]
+    fn test_thread_render() {
+        let html = thread().render().into_string();
+
+        assert!(html.starts_with(r#"<section class="sh-comments" aria-label="Comments">"#));
+        assert!(html.contains(r#"<li class="sh-comment" id="comment-1" data-depth="0">"#));
+        assert!(html.contains(r#"id="comment-3" data-depth="2""#));
+        assert!(html.contains(
+            r##"<a class="sh-comment__permalink" href="#comment-1"><time datetime="2024-03-13T09:00:00Z">2 hours ago</time></a>"##
+        ));
+        assert!(html.contains(r##"href="#comment-2">Link</a>"##));
+        assert!(html.contains("<p>First!</p><p>Second paragraph</p>"));
+        assert!(html.contains(r#"12<span class="sh-visually-hidden"> points</span>"#));
+        assert!(html.contains(r#"1<span class="sh-visually-hidden"> point</span>"#));
+        assert!(html.contains(r#"<details class="sh-comment__replies" open><summary class="sh-comment__replies-toggle">2 replies</summary>"#));
+        assert!(html.contains(">1 reply</summary>"));
+        assert!(!html.contains("<form"));
+    }
+
+    #[test]
+    fn test_reply_forms() {
+        let html = thread()
+            .reply_action("/posts/7/comments")
+            .render()
+            .into_string();
+
+        assert_eq!(
+            html.matches(r#"<details class="sh-comment__reply">"#)
+                .count(),
+            3
+        );
+        assert!(html.contains(r#"action="/posts/7/comments" method="post""#));
+        assert!(html.contains(r#"<input type="hidden" name="parent_id" value="2">"#));
+        assert!(html.contains(r#"for="comment-reply-2">Reply to Ben</label>"#));
+        assert!(html.contains(r#"name="body" rows="3" required"#));
+        assert!(html.contains(
+            r#"<button class="sh-btn sh-btn--primary sh-btn--sm" type="submit">Post reply</button>"#
+        ));
+    }
+
+    #[test]
+    fn test_depth_cap_and_collapse() {
+        let html = thread()
+            .max_depth(2)
+            .collapse_depth(2)
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"<li class="sh-comment sh-comment--capped" id="comment-2""#));
+        assert!(html.contains(r#"<li class="sh-comment" id="comment-1""#));
+        // Top-level replies stay open, the nested list starts closed
+        assert!(html.contains(r#"<details class="sh-comment__replies" open><summary class="sh-comment__replies-toggle">2 replies"#));
+        assert!(html.contains(r#"<details class="sh-comment__replies"><summary class="sh-comment__replies-toggle">1 reply"#));
+    }
+
+    #[test]
+    fn test_comments_css() {
+        let css = comments_css();
+        assert!(css.contains(".sh-comment--capped"));
+        assert!(css.contains(".sh-comment:target"));
+    }
+}
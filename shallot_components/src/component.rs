@@ -5,9 +5,24 @@
 //! - Style variants (sizes, variants, colors)
 //! - Common builder patterns
 //! - ARIA attribute helpers
+//!
+//! # Text fields
+//!
+//! Component structs hold text as `Cow<'a, str>` and their constructors and
+//! setters accept `impl Into<Cow<'a, str>>`. String literals stay zero-copy,
+//! while text built at runtime can be passed as an owned `String` without
+//! having to outlive the component:
+//!
+//! ```
+//! use shallot_components::{Alert, AlertKind};
+//!
+//! let count = 3;
+//! let alert = Alert::new(AlertKind::Info, "Sync", format!("{} files updated", count));
+//! assert!(alert.render().into_string().contains("3 files updated"));
+//! ```
 
 use maud::Render;
-use shallot_foundation::Icon;
+use shallot_foundation::{Elevation, Icon};
 
 /// The core Component trait that all UI components should implement
 pub trait Component: Render {
@@ -313,6 +328,16 @@ impl AriaLive {
             AriaLive::Assertive => "assertive",
         }
     }
+
+    /// Live region role matching this politeness: `status` announces when
+    /// the reader is idle, `alert` interrupts
+    pub fn role(&self) -> Option<&'static str> {
+        match self {
+            AriaLive::Off => None,
+            AriaLive::Polite => Some("status"),
+            AriaLive::Assertive => Some("alert"),
+        }
+    }
 }
 
 /// ARIA relevance types
@@ -438,6 +463,52 @@ impl IconPosition {
     }
 }
 
+/// How floating overlays (popovers, dropdown menus, tooltips) are shown and placed
+///
+/// `Native` panels carry the HTML `popover` attribute and are placed with CSS
+/// anchor positioning, so they render in the top layer and escape
+/// `overflow: hidden` ancestors. Tooltips are hover driven and only take the
+/// anchor positioning half. Where `anchor-name` is unsupported the stylesheet
+/// falls back to the `Css` placement rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlayEngine {
+    /// `details`/`:hover` state with absolutely positioned panels
+    #[default]
+    Css,
+    /// Native `popover` attribute plus `anchor-name`/`position-anchor`
+    Native,
+}
+
+impl OverlayEngine {
+    /// Get the CSS class added to the overlay root, if any
+    pub fn class(&self) -> Option<&'static str> {
+        match self {
+            OverlayEngine::Css => None,
+            OverlayEngine::Native => Some("sh-overlay--native"),
+        }
+    }
+
+    /// Whether the native popover/anchor engine is selected
+    pub fn is_native(&self) -> bool {
+        matches!(self, OverlayEngine::Native)
+    }
+
+    /// Anchor name linking a trigger to its panel, derived from the panel id
+    pub fn anchor_name(id: &str) -> String {
+        let ident: String = id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("--sh-anchor-{}", ident)
+    }
+}
+
 /// Spacing scale for component gaps/padding
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Spacing {
@@ -477,7 +548,9 @@ impl Spacing {
 
 /// Generate base component CSS
 pub fn component_base_css() -> String {
-    r#"
+    let mut css = Elevation::css(":root");
+    css.push_str(
+        r#"
 /* Component Base Styles */
 .sh-component {
   position: relative;
@@ -530,8 +603,9 @@ pub fn component_base_css() -> String {
 .sh-component__icon--right { margin-left: 0.5rem; }
 .sh-component__icon--above { margin-bottom: 0.25rem; }
 .sh-component__icon--below { margin-top: 0.25rem; }
-"#
-    .to_string()
+"#,
+    );
+    css
 }
 
 #[cfg(test)]
@@ -562,6 +636,22 @@ mod tests {
         assert_eq!(ComponentShape::Circle.class_suffix(), "circle");
     }
 
+    #[test]
+    fn test_overlay_engine() {
+        assert_eq!(OverlayEngine::default(), OverlayEngine::Css);
+        assert_eq!(OverlayEngine::Css.class(), None);
+        assert_eq!(OverlayEngine::Native.class(), Some("sh-overlay--native"));
+        assert_eq!(OverlayEngine::anchor_name("menu 1"), "--sh-anchor-menu-1");
+    }
+
+    #[test]
+    fn test_base_css_defines_elevation_tokens() {
+        let css = component_base_css();
+        assert!(css.contains("--sh-elevation-0: none;"));
+        assert!(css.contains("--sh-elevation-5:"));
+        assert!(css.contains("prefers-color-scheme: dark"));
+    }
+
     #[test]
     fn test_aria_attrs_builder() {
         let attrs = AriaAttrs::new()
@@ -580,6 +670,9 @@ mod tests {
     fn test_aria_live() {
         assert_eq!(AriaLive::Polite.as_str(), "polite");
         assert_eq!(AriaLive::Assertive.as_str(), "assertive");
+        assert_eq!(AriaLive::Polite.role(), Some("status"));
+        assert_eq!(AriaLive::Assertive.role(), Some("alert"));
+        assert_eq!(AriaLive::Off.role(), None);
     }
 
     #[test]
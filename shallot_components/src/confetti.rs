@@ -1,3 +1,4 @@
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -127,7 +128,13 @@ impl Render for Confetti {
 }
 
 pub fn confetti_css() -> String {
-    r#"
+    confetti_css_for(MotionConfig::default())
+}
+
+/// Confetti CSS at the given motion level
+pub fn confetti_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-confetti {
     --sh-confetti-dur: 3s;
     position: fixed;
@@ -260,8 +267,19 @@ pub fn confetti_css() -> String {
         opacity: 0;
     }
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-confetti__piece {
+    animation: none;
+    opacity: 0;
+}
+"#,
+        off: r#"
+.sh-confetti {
+    display: none;
+}
+"#,
+    })
 }
 
 #[cfg(test)]
@@ -353,4 +371,16 @@ mod tests {
         assert!(css.contains(".sh-confetti"));
         assert!(css.contains("@keyframes confetti-fall"));
     }
+
+    #[test]
+    fn test_confetti_motion() {
+        assert!(confetti_css().contains("@media (prefers-reduced-motion: reduce)"));
+
+        let css = confetti_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes"));
+        assert!(css.contains("animation: none;"));
+
+        let css = confetti_css_for(MotionConfig::None);
+        assert!(css.contains(".sh-confetti {\n    display: none;"));
+    }
 }
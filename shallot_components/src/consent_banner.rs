@@ -0,0 +1,696 @@
+//! Consent Banner Component - Cookie consent with a plain form POST
+//!
+//! The banner is a form: "Accept all", "Reject non-essential" and "Save
+//! preferences" are submit buttons carrying a `consent_action` value, and
+//! the optional categories are checkboxes named `consent`. The handler turns
+//! the body into a [`ConsentState`] with [`ConsentState::from_form_pairs`],
+//! stores it with [`ConsentState::set_cookie`] and redirects back; on the
+//! next request [`ConsentState::from_cookie_header`] restores it and the
+//! banner stays dismissed.
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Cookie the consent state is stored under
+pub const CONSENT_COOKIE: &str = "sh_consent";
+
+/// Version prefix of the cookie value, bumped if the encoding changes
+const COOKIE_FORMAT: &str = "1";
+
+/// Cookie category a visitor can consent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsentCategory {
+    /// Session, security and load balancing; always on
+    Necessary,
+    Analytics,
+    Marketing,
+}
+
+impl ConsentCategory {
+    pub const ALL: [ConsentCategory; 3] = [
+        ConsentCategory::Necessary,
+        ConsentCategory::Analytics,
+        ConsentCategory::Marketing,
+    ];
+
+    /// Form and cookie value
+    pub fn key(&self) -> &'static str {
+        match self {
+            ConsentCategory::Necessary => "necessary",
+            ConsentCategory::Analytics => "analytics",
+            ConsentCategory::Marketing => "marketing",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.key() == key)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ConsentCategory::Necessary => "Strictly necessary",
+            ConsentCategory::Analytics => "Analytics",
+            ConsentCategory::Marketing => "Marketing",
+        }
+    }
+
+    fn default_description(&self) -> &'static str {
+        match self {
+            ConsentCategory::Necessary => {
+                "Required for sign-in, security and remembering this choice."
+            }
+            ConsentCategory::Analytics => "Help us understand how the site is used.",
+            ConsentCategory::Marketing => "Used to measure and personalise advertising.",
+        }
+    }
+}
+
+/// What the visitor agreed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConsentState {
+    pub analytics: bool,
+    pub marketing: bool,
+}
+
+impl ConsentState {
+    pub fn accept_all() -> Self {
+        Self {
+            analytics: true,
+            marketing: true,
+        }
+    }
+
+    /// Only strictly necessary cookies
+    pub fn reject_all() -> Self {
+        Self::default()
+    }
+
+    pub fn allows(&self, category: ConsentCategory) -> bool {
+        match category {
+            ConsentCategory::Necessary => true,
+            ConsentCategory::Analytics => self.analytics,
+            ConsentCategory::Marketing => self.marketing,
+        }
+    }
+
+    fn set(&mut self, category: ConsentCategory, allowed: bool) {
+        match category {
+            ConsentCategory::Necessary => {}
+            ConsentCategory::Analytics => self.analytics = allowed,
+            ConsentCategory::Marketing => self.marketing = allowed,
+        }
+    }
+
+    /// Encode as a cookie value, e.g. `1.analytics.marketing`
+    pub fn to_cookie_value(&self) -> String {
+        let mut value = COOKIE_FORMAT.to_string();
+        for category in ConsentCategory::ALL {
+            if category != ConsentCategory::Necessary && self.allows(category) {
+                value.push('.');
+                value.push_str(category.key());
+            }
+        }
+        value
+    }
+
+    /// Decode a value written by [`ConsentState::to_cookie_value`]; unknown
+    /// categories are ignored and an unknown format yields `None`
+    pub fn from_cookie_value(value: &str) -> Option<Self> {
+        let mut parts = value.trim().trim_matches('"').split('.');
+        if parts.next()? != COOKIE_FORMAT {
+            return None;
+        }
+        let mut state = Self::default();
+        for category in parts.filter_map(ConsentCategory::from_key) {
+            state.set(category, true);
+        }
+        Some(state)
+    }
+
+    /// Find and decode the consent cookie in a `Cookie` request header
+    pub fn from_cookie_header(header: &str) -> Option<Self> {
+        header.split(';').find_map(|pair| {
+            let (name, value) = pair.trim().split_once('=')?;
+            (name == CONSENT_COOKIE)
+                .then(|| Self::from_cookie_value(value))
+                .flatten()
+        })
+    }
+
+    /// `Set-Cookie` header value persisting this state
+    pub fn set_cookie(&self, max_age_days: u32, secure: bool) -> String {
+        format!(
+            "{}={}; Path=/; Max-Age={}; SameSite=Lax{}",
+            CONSENT_COOKIE,
+            self.to_cookie_value(),
+            u64::from(max_age_days) * 86_400,
+            if secure { "; Secure" } else { "" }
+        )
+    }
+
+    /// Build the state from submitted banner fields. `consent_action` picks
+    /// accept/reject; otherwise the checked `consent` boxes are used.
+    pub fn from_form_pairs<'f, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (&'f str, &'f str)>,
+    {
+        let mut action = None;
+        let mut chosen = Self::default();
+        for (name, value) in pairs {
+            match name {
+                "consent_action" => action = Some(value),
+                "consent" => {
+                    if let Some(category) = ConsentCategory::from_key(value) {
+                        chosen.set(category, true);
+                    }
+                }
+                _ => {}
+            }
+        }
+        match action {
+            Some("accept") => Self::accept_all(),
+            Some("reject") => Self::reject_all(),
+            _ => chosen,
+        }
+    }
+
+    /// [`ConsentState::from_form_pairs`] over a raw
+    /// `application/x-www-form-urlencoded` body
+    pub fn from_form_body(body: &str) -> Self {
+        Self::from_form_pairs(
+            body.split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(name, value)| (name.trim(), value.trim())),
+        )
+    }
+}
+
+/// Where the banner is pinned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConsentPosition {
+    /// Full-width bar along the bottom edge
+    #[default]
+    Bottom,
+    /// Full-width bar along the top edge
+    Top,
+    /// Compact card in the bottom corner
+    Corner,
+}
+
+impl ConsentPosition {
+    fn modifier(&self) -> &'static str {
+        match self {
+            ConsentPosition::Bottom => "bottom",
+            ConsentPosition::Top => "top",
+            ConsentPosition::Corner => "corner",
+        }
+    }
+}
+
+/// Cookie consent banner
+#[derive(Debug, Clone)]
+pub struct ConsentBanner<'a> {
+    pub id: Cow<'a, str>,
+    pub action: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub policy: Option<Markup>,
+    pub state: Option<ConsentState>,
+    pub reopen: bool,
+    pub position: ConsentPosition,
+    pub descriptions: Vec<(ConsentCategory, Cow<'a, str>)>,
+    pub return_to: Option<Cow<'a, str>>,
+    pub hidden: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> ConsentBanner<'a> {
+    /// `action` is the URL the preferences are posted to
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: Cow::Borrowed("sh-consent"),
+            action: action.into(),
+            title: Cow::Borrowed("We use cookies"),
+            policy: None,
+            state: None,
+            reopen: false,
+            position: ConsentPosition::default(),
+            descriptions: Vec::new(),
+            return_to: None,
+            hidden: Vec::new(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Policy text, typically a sentence with a link to the full policy
+    pub fn policy(mut self, policy: Markup) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Consent already on record; the banner renders nothing unless reopened
+    pub fn state(mut self, state: Option<ConsentState>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Show the banner even when consent is recorded, e.g. on a cookie
+    /// settings page, with the checkboxes reflecting the current state
+    pub fn reopen(mut self, reopen: bool) -> Self {
+        self.reopen = reopen;
+        self
+    }
+
+    pub fn position(mut self, position: ConsentPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Replace the default description of a category
+    pub fn describe(
+        mut self,
+        category: ConsentCategory,
+        description: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.descriptions.retain(|(c, _)| *c != category);
+        self.descriptions.push((category, description.into()));
+        self
+    }
+
+    /// Path the handler should redirect to after storing the choice
+    pub fn return_to(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.return_to = Some(path.into());
+        self
+    }
+
+    /// Extra hidden field, e.g. a CSRF token
+    pub fn hidden(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        self.hidden.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn is_dismissed(&self) -> bool {
+        self.state.is_some() && !self.reopen
+    }
+
+    fn description(&self, category: ConsentCategory) -> &str {
+        self.descriptions
+            .iter()
+            .find(|(c, _)| *c == category)
+            .map_or(category.default_description(), |(_, d)| d.as_ref())
+    }
+}
+
+impl<'a> Render for ConsentBanner<'a> {
+    fn render(&self) -> Markup {
+        if self.is_dismissed() {
+            return html! {};
+        }
+        let state = self.state.unwrap_or_default();
+        let title_id = format!("{}-title", self.id);
+
+        html! {
+            aside
+                class={ "sh-consent sh-consent--" (self.position.modifier()) }
+                id=(self.id)
+                aria-labelledby=(title_id) {
+                form class="sh-consent__form" method="post" action=(self.action) {
+                    @for (name, value) in &self.hidden {
+                        input type="hidden" name=(name) value=(value);
+                    }
+                    @if let Some(path) = &self.return_to {
+                        input type="hidden" name="return_to" value=(path);
+                    }
+                    div class="sh-consent__body" {
+                        h2 class="sh-consent__title" id=(title_id) { (self.title) }
+                        @if let Some(policy) = &self.policy {
+                            div class="sh-consent__policy" { (policy) }
+                        }
+                    }
+                    details class="sh-consent__preferences" open[self.reopen] {
+                        summary class="sh-consent__customize" { "Customize" }
+                        fieldset class="sh-consent__categories" {
+                            legend class="sh-visually-hidden" { "Cookie categories" }
+                            @for category in ConsentCategory::ALL {
+                                @let input_id = format!("{}-{}", self.id, category.key());
+                                @let necessary = category == ConsentCategory::Necessary;
+                                div class="sh-consent__category" {
+                                    input
+                                        class="sh-consent__checkbox"
+                                        type="checkbox"
+                                        id=(input_id)
+                                        name="consent"
+                                        value=(category.key())
+                                        checked[state.allows(category)]
+                                        disabled[necessary]
+                                        aria-describedby={ (input_id) "-desc" };
+                                    label class="sh-consent__label" for=(input_id) {
+                                        (category.label())
+                                        @if necessary {
+                                            span class="sh-consent__always" { "Always on" }
+                                        }
+                                    }
+                                    p class="sh-consent__description" id={ (input_id) "-desc" } {
+                                        (self.description(category))
+                                    }
+                                }
+                            }
+                        }
+                        button
+                            class="sh-btn sh-btn--secondary sh-btn--sm sh-consent__save"
+                            type="submit"
+                            name="consent_action"
+                            value="save" {
+                            "Save preferences"
+                        }
+                    }
+                    div class="sh-consent__actions" {
+                        button
+                            class="sh-btn sh-btn--ghost sh-btn--sm"
+                            type="submit"
+                            name="consent_action"
+                            value="reject" {
+                            "Reject non-essential"
+                        }
+                        button
+                            class="sh-btn sh-btn--primary sh-btn--sm"
+                            type="submit"
+                            name="consent_action"
+                            value="accept" {
+                            "Accept all"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the consent banner component
+pub fn consent_banner_css() -> String {
+    r#"
+.sh-consent {
+    position: fixed;
+    z-index: 60;
+    left: 0;
+    right: 0;
+    padding: 1rem 1.25rem;
+    background: var(--sh-surface, #ffffff);
+    color: var(--sh-text, #111827);
+    border-color: var(--sh-border, #e5e7eb);
+    box-shadow: 0 -4px 24px rgba(0, 0, 0, 0.08);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-consent--bottom {
+    bottom: 0;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-consent--top {
+    top: 0;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    box-shadow: 0 4px 24px rgba(0, 0, 0, 0.08);
+}
+
+.sh-consent--corner {
+    left: auto;
+    right: 1rem;
+    bottom: 1rem;
+    max-width: 26rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    box-shadow: 0 8px 32px rgba(0, 0, 0, 0.16);
+}
+
+.sh-consent__form {
+    display: grid;
+    grid-template-columns: 1fr auto;
+    grid-template-areas:
+        "body actions"
+        "prefs prefs";
+    gap: 0.75rem 1.5rem;
+    align-items: center;
+    max-width: 72rem;
+    margin: 0 auto;
+}
+
+.sh-consent--corner .sh-consent__form {
+    grid-template-columns: 1fr;
+    grid-template-areas:
+        "body"
+        "prefs"
+        "actions";
+}
+
+.sh-consent__body {
+    grid-area: body;
+}
+
+.sh-consent__title {
+    margin: 0 0 0.25rem;
+    font-size: 1rem;
+    font-weight: 600;
+}
+
+.sh-consent__policy {
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.875rem;
+    line-height: 1.5;
+}
+
+.sh-consent__policy p {
+    margin: 0;
+}
+
+.sh-consent__policy a {
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-consent__actions {
+    grid-area: actions;
+    display: flex;
+    flex-wrap: wrap;
+    justify-content: flex-end;
+    gap: 0.5rem;
+}
+
+.sh-consent__preferences {
+    grid-area: prefs;
+}
+
+.sh-consent__customize {
+    display: inline-block;
+    color: var(--sh-primary, #3b82f6);
+    font-size: 0.875rem;
+    font-weight: 500;
+    cursor: pointer;
+}
+
+.sh-consent__customize:focus-visible {
+    outline: 2px solid var(--sh-accent, #3b82f6);
+    outline-offset: 2px;
+}
+
+.sh-consent__categories {
+    display: grid;
+    grid-template-columns: repeat(auto-fit, minmax(14rem, 1fr));
+    gap: 0.75rem;
+    margin: 0.75rem 0;
+    padding: 0;
+    border: none;
+}
+
+.sh-consent__category {
+    display: grid;
+    grid-template-columns: auto 1fr;
+    gap: 0.125rem 0.5rem;
+    padding: 0.75rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    background: var(--sh-surface-alt, #f9fafb);
+}
+
+.sh-consent__checkbox {
+    width: 1rem;
+    height: 1rem;
+    margin: 0.125rem 0 0;
+    accent-color: var(--sh-primary, #3b82f6);
+}
+
+.sh-consent__label {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    font-size: 0.875rem;
+    font-weight: 600;
+}
+
+.sh-consent__always {
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.75rem;
+    font-weight: 400;
+}
+
+.sh-consent__description {
+    grid-column: 2;
+    margin: 0;
+    color: var(--sh-text-muted, #6b7280);
+    font-size: 0.8125rem;
+}
+
+@media (max-width: 640px) {
+    .sh-consent__form {
+        grid-template-columns: 1fr;
+        grid-template-areas:
+            "body"
+            "prefs"
+            "actions";
+    }
+
+    .sh-consent__actions {
+        justify-content: stretch;
+    }
+
+    .sh-consent__actions .sh-btn {
+        flex: 1;
+    }
+
+    .sh-consent--corner {
+        left: 1rem;
+        max-width: none;
+    }
+}
+
+@media print {
+    .sh-consent {
+        display: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookie_value_round_trip() {
+        let state = ConsentState {
+            analytics: true,
+            marketing: false,
+        };
+        assert_eq!(state.to_cookie_value(), "1.analytics");
+        assert_eq!(ConsentState::from_cookie_value("1.analytics"), Some(state));
+        assert_eq!(ConsentState::reject_all().to_cookie_value(), "1");
+        assert_eq!(
+            ConsentState::from_cookie_value("1.marketing.analytics.social"),
+            Some(ConsentState::accept_all())
+        );
+        assert_eq!(ConsentState::from_cookie_value("2.analytics"), None);
+    }
+
+    #[test]
+    fn test_cookie_header() {
+        let header = "theme=dark; sh_consent=1.marketing; session=abc";
+        let state = ConsentState::from_cookie_header(header).unwrap();
+        assert!(state.marketing && !state.analytics);
+        assert!(state.allows(ConsentCategory::Necessary));
+        assert_eq!(ConsentState::from_cookie_header("theme=dark"), None);
+    }
+
+    #[test]
+    fn test_set_cookie() {
+        let cookie = ConsentState::accept_all().set_cookie(180, true);
+        assert_eq!(
+            cookie,
+            "sh_consent=1.analytics.marketing; Path=/; Max-Age=15552000; SameSite=Lax; Secure"
+        );
+        assert!(!ConsentState::default()
+            .set_cookie(1, false)
+            .contains("Secure"));
+    }
+
+    #[test]
+    fn test_from_form() {
+        let saved = ConsentState::from_form_body("consent=analytics&consent_action=save");
+        assert_eq!(
+            saved,
+            ConsentState {
+                analytics: true,
+                marketing: false
+            }
+        );
+        let accepted = ConsentState::from_form_body("consent_action=accept");
+        assert_eq!(accepted, ConsentState::accept_all());
+        let rejected = ConsentState::from_form_pairs([
+            ("consent", "analytics"),
+            ("consent", "marketing"),
+            ("consent_action", "reject"),
+        ]);
+        assert_eq!(rejected, ConsentState::reject_all());
+    }
+
+    #[test]
+    fn test_render_form() {
+        let html = ConsentBanner::new("/consent")
+            .policy(html! { p { "See our " a href="/privacy" { "privacy policy" } "." } })
+            .return_to("/pricing")
+            .hidden("csrf", "tok")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<form class="sh-consent__form" method="post" action="/consent">"#));
+        assert!(html.contains(r#"<input type="hidden" name="return_to" value="/pricing">"#));
+        assert!(html.contains(r#"<input type="hidden" name="csrf" value="tok">"#));
+        assert!(html.contains(r#"href="/privacy""#));
+        assert!(html.contains(r#"name="consent_action" value="accept""#));
+        assert!(html.contains(r#"name="consent_action" value="reject""#));
+        assert!(html.contains(r#"aria-labelledby="sh-consent-title""#));
+    }
+
+    #[test]
+    fn test_necessary_always_checked() {
+        let html = ConsentBanner::new("/consent").render().into_string();
+        assert!(html.contains(r#"value="necessary" checked disabled"#));
+        assert!(html.contains(r#"value="analytics" aria-describedby"#));
+    }
+
+    #[test]
+    fn test_dismissed_when_state_known() {
+        let state = ConsentState::from_cookie_header("sh_consent=1.analytics");
+        let banner = ConsentBanner::new("/consent").state(state);
+        assert!(banner.is_dismissed());
+        assert_eq!(banner.render().into_string(), "");
+
+        let html = banner.reopen(true).render().into_string();
+        assert!(html.contains(r#"<details class="sh-consent__preferences" open>"#));
+        assert!(html.contains(r#"value="analytics" checked"#));
+        assert!(!html.contains(r#"value="marketing" checked"#));
+    }
+
+    #[test]
+    fn test_describe_and_position() {
+        let html = ConsentBanner::new("/c")
+            .describe(ConsentCategory::Analytics, "Privacy-friendly stats only.")
+            .position(ConsentPosition::Corner)
+            .render()
+            .into_string();
+        assert!(html.contains("Privacy-friendly stats only."));
+        assert!(html.contains("sh-consent--corner"));
+    }
+
+    #[test]
+    fn test_consent_banner_css() {
+        let css = consent_banner_css();
+        assert!(css.contains(".sh-consent--corner"));
+        assert!(css.contains(".sh-consent__categories"));
+    }
+}
@@ -0,0 +1,438 @@
+//! Countdown Component - time left until a launch or the end of a sale
+//!
+//! The remaining days, hours, minutes and seconds are worked out when the
+//! page is rendered and written into the markup, so the countdown reads
+//! correctly without CSS or motion. With motion, each unit is a registered
+//! `--sh-countdown-value` property animated in steps and shown through a
+//! CSS counter, started at the right phase by a negative delay, so the
+//! browser keeps counting without a script. When the time is up the units
+//! are swapped for the [`Countdown::expired`] content.
+//!
+//! The count starts from the moment of rendering: pages built ahead of
+//! time run behind by their age, so render per request or rebuild often.
+
+use crate::motion::{EffectCss, MotionConfig};
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// Countdown to a target instant
+#[derive(Debug, Clone)]
+pub struct Countdown<'a> {
+    target: u64,
+    now: Option<u64>,
+    label: Option<Cow<'a, str>>,
+    expired: Option<Markup>,
+    seconds: bool,
+    live: bool,
+    units: [Cow<'a, str>; 4],
+}
+
+impl<'a> Countdown<'a> {
+    /// Count down to `target`
+    pub fn new(target: SystemTime) -> Self {
+        Self::at_unix(unix_secs(target))
+    }
+
+    /// Count down to `target` seconds since the Unix epoch
+    pub fn at_unix(target: u64) -> Self {
+        Self {
+            target,
+            now: None,
+            label: None,
+            expired: None,
+            seconds: true,
+            live: true,
+            units: [
+                Cow::Borrowed("Days"),
+                Cow::Borrowed("Hours"),
+                Cow::Borrowed("Minutes"),
+                Cow::Borrowed("Seconds"),
+            ],
+        }
+    }
+
+    /// Render as of `now` rather than the current time
+    pub fn now(mut self, now: SystemTime) -> Self {
+        self.now = Some(unix_secs(now));
+        self
+    }
+
+    /// Heading above the units, e.g. `Sale ends in`
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Shown once the target has passed (default `This has ended`)
+    pub fn expired(mut self, content: Markup) -> Self {
+        self.expired = Some(content);
+        self
+    }
+
+    /// Show the seconds unit (default on)
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Keep counting in the browser (default on); off renders the values
+    /// as of rendering only
+    pub fn live(mut self, live: bool) -> Self {
+        self.live = live;
+        self
+    }
+
+    /// Names of the days, hours, minutes and seconds units
+    pub fn unit_labels(
+        mut self,
+        days: impl Into<Cow<'a, str>>,
+        hours: impl Into<Cow<'a, str>>,
+        minutes: impl Into<Cow<'a, str>>,
+        seconds: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.units = [days.into(), hours.into(), minutes.into(), seconds.into()];
+        self
+    }
+
+    /// Seconds left when rendered
+    pub fn remaining(&self) -> u64 {
+        let now = self.now.unwrap_or_else(|| unix_secs(SystemTime::now()));
+        self.target.saturating_sub(now)
+    }
+
+    fn render_expired(&self) -> Markup {
+        html! {
+            div class="sh-countdown__expired" {
+                @if let Some(content) = &self.expired {
+                    (content)
+                } @else {
+                    p class="sh-countdown__message" { "This has ended" }
+                }
+            }
+        }
+    }
+
+    fn render_unit(&self, name: &str, unit: Unit) -> Markup {
+        // The animation runs from `steps` to 0 over `steps` units; the
+        // offset puts it at the current value with `below` seconds to go
+        // before the next step
+        let offset = (unit.steps - 1 - unit.value) * unit.size + (unit.size - 1 - unit.below);
+        let style = format!(
+            "--sh-countdown-steps: {}; --sh-countdown-period: {}s; --sh-countdown-offset: -{}s;",
+            unit.steps,
+            unit.steps * unit.size,
+            offset
+        );
+        let text = if unit.size == DAY {
+            unit.value.to_string()
+        } else {
+            format!("{:02}", unit.value)
+        };
+        html! {
+            span class="sh-countdown__unit" {
+                span
+                    class=(if unit.size == DAY { "sh-countdown__value sh-countdown__value--days" } else { "sh-countdown__value" })
+                    style=[self.live.then_some(style)] {
+                    span class="sh-countdown__static" { (text) }
+                }
+                span class="sh-countdown__name" { (name) }
+            }
+        }
+    }
+}
+
+/// One unit of the countdown
+#[derive(Clone, Copy)]
+struct Unit {
+    /// Length of one step in seconds
+    size: u64,
+    /// Steps before the unit wraps around
+    steps: u64,
+    value: u64,
+    /// Seconds left within the current step
+    below: u64,
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl<'a> Render for Countdown<'a> {
+    fn render(&self) -> Markup {
+        let remaining = self.remaining();
+        if remaining == 0 {
+            return html! {
+                div class="sh-countdown sh-countdown--expired" role="timer" {
+                    @if let Some(label) = &self.label {
+                        p class="sh-countdown__label" { (label) }
+                    }
+                    (self.render_expired())
+                }
+            };
+        }
+
+        let target = CalendarDate::from_days((self.target / DAY) as i64);
+        let datetime = format!(
+            "{}T{:02}:{:02}:{:02}Z",
+            target,
+            self.target % DAY / HOUR,
+            self.target % HOUR / MINUTE,
+            self.target % MINUTE
+        );
+        let days = remaining / DAY;
+        let unit = |size: u64, steps: u64| Unit {
+            size,
+            steps,
+            value: remaining / size % steps,
+            below: remaining % size,
+        };
+        let classes = if self.live {
+            "sh-countdown sh-countdown--live"
+        } else {
+            "sh-countdown"
+        };
+
+        html! {
+            div
+                class=(classes)
+                role="timer"
+                aria-label=[self.label.as_deref()]
+                style=(format!("--sh-countdown-remaining: {}s;", remaining)) {
+                @if let Some(label) = &self.label {
+                    p class="sh-countdown__label" aria-hidden="true" { (label) }
+                }
+                time class="sh-countdown__units" datetime=(datetime) {
+                    @if days > 0 {
+                        (self.render_unit(&self.units[0], Unit { size: DAY, steps: days + 1, value: days, below: remaining % DAY }))
+                    }
+                    (self.render_unit(&self.units[1], unit(HOUR, 24)))
+                    (self.render_unit(&self.units[2], unit(MINUTE, 60)))
+                    @if self.seconds {
+                        (self.render_unit(&self.units[3], unit(1, 60)))
+                    }
+                }
+                @if self.live {
+                    (self.render_expired())
+                }
+            }
+        }
+    }
+}
+
+pub fn countdown_css() -> String {
+    countdown_css_for(MotionConfig::default())
+}
+
+/// Countdown CSS at the given motion level
+pub fn countdown_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
+@property --sh-countdown-value {
+    syntax: "<integer>";
+    inherits: false;
+    initial-value: 0;
+}
+
+.sh-countdown {
+    display: inline-flex;
+    flex-direction: column;
+    align-items: center;
+    gap: 0.5rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-countdown__label {
+    margin: 0;
+    font-size: 0.875rem;
+    font-weight: 600;
+}
+
+.sh-countdown__units {
+    display: flex;
+    flex-wrap: wrap;
+    justify-content: center;
+    gap: 0.5rem;
+}
+
+.sh-countdown__unit {
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    min-width: 4rem;
+    padding: 0.5rem 0.75rem;
+    background: var(--sh-surface-2, #f3f4f6);
+    border-radius: var(--sh-radius-md, 0.375rem);
+}
+
+.sh-countdown__value {
+    font-size: 2rem;
+    font-weight: 700;
+    line-height: 1.1;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-countdown__name {
+    font-size: 0.75rem;
+    text-transform: uppercase;
+    letter-spacing: 0.05em;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-countdown__message {
+    margin: 0;
+    font-weight: 600;
+}
+
+.sh-countdown--live .sh-countdown__value {
+    counter-reset: sh-countdown var(--sh-countdown-value);
+    animation: sh-countdown-tick var(--sh-countdown-period)
+        steps(var(--sh-countdown-steps), jump-start) var(--sh-countdown-offset) infinite;
+}
+
+.sh-countdown--live .sh-countdown__static {
+    display: none;
+}
+
+.sh-countdown--live .sh-countdown__value::after {
+    content: counter(sh-countdown, decimal-leading-zero);
+    content: counter(sh-countdown, decimal-leading-zero) / "";
+}
+
+.sh-countdown--live .sh-countdown__value--days::after {
+    content: counter(sh-countdown);
+    content: counter(sh-countdown) / "";
+}
+
+.sh-countdown--live .sh-countdown__units {
+    animation: sh-countdown-end 1ms var(--sh-countdown-remaining) forwards;
+}
+
+.sh-countdown--live .sh-countdown__expired {
+    visibility: hidden;
+    position: absolute;
+    animation: sh-countdown-reveal 1ms var(--sh-countdown-remaining) forwards;
+}
+
+@keyframes sh-countdown-tick {
+    from {
+        --sh-countdown-value: var(--sh-countdown-steps);
+    }
+    to {
+        --sh-countdown-value: 0;
+    }
+}
+
+@keyframes sh-countdown-end {
+    to {
+        visibility: hidden;
+        position: absolute;
+    }
+}
+
+@keyframes sh-countdown-reveal {
+    to {
+        visibility: visible;
+        position: static;
+    }
+}
+"#,
+        still: r#"
+.sh-countdown--live .sh-countdown__value {
+    animation: none;
+}
+
+.sh-countdown--live .sh-countdown__static {
+    display: inline;
+}
+
+.sh-countdown--live .sh-countdown__value::after {
+    content: none;
+}
+"#,
+        off: "",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn at(offset: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_800_000_000 + offset)
+    }
+
+    #[test]
+    fn test_countdown_static_values_and_phase() {
+        // 2 days, 3 hours, 4 minutes, 5 seconds
+        let remaining = 2 * DAY + 3 * HOUR + 4 * MINUTE + 5;
+        let html = Countdown::new(at(remaining))
+            .now(at(0))
+            .label("Sale ends in")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"role="timer" aria-label="Sale ends in""#));
+        assert!(html.contains(&format!("--sh-countdown-remaining: {}s;", remaining)));
+        assert!(html.contains(r#"datetime="2027-01-17T11:04:05Z""#));
+        let statics: Vec<&str> = html
+            .split(r#"<span class="sh-countdown__static">"#)
+            .skip(1)
+            .map(|s| &s[..s.find('<').unwrap()])
+            .collect();
+        assert_eq!(statics, ["2", "03", "04", "05"]);
+        // Seconds show 05 for one more second: 54 steps in plus none
+        assert!(html.contains(
+            "--sh-countdown-steps: 60; --sh-countdown-period: 60s; --sh-countdown-offset: -54s;"
+        ));
+        // Minutes step down after the 5 seconds left run out
+        let minutes_offset = 55 * MINUTE + (MINUTE - 1 - 5);
+        assert!(html.contains(&format!(
+            "--sh-countdown-period: 3600s; --sh-countdown-offset: -{}s;",
+            minutes_offset
+        )));
+        assert!(html.contains(r#"aria-hidden="true">Sale ends in"#));
+        assert!(html.contains("This has ended"));
+    }
+
+    #[test]
+    fn test_countdown_options_and_expired() {
+        let html = Countdown::new(at(90))
+            .now(at(0))
+            .seconds(false)
+            .live(false)
+            .render()
+            .into_string();
+        assert!(!html.contains("sh-countdown--live"));
+        assert!(!html.contains("style=\"--sh-countdown-steps"));
+        assert!(!html.contains("Days") && !html.contains("Seconds"));
+        assert!(!html.contains("sh-countdown__expired"));
+
+        let html = Countdown::new(at(0))
+            .now(at(10))
+            .expired(html! { a href="/shop" { "Browse the shop" } })
+            .render()
+            .into_string();
+        assert!(html.contains("sh-countdown--expired"));
+        assert!(html.contains("Browse the shop"));
+        assert!(!html.contains("sh-countdown__units"));
+    }
+
+    #[test]
+    fn test_countdown_css_motion() {
+        let full = countdown_css_for(MotionConfig::Full);
+        assert!(full.contains("@keyframes sh-countdown-tick"));
+        assert!(full.contains("@media (prefers-reduced-motion: reduce)"));
+        let reduced = countdown_css_for(MotionConfig::Reduced);
+        assert!(!reduced.contains("@keyframes"));
+        assert!(
+            reduced.contains(".sh-countdown--live .sh-countdown__static {\n    display: inline;")
+        );
+    }
+}
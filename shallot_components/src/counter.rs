@@ -1,10 +1,210 @@
 //! Counter Component
 //!
 //! Animated number counters and statistics display.
+//!
+//! Counting up is pure CSS: a registered `--sh-count` integer is animated
+//! to the value and each digit group prints its share of it with
+//! `counter()`. The formatted value is always rendered as text, so
+//! browsers without `@property` or `mod()`, reduced motion and
+//! [`MotionConfig::None`] show the final number straight away.
 
 use crate::component::ComponentSize;
+use crate::motion::{EffectCss, MotionConfig};
+use crate::scroll_reveal::Easing;
 use maud::{html, Markup, Render};
 
+/// Scaled values at or above this are not animated (f64 stays exact)
+const MAX_ANIMATED: f64 = 1e15;
+
+/// Longest fraction that can be animated (one padded counter style each)
+const MAX_ANIMATED_DECIMALS: u8 = 6;
+
+/// How a number is written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Digits after the decimal point
+    pub decimals: u8,
+    /// Group thousands with commas
+    pub separator: bool,
+    /// The value is a ratio shown as a percentage: `0.42` -> `42`
+    pub percent: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimals: 0,
+            separator: true,
+            percent: false,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// The value as an integer count of the smallest shown unit
+    fn scaled(&self, value: f64) -> f64 {
+        let value = if self.percent { value * 100.0 } else { value };
+        (value * 10f64.powi(self.decimals as i32)).round()
+    }
+
+    /// `1,234.50` for 1234.5 with two decimals
+    pub fn format(&self, value: f64) -> String {
+        let scaled = self.scaled(value);
+        let (int, frac) = if scaled.abs() < MAX_ANIMATED {
+            let unit = 10u64.pow(self.decimals as u32);
+            let digits = scaled.abs() as u64;
+            let frac = format!("{:0width$}", digits % unit, width = self.decimals as usize);
+            ((digits / unit).to_string(), frac)
+        } else {
+            let shown = if self.percent { value * 100.0 } else { value };
+            let text = format!("{:.1$}", shown.abs(), self.decimals as usize);
+            match text.split_once('.') {
+                Some((int, frac)) => (int.to_string(), frac.to_string()),
+                None => (text, String::new()),
+            }
+        };
+
+        let mut out = String::new();
+        if scaled < 0.0 {
+            out.push('-');
+        }
+        for (i, c) in int.chars().enumerate() {
+            if self.separator && i > 0 && (int.len() - i).is_multiple_of(3) {
+                out.push(',');
+            }
+            out.push(c);
+        }
+        if self.decimals > 0 {
+            out.push('.');
+            out.push_str(&frac);
+        }
+        out
+    }
+}
+
+/// When a count-up starts
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CountStart {
+    /// As soon as the page is shown
+    #[default]
+    Load,
+    /// Driven by scrolling: starts once this fraction (0.0 - 1.0) of the
+    /// number has entered the viewport and ends as it reaches the middle.
+    /// Browsers without `animation-timeline` count up on load instead
+    Scroll(f32),
+}
+
+/// Timing of a count-up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CountAnimation {
+    /// Seconds from zero to the value
+    pub duration: f32,
+    /// Seconds before counting starts
+    pub delay: f32,
+    pub easing: Easing,
+    pub start: CountStart,
+}
+
+impl Default for CountAnimation {
+    fn default() -> Self {
+        Self {
+            duration: 2.0,
+            delay: 0.0,
+            easing: Easing::EaseOutCubic,
+            start: CountStart::Load,
+        }
+    }
+}
+
+impl CountAnimation {
+    fn style(&self) -> String {
+        let mut style = format!(
+            "--sh-count-duration: {}s; --sh-count-easing: {};",
+            self.duration,
+            self.easing.css_value()
+        );
+        match self.start {
+            CountStart::Load if self.delay > 0.0 => {
+                style.push_str(&format!(" --sh-count-delay: {}s;", self.delay));
+            }
+            CountStart::Load => {}
+            CountStart::Scroll(threshold) => {
+                let percent = (threshold.clamp(0.0, 1.0) * 100.0).round();
+                style.push_str(&format!(" --sh-count-range: entry {}% cover 50%;", percent));
+            }
+        }
+        style
+    }
+}
+
+/// The formatted value, plus digit groups that count up to it when
+/// `animation` is given and the value can be animated
+pub(crate) fn render_count(
+    value: f64,
+    format: NumberFormat,
+    animation: Option<&CountAnimation>,
+) -> Markup {
+    let text = format.format(value);
+    let scaled = format.scaled(value);
+    let animation = animation.filter(|_| {
+        (0.0..MAX_ANIMATED).contains(&scaled) && format.decimals <= MAX_ANIMATED_DECIMALS
+    });
+    let Some(animation) = animation else {
+        return html! {
+            span class="sh-count" { span class="sh-count__static" { (text) } }
+        };
+    };
+
+    // Digit groups, most significant first: (unit, modulus, padding)
+    let fraction_unit = 10u64.pow(format.decimals as u32);
+    let int_digits = (scaled as u64 / fraction_unit).to_string().len() as u32;
+    let group_count = if format.separator {
+        int_digits.div_ceil(3)
+    } else {
+        1
+    };
+    let mut groups: Vec<(u64, Option<u64>, u8)> = (0..group_count)
+        .rev()
+        .map(|k| {
+            let leading = k + 1 == group_count;
+            (
+                fraction_unit * 1000u64.pow(k),
+                (!leading).then_some(1000),
+                if leading { 1 } else { 3 },
+            )
+        })
+        .collect();
+    if format.decimals > 0 {
+        groups.push((1, Some(fraction_unit), format.decimals));
+    }
+
+    let class = match animation.start {
+        CountStart::Load => "sh-count sh-count--live",
+        CountStart::Scroll(_) => "sh-count sh-count--live sh-count--scroll",
+    };
+    let last_int = group_count as usize - 1;
+    html! {
+        span class=(class) style=(animation.style()) {
+            span class="sh-count__static" { (text) }
+            span class="sh-count__live" style=(format!("--sh-count-to: {};", scaled)) aria-hidden="true" hidden {
+                @for (i, (unit, modulus, pad)) in groups.iter().enumerate() {
+                    @if i > 0 && i <= last_int {
+                        ","
+                    } @else if i > last_int {
+                        "."
+                    }
+                    span
+                        class=(if *pad > 1 { format!("sh-count__digits sh-count__digits--pad{}", pad) } else { "sh-count__digits".to_string() })
+                        style=(match modulus {
+                            Some(m) => format!("--sh-count-unit: {}; --sh-count-mod: {};", unit, m),
+                            None => format!("--sh-count-unit: {};", unit),
+                        }) {}
+                }
+            }
+        }
+    }
+}
+
 pub struct Counter<'a> {
     value: f64,
     label: Option<&'a str>,
@@ -15,6 +215,8 @@ pub struct Counter<'a> {
     animated: bool,
     decimals: u8,
     separator: bool,
+    percent: bool,
+    animation: CountAnimation,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -39,6 +241,8 @@ impl<'a> Counter<'a> {
             animated: true,
             decimals: 0,
             separator: true,
+            percent: false,
+            animation: CountAnimation::default(),
         }
     }
 
@@ -82,6 +286,49 @@ impl<'a> Counter<'a> {
         self
     }
 
+    /// Show a ratio as a percentage (`0.42` -> `42%`), with a `%` suffix
+    /// unless one is set
+    pub fn percent(mut self) -> Self {
+        self.percent = true;
+        self.suffix = self.suffix.or(Some("%"));
+        self
+    }
+
+    /// Seconds the count-up takes
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.animation.duration = seconds;
+        self
+    }
+
+    /// Seconds before the count-up starts
+    pub fn delay(mut self, seconds: f32) -> Self {
+        self.animation.delay = seconds;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.animation.easing = easing;
+        self
+    }
+
+    pub fn start(mut self, start: CountStart) -> Self {
+        self.animation.start = start;
+        self
+    }
+
+    /// Count up while scrolling into view, see [`CountStart::Scroll`]
+    pub fn on_scroll(self, threshold: f32) -> Self {
+        self.start(CountStart::Scroll(threshold))
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat {
+            decimals: self.decimals,
+            separator: self.separator,
+            percent: self.percent,
+        }
+    }
+
     fn variant_class(&self) -> &'static str {
         match self.variant {
             CounterVariant::Default => "",
@@ -91,35 +338,12 @@ impl<'a> Counter<'a> {
             CounterVariant::Error => "sh-counter--error",
         }
     }
-
-    fn format_value(&self) -> String {
-        let formatted = format!("{:.1$}", self.value, self.decimals as usize);
-        if self.separator {
-            let parts: Vec<&str> = formatted.split('.').collect();
-            if let Some(int_part) = parts.first() {
-                let mut result = String::new();
-                let chars: Vec<char> = int_part.chars().collect();
-                for (i, c) in chars.iter().enumerate().rev() {
-                    if i < chars.len() - 1 && (chars.len() - 1 - i) % 3 == 0 {
-                        result.insert(0, ',');
-                    }
-                    result.insert(0, *c);
-                }
-                if parts.len() > 1 {
-                    result.push('.');
-                    result.push_str(parts[1]);
-                }
-                return result;
-            }
-        }
-        formatted
-    }
 }
 
 impl<'a> Render for Counter<'a> {
     fn render(&self) -> Markup {
         let size_class = format!("sh-counter--{}", self.size.class_suffix());
-        let display_value = self.format_value();
+        let animation = self.animated.then_some(&self.animation);
 
         html! {
             div
@@ -134,11 +358,10 @@ impl<'a> Render for Counter<'a> {
                 span
                     class="sh-counter__value"
                     data-value=(self.value)
-                    data-animated=[if self.animated { Some("true") } else { None }]
                     aria-live="polite"
                     aria-atomic="true"
                 {
-                    (display_value)
+                    (render_count(self.value, self.number_format(), animation))
                 }
 
                 @if let Some(suffix) = self.suffix {
@@ -287,7 +510,13 @@ impl<'a> Render for Statistic<'a> {
 }
 
 pub fn counter_css() -> String {
-    r#"
+    counter_css_for(MotionConfig::default())
+}
+
+/// Counter CSS at the given motion level, count-ups included
+pub fn counter_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-counter {
     display: inline-flex;
     flex-direction: column;
@@ -407,23 +636,86 @@ pub fn counter_css() -> String {
     color: var(--sh-text-muted, #6b7280);
 }
 
-/* Animation */
-.sh-counter__value[data-animated="true"] {
-    animation: sh-counter-fade-in 0.5s ease-out;
+/* Count-up */
+@property --sh-count {
+    syntax: "<integer>";
+    inherits: true;
+    initial-value: 0;
+}
+
+@counter-style sh-count-pad2 { system: extends decimal; pad: 2 "0"; }
+@counter-style sh-count-pad3 { system: extends decimal; pad: 3 "0"; }
+@counter-style sh-count-pad4 { system: extends decimal; pad: 4 "0"; }
+@counter-style sh-count-pad5 { system: extends decimal; pad: 5 "0"; }
+@counter-style sh-count-pad6 { system: extends decimal; pad: 6 "0"; }
+
+.sh-count {
+    font-variant-numeric: tabular-nums;
+}
+
+@supports (width: mod(3px, 2px)) {
+    .sh-count--live .sh-count__static {
+        position: absolute;
+        width: 1px;
+        height: 1px;
+        overflow: hidden;
+        clip-path: inset(50%);
+        white-space: nowrap;
+    }
+
+    .sh-count--live .sh-count__live {
+        display: inline;
+        animation: sh-count var(--sh-count-duration, 2s) var(--sh-count-easing, ease-out)
+            var(--sh-count-delay, 0s) both;
+    }
+
+    .sh-count__digits {
+        counter-reset: sh-count calc(mod(round(down, var(--sh-count) / var(--sh-count-unit, 1), 1), var(--sh-count-mod, 1000000000000000)));
+    }
+
+    .sh-count__digits::after {
+        content: counter(sh-count);
+    }
+
+    .sh-count__digits--pad2::after { content: counter(sh-count, sh-count-pad2); }
+    .sh-count__digits--pad3::after { content: counter(sh-count, sh-count-pad3); }
+    .sh-count__digits--pad4::after { content: counter(sh-count, sh-count-pad4); }
+    .sh-count__digits--pad5::after { content: counter(sh-count, sh-count-pad5); }
+    .sh-count__digits--pad6::after { content: counter(sh-count, sh-count-pad6); }
+}
+
+@supports (width: mod(3px, 2px)) and (animation-timeline: view()) {
+    .sh-count--scroll .sh-count__live {
+        animation-timeline: view();
+        animation-range: var(--sh-count-range, entry 0% cover 50%);
+    }
 }
 
-@keyframes sh-counter-fade-in {
+@keyframes sh-count {
     from {
-        opacity: 0;
-        transform: translateY(10px);
+        --sh-count: 0;
     }
     to {
-        opacity: 1;
-        transform: translateY(0);
+        --sh-count: var(--sh-count-to);
     }
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-count--live .sh-count__live {
+    display: none;
+    animation: none;
+}
+
+.sh-count--live .sh-count__static {
+    position: static;
+    width: auto;
+    height: auto;
+    overflow: visible;
+    clip-path: none;
+}
+"#,
+        off: "",
+    })
 }
 
 #[cfg(test)]
@@ -445,10 +737,88 @@ mod tests {
     #[test]
     fn test_counter_formatting() {
         let counter = Counter::new(1234567.0).separator(true);
-        let formatted = counter.format_value();
+        let formatted = counter.number_format().format(counter.value);
         assert!(formatted.contains(","));
     }
 
+    #[test]
+    fn test_number_format() {
+        let format = NumberFormat::default();
+        assert_eq!(format.format(1234567.0), "1,234,567");
+        assert_eq!(format.format(-1234.4), "-1,234");
+        assert_eq!(format.format(999.0), "999");
+        let decimal = NumberFormat {
+            decimals: 2,
+            ..NumberFormat::default()
+        };
+        assert_eq!(decimal.format(1234.5), "1,234.50");
+        assert_eq!(decimal.format(0.05), "0.05");
+        let percent = NumberFormat {
+            decimals: 1,
+            separator: false,
+            percent: true,
+        };
+        assert_eq!(percent.format(0.4237), "42.4");
+        assert_eq!(percent.format(12.5), "1250.0");
+    }
+
+    #[test]
+    fn test_counter_count_up_markup() {
+        let html = Counter::new(1234.5)
+            .decimals(2)
+            .prefix("$")
+            .duration(1.5)
+            .easing(Easing::Linear)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<span class="sh-count__static">1,234.50</span>"#));
+        assert!(html.contains(r#"style="--sh-count-duration: 1.5s; --sh-count-easing: linear;""#));
+        assert!(html.contains(r#"style="--sh-count-to: 123450;" aria-hidden="true" hidden"#));
+        // Thousands, then hundreds padded to three digits, then cents
+        let groups: Vec<&str> = html
+            .split(r#"<span class="sh-count__digits"#)
+            .skip(1)
+            .map(|g| &g[..g.find("></span>").unwrap()])
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                r#"" style="--sh-count-unit: 100000;""#,
+                r#" sh-count__digits--pad3" style="--sh-count-unit: 100; --sh-count-mod: 1000;""#,
+                r#" sh-count__digits--pad2" style="--sh-count-unit: 1; --sh-count-mod: 100;""#,
+            ]
+        );
+        assert!(html.contains(r#"hidden><span class="sh-count__digits" style="--sh-count-unit: 100000;"></span>,<span"#));
+        assert!(html.contains(r#"--sh-count-mod: 1000;"></span>.<span"#));
+
+        let scroll = Counter::new(0.42)
+            .percent()
+            .on_scroll(0.25)
+            .render()
+            .into_string();
+        assert!(scroll.contains("sh-count--scroll"));
+        assert!(scroll.contains("--sh-count-range: entry 25% cover 50%;"));
+        assert!(scroll.contains(r#"<span class="sh-counter__suffix" aria-hidden="true">%</span>"#));
+
+        // Negative values and plain rendering only print the text
+        for counter in [Counter::new(-5.0), Counter::new(5.0).animated(false)] {
+            let html = counter.render().into_string();
+            assert!(!html.contains("sh-count__live"));
+            assert!(html.contains(r#"<span class="sh-count__static">"#));
+        }
+    }
+
+    #[test]
+    fn test_counter_css_motion() {
+        let full = counter_css();
+        assert!(full.contains("@property --sh-count"));
+        assert!(full.contains("@keyframes sh-count {"));
+        assert!(full.contains("@media (prefers-reduced-motion: reduce)"));
+        let reduced = counter_css_for(MotionConfig::Reduced);
+        assert!(!reduced.contains("@keyframes"));
+        assert!(reduced.contains(".sh-count--live .sh-count__live {\n    display: none;"));
+    }
+
     #[test]
     fn test_statistic() {
         let stat = Statistic::new("$12,345", "Revenue").trend("+12%", TrendDirection::Up);
@@ -0,0 +1,311 @@
+//! CSS Cache - Lazily built stylesheets and cache-busting bundles
+//!
+//! Every `*_css()` function rebuilds its string on each call. The functions in
+//! [`cached`] build each stylesheet once per process and return a `&'static str`,
+//! and [`css_bundle`] pairs the full component stylesheet with a content hash
+//! suitable for fingerprinted filenames (`shallot.1a2b3c4d.css`) and `ETag`
+//! headers.
+
+pub use shallot_foundation::content_hash;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+macro_rules! cached_css {
+    ($($name:ident => $source:path,)*) => {
+        /// Lazily cached versions of the component CSS functions
+        pub mod cached {
+            use std::sync::OnceLock;
+
+            $(
+                #[doc = concat!("Cached output of [`", stringify!($source), "`]")]
+                pub fn $name() -> &'static str {
+                    static CSS: OnceLock<String> = OnceLock::new();
+                    CSS.get_or_init($source)
+                }
+            )*
+
+            /// Cached output of [`crate::all_component_css`]
+            pub fn all_component_css() -> &'static str {
+                static CSS: OnceLock<String> = OnceLock::new();
+                CSS.get_or_init(crate::all_component_css)
+            }
+
+            /// Names of the cached stylesheets, e.g. `"button_css"`
+            pub const NAMES: &[&str] = &[$(stringify!($name),)*];
+
+            /// Cached stylesheet by component name, with or without the
+            /// `_css` suffix (`"button"` or `"button_css"`)
+            pub fn by_name(name: &str) -> Option<&'static str> {
+                let name = name.strip_suffix("_css").unwrap_or(name);
+                match format!("{}_css", name.replace('-', "_")).as_str() {
+                    $(stringify!($name) => Some($name()),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+cached_css! {
+    accordion_css => crate::accordion::accordion_css,
+    address_form_css => crate::address_form::address_form_css,
+    alert_css => crate::alert::alert_css,
+    animated_beam_css => crate::animated_beam::animated_beam_css,
+    animated_text_css => crate::animated_text::animated_text_css,
+    announcement_css => crate::announcement::announcement_css,
+    aspect_ratio_css => crate::aspect_ratio::aspect_ratio_css,
+    auth_pages_css => crate::auth_pages::auth_pages_css,
+    availability_css => crate::availability::availability_css,
+    avatar_css => crate::avatar::avatar_css,
+    avatar_group_css => crate::avatar_group::avatar_group_css,
+    badge_css => crate::badge::badge_css,
+    barcode_css => crate::barcode::barcode_css,
+    bento_css => crate::bento::bento_css,
+    blog_css => crate::blog::blog_css,
+    border_beam_css => crate::border_beam::border_beam_css,
+    bottom_sheet_css => crate::bottom_sheet::bottom_sheet_css,
+    box_css => crate::r#box::box_css,
+    breadcrumbs_css => crate::breadcrumbs::breadcrumbs_css,
+    button_css => crate::button::button_css,
+    calendar_css => crate::calendar::calendar_css,
+    capdrop_css => crate::capdrop::capdrop_css,
+    card_css => crate::card::card_css,
+    carousel_css => crate::carousel::carousel_css,
+    changelog_css => crate::changelog::changelog_css,
+    charts_css => crate::charts::charts_css,
+    chat_css => crate::chat::chat_css,
+    checkbox_group_css => crate::checkbox_group::checkbox_group_css,
+    checkout_css => crate::checkout::checkout_css,
+    code_block_css => crate::code_block::code_block_css,
+    collapsible_css => crate::collapsible::collapsible_css,
+    color_picker_css => crate::color_picker::color_picker_css,
+    command_palette_css => crate::command_palette::command_palette_css,
+    comments_css => crate::comments::comments_css,
+    component_base_css => crate::component::component_base_css,
+    confetti_css => crate::confetti::confetti_css,
+    consent_banner_css => crate::consent_banner::consent_banner_css,
+    copy_button_css => crate::copy_button::copy_button_css,
+    countdown_css => crate::countdown::countdown_css,
+    counter_css => crate::counter::counter_css,
+    credit_card_input_css => crate::credit_card_input::credit_card_input_css,
+    custom_elements_css => crate::custom_elements::custom_elements_css,
+    dashboard_css => crate::dashboard::dashboard_css,
+    data_display_css => crate::data_display::data_display_css,
+    date_picker_css => crate::date_picker::date_picker_css,
+    description_list_css => crate::description_list::description_list_css,
+    diagram_css => crate::diagram::diagram_css,
+    dialog_css => crate::dialog::dialog_css,
+    divider_css => crate::divider::divider_css,
+    dock_css => crate::dock::dock_css,
+    drawer_css => crate::drawer::drawer_css,
+    dropdown_css => crate::dropdown::dropdown_css,
+    empty_state_css => crate::empty_state::empty_state_css,
+    enhanced_button_css => crate::enhanced_button::enhanced_button_css,
+    enhanced_modal_css => crate::enhanced_modal::enhanced_modal_css,
+    faq_css => crate::faq::faq_css,
+    feature_grid_css => crate::feature_grid::feature_grid_css,
+    feed_layout_css => crate::feed_layout::feed_layout_css,
+    feedback_css => crate::feedback::feedback_css,
+    file_list_css => crate::file_list::file_list_css,
+    file_upload_css => crate::file_upload::file_upload_css,
+    focus_css => crate::focus::focus_css,
+    footer_css => crate::footer::footer_css,
+    form_css => crate::form::form_css,
+    fx_css => crate::fx::fx_css,
+    gantt_css => crate::gantt::gantt_css,
+    glass_css => crate::glass_card::glass_css,
+    glass_select_css => crate::glass_select::glass_select_css,
+    glitch_text_css => crate::glitch_text::glitch_text_css,
+    gradient_text_css => crate::gradient_text::gradient_text_css,
+    heatmap_css => crate::heatmap::heatmap_css,
+    hero_css => crate::hero::hero_css,
+    i18n_css => crate::i18n::i18n_css,
+    icon_css => crate::icon::icon_css,
+    image_upload_css => crate::image_upload::image_upload_css,
+    input_css => crate::input::input_css,
+    interop_css => crate::interop::interop_css,
+    join_css => crate::join::join_css,
+    key_value_list_css => crate::key_value_list::key_value_list_css,
+    landmark_css => crate::landmark::landmark_css,
+    layout_css => crate::layout::layout_css,
+    liquid_button_css => crate::liquid_button::liquid_button_css,
+    list_css => crate::list::list_css,
+    logo_cloud_css => crate::logo_cloud::logo_cloud_css,
+    magic_card_css => crate::magic_card::magic_card_css,
+    magnetic_button_css => crate::magnetic_button::magnetic_button_css,
+    marquee_css => crate::marquee::marquee_css,
+    masked_image_css => crate::masked_image::masked_image_css,
+    masonry_css => crate::masonry::masonry_css,
+    math_css => crate::math::math_css,
+    menu_css => crate::menu::menu_css,
+    mesh_gradient_css => crate::mesh_gradient::mesh_gradient_css,
+    multi_select_css => crate::multi_select::multi_select_css,
+    navbar_css => crate::navbar::navbar_css,
+    navigation_css => crate::navigation::navigation_css,
+    newsletter_css => crate::newsletter::newsletter_css,
+    not_found_css => crate::not_found::not_found_css,
+    notification_stack_css => crate::notification_stack::notification_stack_css,
+    orbiting_circles_css => crate::orbiting_circles::orbiting_circles_css,
+    otp_input_css => crate::otp_input::otp_input_css,
+    pagination_css => crate::pagination::pagination_css,
+    parallax_section_css => crate::parallax_section::parallax_section_css,
+    password_input_css => crate::password_input::password_input_css,
+    pattern_css => crate::pattern::pattern_css,
+    phone_input_css => crate::phone_input::phone_input_css,
+    popover_css => crate::popover::popover_css,
+    product_card_css => crate::product_card::product_card_css,
+    progress_circle_css => crate::progress_circle::progress_circle_css,
+    progress_css => crate::progress::progress_css,
+    promo_css => crate::promo::promo_css,
+    pulse_ring_css => crate::pulse_ring::pulse_ring_css,
+    qr_code_css => crate::qr_code::qr_code_css,
+    radio_group_css => crate::radio_group::radio_group_css,
+    range_slider_css => crate::range_slider::range_slider_css,
+    rating_css => crate::rating::rating_css,
+    rating_input_css => crate::rating_input::rating_input_css,
+    refractive_gauge_css => crate::refractive_gauge::refractive_gauge_css,
+    responsive_image_css => crate::responsive_image::responsive_image_css,
+    reviews_css => crate::reviews::reviews_css,
+    rich_text_css => crate::rich_text::rich_text_css,
+    scroll_area_css => crate::scroll_area::scroll_area_css,
+    scroll_progress_css => crate::scroll_progress::scroll_progress_css,
+    scroll_reveal_css => crate::scroll_reveal::scroll_reveal_css,
+    search_input_css => crate::search_input::search_input_css,
+    section_css => crate::section::section_css,
+    shadow_elevator_css => crate::shadow_elevator::shadow_elevator_css,
+    share_links_css => crate::share_links::share_links_css,
+    shimmer_css => crate::shimmer::shimmer_css,
+    sidebar_css => crate::sidebar::sidebar_css,
+    site_map_css => crate::site_map::site_map_css,
+    skeleton_css => crate::skeleton::skeleton_css,
+    slider_css => crate::slider::slider_css,
+    split_pane_css => crate::split_pane::split_pane_css,
+    spotlight_css => crate::spotlight::spotlight_css,
+    stats_css => crate::stats::stats_css,
+    steps_css => crate::steps::steps_css,
+    surface_css => crate::surface::surface_css,
+    table_css => crate::table::table_css,
+    table_of_contents_css => crate::table_of_contents::table_of_contents_css,
+    tabs_css => crate::tabs::tabs_css,
+    tag_input_css => crate::tag_input::tag_input_css,
+    taxonomy_css => crate::taxonomy::taxonomy_css,
+    terminal_css => crate::terminal::terminal_css,
+    text_reveal_css => crate::text_reveal::text_reveal_css,
+    time_picker_css => crate::time_picker::time_picker_css,
+    timeline_css => crate::timeline::timeline_css,
+    toast_css => crate::toast::toast_css,
+    toggle_group_css => crate::toggle_group::toggle_group_css,
+    tooltip_css => crate::tooltip::tooltip_css,
+    tree_view_css => crate::tree_view::tree_view_css,
+    typing_animation_css => crate::typing_animation::typing_animation_css,
+    typography_css => crate::typography::typography_css,
+    video_player_css => crate::video_player::video_player_css,
+    visually_hidden_css => crate::visually_hidden::visually_hidden_css,
+    z_stack_css => crate::z_stack::z_stack_css,
+}
+
+/// A stylesheet together with its content hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssBundle {
+    css: Cow<'static, str>,
+    hash: u64,
+}
+
+impl CssBundle {
+    /// Hash a stylesheet into a bundle
+    pub fn new(css: impl Into<Cow<'static, str>>) -> Self {
+        let css = css.into();
+        let hash = content_hash(css.as_bytes());
+        Self { css, hash }
+    }
+
+    /// The stylesheet text
+    pub fn as_str(&self) -> &str {
+        &self.css
+    }
+
+    /// The stylesheet bytes, ready to be written or served
+    pub fn bytes(&self) -> &[u8] {
+        self.css.as_bytes()
+    }
+
+    /// Full 64-bit content hash as 16 hex digits
+    pub fn hash(&self) -> String {
+        format!("{:016x}", self.hash)
+    }
+
+    /// Short content hash used in fingerprinted filenames
+    pub fn short_hash(&self) -> String {
+        format!("{:08x}", self.hash >> 32)
+    }
+
+    /// Fingerprinted filename, e.g. `filename("shallot")` → `shallot.1a2b3c4d.css`
+    pub fn filename(&self, stem: &str) -> String {
+        format!("{}.{}.css", stem, self.short_hash())
+    }
+
+    /// Strong `ETag` header value (quoted, as required by RFC 9110)
+    pub fn etag(&self) -> String {
+        format!("\"{}\"", self.hash())
+    }
+}
+
+/// The complete component stylesheet, built and hashed once per process
+pub fn css_bundle() -> &'static CssBundle {
+    static BUNDLE: OnceLock<CssBundle> = OnceLock::new();
+    BUNDLE.get_or_init(|| CssBundle::new(cached::all_component_css()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_matches_source() {
+        assert_eq!(cached::button_css(), crate::button::button_css());
+        assert_eq!(cached::all_component_css(), crate::all_component_css());
+    }
+
+    #[test]
+    fn test_cached_by_name() {
+        assert_eq!(cached::by_name("button"), Some(cached::button_css()));
+        assert_eq!(
+            cached::by_name("animated-text"),
+            Some(cached::animated_text_css())
+        );
+        assert_eq!(cached::by_name("card_css"), Some(cached::card_css()));
+        assert_eq!(cached::by_name("nope"), None);
+        assert!(cached::NAMES.contains(&"focus_css"));
+    }
+
+    #[test]
+    fn test_cached_is_built_once() {
+        let first = cached::card_css();
+        let second = cached::card_css();
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn test_bundle_fingerprint() {
+        let bundle = CssBundle::new(".sh-btn { color: red; }");
+        assert_eq!(bundle.hash().len(), 16);
+        assert_eq!(bundle.short_hash().len(), 8);
+        assert!(bundle.hash().starts_with(&bundle.short_hash()));
+
+        let filename = bundle.filename("shallot");
+        assert!(filename.starts_with("shallot."));
+        assert!(filename.ends_with(".css"));
+        assert_eq!(bundle.etag(), format!("\"{}\"", bundle.hash()));
+
+        let changed = CssBundle::new(".sh-btn { color: blue; }");
+        assert_ne!(bundle.hash(), changed.hash());
+    }
+
+    #[test]
+    fn test_css_bundle() {
+        let bundle = css_bundle();
+        assert_eq!(bundle.as_str(), crate::all_component_css());
+        assert_eq!(bundle.bytes().len(), bundle.as_str().len());
+        assert!(std::ptr::eq(bundle, css_bundle()));
+    }
+}
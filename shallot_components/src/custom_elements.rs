@@ -0,0 +1,264 @@
+//! Custom Elements - components wrapped as future Web Component islands
+//!
+//! [`CustomElement`] renders a component inside an autonomous custom element
+//! tag such as `<sh-cart-button>`. Shallot ships no script for it: until a
+//! downstream app defines the element, the tag is laid out with
+//! `display: contents` and the component behaves exactly as it does without
+//! the wrapper. Styles stay on the light-DOM classes (`sh-island--{tag}`
+//! scopes them per island), and [`CustomElement::part`] adds `part`
+//! attributes so a later shadow-DOM upgrade can keep theming them through
+//! `::part()`.
+//!
+//! # Example
+//!
+//! ```
+//! use maud::Render;
+//! use shallot_components::button::Button;
+//! use shallot_components::custom_elements::CustomElement;
+//!
+//! let island = CustomElement::new("sh-cart-button", Button::new("Add to cart").render())
+//!     .unwrap()
+//!     .attr("product-id", "42")
+//!     .part("sh-btn", "button");
+//! let html = island.render().into_string();
+//! assert!(html.starts_with(r#"<sh-cart-button class="sh-island sh-island--sh-cart-button""#));
+//! assert!(html.contains(r#"part="button""#));
+//! ```
+
+use maud::{html, Markup, PreEscaped, Render};
+use std::borrow::Cow;
+
+/// Names the HTML spec reserves even though they contain a hyphen
+const RESERVED_NAMES: &[&str] = &[
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-src",
+    "font-face-uri",
+    "font-face-format",
+    "font-face-name",
+    "missing-glyph",
+];
+
+/// Why a custom element could not be created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomElementError {
+    /// The tag is not a valid autonomous custom element name
+    InvalidName(String),
+    /// The attribute name contains characters HTML does not allow
+    InvalidAttribute(String),
+}
+
+impl std::fmt::Display for CustomElementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomElementError::InvalidName(name) => {
+                write!(f, "`{}` is not a valid custom element name", name)
+            }
+            CustomElementError::InvalidAttribute(name) => {
+                write!(f, "`{}` is not a valid attribute name", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CustomElementError {}
+
+/// Whether `name` can be used as an autonomous custom element tag: it
+/// starts with a lowercase letter, contains a hyphen, uses only lowercase
+/// letters, digits, `-`, `.` and `_`, and is not reserved
+pub fn is_valid_element_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '_'))
+        && !RESERVED_NAMES.contains(&name)
+}
+
+fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+}
+
+/// A component wrapped in a custom element tag
+#[derive(Debug, Clone)]
+pub struct CustomElement<'a> {
+    tag: Cow<'a, str>,
+    pub content: Markup,
+    attrs: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// `(class, part)` pairs: elements with the class get the part name
+    pub parts: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> CustomElement<'a> {
+    /// Wrap `content` in `<tag>`; fails unless `tag` is a valid custom
+    /// element name
+    pub fn new(tag: impl Into<Cow<'a, str>>, content: Markup) -> Result<Self, CustomElementError> {
+        let tag = tag.into();
+        if !is_valid_element_name(&tag) {
+            return Err(CustomElementError::InvalidName(tag.into_owned()));
+        }
+        Ok(Self {
+            tag,
+            content,
+            attrs: Vec::new(),
+            parts: Vec::new(),
+        })
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Add an attribute for the element's future script to read; invalid
+    /// names are rejected by [`CustomElement::try_attr`] and dropped here
+    pub fn attr(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        let name = name.into();
+        if is_valid_attribute_name(&name) {
+            self.attrs.push((name, value.into()));
+        }
+        self
+    }
+
+    /// Add an attribute, failing on a name HTML does not allow
+    pub fn try_attr(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Result<Self, CustomElementError> {
+        let name = name.into();
+        if !is_valid_attribute_name(&name) {
+            return Err(CustomElementError::InvalidAttribute(name.into_owned()));
+        }
+        self.attrs.push((name, value.into()));
+        Ok(self)
+    }
+
+    /// Expose elements carrying `class` as `::part(part)`
+    pub fn part(mut self, class: impl Into<Cow<'a, str>>, part: impl Into<Cow<'a, str>>) -> Self {
+        self.parts.push((class.into(), part.into()));
+        self
+    }
+
+    /// The content with `part` attributes added after matching class lists
+    fn content_with_parts(&self) -> String {
+        let html = self.content.clone().into_string();
+        if self.parts.is_empty() {
+            return html;
+        }
+
+        let mut out = String::with_capacity(html.len());
+        let mut rest = html.as_str();
+        while let Some(start) = rest.find(" class=\"") {
+            let value_start = start + " class=\"".len();
+            let Some(len) = rest[value_start..].find('"') else {
+                break;
+            };
+            let value_end = value_start + len;
+            out.push_str(&rest[..=value_end]);
+
+            let classes: Vec<&str> = rest[value_start..value_end].split_whitespace().collect();
+            let parts: Vec<&str> = self
+                .parts
+                .iter()
+                .filter(|(class, _)| classes.contains(&class.as_ref()))
+                .map(|(_, part)| part.as_ref())
+                .collect();
+            if !parts.is_empty() {
+                out.push_str(" part=\"");
+                out.push_str(&html! { (parts.join(" ")) }.into_string());
+                out.push('"');
+            }
+            rest = &rest[value_end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+impl<'a> Render for CustomElement<'a> {
+    fn render(&self) -> Markup {
+        let mut open = format!(
+            r#"<{tag} class="sh-island sh-island--{tag}" data-sh-island="""#,
+            tag = self.tag
+        );
+        for (name, value) in &self.attrs {
+            open.push_str(&format!(
+                r#" {}="{}""#,
+                name,
+                html! { (value) }.into_string()
+            ));
+        }
+        open.push('>');
+
+        PreEscaped(format!(
+            "{}{}</{}>",
+            open,
+            self.content_with_parts(),
+            self.tag
+        ))
+    }
+}
+
+/// Generate CSS for custom element islands
+pub fn custom_elements_css() -> String {
+    r#"
+/* Islands are plain wrappers until a script defines the element */
+[data-sh-island]:not(:defined) {
+    display: contents;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::button::Button;
+
+    #[test]
+    fn test_element_names() {
+        assert!(is_valid_element_name("sh-cart"));
+        assert!(is_valid_element_name("x-1.2_b"));
+        assert!(!is_valid_element_name("cart"));
+        assert!(!is_valid_element_name("Sh-cart"));
+        assert!(!is_valid_element_name("-cart"));
+        assert!(!is_valid_element_name("font-face"));
+        assert!(!is_valid_element_name("sh-cart><script"));
+
+        let err = CustomElement::new("button", html! {}).unwrap_err();
+        assert_eq!(err, CustomElementError::InvalidName("button".into()));
+        assert_eq!(
+            err.to_string(),
+            "`button` is not a valid custom element name"
+        );
+    }
+
+    #[test]
+    fn test_custom_element_render() {
+        let island = CustomElement::new("sh-cart-button", Button::new("Add").render())
+            .unwrap()
+            .attr("product-id", "4\"2")
+            .attr("bad name", "x")
+            .part("sh-btn", "button")
+            .part("sh-btn--primary", "primary");
+        let html = island.render().into_string();
+
+        assert_eq!(
+            html,
+            r#"<sh-cart-button class="sh-island sh-island--sh-cart-button" data-sh-island="" product-id="4&quot;2"><button type="button" class="sh-btn sh-btn--primary sh-btn--md" part="button primary">Add</button></sh-cart-button>"#
+        );
+        assert!(CustomElement::new("sh-x", html! {})
+            .unwrap()
+            .try_attr("on click", "")
+            .is_err());
+    }
+
+    #[test]
+    fn test_custom_elements_css() {
+        assert!(custom_elements_css().contains("[data-sh-island]:not(:defined)"));
+    }
+}
@@ -0,0 +1,582 @@
+//! Dashboard Component - Preset admin layouts built from panels
+//!
+//! A [`Dashboard`] places [`Panel`]s in three areas rendered top to bottom:
+//! a KPI row, a charts area and a table area. Each area is a 12-column grid
+//! and the [`DashboardPreset`] decides how wide panels are by default; below
+//! 1024px half-width panels pair up and everything else goes full width, and
+//! below 640px every panel is full width.
+//!
+//! Panels are rearranged without drag and drop: a [`DashboardConfig`],
+//! typically stored per user as JSON, lists the panel order, hidden panels,
+//! span overrides and the density.
+
+use maud::{html, Markup, Render};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Which area of the dashboard a panel belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum DashboardArea {
+    Kpi,
+    #[default]
+    Chart,
+    Table,
+}
+
+impl DashboardArea {
+    const ALL: [DashboardArea; 3] = [
+        DashboardArea::Kpi,
+        DashboardArea::Chart,
+        DashboardArea::Table,
+    ];
+
+    fn modifier(&self) -> &'static str {
+        match self {
+            DashboardArea::Kpi => "kpis",
+            DashboardArea::Chart => "charts",
+            DashboardArea::Table => "tables",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            DashboardArea::Kpi => "Key metrics",
+            DashboardArea::Chart => "Charts",
+            DashboardArea::Table => "Tables",
+        }
+    }
+}
+
+/// Default panel widths per area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashboardPreset {
+    /// Four KPIs per row, charts two-up, tables full width
+    #[default]
+    Overview,
+    /// Like `Overview`, but the first chart takes two thirds of the row
+    Focus,
+    /// Four KPIs per row, then charts and tables full width
+    Stacked,
+}
+
+impl DashboardPreset {
+    /// Columns (of 12) a panel spans when it sets none itself
+    pub fn span(&self, area: DashboardArea, index_in_area: usize) -> u8 {
+        match (self, area) {
+            (_, DashboardArea::Kpi) => 3,
+            (_, DashboardArea::Table) => 12,
+            (DashboardPreset::Overview, DashboardArea::Chart) => 6,
+            (DashboardPreset::Focus, DashboardArea::Chart) => {
+                if index_in_area == 0 {
+                    8
+                } else {
+                    4
+                }
+            }
+            (DashboardPreset::Stacked, DashboardArea::Chart) => 12,
+        }
+    }
+}
+
+/// Spacing between and inside panels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DashboardDensity {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl DashboardDensity {
+    fn modifier(&self) -> &'static str {
+        match self {
+            DashboardDensity::Compact => "compact",
+            DashboardDensity::Comfortable => "comfortable",
+            DashboardDensity::Spacious => "spacious",
+        }
+    }
+}
+
+/// Per-user arrangement of a dashboard
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardConfig {
+    /// Panel ids in display order; unlisted panels follow in their
+    /// original order. Panels never move between areas.
+    pub order: Vec<String>,
+    /// Panel ids not to render
+    pub hidden: Vec<String>,
+    /// Column spans (1-12) overriding the preset and the panel
+    pub spans: BTreeMap<String, u8>,
+    pub density: DashboardDensity,
+}
+
+impl DashboardConfig {
+    /// Parse a config stored as JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Card with header, toolbar and footer slots
+#[derive(Debug, Clone)]
+pub struct Panel<'a> {
+    pub id: Cow<'a, str>,
+    pub title: Option<Cow<'a, str>>,
+    pub description: Option<Cow<'a, str>>,
+    pub toolbar: Option<Markup>,
+    pub body: Markup,
+    pub footer: Option<Markup>,
+    pub area: DashboardArea,
+    pub span: Option<u8>,
+    pub row_span: u8,
+}
+
+impl<'a> Panel<'a> {
+    /// `id` is used in the element id and in [`DashboardConfig`]
+    pub fn new(id: impl Into<Cow<'a, str>>, body: Markup) -> Self {
+        Self {
+            id: id.into(),
+            title: None,
+            description: None,
+            toolbar: None,
+            body,
+            footer: None,
+            area: DashboardArea::default(),
+            span: None,
+            row_span: 1,
+        }
+    }
+
+    /// Panel in the KPI row, e.g. wrapping a [`crate::MetricCard`]
+    pub fn kpi(id: impl Into<Cow<'a, str>>, body: Markup) -> Self {
+        Self::new(id, body).area(DashboardArea::Kpi)
+    }
+
+    /// Panel in the charts area
+    pub fn chart(id: impl Into<Cow<'a, str>>, body: Markup) -> Self {
+        Self::new(id, body).area(DashboardArea::Chart)
+    }
+
+    /// Panel in the table area
+    pub fn table(id: impl Into<Cow<'a, str>>, body: Markup) -> Self {
+        Self::new(id, body).area(DashboardArea::Table)
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Controls shown at the end of the header, e.g. filters or links
+    pub fn toolbar(mut self, toolbar: Markup) -> Self {
+        self.toolbar = Some(toolbar);
+        self
+    }
+
+    pub fn footer(mut self, footer: Markup) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    pub fn area(mut self, area: DashboardArea) -> Self {
+        self.area = area;
+        self
+    }
+
+    /// Columns of the 12-column grid to span
+    pub fn span(mut self, span: u8) -> Self {
+        self.span = Some(span.clamp(1, 12));
+        self
+    }
+
+    /// Grid rows to span, for tall charts next to stacked ones
+    pub fn row_span(mut self, rows: u8) -> Self {
+        self.row_span = rows.clamp(1, 4);
+        self
+    }
+
+    fn dom_id(&self) -> String {
+        format!("sh-panel-{}", self.id)
+    }
+
+    fn render_with_span(&self, span: u8) -> Markup {
+        // Half width or less pairs up on medium screens
+        let span_md = if span <= 6 { 6 } else { 12 };
+        let mut style = format!(
+            "--sh-panel-span: {}; --sh-panel-span-md: {};",
+            span, span_md
+        );
+        if self.row_span > 1 {
+            style.push_str(&format!(" --sh-panel-rows: {};", self.row_span));
+        }
+        let title_id = format!("{}-title", self.dom_id());
+        let has_header =
+            self.title.is_some() || self.description.is_some() || self.toolbar.is_some();
+
+        html! {
+            section
+                class="sh-panel"
+                id=(self.dom_id())
+                style=(style)
+                aria-labelledby=[self.title.as_ref().map(|_| &title_id)] {
+                @if has_header {
+                    header class="sh-panel__header" {
+                        div class="sh-panel__heading" {
+                            @if let Some(title) = &self.title {
+                                h2 class="sh-panel__title" id=(title_id) { (title) }
+                            }
+                            @if let Some(description) = &self.description {
+                                p class="sh-panel__description" { (description) }
+                            }
+                        }
+                        @if let Some(toolbar) = &self.toolbar {
+                            div class="sh-panel__toolbar" { (toolbar) }
+                        }
+                    }
+                }
+                div class="sh-panel__body" { (self.body) }
+                @if let Some(footer) = &self.footer {
+                    footer class="sh-panel__footer" { (footer) }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for Panel<'a> {
+    fn render(&self) -> Markup {
+        self.render_with_span(self.span.unwrap_or(12))
+    }
+}
+
+/// Dashboard assembled from panels, a preset and a config
+#[derive(Debug, Clone)]
+pub struct Dashboard<'a> {
+    pub panels: Vec<Panel<'a>>,
+    pub preset: DashboardPreset,
+    pub config: DashboardConfig,
+    pub aria_label: Cow<'a, str>,
+}
+
+impl<'a> Dashboard<'a> {
+    pub fn new(panels: Vec<Panel<'a>>) -> Self {
+        Self {
+            panels,
+            preset: DashboardPreset::default(),
+            config: DashboardConfig::default(),
+            aria_label: Cow::Borrowed("Dashboard"),
+        }
+    }
+
+    pub fn panel(mut self, panel: Panel<'a>) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    pub fn preset(mut self, preset: DashboardPreset) -> Self {
+        self.preset = preset;
+        self
+    }
+
+    /// Apply a stored arrangement
+    pub fn config(mut self, config: DashboardConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Shortcut for setting only the density of the config
+    pub fn density(mut self, density: DashboardDensity) -> Self {
+        self.config.density = density;
+        self
+    }
+
+    pub fn aria_label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.aria_label = label.into();
+        self
+    }
+
+    /// Visible panels of `area` in display order
+    pub fn arranged(&self, area: DashboardArea) -> Vec<&Panel<'a>> {
+        let position = |panel: &Panel| {
+            self.config
+                .order
+                .iter()
+                .position(|id| *id == panel.id)
+                .unwrap_or(usize::MAX)
+        };
+        let mut panels: Vec<&Panel<'a>> = self
+            .panels
+            .iter()
+            .filter(|p| p.area == area && !self.config.hidden.iter().any(|id| *id == p.id))
+            .collect();
+        // Stable, so unlisted panels keep their original order
+        panels.sort_by_key(|p| position(p));
+        panels
+    }
+
+    fn span_for(&self, panel: &Panel, index_in_area: usize) -> u8 {
+        self.config
+            .spans
+            .get(panel.id.as_ref())
+            .map(|span| (*span).clamp(1, 12))
+            .or(panel.span)
+            .unwrap_or_else(|| self.preset.span(panel.area, index_in_area))
+    }
+}
+
+impl<'a> Render for Dashboard<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            div
+                class={ "sh-dashboard sh-dashboard--" (self.config.density.modifier()) }
+                role="region"
+                aria-label=(self.aria_label) {
+                @for area in DashboardArea::ALL {
+                    @let panels = self.arranged(area);
+                    @if !panels.is_empty() {
+                        div
+                            class={ "sh-dashboard__area sh-dashboard__area--" (area.modifier()) }
+                            role="group"
+                            aria-label=(area.label()) {
+                            @for (index, panel) in panels.iter().enumerate() {
+                                (panel.render_with_span(self.span_for(panel, index)))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for dashboards and panels
+pub fn dashboard_css() -> String {
+    r#"
+.sh-dashboard {
+    --sh-dashboard-gap: 1.5rem;
+    --sh-panel-padding: 1.25rem;
+    display: flex;
+    flex-direction: column;
+    gap: var(--sh-dashboard-gap);
+}
+
+.sh-dashboard--compact {
+    --sh-dashboard-gap: 0.75rem;
+    --sh-panel-padding: 0.75rem;
+}
+
+.sh-dashboard--spacious {
+    --sh-dashboard-gap: 2rem;
+    --sh-panel-padding: 1.75rem;
+}
+
+.sh-dashboard__area {
+    display: grid;
+    grid-template-columns: repeat(12, minmax(0, 1fr));
+    grid-auto-flow: row dense;
+    gap: var(--sh-dashboard-gap);
+}
+
+.sh-dashboard__area .sh-panel {
+    grid-column: span var(--sh-panel-span, 12);
+    grid-row: span var(--sh-panel-rows, 1);
+}
+
+.sh-panel {
+    display: flex;
+    flex-direction: column;
+    min-width: 0;
+    background: var(--sh-surface, #fff);
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.75rem);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-panel__header {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: flex-start;
+    justify-content: space-between;
+    gap: 0.5rem 1rem;
+    padding: var(--sh-panel-padding, 1.25rem) var(--sh-panel-padding, 1.25rem) 0;
+}
+
+.sh-panel__title {
+    margin: 0;
+    font-size: 1rem;
+    font-weight: 600;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-panel__description {
+    margin: 0.25rem 0 0;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-panel__toolbar {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-panel__body {
+    flex: 1;
+    min-width: 0;
+    padding: var(--sh-panel-padding, 1.25rem);
+    overflow-x: auto;
+}
+
+.sh-panel__footer {
+    padding: 0.75rem var(--sh-panel-padding, 1.25rem);
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+/* KPI panels usually wrap a metric card that has its own chrome */
+.sh-dashboard__area--kpis .sh-panel:has(> .sh-panel__body > .sh-metric-card:only-child) {
+    background: none;
+    border: none;
+}
+
+.sh-dashboard__area--kpis .sh-panel__body:has(> .sh-metric-card:only-child) {
+    padding: 0;
+}
+
+.sh-dashboard__area--kpis .sh-panel__body > .sh-metric-card {
+    height: 100%;
+}
+
+@media (max-width: 1024px) {
+    .sh-dashboard__area .sh-panel {
+        grid-column: span var(--sh-panel-span-md, 12);
+    }
+}
+
+@media (max-width: 640px) {
+    .sh-dashboard__area .sh-panel {
+        grid-column: span 12;
+        grid-row: auto;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panels() -> Vec<Panel<'static>> {
+        vec![
+            Panel::kpi("revenue", html! { "$12K" }),
+            Panel::kpi("users", html! { "480" }),
+            Panel::chart("traffic", html! { "chart" }).title("Traffic"),
+            Panel::chart("sources", html! { "chart" }),
+            Panel::table("orders", html! { "table" })
+                .title("Recent orders")
+                .description("Last 24 hours")
+                .toolbar(html! { a href="/orders" { "View all" } })
+                .footer(html! { "Updated 5 min ago" }),
+        ]
+    }
+
+    #[test]
+    fn test_panel_slots() {
+        let html = panels().pop().unwrap().render().into_string();
+        assert!(html.contains(r#"id="sh-panel-orders""#));
+        assert!(html.contains(r#"aria-labelledby="sh-panel-orders-title""#));
+        assert!(html.contains(r#"<h2 class="sh-panel__title" id="sh-panel-orders-title">"#));
+        assert!(html.contains("sh-panel__description"));
+        assert!(html.contains(r#"<div class="sh-panel__toolbar"><a href="/orders">"#));
+        assert!(html.contains(r#"<footer class="sh-panel__footer">"#));
+
+        let bare = Panel::new("x", html! { "body" }).render().into_string();
+        assert!(!bare.contains("sh-panel__header"));
+        assert!(!bare.contains("aria-labelledby"));
+    }
+
+    #[test]
+    fn test_dashboard_preset_spans() {
+        let html = Dashboard::new(panels())
+            .preset(DashboardPreset::Focus)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-dashboard sh-dashboard--comfortable"));
+        assert!(html.contains("sh-dashboard__area--kpis"));
+        assert!(html.contains("--sh-panel-span: 3; --sh-panel-span-md: 6;"));
+        assert!(html.contains("--sh-panel-span: 8; --sh-panel-span-md: 12;"));
+        assert!(html.contains("--sh-panel-span: 4; --sh-panel-span-md: 6;"));
+        let kpis = html.find("sh-dashboard__area--kpis").unwrap();
+        let tables = html.find("sh-dashboard__area--tables").unwrap();
+        assert!(kpis < tables);
+    }
+
+    #[test]
+    fn test_dashboard_config_arrangement() {
+        let config = DashboardConfig::from_json(
+            r#"{"order":["sources","users"],"hidden":["revenue"],"spans":{"orders":6},"density":"compact"}"#,
+        )
+        .unwrap();
+        let dashboard = Dashboard::new(panels()).config(config.clone());
+
+        let charts: Vec<&str> = dashboard
+            .arranged(DashboardArea::Chart)
+            .iter()
+            .map(|p| p.id.as_ref())
+            .collect();
+        assert_eq!(charts, vec!["sources", "traffic"]);
+        let kpis: Vec<&str> = dashboard
+            .arranged(DashboardArea::Kpi)
+            .iter()
+            .map(|p| p.id.as_ref())
+            .collect();
+        assert_eq!(kpis, vec!["users"]);
+
+        let html = dashboard.render().into_string();
+        assert!(html.contains("sh-dashboard--compact"));
+        assert!(!html.contains("sh-panel-revenue"));
+        assert!(html.contains("--sh-panel-span: 6;"));
+
+        assert_eq!(
+            DashboardConfig::from_json(&config.to_json()).unwrap(),
+            config
+        );
+        assert_eq!(
+            DashboardConfig::from_json("{}").unwrap(),
+            DashboardConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_dashboard_skips_empty_areas() {
+        let html = Dashboard::new(vec![Panel::table("t", html! {})])
+            .density(DashboardDensity::Spacious)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-dashboard--spacious"));
+        assert!(!html.contains("sh-dashboard__area--kpis"));
+        assert!(html.contains("--sh-panel-span: 12;"));
+    }
+
+    #[test]
+    fn test_dashboard_css() {
+        let css = dashboard_css();
+        assert!(css.contains("repeat(12, minmax(0, 1fr))"));
+        assert!(css.contains(".sh-dashboard--compact"));
+    }
+}
@@ -0,0 +1,507 @@
+//! Date Time - `<time>` elements with formatted and relative text
+//!
+//! [`DateTime`] renders an instant as `<time datetime="…">` with readable
+//! text in the order and clock of a locale: `Mar 13, 2024, 9:05 AM` for
+//! `en-US`, `13 Mar 2024, 09:05` for `en-GB`. [`TimeAgo`] renders the same
+//! element with relative text worked out on the server, `3 days ago` or
+//! `in 2 hours`, and the absolute text as its tooltip. Month names are
+//! English unless replaced with [`DateTime::month_names`].
+
+use crate::form::DateOrder;
+use crate::time_picker::{Time, TimeFormat};
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// An instant, or a whole day when parsed from a date alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    /// Seconds since the Unix epoch
+    pub unix: i64,
+    /// Parsed from `YYYY-MM-DD` with no time of day
+    pub date_only: bool,
+}
+
+impl Timestamp {
+    pub fn from_unix(unix: i64) -> Self {
+        Self {
+            unix,
+            date_only: false,
+        }
+    }
+
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let unix = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs() as i64),
+        };
+        Self::from_unix(unix)
+    }
+
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    /// Parse ISO 8601: `2024-03-13`, `2024-03-13T09:05`, `2024-03-13 09:05:30Z`
+    /// or with an offset like `+02:00`; times without an offset are UTC
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let date = CalendarDate::parse(input.get(..10)?)?;
+        let days = date.days_since_epoch();
+        let rest = &input[10..];
+        if rest.is_empty() {
+            return Some(Self {
+                unix: days * 86_400,
+                date_only: true,
+            });
+        }
+        let rest = rest.strip_prefix(['T', 't', ' '])?;
+        let (clock, offset) = match rest.find(['Z', 'z', '+', '-']) {
+            Some(i) => (&rest[..i], parse_offset(&rest[i..])?),
+            None => (rest, 0),
+        };
+        let mut parts = clock.split(':');
+        let mut field = |max: i64| {
+            parts
+                .next()
+                .filter(|p| p.len() == 2)
+                .and_then(|p| p.parse::<i64>().ok())
+                .filter(|v| *v <= max)
+        };
+        let hour = field(23)?;
+        let minute = field(59)?;
+        // Fractions of a second are dropped
+        let second = match parts.next() {
+            Some(s) => s
+                .split('.')
+                .next()
+                .filter(|s| s.len() == 2)?
+                .parse::<i64>()
+                .ok()
+                .filter(|s| *s <= 60)?,
+            None => 0,
+        };
+        Some(Self::from_unix(
+            days * 86_400 + hour * 3600 + minute * 60 + second - offset,
+        ))
+    }
+
+    /// ISO 8601 for the `datetime` attribute, in UTC
+    pub fn iso(&self) -> String {
+        let (date, seconds) = self.civil(0);
+        if self.date_only {
+            date.to_string()
+        } else {
+            format!(
+                "{}T{:02}:{:02}:{:02}Z",
+                date,
+                seconds / 3600,
+                seconds % 3600 / 60,
+                seconds % 60
+            )
+        }
+    }
+
+    /// Date and seconds into the day, `offset` seconds east of UTC
+    fn civil(&self, offset: i64) -> (CalendarDate, i64) {
+        let local = self.unix + offset;
+        (
+            CalendarDate::from_days(local.div_euclid(86_400)),
+            local.rem_euclid(86_400),
+        )
+    }
+}
+
+/// `Z`, `+02:00`, `-0530` or `+02` -> seconds east of UTC
+fn parse_offset(offset: &str) -> Option<i64> {
+    if offset.eq_ignore_ascii_case("z") {
+        return Some(0);
+    }
+    let sign = match offset.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+    if !matches!(digits.len(), 2 | 4) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits.get(2..).map_or(Some(0), |m| m.parse().ok())?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// How much of the date is spelled out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStyle {
+    /// `3/13/2024`
+    Short,
+    /// `Mar 13, 2024`
+    #[default]
+    Medium,
+    /// `March 13, 2024`
+    Long,
+}
+
+/// A formatted `<time>` element
+#[derive(Debug, Clone)]
+pub struct DateTime<'a> {
+    pub timestamp: Timestamp,
+    pub style: DateStyle,
+    pub order: DateOrder,
+    pub clock: TimeFormat,
+    /// Show the time of day as well as the date
+    pub show_time: bool,
+    /// Offset of the displayed time from UTC, in minutes
+    pub utc_offset: i32,
+    pub month_names: Option<[Cow<'a, str>; 12]>,
+    pub class: Option<Cow<'a, str>>,
+}
+
+impl<'a> DateTime<'a> {
+    /// Show the date, and the time unless the timestamp is a date alone
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self {
+            timestamp,
+            style: DateStyle::Medium,
+            order: DateOrder::MonthDayYear,
+            clock: TimeFormat::Hour12,
+            show_time: !timestamp.date_only,
+            utc_offset: 0,
+            month_names: None,
+            class: None,
+        }
+    }
+
+    /// Parse an ISO 8601 string, see [`Timestamp::parse`]
+    pub fn parse(input: &str) -> Option<Self> {
+        Timestamp::parse(input).map(Self::new)
+    }
+
+    /// Use the date order and clock of a BCP 47 locale
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.order = DateOrder::for_locale(locale);
+        self.clock = TimeFormat::for_locale(locale);
+        self
+    }
+
+    pub fn style(mut self, style: DateStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn show_time(mut self, show: bool) -> Self {
+        self.show_time = show && !self.timestamp.date_only;
+        self
+    }
+
+    /// Display the time `minutes` east of UTC, e.g. 60 for CET
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.utc_offset = minutes;
+        self
+    }
+
+    /// Month names in the page's language, January first
+    pub fn month_names(mut self, names: [&'a str; 12]) -> Self {
+        self.month_names = Some(names.map(Cow::Borrowed));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// The readable text, without markup
+    pub fn text(&self) -> String {
+        let offset = if self.timestamp.date_only {
+            0
+        } else {
+            self.utc_offset as i64 * 60
+        };
+        let (date, seconds) = self.timestamp.civil(offset);
+        let month = date.month as usize - 1;
+        let name = match &self.month_names {
+            Some(names) => names[month].to_string(),
+            None => MONTH_NAMES[month].to_string(),
+        };
+        let name = match self.style {
+            DateStyle::Long => name,
+            _ => name.chars().take(3).collect(),
+        };
+        let (y, m, d) = (date.year, date.month, date.day);
+
+        let mut text = match (self.style, self.order) {
+            (DateStyle::Short, DateOrder::MonthDayYear) => format!("{}/{}/{}", m, d, y),
+            (DateStyle::Short, DateOrder::DayMonthYear) => format!("{:02}/{:02}/{}", d, m, y),
+            (DateStyle::Short, DateOrder::YearMonthDay) => date.to_string(),
+            (_, DateOrder::MonthDayYear) => format!("{} {}, {}", name, d, y),
+            (_, DateOrder::DayMonthYear) => format!("{} {} {}", d, name, y),
+            (_, DateOrder::YearMonthDay) => format!("{} {} {}", y, name, d),
+        };
+        if self.show_time {
+            let hour = (seconds / 3600) as u8;
+            let minute = (seconds % 3600 / 60) as u8;
+            let time = Time { hour, minute };
+            text.push_str(", ");
+            text.push_str(&time.format(self.clock));
+        }
+        text
+    }
+}
+
+impl<'a> Render for DateTime<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            time class=[self.class.as_deref()] datetime=(self.timestamp.iso()) { (self.text()) }
+        }
+    }
+}
+
+/// Where relative text switches to the next unit, in seconds, as in
+/// `just now` under 45 seconds and `2 minutes` under 45 minutes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeThresholds {
+    pub just_now: u64,
+    pub minutes: u64,
+    pub hours: u64,
+    pub days: u64,
+    pub months: u64,
+    /// Beyond this, show the absolute date instead
+    pub absolute_after: Option<u64>,
+}
+
+impl Default for RelativeThresholds {
+    fn default() -> Self {
+        Self {
+            just_now: 45,
+            minutes: 45 * 60,
+            hours: 22 * 3600,
+            days: 26 * 86_400,
+            months: 320 * 86_400,
+            absolute_after: None,
+        }
+    }
+}
+
+/// `3 days ago` or `in 2 hours` for `delta` seconds before or after now
+pub fn relative_time(delta: i64, thresholds: &RelativeThresholds) -> String {
+    let seconds = delta.unsigned_abs();
+    if seconds < thresholds.just_now {
+        return "just now".to_string();
+    }
+    let round = |unit: u64| ((seconds + unit / 2) / unit).max(1);
+    let (count, unit) = if seconds < thresholds.minutes {
+        (round(60), "minute")
+    } else if seconds < thresholds.hours {
+        (round(3600), "hour")
+    } else if seconds < thresholds.days {
+        (round(86_400), "day")
+    } else if seconds < thresholds.months {
+        (round(30 * 86_400).min(11), "month")
+    } else {
+        (round(365 * 86_400), "year")
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if delta < 0 {
+        format!("in {} {}{}", count, unit, plural)
+    } else {
+        format!("{} {}{} ago", count, unit, plural)
+    }
+}
+
+/// A `<time>` element with relative text
+#[derive(Debug, Clone)]
+pub struct TimeAgo<'a> {
+    pub date: DateTime<'a>,
+    /// The moment the text is relative to (defaults to rendering time)
+    pub now: Option<Timestamp>,
+    pub thresholds: RelativeThresholds,
+}
+
+impl<'a> TimeAgo<'a> {
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self {
+            date: DateTime::new(timestamp),
+            now: None,
+            thresholds: RelativeThresholds::default(),
+        }
+    }
+
+    /// Parse an ISO 8601 string, see [`Timestamp::parse`]
+    pub fn parse(input: &str) -> Option<Self> {
+        Timestamp::parse(input).map(Self::new)
+    }
+
+    /// Relative to `now` rather than the time of rendering
+    pub fn now(mut self, now: Timestamp) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    pub fn thresholds(mut self, thresholds: RelativeThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Show the absolute date once the time is more than `seconds` away
+    pub fn absolute_after(mut self, seconds: u64) -> Self {
+        self.thresholds.absolute_after = Some(seconds);
+        self
+    }
+
+    /// Format the absolute text, used as the tooltip and past
+    /// [`TimeAgo::absolute_after`]
+    pub fn date(mut self, date: DateTime<'a>) -> Self {
+        self.date = date;
+        self
+    }
+
+    /// The relative text, or the absolute one past the threshold
+    pub fn text(&self) -> String {
+        let now = self.now.unwrap_or_else(Timestamp::now);
+        let delta = now.unix - self.date.timestamp.unix;
+        match self.thresholds.absolute_after {
+            Some(limit) if delta.unsigned_abs() > limit => self.date.text(),
+            _ => relative_time(delta, &self.thresholds),
+        }
+    }
+}
+
+impl<'a> Render for TimeAgo<'a> {
+    fn render(&self) -> Markup {
+        let absolute = self.date.text();
+        let text = self.text();
+        html! {
+            time
+                class=[self.date.class.as_deref()]
+                datetime=(self.date.timestamp.iso())
+                title=[(text != absolute).then_some(&absolute)] {
+                (text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_parse() {
+        let ts = Timestamp::parse("2024-03-13T09:05:30Z").unwrap();
+        assert_eq!(ts.iso(), "2024-03-13T09:05:30Z");
+        assert_eq!(Timestamp::parse("2024-03-13T11:05:30.250+02:00"), Some(ts));
+        assert_eq!(Timestamp::parse("2024-03-13 03:35:30-0530"), Some(ts));
+        let date = Timestamp::parse("2024-03-13").unwrap();
+        assert!(date.date_only);
+        assert_eq!(date.iso(), "2024-03-13");
+        for bad in [
+            "2024-03-13T9:05",
+            "2024-03-13T25:00",
+            "2024-03-13X",
+            "03/13/2024",
+        ] {
+            assert_eq!(Timestamp::parse(bad), None, "{}", bad);
+        }
+        assert_eq!(Timestamp::from_unix(-1).iso(), "1969-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn test_datetime_locale_formats() {
+        let dt = DateTime::parse("2024-03-13T21:05:00Z").unwrap();
+        assert_eq!(dt.text(), "Mar 13, 2024, 9:05 PM");
+        assert_eq!(dt.clone().locale("en-GB").text(), "13 Mar 2024, 21:05");
+        assert_eq!(
+            dt.clone().locale("de").style(DateStyle::Short).text(),
+            "13/03/2024, 21:05"
+        );
+        assert_eq!(
+            dt.clone()
+                .locale("ja")
+                .style(DateStyle::Long)
+                .show_time(false)
+                .text(),
+            "2024 March 13"
+        );
+        // Shifting past midnight moves the date too
+        assert_eq!(
+            dt.clone().locale("fr").utc_offset(180).text(),
+            "14 Mar 2024, 00:05"
+        );
+        let html = dt.class("sh-post__date").render().into_string();
+        assert_eq!(
+            html,
+            r#"<time class="sh-post__date" datetime="2024-03-13T21:05:00Z">Mar 13, 2024, 9:05 PM</time>"#
+        );
+
+        let german = [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ];
+        let date = DateTime::parse("2024-03-13")
+            .unwrap()
+            .locale("de")
+            .month_names(german);
+        assert_eq!(date.text(), "13 Mär 2024");
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let t = RelativeThresholds::default();
+        assert_eq!(relative_time(10, &t), "just now");
+        assert_eq!(relative_time(90, &t), "2 minutes ago");
+        assert_eq!(relative_time(-3 * 3600, &t), "in 3 hours");
+        assert_eq!(relative_time(23 * 3600, &t), "1 day ago");
+        assert_eq!(relative_time(3 * 86_400, &t), "3 days ago");
+        assert_eq!(relative_time(40 * 86_400, &t), "1 month ago");
+        assert_eq!(relative_time(800 * 86_400, &t), "2 years ago");
+    }
+
+    #[test]
+    fn test_time_ago_render() {
+        let now = Timestamp::parse("2024-03-16T12:00:00Z").unwrap();
+        let html = TimeAgo::parse("2024-03-13T12:00:00Z")
+            .unwrap()
+            .now(now)
+            .render()
+            .into_string();
+        assert_eq!(
+            html,
+            r#"<time datetime="2024-03-13T12:00:00Z" title="Mar 13, 2024, 12:00 PM">3 days ago</time>"#
+        );
+
+        let old = TimeAgo::parse("2024-01-02")
+            .unwrap()
+            .now(now)
+            .absolute_after(7 * 86_400);
+        let html = old.render().into_string();
+        assert_eq!(html, r#"<time datetime="2024-01-02">Jan 2, 2024</time>"#);
+    }
+}
@@ -0,0 +1,1813 @@
+//! Diagram Component - Flowcharts and sequence diagrams as SVG
+//!
+//! A typed graph API plus a small Mermaid-style text syntax, laid out at
+//! build time and emitted as styled inline SVG, so docs sites get diagrams
+//! without shipping a JavaScript renderer.
+//!
+//! Flowcharts use a layered layout: cycles are broken, nodes are ranked by
+//! longest path, long edges get virtual waypoints, and barycenter sweeps
+//! reduce crossings. Sequence diagrams place participants in columns wide
+//! enough for their message labels.
+//!
+//! ```text
+//! flowchart TD
+//!     A[Request] --> B{Cached?}
+//!     B -->|yes| C([Serve])
+//!     B -- no --> D(Render) --> C
+//!
+//! sequenceDiagram
+//!     participant B as Browser
+//!     B->>Server: GET /
+//!     Server-->>B: 200 OK
+//! ```
+
+use maud::{html, Markup, Render};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const CHAR_WIDTH: f64 = 7.8;
+const NODE_HEIGHT: f64 = 40.0;
+const NODE_PADDING: f64 = 16.0;
+const RANK_GAP: f64 = 56.0;
+const NODE_GAP: f64 = 32.0;
+const DUMMY_SIZE: f64 = 8.0;
+const MARGIN: f64 = 16.0;
+const LOOP_SIZE: f64 = 32.0;
+
+const ACTOR_HEIGHT: f64 = 36.0;
+const ACTOR_GAP: f64 = 48.0;
+const MESSAGE_HEIGHT: f64 = 40.0;
+const SELF_MESSAGE_HEIGHT: f64 = 56.0;
+const NOTE_HEIGHT: f64 = 28.0;
+
+fn text_width(text: &str) -> f64 {
+    text.chars().count() as f64 * CHAR_WIDTH
+}
+
+/// Compact coordinate formatting for path data
+fn num(value: f64) -> String {
+    let s = format!("{:.1}", value);
+    match s.strip_suffix(".0") {
+        Some(whole) => whole.to_string(),
+        None => s,
+    }
+}
+
+/// A syntax error in diagram source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagramError {
+    /// 1-based source line
+    pub line: usize,
+    pub message: String,
+}
+
+impl DiagramError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for DiagramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for DiagramError {}
+
+/// Source lines without blanks and `%%` comments, numbered from 1
+fn statements(source: &str) -> impl Iterator<Item = (usize, &str)> {
+    source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("%%"))
+}
+
+fn unquote(text: &str) -> String {
+    let text = text.trim();
+    text.strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(text)
+        .to_string()
+}
+
+fn arrow_marker(id: &str) -> Markup {
+    html! {
+        defs {
+            marker
+                id={ (id) "-arrow" }
+                viewBox="0 0 10 10"
+                refX="10"
+                refY="5"
+                markerWidth="9"
+                markerHeight="9"
+                markerUnits="userSpaceOnUse"
+                orient="auto-start-reverse"
+            {
+                path class="sh-diagram__arrowhead" d="M0 0L10 5L0 10z" {}
+            }
+        }
+    }
+}
+
+/// Flow of ranks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlowDirection {
+    #[default]
+    TopDown,
+    LeftRight,
+}
+
+/// Node outline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeShape {
+    #[default]
+    Rect,
+    Rounded,
+    Stadium,
+    Diamond,
+    Circle,
+}
+
+impl NodeShape {
+    fn modifier(&self) -> &'static str {
+        match self {
+            NodeShape::Rect => "rect",
+            NodeShape::Rounded => "rounded",
+            NodeShape::Stadium => "stadium",
+            NodeShape::Diamond => "diamond",
+            NodeShape::Circle => "circle",
+        }
+    }
+}
+
+/// Edge stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Thick,
+}
+
+impl EdgeStyle {
+    fn modifier(&self) -> &'static str {
+        match self {
+            EdgeStyle::Solid => "solid",
+            EdgeStyle::Dashed => "dashed",
+            EdgeStyle::Thick => "thick",
+        }
+    }
+}
+
+/// Flowchart node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowNode {
+    pub id: String,
+    pub label: String,
+    pub shape: NodeShape,
+    /// Pin the node to a rank instead of deriving it from the edges
+    pub rank: Option<usize>,
+}
+
+impl FlowNode {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            shape: NodeShape::default(),
+            rank: None,
+        }
+    }
+
+    pub fn shape(mut self, shape: NodeShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn rank(mut self, rank: usize) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    fn size(&self) -> (f64, f64) {
+        let text = text_width(&self.label);
+        match self.shape {
+            NodeShape::Rect | NodeShape::Rounded => {
+                ((text + 2.0 * NODE_PADDING).max(56.0), NODE_HEIGHT)
+            }
+            NodeShape::Stadium => ((text + 2.0 * NODE_PADDING + NODE_HEIGHT / 2.0), NODE_HEIGHT),
+            // Wide enough that the label fits inside the slanted sides
+            NodeShape::Diamond => (((text + 2.0 * NODE_PADDING) / 0.8).max(72.0), 56.0),
+            NodeShape::Circle => {
+                let d = (text + NODE_PADDING).max(NODE_HEIGHT);
+                (d, d)
+            }
+        }
+    }
+}
+
+/// Flowchart edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowEdge {
+    pub from: String,
+    pub to: String,
+    pub label: Option<String>,
+    pub style: EdgeStyle,
+    pub arrow: bool,
+}
+
+impl FlowEdge {
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            label: None,
+            style: EdgeStyle::default(),
+            arrow: true,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn style(mut self, style: EdgeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+}
+
+/// Center and size of a laid-out box
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Placed {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+}
+
+enum Route {
+    /// Waypoints from the edge's source to its target
+    Curve(Vec<(f64, f64)>),
+    Straight((f64, f64), (f64, f64)),
+    Loop(Placed),
+}
+
+impl Route {
+    fn path(&self, direction: FlowDirection) -> String {
+        let mut d = String::new();
+        match self {
+            Route::Curve(points) => {
+                let _ = write!(d, "M{} {}", num(points[0].0), num(points[0].1));
+                for pair in points.windows(2) {
+                    let ((px, py), (qx, qy)) = (pair[0], pair[1]);
+                    let (c1, c2) = match direction {
+                        FlowDirection::TopDown => {
+                            let half = (qy - py) / 2.0;
+                            ((px, py + half), (qx, qy - half))
+                        }
+                        FlowDirection::LeftRight => {
+                            let half = (qx - px) / 2.0;
+                            ((px + half, py), (qx - half, qy))
+                        }
+                    };
+                    let _ = write!(
+                        d,
+                        "C{} {} {} {} {} {}",
+                        num(c1.0),
+                        num(c1.1),
+                        num(c2.0),
+                        num(c2.1),
+                        num(qx),
+                        num(qy)
+                    );
+                }
+            }
+            Route::Straight(p, q) => {
+                let _ = write!(d, "M{} {}L{} {}", num(p.0), num(p.1), num(q.0), num(q.1));
+            }
+            Route::Loop(node) => {
+                let right = node.x + node.w / 2.0;
+                let _ = write!(
+                    d,
+                    "M{} {}C{} {} {} {} {} {}",
+                    num(right),
+                    num(node.y - 8.0),
+                    num(right + LOOP_SIZE),
+                    num(node.y - 24.0),
+                    num(right + LOOP_SIZE),
+                    num(node.y + 24.0),
+                    num(right),
+                    num(node.y + 8.0)
+                );
+            }
+        }
+        d
+    }
+
+    /// Where the edge label sits
+    fn midpoint(&self) -> (f64, f64) {
+        match self {
+            Route::Curve(points) => {
+                // Symmetric control points put each segment's midpoint halfway
+                let i = (points.len() - 1) / 2;
+                let (p, q) = (points[i], points[(i + 1).min(points.len() - 1)]);
+                ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0)
+            }
+            Route::Straight(p, q) => ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0),
+            Route::Loop(node) => (node.x + node.w / 2.0 + LOOP_SIZE, node.y),
+        }
+    }
+}
+
+struct FlowLayout {
+    width: f64,
+    height: f64,
+    nodes: Vec<(FlowNode, Placed)>,
+    routes: Vec<Route>,
+}
+
+/// Edges that close a cycle in depth-first order
+fn back_edges(n: usize, edges: &[(usize, usize)]) -> Vec<bool> {
+    let mut out = vec![Vec::new(); n];
+    for (i, &(u, _)) in edges.iter().enumerate() {
+        out[u].push(i);
+    }
+    // 0 = unvisited, 1 = on the stack, 2 = done
+    let mut state = vec![0u8; n];
+    let mut reversed = vec![false; edges.len()];
+    for root in 0..n {
+        if state[root] != 0 {
+            continue;
+        }
+        state[root] = 1;
+        let mut stack = vec![(root, 0)];
+        while let Some((u, next)) = stack.last_mut() {
+            let u = *u;
+            match out[u].get(*next) {
+                Some(&e) => {
+                    *next += 1;
+                    let v = edges[e].1;
+                    match state[v] {
+                        0 => {
+                            state[v] = 1;
+                            stack.push((v, 0));
+                        }
+                        1 => reversed[e] = true,
+                        _ => {}
+                    }
+                }
+                None => {
+                    state[u] = 2;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    reversed
+}
+
+/// Longest-path ranks over an acyclic edge list, honouring pinned ranks
+fn assign_ranks(pinned: &[Option<usize>], dag: &[(usize, usize)]) -> Vec<usize> {
+    let n = pinned.len();
+    let mut ranks: Vec<usize> = pinned.iter().map(|r| r.unwrap_or(0)).collect();
+    let mut out = vec![Vec::new(); n];
+    let mut indegree = vec![0; n];
+    for &(u, v) in dag {
+        out[u].push(v);
+        indegree[v] += 1;
+    }
+    let mut queue: Vec<usize> = (0..n).filter(|&v| indegree[v] == 0).collect();
+    while let Some(u) = queue.pop() {
+        for &v in &out[u] {
+            if pinned[v].is_none() {
+                ranks[v] = ranks[v].max(ranks[u] + 1);
+            }
+            indegree[v] -= 1;
+            if indegree[v] == 0 {
+                queue.push(v);
+            }
+        }
+    }
+    ranks
+}
+
+/// Stable sort of one layer by the mean position of its neighbors
+fn reorder(layer: &mut [usize], neighbors: &[Vec<usize>], pos: &mut [usize]) {
+    let mut keyed: Vec<(f64, usize)> = layer
+        .iter()
+        .map(|&item| {
+            let adjacent = &neighbors[item];
+            let key = if adjacent.is_empty() {
+                pos[item] as f64
+            } else {
+                adjacent.iter().map(|&a| pos[a] as f64).sum::<f64>() / adjacent.len() as f64
+            };
+            (key, item)
+        })
+        .collect();
+    keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for (i, (_, item)) in keyed.into_iter().enumerate() {
+        layer[i] = item;
+        pos[item] = i;
+    }
+}
+
+/// Layered flowchart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flowchart {
+    pub id: String,
+    pub title: Option<String>,
+    pub direction: FlowDirection,
+    pub nodes: Vec<FlowNode>,
+    pub edges: Vec<FlowEdge>,
+}
+
+impl Default for Flowchart {
+    fn default() -> Self {
+        Self::new(FlowDirection::default())
+    }
+}
+
+impl Flowchart {
+    pub fn new(direction: FlowDirection) -> Self {
+        Self {
+            id: "sh-diagram".to_string(),
+            title: None,
+            direction,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Prefix for element ids (arrow markers); unique per page
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Caption and accessible name
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add a node, replacing any node with the same id
+    pub fn node(mut self, node: FlowNode) -> Self {
+        match self.nodes.iter_mut().find(|n| n.id == node.id) {
+            Some(existing) => *existing = node,
+            None => self.nodes.push(node),
+        }
+        self
+    }
+
+    /// Add an edge; endpoints without a node become plain boxes
+    pub fn edge(mut self, edge: FlowEdge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    /// Parse `flowchart TD` / `graph LR` source
+    pub fn parse(source: &str) -> Result<Self, DiagramError> {
+        let mut lines = statements(source);
+        let (line, header) = lines
+            .next()
+            .ok_or_else(|| DiagramError::new(1, "empty diagram"))?;
+        let mut words = header.split_whitespace();
+        if !matches!(words.next(), Some("flowchart" | "graph")) {
+            return Err(DiagramError::new(line, "expected `flowchart` or `graph`"));
+        }
+        let direction = match words.next() {
+            None | Some("TD" | "TB") => FlowDirection::TopDown,
+            Some("LR") => FlowDirection::LeftRight,
+            Some(other) => {
+                return Err(DiagramError::new(
+                    line,
+                    format!("unsupported direction `{}`", other),
+                ))
+            }
+        };
+
+        let mut chart = Flowchart::new(direction);
+        for (line, text) in lines {
+            let keyword = text.split_whitespace().next().unwrap_or_default();
+            match keyword {
+                // Styling directives have no effect on layout
+                "style" | "classDef" | "class" | "linkStyle" | "click" => continue,
+                "subgraph" | "end" => {
+                    return Err(DiagramError::new(line, "subgraphs are not supported"))
+                }
+                _ => {}
+            }
+            for statement in text.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+                chart
+                    .parse_statement(statement)
+                    .map_err(|message| DiagramError::new(line, message))?;
+            }
+        }
+        Ok(chart)
+    }
+
+    fn parse_statement(&mut self, statement: &str) -> Result<(), String> {
+        let (mut previous, mut rest) = self.parse_node_ref(statement)?;
+        while !rest.is_empty() {
+            let (edge, after) = parse_edge_op(rest)?;
+            let (next, after) = self.parse_node_ref(after.trim_start())?;
+            self.edges.push(FlowEdge {
+                from: previous,
+                to: next.clone(),
+                ..edge
+            });
+            previous = next;
+            rest = after;
+        }
+        Ok(())
+    }
+
+    /// `id`, optionally followed by a shaped label like `[text]` or `{text}`
+    fn parse_node_ref<'s>(&mut self, input: &'s str) -> Result<(String, &'s str), String> {
+        let input = input.trim_start();
+        let id_len = input
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(input.len());
+        if id_len == 0 {
+            return Err(format!("expected a node id at `{}`", input));
+        }
+        let id = input[..id_len].to_string();
+        let rest = &input[id_len..];
+
+        const SHAPES: [(&str, &str, NodeShape); 5] = [
+            ("((", "))", NodeShape::Circle),
+            ("([", "])", NodeShape::Stadium),
+            ("[", "]", NodeShape::Rect),
+            ("(", ")", NodeShape::Rounded),
+            ("{", "}", NodeShape::Diamond),
+        ];
+        for (open, close, shape) in SHAPES {
+            if let Some(body) = rest.strip_prefix(open) {
+                let end = body
+                    .find(close)
+                    .ok_or_else(|| format!("unclosed `{}` after `{}`", open, id))?;
+                let label = unquote(&body[..end]);
+                *self = std::mem::take(self).node(FlowNode::new(id.clone(), label).shape(shape));
+                return Ok((id, body[end + close.len()..].trim_start()));
+            }
+        }
+
+        if !self.nodes.iter().any(|n| n.id == id) {
+            self.nodes.push(FlowNode::new(id.clone(), id.clone()));
+        }
+        Ok((id, rest.trim_start()))
+    }
+
+    /// Nodes plus implicit ones named only by edges
+    fn all_nodes(&self) -> Vec<FlowNode> {
+        let mut nodes = self.nodes.clone();
+        for edge in &self.edges {
+            for id in [&edge.from, &edge.to] {
+                if !nodes.iter().any(|n| &n.id == id) {
+                    nodes.push(FlowNode::new(id.clone(), id.clone()));
+                }
+            }
+        }
+        nodes
+    }
+
+    fn layout(&self) -> FlowLayout {
+        let nodes = self.all_nodes();
+        let n = nodes.len();
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+        let edges: Vec<(usize, usize)> = self
+            .edges
+            .iter()
+            .map(|e| (index[e.from.as_str()], index[e.to.as_str()]))
+            .collect();
+
+        let reversed = back_edges(n, &edges);
+        let dag: Vec<(usize, usize)> = edges
+            .iter()
+            .zip(&reversed)
+            .filter(|((u, v), _)| u != v)
+            .map(|(&(u, v), &rev)| if rev { (v, u) } else { (u, v) })
+            .collect();
+        let pinned: Vec<Option<usize>> = nodes.iter().map(|node| node.rank).collect();
+        let mut ranks = assign_ranks(&pinned, &dag);
+
+        // Layout items: real nodes first, then one waypoint per skipped rank
+        let horizontal = self.direction == FlowDirection::LeftRight;
+        let mut sizes: Vec<(f64, f64)> = nodes.iter().map(FlowNode::size).collect();
+        let mut chains: Vec<Option<(Vec<usize>, bool)>> = Vec::with_capacity(edges.len());
+        for &(u, v) in &edges {
+            if u == v || ranks[u] == ranks[v] {
+                chains.push(None);
+                continue;
+            }
+            let (top, bottom, flipped) = if ranks[u] < ranks[v] {
+                (u, v, false)
+            } else {
+                (v, u, true)
+            };
+            let mut chain = vec![top];
+            for rank in ranks[top] + 1..ranks[bottom] {
+                chain.push(ranks.len());
+                ranks.push(rank);
+                sizes.push(if horizontal {
+                    (0.0, DUMMY_SIZE)
+                } else {
+                    (DUMMY_SIZE, 0.0)
+                });
+            }
+            chain.push(bottom);
+            chains.push(Some((chain, flipped)));
+        }
+
+        let items = ranks.len();
+        let mut up = vec![Vec::new(); items];
+        let mut down = vec![Vec::new(); items];
+        for (chain, _) in chains.iter().flatten() {
+            for pair in chain.windows(2) {
+                up[pair[1]].push(pair[0]);
+                down[pair[0]].push(pair[1]);
+            }
+        }
+        let depth = ranks.iter().max().map_or(0, |r| r + 1);
+        let mut layers = vec![Vec::new(); depth];
+        let mut pos = vec![0; items];
+        for (item, &rank) in ranks.iter().enumerate() {
+            pos[item] = layers[rank].len();
+            layers[rank].push(item);
+        }
+        for _ in 0..4 {
+            for layer in layers.iter_mut().skip(1) {
+                reorder(layer, &up, &mut pos);
+            }
+            for layer in layers.iter_mut().rev().skip(1) {
+                reorder(layer, &down, &mut pos);
+            }
+        }
+
+        // Main axis runs along the ranks, cross axis along each layer
+        let axes = |(w, h): (f64, f64)| if horizontal { (w, h) } else { (h, w) };
+        let thickness: Vec<f64> = layers
+            .iter()
+            .map(|layer| layer.iter().map(|&i| axes(sizes[i]).0).fold(0.0, f64::max))
+            .collect();
+        let totals: Vec<f64> = layers
+            .iter()
+            .map(|layer| {
+                layer.iter().map(|&i| axes(sizes[i]).1).sum::<f64>()
+                    + NODE_GAP * layer.len().saturating_sub(1) as f64
+            })
+            .collect();
+        let widest = totals.iter().cloned().fold(0.0, f64::max);
+
+        let mut placed = vec![
+            Placed {
+                x: 0.0,
+                y: 0.0,
+                w: 0.0,
+                h: 0.0
+            };
+            items
+        ];
+        let mut main = MARGIN;
+        for (r, layer) in layers.iter().enumerate() {
+            let center_main = main + thickness[r] / 2.0;
+            let mut cross = MARGIN + (widest - totals[r]) / 2.0;
+            for &item in layer {
+                let (w, h) = sizes[item];
+                let size = axes(sizes[item]).1;
+                let center_cross = cross + size / 2.0;
+                let (x, y) = if horizontal {
+                    (center_main, center_cross)
+                } else {
+                    (center_cross, center_main)
+                };
+                placed[item] = Placed { x, y, w, h };
+                cross += size + NODE_GAP;
+            }
+            main += thickness[r] + RANK_GAP;
+        }
+
+        let exit = |p: &Placed| {
+            if horizontal {
+                (p.x + p.w / 2.0, p.y)
+            } else {
+                (p.x, p.y + p.h / 2.0)
+            }
+        };
+        let entry = |p: &Placed| {
+            if horizontal {
+                (p.x - p.w / 2.0, p.y)
+            } else {
+                (p.x, p.y - p.h / 2.0)
+            }
+        };
+        let routes: Vec<Route> = edges
+            .iter()
+            .zip(chains)
+            .map(|(&(u, v), chain)| match chain {
+                Some((chain, flipped)) => {
+                    let last = chain.len() - 1;
+                    let mut points: Vec<(f64, f64)> = chain
+                        .iter()
+                        .enumerate()
+                        .map(|(k, &item)| match k {
+                            0 => exit(&placed[item]),
+                            k if k == last => entry(&placed[item]),
+                            _ => (placed[item].x, placed[item].y),
+                        })
+                        .collect();
+                    if flipped {
+                        points.reverse();
+                    }
+                    Route::Curve(points)
+                }
+                None if u == v => Route::Loop(placed[u]),
+                None => {
+                    let (a, b) = (placed[u], placed[v]);
+                    let sign = |d: f64| if d >= 0.0 { 1.0 } else { -1.0 };
+                    if horizontal {
+                        let s = sign(b.y - a.y);
+                        Route::Straight((a.x, a.y + s * a.h / 2.0), (b.x, b.y - s * b.h / 2.0))
+                    } else {
+                        let s = sign(b.x - a.x);
+                        Route::Straight((a.x + s * a.w / 2.0, a.y), (b.x - s * b.w / 2.0, b.y))
+                    }
+                }
+            })
+            .collect();
+
+        let mut width: f64 = 0.0;
+        let mut height: f64 = 0.0;
+        for p in &placed[..n] {
+            width = width.max(p.x + p.w / 2.0);
+            height = height.max(p.y + p.h / 2.0);
+        }
+        for route in &routes {
+            if let Route::Loop(p) = route {
+                width = width.max(p.x + p.w / 2.0 + LOOP_SIZE);
+            }
+        }
+
+        FlowLayout {
+            width: width + MARGIN,
+            height: height + MARGIN,
+            nodes: nodes.into_iter().zip(placed).collect(),
+            routes,
+        }
+    }
+
+    fn svg(&self) -> Markup {
+        let layout = self.layout();
+        let label = self.title.as_deref().unwrap_or("Flowchart");
+        let marker = format!("url(#{}-arrow)", self.id);
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                class="sh-diagram__svg"
+                viewBox={ "0 0 " (num(layout.width)) " " (num(layout.height)) }
+                width=(num(layout.width))
+                height=(num(layout.height))
+                role="img"
+                aria-label=(label)
+            {
+                (arrow_marker(&self.id))
+                g class="sh-diagram__edges" {
+                    @for (edge, route) in self.edges.iter().zip(&layout.routes) {
+                        path
+                            class={ "sh-diagram__edge sh-diagram__edge--" (edge.style.modifier()) }
+                            d=(route.path(self.direction))
+                            marker-end=[edge.arrow.then_some(&marker)]
+                        {}
+                    }
+                }
+                g class="sh-diagram__nodes" {
+                    @for (node, p) in &layout.nodes {
+                        g class={ "sh-diagram__node sh-diagram__node--" (node.shape.modifier()) } {
+                            (node_shape(node.shape, p))
+                            text
+                                class="sh-diagram__text"
+                                x=(num(p.x))
+                                y=(num(p.y))
+                                text-anchor="middle"
+                                dominant-baseline="central"
+                            { (node.label) }
+                        }
+                    }
+                }
+                g class="sh-diagram__edge-labels" {
+                    @for (edge, route) in self.edges.iter().zip(&layout.routes) {
+                        @if let Some(text) = &edge.label {
+                            (edge_label(text, route.midpoint()))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Standalone SVG document
+    pub fn to_svg(&self) -> String {
+        self.svg().into_string()
+    }
+}
+
+fn node_shape(shape: NodeShape, p: &Placed) -> Markup {
+    let (left, top) = (p.x - p.w / 2.0, p.y - p.h / 2.0);
+    let radius = match shape {
+        NodeShape::Rect => 4.0,
+        NodeShape::Rounded => 12.0,
+        _ => p.h / 2.0,
+    };
+    match shape {
+        NodeShape::Diamond => html! {
+            polygon class="sh-diagram__shape" points=(format!(
+                "{},{} {},{} {},{} {},{}",
+                num(p.x), num(top),
+                num(left + p.w), num(p.y),
+                num(p.x), num(top + p.h),
+                num(left), num(p.y)
+            )) {}
+        },
+        NodeShape::Circle => html! {
+            circle class="sh-diagram__shape" cx=(num(p.x)) cy=(num(p.y)) r=(num(p.w / 2.0)) {}
+        },
+        _ => html! {
+            rect
+                class="sh-diagram__shape"
+                x=(num(left))
+                y=(num(top))
+                width=(num(p.w))
+                height=(num(p.h))
+                rx=(num(radius))
+            {}
+        },
+    }
+}
+
+fn edge_label(text: &str, (x, y): (f64, f64)) -> Markup {
+    let w = text_width(text) * 0.85 + 8.0;
+    html! {
+        g class="sh-diagram__edge-label" {
+            rect x=(num(x - w / 2.0)) y=(num(y - 9.0)) width=(num(w)) height="18" rx="3" {}
+            text x=(num(x)) y=(num(y)) text-anchor="middle" dominant-baseline="central" {
+                (text)
+            }
+        }
+    }
+}
+
+/// `-->`, `---`, `-.->`, `==>`, optionally labelled as `-->|text|` or `-- text -->`
+fn parse_edge_op(input: &str) -> Result<(FlowEdge, &str), String> {
+    const OPS: [(&str, EdgeStyle, bool); 6] = [
+        ("-.->", EdgeStyle::Dashed, true),
+        ("-.-", EdgeStyle::Dashed, false),
+        ("==>", EdgeStyle::Thick, true),
+        ("===", EdgeStyle::Thick, false),
+        ("-->", EdgeStyle::Solid, true),
+        ("---", EdgeStyle::Solid, false),
+    ];
+    const LABELLED: [(&str, &str, &str, EdgeStyle); 3] = [
+        ("--", "-->", "---", EdgeStyle::Solid),
+        ("-.", ".->", ".-", EdgeStyle::Dashed),
+        ("==", "==>", "===", EdgeStyle::Thick),
+    ];
+    let edge = |style, arrow, label: Option<String>| FlowEdge {
+        from: String::new(),
+        to: String::new(),
+        label,
+        style,
+        arrow,
+    };
+
+    for (op, style, arrow) in OPS {
+        if let Some(rest) = input.strip_prefix(op) {
+            let rest = rest.trim_start();
+            if let Some(body) = rest.strip_prefix('|') {
+                let end = body.find('|').ok_or("unclosed `|` edge label")?;
+                return Ok((
+                    edge(style, arrow, Some(unquote(&body[..end]))),
+                    &body[end + 1..],
+                ));
+            }
+            return Ok((edge(style, arrow, None), rest));
+        }
+    }
+    for (open, with_arrow, without_arrow, style) in LABELLED {
+        if let Some(body) = input.strip_prefix(open) {
+            let found = [(with_arrow, true), (without_arrow, false)]
+                .into_iter()
+                .filter_map(|(close, arrow)| body.find(close).map(|at| (at, close, arrow)))
+                .min_by_key(|(at, close, _)| (*at, usize::MAX - close.len()));
+            if let Some((at, close, arrow)) = found {
+                let label = unquote(&body[..at]);
+                return Ok((edge(style, arrow, Some(label)), &body[at + close.len()..]));
+            }
+        }
+    }
+    Err(format!("expected an edge like `-->` at `{}`", input))
+}
+
+impl Render for Flowchart {
+    fn render(&self) -> Markup {
+        html! {
+            figure class="sh-diagram sh-diagram--flowchart" {
+                (self.svg())
+                @if let Some(title) = &self.title {
+                    figcaption class="sh-diagram__caption" { (title) }
+                }
+            }
+        }
+    }
+}
+
+/// Arrow drawn for a sequence message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageKind {
+    /// `->>` solid line with arrowhead
+    #[default]
+    Sync,
+    /// `-->>` dashed line with arrowhead
+    Reply,
+    /// `->` solid line
+    Line,
+    /// `-->` dashed line
+    DashedLine,
+}
+
+impl MessageKind {
+    fn dashed(&self) -> bool {
+        matches!(self, MessageKind::Reply | MessageKind::DashedLine)
+    }
+
+    fn arrow(&self) -> bool {
+        matches!(self, MessageKind::Sync | MessageKind::Reply)
+    }
+}
+
+/// Sequence diagram column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Participant {
+    pub id: String,
+    pub label: String,
+}
+
+/// Message between two participants (or a participant and itself)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub from: String,
+    pub to: String,
+    pub label: String,
+    pub kind: MessageKind,
+}
+
+impl Message {
+    pub fn new(from: impl Into<String>, to: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            label: label.into(),
+            kind: MessageKind::default(),
+        }
+    }
+
+    pub fn kind(mut self, kind: MessageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Where a note sits relative to its participants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotePlacement {
+    #[default]
+    Over,
+    LeftOf,
+    RightOf,
+}
+
+/// Annotation spanning one or two participants
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    pub participants: Vec<String>,
+    pub text: String,
+    pub placement: NotePlacement,
+}
+
+impl Note {
+    pub fn over(participant: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            participants: vec![participant.into()],
+            text: text.into(),
+            placement: NotePlacement::Over,
+        }
+    }
+
+    /// Note stretched across two participants
+    pub fn spanning(
+        first: impl Into<String>,
+        last: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            participants: vec![first.into(), last.into()],
+            text: text.into(),
+            placement: NotePlacement::Over,
+        }
+    }
+
+    pub fn placement(mut self, placement: NotePlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+/// One row of a sequence diagram
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceStep {
+    Message(Message),
+    Note(Note),
+}
+
+/// Participants exchanging messages over time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceDiagram {
+    pub id: String,
+    pub title: Option<String>,
+    pub participants: Vec<Participant>,
+    pub steps: Vec<SequenceStep>,
+}
+
+impl Default for SequenceDiagram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SequenceDiagram {
+    pub fn new() -> Self {
+        Self {
+            id: "sh-diagram".to_string(),
+            title: None,
+            participants: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Prefix for element ids (arrow markers); unique per page
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Caption and accessible name
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Declare a participant; columns follow declaration order
+    pub fn participant(mut self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        let id = id.into();
+        let label = label.into();
+        match self.participants.iter_mut().find(|p| p.id == id) {
+            Some(existing) => existing.label = label,
+            None => self.participants.push(Participant { id, label }),
+        }
+        self
+    }
+
+    pub fn message(mut self, message: Message) -> Self {
+        self.steps.push(SequenceStep::Message(message));
+        self
+    }
+
+    pub fn note(mut self, note: Note) -> Self {
+        self.steps.push(SequenceStep::Note(note));
+        self
+    }
+
+    /// Parse `sequenceDiagram` source
+    pub fn parse(source: &str) -> Result<Self, DiagramError> {
+        let mut lines = statements(source);
+        match lines.next() {
+            Some((_, "sequenceDiagram")) => {}
+            Some((line, _)) => return Err(DiagramError::new(line, "expected `sequenceDiagram`")),
+            None => return Err(DiagramError::new(1, "empty diagram")),
+        }
+
+        let mut diagram = SequenceDiagram::new();
+        for (line, text) in lines {
+            let error = |message: &str| DiagramError::new(line, message);
+            if let Some(rest) = text
+                .strip_prefix("participant ")
+                .or_else(|| text.strip_prefix("actor "))
+            {
+                let (id, label) = match rest.split_once(" as ") {
+                    Some((id, label)) => (id.trim(), label.trim()),
+                    None => (rest.trim(), rest.trim()),
+                };
+                diagram = diagram.participant(id, label);
+            } else if let Some(rest) = text.strip_prefix("Note ") {
+                let (target, body) = rest
+                    .split_once(':')
+                    .ok_or_else(|| error("note needs `:`"))?;
+                let (placement, who) = if let Some(who) = target.strip_prefix("over ") {
+                    (NotePlacement::Over, who)
+                } else if let Some(who) = target.strip_prefix("left of ") {
+                    (NotePlacement::LeftOf, who)
+                } else if let Some(who) = target.strip_prefix("right of ") {
+                    (NotePlacement::RightOf, who)
+                } else {
+                    return Err(error("expected `over`, `left of` or `right of`"));
+                };
+                diagram.steps.push(SequenceStep::Note(Note {
+                    participants: who.split(',').map(|p| p.trim().to_string()).collect(),
+                    text: body.trim().to_string(),
+                    placement,
+                }));
+            } else {
+                const ARROWS: [(&str, MessageKind); 4] = [
+                    ("-->>", MessageKind::Reply),
+                    ("->>", MessageKind::Sync),
+                    ("-->", MessageKind::DashedLine),
+                    ("->", MessageKind::Line),
+                ];
+                let (at, arrow, kind) = ARROWS
+                    .iter()
+                    .filter_map(|&(arrow, kind)| text.find(arrow).map(|at| (at, arrow, kind)))
+                    .min_by_key(|(at, arrow, _)| (*at, usize::MAX - arrow.len()))
+                    .ok_or_else(|| error("expected a message like `A->>B: text`"))?;
+                let from = text[..at].trim();
+                let rest = &text[at + arrow.len()..];
+                let (to, label) = rest.split_once(':').unwrap_or((rest, ""));
+                let to = to.trim();
+                if from.is_empty() || to.is_empty() {
+                    return Err(error("message needs a sender and a receiver"));
+                }
+                diagram.steps.push(SequenceStep::Message(
+                    Message::new(from, to, label.trim()).kind(kind),
+                ));
+            }
+        }
+        Ok(diagram)
+    }
+
+    /// Declared participants plus any only named by steps
+    fn all_participants(&self) -> Vec<Participant> {
+        let mut all = self.participants.clone();
+        let mut add = |id: &str| {
+            if !all.iter().any(|p| p.id == id) {
+                all.push(Participant {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                });
+            }
+        };
+        for step in &self.steps {
+            match step {
+                SequenceStep::Message(m) => {
+                    add(&m.from);
+                    add(&m.to);
+                }
+                SequenceStep::Note(note) => note.participants.iter().for_each(|p| add(p)),
+            }
+        }
+        all
+    }
+
+    fn svg(&self) -> Markup {
+        let participants = self.all_participants();
+        let column: HashMap<&str, usize> = participants
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (p.id.as_str(), i))
+            .collect();
+        let widths: Vec<f64> = participants
+            .iter()
+            .map(|p| (text_width(&p.label) + 28.0).max(80.0))
+            .collect();
+
+        // Column spacing grows to fit labels between neighbours
+        let mut gaps: Vec<f64> = widths
+            .windows(2)
+            .map(|w| ACTOR_GAP + (w[0] + w[1]) / 2.0)
+            .collect();
+        for step in &self.steps {
+            if let SequenceStep::Message(m) = step {
+                let (a, b) = (column[m.from.as_str()], column[m.to.as_str()]);
+                let need = if a == b {
+                    LOOP_SIZE + 16.0 + text_width(&m.label) + widths[a] / 2.0
+                } else {
+                    (text_width(&m.label) + 24.0) / a.abs_diff(b) as f64
+                };
+                for gap in gaps.iter_mut().take(a.max(b)).skip(a.min(b)) {
+                    *gap = gap.max(need);
+                }
+            }
+        }
+        let mut centers: Vec<f64> = Vec::with_capacity(widths.len());
+        for (i, w) in widths.iter().enumerate() {
+            let center = match i {
+                0 => MARGIN + w / 2.0,
+                _ => centers[i - 1] + gaps[i - 1],
+            };
+            centers.push(center);
+        }
+
+        let top = MARGIN;
+        let mut y = top + ACTOR_HEIGHT + 8.0;
+        let mut right = centers
+            .last()
+            .zip(widths.last())
+            .map_or(MARGIN, |(c, w)| c + w / 2.0);
+        let mut rows = Vec::new();
+        for step in &self.steps {
+            match step {
+                SequenceStep::Message(m) => {
+                    let (a, b) = (column[m.from.as_str()], column[m.to.as_str()]);
+                    let (x1, x2) = (centers[a], centers[b]);
+                    let line_y = y + 22.0;
+                    let class = if m.kind.dashed() {
+                        "sh-diagram__message sh-diagram__message--dashed"
+                    } else {
+                        "sh-diagram__message"
+                    };
+                    let (d, label_x, anchor) = if a == b {
+                        right = right.max(x1 + LOOP_SIZE + 10.0 + text_width(&m.label));
+                        y += SELF_MESSAGE_HEIGHT;
+                        (
+                            format!(
+                                "M{} {}h{}v18h-{}",
+                                num(x1),
+                                num(line_y),
+                                num(LOOP_SIZE),
+                                num(LOOP_SIZE)
+                            ),
+                            x1 + LOOP_SIZE + 6.0,
+                            "start",
+                        )
+                    } else {
+                        y += MESSAGE_HEIGHT;
+                        (
+                            format!("M{} {}H{}", num(x1), num(line_y), num(x2)),
+                            (x1 + x2) / 2.0,
+                            "middle",
+                        )
+                    };
+                    let label_y = if a == b { line_y + 13.0 } else { line_y - 8.0 };
+                    rows.push(html! {
+                        path
+                            class=(class)
+                            d=(d)
+                            marker-end=[m.kind.arrow().then(|| format!("url(#{}-arrow)", self.id))]
+                        {}
+                        @if !m.label.is_empty() {
+                            text
+                                class="sh-diagram__message-label"
+                                x=(num(label_x))
+                                y=(num(label_y))
+                                text-anchor=(anchor)
+                            { (m.label) }
+                        }
+                    });
+                }
+                SequenceStep::Note(note) => {
+                    let cols: Vec<usize> = note
+                        .participants
+                        .iter()
+                        .map(|p| column[p.as_str()])
+                        .collect();
+                    let first = cols.iter().copied().min().unwrap_or(0);
+                    let last = cols.iter().copied().max().unwrap_or(0);
+                    let text_w = text_width(&note.text) + 16.0;
+                    let (left, w) = match note.placement {
+                        NotePlacement::Over => {
+                            let span = centers[last] - centers[first] + 40.0;
+                            let w = text_w.max(span);
+                            ((centers[first] + centers[last]) / 2.0 - w / 2.0, w)
+                        }
+                        NotePlacement::LeftOf => (centers[first] - 10.0 - text_w, text_w),
+                        NotePlacement::RightOf => (centers[last] + 10.0, text_w),
+                    };
+                    right = right.max(left + w);
+                    let note_y = y + 8.0;
+                    y += NOTE_HEIGHT + 16.0;
+                    rows.push(html! {
+                        g class="sh-diagram__note" {
+                            rect
+                                x=(num(left))
+                                y=(num(note_y))
+                                width=(num(w))
+                                height=(num(NOTE_HEIGHT))
+                                rx="3"
+                            {}
+                            text
+                                x=(num(left + w / 2.0))
+                                y=(num(note_y + NOTE_HEIGHT / 2.0))
+                                text-anchor="middle"
+                                dominant-baseline="central"
+                            { (note.text) }
+                        }
+                    });
+                }
+            }
+        }
+
+        let bottom = y + 8.0;
+        let width = right + MARGIN;
+        let height = bottom + ACTOR_HEIGHT + MARGIN;
+        let label = self.title.as_deref().unwrap_or("Sequence diagram");
+        let actor = |i: usize, actor_y: f64| {
+            let (x, w) = (centers[i], widths[i]);
+            html! {
+                g class="sh-diagram__actor" {
+                    rect
+                        class="sh-diagram__shape"
+                        x=(num(x - w / 2.0))
+                        y=(num(actor_y))
+                        width=(num(w))
+                        height=(num(ACTOR_HEIGHT))
+                        rx="4"
+                    {}
+                    text
+                        class="sh-diagram__text"
+                        x=(num(x))
+                        y=(num(actor_y + ACTOR_HEIGHT / 2.0))
+                        text-anchor="middle"
+                        dominant-baseline="central"
+                    { (participants[i].label) }
+                }
+            }
+        };
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                class="sh-diagram__svg"
+                viewBox={ "0 0 " (num(width)) " " (num(height)) }
+                width=(num(width))
+                height=(num(height))
+                role="img"
+                aria-label=(label)
+            {
+                (arrow_marker(&self.id))
+                @for (i, x) in centers.iter().enumerate() {
+                    line
+                        class="sh-diagram__lifeline"
+                        x1=(num(*x))
+                        y1=(num(top + ACTOR_HEIGHT))
+                        x2=(num(*x))
+                        y2=(num(bottom))
+                    {}
+                    (actor(i, top))
+                    (actor(i, bottom))
+                }
+                @for row in rows {
+                    (row)
+                }
+            }
+        }
+    }
+
+    /// Standalone SVG document
+    pub fn to_svg(&self) -> String {
+        self.svg().into_string()
+    }
+}
+
+impl Render for SequenceDiagram {
+    fn render(&self) -> Markup {
+        html! {
+            figure class="sh-diagram sh-diagram--sequence" {
+                (self.svg())
+                @if let Some(title) = &self.title {
+                    figcaption class="sh-diagram__caption" { (title) }
+                }
+            }
+        }
+    }
+}
+
+/// Any diagram, picked from the source header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagram {
+    Flowchart(Flowchart),
+    Sequence(SequenceDiagram),
+}
+
+impl Diagram {
+    /// Parse source starting with `flowchart`/`graph` or `sequenceDiagram`
+    pub fn parse(source: &str) -> Result<Self, DiagramError> {
+        match statements(source).next() {
+            Some((_, header)) if header.starts_with("sequenceDiagram") => {
+                SequenceDiagram::parse(source).map(Diagram::Sequence)
+            }
+            Some((_, header)) if header.starts_with("flowchart") || header.starts_with("graph") => {
+                Flowchart::parse(source).map(Diagram::Flowchart)
+            }
+            Some((line, _)) => Err(DiagramError::new(line, "unknown diagram type")),
+            None => Err(DiagramError::new(1, "empty diagram")),
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        match self {
+            Diagram::Flowchart(chart) => chart.to_svg(),
+            Diagram::Sequence(diagram) => diagram.to_svg(),
+        }
+    }
+}
+
+impl Render for Diagram {
+    fn render(&self) -> Markup {
+        match self {
+            Diagram::Flowchart(chart) => chart.render(),
+            Diagram::Sequence(diagram) => diagram.render(),
+        }
+    }
+}
+
+pub fn diagram_css() -> String {
+    r#"
+.sh-diagram {
+    margin: 1.5rem 0;
+    max-width: 100%;
+    overflow-x: auto;
+}
+
+.sh-diagram__svg {
+    display: block;
+    max-width: 100%;
+    height: auto;
+    margin-inline: auto;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 14px;
+}
+
+.sh-diagram__shape {
+    fill: var(--sh-surface, #ffffff);
+    stroke: var(--sh-accent, #8b5cf6);
+    stroke-width: 1.5;
+}
+
+.sh-diagram__node--diamond .sh-diagram__shape {
+    fill: var(--sh-surface-alt, #f5f3ff);
+}
+
+.sh-diagram__text {
+    fill: var(--sh-text, #111827);
+}
+
+.sh-diagram__edge,
+.sh-diagram__message {
+    fill: none;
+    stroke: var(--sh-text-muted, #6b7280);
+    stroke-width: 1.5;
+}
+
+.sh-diagram__edge--dashed,
+.sh-diagram__message--dashed {
+    stroke-dasharray: 5 4;
+}
+
+.sh-diagram__edge--thick {
+    stroke-width: 3;
+}
+
+.sh-diagram__arrowhead {
+    fill: var(--sh-text-muted, #6b7280);
+}
+
+.sh-diagram__edge-label rect {
+    fill: var(--sh-surface, #ffffff);
+}
+
+.sh-diagram__edge-label text,
+.sh-diagram__message-label {
+    fill: var(--sh-text-muted, #6b7280);
+    font-size: 12px;
+}
+
+.sh-diagram__lifeline {
+    stroke: var(--sh-border, #e5e7eb);
+    stroke-width: 1.5;
+    stroke-dasharray: 4 4;
+}
+
+.sh-diagram__note rect {
+    fill: var(--sh-warning-bg, #fef9c3);
+    stroke: var(--sh-warning, #eab308);
+}
+
+.sh-diagram__note text {
+    fill: var(--sh-text, #111827);
+    font-size: 12px;
+}
+
+.sh-diagram__caption {
+    margin-top: 0.5rem;
+    text-align: center;
+    font-size: var(--sh-font-size-sm, 0.875rem);
+    color: var(--sh-text-muted, #6b7280);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placed<'a>(layout: &'a FlowLayout, id: &str) -> &'a Placed {
+        &layout.nodes.iter().find(|(n, _)| n.id == id).unwrap().1
+    }
+
+    #[test]
+    fn test_parse_flowchart() {
+        let chart = Flowchart::parse(
+            "flowchart LR
+            %% comment
+            A[Start] --> B{Ready?}
+            B -->|yes| C([Ship])
+            B -- not yet --> D(Fix) -.-> B
+            C === E((Done)); E --- F",
+        )
+        .unwrap();
+
+        assert_eq!(chart.direction, FlowDirection::LeftRight);
+        let shapes: Vec<_> = chart
+            .nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n.shape))
+            .collect();
+        assert_eq!(
+            shapes,
+            vec![
+                ("A", NodeShape::Rect),
+                ("B", NodeShape::Diamond),
+                ("C", NodeShape::Stadium),
+                ("D", NodeShape::Rounded),
+                ("E", NodeShape::Circle),
+                ("F", NodeShape::Rect),
+            ]
+        );
+        assert_eq!(chart.nodes[1].label, "Ready?");
+        assert_eq!(chart.nodes[5].label, "F");
+        assert_eq!(chart.edges.len(), 6);
+        assert_eq!(chart.edges[1].label.as_deref(), Some("yes"));
+        assert_eq!(chart.edges[2].label.as_deref(), Some("not yet"));
+        assert_eq!(
+            (chart.edges[3].from.as_str(), chart.edges[3].to.as_str()),
+            ("D", "B")
+        );
+        assert_eq!(chart.edges[3].style, EdgeStyle::Dashed);
+        assert_eq!(chart.edges[4].style, EdgeStyle::Thick);
+        assert!(!chart.edges[4].arrow);
+        assert!(!chart.edges[5].arrow);
+    }
+
+    #[test]
+    fn test_parse_flowchart_errors() {
+        assert_eq!(Flowchart::parse("").unwrap_err().line, 1);
+        assert!(Flowchart::parse("flowchart RL").is_err());
+        let err = Flowchart::parse("graph TD\nA --> B\nA ~~ C").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert!(err.to_string().starts_with("line 3: expected an edge"));
+        assert!(Flowchart::parse("graph TD\nA[open").is_err());
+        assert!(Flowchart::parse("graph TD\nsubgraph one").is_err());
+        assert!(Flowchart::parse("graph TD\nA --> B\nstyle A fill:#f9f").is_ok());
+    }
+
+    #[test]
+    fn test_ranks_and_waypoints() {
+        let chart = Flowchart::new(FlowDirection::TopDown)
+            .edge(FlowEdge::new("a", "b"))
+            .edge(FlowEdge::new("b", "c"))
+            .edge(FlowEdge::new("a", "c"));
+        let layout = chart.layout();
+        let (a, b, c) = (
+            placed(&layout, "a"),
+            placed(&layout, "b"),
+            placed(&layout, "c"),
+        );
+        assert!(a.y < b.y && b.y < c.y);
+
+        // The a -> c edge skips a rank and bends through a waypoint
+        match &layout.routes[2] {
+            Route::Curve(points) => {
+                assert_eq!(points.len(), 3);
+                assert_eq!(points[0], (a.x, a.y + a.h / 2.0));
+                assert_eq!(points[2], (c.x, c.y - c.h / 2.0));
+            }
+            _ => panic!("expected a curve"),
+        }
+    }
+
+    #[test]
+    fn test_cycles_and_self_loops() {
+        let chart = Flowchart::parse("graph TD\nA --> B --> C --> A\nB --> B").unwrap();
+        let layout = chart.layout();
+        assert!(placed(&layout, "A").y < placed(&layout, "B").y);
+        assert!(placed(&layout, "B").y < placed(&layout, "C").y);
+        // The back edge starts at C and ends at A
+        match &layout.routes[2] {
+            Route::Curve(points) => {
+                assert!(points[0].1 > points[points.len() - 1].1);
+            }
+            _ => panic!("expected a curve"),
+        }
+        assert!(matches!(layout.routes[3], Route::Loop(_)));
+    }
+
+    #[test]
+    fn test_pinned_rank_and_direction() {
+        let chart = Flowchart::new(FlowDirection::LeftRight)
+            .node(FlowNode::new("a", "A"))
+            .node(FlowNode::new("z", "Z").rank(3))
+            .edge(FlowEdge::new("a", "b"));
+        let layout = chart.layout();
+        assert!(placed(&layout, "a").x < placed(&layout, "b").x);
+        assert!(placed(&layout, "b").x < placed(&layout, "z").x);
+    }
+
+    #[test]
+    fn test_barycenter_uncrosses_edges() {
+        let chart = Flowchart::new(FlowDirection::TopDown)
+            .node(FlowNode::new("a", "A"))
+            .node(FlowNode::new("b", "B"))
+            .node(FlowNode::new("c", "C"))
+            .node(FlowNode::new("d", "D"))
+            .edge(FlowEdge::new("a", "d"))
+            .edge(FlowEdge::new("b", "c"));
+        let layout = chart.layout();
+        assert!(placed(&layout, "a").x < placed(&layout, "b").x);
+        assert!(placed(&layout, "d").x < placed(&layout, "c").x);
+    }
+
+    #[test]
+    fn test_flowchart_render() {
+        let html = Flowchart::parse("graph TD\nA{Choice} -->|go| B((End))\nA -.- C")
+            .unwrap()
+            .id("flow")
+            .title("Decision flow")
+            .render()
+            .into_string();
+
+        assert!(html.starts_with(r#"<figure class="sh-diagram sh-diagram--flowchart">"#));
+        assert!(html.contains(r#"<marker id="flow-arrow""#));
+        assert!(html.contains(r#"aria-label="Decision flow""#));
+        assert!(html.contains(r#"class="sh-diagram__node sh-diagram__node--diamond"><polygon"#));
+        assert!(html.contains(r#"class="sh-diagram__node sh-diagram__node--circle"><circle"#));
+        assert!(html.contains(r#"class="sh-diagram__edge sh-diagram__edge--solid""#));
+        assert!(html.contains(r#"marker-end="url(#flow-arrow)""#));
+        assert_eq!(html.matches("marker-end=").count(), 1);
+        assert!(html.contains(r#"class="sh-diagram__edge sh-diagram__edge--dashed""#));
+        assert!(html.contains(">go</text>"));
+        assert!(
+            html.contains(r#"<figcaption class="sh-diagram__caption">Decision flow</figcaption>"#)
+        );
+        assert!(!html.contains("<line>"));
+    }
+
+    #[test]
+    fn test_parse_sequence() {
+        let diagram = SequenceDiagram::parse(
+            "sequenceDiagram
+            participant B as Browser
+            actor S
+            B->>S: GET /
+            S-->>B: 200 OK
+            S->S: cache
+            B-->C: ping
+            Note over B,S: TLS
+            Note right of C: offline",
+        )
+        .unwrap();
+
+        assert_eq!(diagram.participants.len(), 2);
+        assert_eq!(diagram.participants[0].label, "Browser");
+        assert_eq!(diagram.all_participants().len(), 3);
+        let kinds: Vec<_> = diagram
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                SequenceStep::Message(m) => Some(m.kind),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                MessageKind::Sync,
+                MessageKind::Reply,
+                MessageKind::Line,
+                MessageKind::DashedLine
+            ]
+        );
+        assert_eq!(
+            diagram.steps[4],
+            SequenceStep::Note(Note::spanning("B", "S", "TLS"))
+        );
+        assert_eq!(
+            diagram.steps[5],
+            SequenceStep::Note(Note::over("C", "offline").placement(NotePlacement::RightOf))
+        );
+
+        assert_eq!(
+            SequenceDiagram::parse("sequenceDiagram\nhello")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert!(SequenceDiagram::parse("sequenceDiagram\nNote above A: x").is_err());
+    }
+
+    #[test]
+    fn test_sequence_render() {
+        let html = SequenceDiagram::new()
+            .participant("c", "Client")
+            .participant("s", "Server")
+            .message(Message::new("c", "s", "a fairly long request label"))
+            .message(Message::new("s", "c", "ok").kind(MessageKind::Reply))
+            .message(Message::new("s", "s", "log"))
+            .note(Note::over("s", "async"))
+            .render()
+            .into_string();
+
+        assert!(html.starts_with(r#"<figure class="sh-diagram sh-diagram--sequence">"#));
+        assert_eq!(html.matches(r#"class="sh-diagram__lifeline""#).count(), 2);
+        // Actors are mirrored below the lifelines
+        assert_eq!(html.matches(">Client</text>").count(), 2);
+        assert!(html.contains(r#"class="sh-diagram__message sh-diagram__message--dashed""#));
+        assert!(html.contains(">a fairly long request label</text>"));
+        assert!(html.contains(r#"<g class="sh-diagram__note">"#));
+        assert!(html.contains("h32v18h-32"));
+        assert!(html.contains(r#"aria-label="Sequence diagram""#));
+    }
+
+    #[test]
+    fn test_sequence_columns_fit_labels() {
+        let label = "x".repeat(60);
+        let wide = SequenceDiagram::new().message(Message::new("a", "b", label.as_str()));
+        let narrow = SequenceDiagram::new().message(Message::new("a", "b", "x"));
+        let width = |svg: String| -> f64 {
+            let start = svg.find(r#"width=""#).unwrap() + 7;
+            let end = start + svg[start..].find('"').unwrap();
+            svg[start..end].parse().unwrap()
+        };
+        assert!(width(wide.to_svg()) > width(narrow.to_svg()) + 300.0);
+    }
+
+    #[test]
+    fn test_empty_diagrams_render() {
+        let html = Diagram::parse("sequenceDiagram").unwrap().render().into_string();
+        assert!(html.contains("<svg"));
+        assert!(!html.contains("sh-diagram__lifeline"));
+
+        let html = Diagram::parse("graph TD").unwrap().render().into_string();
+        assert!(html.contains("<svg"));
+    }
+
+    #[test]
+    fn test_diagram_parse_dispatch() {
+        assert!(matches!(
+            Diagram::parse("%% intro\ngraph LR\nA-->B"),
+            Ok(Diagram::Flowchart(_))
+        ));
+        assert!(matches!(
+            Diagram::parse("sequenceDiagram\nA->>B: hi"),
+            Ok(Diagram::Sequence(_))
+        ));
+        assert_eq!(
+            Diagram::parse("pie\n\"a\": 1").unwrap_err(),
+            DiagramError::new(1, "unknown diagram type")
+        );
+        let svg = Diagram::parse("graph TD\nA-->B").unwrap().to_svg();
+        assert!(svg.starts_with("<svg"));
+    }
+
+    #[test]
+    fn test_diagram_css() {
+        let css = diagram_css();
+        assert!(css.contains(".sh-diagram__edge--dashed"));
+        assert!(css.contains(".sh-diagram__lifeline"));
+    }
+}
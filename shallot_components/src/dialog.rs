@@ -1,6 +1,8 @@
 //! Dialog Component
 //!
-//! Modal dialogs using CSS-only interactions (details/summary pattern).
+//! Modal dialogs using CSS-only interactions (details/summary pattern), or a
+//! real `<dialog>` element whose buttons close it through `method="dialog"`
+//! forms and invoker commands.
 
 use maud::{html, Markup, Render};
 
@@ -12,9 +14,21 @@ pub struct Dialog<'a> {
     size: DialogSize,
     variant: DialogVariant,
     open: bool,
-    #[allow(dead_code)] // Reserved for CSS-only overlay click handling
     close_on_overlay: bool,
     close_button: bool,
+    strategy: DialogStrategy,
+}
+
+/// How a dialog is shown and dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogStrategy {
+    /// `details`/`summary` wrapper with a fixed overlay, opened via the URL fragment
+    #[default]
+    Details,
+    /// Native `<dialog>`: `open` is rendered server-side, triggers use
+    /// `command="show-modal"` where invoker commands exist, and close and
+    /// cancel buttons submit `method="dialog"` forms
+    Native,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,6 +63,27 @@ pub enum DialogVariant {
     Info,
 }
 
+impl DialogVariant {
+    fn class(&self) -> &'static str {
+        match self {
+            DialogVariant::Default => "",
+            DialogVariant::Danger => "sh-dialog--danger",
+            DialogVariant::Success => "sh-dialog--success",
+            DialogVariant::Warning => "sh-dialog--warning",
+            DialogVariant::Info => "sh-dialog--info",
+        }
+    }
+}
+
+fn close_icon() -> Markup {
+    html! {
+        svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+            line x1="18" y1="6" x2="6" y2="18";
+            line x1="6" y1="6" x2="18" y2="18";
+        }
+    }
+}
+
 impl<'a> Dialog<'a> {
     pub fn new(id: &'a str, content: Markup) -> Self {
         Self {
@@ -61,6 +96,7 @@ impl<'a> Dialog<'a> {
             open: false,
             close_on_overlay: true,
             close_button: true,
+            strategy: DialogStrategy::Details,
         }
     }
 
@@ -93,22 +129,88 @@ impl<'a> Dialog<'a> {
         self.close_button = show;
         self
     }
+
+    /// Allow light dismiss (backdrop click) of native dialogs via `closedby`
+    pub fn close_on_overlay(mut self, close: bool) -> Self {
+        self.close_on_overlay = close;
+        self
+    }
+
+    pub fn strategy(mut self, strategy: DialogStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    fn dialog_id(&self) -> String {
+        format!("sh-dialog-{}", self.id)
+    }
+
+    fn render_sections(&self, dialog_id: &str) -> Markup {
+        html! {
+            @if let Some(title) = self.title {
+                header class="sh-dialog__header" {
+                    h2 class="sh-dialog__title" id={(dialog_id) "-title"} { (title) }
+                }
+            }
+
+            div class="sh-dialog__body" {
+                (self.content.clone())
+            }
+
+            @if let Some(footer) = &self.footer {
+                footer class="sh-dialog__footer" {
+                    (footer.clone())
+                }
+            }
+        }
+    }
+
+    fn render_native(&self) -> Markup {
+        let dialog_id = self.dialog_id();
+        let classes = format!(
+            "sh-dialog sh-dialog--native {} {}",
+            self.size.class(),
+            self.variant.class()
+        );
+        let closedby = if self.close_on_overlay {
+            "any"
+        } else {
+            "closerequest"
+        };
+
+        html! {
+            dialog
+                class=(classes.trim_end())
+                id=(dialog_id)
+                open?[self.open]
+                closedby=(closedby)
+                aria-labelledby=[self.title.map(|_| format!("{}-title", dialog_id))]
+            {
+                @if self.close_button {
+                    form method="dialog" class="sh-dialog__close-form" {
+                        button type="submit" value="close" class="sh-dialog__close" aria-label="Close dialog" {
+                            (close_icon())
+                        }
+                    }
+                }
+
+                (self.render_sections(&dialog_id))
+            }
+        }
+    }
 }
 
 impl<'a> Render for Dialog<'a> {
     fn render(&self) -> Markup {
-        let dialog_id = format!("sh-dialog-{}", self.id);
-        let variant_class = match self.variant {
-            DialogVariant::Default => "",
-            DialogVariant::Danger => "sh-dialog--danger",
-            DialogVariant::Success => "sh-dialog--success",
-            DialogVariant::Warning => "sh-dialog--warning",
-            DialogVariant::Info => "sh-dialog--info",
-        };
+        if self.strategy == DialogStrategy::Native {
+            return self.render_native();
+        }
+
+        let dialog_id = self.dialog_id();
 
         html! {
             details
-                class={(format!("sh-dialog-wrapper {}", variant_class))}
+                class={(format!("sh-dialog-wrapper {}", self.variant.class()))}
                 id=(dialog_id)
                 open?[self.open]
             {
@@ -118,28 +220,11 @@ impl<'a> Render for Dialog<'a> {
                     div class={(format!("sh-dialog {}", self.size.class()))} role="dialog" aria-modal="true" aria-labelledby={(format!("{}-title", dialog_id))} {
                         @if self.close_button {
                             a href="#" class="sh-dialog__close" aria-label="Close dialog" {
-                                svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                    line x1="18" y1="6" x2="6" y2="18";
-                                    line x1="6" y1="6" x2="18" y2="18";
-                                }
+                                (close_icon())
                             }
                         }
 
-                        @if let Some(title) = self.title {
-                            header class="sh-dialog__header" {
-                                h2 class="sh-dialog__title" id={(format!("{}-title", dialog_id))} { (title) }
-                            }
-                        }
-
-                        div class="sh-dialog__body" {
-                            (self.content.clone())
-                        }
-
-                        @if let Some(footer) = &self.footer {
-                            footer class="sh-dialog__footer" {
-                                (footer.clone())
-                            }
-                        }
+                        (self.render_sections(&dialog_id))
                     }
                 }
             }
@@ -151,16 +236,54 @@ pub struct DialogTrigger<'a> {
     pub dialog_id: &'a str,
     pub label: &'a str,
     pub trigger_class: Option<&'a str>,
+    pub strategy: DialogStrategy,
+}
+
+impl<'a> DialogTrigger<'a> {
+    pub fn new(dialog_id: &'a str, label: &'a str) -> Self {
+        Self {
+            dialog_id,
+            label,
+            trigger_class: None,
+            strategy: DialogStrategy::Details,
+        }
+    }
+
+    pub fn trigger_class(mut self, class: &'a str) -> Self {
+        self.trigger_class = Some(class);
+        self
+    }
+
+    pub fn strategy(mut self, strategy: DialogStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
 }
 
 impl<'a> Render for DialogTrigger<'a> {
     fn render(&self) -> Markup {
         let trigger_id = format!("sh-dialog-trigger-{}", self.dialog_id);
+        let target_id = format!("sh-dialog-{}", self.dialog_id);
         let cls = self.trigger_class.unwrap_or("sh-btn sh-btn--primary");
 
         html! {
-            a href={(format!("#sh-dialog-{}", self.dialog_id))} class=(cls) id=(trigger_id) {
-                (self.label)
+            @match self.strategy {
+                DialogStrategy::Details => {
+                    a href={ "#" (target_id) } class=(cls) id=(trigger_id) {
+                        (self.label)
+                    }
+                }
+                DialogStrategy::Native => {
+                    button
+                        type="button"
+                        class=(cls)
+                        id=(trigger_id)
+                        commandfor=(target_id)
+                        command="show-modal"
+                    {
+                        (self.label)
+                    }
+                }
             }
         }
     }
@@ -173,6 +296,9 @@ pub struct ConfirmDialog<'a> {
     confirm_label: &'a str,
     cancel_label: &'a str,
     variant: DialogVariant,
+    strategy: DialogStrategy,
+    action: Option<&'a str>,
+    open: bool,
 }
 
 impl<'a> ConfirmDialog<'a> {
@@ -184,6 +310,9 @@ impl<'a> ConfirmDialog<'a> {
             confirm_label: "Confirm",
             cancel_label: "Cancel",
             variant: DialogVariant::Default,
+            strategy: DialogStrategy::Details,
+            action: None,
+            open: false,
         }
     }
 
@@ -201,23 +330,72 @@ impl<'a> ConfirmDialog<'a> {
         self.variant = variant;
         self
     }
+
+    pub fn strategy(mut self, strategy: DialogStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// URL the confirm button POSTs to; cancel only closes the dialog
+    pub fn action(mut self, action: &'a str) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    fn render_footer(&self) -> Markup {
+        let confirm_class = if self.variant == DialogVariant::Danger {
+            "sh-btn sh-btn--danger"
+        } else {
+            "sh-btn sh-btn--primary"
+        };
+
+        html! {
+            @match self.strategy {
+                DialogStrategy::Details => {
+                    div class="sh-dialog__actions" {
+                        a href="#" class="sh-btn sh-btn--ghost" { (self.cancel_label) }
+                        a href=(self.action.unwrap_or("#")) class=(confirm_class) { (self.confirm_label) }
+                    }
+                }
+                DialogStrategy::Native => {
+                    form method="dialog" class="sh-dialog__actions" {
+                        button type="submit" value="cancel" class="sh-btn sh-btn--ghost" autofocus {
+                            (self.cancel_label)
+                        }
+                        button
+                            type="submit"
+                            name="intent"
+                            value="confirm"
+                            class=(confirm_class)
+                            formmethod=[self.action.map(|_| "post")]
+                            formaction=[self.action]
+                        {
+                            (self.confirm_label)
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Render for ConfirmDialog<'a> {
     fn render(&self) -> Markup {
         let content = html! { p { (self.message) } };
-        let footer = html! {
-            div class="sh-dialog__actions" {
-                a href="#" class="sh-btn sh-btn--ghost" { (self.cancel_label) }
-                a href="#" class="sh-btn sh-btn--primary" { (self.confirm_label) }
-            }
-        };
 
         Dialog::new(self.id, content)
             .title(self.title)
-            .footer(footer)
+            .footer(self.render_footer())
             .variant(self.variant)
             .size(DialogSize::Sm)
+            .strategy(self.strategy)
+            .open(self.open)
+            .close_on_overlay(false)
             .render()
     }
 }
@@ -260,7 +438,7 @@ pub fn dialog_css() -> String {
     max-height: calc(100vh - 2rem);
     background: var(--sh-surface, #fff);
     border-radius: var(--sh-radius-lg, 0.75rem);
-    box-shadow: var(--sh-shadow-xl, 0 25px 50px -12px rgba(0, 0, 0, 0.25));
+    box-shadow: var(--sh-elevation-5);
     overflow: hidden;
     animation: sh-dialog-scale-in 0.2s ease;
 }
@@ -342,22 +520,65 @@ pub fn dialog_css() -> String {
 }
 
 /* Variants */
-.sh-dialog-wrapper--danger .sh-dialog__title {
+.sh-dialog--danger .sh-dialog__title {
     color: var(--sh-error, #ef4444);
 }
 
-.sh-dialog-wrapper--success .sh-dialog__title {
+.sh-dialog--success .sh-dialog__title {
     color: var(--sh-success, #10b981);
 }
 
-.sh-dialog-wrapper--warning .sh-dialog__title {
+.sh-dialog--warning .sh-dialog__title {
     color: var(--sh-warning, #f59e0b);
 }
 
-.sh-dialog-wrapper--info .sh-dialog__title {
+.sh-dialog--info .sh-dialog__title {
     color: var(--sh-info, #3b82f6);
 }
 
+/* Native <dialog> */
+.sh-dialog--native {
+    padding: 0;
+    border: 0;
+    color: inherit;
+}
+
+.sh-dialog--native::backdrop {
+    background: rgba(0, 0, 0, 0.5);
+    backdrop-filter: blur(4px);
+}
+
+.sh-dialog--native[open] {
+    animation: sh-dialog-scale-in 0.2s ease;
+}
+
+/* Opened server-side with the `open` attribute: not modal, so fake the backdrop */
+.sh-dialog--native[open]:not(:modal) {
+    position: fixed;
+    inset: 0;
+    z-index: 1000;
+    margin: auto;
+    box-shadow: 0 0 0 100vmax rgba(0, 0, 0, 0.5);
+}
+
+.sh-dialog--native .sh-dialog__close {
+    background: none;
+    border: 0;
+    cursor: pointer;
+}
+
+.sh-dialog__close-form {
+    margin: 0;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-dialog--native[open],
+    .sh-dialog-overlay,
+    .sh-dialog {
+        animation: none;
+    }
+}
+
 /* Dark mode support */
 @media (prefers-color-scheme: dark) {
     .sh-dialog {
@@ -389,6 +610,88 @@ mod tests {
         assert_eq!(dialog.size, DialogSize::Lg);
     }
 
+    #[test]
+    fn test_dialog_native_render() {
+        let html_str = Dialog::new("settings", html! { p { "Body" } })
+            .title("Settings")
+            .strategy(DialogStrategy::Native)
+            .open(true)
+            .render()
+            .into_string();
+
+        assert!(html_str.starts_with("<dialog class=\"sh-dialog sh-dialog--native sh-dialog--md\""));
+        assert!(html_str.contains("id=\"sh-dialog-settings\" open closedby=\"any\""));
+        assert!(html_str.contains("aria-labelledby=\"sh-dialog-settings-title\""));
+        assert!(html_str.contains("<form method=\"dialog\" class=\"sh-dialog__close-form\">"));
+        assert!(!html_str.contains("<details"));
+    }
+
+    #[test]
+    fn test_dialog_native_closed_without_light_dismiss() {
+        let html_str = Dialog::new("x", html! {})
+            .strategy(DialogStrategy::Native)
+            .close_on_overlay(false)
+            .close_button(false)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("closedby=\"closerequest\""));
+        assert!(!html_str.contains(" open"));
+        assert!(!html_str.contains("aria-labelledby"));
+        assert!(!html_str.contains("<form"));
+    }
+
+    #[test]
+    fn test_dialog_trigger_strategies() {
+        let link = DialogTrigger::new("help", "Help").render().into_string();
+        assert!(link.contains("href=\"#sh-dialog-help\""));
+
+        let button = DialogTrigger::new("help", "Help")
+            .strategy(DialogStrategy::Native)
+            .render()
+            .into_string();
+        assert!(button.contains("commandfor=\"sh-dialog-help\" command=\"show-modal\""));
+    }
+
+    #[test]
+    fn test_confirm_dialog_native_form() {
+        let html_str = ConfirmDialog::new("delete", "Delete project?", "This cannot be undone.")
+            .variant(DialogVariant::Danger)
+            .strategy(DialogStrategy::Native)
+            .action("/projects/1/delete")
+            .confirm_label("Delete")
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("sh-dialog--danger"));
+        assert!(html_str.contains("closedby=\"closerequest\""));
+        assert!(html_str.contains("<form method=\"dialog\" class=\"sh-dialog__actions\">"));
+        assert!(html_str.contains("value=\"cancel\""));
+        assert!(html_str.contains(
+            "value=\"confirm\" class=\"sh-btn sh-btn--danger\" formmethod=\"post\" formaction=\"/projects/1/delete\""
+        ));
+    }
+
+    #[test]
+    fn test_confirm_dialog_native_without_action() {
+        let html_str = ConfirmDialog::new("ok", "Continue?", "Sure?")
+            .strategy(DialogStrategy::Native)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("value=\"confirm\""));
+        assert!(!html_str.contains("formmethod"));
+    }
+
+    #[test]
+    fn test_dialog_css_native() {
+        let css = dialog_css();
+        assert!(css.contains(".sh-dialog--native::backdrop"));
+        assert!(css.contains(".sh-dialog--native[open]:not(:modal)"));
+        assert!(css.contains(".sh-dialog--danger .sh-dialog__title"));
+        assert!(css.contains("box-shadow: var(--sh-elevation-5);"));
+    }
+
     #[test]
     fn test_confirm_dialog() {
         let confirm = ConfirmDialog::new("delete", "Delete Item?", "Are you sure?")
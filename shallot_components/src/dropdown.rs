@@ -1,3 +1,4 @@
+use crate::component::OverlayEngine;
 use maud::{html, Markup, Render};
 
 pub struct Dropdown<'a> {
@@ -6,6 +7,8 @@ pub struct Dropdown<'a> {
     pub open: bool,
     pub align_end: bool,
     pub aria_label: &'a str,
+    pub id: Option<&'a str>,
+    pub engine: OverlayEngine,
 }
 
 impl<'a> Dropdown<'a> {
@@ -16,6 +19,8 @@ impl<'a> Dropdown<'a> {
             open: false,
             align_end: false,
             aria_label,
+            id: None,
+            engine: OverlayEngine::Css,
         }
     }
 
@@ -28,6 +33,46 @@ impl<'a> Dropdown<'a> {
         self.align_end = align_end;
         self
     }
+
+    /// Set the menu id, used as the `popovertarget` in native mode
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Choose between the `details` engine and the native `popover` attribute.
+    /// Native menus always start closed, so `open` only applies to `Css`.
+    pub fn engine(mut self, engine: OverlayEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    fn render_native(&self, class: &str) -> Markup {
+        let id = self.id.unwrap_or("sh-dropdown-menu");
+        let anchor = OverlayEngine::anchor_name(id);
+
+        html! {
+            div class={ (class) " sh-overlay--native" } {
+                button
+                    type="button"
+                    class="sh-dropdown__trigger"
+                    popovertarget=(id)
+                    aria-label=(self.aria_label)
+                    style={ "anchor-name: " (anchor) }
+                {
+                    (self.trigger)
+                }
+                div
+                    class="sh-dropdown__menu"
+                    id=(id)
+                    popover="auto"
+                    style={ "position-anchor: " (anchor) }
+                {
+                    (self.menu)
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Render for Dropdown<'a> {
@@ -38,12 +83,16 @@ impl<'a> Render for Dropdown<'a> {
             "sh-dropdown sh-dropdown--start"
         };
 
+        if self.engine.is_native() {
+            return self.render_native(class);
+        }
+
         html! {
             details class=(class) open?[self.open] {
                 summary class="sh-dropdown__trigger" aria-label=(self.aria_label) {
                     (self.trigger)
                 }
-                div class="sh-dropdown__menu" {
+                div class="sh-dropdown__menu" id=[self.id] {
                     (self.menu)
                 }
             }
@@ -86,7 +135,7 @@ pub fn dropdown_css() -> String {
     background: var(--sh-surface, #fff);
     border: 1px solid var(--sh-border, #e5e7eb);
     border-radius: var(--sh-radius-lg, 0.5rem);
-    box-shadow: var(--sh-shadow-lg, 0 10px 40px rgba(0, 0, 0, 0.1));
+    box-shadow: var(--sh-elevation-3);
     z-index: 1000;
     animation: dropdown-fade 0.2s ease;
 }
@@ -96,6 +145,38 @@ pub fn dropdown_css() -> String {
     right: 0;
 }
 
+/* Native engine: popover attribute + anchor positioning */
+.sh-overlay--native .sh-dropdown__trigger {
+    font: inherit;
+    color: inherit;
+    background: none;
+    border: 0;
+}
+
+@supports (anchor-name: --sh-anchor) {
+    .sh-overlay--native .sh-dropdown__menu {
+        position: fixed;
+        inset: auto;
+        margin: 0.25rem 0 0;
+        position-area: block-end span-inline-end;
+        position-try-fallbacks: flip-block, flip-inline;
+    }
+
+    .sh-dropdown--end.sh-overlay--native .sh-dropdown__menu {
+        position-area: block-end span-inline-start;
+    }
+}
+
+/* Without anchor positioning the top-layer menu is docked to the viewport */
+@supports not (anchor-name: --sh-anchor) {
+    .sh-overlay--native .sh-dropdown__menu {
+        position: fixed;
+        inset: auto 1rem 1rem;
+        margin: 0 auto;
+        max-width: min(24rem, calc(100vw - 2rem));
+    }
+}
+
 @keyframes dropdown-fade {
     from {
         opacity: 0;
@@ -133,10 +214,42 @@ mod tests {
         assert!(dd.align_end);
     }
 
+    #[test]
+    fn test_dropdown_native_engine() {
+        let html_str = Dropdown::new(html! { "Menu" }, html! { "Items" }, "Account")
+            .id("account-menu")
+            .open(true)
+            .engine(OverlayEngine::Native)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("sh-dropdown--start sh-overlay--native"));
+        assert!(html_str.contains("popovertarget=\"account-menu\""));
+        assert!(html_str.contains("id=\"account-menu\" popover=\"auto\""));
+        assert!(html_str.contains("anchor-name: --sh-anchor-account-menu"));
+        assert!(html_str.contains("position-anchor: --sh-anchor-account-menu"));
+        assert!(!html_str.contains("<details"));
+        assert!(!html_str.contains("open"));
+    }
+
+    #[test]
+    fn test_dropdown_css_engine_render() {
+        let html_str = Dropdown::new(html! {}, html! {}, "Test")
+            .id("menu")
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("<details class=\"sh-dropdown sh-dropdown--start\">"));
+        assert!(html_str.contains("class=\"sh-dropdown__menu\" id=\"menu\""));
+        assert!(!html_str.contains("popover"));
+    }
+
     #[test]
     fn test_dropdown_css() {
         let css = dropdown_css();
         assert!(css.contains(".sh-dropdown"));
         assert!(css.contains(".sh-dropdown__menu"));
+        assert!(css.contains("box-shadow: var(--sh-elevation-3);"));
+        assert!(css.contains("position-area: block-end span-inline-start"));
     }
 }
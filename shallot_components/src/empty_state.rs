@@ -2,6 +2,7 @@
 //! Provides helpful guidance when no data is available
 
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 
 /// Size variants for the empty state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -16,24 +17,24 @@ pub enum EmptyStateSize {
 #[derive(Debug, Clone)]
 pub struct EmptyState<'a> {
     /// Title text
-    pub title: &'a str,
+    pub title: Cow<'a, str>,
     /// Description text
-    pub description: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
     /// Icon name (CSS class or inline SVG)
-    pub icon: Option<&'a str>,
+    pub icon: Option<Cow<'a, str>>,
     /// Size variant
     pub size: EmptyStateSize,
     /// Action button markup (passed in)
     pub action: Option<Markup>,
     /// Custom illustration/image URL
-    pub image: Option<&'a str>,
+    pub image: Option<Cow<'a, str>>,
 }
 
 impl<'a> EmptyState<'a> {
     /// Create a new empty state with a title
-    pub fn new(title: &'a str) -> Self {
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            title,
+            title: title.into(),
             description: None,
             icon: None,
             size: EmptyStateSize::default(),
@@ -43,14 +44,14 @@ impl<'a> EmptyState<'a> {
     }
 
     /// Set the description
-    pub fn description(mut self, description: &'a str) -> Self {
-        self.description = Some(description);
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
         self
     }
 
     /// Set the icon
-    pub fn icon(mut self, icon: &'a str) -> Self {
-        self.icon = Some(icon);
+    pub fn icon(mut self, icon: impl Into<Cow<'a, str>>) -> Self {
+        self.icon = Some(icon.into());
         self
     }
 
@@ -67,8 +68,8 @@ impl<'a> EmptyState<'a> {
     }
 
     /// Set a custom image
-    pub fn image(mut self, image: &'a str) -> Self {
-        self.image = Some(image);
+    pub fn image(mut self, image: impl Into<Cow<'a, str>>) -> Self {
+        self.image = Some(image.into());
         self
     }
 
@@ -90,11 +91,11 @@ impl<'a> Render for EmptyState<'a> {
 
         html! {
             div class=(classes) role="status" aria-label="Empty state" {
-                @if let Some(image) = self.image {
+                @if let Some(image) = &self.image {
                     div class="sh-empty-state__image" {
                         img src=(image) alt="" loading="lazy";
                     }
-                } @else if let Some(icon) = self.icon {
+                } @else if let Some(icon) = &self.icon {
                     div class="sh-empty-state__icon" {
                         span class=(format!("sh-icon sh-icon--{}", icon)) {}
                     }
@@ -122,7 +123,7 @@ impl<'a> Render for EmptyState<'a> {
                     (self.title)
                 }
 
-                @if let Some(description) = self.description {
+                @if let Some(description) = &self.description {
                     p class="sh-empty-state__description" {
                         (description)
                     }
@@ -236,7 +237,7 @@ mod tests {
 
         assert_eq!(state.title, "No results found");
         assert_eq!(
-            state.description,
+            state.description.as_deref(),
             Some("Try adjusting your search criteria")
         );
     }
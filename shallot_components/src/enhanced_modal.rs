@@ -1,3 +1,4 @@
+use crate::dialog::DialogStrategy;
 use maud::{html, Markup, PreEscaped};
 use std::collections::HashMap;
 
@@ -81,6 +82,8 @@ pub struct EnhancedModal<'a> {
     pub aria: AriaModal,
     pub custom_class: Option<&'a str>,
     pub data_attributes: HashMap<&'a str, &'a str>,
+    pub strategy: DialogStrategy,
+    pub open: bool,
 }
 
 impl<'a> EnhancedModal<'a> {
@@ -97,6 +100,8 @@ impl<'a> EnhancedModal<'a> {
             aria: AriaModal::default(),
             custom_class: None,
             data_attributes: HashMap::new(),
+            strategy: DialogStrategy::Details,
+            open: false,
         }
     }
 
@@ -145,6 +150,18 @@ impl<'a> EnhancedModal<'a> {
         self
     }
 
+    /// Render as a native `<dialog>` instead of a scripted overlay
+    pub fn strategy(mut self, strategy: DialogStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Render the native dialog already open (non-modal, no script needed)
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
     fn modal_classes(&self) -> String {
         let mut classes = vec!["sh-modal"];
 
@@ -171,6 +188,25 @@ impl<'a> EnhancedModal<'a> {
         classes.join(" ")
     }
 
+    fn backdrop_modifier(&self) -> &'static str {
+        match self.backdrop {
+            BackdropStyle::Blur => "blur",
+            BackdropStyle::Dark => "dark",
+            BackdropStyle::Transparent => "transparent",
+            BackdropStyle::Gradient => "gradient",
+        }
+    }
+
+    fn closedby(&self) -> &'static str {
+        if self.config.close_on_backdrop {
+            "any"
+        } else if self.config.close_on_esc {
+            "closerequest"
+        } else {
+            "none"
+        }
+    }
+
     fn backdrop_classes(&self) -> String {
         let mut classes = vec!["sh-modal-backdrop"];
 
@@ -212,14 +248,24 @@ impl<'a> EnhancedModal<'a> {
     }
 
     fn render_header(&self) -> Markup {
-        let close_button = html! {
-            button
-                class="sh-modal__close"
-                type="button"
-                aria-label="Close modal"
-                data-modal-close=(self.id)
-            {
-                span aria-hidden="true" { (PreEscaped("×".to_string())) }
+        let close_button = if self.strategy == DialogStrategy::Native {
+            html! {
+                form method="dialog" class="sh-modal__close-form" {
+                    button class="sh-modal__close" type="submit" value="close" aria-label="Close modal" {
+                        span aria-hidden="true" { (PreEscaped("×".to_string())) }
+                    }
+                }
+            }
+        } else {
+            html! {
+                button
+                    class="sh-modal__close"
+                    type="button"
+                    aria-label="Close modal"
+                    data-modal-close=(self.id)
+                {
+                    span aria-hidden="true" { (PreEscaped("×".to_string())) }
+                }
             }
         };
 
@@ -244,7 +290,48 @@ impl<'a> EnhancedModal<'a> {
         }
     }
 
+    fn render_content(&self) -> Markup {
+        html! {
+            div class="sh-modal__content" {
+                (self.render_header())
+                div class="sh-modal__body" {
+                    (self.children)
+                }
+                @if let Some(footer) = &self.footer {
+                    div class="sh-modal__footer" {
+                        (footer)
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_native(&self) -> Markup {
+        let classes = format!(
+            "{} sh-modal--native sh-modal--backdrop-{}",
+            self.modal_classes(),
+            self.backdrop_modifier()
+        );
+
+        html! {
+            dialog
+                id=(self.id)
+                class=(classes)
+                open?[self.open]
+                closedby=(self.closedby())
+                aria-labelledby=[self.title.map(|_| format!("{}-title", self.id))]
+                aria-describedby=[self.aria.described_by.as_deref()]
+            {
+                (self.render_content())
+            }
+        }
+    }
+
     pub fn render(&self) -> Markup {
+        if self.strategy == DialogStrategy::Native {
+            return self.render_native();
+        }
+
         let aria_attrs = self.render_aria_attributes();
         let data_attrs = self.render_data_attributes();
 
@@ -257,17 +344,7 @@ impl<'a> EnhancedModal<'a> {
                 (data_attrs)
                 div class=(self.backdrop_classes()) {
                     div class="sh-modal__container" {
-                        div class="sh-modal__content" {
-                            (self.render_header())
-                            div class="sh-modal__body" {
-                                (self.children)
-                            }
-                            @if let Some(footer) = &self.footer {
-                                div class="sh-modal__footer" {
-                                    (footer)
-                                }
-                            }
-                        }
+                        (self.render_content())
                     }
                 }
             }
@@ -596,10 +673,74 @@ pub fn enhanced_modal_css() -> String {
 .sh-modal[data-focus-trap="true"]:focus-within::before {{
   border-color: var(--sh-accent);
 }}
-"#
+{native}"#,
+        native = NATIVE_MODAL_CSS
     )
 }
 
+const NATIVE_MODAL_CSS: &str = r#"
+/* Native <dialog> strategy */
+.sh-modal.sh-modal--native {
+  display: none;
+  position: fixed;
+  inset: 0;
+  width: 100%;
+  max-width: none;
+  height: 100%;
+  max-height: none;
+  margin: 0;
+  padding: 1rem;
+  border: 0;
+  background: transparent;
+  color: inherit;
+  opacity: 1;
+  visibility: visible;
+}
+
+.sh-modal.sh-modal--native[open] {
+  display: flex;
+}
+
+.sh-modal--native .sh-modal__content {
+  width: 100%;
+}
+
+.sh-modal--native::backdrop {
+  background: rgba(0, 0, 0, 0.5);
+}
+
+.sh-modal--backdrop-blur::backdrop {
+  backdrop-filter: blur(8px);
+}
+
+.sh-modal--backdrop-dark::backdrop {
+  background: rgba(0, 0, 0, 0.8);
+}
+
+.sh-modal--backdrop-transparent::backdrop {
+  background: transparent;
+}
+
+.sh-modal--backdrop-gradient::backdrop {
+  background: linear-gradient(
+    135deg,
+    rgba(0, 0, 0, 0.7) 0%,
+    rgba(0, 0, 0, 0.3) 50%,
+    rgba(0, 0, 0, 0.7) 100%
+  );
+}
+
+/* Opened server-side: not modal, so paint the backdrop on the dialog itself */
+.sh-modal--native[open]:not(:modal) {
+  z-index: 50;
+  background: rgba(0, 0, 0, 0.5);
+}
+
+.sh-modal__close-form {
+  margin: 0;
+}
+"#;
+
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -637,10 +778,46 @@ mod tests {
         assert_eq!(modal.backdrop, BackdropStyle::Dark);
     }
 
+    #[test]
+    fn test_enhanced_modal_native() {
+        let html_str = EnhancedModal::new("confirm", html! { "Sure?" })
+            .title("Confirm")
+            .backdrop(BackdropStyle::Dark)
+            .strategy(DialogStrategy::Native)
+            .open(true)
+            .render()
+            .into_string();
+
+        assert!(html_str.starts_with("<dialog id=\"confirm\""));
+        assert!(html_str.contains("sh-modal--native sh-modal--backdrop-dark"));
+        assert!(html_str.contains(" open closedby=\"any\""));
+        assert!(html_str.contains("aria-labelledby=\"confirm-title\""));
+        assert!(html_str.contains("<form method=\"dialog\" class=\"sh-modal__close-form\">"));
+        assert!(!html_str.contains("data-modal-close"));
+    }
+
+    #[test]
+    fn test_enhanced_modal_native_closedby() {
+        let config = ModalConfig {
+            close_on_backdrop: false,
+            close_on_esc: false,
+            ..ModalConfig::default()
+        };
+        let html_str = EnhancedModal::new("locked", html! {})
+            .config(config)
+            .strategy(DialogStrategy::Native)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("closedby=\"none\""));
+        assert!(!html_str.contains(" open"));
+    }
+
     #[test]
     fn test_enhanced_modal_css() {
         let css = enhanced_modal_css();
         assert!(css.contains(".sh-modal"));
         assert!(css.contains(".sh-modal__container"));
+        assert!(css.contains(".sh-modal--backdrop-gradient::backdrop"));
     }
 }
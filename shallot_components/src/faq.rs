@@ -0,0 +1,438 @@
+//! FAQ Component - Question/answer list with optional FAQPage JSON-LD
+//!
+//! Each question is a native `<details>` element whose `<summary>` holds a
+//! real heading, so the questions appear in the document outline and the
+//! answers open without JavaScript. Every item gets a fragment id derived
+//! from its question (`#how-do-i-reset-my-password`); linking to it, or
+//! finding text inside it, opens the answer in current browsers.
+//!
+//! With [`Faq::structured_data`] the same items are emitted as a
+//! schema.org `FAQPage` script, keeping markup and structured data in sync.
+
+use maud::{html, Markup, PreEscaped, Render};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+/// Lowercase ASCII slug of `text`, e.g. "What's new?" becomes "what-s-new"
+pub fn faq_anchor(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "question".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// A single question and its answer
+#[derive(Debug, Clone)]
+pub struct FaqItem<'a> {
+    pub question: Cow<'a, str>,
+    pub answer: Markup,
+    pub id: Option<Cow<'a, str>>,
+    pub open: bool,
+}
+
+impl<'a> FaqItem<'a> {
+    /// Question with a rich answer; the answer HTML is also used as the
+    /// JSON-LD answer text
+    pub fn new(question: impl Into<Cow<'a, str>>, answer: Markup) -> Self {
+        Self {
+            question: question.into(),
+            answer,
+            id: None,
+            open: false,
+        }
+    }
+
+    /// Question with a plain-text answer, rendered as one paragraph
+    pub fn text(question: impl Into<Cow<'a, str>>, answer: &str) -> Self {
+        Self::new(question, html! { p { (answer) } })
+    }
+
+    /// Override the fragment id derived from the question
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+/// Frequently asked questions section
+#[derive(Debug, Clone)]
+pub struct Faq<'a> {
+    pub title: Option<Cow<'a, str>>,
+    pub items: Vec<FaqItem<'a>>,
+    pub heading_level: u8,
+    pub exclusive: bool,
+    pub structured_data: bool,
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> Faq<'a> {
+    pub fn new(items: Vec<FaqItem<'a>>) -> Self {
+        Self {
+            title: None,
+            items,
+            heading_level: 2,
+            exclusive: false,
+            structured_data: false,
+            id: Cow::Borrowed("faq"),
+        }
+    }
+
+    pub fn item(mut self, item: FaqItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Section heading; questions sit one level below it
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Heading level of the title, or of the questions when there is no
+    /// title (2-5)
+    pub fn heading_level(mut self, level: u8) -> Self {
+        self.heading_level = level.clamp(2, 5);
+        self
+    }
+
+    /// Keep at most one answer open at a time, via a shared `name`
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Emit a `FAQPage` JSON-LD script after the list
+    pub fn structured_data(mut self, enabled: bool) -> Self {
+        self.structured_data = enabled;
+        self
+    }
+
+    /// Section id; also names the group of an exclusive FAQ
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Fragment id of every item, with `-2`, `-3`… added to repeated slugs
+    pub fn anchors(&self) -> Vec<String> {
+        let mut anchors: Vec<String> = Vec::with_capacity(self.items.len());
+        for item in &self.items {
+            let base = match &item.id {
+                Some(id) => id.to_string(),
+                None => faq_anchor(&item.question),
+            };
+            let mut anchor = base.clone();
+            let mut n = 2;
+            while anchors.contains(&anchor) {
+                anchor = format!("{}-{}", base, n);
+                n += 1;
+            }
+            anchors.push(anchor);
+        }
+        anchors
+    }
+
+    /// schema.org `FAQPage` document for the items
+    pub fn to_json_ld(&self) -> Value {
+        let entities: Vec<Value> = self
+            .items
+            .iter()
+            .map(|item| {
+                json!({
+                    "@type": "Question",
+                    "name": item.question,
+                    "acceptedAnswer": {
+                        "@type": "Answer",
+                        "text": item.answer.0.trim(),
+                    },
+                })
+            })
+            .collect();
+        json!({
+            "@context": "https://schema.org",
+            "@type": "FAQPage",
+            "mainEntity": entities,
+        })
+    }
+
+    /// `<script type="application/ld+json">` carrying [`Faq::to_json_ld`]
+    pub fn json_ld_script(&self) -> Markup {
+        // `<` is escaped so answer HTML cannot close the script early
+        let json = self.to_json_ld().to_string().replace('<', "\\u003c");
+        html! {
+            script type="application/ld+json" { (PreEscaped(json)) }
+        }
+    }
+
+    fn question_level(&self) -> u8 {
+        if self.title.is_some() {
+            self.heading_level + 1
+        } else {
+            self.heading_level
+        }
+    }
+}
+
+impl<'a> Render for Faq<'a> {
+    fn render(&self) -> Markup {
+        let anchors = self.anchors();
+        let group = self.exclusive.then(|| format!("{}-group", self.id));
+        let level = self.question_level();
+
+        html! {
+            section class="sh-faq" id=(self.id) aria-label=[self.title.is_none().then_some("Frequently asked questions")] {
+                @if let Some(title) = &self.title {
+                    @match self.heading_level {
+                        2 => h2 class="sh-faq__title" { (title) },
+                        3 => h3 class="sh-faq__title" { (title) },
+                        4 => h4 class="sh-faq__title" { (title) },
+                        _ => h5 class="sh-faq__title" { (title) },
+                    }
+                }
+                @for (item, anchor) in self.items.iter().zip(&anchors) {
+                    details class="sh-faq__item" id=(anchor) name=[group.as_deref()] open?[item.open] {
+                        summary class="sh-faq__summary" {
+                            @match level {
+                                2 => h2 class="sh-faq__question" { (item.question) },
+                                3 => h3 class="sh-faq__question" { (item.question) },
+                                4 => h4 class="sh-faq__question" { (item.question) },
+                                5 => h5 class="sh-faq__question" { (item.question) },
+                                _ => h6 class="sh-faq__question" { (item.question) },
+                            }
+                            span class="sh-faq__icon" aria-hidden="true" {}
+                        }
+                        div class="sh-faq__answer" {
+                            (item.answer)
+                            a class="sh-faq__permalink" href={ "#" (anchor) } {
+                                "Link to this question"
+                            }
+                        }
+                    }
+                }
+                @if self.structured_data && !self.items.is_empty() {
+                    (self.json_ld_script())
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the FAQ component
+pub fn faq_css() -> String {
+    r#"
+.sh-faq {
+    display: flex;
+    flex-direction: column;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-faq__title {
+    margin: 0 0 1rem;
+    font-size: 1.5rem;
+    font-weight: 700;
+}
+
+.sh-faq__item {
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    scroll-margin-top: 1rem;
+}
+
+.sh-faq__item:first-of-type {
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-faq__summary {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: 1rem;
+    padding: 1rem 0;
+    cursor: pointer;
+    list-style: none;
+}
+
+.sh-faq__summary::-webkit-details-marker {
+    display: none;
+}
+
+.sh-faq__summary:focus-visible {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 2px;
+    border-radius: var(--sh-radius-md, 0.375rem);
+}
+
+.sh-faq__question {
+    margin: 0;
+    font-size: 1rem;
+    font-weight: 600;
+    line-height: 1.5;
+}
+
+.sh-faq__icon {
+    position: relative;
+    flex-shrink: 0;
+    width: 1rem;
+    height: 1rem;
+}
+
+.sh-faq__icon::before,
+.sh-faq__icon::after {
+    content: "";
+    position: absolute;
+    top: 50%;
+    left: 0;
+    width: 100%;
+    height: 2px;
+    margin-top: -1px;
+    background: var(--sh-text-muted, #6b7280);
+    transition: transform 0.2s ease;
+}
+
+.sh-faq__icon::after {
+    transform: rotate(90deg);
+}
+
+.sh-faq__item[open] .sh-faq__icon::after {
+    transform: rotate(0deg);
+}
+
+.sh-faq__item:target .sh-faq__summary {
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-faq__answer {
+    padding: 0 0 1rem;
+    line-height: 1.6;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-faq__answer > :first-child {
+    margin-top: 0;
+}
+
+.sh-faq__permalink {
+    display: inline-block;
+    margin-top: 0.5rem;
+    font-size: 0.8125rem;
+    color: var(--sh-primary, #3b82f6);
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-faq__icon::before,
+    .sh-faq__icon::after {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Faq<'static> {
+        Faq::new(vec![
+            FaqItem::text(
+                "How do I reset my password?",
+                "Use the link on the sign-in page.",
+            ),
+            FaqItem::new(
+                "Do you ship abroad?",
+                html! { p { "Yes, to " strong { "40 countries" } "." } },
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_faq_anchor() {
+        assert_eq!(
+            faq_anchor("How do I reset my password?"),
+            "how-do-i-reset-my-password"
+        );
+        assert_eq!(faq_anchor("What's new in v2.0"), "what-s-new-in-v2-0");
+        assert_eq!(faq_anchor("???"), "question");
+    }
+
+    #[test]
+    fn test_anchors_are_unique() {
+        let faq = Faq::new(vec![
+            FaqItem::text("Pricing", "a"),
+            FaqItem::text("Pricing?", "b"),
+            FaqItem::text("Billing", "c").id("money"),
+        ]);
+        assert_eq!(faq.anchors(), vec!["pricing", "pricing-2", "money"]);
+    }
+
+    #[test]
+    fn test_faq_render_headings() {
+        let html = sample()
+            .title("Help")
+            .heading_level(2)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<h2 class="sh-faq__title">Help</h2>"#));
+        assert!(html.contains(r#"<h3 class="sh-faq__question">How do I reset my password?</h3>"#));
+        assert!(html.contains(r#"<details class="sh-faq__item" id="do-you-ship-abroad">"#));
+        assert!(html.contains(r##"href="#do-you-ship-abroad""##));
+        assert!(!html.contains("application/ld+json"));
+
+        let untitled = sample().heading_level(3).render().into_string();
+        assert!(untitled.contains(r#"aria-label="Frequently asked questions""#));
+        assert!(untitled.contains(r#"<h3 class="sh-faq__question">"#));
+    }
+
+    #[test]
+    fn test_faq_exclusive_and_open() {
+        let html = Faq::new(vec![
+            FaqItem::text("One", "1").open(true),
+            FaqItem::text("Two", "2"),
+        ])
+        .id("help")
+        .exclusive(true)
+        .render()
+        .into_string();
+        assert!(html.contains(r#"id="one" name="help-group" open"#));
+        assert!(html.contains(r#"id="two" name="help-group">"#));
+    }
+
+    #[test]
+    fn test_faq_json_ld() {
+        let json = sample().to_json_ld();
+        assert_eq!(json["@type"], "FAQPage");
+        assert_eq!(json["mainEntity"][0]["name"], "How do I reset my password?");
+        assert_eq!(
+            json["mainEntity"][1]["acceptedAnswer"]["text"],
+            "<p>Yes, to <strong>40 countries</strong>.</p>"
+        );
+
+        let html = sample().structured_data(true).render().into_string();
+        assert!(html.contains(r#"<script type="application/ld+json">"#));
+        assert!(html.contains(
+            r#""text":"\u003cp>Yes, to \u003cstrong>40 countries\u003c/strong>.\u003c/p>""#
+        ));
+    }
+
+    #[test]
+    fn test_faq_css() {
+        let css = faq_css();
+        assert!(css.contains(".sh-faq__summary"));
+        assert!(css.contains(".sh-faq__item[open]"));
+    }
+}
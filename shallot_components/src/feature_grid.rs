@@ -0,0 +1,304 @@
+//! Feature Grid Component - Icon, title and description cells
+//!
+//! Columns collapse on narrow screens without media queries: each cell has
+//! a minimum width and the grid fits as many columns as there is room for,
+//! up to [`FeatureGrid::columns`].
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// A single feature cell
+#[derive(Debug, Clone)]
+pub struct Feature<'a> {
+    pub title: Cow<'a, str>,
+    pub description: Cow<'a, str>,
+    pub icon: Option<Markup>,
+    pub href: Option<Cow<'a, str>>,
+}
+
+impl<'a> Feature<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>, description: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            icon: None,
+            href: None,
+        }
+    }
+
+    /// Icon shown above the title, e.g. an inline SVG or [`crate::Icon`];
+    /// it is decorative and hidden from screen readers
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Add a "Learn more" link
+    pub fn href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+}
+
+/// Feature cell style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeatureGridVariant {
+    #[default]
+    Plain,
+    /// Each cell on a raised surface
+    Cards,
+    /// Cells separated by hairlines
+    Divided,
+}
+
+/// Grid of [`Feature`] cells
+#[derive(Debug, Clone)]
+pub struct FeatureGrid<'a> {
+    pub features: Vec<Feature<'a>>,
+    pub columns: u8,
+    pub variant: FeatureGridVariant,
+    pub centered: bool,
+    pub heading_level: u8,
+    pub link_label: Cow<'a, str>,
+}
+
+impl<'a> FeatureGrid<'a> {
+    pub fn new(features: Vec<Feature<'a>>) -> Self {
+        Self {
+            features,
+            columns: 3,
+            variant: FeatureGridVariant::default(),
+            centered: false,
+            heading_level: 3,
+            link_label: Cow::Borrowed("Learn more"),
+        }
+    }
+
+    pub fn feature(mut self, feature: Feature<'a>) -> Self {
+        self.features.push(feature);
+        self
+    }
+
+    /// Maximum columns on wide screens (1-4)
+    pub fn columns(mut self, columns: u8) -> Self {
+        self.columns = columns.clamp(1, 4);
+        self
+    }
+
+    pub fn variant(mut self, variant: FeatureGridVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Center the icon and text in each cell
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+
+    /// Heading level of the feature titles (2-6)
+    pub fn heading_level(mut self, level: u8) -> Self {
+        self.heading_level = level.clamp(2, 6);
+        self
+    }
+
+    /// Text of the links added by [`Feature::href`]
+    pub fn link_label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.link_label = label.into();
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        let variant = match self.variant {
+            FeatureGridVariant::Plain => "plain",
+            FeatureGridVariant::Cards => "cards",
+            FeatureGridVariant::Divided => "divided",
+        };
+        let mut classes = format!("sh-feature-grid sh-feature-grid--{}", variant);
+        if self.centered {
+            classes.push_str(" sh-feature-grid--centered");
+        }
+        classes
+    }
+
+    fn render_title(&self, title: &str) -> Markup {
+        html! {
+            @match self.heading_level {
+                2 => h2 class="sh-feature__title" { (title) },
+                3 => h3 class="sh-feature__title" { (title) },
+                4 => h4 class="sh-feature__title" { (title) },
+                5 => h5 class="sh-feature__title" { (title) },
+                _ => h6 class="sh-feature__title" { (title) },
+            }
+        }
+    }
+}
+
+impl<'a> Render for FeatureGrid<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            ul class=(self.build_classes()) style=(format!("--sh-feature-columns: {};", self.columns)) {
+                @for feature in &self.features {
+                    li class="sh-feature" {
+                        @if let Some(icon) = &feature.icon {
+                            span class="sh-feature__icon" aria-hidden="true" { (icon) }
+                        }
+                        (self.render_title(&feature.title))
+                        p class="sh-feature__description" { (feature.description) }
+                        @if let Some(href) = &feature.href {
+                            a class="sh-feature__link" href=(href) {
+                                (self.link_label)
+                                span class="sh-visually-hidden" { ": " (feature.title) }
+                                span aria-hidden="true" { " →" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the feature grid
+pub fn feature_grid_css() -> String {
+    r#"
+.sh-feature-grid {
+    --sh-feature-gap: 2rem;
+    display: grid;
+    grid-template-columns: repeat(
+        auto-fit,
+        minmax(max(15rem, calc((100% - (var(--sh-feature-columns, 3) - 1) * var(--sh-feature-gap)) / var(--sh-feature-columns, 3))), 1fr)
+    );
+    gap: var(--sh-feature-gap);
+    margin: 0;
+    padding: 0;
+    list-style: none;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-feature {
+    display: flex;
+    flex-direction: column;
+    align-items: flex-start;
+    gap: 0.5rem;
+}
+
+.sh-feature-grid--centered .sh-feature {
+    align-items: center;
+    text-align: center;
+}
+
+.sh-feature__icon {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    width: 2.75rem;
+    height: 2.75rem;
+    margin-bottom: 0.5rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: color-mix(in srgb, var(--sh-primary, #3b82f6) 12%, transparent);
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-feature__icon svg,
+.sh-feature__icon img {
+    width: 1.5rem;
+    height: 1.5rem;
+}
+
+.sh-feature__title {
+    margin: 0;
+    font-size: 1.125rem;
+    font-weight: 600;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-feature__description {
+    margin: 0;
+    line-height: 1.6;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-feature__link {
+    margin-top: auto;
+    padding-top: 0.5rem;
+    font-size: 0.875rem;
+    font-weight: 600;
+    color: var(--sh-primary, #3b82f6);
+    text-decoration: none;
+}
+
+.sh-feature__link:hover {
+    text-decoration: underline;
+}
+
+.sh-feature-grid--cards .sh-feature {
+    padding: 1.5rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.75rem);
+    background: var(--sh-surface, #fff);
+    box-shadow: 0 1px 2px rgba(0, 0, 0, 0.04);
+}
+
+.sh-feature-grid--divided {
+    --sh-feature-gap: 0px;
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+    border-left: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-feature-grid--divided .sh-feature {
+    padding: 1.5rem;
+    border-right: 1px solid var(--sh-border, #e5e7eb);
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> FeatureGrid<'static> {
+        FeatureGrid::new(vec![
+            Feature::new("Zero JavaScript", "Everything renders on the server.")
+                .icon(html! { svg viewBox="0 0 24 24" {} }),
+            Feature::new("Accessible", "Semantic HTML by default.").href("/docs/a11y"),
+        ])
+    }
+
+    #[test]
+    fn test_feature_grid_render() {
+        let html = sample().render().into_string();
+        assert!(html.contains("sh-feature-grid sh-feature-grid--plain"));
+        assert!(html.contains("--sh-feature-columns: 3;"));
+        assert!(html.contains(r#"<span class="sh-feature__icon" aria-hidden="true"><svg"#));
+        assert!(html.contains(r#"<h3 class="sh-feature__title">Zero JavaScript</h3>"#));
+        assert!(html.contains(r#"href="/docs/a11y""#));
+        assert!(html.contains(": Accessible"));
+        assert_eq!(html.matches(r#"<li class="sh-feature">"#).count(), 2);
+    }
+
+    #[test]
+    fn test_feature_grid_options() {
+        let html = sample()
+            .columns(9)
+            .variant(FeatureGridVariant::Cards)
+            .centered(true)
+            .heading_level(2)
+            .link_label("Read the guide")
+            .render()
+            .into_string();
+        assert!(html.contains("--sh-feature-columns: 4;"));
+        assert!(html.contains("sh-feature-grid--cards sh-feature-grid--centered"));
+        assert!(html.contains(r#"<h2 class="sh-feature__title">"#));
+        assert!(html.contains("Read the guide"));
+    }
+
+    #[test]
+    fn test_feature_grid_css() {
+        let css = feature_grid_css();
+        assert!(css.contains("auto-fit"));
+        assert!(css.contains(".sh-feature-grid--divided"));
+    }
+}
@@ -0,0 +1,682 @@
+//! Feed Generation
+//!
+//! Typed site feeds rendered as RSS 2.0, Atom 1.0 or JSON Feed 1.1 from the
+//! same [`Feed`] value. Dates are supplied as RFC 3339 strings
+//! (`2026-02-24T00:00:00Z` or `2026-02-24`) and converted to RFC 822 for RSS.
+
+use maud::{html, Markup, PreEscaped};
+use serde_json::{json, Map, Value};
+use shallot_foundation::{CalendarDate, MONTH_ABBREVIATIONS};
+
+pub(crate) const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+/// Output format for a feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedFormat {
+    #[default]
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    /// MIME type for the `Content-Type` header and `<link rel="alternate">`
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Atom => "application/atom+xml",
+            FeedFormat::Json => "application/feed+json",
+        }
+    }
+
+    /// Conventional file name for the feed
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss.xml",
+            FeedFormat::Atom => "atom.xml",
+            FeedFormat::Json => "feed.json",
+        }
+    }
+}
+
+/// Feed or item author
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedAuthor {
+    pub name: String,
+    pub email: Option<String>,
+    pub url: Option<String>,
+}
+
+impl FeedAuthor {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: None,
+            url: None,
+        }
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// RSS `author` value: `email (Name)`, which RSS requires to lead with an email
+    fn rss_value(&self) -> Option<String> {
+        self.email
+            .as_ref()
+            .map(|email| format!("{} ({})", email, self.name))
+    }
+}
+
+/// Attached media file (podcast audio, video, download)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enclosure {
+    pub url: String,
+    pub mime_type: String,
+    pub length: u64,
+}
+
+impl Enclosure {
+    pub fn new(url: impl Into<String>, mime_type: impl Into<String>, length: u64) -> Self {
+        Self {
+            url: url.into(),
+            mime_type: mime_type.into(),
+            length,
+        }
+    }
+}
+
+/// A single feed entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedItem {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub published: String,
+    pub updated: Option<String>,
+    pub summary: Option<String>,
+    pub content_html: Option<String>,
+    pub authors: Vec<FeedAuthor>,
+    pub categories: Vec<String>,
+    pub enclosure: Option<Enclosure>,
+}
+
+impl FeedItem {
+    /// Create an item; the URL doubles as its permanent id
+    pub fn new(
+        title: impl Into<String>,
+        url: impl Into<String>,
+        published: impl Into<String>,
+    ) -> Self {
+        let url = url.into();
+        Self {
+            id: url.clone(),
+            title: title.into(),
+            url,
+            published: published.into(),
+            updated: None,
+            summary: None,
+            content_html: None,
+            authors: Vec::new(),
+            categories: Vec::new(),
+            enclosure: None,
+        }
+    }
+
+    /// Override the permanent id (defaults to the URL)
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn updated(mut self, updated: impl Into<String>) -> Self {
+        self.updated = Some(updated.into());
+        self
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Full HTML body; escaped into the XML formats
+    pub fn content_html(mut self, html: impl Into<String>) -> Self {
+        self.content_html = Some(html.into());
+        self
+    }
+
+    pub fn author(mut self, author: FeedAuthor) -> Self {
+        self.authors.push(author);
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.categories.push(category.into());
+        self
+    }
+
+    pub fn enclosure(mut self, enclosure: Enclosure) -> Self {
+        self.enclosure = Some(enclosure);
+        self
+    }
+
+    fn last_modified(&self) -> &str {
+        self.updated.as_deref().unwrap_or(&self.published)
+    }
+}
+
+/// A site feed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feed {
+    pub title: String,
+    pub home_url: String,
+    pub feed_url: String,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub icon: Option<String>,
+    pub updated: Option<String>,
+    pub authors: Vec<FeedAuthor>,
+    pub items: Vec<FeedItem>,
+}
+
+impl Feed {
+    /// Create a feed; `feed_url` is where this rendering will be served
+    pub fn new(
+        title: impl Into<String>,
+        home_url: impl Into<String>,
+        feed_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            home_url: home_url.into(),
+            feed_url: feed_url.into(),
+            description: None,
+            language: None,
+            icon: None,
+            updated: None,
+            authors: Vec::new(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Language tag such as `en-us`
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the feed timestamp (defaults to the newest item)
+    pub fn updated(mut self, updated: impl Into<String>) -> Self {
+        self.updated = Some(updated.into());
+        self
+    }
+
+    pub fn author(mut self, author: FeedAuthor) -> Self {
+        self.authors.push(author);
+        self
+    }
+
+    pub fn item(mut self, item: FeedItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = FeedItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Feed timestamp, falling back to the most recent item
+    pub fn last_updated(&self) -> Option<&str> {
+        self.updated.as_deref().or_else(|| {
+            self.items
+                .iter()
+                .map(FeedItem::last_modified)
+                .max_by_key(|date| parse_rfc3339(date).map(|d| d.timestamp()))
+        })
+    }
+
+    /// Render in the given format
+    pub fn render(&self, format: FeedFormat) -> String {
+        match format {
+            FeedFormat::Rss => self.to_rss(),
+            FeedFormat::Atom => self.to_atom(),
+            FeedFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Render as RSS 2.0
+    pub fn to_rss(&self) -> String {
+        let markup: Markup = html! {
+            (PreEscaped(XML_DECLARATION))
+            rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" {
+                channel {
+                    title { (self.title) }
+                    link { (self.home_url) }
+                    description { (self.description.as_deref().unwrap_or(&self.title)) }
+                    atom:link href=(self.feed_url) rel="self" type=(FeedFormat::Rss.content_type()) {}
+                    @if let Some(language) = &self.language {
+                        language { (language) }
+                    }
+                    @if let Some(updated) = self.last_updated() {
+                        lastBuildDate { (rfc2822(updated)) }
+                    }
+                    @if let Some(icon) = &self.icon {
+                        image {
+                            url { (icon) }
+                            title { (self.title) }
+                            link { (self.home_url) }
+                        }
+                    }
+                    @for item in &self.items {
+                        item {
+                            title { (item.title) }
+                            link { (item.url) }
+                            guid isPermaLink=(if item.id == item.url { "true" } else { "false" }) {
+                                (item.id)
+                            }
+                            pubDate { (rfc2822(&item.published)) }
+                            @if let Some(body) = item.content_html.as_ref().or(item.summary.as_ref()) {
+                                description { (body) }
+                            }
+                            @for author in &item.authors {
+                                @if let Some(value) = author.rss_value() {
+                                    author { (value) }
+                                }
+                            }
+                            @for category in &item.categories {
+                                category { (category) }
+                            }
+                            @if let Some(enclosure) = &item.enclosure {
+                                enclosure
+                                    url=(enclosure.url)
+                                    length=(enclosure.length)
+                                    type=(enclosure.mime_type)
+                                {}
+                            }
+                        }
+                    }
+                }
+            }
+        };
+        markup.into_string()
+    }
+
+    /// Render as Atom 1.0
+    pub fn to_atom(&self) -> String {
+        let markup: Markup = html! {
+            (PreEscaped(XML_DECLARATION))
+            feed xmlns="http://www.w3.org/2005/Atom" xml:lang=[self.language.as_deref()] {
+                title { (self.title) }
+                @if let Some(description) = &self.description {
+                    subtitle { (description) }
+                }
+                link href=(self.home_url) rel="alternate" {}
+                link href=(self.feed_url) rel="self" type=(FeedFormat::Atom.content_type()) {}
+                id { (self.feed_url) }
+                updated { (self.last_updated().unwrap_or("1970-01-01T00:00:00Z")) }
+                @if let Some(icon) = &self.icon {
+                    icon { (icon) }
+                }
+                @for author in &self.authors {
+                    (atom_author(author))
+                }
+                @for item in &self.items {
+                    entry {
+                        title { (item.title) }
+                        link href=(item.url) rel="alternate" {}
+                        id { (item.id) }
+                        published { (item.published) }
+                        updated { (item.last_modified()) }
+                        @for author in &item.authors {
+                            (atom_author(author))
+                        }
+                        @if let Some(summary) = &item.summary {
+                            summary { (summary) }
+                        }
+                        @if let Some(content) = &item.content_html {
+                            content type="html" { (content) }
+                        }
+                        @for category in &item.categories {
+                            category term=(category) {}
+                        }
+                        @if let Some(enclosure) = &item.enclosure {
+                            link
+                                rel="enclosure"
+                                href=(enclosure.url)
+                                type=(enclosure.mime_type)
+                                length=(enclosure.length)
+                            {}
+                        }
+                    }
+                }
+            }
+        };
+        markup.into_string()
+    }
+
+    /// Render as JSON Feed 1.1
+    pub fn to_json(&self) -> String {
+        let mut feed = Map::new();
+        feed.insert("version".into(), json!("https://jsonfeed.org/version/1.1"));
+        feed.insert("title".into(), json!(self.title));
+        feed.insert("home_page_url".into(), json!(self.home_url));
+        feed.insert("feed_url".into(), json!(self.feed_url));
+        insert_opt(&mut feed, "description", self.description.as_deref());
+        insert_opt(&mut feed, "language", self.language.as_deref());
+        insert_opt(&mut feed, "icon", self.icon.as_deref());
+        if !self.authors.is_empty() {
+            feed.insert("authors".into(), json_authors(&self.authors));
+        }
+
+        let items: Vec<Value> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut entry = Map::new();
+                entry.insert("id".into(), json!(item.id));
+                entry.insert("url".into(), json!(item.url));
+                entry.insert("title".into(), json!(item.title));
+                insert_opt(&mut entry, "summary", item.summary.as_deref());
+                let content = item.content_html.as_deref().or(item.summary.as_deref());
+                entry.insert("content_html".into(), json!(content.unwrap_or("")));
+                entry.insert("date_published".into(), json!(item.published));
+                insert_opt(&mut entry, "date_modified", item.updated.as_deref());
+                if !item.authors.is_empty() {
+                    entry.insert("authors".into(), json_authors(&item.authors));
+                }
+                if !item.categories.is_empty() {
+                    entry.insert("tags".into(), json!(item.categories));
+                }
+                if let Some(enclosure) = &item.enclosure {
+                    entry.insert(
+                        "attachments".into(),
+                        json!([{
+                            "url": enclosure.url,
+                            "mime_type": enclosure.mime_type,
+                            "size_in_bytes": enclosure.length,
+                        }]),
+                    );
+                }
+                Value::Object(entry)
+            })
+            .collect();
+        feed.insert("items".into(), Value::Array(items));
+
+        serde_json::to_string_pretty(&Value::Object(feed)).unwrap_or_default()
+    }
+}
+
+fn atom_author(author: &FeedAuthor) -> Markup {
+    html! {
+        author {
+            name { (author.name) }
+            @if let Some(email) = &author.email {
+                email { (email) }
+            }
+            @if let Some(url) = &author.url {
+                uri { (url) }
+            }
+        }
+    }
+}
+
+fn json_authors(authors: &[FeedAuthor]) -> Value {
+    authors
+        .iter()
+        .map(|author| {
+            let mut value = Map::new();
+            value.insert("name".into(), json!(author.name));
+            insert_opt(&mut value, "url", author.url.as_deref());
+            Value::Object(value)
+        })
+        .collect()
+}
+
+fn insert_opt(map: &mut Map<String, Value>, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        map.insert(key.into(), json!(value));
+    }
+}
+
+/// Broken-down RFC 3339 timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DateTime {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    offset_minutes: i32,
+}
+
+impl DateTime {
+    /// Days since 1970-01-01
+    fn days(&self) -> i64 {
+        CalendarDate::new(self.year, self.month, self.day).days_since_epoch()
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.days() * 86_400 + (self.hour * 3600 + self.minute * 60 + self.second) as i64
+            - self.offset_minutes as i64 * 60
+    }
+}
+
+fn parse_rfc3339(input: &str) -> Option<DateTime> {
+    let input = input.trim();
+    let (date, time) = match input.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (input, None),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|m| (1..=12).contains(m))?;
+    let day = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|d| (1..=31).contains(d))?;
+
+    let mut parsed = DateTime {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        offset_minutes: 0,
+    };
+
+    if let Some(time) = time {
+        let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+            (clock, 0)
+        } else {
+            let split = time.rfind(['+', '-'])?;
+            let (clock, offset) = time.split_at(split);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (h, m) = offset[1..].split_once(':')?;
+            let minutes = h.parse::<i32>().ok()? * 60 + m.parse::<i32>().ok()?;
+            (clock, sign * minutes)
+        };
+        let clock = clock.split('.').next()?;
+        let mut fields = clock.splitn(3, ':');
+        parsed.hour = fields.next()?.parse().ok().filter(|h| *h < 24)?;
+        parsed.minute = fields.next()?.parse().ok().filter(|m| *m < 60)?;
+        parsed.second = fields
+            .next()
+            .unwrap_or("0")
+            .parse()
+            .ok()
+            .filter(|s| *s < 61)?;
+        parsed.offset_minutes = offset;
+    }
+
+    Some(parsed)
+}
+
+/// Convert an RFC 3339 date to the RFC 822 form RSS expects.
+/// Unparseable input is passed through unchanged.
+pub fn rfc2822(date: &str) -> String {
+    const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+
+    let Some(parsed) = parse_rfc3339(date) else {
+        return date.to_string();
+    };
+
+    let sign = if parsed.offset_minutes < 0 { '-' } else { '+' };
+    let offset = parsed.offset_minutes.abs();
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        DAYS[parsed.days().rem_euclid(7) as usize],
+        parsed.day,
+        MONTH_ABBREVIATIONS[parsed.month as usize - 1],
+        parsed.year,
+        parsed.hour,
+        parsed.minute,
+        parsed.second,
+        sign,
+        offset / 60,
+        offset % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_feed() -> Feed {
+        Feed::new(
+            "Shallot Updates",
+            "https://shallot.rs/",
+            "https://shallot.rs/feed.xml",
+        )
+        .description("Zero-JS <Rust> UI")
+        .language("en-us")
+        .author(FeedAuthor::new("Shallot Team").url("https://shallot.rs/"))
+        .item(
+            FeedItem::new(
+                "Release & notes",
+                "https://shallot.rs/blog/v1",
+                "2026-02-24T00:00:00Z",
+            )
+            .summary("All components complete")
+            .content_html("<p>All <b>done</b></p>")
+            .author(FeedAuthor::new("Ada").email("ada@shallot.rs"))
+            .category("release")
+            .enclosure(Enclosure::new(
+                "https://shallot.rs/v1.mp3",
+                "audio/mpeg",
+                1024,
+            )),
+        )
+        .item(
+            FeedItem::new("Older", "https://shallot.rs/blog/old", "2026-02-20")
+                .id("urn:shallot:old"),
+        )
+    }
+
+    #[test]
+    fn test_rfc2822_conversion() {
+        assert_eq!(
+            rfc2822("2026-02-24T00:00:00Z"),
+            "Tue, 24 Feb 2026 00:00:00 +0000"
+        );
+        assert_eq!(
+            rfc2822("1970-01-01T08:30:15.250-05:30"),
+            "Thu, 01 Jan 1970 08:30:15 -0530"
+        );
+        assert_eq!(rfc2822("2024-02-29"), "Thu, 29 Feb 2024 00:00:00 +0000");
+        assert_eq!(rfc2822("yesterday"), "yesterday");
+    }
+
+    #[test]
+    fn test_last_updated_picks_newest_item() {
+        let feed = sample_feed();
+        assert_eq!(feed.last_updated(), Some("2026-02-24T00:00:00Z"));
+        assert_eq!(
+            feed.clone().updated("2026-03-01").last_updated(),
+            Some("2026-03-01")
+        );
+    }
+
+    #[test]
+    fn test_rss_output() {
+        let rss = sample_feed().to_rss();
+
+        assert!(rss.starts_with(XML_DECLARATION));
+        assert!(rss.contains(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#));
+        assert!(rss.contains(
+            r#"<atom:link href="https://shallot.rs/feed.xml" rel="self" type="application/rss+xml"></atom:link>"#
+        ));
+        assert!(rss.contains("<description>Zero-JS &lt;Rust&gt; UI</description>"));
+        assert!(rss.contains("<title>Release &amp; notes</title>"));
+        assert!(rss.contains("<pubDate>Tue, 24 Feb 2026 00:00:00 +0000</pubDate>"));
+        assert!(rss
+            .contains("<description>&lt;p&gt;All &lt;b&gt;done&lt;/b&gt;&lt;/p&gt;</description>"));
+        assert!(rss.contains("<author>ada@shallot.rs (Ada)</author>"));
+        assert!(rss.contains("<category>release</category>"));
+        assert!(rss.contains(r#"<guid isPermaLink="false">urn:shallot:old</guid>"#));
+        assert!(rss.contains(
+            r#"<enclosure url="https://shallot.rs/v1.mp3" length="1024" type="audio/mpeg"></enclosure>"#
+        ));
+        assert!(rss.contains("<lastBuildDate>Tue, 24 Feb 2026 00:00:00 +0000</lastBuildDate>"));
+    }
+
+    #[test]
+    fn test_atom_output() {
+        let atom = sample_feed().to_atom();
+
+        assert!(atom.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="en-us">"#));
+        assert!(atom.contains("<id>https://shallot.rs/feed.xml</id>"));
+        assert!(atom.contains("<updated>2026-02-24T00:00:00Z</updated>"));
+        assert!(atom.contains("<subtitle>Zero-JS &lt;Rust&gt; UI</subtitle>"));
+        assert!(atom.contains(r#"<content type="html">&lt;p&gt;"#));
+        assert!(atom.contains(r#"<category term="release"></category>"#));
+        assert!(atom.contains(r#"<link rel="enclosure" href="https://shallot.rs/v1.mp3""#));
+        assert!(atom.contains("<email>ada@shallot.rs</email>"));
+    }
+
+    #[test]
+    fn test_json_feed_output() {
+        let json: Value = serde_json::from_str(&sample_feed().to_json()).unwrap();
+
+        assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(json["home_page_url"], "https://shallot.rs/");
+        assert_eq!(json["authors"][0]["name"], "Shallot Team");
+        assert_eq!(json["items"][0]["content_html"], "<p>All <b>done</b></p>");
+        assert_eq!(json["items"][0]["tags"][0], "release");
+        assert_eq!(json["items"][0]["attachments"][0]["size_in_bytes"], 1024);
+        assert_eq!(json["items"][1]["id"], "urn:shallot:old");
+        assert!(json["items"][1].get("summary").is_none());
+    }
+
+    #[test]
+    fn test_feed_format_metadata() {
+        assert_eq!(FeedFormat::Json.content_type(), "application/feed+json");
+        assert_eq!(FeedFormat::Atom.file_name(), "atom.xml");
+        let feed = sample_feed();
+        assert_eq!(feed.render(FeedFormat::Rss), feed.to_rss());
+    }
+}
@@ -0,0 +1,419 @@
+//! Focus - keyboard focus rings, skip links and focus order checks
+//!
+//! Every focusable element gets the same `:focus-visible` ring, drawn from
+//! the `--sh-focus-ring-*` tokens so a theme can restyle it in one place.
+//! [`SkipLinks`] renders a list of "skip to" links that stay off screen
+//! until they receive focus, and [`focus_order_audit`] scans rendered HTML
+//! for tab order problems.
+//!
+//! # Example
+//!
+//! ```
+//! use maud::Render;
+//! use shallot_components::focus::{focus_order_audit, SkipLinks};
+//!
+//! let links = SkipLinks::new()
+//!     .link("main", "Skip to content")
+//!     .link("search", "Skip to search");
+//! let html = links.render().into_string();
+//! assert!(html.contains(r##"href="#main""##));
+//! assert!(focus_order_audit(&html).is_empty());
+//! ```
+
+use maud::{html, Markup, Render};
+
+/// Elements that take keyboard focus and get the focus ring
+pub const FOCUSABLE_SELECTORS: &[&str] = &[
+    "a[href]",
+    "button",
+    "input",
+    "select",
+    "textarea",
+    "summary",
+    "[tabindex]",
+    "[contenteditable]",
+];
+
+/// Token values for the `:focus-visible` ring
+#[derive(Debug, Clone)]
+pub struct FocusRing<'a> {
+    pub width_px: u8,
+    pub offset_px: u8,
+    pub color: &'a str,
+}
+
+impl Default for FocusRing<'_> {
+    fn default() -> Self {
+        Self {
+            width_px: 2,
+            offset_px: 2,
+            color: "var(--sh-focus-ring, var(--sh-accent, #3b82f6))",
+        }
+    }
+}
+
+impl<'a> FocusRing<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Outline thickness in pixels
+    pub fn width(mut self, width_px: u8) -> Self {
+        self.width_px = width_px;
+        self
+    }
+
+    /// Gap between the element and its ring in pixels
+    pub fn offset(mut self, offset_px: u8) -> Self {
+        self.offset_px = offset_px;
+        self
+    }
+
+    /// Ring colour, any CSS colour value
+    pub fn color(mut self, color: &'a str) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Ring tokens as custom properties on `selector`
+    pub fn css(&self, selector: &str) -> String {
+        format!(
+            "{selector} {{\n    --sh-focus-ring-width: {}px;\n    --sh-focus-ring-offset: {}px;\n    --sh-focus-ring-color: {};\n}}\n",
+            self.width_px, self.offset_px, self.color
+        )
+    }
+}
+
+/// A list of links that jump past repeated page chrome
+#[derive(Debug, Clone)]
+pub struct SkipLinks<'a> {
+    pub links: Vec<(String, &'a str)>,
+    pub aria_label: &'a str,
+}
+
+impl Default for SkipLinks<'_> {
+    fn default() -> Self {
+        Self {
+            links: Vec::new(),
+            aria_label: "Skip links",
+        }
+    }
+}
+
+impl<'a> SkipLinks<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a link to the element with id `target` (a leading `#` is optional)
+    pub fn link(mut self, target: &str, label: &'a str) -> Self {
+        let href = if target.starts_with('#') {
+            target.to_string()
+        } else {
+            format!("#{target}")
+        };
+        self.links.push((href, label));
+        self
+    }
+
+    pub fn aria_label(mut self, label: &'a str) -> Self {
+        self.aria_label = label;
+        self
+    }
+}
+
+impl Render for SkipLinks<'_> {
+    fn render(&self) -> Markup {
+        html! {
+            nav class="sh-skip-links" aria-label=(self.aria_label) {
+                ul class="sh-skip-links__list" {
+                    @for (href, label) in &self.links {
+                        li {
+                            a class="sh-skip-links__link" href=(href) { (label) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for focus rings and skip links
+pub fn focus_css() -> String {
+    let mut css = FocusRing::default().css(":root");
+    css.push_str(&format!(
+        ":where({}):focus-visible {{\n    outline: var(--sh-focus-ring-width) solid var(--sh-focus-ring-color);\n    outline-offset: var(--sh-focus-ring-offset);\n}}\n",
+        FOCUSABLE_SELECTORS.join(", ")
+    ));
+    css.push_str(
+        r#"
+.sh-skip-links__list {
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-skip-links__link {
+    position: absolute;
+    top: var(--sh-space-4, 1rem);
+    left: var(--sh-space-4, 1rem);
+    z-index: 9999;
+    padding: var(--sh-space-3, 0.75rem) var(--sh-space-4, 1rem);
+    background: var(--sh-surface, #ffffff);
+    color: var(--sh-text, #111827);
+    font-weight: 600;
+    text-decoration: none;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    box-shadow: var(--sh-elevation-3);
+    transform: translateY(-200%);
+}
+
+.sh-skip-links__link:focus {
+    transform: none;
+}
+
+@media (forced-colors: active) {
+    .sh-skip-links__link {
+        border: 2px solid CanvasText;
+    }
+}
+"#,
+    );
+    css
+}
+
+/// Kind of tab order problem found by [`focus_order_audit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusIssueKind {
+    /// `tabindex` above zero, which reorders the whole page's tab sequence
+    PositiveTabindex,
+    /// An enabled interactive element that keyboard users cannot tab to
+    Unreachable,
+}
+
+/// One tab order problem in rendered HTML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusIssue {
+    /// The offending element as `tag`, `tag#id` or `tag.class`
+    pub element: String,
+    pub kind: FocusIssueKind,
+}
+
+/// ARIA roles that promise keyboard interaction
+const INTERACTIVE_ROLES: &[&str] = &[
+    "button", "checkbox", "combobox", "link", "menuitem", "radio", "slider", "switch", "tab",
+    "textbox",
+];
+
+/// Check rendered HTML for positive `tabindex` values and interactive
+/// elements that are left out of the tab order.
+///
+/// An element is unreachable when it is natively focusable but carries a
+/// negative `tabindex` without being disabled, or when it has an
+/// interactive `role` but neither native focusability nor a `tabindex`.
+pub fn focus_order_audit(html: &str) -> Vec<FocusIssue> {
+    let mut issues = Vec::new();
+    for tag in start_tags(html) {
+        let tabindex = tag
+            .attr("tabindex")
+            .and_then(|value| value.trim().parse::<i32>().ok());
+        let disabled = tag.attr("disabled").is_some()
+            || tag.attr("aria-disabled").is_some_and(|v| v != "false")
+            || tag.attr("hidden").is_some()
+            || tag.attr("aria-hidden").is_some_and(|v| v != "false");
+        let native = match tag.name.as_str() {
+            "a" | "area" => tag.attr("href").is_some(),
+            "input" => tag.attr("type") != Some("hidden"),
+            "button" | "select" | "textarea" | "summary" | "iframe" => true,
+            _ => tag.attr("contenteditable").is_some_and(|v| v != "false"),
+        };
+        let interactive_role = tag
+            .attr("role")
+            .is_some_and(|role| INTERACTIVE_ROLES.contains(&role));
+
+        let kind = match tabindex {
+            Some(index) if index > 0 => Some(FocusIssueKind::PositiveTabindex),
+            Some(index) if index < 0 && native && !disabled => Some(FocusIssueKind::Unreachable),
+            None if interactive_role && !native && !disabled => Some(FocusIssueKind::Unreachable),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            issues.push(FocusIssue {
+                element: tag.describe(),
+                kind,
+            });
+        }
+    }
+    issues
+}
+
+struct StartTag<'h> {
+    name: String,
+    attrs: Vec<(String, Option<&'h str>)>,
+}
+
+impl<'h> StartTag<'h> {
+    fn attr(&self, name: &str) -> Option<&'h str> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.unwrap_or(""))
+    }
+
+    fn describe(&self) -> String {
+        if let Some(id) = self.attr("id") {
+            format!("{}#{id}", self.name)
+        } else if let Some(class) = self.attr("class").and_then(|c| c.split_whitespace().next()) {
+            format!("{}.{class}", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+/// Opening tags in document order, skipping comments, doctypes and closing tags
+fn start_tags(html: &str) -> Vec<StartTag<'_>> {
+    let bytes = html.as_bytes();
+    let mut tags = Vec::new();
+    let mut i = 0;
+    while let Some(offset) = html[i..].find('<') {
+        i += offset + 1;
+        if html[i..].starts_with("!--") {
+            i = html[i..].find("-->").map_or(html.len(), |end| i + end + 3);
+            continue;
+        }
+        if !bytes.get(i).is_some_and(u8::is_ascii_alphabetic) {
+            continue;
+        }
+        let name_end = html[i..]
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .map_or(html.len(), |end| i + end);
+        let name = html[i..name_end].to_ascii_lowercase();
+        i = name_end;
+
+        let mut attrs = Vec::new();
+        loop {
+            while bytes
+                .get(i)
+                .is_some_and(|b| b.is_ascii_whitespace() || *b == b'/')
+            {
+                i += 1;
+            }
+            if i >= bytes.len() || bytes[i] == b'>' {
+                i += 1;
+                break;
+            }
+            let attr_end = html[i..]
+                .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+                .map_or(html.len(), |end| i + end);
+            let attr = html[i..attr_end].to_ascii_lowercase();
+            i = attr_end;
+            let mut value = None;
+            if bytes.get(i) == Some(&b'=') {
+                i += 1;
+                match bytes.get(i) {
+                    Some(&quote @ (b'"' | b'\'')) => {
+                        let end = html[i + 1..]
+                            .find(quote as char)
+                            .map_or(html.len(), |end| i + 1 + end);
+                        value = Some(&html[i + 1..end]);
+                        i = (end + 1).min(html.len());
+                    }
+                    _ => {
+                        let end = html[i..]
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .map_or(html.len(), |end| i + end);
+                        value = Some(&html[i..end]);
+                        i = end;
+                    }
+                }
+            }
+            attrs.push((attr, value));
+        }
+        tags.push(StartTag { name, attrs });
+        if i >= html.len() {
+            break;
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_ring_tokens() {
+        let css = FocusRing::new().width(3).color("Highlight").css(".brand");
+        assert!(css.starts_with(".brand {"));
+        assert!(css.contains("--sh-focus-ring-width: 3px;"));
+        assert!(css.contains("--sh-focus-ring-offset: 2px;"));
+        assert!(css.contains("--sh-focus-ring-color: Highlight;"));
+
+        let css = focus_css();
+        assert!(css.contains("a[href], button, input"));
+        assert!(css.contains(":focus-visible {"));
+        assert!(
+            css.contains("outline: var(--sh-focus-ring-width) solid var(--sh-focus-ring-color);")
+        );
+        assert!(css.contains(".sh-skip-links__link:focus"));
+    }
+
+    #[test]
+    fn test_skip_links_render() {
+        let html = SkipLinks::new()
+            .link("main", "Skip to content")
+            .link("#nav", "Skip to navigation")
+            .aria_label("Jump to")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"aria-label="Jump to""#));
+        assert!(html.contains(r##"href="#main""##));
+        assert!(html.contains(r##"href="#nav""##));
+        assert_eq!(html.matches("sh-skip-links__link").count(), 2);
+    }
+
+    #[test]
+    fn test_focus_order_audit() {
+        let html = r#"
+            <!-- <button tabindex="5"> -->
+            <a href="/home" tabindex="3">Home</a>
+            <button id="save" tabindex="-1">Save</button>
+            <button tabindex="-1" disabled>Off</button>
+            <a class="sh-btn sh-btn--primary" aria-disabled="true" tabindex="-1">Link</a>
+            <div class="sh-card sh-card--clickable" role="button">Card</div>
+            <div role="button" tabindex="0">Card</div>
+            <input type="hidden" tabindex="-1" name="csrf">
+        "#;
+        assert_eq!(
+            focus_order_audit(html),
+            vec![
+                FocusIssue {
+                    element: "a".into(),
+                    kind: FocusIssueKind::PositiveTabindex,
+                },
+                FocusIssue {
+                    element: "button#save".into(),
+                    kind: FocusIssueKind::Unreachable,
+                },
+                FocusIssue {
+                    element: "div.sh-card".into(),
+                    kind: FocusIssueKind::Unreachable,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_focus_order_audit_passes_components() {
+        use crate::button::Button;
+
+        let html = html! {
+            (SkipLinks::new().link("main", "Skip to content"))
+            (Button::new("Save").render())
+            (Button::new("Docs").href("/docs").disabled(true).render())
+        }
+        .into_string();
+        assert!(focus_order_audit(&html).is_empty());
+    }
+}
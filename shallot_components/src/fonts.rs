@@ -0,0 +1,643 @@
+//! Fonts - `@font-face` declarations, preload hints and the type scale
+//!
+//! [`FontFace`] describes one font file (or a `local()` install) and emits
+//! its `@font-face` rule; variable fonts declare their axis ranges with
+//! [`FontFace::axis`]. A [`FontSet`] gathers the faces of a site, writes
+//! their CSS and the `<link rel="preload">` tags for the files needed for
+//! first paint.
+//!
+//! [`TextSize`] reads the `--sh-font-size-*` and `--sh-line-height-*`
+//! custom properties of the design tokens' [`TypographyScale`]. A
+//! [`TypeScale`] generates those steps as a modular scale from a base size
+//! and a ratio; emit its CSS after the design tokens to override them.
+//!
+//! [`FluidSize`] grows a size between two viewport widths with `clamp()`;
+//! pass one to `Text::fluid` / `Heading::fluid`, or use
+//! [`TypeScale::fluid_css`] to make the whole scale fluid.
+//!
+//! ```ignore
+//! let fonts = FontSet::new().face(
+//!     FontFace::new("Inter")
+//!         .source(FontSource::local("Inter"))
+//!         .source(FontSource::woff2("/fonts/inter-var.woff2"))
+//!         .axis(FontAxis::weight(100.0, 900.0))
+//!         .preload(true),
+//! );
+//! html! { head { (fonts.preload_tags()) style { (fonts.css()) (TypeScale::new(1.0, 1.25).css(":root")) } } }
+//! ```
+
+use crate::typography::TextSize;
+use maud::{html, Markup};
+use shallot_foundation::{TextStep, TypographyScale};
+
+/// `font-display` strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FontDisplay {
+    Auto,
+    Block,
+    #[default]
+    Swap,
+    Fallback,
+    Optional,
+}
+
+impl FontDisplay {
+    pub const fn css_value(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Block => "block",
+            Self::Swap => "swap",
+            Self::Fallback => "fallback",
+            Self::Optional => "optional",
+        }
+    }
+}
+
+/// Font file format, used for `format()` and the preload MIME type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontFormat {
+    Woff2,
+    Woff,
+    TrueType,
+    OpenType,
+}
+
+impl FontFormat {
+    pub const fn css_value(&self) -> &'static str {
+        match self {
+            Self::Woff2 => "woff2",
+            Self::Woff => "woff",
+            Self::TrueType => "truetype",
+            Self::OpenType => "opentype",
+        }
+    }
+
+    pub const fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Woff2 => "font/woff2",
+            Self::Woff => "font/woff",
+            Self::TrueType => "font/ttf",
+            Self::OpenType => "font/otf",
+        }
+    }
+}
+
+/// One entry of a `src:` list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontSource {
+    /// A font installed on the visitor's system
+    Local(String),
+    Url {
+        url: String,
+        format: FontFormat,
+    },
+}
+
+impl FontSource {
+    pub fn local(name: impl Into<String>) -> Self {
+        Self::Local(name.into())
+    }
+
+    pub fn woff2(url: impl Into<String>) -> Self {
+        Self::Url {
+            url: url.into(),
+            format: FontFormat::Woff2,
+        }
+    }
+
+    pub fn woff(url: impl Into<String>) -> Self {
+        Self::Url {
+            url: url.into(),
+            format: FontFormat::Woff,
+        }
+    }
+
+    pub fn url(url: impl Into<String>, format: FontFormat) -> Self {
+        Self::Url {
+            url: url.into(),
+            format,
+        }
+    }
+
+    fn css(&self, variable: bool) -> String {
+        match self {
+            Self::Local(name) => format!("local(\"{}\")", css_string(name)),
+            Self::Url { url, format } => {
+                let tech = if variable { " tech(variations)" } else { "" };
+                format!(
+                    "url(\"{}\") format(\"{}\"){}",
+                    css_string(url),
+                    format.css_value(),
+                    tech
+                )
+            }
+        }
+    }
+}
+
+/// A variation axis a variable font supports
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontAxis {
+    /// Four-letter axis tag, e.g. `wght` or `GRAD`
+    pub tag: String,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl FontAxis {
+    pub fn new(tag: impl Into<String>, min: f32, max: f32) -> Self {
+        Self {
+            tag: tag.into(),
+            min,
+            max,
+        }
+    }
+
+    /// `wght`, declared as a `font-weight` range
+    pub fn weight(min: f32, max: f32) -> Self {
+        Self::new("wght", min, max)
+    }
+
+    /// `wdth` in percent, declared as a `font-stretch` range
+    pub fn width(min: f32, max: f32) -> Self {
+        Self::new("wdth", min, max)
+    }
+
+    /// `slnt` in degrees, declared as an oblique `font-style` range
+    pub fn slant(min: f32, max: f32) -> Self {
+        Self::new("slnt", min, max)
+    }
+}
+
+/// An `@font-face` rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    pub family: String,
+    pub sources: Vec<FontSource>,
+    pub weight: Option<String>,
+    pub style: Option<String>,
+    pub display: FontDisplay,
+    pub unicode_range: Option<String>,
+    pub axes: Vec<FontAxis>,
+    pub preload: bool,
+}
+
+impl FontFace {
+    pub fn new(family: impl Into<String>) -> Self {
+        Self {
+            family: family.into(),
+            sources: Vec::new(),
+            weight: None,
+            style: None,
+            display: FontDisplay::default(),
+            unicode_range: None,
+            axes: Vec::new(),
+            preload: false,
+        }
+    }
+
+    /// Sources are tried in the order they are added
+    pub fn source(mut self, source: FontSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// A static weight such as `400` or `bold`
+    pub fn weight(mut self, weight: impl Into<String>) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+
+    /// `normal` or `italic`
+    pub fn style(mut self, style: impl Into<String>) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    pub fn display(mut self, display: FontDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Limit the face to a subset, e.g. `U+0000-00FF`
+    pub fn unicode_range(mut self, range: impl Into<String>) -> Self {
+        self.unicode_range = Some(range.into());
+        self
+    }
+
+    /// Declare a variation axis; this marks the face as a variable font
+    pub fn axis(mut self, axis: FontAxis) -> Self {
+        self.axes.push(axis);
+        self
+    }
+
+    /// Emit a preload hint for the first URL source
+    pub fn preload(mut self, preload: bool) -> Self {
+        self.preload = preload;
+        self
+    }
+
+    fn axis_range(&self, tag: &str) -> Option<(f32, f32)> {
+        self.axes
+            .iter()
+            .find(|axis| axis.tag == tag)
+            .map(|axis| (axis.min, axis.max))
+    }
+
+    /// The `@font-face` rule
+    pub fn css(&self) -> String {
+        let variable = !self.axes.is_empty();
+        let sources: Vec<String> = self.sources.iter().map(|s| s.css(variable)).collect();
+
+        let mut css = format!(
+            "@font-face {{\n  font-family: \"{}\";\n  src: {};\n",
+            css_string(&self.family),
+            sources.join(", ")
+        );
+        let weight = match self.axis_range("wght") {
+            Some((min, max)) => Some(format!("{} {}", min, max)),
+            None => self.weight.clone(),
+        };
+        if let Some(weight) = weight {
+            css.push_str(&format!("  font-weight: {};\n", weight));
+        }
+        if let Some((min, max)) = self.axis_range("wdth") {
+            css.push_str(&format!("  font-stretch: {}% {}%;\n", min, max));
+        }
+        let style = match self.axis_range("slnt") {
+            Some((min, max)) => Some(format!("oblique {}deg {}deg", min, max)),
+            None => self.style.clone(),
+        };
+        if let Some(style) = style {
+            css.push_str(&format!("  font-style: {};\n", style));
+        }
+        css.push_str(&format!("  font-display: {};\n", self.display.css_value()));
+        if let Some(range) = &self.unicode_range {
+            css.push_str(&format!("  unicode-range: {};\n", range));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// `<link rel="preload">` for the first URL source; fonts are always
+    /// fetched in CORS mode, so `crossorigin` is required even same-origin
+    pub fn preload_tag(&self) -> Option<Markup> {
+        self.sources.iter().find_map(|source| match source {
+            FontSource::Url { url, format } => Some(html! {
+                link rel="preload" href=(url) as="font" type=(format.mime_type()) crossorigin;
+            }),
+            FontSource::Local(_) => None,
+        })
+    }
+}
+
+/// `font-variation-settings` value for custom axes, e.g.
+/// `"wght" 650, "GRAD" 50`
+pub fn font_variation_settings(settings: &[(&str, f32)]) -> String {
+    settings
+        .iter()
+        .map(|(tag, value)| format!("\"{}\" {}", css_string(tag), value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The fonts of a site
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontSet {
+    pub faces: Vec<FontFace>,
+}
+
+impl FontSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn face(mut self, face: FontFace) -> Self {
+        self.faces.push(face);
+        self
+    }
+
+    /// Every `@font-face` rule
+    pub fn css(&self) -> String {
+        self.faces
+            .iter()
+            .map(FontFace::css)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// `<head>` fragment preloading the faces marked with
+    /// [`FontFace::preload`]
+    pub fn preload_tags(&self) -> Markup {
+        html! {
+            @for face in self.faces.iter().filter(|face| face.preload) {
+                @if let Some(tag) = face.preload_tag() {
+                    (tag)
+                }
+            }
+        }
+    }
+}
+
+/// A modular type scale: each [`TextSize`] step is the previous one times
+/// `ratio`, with `Md` at `base_rem`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypeScale {
+    pub base_rem: f32,
+    pub ratio: f32,
+    /// Unitless line height for `Md` and smaller
+    pub body_leading: f32,
+    /// Unitless line height for steps above `Md`
+    pub heading_leading: f32,
+    /// Line heights are rounded up to a multiple of this, in rem
+    pub rhythm_rem: f32,
+}
+
+impl TypeScale {
+    /// Common ratios
+    pub const MAJOR_SECOND: f32 = 1.125;
+    pub const MINOR_THIRD: f32 = 1.2;
+    pub const MAJOR_THIRD: f32 = 1.25;
+    pub const PERFECT_FOURTH: f32 = 1.333;
+    pub const GOLDEN_RATIO: f32 = 1.618;
+
+    pub fn new(base_rem: f32, ratio: f32) -> Self {
+        Self {
+            base_rem,
+            ratio,
+            body_leading: 1.5,
+            heading_leading: 1.25,
+            rhythm_rem: 0.25,
+        }
+    }
+
+    pub fn leading(mut self, body: f32, heading: f32) -> Self {
+        self.body_leading = body;
+        self.heading_leading = heading;
+        self
+    }
+
+    /// Set the rounding grid for line heights; `0` disables rounding
+    pub fn rhythm(mut self, rhythm_rem: f32) -> Self {
+        self.rhythm_rem = rhythm_rem;
+        self
+    }
+
+    /// Font size of `size`, in rem
+    pub fn size(&self, size: TextSize) -> f32 {
+        self.base_rem * self.ratio.powi(size.step())
+    }
+
+    /// Line height of `size`, in rem
+    pub fn line_height(&self, size: TextSize) -> f32 {
+        let leading = if size.step() > 0 {
+            self.heading_leading
+        } else {
+            self.body_leading
+        };
+        let height = self.size(size) * leading;
+        if self.rhythm_rem > 0.0 {
+            // Round before ceil so float noise does not add a whole step
+            let steps = ((height / self.rhythm_rem) * 1000.0).round() / 1000.0;
+            steps.ceil() * self.rhythm_rem
+        } else {
+            height
+        }
+    }
+
+    /// Every step, smallest first
+    pub fn text_steps(&self) -> [TextStep; 7] {
+        TextSize::ALL.map(|size| TextStep::new(self.size(size), self.line_height(size)))
+    }
+
+    /// The default design tokens typography with this scale's steps
+    pub fn typography(&self) -> TypographyScale {
+        TypographyScale {
+            font_size_base: self.base_rem * REM_PX,
+            scale_ratio: self.ratio,
+            ..TypographyScale::default()
+        }
+        .text_steps(self.text_steps())
+    }
+
+    /// Custom properties for every step, as a rule for `selector`
+    pub fn css(&self, selector: &str) -> String {
+        self.typography().text_css(selector)
+    }
+}
+
+/// Root font size used to convert viewport widths to rem
+const REM_PX: f32 = 16.0;
+
+/// A font size that grows linearly with the viewport between two widths,
+/// as `clamp(min, rem + vw, max)`, without media queries
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FluidSize {
+    /// Size at `min_viewport_px` and below, in rem
+    pub min_rem: f32,
+    /// Size at `max_viewport_px` and above, in rem
+    pub max_rem: f32,
+    pub min_viewport_px: f32,
+    pub max_viewport_px: f32,
+    /// Unitless line height, so it follows the size
+    pub leading: f32,
+}
+
+impl FluidSize {
+    /// Viewport range used unless [`FluidSize::viewport`] is set
+    pub const DEFAULT_VIEWPORT: (f32, f32) = (320.0, 1280.0);
+
+    pub fn new(min_rem: f32, max_rem: f32) -> Self {
+        Self {
+            min_rem,
+            max_rem,
+            min_viewport_px: Self::DEFAULT_VIEWPORT.0,
+            max_viewport_px: Self::DEFAULT_VIEWPORT.1,
+            leading: 1.25,
+        }
+    }
+
+    /// `size` on `small` at narrow viewports, on `large` at wide ones
+    pub fn between(size: TextSize, small: &TypeScale, large: &TypeScale) -> Self {
+        let leading = if size.step() > 0 {
+            large.heading_leading
+        } else {
+            large.body_leading
+        };
+        Self::new(small.size(size), large.size(size)).leading(leading)
+    }
+
+    /// Viewport widths, in px, where the size stops growing
+    pub fn viewport(mut self, min_px: f32, max_px: f32) -> Self {
+        self.min_viewport_px = min_px;
+        self.max_viewport_px = max_px;
+        self
+    }
+
+    pub fn leading(mut self, leading: f32) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// The `clamp()` expression
+    pub fn css_value(&self) -> String {
+        let lower = self.min_rem.min(self.max_rem);
+        let upper = self.min_rem.max(self.max_rem);
+        let range_px = self.max_viewport_px - self.min_viewport_px;
+        if range_px <= 0.0 || lower == upper {
+            return format!("{}rem", round3(self.max_rem));
+        }
+
+        // rem gained per px of viewport; 1vw is a hundredth of the width
+        let slope = (self.max_rem - self.min_rem) / range_px;
+        let intercept = self.min_rem - slope * self.min_viewport_px;
+        let vw = slope * REM_PX * 100.0;
+        let (sign, vw) = if vw < 0.0 { ('-', -vw) } else { ('+', vw) };
+        format!(
+            "clamp({}rem, {}rem {} {}vw, {}rem)",
+            round3(lower),
+            round3(intercept),
+            sign,
+            round3(vw),
+            round3(upper)
+        )
+    }
+}
+
+impl TypeScale {
+    /// Custom properties that move every step from this scale at
+    /// `min_px` to `large` at `max_px`
+    pub fn fluid_css(&self, large: &TypeScale, min_px: f32, max_px: f32, selector: &str) -> String {
+        let mut css = format!("{} {{\n", selector);
+        for size in TextSize::ALL {
+            let fluid = FluidSize::between(size, self, large).viewport(min_px, max_px);
+            css.push_str(&format!(
+                "  --sh-font-size-{}: {};\n  --sh-line-height-{}: {};\n",
+                size.name(),
+                fluid.css_value(),
+                size.name(),
+                fluid.leading
+            ));
+        }
+        css.push_str("  --sh-font-size-base: var(--sh-font-size-md);\n}\n");
+        css
+    }
+}
+
+fn round3(value: f32) -> f32 {
+    (value * 1000.0).round() / 1000.0
+}
+
+/// Escape a value for a double-quoted CSS string
+fn css_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_face_css() {
+        let css = FontFace::new("Inter")
+            .source(FontSource::local("Inter"))
+            .source(FontSource::woff2("/fonts/inter.woff2"))
+            .weight("400")
+            .style("normal")
+            .display(FontDisplay::Optional)
+            .unicode_range("U+0000-00FF")
+            .css();
+        assert_eq!(
+            css,
+            "@font-face {\n  font-family: \"Inter\";\n  src: local(\"Inter\"), url(\"/fonts/inter.woff2\") format(\"woff2\");\n  font-weight: 400;\n  font-style: normal;\n  font-display: optional;\n  unicode-range: U+0000-00FF;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_variable_font_axes() {
+        let css = FontFace::new("Roboto Flex")
+            .source(FontSource::woff2("/fonts/flex.woff2"))
+            .axis(FontAxis::weight(100.0, 1000.0))
+            .axis(FontAxis::width(25.0, 151.0))
+            .axis(FontAxis::slant(-10.0, 0.0))
+            .axis(FontAxis::new("GRAD", -200.0, 150.0))
+            .css();
+        assert!(css.contains("format(\"woff2\") tech(variations)"));
+        assert!(css.contains("font-weight: 100 1000;"));
+        assert!(css.contains("font-stretch: 25% 151%;"));
+        assert!(css.contains("font-style: oblique -10deg 0deg;"));
+        assert!(css.contains("font-display: swap;"));
+        assert_eq!(
+            font_variation_settings(&[("wght", 650.0), ("GRAD", 50.0)]),
+            "\"wght\" 650, \"GRAD\" 50"
+        );
+    }
+
+    #[test]
+    fn test_preload_tags() {
+        let fonts = FontSet::new()
+            .face(
+                FontFace::new("Inter")
+                    .source(FontSource::local("Inter"))
+                    .source(FontSource::woff2("/fonts/inter.woff2"))
+                    .preload(true),
+            )
+            .face(FontFace::new("Mono").source(FontSource::woff("/fonts/mono.woff")))
+            .face(
+                FontFace::new("System")
+                    .source(FontSource::local("Arial"))
+                    .preload(true),
+            );
+        let html = fonts.preload_tags().into_string();
+        assert_eq!(
+            html,
+            r#"<link rel="preload" href="/fonts/inter.woff2" as="font" type="font/woff2" crossorigin>"#
+        );
+        assert_eq!(fonts.css().matches("@font-face").count(), 3);
+    }
+
+    #[test]
+    fn test_type_scale() {
+        let scale = TypeScale::new(1.0, TypeScale::MAJOR_THIRD);
+        assert_eq!(scale.size(TextSize::Md), 1.0);
+        assert_eq!(scale.size(TextSize::Lg), 1.25);
+        assert_eq!(scale.size(TextSize::Sm), 0.8);
+        assert_eq!(scale.line_height(TextSize::Md), 1.5);
+        // 1.5625 * 1.25 = 1.953 rounds up to the 0.25rem grid
+        assert_eq!(scale.line_height(TextSize::Xl), 2.0);
+        assert_eq!(scale.rhythm(0.0).line_height(TextSize::Lg), 1.5625);
+
+        let css = scale.css(":root");
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("  --sh-font-size-lg: 1.25rem;\n"));
+        assert!(css.contains("  --sh-line-height-md: 1.5rem;\n"));
+        assert!(css.contains("  --sh-font-size-2xl: 1.953rem;\n"));
+        assert!(css.contains("  --sh-font-size-3xl: 2.441rem;\n"));
+        assert!(css.contains("--sh-font-size-base: var(--sh-font-size-md);"));
+        assert_eq!(scale.typography().text_steps[3].size_rem, 1.25);
+    }
+
+    #[test]
+    fn test_fluid_size() {
+        // 24px at 320px wide to 48px at 1280px: 16px + 2.5vw
+        let fluid = FluidSize::new(1.5, 3.0);
+        assert_eq!(fluid.css_value(), "clamp(1.5rem, 1rem + 2.5vw, 3rem)");
+        assert_eq!(
+            FluidSize::new(2.0, 1.0).viewport(400.0, 800.0).css_value(),
+            "clamp(1rem, 3rem - 4vw, 2rem)"
+        );
+        assert_eq!(FluidSize::new(1.0, 1.0).css_value(), "1rem");
+
+        let small = TypeScale::new(1.0, TypeScale::MAJOR_SECOND);
+        let large = TypeScale::new(1.0, TypeScale::PERFECT_FOURTH);
+        let h1 = FluidSize::between(TextSize::Xxxl, &small, &large);
+        assert_eq!(h1.min_rem, small.size(TextSize::Xxxl));
+        assert_eq!(h1.max_rem, large.size(TextSize::Xxxl));
+        assert_eq!(h1.leading, 1.25);
+
+        let css = small.fluid_css(&large, 320.0, 1280.0, ":root");
+        assert!(css.contains("  --sh-font-size-md: 1rem;\n"));
+        assert!(css.contains("  --sh-font-size-3xl: clamp(1.602rem, "));
+        assert!(css.contains("  --sh-line-height-md: 1.5;\n"));
+    }
+}
@@ -6,7 +6,13 @@
 //! - Field-level and form-level validation
 //! - Error message formatting
 
+use crate::form_async::{AsyncValidator, TimeoutPolicy, ValidationCtx};
+use crate::form_upload::{FileValidation, UploadedFile};
+use crate::input::InputType;
+use shallot_foundation::CalendarDate;
 use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
 
 /// A validation error
 #[derive(Debug, Clone, PartialEq)]
@@ -38,6 +44,96 @@ pub type ValidationResult = Result<(), Vec<ValidationError>>;
 pub trait Validator: Send + Sync {
     /// Validate a value and return an error if invalid
     fn validate(&self, value: &str, field_name: &str) -> Option<ValidationError>;
+
+    /// Add the HTML constraint attributes that check the same rule in the
+    /// browser; rules with no HTML equivalent add nothing
+    fn constrain(&self, _constraints: &mut HtmlConstraints) {}
+}
+
+/// HTML constraint validation attributes matching a field's validators
+///
+/// Built by [`FieldValidation::constraints`] and applied with
+/// [`crate::Input::constraints`], [`crate::Textarea::constraints`] or
+/// [`crate::Select::constraints`], so the browser refuses what the server
+/// would before the form is sent. When several rules touch one attribute
+/// the strictest wins. The server still validates every submission.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HtmlConstraints {
+    pub required: bool,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    /// Pattern the whole value must match, as the `pattern` attribute reads it
+    pub pattern: Option<String>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub step: Option<&'static str>,
+    /// Input type the rules imply, e.g. [`InputType::Email`]
+    pub input_type: Option<InputType>,
+}
+
+impl HtmlConstraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn require(&mut self) {
+        self.required = true;
+    }
+
+    pub fn min_length(&mut self, min: usize) {
+        self.min_length = Some(self.min_length.map_or(min, |m| m.max(min)));
+    }
+
+    pub fn max_length(&mut self, max: usize) {
+        self.max_length = Some(self.max_length.map_or(max, |m| m.min(max)));
+    }
+
+    /// Require the whole value to match `pattern`; a second pattern is
+    /// combined with the first so both must match
+    pub fn pattern(&mut self, pattern: &str) {
+        self.pattern = Some(match self.pattern.take() {
+            Some(existing) => format!("(?=(?:{})$)(?:{})", existing, pattern),
+            None => pattern.to_string(),
+        });
+    }
+
+    /// Require a match anywhere in the value, as [`regex::Regex::is_match`]
+    /// does, unless `pattern` is already anchored at both ends
+    pub fn search_pattern(&mut self, pattern: &str) {
+        let anchored =
+            pattern.starts_with('^') && pattern.ends_with('$') && !pattern.ends_with("\\$");
+        if anchored {
+            self.pattern(pattern);
+        } else {
+            self.pattern(&format!(".*(?:{}).*", pattern));
+        }
+    }
+
+    pub fn min(&mut self, min: f64) {
+        let current = self.min.as_deref().and_then(|m| m.parse::<f64>().ok());
+        if current.is_none_or(|m| min > m) {
+            self.min = Some(min.to_string());
+        }
+    }
+
+    pub fn max(&mut self, max: f64) {
+        let current = self.max.as_deref().and_then(|m| m.parse::<f64>().ok());
+        if current.is_none_or(|m| max < m) {
+            self.max = Some(max.to_string());
+        }
+    }
+
+    /// Set the `step`, unless a whole-number step is already set
+    pub fn step(&mut self, step: &'static str) {
+        if self.step.is_none() || step == "1" {
+            self.step = Some(step);
+        }
+    }
+
+    /// Set the input type, unless an earlier rule set one
+    pub fn input_type(&mut self, input_type: InputType) {
+        self.input_type.get_or_insert(input_type);
+    }
 }
 
 /// Required field validator
@@ -73,6 +169,10 @@ impl Validator for RequiredValidator {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.require();
+    }
 }
 
 /// Minimum length validator
@@ -108,6 +208,14 @@ impl Validator for MinLengthValidator {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        // `minlength` skips empty values; this validator does not
+        if self.min > 0 {
+            constraints.require();
+        }
+        constraints.min_length(self.min);
+    }
 }
 
 /// Maximum length validator
@@ -143,6 +251,10 @@ impl Validator for MaxLengthValidator {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.max_length(self.max);
+    }
 }
 
 /// Pattern validator (regex)
@@ -175,6 +287,14 @@ impl Validator for PatternValidator {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        // `pattern` skips empty values; this validator does not
+        if !self.pattern.is_match("") {
+            constraints.require();
+        }
+        constraints.search_pattern(self.pattern.as_str());
+    }
 }
 
 /// Email validator
@@ -209,16 +329,23 @@ impl Validator for EmailValidator {
             return None;
         }
         // Simple email validation regex
-        let email_regex =
-            regex::Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap();
+        let email_regex = regex::Regex::new(EMAIL_PATTERN).unwrap();
         if !email_regex.is_match(value) {
             Some(ValidationError::new(field_name, &self.message, "email"))
         } else {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.input_type(InputType::Email);
+        constraints.search_pattern(EMAIL_PATTERN);
+    }
 }
 
+const EMAIL_PATTERN: &str = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$";
+const URL_PATTERN: &str = r"^https?://.+";
+
 /// URL validator
 #[derive(Debug, Clone)]
 pub struct UrlValidator {
@@ -246,13 +373,20 @@ impl Default for UrlValidator {
 
 impl Validator for UrlValidator {
     fn validate(&self, value: &str, field_name: &str) -> Option<ValidationError> {
-        let url_regex = regex::Regex::new(r"^https?://.+").unwrap();
+        let url_regex = regex::Regex::new(URL_PATTERN).unwrap();
         if !url_regex.is_match(value) {
             Some(ValidationError::new(field_name, &self.message, "url"))
         } else {
             None
         }
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        // Empty values fail the URL check
+        constraints.require();
+        constraints.input_type(InputType::Url);
+        constraints.search_pattern(URL_PATTERN);
+    }
 }
 
 /// Numeric range validator
@@ -306,6 +440,17 @@ impl Validator for RangeValidator<i32> {
 
         None
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.input_type(InputType::Number);
+        constraints.step("1");
+        if let Some(min) = self.min {
+            constraints.min(min.into());
+        }
+        if let Some(max) = self.max {
+            constraints.max(max.into());
+        }
+    }
 }
 
 impl Validator for RangeValidator<f64> {
@@ -326,12 +471,308 @@ impl Validator for RangeValidator<f64> {
 
         None
     }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.input_type(InputType::Number);
+        constraints.step("any");
+        if let Some(min) = self.min {
+            constraints.min(min);
+        }
+        if let Some(max) = self.max {
+            constraints.max(max);
+        }
+    }
+}
+
+/// A single requirement in a [`PasswordPolicy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordRule {
+    MinLength(usize),
+    MaxLength(usize),
+    Lowercase,
+    Uppercase,
+    Digit,
+    Symbol,
+}
+
+impl PasswordRule {
+    /// Checklist label, e.g. "At least 8 characters"
+    pub fn label(&self) -> String {
+        match self {
+            PasswordRule::MinLength(n) => format!("At least {} characters", n),
+            PasswordRule::MaxLength(n) => format!("At most {} characters", n),
+            PasswordRule::Lowercase => "A lowercase letter".to_string(),
+            PasswordRule::Uppercase => "An uppercase letter".to_string(),
+            PasswordRule::Digit => "A number".to_string(),
+            PasswordRule::Symbol => "A symbol".to_string(),
+        }
+    }
+
+    /// Error message shown when the rule is not met
+    pub fn message(&self) -> String {
+        match self {
+            PasswordRule::MinLength(n) => format!("Must be at least {} characters", n),
+            PasswordRule::MaxLength(n) => format!("Must be at most {} characters", n),
+            PasswordRule::Lowercase => "Must include a lowercase letter".to_string(),
+            PasswordRule::Uppercase => "Must include an uppercase letter".to_string(),
+            PasswordRule::Digit => "Must include a number".to_string(),
+            PasswordRule::Symbol => "Must include a symbol".to_string(),
+        }
+    }
+
+    /// Error code, matching the other validators
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordRule::MinLength(_) => "min_length",
+            PasswordRule::MaxLength(_) => "max_length",
+            PasswordRule::Lowercase => "password_lowercase",
+            PasswordRule::Uppercase => "password_uppercase",
+            PasswordRule::Digit => "password_digit",
+            PasswordRule::Symbol => "password_symbol",
+        }
+    }
+
+    /// Whether `password` satisfies this rule
+    pub fn is_met(&self, password: &str) -> bool {
+        match self {
+            PasswordRule::MinLength(n) => password.chars().count() >= *n,
+            PasswordRule::MaxLength(n) => password.chars().count() <= *n,
+            PasswordRule::Lowercase => password.chars().any(char::is_lowercase),
+            PasswordRule::Uppercase => password.chars().any(char::is_uppercase),
+            PasswordRule::Digit => password.chars().any(|c| c.is_ascii_digit()),
+            PasswordRule::Symbol => password.chars().any(is_symbol),
+        }
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
+
+/// Rough strength of a password, from its length and character variety
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Score a password. Anything under 8 characters is weak; beyond that
+    /// each character class and every length step past 12 adds a point.
+    pub fn of(password: &str) -> Self {
+        let length = password.chars().count();
+        if length < 8 {
+            return PasswordStrength::Weak;
+        }
+        let classes = [
+            PasswordRule::Lowercase,
+            PasswordRule::Uppercase,
+            PasswordRule::Digit,
+            PasswordRule::Symbol,
+        ]
+        .iter()
+        .filter(|rule| rule.is_met(password))
+        .count();
+        let score = classes + usize::from(length >= 12) + usize::from(length >= 16);
+        match score {
+            0..=2 => PasswordStrength::Weak,
+            3 => PasswordStrength::Fair,
+            4 => PasswordStrength::Good,
+            _ => PasswordStrength::Strong,
+        }
+    }
+
+    /// Lowercase name, used for CSS modifiers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordStrength::Weak => "weak",
+            PasswordStrength::Fair => "fair",
+            PasswordStrength::Good => "good",
+            PasswordStrength::Strong => "strong",
+        }
+    }
+}
+
+/// Outcome of checking a password against a [`PasswordPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordReport {
+    /// Every rule of the policy, with whether it was met
+    pub rules: Vec<(PasswordRule, bool)>,
+    pub strength: PasswordStrength,
+}
+
+impl PasswordReport {
+    /// Whether every rule was met
+    pub fn is_valid(&self) -> bool {
+        self.rules.iter().all(|(_, met)| *met)
+    }
+
+    /// Rules that were not met
+    pub fn unmet(&self) -> Vec<PasswordRule> {
+        self.rules
+            .iter()
+            .filter(|(_, met)| !met)
+            .map(|(rule, _)| *rule)
+            .collect()
+    }
+}
+
+/// Password requirements, checked on the server and mirrored into the
+/// `minlength`/`maxlength`/`pattern` attributes of a `PasswordInput`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: Option<usize>,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digit: bool,
+    pub symbol: bool,
+}
+
+impl PasswordPolicy {
+    /// Create a policy requiring only 8 characters
+    pub fn new() -> Self {
+        Self {
+            min_length: 8,
+            max_length: None,
+            lowercase: false,
+            uppercase: false,
+            digit: false,
+            symbol: false,
+        }
+    }
+
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    pub fn require_lowercase(mut self) -> Self {
+        self.lowercase = true;
+        self
+    }
+
+    pub fn require_uppercase(mut self) -> Self {
+        self.uppercase = true;
+        self
+    }
+
+    pub fn require_digit(mut self) -> Self {
+        self.digit = true;
+        self
+    }
+
+    pub fn require_symbol(mut self) -> Self {
+        self.symbol = true;
+        self
+    }
+
+    /// The rules of this policy, in checklist order
+    pub fn rules(&self) -> Vec<PasswordRule> {
+        let mut rules = vec![PasswordRule::MinLength(self.min_length)];
+        if let Some(max) = self.max_length {
+            rules.push(PasswordRule::MaxLength(max));
+        }
+        for (enabled, rule) in [
+            (self.lowercase, PasswordRule::Lowercase),
+            (self.uppercase, PasswordRule::Uppercase),
+            (self.digit, PasswordRule::Digit),
+            (self.symbol, PasswordRule::Symbol),
+        ] {
+            if enabled {
+                rules.push(rule);
+            }
+        }
+        rules
+    }
+
+    /// Check `password` against every rule
+    pub fn check(&self, password: &str) -> PasswordReport {
+        PasswordReport {
+            rules: self
+                .rules()
+                .into_iter()
+                .map(|rule| (rule, rule.is_met(password)))
+                .collect(),
+            strength: PasswordStrength::of(password),
+        }
+    }
+
+    /// HTML `pattern` attribute enforcing the character-class rules, or
+    /// `None` when only length is required (`minlength` covers that)
+    pub fn pattern(&self) -> Option<String> {
+        let lookaheads: String = [
+            (self.lowercase, r"(?=.*\p{Ll})"),
+            (self.uppercase, r"(?=.*\p{Lu})"),
+            (self.digit, r"(?=.*\d)"),
+            (self.symbol, r"(?=.*[^\p{L}\p{N}\s])"),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, lookahead)| *lookahead)
+        .collect();
+        if lookaheads.is_empty() {
+            return None;
+        }
+        let max = self.max_length.map(|m| m.to_string()).unwrap_or_default();
+        Some(format!("{}.{{{},{}}}", lookaheads, self.min_length, max))
+    }
+
+    /// One-line summary for the native validation tooltip
+    pub fn summary(&self) -> String {
+        let labels: Vec<String> = self
+            .rules()
+            .iter()
+            .map(|rule| rule.label().to_lowercase())
+            .collect();
+        format!("Password needs {}", labels.join(", "))
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for PasswordPolicy {
+    fn validate(&self, value: &str, field_name: &str) -> Option<ValidationError> {
+        // Leave empty values to the required validator
+        if value.is_empty() {
+            return None;
+        }
+        self.rules()
+            .into_iter()
+            .find(|rule| !rule.is_met(value))
+            .map(|rule| ValidationError::new(field_name, rule.message(), rule.code()))
+    }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.min_length(self.min_length);
+        if let Some(max) = self.max_length {
+            constraints.max_length(max);
+        }
+        if let Some(pattern) = self.pattern() {
+            constraints.pattern(&pattern);
+        }
+    }
 }
 
 /// Field validation configuration
 pub struct FieldValidation {
     pub field_name: String,
     pub validators: Vec<Box<dyn Validator>>,
+    /// Checks needing I/O, run by [`FormSchema::validate_async`]
+    pub async_validators: Vec<Box<dyn AsyncValidator>>,
+    /// Only validate when this holds, see [`FieldValidation::when`]
+    pub condition: Option<Condition>,
 }
 
 impl Clone for FieldValidation {
@@ -340,6 +781,8 @@ impl Clone for FieldValidation {
         Self {
             field_name: self.field_name.clone(),
             validators: Vec::new(),
+            async_validators: Vec::new(),
+            condition: self.condition.clone(),
         }
     }
 }
@@ -352,6 +795,11 @@ impl std::fmt::Debug for FieldValidation {
                 "validators",
                 &format!("[{} validators]", self.validators.len()),
             )
+            .field(
+                "async_validators",
+                &format!("[{} validators]", self.async_validators.len()),
+            )
+            .field("condition", &self.condition)
             .finish()
     }
 }
@@ -362,6 +810,8 @@ impl FieldValidation {
         Self {
             field_name: field_name.into(),
             validators: Vec::new(),
+            async_validators: Vec::new(),
+            condition: None,
         }
     }
 
@@ -371,6 +821,20 @@ impl FieldValidation {
         self
     }
 
+    /// Add a validator that needs I/O, e.g. a uniqueness check. It runs
+    /// once the sync validators pass.
+    pub fn add_async_validator<V: AsyncValidator + 'static>(mut self, validator: V) -> Self {
+        self.async_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Only apply the validators when `condition` holds for the submitted
+    /// form, e.g. require `other_reason` when `reason` is `other`
+    pub fn when(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
     /// Validate a value
     pub fn validate(&self, value: &str) -> Vec<ValidationError> {
         self.validators
@@ -378,53 +842,376 @@ impl FieldValidation {
             .filter_map(|v| v.validate(value, &self.field_name))
             .collect()
     }
+
+    /// Whether the validators apply to this submission
+    pub fn applies(&self, data: &HashMap<String, String>) -> bool {
+        self.condition.as_ref().is_none_or(|c| c.is_met(data))
+    }
+
+    /// HTML constraint attributes checking the same rules in the browser
+    pub fn constraints(&self) -> HtmlConstraints {
+        let mut constraints = HtmlConstraints::new();
+        for validator in &self.validators {
+            validator.constrain(&mut constraints);
+        }
+        // The browser cannot see the condition, so it must not insist
+        if self.condition.is_some() {
+            constraints.required = false;
+        }
+        constraints
+    }
+}
+
+/// A test on another field's submitted value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// The field has exactly this value
+    Equals(String, String),
+    /// The field has one of these values
+    OneOf(String, Vec<String>),
+    /// The field is not blank, e.g. a checked checkbox
+    Filled(String),
+}
+
+impl Condition {
+    pub fn equals(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Condition::Equals(field.into(), value.into())
+    }
+
+    pub fn one_of<I, V>(field: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        Condition::OneOf(field.into(), values.into_iter().map(Into::into).collect())
+    }
+
+    pub fn filled(field: impl Into<String>) -> Self {
+        Condition::Filled(field.into())
+    }
+
+    /// Whether the condition holds for the submitted values
+    pub fn is_met(&self, data: &HashMap<String, String>) -> bool {
+        let value = |field: &str| data.get(field).map(|s| s.as_str()).unwrap_or("");
+        match self {
+            Condition::Equals(field, expected) => value(field) == expected,
+            Condition::OneOf(field, values) => values.iter().any(|v| v == value(field)),
+            Condition::Filled(field) => !value(field).trim().is_empty(),
+        }
+    }
+}
+
+/// How [`FormRule::compare`] orders two values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    NotEqual,
+}
+
+impl Comparison {
+    fn holds(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            Comparison::Less => ordering == Less,
+            Comparison::LessOrEqual => ordering != Greater,
+            Comparison::Greater => ordering == Greater,
+            Comparison::GreaterOrEqual => ordering != Less,
+            Comparison::NotEqual => ordering != Equal,
+        }
+    }
+
+    fn phrase(&self) -> &'static str {
+        match self {
+            Comparison::Less => "less than",
+            Comparison::LessOrEqual => "at most",
+            Comparison::Greater => "greater than",
+            Comparison::GreaterOrEqual => "at least",
+            Comparison::NotEqual => "different from",
+        }
+    }
+}
+
+/// A rule across several fields, checked by [`FormSchema::validate`] after
+/// the single-field validators; its error is reported on `field`
+#[derive(Debug, Clone)]
+pub enum FormRule {
+    /// `field` must equal `other`, e.g. a password confirmation
+    Matches {
+        field: String,
+        other: String,
+        message: String,
+    },
+    /// `field` must relate to `other` as `comparison` says. Numbers compare
+    /// numerically and anything else as text, which orders ISO 8601 dates
+    /// and times correctly. Skipped while either field is empty.
+    Compare {
+        field: String,
+        comparison: Comparison,
+        other: String,
+        message: String,
+    },
+    /// Any check over the submitted values, returning a message on failure
+    Custom {
+        field: String,
+        check: fn(&HashMap<String, String>) -> Option<String>,
+    },
+}
+
+impl FormRule {
+    pub fn matches(field: impl Into<String>, other: impl Into<String>) -> Self {
+        FormRule::Matches {
+            field: field.into(),
+            other: other.into(),
+            message: "Values do not match".to_string(),
+        }
+    }
+
+    pub fn compare(
+        field: impl Into<String>,
+        comparison: Comparison,
+        other: impl Into<String>,
+    ) -> Self {
+        let other = other.into();
+        FormRule::Compare {
+            field: field.into(),
+            comparison,
+            message: format!("Must be {} {}", comparison.phrase(), other),
+            other,
+        }
+    }
+
+    pub fn custom(
+        field: impl Into<String>,
+        check: fn(&HashMap<String, String>) -> Option<String>,
+    ) -> Self {
+        FormRule::Custom {
+            field: field.into(),
+            check,
+        }
+    }
+
+    /// Replace the error message; custom rules bring their own
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        match &mut self {
+            FormRule::Matches { message: m, .. } | FormRule::Compare { message: m, .. } => {
+                *m = message.into();
+            }
+            FormRule::Custom { .. } => {}
+        }
+        self
+    }
+
+    /// Field the error is reported on
+    pub fn field(&self) -> &str {
+        match self {
+            FormRule::Matches { field, .. }
+            | FormRule::Compare { field, .. }
+            | FormRule::Custom { field, .. } => field,
+        }
+    }
+
+    /// Check the rule against the submitted values
+    pub fn validate(&self, data: &HashMap<String, String>) -> Option<ValidationError> {
+        let value = |field: &str| data.get(field).map(|s| s.as_str()).unwrap_or("");
+        match self {
+            FormRule::Matches {
+                field,
+                other,
+                message,
+            } => (value(field) != value(other))
+                .then(|| ValidationError::new(field, message, "matches")),
+            FormRule::Compare {
+                field,
+                comparison,
+                other,
+                message,
+            } => {
+                let (a, b) = (value(field).trim(), value(other).trim());
+                if a.is_empty() || b.is_empty() {
+                    return None;
+                }
+                let ordering = match (a.parse::<f64>(), b.parse::<f64>()) {
+                    (Ok(a), Ok(b)) => a.partial_cmp(&b)?,
+                    _ => a.cmp(b),
+                };
+                (!comparison.holds(ordering))
+                    .then(|| ValidationError::new(field, message, "compare"))
+            }
+            FormRule::Custom { field, check } => {
+                check(data).map(|message| ValidationError::new(field, message, "custom"))
+            }
+        }
+    }
+}
+
+/// Validation errors grouped by field, in the order the schema declares
+/// them; form-wide errors (an empty field name) come first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormErrors {
+    groups: Vec<(String, Vec<ValidationError>)>,
+}
+
+impl FormErrors {
+    /// Group `errors` by field, keeping the order fields first appear in
+    pub fn new(errors: Vec<ValidationError>) -> Self {
+        let mut groups: Vec<(String, Vec<ValidationError>)> = Vec::new();
+        for error in errors {
+            match groups.iter_mut().find(|(field, _)| *field == error.field) {
+                Some((_, group)) => group.push(error),
+                None => groups.push((error.field.clone(), vec![error])),
+            }
+        }
+        groups.sort_by_key(|(field, _)| !field.is_empty());
+        Self { groups }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Total number of errors
+    pub fn len(&self) -> usize {
+        self.groups.iter().map(|(_, errors)| errors.len()).sum()
+    }
+
+    /// Errors for one field
+    pub fn get(&self, field: &str) -> &[ValidationError] {
+        self.groups
+            .iter()
+            .find(|(f, _)| f == field)
+            .map_or(&[], |(_, errors)| errors.as_slice())
+    }
+
+    /// Messages for one field
+    pub fn messages(&self, field: &str) -> Vec<&str> {
+        self.get(field).iter().map(|e| e.message.as_str()).collect()
+    }
+
+    /// Fields with errors, in order
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.groups.iter().map(|(field, _)| field.as_str())
+    }
+
+    /// `(field, errors)` groups, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[ValidationError])> {
+        self.groups
+            .iter()
+            .map(|(field, errors)| (field.as_str(), errors.as_slice()))
+    }
+
+    /// All errors, one group after another
+    pub fn into_errors(self) -> Vec<ValidationError> {
+        self.groups
+            .into_iter()
+            .flat_map(|(_, errors)| errors)
+            .collect()
+    }
 }
 
 /// Form validation schema
 #[derive(Debug, Clone, Default)]
 pub struct FormSchema {
-    fields: HashMap<String, FieldValidation>,
+    pub(crate) fields: Vec<FieldValidation>,
+    pub(crate) rules: Vec<FormRule>,
+    pub(crate) files: Vec<FileValidation>,
+    /// Per-check limit for async validators, see [`FormSchema::async_timeout`]
+    pub(crate) async_timeout: Option<Duration>,
+    pub(crate) timeout_policy: TimeoutPolicy,
 }
 
 impl FormSchema {
     /// Create a new form schema
     pub fn new() -> Self {
         Self {
-            fields: HashMap::new(),
+            fields: Vec::new(),
+            rules: Vec::new(),
+            files: Vec::new(),
+            async_timeout: None,
+            timeout_policy: TimeoutPolicy::default(),
         }
     }
 
-    /// Add a field validation
+    /// Add a field validation, replacing any earlier one for the same field
     pub fn field(mut self, validation: FieldValidation) -> Self {
-        self.fields
-            .insert(validation.field_name.clone(), validation);
+        match self
+            .fields
+            .iter_mut()
+            .find(|f| f.field_name == validation.field_name)
+        {
+            Some(existing) => *existing = validation,
+            None => self.fields.push(validation),
+        }
+        self
+    }
+
+    /// Add a rule across several fields
+    pub fn rule(mut self, rule: FormRule) -> Self {
+        self.rules.push(rule);
         self
     }
 
     /// Validate all fields in a form data map
     pub fn validate(&self, data: &HashMap<String, String>) -> ValidationResult {
+        let errors = self.errors(data);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate all fields, grouping the errors by field
+    pub fn validate_grouped(&self, data: &HashMap<String, String>) -> Result<(), FormErrors> {
+        self.validate(data).map_err(FormErrors::new)
+    }
+
+    /// Field errors in declaration order, then errors from form rules.
+    /// Async validators contribute their sync fallback.
+    pub(crate) fn errors(&self, data: &HashMap<String, String>) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
-        for (field_name, field_validation) in &self.fields {
-            let value = data.get(field_name).map(|s| s.as_str()).unwrap_or("");
+        for field_validation in self.fields.iter().filter(|f| f.applies(data)) {
+            let value = data
+                .get(&field_validation.field_name)
+                .map(|s| s.as_str())
+                .unwrap_or("");
             let field_errors = field_validation.validate(value);
+            if field_errors.is_empty() {
+                let ctx = ValidationCtx::new(&field_validation.field_name, data);
+                errors.extend(
+                    field_validation
+                        .async_validators
+                        .iter()
+                        .filter_map(|v| v.validate_sync(value, &ctx)),
+                );
+            }
             errors.extend(field_errors);
         }
+        errors.extend(self.rules.iter().filter_map(|rule| rule.validate(data)));
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors)
-        }
+        errors
     }
 
-    /// Validate a single field
+    /// Validate a single field, ignoring its condition and form rules
     pub fn validate_field(&self, field_name: &str, value: &str) -> Vec<ValidationError> {
-        self.fields
-            .get(field_name)
+        self.get(field_name)
             .map(|f| f.validate(value))
             .unwrap_or_default()
     }
+
+    /// HTML constraint attributes for a field, empty for unknown fields
+    pub fn constraints(&self, field_name: &str) -> HtmlConstraints {
+        self.get(field_name)
+            .map(|f| f.constraints())
+            .unwrap_or_default()
+    }
+
+    fn get(&self, field_name: &str) -> Option<&FieldValidation> {
+        self.fields.iter().find(|f| f.field_name == field_name)
+    }
 }
 
 /// Helper functions for common validations
@@ -470,12 +1257,79 @@ pub mod validators {
     pub fn pattern(pattern: &str) -> Result<PatternValidator, regex::Error> {
         PatternValidator::new(pattern)
     }
+    /// Create a password policy validator
+    pub fn password() -> PasswordPolicy {
+        PasswordPolicy::new()
+    }
+
+    /// Create a one-time code validator for `length` digits
+    pub fn otp(length: usize) -> crate::otp_input::OtpValidator {
+        crate::otp_input::OtpValidator::new(length)
+    }
+}
+
+/// Order of day, month and year in dates typed by hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+impl DateOrder {
+    /// Order used in a BCP 47 locale, e.g. `en-US` or `ja`
+    pub fn for_locale(locale: &str) -> Self {
+        let locale = locale.to_ascii_lowercase().replace('_', "-");
+        let language = locale.split('-').next().unwrap_or("");
+        match locale.as_str() {
+            "en-us" | "en-ph" | "en-as" | "en-gu" | "en-pr" | "en-um" | "en-vi" => {
+                DateOrder::MonthDayYear
+            }
+            "en-ca" => DateOrder::YearMonthDay,
+            _ if matches!(language, "zh" | "ja" | "ko" | "hu" | "lt" | "mn" | "sv" | "fa") => {
+                DateOrder::YearMonthDay
+            }
+            _ => DateOrder::DayMonthYear,
+        }
+    }
+
+    /// How the order reads in an error message
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            DateOrder::DayMonthYear => "DD/MM/YYYY",
+            DateOrder::MonthDayYear => "MM/DD/YYYY",
+            DateOrder::YearMonthDay => "YYYY-MM-DD",
+        }
+    }
+}
+
+/// Read a date with `/`, `.` or `-` between four-digit year, month and day
+fn parse_date(value: &str, order: DateOrder) -> Option<CalendarDate> {
+    let parts: Vec<&str> = value.split(['/', '.', '-']).map(str::trim).collect();
+    let [a, b, c] = parts.as_slice() else {
+        return None;
+    };
+    let (year, month, day) = if a.len() == 4 {
+        (a, b, c)
+    } else {
+        match order {
+            DateOrder::DayMonthYear => (c, b, a),
+            DateOrder::MonthDayYear => (c, a, b),
+            DateOrder::YearMonthDay => return None,
+        }
+    };
+    if year.len() != 4 || month.len() > 2 || day.len() > 2 {
+        return None;
+    }
+    CalendarDate::try_new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
 }
 
 /// Form state management
 #[derive(Debug, Clone, Default)]
 pub struct FormState {
     pub values: HashMap<String, String>,
+    /// Every value of names submitted more than once, e.g. checkbox groups
+    pub all_values: HashMap<String, Vec<String>>,
     pub errors: HashMap<String, Vec<String>>,
     pub touched: HashMap<String, bool>,
     pub is_valid: bool,
@@ -494,11 +1348,112 @@ impl FormState {
         self.is_dirty = true;
     }
 
+    /// State holding a submission; repeated names keep all their values
+    pub fn from_data(data: &FormData) -> Self {
+        let mut state = Self::new();
+        for (name, value) in data.to_map() {
+            let all = data.get_all(&name);
+            if all.len() > 1 {
+                state
+                    .all_values
+                    .insert(name.clone(), all.iter().map(|v| v.to_string()).collect());
+            }
+            state.set_value(name, value);
+        }
+        state
+    }
+
     /// Get a field value
     pub fn get_value(&self, field: &str) -> Option<&String> {
         self.values.get(field)
     }
 
+    /// Set every value of a multi-value field; the first is its value
+    pub fn set_values(&mut self, field: impl Into<String>, values: Vec<String>) {
+        let field = field.into();
+        match values.first() {
+            Some(first) => self.set_value(field.clone(), first.clone()),
+            None => {
+                self.values.remove(&field);
+                self.is_dirty = true;
+            }
+        }
+        self.all_values.insert(field, values);
+    }
+
+    /// Every value of a field, in submission order
+    pub fn get_all(&self, field: &str) -> Vec<&str> {
+        match self.all_values.get(field) {
+            Some(values) => values.iter().map(|v| v.as_str()).collect(),
+            None => self.values.get(field).map(|v| v.as_str()).into_iter().collect(),
+        }
+    }
+
+    /// Parse a field with [`FromStr`]. A missing or blank field is
+    /// `Ok(None)`; leave requiring it to the schema.
+    pub fn get_parsed<T: FromStr>(&self, field: &str) -> Result<Option<T>, ValidationError> {
+        match self.filled(field) {
+            None => Ok(None),
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| ValidationError::new(field, "Enter a valid value", "parse")),
+        }
+    }
+
+    /// Parse every value of a multi-value field
+    pub fn get_parsed_all<T: FromStr>(&self, field: &str) -> Result<Vec<T>, ValidationError> {
+        self.get_all(field)
+            .into_iter()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| ValidationError::new(field, "Enter a valid value", "parse"))
+            })
+            .collect()
+    }
+
+    /// Read a checkbox: checked boxes submit `on` (or their `value`), and
+    /// unchecked ones submit nothing. `false`, `off`, `no` and `0` also read
+    /// as unchecked, for hidden fallback inputs.
+    pub fn get_bool(&self, field: &str) -> bool {
+        self.filled(field).is_some_and(|value| {
+            !["false", "off", "no", "0"]
+                .iter()
+                .any(|off| value.eq_ignore_ascii_case(off))
+        })
+    }
+
+    /// Parse a date typed in `locale`'s order, e.g. `31/12/2024` for
+    /// `en-GB` and `12/31/2024` for `en-US`. ISO `2024-12-31`, what
+    /// `<input type="date">` submits, is read in every locale.
+    pub fn get_date(
+        &self,
+        field: &str,
+        locale: &str,
+    ) -> Result<Option<CalendarDate>, ValidationError> {
+        let Some(value) = self.filled(field) else {
+            return Ok(None);
+        };
+        let order = DateOrder::for_locale(locale);
+        parse_date(value, order).map(Some).ok_or_else(|| {
+            ValidationError::new(
+                field,
+                format!("Enter a date as {}", order.pattern()),
+                "date",
+            )
+        })
+    }
+
+    /// Trimmed value, `None` when missing or blank
+    fn filled(&self, field: &str) -> Option<&str> {
+        self.values
+            .get(field)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+
     /// Mark a field as touched
     pub fn touch(&mut self, field: impl Into<String>) {
         self.touched.insert(field.into(), true);
@@ -526,35 +1481,121 @@ impl FormState {
         self.is_valid = true;
     }
 
-    /// Update form validity based on errors
-    fn update_validity(&mut self) {
-        self.is_valid = self.errors.values().all(|e| e.is_empty());
+    /// Update form validity based on errors
+    fn update_validity(&mut self) {
+        self.is_valid = self.errors.values().all(|e| e.is_empty());
+    }
+
+    /// Validate the form with a schema
+    pub fn validate(&mut self, schema: &FormSchema) -> bool {
+        self.errors.clear();
+
+        for error in schema.errors(&self.values) {
+            self.errors
+                .entry(error.field)
+                .or_default()
+                .push(error.message);
+        }
+
+        self.update_validity();
+        self.is_valid
+    }
+}
+
+/// Submitted form fields in the order they arrived; names may repeat
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormData {
+    pub fields: Vec<(String, String)>,
+    /// Files from a multipart body, see [`FormData::parse_multipart`]
+    pub files: Vec<UploadedFile>,
+}
+
+impl FormData {
+    /// Create empty form data
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect already-decoded pairs, e.g. from a framework's form extractor
+    pub fn from_pairs<K, V, I>(pairs: I) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Self {
+            fields: pairs
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Parse an `application/x-www-form-urlencoded` body
+    pub fn parse_urlencoded(body: &str) -> Self {
+        Self::from_pairs(body.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+            match pair.split_once('=') {
+                Some((name, value)) => (percent_decode(name), percent_decode(value)),
+                None => (percent_decode(pair), String::new()),
+            }
+        }))
+    }
+
+    /// Add a field
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.fields.push((name.into(), value.into()));
     }
 
-    /// Validate the form with a schema
-    pub fn validate(&mut self, schema: &FormSchema) -> bool {
-        self.errors.clear();
+    /// First value submitted under `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
 
-        for (field_name, field_validation) in &schema.fields {
-            let value = self
-                .values
-                .get(field_name)
-                .map(|s| s.as_str())
-                .unwrap_or("");
-            let errors: Vec<String> = field_validation
-                .validate(value)
-                .into_iter()
-                .map(|e| e.message)
-                .collect();
+    /// Every value submitted under `name`, e.g. for checkbox groups
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
 
-            if !errors.is_empty() {
-                self.errors.insert(field_name.clone(), errors);
-            }
+    /// The first value of each name, as [`FormSchema::validate`] takes them
+    pub fn to_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        for (name, value) in self.fields.iter().rev() {
+            map.insert(name.clone(), value.clone());
         }
+        map
+    }
+}
 
-        self.update_validity();
-        self.is_valid
+/// Decode `+` and `%XX` escapes; invalid escapes are kept as written
+fn percent_decode(input: &str) -> String {
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match (bytes[i], bytes.get(i + 1), bytes.get(i + 2)) {
+            (b'%', Some(&hi), Some(&lo)) => hex(hi).zip(hex(lo)).map(|(h, l)| (h << 4) | l),
+            _ => None,
+        };
+        match (escaped, bytes[i]) {
+            (Some(byte), _) => {
+                out.push(byte);
+                i += 2;
+            }
+            (None, b'+') => out.push(b' '),
+            (None, byte) => out.push(byte),
+        }
+        i += 1;
     }
+    String::from_utf8_lossy(&out).into_owned()
 }
 
 #[cfg(test)]
@@ -570,6 +1611,144 @@ mod tests {
         assert!(validator.validate("   ", "field").is_some());
     }
 
+    #[test]
+    fn test_field_validation_constraints() {
+        let constraints = FieldValidation::new("username")
+            .add_validator(required())
+            .add_validator(min_length(3))
+            .add_validator(max_length(20))
+            .add_validator(max_length(16))
+            .add_validator(pattern("[a-z]+").unwrap())
+            .constraints();
+        assert!(constraints.required);
+        assert_eq!(constraints.min_length, Some(3));
+        assert_eq!(constraints.max_length, Some(16));
+        assert_eq!(constraints.pattern.as_deref(), Some(".*(?:[a-z]+).*"));
+
+        let email = FieldValidation::new("email")
+            .add_validator(email())
+            .add_validator(pattern("^.+@example\\.com$").unwrap())
+            .constraints();
+        // The pattern rejects an empty value, which `pattern` alone allows
+        assert!(email.required);
+        assert_eq!(email.input_type, Some(InputType::Email));
+        assert_eq!(
+            email.pattern.as_deref(),
+            Some(r"(?=(?:^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$)$)(?:^.+@example\.com$)")
+        );
+
+        let age = FormSchema::new()
+            .field(
+                FieldValidation::new("age")
+                    .add_validator(range_i32().with_min(18).with_max(130))
+                    .add_validator(range_f64().with_min(0.0).with_max(99.5)),
+            )
+            .constraints("age");
+        assert_eq!(age.min.as_deref(), Some("18"));
+        assert_eq!(age.max.as_deref(), Some("99.5"));
+        assert_eq!(age.step, Some("1"));
+        assert_eq!(FormSchema::new().constraints("missing"), HtmlConstraints::new());
+    }
+
+    fn data(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_cross_field_rules() {
+        let schema = FormSchema::new()
+            .field(FieldValidation::new("password").add_validator(min_length(8)))
+            .rule(FormRule::matches("confirm", "password").with_message("Passwords differ"))
+            .rule(FormRule::compare("end", Comparison::Greater, "start"))
+            .rule(FormRule::compare("guests", Comparison::LessOrEqual, "seats"));
+
+        let ok = data(&[
+            ("password", "correct horse"),
+            ("confirm", "correct horse"),
+            ("start", "2024-03-01"),
+            ("end", "2024-03-09"),
+            ("guests", "9"),
+            ("seats", "10"),
+        ]);
+        assert!(schema.validate(&ok).is_ok());
+
+        let bad = data(&[
+            ("password", "correct horse"),
+            ("confirm", "correct hose"),
+            ("start", "2024-03-09"),
+            ("end", "2024-03-01"),
+            ("guests", "11"),
+            ("seats", "10"),
+        ]);
+        let errors = schema.validate(&bad).unwrap_err();
+        let codes: Vec<_> = errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect();
+        assert_eq!(
+            codes,
+            [("confirm", "matches"), ("end", "compare"), ("guests", "compare")]
+        );
+        assert_eq!(errors[0].message, "Passwords differ");
+        assert_eq!(errors[1].message, "Must be greater than start");
+
+        // Comparisons wait until both sides are filled
+        let partial = data(&[("password", "correct horse"), ("confirm", "correct horse")]);
+        assert!(schema.validate(&partial).is_ok());
+    }
+
+    #[test]
+    fn test_conditional_validation() {
+        let schema = FormSchema::new()
+            .field(FieldValidation::new("reason").add_validator(required()))
+            .field(
+                FieldValidation::new("other_reason")
+                    .when(Condition::equals("reason", "other"))
+                    .add_validator(required()),
+            )
+            .field(
+                FieldValidation::new("company")
+                    .when(Condition::filled("invoice"))
+                    .add_validator(required()),
+            );
+
+        assert!(schema.validate(&data(&[("reason", "price")])).is_ok());
+        let errors = schema.validate(&data(&[("reason", "other"), ("invoice", "on")])).unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["other_reason", "company"]);
+        assert!(!schema.constraints("other_reason").required);
+
+        let mut state = FormState::new();
+        state.set_value("reason", "other");
+        assert!(!state.validate(&schema));
+        assert!(state.get_errors("other_reason").is_some());
+        assert!(state.get_errors("company").is_none());
+    }
+
+    #[test]
+    fn test_grouped_errors() {
+        let schema = FormSchema::new()
+            .field(
+                FieldValidation::new("name")
+                    .add_validator(required())
+                    .add_validator(min_length(2)),
+            )
+            .field(FieldValidation::new("email").add_validator(email()))
+            .rule(FormRule::custom("", |data| {
+                data.get("email")
+                    .is_none_or(|e| e.is_empty())
+                    .then(|| "Give us a way to reach you".to_string())
+            }));
+
+        let errors = schema.validate_grouped(&data(&[])).unwrap_err();
+        assert_eq!(errors.fields().collect::<Vec<_>>(), ["", "name"]);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.messages(""), ["Give us a way to reach you"]);
+        assert_eq!(errors.get("name").len(), 2);
+        assert!(errors.get("email").is_empty());
+        assert_eq!(errors.into_errors()[0].code, "custom");
+    }
+
     #[test]
     fn test_min_length_validator() {
         let validator = MinLengthValidator::new(5);
@@ -632,6 +1811,76 @@ mod tests {
         assert!(schema.validate(&data).is_err());
     }
 
+    #[test]
+    fn test_password_policy() {
+        let policy = password()
+            .min_length(10)
+            .require_uppercase()
+            .require_digit()
+            .require_symbol();
+        assert!(policy.validate("", "pw").is_none());
+        assert!(policy.validate("Correct-horse-1", "pw").is_none());
+
+        let error = policy.validate("Short1!", "pw").unwrap();
+        assert_eq!(error.code, "min_length");
+        assert_eq!(error.message, "Must be at least 10 characters");
+        assert_eq!(
+            policy.validate("lowercase-only-1", "pw").unwrap().code,
+            "password_uppercase"
+        );
+
+        let report = policy.check("nouppercase1!");
+        assert!(!report.is_valid());
+        assert_eq!(report.unmet(), vec![PasswordRule::Uppercase]);
+        assert_eq!(report.rules.len(), 4);
+    }
+
+    #[test]
+    fn test_password_policy_pattern() {
+        assert_eq!(PasswordPolicy::new().pattern(), None);
+        let policy = PasswordPolicy::new()
+            .min_length(12)
+            .max_length(64)
+            .require_lowercase()
+            .require_digit();
+        assert_eq!(
+            policy.pattern().as_deref(),
+            Some(r"(?=.*\p{Ll})(?=.*\d).{12,64}")
+        );
+        assert_eq!(
+            policy.summary(),
+            "Password needs at least 12 characters, at most 64 characters, a lowercase letter, a number"
+        );
+        let open_ended = PasswordPolicy::new().require_symbol();
+        assert!(open_ended.pattern().unwrap().ends_with(".{8,}"));
+    }
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(PasswordStrength::of("Ab1!"), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::of("password"), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::of("Password1"), PasswordStrength::Fair);
+        assert_eq!(PasswordStrength::of("Passw0rd!"), PasswordStrength::Good);
+        assert_eq!(
+            PasswordStrength::of("Tr0ub4dor&3-staple"),
+            PasswordStrength::Strong
+        );
+        assert!(PasswordStrength::Strong > PasswordStrength::Weak);
+    }
+
+    #[test]
+    fn test_form_data_urlencoded() {
+        let data = FormData::parse_urlencoded("name=Ada+Lovelace&tag=a&tag=b%26c&note=100%&empty");
+        assert_eq!(data.get("name"), Some("Ada Lovelace"));
+        assert_eq!(data.get_all("tag"), vec!["a", "b&c"]);
+        assert_eq!(data.get("note"), Some("100%"));
+        assert_eq!(data.get("empty"), Some(""));
+        assert_eq!(data.get("missing"), None);
+
+        let utf8 = FormData::parse_urlencoded("city=Z%C3%BCrich");
+        assert_eq!(utf8.get("city"), Some("Zürich"));
+    }
+
     #[test]
     fn test_form_state() {
         let mut state = FormState::new();
@@ -646,6 +1895,55 @@ mod tests {
         assert!(!state.is_touched("email"));
     }
 
+    #[test]
+    fn test_form_state_typed_values() {
+        let state = FormState::from_data(&FormData::parse_urlencoded(
+            "age=42&price=%209.5%20&qty=many&newsletter=on&terms=off&tag=a&tag=b&n=1&n=2",
+        ));
+        assert_eq!(state.get_parsed::<u32>("age"), Ok(Some(42)));
+        assert_eq!(state.get_parsed::<f64>("price"), Ok(Some(9.5)));
+        assert_eq!(state.get_parsed::<u32>("missing"), Ok(None));
+        let error = state.get_parsed::<u32>("qty").unwrap_err();
+        assert_eq!((error.field.as_str(), error.code.as_str()), ("qty", "parse"));
+
+        assert!(state.get_bool("newsletter"));
+        assert!(!state.get_bool("terms"));
+        assert!(!state.get_bool("missing"));
+
+        assert_eq!(state.get_value("tag").map(String::as_str), Some("a"));
+        assert_eq!(state.get_all("tag"), ["a", "b"]);
+        assert_eq!(state.get_all("age"), ["42"]);
+        assert!(state.get_all("missing").is_empty());
+        assert_eq!(state.get_parsed_all::<u8>("n"), Ok(vec![1, 2]));
+
+        let mut state = FormState::new();
+        state.set_values("color", vec!["red".into(), "blue".into()]);
+        assert_eq!(state.get_value("color").map(String::as_str), Some("red"));
+        assert_eq!(state.get_all("color"), ["red", "blue"]);
+    }
+
+    #[test]
+    fn test_form_state_dates() {
+        let mut state = FormState::new();
+        state.set_value("day", "03/04/2024");
+        state.set_value("iso", "2024-02-29");
+        state.set_value("bad", "31/02/2024");
+
+        let date = |y, m, d| Ok(Some(CalendarDate::new(y, m, d)));
+        assert_eq!(state.get_date("day", "en-GB"), date(2024, 4, 3));
+        assert_eq!(state.get_date("day", "en-US"), date(2024, 3, 4));
+        assert_eq!(state.get_date("day", "de-DE"), date(2024, 4, 3));
+        assert_eq!(state.get_date("iso", "en-US"), date(2024, 2, 29));
+        assert_eq!(state.get_date("iso", "ja"), date(2024, 2, 29));
+        assert_eq!(state.get_date("missing", "en"), Ok(None));
+        assert!(state.get_date("day", "ja").is_err());
+
+        let error = state.get_date("bad", "fr").unwrap_err();
+        assert_eq!(error.message, "Enter a date as DD/MM/YYYY");
+        assert_eq!(DateOrder::for_locale("en_US"), DateOrder::MonthDayYear);
+        assert_eq!(DateOrder::for_locale("zh-Hant-TW"), DateOrder::YearMonthDay);
+    }
+
     #[test]
     fn test_helper_functions() {
         let required = validators::required();
@@ -663,6 +1961,11 @@ mod tests {
 // UI Components for Form Rendering
 // =============================================================================
 
+use crate::component::AriaLive;
+use crate::htmx::HxAttrs;
+#[cfg(feature = "htmx")]
+use crate::htmx::{HxSwap, RenderMode};
+use crate::signing::{hmac_sha256, to_hex, verify_hex_signature};
 use maud::{html, Markup};
 
 /// Form variant for visual styling
@@ -712,6 +2015,14 @@ pub struct Form<'a> {
     pub aria_label: Option<&'a str>,
     /// Whether to show validation errors inline
     pub show_inline_errors: bool,
+    /// Hidden fields rendered ahead of the visible ones
+    pub hidden_fields: Vec<HiddenField<'a>>,
+    /// Name of the honeypot field, if any
+    pub honeypot: Option<&'a str>,
+    /// Signed time-trap token issued when the form was built
+    pub time_trap: Option<String>,
+    hx: HxAttrs<'a>,
+    turbo_frame: Option<&'a str>,
 }
 
 impl<'a> Form<'a> {
@@ -729,6 +2040,11 @@ impl<'a> Form<'a> {
             class: None,
             aria_label: None,
             show_inline_errors: true,
+            hidden_fields: Vec::new(),
+            honeypot: None,
+            time_trap: None,
+            hx: HxAttrs::default(),
+            turbo_frame: None,
         }
     }
 
@@ -792,6 +2108,84 @@ impl<'a> Form<'a> {
         self
     }
 
+    /// Add a CSRF token under [`CSRF_FIELD_NAME`]
+    pub fn csrf_token(self, token: &'a str) -> Self {
+        self.hidden_field(HiddenField::csrf(token))
+    }
+
+    /// Add a CSRF token under the field name a middleware expects
+    pub fn csrf_field(self, name: &'a str, token: &'a str) -> Self {
+        self.hidden_field(HiddenField::new(name, token))
+    }
+
+    /// Add a hidden field
+    pub fn hidden(self, name: &'a str, value: &'a str) -> Self {
+        self.hidden_field(HiddenField::new(name, value))
+    }
+
+    /// Add a prepared hidden field
+    pub fn hidden_field(mut self, field: HiddenField<'a>) -> Self {
+        self.hidden_fields.push(field);
+        self
+    }
+
+    /// Submit as POST with a `_method` field naming the intended verb
+    pub fn method_override(mut self, method: FormMethodOverride) -> Self {
+        self.method = Some("POST");
+        self.hidden_fields.push(method.field());
+        self
+    }
+
+    /// Add a hidden text field that people never see but form-filling
+    /// bots do; check it with [`SpamCheck::honeypot`]
+    pub fn with_honeypot(mut self, name: &'a str) -> Self {
+        self.honeypot = Some(name);
+        self
+    }
+
+    /// Add a signed timestamp so [`SpamCheck::time_trap`] can reject
+    /// submissions sent sooner than `min_seconds` after rendering. `key` is
+    /// the server secret the token is signed with.
+    pub fn with_time_trap(mut self, min_seconds: u64, key: &[u8]) -> Self {
+        self.time_trap = Some(issue_time_token(key, min_seconds, unix_now()));
+        self
+    }
+
+    /// Submit with an HTMX GET to `url`
+    #[cfg(feature = "htmx")]
+    pub fn hx_get(mut self, url: &'a str) -> Self {
+        self.hx.get = Some(url.into());
+        self
+    }
+
+    /// Submit with an HTMX POST to `url`
+    #[cfg(feature = "htmx")]
+    pub fn hx_post(mut self, url: &'a str) -> Self {
+        self.hx.post = Some(url.into());
+        self
+    }
+
+    /// Element the HTMX response is swapped into
+    #[cfg(feature = "htmx")]
+    pub fn hx_target(mut self, selector: &'a str) -> Self {
+        self.hx.target = Some(selector.into());
+        self
+    }
+
+    /// How the HTMX response is swapped in
+    #[cfg(feature = "htmx")]
+    pub fn hx_swap(mut self, swap: HxSwap) -> Self {
+        self.hx.swap = Some(swap);
+        self
+    }
+
+    /// Render the response in the Turbo frame with id `frame`
+    #[cfg(feature = "turbo")]
+    pub fn turbo_frame(mut self, frame: &'a str) -> Self {
+        self.turbo_frame = Some(frame);
+        self
+    }
+
     /// Render the form
     pub fn render(self) -> Markup {
         let variant_class = match self.variant {
@@ -817,12 +2211,51 @@ impl<'a> Form<'a> {
                 aria-label=[self.aria_label]
                 role="form"
                 novalidate
+                hx-get=[self.hx.get()]
+                hx-post=[self.hx.post()]
+                hx-target=[self.hx.target()]
+                hx-swap=[self.hx.swap()]
+                data-turbo-frame=[self.turbo_frame]
             {
-                (self.fields)
-                @if let Some(button) = self.submit_button {
-                    div class="sh-form__actions" {
-                        (button)
-                    }
+                (self.render_contents())
+            }
+        }
+    }
+
+    /// Render the form, or with [`RenderMode::Fragment`] only its contents
+    /// (hidden fields, spam traps, fields and actions) for an HTMX response
+    #[cfg(feature = "htmx")]
+    pub fn render_as(self, mode: RenderMode) -> Markup {
+        match mode {
+            RenderMode::Full => self.render(),
+            RenderMode::Fragment => self.render_contents(),
+        }
+    }
+
+    fn render_contents(&self) -> Markup {
+        html! {
+            @for field in &self.hidden_fields {
+                (field.render())
+            }
+            @if let Some(token) = &self.time_trap {
+                (HiddenField::new(TIME_TRAP_FIELD, token).render())
+            }
+            @if let Some(name) = self.honeypot {
+                div class="sh-form__trap" aria-hidden="true" {
+                    label for={ "sh-form-trap-" (name) } { "Leave this field empty" }
+                    input
+                        type="text"
+                        id={ "sh-form-trap-" (name) }
+                        name=(name)
+                        value=""
+                        tabindex="-1"
+                        autocomplete="off";
+                }
+            }
+            (self.fields)
+            @if let Some(button) = &self.submit_button {
+                div class="sh-form__actions" {
+                    (button)
                 }
             }
         }
@@ -835,6 +2268,225 @@ impl<'a> Default for Form<'a> {
     }
 }
 
+// =============================================================================
+// Hidden Fields
+// =============================================================================
+
+/// Field name used by [`Form::csrf_token`] and [`HiddenField::csrf`]
+pub const CSRF_FIELD_NAME: &str = "csrf_token";
+
+/// Hidden input carrying state the server needs back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HiddenField<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
+}
+
+impl<'a> HiddenField<'a> {
+    /// Create a hidden field
+    pub fn new(name: &'a str, value: &'a str) -> Self {
+        Self { name, value }
+    }
+
+    /// CSRF token field named [`CSRF_FIELD_NAME`]
+    pub fn csrf(token: &'a str) -> Self {
+        Self::new(CSRF_FIELD_NAME, token)
+    }
+
+    /// Render the hidden input
+    pub fn render(self) -> Markup {
+        html! {
+            input type="hidden" name=(self.name) value=(self.value);
+        }
+    }
+}
+
+/// HTTP verb tunnelled through a POST form, since HTML forms can only
+/// submit GET and POST. Pairs with method-override middleware that reads
+/// the `_method` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormMethodOverride {
+    Put,
+    Patch,
+    Delete,
+}
+
+impl FormMethodOverride {
+    /// Field name the override is submitted under
+    pub const FIELD_NAME: &'static str = "_method";
+
+    /// Verb as sent in the field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FormMethodOverride::Put => "PUT",
+            FormMethodOverride::Patch => "PATCH",
+            FormMethodOverride::Delete => "DELETE",
+        }
+    }
+
+    /// Parse a submitted `_method` value, case-insensitively
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "PUT" => Some(FormMethodOverride::Put),
+            "PATCH" => Some(FormMethodOverride::Patch),
+            "DELETE" => Some(FormMethodOverride::Delete),
+            _ => None,
+        }
+    }
+
+    /// The hidden `_method` field
+    pub fn field(&self) -> HiddenField<'static> {
+        HiddenField::new(Self::FIELD_NAME, self.as_str())
+    }
+
+    /// Render the hidden `_method` field
+    pub fn render(&self) -> Markup {
+        self.field().render()
+    }
+}
+
+// =============================================================================
+// Spam Protection
+// =============================================================================
+
+/// Field carrying the time-trap token from [`Form::with_time_trap`]
+pub const TIME_TRAP_FIELD: &str = "_form_ts";
+
+/// Clock skew tolerated between issuing and checking a token, in seconds
+const CLOCK_SKEW: u64 = 60;
+
+pub(crate) fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// `issued.min_seconds.signature`, signed with HMAC-SHA256
+fn issue_time_token(key: &[u8], min_seconds: u64, issued_at: u64) -> String {
+    let payload = format!("{}.{}", issued_at, min_seconds);
+    let signature = to_hex(&hmac_sha256(key, payload.as_bytes()));
+    format!("{}.{}", payload, signature)
+}
+
+/// Why a submission was rejected as spam
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpamError {
+    /// The honeypot field had a value
+    HoneypotFilled,
+    /// No time-trap token was submitted
+    MissingToken,
+    /// The token was malformed or its signature did not match
+    InvalidToken,
+    /// Sent faster than a person could fill the form
+    TooFast { elapsed: u64, required: u64 },
+    /// The token is older than the allowed age
+    Expired,
+}
+
+impl std::fmt::Display for SpamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpamError::HoneypotFilled => write!(f, "honeypot field was filled in"),
+            SpamError::MissingToken => write!(f, "form timestamp is missing"),
+            SpamError::InvalidToken => write!(f, "form timestamp is invalid"),
+            SpamError::TooFast { elapsed, required } => write!(
+                f,
+                "form was submitted after {}s, minimum is {}s",
+                elapsed, required
+            ),
+            SpamError::Expired => write!(f, "form timestamp has expired"),
+        }
+    }
+}
+
+impl std::error::Error for SpamError {}
+
+/// Server-side check for the traps added by [`Form::with_honeypot`] and
+/// [`Form::with_time_trap`]
+#[derive(Debug, Clone, Copy)]
+pub struct SpamCheck<'a> {
+    /// Honeypot field that must come back empty
+    pub honeypot: Option<&'a str>,
+    /// Secret used to verify the time-trap token
+    pub key: Option<&'a [u8]>,
+    /// Oldest token accepted, in seconds
+    pub max_age: u64,
+}
+
+impl<'a> SpamCheck<'a> {
+    /// Create a check with no traps enabled
+    pub fn new() -> Self {
+        Self {
+            honeypot: None,
+            key: None,
+            max_age: 24 * 60 * 60,
+        }
+    }
+
+    /// Reject submissions where the honeypot `name` is filled in
+    pub fn honeypot(mut self, name: &'a str) -> Self {
+        self.honeypot = Some(name);
+        self
+    }
+
+    /// Require a valid time-trap token signed with `key`
+    pub fn time_trap(mut self, key: &'a [u8]) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set the oldest token accepted (defaults to 24 hours)
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = seconds;
+        self
+    }
+
+    /// Check a submission against the current time
+    pub fn validate(&self, data: &FormData) -> Result<(), SpamError> {
+        self.validate_at(data, unix_now())
+    }
+
+    /// Check a submission as of `now` (seconds since the Unix epoch)
+    pub fn validate_at(&self, data: &FormData, now: u64) -> Result<(), SpamError> {
+        if let Some(name) = self.honeypot {
+            if data.get(name).is_some_and(|v| !v.trim().is_empty()) {
+                return Err(SpamError::HoneypotFilled);
+            }
+        }
+
+        let Some(key) = self.key else {
+            return Ok(());
+        };
+        let token = data.get(TIME_TRAP_FIELD).ok_or(SpamError::MissingToken)?;
+        let (payload, signature) = token.rsplit_once('.').ok_or(SpamError::InvalidToken)?;
+        if !verify_hex_signature(key, payload.as_bytes(), signature) {
+            return Err(SpamError::InvalidToken);
+        }
+        let (issued, required) = payload
+            .split_once('.')
+            .and_then(|(issued, min)| Some((issued.parse::<u64>().ok()?, min.parse::<u64>().ok()?)))
+            .ok_or(SpamError::InvalidToken)?;
+        if issued > now + CLOCK_SKEW {
+            return Err(SpamError::InvalidToken);
+        }
+
+        let elapsed = now.saturating_sub(issued);
+        if elapsed < required {
+            return Err(SpamError::TooFast { elapsed, required });
+        }
+        if elapsed > self.max_age {
+            return Err(SpamError::Expired);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Default for SpamCheck<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // =============================================================================
 // Form Field Component
 // =============================================================================
@@ -951,8 +2603,8 @@ impl<'a> FormField<'a> {
                 @if let Some(error_msg) = self.error {
                     span
                         class="sh-form-field__error"
-                        role="alert"
-                        aria-live="polite"
+                        role=[AriaLive::Assertive.role()]
+                        aria-live=(AriaLive::Assertive.as_str())
                     {
                         (error_msg)
                     }
@@ -1197,6 +2849,38 @@ pub fn form_css() -> String {
     color: var(--sh-accent);
 }
 
+/* Honeypot: off-screen rather than display: none, which bots skip */
+.sh-form__trap {
+    position: absolute;
+    left: -10000px;
+    width: 1px;
+    height: 1px;
+    overflow: hidden;
+}
+
+/* Errors restored from a FormSnapshot */
+.sh-form__errors {
+    padding: 0.75rem 1rem;
+    border: 1px solid var(--sh-error, #dc2626);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: color-mix(in srgb, var(--sh-error, #dc2626) 8%, transparent);
+    color: var(--sh-error, #dc2626);
+}
+
+.sh-form__errors-title {
+    margin: 0 0 0.25rem;
+    font-weight: 600;
+}
+
+.sh-form__errors ul {
+    margin: 0;
+    padding-left: 1.25rem;
+}
+
+.sh-form__errors a {
+    color: inherit;
+}
+
 /* Reduced motion */
 @media (prefers-reduced-motion: reduce) {
     .sh-form * {
@@ -1259,6 +2943,7 @@ mod ui_tests {
         let html = field.into_string();
         assert!(html.contains("sh-form-field--error"));
         assert!(html.contains("Invalid email format"));
+        assert!(html.contains(r#"role="alert" aria-live="assertive""#));
     }
 
     #[test]
@@ -1275,6 +2960,168 @@ mod ui_tests {
         assert!(html.contains("Personal Information"));
     }
 
+    #[test]
+    fn test_form_csrf_and_hidden_fields() {
+        let html = Form::new()
+            .action("/profile")
+            .csrf_token("tok123")
+            .hidden("next", "/home")
+            .fields(html! { input type="text" name="name"; })
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<input type="hidden" name="csrf_token" value="tok123">"#));
+        assert!(html.contains(r#"<input type="hidden" name="next" value="/home">"#));
+        let csrf = html.find("csrf_token").unwrap();
+        let name = html.find(r#"name="name""#).unwrap();
+        assert!(csrf < name);
+
+        let html = Form::new()
+            .csrf_field("authenticity_token", "t")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="authenticity_token" value="t""#));
+    }
+
+    #[test]
+    fn test_form_method_override() {
+        let html = Form::new()
+            .method("GET")
+            .method_override(FormMethodOverride::Delete)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"method="POST""#));
+        assert!(html.contains(r#"<input type="hidden" name="_method" value="DELETE">"#));
+
+        assert_eq!(
+            FormMethodOverride::parse("patch"),
+            Some(FormMethodOverride::Patch)
+        );
+        assert_eq!(FormMethodOverride::parse("POST"), None);
+        assert_eq!(
+            FormMethodOverride::Put.render().into_string(),
+            r#"<input type="hidden" name="_method" value="PUT">"#
+        );
+    }
+
+    #[test]
+    fn test_form_spam_traps_render() {
+        let html = Form::new()
+            .with_honeypot("website")
+            .with_time_trap(3, b"secret")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<div class="sh-form__trap" aria-hidden="true">"#));
+        assert!(html.contains(r#"name="website" value="" tabindex="-1" autocomplete="off""#));
+        assert!(html.contains(r#"name="_form_ts""#));
+        assert!(form_css().contains(".sh-form__trap"));
+    }
+
+    #[test]
+    fn test_spam_check_honeypot() {
+        let check = SpamCheck::new().honeypot("website");
+        let clean = FormData::from_pairs([("website", ""), ("msg", "hi")]);
+        assert_eq!(check.validate(&clean), Ok(()));
+        let spam = FormData::from_pairs([("website", "http://spam.example")]);
+        assert_eq!(check.validate(&spam), Err(SpamError::HoneypotFilled));
+    }
+
+    #[test]
+    fn test_spam_check_time_trap() {
+        let key = b"secret";
+        let token = issue_time_token(key, 5, 1_000);
+        let data = FormData::from_pairs([(TIME_TRAP_FIELD, token.as_str())]);
+        let check = SpamCheck::new().time_trap(key).max_age(3600);
+
+        assert_eq!(check.validate_at(&data, 1_010), Ok(()));
+        assert_eq!(
+            check.validate_at(&data, 1_002),
+            Err(SpamError::TooFast {
+                elapsed: 2,
+                required: 5
+            })
+        );
+        assert_eq!(check.validate_at(&data, 10_000), Err(SpamError::Expired));
+        assert_eq!(
+            check.validate_at(&FormData::new(), 1_010),
+            Err(SpamError::MissingToken)
+        );
+    }
+
+    #[test]
+    fn test_spam_check_rejects_tampering() {
+        let key = b"secret";
+        let token = issue_time_token(key, 5, 1_000);
+        let lowered = token.replacen(".5.", ".0.", 1);
+        let check = SpamCheck::new().time_trap(key);
+        for bad in [lowered.as_str(), "garbage", "1000.5"] {
+            let data = FormData::from_pairs([(TIME_TRAP_FIELD, bad)]);
+            assert_eq!(
+                check.validate_at(&data, 1_010),
+                Err(SpamError::InvalidToken)
+            );
+        }
+        let other_key = SpamCheck::new().time_trap(b"other");
+        let data = FormData::from_pairs([(TIME_TRAP_FIELD, token.as_str())]);
+        assert_eq!(
+            other_key.validate_at(&data, 1_010),
+            Err(SpamError::InvalidToken)
+        );
+
+        let future = issue_time_token(key, 0, 5_000);
+        let data = FormData::from_pairs([(TIME_TRAP_FIELD, future.as_str())]);
+        assert_eq!(
+            check.validate_at(&data, 1_000),
+            Err(SpamError::InvalidToken)
+        );
+        assert_eq!(
+            SpamError::TooFast {
+                elapsed: 1,
+                required: 3
+            }
+            .to_string(),
+            "form was submitted after 1s, minimum is 3s"
+        );
+    }
+
+    #[test]
+    fn test_hidden_field() {
+        let html = HiddenField::new("id", "a\"b").render().into_string();
+        assert_eq!(html, r#"<input type="hidden" name="id" value="a&quot;b">"#);
+        assert_eq!(HiddenField::csrf("x").name, CSRF_FIELD_NAME);
+    }
+
+    #[cfg(feature = "turbo")]
+    #[test]
+    fn test_form_turbo_frame() {
+        let html = Form::new()
+            .action("/search")
+            .turbo_frame("results")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"data-turbo-frame="results""#));
+    }
+
+    #[cfg(feature = "htmx")]
+    #[test]
+    fn test_form_htmx_fragment() {
+        let form = || {
+            Form::new()
+                .action("/subscribe")
+                .hx_post("/subscribe/partial")
+                .hx_swap(HxSwap::OuterHtml)
+                .csrf_token("tok")
+                .fields(html! { input name="email"; })
+        };
+
+        let full = form().render_as(RenderMode::Full).into_string();
+        assert!(full.contains(r#"hx-post="/subscribe/partial" hx-swap="outerHTML""#));
+
+        let fragment = form().render_as(RenderMode::Fragment).into_string();
+        assert!(!fragment.contains("<form"));
+        assert!(fragment.starts_with(r#"<input type="hidden" name="csrf_token" value="tok">"#));
+        assert!(fragment.contains(r#"<input name="email">"#));
+    }
+
     #[test]
     fn test_form_css_generation() {
         let css = form_css();
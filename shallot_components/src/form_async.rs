@@ -0,0 +1,596 @@
+//! Async Validation - checks that need I/O, and a POST handler running them
+//!
+//! Some rules can only be checked against stored data: an email already
+//! registered, a slug already taken. An [`AsyncValidator`] sits on a
+//! [`FieldValidation`] next to the sync validators and is run by
+//! [`FormSchema::validate_async`], which gets a [`ValidationCtx`] with the
+//! rest of the submission and the application state, e.g. a database pool.
+//!
+//! Async checks only run for fields whose sync validators passed, so a
+//! malformed email never reaches the database. They run concurrently, each
+//! limited by [`FormSchema::async_timeout`]. A check that runs out of time
+//! either rejects the field or falls back to the validator's sync check,
+//! depending on the [`TimeoutPolicy`]. The sync [`FormSchema::validate`]
+//! always uses that fallback. No async runtime is required: futures are boxed
+//! and the timeouts are `futures-timer` delays sharing one timer thread, so
+//! any executor can drive them. A check that finishes cancels its delay.
+//!
+//! [`FormHandler`] processes a POST with all of this: spam traps, then the
+//! schema with its async checks and file rules, giving a [`FormOutcome`].
+//!
+//! # Example
+//!
+//! ```
+//! use shallot_components::form::{FieldValidation, FormSchema, ValidationError};
+//! use shallot_components::form_async::{AsyncValidator, ValidationCtx, ValidationFuture};
+//!
+//! struct Users(Vec<&'static str>);
+//!
+//! struct EmailAvailable;
+//!
+//! impl AsyncValidator for EmailAvailable {
+//!     fn validate<'a>(&'a self, value: &'a str, ctx: &'a ValidationCtx<'a>) -> ValidationFuture<'a> {
+//!         Box::pin(async move {
+//!             let users = ctx.state::<Users>()?;
+//!             // A real check would query the database here
+//!             users.0.contains(&value).then(|| {
+//!                 ValidationError::new(ctx.field, "That email is already registered", "taken")
+//!             })
+//!         })
+//!     }
+//! }
+//!
+//! let schema = FormSchema::new()
+//!     .field(FieldValidation::new("email").add_async_validator(EmailAvailable));
+//! let users = Users(vec!["ada@example.com"]);
+//! let data = [("email".to_string(), "ada@example.com".to_string())].into();
+//! let check = schema.validate_async_with(&data, &users);
+//! // `check` is a future; await it in the request handler
+//! # drop(check);
+//! ```
+
+use crate::form::{
+    FormData, FormErrors, FormSchema, SpamCheck, SpamError, ValidationError, ValidationResult,
+};
+use crate::form_snapshot::FormSnapshot;
+use futures_timer::Delay;
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Limit for a single async check unless the schema sets one
+pub const DEFAULT_ASYNC_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Future returned by [`AsyncValidator::validate`]
+pub type ValidationFuture<'a> = Pin<Box<dyn Future<Output = Option<ValidationError>> + Send + 'a>>;
+
+/// Application state shared with async validators
+pub type ValidationState = dyn Any + Send + Sync;
+
+/// What an async validator can see besides the value it checks
+pub struct ValidationCtx<'a> {
+    /// Field being validated
+    pub field: &'a str,
+    /// Every submitted value, first value per name
+    pub values: &'a HashMap<String, String>,
+    state: Option<&'a ValidationState>,
+}
+
+impl<'a> ValidationCtx<'a> {
+    pub fn new(field: &'a str, values: &'a HashMap<String, String>) -> Self {
+        Self {
+            field,
+            values,
+            state: None,
+        }
+    }
+
+    /// Attach application state, read back with [`ValidationCtx::state`]
+    pub fn with_state(mut self, state: &'a ValidationState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Another submitted value, empty when missing
+    pub fn value(&self, name: &str) -> &'a str {
+        self.values.get(name).map(|s| s.as_str()).unwrap_or("")
+    }
+
+    /// The application state, if it was given and is a `T`
+    pub fn state<T: Any>(&self) -> Option<&'a T> {
+        self.state.and_then(|state| state.downcast_ref())
+    }
+}
+
+impl std::fmt::Debug for ValidationCtx<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidationCtx")
+            .field("field", &self.field)
+            .field("values", &self.values)
+            .field("state", &self.state.is_some())
+            .finish()
+    }
+}
+
+/// A validation rule that needs I/O, such as a uniqueness check
+pub trait AsyncValidator: Send + Sync {
+    /// Check a value, resolving to an error if it is invalid
+    fn validate<'a>(&'a self, value: &'a str, ctx: &'a ValidationCtx<'a>) -> ValidationFuture<'a>;
+
+    /// Check run instead of [`AsyncValidator::validate`] by the sync
+    /// [`FormSchema::validate`], and after a timeout under
+    /// [`TimeoutPolicy::Fallback`]. Accepts everything by default.
+    fn validate_sync(&self, _value: &str, _ctx: &ValidationCtx<'_>) -> Option<ValidationError> {
+        None
+    }
+}
+
+/// What happens to a field whose async check runs out of time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeoutPolicy {
+    /// Report a `timeout` error asking to try again
+    #[default]
+    Reject,
+    /// Use the validator's sync check instead
+    Fallback,
+}
+
+impl FormSchema {
+    /// Limit each async check to `timeout` (defaults to
+    /// [`DEFAULT_ASYNC_TIMEOUT`])
+    pub fn async_timeout(mut self, timeout: Duration) -> Self {
+        self.async_timeout = Some(timeout);
+        self
+    }
+
+    /// Decide what a timed-out async check means (defaults to rejecting)
+    pub fn on_timeout(mut self, policy: TimeoutPolicy) -> Self {
+        self.timeout_policy = policy;
+        self
+    }
+
+    /// Validate all fields, running async validators as well
+    pub async fn validate_async(&self, data: &HashMap<String, String>) -> ValidationResult {
+        self.run_async(data, None).await
+    }
+
+    /// Validate all fields, giving async validators `state`, e.g. a
+    /// database pool
+    pub async fn validate_async_with(
+        &self,
+        data: &HashMap<String, String>,
+        state: &ValidationState,
+    ) -> ValidationResult {
+        self.run_async(data, Some(state)).await
+    }
+
+    async fn run_async(
+        &self,
+        data: &HashMap<String, String>,
+        state: Option<&ValidationState>,
+    ) -> ValidationResult {
+        let timeout = self.async_timeout.unwrap_or(DEFAULT_ASYNC_TIMEOUT);
+        let mut errors = Vec::new();
+        let mut pending = Vec::new();
+
+        for field in self.fields.iter().filter(|f| f.applies(data)) {
+            let value = data
+                .get(&field.field_name)
+                .map(|s| s.as_str())
+                .unwrap_or("");
+            let field_errors = field.validate(value);
+            if field_errors.is_empty() && !field.async_validators.is_empty() {
+                let mut ctx = ValidationCtx::new(&field.field_name, data);
+                if let Some(state) = state {
+                    ctx = ctx.with_state(state);
+                }
+                pending.push((field, value, ctx));
+            }
+            errors.extend(field_errors);
+        }
+
+        let mut checks = Vec::new();
+        let mut futures = Vec::new();
+        for (field, value, ctx) in &pending {
+            for validator in &field.async_validators {
+                checks.push((validator, *value, ctx));
+                futures.push(Timeout::new(validator.validate(value, ctx), timeout));
+            }
+        }
+
+        for ((validator, value, ctx), outcome) in checks.into_iter().zip(join_all(futures).await) {
+            match outcome {
+                Some(error) => errors.extend(error),
+                None => match self.timeout_policy {
+                    TimeoutPolicy::Reject => errors.push(ValidationError::new(
+                        ctx.field,
+                        "Could not be checked in time, please try again",
+                        "timeout",
+                    )),
+                    TimeoutPolicy::Fallback => errors.extend(validator.validate_sync(value, ctx)),
+                },
+            }
+        }
+        errors.extend(self.rules.iter().filter_map(|rule| rule.validate(data)));
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Processes a form POST: spam traps first, then the schema including its
+/// async validators
+pub struct FormHandler<'a> {
+    schema: &'a FormSchema,
+    spam_check: Option<SpamCheck<'a>>,
+    state: Option<&'a ValidationState>,
+}
+
+/// Result of [`FormHandler::handle`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormOutcome {
+    /// Every check passed
+    Valid(FormData),
+    /// Validation failed; send the form back with the errors
+    Invalid { data: FormData, errors: FormErrors },
+    /// A spam trap fired; drop the submission
+    Spam(SpamError),
+}
+
+impl FormOutcome {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, FormOutcome::Valid(_))
+    }
+
+    /// Snapshot of a failed submission for the redirect back to the form
+    pub fn snapshot(&self) -> Option<FormSnapshot> {
+        match self {
+            FormOutcome::Invalid { data, errors } => {
+                let errors: Vec<_> = errors
+                    .iter()
+                    .flat_map(|(_, errors)| errors.iter().cloned())
+                    .collect();
+                Some(FormSnapshot::from_data(data).validation_errors(&errors))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> FormHandler<'a> {
+    pub fn new(schema: &'a FormSchema) -> Self {
+        Self {
+            schema,
+            spam_check: None,
+            state: None,
+        }
+    }
+
+    /// Reject submissions caught by `check` before validating
+    pub fn spam_check(mut self, check: SpamCheck<'a>) -> Self {
+        self.spam_check = Some(check);
+        self
+    }
+
+    /// Application state handed to async validators
+    pub fn state(mut self, state: &'a ValidationState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Process an `application/x-www-form-urlencoded` body
+    pub async fn handle(&self, body: &str) -> FormOutcome {
+        self.handle_data(FormData::parse_urlencoded(body)).await
+    }
+
+    /// Process a `multipart/form-data` body, validating its files against
+    /// the schema's file fields; a body that does not parse is invalid
+    pub async fn handle_multipart(&self, body: &[u8], content_type: &str) -> FormOutcome {
+        match FormData::parse_multipart(body, content_type) {
+            Ok(data) => self.handle_data(data).await,
+            Err(error) => FormOutcome::Invalid {
+                data: FormData::new(),
+                errors: FormErrors::new(vec![ValidationError::new(
+                    "",
+                    error.to_string(),
+                    "multipart",
+                )]),
+            },
+        }
+    }
+
+    /// Process already-decoded form data
+    pub async fn handle_data(&self, data: FormData) -> FormOutcome {
+        if let Some(check) = &self.spam_check {
+            if let Err(error) = check.validate(&data) {
+                return FormOutcome::Spam(error);
+            }
+        }
+        let values = data.to_map();
+        let mut errors = self
+            .schema
+            .run_async(&values, self.state)
+            .await
+            .err()
+            .unwrap_or_default();
+        errors.extend(self.schema.validate_files(&data.files));
+        if errors.is_empty() {
+            FormOutcome::Valid(data)
+        } else {
+            FormOutcome::Invalid {
+                data,
+                errors: FormErrors::new(errors),
+            }
+        }
+    }
+}
+
+/// Resolves to `None` if `future` has not finished within the limit
+struct Timeout<F> {
+    future: F,
+    limit: Duration,
+    /// Armed on the first pending poll; dropping it cancels the timer
+    delay: Option<Delay>,
+}
+
+impl<F> Timeout<F> {
+    fn new(future: F, limit: Duration) -> Self {
+        Self {
+            future,
+            limit,
+            delay: None,
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for Timeout<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = Pin::new(&mut self.future).poll(cx) {
+            self.delay = None;
+            return Poll::Ready(Some(output));
+        }
+        let limit = self.limit;
+        let delay = self.delay.get_or_insert_with(|| Delay::new(limit));
+        Pin::new(delay).poll(cx).map(|()| None)
+    }
+}
+
+/// Poll every future until all are done, keeping their order
+async fn join_all<F: Future + Unpin>(mut futures: Vec<F>) -> Vec<F::Output> {
+    let mut outputs: Vec<Option<F::Output>> = futures.iter().map(|_| None).collect();
+    std::future::poll_fn(|cx| {
+        let mut done = true;
+        for (future, output) in futures.iter_mut().zip(outputs.iter_mut()) {
+            if output.is_none() {
+                match Pin::new(future).poll(cx) {
+                    Poll::Ready(value) => *output = Some(value),
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+        if done {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+    outputs.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::{validators, FieldValidation};
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::park();
+        }
+    }
+
+    struct Taken;
+
+    impl AsyncValidator for Taken {
+        fn validate<'a>(
+            &'a self,
+            value: &'a str,
+            ctx: &'a ValidationCtx<'a>,
+        ) -> ValidationFuture<'a> {
+            Box::pin(async move {
+                let taken = ctx.state::<Vec<&str>>()?;
+                taken
+                    .contains(&value)
+                    .then(|| ValidationError::new(ctx.field, "Already taken", "taken"))
+            })
+        }
+    }
+
+    /// A lookup that never answers
+    struct Stalled;
+
+    impl AsyncValidator for Stalled {
+        fn validate<'a>(&'a self, _: &'a str, _: &'a ValidationCtx<'a>) -> ValidationFuture<'a> {
+            Box::pin(std::future::pending())
+        }
+
+        fn validate_sync(&self, value: &str, ctx: &ValidationCtx<'_>) -> Option<ValidationError> {
+            (value == "admin").then(|| ValidationError::new(ctx.field, "Reserved", "reserved"))
+        }
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        FormData::from_pairs(pairs.iter().copied()).to_map()
+    }
+
+    #[test]
+    fn test_async_validators_use_state_after_sync_checks() {
+        let schema = FormSchema::new().field(
+            FieldValidation::new("slug")
+                .add_validator(validators::min_length(3))
+                .add_async_validator(Taken),
+        );
+        let taken = vec!["about"];
+
+        let errors = block_on(schema.validate_async_with(&values(&[("slug", "about")]), &taken))
+            .unwrap_err();
+        assert_eq!(errors[0].code, "taken");
+        assert!(block_on(schema.validate_async_with(&values(&[("slug", "blog")]), &taken)).is_ok());
+
+        // The sync check fails first, so the lookup never runs
+        let errors =
+            block_on(schema.validate_async_with(&values(&[("slug", "ab")]), &taken)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "min_length");
+
+        // Without state the validator has nothing to check against
+        assert!(block_on(schema.validate_async(&values(&[("slug", "about")]))).is_ok());
+    }
+
+    #[test]
+    fn test_async_timeout_policies() {
+        let schema = || {
+            FormSchema::new()
+                .field(FieldValidation::new("username").add_async_validator(Stalled))
+                .async_timeout(Duration::from_millis(20))
+        };
+        let data = values(&[("username", "admin")]);
+
+        let errors = block_on(schema().validate_async(&data)).unwrap_err();
+        assert_eq!(errors[0].code, "timeout");
+
+        let fallback = schema().on_timeout(TimeoutPolicy::Fallback);
+        let errors = block_on(fallback.validate_async(&data)).unwrap_err();
+        assert_eq!(errors[0].code, "reserved");
+        assert!(block_on(fallback.validate_async(&values(&[("username", "ada")]))).is_ok());
+
+        // The sync path uses the fallback directly
+        assert_eq!(schema().validate(&data).unwrap_err()[0].code, "reserved");
+    }
+
+    #[test]
+    fn test_timeout_only_arms_a_pending_check() {
+        let mut quick = Timeout::new(std::future::ready(1), DEFAULT_ASYNC_TIMEOUT);
+        assert_eq!(block_on(&mut quick), Some(1));
+        assert!(quick.delay.is_none());
+
+        // Pending once, then done: the armed delay is dropped on completion
+        let mut polled = false;
+        let mut slow = Timeout::new(
+            std::future::poll_fn(move |cx| {
+                if std::mem::replace(&mut polled, true) {
+                    Poll::Ready(2)
+                } else {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            }),
+            DEFAULT_ASYNC_TIMEOUT,
+        );
+        assert_eq!(block_on(&mut slow), Some(2));
+        assert!(slow.delay.is_none());
+    }
+
+    #[test]
+    fn test_form_handler_outcomes() {
+        let schema = FormSchema::new()
+            .field(
+                FieldValidation::new("email")
+                    .add_validator(validators::required())
+                    .add_async_validator(Taken),
+            )
+            .field(FieldValidation::new("name").add_validator(validators::required()));
+        let taken = vec!["ada@example.com"];
+        let handler = FormHandler::new(&schema)
+            .spam_check(SpamCheck::new().honeypot("website"))
+            .state(&taken);
+
+        let outcome = block_on(handler.handle("email=grace%40example.com&name=Grace"));
+        assert!(outcome.is_valid());
+        assert!(outcome.snapshot().is_none());
+
+        let outcome = block_on(handler.handle("email=ada%40example.com&name="));
+        let FormOutcome::Invalid { errors, .. } = &outcome else {
+            panic!("expected invalid, got {:?}", outcome);
+        };
+        assert_eq!(errors.fields().collect::<Vec<_>>(), ["name", "email"]);
+        let snapshot = outcome.snapshot().unwrap();
+        assert_eq!(snapshot.value("email"), Some("ada@example.com"));
+        assert_eq!(snapshot.errors_for("email"), ["Already taken"]);
+
+        let outcome = block_on(handler.handle("email=x&name=y&website=spam"));
+        assert_eq!(outcome, FormOutcome::Spam(SpamError::HoneypotFilled));
+    }
+
+    #[test]
+    fn test_form_handler_multipart_files() {
+        use crate::form_upload::{AllowedTypes, FileValidation};
+        let schema = FormSchema::new()
+            .field(FieldValidation::new("title").add_validator(validators::required()))
+            .file_field(
+                FileValidation::new("attachment")
+                    .required()
+                    .add_validator(AllowedTypes::new(["application/pdf"])),
+            );
+        let handler = FormHandler::new(&schema);
+        let content_type = "multipart/form-data; boundary=b";
+        let body = |file: &str| {
+            format!(
+                "--b\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nReport\r\n\
+                 --b\r\nContent-Disposition: form-data; name=\"attachment\"; filename=\"r.pdf\"\r\n\r\n\
+                 {}\r\n--b--\r\n",
+                file
+            )
+        };
+
+        let outcome = block_on(handler.handle_multipart(body("%PDF-1.7").as_bytes(), content_type));
+        let FormOutcome::Valid(data) = outcome else {
+            panic!("expected valid, got {:?}", outcome);
+        };
+        assert_eq!(data.files[0].filename, "r.pdf");
+
+        let outcome = block_on(handler.handle_multipart(body("MZ").as_bytes(), content_type));
+        let FormOutcome::Invalid { errors, .. } = outcome else {
+            panic!("expected invalid");
+        };
+        assert_eq!(errors.get("attachment")[0].code, "file_type");
+
+        let outcome = block_on(handler.handle_multipart(b"junk", "multipart/form-data"));
+        assert!(!outcome.is_valid());
+    }
+
+    #[test]
+    fn test_validate_async_is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        let schema = FormSchema::new();
+        let data = HashMap::new();
+        let future = schema.validate_async(&data);
+        assert_send(&future);
+        let handler = FormHandler::new(&schema);
+        assert_send(&handler.handle(""));
+    }
+}
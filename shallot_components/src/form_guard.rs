@@ -0,0 +1,419 @@
+//! Form Guard - rate limiting and spam traps for form endpoints
+//!
+//! Zero-JS forms can't use a script challenge, so abuse is handled on the
+//! server. A [`FormGuard`] holds every check in one place: the honeypot and
+//! time-trap fields it adds to a [`Form`] with [`Form::guard`], and a
+//! [`RateLimiter`] that gives each client a token bucket plus a minimum gap
+//! between submissions. [`FormGuard::check`] runs them all on a POST.
+//!
+//! Clients are keyed by [`client_key`], an HMAC of the address and user
+//! agent, so the limiter never holds raw IP addresses. The limiter lives in
+//! memory, per process; put it in the application state and share it
+//! between requests.
+//!
+//! # Example
+//!
+//! ```
+//! use shallot_components::form::{Form, FormData};
+//! use shallot_components::form_guard::{client_key, FormGuard, RateLimiter};
+//!
+//! let limiter = RateLimiter::per_minute(5);
+//! let guard = FormGuard::new(b"server secret")
+//!     .honeypot("website")
+//!     .min_fill_time(3)
+//!     .limiter(&limiter);
+//!
+//! // GET: render the form with both traps
+//! let form = Form::new().action("/contact").guard(&guard);
+//!
+//! // POST: reject bots and floods before validating
+//! let data = FormData::parse_urlencoded("website=http%3A%2F%2Fspam.example");
+//! let client = client_key(b"server secret", "203.0.113.7", "Mozilla/5.0");
+//! assert!(guard.check(&client, &data).is_err());
+//! ```
+
+use crate::form::{Form, FormData, SpamCheck, SpamError};
+use crate::signing::{hmac_sha256, to_hex};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Opaque rate-limit key for a client, so raw addresses are never stored
+pub fn client_key(secret: &[u8], ip: &str, user_agent: &str) -> String {
+    let message = format!("{}\n{}", ip, user_agent);
+    to_hex(&hmac_sha256(secret, message.as_bytes())[..16])
+}
+
+/// Why a submission was turned away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuardError {
+    /// The client used up its submissions; try again after `retry_after`
+    RateLimited { retry_after: Duration },
+    /// Sent too soon after the client's previous submission
+    TooSoon { retry_after: Duration },
+    /// Caught by the honeypot or time trap
+    Spam(SpamError),
+}
+
+impl GuardError {
+    /// Wait before the client may submit again, for a `Retry-After` header
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            GuardError::RateLimited { retry_after } | GuardError::TooSoon { retry_after } => {
+                Some(*retry_after)
+            }
+            GuardError::Spam(_) => None,
+        }
+    }
+
+    /// HTTP status to answer with: 429 when limited, 400 for spam
+    pub fn status(&self) -> u16 {
+        match self {
+            GuardError::Spam(_) => 400,
+            _ => 429,
+        }
+    }
+}
+
+impl std::fmt::Display for GuardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuardError::RateLimited { retry_after } => write!(
+                f,
+                "too many submissions, retry in {}s",
+                retry_after.as_secs().max(1)
+            ),
+            GuardError::TooSoon { retry_after } => write!(
+                f,
+                "submitted too soon after the last one, retry in {}s",
+                retry_after.as_secs().max(1)
+            ),
+            GuardError::Spam(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for GuardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GuardError::Spam(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SpamError> for GuardError {
+    fn from(error: SpamError) -> Self {
+        GuardError::Spam(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    last_accepted: Option<Instant>,
+}
+
+/// Token-bucket rate limiter keyed by client
+///
+/// Each client starts with `capacity` submissions and earns one back every
+/// refill period; an optional minimum interval spaces out even the
+/// submissions the bucket would allow.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill: Duration,
+    min_interval: Option<Duration>,
+    max_clients: usize,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Allow bursts of `capacity`, earning one submission back every `refill`
+    pub fn new(capacity: u32, refill: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1) as f64,
+            refill: refill.max(Duration::from_millis(1)),
+            min_interval: None,
+            max_clients: 10_000,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Allow `count` submissions per minute
+    pub fn per_minute(count: u32) -> Self {
+        Self::new(count, Duration::from_secs(60) / count.max(1))
+    }
+
+    /// Allow `count` submissions per hour
+    pub fn per_hour(count: u32) -> Self {
+        Self::new(count, Duration::from_secs(3600) / count.max(1))
+    }
+
+    /// Refuse a client's submission sent within `interval` of its last
+    /// accepted one
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = Some(interval);
+        self
+    }
+
+    /// Clients tracked before idle ones are dropped (default 10,000)
+    pub fn max_clients(mut self, max: usize) -> Self {
+        self.max_clients = max;
+        self
+    }
+
+    /// Take a submission from `client`'s bucket
+    pub fn check(&self, client: &str) -> Result<(), GuardError> {
+        self.check_at(client, Instant::now())
+    }
+
+    /// Take a submission from `client`'s bucket as of `now`
+    pub fn check_at(&self, client: &str, now: Instant) -> Result<(), GuardError> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= self.max_clients && !buckets.contains_key(client) {
+            self.prune(&mut buckets, now);
+        }
+        let bucket = buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            updated: now,
+            last_accepted: None,
+        });
+        bucket.tokens = self.refilled(bucket, now);
+        bucket.updated = now;
+
+        if let (Some(interval), Some(last)) = (self.min_interval, bucket.last_accepted) {
+            let since = now.saturating_duration_since(last);
+            if since < interval {
+                return Err(GuardError::TooSoon {
+                    retry_after: interval - since,
+                });
+            }
+        }
+        if bucket.tokens < 1.0 {
+            return Err(GuardError::RateLimited {
+                retry_after: self.refill.mul_f64(1.0 - bucket.tokens),
+            });
+        }
+        bucket.tokens -= 1.0;
+        bucket.last_accepted = Some(now);
+        Ok(())
+    }
+
+    /// Submissions `client` has left right now
+    pub fn remaining(&self, client: &str) -> u32 {
+        let buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets
+            .get(client)
+            .map_or(self.capacity, |bucket| {
+                self.refilled(bucket, Instant::now())
+            })
+            .floor() as u32
+    }
+
+    /// Forget `client`, e.g. after it signs in
+    pub fn reset(&self, client: &str) {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets.remove(client);
+    }
+
+    fn refilled(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.updated);
+        (bucket.tokens + elapsed.as_secs_f64() / self.refill.as_secs_f64()).min(self.capacity)
+    }
+
+    /// Drop clients whose bucket has refilled, which are as good as new
+    fn prune(&self, buckets: &mut HashMap<String, Bucket>, now: Instant) {
+        let interval = self.min_interval.unwrap_or_default();
+        buckets.retain(|_, bucket| {
+            let spaced = bucket
+                .last_accepted
+                .is_none_or(|last| now.saturating_duration_since(last) >= interval);
+            self.refilled(bucket, now) < self.capacity || !spaced
+        });
+    }
+}
+
+/// Every anti-abuse check for one form endpoint
+#[derive(Debug, Clone, Copy)]
+pub struct FormGuard<'a> {
+    /// Secret signing the time-trap token
+    pub key: &'a [u8],
+    /// Honeypot field that must come back empty
+    pub honeypot: Option<&'a str>,
+    /// Seconds a person needs at least to fill the form, 0 for no time trap
+    pub min_fill_time: u64,
+    /// Oldest time-trap token accepted, in seconds
+    pub max_age: u64,
+    /// Per-client limits
+    pub limiter: Option<&'a RateLimiter>,
+}
+
+impl<'a> FormGuard<'a> {
+    /// A guard signing its time trap with `key`, with no checks enabled
+    pub fn new(key: &'a [u8]) -> Self {
+        Self {
+            key,
+            honeypot: None,
+            min_fill_time: 0,
+            max_age: SpamCheck::new().max_age,
+            limiter: None,
+        }
+    }
+
+    /// Add a honeypot field named `name`
+    pub fn honeypot(mut self, name: &'a str) -> Self {
+        self.honeypot = Some(name);
+        self
+    }
+
+    /// Add a time trap refusing forms sent within `seconds` of rendering
+    pub fn min_fill_time(mut self, seconds: u64) -> Self {
+        self.min_fill_time = seconds;
+        self
+    }
+
+    /// Set the oldest rendered form accepted (defaults to 24 hours)
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = seconds;
+        self
+    }
+
+    /// Limit submissions per client
+    pub fn limiter(mut self, limiter: &'a RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// The trap checks on their own, e.g. for
+    /// [`FormHandler::spam_check`](crate::form_async::FormHandler::spam_check)
+    pub fn spam_check(&self) -> SpamCheck<'a> {
+        let mut check = SpamCheck::new().max_age(self.max_age);
+        if let Some(name) = self.honeypot {
+            check = check.honeypot(name);
+        }
+        if self.min_fill_time > 0 {
+            check = check.time_trap(self.key);
+        }
+        check
+    }
+
+    /// Check a submission from `client`, a key from [`client_key`]. The
+    /// limiter runs first, so submissions caught as spam still use up the
+    /// client's allowance.
+    pub fn check(&self, client: &str, data: &FormData) -> Result<(), GuardError> {
+        if let Some(limiter) = self.limiter {
+            limiter.check(client)?;
+        }
+        self.spam_check().validate(data)?;
+        Ok(())
+    }
+}
+
+impl<'a> Form<'a> {
+    /// Add the honeypot and time-trap fields `guard` checks for
+    pub fn guard(mut self, guard: &FormGuard<'a>) -> Self {
+        if let Some(name) = guard.honeypot {
+            self = self.with_honeypot(name);
+        }
+        if guard.min_fill_time > 0 {
+            self = self.with_time_trap(guard.min_fill_time, guard.key);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::TIME_TRAP_FIELD;
+
+    #[test]
+    fn test_rate_limiter_token_bucket() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+        assert!(limiter.check_at("a", start).is_ok());
+        assert!(limiter.check_at("a", start).is_ok());
+        let error = limiter
+            .check_at("a", start + Duration::from_secs(4))
+            .unwrap_err();
+        let retry_after = error.retry_after().unwrap().as_secs_f64();
+        assert!(matches!(error, GuardError::RateLimited { .. }));
+        assert!((retry_after - 6.0).abs() < 0.001);
+        // Other clients have their own bucket
+        assert!(limiter.check_at("b", start).is_ok());
+        // One token back after a refill period
+        assert!(limiter
+            .check_at("a", start + Duration::from_secs(10))
+            .is_ok());
+        assert!(limiter
+            .check_at("a", start + Duration::from_secs(11))
+            .is_err());
+
+        limiter.reset("a");
+        assert_eq!(limiter.remaining("a"), 2);
+    }
+
+    #[test]
+    fn test_rate_limiter_min_interval_and_pruning() {
+        let limiter = RateLimiter::per_minute(10)
+            .min_interval(Duration::from_secs(5))
+            .max_clients(2);
+        let start = Instant::now();
+        assert!(limiter.check_at("a", start).is_ok());
+        let error = limiter
+            .check_at("a", start + Duration::from_secs(2))
+            .unwrap_err();
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(3)));
+        assert_eq!(error.status(), 429);
+        assert!(limiter
+            .check_at("a", start + Duration::from_secs(5))
+            .is_ok());
+
+        assert!(limiter.check_at("b", start).is_ok());
+        assert!(limiter
+            .check_at("a", start + Duration::from_secs(38))
+            .is_ok());
+        // "b" has refilled by now and is dropped to make room; "a" is not
+        let later = start + Duration::from_secs(40);
+        assert!(limiter.check_at("c", later).is_ok());
+        let buckets = limiter.buckets.lock().unwrap();
+        assert!(buckets.contains_key("a") && !buckets.contains_key("b"));
+    }
+
+    #[test]
+    fn test_form_guard_renders_and_checks_traps() {
+        let limiter = RateLimiter::per_minute(1);
+        let guard = FormGuard::new(b"secret")
+            .honeypot("website")
+            .min_fill_time(0)
+            .limiter(&limiter);
+        let html = Form::new().guard(&guard).render().into_string();
+        assert!(html.contains(r#"name="website""#));
+        assert!(!html.contains(TIME_TRAP_FIELD));
+
+        let client = client_key(b"secret", "203.0.113.7", "curl/8");
+        assert_eq!(client.len(), 32);
+        assert!(!client.contains("203.0.113.7"));
+        assert_ne!(client, client_key(b"secret", "203.0.113.8", "curl/8"));
+
+        let spam = FormData::parse_urlencoded("website=buy");
+        let error = guard.check(&client, &spam).unwrap_err();
+        assert_eq!(error, GuardError::Spam(SpamError::HoneypotFilled));
+        assert_eq!(error.status(), 400);
+        // The spam attempt used the only submission this minute
+        assert!(matches!(
+            guard.check(&client, &FormData::parse_urlencoded("website=")),
+            Err(GuardError::RateLimited { .. })
+        ));
+
+        let timed = FormGuard::new(b"secret").min_fill_time(30);
+        let html = Form::new().guard(&timed).render().into_string();
+        assert!(html.contains(TIME_TRAP_FIELD));
+        assert_eq!(
+            timed.check(&client, &FormData::parse_urlencoded("")),
+            Err(GuardError::Spam(SpamError::MissingToken))
+        );
+    }
+}
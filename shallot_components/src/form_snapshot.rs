@@ -0,0 +1,695 @@
+//! Form Snapshot - repopulating forms across a POST-redirect-GET
+//!
+//! A form that works without JavaScript posts to a handler, which validates
+//! and redirects back to the form page when something is wrong. A
+//! [`FormSnapshot`] carries the submitted values and errors across that
+//! redirect without a session: it is signed with HMAC-SHA256 and encoded
+//! URL-safe, for a query parameter or a short-lived cookie, both named
+//! [`SNAPSHOT_PARAM`]. On the way back each field builder takes its value
+//! and error from the snapshot with `restore` (e.g. [`Input::restore`],
+//! [`Select::restore`]) and [`Form::restore_errors`] lists the errors above
+//! the fields. Hand-written field markup can be refilled with
+//! [`Form::restore`] instead, which rewrites the rendered controls.
+//!
+//! The payload is signed, not encrypted, and visible to whoever holds it;
+//! drop secrets with [`FormSnapshot::without`]. Password, file and hidden
+//! inputs are never repopulated.
+//!
+//! # Example
+//!
+//! ```
+//! use maud::html;
+//! use shallot_components::form::{Form, FormData};
+//! use shallot_components::form_snapshot::FormSnapshot;
+//! use shallot_components::input::{Input, InputType, Select, SelectOption};
+//!
+//! let key = b"server secret";
+//!
+//! // POST /signup: validation failed, redirect back with the snapshot
+//! let submitted = FormData::parse_urlencoded("email=ada%40example&plan=pro");
+//! let token = FormSnapshot::from_data(&submitted)
+//!     .error("email", "Enter a valid email address")
+//!     .encode(key);
+//!
+//! // GET /signup?_form_snapshot=...
+//! let snapshot = FormSnapshot::decode(&token, key).unwrap();
+//! let plans = vec![
+//!     SelectOption { label: "Free", value: "free", selected: true, disabled: false },
+//!     SelectOption { label: "Pro", value: "pro", selected: false, disabled: false },
+//! ];
+//! let html = Form::new()
+//!     .action("/signup")
+//!     .fields(html! {
+//!         (Input::new("email").type_(InputType::Email).restore(&snapshot))
+//!         (Select::new("plan", plans).restore(&snapshot))
+//!     })
+//!     .restore_errors(&snapshot)
+//!     .render()
+//!     .into_string();
+//!
+//! assert!(html.contains(r#"value="ada@example""#));
+//! assert!(html.contains(r#"<option value="pro" selected>"#));
+//! assert!(html.contains("Enter a valid email address"));
+//! ```
+//!
+//! [`Input::restore`]: crate::input::Input::restore
+//! [`Select::restore`]: crate::input::Select::restore
+
+use crate::form::{
+    unix_now, Form, FormData, FormMethodOverride, FormState, ValidationError, CSRF_FIELD_NAME,
+    TIME_TRAP_FIELD,
+};
+use crate::signing::{
+    base64url_decode, base64url_encode, hmac_sha256, to_hex, verify_hex_signature,
+};
+use maud::{html, PreEscaped};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Query parameter and cookie name a snapshot travels under
+pub const SNAPSHOT_PARAM: &str = "_form_snapshot";
+
+/// Oldest snapshot [`FormSnapshot::decode`] accepts, in seconds
+pub const SNAPSHOT_MAX_AGE: u64 = 10 * 60;
+
+/// Submitted values and validation errors, ready to sign and send back
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormSnapshot {
+    /// Field values in submission order; names may repeat
+    #[serde(rename = "v")]
+    pub values: Vec<(String, String)>,
+    /// `(field, message)` pairs; an empty field is a form-level error
+    #[serde(rename = "e")]
+    pub errors: Vec<(String, String)>,
+    /// Seconds since the Unix epoch when the snapshot was taken
+    #[serde(rename = "t")]
+    pub issued_at: u64,
+}
+
+/// Why a snapshot token was not accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// Not a `payload.signature` token, or the payload did not decode
+    Malformed,
+    /// The signature did not match
+    InvalidSignature,
+    /// Older than the allowed age
+    Expired,
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::Malformed => write!(f, "form snapshot is malformed"),
+            SnapshotError::InvalidSignature => write!(f, "form snapshot signature is invalid"),
+            SnapshotError::Expired => write!(f, "form snapshot has expired"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl FormSnapshot {
+    /// Snapshot a submission, leaving out the CSRF, time-trap and method
+    /// override fields
+    pub fn from_data(data: &FormData) -> Self {
+        let internal = [
+            CSRF_FIELD_NAME,
+            TIME_TRAP_FIELD,
+            FormMethodOverride::FIELD_NAME,
+        ];
+        Self {
+            values: data
+                .fields
+                .iter()
+                .filter(|(name, _)| !internal.contains(&name.as_str()))
+                .cloned()
+                .collect(),
+            errors: Vec::new(),
+            issued_at: unix_now(),
+        }
+    }
+
+    /// Snapshot a validated [`FormState`], fields sorted by name
+    pub fn from_state(state: &FormState) -> Self {
+        let mut names: Vec<_> = state.values.keys().chain(state.all_values.keys()).collect();
+        names.sort();
+        names.dedup();
+        let values = names
+            .into_iter()
+            .flat_map(|name| {
+                state
+                    .get_all(name)
+                    .into_iter()
+                    .map(move |value| (name.clone(), value.to_string()))
+            })
+            .collect();
+        let mut errors: Vec<_> = state
+            .errors
+            .iter()
+            .flat_map(|(name, messages)| messages.iter().map(move |m| (name.clone(), m.clone())))
+            .collect();
+        errors.sort();
+        Self {
+            values,
+            errors,
+            issued_at: unix_now(),
+        }
+    }
+
+    /// Add an error for `field`, or for the whole form when `field` is empty
+    pub fn error(mut self, field: impl Into<String>, message: impl Into<String>) -> Self {
+        self.errors.push((field.into(), message.into()));
+        self
+    }
+
+    /// Add the errors from a failed [`crate::form::FormSchema::validate`]
+    pub fn validation_errors(mut self, errors: &[ValidationError]) -> Self {
+        self.errors
+            .extend(errors.iter().map(|e| (e.field.clone(), e.message.clone())));
+        self
+    }
+
+    /// Drop every value submitted under `name`, e.g. a password
+    pub fn without(mut self, name: &str) -> Self {
+        self.values.retain(|(n, _)| n != name);
+        self
+    }
+
+    /// First value submitted under `name`
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values_for(name).into_iter().next()
+    }
+
+    /// Every value submitted under `name`
+    pub fn values_for(&self, name: &str) -> Vec<&str> {
+        self.values
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    /// Messages for `field`
+    pub fn errors_for(&self, field: &str) -> Vec<&str> {
+        self.errors
+            .iter()
+            .filter(|(f, _)| f == field)
+            .map(|(_, m)| m.as_str())
+            .collect()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// The snapshot as a [`FormState`]; repeated names keep all their values
+    pub fn to_state(&self) -> FormState {
+        let mut state = FormState::from_data(&FormData::from_pairs(self.values.iter().cloned()));
+        let mut errors: HashMap<&str, Vec<String>> = HashMap::new();
+        for (field, message) in &self.errors {
+            errors.entry(field).or_default().push(message.clone());
+        }
+        for (field, messages) in errors {
+            state.touch(field);
+            state.set_errors(field, messages);
+        }
+        state
+    }
+
+    /// `payload.signature`: base64url JSON signed with HMAC-SHA256 under
+    /// `key`, safe in a query string or cookie value
+    pub fn encode(&self, key: &[u8]) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        let payload = base64url_encode(&json);
+        let signature = to_hex(&hmac_sha256(key, payload.as_bytes()));
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Verify and decode a token from [`FormSnapshot::encode`] taken within
+    /// [`SNAPSHOT_MAX_AGE`]
+    pub fn decode(token: &str, key: &[u8]) -> Result<Self, SnapshotError> {
+        Self::decode_at(token, key, unix_now(), SNAPSHOT_MAX_AGE)
+    }
+
+    /// Verify and decode a token as of `now`, accepting snapshots up to
+    /// `max_age` seconds old
+    pub fn decode_at(
+        token: &str,
+        key: &[u8],
+        now: u64,
+        max_age: u64,
+    ) -> Result<Self, SnapshotError> {
+        let (payload, signature) = token.rsplit_once('.').ok_or(SnapshotError::Malformed)?;
+        if !verify_hex_signature(key, payload.as_bytes(), signature) {
+            return Err(SnapshotError::InvalidSignature);
+        }
+        let json = base64url_decode(payload).ok_or(SnapshotError::Malformed)?;
+        let snapshot: Self = serde_json::from_slice(&json).map_err(|_| SnapshotError::Malformed)?;
+        if now.saturating_sub(snapshot.issued_at) > max_age {
+            return Err(SnapshotError::Expired);
+        }
+        Ok(snapshot)
+    }
+
+    /// `_form_snapshot=<token>` to append to the redirect URL
+    pub fn query(&self, key: &[u8]) -> String {
+        format!("{}={}", SNAPSHOT_PARAM, self.encode(key))
+    }
+
+    /// `Set-Cookie` value carrying the snapshot for [`SNAPSHOT_MAX_AGE`];
+    /// clear it with [`FormSnapshot::clear_cookie`] once read
+    pub fn cookie(&self, key: &[u8]) -> String {
+        format!(
+            "{}={}; Max-Age={}; Path=/; HttpOnly; SameSite=Lax",
+            SNAPSHOT_PARAM,
+            self.encode(key),
+            SNAPSHOT_MAX_AGE
+        )
+    }
+
+    /// `Set-Cookie` value removing the snapshot cookie
+    pub fn clear_cookie() -> String {
+        format!(
+            "{}=; Max-Age=0; Path=/; HttpOnly; SameSite=Lax",
+            SNAPSHOT_PARAM
+        )
+    }
+}
+
+impl<'a> Form<'a> {
+    /// List the snapshot's errors above the fields, each linking to its
+    /// field. Call after [`Form::fields`].
+    pub fn restore_errors(mut self, snapshot: &FormSnapshot) -> Self {
+        let fields = std::mem::replace(&mut self.fields, html! {});
+        self.fields = html! {
+            @if snapshot.has_errors() {
+                div class="sh-form__errors" role="alert" {
+                    p class="sh-form__errors-title" { "Please correct the following:" }
+                    ul {
+                        @for (field, message) in &snapshot.errors {
+                            li {
+                                @if field.is_empty() {
+                                    (message)
+                                } @else {
+                                    a href={ "#" (field) } { (message) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            (fields)
+        };
+        self
+    }
+
+    /// Refill hand-written markup set with [`Form::fields`] from `snapshot`
+    /// and list its errors above it. Call after `fields`; fields built with
+    /// the input builders' `restore` only need [`Form::restore_errors`].
+    ///
+    /// Controls are matched by `name`: text-like inputs get their `value`
+    /// (the nth input of a repeated name gets the nth value), textareas
+    /// their content, checkboxes and radios `checked` and select options
+    /// `selected` by `value`. Controls with errors get `aria-invalid`.
+    pub fn restore(mut self, snapshot: &FormSnapshot) -> Self {
+        let fields = std::mem::replace(&mut self.fields, html! {}).into_string();
+        self.fields = PreEscaped(repopulate(&fields, snapshot));
+        self.restore_errors(snapshot)
+    }
+}
+
+type Attrs = Vec<(String, Option<String>)>;
+
+/// A form control start or end tag; quoted attribute values may hold `>`
+fn tag_regex() -> &'static Regex {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    TAG.get_or_init(|| {
+        Regex::new(r#"(?i)<(/?)(input|textarea|select|option)\b((?:[^>"']|"[^"]*"|'[^']*')*)>"#)
+            .expect("valid regex")
+    })
+}
+
+/// An attribute with a double-quoted, single-quoted, unquoted or no value
+fn attr_regex() -> &'static Regex {
+    static ATTR: OnceLock<Regex> = OnceLock::new();
+    ATTR.get_or_init(|| {
+        Regex::new(r#"([^\s="'/>]+)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'=<>`]+)))?"#)
+            .expect("valid regex")
+    })
+}
+
+fn parse_attrs(source: &str) -> Attrs {
+    attr_regex()
+        .captures_iter(source)
+        .map(|a| {
+            // Values are written back double-quoted
+            let value = a
+                .get(2)
+                .or(a.get(4))
+                .map(|v| v.as_str().to_string())
+                .or_else(|| a.get(3).map(|v| v.as_str().replace('"', "&quot;")));
+            (a[1].to_string(), value)
+        })
+        .collect()
+}
+
+fn repopulate(fields: &str, snapshot: &FormSnapshot) -> String {
+    let mut out = String::with_capacity(fields.len());
+    let mut last = 0;
+    let mut in_replaced_textarea = false;
+    let mut selected: Option<Vec<&str>> = None;
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for caps in tag_regex().captures_iter(fields) {
+        let whole = caps.get(0).expect("match");
+        if !in_replaced_textarea {
+            out.push_str(&fields[last..whole.start()]);
+        }
+        last = whole.end();
+
+        let element = caps[2].to_ascii_lowercase();
+        let element = element.as_str();
+        if &caps[1] == "/" {
+            match element {
+                "textarea" => in_replaced_textarea = false,
+                "select" => selected = None,
+                _ => {}
+            }
+            out.push_str(whole.as_str());
+            continue;
+        }
+
+        let mut attrs = parse_attrs(&caps[3]);
+        let name = get(&attrs, "name").map(unescape);
+        if let Some(name) = &name {
+            if element != "option" && !snapshot.errors_for(name).is_empty() {
+                set(&mut attrs, "aria-invalid", Some("true"));
+            }
+        }
+
+        match (element, &name) {
+            ("input", Some(name)) => {
+                let index = seen.entry(name.clone()).or_default();
+                restore_input(&mut attrs, snapshot, name, *index);
+                *index += 1;
+            }
+            ("textarea", Some(name)) => {
+                if let Some(value) = snapshot.value(name) {
+                    out.push_str(&render_tag(element, &attrs));
+                    out.push_str(&escape(value));
+                    in_replaced_textarea = true;
+                    continue;
+                }
+            }
+            ("select", Some(name)) => {
+                let values = snapshot.values_for(name);
+                selected = (!snapshot.values.is_empty()).then_some(values);
+            }
+            ("option", _) => {
+                if let (Some(values), Some(value)) = (&selected, get(&attrs, "value")) {
+                    let on = values.contains(&unescape(value).as_str());
+                    set(&mut attrs, "selected", on.then_some(""));
+                }
+            }
+            _ => {}
+        }
+        out.push_str(&render_tag(element, &attrs));
+    }
+    if !in_replaced_textarea {
+        out.push_str(&fields[last..]);
+    }
+    out
+}
+
+fn restore_input(attrs: &mut Attrs, snapshot: &FormSnapshot, name: &str, index: usize) {
+    let kind = get(attrs, "type").unwrap_or("text").to_ascii_lowercase();
+    match kind.as_str() {
+        "password" | "file" | "hidden" | "submit" | "button" | "reset" | "image" => {}
+        "checkbox" | "radio" => {
+            // An unchecked box is not submitted at all, so once anything
+            // was submitted, absence means unchecked
+            if !snapshot.values.is_empty() {
+                let value = get(attrs, "value").map_or_else(|| "on".to_string(), unescape);
+                let on = snapshot.values_for(name).contains(&value.as_str());
+                set(attrs, "checked", on.then_some(""));
+            }
+        }
+        _ => {
+            if let Some(value) = snapshot.values_for(name).get(index) {
+                set(attrs, "value", Some(&escape(value)));
+            }
+        }
+    }
+}
+
+fn get<'v>(attrs: &'v Attrs, name: &str) -> Option<&'v str> {
+    attrs
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .and_then(|(_, v)| v.as_deref())
+}
+
+/// Set, or with `None` remove, an attribute; `Some("")` writes it bare
+fn set(attrs: &mut Attrs, name: &str, value: Option<&str>) {
+    let value = value.map(|v| (!v.is_empty()).then(|| v.to_string()));
+    match (
+        attrs.iter().position(|(n, _)| n.eq_ignore_ascii_case(name)),
+        value,
+    ) {
+        (Some(i), Some(value)) => attrs[i].1 = value,
+        (Some(i), None) => {
+            attrs.remove(i);
+        }
+        (None, Some(value)) => attrs.push((name.to_string(), value)),
+        (None, None) => {}
+    }
+}
+
+fn render_tag(element: &str, attrs: &Attrs) -> String {
+    let mut tag = format!("<{}", element);
+    for (name, value) in attrs {
+        match value {
+            Some(value) => tag.push_str(&format!(r#" {}="{}""#, name, value)),
+            None => tag.push_str(&format!(" {}", name)),
+        }
+    }
+    tag.push('>');
+    tag
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"secret";
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let data = FormData::from_pairs([
+            ("csrf_token", "tok"),
+            ("name", "Ada \"The Countess\""),
+            ("tag", "a"),
+            ("tag", "b"),
+            ("password", "hunter2"),
+        ]);
+        let snapshot = FormSnapshot::from_data(&data)
+            .without("password")
+            .error("name", "Too fancy")
+            .error("", "Try again");
+        let token = snapshot.encode(KEY);
+        assert!(token
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(&b)));
+
+        let decoded = FormSnapshot::decode(&token, KEY).unwrap();
+        assert_eq!(decoded, snapshot);
+        assert_eq!(decoded.value("csrf_token"), None);
+        assert_eq!(decoded.value("password"), None);
+        assert_eq!(decoded.values_for("tag"), ["a", "b"]);
+        assert_eq!(decoded.errors_for(""), ["Try again"]);
+
+        let state = decoded.to_state();
+        assert_eq!(state.get_value("tag").map(String::as_str), Some("a"));
+        assert_eq!(state.get_all("tag"), ["a", "b"]);
+        assert_eq!(
+            FormSnapshot::from_state(&state).values_for("tag"),
+            ["a", "b"]
+        );
+        assert!(!state.is_valid);
+        assert!(snapshot.query(KEY).starts_with("_form_snapshot="));
+        assert!(snapshot
+            .cookie(KEY)
+            .ends_with("; Max-Age=600; Path=/; HttpOnly; SameSite=Lax"));
+    }
+
+    #[test]
+    fn test_snapshot_rejects_tampering_and_age() {
+        let snapshot = FormSnapshot {
+            values: vec![("q".into(), "x".into())],
+            errors: Vec::new(),
+            issued_at: 1_000,
+        };
+        let token = snapshot.encode(KEY);
+        assert_eq!(
+            FormSnapshot::decode_at(&token, KEY, 1_100, 600),
+            Ok(snapshot)
+        );
+        assert_eq!(
+            FormSnapshot::decode_at(&token, KEY, 2_000, 600),
+            Err(SnapshotError::Expired)
+        );
+        assert_eq!(
+            FormSnapshot::decode_at(&token, b"other", 1_100, 600),
+            Err(SnapshotError::InvalidSignature)
+        );
+        let (payload, _) = token.split_once('.').unwrap();
+        let forged = format!("{}.{}", payload, to_hex(&hmac_sha256(KEY, b"x")));
+        assert_eq!(
+            FormSnapshot::decode_at(&forged, KEY, 1_100, 600),
+            Err(SnapshotError::InvalidSignature)
+        );
+        assert_eq!(
+            FormSnapshot::decode_at("garbage", KEY, 1_100, 600),
+            Err(SnapshotError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_form_restore_repopulates_controls() {
+        let snapshot = FormSnapshot::from_data(&FormData::from_pairs([
+            ("name", "<Ada>"),
+            ("bio", "Hello\n& goodbye"),
+            ("plan", "pro"),
+            ("news", "on"),
+            ("color", "blue"),
+            ("link", "one"),
+            ("link", "two"),
+            ("secret", "pw"),
+        ]))
+        .error("name", "Required");
+
+        let html = Form::new()
+            .fields(html! {
+                input id="name" name="name" value="old";
+                textarea name="bio" { "placeholder text" }
+                select name="plan" {
+                    option value="free" selected { "Free" }
+                    option value="pro" { "Pro" }
+                }
+                input type="checkbox" name="news";
+                input type="checkbox" name="terms" checked;
+                input type="radio" name="color" value="red" checked;
+                input type="radio" name="color" value="blue";
+                input name="link";
+                input name="link";
+                input type="password" name="secret";
+            })
+            .restore(&snapshot)
+            .render()
+            .into_string();
+
+        assert!(html.contains(r##"<a href="#name">Required</a>"##));
+        assert!(html
+            .contains(r#"<input id="name" name="name" value="&lt;Ada&gt;" aria-invalid="true">"#));
+        assert!(html.contains("<textarea name=\"bio\">Hello\n&amp; goodbye</textarea>"));
+        assert!(html.contains(r#"<option value="free">Free</option><option value="pro" selected>"#));
+        assert!(html.contains(r#"<input type="checkbox" name="news" checked>"#));
+        assert!(html.contains(r#"<input type="checkbox" name="terms">"#));
+        assert!(html.contains(r#"<input type="radio" name="color" value="red">"#));
+        assert!(html.contains(r#"<input type="radio" name="color" value="blue" checked>"#));
+        assert!(html.contains(r#"<input name="link" value="one"><input name="link" value="two">"#));
+        assert!(html.contains(r#"<input type="password" name="secret">"#));
+    }
+
+    #[test]
+    fn test_form_restore_reads_any_attribute_quoting() {
+        let snapshot = FormSnapshot::from_data(&FormData::from_pairs([
+            ("q", "rust"),
+            ("sort", "new"),
+            ("note", "it's \"fine\""),
+        ]));
+        let html = Form::new()
+            .fields(PreEscaped(
+                concat!(
+                    r#"<INPUT name=q data-hint="a > b" value='x'>"#,
+                    "<select name='sort'><option value=top selected>Top</option>",
+                    "<option value='new'>New</option></select>",
+                    r#"<input name=note title='say "hi"'>"#,
+                )
+                .to_string(),
+            ))
+            .restore(&snapshot)
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"<input name="q" data-hint="a > b" value="rust">"#));
+        assert!(html.contains(r#"<option value="top">Top</option><option value="new" selected>"#));
+        assert!(html.contains(
+            r#"<input name="note" title="say &quot;hi&quot;" value="it's &quot;fine&quot;">"#
+        ));
+    }
+
+    #[test]
+    fn test_builders_restore_from_snapshot() {
+        use crate::input::{Checkbox, Input, InputType, Radio, Select, SelectOption, Textarea};
+
+        let snapshot = FormSnapshot::from_data(&FormData::from_pairs([
+            ("name", "Ada"),
+            ("pw", "hunter2"),
+            ("bio", "Hi"),
+            ("plan", "pro"),
+            ("color", "blue"),
+        ]))
+        .error("name", "Taken");
+        let option = |value| SelectOption {
+            label: value,
+            value,
+            selected: value == "free",
+            disabled: false,
+        };
+        let html = Form::new()
+            .fields(html! {
+                (Input::new("name").value("old").restore(&snapshot))
+                (Input::new("pw").type_(InputType::Password).restore(&snapshot))
+                (Textarea::new("bio").restore(&snapshot))
+                (Select::new("plan", vec![option("free"), option("pro")]).restore(&snapshot))
+                (Checkbox::new("News", "news").checked(true).restore(&snapshot))
+                (Radio::new("Red", "color", "red").restore(&snapshot))
+                (Radio::new("Blue", "color", "blue").restore(&snapshot))
+            })
+            .restore_errors(&snapshot)
+            .render()
+            .into_string();
+
+        assert!(html.contains(r##"<a href="#name">Taken</a>"##));
+        assert!(html.contains(r#"value="Ada""#));
+        assert!(html.contains(r#"aria-invalid="true""#));
+        assert!(html.contains("Taken</div>"));
+        assert!(!html.contains("hunter2"));
+        assert!(html.contains(">Hi</textarea>"));
+        assert!(html.contains(r#"<option value="free">free</option><option value="pro" selected>"#));
+        assert!(html.contains(r#"value="blue" checked"#));
+        assert!(!html.contains(r#"value="red" checked"#));
+        assert!(!html.contains(r#"name="news" id="sh-checkbox-news" checked"#));
+    }
+}
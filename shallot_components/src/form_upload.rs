@@ -0,0 +1,739 @@
+//! Form Uploads - multipart parsing and rules for uploaded files
+//!
+//! The server half of [`FileUpload`](crate::file_upload::FileUpload) and
+//! [`ImageUpload`](crate::image_upload::ImageUpload).
+//! [`FormData::parse_multipart`] reads a `multipart/form-data` body into
+//! fields and [`UploadedFile`]s, and a [`FileValidation`] added to a
+//! [`FormSchema`] with [`FormSchema::file_field`] checks them. The type is
+//! taken from the file's leading magic bytes, never from the extension or
+//! the browser's declared type, so a script renamed `photo.png` is still
+//! refused. [`FormHandler::handle_multipart`] does all of this for a POST.
+//!
+//! Store uploads under [`UploadedFile::safe_filename`], or a name of your
+//! own, not the submitted one. Limit the body size in the web server too;
+//! the whole body is read before these rules run.
+//!
+//! [`FormHandler::handle_multipart`]: crate::form_async::FormHandler::handle_multipart
+
+use crate::form::{FormData, FormSchema, ValidationError};
+
+/// A file from a multipart form submission
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct UploadedFile {
+    /// Name of the form field it was sent under
+    pub field: String,
+    /// Name the browser sent; see [`UploadedFile::safe_filename`]
+    pub filename: String,
+    /// Type the browser declared, which the client controls
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl std::fmt::Debug for UploadedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadedFile")
+            .field("field", &self.field)
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .field("data", &format!("[{} bytes]", self.data.len()))
+            .finish()
+    }
+}
+
+impl UploadedFile {
+    pub fn new(field: impl Into<String>, filename: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            field: field.into(),
+            filename: filename.into(),
+            content_type: None,
+            data,
+        }
+    }
+
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Size in bytes
+    pub fn size(&self) -> u64 {
+        self.data.len() as u64
+    }
+
+    /// MIME type read from the file's magic bytes
+    pub fn detected_type(&self) -> Option<&'static str> {
+        sniff_mime(&self.data)
+    }
+
+    /// Width and height of a PNG, JPEG, GIF, WebP or BMP image
+    pub fn image_dimensions(&self) -> Option<(u32, u32)> {
+        image_dimensions(&self.data)
+    }
+
+    /// The submitted name made safe to store, see [`sanitize_filename`]
+    pub fn safe_filename(&self) -> String {
+        sanitize_filename(&self.filename)
+    }
+}
+
+/// Why a multipart body could not be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultipartError {
+    /// The content type carries no `boundary` parameter
+    MissingBoundary,
+    /// The body does not follow the multipart format
+    Malformed,
+}
+
+impl std::fmt::Display for MultipartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultipartError::MissingBoundary => write!(f, "multipart body has no boundary"),
+            MultipartError::Malformed => write!(f, "multipart body is malformed"),
+        }
+    }
+}
+
+impl std::error::Error for MultipartError {}
+
+impl FormData {
+    /// Parse a `multipart/form-data` body; `content_type` is the request's
+    /// `Content-Type` header, which carries the boundary. File inputs left
+    /// empty are skipped.
+    pub fn parse_multipart(body: &[u8], content_type: &str) -> Result<Self, MultipartError> {
+        let boundary = param(content_type, "boundary").ok_or(MultipartError::MissingBoundary)?;
+        let delimiter = format!("--{}", boundary).into_bytes();
+        let close = [b"\r\n".as_slice(), &delimiter].concat();
+
+        let start = find(body, &delimiter).ok_or(MultipartError::Malformed)?;
+        let mut rest = &body[start + delimiter.len()..];
+        let mut data = FormData::new();
+        loop {
+            if rest.starts_with(b"--") {
+                return Ok(data);
+            }
+            rest = rest
+                .strip_prefix(b"\r\n")
+                .ok_or(MultipartError::Malformed)?;
+            let header_end = find(rest, b"\r\n\r\n").ok_or(MultipartError::Malformed)?;
+            let headers =
+                std::str::from_utf8(&rest[..header_end]).map_err(|_| MultipartError::Malformed)?;
+            rest = &rest[header_end + 4..];
+            let end = find(rest, &close).ok_or(MultipartError::Malformed)?;
+            let content = &rest[..end];
+            rest = &rest[end + close.len()..];
+
+            let mut disposition = None;
+            let mut part_type = None;
+            for line in headers.split("\r\n") {
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.trim().eq_ignore_ascii_case("content-disposition") {
+                        disposition = Some(value.trim());
+                    } else if name.trim().eq_ignore_ascii_case("content-type") {
+                        part_type = Some(value.trim().to_string());
+                    }
+                }
+            }
+            let disposition = disposition.ok_or(MultipartError::Malformed)?;
+            let name = param(disposition, "name").ok_or(MultipartError::Malformed)?;
+            match param(disposition, "filename") {
+                Some(filename) if filename.is_empty() && content.is_empty() => {}
+                Some(filename) => data.files.push(UploadedFile {
+                    field: name,
+                    filename,
+                    content_type: part_type,
+                    data: content.to_vec(),
+                }),
+                None => data.insert(name, String::from_utf8_lossy(content)),
+            }
+        }
+    }
+}
+
+/// Value of `name=value` or `name="value"` in a header like
+/// `form-data; name="a"; filename="b"`
+fn param(header: &str, name: &str) -> Option<String> {
+    let mut rest = header;
+    while let Some(index) = rest.find(';') {
+        rest = rest[index + 1..].trim_start();
+        let (key, after) = rest.split_once('=')?;
+        let after = after.trim_start();
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => {
+                let end = after.find(';').unwrap_or(after.len());
+                (after[..end].trim(), &after[end..])
+            }
+        };
+        if key.trim().eq_ignore_ascii_case(name) {
+            return Some(value.to_string());
+        }
+        rest = remaining;
+    }
+    None
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// MIME type of common file formats from their magic bytes. SVG and other
+/// text formats are not recognised.
+pub fn sniff_mime(data: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, magic: &[u8]| data.get(offset..offset + magic.len()) == Some(magic);
+    if at(0, b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if at(0, &[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        Some("image/gif")
+    } else if at(0, b"RIFF") && at(8, b"WEBP") {
+        Some("image/webp")
+    } else if at(0, b"RIFF") && at(8, b"WAVE") {
+        Some("audio/wav")
+    } else if at(0, b"BM") && data.len() > 26 {
+        Some("image/bmp")
+    } else if at(4, b"ftyp") {
+        match data.get(8..12) {
+            Some(b"avif" | b"avis") => Some("image/avif"),
+            Some(b"heic" | b"heix" | b"mif1") => Some("image/heic"),
+            _ => Some("video/mp4"),
+        }
+    } else if at(0, &[0x1A, 0x45, 0xDF, 0xA3]) {
+        Some("video/webm")
+    } else if at(0, b"%PDF-") {
+        Some("application/pdf")
+    } else if at(0, b"PK\x03\x04") {
+        Some("application/zip")
+    } else if at(0, &[0x1F, 0x8B]) {
+        Some("application/gzip")
+    } else if at(0, b"OggS") {
+        Some("audio/ogg")
+    } else if at(0, b"ID3") || at(0, &[0xFF, 0xFB]) {
+        Some("audio/mpeg")
+    } else {
+        None
+    }
+}
+
+/// Width and height from a PNG, JPEG, GIF, WebP or BMP header
+pub fn image_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |i: usize| Some(u16::from_be_bytes(data.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le16 = |i: usize| Some(u16::from_le_bytes(data.get(i..i + 2)?.try_into().ok()?) as u32);
+    let be32 = |i: usize| Some(u32::from_be_bytes(data.get(i..i + 4)?.try_into().ok()?));
+    let le24 = |i: usize| {
+        let b = data.get(i..i + 3)?;
+        Some(b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16)
+    };
+
+    match sniff_mime(data)? {
+        "image/png" if data.get(12..16) == Some(b"IHDR") => Some((be32(16)?, be32(20)?)),
+        "image/gif" => Some((le16(6)?, le16(8)?)),
+        "image/bmp" => {
+            let width = i32::from_le_bytes(data.get(18..22)?.try_into().ok()?);
+            let height = i32::from_le_bytes(data.get(22..26)?.try_into().ok()?);
+            Some((width.unsigned_abs(), height.unsigned_abs()))
+        }
+        "image/webp" => match data.get(12..16)? {
+            b"VP8X" => Some((le24(24)? + 1, le24(27)? + 1)),
+            b"VP8 " => Some((le16(26)? & 0x3FFF, le16(28)? & 0x3FFF)),
+            b"VP8L" => {
+                let b = data.get(21..25)?;
+                let width = 1 + (b[0] as u32 | (b[1] as u32 & 0x3F) << 8);
+                let height =
+                    1 + (b[1] as u32 >> 6 | (b[2] as u32) << 2 | (b[3] as u32 & 0x0F) << 10);
+                Some((width, height))
+            }
+            _ => None,
+        },
+        "image/jpeg" => {
+            // Walk the segments to the start-of-frame marker
+            let mut i = 2;
+            loop {
+                if *data.get(i)? != 0xFF {
+                    return None;
+                }
+                let marker = *data.get(i + 1)?;
+                match marker {
+                    0xFF => i += 1,
+                    0x01 | 0xD0..=0xD9 => i += 2,
+                    0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                        return Some((be16(i + 7)?, be16(i + 5)?));
+                    }
+                    _ => i += 2 + be16(i + 2)? as usize,
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A submitted filename made safe to store: directories stripped, control
+/// and reserved characters replaced, leading dots removed, Windows device
+/// names escaped and the length kept to 255 bytes with its extension
+pub fn sanitize_filename(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or("");
+    let replaced: String = base
+        .chars()
+        .map(|c| {
+            if c.is_control() || "<>:\"|?*".contains(c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let collapsed = replaced.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut name = collapsed
+        .trim_start_matches('.')
+        .trim_end_matches(['.', ' '])
+        .to_string();
+
+    let stem = name.split('.').next().unwrap_or("").to_ascii_uppercase();
+    let reserved = matches!(stem.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || (stem.len() == 4
+            && (stem.starts_with("COM") || stem.starts_with("LPT"))
+            && stem.ends_with(|c: char| c.is_ascii_digit()));
+    if reserved {
+        name.insert(0, '_');
+    }
+    if name.is_empty() {
+        return "file".to_string();
+    }
+
+    if name.len() > 255 {
+        let (stem, extension) = match name.rsplit_once('.') {
+            Some((stem, ext)) if ext.len() <= 16 => (stem, format!(".{}", ext)),
+            _ => (name.as_str(), String::new()),
+        };
+        let mut end = 255 - extension.len();
+        while !stem.is_char_boundary(end) {
+            end -= 1;
+        }
+        name = format!("{}{}", &stem[..end], extension);
+    }
+    name
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{} KB", bytes / 1024),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0).replace(".0 ", " "),
+    }
+}
+
+/// A validation rule for an uploaded file
+pub trait FileValidator: Send + Sync {
+    /// Validate a file and return an error if it is refused
+    fn validate(&self, file: &UploadedFile, field_name: &str) -> Option<ValidationError>;
+
+    /// Types for the input's `accept` attribute, empty when the rule
+    /// allows any type
+    fn accept(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Maximum file size validator
+#[derive(Debug, Clone)]
+pub struct MaxFileSize {
+    max: u64,
+    message: String,
+}
+
+impl MaxFileSize {
+    /// Refuse files larger than `max` bytes
+    pub fn new(max: u64) -> Self {
+        Self {
+            max,
+            message: format!("Files must be at most {}", format_size(max)),
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl FileValidator for MaxFileSize {
+    fn validate(&self, file: &UploadedFile, field_name: &str) -> Option<ValidationError> {
+        (file.size() > self.max)
+            .then(|| ValidationError::new(field_name, &self.message, "file_size"))
+    }
+}
+
+/// MIME allowlist validator, checked against the file's magic bytes
+#[derive(Debug, Clone)]
+pub struct AllowedTypes {
+    types: Vec<String>,
+    message: String,
+}
+
+impl AllowedTypes {
+    /// Allow these MIME types; `image/*` allows any recognised image
+    pub fn new<I, T>(types: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            types: types.into_iter().map(Into::into).collect(),
+            message: "This file type is not allowed".to_string(),
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn allows(&self, mime: &str) -> bool {
+        self.types
+            .iter()
+            .any(|allowed| match allowed.strip_suffix("/*") {
+                Some(family) => mime.split('/').next() == Some(family),
+                None => allowed == mime,
+            })
+    }
+}
+
+impl FileValidator for AllowedTypes {
+    fn validate(&self, file: &UploadedFile, field_name: &str) -> Option<ValidationError> {
+        let allowed = file.detected_type().is_some_and(|mime| self.allows(mime));
+        (!allowed).then(|| ValidationError::new(field_name, &self.message, "file_type"))
+    }
+
+    fn accept(&self) -> Vec<String> {
+        self.types.clone()
+    }
+}
+
+/// Image dimension limits validator
+#[derive(Debug, Clone, Default)]
+pub struct ImageDimensions {
+    min: Option<(u32, u32)>,
+    max: Option<(u32, u32)>,
+    message: Option<String>,
+}
+
+impl ImageDimensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuse images narrower or shorter than this
+    pub fn with_min(mut self, width: u32, height: u32) -> Self {
+        self.min = Some((width, height));
+        self
+    }
+
+    /// Refuse images wider or taller than this
+    pub fn with_max(mut self, width: u32, height: u32) -> Self {
+        self.max = Some((width, height));
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    fn error(&self, field_name: &str, default: String, code: &str) -> ValidationError {
+        ValidationError::new(field_name, self.message.clone().unwrap_or(default), code)
+    }
+}
+
+impl FileValidator for ImageDimensions {
+    fn validate(&self, file: &UploadedFile, field_name: &str) -> Option<ValidationError> {
+        let Some((width, height)) = file.image_dimensions() else {
+            return Some(self.error(field_name, "Upload an image".to_string(), "image"));
+        };
+        if let Some((min_w, min_h)) = self.min {
+            if width < min_w || height < min_h {
+                let message = format!("Images must be at least {}x{} pixels", min_w, min_h);
+                return Some(self.error(field_name, message, "image_min"));
+            }
+        }
+        if let Some((max_w, max_h)) = self.max {
+            if width > max_w || height > max_h {
+                let message = format!("Images must be at most {}x{} pixels", max_w, max_h);
+                return Some(self.error(field_name, message, "image_max"));
+            }
+        }
+        None
+    }
+
+    fn accept(&self) -> Vec<String> {
+        vec!["image/*".to_string()]
+    }
+}
+
+/// Rules for one file input
+pub struct FileValidation {
+    pub field_name: String,
+    pub validators: Vec<Box<dyn FileValidator>>,
+    /// At least one file must be chosen
+    pub required: bool,
+    /// Most files accepted from a `multiple` input
+    pub max_files: Option<usize>,
+}
+
+impl Clone for FileValidation {
+    fn clone(&self) -> Self {
+        // Clone without validators since Box<dyn FileValidator> can't be cloned
+        Self {
+            field_name: self.field_name.clone(),
+            validators: Vec::new(),
+            required: self.required,
+            max_files: self.max_files,
+        }
+    }
+}
+
+impl std::fmt::Debug for FileValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileValidation")
+            .field("field_name", &self.field_name)
+            .field(
+                "validators",
+                &format!("[{} validators]", self.validators.len()),
+            )
+            .field("required", &self.required)
+            .field("max_files", &self.max_files)
+            .finish()
+    }
+}
+
+impl FileValidation {
+    pub fn new(field_name: impl Into<String>) -> Self {
+        Self {
+            field_name: field_name.into(),
+            validators: Vec::new(),
+            required: false,
+            max_files: None,
+        }
+    }
+
+    /// Add a validator, applied to every file of the field
+    pub fn add_validator<V: FileValidator + 'static>(mut self, validator: V) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn max_files(mut self, max: usize) -> Self {
+        self.max_files = Some(max);
+        self
+    }
+
+    /// Check the files sent under this field
+    pub fn validate(&self, files: &[UploadedFile]) -> Vec<ValidationError> {
+        let files: Vec<_> = files
+            .iter()
+            .filter(|f| f.field == self.field_name)
+            .collect();
+        if files.is_empty() {
+            return if self.required {
+                vec![ValidationError::new(
+                    &self.field_name,
+                    "Choose a file to upload",
+                    "required",
+                )]
+            } else {
+                Vec::new()
+            };
+        }
+        if let Some(max) = self.max_files.filter(|max| files.len() > *max) {
+            return vec![ValidationError::new(
+                &self.field_name,
+                format!("Choose at most {} files", max),
+                "max_files",
+            )];
+        }
+        files
+            .iter()
+            .flat_map(|file| {
+                self.validators
+                    .iter()
+                    .filter_map(|v| v.validate(file, &self.field_name))
+            })
+            .collect()
+    }
+
+    /// Value for the input's `accept` attribute, so the file picker offers
+    /// the same types the server allows
+    pub fn accept(&self) -> Option<String> {
+        let types: Vec<String> = self.validators.iter().flat_map(|v| v.accept()).collect();
+        // Every rule applies, so exact types narrow a wildcard like `image/*`
+        let exact: Vec<String> = types
+            .iter()
+            .filter(|t| !t.ends_with("/*"))
+            .cloned()
+            .collect();
+        let types = if exact.is_empty() { types } else { exact };
+        (!types.is_empty()).then(|| types.join(","))
+    }
+}
+
+impl FormSchema {
+    /// Add rules for a file input, replacing earlier rules for the field
+    pub fn file_field(mut self, validation: FileValidation) -> Self {
+        match self
+            .files
+            .iter_mut()
+            .find(|f| f.field_name == validation.field_name)
+        {
+            Some(existing) => *existing = validation,
+            None => self.files.push(validation),
+        }
+        self
+    }
+
+    /// Check uploaded files against the file fields
+    pub fn validate_files(&self, files: &[UploadedFile]) -> Vec<ValidationError> {
+        self.files.iter().flat_map(|f| f.validate(files)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        data.extend(width.to_be_bytes());
+        data.extend(height.to_be_bytes());
+        data.extend([8, 6, 0, 0, 0]);
+        data
+    }
+
+    #[test]
+    fn test_sniff_and_dimensions() {
+        assert_eq!(sniff_mime(&png(1, 1)), Some("image/png"));
+        assert_eq!(image_dimensions(&png(640, 480)), Some((640, 480)));
+
+        let gif = b"GIF89a\x40\x01\xf0\x00".to_vec();
+        assert_eq!(image_dimensions(&gif), Some((320, 240)));
+
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46];
+        jpeg.extend([0xFF, 0xC0, 0x00, 0x11, 0x08, 0x02, 0x58, 0x03, 0x20, 0x03]);
+        assert_eq!(sniff_mime(&jpeg), Some("image/jpeg"));
+        assert_eq!(image_dimensions(&jpeg), Some((800, 600)));
+
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0".to_vec();
+        webp.extend([0x7F, 0x07, 0x00, 0x37, 0x04, 0x00]);
+        assert_eq!(image_dimensions(&webp), Some((1920, 1080)));
+
+        assert_eq!(sniff_mime(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(sniff_mime(b"<svg onload=alert(1)>"), None);
+        assert_eq!(image_dimensions(b"%PDF-1.7"), None);
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(
+            sanitize_filename(r"C:\Users\ada\My  Photo.JPG"),
+            "My Photo.JPG"
+        );
+        assert_eq!(sanitize_filename(".htaccess"), "htaccess");
+        assert_eq!(sanitize_filename("a<b>:c?.txt"), "a_b__c_.txt");
+        assert_eq!(sanitize_filename("con.txt"), "_con.txt");
+        assert_eq!(sanitize_filename("COM1"), "_COM1");
+        assert_eq!(sanitize_filename("..."), "file");
+        let long = format!("{}.png", "é".repeat(200));
+        let safe = sanitize_filename(&long);
+        assert!(safe.len() <= 255 && safe.ends_with("é.png"));
+    }
+
+    #[test]
+    fn test_parse_multipart() {
+        let body = b"preamble\r\n--XyZ\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nHello; world\r\n--XyZ\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"me;1.png\"\r\nContent-Type: image/png\r\n\r\n\x89PNG\r\n\x1a\n\r\n--XyZ\r\nContent-Disposition: form-data; name=\"extra\"; filename=\"\"\r\nContent-Type: application/octet-stream\r\n\r\n\r\n--XyZ--\r\n";
+        let data =
+            FormData::parse_multipart(body, "multipart/form-data; boundary=\"XyZ\"").unwrap();
+        assert_eq!(data.get("title"), Some("Hello; world"));
+        assert_eq!(data.files.len(), 1);
+        let file = &data.files[0];
+        assert_eq!(
+            (file.field.as_str(), file.filename.as_str()),
+            ("avatar", "me;1.png")
+        );
+        assert_eq!(file.content_type.as_deref(), Some("image/png"));
+        assert_eq!(file.data, b"\x89PNG\r\n\x1a\n");
+
+        assert_eq!(
+            FormData::parse_multipart(body, "multipart/form-data"),
+            Err(MultipartError::MissingBoundary)
+        );
+        assert_eq!(
+            FormData::parse_multipart(b"--XyZ\r\nbroken", "multipart/form-data; boundary=XyZ"),
+            Err(MultipartError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_file_validation() {
+        let schema = FormSchema::new().file_field(
+            FileValidation::new("photos")
+                .required()
+                .max_files(2)
+                .add_validator(MaxFileSize::new(2 * 1024 * 1024))
+                .add_validator(AllowedTypes::new(["image/png", "image/jpeg"]))
+                .add_validator(
+                    ImageDimensions::new()
+                        .with_min(100, 100)
+                        .with_max(4000, 4000),
+                ),
+        );
+        let photo = |name: &str, data: Vec<u8>| UploadedFile::new("photos", name, data);
+
+        assert!(schema
+            .validate_files(&[photo("a.png", png(800, 600))])
+            .is_empty());
+        assert_eq!(schema.validate_files(&[])[0].code, "required");
+
+        let renamed = photo("evil.png", b"<?php system($_GET['c']); ?>".to_vec());
+        let codes: Vec<_> = schema
+            .validate_files(&[renamed])
+            .into_iter()
+            .map(|e| e.code)
+            .collect();
+        assert_eq!(codes, ["file_type", "image"]);
+
+        let errors = schema.validate_files(&[photo("tiny.png", png(10, 10))]);
+        assert_eq!(errors[0].message, "Images must be at least 100x100 pixels");
+
+        let mut huge = png(800, 600);
+        huge.resize(3 * 1024 * 1024, 0);
+        assert_eq!(
+            schema.validate_files(&[photo("huge.png", huge)])[0].message,
+            "Files must be at most 2 MB"
+        );
+
+        let three = vec![photo("1.png", png(200, 200)); 3];
+        assert_eq!(schema.validate_files(&three)[0].code, "max_files");
+
+        let accept = FileValidation::new("photos")
+            .add_validator(AllowedTypes::new(["image/png", "image/jpeg"]))
+            .add_validator(ImageDimensions::new())
+            .accept();
+        assert_eq!(accept.as_deref(), Some("image/png,image/jpeg"));
+        assert_eq!(
+            FileValidation::new("any")
+                .add_validator(MaxFileSize::new(1))
+                .accept(),
+            None
+        );
+    }
+}
@@ -23,6 +23,7 @@
 //! }
 //! ```
 
+use crate::phone_input::PhoneNumber;
 use std::marker::PhantomData;
 
 /// Form field states (phantom types)
@@ -48,6 +49,10 @@ pub enum ValidationError {
     Url,
     Numeric,
     Integer,
+    Phone,
+    TooManyTags(usize),
+    /// A tag used characters outside letters, digits and the listed extras
+    TagCharset(String),
     Custom(String),
 }
 
@@ -64,6 +69,16 @@ impl std::fmt::Display for ValidationError {
             Self::Url => write!(f, "Please enter a valid URL"),
             Self::Numeric => write!(f, "Must be a number"),
             Self::Integer => write!(f, "Must be a whole number"),
+            Self::Phone => write!(f, "Please enter a valid phone number"),
+            Self::TooManyTags(n) => write!(f, "Use at most {} tags", n),
+            Self::TagCharset(extra) if extra.is_empty() => {
+                write!(f, "Tags may only contain letters and numbers")
+            }
+            Self::TagCharset(extra) => write!(
+                f,
+                "Tags may only contain letters, numbers and {}",
+                extra.chars().map(String::from).collect::<Vec<_>>().join(" ")
+            ),
             Self::Custom(msg) => write!(f, "{}", msg),
         }
     }
@@ -84,6 +99,14 @@ pub enum ValidationRule {
     Numeric,
     Integer,
     OneOf(Vec<String>),
+    /// Phone number, read as national to the given ISO country code unless
+    /// it starts with `+` or `00`
+    Phone(String),
+    /// At most this many tags in a comma-separated list
+    MaxTags(usize),
+    /// Each comma-separated tag uses only letters, digits, spaces and these
+    /// extra characters
+    TagCharset(String),
     Custom(fn(&str) -> Option<String>),
 }
 
@@ -164,6 +187,28 @@ impl ValidationRule {
                     Err(ValidationError::Custom("Invalid selection".to_string()))
                 }
             }
+            Self::Phone(country) => {
+                if value.is_empty() || PhoneNumber::parse(value, country).is_ok() {
+                    Ok(())
+                } else {
+                    Err(ValidationError::Phone)
+                }
+            }
+            Self::MaxTags(max) => {
+                if split_tags(value).count() <= *max {
+                    Ok(())
+                } else {
+                    Err(ValidationError::TooManyTags(*max))
+                }
+            }
+            Self::TagCharset(extra) => {
+                let allowed = |c: char| c.is_alphanumeric() || c == ' ' || extra.contains(c);
+                if split_tags(value).all(|tag| tag.chars().all(allowed)) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::TagCharset(extra.clone()))
+                }
+            }
             Self::Custom(validator) => match validator(value) {
                 None => Ok(()),
                 Some(msg) => Err(ValidationError::Custom(msg)),
@@ -172,6 +217,11 @@ impl ValidationRule {
     }
 }
 
+/// Non-empty, trimmed entries of a comma-separated tag list
+pub fn split_tags(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|tag| !tag.is_empty())
+}
+
 /// A form field with compile-time state tracking
 #[derive(Debug, Clone)]
 pub struct FormField<State = Pristine> {
@@ -434,6 +484,22 @@ pub mod presets {
     pub fn integer() -> Vec<ValidationRule> {
         vec![ValidationRule::Required, ValidationRule::Integer]
     }
+
+    /// Tag list validation: a maximum count and slug-like characters
+    pub fn tags(max: usize) -> Vec<ValidationRule> {
+        vec![
+            ValidationRule::MaxTags(max),
+            ValidationRule::TagCharset("-_".to_string()),
+        ]
+    }
+
+    /// Phone number validation for a default country
+    pub fn phone(country: &str) -> Vec<ValidationRule> {
+        vec![
+            ValidationRule::Required,
+            ValidationRule::Phone(country.to_string()),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -456,6 +522,36 @@ mod tests {
         assert!(rule.validate("abcdef").is_ok());
     }
 
+    #[test]
+    fn test_phone_validation() {
+        let rule = ValidationRule::Phone("GB".to_string());
+        assert!(rule.validate("07400 123456").is_ok());
+        assert!(rule.validate("+1 (201) 555-0123").is_ok());
+        assert!(rule.validate("").is_ok());
+        assert_eq!(rule.validate("0740"), Err(ValidationError::Phone));
+        assert_eq!(presets::phone("US").len(), 2);
+    }
+
+    #[test]
+    fn test_tag_validation() {
+        let rule = ValidationRule::MaxTags(2);
+        assert!(rule.validate("rust, web").is_ok());
+        assert!(rule.validate("rust, , web,").is_ok());
+        assert_eq!(rule.validate("a,b,c"), Err(ValidationError::TooManyTags(2)));
+
+        let rule = ValidationRule::TagCharset("-".to_string());
+        assert!(rule.validate("web-dev, open source").is_ok());
+        assert_eq!(
+            rule.validate("c++"),
+            Err(ValidationError::TagCharset("-".to_string()))
+        );
+        assert_eq!(
+            ValidationError::TagCharset("-_".to_string()).to_string(),
+            "Tags may only contain letters, numbers and - _"
+        );
+        assert_eq!(presets::tags(5).len(), 2);
+    }
+
     #[test]
     fn test_email_validation() {
         let rule = ValidationRule::Email;
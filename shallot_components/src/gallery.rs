@@ -0,0 +1,739 @@
+//! Gallery - Styleguide site generator for design systems built on shallot
+//!
+//! Register each component with one or more example builders and the gallery
+//! writes a browsable static site: an index grouped by category and one page
+//! per component with live previews and code samples. Code can be given per
+//! example or pulled from the fenced Rust blocks of a doc comment with
+//! [`GalleryEntry::docs`].
+//!
+//! ```ignore
+//! Gallery::new("Acme UI")
+//!     .category(GalleryCategory::new("forms", "Forms"))
+//!     .entry(
+//!         GalleryEntry::new("Button", "forms")
+//!             .description("Primary call to action")
+//!             .docs(include_str!("button.rs"))
+//!             .example("Primary", || html! { (Button::new("Save")) }),
+//!     )
+//!     .css(all_component_css())
+//!     .write_to("target/styleguide")?;
+//! ```
+
+use crate::code_block::{CodeBlock, Language};
+use crate::seo::{write_files, SeoFile};
+use maud::{html, Markup, DOCTYPE};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Fenced-block info strings rustdoc treats as Rust
+const RUST_FENCE_ATTRS: &[&str] = &[
+    "rust",
+    "ignore",
+    "no_run",
+    "should_panic",
+    "compile_fail",
+    "edition2018",
+    "edition2021",
+];
+
+/// Fenced Rust code blocks in a doc comment or Markdown text.
+///
+/// `//!` and `///` prefixes are stripped, so a whole source file can be
+/// passed in. Lines hidden from rustdoc output (`# ...`) are dropped.
+pub fn extract_code_examples(doc: &str) -> Vec<String> {
+    enum Fence<'d> {
+        Outside,
+        Rust(Vec<&'d str>),
+        Other,
+    }
+
+    let mut examples = Vec::new();
+    let mut fence = Fence::Outside;
+
+    for line in doc.lines() {
+        let trimmed = line.trim_start();
+        let text = match trimmed
+            .strip_prefix("//!")
+            .or_else(|| trimmed.strip_prefix("///"))
+        {
+            Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+            // Outside doc comments only Markdown input has fences
+            None => line,
+        };
+
+        if let Some(info) = text.trim_start().strip_prefix("```") {
+            fence = match fence {
+                Fence::Outside => {
+                    let info = info.trim();
+                    let is_rust = info.is_empty()
+                        || info
+                            .split(',')
+                            .all(|attr| RUST_FENCE_ATTRS.contains(&attr.trim()));
+                    if is_rust {
+                        Fence::Rust(Vec::new())
+                    } else {
+                        Fence::Other
+                    }
+                }
+                Fence::Rust(lines) => {
+                    if lines.iter().any(|line| !line.trim().is_empty()) {
+                        examples.push(lines.join("\n"));
+                    }
+                    Fence::Outside
+                }
+                Fence::Other => Fence::Outside,
+            };
+            continue;
+        }
+
+        if let Fence::Rust(lines) = &mut fence {
+            let code = text.trim_start();
+            if code != "#" && !code.starts_with("# ") {
+                lines.push(text);
+            }
+        }
+    }
+    examples
+}
+
+fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "component".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Group of components in the sidebar and on the index page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GalleryCategory {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl GalleryCategory {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// One live preview of a component
+#[derive(Debug, Clone)]
+pub struct GalleryExample {
+    pub title: String,
+    pub render: fn() -> Markup,
+    pub code: Option<String>,
+}
+
+/// A registered component
+#[derive(Debug, Clone)]
+pub struct GalleryEntry {
+    pub name: String,
+    pub category: String,
+    pub description: Option<String>,
+    pub examples: Vec<GalleryExample>,
+    /// Code blocks from [`GalleryEntry::docs`], paired with examples in order
+    pub doc_examples: Vec<String>,
+}
+
+impl GalleryEntry {
+    /// `category` is the id of a [`GalleryCategory`]
+    pub fn new(name: impl Into<String>, category: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            category: category.into(),
+            description: None,
+            examples: Vec::new(),
+            doc_examples: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a preview; its code comes from the matching doc example, if any
+    pub fn example(mut self, title: impl Into<String>, render: fn() -> Markup) -> Self {
+        self.examples.push(GalleryExample {
+            title: title.into(),
+            render,
+            code: None,
+        });
+        self
+    }
+
+    /// Add a preview with an explicit code sample
+    pub fn example_with_code(
+        mut self,
+        title: impl Into<String>,
+        render: fn() -> Markup,
+        code: impl Into<String>,
+    ) -> Self {
+        self.examples.push(GalleryExample {
+            title: title.into(),
+            render,
+            code: Some(code.into()),
+        });
+        self
+    }
+
+    /// Take code samples from the doc comments in `source`, e.g.
+    /// `include_str!("button.rs")`
+    pub fn docs(mut self, source: &str) -> Self {
+        self.doc_examples = extract_code_examples(source);
+        self
+    }
+
+    /// File name stem of the component page
+    pub fn slug(&self) -> String {
+        slug(&self.name)
+    }
+
+    /// Code shown under example `index`
+    pub fn code_for(&self, index: usize) -> Option<&str> {
+        self.examples
+            .get(index)
+            .and_then(|example| example.code.as_deref())
+            .or_else(|| self.doc_examples.get(index).map(String::as_str))
+    }
+}
+
+/// Styleguide site for a set of components
+#[derive(Debug, Clone)]
+pub struct Gallery {
+    pub title: String,
+    pub description: Option<String>,
+    pub categories: Vec<GalleryCategory>,
+    pub entries: Vec<GalleryEntry>,
+    pub stylesheets: Vec<String>,
+    pub css: String,
+}
+
+impl Gallery {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            categories: Vec::new(),
+            entries: Vec::new(),
+            stylesheets: Vec::new(),
+            css: String::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Categories are listed in the order they are added
+    pub fn category(mut self, category: GalleryCategory) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    pub fn entry(mut self, entry: GalleryEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Link an extra stylesheet; relative paths are resolved from the
+    /// gallery root
+    pub fn stylesheet(mut self, href: impl Into<String>) -> Self {
+        self.stylesheets.push(href.into());
+        self
+    }
+
+    /// CSS appended to `gallery.css`, usually the design system's
+    /// component styles
+    pub fn css(mut self, css: impl Into<String>) -> Self {
+        self.css.push_str(&css.into());
+        self
+    }
+
+    /// Categories with their entries; entries naming an unregistered
+    /// category are grouped under that id
+    pub fn groups(&self) -> Vec<(GalleryCategory, Vec<&GalleryEntry>)> {
+        let mut groups: Vec<(GalleryCategory, Vec<&GalleryEntry>)> = self
+            .categories
+            .iter()
+            .map(|category| (category.clone(), Vec::new()))
+            .collect();
+        for entry in &self.entries {
+            match groups.iter_mut().find(|(c, _)| c.id == entry.category) {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((
+                    GalleryCategory::new(entry.category.clone(), entry.category.clone()),
+                    vec![entry],
+                )),
+            }
+        }
+        groups.retain(|(_, entries)| !entries.is_empty());
+        groups
+    }
+
+    /// Index page listing every component by category
+    pub fn index_page(&self) -> Markup {
+        let body = html! {
+            header class="sh-gallery__intro" {
+                h1 class="sh-gallery__title" { (self.title) }
+                @if let Some(description) = &self.description {
+                    p class="sh-gallery__lead" { (description) }
+                }
+            }
+            @for (category, entries) in self.groups() {
+                section class="sh-gallery__category" id=(format!("category-{}", slug(&category.id))) {
+                    h2 { (category.name) }
+                    @if let Some(description) = &category.description {
+                        p class="sh-gallery__muted" { (description) }
+                    }
+                    ul class="sh-gallery__cards" {
+                        @for entry in entries {
+                            li class="sh-gallery__card" {
+                                @if let Some(example) = entry.examples.first() {
+                                    div class="sh-gallery__thumb" aria-hidden="true" inert { ((example.render)()) }
+                                }
+                                a class="sh-gallery__card-link" href=(format!("components/{}.html", entry.slug())) {
+                                    (entry.name)
+                                }
+                                @if let Some(description) = &entry.description {
+                                    p class="sh-gallery__muted" { (description) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        };
+        self.layout(&self.title, "", None, body)
+    }
+
+    /// Page for one component with every example and its code
+    pub fn component_page(&self, entry: &GalleryEntry) -> Markup {
+        let slug = entry.slug();
+        let body = html! {
+            header class="sh-gallery__intro" {
+                h1 class="sh-gallery__title" { (entry.name) }
+                @if let Some(description) = &entry.description {
+                    p class="sh-gallery__lead" { (description) }
+                }
+            }
+            @for (i, example) in entry.examples.iter().enumerate() {
+                section class="sh-gallery__example" id=(format!("{}-{}", slug, slug_or_index(&example.title, i))) {
+                    h2 class="sh-gallery__example-title" { (example.title) }
+                    div class="sh-gallery__preview" { ((example.render)()) }
+                    @if let Some(code) = entry.code_for(i) {
+                        (CodeBlock::new(code).language(Language::Rust).show_copy_button(false).render())
+                    }
+                }
+            }
+        };
+        self.layout(
+            &format!("{} · {}", entry.name, self.title),
+            "../",
+            Some(&slug),
+            body,
+        )
+    }
+
+    /// Every page plus `gallery.css`, relative to the output directory
+    pub fn files(&self) -> Vec<SeoFile> {
+        let mut files = vec![
+            SeoFile {
+                name: "index.html".to_string(),
+                contents: self.index_page().into_string(),
+            },
+            SeoFile {
+                name: "gallery.css".to_string(),
+                contents: format!("{}{}", gallery_css(), self.css),
+            },
+        ];
+        for entry in &self.entries {
+            files.push(SeoFile {
+                name: format!("components/{}.html", entry.slug()),
+                contents: self.component_page(entry).into_string(),
+            });
+        }
+        files
+    }
+
+    /// Write [`Gallery::files`] into `dir`, returning the written paths
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        write_files(dir.as_ref(), self.files())
+    }
+
+    fn nav(&self, root: &str, current: Option<&str>) -> Markup {
+        html! {
+            nav class="sh-gallery__nav" aria-label="Components" {
+                a class="sh-gallery__home" href=(format!("{}index.html", root)) { (self.title) }
+                @for (category, entries) in self.groups() {
+                    p class="sh-gallery__nav-heading" { (category.name) }
+                    ul class="sh-gallery__nav-list" {
+                        @for entry in entries {
+                            @let slug = entry.slug();
+                            li {
+                                a
+                                    class="sh-gallery__nav-link"
+                                    href=(format!("{}components/{}.html", root, slug))
+                                    aria-current=[(current == Some(slug.as_str())).then_some("page")]
+                                { (entry.name) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn layout(&self, title: &str, root: &str, current: Option<&str>, body: Markup) -> Markup {
+        html! {
+            (DOCTYPE)
+            html lang="en" {
+                head {
+                    meta charset="utf-8";
+                    meta name="viewport" content="width=device-width, initial-scale=1";
+                    title { (title) }
+                    link rel="stylesheet" href=(format!("{}gallery.css", root));
+                    @for href in &self.stylesheets {
+                        link rel="stylesheet" href=(resolve_href(root, href));
+                    }
+                }
+                body class="sh-gallery" {
+                    (self.nav(root, current))
+                    main class="sh-gallery__main" { (body) }
+                }
+            }
+        }
+    }
+}
+
+fn slug_or_index(title: &str, index: usize) -> String {
+    match slug(title).as_str() {
+        "component" => format!("example-{}", index + 1),
+        s => s.to_string(),
+    }
+}
+
+/// Prefix relative links with the path back to the gallery root
+fn resolve_href(root: &str, href: &str) -> String {
+    if href.starts_with('/') || href.contains("://") {
+        href.to_string()
+    } else {
+        format!("{}{}", root, href)
+    }
+}
+
+/// Generate CSS for the gallery pages
+pub fn gallery_css() -> String {
+    r#"
+.sh-gallery {
+    display: grid;
+    grid-template-columns: 15rem minmax(0, 1fr);
+    min-height: 100vh;
+    margin: 0;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+    background: var(--sh-surface, #fff);
+}
+
+.sh-gallery__nav {
+    position: sticky;
+    top: 0;
+    height: 100vh;
+    overflow-y: auto;
+    padding: 1.5rem 1rem;
+    border-right: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-gallery__home {
+    display: block;
+    margin-bottom: 1rem;
+    font-weight: 700;
+    color: inherit;
+    text-decoration: none;
+}
+
+.sh-gallery__nav-heading {
+    margin: 1rem 0 0.25rem;
+    font-size: 0.75rem;
+    font-weight: 600;
+    letter-spacing: 0.04em;
+    text-transform: uppercase;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-gallery__nav-list {
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-gallery__nav-link {
+    display: block;
+    padding: 0.25rem 0.5rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font-size: 0.875rem;
+    color: inherit;
+    text-decoration: none;
+}
+
+.sh-gallery__nav-link:hover,
+.sh-gallery__nav-link[aria-current="page"] {
+    background: color-mix(in srgb, var(--sh-primary, #3b82f6) 10%, transparent);
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-gallery__main {
+    max-width: 64rem;
+    padding: 2rem;
+}
+
+.sh-gallery__title {
+    margin: 0 0 0.5rem;
+}
+
+.sh-gallery__lead {
+    margin: 0 0 2rem;
+    font-size: 1.125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-gallery__muted {
+    margin: 0.25rem 0 0;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-gallery__category {
+    margin-bottom: 2.5rem;
+}
+
+.sh-gallery__cards {
+    display: grid;
+    grid-template-columns: repeat(auto-fill, minmax(14rem, 1fr));
+    gap: 1rem;
+    margin: 1rem 0 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-gallery__card {
+    position: relative;
+    padding: 1rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.75rem);
+}
+
+.sh-gallery__card:hover {
+    border-color: var(--sh-primary, #3b82f6);
+}
+
+.sh-gallery__thumb {
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    height: 7rem;
+    margin-bottom: 0.75rem;
+    overflow: hidden;
+    pointer-events: none;
+}
+
+.sh-gallery__card-link {
+    font-weight: 600;
+    color: inherit;
+    text-decoration: none;
+}
+
+/* The whole card is the link target */
+.sh-gallery__card-link::after {
+    content: "";
+    position: absolute;
+    inset: 0;
+}
+
+.sh-gallery__example {
+    margin-bottom: 2.5rem;
+}
+
+.sh-gallery__example-title {
+    font-size: 1.125rem;
+}
+
+.sh-gallery__preview {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 1rem;
+    padding: 2rem;
+    margin-bottom: 1rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.75rem);
+}
+
+@media (max-width: 48rem) {
+    .sh-gallery {
+        grid-template-columns: 1fr;
+    }
+
+    .sh-gallery__nav {
+        position: static;
+        height: auto;
+        border-right: 0;
+        border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"//! Button
+//!
+//! ```
+//! # use demo::Button;
+//! let button = Button::new("Save");
+//! ```
+//!
+//! ```html
+//! <button>Save</button>
+//! ```
+//!
+//! ```rust,no_run
+//! Button::new("Delete").danger()
+//! ```
+pub struct Button;
+"#;
+
+    fn button() -> Markup {
+        html! { button class="demo" { "Save" } }
+    }
+
+    fn gallery() -> Gallery {
+        Gallery::new("Acme UI")
+            .description("Components for Acme")
+            .category(GalleryCategory::new("forms", "Forms").description("Inputs and buttons"))
+            .entry(
+                GalleryEntry::new("Button", "forms")
+                    .description("Call to action")
+                    .docs(SOURCE)
+                    .example("Primary", button)
+                    .example("Danger", button)
+                    .example_with_code("Ghost", button, "Button::new(\"Ghost\")"),
+            )
+            .entry(GalleryEntry::new("Data Table", "data").example("Basic", button))
+            .stylesheet("styles/acme.css")
+    }
+
+    #[test]
+    fn test_extract_code_examples() {
+        assert_eq!(
+            extract_code_examples(SOURCE),
+            vec![
+                "let button = Button::new(\"Save\");".to_string(),
+                "Button::new(\"Delete\").danger()".to_string(),
+            ]
+        );
+        assert_eq!(
+            extract_code_examples("Intro\n\n```\nlet x = 1;\n    #[derive(Debug)]\n```\n"),
+            vec!["let x = 1;\n    #[derive(Debug)]".to_string()]
+        );
+        assert!(extract_code_examples("no code here").is_empty());
+    }
+
+    #[test]
+    fn test_gallery_groups() {
+        let gallery = gallery();
+        let groups = gallery.groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0.name, "Forms");
+        // Unregistered categories are kept under their id
+        assert_eq!(groups[1].0.id, "data");
+        assert_eq!(groups[1].1[0].slug(), "data-table");
+    }
+
+    #[test]
+    fn test_gallery_entry_code() {
+        let gallery = gallery();
+        let entry = &gallery.entries[0];
+        assert_eq!(
+            entry.code_for(0),
+            Some("let button = Button::new(\"Save\");")
+        );
+        assert_eq!(entry.code_for(1), Some("Button::new(\"Delete\").danger()"));
+        assert_eq!(entry.code_for(2), Some("Button::new(\"Ghost\")"));
+        assert_eq!(entry.code_for(3), None);
+    }
+
+    #[test]
+    fn test_gallery_index_page() {
+        let html = gallery().index_page().into_string();
+        assert!(html.contains(r#"<link rel="stylesheet" href="gallery.css">"#));
+        assert!(html.contains(r#"<link rel="stylesheet" href="styles/acme.css">"#));
+        assert!(html.contains(r#"id="category-forms""#));
+        assert!(html.contains(r#"href="components/button.html">Button</a>"#));
+        assert!(html.contains(
+            r#"<div class="sh-gallery__thumb" aria-hidden="true" inert><button class="demo">"#
+        ));
+        assert!(html.contains("Inputs and buttons"));
+    }
+
+    #[test]
+    fn test_gallery_component_page() {
+        let gallery = gallery();
+        let html = gallery.component_page(&gallery.entries[0]).into_string();
+        assert!(html.contains("<title>Button · Acme UI</title>"));
+        assert!(html.contains(r#"href="../styles/acme.css""#));
+        assert!(html.contains(r#"href="../components/button.html" aria-current="page""#));
+        assert!(html.contains(r#"id="button-danger""#));
+        assert_eq!(
+            html.matches(r#"<div class="sh-gallery__preview">"#).count(),
+            3
+        );
+        assert!(html.contains("danger()"));
+        assert!(html.contains("sh-code-block"));
+    }
+
+    #[test]
+    fn test_gallery_files() {
+        let files = gallery().files();
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "index.html",
+                "gallery.css",
+                "components/button.html",
+                "components/data-table.html"
+            ]
+        );
+
+        let dir = std::env::temp_dir().join(format!("shallot-gallery-{}", std::process::id()));
+        let written = gallery().write_to(&dir).unwrap();
+        assert_eq!(written.len(), 4);
+        assert!(dir.join("components/button.html").exists());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,990 @@
+//! Gantt Chart - Task schedules and resource timelines
+//!
+//! Tasks with start/end dates, progress and dependencies drawn as SVG rows
+//! on a shared time axis, with a today marker and a configurable scale.
+//! Groups collapse through native `<details>`, so the chart stays zero-JS.
+//! Each block of rows is its own SVG so collapsing never misaligns a drawn
+//! arrow; dependencies across blocks are listed in the bar's tooltip.
+
+use crate::charts::ChartColor;
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+const LABEL_WIDTH: f64 = 180.0;
+const LABEL_CHARS: usize = 24;
+const ROW_HEIGHT: f64 = 32.0;
+const BAR_HEIGHT: f64 = 18.0;
+const AXIS_HEIGHT: f64 = 44.0;
+
+fn num(value: f64) -> String {
+    let s = format!("{:.1}", value);
+    match s.strip_suffix(".0") {
+        Some(whole) => whole.to_string(),
+        None => s,
+    }
+}
+
+/// Calendar unit used for axis ticks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+    fn floor(&self, date: CalendarDate) -> CalendarDate {
+        match self {
+            Unit::Day => date,
+            Unit::Week => date.add_days(-date.weekday()),
+            Unit::Month => CalendarDate::new(date.year, date.month, 1),
+            Unit::Quarter => CalendarDate::new(date.year, (date.month - 1) / 3 * 3 + 1, 1),
+            Unit::Year => CalendarDate::new(date.year, 1, 1),
+        }
+    }
+
+    /// Start of the unit after the one containing `date`
+    fn next(&self, date: CalendarDate) -> CalendarDate {
+        let start = self.floor(date);
+        let months = match self {
+            Unit::Day => return start.add_days(1),
+            Unit::Week => return start.add_days(7),
+            Unit::Month => 1,
+            Unit::Quarter => 3,
+            Unit::Year => 12,
+        };
+        let index = start.month - 1 + months;
+        CalendarDate::new(start.year + (index / 12) as i32, index % 12 + 1, 1)
+    }
+
+    fn label(&self, date: CalendarDate) -> String {
+        let month = date.month_abbreviation();
+        match self {
+            Unit::Day => date.day.to_string(),
+            Unit::Week => format!("{} {}", month, date.day),
+            Unit::Month => month.to_string(),
+            Unit::Quarter => format!("Q{}", (date.month - 1) / 3 + 1),
+            Unit::Year => date.year.to_string(),
+        }
+    }
+}
+
+/// Axis resolution: how wide a day is and which ticks are labelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeScale {
+    Day,
+    #[default]
+    Week,
+    Month,
+    Quarter,
+}
+
+impl TimeScale {
+    fn day_width(&self) -> f64 {
+        match self {
+            TimeScale::Day => 32.0,
+            TimeScale::Week => 14.0,
+            TimeScale::Month => 4.0,
+            TimeScale::Quarter => 1.6,
+        }
+    }
+
+    /// (upper tier, lower tier) of the axis header
+    fn units(&self) -> (Unit, Unit) {
+        match self {
+            TimeScale::Day => (Unit::Month, Unit::Day),
+            TimeScale::Week => (Unit::Month, Unit::Week),
+            TimeScale::Month => (Unit::Year, Unit::Month),
+            TimeScale::Quarter => (Unit::Year, Unit::Quarter),
+        }
+    }
+}
+
+/// Row layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GanttView {
+    /// One row per task, grouped by [`GanttTask::group`]
+    #[default]
+    Tasks,
+    /// One row per resource holding all of its tasks
+    Resources,
+}
+
+/// Scheduled task or milestone
+#[derive(Debug, Clone, PartialEq)]
+pub struct GanttTask<'a> {
+    pub id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub start: CalendarDate,
+    /// Last day of the task, inclusive
+    pub end: CalendarDate,
+    /// Completed fraction, 0.0 to 1.0
+    pub progress: f32,
+    pub dependencies: Vec<Cow<'a, str>>,
+    pub group: Option<Cow<'a, str>>,
+    pub resource: Option<Cow<'a, str>>,
+    pub milestone: bool,
+    pub color: Option<ChartColor>,
+}
+
+impl<'a> GanttTask<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+        start: CalendarDate,
+        end: CalendarDate,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            start,
+            end: end.max(start),
+            progress: 0.0,
+            dependencies: Vec::new(),
+            group: None,
+            resource: None,
+            milestone: false,
+            color: None,
+        }
+    }
+
+    /// Zero-length marker drawn as a diamond
+    pub fn milestone(
+        id: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+        date: CalendarDate,
+    ) -> Self {
+        Self {
+            milestone: true,
+            ..Self::new(id, name, date, date)
+        }
+    }
+
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = progress.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Finish-to-start dependency on another task's id
+    pub fn depends_on(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.dependencies.push(id.into());
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<Cow<'a, str>>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Person or team assigned, used by [`GanttView::Resources`]
+    pub fn resource(mut self, resource: impl Into<Cow<'a, str>>) -> Self {
+        self.resource = Some(resource.into());
+        self
+    }
+
+    pub fn color(mut self, color: ChartColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn days(&self) -> i64 {
+        self.end.days_since_epoch() - self.start.days_since_epoch() + 1
+    }
+
+    fn tooltip(&self, predecessors: &[&str]) -> String {
+        let mut text = if self.milestone {
+            format!("{}: {}", self.name, self.start)
+        } else {
+            format!(
+                "{}: {} – {} ({:.0}%)",
+                self.name,
+                self.start,
+                self.end,
+                self.progress * 100.0
+            )
+        };
+        if !predecessors.is_empty() {
+            text.push_str(&format!("\nAfter: {}", predecessors.join(", ")));
+        }
+        text
+    }
+}
+
+/// Maps days onto x coordinates
+struct Frame {
+    start: CalendarDate,
+    end: CalendarDate,
+    day_width: f64,
+    today: Option<i64>,
+}
+
+impl Frame {
+    fn days(&self) -> i64 {
+        self.end.days_since_epoch() - self.start.days_since_epoch()
+    }
+
+    fn x(&self, date: CalendarDate) -> f64 {
+        self.x_day(date.days_since_epoch())
+    }
+
+    fn x_day(&self, day: i64) -> f64 {
+        LABEL_WIDTH + (day - self.start.days_since_epoch()) as f64 * self.day_width
+    }
+
+    fn width(&self) -> f64 {
+        LABEL_WIDTH + self.days() as f64 * self.day_width
+    }
+
+    /// Unit starts within the frame, paired with the following boundary
+    fn ticks(&self, unit: Unit) -> Vec<(CalendarDate, CalendarDate)> {
+        let mut ticks = Vec::new();
+        let mut at = unit.floor(self.start);
+        while at < self.end {
+            let next = unit.next(at);
+            ticks.push((at.max(self.start), next.min(self.end)));
+            at = next;
+        }
+        ticks
+    }
+}
+
+struct Row<'t, 'a> {
+    label: Cow<'t, str>,
+    tasks: Vec<&'t GanttTask<'a>>,
+}
+
+struct Block<'t, 'a> {
+    group: Option<&'t str>,
+    rows: Vec<Row<'t, 'a>>,
+}
+
+fn truncate(label: &str) -> Cow<'_, str> {
+    if label.chars().count() <= LABEL_CHARS {
+        Cow::Borrowed(label)
+    } else {
+        let cut: String = label.chars().take(LABEL_CHARS - 1).collect();
+        Cow::Owned(format!("{}…", cut))
+    }
+}
+
+/// Gantt chart / resource timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct GanttChart<'a> {
+    pub tasks: Vec<GanttTask<'a>>,
+    pub scale: TimeScale,
+    pub view: GanttView,
+    pub today: Option<CalendarDate>,
+    pub show_today: bool,
+    pub range: Option<(CalendarDate, CalendarDate)>,
+    pub collapsed: Vec<Cow<'a, str>>,
+    pub id: Cow<'a, str>,
+    pub label: Option<Cow<'a, str>>,
+    pub custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> GanttChart<'a> {
+    pub fn new(tasks: Vec<GanttTask<'a>>) -> Self {
+        Self {
+            tasks,
+            scale: TimeScale::default(),
+            view: GanttView::default(),
+            today: None,
+            show_today: true,
+            range: None,
+            collapsed: Vec::new(),
+            id: Cow::Borrowed("sh-gantt"),
+            label: None,
+            custom_class: None,
+        }
+    }
+
+    pub fn task(mut self, task: GanttTask<'a>) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    pub fn scale(mut self, scale: TimeScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Switch to one row per resource
+    pub fn by_resource(mut self) -> Self {
+        self.view = GanttView::Resources;
+        self
+    }
+
+    /// Date of the today marker (defaults to the build date)
+    pub fn today(mut self, today: CalendarDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    pub fn show_today(mut self, show: bool) -> Self {
+        self.show_today = show;
+        self
+    }
+
+    /// Fix the visible range instead of fitting it to the tasks
+    pub fn range(mut self, start: CalendarDate, end: CalendarDate) -> Self {
+        self.range = Some((start, end.max(start)));
+        self
+    }
+
+    /// Render a group closed
+    pub fn collapsed(mut self, group: impl Into<Cow<'a, str>>) -> Self {
+        self.collapsed.push(group.into());
+        self
+    }
+
+    /// Prefix for the arrow marker id; unique per page
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn frame(&self) -> Frame {
+        let (_, minor) = self.scale.units();
+        let (first, last) = self.range.unwrap_or_else(|| {
+            let first = self.tasks.iter().map(|t| t.start).min();
+            let last = self.tasks.iter().map(|t| t.end).max();
+            let today = CalendarDate::today();
+            (first.unwrap_or(today), last.unwrap_or(today))
+        });
+        let today = self.show_today.then(|| {
+            self.today
+                .unwrap_or_else(CalendarDate::today)
+                .days_since_epoch()
+        });
+        Frame {
+            start: minor.floor(first),
+            end: minor.next(last),
+            day_width: self.scale.day_width(),
+            today,
+        }
+    }
+
+    fn blocks(&self) -> Vec<Block<'_, 'a>> {
+        match self.view {
+            GanttView::Tasks => {
+                let mut blocks = vec![Block {
+                    group: None,
+                    rows: Vec::new(),
+                }];
+                for task in &self.tasks {
+                    let group = task.group.as_deref();
+                    let index = match blocks.iter().position(|b| b.group == group) {
+                        Some(index) => index,
+                        None => {
+                            blocks.push(Block {
+                                group,
+                                rows: Vec::new(),
+                            });
+                            blocks.len() - 1
+                        }
+                    };
+                    blocks[index].rows.push(Row {
+                        label: Cow::Borrowed(&task.name),
+                        tasks: vec![task],
+                    });
+                }
+                blocks.retain(|b| !b.rows.is_empty());
+                blocks
+            }
+            GanttView::Resources => {
+                let mut rows: Vec<Row<'_, 'a>> = Vec::new();
+                let mut unassigned = Vec::new();
+                for task in &self.tasks {
+                    let Some(resource) = task.resource.as_deref() else {
+                        unassigned.push(task);
+                        continue;
+                    };
+                    match rows.iter_mut().find(|r| r.label == resource) {
+                        Some(row) => row.tasks.push(task),
+                        None => rows.push(Row {
+                            label: Cow::Borrowed(resource),
+                            tasks: vec![task],
+                        }),
+                    }
+                }
+                if !unassigned.is_empty() {
+                    rows.push(Row {
+                        label: Cow::Borrowed("Unassigned"),
+                        tasks: unassigned,
+                    });
+                }
+                vec![Block { group: None, rows }]
+            }
+        }
+    }
+
+    fn axis(&self, frame: &Frame) -> Markup {
+        let (major, minor) = self.scale.units();
+        let width = frame.width();
+        let corner = match self.view {
+            GanttView::Tasks => "Task",
+            GanttView::Resources => "Resource",
+        };
+        html! {
+            svg
+                class="sh-gantt__axis"
+                width=(num(width))
+                height=(num(AXIS_HEIGHT))
+                viewBox={ "0 0 " (num(width)) " " (num(AXIS_HEIGHT)) }
+                aria-hidden="true"
+            {
+                text class="sh-gantt__corner" x="8" y="36" { (corner) }
+                @for (start, _) in frame.ticks(major) {
+                    @let x = frame.x(start);
+                    line class="sh-gantt__grid sh-gantt__grid--major" x1=(num(x)) y1="0" x2=(num(x)) y2=(num(AXIS_HEIGHT)) {}
+                    text class="sh-gantt__axis-label" x=(num(x + 4.0)) y="16" {
+                        @if major == Unit::Month {
+                            (major.label(start)) " " (start.year)
+                        } @else {
+                            (major.label(start))
+                        }
+                    }
+                }
+                @for (start, end) in frame.ticks(minor) {
+                    @let (x, x_end) = (frame.x(start), frame.x(end));
+                    // Skip labels for partial units too narrow to hold them
+                    @if x_end - x >= 18.0 {
+                        text
+                            class="sh-gantt__axis-label sh-gantt__axis-label--minor"
+                            x=(num((x + x_end) / 2.0))
+                            y="36"
+                            text-anchor="middle"
+                        { (minor.label(start)) }
+                    }
+                }
+                @if let Some(x) = self.today_x(frame) {
+                    path class="sh-gantt__today-marker" d={ "M" (num(x - 5.0)) " " (num(AXIS_HEIGHT - 6.0)) "h10l-5 6z" } {}
+                }
+            }
+        }
+    }
+
+    fn today_x(&self, frame: &Frame) -> Option<f64> {
+        let today = frame.today?;
+        let range = frame.start.days_since_epoch()..frame.end.days_since_epoch();
+        range
+            .contains(&today)
+            .then(|| frame.x_day(today) + frame.day_width / 2.0)
+    }
+
+    fn rows(&self, frame: &Frame, rows: &[Row<'_, 'a>]) -> Markup {
+        let (_, minor) = self.scale.units();
+        let width = frame.width();
+        let height = rows.len() as f64 * ROW_HEIGHT;
+        let marker = format!("url(#{}-arrow)", self.id);
+
+        // Bar extents of every task drawn in this block, for dependency arrows
+        let mut placed: HashMap<&str, (f64, f64, f64)> = HashMap::new();
+        for (r, row) in rows.iter().enumerate() {
+            let y = r as f64 * ROW_HEIGHT + ROW_HEIGHT / 2.0;
+            for task in &row.tasks {
+                let x = frame.x(task.start);
+                let x_end = if task.milestone {
+                    x
+                } else {
+                    x + task.days() as f64 * frame.day_width
+                };
+                placed.insert(&task.id, (x, x_end, y));
+            }
+        }
+        let arrows: Vec<String> = rows
+            .iter()
+            .flat_map(|row| &row.tasks)
+            .flat_map(|task| {
+                let (x2, _, y2) = placed[task.id.as_ref()];
+                let placed = &placed;
+                task.dependencies.iter().filter_map(move |dep| {
+                    let &(_, x1, y1) = placed.get(dep.as_ref())?;
+                    let d = if x2 - x1 >= 16.0 || y1 == y2 {
+                        format!(
+                            "M{} {}H{}V{}H{}",
+                            num(x1),
+                            num(y1),
+                            num(x1 + 8.0),
+                            num(y2),
+                            num(x2)
+                        )
+                    } else {
+                        // Predecessor ends after the start: loop back between rows
+                        let mid = (y1 + y2) / 2.0;
+                        format!(
+                            "M{} {}h8V{}H{}V{}H{}",
+                            num(x1),
+                            num(y1),
+                            num(mid),
+                            num(x2 - 8.0),
+                            num(y2),
+                            num(x2)
+                        )
+                    };
+                    Some(d)
+                })
+            })
+            .collect();
+
+        html! {
+            svg
+                class="sh-gantt__rows"
+                width=(num(width))
+                height=(num(height))
+                viewBox={ "0 0 " (num(width)) " " (num(height)) }
+            {
+                @for r in (1..rows.len()).step_by(2) {
+                    rect class="sh-gantt__stripe" x="0" y=(num(r as f64 * ROW_HEIGHT)) width=(num(width)) height=(num(ROW_HEIGHT)) {}
+                }
+                @for (start, _) in frame.ticks(minor) {
+                    @let x = frame.x(start);
+                    line class="sh-gantt__grid" x1=(num(x)) y1="0" x2=(num(x)) y2=(num(height)) {}
+                }
+                @for (r, row) in rows.iter().enumerate() {
+                    @let y = r as f64 * ROW_HEIGHT + ROW_HEIGHT / 2.0;
+                    text class="sh-gantt__label" x="8" y=(num(y)) dominant-baseline="central" {
+                        title { (row.label) }
+                        (truncate(&row.label))
+                    }
+                    @for task in &row.tasks {
+                        (self.bar(frame, task, y))
+                    }
+                }
+                @if !arrows.is_empty() {
+                    defs {
+                        marker
+                            id={ (self.id) "-arrow" }
+                            viewBox="0 0 10 10"
+                            refX="10"
+                            refY="5"
+                            markerWidth="7"
+                            markerHeight="7"
+                            markerUnits="userSpaceOnUse"
+                            orient="auto"
+                        {
+                            path class="sh-gantt__arrowhead" d="M0 0L10 5L0 10z" {}
+                        }
+                    }
+                    @for d in &arrows {
+                        path class="sh-gantt__dependency" d=(d) marker-end=(marker) {}
+                    }
+                }
+                @if let Some(x) = self.today_x(frame) {
+                    line class="sh-gantt__today" x1=(num(x)) y1="0" x2=(num(x)) y2=(num(height)) {}
+                }
+            }
+        }
+    }
+
+    fn bar(&self, frame: &Frame, task: &GanttTask<'a>, y: f64) -> Markup {
+        let x = frame.x(task.start);
+        let w = task.days() as f64 * frame.day_width;
+        let top = y - BAR_HEIGHT / 2.0;
+        let predecessors: Vec<&str> = task
+            .dependencies
+            .iter()
+            .filter_map(|dep| self.tasks.iter().find(|t| &t.id == dep))
+            .map(|t| t.name.as_ref())
+            .collect();
+        let style = task
+            .color
+            .map(|c| format!("--sh-gantt-bar: {}", c.css_value()));
+        let class = if task.milestone {
+            "sh-gantt__task sh-gantt__task--milestone"
+        } else {
+            "sh-gantt__task"
+        };
+        html! {
+            g class=(class) style=[style] data-task=(task.id) {
+                title { (task.tooltip(&predecessors)) }
+                @if task.milestone {
+                    @let r = BAR_HEIGHT / 2.0;
+                    path class="sh-gantt__milestone" d={
+                        "M" (num(x)) " " (num(y - r)) "l" (num(r)) " " (num(r))
+                        "l-" (num(r)) " " (num(r)) "l-" (num(r)) "-" (num(r)) "z"
+                    } {}
+                } @else {
+                    rect class="sh-gantt__bar" x=(num(x)) y=(num(top)) width=(num(w)) height=(num(BAR_HEIGHT)) rx="4" {}
+                    @if task.progress > 0.0 {
+                        rect
+                            class="sh-gantt__progress"
+                            x=(num(x))
+                            y=(num(top))
+                            width=(num(w * task.progress as f64))
+                            height=(num(BAR_HEIGHT))
+                            rx="4"
+                        {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Group header row: caret, name and a bar spanning the group's tasks
+    fn summary(&self, frame: &Frame, group: &str, rows: &[Row<'_, 'a>]) -> Markup {
+        let tasks: Vec<&GanttTask<'a>> =
+            rows.iter().flat_map(|r| r.tasks.iter().copied()).collect();
+        let start = tasks.iter().map(|t| t.start).min();
+        let end = tasks.iter().map(|t| t.end).max();
+        let total: i64 = tasks.iter().map(|t| t.days()).sum();
+        let done: f64 = tasks
+            .iter()
+            .map(|t| t.days() as f64 * t.progress as f64)
+            .sum();
+        let progress = if total > 0 { done / total as f64 } else { 0.0 };
+        let width = frame.width();
+        let y = ROW_HEIGHT / 2.0;
+
+        html! {
+            svg
+                class="sh-gantt__summary-row"
+                width=(num(width))
+                height=(num(ROW_HEIGHT))
+                viewBox={ "0 0 " (num(width)) " " (num(ROW_HEIGHT)) }
+            {
+                path class="sh-gantt__caret" d={ "M8 " (num(y - 5.0)) "l6 5l-6 5z" } {}
+                text class="sh-gantt__group-label" x="20" y=(num(y)) dominant-baseline="central" {
+                    (truncate(group))
+                }
+                @if let (Some(start), Some(end)) = (start, end) {
+                    @let x = frame.x(start);
+                    @let w = frame.x(end.add_days(1)) - x;
+                    rect class="sh-gantt__summary-bar" x=(num(x)) y=(num(y - 4.0)) width=(num(w)) height="8" rx="2" {
+                        title { (group) ": " (start) " – " (end) " (" (format!("{:.0}", progress * 100.0)) "%)" }
+                    }
+                }
+                @if let Some(x) = self.today_x(frame) {
+                    line class="sh-gantt__today" x1=(num(x)) y1="0" x2=(num(x)) y2=(num(ROW_HEIGHT)) {}
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for GanttChart<'a> {
+    fn render(&self) -> Markup {
+        let frame = self.frame();
+        let blocks = self.blocks();
+        let class = match &self.custom_class {
+            Some(custom) => format!("sh-gantt {}", custom),
+            None => "sh-gantt".to_string(),
+        };
+        let label = self.label.as_deref().unwrap_or("Gantt chart");
+
+        html! {
+            div class=(class) role="figure" aria-label=(label) {
+                div class="sh-gantt__scroll" {
+                    (self.axis(&frame))
+                    @for block in &blocks {
+                        @match block.group {
+                            Some(group) => {
+                                details
+                                    class="sh-gantt__group"
+                                    open[!self.collapsed.iter().any(|c| c.as_ref() == group)]
+                                {
+                                    summary class="sh-gantt__summary" {
+                                        (self.summary(&frame, group, &block.rows))
+                                    }
+                                    (self.rows(&frame, &block.rows))
+                                }
+                            }
+                            None => (self.rows(&frame, &block.rows)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn gantt_css() -> String {
+    r#"
+.sh-gantt {
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #ffffff);
+    color: var(--sh-text, #111827);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 12px;
+}
+
+.sh-gantt__scroll {
+    overflow-x: auto;
+}
+
+.sh-gantt svg {
+    display: block;
+}
+
+.sh-gantt__axis {
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-gantt__corner,
+.sh-gantt__axis-label,
+.sh-gantt__group-label {
+    fill: var(--sh-text, #111827);
+    font-weight: 600;
+}
+
+.sh-gantt__axis-label--minor {
+    fill: var(--sh-text-muted, #6b7280);
+    font-weight: 400;
+}
+
+.sh-gantt__label {
+    fill: var(--sh-text, #111827);
+}
+
+.sh-gantt__grid {
+    stroke: var(--sh-border, #e5e7eb);
+    stroke-width: 1;
+}
+
+.sh-gantt__grid--major {
+    stroke: var(--sh-text-muted, #6b7280);
+    stroke-opacity: 0.4;
+}
+
+.sh-gantt__stripe {
+    fill: var(--sh-text, #111827);
+    fill-opacity: 0.03;
+}
+
+.sh-gantt__bar {
+    fill: var(--sh-gantt-bar, var(--sh-primary, #8b5cf6));
+    fill-opacity: 0.35;
+}
+
+.sh-gantt__progress {
+    fill: var(--sh-gantt-bar, var(--sh-primary, #8b5cf6));
+}
+
+.sh-gantt__milestone {
+    fill: var(--sh-gantt-bar, var(--sh-accent, #f59e0b));
+}
+
+.sh-gantt__summary-bar {
+    fill: var(--sh-text-muted, #6b7280);
+}
+
+.sh-gantt__dependency {
+    fill: none;
+    stroke: var(--sh-text-muted, #6b7280);
+    stroke-width: 1.25;
+}
+
+.sh-gantt__arrowhead {
+    fill: var(--sh-text-muted, #6b7280);
+}
+
+.sh-gantt__today {
+    stroke: var(--sh-error, #ef4444);
+    stroke-width: 2;
+}
+
+.sh-gantt__today-marker {
+    fill: var(--sh-error, #ef4444);
+}
+
+.sh-gantt__group {
+    border-top: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-gantt__summary {
+    display: block;
+    cursor: pointer;
+    list-style: none;
+}
+
+.sh-gantt__summary::-webkit-details-marker {
+    display: none;
+}
+
+.sh-gantt__summary:focus-visible {
+    outline: 2px solid var(--sh-primary, #8b5cf6);
+    outline-offset: -2px;
+}
+
+.sh-gantt__caret {
+    fill: var(--sh-text-muted, #6b7280);
+    transform-box: fill-box;
+    transform-origin: center;
+    transition: transform 0.15s ease;
+}
+
+.sh-gantt__group[open] .sh-gantt__caret {
+    transform: rotate(90deg);
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-gantt__caret {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> CalendarDate {
+        CalendarDate::parse(s).unwrap()
+    }
+
+    fn sample() -> GanttChart<'static> {
+        GanttChart::new(vec![
+            GanttTask::new("spec", "Write spec", date("2024-03-04"), date("2024-03-08"))
+                .progress(1.0)
+                .group("Design"),
+            GanttTask::new("build", "Build", date("2024-03-11"), date("2024-03-22"))
+                .progress(0.5)
+                .depends_on("spec")
+                .group("Design")
+                .resource("Ana"),
+            GanttTask::milestone("ship", "Ship", date("2024-03-25"))
+                .depends_on("build")
+                .resource("Ana"),
+        ])
+        .today(date("2024-03-13"))
+        .id("plan")
+    }
+
+    #[test]
+    fn test_date_round_trip() {
+        for s in ["1970-01-01", "2000-02-29", "2024-12-31", "1969-07-20"] {
+            let d = date(s);
+            assert_eq!(CalendarDate::from_days(d.days_since_epoch()), d);
+            assert_eq!(d.to_string(), s);
+        }
+        assert_eq!(date("2024-03-01").add_days(-1), date("2024-02-29"));
+        assert_eq!(date("2024-03-13").weekday(), 2);
+        assert!(CalendarDate::parse("2023-02-29").is_none());
+        assert!(CalendarDate::parse("2024-13-01").is_none());
+    }
+
+    #[test]
+    fn test_units() {
+        let d = date("2024-05-15");
+        assert_eq!(Unit::Week.floor(d), date("2024-05-13"));
+        assert_eq!(Unit::Quarter.floor(d), date("2024-04-01"));
+        assert_eq!(Unit::Quarter.next(d), date("2024-07-01"));
+        assert_eq!(Unit::Month.next(date("2024-12-20")), date("2025-01-01"));
+        assert_eq!(Unit::Week.label(d), "May 15");
+        assert_eq!(Unit::Quarter.label(d), "Q2");
+    }
+
+    #[test]
+    fn test_frame_fits_tasks() {
+        let chart = sample();
+        let frame = chart.frame();
+        // Week scale snaps to Mondays
+        assert_eq!(frame.start, date("2024-03-04"));
+        assert_eq!(frame.end, date("2024-04-01"));
+        assert_eq!(frame.x(date("2024-03-05")), LABEL_WIDTH + 14.0);
+
+        let frame = chart.clone().scale(TimeScale::Month).frame();
+        assert_eq!(frame.start, date("2024-03-01"));
+        assert_eq!(frame.end, date("2024-04-01"));
+    }
+
+    #[test]
+    fn test_task_builders() {
+        let task = GanttTask::new("a", "A", date("2024-01-10"), date("2024-01-01")).progress(3.0);
+        assert_eq!(task.end, task.start);
+        assert_eq!(task.progress, 1.0);
+        assert_eq!(task.days(), 1);
+        assert!(GanttTask::milestone("m", "M", date("2024-01-01")).milestone);
+    }
+
+    #[test]
+    fn test_gantt_render() {
+        let html = sample().collapsed("Design").render().into_string();
+
+        assert!(
+            html.starts_with(r#"<div class="sh-gantt" role="figure" aria-label="Gantt chart">"#)
+        );
+        assert!(html
+            .contains(r#"<details class="sh-gantt__group"><summary class="sh-gantt__summary">"#));
+        assert!(html.contains(">Design</text>"));
+        assert!(html.contains(">Mar 2024</text>"));
+        assert!(html.contains(r#"class="sh-gantt__progress""#));
+        assert!(html.contains(r#"class="sh-gantt__task sh-gantt__task--milestone""#));
+        assert!(
+            html.contains("<title>Build: 2024-03-11 – 2024-03-22 (50%)\nAfter: Write spec</title>")
+        );
+        // spec -> build sits in the Design block; build -> ship crosses blocks
+        assert_eq!(html.matches(r#"class="sh-gantt__dependency""#).count(), 1);
+        assert!(html.contains(r#"marker-end="url(#plan-arrow)""#));
+        assert!(html.contains(r#"class="sh-gantt__today""#));
+
+        let open = sample().render().into_string();
+        assert!(open.contains(r#"<details class="sh-gantt__group" open>"#));
+    }
+
+    #[test]
+    fn test_today_marker_bounds() {
+        let outside = sample().today(date("2025-01-01")).render().into_string();
+        assert!(!outside.contains("sh-gantt__today"));
+        let hidden = sample().show_today(false).render().into_string();
+        assert!(!hidden.contains("sh-gantt__today"));
+    }
+
+    #[test]
+    fn test_resource_view() {
+        let chart = sample().by_resource();
+        let blocks = chart.blocks();
+        assert_eq!(blocks.len(), 1);
+        let labels: Vec<&str> = blocks[0].rows.iter().map(|r| r.label.as_ref()).collect();
+        assert_eq!(labels, vec!["Ana", "Unassigned"]);
+        assert_eq!(blocks[0].rows[0].tasks.len(), 2);
+
+        let html = chart.render().into_string();
+        assert!(html.contains(">Resource</text>"));
+        assert!(!html.contains("<details"));
+        assert_eq!(html.matches(r#"class="sh-gantt__dependency""#).count(), 2);
+    }
+
+    #[test]
+    fn test_task_color_and_truncation() {
+        let html = GanttChart::new(vec![GanttTask::new(
+            "x",
+            "A task name that is far too long for the column",
+            date("2024-01-01"),
+            date("2024-01-02"),
+        )
+        .color(ChartColor::Success)])
+        .scale(TimeScale::Day)
+        .show_today(false)
+        .render()
+        .into_string();
+
+        assert!(html.contains(r#"style="--sh-gantt-bar: var(--sh-success)""#));
+        assert!(html.contains("A task name that is far…"));
+        assert!(html.contains(r#"width="64" height="18""#));
+    }
+
+    #[test]
+    fn test_gantt_css() {
+        let css = gantt_css();
+        assert!(css.contains(".sh-gantt__today"));
+        assert!(css.contains(".sh-gantt__group[open] .sh-gantt__caret"));
+    }
+}
@@ -1,5 +1,6 @@
 //! Glitch Text Component - Glitch animation effect
 
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 /// Glitch effect intensity
@@ -110,7 +111,13 @@ impl<'a> Render for GlitchText<'a> {
 
 /// Generate CSS for glitch text components
 pub fn glitch_text_css() -> String {
-    r#"
+    glitch_text_css_for(MotionConfig::default())
+}
+
+/// GlitchText CSS at the given motion level
+pub fn glitch_text_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 /* Glitch Text Styles */
 .sh-glitch-text {
     position: relative;
@@ -212,15 +219,24 @@ pub fn glitch_text_css() -> String {
     }
 }
 
-/* Reduced motion */
-@media (prefers-reduced-motion: reduce) {
-    .sh-glitch-text::before,
-    .sh-glitch-text::after {
-        animation: none;
-    }
+"#,
+        still: r#"
+.sh-glitch-text::before,
+.sh-glitch-text::after,
+.sh-glitch-text--static:hover::before,
+.sh-glitch-text--static:hover::after {
+    animation: none;
 }
-"#
-    .to_string()
+"#,
+        off: r#"
+.sh-glitch-text::before,
+.sh-glitch-text::after,
+.sh-glitch-text--static:hover::before,
+.sh-glitch-text--static:hover::after {
+    display: none;
+}
+"#,
+    })
 }
 
 #[cfg(test)]
@@ -250,4 +266,15 @@ mod tests {
 
         assert!(!glitch.animated);
     }
+
+    #[test]
+    fn test_glitch_motion() {
+        let css = glitch_text_css();
+        assert!(css.contains("@keyframes glitch-1"));
+        assert_eq!(css.matches("prefers-reduced-motion").count(), 1);
+
+        let css = glitch_text_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes"));
+        assert!(!css.contains("prefers-reduced-motion"));
+    }
 }
@@ -1,5 +1,17 @@
 //! Gradient Text Component - Animated gradient text effect
 //! Pure CSS animation, no JavaScript
+//!
+//! # Example
+//! ```
+//! use maud::Render;
+//! use shallot_components::gradient_text::{GradientDirection, GradientText};
+//!
+//! let title = GradientText::new("Zero JavaScript")
+//!     .colors(vec!["#8b5cf6", "#ec4899"])
+//!     .direction(GradientDirection::Diagonal)
+//!     .tag("h2")
+//!     .render();
+//! ```
 
 use maud::{html, Markup, Render};
 
@@ -0,0 +1,769 @@
+//! Heatmap Component - Matrix heatmaps and contribution calendars
+//!
+//! Values are binned into intensity levels and drawn as SVG cells, each
+//! with a `<title>` tooltip. The same data is emitted as a visually hidden
+//! table, so screen readers get real rows and columns instead of shapes.
+
+use crate::charts::ChartColor;
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+const GAP: f64 = 3.0;
+const LABEL_CHAR_WIDTH: f64 = 7.0;
+
+fn num(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.2}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+fn human_date(date: CalendarDate) -> String {
+    format!("{} {}, {}", date.month_abbreviation(), date.day, date.year)
+}
+
+/// Maps values onto intensity levels
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorScale {
+    /// Hue mixed with the empty color for each level
+    pub color: ChartColor,
+    /// Explicit fill per level, lowest first; overrides `color` and `levels`
+    pub palette: Vec<&'static str>,
+    pub levels: u8,
+    /// Lower bounds of levels 1 and up; empty splits the range evenly
+    pub thresholds: Vec<f64>,
+}
+
+impl Default for ColorScale {
+    fn default() -> Self {
+        Self::new(ChartColor::Success)
+    }
+}
+
+impl ColorScale {
+    pub fn new(color: ChartColor) -> Self {
+        Self {
+            color,
+            palette: Vec::new(),
+            levels: 5,
+            thresholds: Vec::new(),
+        }
+    }
+
+    pub fn palette(mut self, palette: Vec<&'static str>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Number of levels including the empty one (2-10)
+    pub fn levels(mut self, levels: u8) -> Self {
+        self.levels = levels.clamp(2, 10);
+        self
+    }
+
+    pub fn thresholds(mut self, thresholds: Vec<f64>) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn level_count(&self) -> usize {
+        if !self.palette.is_empty() {
+            self.palette.len()
+        } else if !self.thresholds.is_empty() {
+            self.thresholds.len() + 1
+        } else {
+            self.levels as usize
+        }
+    }
+
+    /// Level of `value` given the largest value shown; 0 means empty
+    pub fn level(&self, value: Option<f64>, max: f64) -> usize {
+        let count = self.level_count();
+        let Some(value) = value else { return 0 };
+        if !self.thresholds.is_empty() {
+            let level = self.thresholds.iter().filter(|t| value >= **t).count();
+            return level.min(count - 1);
+        }
+        if value <= 0.0 || max <= 0.0 {
+            return 0;
+        }
+        ((value / max * (count - 1) as f64).ceil() as usize).clamp(1, count - 1)
+    }
+
+    /// Inline style for one level's cell or swatch
+    fn cell_style(&self, level: usize) -> String {
+        match self.palette.get(level) {
+            Some(fill) => format!("--sh-heatmap-fill: {}", fill),
+            None => format!(
+                "--sh-heatmap-level: {}",
+                num(level as f64 / (self.level_count() - 1) as f64)
+            ),
+        }
+    }
+
+    fn root_style(&self) -> String {
+        format!("--sh-heatmap-color: {}", self.color.css_value())
+    }
+
+    fn legend(&self) -> Markup {
+        html! {
+            div class="sh-heatmap__legend" aria-hidden="true" {
+                span { "Less" }
+                @for level in 0..self.level_count() {
+                    span class="sh-heatmap__swatch" style=(self.cell_style(level)) {}
+                }
+                span { "More" }
+            }
+        }
+    }
+}
+
+/// Generic matrix heatmap
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap<'a> {
+    pub rows: Vec<Cow<'a, str>>,
+    pub columns: Vec<Cow<'a, str>>,
+    /// Row-major values; `None` marks a missing cell
+    pub values: Vec<Vec<Option<f64>>>,
+    pub scale: ColorScale,
+    pub cell_size: u16,
+    pub show_values: bool,
+    pub show_legend: bool,
+    pub caption: Option<Cow<'a, str>>,
+    pub custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new<R, C>(rows: Vec<R>, columns: Vec<C>, values: Vec<Vec<Option<f64>>>) -> Self
+    where
+        R: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+    {
+        Self {
+            rows: rows.into_iter().map(Into::into).collect(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            values,
+            scale: ColorScale::default(),
+            cell_size: 28,
+            show_values: false,
+            show_legend: true,
+            caption: None,
+            custom_class: None,
+        }
+    }
+
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn cell_size(mut self, size: u16) -> Self {
+        self.cell_size = size.max(4);
+        self
+    }
+
+    /// Print each value inside its cell
+    pub fn show_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    pub fn caption(mut self, caption: impl Into<Cow<'a, str>>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn value(&self, row: usize, column: usize) -> Option<f64> {
+        self.values.get(row)?.get(column).copied().flatten()
+    }
+
+    fn max(&self) -> f64 {
+        self.values
+            .iter()
+            .flatten()
+            .flatten()
+            .fold(0.0, |a, &b| a.max(b))
+    }
+}
+
+impl<'a> Render for Heatmap<'a> {
+    fn render(&self) -> Markup {
+        let cell = self.cell_size as f64;
+        let pitch = cell + GAP;
+        let left = self
+            .rows
+            .iter()
+            .map(|r| r.chars().count())
+            .max()
+            .unwrap_or(0) as f64
+            * LABEL_CHAR_WIDTH
+            + 8.0;
+        let top = 20.0;
+        let width = left + self.columns.len() as f64 * pitch;
+        let height = top + self.rows.len() as f64 * pitch;
+        let max = self.max();
+        let class = match &self.custom_class {
+            Some(custom) => format!("sh-heatmap {}", custom),
+            None => "sh-heatmap".to_string(),
+        };
+
+        html! {
+            figure class=(class) style=(self.scale.root_style()) {
+                svg
+                    class="sh-heatmap__svg"
+                    width=(num(width))
+                    height=(num(height))
+                    viewBox={ "0 0 " (num(width)) " " (num(height)) }
+                    aria-hidden="true"
+                {
+                    @for (c, column) in self.columns.iter().enumerate() {
+                        text
+                            class="sh-heatmap__label"
+                            x=(num(left + c as f64 * pitch + cell / 2.0))
+                            y="12"
+                            text-anchor="middle"
+                        { (column) }
+                    }
+                    @for (r, row) in self.rows.iter().enumerate() {
+                        @let y = top + r as f64 * pitch;
+                        text
+                            class="sh-heatmap__label"
+                            x=(num(left - 6.0))
+                            y=(num(y + cell / 2.0))
+                            text-anchor="end"
+                            dominant-baseline="central"
+                        { (row) }
+                        @for (c, column) in self.columns.iter().enumerate() {
+                            @let value = self.value(r, c);
+                            @let level = self.scale.level(value, max);
+                            @let x = left + c as f64 * pitch;
+                            rect
+                                class="sh-heatmap__cell"
+                                x=(num(x))
+                                y=(num(y))
+                                width=(num(cell))
+                                height=(num(cell))
+                                rx="2"
+                                data-level=(level)
+                                style=(self.scale.cell_style(level))
+                            {
+                                title {
+                                    (row) " × " (column) ": "
+                                    @match value {
+                                        Some(v) => (num(v)),
+                                        None => "no data",
+                                    }
+                                }
+                            }
+                            @if let (true, Some(v)) = (self.show_values, value) {
+                                text
+                                    class="sh-heatmap__value"
+                                    x=(num(x + cell / 2.0))
+                                    y=(num(y + cell / 2.0))
+                                    text-anchor="middle"
+                                    dominant-baseline="central"
+                                { (num(v)) }
+                            }
+                        }
+                    }
+                }
+                table class="sh-visually-hidden" {
+                    @if let Some(caption) = &self.caption {
+                        caption { (caption) }
+                    }
+                    thead {
+                        tr {
+                            td {}
+                            @for column in &self.columns {
+                                th scope="col" { (column) }
+                            }
+                        }
+                    }
+                    tbody {
+                        @for (r, row) in self.rows.iter().enumerate() {
+                            tr {
+                                th scope="row" { (row) }
+                                @for c in 0..self.columns.len() {
+                                    td {
+                                        @if let Some(v) = self.value(r, c) {
+                                            (num(v))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                @if self.show_legend {
+                    (self.scale.legend())
+                }
+                @if let Some(caption) = &self.caption {
+                    figcaption class="sh-heatmap__caption" aria-hidden="true" { (caption) }
+                }
+            }
+        }
+    }
+}
+
+/// GitHub-style calendar: one column per week, one row per weekday
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContributionCalendar<'a> {
+    pub values: Vec<(CalendarDate, f64)>,
+    /// Last day shown (defaults to the build date)
+    pub end: Option<CalendarDate>,
+    pub weeks: u16,
+    pub monday_first: bool,
+    pub scale: ColorScale,
+    pub cell_size: u16,
+    /// Singular noun for tooltips and the summary, e.g. "commit"
+    pub unit: Cow<'a, str>,
+    pub show_legend: bool,
+    pub custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> ContributionCalendar<'a> {
+    pub fn new(values: Vec<(CalendarDate, f64)>) -> Self {
+        Self {
+            values,
+            end: None,
+            weeks: 53,
+            monday_first: false,
+            scale: ColorScale::default(),
+            cell_size: 11,
+            unit: Cow::Borrowed("contribution"),
+            show_legend: true,
+            custom_class: None,
+        }
+    }
+
+    pub fn end(mut self, end: CalendarDate) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn weeks(mut self, weeks: u16) -> Self {
+        self.weeks = weeks.max(1);
+        self
+    }
+
+    /// Start weeks on Monday instead of Sunday
+    pub fn monday_first(mut self, monday_first: bool) -> Self {
+        self.monday_first = monday_first;
+        self
+    }
+
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn cell_size(mut self, size: u16) -> Self {
+        self.cell_size = size.max(4);
+        self
+    }
+
+    pub fn unit(mut self, unit: impl Into<Cow<'a, str>>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    /// First day of the first column
+    fn start(&self, end: CalendarDate) -> CalendarDate {
+        let offset = (end.weekday() + i64::from(!self.monday_first)) % 7;
+        end.add_days(-offset - (self.weeks as i64 - 1) * 7)
+    }
+
+    fn describe(&self, value: f64, date: CalendarDate) -> String {
+        let unit = if value == 1.0 {
+            self.unit.to_string()
+        } else {
+            format!("{}s", self.unit)
+        };
+        if value == 0.0 {
+            format!("No {} on {}", unit, human_date(date))
+        } else {
+            format!("{} {} on {}", num(value), unit, human_date(date))
+        }
+    }
+
+    /// Columns where a new month's label goes
+    fn month_labels(&self, start: CalendarDate) -> Vec<(usize, &'static str)> {
+        let mut labels: Vec<(usize, &'static str)> = Vec::new();
+        let mut previous = None;
+        for week in 0..self.weeks as usize {
+            let date = start.add_days(week as i64 * 7);
+            if previous != Some(date.month) {
+                labels.push((week, date.month_abbreviation()));
+                previous = Some(date.month);
+            }
+        }
+        // A partial first month is dropped when it would collide with the next
+        if labels.len() > 1 && labels[1].0 < 3 {
+            labels.remove(0);
+        }
+        labels
+    }
+}
+
+impl<'a> Render for ContributionCalendar<'a> {
+    fn render(&self) -> Markup {
+        const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+        let end = self.end.unwrap_or_else(CalendarDate::today);
+        let start = self.start(end);
+        let mut totals: HashMap<CalendarDate, f64> = HashMap::new();
+        for (date, value) in &self.values {
+            *totals.entry(*date).or_default() += value;
+        }
+        let (first, last) = (start.days_since_epoch(), end.days_since_epoch());
+        let in_range = |d: &CalendarDate| (first..=last).contains(&d.days_since_epoch());
+        let total: f64 = totals
+            .iter()
+            .filter(|(d, _)| in_range(d))
+            .map(|(_, v)| v)
+            .sum();
+        let max = totals
+            .iter()
+            .filter(|(d, _)| in_range(d))
+            .fold(0.0f64, |a, (_, &b)| a.max(b));
+
+        let cell = self.cell_size as f64;
+        let pitch = cell + GAP;
+        let (left, top) = (32.0, 18.0);
+        let weeks = self.weeks as usize;
+        let width = left + weeks as f64 * pitch;
+        let height = top + 7.0 * pitch;
+        let day_name = |row: usize| DAYS[(row + usize::from(self.monday_first)) % 7];
+        let date_at = |week: usize, row: usize| start.add_days((week * 7 + row) as i64);
+        let summary = format!(
+            "{} {}s from {} to {}",
+            num(total),
+            self.unit,
+            human_date(start),
+            human_date(end)
+        );
+        let class = match &self.custom_class {
+            Some(custom) => format!("sh-heatmap sh-heatmap--calendar {}", custom),
+            None => "sh-heatmap sh-heatmap--calendar".to_string(),
+        };
+
+        html! {
+            figure class=(class) style=(self.scale.root_style()) {
+                svg
+                    class="sh-heatmap__svg"
+                    width=(num(width))
+                    height=(num(height))
+                    viewBox={ "0 0 " (num(width)) " " (num(height)) }
+                    aria-hidden="true"
+                {
+                    @for (week, month) in self.month_labels(start) {
+                        text class="sh-heatmap__label" x=(num(left + week as f64 * pitch)) y="10" {
+                            (month)
+                        }
+                    }
+                    @for row in [1, 3, 5] {
+                        text
+                            class="sh-heatmap__label"
+                            x="0"
+                            y=(num(top + row as f64 * pitch + cell / 2.0))
+                            dominant-baseline="central"
+                        { (day_name(row)) }
+                    }
+                    @for week in 0..weeks {
+                        @for row in 0..7 {
+                            @let date = date_at(week, row);
+                            @if date <= end {
+                                @let value = totals.get(&date).copied().unwrap_or(0.0);
+                                @let level = self.scale.level(Some(value), max);
+                                rect
+                                    class="sh-heatmap__cell"
+                                    x=(num(left + week as f64 * pitch))
+                                    y=(num(top + row as f64 * pitch))
+                                    width=(num(cell))
+                                    height=(num(cell))
+                                    rx="2"
+                                    data-date=(date)
+                                    data-level=(level)
+                                    style=(self.scale.cell_style(level))
+                                {
+                                    title { (self.describe(value, date)) }
+                                }
+                            }
+                        }
+                    }
+                }
+                table class="sh-visually-hidden" {
+                    caption { (summary) }
+                    thead {
+                        tr {
+                            td {}
+                            @for week in 0..weeks {
+                                th scope="col" { "Week of " (human_date(date_at(week, 0))) }
+                            }
+                        }
+                    }
+                    tbody {
+                        @for row in 0..7 {
+                            tr {
+                                th scope="row" { (day_name(row)) }
+                                @for week in 0..weeks {
+                                    @let date = date_at(week, row);
+                                    td {
+                                        @if date <= end {
+                                            (num(totals.get(&date).copied().unwrap_or(0.0)))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div class="sh-heatmap__footer" aria-hidden="true" {
+                    span class="sh-heatmap__summary" { (summary) }
+                    @if self.show_legend {
+                        (self.scale.legend())
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn heatmap_css() -> String {
+    r#"
+.sh-heatmap {
+    --sh-heatmap-empty: var(--sh-surface-alt, #ebedf0);
+    display: inline-flex;
+    flex-direction: column;
+    gap: 0.5rem;
+    margin: 0;
+    max-width: 100%;
+    overflow-x: auto;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 12px;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-heatmap__svg {
+    display: block;
+}
+
+.sh-heatmap__label {
+    fill: var(--sh-text-muted, #6b7280);
+    font-size: 10px;
+}
+
+.sh-heatmap__cell {
+    fill: var(
+        --sh-heatmap-fill,
+        color-mix(
+            in srgb,
+            var(--sh-heatmap-color) calc(var(--sh-heatmap-level, 0) * 100%),
+            var(--sh-heatmap-empty)
+        )
+    );
+    stroke: var(--sh-text, #111827);
+    stroke-opacity: 0.05;
+}
+
+.sh-heatmap__value {
+    fill: var(--sh-text, #111827);
+    font-size: 10px;
+    pointer-events: none;
+}
+
+.sh-heatmap__footer {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: space-between;
+    gap: 0.5rem 1rem;
+}
+
+.sh-heatmap__legend {
+    display: inline-flex;
+    align-items: center;
+    gap: 3px;
+}
+
+.sh-heatmap__legend > span:first-child {
+    margin-right: 0.25rem;
+}
+
+.sh-heatmap__legend > span:last-child {
+    margin-left: 0.25rem;
+}
+
+.sh-heatmap__swatch {
+    width: 10px;
+    height: 10px;
+    border-radius: 2px;
+    background: var(
+        --sh-heatmap-fill,
+        color-mix(
+            in srgb,
+            var(--sh-heatmap-color) calc(var(--sh-heatmap-level, 0) * 100%),
+            var(--sh-heatmap-empty)
+        )
+    );
+}
+
+.sh-heatmap__caption {
+    color: var(--sh-text, #111827);
+    font-size: var(--sh-font-size-sm, 0.875rem);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> CalendarDate {
+        CalendarDate::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_scale_levels() {
+        let scale = ColorScale::default();
+        assert_eq!(scale.level_count(), 5);
+        assert_eq!(scale.level(None, 10.0), 0);
+        assert_eq!(scale.level(Some(0.0), 10.0), 0);
+        assert_eq!(scale.level(Some(0.1), 10.0), 1);
+        assert_eq!(scale.level(Some(5.0), 10.0), 2);
+        assert_eq!(scale.level(Some(10.0), 10.0), 4);
+
+        let stepped = ColorScale::default().thresholds(vec![1.0, 10.0]);
+        assert_eq!(stepped.level_count(), 3);
+        assert_eq!(stepped.level(Some(0.5), 100.0), 0);
+        assert_eq!(stepped.level(Some(50.0), 100.0), 2);
+
+        let palette = ColorScale::default().palette(vec!["#eee", "#9be9a8", "#216e39"]);
+        assert_eq!(palette.level_count(), 3);
+        assert_eq!(palette.cell_style(2), "--sh-heatmap-fill: #216e39");
+        assert_eq!(
+            ColorScale::default().cell_style(1),
+            "--sh-heatmap-level: 0.25"
+        );
+        assert_eq!(ColorScale::default().levels(20).levels, 10);
+    }
+
+    #[test]
+    fn test_matrix_render() {
+        let html = Heatmap::new(
+            vec!["Mon", "Tue"],
+            vec!["9am", "noon"],
+            vec![vec![Some(4.0), None], vec![Some(1.5), Some(8.0)]],
+        )
+        .scale(ColorScale::new(ChartColor::Primary))
+        .show_values(true)
+        .caption("Visits by hour")
+        .render()
+        .into_string();
+
+        assert!(html.starts_with(
+            r#"<figure class="sh-heatmap" style="--sh-heatmap-color: var(--sh-primary)">"#
+        ));
+        assert_eq!(html.matches(r#"<rect class="sh-heatmap__cell""#).count(), 4);
+        assert!(html.contains("<title>Mon × noon: no data</title>"));
+        assert!(html.contains("<title>Tue × 9am: 1.5</title>"));
+        assert!(html.contains(r#"data-level="4" style="--sh-heatmap-level: 1""#));
+        assert!(
+            html.contains(r#"<table class="sh-visually-hidden"><caption>Visits by hour</caption>"#)
+        );
+        assert!(html.contains(r#"<th scope="row">Tue</th><td>1.5</td><td>8</td>"#));
+        assert!(html.contains(r#"<th scope="col">noon</th>"#));
+        assert!(html.contains(r#"class="sh-heatmap__value""#));
+        assert_eq!(html.matches(r#"class="sh-heatmap__swatch""#).count(), 5);
+    }
+
+    #[test]
+    fn test_calendar_grid() {
+        // 2024-03-13 is a Wednesday
+        let calendar = ContributionCalendar::new(vec![])
+            .end(date("2024-03-13"))
+            .weeks(4);
+        assert_eq!(calendar.start(date("2024-03-13")), date("2024-02-18"));
+        let monday = calendar.clone().monday_first(true);
+        assert_eq!(monday.start(date("2024-03-13")), date("2024-02-19"));
+
+        let html = calendar.render().into_string();
+        // Three full weeks plus Sunday through Wednesday
+        assert_eq!(
+            html.matches(r#"<rect class="sh-heatmap__cell""#).count(),
+            25
+        );
+        assert!(html.contains(r#"data-date="2024-03-13""#));
+        assert!(!html.contains(r#"data-date="2024-03-14""#));
+    }
+
+    #[test]
+    fn test_calendar_values_and_labels() {
+        let html = ContributionCalendar::new(vec![
+            (date("2024-03-01"), 2.0),
+            (date("2024-03-01"), 3.0),
+            (date("2024-03-04"), 1.0),
+            (date("2023-01-01"), 100.0),
+        ])
+        .end(date("2024-03-13"))
+        .weeks(8)
+        .unit("commit")
+        .render()
+        .into_string();
+
+        assert!(html.contains("<title>5 commits on Mar 1, 2024</title>"));
+        assert!(html.contains("<title>1 commit on Mar 4, 2024</title>"));
+        assert!(html.contains("<title>No commits on Mar 5, 2024</title>"));
+        // Out-of-range values neither count nor skew the scale
+        assert!(html.contains("<caption>6 commits from Jan 21, 2024 to Mar 13, 2024</caption>"));
+        assert!(html.contains(r#"data-date="2024-03-01" data-level="4""#));
+        assert!(html.contains(">Feb</text>"));
+        assert!(html.contains(">Mar</text>"));
+        assert!(html.contains(">Mon</text>"));
+        assert!(html.contains(r#"<th scope="col">Week of Jan 21, 2024</th>"#));
+    }
+
+    #[test]
+    fn test_month_labels_skip_crowded_start() {
+        let calendar = ContributionCalendar::new(vec![]).weeks(10);
+        let labels = calendar.month_labels(date("2024-01-28"));
+        assert_eq!(labels[0], (1, "Feb"));
+
+        let labels = calendar.month_labels(date("2024-01-07"));
+        assert_eq!(labels[0], (0, "Jan"));
+    }
+
+    #[test]
+    fn test_heatmap_css() {
+        let css = heatmap_css();
+        assert!(css.contains(".sh-heatmap__cell"));
+        assert!(css.contains("--sh-heatmap-level"));
+    }
+}
@@ -0,0 +1,666 @@
+//! Hero Components - Landing page hero sections
+//!
+//! [`Hero`] lays out an eyebrow, the page `<h1>`, a subtitle and a
+//! [`CtaGroup`], either centered or split beside a media slot. Backgrounds
+//! reuse [`MeshGradientBackground`] and [`Pattern`] as decorative layers,
+//! and a stats strip (e.g. [`crate::Stats`]) can be slotted in below.
+//!
+//! [`HeroBackground::Scanlines`] carries over the CRT scanline and vignette
+//! overlay of the website's retro hero.
+
+use crate::mesh_gradient::MeshGradientBackground;
+use crate::pattern::{Pattern, PatternKind, PatternOpacity};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Visual weight of a call-to-action link
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CtaVariant {
+    #[default]
+    Primary,
+    Secondary,
+    Ghost,
+}
+
+impl CtaVariant {
+    fn class(&self) -> &'static str {
+        match self {
+            CtaVariant::Primary => "sh-btn sh-btn--primary sh-btn--lg",
+            CtaVariant::Secondary => "sh-btn sh-btn--secondary sh-btn--lg",
+            CtaVariant::Ghost => "sh-btn sh-btn--ghost sh-btn--lg",
+        }
+    }
+}
+
+/// A call-to-action link styled as a button
+#[derive(Debug, Clone)]
+pub struct Cta<'a> {
+    pub label: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+    pub variant: CtaVariant,
+    pub external: bool,
+}
+
+impl<'a> Cta<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+            variant: CtaVariant::default(),
+            external: false,
+        }
+    }
+
+    /// Secondary-styled link
+    pub fn secondary(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(label, href).variant(CtaVariant::Secondary)
+    }
+
+    pub fn variant(mut self, variant: CtaVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Open in a new tab with `rel="noopener noreferrer"`
+    pub fn external(mut self, external: bool) -> Self {
+        self.external = external;
+        self
+    }
+}
+
+impl<'a> Render for Cta<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            a
+                class=(self.variant.class())
+                href=(self.href)
+                target=[self.external.then_some("_blank")]
+                rel=[self.external.then_some("noopener noreferrer")] {
+                (self.label)
+            }
+        }
+    }
+}
+
+/// Row of call-to-action links
+#[derive(Debug, Clone, Default)]
+pub struct CtaGroup<'a> {
+    pub ctas: Vec<Cta<'a>>,
+    pub note: Option<Cow<'a, str>>,
+}
+
+impl<'a> CtaGroup<'a> {
+    pub fn new(ctas: Vec<Cta<'a>>) -> Self {
+        Self { ctas, note: None }
+    }
+
+    pub fn cta(mut self, cta: Cta<'a>) -> Self {
+        self.ctas.push(cta);
+        self
+    }
+
+    /// Small print under the buttons, e.g. "No credit card required"
+    pub fn note(mut self, note: impl Into<Cow<'a, str>>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ctas.is_empty() && self.note.is_none()
+    }
+}
+
+impl<'a> Render for CtaGroup<'a> {
+    fn render(&self) -> Markup {
+        if self.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            div class="sh-cta-group" {
+                @if !self.ctas.is_empty() {
+                    div class="sh-cta-group__actions" {
+                        @for cta in &self.ctas {
+                            (cta)
+                        }
+                    }
+                }
+                @if let Some(note) = &self.note {
+                    p class="sh-cta-group__note" { (note) }
+                }
+            }
+        }
+    }
+}
+
+/// Decorative layer behind the hero content
+#[derive(Debug, Clone, Default)]
+pub enum HeroBackground<'a> {
+    #[default]
+    None,
+    /// Diagonal linear gradient between two colors
+    Gradient(&'a str, &'a str),
+    /// Animated [`MeshGradientBackground`] with these colors
+    Mesh(Vec<&'a str>),
+    /// Light [`Pattern`] fading out towards the edges
+    Pattern(PatternKind),
+    /// CRT scanlines and vignette, as on the retro hero
+    Scanlines,
+}
+
+impl<'a> HeroBackground<'a> {
+    fn modifier(&self) -> &'static str {
+        match self {
+            HeroBackground::None => "plain",
+            HeroBackground::Gradient(..) => "gradient",
+            HeroBackground::Mesh(_) => "mesh",
+            HeroBackground::Pattern(_) => "pattern",
+            HeroBackground::Scanlines => "scanlines",
+        }
+    }
+
+    fn style(&self) -> Option<String> {
+        match self {
+            HeroBackground::Gradient(from, to) => Some(format!(
+                "--sh-hero-gradient-from: {}; --sh-hero-gradient-to: {};",
+                from, to
+            )),
+            _ => None,
+        }
+    }
+
+    fn render_layer(&self) -> Markup {
+        html! {
+            @match self {
+                HeroBackground::None | HeroBackground::Gradient(..) => {},
+                HeroBackground::Mesh(colors) => {
+                    div class="sh-hero__background" aria-hidden="true" {
+                        (MeshGradientBackground::new().colors(colors.clone()))
+                    }
+                },
+                HeroBackground::Pattern(kind) => {
+                    div class="sh-hero__background" aria-hidden="true" {
+                        (Pattern::new().kind(*kind).opacity(PatternOpacity::Light))
+                    }
+                },
+                HeroBackground::Scanlines => {
+                    div class="sh-hero__background sh-hero__crt" aria-hidden="true" {}
+                },
+            }
+        }
+    }
+}
+
+/// How the hero content is arranged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeroLayout {
+    #[default]
+    Centered,
+    /// Text beside the media slot
+    Split,
+    /// Media first on wide screens
+    SplitReversed,
+}
+
+/// Vertical space taken by the hero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeroSize {
+    Md,
+    #[default]
+    Lg,
+    /// At least the viewport height
+    Screen,
+}
+
+/// Landing page hero section
+#[derive(Debug, Clone)]
+pub struct Hero<'a> {
+    pub title: Cow<'a, str>,
+    pub eyebrow: Option<Cow<'a, str>>,
+    pub subtitle: Option<Cow<'a, str>>,
+    pub ctas: CtaGroup<'a>,
+    pub media: Option<Markup>,
+    pub stats: Option<Markup>,
+    pub layout: HeroLayout,
+    pub background: HeroBackground<'a>,
+    pub size: HeroSize,
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Hero<'a> {
+    /// Centered hero
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            eyebrow: None,
+            subtitle: None,
+            ctas: CtaGroup::default(),
+            media: None,
+            stats: None,
+            layout: HeroLayout::Centered,
+            background: HeroBackground::None,
+            size: HeroSize::default(),
+            id: None,
+        }
+    }
+
+    /// Split hero with text beside `media` (an image, screenshot or demo)
+    pub fn split(title: impl Into<Cow<'a, str>>, media: Markup) -> Self {
+        Self::new(title).media(media).layout(HeroLayout::Split)
+    }
+
+    /// Short label above the title, e.g. "New in v2"
+    pub fn eyebrow(mut self, eyebrow: impl Into<Cow<'a, str>>) -> Self {
+        self.eyebrow = Some(eyebrow.into());
+        self
+    }
+
+    pub fn subtitle(mut self, subtitle: impl Into<Cow<'a, str>>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn cta(mut self, cta: Cta<'a>) -> Self {
+        self.ctas = self.ctas.cta(cta);
+        self
+    }
+
+    pub fn ctas(mut self, ctas: CtaGroup<'a>) -> Self {
+        self.ctas = ctas;
+        self
+    }
+
+    /// Media slot; shown beside the text in split layouts and below it when
+    /// centered
+    pub fn media(mut self, media: Markup) -> Self {
+        self.media = Some(media);
+        self
+    }
+
+    /// Strip below the hero content, typically [`crate::Stats`]
+    pub fn stats(mut self, stats: Markup) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    pub fn layout(mut self, layout: HeroLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn background(mut self, background: HeroBackground<'a>) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn size(mut self, size: HeroSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        let layout = match self.layout {
+            HeroLayout::Centered => "centered",
+            HeroLayout::Split => "split",
+            HeroLayout::SplitReversed => "split sh-hero--reversed",
+        };
+        let size = match self.size {
+            HeroSize::Md => "md",
+            HeroSize::Lg => "lg",
+            HeroSize::Screen => "screen",
+        };
+        format!(
+            "sh-hero sh-hero--{} sh-hero--{} sh-hero--bg-{}",
+            layout,
+            size,
+            self.background.modifier()
+        )
+    }
+}
+
+impl<'a> Render for Hero<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            section class=(self.build_classes()) id=[self.id.as_deref()] style=[self.background.style()] {
+                (self.background.render_layer())
+                div class="sh-hero__inner" {
+                    div class="sh-hero__content" {
+                        @if let Some(eyebrow) = &self.eyebrow {
+                            p class="sh-hero__eyebrow" { (eyebrow) }
+                        }
+                        h1 class="sh-hero__title" { (self.title) }
+                        @if let Some(subtitle) = &self.subtitle {
+                            p class="sh-hero__subtitle" { (subtitle) }
+                        }
+                        (self.ctas)
+                    }
+                    @if let Some(media) = &self.media {
+                        div class="sh-hero__media" { (media) }
+                    }
+                }
+                @if let Some(stats) = &self.stats {
+                    div class="sh-hero__stats" { (stats) }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for hero components
+pub fn hero_css() -> String {
+    r#"
+.sh-hero {
+    position: relative;
+    isolation: isolate;
+    display: flex;
+    flex-direction: column;
+    justify-content: center;
+    gap: 3rem;
+    padding: 4rem 1.5rem;
+    overflow: hidden;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-hero--md {
+    padding-block: 3rem;
+}
+
+.sh-hero--lg {
+    padding-block: clamp(4rem, 10vw, 7rem);
+}
+
+.sh-hero--screen {
+    min-height: 100vh;
+    min-height: 100svh;
+}
+
+.sh-hero__background {
+    position: absolute;
+    inset: 0;
+    z-index: -1;
+    pointer-events: none;
+}
+
+.sh-hero__background > .sh-meshgradient,
+.sh-hero__background > .sh-pattern {
+    position: absolute;
+    inset: 0;
+    height: 100% !important;
+    border-radius: 0;
+}
+
+.sh-hero--bg-pattern .sh-pattern {
+    background-color: transparent;
+    mask-image: radial-gradient(ellipse at center, #000 30%, transparent 75%);
+    -webkit-mask-image: radial-gradient(ellipse at center, #000 30%, transparent 75%);
+}
+
+.sh-hero--bg-gradient {
+    background: linear-gradient(
+        135deg,
+        var(--sh-hero-gradient-from, var(--sh-primary, #3b82f6)),
+        var(--sh-hero-gradient-to, var(--sh-accent, #8b5cf6))
+    );
+    color: #fff;
+}
+
+.sh-hero--bg-mesh {
+    color: #fff;
+}
+
+.sh-hero--bg-gradient .sh-hero__subtitle,
+.sh-hero--bg-mesh .sh-hero__subtitle,
+.sh-hero--bg-gradient .sh-hero__eyebrow,
+.sh-hero--bg-mesh .sh-hero__eyebrow,
+.sh-hero--bg-gradient .sh-cta-group__note,
+.sh-hero--bg-mesh .sh-cta-group__note {
+    color: rgba(255, 255, 255, 0.85);
+}
+
+/* Scanlines and CRT vignette */
+.sh-hero--bg-scanlines::before {
+    content: "";
+    position: absolute;
+    inset: 0;
+    z-index: -1;
+    background: repeating-linear-gradient(
+        0deg,
+        rgba(0, 0, 0, 0.03) 0px,
+        rgba(0, 0, 0, 0.03) 1px,
+        transparent 1px,
+        transparent 2px
+    );
+    pointer-events: none;
+}
+
+.sh-hero__crt {
+    background: radial-gradient(ellipse at center, transparent 50%, rgba(0, 0, 0, 0.15) 100%);
+}
+
+.sh-hero__inner {
+    display: grid;
+    gap: 3rem;
+    width: 100%;
+    max-width: 72rem;
+    margin: 0 auto;
+}
+
+.sh-hero--centered .sh-hero__inner {
+    justify-items: center;
+    text-align: center;
+}
+
+.sh-hero--centered .sh-hero__content {
+    max-width: 48rem;
+}
+
+.sh-hero--split .sh-hero__inner {
+    align-items: center;
+}
+
+@media (min-width: 900px) {
+    .sh-hero--split .sh-hero__inner {
+        grid-template-columns: 1fr 1fr;
+    }
+
+    .sh-hero--reversed .sh-hero__media {
+        order: -1;
+    }
+}
+
+.sh-hero__content {
+    display: flex;
+    flex-direction: column;
+    gap: 1.25rem;
+}
+
+.sh-hero--centered .sh-hero__content {
+    align-items: center;
+}
+
+.sh-hero__eyebrow {
+    margin: 0;
+    font-size: 0.875rem;
+    font-weight: 600;
+    letter-spacing: 0.05em;
+    text-transform: uppercase;
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-hero__title {
+    margin: 0;
+    font-size: clamp(2.25rem, 6vw, 4rem);
+    font-weight: 800;
+    line-height: 1.05;
+    letter-spacing: -0.03em;
+    text-wrap: balance;
+}
+
+.sh-hero__subtitle {
+    margin: 0;
+    max-width: 40rem;
+    font-size: clamp(1.0625rem, 2vw, 1.25rem);
+    line-height: 1.6;
+    color: var(--sh-text-muted, #6b7280);
+    text-wrap: pretty;
+}
+
+.sh-hero__media {
+    width: 100%;
+    min-width: 0;
+}
+
+.sh-hero__media img,
+.sh-hero__media video {
+    display: block;
+    width: 100%;
+    height: auto;
+    border-radius: var(--sh-radius-lg, 0.75rem);
+}
+
+.sh-hero__stats {
+    width: 100%;
+    max-width: 72rem;
+    margin: 0 auto;
+}
+
+/* CTA group */
+.sh-cta-group {
+    display: flex;
+    flex-direction: column;
+    gap: 0.75rem;
+    margin-top: 0.5rem;
+}
+
+.sh-hero--centered .sh-cta-group {
+    align-items: center;
+}
+
+.sh-cta-group__actions {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 0.75rem;
+}
+
+.sh-cta-group__actions .sh-btn {
+    text-decoration: none;
+}
+
+.sh-cta-group__note {
+    margin: 0;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+@media (max-width: 480px) {
+    .sh-cta-group__actions {
+        flex-direction: column;
+        width: 100%;
+    }
+
+    .sh-cta-group__actions .sh-btn {
+        justify-content: center;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cta_group_render() {
+        let html = CtaGroup::new(vec![
+            Cta::new("Get started", "/docs"),
+            Cta::secondary("GitHub", "https://github.com/example").external(true),
+        ])
+        .note("Free and open source")
+        .render()
+        .into_string();
+        assert!(html.contains(r#"<a class="sh-btn sh-btn--primary sh-btn--lg" href="/docs">"#));
+        assert!(html.contains(r#"target="_blank" rel="noopener noreferrer""#));
+        assert!(html.contains("Free and open source"));
+        assert_eq!(CtaGroup::default().render().into_string(), "");
+    }
+
+    #[test]
+    fn test_centered_hero() {
+        let html = Hero::new("Build without JavaScript")
+            .eyebrow("v2.0")
+            .subtitle("Components rendered on the server")
+            .cta(Cta::new("Start", "/start"))
+            .background(HeroBackground::Gradient("#0ea5e9", "#6366f1"))
+            .id("top")
+            .render()
+            .into_string();
+        assert!(html.contains("sh-hero--centered sh-hero--lg sh-hero--bg-gradient"));
+        assert!(html.contains("--sh-hero-gradient-from: #0ea5e9;"));
+        assert!(html.contains(r#"<h1 class="sh-hero__title">Build without JavaScript</h1>"#));
+        assert!(html.contains(r#"id="top""#));
+        assert!(html.contains("sh-cta-group"));
+        assert!(!html.contains("sh-hero__media"));
+    }
+
+    #[test]
+    fn test_split_hero_with_stats() {
+        let html = Hero::split(
+            "Ship faster",
+            html! { img src="/shot.png" alt="Screenshot"; },
+        )
+        .layout(HeroLayout::SplitReversed)
+        .stats(html! { div class="sh-stats" {} })
+        .size(HeroSize::Screen)
+        .render()
+        .into_string();
+        assert!(html.contains("sh-hero--split sh-hero--reversed"));
+        assert!(html.contains("sh-hero--screen"));
+        assert!(html.contains(r#"<div class="sh-hero__media"><img src="/shot.png""#));
+        assert!(html.contains(r#"<div class="sh-hero__stats"><div class="sh-stats">"#));
+        assert!(!html.contains("sh-cta-group"));
+    }
+
+    #[test]
+    fn test_hero_backgrounds() {
+        let mesh = Hero::new("Mesh")
+            .background(HeroBackground::Mesh(vec!["#f00", "#0f0"]))
+            .render()
+            .into_string();
+        assert!(mesh.contains(
+            r#"<div class="sh-hero__background" aria-hidden="true"><div class="sh-meshgradient"#
+        ));
+        assert!(mesh.contains("stop-color: #0f0"));
+
+        let pattern = Hero::new("Grid")
+            .background(HeroBackground::Pattern(PatternKind::Grid))
+            .render()
+            .into_string();
+        assert!(pattern.contains("sh-pattern--grid sh-pattern--md sh-pattern--light"));
+
+        let retro = Hero::new("1998")
+            .background(HeroBackground::Scanlines)
+            .render()
+            .into_string();
+        assert!(retro.contains("sh-hero--bg-scanlines"));
+        assert!(retro.contains("sh-hero__crt"));
+
+        let plain = Hero::new("Plain").render().into_string();
+        assert!(plain.contains("sh-hero--bg-plain"));
+        assert!(!plain.contains("sh-hero__background"));
+    }
+
+    #[test]
+    fn test_hero_css() {
+        let css = hero_css();
+        assert!(css.contains(".sh-hero--split .sh-hero__inner"));
+        assert!(css.contains(".sh-cta-group__actions"));
+        assert!(css.contains(".sh-hero--bg-scanlines::before"));
+    }
+}
@@ -0,0 +1,126 @@
+//! HTMX - optional progressive-enhancement attributes
+//!
+//! Components stay zero-JS: every link and form works as a plain request.
+//! With the `htmx` feature, [`crate::button::Button`],
+//! [`crate::form::Form`] and [`crate::pagination::Pagination`] gain
+//! `hx_get`, `hx_post`, `hx_target` and `hx_swap` builders so pages that load
+//! HTMX can swap in partial responses instead. `Form` and `Pagination` can
+//! also render as a [`RenderMode::Fragment`], leaving out their wrapper
+//! element, which is what those partial responses contain.
+//!
+//! Without the feature the attributes are never set and nothing extra is
+//! rendered.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let button = Button::new("Load more")
+//!     .href("/posts?page=2")
+//!     .hx_get("/posts/partial?page=2")
+//!     .hx_target("#posts")
+//!     .hx_swap(HxSwap::BeforeEnd);
+//! ```
+
+use std::borrow::Cow;
+
+/// How HTMX inserts the response (`hx-swap`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HxSwap {
+    InnerHtml,
+    OuterHtml,
+    BeforeBegin,
+    AfterBegin,
+    BeforeEnd,
+    AfterEnd,
+    Delete,
+    None,
+}
+
+impl HxSwap {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HxSwap::InnerHtml => "innerHTML",
+            HxSwap::OuterHtml => "outerHTML",
+            HxSwap::BeforeBegin => "beforebegin",
+            HxSwap::AfterBegin => "afterbegin",
+            HxSwap::BeforeEnd => "beforeend",
+            HxSwap::AfterEnd => "afterend",
+            HxSwap::Delete => "delete",
+            HxSwap::None => "none",
+        }
+    }
+}
+
+/// HTMX attributes carried by a component; empty by default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HxAttrs<'a> {
+    pub get: Option<Cow<'a, str>>,
+    pub post: Option<Cow<'a, str>>,
+    pub target: Option<Cow<'a, str>>,
+    pub swap: Option<HxSwap>,
+}
+
+impl<'a> HxAttrs<'a> {
+    /// `hx-get` value
+    pub fn get(&self) -> Option<&str> {
+        self.get.as_deref()
+    }
+
+    /// `hx-post` value
+    pub fn post(&self) -> Option<&str> {
+        self.post.as_deref()
+    }
+
+    /// `hx-target` value
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    /// `hx-swap` value
+    pub fn swap(&self) -> Option<&'static str> {
+        self.swap.as_ref().map(HxSwap::as_str)
+    }
+}
+
+/// Whether a component renders its wrapper element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// The complete component, for full page loads
+    #[default]
+    Full,
+    /// Only the component's contents, for HTMX partial responses
+    Fragment,
+}
+
+impl RenderMode {
+    /// Pick the mode from a request's `HX-Request` header value
+    pub fn from_hx_request(header: Option<&str>) -> Self {
+        match header {
+            Some(value) if value.eq_ignore_ascii_case("true") => RenderMode::Fragment,
+            _ => RenderMode::Full,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hx_attrs_default_empty() {
+        let hx = HxAttrs::default();
+        assert_eq!(hx.get(), None);
+        assert_eq!(hx.swap(), None);
+        assert_eq!(HxSwap::OuterHtml.as_str(), "outerHTML");
+    }
+
+    #[test]
+    fn test_render_mode_from_header() {
+        assert_eq!(
+            RenderMode::from_hx_request(Some("true")),
+            RenderMode::Fragment
+        );
+        assert_eq!(RenderMode::from_hx_request(None), RenderMode::Full);
+        assert_eq!(RenderMode::from_hx_request(Some("false")), RenderMode::Full);
+    }
+}
@@ -0,0 +1,241 @@
+//! Internationalization - site locales and the language switcher
+//!
+//! A [`Locale`] is registered with [`SiteBuilder::locale`]; localized routes
+//! are then generated once per locale under its code, `/en/about` and
+//! `/de/about`. Pages that exist in several locales know each other as
+//! [`Alternate`]s: the builder emits them as `hreflang` links in the head,
+//! and [`LanguageSwitcher`] turns them into a menu for the navbar.
+//!
+//! [`SiteBuilder::locale`]: crate::site::SiteBuilder::locale
+
+use crate::site::PageContext;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// A language the site is published in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    /// BCP 47 tag, used as the path prefix and `lang`, e.g. `pt-BR`
+    pub code: String,
+    /// Name in the language itself, e.g. `Deutsch`
+    pub name: String,
+    /// Written right to left
+    pub rtl: bool,
+}
+
+impl Locale {
+    pub fn new(code: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            name: name.into(),
+            rtl: false,
+        }
+    }
+
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Value for the `dir` attribute
+    pub fn dir(&self) -> &'static str {
+        if self.rtl {
+            "rtl"
+        } else {
+            "ltr"
+        }
+    }
+}
+
+/// The same page in another locale
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternate {
+    pub locale: Locale,
+    pub path: String,
+}
+
+/// Menu linking to the current page in every locale it exists in
+#[derive(Debug, Clone)]
+pub struct LanguageSwitcher<'a> {
+    current: Option<Cow<'a, str>>,
+    options: Vec<(Cow<'a, Locale>, Cow<'a, str>)>,
+    label: Cow<'a, str>,
+}
+
+impl<'a> LanguageSwitcher<'a> {
+    /// A switcher with `current` as the selected locale code
+    pub fn new(current: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            current: Some(current.into()),
+            options: Vec::new(),
+            label: Cow::Borrowed("Language"),
+        }
+    }
+
+    /// The page's own locale and its alternates
+    pub fn for_page(page: &'a PageContext) -> Self {
+        Self {
+            current: page.locale.as_ref().map(|l| Cow::Borrowed(l.code.as_str())),
+            options: page
+                .alternates
+                .iter()
+                .map(|alt| (Cow::Borrowed(&alt.locale), Cow::Borrowed(alt.path.as_str())))
+                .collect(),
+            label: Cow::Borrowed("Language"),
+        }
+    }
+
+    pub fn option(mut self, locale: Locale, href: impl Into<Cow<'a, str>>) -> Self {
+        self.options.push((Cow::Owned(locale), href.into()));
+        self
+    }
+
+    /// Accessible name of the menu (default `Language`)
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+}
+
+impl<'a> Render for LanguageSwitcher<'a> {
+    fn render(&self) -> Markup {
+        if self.options.len() < 2 {
+            return html! {};
+        }
+        let current = self
+            .options
+            .iter()
+            .map(|(locale, _)| locale)
+            .find(|locale| Some(locale.code.as_str()) == self.current.as_deref());
+
+        html! {
+            nav class="sh-language-switcher" aria-label=(self.label) {
+                details class="sh-language-switcher__details" {
+                    summary class="sh-language-switcher__toggle" {
+                        @if let Some(locale) = current {
+                            span lang=(locale.code) { (locale.name) }
+                        } @else {
+                            (self.label)
+                        }
+                    }
+                    ul class="sh-language-switcher__menu" {
+                        @for (locale, href) in &self.options {
+                            @let is_current = Some(locale.code.as_str()) == self.current.as_deref();
+                            li {
+                                a
+                                    class="sh-language-switcher__link"
+                                    href=(href)
+                                    hreflang=(locale.code)
+                                    lang=(locale.code)
+                                    aria-current=[is_current.then_some("page")] {
+                                    (locale.name)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the language switcher
+pub fn i18n_css() -> String {
+    r#"
+.sh-language-switcher {
+    position: relative;
+    display: inline-block;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-language-switcher__toggle {
+    display: inline-flex;
+    align-items: center;
+    gap: 0.375rem;
+    padding: 0.375rem 0.75rem;
+    border: 1px solid var(--sh-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    cursor: pointer;
+    list-style: none;
+}
+
+.sh-language-switcher__toggle::-webkit-details-marker {
+    display: none;
+}
+
+.sh-language-switcher__toggle::after {
+    content: "";
+    width: 0.4em;
+    height: 0.4em;
+    border-right: 2px solid currentColor;
+    border-bottom: 2px solid currentColor;
+    transform: rotate(45deg) translateY(-25%);
+}
+
+.sh-language-switcher__toggle:focus-visible {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 2px;
+}
+
+.sh-language-switcher__menu {
+    position: absolute;
+    inset-inline-end: 0;
+    z-index: 20;
+    min-width: 10rem;
+    margin: 0.25rem 0 0;
+    padding: 0.25rem;
+    list-style: none;
+    background: var(--sh-surface, #ffffff);
+    border: 1px solid var(--sh-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    box-shadow: 0 4px 12px rgb(0 0 0 / 0.1);
+}
+
+.sh-language-switcher__link {
+    display: block;
+    padding: 0.375rem 0.75rem;
+    border-radius: var(--sh-radius-sm, 0.25rem);
+    color: inherit;
+    text-decoration: none;
+}
+
+.sh-language-switcher__link:hover,
+.sh-language-switcher__link:focus-visible {
+    background: var(--sh-surface-hover, #f3f4f6);
+}
+
+.sh-language-switcher__link[aria-current="page"] {
+    font-weight: 600;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_switcher_marks_current() {
+        let html = LanguageSwitcher::new("de")
+            .option(Locale::new("en", "English"), "/en/about")
+            .option(Locale::new("de", "Deutsch"), "/de/about")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<span lang="de">Deutsch</span>"#));
+        assert!(html.contains(
+            r#"href="/de/about" hreflang="de" lang="de" aria-current="page">Deutsch</a>"#
+        ));
+        assert!(html.contains(r#"href="/en/about" hreflang="en" lang="en">English</a>"#));
+    }
+
+    #[test]
+    fn test_language_switcher_hidden_without_alternates() {
+        let html = LanguageSwitcher::new("en")
+            .option(Locale::new("en", "English"), "/en/")
+            .render()
+            .into_string();
+        assert!(html.is_empty());
+        assert_eq!(Locale::new("ar", "العربية").rtl(true).dir(), "rtl");
+    }
+}
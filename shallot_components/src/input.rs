@@ -1,8 +1,10 @@
 use crate::component::{
     AriaAttrs, Component, ComponentSize,
 };
+use crate::form::HtmlConstraints;
+use crate::form_snapshot::FormSnapshot;
 use maud::{html, Markup, Render};
-use shallot_foundation::Icon;
+use shallot_foundation::{ClassList, Icon};
 
 /// Enhanced Input component with comprehensive features
 pub struct Input<'a> {
@@ -52,6 +54,8 @@ pub struct Input<'a> {
     max: Option<&'a str>,
     /// Step increment
     step: Option<&'a str>,
+    /// Minimum length
+    min_length: Option<usize>,
     /// Maximum length
     max_length: Option<usize>,
     /// Auto-focus on mount
@@ -145,6 +149,7 @@ impl<'a> Input<'a> {
             min: None,
             max: None,
             step: None,
+            min_length: None,
             max_length: None,
             autofocus: false,
         }
@@ -285,41 +290,54 @@ impl<'a> Input<'a> {
         self
     }
 
-    /// Build the CSS classes for the input wrapper
-    fn build_wrapper_classes(&self) -> String {
-        let mut classes = vec!["sh-input-wrapper".to_string()];
-
-        classes.push(format!("sh-input-wrapper--{}", self.size.class_suffix()));
-        classes.push(format!("sh-input-wrapper--{}", self.variant.class_suffix()));
-
-        if self.has_error {
-            classes.push("sh-input-wrapper--error".to_string());
-        }
-
-        if self.disabled {
-            classes.push("sh-input-wrapper--disabled".to_string());
-        }
-
-        if self.icon_left.is_some() {
-            classes.push("sh-input-wrapper--has-left-icon".to_string());
+    /// Apply the HTML constraints of a [`FieldValidation`], so the browser
+    /// checks the same rules as the server. A type other than text is kept.
+    ///
+    /// [`FieldValidation`]: crate::form::FieldValidation
+    pub fn constraints(mut self, constraints: &'a HtmlConstraints) -> Self {
+        self.required |= constraints.required;
+        if let (InputType::Text, Some(type_)) = (self.type_, constraints.input_type) {
+            self.type_ = type_;
         }
+        self.pattern = constraints.pattern.as_deref().or(self.pattern);
+        self.min = constraints.min.as_deref().or(self.min);
+        self.max = constraints.max.as_deref().or(self.max);
+        self.step = constraints.step.or(self.step);
+        self.min_length = constraints.min_length.or(self.min_length);
+        self.max_length = constraints.max_length.or(self.max_length);
+        self
+    }
 
-        if self.icon_right.is_some() {
-            classes.push("sh-input-wrapper--has-right-icon".to_string());
+    /// Refill the value and first error submitted under this input's name;
+    /// a password is never refilled
+    pub fn restore(mut self, snapshot: &'a FormSnapshot) -> Self {
+        if self.type_ != InputType::Password {
+            self.value = snapshot.value(self.name).or(self.value);
         }
-
-        if let Some(custom) = self.custom_class {
-            classes.push(custom.to_string());
+        if let Some(error) = snapshot.errors_for(self.name).first().copied() {
+            self = self.error(error);
         }
+        self
+    }
 
-        classes.join(" ")
+    /// Build the CSS classes for the input wrapper
+    fn build_wrapper_classes(&self) -> String {
+        ClassList::new("sh-input-wrapper")
+            .modifier("sh-input-wrapper", self.size.class_suffix())
+            .modifier("sh-input-wrapper", self.variant.class_suffix())
+            .push_if("sh-input-wrapper--error", self.has_error)
+            .push_if("sh-input-wrapper--disabled", self.disabled)
+            .push_if("sh-input-wrapper--has-left-icon", self.icon_left.is_some())
+            .push_if("sh-input-wrapper--has-right-icon", self.icon_right.is_some())
+            .push_opt(self.custom_class)
+            .build()
     }
 
     /// Build the CSS classes for the input element
     fn build_input_classes(&self) -> String {
-        let mut classes = vec!["sh-input".to_string()];
-        classes.push(format!("sh-input--{}", self.size.class_suffix()));
-        classes.join(" ")
+        ClassList::new("sh-input")
+            .modifier("sh-input", self.size.class_suffix())
+            .build()
     }
 
     /// Render an icon
@@ -370,6 +388,7 @@ impl<'a> Render for Input<'a> {
                         min=[self.min]
                         max=[self.max]
                         step=[self.step]
+                        minlength=[self.min_length.map(|m| m.to_string())]
                         maxlength=[self.max_length.map(|m| m.to_string())]
                         autofocus?[self.autofocus]
                         aria-invalid=[if self.has_error { Some("true") } else { None }]
@@ -421,6 +440,7 @@ pub struct Textarea<'a> {
     helper_text: Option<&'a str>,
     size: ComponentSize,
     resize: TextareaResize,
+    min_length: Option<usize>,
     max_length: Option<usize>,
     custom_class: Option<&'a str>,
 }
@@ -462,6 +482,7 @@ impl<'a> Textarea<'a> {
             helper_text: None,
             size: ComponentSize::Md,
             resize: TextareaResize::Both,
+            min_length: None,
             max_length: None,
             custom_class: None,
         }
@@ -507,6 +528,22 @@ impl<'a> Textarea<'a> {
         self.max_length = Some(m);
         self
     }
+    /// Apply the required and length constraints of a [`FieldValidation`];
+    /// textareas have no `pattern` attribute
+    ///
+    /// [`FieldValidation`]: crate::form::FieldValidation
+    pub fn constraints(mut self, c: &HtmlConstraints) -> Self {
+        self.required |= c.required;
+        self.min_length = c.min_length.or(self.min_length);
+        self.max_length = c.max_length.or(self.max_length);
+        self
+    }
+    /// Refill the content and first error submitted under this name
+    pub fn restore(mut self, snapshot: &'a FormSnapshot) -> Self {
+        self.value = snapshot.value(self.name).or(self.value);
+        self.error = snapshot.errors_for(self.name).first().copied().or(self.error);
+        self
+    }
 }
 
 impl<'a> Render for Textarea<'a> {
@@ -536,6 +573,7 @@ impl<'a> Render for Textarea<'a> {
                     required?[self.required]
                     disabled?[self.disabled]
                     readonly?[self.readonly]
+                    minlength=[self.min_length.map(|m| m.to_string())]
                     maxlength=[self.max_length.map(|m| m.to_string())]
                     style=(format!("resize: {}", self.resize.css_value()))
                 {
@@ -594,6 +632,14 @@ impl<'a> Checkbox<'a> {
         self.indeterminate = indeterminate;
         self
     }
+    /// Check the box when the snapshot holds it; once anything was
+    /// submitted, absence means unchecked
+    pub fn restore(mut self, snapshot: &FormSnapshot) -> Self {
+        if !snapshot.values.is_empty() {
+            self.checked = snapshot.value(self.name).is_some();
+        }
+        self
+    }
     pub fn helper_text(mut self, text: &'a str) -> Self {
         self.helper_text = Some(text);
         self
@@ -672,6 +718,13 @@ impl<'a> Radio<'a> {
         self.size = size;
         self
     }
+    /// Check the radio when its value was submitted under its name
+    pub fn restore(mut self, snapshot: &FormSnapshot) -> Self {
+        if !snapshot.values.is_empty() {
+            self.checked = snapshot.values_for(self.name).contains(&self.value);
+        }
+        self
+    }
 }
 
 impl<'a> Render for Radio<'a> {
@@ -735,6 +788,14 @@ impl<'a> Switch<'a> {
         self.size = size;
         self
     }
+    /// Turn the switch on when the snapshot holds it, like
+    /// [`Checkbox::restore`]
+    pub fn restore(mut self, snapshot: &FormSnapshot) -> Self {
+        if !snapshot.values.is_empty() {
+            self.checked = snapshot.value(self.name).is_some();
+        }
+        self
+    }
 }
 
 impl<'a> Render for Switch<'a> {
@@ -828,6 +889,25 @@ impl<'a> Select<'a> {
         self.multiple = m;
         self
     }
+    /// Apply the required constraint of a [`FieldValidation`]
+    ///
+    /// [`FieldValidation`]: crate::form::FieldValidation
+    pub fn constraints(mut self, c: &HtmlConstraints) -> Self {
+        self.required |= c.required;
+        self
+    }
+    /// Select the options submitted under this name and show the first
+    /// error
+    pub fn restore(mut self, snapshot: &'a FormSnapshot) -> Self {
+        if !snapshot.values.is_empty() {
+            let values = snapshot.values_for(self.name);
+            for option in &mut self.options {
+                option.selected = values.contains(&option.value);
+            }
+        }
+        self.error = snapshot.errors_for(self.name).first().copied().or(self.error);
+        self
+    }
 }
 
 impl<'a> Render for Select<'a> {
@@ -1220,6 +1300,56 @@ pub fn input_css() -> String {
   cursor: not-allowed;
   background-color: var(--sh-surface-2);
 }
+
+/* Forced colors: focus rings are box-shadows and checked states are
+   backgrounds, both lost in high-contrast themes */
+@media (forced-colors: active) {
+  .sh-input:focus,
+  .sh-textarea:focus,
+  .sh-select:focus,
+  .sh-input-wrapper--error .sh-input:focus,
+  .sh-checkbox__input:focus + .sh-checkbox__control,
+  .sh-switch__input:focus + .sh-switch__track {
+    outline: 2px solid Highlight;
+    outline-offset: 2px;
+  }
+
+  .sh-input:disabled,
+  .sh-select:disabled {
+    border-color: GrayText;
+    color: GrayText;
+  }
+
+  .sh-checkbox__input:checked + .sh-checkbox__control {
+    background: Highlight;
+    border-color: Highlight;
+  }
+
+  .sh-checkbox__input:checked + .sh-checkbox__control::after {
+    border-color: HighlightText;
+  }
+
+  .sh-radio__input:checked + .sh-radio__control {
+    border-color: Highlight;
+  }
+
+  .sh-radio__input:checked + .sh-radio__control::after {
+    background: Highlight;
+  }
+
+  .sh-switch__thumb {
+    background: ButtonText;
+  }
+
+  .sh-switch__input:checked + .sh-switch__track {
+    background: Highlight;
+    border-color: Highlight;
+  }
+
+  .sh-switch__input:checked + .sh-switch__track .sh-switch__thumb {
+    background: HighlightText;
+  }
+}
 "#.to_string()
 }
 
@@ -1240,6 +1370,31 @@ mod tests {
         assert!(input.required);
     }
 
+    #[test]
+    fn test_constraints_from_field_validation() {
+        use crate::form::{validators, FieldValidation};
+
+        let constraints = FieldValidation::new("email")
+            .add_validator(validators::required())
+            .add_validator(validators::email())
+            .add_validator(validators::max_length(64))
+            .constraints();
+        let html = Input::new("email").constraints(&constraints).render().into_string();
+        assert!(html.contains(r#"type="email""#));
+        assert!(html.contains(" required"));
+        assert!(html.contains(r#"pattern="^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$""#));
+        assert!(html.contains(r#"maxlength="64""#));
+
+        let bio = FieldValidation::new("bio")
+            .add_validator(validators::min_length(10))
+            .constraints();
+        let html = Textarea::new("bio").constraints(&bio).render().into_string();
+        assert!(html.contains(r#"minlength="10""#));
+        assert!(html.contains(" required"));
+        let html = Select::new("plan", vec![]).constraints(&bio).render().into_string();
+        assert!(html.contains(" required"));
+    }
+
     #[test]
     fn test_input_factory_methods() {
         let email = Input::email("contact");
@@ -1274,6 +1429,13 @@ mod tests {
         assert_eq!(textarea.resize, TextareaResize::Vertical);
     }
 
+    #[test]
+    fn test_input_css_forced_colors() {
+        let css = input_css();
+        assert_eq!(shallot_testing::accessibility::forced_colors_issues(&css), vec![]);
+        assert!(css.contains(".sh-switch__thumb {\n    background: ButtonText;"));
+    }
+
     #[test]
     fn test_checkbox() {
         let checkbox = Checkbox::new("Accept terms", "terms")
@@ -0,0 +1,176 @@
+//! Interop - shallot components inside Leptos, Yew and other WASM frameworks
+//!
+//! Components render to plain HTML strings, so any framework that can set
+//! `innerHTML` can display them with the same markup and CSS as the SSR
+//! pages. [`ForeignView`] renders a component once and hands out the HTML
+//! along with a host element class: the host uses `display: contents`, so
+//! wrapping a component in it does not change layout.
+//!
+//! With the `leptos` or `yew` feature enabled, [`leptos::view`] and
+//! [`yew::html`] wrap a component as a view of that framework
+//! (`leptos-ssr` also turns on Leptos's server renderer):
+//!
+//! ```ignore
+//! // Leptos
+//! view! { <p>"Status: " {interop::leptos::view(&Badge::new("New"))}</p> }
+//!
+//! // Yew
+//! html! { <p>{ "Status: " }{ interop::yew::html(&Badge::new("New")) }</p> }
+//! ```
+//!
+//! Include [`crate::all_component_css`] (or the per-component CSS) in the
+//! WASM app's stylesheet as usual.
+
+use maud::{Markup, Render};
+
+/// Class of the element a framework renders a component into
+pub const HOST_CLASS: &str = "sh-host";
+
+/// A rendered component ready to hand to a framework's raw HTML API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignView {
+    html: String,
+}
+
+impl ForeignView {
+    pub fn new(markup: Markup) -> Self {
+        Self {
+            html: markup.into_string(),
+        }
+    }
+
+    /// Render any [`Render`] component
+    pub fn from_component(component: &impl Render) -> Self {
+        Self::new(component.render())
+    }
+
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+
+    pub fn into_html(self) -> String {
+        self.html
+    }
+
+    /// The HTML wrapped in a host element, for frameworks that insert a
+    /// whole HTML string rather than filling an existing element
+    pub fn hosted(&self) -> String {
+        format!(r#"<div class="{HOST_CLASS}">{}</div>"#, self.html)
+    }
+}
+
+impl From<Markup> for ForeignView {
+    fn from(markup: Markup) -> Self {
+        Self::new(markup)
+    }
+}
+
+/// Leptos view functions for shallot components
+#[cfg(feature = "leptos")]
+pub mod leptos {
+    use super::{ForeignView, HOST_CLASS};
+    use ::leptos::prelude::*;
+    use ::maud::{Markup, Render};
+
+    /// `component` as a Leptos view, with the markup of its SSR render
+    pub fn view(component: &impl Render) -> impl IntoView {
+        markup_view(component.render())
+    }
+
+    /// Already rendered markup as a Leptos view
+    pub fn markup_view(markup: Markup) -> impl IntoView {
+        let html = ForeignView::new(markup).into_html();
+        ::leptos::view! { <div class=HOST_CLASS inner_html=html></div> }
+    }
+}
+
+/// Yew view functions for shallot components
+#[cfg(feature = "yew")]
+pub mod yew {
+    use super::{ForeignView, HOST_CLASS};
+    use ::maud::{Markup, Render};
+    use ::yew::{AttrValue, Html};
+
+    /// `component` as Yew [`Html`], with the markup of its SSR render
+    pub fn html(component: &impl Render) -> Html {
+        markup_html(component.render())
+    }
+
+    /// Already rendered markup as Yew [`Html`]
+    pub fn markup_html(markup: Markup) -> Html {
+        let html = AttrValue::from(ForeignView::new(markup).into_html());
+        ::yew::html! {
+            <div class={HOST_CLASS}>{ Html::from_html_unchecked(html) }</div>
+        }
+    }
+}
+
+/// Generate CSS for framework host elements
+pub fn interop_css() -> String {
+    format!(
+        r#"
+.{HOST_CLASS} {{
+    display: contents;
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::badge::Badge;
+    use crate::button::Button;
+
+    #[test]
+    fn test_foreign_view_matches_ssr() {
+        let ssr = Button::new("Save").render().into_string();
+        let view = ForeignView::new(Button::new("Save").render());
+        assert_eq!(view.html(), ssr);
+        assert_eq!(
+            view.hosted(),
+            format!(r#"<div class="sh-host">{ssr}</div>"#)
+        );
+
+        let badge = Badge::new("New");
+        assert_eq!(
+            ForeignView::from_component(&badge).into_html(),
+            badge.render().into_string()
+        );
+    }
+
+    #[cfg(feature = "leptos-ssr")]
+    #[test]
+    fn test_leptos_view_matches_ssr() {
+        use ::leptos::prelude::RenderHtml;
+
+        let ssr = Badge::new("New").render().into_string();
+        let html = super::leptos::view(&Badge::new("New")).to_html();
+        assert_eq!(html, format!(r#"<div class="sh-host">{ssr}</div>"#));
+    }
+
+    #[cfg(feature = "yew")]
+    #[test]
+    fn test_yew_html_wraps_ssr() {
+        use ::yew::virtual_dom::VNode;
+
+        let ssr = Badge::new("New").render().into_string();
+        let VNode::VTag(tag) = super::yew::html(&Badge::new("New")) else {
+            panic!("expected a host element");
+        };
+        assert_eq!(tag.tag(), "div");
+        assert_eq!(
+            tag.attributes.iter().collect::<Vec<_>>(),
+            [("class", "sh-host")]
+        );
+        match tag.children() {
+            Some(VNode::VRaw(raw)) => assert_eq!(raw.html.as_str(), ssr),
+            other => panic!("expected raw HTML, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interop_css() {
+        assert!(interop_css().contains(".sh-host {\n    display: contents;\n}"));
+    }
+}
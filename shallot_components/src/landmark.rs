@@ -0,0 +1,407 @@
+//! Landmarks - semantic page regions with skip navigation
+//!
+//! [`PageHeader`], [`Nav`], [`Main`], [`Aside`] and [`PageFooter`] render
+//! the HTML landmark elements screen readers list for quick navigation.
+//! [`PageLayout`] arranges them into a page: it takes exactly one [`Main`]
+//! in its constructor, so a page built with it cannot end up with two, and
+//! it renders [`SkipLinks`] to the main content and to every labelled
+//! region that has an id.
+//!
+//! Application layouts with a sidebar use [`crate::sidebar::AppShell`],
+//! which provides its own `main` and skip links.
+//!
+//! # Example
+//!
+//! ```
+//! use maud::{html, Render};
+//! use shallot_components::landmark::{Main, Nav, PageFooter, PageHeader, PageLayout};
+//!
+//! let page = PageLayout::new(Main::new(html! { h1 { "Docs" } }))
+//!     .header(PageHeader::new(html! { "Shallot" }))
+//!     .nav(Nav::new("Primary", html! { a href="/" { "Home" } }).id("primary-nav"))
+//!     .footer(PageFooter::new(html! { "© 2026" }));
+//! let html = page.render().into_string();
+//! assert!(html.contains(r##"href="#main""##));
+//! assert!(html.contains(r##"href="#primary-nav""##));
+//! ```
+
+use crate::focus::SkipLinks;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Site banner: logo, title and global actions (`<header>`)
+#[derive(Debug, Clone)]
+pub struct PageHeader<'a> {
+    pub content: Markup,
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> PageHeader<'a> {
+    pub fn new(content: Markup) -> Self {
+        Self { content, id: None }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl<'a> Render for PageHeader<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            header class="sh-page-header" id=[self.id.as_deref()] {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// A block of navigation links (`<nav>`)
+///
+/// The label is required: pages usually have more than one navigation
+/// region and screen readers announce each by its label.
+#[derive(Debug, Clone)]
+pub struct Nav<'a> {
+    pub label: Cow<'a, str>,
+    pub content: Markup,
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Nav<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self {
+            label: label.into(),
+            content,
+            id: None,
+        }
+    }
+
+    /// Give the region an id so [`PageLayout`] adds a skip link to it
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl<'a> Render for Nav<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            nav class="sh-nav" id=[self.id.as_deref()] aria-label=(self.label) {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// The page's primary content (`<main>`), the target of the first skip link
+#[derive(Debug, Clone)]
+pub struct Main<'a> {
+    pub content: Markup,
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> Main<'a> {
+    pub fn new(content: Markup) -> Self {
+        Self {
+            content,
+            id: Cow::Borrowed("main"),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<'a> Render for Main<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            main class="sh-main" id=(self.id) {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// Content related to the main content (`<aside>`)
+#[derive(Debug, Clone)]
+pub struct Aside<'a> {
+    pub content: Markup,
+    pub label: Option<Cow<'a, str>>,
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Aside<'a> {
+    pub fn new(content: Markup) -> Self {
+        Self {
+            content,
+            label: None,
+            id: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Give the region an id so a labelled aside gets a skip link
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl<'a> Render for Aside<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            aside class="sh-aside" id=[self.id.as_deref()] aria-label=[self.label.as_deref()] {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// Site-wide footer (`<footer>`)
+#[derive(Debug, Clone)]
+pub struct PageFooter<'a> {
+    pub content: Markup,
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> PageFooter<'a> {
+    pub fn new(content: Markup) -> Self {
+        Self { content, id: None }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl<'a> Render for PageFooter<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            footer class="sh-page-footer" id=[self.id.as_deref()] {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// A full page of landmarks: skip links, header, navigation, main content
+/// with optional asides, and footer
+#[derive(Debug, Clone)]
+pub struct PageLayout<'a> {
+    pub header: Option<PageHeader<'a>>,
+    pub navs: Vec<Nav<'a>>,
+    pub main: Main<'a>,
+    pub asides: Vec<Aside<'a>>,
+    pub footer: Option<PageFooter<'a>>,
+    pub skip_links: bool,
+}
+
+impl<'a> PageLayout<'a> {
+    pub fn new(main: Main<'a>) -> Self {
+        Self {
+            header: None,
+            navs: Vec::new(),
+            main,
+            asides: Vec::new(),
+            footer: None,
+            skip_links: true,
+        }
+    }
+
+    pub fn header(mut self, header: PageHeader<'a>) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn nav(mut self, nav: Nav<'a>) -> Self {
+        self.navs.push(nav);
+        self
+    }
+
+    pub fn aside(mut self, aside: Aside<'a>) -> Self {
+        self.asides.push(aside);
+        self
+    }
+
+    pub fn footer(mut self, footer: PageFooter<'a>) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// Render skip links ahead of the header (on by default)
+    pub fn skip_links(mut self, skip_links: bool) -> Self {
+        self.skip_links = skip_links;
+        self
+    }
+
+    /// Skip link targets as `(id, label)`, main content first
+    pub fn skip_targets(&self) -> Vec<(&str, String)> {
+        let mut targets = vec![(self.main.id.as_ref(), "Skip to main content".to_string())];
+        for nav in &self.navs {
+            if let Some(id) = &nav.id {
+                targets.push((id.as_ref(), format!("Skip to {}", nav.label)));
+            }
+        }
+        for aside in &self.asides {
+            if let (Some(id), Some(label)) = (&aside.id, &aside.label) {
+                targets.push((id.as_ref(), format!("Skip to {label}")));
+            }
+        }
+        targets
+    }
+}
+
+impl<'a> Render for PageLayout<'a> {
+    fn render(&self) -> Markup {
+        let targets = self.skip_targets();
+        let skip_links = targets
+            .iter()
+            .fold(SkipLinks::new(), |links, (id, label)| links.link(id, label));
+
+        html! {
+            div class="sh-page" {
+                @if self.skip_links {
+                    (skip_links)
+                }
+                @if let Some(header) = &self.header {
+                    (header)
+                }
+                @for nav in &self.navs {
+                    (nav)
+                }
+                @if self.asides.is_empty() {
+                    (self.main)
+                } @else {
+                    div class="sh-page__body" {
+                        (self.main)
+                        @for aside in &self.asides {
+                            (aside)
+                        }
+                    }
+                }
+                @if let Some(footer) = &self.footer {
+                    (footer)
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for landmark layout components
+pub fn landmark_css() -> String {
+    r#"
+.sh-page {
+    display: flex;
+    flex-direction: column;
+    min-height: 100vh;
+}
+
+.sh-page > .sh-main,
+.sh-page__body {
+    flex: 1;
+}
+
+.sh-page__body {
+    display: flex;
+    gap: var(--sh-space-6, 1.5rem);
+}
+
+.sh-page__body > .sh-main {
+    flex: 1;
+    min-width: 0;
+}
+
+.sh-aside {
+    flex: 0 0 var(--sh-aside-w, 280px);
+}
+
+@media (max-width: 768px) {
+    .sh-page__body {
+        flex-direction: column;
+    }
+
+    .sh-aside {
+        flex-basis: auto;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::focus::focus_order_audit;
+
+    fn docs_page() -> PageLayout<'static> {
+        PageLayout::new(Main::new(html! { h1 { "Docs" } }).id("content"))
+            .header(PageHeader::new(html! { "Shallot" }))
+            .nav(Nav::new("Primary", html! { a href="/" { "Home" } }).id("primary-nav"))
+            .nav(Nav::new("Breadcrumb", html! { a href="/docs" { "Docs" } }))
+            .aside(
+                Aside::new(html! { "On this page" })
+                    .label("Table of contents")
+                    .id("toc"),
+            )
+            .footer(PageFooter::new(html! { "Footer" }))
+    }
+
+    #[test]
+    fn test_landmark_elements() {
+        let nav = Nav::new("Primary", html! {}).render().into_string();
+        assert_eq!(nav, r#"<nav class="sh-nav" aria-label="Primary"></nav>"#);
+
+        let main = Main::new(html! { "Body" }).render().into_string();
+        assert_eq!(main, r#"<main class="sh-main" id="main">Body</main>"#);
+
+        let aside = Aside::new(html! {}).label("Related").render().into_string();
+        assert!(aside.contains(r#"<aside class="sh-aside" aria-label="Related">"#));
+        assert!(PageHeader::new(html! {})
+            .render()
+            .into_string()
+            .starts_with("<header"));
+        assert!(PageFooter::new(html! {})
+            .render()
+            .into_string()
+            .starts_with("<footer"));
+    }
+
+    #[test]
+    fn test_page_layout_skip_links() {
+        let page = docs_page();
+        assert_eq!(
+            page.skip_targets(),
+            vec![
+                ("content", "Skip to main content".to_string()),
+                ("primary-nav", "Skip to Primary".to_string()),
+                ("toc", "Skip to Table of contents".to_string()),
+            ]
+        );
+
+        let html = page.render().into_string();
+        assert!(html.contains(r##"href="#content""##));
+        assert!(html.contains(r##"href="#toc""##));
+        assert_eq!(html.matches("<main").count(), 1);
+        assert!(html.find("sh-skip-links").unwrap() < html.find("<header").unwrap());
+        assert!(html.contains(r#"<div class="sh-page__body"><main"#));
+        assert!(focus_order_audit(&html).is_empty());
+
+        let plain = docs_page().skip_links(false).render().into_string();
+        assert!(!plain.contains("sh-skip-links"));
+    }
+
+    #[test]
+    fn test_landmark_css() {
+        let css = landmark_css();
+        assert!(css.contains(".sh-page__body"));
+        assert!(css.contains("--sh-aside-w"));
+    }
+}
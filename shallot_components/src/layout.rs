@@ -1,3 +1,4 @@
+use crate::r#box::SpacingValue;
 use maud::{html, Markup, Render};
 use shallot_foundation::{Breakpoint, ResponsiveValue};
 
@@ -95,8 +96,8 @@ impl Render for Container {
 pub struct Grid {
     children: Markup,
     columns: ResponsiveValue<u8>,
-    gap: u16,
-    row_gap: Option<u16>,
+    gap: SpacingValue,
+    row_gap: Option<SpacingValue>,
     min_child_width: Option<u16>,
     auto_fit: bool,
     class: Option<String>,
@@ -108,7 +109,7 @@ impl Grid {
         Self {
             children,
             columns: ResponsiveValue::new(1).with_sm(2).with_md(3).with_lg(4),
-            gap: 16,
+            gap: SpacingValue::Px(16),
             row_gap: None,
             min_child_width: None,
             auto_fit: false,
@@ -128,15 +129,15 @@ impl Grid {
         self
     }
 
-    /// Set gap between items
-    pub fn gap(mut self, gap: u16) -> Self {
-        self.gap = gap;
+    /// Set gap between items: pixels or a [`SpacingToken`](crate::r#box::SpacingToken)
+    pub fn gap(mut self, gap: impl Into<SpacingValue>) -> Self {
+        self.gap = gap.into();
         self
     }
 
     /// Set row gap
-    pub fn row_gap(mut self, gap: u16) -> Self {
-        self.row_gap = Some(gap);
+    pub fn row_gap(mut self, gap: impl Into<SpacingValue>) -> Self {
+        self.row_gap = Some(gap.into());
         self
     }
 
@@ -156,25 +157,27 @@ impl Grid {
     fn build_style(&self) -> String {
         let gap = self.gap;
         let row_gap = self.row_gap.unwrap_or(gap);
+        let row_gap_style = if row_gap != gap {
+            format!("row-gap: {};", row_gap.css_value())
+        } else {
+            String::new()
+        };
 
         if self.auto_fit && self.min_child_width.is_some() {
             let min_w = self.min_child_width.unwrap();
             format!(
-                "display: grid; grid-template-columns: repeat(auto-fit, minmax({}px, 1fr)); gap: {}px {};",
-                min_w, gap,
-                if row_gap != gap { format!("row-gap: {}px;", row_gap) } else { String::new() }
+                "display: grid; grid-template-columns: repeat(auto-fit, minmax({}px, 1fr)); gap: {}; {}",
+                min_w,
+                gap.css_value(),
+                row_gap_style
             )
         } else {
             let cols = self.columns.get(Breakpoint::Xs).unwrap_or(&1);
             format!(
-                "display: grid; grid-template-columns: repeat({}, minmax(0, 1fr)); gap: {}px {};",
+                "display: grid; grid-template-columns: repeat({}, minmax(0, 1fr)); gap: {}; {}",
                 cols,
-                gap,
-                if row_gap != gap {
-                    format!("row-gap: {}px;", row_gap)
-                } else {
-                    String::new()
-                }
+                gap.css_value(),
+                row_gap_style
             )
         }
     }
@@ -203,7 +206,7 @@ impl Render for Grid {
 /// Stack layout component (flexbox)
 pub struct Stack {
     children: Markup,
-    gap: u16,
+    gap: SpacingValue,
     horizontal: bool,
     wrap: bool,
     align_items: AlignItems,
@@ -260,7 +263,7 @@ impl Stack {
     pub fn new(children: Markup) -> Self {
         Self {
             children,
-            gap: 16,
+            gap: SpacingValue::Px(16),
             horizontal: false,
             wrap: false,
             align_items: AlignItems::Stretch,
@@ -273,7 +276,7 @@ impl Stack {
     pub fn row(children: Markup) -> Self {
         Self {
             children,
-            gap: 16,
+            gap: SpacingValue::Px(16),
             horizontal: true,
             wrap: false,
             align_items: AlignItems::Center,
@@ -282,9 +285,9 @@ impl Stack {
         }
     }
 
-    /// Set gap between items
-    pub fn gap(mut self, gap: u16) -> Self {
-        self.gap = gap;
+    /// Set gap between items: pixels or a [`SpacingToken`](crate::r#box::SpacingToken)
+    pub fn gap(mut self, gap: impl Into<SpacingValue>) -> Self {
+        self.gap = gap.into();
         self
     }
 
@@ -336,8 +339,12 @@ impl Render for Stack {
         let wrap_val = if self.wrap { "wrap" } else { "nowrap" };
 
         let style = format!(
-            "display: flex; flex-direction: {}; gap: {}px; align-items: {}; justify-content: {}; flex-wrap: {};",
-            direction, self.gap, self.align_items.css(), self.justify_content.css(), wrap_val
+            "display: flex; flex-direction: {}; gap: {}; align-items: {}; justify-content: {}; flex-wrap: {};",
+            direction,
+            self.gap.css_value(),
+            self.align_items.css(),
+            self.justify_content.css(),
+            wrap_val
         );
 
         html! {
@@ -417,7 +424,7 @@ impl Render for Divider {
 
 /// Spacer component for flexible spacing
 pub struct Spacer {
-    size: Option<u16>,
+    size: Option<SpacingValue>,
     flex: bool,
 }
 
@@ -431,9 +438,9 @@ impl Spacer {
     }
 
     /// Create a fixed-size spacer
-    pub fn size(size: u16) -> Self {
+    pub fn size(size: impl Into<SpacingValue>) -> Self {
         Self {
-            size: Some(size),
+            size: Some(size.into()),
             flex: false,
         }
     }
@@ -443,8 +450,9 @@ impl Render for Spacer {
     fn render(&self) -> Markup {
         let style = if self.flex {
             "flex: 1;".to_string()
-        } else if let Some(s) = self.size {
-            format!("width: {}px; height: {}px;", s, s)
+        } else if let Some(size) = self.size {
+            let size = size.css_value();
+            format!("width: {}; height: {};", size, size)
         } else {
             String::new()
         };
@@ -459,7 +467,7 @@ impl Render for Spacer {
 pub struct Section {
     children: Markup,
     class: Option<String>,
-    padding_y: u16,
+    padding_y: SpacingValue,
     bg_color: Option<String>,
 }
 
@@ -469,14 +477,14 @@ impl Section {
         Self {
             children,
             class: None,
-            padding_y: 64,
+            padding_y: SpacingValue::Px(64),
             bg_color: None,
         }
     }
 
     /// Set vertical padding
-    pub fn padding_y(mut self, padding: u16) -> Self {
-        self.padding_y = padding;
+    pub fn padding_y(mut self, padding: impl Into<SpacingValue>) -> Self {
+        self.padding_y = padding.into();
         self
     }
 
@@ -500,10 +508,8 @@ impl Render for Section {
             classes.push(custom);
         }
 
-        let mut style = format!(
-            "padding-top: {}px; padding-bottom: {}px;",
-            self.padding_y, self.padding_y
-        );
+        let padding_y = self.padding_y.css_value();
+        let mut style = format!("padding-top: {}; padding-bottom: {};", padding_y, padding_y);
         if let Some(bg) = &self.bg_color {
             style.push_str(&format!(" background-color: {};", bg));
         }
@@ -688,6 +694,7 @@ pub fn layout_css() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::r#box::SpacingToken;
 
     #[test]
     fn test_container() {
@@ -707,7 +714,7 @@ mod tests {
             .min_child_width(200);
 
         assert!(grid.auto_fit);
-        assert_eq!(grid.gap, 24);
+        assert_eq!(grid.gap, SpacingValue::Px(24));
     }
 
     #[test]
@@ -718,10 +725,33 @@ mod tests {
             .justify(JustifyContent::Between);
 
         assert!(stack.horizontal);
-        assert_eq!(stack.gap, 8);
+        assert_eq!(stack.gap, SpacingValue::Px(8));
         assert_eq!(stack.align_items, AlignItems::Center);
     }
 
+    #[test]
+    fn test_spacing_tokens() {
+        let stack = Stack::new(html! { "Items" })
+            .gap(SpacingToken::S4)
+            .render()
+            .into_string();
+        assert!(stack.contains("gap: var(--sh-space-4);"));
+
+        let grid = Grid::new(html! { "Items" })
+            .gap(SpacingToken::S2)
+            .row_gap(SpacingToken::S6)
+            .render()
+            .into_string();
+        assert!(grid.contains("gap: var(--sh-space-2);"));
+        assert!(grid.contains("row-gap: var(--sh-space-6);"));
+
+        let section = Section::new(html! { "Body" })
+            .padding_y(SpacingToken::S12)
+            .render()
+            .into_string();
+        assert!(section.contains("padding-top: var(--sh-space-12);"));
+    }
+
     #[test]
     fn test_divider() {
         let divider = Divider::vertical().inset(true);
@@ -744,6 +774,6 @@ mod tests {
         assert!(flex.flex);
 
         let fixed = Spacer::size(16);
-        assert_eq!(fixed.size, Some(16));
+        assert_eq!(fixed.size, Some(SpacingValue::Px(16)));
     }
 }
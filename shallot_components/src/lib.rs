@@ -6,25 +6,63 @@
 //! - Zero JavaScript philosophy
 
 // Core component system
+pub mod assets;
 pub mod component;
+pub mod css_cache;
+pub mod custom_elements;
+pub mod feed;
+pub mod focus;
+pub mod fonts;
+pub mod htmx;
+pub mod interop;
+pub mod motion;
+pub mod route;
+pub mod seo;
+pub mod i18n;
+pub mod not_found;
+pub mod site;
+pub mod taxonomy;
+pub mod gallery;
+mod signing;
+#[cfg(feature = "turbo")]
+pub mod turbo;
+pub mod view_transitions;
 
 // Layout components
 pub mod r#box;
+pub mod dashboard;
+pub mod landmark;
 pub mod layout;
 pub mod typography;
 
 // Form components
+pub mod address_form;
+pub mod auth_pages;
 pub mod form_validation;
 pub mod input;
 
 // Content components
+pub mod blog;
 pub mod card;
+pub mod changelog;
+pub mod checkout;
+pub mod faq;
+pub mod hero;
+pub mod feature_grid;
+pub mod logo_cloud;
 pub mod code_block;
+pub mod math;
+pub mod terminal;
 pub mod product_card;
+pub mod promo;
+pub mod reviews;
+pub mod responsive_image;
+pub mod share_links;
 
 // Data display components
 pub mod avatar;
 pub mod badge;
+pub mod barcode;
 pub mod skeleton;
 pub mod stats;
 // Skeleton module declared above
@@ -50,11 +88,17 @@ pub mod calendar;
 pub mod carousel;
 pub mod charts;
 pub mod data_display;
+pub mod diagram;
+pub mod gantt;
+pub mod heatmap;
 pub mod timeline;
 
 // Feedback
 pub mod alert;
+pub mod announcement;
+pub mod consent_banner;
 pub mod feedback;
+pub mod live_region;
 pub mod progress;
 pub mod rating;
 pub mod toast;
@@ -64,6 +108,7 @@ pub mod animated_beam;
 pub mod animated_text;
 pub mod border_beam;
 pub mod confetti;
+pub mod countdown;
 pub mod fx;
 pub mod glass_card;
 pub mod glitch_text;
@@ -72,6 +117,7 @@ pub mod magnetic_button;
 pub mod marquee;
 pub mod orbiting_circles;
 pub mod parallax_section;
+pub mod scroll_progress;
 pub mod scroll_reveal;
 pub mod text_reveal;
 pub mod typing_animation;
@@ -85,6 +131,10 @@ pub mod popover;
 pub mod collapsible;
 pub mod footer;
 pub mod form;
+pub mod form_async;
+pub mod form_guard;
+pub mod form_snapshot;
+pub mod form_upload;
 pub mod icon;
 pub mod join;
 pub mod pattern;
@@ -94,6 +144,7 @@ pub mod slider;
 pub mod steps;
 pub mod surface;
 pub mod util;
+pub mod visually_hidden;
 
 // New form components
 pub mod copy_button;
@@ -104,17 +155,26 @@ pub mod date_picker;
 pub mod otp_input;
 pub mod range_slider;
 pub mod time_picker;
+pub mod availability;
+pub mod datetime;
 // Additional form components
 pub mod credit_card_input;
 pub mod glass_select;
 pub mod image_upload;
 pub mod multi_select;
+pub mod newsletter;
+pub mod password_input;
+pub mod phone_input;
 pub mod rich_text;
 
 // State and feedback
 pub mod empty_state;
 pub mod notification_stack;
 
+// Messaging
+pub mod chat;
+pub mod comments;
+
 // Data display
 pub mod key_value_list;
 pub mod site_map;
@@ -164,12 +224,14 @@ pub mod rating_input;
 pub mod description_list;
 pub mod divider;
 pub mod progress_circle;
+pub mod qr_code;
 
 // Signature animated components
 pub mod capdrop;
 pub mod liquid_button;
 pub mod masked_image;
 pub mod mesh_gradient;
+pub mod og_image;
 pub mod refractive_gauge;
 pub mod shadow_elevator;
 
@@ -177,9 +239,35 @@ pub mod shadow_elevator;
 pub use component::{
     component_base_css, AriaAttrs, AriaLive, AriaPopup, AriaRelevance, Component, ComponentColor,
     ComponentShape, ComponentSize, ComponentState, ComponentVariant, IconConfig, IconPosition,
-    Spacing,
+    OverlayEngine, Spacing,
+};
+
+pub use css_cache::{cached, css_bundle, CssBundle};
+
+pub use assets::{
+    write_icons, DisplayMode, IconPurpose, IconResizer, IconSpec, Manifest, ManifestIcon,
+    STANDARD_ICONS,
+};
+#[cfg(feature = "image")]
+pub use assets::ImageResizer;
+
+pub use feed::{Enclosure, Feed, FeedAuthor, FeedFormat, FeedItem};
+
+pub use gallery::{
+    extract_code_examples, gallery_css, Gallery, GalleryCategory, GalleryEntry, GalleryExample,
+};
+pub use seo::{ChangeFreq, Robots, RobotsGroup, SeoFile, Sitemap, SitemapUrl};
+pub use i18n::{i18n_css, Alternate, LanguageSwitcher, Locale};
+pub use not_found::{not_found_css, NotFoundPage};
+pub use site::{
+    Head, Layout, PageContext, Redirect, RedirectManifest, Shell, SiteBuilder, SiteError, SitePage,
 };
+pub use taxonomy::{taxonomy_css, TagCloud, Taxonomy, TaxonomyItem, Term, TermPage};
 
+pub use dashboard::{
+    dashboard_css, Dashboard, DashboardArea, DashboardConfig, DashboardDensity, DashboardPreset,
+    Panel,
+};
 pub use layout::{
     layout_css, AlignItems, AspectRatio, Container, Divider, Grid, JustifyContent, Section, Spacer,
     Stack,
@@ -187,15 +275,39 @@ pub use layout::{
 
 pub use r#box::{
     box_css, BorderStyle, Box, BoxSizing, Center, Cursor, Display, Overflow, Position, SizeValue,
-    SpacingValue,
+    SpacingToken, SpacingValue,
 };
 
+pub use motion::{strip_keyframes, EffectCss, MotionConfig};
+pub use route::{RouteContext, RouteMatch};
+pub use htmx::{HxAttrs, HxSwap, RenderMode};
+pub use interop::{interop_css, ForeignView, HOST_CLASS};
+pub use custom_elements::{custom_elements_css, CustomElement, CustomElementError};
+#[cfg(feature = "turbo")]
+pub use turbo::{TurboFrame, TurboStream, TurboStreamAction, TURBO_STREAM_MIME};
+pub use landmark::{landmark_css, Aside, Main, Nav, PageFooter, PageHeader, PageLayout};
+pub use live_region::LiveRegion;
+pub use visually_hidden::{visually_hidden_css, SkipLink, VisuallyHidden};
+pub use focus::{focus_css, focus_order_audit, FocusIssue, FocusIssueKind, FocusRing, SkipLinks};
+pub use fonts::{
+    font_variation_settings, FluidSize, FontAxis, FontDisplay, FontFace, FontFormat, FontSet,
+    FontSource, TypeScale,
+};
 pub use typography::{
     typography_css, Code, FontFamily, FontWeight, Heading, List, ListItem, ListVariant,
     MarkerStyle, Quote, QuoteVariant, Text, TextAlign, TextColor, TextDecoration, TextSize,
     TextTransform,
 };
 
+pub use auth_pages::{
+    auth_pages_css, AuthLayout, AuthOptions, ForgotPasswordForm, LoginForm, OAuthProvider,
+    RegisterForm, TwoFactorForm,
+};
+
+pub use address_form::{
+    address_form_css, Address, AddressCountry, AddressError, AddressField, AddressForm,
+    ADDRESS_COUNTRIES,
+};
 pub use input::{
     input_css, Checkbox, Input, InputType, InputVariant, Radio, Select, SelectOption, Switch,
     Textarea, TextareaResize,
@@ -206,7 +318,16 @@ pub use form_validation::{
     Valid, ValidationError, ValidationRule,
 };
 
-pub use card::{card_css, Card, CardSection, CardVariant, Elevation};
+pub use blog::{
+    blog_css, reading_time, word_count, ArticleAuthor, ArticleHeader, AuthorBio, RelatedPost,
+    RelatedPosts, WORDS_PER_MINUTE,
+};
+pub use card::{card_css, Card, CardSection, CardVariant};
+pub use shallot_foundation::{CalendarDate, Elevation};
+
+pub use changelog::{
+    changelog_css, Change, ChangeKind, Changelog, Release, SemVer, SemVerError, VersionBump,
+};
 
 pub use calendar::{calendar_css, Calendar, CalendarSize, CalendarVariant};
 
@@ -215,16 +336,57 @@ pub use code_block::{
     Platform,
 };
 
+pub use math::{math_css, Math, MathError, MathSyntax};
+
+pub use terminal::{
+    parse_ansi, strip_ansi, terminal_css, AnsiColor, AnsiSpan, AnsiStyle, LogLevel, Terminal,
+    TerminalLine, WrapMode,
+};
+
+pub use responsive_image::{
+    responsive_image_css, ImageFit, ImageRadius, ImageSource, ResponsiveImage,
+};
+pub use view_transitions::{
+    transition_name, view_transitions_css, RootTransition, ViewTransitions,
+};
+pub use checkout::{
+    checkout_css, CartTable, CartUpdate, Checkout, CheckoutError, Discount, Order, OrderSummary,
+    PaymentSection, ShippingMethod, ShippingOptions, TaxRate, CART_ACTION_FIELD, CART_ITEM_FIELD,
+    CART_QUANTITY_FIELD, SHIPPING_METHOD_FIELD,
+};
+pub use faq::{faq_anchor, faq_css, Faq, FaqItem};
+pub use hero::{
+    hero_css, Cta, CtaGroup, CtaVariant, Hero, HeroBackground, HeroLayout, HeroSize,
+};
+pub use feature_grid::{feature_grid_css, Feature, FeatureGrid, FeatureGridVariant};
+pub use logo_cloud::{logo_cloud_css, Logo, LogoCloud};
 pub use product_card::{
     product_card_css, BadgeStyle, CartItem, CartSummary, ProductCard, ProductCardVariant,
     ProductPrice, QuantityStepper, Rating, RatingSize,
 };
+pub use promo::{
+    format_countdown, normalize_coupon_code, promo_css, CouponInput, CouponState, CouponSubmission,
+    DismissedPromos, PromoBanner, PromoVariant, PROMO_COOKIE, PROMO_DISMISS_PARAM,
+};
+pub use reviews::{
+    format_review_date, reviews_css, HelpfulVote, ReviewCard, ReviewSummary, Testimonial,
+    TestimonialCarousel, REVIEW_ID_FIELD, REVIEW_VOTE_FIELD,
+};
+pub use share_links::{
+    encode_uri_component, mastodon_share_url, share_links_css, ShareLinks, ShareTarget,
+    MASTODON_INSTANCE_FIELD, MASTODON_TEXT_FIELD,
+};
 
 pub use badge::{badge_css, Badge, BadgeVariant, CountBadge, StatusDot};
 
+pub use barcode::{barcode_css, Barcode, BarcodeError, BarcodeFormat};
+
 pub use avatar::{avatar_css, Avatar, AvatarBorder, AvatarGroup, AvatarShape, AvatarStatus};
 
-pub use stats::{stats_css, MetricCard, Stat, StatTrend, Stats, StatsLayout, TrendDirection};
+pub use stats::{
+    percent_change, stats_css, ComparisonBar, DeltaSemantics, MetricCard, Stat, StatDelta,
+    StatTrend, Stats, StatsLayout, TrendDirection,
+};
 
 pub use magic_card::{magic_card_css, MagicCard};
 
@@ -241,6 +403,14 @@ pub use animated_beam::{animated_beam_css, beam_presets, AnimatedBeam, AnimatedB
 pub use button::{button_css, Button, ButtonSize, ButtonVariant};
 
 pub use alert::{alert_css, Alert, AlertKind};
+pub use announcement::{
+    announcement_css, dismiss_param, Announcement, AnnouncementSeverity, DismissedAnnouncements,
+    ANNOUNCEMENT_COOKIE, ANNOUNCEMENT_DISMISS_PARAM,
+};
+pub use consent_banner::{
+    consent_banner_css, ConsentBanner, ConsentCategory, ConsentPosition, ConsentState,
+    CONSENT_COOKIE,
+};
 
 pub use fx::{fx_css, GlowCard, ShimmerButton, ShinyButton};
 
@@ -255,20 +425,48 @@ pub use enhanced_modal::{
 
 pub use glass_card::{glass_css, GlassCard, GlassIntensity, GlassPanel, GlowPosition};
 
+pub use scroll_progress::{scroll_progress_css, ScrollProgress, ScrollProgressPosition};
 pub use scroll_reveal::{
-    scroll_reveal_css, Easing, ParallaxContainer, RevealAnimation, RevealThreshold, ScrollReveal,
-    StaggerContainer,
+    scroll_reveal_css, Easing, ParallaxContainer, RevealAnimation, RevealThreshold, RevealTimeline,
+    ScrollReveal, StaggerContainer, ViewRange,
 };
 
-pub use charts::{charts_css, BarChart, ChartColor, CurveType, Gauge, Sparkline};
+pub use charts::{
+    charts_css, BarChart, ChartColor, CurveType, Gauge, MeterFormat, Sparkline, Thresholds,
+};
+pub use diagram::{
+    diagram_css, Diagram, DiagramError, EdgeStyle, FlowDirection, FlowEdge, FlowNode, Flowchart,
+    Message, MessageKind, NodeShape, Note, NotePlacement, Participant, SequenceDiagram,
+    SequenceStep,
+};
+pub use gantt::{gantt_css, GanttChart, GanttTask, GanttView, TimeScale};
+pub use datetime::{relative_time, DateStyle, DateTime, RelativeThresholds, TimeAgo, Timestamp};
+pub use heatmap::{heatmap_css, ColorScale, ContributionCalendar, Heatmap};
+
+pub use chat::{
+    chat_css, ChatInput, GroupPosition, MessageBubble, MessageDirection, MessageList, ReceiptStatus,
+};
+pub use comments::{comments_css, Comment, CommentThread};
 
 pub use util::css;
 
 pub use form::{
-    form_css, validators, EmailValidator, FieldValidation, Form, FormField as FormUiField,
-    FormGroup, FormGroupLayout, FormSchema, FormSize, FormState, FormVariant, MaxLengthValidator,
-    MinLengthValidator, PatternValidator, RangeValidator, RequiredValidator, UrlValidator,
-    ValidationResult, Validator,
+    form_css, validators, Comparison, Condition, DateOrder, EmailValidator, FieldValidation, Form,
+    FormData, FormErrors, FormField as FormUiField, FormGroup, FormGroupLayout, FormMethodOverride,
+    FormRule, FormSchema, FormSize, FormState, FormVariant, HiddenField, HtmlConstraints,
+    MaxLengthValidator, MinLengthValidator, PasswordPolicy, PasswordReport, PasswordRule,
+    PasswordStrength, PatternValidator, RangeValidator, RequiredValidator, SpamCheck, SpamError,
+    UrlValidator, ValidationResult, Validator, CSRF_FIELD_NAME, TIME_TRAP_FIELD,
+};
+pub use form_async::{
+    AsyncValidator, FormHandler, FormOutcome, TimeoutPolicy, ValidationCtx, ValidationFuture,
+    ValidationState, DEFAULT_ASYNC_TIMEOUT,
+};
+pub use form_guard::{client_key, FormGuard, GuardError, RateLimiter};
+pub use form_snapshot::{FormSnapshot, SnapshotError, SNAPSHOT_MAX_AGE, SNAPSHOT_PARAM};
+pub use form_upload::{
+    image_dimensions, sanitize_filename, sniff_mime, AllowedTypes, FileValidation, FileValidator,
+    ImageDimensions, MaxFileSize, MultipartError, UploadedFile,
 };
 
 pub use collapsible::{collapsible_css, Collapsible};
@@ -279,13 +477,21 @@ pub use slider::{slider_css, Slider, SliderSize, SliderVariant};
 pub use steps::{steps_css, Steps};
 
 // Re-added component exports
-pub use accordion::{accordion_css, Accordion, AccordionItem, AccordionVariant};
+pub use accordion::{accordion_css, Accordion, AccordionItem, AccordionMarker, AccordionVariant};
 pub use avatar_group::{
     avatar_group_css, AvatarGroup as StackedAvatarGroup, AvatarGroupVariant, AvatarItem,
 };
-pub use color_picker::{color_picker_css, ColorPicker, ColorSwatch, ColorSwatches};
-pub use counter::{counter_css, Counter, CounterGroup, CounterLayout, CounterVariant, Statistic};
-pub use dialog::{dialog_css, ConfirmDialog, Dialog, DialogSize, DialogTrigger, DialogVariant};
+pub use color_picker::{
+    color_picker_css, normalize_hex_color, ColorPalette, ColorPicker, ColorSwatch, ColorSwatches,
+    COLOR_PICKER_CUSTOM_VALUE,
+};
+pub use counter::{
+    counter_css, counter_css_for, CountAnimation, CountStart, Counter, CounterGroup, CounterLayout,
+    CounterVariant, NumberFormat, Statistic,
+};
+pub use dialog::{
+    dialog_css, ConfirmDialog, Dialog, DialogSize, DialogStrategy, DialogTrigger, DialogVariant,
+};
 pub use drawer::{drawer_css, Drawer};
 pub use dropdown::{dropdown_css, Dropdown};
 pub use menu::{
@@ -295,15 +501,19 @@ pub use pagination::{
     pagination_css, Pagination, PaginationInfo, PaginationVariant, SimplePagination,
 };
 pub use search_input::{
-    search_input_css, SearchInput, SearchResult, SearchVariant, SearchWithResults,
+    highlight_matches, search_input_css, SearchInput, SearchResult, SearchScope, SearchVariant,
+    SearchWithResults,
 };
 
 pub use dock::{dock_css, Dock};
-pub use navbar::{navbar_css, Navbar};
+pub use navbar::{navbar_css, Navbar, NavbarItem, NavbarLink, NavbarSize, NavbarVariant};
 pub use navigation::navigation_css;
-pub use sidebar::{sidebar_css, Sidebar};
+pub use sidebar::{
+    sidebar_css, AppShell, Sidebar, SidebarNav, SidebarNavItem, SidebarPosition, SidebarSection,
+    SidebarVariant,
+};
 pub use table::{table_css, ColumnAlign, SortDir, Table, TableColumn, TableRow, TableVariant};
-pub use tag_input::{tag_input_css, Tag, TagInput, TagList, TagVariant};
+pub use tag_input::{tag_input_css, Tag, TagInput, TagList, TagSet, TagVariant};
 pub use toggle_group::{
     toggle_group_css, IconButton, IconButtonGroup, ToggleGroup, ToggleItem, ToggleVariant,
 };
@@ -325,13 +535,14 @@ pub use parallax_section::{
     parallax_section_css, ParallaxDirection, ParallaxLayer, ParallaxSection, ParallaxSpeed,
 };
 pub use site_map::{site_map_css, SiteMap};
-pub use table_of_contents::{table_of_contents_css, TableOfContents};
+pub use table_of_contents::{table_of_contents_css, TableOfContents, TocItem, TocVariant};
 pub use video_player::{video_player_css, VideoPlayer};
 
-pub use tabs::{tabs_css, AnimatedTabs, Tab, TabAnimation, TabSize, TabVariant, Tabs};
+pub use tabs::{tabs_css, AnimatedTabs, Tab, TabAnimation, TabSize, TabStrategy, TabVariant, Tabs};
 // Effects - Animation components
 pub use border_beam::{border_beam_css, BorderBeam, BorderBeamSize, BorderBeamVariant};
 pub use confetti::{confetti_css, Confetti, ConfettiShape, ConfettiSize, ConfettiVariant};
+pub use countdown::{countdown_css, countdown_css_for, Countdown};
 pub use orbiting_circles::{
     orbiting_circles_css, OrbitDirection, OrbitingCircle, OrbitingCircles, OrbitingCirclesSize,
     OrbitingCirclesVariant,
@@ -344,12 +555,17 @@ pub use typing_animation::{
 
 pub use bento::{bento_css, BentoCard, BentoCardSize, BentoGrid, BentoVariant};
 pub use breadcrumbs::{breadcrumbs_css, Breadcrumbs};
-pub use carousel::{carousel_css, Carousel};
+pub use carousel::{
+    carousel_css, Carousel, CarouselAnimation, CarouselIndicatorStyle, CarouselSlide,
+};
 pub use timeline::{timeline_css, Timeline, TimelineItem};
 
 pub use liquid_button::{liquid_button_css, LiquidButton, LiquidButtonSize};
 pub use masked_image::{masked_image_css, MaskShape, MaskedImage};
 pub use mesh_gradient::{mesh_gradient_css, MeshGradientBackground};
+pub use og_image::{OgAuthor, OgBackground, OgImage, OgRasterizer, OG_HEIGHT, OG_WIDTH};
+#[cfg(feature = "resvg")]
+pub use og_image::ResvgRasterizer;
 pub use refractive_gauge::{refractive_gauge_css, RefractiveGauge};
 pub use shadow_elevator::{shadow_elevator_css, ShadowElevator};
 
@@ -360,27 +576,61 @@ pub use file_list::{file_list_css, FileList};
 pub use glass_select::{glass_select_css, GlassSelect};
 pub use icon::{icon_css, Icon, IconButton as SimpleIconButton};
 pub use image_upload::{image_upload_css, ImageUpload};
-pub use multi_select::{multi_select_css, MultiSelect};
+pub use availability::{
+    availability_css, AvailabilityDay, AvailabilityPicker, AvailabilitySlot,
+};
+pub use multi_select::{multi_select_css, MultiSelect, MultiSelectOption};
+pub use newsletter::{
+    newsletter_css, NewsletterError, NewsletterForm, NewsletterLayout, NewsletterSignup,
+    NEWSLETTER_CONSENT_FIELD, NEWSLETTER_EMAIL_FIELD,
+};
+pub use password_input::{password_input_css, PasswordInput};
+pub use phone_input::{
+    phone_input_css, PhoneCountry, PhoneError, PhoneInput, PhoneNumber, PHONE_COUNTRIES,
+    PHONE_PATTERN,
+};
 pub use rich_text::{rich_text_css, RichText};
 pub use z_stack::{z_stack_css, ZStack};
 
 pub use feedback::{feedback_css, Spinner};
 pub use progress::{progress_css, ProgressBar};
 pub use progress_circle::{progress_circle_css, ProgressCircle};
+pub use qr_code::{qr_code_css, QrCode, QrEcc, QrError, QrMatrix};
 pub use rating::{rating_css, Rating as StarRating};
 pub use toast::{toast_css, Toast, ToastContainer};
 
 /// Generate all component CSS
 pub fn all_component_css() -> String {
+    all_component_css_for(MotionConfig::default())
+}
+
+/// Generate all component CSS, with effect components at the given motion level
+pub fn all_component_css_for(motion: MotionConfig) -> String {
     let mut css = String::new();
 
     // Base component styles
     css.push_str(&component_base_css());
     css.push('\n');
 
+    // Focus rings and skip links
+    css.push_str(&focus_css());
+    css.push('\n');
+    css.push_str(&visually_hidden_css());
+    css.push('\n');
+    css.push_str(&interop_css());
+    css.push('\n');
+    css.push_str(&custom_elements_css());
+    css.push('\n');
+
     // Layout
     css.push_str(&layout_css());
     css.push('\n');
+    css.push_str(&landmark_css());
+    css.push('\n');
+
+    // Dashboard
+    css.push_str(&dashboard_css());
+    css.push('\n');
 
     // Navigation
     css.push_str(&navbar_css());
@@ -396,6 +646,14 @@ pub fn all_component_css() -> String {
     css.push_str(&alert_css());
     css.push('\n');
 
+    // Announcement
+    css.push_str(&announcement_css());
+    css.push('\n');
+
+    // Consent Banner
+    css.push_str(&consent_banner_css());
+    css.push('\n');
+
     // Button
     css.push_str(&button_css());
     css.push('\n');
@@ -408,10 +666,26 @@ pub fn all_component_css() -> String {
     css.push_str(&input_css());
     css.push('\n');
 
+    // Auth Pages
+    css.push_str(&auth_pages_css());
+    css.push('\n');
+
+    // Address Form
+    css.push_str(&address_form_css());
+    css.push('\n');
+
     // Card
     css.push_str(&card_css());
     css.push('\n');
 
+    // Changelog
+    css.push_str(&changelog_css());
+    css.push('\n');
+
+    // Blog
+    css.push_str(&blog_css());
+    css.push('\n');
+
     // Badge
     css.push_str(&badge_css());
     css.push('\n');
@@ -468,6 +742,18 @@ pub fn all_component_css() -> String {
     css.push_str(&charts_css());
     css.push('\n');
 
+    // Diagrams
+    css.push_str(&diagram_css());
+    css.push('\n');
+
+    // Gantt
+    css.push_str(&gantt_css());
+    css.push('\n');
+
+    // Heatmap
+    css.push_str(&heatmap_css());
+    css.push('\n');
+
     // Calendar
     css.push_str(&calendar_css());
     css.push('\n');
@@ -476,10 +762,53 @@ pub fn all_component_css() -> String {
     css.push_str(&code_block_css());
     css.push('\n');
 
+    // Math
+    css.push_str(&math::math_css());
+    css.push('\n');
+
+    // Terminal
+    css.push_str(&terminal_css());
+    css.push('\n');
+
     // Product Card
     css.push_str(&product_card_css());
     css.push('\n');
 
+    // Checkout
+    css.push_str(&checkout_css());
+    css.push('\n');
+
+    // FAQ
+    css.push_str(&faq_css());
+    css.push('\n');
+
+    // Hero
+    css.push_str(&hero_css());
+    css.push('\n');
+
+    // Feature grid
+    css.push_str(&feature_grid_css());
+    css.push('\n');
+
+    // Logo cloud
+    css.push_str(&logo_cloud_css());
+    css.push('\n');
+
+    // Promo
+    css.push_str(&promo_css());
+    css.push('\n');
+
+    // Reviews
+    css.push_str(&reviews_css());
+    css.push('\n');
+
+    // Share Links
+    css.push_str(&share_links_css());
+    css.push('\n');
+
+    css.push_str(&responsive_image_css());
+    css.push('\n');
+
     // Re-added components
     css.push_str(&table_css());
     css.push('\n');
@@ -523,7 +852,7 @@ pub fn all_component_css() -> String {
     css.push_str(&color_picker_css());
     css.push('\n');
 
-    css.push_str(&counter_css());
+    css.push_str(&counter::counter_css_for(motion));
     css.push('\n');
 
     // List components
@@ -540,6 +869,8 @@ pub fn all_component_css() -> String {
 
     css.push_str(&time_picker::time_picker_css());
     css.push('\n');
+    css.push_str(&availability_css());
+    css.push('\n');
 
     css.push_str(&otp_input::otp_input_css());
     css.push('\n');
@@ -554,6 +885,13 @@ pub fn all_component_css() -> String {
     css.push_str(&notification_stack::notification_stack_css());
     css.push('\n');
 
+    // New components - Messaging
+    css.push_str(&chat::chat_css());
+    css.push('\n');
+
+    css.push_str(&comments::comments_css());
+    css.push('\n');
+
     // New components - Data display
     css.push_str(&tree_view::tree_view_css());
     css.push('\n');
@@ -585,6 +923,18 @@ pub fn all_component_css() -> String {
     css.push_str(&credit_card_input_css());
     css.push('\n');
 
+    // Password Input
+    css.push_str(&password_input_css());
+    css.push('\n');
+
+    // Phone Input
+    css.push_str(&phone_input_css());
+    css.push('\n');
+
+    // Newsletter
+    css.push_str(&newsletter_css());
+    css.push('\n');
+
     // Glass Select
     css.push_str(&glass_select_css());
     css.push('\n');
@@ -625,7 +975,10 @@ pub fn all_component_css() -> String {
     css.push_str(&surface::surface_css());
     css.push('\n');
 
-    css.push_str(&marquee::marquee_css());
+    css.push_str(&marquee::marquee_css_for(motion));
+    css.push('\n');
+
+    css.push_str(&countdown::countdown_css_for(motion));
     css.push('\n');
 
     css.push_str(&bento::bento_css());
@@ -656,6 +1009,12 @@ pub fn all_component_css() -> String {
     css.push_str(&progress_circle::progress_circle_css());
     css.push('\n');
 
+    css.push_str(&qr_code::qr_code_css());
+    css.push('\n');
+
+    css.push_str(&barcode::barcode_css());
+    css.push('\n');
+
     css.push_str(&divider::divider_css());
     css.push('\n');
 
@@ -678,14 +1037,23 @@ pub fn all_component_css() -> String {
     css.push_str(&shadow_elevator_css());
     css.push('\n');
 
-    css.push_str(&glitch_text_css());
+    css.push_str(&glitch_text::glitch_text_css_for(motion));
     css.push('\n');
 
     css.push_str(&parallax_section_css());
     css.push('\n');
 
+    css.push_str(&scroll_progress_css());
+    css.push('\n');
+
     css.push_str(&tabs_css());
     css.push('\n');
+    css.push_str(&taxonomy_css());
+    css.push('\n');
+    css.push_str(&not_found_css());
+    css.push('\n');
+    css.push_str(&i18n_css());
+    css.push('\n');
 
     // Form components
     css.push_str(&form_css());
@@ -746,15 +1114,15 @@ pub fn all_component_css() -> String {
     css.push('\n');
 
     // BorderBeam
-    css.push_str(&border_beam::border_beam_css());
+    css.push_str(&border_beam::border_beam_css_for(motion));
     css.push('\n');
 
     // Confetti
-    css.push_str(&confetti::confetti_css());
+    css.push_str(&confetti::confetti_css_for(motion));
     css.push('\n');
 
     // OrbitingCircles
-    css.push_str(&orbiting_circles::orbiting_circles_css());
+    css.push_str(&orbiting_circles::orbiting_circles_css_for(motion));
     css.push('\n');
 
     // Pattern
@@ -766,7 +1134,7 @@ pub fn all_component_css() -> String {
     css.push('\n');
 
     // TypingAnimation
-    css.push_str(&typing_animation::typing_animation_css());
+    css.push_str(&typing_animation::typing_animation_css_for(motion));
     css.push('\n');
 
     css
@@ -795,4 +1163,14 @@ mod tests {
         assert!(!css.is_empty());
         assert!(css.contains(".sh-"));
     }
+
+    #[test]
+    fn test_css_generation_motion() {
+        assert!(all_component_css().contains("@keyframes confetti-fall"));
+
+        let css = all_component_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes confetti-fall"));
+        assert!(!css.contains("@keyframes orbit-spin"));
+        assert!(css.contains(".sh-marquee__track {\n    animation: none;"));
+    }
 }
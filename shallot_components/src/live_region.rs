@@ -0,0 +1,156 @@
+//! Live Region Component - Announces server-rendered updates to screen readers
+//!
+//! A page that re-renders after a form post or a redirect has no script to
+//! push messages to assistive technology. Marking the message container as
+//! a live region makes screen readers read it out when it appears:
+//! [`AriaLive::Polite`] regions get `role="status"` and wait for the reader
+//! to go idle, [`AriaLive::Assertive`] regions get `role="alert"` and
+//! interrupt.
+//!
+//! # Example
+//!
+//! ```
+//! use maud::{html, Render};
+//! use shallot_components::live_region::LiveRegion;
+//!
+//! let region = LiveRegion::polite(html! { "3 items added to your cart" }).visually_hidden(true);
+//! let html = region.render().into_string();
+//! assert!(html.contains(r#"role="status""#));
+//! assert!(html.contains(r#"aria-live="polite""#));
+//! ```
+
+use crate::component::{AriaLive, AriaRelevance};
+use maud::{html, Markup, Render};
+
+/// A container whose content changes are announced
+#[derive(Debug, Clone)]
+pub struct LiveRegion<'a> {
+    pub content: Markup,
+    pub politeness: AriaLive,
+    pub atomic: bool,
+    pub relevant: Option<AriaRelevance>,
+    pub busy: bool,
+    pub visually_hidden: bool,
+    pub id: Option<&'a str>,
+}
+
+impl<'a> LiveRegion<'a> {
+    /// Create a live region with the given politeness
+    pub fn new(content: Markup, politeness: AriaLive) -> Self {
+        Self {
+            content,
+            politeness,
+            atomic: true,
+            relevant: None,
+            busy: false,
+            visually_hidden: false,
+            id: None,
+        }
+    }
+
+    /// Region read out once the screen reader is idle (`role="status"`)
+    pub fn polite(content: Markup) -> Self {
+        Self::new(content, AriaLive::Polite)
+    }
+
+    /// Region read out immediately (`role="alert"`)
+    pub fn assertive(content: Markup) -> Self {
+        Self::new(content, AriaLive::Assertive)
+    }
+
+    /// Read the whole region on every change rather than just the changed node
+    pub fn atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    /// Which kinds of change are announced
+    pub fn relevant(mut self, relevant: AriaRelevance) -> Self {
+        self.relevant = Some(relevant);
+        self
+    }
+
+    /// Hold announcements while the content is still loading
+    pub fn busy(mut self, busy: bool) -> Self {
+        self.busy = busy;
+        self
+    }
+
+    /// Keep the region out of the visual layout
+    pub fn visually_hidden(mut self, hidden: bool) -> Self {
+        self.visually_hidden = hidden;
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl<'a> Render for LiveRegion<'a> {
+    fn render(&self) -> Markup {
+        let class = if self.visually_hidden {
+            "sh-live-region sh-visually-hidden"
+        } else {
+            "sh-live-region"
+        };
+
+        html! {
+            div
+                class=(class)
+                id=[self.id]
+                role=[self.politeness.role()]
+                aria-live=(self.politeness.as_str())
+                aria-atomic=(self.atomic)
+                aria-relevant=[self.relevant.map(|r| r.as_str())]
+                aria-busy=[self.busy.then_some("true")]
+            {
+                (self.content)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_region_polite() {
+        let html = LiveRegion::polite(html! { "Saved" })
+            .id("save-status")
+            .render()
+            .into_string();
+        assert_eq!(
+            html,
+            r#"<div class="sh-live-region" id="save-status" role="status" aria-live="polite" aria-atomic="true">Saved</div>"#
+        );
+    }
+
+    #[test]
+    fn test_live_region_assertive() {
+        let html = LiveRegion::assertive(html! { "Payment failed" })
+            .atomic(false)
+            .relevant(AriaRelevance::Additions)
+            .busy(true)
+            .visually_hidden(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"class="sh-live-region sh-visually-hidden""#));
+        assert!(html.contains(r#"role="alert""#));
+        assert!(html.contains(r#"aria-live="assertive""#));
+        assert!(html.contains(r#"aria-atomic="false""#));
+        assert!(html.contains(r#"aria-relevant="additions""#));
+        assert!(html.contains(r#"aria-busy="true""#));
+    }
+
+    #[test]
+    fn test_live_region_off() {
+        let html = LiveRegion::new(html! { "Quiet" }, AriaLive::Off)
+            .render()
+            .into_string();
+        assert!(!html.contains("role="));
+        assert!(html.contains(r#"aria-live="off""#));
+    }
+}
@@ -0,0 +1,252 @@
+//! Logo Cloud Component - "Trusted by" rows of customer logos
+//!
+//! Logos render grayscale and regain their colors on hover or focus. The
+//! marquee mode scrolls the row with [`Marquee`] instead of wrapping it.
+
+use crate::marquee::{Marquee, MarqueeVariant};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// A single logo image
+#[derive(Debug, Clone)]
+pub struct Logo<'a> {
+    pub src: Cow<'a, str>,
+    /// Company name, used as the image alt text
+    pub name: Cow<'a, str>,
+    pub href: Option<Cow<'a, str>>,
+}
+
+impl<'a> Logo<'a> {
+    pub fn new(src: impl Into<Cow<'a, str>>, name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            src: src.into(),
+            name: name.into(),
+            href: None,
+        }
+    }
+
+    /// Link the logo, e.g. to a case study
+    pub fn href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+}
+
+impl<'a> Render for Logo<'a> {
+    fn render(&self) -> Markup {
+        let image = html! {
+            img class="sh-logo-cloud__image" src=(self.src) alt=(self.name) loading="lazy" decoding="async";
+        };
+        html! {
+            @if let Some(href) = &self.href {
+                a class="sh-logo-cloud__logo" href=(href) { (image) }
+            } @else {
+                span class="sh-logo-cloud__logo" { (image) }
+            }
+        }
+    }
+}
+
+/// Row of [`Logo`]s with an optional heading
+#[derive(Debug, Clone)]
+pub struct LogoCloud<'a> {
+    pub logos: Vec<Logo<'a>>,
+    pub title: Option<Cow<'a, str>>,
+    pub grayscale: bool,
+    /// Scroll duration in seconds when rendered as a marquee
+    pub marquee: Option<f32>,
+}
+
+impl<'a> LogoCloud<'a> {
+    pub fn new(logos: Vec<Logo<'a>>) -> Self {
+        Self {
+            logos,
+            title: None,
+            grayscale: true,
+            marquee: None,
+        }
+    }
+
+    pub fn logo(mut self, logo: Logo<'a>) -> Self {
+        self.logos.push(logo);
+        self
+    }
+
+    /// Heading above the row, e.g. "Trusted by teams at"
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Render logos in grayscale until hovered (default: true)
+    pub fn grayscale(mut self, grayscale: bool) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+
+    /// Scroll the logos continuously, one loop every `speed_s` seconds
+    pub fn marquee(mut self, speed_s: f32) -> Self {
+        self.marquee = Some(speed_s);
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = String::from("sh-logo-cloud");
+        if self.grayscale {
+            classes.push_str(" sh-logo-cloud--grayscale");
+        }
+        if self.marquee.is_some() {
+            classes.push_str(" sh-logo-cloud--marquee");
+        }
+        classes
+    }
+}
+
+impl<'a> Render for LogoCloud<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            section class=(self.build_classes()) aria-label=[self.title.as_deref()] {
+                @if let Some(title) = &self.title {
+                    p class="sh-logo-cloud__title" { (title) }
+                }
+                @if let Some(speed) = self.marquee {
+                    (Marquee::new(self.logos.iter().map(Render::render).collect())
+                        .variant(MarqueeVariant::Gradient)
+                        .speed(speed)
+                        .gap(48))
+                } @else {
+                    ul class="sh-logo-cloud__list" {
+                        @for logo in &self.logos {
+                            li class="sh-logo-cloud__item" { (logo) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the logo cloud
+pub fn logo_cloud_css() -> String {
+    r#"
+.sh-logo-cloud {
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    gap: 1.5rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-logo-cloud__title {
+    margin: 0;
+    font-size: 0.875rem;
+    font-weight: 600;
+    letter-spacing: 0.04em;
+    text-transform: uppercase;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-logo-cloud__list {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: center;
+    gap: 2rem 3rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-logo-cloud__logo {
+    display: inline-flex;
+    align-items: center;
+}
+
+.sh-logo-cloud__image {
+    display: block;
+    width: auto;
+    max-width: 8rem;
+    height: 2rem;
+    object-fit: contain;
+    transition: filter 0.2s ease, opacity 0.2s ease;
+}
+
+.sh-logo-cloud--grayscale .sh-logo-cloud__image {
+    filter: grayscale(1);
+    opacity: 0.6;
+}
+
+.sh-logo-cloud--grayscale .sh-logo-cloud__logo:hover .sh-logo-cloud__image,
+.sh-logo-cloud--grayscale .sh-logo-cloud__logo:focus-visible .sh-logo-cloud__image {
+    filter: none;
+    opacity: 1;
+}
+
+.sh-logo-cloud--marquee {
+    align-items: stretch;
+}
+
+.sh-logo-cloud--marquee .sh-logo-cloud__title {
+    text-align: center;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-logo-cloud .sh-marquee__track {
+        animation: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logos() -> Vec<Logo<'static>> {
+        vec![
+            Logo::new("/logos/acme.svg", "Acme"),
+            Logo::new("/logos/globex.svg", "Globex").href("/customers/globex"),
+        ]
+    }
+
+    #[test]
+    fn test_logo_cloud_render() {
+        let html = LogoCloud::new(logos())
+            .title("Trusted by teams at")
+            .render()
+            .into_string();
+        assert!(html.contains("sh-logo-cloud sh-logo-cloud--grayscale"));
+        assert!(html.contains(r#"aria-label="Trusted by teams at""#));
+        assert!(html.contains(r#"alt="Acme""#));
+        assert!(html.contains(r#"<a class="sh-logo-cloud__logo" href="/customers/globex">"#));
+        assert_eq!(html.matches("sh-logo-cloud__item").count(), 2);
+        assert!(!html.contains("sh-marquee"));
+    }
+
+    #[test]
+    fn test_logo_cloud_color() {
+        let html = LogoCloud::new(logos())
+            .grayscale(false)
+            .render()
+            .into_string();
+        assert!(!html.contains("sh-logo-cloud--grayscale"));
+        assert!(!html.contains("aria-label"));
+    }
+
+    #[test]
+    fn test_logo_cloud_marquee() {
+        let html = LogoCloud::new(logos()).marquee(30.0).render().into_string();
+        assert!(html.contains("sh-logo-cloud--marquee"));
+        assert!(html.contains("sh-marquee sh-marquee--gradient"));
+        assert!(html.contains("--sh-marquee-dur: 30s"));
+        assert!(!html.contains("sh-logo-cloud__list"));
+    }
+
+    #[test]
+    fn test_logo_cloud_css() {
+        let css = logo_cloud_css();
+        assert!(css.contains("grayscale(1)"));
+        assert!(css.contains(":hover"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -144,7 +145,13 @@ impl Render for Marquee {
 }
 
 pub fn marquee_css() -> String {
-    r#"
+    marquee_css_for(MotionConfig::default())
+}
+
+/// Marquee CSS at the given motion level
+pub fn marquee_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-marquee {
     --sh-marquee-dur: 18s;
     --sh-marquee-gap: 16px;
@@ -216,8 +223,18 @@ pub fn marquee_css() -> String {
 .sh-marquee--right {
     direction: rtl;
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-marquee {
+    overflow-x: auto;
+}
+
+.sh-marquee__track {
+    animation: none;
+}
+"#,
+        off: "",
+    })
 }
 
 #[cfg(test)]
@@ -307,4 +324,11 @@ mod tests {
         assert!(css.contains(".sh-marquee"));
         assert!(css.contains("@keyframes scroll"));
     }
+
+    #[test]
+    fn test_marquee_motion() {
+        let css = marquee_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes"));
+        assert!(css.contains("overflow-x: auto;"));
+    }
 }
@@ -0,0 +1,1296 @@
+//! Math Component - LaTeX and AsciiMath rendered as MathML
+//!
+//! Converts a practical subset of LaTeX (fractions, roots, scripts, big
+//! operators, `\left`/`\right`, matrices, font commands, accents) or
+//! AsciiMath into MathML Core, which every evergreen browser renders
+//! natively. No MathJax or KaTeX runtime is needed; the source is kept as
+//! `alttext` and as an annotation, and is shown as plain text if it cannot
+//! be parsed.
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Input notation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MathSyntax {
+    #[default]
+    Latex,
+    AsciiMath,
+}
+
+impl MathSyntax {
+    fn encoding(&self) -> &'static str {
+        match self {
+            MathSyntax::Latex => "application/x-tex",
+            MathSyntax::AsciiMath => "text/x-asciimath",
+        }
+    }
+}
+
+/// Reason a formula could not be converted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+    UnexpectedEnd,
+    Unexpected { found: String, offset: usize },
+    UnknownCommand(String),
+    UnknownEnvironment(String),
+    MismatchedEnvironment { expected: String, found: String },
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "Formula ends unexpectedly"),
+            Self::Unexpected { found, offset } => {
+                write!(f, "Unexpected {:?} at offset {}", found, offset)
+            }
+            Self::UnknownCommand(name) => write!(f, "Unknown command \\{}", name),
+            Self::UnknownEnvironment(name) => write!(f, "Unknown environment {}", name),
+            Self::MismatchedEnvironment { expected, found } => {
+                write!(f, "\\begin{{{}}} closed by \\end{{{}}}", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sym {
+    Ident(&'static str),
+    Op(&'static str),
+    /// Big operator; `true` places limits above and below
+    Large(&'static str, bool),
+    Func(&'static str),
+}
+
+/// LaTeX command names (without the backslash)
+const SYMBOLS: &[(&str, Sym)] = &[
+    ("alpha", Sym::Ident("α")),
+    ("beta", Sym::Ident("β")),
+    ("gamma", Sym::Ident("γ")),
+    ("delta", Sym::Ident("δ")),
+    ("epsilon", Sym::Ident("ϵ")),
+    ("varepsilon", Sym::Ident("ε")),
+    ("zeta", Sym::Ident("ζ")),
+    ("eta", Sym::Ident("η")),
+    ("theta", Sym::Ident("θ")),
+    ("vartheta", Sym::Ident("ϑ")),
+    ("iota", Sym::Ident("ι")),
+    ("kappa", Sym::Ident("κ")),
+    ("lambda", Sym::Ident("λ")),
+    ("mu", Sym::Ident("μ")),
+    ("nu", Sym::Ident("ν")),
+    ("xi", Sym::Ident("ξ")),
+    ("pi", Sym::Ident("π")),
+    ("varpi", Sym::Ident("ϖ")),
+    ("rho", Sym::Ident("ρ")),
+    ("varrho", Sym::Ident("ϱ")),
+    ("sigma", Sym::Ident("σ")),
+    ("varsigma", Sym::Ident("ς")),
+    ("tau", Sym::Ident("τ")),
+    ("upsilon", Sym::Ident("υ")),
+    ("phi", Sym::Ident("ϕ")),
+    ("varphi", Sym::Ident("φ")),
+    ("chi", Sym::Ident("χ")),
+    ("psi", Sym::Ident("ψ")),
+    ("omega", Sym::Ident("ω")),
+    ("Gamma", Sym::Ident("Γ")),
+    ("Delta", Sym::Ident("Δ")),
+    ("Theta", Sym::Ident("Θ")),
+    ("Lambda", Sym::Ident("Λ")),
+    ("Xi", Sym::Ident("Ξ")),
+    ("Pi", Sym::Ident("Π")),
+    ("Sigma", Sym::Ident("Σ")),
+    ("Upsilon", Sym::Ident("Υ")),
+    ("Phi", Sym::Ident("Φ")),
+    ("Psi", Sym::Ident("Ψ")),
+    ("Omega", Sym::Ident("Ω")),
+    ("infty", Sym::Ident("∞")),
+    ("partial", Sym::Ident("∂")),
+    ("nabla", Sym::Ident("∇")),
+    ("emptyset", Sym::Ident("∅")),
+    ("varnothing", Sym::Ident("∅")),
+    ("hbar", Sym::Ident("ℏ")),
+    ("ell", Sym::Ident("ℓ")),
+    ("aleph", Sym::Ident("ℵ")),
+    ("Re", Sym::Ident("ℜ")),
+    ("Im", Sym::Ident("ℑ")),
+    ("pm", Sym::Op("±")),
+    ("mp", Sym::Op("∓")),
+    ("times", Sym::Op("×")),
+    ("div", Sym::Op("÷")),
+    ("cdot", Sym::Op("⋅")),
+    ("ast", Sym::Op("∗")),
+    ("star", Sym::Op("⋆")),
+    ("circ", Sym::Op("∘")),
+    ("bullet", Sym::Op("∙")),
+    ("leq", Sym::Op("≤")),
+    ("le", Sym::Op("≤")),
+    ("geq", Sym::Op("≥")),
+    ("ge", Sym::Op("≥")),
+    ("neq", Sym::Op("≠")),
+    ("ne", Sym::Op("≠")),
+    ("approx", Sym::Op("≈")),
+    ("equiv", Sym::Op("≡")),
+    ("sim", Sym::Op("∼")),
+    ("simeq", Sym::Op("≃")),
+    ("cong", Sym::Op("≅")),
+    ("propto", Sym::Op("∝")),
+    ("ll", Sym::Op("≪")),
+    ("gg", Sym::Op("≫")),
+    ("to", Sym::Op("→")),
+    ("rightarrow", Sym::Op("→")),
+    ("leftarrow", Sym::Op("←")),
+    ("leftrightarrow", Sym::Op("↔")),
+    ("Rightarrow", Sym::Op("⇒")),
+    ("Leftarrow", Sym::Op("⇐")),
+    ("Leftrightarrow", Sym::Op("⇔")),
+    ("implies", Sym::Op("⟹")),
+    ("iff", Sym::Op("⟺")),
+    ("mapsto", Sym::Op("↦")),
+    ("in", Sym::Op("∈")),
+    ("notin", Sym::Op("∉")),
+    ("ni", Sym::Op("∋")),
+    ("subset", Sym::Op("⊂")),
+    ("supset", Sym::Op("⊃")),
+    ("subseteq", Sym::Op("⊆")),
+    ("supseteq", Sym::Op("⊇")),
+    ("cup", Sym::Op("∪")),
+    ("cap", Sym::Op("∩")),
+    ("setminus", Sym::Op("∖")),
+    ("wedge", Sym::Op("∧")),
+    ("land", Sym::Op("∧")),
+    ("vee", Sym::Op("∨")),
+    ("lor", Sym::Op("∨")),
+    ("neg", Sym::Op("¬")),
+    ("lnot", Sym::Op("¬")),
+    ("forall", Sym::Op("∀")),
+    ("exists", Sym::Op("∃")),
+    ("mid", Sym::Op("∣")),
+    ("parallel", Sym::Op("∥")),
+    ("perp", Sym::Op("⊥")),
+    ("angle", Sym::Op("∠")),
+    ("ldots", Sym::Op("…")),
+    ("dots", Sym::Op("…")),
+    ("cdots", Sym::Op("⋯")),
+    ("vdots", Sym::Op("⋮")),
+    ("ddots", Sym::Op("⋱")),
+    ("prime", Sym::Op("′")),
+    ("langle", Sym::Op("⟨")),
+    ("rangle", Sym::Op("⟩")),
+    ("lfloor", Sym::Op("⌊")),
+    ("rfloor", Sym::Op("⌋")),
+    ("lceil", Sym::Op("⌈")),
+    ("rceil", Sym::Op("⌉")),
+    ("vert", Sym::Op("|")),
+    ("Vert", Sym::Op("‖")),
+    ("colon", Sym::Op(":")),
+    ("sum", Sym::Large("∑", true)),
+    ("prod", Sym::Large("∏", true)),
+    ("coprod", Sym::Large("∐", true)),
+    ("bigcup", Sym::Large("⋃", true)),
+    ("bigcap", Sym::Large("⋂", true)),
+    ("lim", Sym::Large("lim", true)),
+    ("max", Sym::Large("max", true)),
+    ("min", Sym::Large("min", true)),
+    ("sup", Sym::Large("sup", true)),
+    ("inf", Sym::Large("inf", true)),
+    ("int", Sym::Large("∫", false)),
+    ("iint", Sym::Large("∬", false)),
+    ("iiint", Sym::Large("∭", false)),
+    ("oint", Sym::Large("∮", false)),
+    ("sin", Sym::Func("sin")),
+    ("cos", Sym::Func("cos")),
+    ("tan", Sym::Func("tan")),
+    ("cot", Sym::Func("cot")),
+    ("sec", Sym::Func("sec")),
+    ("csc", Sym::Func("csc")),
+    ("arcsin", Sym::Func("arcsin")),
+    ("arccos", Sym::Func("arccos")),
+    ("arctan", Sym::Func("arctan")),
+    ("sinh", Sym::Func("sinh")),
+    ("cosh", Sym::Func("cosh")),
+    ("tanh", Sym::Func("tanh")),
+    ("log", Sym::Func("log")),
+    ("ln", Sym::Func("ln")),
+    ("lg", Sym::Func("lg")),
+    ("exp", Sym::Func("exp")),
+    ("det", Sym::Func("det")),
+    ("dim", Sym::Func("dim")),
+    ("ker", Sym::Func("ker")),
+    ("deg", Sym::Func("deg")),
+    ("gcd", Sym::Func("gcd")),
+    ("arg", Sym::Func("arg")),
+];
+
+/// AsciiMath tokens that differ from the LaTeX names
+const ASCII_SYMBOLS: &[(&str, Sym)] = &[
+    ("<=>", Sym::Op("⇔")),
+    ("|->", Sym::Op("↦")),
+    ("***", Sym::Op("⋆")),
+    ("**", Sym::Op("∗")),
+    ("+-", Sym::Op("±")),
+    ("-+", Sym::Op("∓")),
+    ("xx", Sym::Op("×")),
+    ("-:", Sym::Op("÷")),
+    ("!=", Sym::Op("≠")),
+    ("<=", Sym::Op("≤")),
+    (">=", Sym::Op("≥")),
+    ("~~", Sym::Op("≈")),
+    ("-=", Sym::Op("≡")),
+    ("~=", Sym::Op("≅")),
+    ("->", Sym::Op("→")),
+    ("<-", Sym::Op("←")),
+    ("=>", Sym::Op("⇒")),
+    ("!in", Sym::Op("∉")),
+    ("in", Sym::Op("∈")),
+    ("sube", Sym::Op("⊆")),
+    ("supe", Sym::Op("⊇")),
+    ("sub", Sym::Op("⊂")),
+    ("sup", Sym::Op("⊃")),
+    ("uu", Sym::Op("∪")),
+    ("nn", Sym::Op("∩")),
+    ("AA", Sym::Op("∀")),
+    ("EE", Sym::Op("∃")),
+    ("...", Sym::Op("…")),
+    ("cdots", Sym::Op("⋯")),
+    ("*", Sym::Op("⋅")),
+    ("oo", Sym::Ident("∞")),
+    ("del", Sym::Ident("∂")),
+    ("grad", Sym::Ident("∇")),
+    ("O/", Sym::Ident("∅")),
+    ("RR", Sym::Ident("ℝ")),
+    ("NN", Sym::Ident("ℕ")),
+    ("ZZ", Sym::Ident("ℤ")),
+    ("QQ", Sym::Ident("ℚ")),
+    ("CC", Sym::Ident("ℂ")),
+];
+
+/// Commands taking arguments, shared by both notations where the name matches
+const ASCII_COMMANDS: &[&str] = &[
+    "sqrt", "root", "frac", "text", "hat", "bar", "vec", "dot", "ddot", "tilde", "ul", "bbb", "bb",
+    "cc", "tt", "sf",
+];
+
+fn latex_symbol(name: &str) -> Option<Sym> {
+    SYMBOLS.iter().find(|(n, _)| *n == name).map(|(_, s)| *s)
+}
+
+/// Longest AsciiMath token at the start of `rest`
+fn ascii_token(rest: &str) -> Option<(&'static str, Option<Sym>)> {
+    let shared = SYMBOLS.iter().filter(|(name, sym)| match sym {
+        Sym::Ident(s) => s.chars().all(|c| ('\u{0370}'..='\u{03FF}').contains(&c)),
+        Sym::Large(..) | Sym::Func(_) => *name != "sup",
+        Sym::Op(_) => false,
+    });
+    ASCII_SYMBOLS
+        .iter()
+        .chain(shared)
+        .map(|(name, sym)| (*name, Some(*sym)))
+        .chain(ASCII_COMMANDS.iter().map(|name| (*name, None)))
+        .filter(|(name, _)| rest.starts_with(name))
+        .max_by_key(|(name, _)| name.len())
+}
+
+/// Font of identifiers and numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Variant {
+    #[default]
+    Auto,
+    Normal,
+    Bold,
+    DoubleStruck,
+    Script,
+    SansSerif,
+    Monospace,
+}
+
+/// Map to the Unicode mathematical alphanumerics, as MathML Core expects
+fn styled(text: &str, variant: Variant) -> String {
+    text.chars().map(|c| styled_char(c, variant)).collect()
+}
+
+fn styled_char(c: char, variant: Variant) -> char {
+    let (upper, lower, digit, holes): (u32, u32, Option<u32>, &[(char, char)]) = match variant {
+        Variant::Auto | Variant::Normal => return c,
+        Variant::Bold => (0x1D400, 0x1D41A, Some(0x1D7CE), &[]),
+        Variant::DoubleStruck => (
+            0x1D538,
+            0x1D552,
+            Some(0x1D7D8),
+            &[
+                ('C', 'ℂ'),
+                ('H', 'ℍ'),
+                ('N', 'ℕ'),
+                ('P', 'ℙ'),
+                ('Q', 'ℚ'),
+                ('R', 'ℝ'),
+                ('Z', 'ℤ'),
+            ],
+        ),
+        Variant::Script => (
+            0x1D49C,
+            0x1D4B6,
+            None,
+            &[
+                ('B', 'ℬ'),
+                ('E', 'ℰ'),
+                ('F', 'ℱ'),
+                ('H', 'ℋ'),
+                ('I', 'ℐ'),
+                ('L', 'ℒ'),
+                ('M', 'ℳ'),
+                ('R', 'ℛ'),
+                ('e', 'ℯ'),
+                ('g', 'ℊ'),
+                ('o', 'ℴ'),
+            ],
+        ),
+        Variant::SansSerif => (0x1D5A0, 0x1D5BA, Some(0x1D7E2), &[]),
+        Variant::Monospace => (0x1D670, 0x1D68A, Some(0x1D7F6), &[]),
+    };
+    if let Some(&(_, mapped)) = holes.iter().find(|(k, _)| *k == c) {
+        return mapped;
+    }
+    let code = match c {
+        'A'..='Z' => upper + (c as u32 - 'A' as u32),
+        'a'..='z' => lower + (c as u32 - 'a' as u32),
+        '0'..='9' => match digit {
+            Some(zero) => zero + (c as u32 - '0' as u32),
+            None => return c,
+        },
+        _ => return c,
+    };
+    char::from_u32(code).unwrap_or(c)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Ident(String, Variant),
+    Number(String, Variant),
+    Op(String),
+    Large(String, bool),
+    Func(String),
+    Text(String),
+    Space(&'static str),
+    Row(Vec<Node>),
+    Fenced(String, Vec<Node>, String),
+    Frac(Box<Node>, Box<Node>, Option<bool>),
+    Sqrt(Box<Node>, Option<Box<Node>>),
+    Scripts {
+        base: Box<Node>,
+        sub: Option<Box<Node>>,
+        sup: Option<Box<Node>>,
+    },
+    Over(Box<Node>, &'static str),
+    Under(Box<Node>, &'static str),
+    Table {
+        rows: Vec<Vec<Node>>,
+        open: &'static str,
+        close: &'static str,
+        align: &'static str,
+    },
+}
+
+impl From<Sym> for Node {
+    fn from(sym: Sym) -> Self {
+        match sym {
+            Sym::Ident(s) => Node::Ident(s.to_string(), Variant::Auto),
+            Sym::Op(s) => Node::Op(s.to_string()),
+            Sym::Large(s, limits) => Node::Large(s.to_string(), limits),
+            Sym::Func(s) => Node::Func(s.to_string()),
+        }
+    }
+}
+
+impl Node {
+    fn applies_function(&self) -> bool {
+        match self {
+            Node::Func(_) => true,
+            Node::Scripts { base, .. } => matches!(**base, Node::Func(_)),
+            _ => false,
+        }
+    }
+}
+
+/// A single node stays bare; anything else becomes an `mrow`
+fn row(mut nodes: Vec<Node>) -> Node {
+    if nodes.len() == 1 {
+        nodes.remove(0)
+    } else {
+        Node::Row(nodes)
+    }
+}
+
+/// AsciiMath drops the brackets around script and fraction operands
+fn strip_brackets(node: Node) -> Node {
+    match node {
+        Node::Fenced(_, body, _) => row(body),
+        other => other,
+    }
+}
+
+fn char_op(c: char) -> String {
+    match c {
+        '-' => "−".to_string(),
+        '*' => "∗".to_string(),
+        '\'' => "′".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+fn starts_with_command(rest: &str, name: &str) -> bool {
+    rest.strip_prefix('\\')
+        .and_then(|r| r.strip_prefix(name))
+        .is_some_and(|r| !r.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+    syntax: MathSyntax,
+    variant: Variant,
+}
+
+impl<'s> Parser<'s> {
+    fn new(src: &'s str, syntax: MathSyntax) -> Self {
+        Self {
+            src,
+            pos: 0,
+            syntax,
+            variant: Variant::Auto,
+        }
+    }
+
+    fn parse(mut self) -> Result<Vec<Node>, MathError> {
+        let nodes = self.parse_row()?;
+        self.skip_ws();
+        match self.peek() {
+            None => Ok(nodes),
+            Some(_) => Err(self.unexpected()),
+        }
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn unexpected(&self) -> MathError {
+        match self.peek() {
+            None => MathError::UnexpectedEnd,
+            Some(c) => MathError::Unexpected {
+                found: c.to_string(),
+                offset: self.pos,
+            },
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), MathError> {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn at_row_end(&self) -> bool {
+        let rest = self.rest();
+        match self.syntax {
+            MathSyntax::Latex => {
+                rest.is_empty()
+                    || rest.starts_with(['}', '&'])
+                    || rest.starts_with("\\\\")
+                    || starts_with_command(rest, "end")
+                    || starts_with_command(rest, "right")
+            }
+            MathSyntax::AsciiMath => rest.is_empty() || rest.starts_with([')', ']', '}']),
+        }
+    }
+
+    fn parse_row(&mut self) -> Result<Vec<Node>, MathError> {
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.at_row_end() {
+                break;
+            }
+            let mut node = self.parse_scripted()?;
+            if self.syntax == MathSyntax::AsciiMath {
+                self.skip_ws();
+                if self.eat("//") {
+                    nodes.push(node);
+                    node = Node::Op("/".to_string());
+                } else if self.eat("/") {
+                    let denominator = self.parse_argument()?;
+                    node = Node::Frac(Box::new(strip_brackets(node)), Box::new(denominator), None);
+                }
+            }
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+
+    fn parse_scripted(&mut self) -> Result<Node, MathError> {
+        let base = self.parse_atom()?;
+        let (mut sub, mut sup) = (None, None);
+        loop {
+            self.skip_ws();
+            if sub.is_none() && self.eat("_") {
+                sub = Some(Box::new(self.parse_argument()?));
+            } else if sup.is_none() && self.eat("^") {
+                sup = Some(Box::new(self.parse_argument()?));
+            } else {
+                break;
+            }
+        }
+        if sub.is_none() && sup.is_none() {
+            return Ok(base);
+        }
+        Ok(Node::Scripts {
+            base: Box::new(base),
+            sub,
+            sup,
+        })
+    }
+
+    /// Operand of a script, fraction or root
+    fn parse_argument(&mut self) -> Result<Node, MathError> {
+        self.skip_ws();
+        if self.at_row_end() {
+            return Err(self.unexpected());
+        }
+        match self.syntax {
+            // `\frac12` takes one digit per argument
+            MathSyntax::Latex if self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                let digit = self.bump().unwrap_or_default();
+                Ok(Node::Number(
+                    styled(&digit.to_string(), self.variant),
+                    self.variant,
+                ))
+            }
+            MathSyntax::Latex => self.parse_atom(),
+            MathSyntax::AsciiMath => self.parse_atom().map(strip_brackets),
+        }
+    }
+
+    /// Parse `arg` with a font variant applied
+    fn parse_styled(&mut self, variant: Variant) -> Result<Node, MathError> {
+        let previous = std::mem::replace(&mut self.variant, variant);
+        let node = self.parse_argument();
+        self.variant = previous;
+        node
+    }
+
+    /// Raw text between `open` and its matching `close`
+    fn raw_group(&mut self, open: char, close: char) -> Result<String, MathError> {
+        self.expect(open)?;
+        let start = self.pos;
+        let mut depth = 0;
+        while let Some(c) = self.bump() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                if depth == 0 {
+                    return Ok(self.src[start..self.pos - c.len_utf8()].to_string());
+                }
+                depth -= 1;
+            }
+        }
+        Err(MathError::UnexpectedEnd)
+    }
+
+    fn parse_number(&mut self) -> Node {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            let decimal = c == '.'
+                && self.rest()[1..].starts_with(|d: char| d.is_ascii_digit())
+                && !self.src[start..self.pos].contains('.');
+            if !c.is_ascii_digit() && !decimal {
+                break;
+            }
+            self.bump();
+        }
+        Node::Number(
+            styled(&self.src[start..self.pos], self.variant),
+            self.variant,
+        )
+    }
+
+    fn parse_letter(&mut self, c: char) -> Node {
+        self.bump();
+        let mut text = c.to_string();
+        // Upright runs (`\mathrm{max}`) read as one identifier
+        if self.variant == Variant::Normal {
+            while let Some(next) = self.peek().filter(char::is_ascii_alphabetic) {
+                text.push(next);
+                self.bump();
+            }
+        }
+        Node::Ident(styled(&text, self.variant), self.variant)
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, MathError> {
+        self.skip_ws();
+        let c = self.peek().ok_or(MathError::UnexpectedEnd)?;
+        match self.syntax {
+            MathSyntax::Latex => self.parse_latex_atom(c),
+            MathSyntax::AsciiMath => self.parse_ascii_atom(c),
+        }
+    }
+
+    fn parse_latex_atom(&mut self, c: char) -> Result<Node, MathError> {
+        match c {
+            '{' => {
+                self.bump();
+                let nodes = self.parse_row()?;
+                self.expect('}')?;
+                Ok(row(nodes))
+            }
+            '\\' => self.parse_command(),
+            // A script with an empty base
+            '^' | '_' => Ok(Node::Row(Vec::new())),
+            '}' | '&' => Err(self.unexpected()),
+            c if c.is_ascii_digit() => Ok(self.parse_number()),
+            c if c.is_alphabetic() => Ok(self.parse_letter(c)),
+            c => {
+                self.bump();
+                Ok(Node::Op(char_op(c)))
+            }
+        }
+    }
+
+    fn parse_ascii_atom(&mut self, c: char) -> Result<Node, MathError> {
+        if let Some((token, sym)) = ascii_token(self.rest()) {
+            self.pos += token.len();
+            return match sym {
+                Some(sym) => Ok(sym.into()),
+                None => self.parse_shared_command(token),
+            };
+        }
+        match c {
+            '(' | '[' | '{' => {
+                self.bump();
+                let body = self.parse_row()?;
+                let close = self.bump().ok_or(MathError::UnexpectedEnd)?;
+                Ok(Node::Fenced(c.to_string(), body, close.to_string()))
+            }
+            '"' => {
+                self.bump();
+                let start = self.pos;
+                let len = self.rest().find('"').ok_or(MathError::UnexpectedEnd)?;
+                self.pos += len + 1;
+                Ok(Node::Text(self.src[start..start + len].to_string()))
+            }
+            '^' | '_' => Ok(Node::Row(Vec::new())),
+            c if c.is_ascii_digit() => Ok(self.parse_number()),
+            c if c.is_alphabetic() => Ok(self.parse_letter(c)),
+            c => {
+                self.bump();
+                Ok(Node::Op(char_op(c)))
+            }
+        }
+    }
+
+    /// Argument-taking commands spelled the same way in both notations
+    fn parse_shared_command(&mut self, name: &str) -> Result<Node, MathError> {
+        let over = |node, accent| Ok(Node::Over(Box::new(node), accent));
+        match name {
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.parse_argument()?;
+                let denominator = self.parse_argument()?;
+                let style = match name {
+                    "dfrac" => Some(true),
+                    "tfrac" => Some(false),
+                    _ => None,
+                };
+                Ok(Node::Frac(
+                    Box::new(numerator),
+                    Box::new(denominator),
+                    style,
+                ))
+            }
+            "sqrt" => Ok(Node::Sqrt(Box::new(self.parse_argument()?), None)),
+            "root" => {
+                let index = self.parse_argument()?;
+                let radicand = self.parse_argument()?;
+                Ok(Node::Sqrt(Box::new(radicand), Some(Box::new(index))))
+            }
+            "text" => {
+                let (open, close) = match self.syntax {
+                    MathSyntax::Latex => ('{', '}'),
+                    MathSyntax::AsciiMath => ('(', ')'),
+                };
+                Ok(Node::Text(self.raw_group(open, close)?))
+            }
+            "hat" => over(self.parse_argument()?, "^"),
+            "bar" => over(self.parse_argument()?, "‾"),
+            "vec" => over(self.parse_argument()?, "→"),
+            "dot" => over(self.parse_argument()?, "˙"),
+            "ddot" => over(self.parse_argument()?, "¨"),
+            "tilde" => over(self.parse_argument()?, "˜"),
+            "ul" => Ok(Node::Under(Box::new(self.parse_argument()?), "_")),
+            "bb" => self.parse_styled(Variant::Bold),
+            "bbb" => self.parse_styled(Variant::DoubleStruck),
+            "cc" => self.parse_styled(Variant::Script),
+            "tt" => self.parse_styled(Variant::Monospace),
+            "sf" => self.parse_styled(Variant::SansSerif),
+            _ => Err(MathError::UnknownCommand(name.to_string())),
+        }
+    }
+
+    fn parse_command(&mut self) -> Result<Node, MathError> {
+        self.bump();
+        let start = self.pos;
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                    self.bump();
+                }
+            }
+            Some(_) => {
+                self.bump();
+            }
+            None => return Err(MathError::UnexpectedEnd),
+        }
+        let name = &self.src[start..self.pos];
+
+        match name {
+            "frac" | "dfrac" | "tfrac" | "text" | "hat" | "bar" | "vec" | "dot" | "ddot"
+            | "tilde" => self.parse_shared_command(name),
+            "sqrt" => {
+                self.skip_ws();
+                let index = if self.peek() == Some('[') {
+                    let raw = self.raw_group('[', ']')?;
+                    Some(Box::new(row(Parser::new(&raw, self.syntax).parse()?)))
+                } else {
+                    None
+                };
+                Ok(Node::Sqrt(Box::new(self.parse_argument()?), index))
+            }
+            "textrm" | "textit" | "textbf" | "mbox" => Ok(Node::Text(self.raw_group('{', '}')?)),
+            "operatorname" => Ok(Node::Func(self.raw_group('{', '}')?.trim().to_string())),
+            "mathrm" => self.parse_styled(Variant::Normal),
+            "mathit" => self.parse_styled(Variant::Auto),
+            "mathbf" | "boldsymbol" | "bm" => self.parse_styled(Variant::Bold),
+            "mathbb" => self.parse_styled(Variant::DoubleStruck),
+            "mathcal" | "mathscr" => self.parse_styled(Variant::Script),
+            "mathsf" => self.parse_styled(Variant::SansSerif),
+            "mathtt" => self.parse_styled(Variant::Monospace),
+            "widehat" => Ok(Node::Over(Box::new(self.parse_argument()?), "^")),
+            "widetilde" => Ok(Node::Over(Box::new(self.parse_argument()?), "˜")),
+            "overline" => Ok(Node::Over(Box::new(self.parse_argument()?), "‾")),
+            "overbrace" => Ok(Node::Over(Box::new(self.parse_argument()?), "⏞")),
+            "underline" => Ok(Node::Under(Box::new(self.parse_argument()?), "_")),
+            "underbrace" => Ok(Node::Under(Box::new(self.parse_argument()?), "⏟")),
+            "left" => {
+                let open = self.parse_delimiter()?;
+                let body = self.parse_row()?;
+                if !self.eat("\\right") {
+                    return Err(self.unexpected());
+                }
+                let close = self.parse_delimiter()?;
+                Ok(Node::Fenced(open, body, close))
+            }
+            "begin" => self.parse_environment(),
+            "," => Ok(Node::Space("0.1667em")),
+            ":" | ">" => Ok(Node::Space("0.2222em")),
+            ";" => Ok(Node::Space("0.2778em")),
+            " " => Ok(Node::Space("0.25em")),
+            "quad" => Ok(Node::Space("1em")),
+            "qquad" => Ok(Node::Space("2em")),
+            // Negative space and style switches have no MathML Core equivalent
+            "!" | "displaystyle" | "textstyle" | "limits" | "nolimits" => Ok(Node::Row(Vec::new())),
+            "{" | "}" | "%" | "$" | "#" | "&" | "_" => Ok(Node::Op(name.to_string())),
+            "|" => Ok(Node::Op("‖".to_string())),
+            _ => latex_symbol(name)
+                .map(Node::from)
+                .ok_or_else(|| MathError::UnknownCommand(name.to_string())),
+        }
+    }
+
+    fn parse_delimiter(&mut self) -> Result<String, MathError> {
+        self.skip_ws();
+        if self.peek() == Some('\\') {
+            self.bump();
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                self.bump();
+            }
+            if self.pos == start {
+                self.bump();
+            }
+            let name = &self.src[start..self.pos];
+            return match name {
+                "{" | "}" => Ok(name.to_string()),
+                "|" | "Vert" => Ok("‖".to_string()),
+                _ => match latex_symbol(name) {
+                    Some(Sym::Op(s)) => Ok(s.to_string()),
+                    _ => Err(MathError::UnknownCommand(name.to_string())),
+                },
+            };
+        }
+        match self.bump() {
+            Some('.') => Ok(String::new()),
+            Some(c) => Ok(c.to_string()),
+            None => Err(MathError::UnexpectedEnd),
+        }
+    }
+
+    fn parse_environment(&mut self) -> Result<Node, MathError> {
+        let name = self.raw_group('{', '}')?;
+        let (open, close, align) = match name.as_str() {
+            "matrix" => ("", "", "center"),
+            "pmatrix" => ("(", ")", "center"),
+            "bmatrix" => ("[", "]", "center"),
+            "Bmatrix" => ("{", "}", "center"),
+            "vmatrix" => ("|", "|", "center"),
+            "Vmatrix" => ("‖", "‖", "center"),
+            "cases" => ("{", "", "left"),
+            "aligned" | "align" | "align*" => ("", "", "right left"),
+            _ => return Err(MathError::UnknownEnvironment(name)),
+        };
+
+        let mut rows = vec![Vec::new()];
+        loop {
+            let cell = self.parse_row()?;
+            if let Some(current) = rows.last_mut() {
+                current.push(row(cell));
+            }
+            self.skip_ws();
+            if self.eat("&") {
+                continue;
+            }
+            if self.eat("\\\\") {
+                rows.push(Vec::new());
+                continue;
+            }
+            if starts_with_command(self.rest(), "end") {
+                self.pos += "\\end".len();
+                let end = self.raw_group('{', '}')?;
+                if end != name {
+                    return Err(MathError::MismatchedEnvironment {
+                        expected: name,
+                        found: end,
+                    });
+                }
+                break;
+            }
+            return Err(self.unexpected());
+        }
+
+        // A trailing `\\` leaves one empty cell behind
+        if rows.len() > 1 && rows.last() == Some(&vec![Node::Row(Vec::new())]) {
+            rows.pop();
+        }
+        Ok(Node::Table {
+            rows,
+            open,
+            close,
+            align,
+        })
+    }
+}
+
+fn render_row(nodes: &[Node]) -> Markup {
+    html! {
+        @for (i, node) in nodes.iter().enumerate() {
+            (render_node(node))
+            @if node.applies_function() && i + 1 < nodes.len() {
+                mo { "\u{2061}" }
+            }
+        }
+    }
+}
+
+fn render_node(node: &Node) -> Markup {
+    match node {
+        Node::Ident(text, Variant::Normal) => html! { mi mathvariant="normal" { (text) } },
+        Node::Ident(text, _) => html! { mi { (text) } },
+        Node::Number(text, _) => html! { mn { (text) } },
+        Node::Op(text) => html! { mo { (text) } },
+        Node::Large(text, limits) => {
+            html! { mo movablelimits=[limits.then_some("true")] { (text) } }
+        }
+        Node::Func(name) => html! { mi { (name) } },
+        Node::Text(text) => html! { mtext { (text) } },
+        Node::Space(width) => html! { mspace width=(width) {} },
+        Node::Row(nodes) => html! { mrow { (render_row(nodes)) } },
+        Node::Fenced(open, body, close) => html! {
+            mrow {
+                @if !open.is_empty() {
+                    mo fence="true" { (open) }
+                }
+                (render_row(body))
+                @if !close.is_empty() {
+                    mo fence="true" { (close) }
+                }
+            }
+        },
+        Node::Frac(numerator, denominator, style) => {
+            let frac = html! { mfrac { (render_node(numerator)) (render_node(denominator)) } };
+            match style {
+                Some(display) => html! { mstyle displaystyle=(display) { (frac) } },
+                None => frac,
+            }
+        }
+        Node::Sqrt(radicand, None) => html! { msqrt { (render_node(radicand)) } },
+        Node::Sqrt(radicand, Some(index)) => {
+            html! { mroot { (render_node(radicand)) (render_node(index)) } }
+        }
+        Node::Scripts { base, sub, sup } => {
+            let limits = matches!(**base, Node::Large(_, true));
+            let base = render_node(base);
+            match (sub, sup) {
+                (Some(sub), Some(sup)) if limits => {
+                    html! { munderover { (base) (render_node(sub)) (render_node(sup)) } }
+                }
+                (Some(sub), Some(sup)) => {
+                    html! { msubsup { (base) (render_node(sub)) (render_node(sup)) } }
+                }
+                (Some(sub), None) if limits => html! { munder { (base) (render_node(sub)) } },
+                (Some(sub), None) => html! { msub { (base) (render_node(sub)) } },
+                (None, Some(sup)) if limits => html! { mover { (base) (render_node(sup)) } },
+                (None, Some(sup)) => html! { msup { (base) (render_node(sup)) } },
+                (None, None) => base,
+            }
+        }
+        Node::Over(base, accent) => html! {
+            mover accent="true" { (render_node(base)) mo { (accent) } }
+        },
+        Node::Under(base, accent) => html! {
+            munder accentunder="true" { (render_node(base)) mo { (accent) } }
+        },
+        Node::Table {
+            rows,
+            open,
+            close,
+            align,
+        } => html! {
+            mrow {
+                @if !open.is_empty() {
+                    mo fence="true" { (open) }
+                }
+                mtable columnalign=(align) {
+                    @for cells in rows {
+                        mtr {
+                            @for cell in cells {
+                                mtd { (render_node(cell)) }
+                            }
+                        }
+                    }
+                }
+                @if !close.is_empty() {
+                    mo fence="true" { (close) }
+                }
+            }
+        },
+    }
+}
+
+/// Formula rendered as native MathML
+#[derive(Debug, Clone)]
+pub struct Math<'a> {
+    source: Cow<'a, str>,
+    syntax: MathSyntax,
+    display: bool,
+    label: Option<Cow<'a, str>>,
+    custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> Math<'a> {
+    pub fn new(source: impl Into<Cow<'a, str>>, syntax: MathSyntax) -> Self {
+        Self {
+            source: source.into(),
+            syntax,
+            display: false,
+            label: None,
+            custom_class: None,
+        }
+    }
+
+    pub fn latex(source: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(source, MathSyntax::Latex)
+    }
+
+    pub fn ascii(source: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(source, MathSyntax::AsciiMath)
+    }
+
+    /// Block (display) mode instead of inline
+    pub fn display(mut self, display: bool) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Spoken alternative text (defaults to the source)
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    /// The `<math>` element alone
+    pub fn to_mathml(&self) -> Result<Markup, MathError> {
+        let nodes = Parser::new(&self.source, self.syntax).parse()?;
+        let alt = self.label.as_deref().unwrap_or(&self.source);
+        Ok(html! {
+            math
+                xmlns="http://www.w3.org/1998/Math/MathML"
+                display=(if self.display { "block" } else { "inline" })
+                alttext=(alt)
+            {
+                semantics {
+                    mrow { (render_row(&nodes)) }
+                    annotation encoding=(self.syntax.encoding()) { (self.source) }
+                }
+            }
+        })
+    }
+}
+
+impl<'a> Render for Math<'a> {
+    fn render(&self) -> Markup {
+        let mut classes = vec!["sh-math"];
+        if self.display {
+            classes.push("sh-math--display");
+        }
+        if let Some(class) = &self.custom_class {
+            classes.push(class);
+        }
+
+        let content = match self.to_mathml() {
+            Ok(mathml) => mathml,
+            Err(err) => {
+                classes.push("sh-math--error");
+                html! { code class="sh-math__source" title=(err) { (self.source) } }
+            }
+        };
+        let class = classes.join(" ");
+
+        html! {
+            @if self.display {
+                div class=(class) { (content) }
+            } @else {
+                span class=(class) { (content) }
+            }
+        }
+    }
+}
+
+pub fn math_css() -> String {
+    r#"
+.sh-math math {
+    font-family: "STIX Two Math", "Cambria Math", "Latin Modern Math", math;
+    font-size: 1.05em;
+}
+
+.sh-math--display {
+    display: block;
+    margin: 1em 0;
+    overflow-x: auto;
+    overflow-y: hidden;
+    text-align: center;
+}
+
+.sh-math--display math {
+    font-size: 1.15em;
+}
+
+.sh-math--error .sh-math__source {
+    font-family: var(--sh-font-mono, ui-monospace, monospace);
+    font-size: 0.9em;
+    color: var(--sh-color-error, #dc2626);
+    white-space: pre-wrap;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latex(source: &str) -> String {
+        Math::latex(source).to_mathml().unwrap().into_string()
+    }
+
+    fn ascii(source: &str) -> String {
+        Math::ascii(source).to_mathml().unwrap().into_string()
+    }
+
+    #[test]
+    fn test_fraction_and_scripts() {
+        assert!(latex(r"\frac{a}{b}").contains("<mfrac><mi>a</mi><mi>b</mi></mfrac>"));
+        assert!(latex(r"\frac12").contains("<mfrac><mn>1</mn><mn>2</mn></mfrac>"));
+        let html = latex("x^2 - y_{i+1}");
+        assert!(html.contains("<msup><mi>x</mi><mn>2</mn></msup>"));
+        assert!(html.contains("<mo>−</mo>"));
+        assert!(html.contains("<msub><mi>y</mi><mrow><mi>i</mi><mo>+</mo><mn>1</mn></mrow></msub>"));
+        assert!(latex("a_1^2").contains("<msubsup><mi>a</mi><mn>1</mn><mn>2</mn></msubsup>"));
+    }
+
+    #[test]
+    fn test_roots() {
+        assert!(latex(r"\sqrt{2}").contains("<msqrt><mn>2</mn></msqrt>"));
+        assert!(latex(r"\sqrt[3]{x}").contains("<mroot><mi>x</mi><mn>3</mn></mroot>"));
+    }
+
+    #[test]
+    fn test_big_operators() {
+        let sum = latex(r"\sum_{i=1}^{n} i");
+        assert!(sum.contains(r#"<munderover><mo movablelimits="true">∑</mo>"#));
+        let integral = latex(r"\int_0^1 f(x)\,dx");
+        assert!(integral.contains("<msubsup><mo>∫</mo><mn>0</mn><mn>1</mn></msubsup>"));
+        assert!(integral.contains(r#"<mspace width="0.1667em"></mspace>"#));
+        assert!(latex(r"\lim_{x \to 0}").contains("<munder><mo movablelimits=\"true\">lim</mo>"));
+    }
+
+    #[test]
+    fn test_symbols_and_functions() {
+        assert!(latex(r"\alpha \leq \beta").contains("<mi>α</mi><mo>≤</mo><mi>β</mi>"));
+        assert!(latex(r"\sin x").contains("<mi>sin</mi><mo>\u{2061}</mo><mi>x</mi>"));
+        assert!(latex(r"\sin^2 x").contains("</msup><mo>\u{2061}</mo>"));
+        assert!(latex("3.14").contains("<mn>3.14</mn>"));
+    }
+
+    #[test]
+    fn test_fences_and_environments() {
+        let fenced = latex(r"\left( \frac{1}{2} \right]");
+        assert!(fenced.contains(r#"<mrow><mo fence="true">(</mo><mfrac>"#));
+        assert!(fenced.contains(r#"<mo fence="true">]</mo></mrow>"#));
+        assert!(!latex(r"\left. x \right|").contains(r#"<mo fence="true"></mo>"#));
+
+        let matrix = latex(r"\begin{pmatrix} a & b \\ c & d \\ \end{pmatrix}");
+        assert!(matrix.contains(r#"<mtable columnalign="center">"#));
+        assert_eq!(matrix.matches("<mtr>").count(), 2);
+        assert_eq!(matrix.matches("<mtd>").count(), 4);
+        assert!(matrix.contains(r#"<mo fence="true">(</mo>"#));
+
+        let cases = latex(r"f(x) = \begin{cases} 1 & x > 0 \\ 0 & \text{otherwise} \end{cases}");
+        assert!(cases.contains(r#"columnalign="left""#));
+        assert!(cases.contains("<mtext>otherwise</mtext>"));
+    }
+
+    #[test]
+    fn test_font_variants_and_accents() {
+        assert!(latex(r"\mathbb{R}").contains("<mi>ℝ</mi>"));
+        assert!(latex(r"\mathbf{x}").contains("<mi>\u{1D431}</mi>"));
+        assert!(latex(r"\mathcal{L}").contains("<mi>ℒ</mi>"));
+        assert!(latex(r"\mathrm{d}x").contains(r#"<mi mathvariant="normal">d</mi>"#));
+        assert!(latex(r"\operatorname{sgn} x").contains("<mi>sgn</mi><mo>\u{2061}</mo>"));
+        assert!(latex(r"\hat{x}").contains(r#"<mover accent="true"><mi>x</mi><mo>^</mo></mover>"#));
+        assert!(latex(r"\vec v").contains("<mo>→</mo>"));
+    }
+
+    #[test]
+    fn test_latex_errors() {
+        assert_eq!(
+            Math::latex(r"\frac{a}").to_mathml().unwrap_err(),
+            MathError::UnexpectedEnd
+        );
+        assert_eq!(
+            Math::latex(r"\foo").to_mathml().unwrap_err(),
+            MathError::UnknownCommand("foo".into())
+        );
+        assert_eq!(
+            Math::latex(r"\begin{pmatrix} a \end{bmatrix}")
+                .to_mathml()
+                .unwrap_err(),
+            MathError::MismatchedEnvironment {
+                expected: "pmatrix".into(),
+                found: "bmatrix".into()
+            }
+        );
+        assert!(matches!(
+            Math::latex("a}").to_mathml().unwrap_err(),
+            MathError::Unexpected { offset: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_asciimath() {
+        let frac = ascii("(a+b)/2");
+        assert!(
+            frac.contains("<mfrac><mrow><mi>a</mi><mo>+</mo><mi>b</mi></mrow><mn>2</mn></mfrac>")
+        );
+        assert!(ascii("sqrt(x^2+1)").contains("<msqrt><mrow><msup><mi>x</mi><mn>2</mn></msup>"));
+        assert!(ascii("sum_(i=1)^n i").contains("<munderover>"));
+        assert!(ascii("x <= y != z").contains("<mo>≤</mo><mi>y</mi><mo>≠</mo>"));
+        assert!(ascii("alpha in RR").contains("<mi>α</mi><mo>∈</mo><mi>ℝ</mi>"));
+        assert!(ascii(r#"x "if" x > 0"#).contains("<mtext>if</mtext>"));
+        assert!(ascii("bb v xx bbb N").contains("<mi>\u{1D42F}</mi><mo>×</mo><mi>ℕ</mi>"));
+        // Keeps brackets outside argument positions (half-open interval)
+        assert!(ascii("[0, 1)").contains(r#"<mo fence="true">[</mo>"#));
+        assert!(ascii("int_0^oo").contains("<mi>∞</mi>"));
+    }
+
+    #[test]
+    fn test_math_render_modes() {
+        let inline = Math::latex("E = mc^2").render().into_string();
+        assert!(inline.starts_with(r#"<span class="sh-math">"#));
+        assert!(inline.contains(r#"display="inline""#));
+        assert!(inline.contains(r#"alttext="E = mc^2""#));
+        assert!(
+            inline.contains(r#"<annotation encoding="application/x-tex">E = mc^2</annotation>"#)
+        );
+
+        let block = Math::ascii("a^2 + b^2 = c^2")
+            .display(true)
+            .label("Pythagorean theorem")
+            .render()
+            .into_string();
+        assert!(block.starts_with(r#"<div class="sh-math sh-math--display">"#));
+        assert!(block.contains(r#"display="block""#));
+        assert!(block.contains(r#"alttext="Pythagorean theorem""#));
+        assert!(block.contains(r#"encoding="text/x-asciimath""#));
+    }
+
+    #[test]
+    fn test_math_fallback() {
+        let html = Math::latex(r"\frac{1}{").render().into_string();
+        assert!(html.contains("sh-math--error"));
+        assert!(html.contains(
+            r#"<code class="sh-math__source" title="Formula ends unexpectedly">\frac{1}{</code>"#
+        ));
+        assert!(!html.contains("<math"));
+    }
+
+    #[test]
+    fn test_math_css() {
+        let css = math_css();
+        assert!(css.contains(".sh-math--display"));
+        assert!(css.contains("math"));
+    }
+}
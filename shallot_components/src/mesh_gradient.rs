@@ -98,34 +98,43 @@ impl<'a> Default for MeshGradientBackground<'a> {
     }
 }
 
+impl<'a> MeshGradientBackground<'a> {
+    /// Gradient colors, in stop order
+    pub fn palette(&self) -> &[&'a str] {
+        &self.colors
+    }
+
+    /// The gradient `defs` and layered rects, sized `width` x `height`, for
+    /// embedding in another SVG (see `og_image`). Ids are prefixed with `id`
+    /// and elements are closed explicitly so the output is valid XML.
+    pub fn svg_layers(&self, id: &str, width: u32, height: u32) -> Markup {
+        let layers = [("50%", "50%", "60%"), ("80%", "20%", "50%"), ("20%", "80%", "50%")];
+
+        html! {
+            defs {
+                @for (n, (cx, cy, r)) in layers.iter().enumerate() {
+                    radialGradient id=(format!("{}-{}", id, n + 1)) cx=(cx) cy=(cy) r=(r) {
+                        @for (i, color) in self.colors.iter().enumerate() {
+                            stop offset=(format!("{}%", i * 25)) style=(format!("stop-color: {}", color)) {}
+                        }
+                    }
+                }
+            }
+            rect width=(width) height=(height) fill=(format!("url(#{}-1)", id)) {}
+            rect width=(width) height=(height) fill=(format!("url(#{}-2)", id)) opacity="0.5" {}
+            rect width=(width) height=(height) fill=(format!("url(#{}-3)", id)) opacity="0.5" {}
+        }
+    }
+}
+
 impl<'a> Render for MeshGradientBackground<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
-        let gradient_id = "mesh-gradient";
 
         html! {
             div class=(classes) aria-hidden="true" {
                 svg class="sh-meshgradient__svg" viewBox="0 0 100 100" preserveAspectRatio="none" {
-                    defs {
-                        radialGradient id=(format!("{}-1", gradient_id)) cx="50%" cy="50%" r="60%" {
-                            @for (i, color) in self.colors.iter().enumerate() {
-                                stop offset=(format!("{}%", i * 25)) style=(format!("stop-color: {}", color));
-                            }
-                        }
-                        radialGradient id=(format!("{}-2", gradient_id)) cx="80%" cy="20%" r="50%" {
-                            @for (i, color) in self.colors.iter().enumerate() {
-                                stop offset=(format!("{}%", i * 25)) style=(format!("stop-color: {}", color));
-                            }
-                        }
-                        radialGradient id=(format!("{}-3", gradient_id)) cx="20%" cy="80%" r="50%" {
-                            @for (i, color) in self.colors.iter().enumerate() {
-                                stop offset=(format!("{}%", i * 25)) style=(format!("stop-color: {}", color));
-                            }
-                        }
-                    }
-                    rect width="100" height="100" fill=(format!("url(#{}-1)", gradient_id));
-                    rect width="100" height="100" fill=(format!("url(#{}-2)", gradient_id)) opacity="0.5";
-                    rect width="100" height="100" fill=(format!("url(#{}-3)", gradient_id)) opacity="0.5";
+                    (self.svg_layers("mesh-gradient", 100, 100))
                 }
             }
         }
@@ -0,0 +1,150 @@
+//! Motion - how much animation the effect components emit
+//!
+//! Effect components (confetti, marquee, typing animation, border beam,
+//! glitch text, orbiting circles) describe their stylesheet as an
+//! [`EffectCss`]: the animated rules, the rules that freeze the effect on a
+//! still frame, and the rules that switch its decoration off. A
+//! [`MotionConfig`] picks which of those end up in the CSS:
+//!
+//! - [`MotionConfig::Full`] keeps the keyframes and freezes the effect under
+//!   `prefers-reduced-motion: reduce`.
+//! - [`MotionConfig::Reduced`] drops every `@keyframes` block and always
+//!   shows the still frame.
+//! - [`MotionConfig::None`] drops the keyframes and hides decoration that
+//!   only means something in motion.
+//!
+//! ```ignore
+//! let css = all_component_css_for(MotionConfig::Reduced);
+//! assert!(!css.contains("@keyframes confetti-fall"));
+//! ```
+
+/// Amount of motion in the effect components' CSS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotionConfig {
+    /// Animate, falling back to the still frame for `prefers-reduced-motion`
+    #[default]
+    Full,
+    /// Never animate; show the still frame
+    Reduced,
+    /// Never animate; hide motion-only decoration
+    None,
+}
+
+/// Stylesheet of one effect component, split by motion
+#[derive(Debug, Clone, Copy)]
+pub struct EffectCss<'a> {
+    /// Complete animated rules, `@keyframes` included
+    pub animated: &'a str,
+    /// Rules that stop the animation on a readable still frame
+    pub still: &'a str,
+    /// Extra rules for [`MotionConfig::None`]
+    pub off: &'a str,
+}
+
+impl MotionConfig {
+    /// Whether `@keyframes` blocks are emitted
+    pub fn keyframes(self) -> bool {
+        self == MotionConfig::Full
+    }
+
+    /// Assemble an effect's CSS for this motion level
+    pub fn effect_css(self, effect: EffectCss) -> String {
+        match self {
+            MotionConfig::Full => {
+                let mut css = effect.animated.to_string();
+                css.push_str("\n/* Reduced motion */\n@media (prefers-reduced-motion: reduce) {\n");
+                for line in effect.still.trim_matches('\n').lines() {
+                    if !line.is_empty() {
+                        css.push_str("    ");
+                    }
+                    css.push_str(line);
+                    css.push('\n');
+                }
+                css.push_str("}\n");
+                css
+            }
+            MotionConfig::Reduced => {
+                let mut css = strip_keyframes(effect.animated);
+                css.push_str(effect.still);
+                css
+            }
+            MotionConfig::None => {
+                let mut css = strip_keyframes(effect.animated);
+                css.push_str(effect.still);
+                css.push_str(effect.off);
+                css
+            }
+        }
+    }
+}
+
+/// Remove every `@keyframes name { ... }` block from a stylesheet
+pub fn strip_keyframes(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("@keyframes") {
+        out.push_str(&rest[..start]);
+        let block = &rest[start..];
+        let mut depth = 0usize;
+        let mut end = block.len();
+        for (i, c) in block.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        end = i + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        rest = block[end..].strip_prefix('\n').unwrap_or(&block[end..]);
+        // Drop the blank line the block leaves behind
+        if out.ends_with("\n\n") && rest.starts_with('\n') {
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EFFECT: EffectCss = EffectCss {
+        animated: ".fx {\n    animation: spin 1s infinite;\n}\n\n@keyframes spin {\n    from { transform: rotate(0deg); }\n    to { transform: rotate(360deg); }\n}\n\n.fx--big { width: 2rem; }\n",
+        still: ".fx { animation: none; }\n",
+        off: ".fx { display: none; }\n",
+    };
+
+    #[test]
+    fn test_strip_keyframes() {
+        let css = strip_keyframes(EFFECT.animated);
+        assert!(!css.contains("@keyframes"));
+        assert!(!css.contains("rotate"));
+        assert!(css.contains(".fx--big { width: 2rem; }"));
+        assert!(!css.contains("\n\n\n"));
+    }
+
+    #[test]
+    fn test_motion_levels() {
+        let full = MotionConfig::Full.effect_css(EFFECT);
+        assert!(full.contains("@keyframes spin"));
+        assert!(full.contains(
+            "@media (prefers-reduced-motion: reduce) {\n    .fx { animation: none; }\n}\n"
+        ));
+
+        let reduced = MotionConfig::Reduced.effect_css(EFFECT);
+        assert!(!reduced.contains("@keyframes"));
+        assert!(!reduced.contains("prefers-reduced-motion"));
+        assert!(reduced.ends_with(".fx { animation: none; }\n"));
+
+        let none = MotionConfig::None.effect_css(EFFECT);
+        assert!(none.ends_with(".fx { display: none; }\n"));
+        assert!(MotionConfig::default().keyframes());
+        assert!(!MotionConfig::None.keyframes());
+    }
+}
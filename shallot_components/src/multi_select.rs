@@ -1,8 +1,11 @@
-//! MultiSelect Component - CSS-only Tag Selection Interface
+//! MultiSelect Component - Checkbox dropdown built on `<details>`
 //!
-//! A multi-select interface using checkbox hacks and CSS peer selectors.
-//! Selected items appear as removable tags.
+//! Every option is a checkbox sharing the select's name, so the form posts
+//! `name=a&name=b` like a native `<select multiple>`. Read the values back
+//! with [`MultiSelect::values_from_form`], which also expands the optional
+//! "Select all" box and enforces [`MultiSelect::max_selections`].
 
+use crate::form::{FormData, ValidationError};
 use maud::{html, Markup, Render};
 
 /// Option for MultiSelect
@@ -11,12 +14,22 @@ pub struct MultiSelectOption<'a> {
     pub label: &'a str,
 }
 
+impl<'a> MultiSelectOption<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self { value, label }
+    }
+}
+
 /// MultiSelect component
 pub struct MultiSelect<'a> {
     name: &'a str,
     options: Vec<MultiSelectOption<'a>>,
     selected: Vec<&'a str>,
+    label: Option<&'a str>,
     placeholder: Option<&'a str>,
+    select_all: Option<&'a str>,
+    max_selections: Option<usize>,
+    open: bool,
     disabled: bool,
     class: Option<&'a str>,
 }
@@ -28,7 +41,11 @@ impl<'a> MultiSelect<'a> {
             name,
             options,
             selected: Vec::new(),
+            label: None,
             placeholder: None,
+            select_all: None,
+            max_selections: None,
+            open: false,
             disabled: false,
             class: None,
         }
@@ -40,12 +57,37 @@ impl<'a> MultiSelect<'a> {
         self
     }
 
-    /// Set placeholder
+    /// Accessible name of the option group
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Set placeholder shown in the trigger while nothing is selected
     pub fn placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
     }
 
+    /// Add a "Select all" box with the given label; it submits
+    /// `{name}_all` instead of checking every option
+    pub fn select_all(mut self, label: &'a str) -> Self {
+        self.select_all = Some(label);
+        self
+    }
+
+    /// Limit how many options may be chosen, shown as a hint under the list
+    pub fn max_selections(mut self, max: usize) -> Self {
+        self.max_selections = Some(max);
+        self
+    }
+
+    /// Render the dropdown expanded
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -58,6 +100,35 @@ impl<'a> MultiSelect<'a> {
         self
     }
 
+    /// Name of the "Select all" checkbox
+    pub fn select_all_name(&self) -> String {
+        format!("{}_all", self.name)
+    }
+
+    /// Known option values submitted in `data`, in option order.
+    ///
+    /// Unknown and duplicate values are dropped. Fails when more than
+    /// [`MultiSelect::max_selections`] options were chosen.
+    pub fn values_from_form(&self, data: &FormData) -> Result<Vec<&'a str>, ValidationError> {
+        let all = self.shows_select_all() && data.get(&self.select_all_name()).is_some();
+        let submitted = data.get_all(self.name);
+        let values: Vec<&'a str> = self
+            .options
+            .iter()
+            .map(|option| option.value)
+            .filter(|value| all || submitted.contains(value))
+            .collect();
+
+        match self.max_selections {
+            Some(max) if values.len() > max => Err(ValidationError::new(
+                self.name,
+                format!("Choose at most {} options", max),
+                "max_selections",
+            )),
+            _ => Ok(values),
+        }
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-multiselect".to_string()];
         if self.disabled {
@@ -72,64 +143,110 @@ impl<'a> MultiSelect<'a> {
     fn is_selected(&self, value: &str) -> bool {
         self.selected.contains(&value)
     }
+
+    /// "Select all" is left out when it would exceed the maximum
+    fn shows_select_all(&self) -> bool {
+        self.select_all.is_some()
+            && self
+                .max_selections
+                .is_none_or(|max| max >= self.options.len())
+    }
+
+    fn selected_options(&self) -> Vec<&MultiSelectOption<'a>> {
+        self.options
+            .iter()
+            .filter(|option| self.is_selected(option.value))
+            .collect()
+    }
+
+    /// Trigger text: the labels for one or two selections, otherwise a count
+    fn summary(&self) -> String {
+        let selected = self.selected_options();
+        match selected.len() {
+            0 => self.placeholder.unwrap_or("Select options").to_string(),
+            1 | 2 => selected
+                .iter()
+                .map(|option| option.label)
+                .collect::<Vec<_>>()
+                .join(", "),
+            n => format!("{} selected", n),
+        }
+    }
 }
 
 impl<'a> Render for MultiSelect<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
+        let id = format!("sh-multiselect-{}", self.name);
+        let hint_id = format!("{}-hint", id);
+        let selected_count = self.selected_options().len();
+        let all_selected = !self.options.is_empty() && selected_count == self.options.len();
 
         html! {
-            div
-                class=(classes)
-                role="group"
-                aria-label="Multi-select options"
-            {
-                @if let Some(placeholder) = self.placeholder {
-                    span class="sh-multiselect__placeholder" { (placeholder) }
+            details class=(classes) id=(id) open?[self.open] {
+                summary class="sh-multiselect__trigger" {
+                    span
+                        class={
+                            "sh-multiselect__summary"
+                            @if selected_count == 0 { " sh-multiselect__placeholder" }
+                        }
+                    {
+                        @if let Some(label) = self.label {
+                            span class="sh-visually-hidden" { (label) ": " }
+                        }
+                        (self.summary())
+                    }
+                    span class="sh-multiselect__chevron" aria-hidden="true" {
+                        svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" {
+                            polyline points="6 9 12 15 18 9";
+                        }
+                    }
                 }
 
-                div class="sh-multiselect__options" {
-                    @for option in &self.options {
-                        label class="sh-multiselect__option" {
-                            input
-                                type="checkbox"
-                                name=(self.name)
-                                value=(option.value)
-                                checked=[if self.is_selected(option.value) { Some("") } else { None }]
-                                disabled?[self.disabled]
-                                class="sh-multiselect__input"
-                            ;
-                            span class="sh-multiselect__label" { (option.label) }
-                            span class="sh-multiselect__check" aria-hidden="true" {
-                                svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="3" {
-                                    polyline points="20 6 9 17 4 12";
-                                }
+                div class="sh-multiselect__panel" {
+                    fieldset
+                        class="sh-multiselect__options"
+                        disabled?[self.disabled]
+                        aria-describedby=[self.max_selections.map(|_| hint_id.as_str())]
+                    {
+                        legend class="sh-visually-hidden" { (self.label.unwrap_or("Options")) }
+
+                        @if let (true, Some(label)) = (self.shows_select_all(), self.select_all) {
+                            label class="sh-multiselect__option sh-multiselect__option--all" {
+                                input
+                                    type="checkbox"
+                                    name=(self.select_all_name())
+                                    value="1"
+                                    checked?[all_selected]
+                                    class="sh-multiselect__input sh-multiselect__all-input";
+                                span class="sh-multiselect__label" { (label) }
                             }
                         }
-                    }
-                }
 
-                @if !self.selected.is_empty() {
-                    div class="sh-multiselect__tags" aria-label="Selected items" {
-                        @for value in &self.selected {
-                            @if let Some(option) = self.options.iter().find(|o| o.value == *value) {
-                                span class="sh-multiselect__tag" {
-                                    (option.label)
-                                    button
-                                        type="button"
-                                        class="sh-multiselect__tag-remove"
-                                        aria-label=(format!("Remove {}", option.label))
-                                        tabindex="0"
-                                    {
-                                        svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="3" {
-                                            line x1="18" y1="6" x2="6" y2="18";
-                                            line x1="6" y1="6" x2="18" y2="18";
-                                        }
+                        @for option in &self.options {
+                            label class="sh-multiselect__option" {
+                                input
+                                    type="checkbox"
+                                    name=(self.name)
+                                    value=(option.value)
+                                    checked?[self.is_selected(option.value)]
+                                    class="sh-multiselect__input";
+                                span class="sh-multiselect__label" { (option.label) }
+                                span class="sh-multiselect__check" aria-hidden="true" {
+                                    svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="3" {
+                                        polyline points="20 6 9 17 4 12";
                                     }
                                 }
                             }
                         }
                     }
+
+                    div class="sh-multiselect__footer" {
+                        span class="sh-multiselect__count" aria-hidden="true" {}
+                        @if let Some(max) = self.max_selections {
+                            span class="sh-multiselect__hint" id=(hint_id) { "Choose up to " (max) }
+                        }
+                    }
                 }
             }
         }
@@ -141,49 +258,117 @@ pub fn multi_select_css() -> String {
     r#"
 .sh-multiselect {
     position: relative;
+    display: inline-block;
+    min-width: 14rem;
+    font-size: 0.875rem;
+}
+
+.sh-multiselect__trigger {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: 0.5rem;
+    min-height: 2.5rem;
+    padding: 0.5rem 0.75rem;
     border: 1px solid var(--sh-border, #e5e7eb);
     border-radius: var(--sh-radius-lg, 0.5rem);
-    padding: 0.75rem;
     background: var(--sh-surface, #fff);
-    min-height: 3rem;
+    color: var(--sh-text, #1f2937);
+    cursor: pointer;
+    list-style: none;
+}
+
+.sh-multiselect__trigger::-webkit-details-marker {
+    display: none;
+}
+
+.sh-multiselect__trigger:focus-visible {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 2px;
+}
+
+.sh-multiselect__summary {
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
 }
 
 .sh-multiselect__placeholder {
     color: var(--sh-text-muted, #9ca3af);
-    font-size: 0.875rem;
-    pointer-events: none;
+}
+
+.sh-multiselect__chevron {
+    display: flex;
+    flex-shrink: 0;
+    color: var(--sh-text-muted, #6b7280);
+    transition: transform 0.2s ease;
+}
+
+.sh-multiselect[open] .sh-multiselect__chevron {
+    transform: rotate(180deg);
+}
+
+.sh-multiselect__panel {
+    position: absolute;
+    top: calc(100% + 0.25rem);
+    left: 0;
+    right: 0;
+    z-index: 20;
+    padding: 0.375rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-lg, 0.5rem);
+    background: var(--sh-surface, #fff);
+    box-shadow: 0 10px 25px -5px rgba(0, 0, 0, 0.1);
+    counter-reset: sh-multiselect;
 }
 
 .sh-multiselect__options {
     display: flex;
-    flex-wrap: wrap;
-    gap: 0.5rem;
-    margin-top: 0.5rem;
+    flex-direction: column;
+    max-height: 16rem;
+    overflow-y: auto;
+    margin: 0;
+    padding: 0;
+    border: 0;
 }
 
 .sh-multiselect__option {
     display: flex;
     align-items: center;
     gap: 0.5rem;
-    padding: 0.5rem 0.75rem;
-    background: var(--sh-surface-2, #f3f4f6);
+    padding: 0.5rem 0.625rem;
     border-radius: var(--sh-radius-md, 0.375rem);
     cursor: pointer;
-    transition: all 0.2s ease;
-    font-size: 0.875rem;
+    transition: background 0.2s ease;
 }
 
 .sh-multiselect__option:hover {
-    background: var(--sh-surface-hover, #e5e7eb);
+    background: var(--sh-surface-hover, #f3f4f6);
+}
+
+.sh-multiselect__option--all {
+    margin-bottom: 0.25rem;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: 0;
+    font-weight: 500;
+}
+
+.sh-multiselect__option:not(.sh-multiselect__option--all):has(.sh-multiselect__input:checked) {
+    counter-increment: sh-multiselect;
 }
 
 .sh-multiselect__input {
     width: 1rem;
     height: 1rem;
+    margin: 0;
     accent-color: var(--sh-primary, #3b82f6);
     cursor: pointer;
 }
 
+.sh-multiselect__label {
+    flex: 1;
+}
+
 .sh-multiselect__check {
     display: none;
     color: var(--sh-primary, #3b82f6);
@@ -198,48 +383,31 @@ pub fn multi_select_css() -> String {
     display: inline-block;
 }
 
-.sh-multiselect__tags {
-    display: flex;
-    flex-wrap: wrap;
-    gap: 0.5rem;
-    margin-top: 0.75rem;
-    padding-top: 0.75rem;
-    border-top: 1px solid var(--sh-border, #e5e7eb);
-}
-
-.sh-multiselect__tag {
-    display: inline-flex;
-    align-items: center;
-    gap: 0.375rem;
-    padding: 0.25rem 0.5rem;
-    background: var(--sh-primary, #3b82f6);
-    color: white;
-    border-radius: var(--sh-radius-sm, 0.25rem);
-    font-size: 0.75rem;
-    font-weight: 500;
+/* "Select all" overrides the individual boxes */
+.sh-multiselect__options:has(.sh-multiselect__all-input:checked) .sh-multiselect__option:not(.sh-multiselect__option--all) {
+    opacity: 0.6;
 }
 
-.sh-multiselect__tag-remove {
+.sh-multiselect__footer {
     display: flex;
-    align-items: center;
-    justify-content: center;
-    background: transparent;
-    border: none;
-    color: white;
-    cursor: pointer;
-    padding: 0;
-    opacity: 0.8;
-    transition: opacity 0.2s ease;
+    justify-content: space-between;
+    gap: 0.5rem;
+    padding: 0.375rem 0.625rem 0.125rem;
+    font-size: 0.75rem;
+    color: var(--sh-text-muted, #6b7280);
 }
 
-.sh-multiselect__tag-remove:hover {
-    opacity: 1;
+.sh-multiselect__count::before {
+    content: counter(sh-multiselect) " selected";
 }
 
 /* Disabled state */
 .sh-multiselect--disabled {
     opacity: 0.5;
-    pointer-events: none;
+}
+
+.sh-multiselect--disabled .sh-multiselect__trigger {
+    cursor: not-allowed;
 }
 "#
     .to_string()
@@ -297,6 +465,81 @@ mod tests {
     fn test_multiselect_css() {
         let css = multi_select_css();
         assert!(css.contains(".sh-multiselect"));
-        assert!(css.contains(".sh-multiselect__tag"));
+        assert!(css.contains(".sh-multiselect__trigger"));
+        assert!(css.contains("counter(sh-multiselect)"));
+    }
+
+    fn fruits() -> Vec<MultiSelectOption<'static>> {
+        vec![
+            MultiSelectOption::new("apple", "Apple"),
+            MultiSelectOption::new("pear", "Pear"),
+            MultiSelectOption::new("plum", "Plum"),
+        ]
+    }
+
+    #[test]
+    fn test_multiselect_render() {
+        let html = MultiSelect::new("fruit", fruits())
+            .label("Fruit")
+            .selected(vec!["pear"])
+            .select_all("Select all")
+            .render()
+            .into_string();
+        assert!(html.starts_with(r#"<details class="sh-multiselect" id="sh-multiselect-fruit">"#));
+        assert!(html.contains(r#"<span class="sh-visually-hidden">Fruit: </span>Pear"#));
+        assert!(html.contains(r#"name="fruit" value="pear" checked"#));
+        assert!(html.contains(r#"name="fruit_all" value="1" class"#));
+        assert_eq!(html.matches(r#"name="fruit""#).count(), 3);
+        assert!(!html.contains("sh-multiselect__hint"));
+    }
+
+    #[test]
+    fn test_multiselect_summary() {
+        let ms = MultiSelect::new("fruit", fruits()).placeholder("Pick fruit");
+        assert_eq!(ms.summary(), "Pick fruit");
+        let ms = ms.selected(vec!["plum", "apple"]);
+        assert_eq!(ms.summary(), "Apple, Plum");
+        let ms = ms.selected(vec!["plum", "apple", "pear"]);
+        assert_eq!(ms.summary(), "3 selected");
+        let html = ms.select_all("All").open(true).render().into_string();
+        assert!(html.contains(" open>"));
+        assert!(html.contains(r#"name="fruit_all" value="1" checked"#));
+    }
+
+    #[test]
+    fn test_multiselect_max_selections() {
+        let ms = MultiSelect::new("fruit", fruits())
+            .select_all("Select all")
+            .max_selections(2);
+        let html = ms.render().into_string();
+        assert!(html.contains("Choose up to 2"));
+        assert!(html.contains(r#"aria-describedby="sh-multiselect-fruit-hint""#));
+        // Selecting everything would exceed the limit
+        assert!(!html.contains("fruit_all"));
+
+        let data = FormData::from_pairs([("fruit", "apple"), ("fruit", "pear"), ("fruit", "plum")]);
+        let err = ms.values_from_form(&data).unwrap_err();
+        assert_eq!(err.field, "fruit");
+        assert_eq!(err.code, "max_selections");
+    }
+
+    #[test]
+    fn test_multiselect_values_from_form() {
+        let ms = MultiSelect::new("fruit", fruits()).select_all("Select all");
+        let data = FormData::from_pairs([
+            ("fruit", "plum"),
+            ("fruit", "kiwi"),
+            ("fruit", "apple"),
+            ("fruit", "plum"),
+        ]);
+        assert_eq!(ms.values_from_form(&data), Ok(vec!["apple", "plum"]));
+
+        let data = FormData::from_pairs([("fruit_all", "1")]);
+        assert_eq!(
+            ms.values_from_form(&data),
+            Ok(vec!["apple", "pear", "plum"])
+        );
+
+        assert_eq!(ms.values_from_form(&FormData::default()), Ok(vec![]));
     }
 }
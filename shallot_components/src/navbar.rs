@@ -2,8 +2,17 @@
 //!
 //! Provides a responsive navigation bar with support for sticky, fixed,
 //! and transparent styles.
-
+//!
+//! Navigation links passed with [`Navbar::items`] render as a list in the
+//! center section; [`NavbarItem::Group`] entries open a dropdown built on
+//! `<details>`. With [`Navbar::collapsible`] the center and end sections fold
+//! behind a checkbox-driven hamburger button on small screens. Links are
+//! marked active by hand or from the page's [`RouteContext`] via
+//! [`Navbar::route`].
+
+use crate::route::{route_match, RouteContext, RouteMatch};
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 
 /// Navbar variant determining position and style
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,6 +26,10 @@ pub enum NavbarVariant {
     Fixed,
     /// Transparent background for hero sections
     Transparent,
+    /// Sticky, transparent at the top of the page and solid once scrolled
+    ///
+    /// Uses `animation-timeline` where supported and stays solid elsewhere.
+    StickyTransparent,
 }
 
 /// Navbar size
@@ -28,6 +41,54 @@ pub enum NavbarSize {
     Lg,
 }
 
+/// A single navigation link
+#[derive(Debug, Clone)]
+pub struct NavbarLink<'a> {
+    pub label: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+    pub active: bool,
+}
+
+impl<'a> NavbarLink<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+            active: false,
+        }
+    }
+
+    /// Mark the link as the current page
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+}
+
+/// Entry in the navbar's link list
+#[derive(Debug, Clone)]
+pub enum NavbarItem<'a> {
+    Link(NavbarLink<'a>),
+    /// Labelled dropdown of links
+    Group {
+        label: Cow<'a, str>,
+        links: Vec<NavbarLink<'a>>,
+    },
+}
+
+impl<'a> NavbarItem<'a> {
+    pub fn link(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        NavbarItem::Link(NavbarLink::new(label, href))
+    }
+
+    pub fn group(label: impl Into<Cow<'a, str>>, links: Vec<NavbarLink<'a>>) -> Self {
+        NavbarItem::Group {
+            label: label.into(),
+            links,
+        }
+    }
+}
+
 /// Navbar Component
 #[derive(Debug, Clone)]
 pub struct Navbar<'a> {
@@ -38,6 +99,12 @@ pub struct Navbar<'a> {
     pub size: NavbarSize,
     pub aria_label: Option<&'a str>,
     pub expanded: bool,
+    pub id: Cow<'a, str>,
+    pub items: Vec<NavbarItem<'a>>,
+    pub cta: Option<Markup>,
+    pub announcement: Option<Markup>,
+    pub collapsible: bool,
+    pub current_path: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for Navbar<'a> {
@@ -50,6 +117,12 @@ impl<'a> Default for Navbar<'a> {
             size: NavbarSize::default(),
             aria_label: None,
             expanded: false,
+            id: Cow::Borrowed("sh-navbar"),
+            items: Vec::new(),
+            cta: None,
+            announcement: None,
+            collapsible: false,
+            current_path: None,
         }
     }
 }
@@ -102,6 +175,55 @@ impl<'a> Navbar<'a> {
         self
     }
 
+    /// Set the id prefix used for the menu toggle and dropdown group
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the navigation links rendered in the center section
+    pub fn items(mut self, items: Vec<NavbarItem<'a>>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Set the call-to-action shown at the end of the bar
+    pub fn cta(mut self, cta: Markup) -> Self {
+        self.cta = Some(cta);
+        self
+    }
+
+    /// Set an announcement bar rendered above the navbar
+    pub fn announcement(mut self, announcement: Markup) -> Self {
+        self.announcement = Some(announcement);
+        self
+    }
+
+    /// Fold the center and end sections behind a hamburger on small screens
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Mark links to `path` as the current page
+    pub fn current_path(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.current_path = Some(path.into());
+        self
+    }
+
+    /// Take the current path from the page's route
+    pub fn route(self, route: &RouteContext<'a>) -> Self {
+        self.current_path(route.path.clone())
+    }
+
+    fn is_active(&self, link: &NavbarLink<'_>) -> bool {
+        link.active
+            || self
+                .current_path
+                .as_deref()
+                .is_some_and(|current| route_match(&link.href, current) == RouteMatch::Exact)
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-navbar"];
 
@@ -110,6 +232,10 @@ impl<'a> Navbar<'a> {
             NavbarVariant::Sticky => classes.push("sh-navbar--sticky"),
             NavbarVariant::Fixed => classes.push("sh-navbar--fixed"),
             NavbarVariant::Transparent => classes.push("sh-navbar--transparent"),
+            NavbarVariant::StickyTransparent => {
+                classes.push("sh-navbar--sticky");
+                classes.push("sh-navbar--scroll-solid");
+            }
         }
 
         match self.size {
@@ -122,23 +248,110 @@ impl<'a> Navbar<'a> {
             classes.push("sh-navbar--expanded");
         }
 
+        if self.collapsible {
+            classes.push("sh-navbar--collapsible");
+        }
+
         classes.join(" ")
     }
+
+    fn render_link(&self, link: &NavbarLink<'_>) -> Markup {
+        let active = self.is_active(link);
+        html! {
+            a
+                class=(if active { "sh-navbar__link sh-navbar__link--active" } else { "sh-navbar__link" })
+                href=(link.href)
+                aria-current=[active.then_some("page")]
+            {
+                (link.label)
+            }
+        }
+    }
+
+    fn render_items(&self) -> Markup {
+        let dropdown_group = format!("{}-dropdown", self.id);
+
+        html! {
+            nav class="sh-navbar__nav" aria-label="Primary" {
+                ul class="sh-navbar__items" {
+                    @for item in &self.items {
+                        @match item {
+                            NavbarItem::Link(link) => {
+                                li class="sh-navbar__item" { (self.render_link(link)) }
+                            }
+                            NavbarItem::Group { label, links } => {
+                                @let active = links.iter().any(|link| self.is_active(link));
+                                li class="sh-navbar__item" {
+                                    details class="sh-navbar__dropdown" name=(dropdown_group) {
+                                        summary
+                                            class=(if active { "sh-navbar__link sh-navbar__link--active" } else { "sh-navbar__link" })
+                                        {
+                                            (label)
+                                            span class="sh-navbar__caret" aria-hidden="true" {}
+                                        }
+                                        ul class="sh-navbar__dropdown-menu" {
+                                            @for link in links {
+                                                li { (self.render_link(link)) }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Render for Navbar<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
+        let toggle_id = format!("{}-toggle", self.id);
+        let menu_id = format!("{}-menu", self.id);
 
         html! {
+            @if let Some(announcement) = &self.announcement {
+                div class="sh-navbar-announcement" role="region" aria-label="Announcement" {
+                    (announcement)
+                }
+            }
+            @if self.variant == NavbarVariant::StickyTransparent {
+                div class="sh-navbar-sentinel" aria-hidden="true" {}
+            }
             header
                 class=(classes)
                 role="banner"
                 aria-label=(self.aria_label.unwrap_or("Main navigation"))
             {
                 div class="sh-navbar__start" { (self.start) }
-                div class="sh-navbar__center" { (self.center) }
-                div class="sh-navbar__end" { (self.end) }
+                @if self.collapsible {
+                    input
+                        type="checkbox"
+                        class="sh-navbar__toggle"
+                        id=(toggle_id)
+                        aria-controls=(menu_id)
+                        aria-label="Toggle menu"
+                        checked?[self.expanded];
+                    label class="sh-navbar__hamburger" for=(toggle_id) aria-hidden="true" {
+                        span class="sh-navbar__hamburger-bar" {}
+                        span class="sh-navbar__hamburger-bar" {}
+                        span class="sh-navbar__hamburger-bar" {}
+                    }
+                }
+                div class="sh-navbar__center" id=[self.collapsible.then_some(&menu_id)] {
+                    (self.center)
+                    @if !self.items.is_empty() {
+                        (self.render_items())
+                    }
+                }
+                div class="sh-navbar__end" {
+                    (self.end)
+                    @if let Some(cta) = &self.cta {
+                        div class="sh-navbar__cta" { (cta) }
+                    }
+                }
             }
         }
     }
@@ -227,9 +440,246 @@ pub fn navbar_css() -> String {
     display: flex;
 }
 
+/* Link list */
+.sh-navbar__items {
+    display: flex;
+    align-items: center;
+    gap: 0.25rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-navbar__item {
+    position: relative;
+}
+
+.sh-navbar__link {
+    display: inline-flex;
+    align-items: center;
+    gap: 0.375rem;
+    padding: 0.5rem 0.75rem;
+    border-radius: 0.375rem;
+    font-size: 0.875rem;
+    font-weight: 500;
+    color: var(--sh-text-muted);
+    text-decoration: none;
+    cursor: pointer;
+    list-style: none;
+    transition: color 0.15s, background 0.15s;
+}
+
+.sh-navbar__link::-webkit-details-marker {
+    display: none;
+}
+
+.sh-navbar__link:hover,
+.sh-navbar__link--active {
+    color: var(--sh-text);
+}
+
+.sh-navbar__link:hover {
+    background: var(--sh-surface-alt);
+}
+
+.sh-navbar__link:focus-visible {
+    outline: 2px solid var(--sh-accent);
+    outline-offset: 2px;
+}
+
+/* Dropdowns */
+.sh-navbar__caret {
+    width: 0.375rem;
+    height: 0.375rem;
+    border-right: 1.5px solid currentColor;
+    border-bottom: 1.5px solid currentColor;
+    transform: translateY(-2px) rotate(45deg);
+    transition: transform 0.2s;
+}
+
+.sh-navbar__dropdown[open] .sh-navbar__caret {
+    transform: translateY(1px) rotate(-135deg);
+}
+
+.sh-navbar__dropdown-menu {
+    position: absolute;
+    top: calc(100% + 0.5rem);
+    left: 0;
+    z-index: 110;
+    min-width: 12rem;
+    margin: 0;
+    padding: 0.375rem;
+    list-style: none;
+    background: var(--sh-surface);
+    border: 1px solid var(--sh-border);
+    border-radius: 0.5rem;
+    box-shadow: 0 10px 25px -5px rgb(0 0 0 / 0.1);
+}
+
+.sh-navbar__dropdown-menu .sh-navbar__link {
+    display: flex;
+    width: 100%;
+}
+
+/* Call to action */
+.sh-navbar__cta {
+    display: flex;
+    align-items: center;
+    margin-left: 0.5rem;
+}
+
+/* Announcement bar */
+.sh-navbar-announcement {
+    padding: 0.5rem 1rem;
+    font-size: 0.875rem;
+    text-align: center;
+    background: var(--sh-primary);
+    color: white;
+}
+
+.sh-navbar-announcement a {
+    color: inherit;
+    font-weight: 600;
+}
+
+/* Hamburger toggle */
+.sh-navbar__toggle {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    opacity: 0;
+    pointer-events: none;
+}
+
+.sh-navbar__hamburger {
+    display: none;
+}
+
+@media (max-width: 768px) {
+    .sh-navbar--collapsible {
+        flex-wrap: wrap;
+    }
+
+    .sh-navbar--collapsible .sh-navbar__hamburger {
+        display: inline-flex;
+        flex-direction: column;
+        justify-content: center;
+        gap: 4px;
+        width: 2.5rem;
+        height: 2.5rem;
+        margin-left: auto;
+        padding: 0.5rem;
+        border-radius: 0.375rem;
+        cursor: pointer;
+    }
+
+    .sh-navbar__hamburger-bar {
+        height: 2px;
+        border-radius: 1px;
+        background: currentColor;
+        transition: transform 0.2s, opacity 0.2s;
+    }
+
+    .sh-navbar__toggle:checked + .sh-navbar__hamburger .sh-navbar__hamburger-bar:nth-child(1) {
+        transform: translateY(6px) rotate(45deg);
+    }
+
+    .sh-navbar__toggle:checked + .sh-navbar__hamburger .sh-navbar__hamburger-bar:nth-child(2) {
+        opacity: 0;
+    }
+
+    .sh-navbar__toggle:checked + .sh-navbar__hamburger .sh-navbar__hamburger-bar:nth-child(3) {
+        transform: translateY(-6px) rotate(-45deg);
+    }
+
+    .sh-navbar__toggle:focus-visible + .sh-navbar__hamburger {
+        outline: 2px solid var(--sh-accent);
+    }
+
+    .sh-navbar--collapsible .sh-navbar__center,
+    .sh-navbar--collapsible .sh-navbar__end {
+        display: none;
+        flex: 1 0 100%;
+        flex-direction: column;
+        align-items: stretch;
+        padding-top: 0.75rem;
+    }
+
+    .sh-navbar__toggle:checked ~ .sh-navbar__center,
+    .sh-navbar__toggle:checked ~ .sh-navbar__end {
+        display: flex;
+    }
+
+    .sh-navbar--collapsible .sh-navbar__items {
+        flex-direction: column;
+        align-items: stretch;
+    }
+
+    .sh-navbar--collapsible .sh-navbar__dropdown-menu {
+        position: static;
+        border: none;
+        box-shadow: none;
+        padding-left: 1rem;
+    }
+
+    .sh-navbar--collapsible .sh-navbar__cta {
+        margin-left: 0;
+    }
+}
+
+/* Transparent until the top sentinel scrolls away */
+.sh-navbar-sentinel {
+    position: absolute;
+    top: 0;
+    left: 0;
+    width: 1px;
+    height: var(--sh-navbar-solid-after, 80px);
+    pointer-events: none;
+    visibility: hidden;
+}
+
+@keyframes sh-navbar-solidify {
+    from {
+        background: transparent;
+        border-bottom-color: transparent;
+        box-shadow: none;
+    }
+    to {
+        background: var(--sh-surface);
+        border-bottom-color: var(--sh-border);
+        box-shadow: 0 1px 3px rgb(0 0 0 / 0.08);
+    }
+}
+
+@supports (animation-timeline: scroll()) {
+    .sh-navbar--scroll-solid {
+        animation: sh-navbar-solidify linear both;
+        animation-timeline: scroll(root block);
+        animation-range: 0 var(--sh-navbar-solid-after, 80px);
+    }
+}
+
+@supports (timeline-scope: --sh-navbar-top) {
+    :root:has(.sh-navbar-sentinel) {
+        timeline-scope: --sh-navbar-top;
+    }
+
+    .sh-navbar-sentinel {
+        view-timeline: --sh-navbar-top block;
+    }
+
+    .sh-navbar--scroll-solid {
+        animation-timeline: --sh-navbar-top;
+        animation-range: exit 0% exit 100%;
+    }
+}
+
 /* Reduced motion */
 @media (prefers-reduced-motion: reduce) {
-    .sh-navbar {
+    .sh-navbar,
+    .sh-navbar__link,
+    .sh-navbar__caret,
+    .sh-navbar__hamburger-bar {
         transition: none;
     }
 }
@@ -294,4 +744,101 @@ mod tests {
         assert!(css.contains(".sh-navbar--fixed"));
         assert!(css.contains(".sh-navbar--transparent"));
     }
+
+    fn product_items() -> Vec<NavbarItem<'static>> {
+        vec![
+            NavbarItem::link("Pricing", "/pricing"),
+            NavbarItem::group(
+                "Product",
+                vec![
+                    NavbarLink::new("Features", "/features").active(true),
+                    NavbarLink::new("Changelog", "/changelog"),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_navbar_items_and_dropdowns() {
+        let html = Navbar::new()
+            .id("site")
+            .items(product_items())
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"<nav class="sh-navbar__nav" aria-label="Primary">"#));
+        assert!(html.contains(r#"href="/pricing""#));
+        assert!(html.contains(r#"<details class="sh-navbar__dropdown" name="site-dropdown">"#));
+        assert!(html.contains(r#"href="/features" aria-current="page""#));
+        assert!(html.contains(r#"<summary class="sh-navbar__link sh-navbar__link--active">"#));
+    }
+
+    #[test]
+    fn test_navbar_route() {
+        let route = RouteContext::new("/changelog/");
+        let html = Navbar::new()
+            .route(&route)
+            .items(vec![
+                NavbarItem::link("Pricing", "/pricing"),
+                NavbarItem::group("Product", vec![NavbarLink::new("Changelog", "/changelog")]),
+            ])
+            .render()
+            .into_string();
+
+        assert_eq!(html.matches(r#"aria-current="page""#).count(), 1);
+        assert!(html.contains(r#"href="/changelog" aria-current="page""#));
+        assert!(html.contains(r#"<summary class="sh-navbar__link sh-navbar__link--active">"#));
+    }
+
+    #[test]
+    fn test_navbar_collapsible_toggle() {
+        let html = Navbar::new()
+            .id("site")
+            .collapsible(true)
+            .expanded(true)
+            .render()
+            .into_string();
+
+        assert!(html.contains("sh-navbar--collapsible"));
+        assert!(html.contains(r#"id="site-toggle""#));
+        assert!(html.contains(r#"aria-controls="site-menu""#));
+        assert!(html.contains(" checked"));
+        assert!(html.contains(r#"class="sh-navbar__center" id="site-menu""#));
+        assert!(html.contains(r#"for="site-toggle""#));
+    }
+
+    #[test]
+    fn test_navbar_not_collapsible_by_default() {
+        let html = Navbar::new().items(product_items()).render().into_string();
+
+        assert!(!html.contains("sh-navbar__toggle"));
+        assert!(!html.contains("sh-navbar__hamburger"));
+    }
+
+    #[test]
+    fn test_navbar_cta_and_announcement() {
+        let html = Navbar::new()
+            .cta(html! { a href="/signup" { "Sign up" } })
+            .announcement(html! { "v2 is out" })
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"<div class="sh-navbar__cta"><a href="/signup">"#));
+        assert!(html.starts_with(r#"<div class="sh-navbar-announcement""#));
+    }
+
+    #[test]
+    fn test_navbar_sticky_transparent() {
+        let navbar = Navbar::new().variant(NavbarVariant::StickyTransparent);
+        let classes = navbar.build_classes();
+        assert!(classes.contains("sh-navbar--sticky"));
+        assert!(classes.contains("sh-navbar--scroll-solid"));
+
+        let html = navbar.render().into_string();
+        assert!(html.starts_with(r#"<div class="sh-navbar-sentinel" aria-hidden="true">"#));
+
+        let css = navbar_css();
+        assert!(css.contains("animation-timeline: scroll(root block)"));
+        assert!(css.contains("view-timeline: --sh-navbar-top block"));
+    }
 }
@@ -0,0 +1,427 @@
+//! Newsletter Component - Email signup form with consent and success state
+//!
+//! The form posts to your own endpoint; parse the submission with
+//! [`NewsletterSignup::from_form`] and render the form again with
+//! [`NewsletterForm::success`] or [`NewsletterForm::error`] as the response.
+
+use crate::form::{validators, Form, FormData, FormVariant, Validator};
+use crate::input::{Checkbox, Input};
+use maud::{html, Markup, Render};
+use std::fmt;
+
+/// Name of the email field
+pub const NEWSLETTER_EMAIL_FIELD: &str = "email";
+
+/// Name of the consent checkbox
+pub const NEWSLETTER_CONSENT_FIELD: &str = "consent";
+
+/// Reasons a signup is rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewsletterError {
+    InvalidEmail,
+    ConsentRequired,
+}
+
+impl fmt::Display for NewsletterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NewsletterError::InvalidEmail => write!(f, "Please enter a valid email address"),
+            NewsletterError::ConsentRequired => {
+                write!(f, "Please agree to receive emails before subscribing")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NewsletterError {}
+
+/// A validated signup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewsletterSignup {
+    /// Trimmed, lowercased address
+    pub email: String,
+    pub consent: bool,
+}
+
+impl NewsletterSignup {
+    /// Read a [`NewsletterForm`] submission; set `require_consent` when the
+    /// form rendered a consent checkbox
+    pub fn from_form(data: &FormData, require_consent: bool) -> Result<Self, NewsletterError> {
+        let email = data
+            .get(NEWSLETTER_EMAIL_FIELD)
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        if email.is_empty()
+            || validators::email()
+                .validate(&email, NEWSLETTER_EMAIL_FIELD)
+                .is_some()
+        {
+            return Err(NewsletterError::InvalidEmail);
+        }
+        let consent = data.get(NEWSLETTER_CONSENT_FIELD).is_some();
+        if require_consent && !consent {
+            return Err(NewsletterError::ConsentRequired);
+        }
+        Ok(Self { email, consent })
+    }
+}
+
+/// Arrangement of the email field and button
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewsletterLayout {
+    /// Field and button on one row, for footers and hero sections
+    #[default]
+    Inline,
+    /// Field above a full-width button, for sidebars and cards
+    Stacked,
+}
+
+/// Newsletter signup form
+pub struct NewsletterForm<'a> {
+    action: &'a str,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    label: &'a str,
+    placeholder: &'a str,
+    button_label: &'a str,
+    consent: Option<&'a str>,
+    value: Option<&'a str>,
+    error: Option<&'a str>,
+    success: Option<&'a str>,
+    csrf_token: Option<&'a str>,
+    honeypot: Option<&'a str>,
+    layout: NewsletterLayout,
+}
+
+impl<'a> NewsletterForm<'a> {
+    /// `action` is the URL the signup is posted to
+    pub fn new(action: &'a str) -> Self {
+        Self {
+            action,
+            title: None,
+            description: None,
+            label: "Email address",
+            placeholder: "you@example.com",
+            button_label: "Subscribe",
+            consent: None,
+            value: None,
+            error: None,
+            success: None,
+            csrf_token: None,
+            honeypot: None,
+            layout: NewsletterLayout::default(),
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    pub fn button_label(mut self, label: &'a str) -> Self {
+        self.button_label = label;
+        self
+    }
+
+    /// Add a required consent checkbox with this label
+    pub fn consent(mut self, label: &'a str) -> Self {
+        self.consent = Some(label);
+        self
+    }
+
+    /// Pre-fill the email, e.g. after a rejected submission
+    pub fn value(mut self, email: &'a str) -> Self {
+        self.value = Some(email);
+        self
+    }
+
+    pub fn error(mut self, message: &'a str) -> Self {
+        self.error = Some(message);
+        self
+    }
+
+    /// Replace the form with a confirmation message
+    pub fn success(mut self, message: &'a str) -> Self {
+        self.success = Some(message);
+        self
+    }
+
+    pub fn csrf_token(mut self, token: &'a str) -> Self {
+        self.csrf_token = Some(token);
+        self
+    }
+
+    /// Add a honeypot field; see [`Form::with_honeypot`]
+    pub fn honeypot(mut self, name: &'a str) -> Self {
+        self.honeypot = Some(name);
+        self
+    }
+
+    pub fn layout(mut self, layout: NewsletterLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    fn layout_class(&self) -> &'static str {
+        match self.layout {
+            NewsletterLayout::Inline => "sh-newsletter sh-newsletter--inline",
+            NewsletterLayout::Stacked => "sh-newsletter sh-newsletter--stacked",
+        }
+    }
+
+    fn render_form(&self) -> Markup {
+        let mut email = Input::email(NEWSLETTER_EMAIL_FIELD)
+            .label(self.label)
+            .placeholder(self.placeholder)
+            .autocomplete("email")
+            .required(true);
+        if let Some(value) = self.value {
+            email = email.value(value);
+        }
+        if let Some(error) = self.error {
+            email = email.error(error);
+        }
+
+        let fields = html! {
+            div class="sh-newsletter__row" {
+                (email)
+                button class="sh-btn sh-btn--primary sh-btn--md sh-newsletter__submit" type="submit" {
+                    (self.button_label)
+                }
+            }
+            @if let Some(consent) = self.consent {
+                div class="sh-newsletter__consent" {
+                    (Checkbox::new(consent, NEWSLETTER_CONSENT_FIELD).required(true))
+                }
+            }
+        };
+
+        let mut form = Form::new()
+            .action(self.action)
+            .method("post")
+            .variant(FormVariant::Compact)
+            .class("sh-newsletter__form")
+            .aria_label("Newsletter signup")
+            .fields(fields);
+        if let Some(token) = self.csrf_token {
+            form = form.csrf_token(token);
+        }
+        if let Some(name) = self.honeypot {
+            form = form.with_honeypot(name);
+        }
+        form.render()
+    }
+}
+
+impl<'a> Render for NewsletterForm<'a> {
+    fn render(&self) -> Markup {
+        let class = if self.success.is_some() {
+            format!("{} sh-newsletter--success", self.layout_class())
+        } else {
+            self.layout_class().to_string()
+        };
+
+        html! {
+            section class=(class) aria-label=[self.title.is_none().then_some("Newsletter")] {
+                @if let Some(title) = self.title {
+                    h2 class="sh-newsletter__title" { (title) }
+                }
+                @if let Some(message) = self.success {
+                    p class="sh-newsletter__success" role="status" {
+                        span class="sh-newsletter__check" aria-hidden="true" { "✓" }
+                        (message)
+                    }
+                } @else {
+                    @if let Some(description) = self.description {
+                        p class="sh-newsletter__description" { (description) }
+                    }
+                    (self.render_form())
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the newsletter form
+pub fn newsletter_css() -> String {
+    r#"
+.sh-newsletter {
+    display: flex;
+    flex-direction: column;
+    gap: 0.75rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-newsletter__title {
+    margin: 0;
+    font-size: 1.25rem;
+    font-weight: 700;
+}
+
+.sh-newsletter__description {
+    margin: 0;
+    color: var(--sh-text-muted, #6b7280);
+    line-height: 1.5;
+}
+
+.sh-newsletter__row {
+    display: flex;
+    gap: 0.5rem;
+}
+
+.sh-newsletter--inline .sh-newsletter__row {
+    align-items: flex-end;
+}
+
+.sh-newsletter--inline .sh-newsletter__row .sh-input-wrapper {
+    flex: 1;
+    min-width: 0;
+}
+
+.sh-newsletter--stacked .sh-newsletter__row {
+    flex-direction: column;
+}
+
+.sh-newsletter--stacked .sh-newsletter__submit {
+    width: 100%;
+}
+
+@media (max-width: 480px) {
+    .sh-newsletter--inline .sh-newsletter__row {
+        flex-direction: column;
+        align-items: stretch;
+    }
+}
+
+.sh-newsletter__consent {
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-newsletter__success {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0.75rem 1rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: color-mix(in srgb, var(--sh-success, #10b981) 12%, transparent);
+    color: var(--sh-success, #10b981);
+    font-weight: 500;
+}
+
+.sh-newsletter__check {
+    display: inline-flex;
+    align-items: center;
+    justify-content: center;
+    width: 1.5rem;
+    height: 1.5rem;
+    border-radius: 9999px;
+    background: var(--sh-success, #10b981);
+    color: #fff;
+    font-size: 0.875rem;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newsletter_signup_from_form() {
+        let data = FormData::from_pairs([("email", " Ada@Example.com "), ("consent", "on")]);
+        assert_eq!(
+            NewsletterSignup::from_form(&data, true),
+            Ok(NewsletterSignup {
+                email: "ada@example.com".to_string(),
+                consent: true,
+            })
+        );
+
+        let no_consent = FormData::from_pairs([("email", "ada@example.com")]);
+        assert_eq!(
+            NewsletterSignup::from_form(&no_consent, true),
+            Err(NewsletterError::ConsentRequired)
+        );
+        assert!(NewsletterSignup::from_form(&no_consent, false).is_ok());
+
+        let bad = FormData::from_pairs([("email", "not-an-email")]);
+        assert_eq!(
+            NewsletterSignup::from_form(&bad, false),
+            Err(NewsletterError::InvalidEmail)
+        );
+        let empty = FormData::from_pairs([("consent", "on")]);
+        assert_eq!(
+            NewsletterSignup::from_form(&empty, false),
+            Err(NewsletterError::InvalidEmail)
+        );
+    }
+
+    #[test]
+    fn test_newsletter_form_render() {
+        let html = NewsletterForm::new("/subscribe")
+            .title("Stay in the loop")
+            .consent("Send me the monthly newsletter")
+            .csrf_token("tok")
+            .honeypot("website")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"action="/subscribe""#));
+        assert!(html.contains(r#"type="email""#));
+        assert!(html.contains(r#"autocomplete="email""#));
+        assert!(html.contains(r#"name="consent""#));
+        assert!(html.contains("Send me the monthly newsletter"));
+        assert!(html.contains(r#"name="csrf_token" value="tok""#));
+        assert!(html.contains("sh-form__trap"));
+        assert!(html.contains("sh-newsletter--inline"));
+    }
+
+    #[test]
+    fn test_newsletter_form_states() {
+        let error = NewsletterForm::new("/subscribe")
+            .value("ada@")
+            .error("Please enter a valid email address")
+            .layout(NewsletterLayout::Stacked)
+            .render()
+            .into_string();
+        assert!(error.contains("sh-input-wrapper--error"));
+        assert!(error.contains(r#"value="ada@""#));
+        assert!(error.contains("sh-newsletter--stacked"));
+
+        let success = NewsletterForm::new("/subscribe")
+            .description("Weekly tips")
+            .success("Check your inbox to confirm your subscription")
+            .render()
+            .into_string();
+        assert!(success.contains("sh-newsletter--success"));
+        assert!(success.contains(r#"role="status""#));
+        assert!(!success.contains("<form"));
+        assert!(!success.contains("Weekly tips"));
+    }
+
+    #[test]
+    fn test_newsletter_css() {
+        let css = newsletter_css();
+        assert!(css.contains(".sh-newsletter__row"));
+        assert!(css.contains(".sh-newsletter__success"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Not Found Page - The body of a site's `404.html`
+//!
+//! [`NotFoundPage`] shows the status code, a heading and message, an
+//! optional site search and a few suggested links, with a button back
+//! home. Every piece of text can be replaced, and [`SiteBuilder::not_found`]
+//! adds it to a site as a `noindex` page at `/404.html`, the path static
+//! hosts serve for missing pages.
+
+use crate::button::{Button, ButtonVariant};
+use crate::site::{Head, SiteBuilder};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// A styled "page not found" body
+#[derive(Debug, Clone)]
+pub struct NotFoundPage<'a> {
+    /// Large status code shown above the title
+    pub code: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub message: Cow<'a, str>,
+    pub home_href: Cow<'a, str>,
+    pub home_label: Cow<'a, str>,
+    /// Search form target, queried with `?q=`
+    pub search_action: Option<Cow<'a, str>>,
+    /// Suggested pages as `(label, href)`
+    pub links: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> NotFoundPage<'a> {
+    pub fn new() -> Self {
+        Self {
+            code: Cow::Borrowed("404"),
+            title: Cow::Borrowed("Page not found"),
+            message: Cow::Borrowed("The page you are looking for has moved or no longer exists."),
+            home_href: Cow::Borrowed("/"),
+            home_label: Cow::Borrowed("Back to home"),
+            search_action: None,
+            links: Vec::new(),
+        }
+    }
+
+    pub fn code(mut self, code: impl Into<Cow<'a, str>>) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<Cow<'a, str>>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    pub fn home(mut self, href: impl Into<Cow<'a, str>>, label: impl Into<Cow<'a, str>>) -> Self {
+        self.home_href = href.into();
+        self.home_label = label.into();
+        self
+    }
+
+    /// Offer a search box submitting to `action`
+    pub fn search(mut self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.search_action = Some(action.into());
+        self
+    }
+
+    /// Suggest a page
+    pub fn link(mut self, label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        self.links.push((label.into(), href.into()));
+        self
+    }
+}
+
+impl Default for NotFoundPage<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Render for NotFoundPage<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            section class="sh-not-found" aria-labelledby="sh-not-found-title" {
+                p class="sh-not-found__code" aria-hidden="true" { (self.code) }
+                h1 id="sh-not-found-title" class="sh-not-found__title" { (self.title) }
+                p class="sh-not-found__message" { (self.message) }
+
+                @if let Some(action) = &self.search_action {
+                    form class="sh-not-found__search" role="search" action=(action) method="get" {
+                        input
+                            class="sh-not-found__search-input"
+                            type="search"
+                            name="q"
+                            placeholder="Search the site"
+                            aria-label="Search the site";
+                        // `Button` renders `type="button"`, which would not submit
+                        button type="submit" class="sh-btn sh-btn--secondary sh-btn--md" {
+                            "Search"
+                        }
+                    }
+                }
+
+                @if !self.links.is_empty() {
+                    nav class="sh-not-found__links" aria-label="Suggested pages" {
+                        ul {
+                            @for (label, href) in &self.links {
+                                li { a href=(href) { (label) } }
+                            }
+                        }
+                    }
+                }
+
+                div class="sh-not-found__actions" {
+                    (Button::new(self.home_label.as_ref())
+                        .variant(ButtonVariant::Primary)
+                        .href(self.home_href.as_ref())
+                        .render())
+                }
+            }
+        }
+    }
+}
+
+impl<'a> SiteBuilder<'a> {
+    /// Serve `page` at `/404.html`, kept out of search indexes
+    pub fn not_found(self, page: &NotFoundPage<'_>) -> Self {
+        let content = page.render();
+        self.page(
+            "/404.html",
+            Head::new(page.title.as_ref()).noindex(),
+            |_| content,
+        )
+    }
+}
+
+/// Generate CSS for the not found page
+pub fn not_found_css() -> String {
+    r#"
+.sh-not-found {
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    gap: var(--sh-spacing-md, 1rem);
+    max-width: 32rem;
+    margin: 0 auto;
+    padding: var(--sh-spacing-2xl, 3rem) var(--sh-spacing-md, 1rem);
+    text-align: center;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-not-found__code {
+    margin: 0;
+    font-size: clamp(4rem, 18vw, 8rem);
+    font-weight: 800;
+    line-height: 1;
+    letter-spacing: -0.04em;
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-not-found__title {
+    margin: 0;
+    font-size: 1.75rem;
+}
+
+.sh-not-found__message {
+    margin: 0;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-not-found__search {
+    display: flex;
+    gap: 0.5rem;
+    width: 100%;
+}
+
+.sh-not-found__search-input {
+    flex: 1;
+    min-width: 0;
+    padding: 0.5rem 0.75rem;
+    border: 1px solid var(--sh-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font: inherit;
+}
+
+.sh-not-found__search-input:focus-visible {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 1px;
+}
+
+.sh-not-found__links ul {
+    display: flex;
+    flex-wrap: wrap;
+    justify-content: center;
+    gap: 0.5rem 1.25rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-not-found__links a {
+    color: var(--sh-primary, #3b82f6);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found_defaults() {
+        let html = NotFoundPage::new().render().into_string();
+        assert!(html.contains(r#"<p class="sh-not-found__code" aria-hidden="true">404</p>"#));
+        assert!(html.contains("Page not found"));
+        assert!(html.contains(r#"href="/""#));
+        assert!(!html.contains("<form"));
+        assert!(!html.contains("Suggested pages"));
+    }
+
+    #[test]
+    fn test_not_found_customized() {
+        let html = NotFoundPage::new()
+            .title("Lost?")
+            .message("Try one of these")
+            .home("/docs", "Docs home")
+            .search("/search")
+            .link("Blog", "/blog")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"action="/search" method="get""#));
+        assert!(html.contains(r#"name="q""#));
+        assert!(html.contains(r#"<button type="submit""#));
+        assert!(html.contains(r#"<a href="/blog">Blog</a>"#));
+        assert!(html.contains("Docs home"));
+    }
+
+    #[test]
+    fn test_site_builder_not_found() {
+        let pages = SiteBuilder::new()
+            .not_found(&NotFoundPage::new())
+            .build()
+            .unwrap();
+        assert_eq!(pages[0].file.to_str(), Some("404.html"));
+        assert!(pages[0]
+            .html
+            .contains(r#"<meta name="robots" content="noindex">"#));
+        assert!(pages[0].html.contains("<title>Page not found</title>"));
+    }
+}
@@ -0,0 +1,510 @@
+//! OgImage - Social Share Card Templates
+//!
+//! Composes a 1200x630 SVG share card (title, logo slot, background, author)
+//! for `og:image`. Every element is explicitly closed so the output is a
+//! well-formed standalone `.svg` file. Turning it into a PNG goes through an
+//! [`OgRasterizer`]; with the `resvg` feature enabled, [`ResvgRasterizer`]
+//! renders the card with system fonts loaded:
+//!
+//! ```ignore
+//! let png = OgImage::new("Hello").to_png(&ResvgRasterizer::new())?;
+//! std::fs::write("og/hello.png", png)?;
+//! ```
+
+use crate::mesh_gradient::MeshGradientBackground;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Recommended OpenGraph image width
+pub const OG_WIDTH: u32 = 1200;
+/// Recommended OpenGraph image height
+pub const OG_HEIGHT: u32 = 630;
+
+const PADDING: u32 = 80;
+const FONT_STACK: &str =
+    "-apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif";
+
+/// Turns a rendered SVG card into PNG bytes
+pub trait OgRasterizer {
+    type Error;
+
+    fn rasterize(&self, svg: &str, width: u32, height: u32) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// [`OgRasterizer`] backed by resvg
+#[cfg(feature = "resvg")]
+pub struct ResvgRasterizer {
+    options: resvg::usvg::Options<'static>,
+}
+
+#[cfg(feature = "resvg")]
+impl ResvgRasterizer {
+    /// Rasterizer with the system fonts loaded for the card text
+    pub fn new() -> Self {
+        let mut options = resvg::usvg::Options::default();
+        options.fontdb_mut().load_system_fonts();
+        Self { options }
+    }
+
+    /// Also load the fonts in `dir`, e.g. the site's bundled brand fonts
+    pub fn fonts_dir(mut self, dir: impl AsRef<std::path::Path>) -> Self {
+        self.options.fontdb_mut().load_fonts_dir(dir);
+        self
+    }
+}
+
+#[cfg(feature = "resvg")]
+impl Default for ResvgRasterizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "resvg")]
+impl OgRasterizer for ResvgRasterizer {
+    type Error = String;
+
+    fn rasterize(&self, svg: &str, width: u32, height: u32) -> Result<Vec<u8>, String> {
+        use resvg::tiny_skia::{Pixmap, Transform};
+
+        let tree = resvg::usvg::Tree::from_str(svg, &self.options).map_err(|e| e.to_string())?;
+        let mut pixmap = Pixmap::new(width, height).ok_or("image has zero width or height")?;
+        let size = tree.size();
+        let transform = Transform::from_scale(
+            width as f32 / size.width(),
+            height as f32 / size.height(),
+        );
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+        pixmap.encode_png().map_err(|e| e.to_string())
+    }
+}
+
+/// Card background
+pub enum OgBackground<'a> {
+    Solid(Cow<'a, str>),
+    /// Diagonal linear gradient through the given colors
+    Linear(Vec<Cow<'a, str>>),
+    /// Blurred mesh gradient reusing [`MeshGradientBackground`]'s layers
+    Mesh(MeshGradientBackground<'a>),
+}
+
+impl<'a> Default for OgBackground<'a> {
+    fn default() -> Self {
+        OgBackground::Mesh(MeshGradientBackground::new())
+    }
+}
+
+/// Author line with an optional avatar image (falls back to initials)
+pub struct OgAuthor<'a> {
+    pub name: Cow<'a, str>,
+    pub avatar: Option<Cow<'a, str>>,
+}
+
+impl<'a> OgAuthor<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            avatar: None,
+        }
+    }
+
+    /// Avatar image URL or data URI
+    pub fn avatar(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.avatar = Some(href.into());
+        self
+    }
+
+    fn initials(&self) -> String {
+        self.name
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .take(2)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+}
+
+/// Greedy word wrap to `max_chars` per line, ellipsizing past `max_lines`
+fn wrap_lines(text: &str, max_chars: usize, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let needed = if current.is_empty() {
+            word.chars().count()
+        } else {
+            current.chars().count() + 1 + word.chars().count()
+        };
+        if needed > max_chars && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            let keep = max_chars.saturating_sub(1).min(last.chars().count());
+            *last = last
+                .chars()
+                .take(keep)
+                .collect::<String>()
+                .trim_end()
+                .to_string();
+            last.push('…');
+        }
+    }
+    lines
+}
+
+/// OpenGraph share card
+pub struct OgImage<'a> {
+    title: Cow<'a, str>,
+    subtitle: Option<Cow<'a, str>>,
+    site_name: Option<Cow<'a, str>>,
+    logo: Option<Markup>,
+    author: Option<OgAuthor<'a>>,
+    background: OgBackground<'a>,
+    text_color: Cow<'a, str>,
+    width: u32,
+    height: u32,
+}
+
+impl<'a> OgImage<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            title: title.into(),
+            subtitle: None,
+            site_name: None,
+            logo: None,
+            author: None,
+            background: OgBackground::default(),
+            text_color: Cow::Borrowed("#ffffff"),
+            width: OG_WIDTH,
+            height: OG_HEIGHT,
+        }
+    }
+
+    pub fn subtitle(mut self, subtitle: impl Into<Cow<'a, str>>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn site_name(mut self, site_name: impl Into<Cow<'a, str>>) -> Self {
+        self.site_name = Some(site_name.into());
+        self
+    }
+
+    /// Logo slot: SVG content drawn inside a 64x64 box at the top left
+    pub fn logo(mut self, logo: Markup) -> Self {
+        self.logo = Some(logo);
+        self
+    }
+
+    pub fn author(mut self, author: OgAuthor<'a>) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    pub fn background(mut self, background: OgBackground<'a>) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn text_color(mut self, color: impl Into<Cow<'a, str>>) -> Self {
+        self.text_color = color.into();
+        self
+    }
+
+    /// Override the canvas size (defaults to 1200x630)
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width.max(1);
+        self.height = height.max(1);
+        self
+    }
+
+    /// Title font size, shrinking for longer titles
+    fn title_font_size(&self) -> u32 {
+        match self.title.chars().count() {
+            0..=30 => 80,
+            31..=60 => 64,
+            _ => 52,
+        }
+    }
+
+    fn title_lines(&self) -> Vec<String> {
+        let font_size = self.title_font_size();
+        // Average glyph width is roughly 0.55em in the sans stack
+        let usable = self.width.saturating_sub(PADDING * 2) as f32;
+        let max_chars = (usable / (font_size as f32 * 0.55)).max(8.0) as usize;
+        wrap_lines(&self.title, max_chars, 3)
+    }
+
+    fn render_background(&self) -> Markup {
+        let (w, h) = (self.width, self.height);
+        match &self.background {
+            OgBackground::Solid(color) => html! {
+                rect width=(w) height=(h) fill=(color) {}
+            },
+            OgBackground::Linear(colors) => {
+                let last = colors.len().saturating_sub(1).max(1);
+                html! {
+                    defs {
+                        linearGradient id="og-bg" x1="0" y1="0" x2="1" y2="1" {
+                            @for (i, color) in colors.iter().enumerate() {
+                                stop offset=(format!("{}%", i * 100 / last)) stop-color=(color) {}
+                            }
+                        }
+                    }
+                    rect width=(w) height=(h) fill="url(#og-bg)" {}
+                }
+            }
+            OgBackground::Mesh(mesh) => {
+                let base = mesh.palette().first().copied().unwrap_or("#667eea");
+                html! {
+                    defs {
+                        filter id="og-mesh-blur" x="-10%" y="-10%" width="120%" height="120%" {
+                            feGaussianBlur stdDeviation="60" {}
+                        }
+                    }
+                    rect width=(w) height=(h) fill=(base) {}
+                    g filter="url(#og-mesh-blur)" {
+                        (mesh.svg_layers("og-mesh", w, h))
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_author(&self, author: &OgAuthor<'a>) -> Markup {
+        let r = 32;
+        let cx = PADDING + r;
+        let cy = self.height - PADDING - r;
+
+        html! {
+            g class="og-author" {
+                defs {
+                    clipPath id="og-avatar-clip" {
+                        circle cx=(cx) cy=(cy) r=(r) {}
+                    }
+                }
+                circle cx=(cx) cy=(cy) r=(r) fill=(self.text_color) fill-opacity="0.2" {}
+                @if let Some(href) = &author.avatar {
+                    image
+                        href=(href)
+                        x=(cx - r)
+                        y=(cy - r)
+                        width=(r * 2)
+                        height=(r * 2)
+                        preserveAspectRatio="xMidYMid slice"
+                        clip-path="url(#og-avatar-clip)"
+                    {}
+                } @else {
+                    text
+                        x=(cx)
+                        y=(cy + 10)
+                        text-anchor="middle"
+                        font-size="28"
+                        font-weight="600"
+                        fill=(self.text_color)
+                    { (author.initials()) }
+                }
+                text x=(cx + r + 20) y=(cy + 10) font-size="30" font-weight="500" fill=(self.text_color) {
+                    (author.name)
+                }
+            }
+        }
+    }
+
+    /// The card as an SVG document
+    pub fn to_svg(&self) -> String {
+        self.render().into_string()
+    }
+
+    /// Rasterize the card, e.g. to write `og/<slug>.png`
+    pub fn to_png<R: OgRasterizer>(&self, rasterizer: &R) -> Result<Vec<u8>, R::Error> {
+        rasterizer.rasterize(&self.to_svg(), self.width, self.height)
+    }
+
+    /// `og:image` and Twitter card meta tags for a page using this card
+    pub fn meta_tags(&self, image_url: &str) -> Markup {
+        html! {
+            meta property="og:image" content=(image_url);
+            meta property="og:image:width" content=(self.width);
+            meta property="og:image:height" content=(self.height);
+            meta property="og:image:alt" content=(self.title);
+            meta name="twitter:card" content="summary_large_image";
+            meta name="twitter:image" content=(image_url);
+        }
+    }
+}
+
+impl<'a> Render for OgImage<'a> {
+    fn render(&self) -> Markup {
+        let font_size = self.title_font_size();
+        let line_height = font_size * 6 / 5;
+        let lines = self.title_lines();
+        let block_height = line_height * lines.len() as u32;
+        let title_top = (self.height.saturating_sub(block_height)) / 2 + font_size;
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                width=(self.width)
+                height=(self.height)
+                viewBox=(format!("0 0 {} {}", self.width, self.height))
+                font-family=(FONT_STACK)
+                role="img"
+                aria-label=(self.title)
+            {
+                (self.render_background())
+
+                @if let Some(logo) = &self.logo {
+                    svg class="og-logo" x=(PADDING) y=(PADDING) width="64" height="64" viewBox="0 0 64 64" {
+                        (logo)
+                    }
+                }
+                @if let Some(site_name) = &self.site_name {
+                    text
+                        x=(if self.logo.is_some() { PADDING + 84 } else { PADDING })
+                        y=(PADDING + 42)
+                        font-size="32"
+                        font-weight="600"
+                        fill=(self.text_color)
+                    { (site_name) }
+                }
+
+                text
+                    class="og-title"
+                    x=(PADDING)
+                    y=(title_top)
+                    font-size=(font_size)
+                    font-weight="800"
+                    fill=(self.text_color)
+                {
+                    @for (i, line) in lines.iter().enumerate() {
+                        tspan x=(PADDING) dy=(if i == 0 { 0 } else { line_height }) { (line) }
+                    }
+                }
+
+                @if let Some(subtitle) = &self.subtitle {
+                    text
+                        class="og-subtitle"
+                        x=(PADDING)
+                        y=(title_top + block_height - font_size + 56)
+                        font-size="32"
+                        fill=(self.text_color)
+                        fill-opacity="0.85"
+                    { (subtitle) }
+                }
+
+                @if let Some(author) = &self.author {
+                    (self.render_author(author))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeRasterizer;
+
+    impl OgRasterizer for FakeRasterizer {
+        type Error = ();
+
+        fn rasterize(&self, svg: &str, width: u32, height: u32) -> Result<Vec<u8>, ()> {
+            Ok(format!("{}x{}:{}", width, height, svg.len()).into_bytes())
+        }
+    }
+
+    #[test]
+    fn test_wrap_lines() {
+        assert_eq!(wrap_lines("one two three", 7, 3), ["one two", "three"]);
+        assert_eq!(wrap_lines("a b c d e f", 3, 2), ["a b", "c…"]);
+        assert_eq!(
+            wrap_lines("supercalifragilistic", 5, 2),
+            ["supercalifragilistic"]
+        );
+        assert!(wrap_lines("", 10, 2).is_empty());
+    }
+
+    #[test]
+    fn test_og_image_default_svg() {
+        let svg = OgImage::new("Zero-JS UI & more")
+            .site_name("Shallot")
+            .to_svg();
+
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"1200\" height=\"630\""
+        ));
+        assert!(svg.contains("aria-label=\"Zero-JS UI &amp; more\""));
+        assert!(svg.contains("filter=\"url(#og-mesh-blur)\""));
+        assert!(svg.contains("id=\"og-mesh-1\""));
+        assert!(svg.contains(">Shallot</text>"));
+        assert!(svg.contains("font-size=\"80\""));
+    }
+
+    #[test]
+    fn test_og_image_slots() {
+        let svg = OgImage::new("A considerably longer title that needs to wrap onto lines")
+            .logo(html! { circle cx="32" cy="32" r="30" fill="#fff" {} })
+            .subtitle("Docs")
+            .author(OgAuthor::new("Ada Lovelace"))
+            .background(OgBackground::Linear(vec!["#111".into(), "#333".into()]))
+            .to_svg();
+
+        assert!(svg.contains("class=\"og-logo\""));
+        assert!(svg.contains("<circle cx=\"32\" cy=\"32\" r=\"30\" fill=\"#fff\"></circle>"));
+        assert!(svg.contains("linearGradient id=\"og-bg\""));
+        assert!(svg.contains("offset=\"100%\""));
+        assert!(svg.contains(">AL</text>"));
+        assert!(svg.contains(">Ada Lovelace</text>"));
+        assert!(svg.matches("<tspan").count() >= 2);
+        assert!(svg.contains("font-size=\"64\""));
+    }
+
+    #[test]
+    fn test_og_author_avatar_image() {
+        let svg = OgImage::new("Post")
+            .background(OgBackground::Solid("#000".into()))
+            .author(OgAuthor::new("Grace").avatar("https://example.com/g.png"))
+            .to_svg();
+
+        assert!(svg.contains("<rect width=\"1200\" height=\"630\" fill=\"#000\"></rect>"));
+        assert!(svg.contains("href=\"https://example.com/g.png\""));
+        assert!(svg.contains("clip-path=\"url(#og-avatar-clip)\""));
+    }
+
+    #[test]
+    fn test_og_png_and_meta() {
+        let card = OgImage::new("Hello").size(600, 315);
+        let png = card.to_png(&FakeRasterizer).unwrap();
+        assert!(String::from_utf8(png).unwrap().starts_with("600x315:"));
+
+        let meta = card
+            .meta_tags("https://shallot.rs/og/hello.png")
+            .into_string();
+        assert!(meta.contains("property=\"og:image\" content=\"https://shallot.rs/og/hello.png\""));
+        assert!(meta.contains("property=\"og:image:width\" content=\"600\""));
+        assert!(meta.contains("content=\"summary_large_image\""));
+    }
+
+    #[cfg(feature = "resvg")]
+    #[test]
+    fn test_resvg_rasterizer_png() {
+        let png = OgImage::new("Hello")
+            .size(600, 315)
+            .to_png(&ResvgRasterizer::new())
+            .unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -158,7 +159,13 @@ impl Render for OrbitingCircles {
 }
 
 pub fn orbiting_circles_css() -> String {
-    r#"
+    orbiting_circles_css_for(MotionConfig::default())
+}
+
+/// OrbitingCircles CSS at the given motion level
+pub fn orbiting_circles_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-orbit {
     --sh-orbit-dur: 20s;
     --sh-orbit-dir: normal;
@@ -293,8 +300,19 @@ pub fn orbiting_circles_css() -> String {
 .sh-orbit--gradient .sh-orbit__icon img {
     filter: brightness(0) invert(1);
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-orbit__circle,
+.sh-orbit__icon {
+    animation: none;
+}
+"#,
+        off: r#"
+.sh-orbit__circle {
+    display: none;
+}
+"#,
+    })
 }
 
 #[cfg(test)]
@@ -394,4 +412,11 @@ mod tests {
         assert!(css.contains(".sh-orbit"));
         assert!(css.contains("@keyframes orbit-spin"));
     }
+
+    #[test]
+    fn test_orbiting_circles_motion() {
+        let css = orbiting_circles_css_for(MotionConfig::None);
+        assert!(!css.contains("@keyframes"));
+        assert!(css.contains(".sh-orbit__circle {\n    display: none;"));
+    }
 }
@@ -1,9 +1,20 @@
 //! OTP Input Component - One-time password input
 //!
-//! A zero-JavaScript OTP input with multiple single-character fields.
+//! A zero-JavaScript OTP input with multiple single-character fields, named
+//! `name[0]`, `name[1]`, ..., optionally grouped as `123 456`. Without a
+//! script the split boxes can't take a pasted or autofilled code, so
+//! [`OtpLayout::Single`] renders one field with a pattern instead, and
+//! [`OtpLayout::Auto`] picks it for codes too long to split comfortably.
+//!
+//! On the server, [`otp_value`] reads the code back in either layout and
+//! [`OtpValidator`] checks its format.
 
+use crate::form::{FormData, HtmlConstraints, ValidationError, Validator};
 use maud::{html, Markup, Render};
 
+/// Longest code [`OtpLayout::Auto`] still splits into boxes
+const AUTO_SPLIT_MAX: usize = 8;
+
 /// OTP input size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OtpSize {
@@ -22,6 +33,56 @@ pub enum OtpVariant {
     Underline,
 }
 
+/// Characters a code is made of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpCharset {
+    #[default]
+    Numeric,
+    /// Letters and digits; letters are compared uppercase
+    Alphanumeric,
+}
+
+impl OtpCharset {
+    fn class(&self) -> &'static str {
+        match self {
+            OtpCharset::Numeric => "[0-9]",
+            OtpCharset::Alphanumeric => "[A-Za-z0-9]",
+        }
+    }
+
+    fn inputmode(&self) -> &'static str {
+        match self {
+            OtpCharset::Numeric => "numeric",
+            OtpCharset::Alphanumeric => "text",
+        }
+    }
+
+    fn allows(&self, c: char) -> bool {
+        match self {
+            OtpCharset::Numeric => c.is_ascii_digit(),
+            OtpCharset::Alphanumeric => c.is_ascii_alphanumeric(),
+        }
+    }
+
+    /// Pattern for a single field holding the whole code, allowing the
+    /// spaces and dashes people paste with it
+    fn pattern(&self, length: usize) -> String {
+        format!("[ -]*(?:{}[ -]*){{{}}}", self.class(), length)
+    }
+}
+
+/// How the code's characters are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpLayout {
+    /// One box per character
+    #[default]
+    Split,
+    /// One field for the whole code, which takes pastes and autofill
+    Single,
+    /// Split for codes of up to 8 characters, single for longer ones
+    Auto,
+}
+
 /// OTP Input component
 #[derive(Debug, Clone)]
 pub struct OtpInput<'a> {
@@ -35,6 +96,10 @@ pub struct OtpInput<'a> {
     pub required: bool,
     pub label: Option<&'a str>,
     pub autocomplete: bool,
+    /// Characters per visual group, e.g. 3 for `123 456`
+    pub group_size: Option<usize>,
+    pub charset: OtpCharset,
+    pub layout: OtpLayout,
 }
 
 impl<'a> OtpInput<'a> {
@@ -51,6 +116,9 @@ impl<'a> OtpInput<'a> {
             required: false,
             label: None,
             autocomplete: true,
+            group_size: None,
+            charset: OtpCharset::Numeric,
+            layout: OtpLayout::Split,
         }
     }
 
@@ -96,12 +164,48 @@ impl<'a> OtpInput<'a> {
         self
     }
 
-    /// Enable/disable autocomplete
+    /// Enable/disable `autocomplete="one-time-code"`
     pub fn autocomplete(mut self, autocomplete: bool) -> Self {
         self.autocomplete = autocomplete;
         self
     }
 
+    /// Split the boxes into groups of `size`: 3 gives 3-3 for six digits,
+    /// 2 gives 2-2-2
+    pub fn grouping(mut self, size: usize) -> Self {
+        self.group_size = (size > 0).then_some(size);
+        self
+    }
+
+    /// Accept letters as well as digits
+    pub fn alphanumeric(mut self) -> Self {
+        self.charset = OtpCharset::Alphanumeric;
+        self
+    }
+
+    /// Set the layout
+    pub fn layout(mut self, layout: OtpLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// A validator checking codes in this input's format
+    pub fn validator(&self) -> OtpValidator {
+        let validator = OtpValidator::new(self.length);
+        match self.charset {
+            OtpCharset::Numeric => validator,
+            OtpCharset::Alphanumeric => validator.alphanumeric(),
+        }
+    }
+
+    fn is_single(&self) -> bool {
+        match self.layout {
+            OtpLayout::Split => false,
+            OtpLayout::Single => true,
+            OtpLayout::Auto => self.length > AUTO_SPLIT_MAX,
+        }
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-otp-input".to_string()];
 
@@ -117,46 +221,112 @@ impl<'a> OtpInput<'a> {
             OtpVariant::Underline => "sh-otp-input--underline".to_string(),
         });
 
+        if self.is_single() {
+            classes.push("sh-otp-input--single".to_string());
+        }
+
         if self.disabled {
             classes.push("sh-otp-input--disabled".to_string());
         }
 
         classes.join(" ")
     }
+
+    fn render_label(&self, for_id: Option<&str>) -> Markup {
+        html! {
+            @if let Some(label) = self.label {
+                label class="sh-otp-input__label" for=[for_id] {
+                    (label)
+                    @if self.required {
+                        span class="sh-otp-input__required" { "*" }
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_single(&self, classes: &str) -> Markup {
+        let capitalize = self.charset == OtpCharset::Alphanumeric;
+        html! {
+            div class=(classes) {
+                (self.render_label(Some(self.id)))
+                input
+                    type="text"
+                    inputmode=(self.charset.inputmode())
+                    pattern=(self.charset.pattern(self.length))
+                    maxlength=(self.length * 2)
+                    id=(self.id)
+                    name=(self.name)
+                    class="sh-otp-input__field sh-otp-input__field--single"
+                    value=[self.value]
+                    disabled?[self.disabled]
+                    required?[self.required]
+                    autocomplete=[self.autocomplete.then_some("one-time-code")]
+                    autocapitalize=[capitalize.then_some("characters")]
+                    spellcheck="false"
+                    aria-label=[self.label.is_none().then_some("OTP code")];
+            }
+        }
+    }
+
+    fn render_box(&self, i: usize, chars: &[char]) -> Markup {
+        let char_value = chars.get(i).map(|c| c.to_string()).unwrap_or_default();
+        let noun = match self.charset {
+            OtpCharset::Numeric => "Digit",
+            OtpCharset::Alphanumeric => "Character",
+        };
+        // Autofill puts the whole code into one field, so offer it on the first
+        let autocomplete = match (self.autocomplete, i) {
+            (true, 0) => Some("one-time-code"),
+            (true, _) => Some("off"),
+            (false, _) => None,
+        };
+        html! {
+            input
+                type="text"
+                inputmode=(self.charset.inputmode())
+                pattern=(self.charset.class())
+                maxlength="1"
+                id=(format!("{}-{}", self.id, i))
+                name=(format!("{}[{}]", self.name, i))
+                class="sh-otp-input__field"
+                value=(char_value)
+                disabled?[self.disabled]
+                required?[self.required]
+                autocomplete=[autocomplete]
+                aria-label=(format!("{} {}", noun, i + 1));
+        }
+    }
 }
 
 impl<'a> Render for OtpInput<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
+        if self.is_single() {
+            return self.render_single(&classes);
+        }
         let chars: Vec<char> = self.value.unwrap_or("").chars().collect();
+        let group_size = self.group_size.unwrap_or(self.length);
 
         html! {
             div class=(classes) role="group" aria-label=[self.label.or(Some("OTP code"))] {
-                @if let Some(label) = self.label {
-                    label class="sh-otp-input__label" {
-                        (label)
-                        @if self.required {
-                            span class="sh-otp-input__required" { "*" }
-                        }
-                    }
-                }
+                (self.render_label(None))
                 div class="sh-otp-input__fields" {
-                    @for i in 0..self.length {
-                        @let char_value = chars.get(i).map(|c| c.to_string()).unwrap_or_default();
-                        @let field_id = format!("{}-{}", self.id, i);
-                        input
-                            type="text"
-                            inputmode="numeric"
-                            pattern="[0-9]"
-                            maxlength="1"
-                            id=(field_id)
-                            name=(format!("{}[{}]", self.name, i))
-                            class="sh-otp-input__field"
-                            value=(char_value)
-                            disabled?[self.disabled]
-                            required?[self.required]
-                            autocomplete=[if self.autocomplete { Some("one-time-code") } else { None }]
-                            aria-label=(format!("Digit {}", i + 1));
+                    @for start in (0..self.length).step_by(group_size) {
+                        @if start > 0 {
+                            span class="sh-otp-input__separator" aria-hidden="true" { "-" }
+                        }
+                        @if self.group_size.is_some() {
+                            span class="sh-otp-input__group" {
+                                @for i in start..(start + group_size).min(self.length) {
+                                    (self.render_box(i, &chars))
+                                }
+                            }
+                        } @else {
+                            @for i in start..self.length {
+                                (self.render_box(i, &chars))
+                            }
+                        }
                     }
                 }
             }
@@ -164,6 +334,81 @@ impl<'a> Render for OtpInput<'a> {
     }
 }
 
+/// Remove the spaces and dashes people type or paste between groups, and
+/// uppercase letters
+pub fn normalize_otp(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// The code submitted by an [`OtpInput`] named `name`: its single field,
+/// or the `name[0]`, `name[1]`, ... boxes joined, normalized with
+/// [`normalize_otp`]
+pub fn otp_value(data: &FormData, name: &str) -> Option<String> {
+    if let Some(value) = data.get(name) {
+        return Some(normalize_otp(value));
+    }
+    let boxes: Vec<&str> = (0..)
+        .map_while(|i| data.get(&format!("{}[{}]", name, i)))
+        .collect();
+    (!boxes.is_empty()).then(|| normalize_otp(&boxes.concat()))
+}
+
+/// Checks a submitted one-time code has the expected length and
+/// characters; empty values are left to the required validator
+#[derive(Debug, Clone)]
+pub struct OtpValidator {
+    length: usize,
+    charset: OtpCharset,
+    message: Option<String>,
+}
+
+impl OtpValidator {
+    /// Expect a code of `length` digits
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            charset: OtpCharset::Numeric,
+            message: None,
+        }
+    }
+
+    /// Accept letters as well as digits
+    pub fn alphanumeric(mut self) -> Self {
+        self.charset = OtpCharset::Alphanumeric;
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+impl Validator for OtpValidator {
+    fn validate(&self, value: &str, field_name: &str) -> Option<ValidationError> {
+        if value.is_empty() {
+            return None;
+        }
+        let code = normalize_otp(value);
+        if code.chars().count() == self.length && code.chars().all(|c| self.charset.allows(c)) {
+            return None;
+        }
+        let message = self.message.clone().unwrap_or_else(|| match self.charset {
+            OtpCharset::Numeric => format!("Enter the {}-digit code", self.length),
+            OtpCharset::Alphanumeric => format!("Enter the {}-character code", self.length),
+        });
+        Some(ValidationError::new(field_name, message, "otp"))
+    }
+
+    fn constrain(&self, constraints: &mut HtmlConstraints) {
+        constraints.pattern(&self.charset.pattern(self.length));
+    }
+}
+
 /// Generate OTP input CSS
 pub fn otp_input_css() -> String {
     r#"
@@ -218,6 +463,25 @@ pub fn otp_input_css() -> String {
     background: var(--sh-surface);
 }
 
+.sh-otp-input__group {
+    display: flex;
+    gap: 0.5rem;
+}
+
+.sh-otp-input__separator {
+    align-self: center;
+    color: var(--sh-text-muted);
+    font-weight: 600;
+}
+
+/* Single field layout */
+.sh-otp-input.sh-otp-input--single .sh-otp-input__field {
+    width: 100%;
+    max-width: 18rem;
+    padding: 0 0.75rem;
+    letter-spacing: 0.35em;
+}
+
 /* Size variants */
 .sh-otp-input--sm .sh-otp-input__field {
     width: 2.5rem;
@@ -308,4 +572,73 @@ mod tests {
 
         assert_eq!(otp.size, OtpSize::Lg);
     }
+
+    #[test]
+    fn test_otp_input_grouping_and_autocomplete() {
+        let html = OtpInput::new("otp", "code")
+            .grouping(2)
+            .render()
+            .into_string();
+        assert_eq!(html.matches("sh-otp-input__group\"").count(), 3);
+        assert_eq!(html.matches("sh-otp-input__separator").count(), 2);
+        assert_eq!(html.matches(r#"autocomplete="one-time-code""#).count(), 1);
+        assert!(html.contains(r#"name="code[5]""#));
+
+        let html = OtpInput::new("otp", "code")
+            .alphanumeric()
+            .render()
+            .into_string();
+        assert!(html.contains(r#"inputmode="text" pattern="[A-Za-z0-9]""#));
+        assert!(html.contains(r#"aria-label="Character 1""#));
+    }
+
+    #[test]
+    fn test_otp_input_single_layout() {
+        let html = OtpInput::new("otp", "code")
+            .label("Code")
+            .layout(OtpLayout::Single)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<label class="sh-otp-input__label" for="otp">"#));
+        assert!(html.contains(r#"pattern="[ -]*(?:[0-9][ -]*){6}""#));
+        assert!(html.contains(r#"name="code""#));
+        assert!(html.contains(r#"autocomplete="one-time-code""#));
+        assert!(!html.contains(r#"role="group""#));
+
+        let auto = OtpInput::new("otp", "code").layout(OtpLayout::Auto);
+        assert!(!auto
+            .clone()
+            .length(8)
+            .render()
+            .into_string()
+            .contains("--single"));
+        assert!(auto.length(10).render().into_string().contains("--single"));
+    }
+
+    #[test]
+    fn test_otp_value_and_validator() {
+        let split = FormData::parse_urlencoded("code%5B0%5D=1&code%5B1%5D=2&code%5B2%5D=3");
+        assert_eq!(otp_value(&split, "code").as_deref(), Some("123"));
+        let single = FormData::parse_urlencoded("code=ab-12+c3");
+        assert_eq!(otp_value(&single, "code").as_deref(), Some("AB12C3"));
+        assert_eq!(otp_value(&single, "other"), None);
+
+        let validator = OtpInput::new("otp", "code").validator();
+        assert!(validator.validate("123 456", "code").is_none());
+        assert!(validator.validate("", "code").is_none());
+        let error = validator.validate("12345", "code").unwrap();
+        assert_eq!(error.message, "Enter the 6-digit code");
+        assert_eq!(error.code, "otp");
+        assert!(validator.validate("12345a", "code").is_some());
+        assert!(OtpValidator::new(6)
+            .alphanumeric()
+            .validate("12345a", "code")
+            .is_none());
+
+        let mut constraints = HtmlConstraints::default();
+        validator.constrain(&mut constraints);
+        let pattern =
+            regex::Regex::new(&format!("^(?:{})$", constraints.pattern.unwrap())).unwrap();
+        assert!(pattern.is_match("123-456") && !pattern.is_match("12345"));
+    }
 }
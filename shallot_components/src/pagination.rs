@@ -3,6 +3,10 @@
 //! Navigation for paginated content with CSS-only interactions.
 
 use crate::component::ComponentSize;
+use crate::htmx::HxAttrs;
+#[cfg(feature = "htmx")]
+use crate::htmx::{HxSwap, RenderMode};
+use crate::route::RouteContext;
 use maud::{html, Markup, Render};
 
 pub struct Pagination<'a> {
@@ -14,6 +18,7 @@ pub struct Pagination<'a> {
     show_first_last: bool,
     show_prev_next: bool,
     max_visible: u32,
+    hx: HxAttrs<'a>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -36,6 +41,7 @@ impl<'a> Pagination<'a> {
             show_first_last: true,
             show_prev_next: true,
             max_visible: 5,
+            hx: HxAttrs::default(),
         }
     }
 
@@ -64,6 +70,50 @@ impl<'a> Pagination<'a> {
         self
     }
 
+    /// Take the current page from the route's `page` parameter, falling
+    /// back to the first page when it is missing or malformed
+    pub fn route(mut self, route: &RouteContext) -> Self {
+        self.current = route
+            .param_value("page")
+            .and_then(|page| page.parse().ok())
+            .unwrap_or(1)
+            .clamp(1, self.total.max(1));
+        self
+    }
+
+    /// Load pages with an HTMX GET to `url`, filled in like the base URL
+    /// (`{page}` placeholder or `page` query parameter). Links keep their
+    /// `href` for full page loads.
+    #[cfg(feature = "htmx")]
+    pub fn hx_get(mut self, url: &'a str) -> Self {
+        self.hx.get = Some(url.into());
+        self
+    }
+
+    /// Element the HTMX response is swapped into
+    #[cfg(feature = "htmx")]
+    pub fn hx_target(mut self, selector: &'a str) -> Self {
+        self.hx.target = Some(selector.into());
+        self
+    }
+
+    /// How the HTMX response is swapped in
+    #[cfg(feature = "htmx")]
+    pub fn hx_swap(mut self, swap: HxSwap) -> Self {
+        self.hx.swap = Some(swap);
+        self
+    }
+
+    /// Render the pagination, or with [`RenderMode::Fragment`] only its
+    /// page list for an HTMX response
+    #[cfg(feature = "htmx")]
+    pub fn render_as(&self, mode: RenderMode) -> Markup {
+        match mode {
+            RenderMode::Full => self.render(),
+            RenderMode::Fragment => self.render_list(),
+        }
+    }
+
     fn variant_class(&self) -> &'static str {
         match self.variant {
             PaginationVariant::Default => "sh-pagination--default",
@@ -116,13 +166,21 @@ impl<'a> Pagination<'a> {
     }
 
     fn url_for_page(&self, page: u32) -> String {
-        if self.base_url.contains("{page}") {
-            self.base_url.replace("{page}", &page.to_string())
-        } else if self.base_url.contains('?') {
-            format!("{}&page={}", self.base_url, page)
-        } else {
-            format!("{}?page={}", self.base_url, page)
-        }
+        page_url(self.base_url, page)
+    }
+
+    fn hx_url_for_page(&self, page: u32) -> Option<String> {
+        self.hx.get().map(|url| page_url(url, page))
+    }
+}
+
+fn page_url(base_url: &str, page: u32) -> String {
+    if base_url.contains("{page}") {
+        base_url.replace("{page}", &page.to_string())
+    } else if base_url.contains('?') {
+        format!("{}&page={}", base_url, page)
+    } else {
+        format!("{}?page={}", base_url, page)
     }
 }
 
@@ -131,9 +189,8 @@ enum PageNumber {
     Ellipsis,
 }
 
-impl<'a> Render for Pagination<'a> {
-    fn render(&self) -> Markup {
-        let size_class = format!("sh-pagination--{}", self.size.class_suffix());
+impl<'a> Pagination<'a> {
+    fn render_list(&self) -> Markup {
         let pages = self.build_page_range();
         let prev_page = if self.current > 1 {
             Some(self.current - 1)
@@ -147,85 +204,83 @@ impl<'a> Render for Pagination<'a> {
         };
 
         html! {
-            nav class={(format!("sh-pagination {} {}", self.variant_class(), size_class))} role="navigation" aria-label="Pagination" {
-                ul class="sh-pagination__list" {
-                    @if self.show_first_last && self.current > 1 {
-                        li class="sh-pagination__item" {
-                            a href=(self.url_for_page(1)) class="sh-pagination__link sh-pagination__link--first" aria-label="First page" {
-                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                    polyline points="11 17 6 12 11 7";
-                                    polyline points="18 17 13 12 18 7";
-                                }
+            ul class="sh-pagination__list" {
+                @if self.show_first_last && self.current > 1 {
+                    li class="sh-pagination__item" {
+                        a href=(self.url_for_page(1)) hx-get=[self.hx_url_for_page(1)] hx-target=[self.hx.target()] hx-swap=[self.hx.swap()] class="sh-pagination__link sh-pagination__link--first" aria-label="First page" {
+                            svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                polyline points="11 17 6 12 11 7";
+                                polyline points="18 17 13 12 18 7";
                             }
                         }
                     }
+                }
 
-                    @if self.show_prev_next {
-                        li class="sh-pagination__item" {
-                            @if let Some(prev) = prev_page {
-                                a href=(self.url_for_page(prev)) class="sh-pagination__link sh-pagination__link--prev" aria-label="Previous page" {
-                                    svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                        polyline points="15 18 9 12 15 6";
-                                    }
+                @if self.show_prev_next {
+                    li class="sh-pagination__item" {
+                        @if let Some(prev) = prev_page {
+                            a href=(self.url_for_page(prev)) hx-get=[self.hx_url_for_page(prev)] hx-target=[self.hx.target()] hx-swap=[self.hx.swap()] class="sh-pagination__link sh-pagination__link--prev" aria-label="Previous page" {
+                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                    polyline points="15 18 9 12 15 6";
                                 }
-                            } @else {
-                                span class="sh-pagination__link sh-pagination__link--prev sh-pagination__link--disabled" {
-                                    svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                        polyline points="15 18 9 12 15 6";
-                                    }
+                            }
+                        } @else {
+                            span class="sh-pagination__link sh-pagination__link--prev sh-pagination__link--disabled" {
+                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                    polyline points="15 18 9 12 15 6";
                                 }
                             }
                         }
                     }
+                }
 
-                    @for page in &pages {
-                        @match page {
-                            PageNumber::Page(num) => {
-                                li class="sh-pagination__item" {
-                                    @if *num == self.current {
-                                        span class="sh-pagination__link sh-pagination__link--active" aria-current="page" {
-                                            (num)
-                                        }
-                                    } @else {
-                                        a href=(self.url_for_page(*num)) class="sh-pagination__link" {
-                                            (num)
-                                        }
+                @for page in &pages {
+                    @match page {
+                        PageNumber::Page(num) => {
+                            li class="sh-pagination__item" {
+                                @if *num == self.current {
+                                    span class="sh-pagination__link sh-pagination__link--active" aria-current="page" {
+                                        (num)
+                                    }
+                                } @else {
+                                    a href=(self.url_for_page(*num)) hx-get=[self.hx_url_for_page(*num)] hx-target=[self.hx.target()] hx-swap=[self.hx.swap()] class="sh-pagination__link" {
+                                        (num)
                                     }
                                 }
                             }
-                            PageNumber::Ellipsis => {
-                                li class="sh-pagination__item" {
-                                    span class="sh-pagination__ellipsis" { "..." }
-                                }
+                        }
+                        PageNumber::Ellipsis => {
+                            li class="sh-pagination__item" {
+                                span class="sh-pagination__ellipsis" { "..." }
                             }
                         }
                     }
+                }
 
-                    @if self.show_prev_next {
-                        li class="sh-pagination__item" {
-                            @if let Some(next) = next_page {
-                                a href=(self.url_for_page(next)) class="sh-pagination__link sh-pagination__link--next" aria-label="Next page" {
-                                    svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                        polyline points="9 18 15 12 9 6";
-                                    }
+                @if self.show_prev_next {
+                    li class="sh-pagination__item" {
+                        @if let Some(next) = next_page {
+                            a href=(self.url_for_page(next)) hx-get=[self.hx_url_for_page(next)] hx-target=[self.hx.target()] hx-swap=[self.hx.swap()] class="sh-pagination__link sh-pagination__link--next" aria-label="Next page" {
+                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                    polyline points="9 18 15 12 9 6";
                                 }
-                            } @else {
-                                span class="sh-pagination__link sh-pagination__link--next sh-pagination__link--disabled" {
-                                    svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                        polyline points="9 18 15 12 9 6";
-                                    }
+                            }
+                        } @else {
+                            span class="sh-pagination__link sh-pagination__link--next sh-pagination__link--disabled" {
+                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                    polyline points="9 18 15 12 9 6";
                                 }
                             }
                         }
                     }
+                }
 
-                    @if self.show_first_last && self.current < self.total {
-                        li class="sh-pagination__item" {
-                            a href=(self.url_for_page(self.total)) class="sh-pagination__link sh-pagination__link--last" aria-label="Last page" {
-                                svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                    polyline points="13 17 18 12 13 7";
-                                    polyline points="6 17 11 12 6 7";
-                                }
+                @if self.show_first_last && self.current < self.total {
+                    li class="sh-pagination__item" {
+                        a href=(self.url_for_page(self.total)) hx-get=[self.hx_url_for_page(self.total)] hx-target=[self.hx.target()] hx-swap=[self.hx.swap()] class="sh-pagination__link sh-pagination__link--last" aria-label="Last page" {
+                            svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+                                polyline points="13 17 18 12 13 7";
+                                polyline points="6 17 11 12 6 7";
                             }
                         }
                     }
@@ -235,6 +290,18 @@ impl<'a> Render for Pagination<'a> {
     }
 }
 
+impl<'a> Render for Pagination<'a> {
+    fn render(&self) -> Markup {
+        let size_class = format!("sh-pagination--{}", self.size.class_suffix());
+
+        html! {
+            nav class={(format!("sh-pagination {} {}", self.variant_class(), size_class))} role="navigation" aria-label="Pagination" {
+                (self.render_list())
+            }
+        }
+    }
+}
+
 pub struct SimplePagination<'a> {
     current: u32,
     total: u32,
@@ -251,13 +318,7 @@ impl<'a> SimplePagination<'a> {
     }
 
     fn url_for_page(&self, page: u32) -> String {
-        if self.base_url.contains("{page}") {
-            self.base_url.replace("{page}", &page.to_string())
-        } else if self.base_url.contains('?') {
-            format!("{}&page={}", self.base_url, page)
-        } else {
-            format!("{}?page={}", self.base_url, page)
-        }
+        page_url(self.base_url, page)
     }
 }
 
@@ -495,4 +556,36 @@ mod tests {
         let pagination3 = Pagination::new(1, 10, "/items/{page}");
         assert_eq!(pagination3.url_for_page(2), "/items/2");
     }
+
+    #[cfg(feature = "htmx")]
+    #[test]
+    fn test_pagination_htmx() {
+        let pagination = Pagination::new(2, 5, "/items")
+            .hx_get("/items/partial/{page}")
+            .hx_target("#items")
+            .hx_swap(HxSwap::InnerHtml);
+
+        let html = pagination.render_as(RenderMode::Full).into_string();
+        assert!(html.contains(
+            r##"href="/items?page=3" hx-get="/items/partial/3" hx-target="#items" hx-swap="innerHTML""##
+        ));
+
+        let fragment = pagination.render_as(RenderMode::Fragment).into_string();
+        assert!(fragment.starts_with(r#"<ul class="sh-pagination__list">"#));
+        assert!(!fragment.contains("<nav"));
+    }
+
+    #[test]
+    fn test_pagination_route() {
+        let route = RouteContext::new("/items?page=4");
+        let pagination = Pagination::new(1, 10, "/items").route(&route);
+        assert_eq!(pagination.current, 4);
+        let html = pagination.render().into_string();
+        assert!(html.contains(r#"aria-current="page">4</span>"#));
+
+        let route = RouteContext::new("/items?page=99");
+        assert_eq!(Pagination::new(1, 10, "/items").route(&route).current, 10);
+        let route = RouteContext::new("/items?page=abc");
+        assert_eq!(Pagination::new(3, 10, "/items").route(&route).current, 1);
+    }
 }
@@ -1,5 +1,10 @@
 //! Parallax Section Component - Scroll-based parallax effect
+//!
+//! Set a scroll-driven [`RevealTimeline`] to move the background with
+//! `animation-timeline` in supporting browsers; elsewhere the background
+//! stays put.
 
+use crate::scroll_reveal::RevealTimeline;
 use maud::{html, Markup, Render};
 
 /// Parallax speed multiplier
@@ -31,6 +36,7 @@ pub struct ParallaxSection<'a> {
     pub background: Option<&'a str>,
     pub overlay: bool,
     pub overlay_opacity: f32,
+    pub timeline: RevealTimeline,
 }
 
 impl<'a> ParallaxSection<'a> {
@@ -43,6 +49,7 @@ impl<'a> ParallaxSection<'a> {
             background: None,
             overlay: false,
             overlay_opacity: 0.5,
+            timeline: RevealTimeline::Time,
         }
     }
 
@@ -76,6 +83,12 @@ impl<'a> ParallaxSection<'a> {
         self
     }
 
+    /// Bind the background movement to a scroll or view timeline
+    pub fn timeline(mut self, timeline: RevealTimeline) -> Self {
+        self.timeline = timeline;
+        self
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-parallax"];
 
@@ -96,6 +109,12 @@ impl<'a> ParallaxSection<'a> {
             classes.push("sh-parallax--overlay");
         }
 
+        match self.timeline {
+            RevealTimeline::Time => {}
+            RevealTimeline::View(_) => classes.push("sh-parallax--timeline-view"),
+            RevealTimeline::Scroll { .. } => classes.push("sh-parallax--timeline-scroll"),
+        }
+
         classes.join(" ")
     }
 }
@@ -104,10 +123,14 @@ impl<'a> Render for ParallaxSection<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
         let style = format!(
-            "--sh-parallax-height: {}; {}",
+            "--sh-parallax-height: {}; {}{}",
             self.height,
             self.background
                 .map(|bg| format!("--sh-parallax-bg: {};", bg))
+                .unwrap_or_default(),
+            self.timeline
+                .range_css()
+                .map(|range| format!(" --sh-parallax-range: {};", range))
                 .unwrap_or_default()
         );
         let overlay_style = format!("--sh-parallax-overlay-opacity: {};", self.overlay_opacity);
@@ -269,6 +292,33 @@ pub fn parallax_section_css() -> String {
     50% { transform: translateY(10px); }
 }
 
+/* Scroll-driven timelines */
+.sh-parallax--timeline-view {
+    view-timeline: --sh-parallax block;
+}
+
+@media (prefers-reduced-motion: no-preference) {
+    @supports (animation-timeline: view()) {
+        .sh-parallax.sh-parallax--timeline-view .sh-parallax__bg,
+        .sh-parallax.sh-parallax--timeline-scroll .sh-parallax__bg {
+            animation-duration: auto;
+            animation-delay: 0s;
+            animation-iteration-count: 1;
+            animation-fill-mode: both;
+            animation-play-state: running;
+            animation-range: var(--sh-parallax-range, cover 0% cover 100%);
+        }
+
+        .sh-parallax.sh-parallax--timeline-view .sh-parallax__bg {
+            animation-timeline: --sh-parallax;
+        }
+
+        .sh-parallax.sh-parallax--timeline-scroll .sh-parallax__bg {
+            animation-timeline: scroll(root block);
+        }
+    }
+}
+
 /* Reduced motion */
 @media (prefers-reduced-motion: reduce) {
     .sh-parallax__bg,
@@ -323,4 +373,21 @@ mod tests {
         assert_eq!(layer.depth, 0.8);
         assert_eq!(layer.offset_y, "50px");
     }
+
+    #[test]
+    fn test_parallax_timeline() {
+        use crate::scroll_reveal::ViewRange;
+
+        let section = ParallaxSection::new(html! { "Content" })
+            .background("url(hero.jpg)")
+            .timeline(RevealTimeline::View(ViewRange::Cover));
+        let html = section.render().into_string();
+
+        assert!(html.contains("sh-parallax--timeline-view"));
+        assert!(html.contains("--sh-parallax-range: cover 0% cover 100%;"));
+
+        let plain = ParallaxSection::new(html! {}).render().into_string();
+        assert!(!plain.contains("--sh-parallax-range"));
+        assert!(parallax_section_css().contains("animation-timeline: --sh-parallax;"));
+    }
 }
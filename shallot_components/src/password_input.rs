@@ -0,0 +1,560 @@
+//! PasswordInput Component - Password field with reveal toggle, checklist and meter
+//!
+//! Everything works without JavaScript:
+//! - Show/hide is a checkbox hack. The input is a text field masked with
+//!   `-webkit-text-security`, and checking the toggle removes the mask.
+//! - The rules in a [`PasswordPolicy`] become `minlength`, `maxlength` and
+//!   a lookahead `pattern`. The browser reports validity for the whole
+//!   pattern, so the checklist and meter light up once every rule passes.
+//! - After a failed submit, pass the server-side [`PasswordReport`] to mark
+//!   each rule individually and show the scored strength.
+
+use crate::form::{PasswordPolicy, PasswordReport};
+use maud::{html, Markup, Render};
+
+/// PasswordInput component
+pub struct PasswordInput<'a> {
+    name: &'a str,
+    id: Option<&'a str>,
+    label: &'a str,
+    placeholder: Option<&'a str>,
+    policy: Option<PasswordPolicy>,
+    report: Option<PasswordReport>,
+    new_password: bool,
+    revealable: bool,
+    checklist: bool,
+    meter: bool,
+    hint: Option<&'a str>,
+    error: Option<&'a str>,
+    required: bool,
+    disabled: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> PasswordInput<'a> {
+    /// Create a new PasswordInput
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            id: None,
+            label: "Password",
+            placeholder: None,
+            policy: None,
+            report: None,
+            new_password: false,
+            revealable: true,
+            checklist: true,
+            meter: true,
+            hint: None,
+            error: None,
+            required: false,
+            disabled: false,
+            class: None,
+        }
+    }
+
+    /// Set the input id (defaults to `sh-password-{name}`)
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the label text
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Set placeholder
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Enforce a policy. This also marks the field as a new password, so
+    /// password managers offer to generate one.
+    pub fn policy(mut self, policy: PasswordPolicy) -> Self {
+        self.policy = Some(policy);
+        self.new_password = true;
+        self
+    }
+
+    /// Show the result of checking the last submission on the server
+    pub fn report(mut self, report: PasswordReport) -> Self {
+        self.report = Some(report);
+        self
+    }
+
+    /// Use `autocomplete="new-password"` instead of `current-password`
+    pub fn new_password(mut self, new_password: bool) -> Self {
+        self.new_password = new_password;
+        self
+    }
+
+    /// Show the show/hide toggle (default: true)
+    pub fn revealable(mut self, revealable: bool) -> Self {
+        self.revealable = revealable;
+        self
+    }
+
+    /// Show the requirements checklist when a policy is set (default: true)
+    pub fn checklist(mut self, checklist: bool) -> Self {
+        self.checklist = checklist;
+        self
+    }
+
+    /// Show the strength meter when a policy is set (default: true)
+    pub fn meter(mut self, meter: bool) -> Self {
+        self.meter = meter;
+        self
+    }
+
+    /// Set hint text below the field
+    pub fn hint(mut self, hint: &'a str) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Set an error message
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    /// Set required state
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Add custom class
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = vec!["sh-password".to_string()];
+        if let Some(report) = &self.report {
+            classes.push(format!("sh-password--{}", report.strength.as_str()));
+        }
+        if self.error.is_some() {
+            classes.push("sh-password--error".to_string());
+        }
+        if self.disabled {
+            classes.push("sh-password--disabled".to_string());
+        }
+        if let Some(custom) = self.class {
+            classes.push(custom.to_string());
+        }
+        classes.join(" ")
+    }
+}
+
+impl<'a> Render for PasswordInput<'a> {
+    fn render(&self) -> Markup {
+        let classes = self.build_classes();
+        let input_id = self
+            .id
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("sh-password-{}", self.name));
+        let reveal_id = format!("{}-reveal", input_id);
+        let rules_id = format!("{}-rules", input_id);
+        let hint_id = format!("{}-hint", input_id);
+        let error_id = format!("{}-error", input_id);
+
+        let checklist = self.checklist && self.policy.is_some();
+        let meter = self.meter && self.policy.is_some();
+        let described_by: Vec<&str> = [
+            (checklist, rules_id.as_str()),
+            (self.hint.is_some(), hint_id.as_str()),
+            (self.error.is_some(), error_id.as_str()),
+        ]
+        .iter()
+        .filter(|(shown, _)| *shown)
+        .map(|(_, id)| *id)
+        .collect();
+        let described_by = (!described_by.is_empty()).then(|| described_by.join(" "));
+
+        let autocomplete = if self.new_password {
+            "new-password"
+        } else {
+            "current-password"
+        };
+        let input_type = if self.revealable { "text" } else { "password" };
+        let input_class = if self.revealable {
+            "sh-password__input sh-password__input--masked"
+        } else {
+            "sh-password__input"
+        };
+        // `:placeholder-shown` tells an empty field apart, so always set one
+        let placeholder = self.placeholder.unwrap_or(" ");
+
+        let policy = self.policy.as_ref();
+        let rules: Vec<(String, Option<bool>)> = match (policy, &self.report) {
+            (_, Some(report)) => report
+                .rules
+                .iter()
+                .map(|(rule, met)| (rule.label(), Some(*met)))
+                .collect(),
+            (Some(policy), None) => policy
+                .rules()
+                .iter()
+                .map(|rule| (rule.label(), None))
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+
+        html! {
+            div class=(classes) {
+                label class="sh-password__label" for=(input_id) { (self.label) }
+                div class="sh-password__control" {
+                    @if self.revealable {
+                        input
+                            type="checkbox"
+                            class="sh-password__reveal sh-visually-hidden"
+                            id=(reveal_id)
+                            aria-controls=(input_id)
+                            disabled?[self.disabled];
+                    }
+                    input
+                        type=(input_type)
+                        class=(input_class)
+                        id=(input_id)
+                        name=(self.name)
+                        placeholder=(placeholder)
+                        autocomplete=(autocomplete)
+                        autocapitalize="off"
+                        spellcheck="false"
+                        minlength=[policy.map(|p| p.min_length)]
+                        maxlength=[policy.and_then(|p| p.max_length)]
+                        pattern=[policy.and_then(|p| p.pattern())]
+                        title=[policy.map(|p| p.summary())]
+                        aria-invalid=[self.error.map(|_| "true")]
+                        aria-describedby=[described_by]
+                        required?[self.required]
+                        disabled?[self.disabled];
+                    @if self.revealable {
+                        label class="sh-password__toggle" for=(reveal_id) {
+                            span class="sh-password__toggle-show" { "Show" }
+                            span class="sh-password__toggle-hide" { "Hide" }
+                            span class="sh-visually-hidden" { " password" }
+                        }
+                    }
+                    @if meter {
+                        div class="sh-password__meter" aria-hidden="true" {
+                            div class="sh-password__meter-bar" {}
+                        }
+                    }
+                    @if checklist {
+                        ul class="sh-password__rules" id=(rules_id) {
+                            @for (label, met) in &rules {
+                                @let rule_class = match met {
+                                    Some(true) => "sh-password__rule sh-password__rule--met",
+                                    Some(false) => "sh-password__rule sh-password__rule--unmet",
+                                    None => "sh-password__rule",
+                                };
+                                li class=(rule_class) {
+                                    span class="sh-password__rule-icon" aria-hidden="true" {}
+                                    (label)
+                                    @match met {
+                                        Some(true) => span class="sh-visually-hidden" { " (met)" },
+                                        Some(false) => span class="sh-visually-hidden" { " (not met)" },
+                                        None => {},
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                @if let Some(hint) = self.hint {
+                    p class="sh-password__hint" id=(hint_id) { (hint) }
+                }
+                @if let Some(error) = self.error {
+                    p class="sh-password__error" id=(error_id) role="alert" { (error) }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for PasswordInput component
+pub fn password_input_css() -> String {
+    r#"
+.sh-password {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+    width: 100%;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-password__label {
+    font-size: 0.875rem;
+    font-weight: 500;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-password__control {
+    position: relative;
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+}
+
+.sh-password__input {
+    box-sizing: border-box;
+    width: 100%;
+    height: 2.75rem;
+    padding: 0 4.5rem 0 0.875rem;
+    font-size: 1rem;
+    color: var(--sh-text, #1f2937);
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    transition: border-color 0.2s ease, box-shadow 0.2s ease;
+}
+
+.sh-password__input:focus {
+    outline: none;
+    border-color: var(--sh-primary, #3b82f6);
+    box-shadow: 0 0 0 3px rgba(59, 130, 246, 0.1);
+}
+
+.sh-password__input--masked {
+    -webkit-text-security: disc;
+}
+
+.sh-password__reveal:checked ~ .sh-password__input--masked {
+    -webkit-text-security: none;
+}
+
+/* Show / hide toggle */
+.sh-password__toggle {
+    position: absolute;
+    top: 0;
+    right: 0;
+    display: flex;
+    align-items: center;
+    height: 2.75rem;
+    padding: 0 0.875rem;
+    font-size: 0.8125rem;
+    font-weight: 500;
+    color: var(--sh-primary, #3b82f6);
+    cursor: pointer;
+    user-select: none;
+}
+
+.sh-password__reveal:focus-visible ~ .sh-password__toggle {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: -2px;
+    border-radius: var(--sh-radius-md, 0.375rem);
+}
+
+.sh-password__toggle-hide,
+.sh-password__reveal:checked ~ .sh-password__toggle .sh-password__toggle-show {
+    display: none;
+}
+
+.sh-password__reveal:checked ~ .sh-password__toggle .sh-password__toggle-hide {
+    display: inline;
+}
+
+/* Strength meter */
+.sh-password__meter {
+    height: 0.25rem;
+    border-radius: 9999px;
+    background: var(--sh-surface-alt, #f3f4f6);
+    overflow: hidden;
+}
+
+.sh-password__meter-bar {
+    width: 0;
+    height: 100%;
+    border-radius: inherit;
+    background: var(--sh-error, #ef4444);
+    transition: width 0.3s ease, background-color 0.3s ease;
+}
+
+.sh-password__input:not(:placeholder-shown):invalid ~ .sh-password__meter .sh-password__meter-bar {
+    width: 33%;
+    background: var(--sh-error, #ef4444);
+}
+
+.sh-password__input:not(:placeholder-shown):valid ~ .sh-password__meter .sh-password__meter-bar {
+    width: 100%;
+    background: var(--sh-success, #10b981);
+}
+
+/* Server-scored strength, shown until the field is edited again */
+.sh-password--weak .sh-password__input:placeholder-shown ~ .sh-password__meter .sh-password__meter-bar {
+    width: 25%;
+    background: var(--sh-error, #ef4444);
+}
+
+.sh-password--fair .sh-password__input:placeholder-shown ~ .sh-password__meter .sh-password__meter-bar {
+    width: 50%;
+    background: var(--sh-warning, #f59e0b);
+}
+
+.sh-password--good .sh-password__input:placeholder-shown ~ .sh-password__meter .sh-password__meter-bar {
+    width: 75%;
+    background: var(--sh-success, #10b981);
+}
+
+.sh-password--strong .sh-password__input:placeholder-shown ~ .sh-password__meter .sh-password__meter-bar {
+    width: 100%;
+    background: var(--sh-success, #10b981);
+}
+
+/* Requirements checklist */
+.sh-password__rules {
+    display: flex;
+    flex-direction: column;
+    gap: 0.25rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-password__rule {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    transition: color 0.2s ease;
+}
+
+.sh-password__rule-icon::before {
+    content: "○";
+}
+
+.sh-password__rule--met,
+.sh-password__input:not(:placeholder-shown):valid ~ .sh-password__rules .sh-password__rule {
+    color: var(--sh-success, #10b981);
+}
+
+.sh-password__rule--met .sh-password__rule-icon::before,
+.sh-password__input:not(:placeholder-shown):valid ~ .sh-password__rules .sh-password__rule-icon::before {
+    content: "✓";
+}
+
+.sh-password__rule--unmet {
+    color: var(--sh-error, #ef4444);
+}
+
+.sh-password__rule--unmet .sh-password__rule-icon::before {
+    content: "✕";
+}
+
+.sh-password__hint {
+    margin: 0;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-password__error {
+    margin: 0;
+    font-size: 0.8125rem;
+    color: var(--sh-error, #ef4444);
+}
+
+.sh-password--error .sh-password__input {
+    border-color: var(--sh-error, #ef4444);
+}
+
+/* Disabled state */
+.sh-password--disabled {
+    opacity: 0.5;
+    pointer-events: none;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-password__input,
+    .sh-password__meter-bar,
+    .sh-password__rule {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PasswordPolicy {
+        PasswordPolicy::new().min_length(10).require_digit()
+    }
+
+    #[test]
+    fn test_password_input_defaults() {
+        let html = PasswordInput::new("password").render().into_string();
+        assert!(html.contains(r#"id="sh-password-password""#));
+        assert!(html.contains(r#"autocomplete="current-password""#));
+        assert!(html.contains("sh-password__input--masked"));
+        assert!(html.contains(r#"for="sh-password-password-reveal""#));
+        assert!(html.contains(r#"placeholder=" ""#));
+        assert!(!html.contains("sh-password__rules"));
+        assert!(!html.contains("sh-password__meter"));
+    }
+
+    #[test]
+    fn test_password_input_policy_attributes() {
+        let html = PasswordInput::new("pw")
+            .policy(policy().max_length(64))
+            .render()
+            .into_string();
+        assert!(html.contains(r#"autocomplete="new-password""#));
+        assert!(html.contains(r#"minlength="10""#));
+        assert!(html.contains(r#"maxlength="64""#));
+        assert!(html.contains(r#"pattern="(?=.*\d).{10,64}""#));
+        assert!(html.contains("At least 10 characters"));
+        assert!(html.contains("A number"));
+        assert!(html.contains(r#"aria-describedby="sh-password-pw-rules""#));
+        assert!(html.contains("sh-password__meter-bar"));
+    }
+
+    #[test]
+    fn test_password_input_report() {
+        let report = policy().check("short");
+        let html = PasswordInput::new("pw")
+            .policy(policy())
+            .report(report)
+            .error("Choose a longer password")
+            .render()
+            .into_string();
+        assert!(html.contains("sh-password--weak"));
+        assert_eq!(html.matches("sh-password__rule--unmet").count(), 2);
+        assert!(html.contains(" (not met)"));
+        assert!(html.contains(r#"aria-invalid="true""#));
+        assert!(html.contains(r#"aria-describedby="sh-password-pw-rules sh-password-pw-error""#));
+    }
+
+    #[test]
+    fn test_password_input_not_revealable() {
+        let html = PasswordInput::new("pw")
+            .revealable(false)
+            .new_password(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"type="password""#));
+        assert!(!html.contains("sh-password__reveal"));
+        assert!(html.contains(r#"autocomplete="new-password""#));
+    }
+
+    #[test]
+    fn test_password_input_css() {
+        let css = password_input_css();
+        assert!(css.contains(".sh-password__reveal:checked ~ .sh-password__input--masked"));
+        assert!(css.contains(":valid ~ .sh-password__meter"));
+    }
+}
@@ -0,0 +1,626 @@
+//! PhoneInput Component - Telephone field with a country dial-code select
+//!
+//! The country `<select>` is named `{name}_country` and the number field
+//! `{name}`. The browser only checks the allowed characters using
+//! [`PHONE_PATTERN`]. On the server, [`PhoneNumber::parse`] applies the
+//! selected country's dial code, trunk prefix and length rules, and
+//! normalizes the result to E.164.
+
+use maud::{html, Markup, Render};
+
+/// Characters a phone number may be typed with: an optional leading `+`,
+/// digits, spaces and `( ) . -` separators
+pub const PHONE_PATTERN: &str = r"\+?[0-9\s\(\)\.\-]{6,24}";
+
+/// Dialling rules for one country
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoneCountry {
+    /// ISO 3166-1 alpha-2 code
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Country calling code, without the `+`
+    pub dial_code: &'static str,
+    /// National prefix dropped in international format, e.g. "0"
+    pub trunk_prefix: Option<&'static str>,
+    /// Shortest and longest national significant number, in digits
+    pub min_digits: usize,
+    pub max_digits: usize,
+    /// Example number in local format, used as the placeholder
+    pub example: &'static str,
+}
+
+impl PhoneCountry {
+    /// Flag emoji built from the regional indicator symbols
+    pub fn flag(&self) -> String {
+        self.code
+            .chars()
+            .filter_map(|c| {
+                char::from_u32(0x1F1E6 + (c.to_ascii_uppercase() as u32).checked_sub('A' as u32)?)
+            })
+            .collect()
+    }
+
+    /// Look up a country by ISO code (case-insensitive)
+    pub fn find(code: &str) -> Option<&'static PhoneCountry> {
+        PHONE_COUNTRIES
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(code))
+    }
+}
+
+macro_rules! phone_country {
+    ($code:literal, $name:literal, $dial:literal, $trunk:expr, $min:literal..=$max:literal, $example:literal) => {
+        PhoneCountry {
+            code: $code,
+            name: $name,
+            dial_code: $dial,
+            trunk_prefix: $trunk,
+            min_digits: $min,
+            max_digits: $max,
+            example: $example,
+        }
+    };
+}
+
+/// Countries offered by default, in select order
+pub const PHONE_COUNTRIES: &[PhoneCountry] = &[
+    phone_country!("US", "United States", "1", None, 10..=10, "(201) 555-0123"),
+    phone_country!("CA", "Canada", "1", None, 10..=10, "(506) 234-5678"),
+    phone_country!(
+        "GB",
+        "United Kingdom",
+        "44",
+        Some("0"),
+        9..=10,
+        "07400 123456"
+    ),
+    phone_country!("IE", "Ireland", "353", Some("0"), 7..=9, "085 012 3456"),
+    phone_country!("FR", "France", "33", Some("0"), 9..=9, "06 12 34 56 78"),
+    phone_country!("DE", "Germany", "49", Some("0"), 7..=12, "030 123456"),
+    phone_country!("ES", "Spain", "34", None, 9..=9, "612 34 56 78"),
+    phone_country!("IT", "Italy", "39", None, 6..=11, "312 345 6789"),
+    phone_country!("NL", "Netherlands", "31", Some("0"), 9..=9, "06 12345678"),
+    phone_country!("BE", "Belgium", "32", Some("0"), 8..=9, "0470 12 34 56"),
+    phone_country!("CH", "Switzerland", "41", Some("0"), 9..=9, "078 123 45 67"),
+    phone_country!("SE", "Sweden", "46", Some("0"), 7..=9, "070-123 45 67"),
+    phone_country!("AU", "Australia", "61", Some("0"), 9..=9, "0412 345 678"),
+    phone_country!("NZ", "New Zealand", "64", Some("0"), 8..=10, "021 123 4567"),
+    phone_country!("JP", "Japan", "81", Some("0"), 9..=10, "090-1234-5678"),
+    phone_country!("CN", "China", "86", Some("0"), 10..=11, "131 2345 6789"),
+    phone_country!("IN", "India", "91", Some("0"), 10..=10, "081234 56789"),
+    phone_country!("BR", "Brazil", "55", Some("0"), 10..=11, "(11) 96123-4567"),
+    phone_country!("MX", "Mexico", "52", None, 10..=10, "55 1234 5678"),
+    phone_country!("ZA", "South Africa", "27", Some("0"), 9..=9, "071 123 4567"),
+];
+
+/// Why a phone number could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneError {
+    Empty,
+    InvalidCharacters,
+    UnknownCountry(String),
+    UnknownDialCode,
+    TooShort,
+    TooLong,
+}
+
+impl std::fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhoneError::Empty => write!(f, "phone number is empty"),
+            PhoneError::InvalidCharacters => {
+                write!(
+                    f,
+                    "phone number may only contain digits, spaces and + ( ) . -"
+                )
+            }
+            PhoneError::UnknownCountry(code) => write!(f, "unknown country code: {}", code),
+            PhoneError::UnknownDialCode => write!(f, "unknown international dialling code"),
+            PhoneError::TooShort => write!(f, "phone number is too short"),
+            PhoneError::TooLong => write!(f, "phone number is too long"),
+        }
+    }
+}
+
+impl std::error::Error for PhoneError {}
+
+/// A validated phone number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    pub country: &'static PhoneCountry,
+    /// National significant number: digits only, trunk prefix removed
+    pub national: String,
+}
+
+impl PhoneNumber {
+    /// Parse a number as typed. International input (`+44 …` or `0044 …`)
+    /// picks the country from its dial code; anything else is read as a
+    /// national number of `default_country`.
+    pub fn parse(input: &str, default_country: &str) -> Result<Self, PhoneError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(PhoneError::Empty);
+        }
+        let (plus, rest) = match input.strip_prefix('+') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        if !rest
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace() || "().-".contains(c))
+        {
+            return Err(PhoneError::InvalidCharacters);
+        }
+        let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+        let default = PhoneCountry::find(default_country)
+            .ok_or_else(|| PhoneError::UnknownCountry(default_country.to_string()))?;
+
+        let international = if plus {
+            Some(digits.as_str())
+        } else {
+            digits.strip_prefix("00")
+        };
+        let (country, national) = match international {
+            Some(digits) => {
+                let country = Self::country_for_dial(digits, default)?;
+                (country, &digits[country.dial_code.len()..])
+            }
+            None => {
+                let national = default
+                    .trunk_prefix
+                    .and_then(|trunk| digits.strip_prefix(trunk))
+                    .unwrap_or(&digits);
+                (default, national)
+            }
+        };
+
+        if national.len() < country.min_digits {
+            return Err(PhoneError::TooShort);
+        }
+        if national.len() > country.max_digits {
+            return Err(PhoneError::TooLong);
+        }
+        Ok(Self {
+            country,
+            national: national.to_string(),
+        })
+    }
+
+    /// Parse and return the E.164 form, e.g. `+442079460958`
+    pub fn normalize(input: &str, default_country: &str) -> Result<String, PhoneError> {
+        Self::parse(input, default_country).map(|number| number.e164())
+    }
+
+    /// Longest matching dial code; shared codes such as `+1` prefer the
+    /// default country
+    fn country_for_dial(
+        digits: &str,
+        default: &'static PhoneCountry,
+    ) -> Result<&'static PhoneCountry, PhoneError> {
+        if digits.starts_with(default.dial_code) {
+            return Ok(default);
+        }
+        // Reversed so ties go to the country listed first
+        PHONE_COUNTRIES
+            .iter()
+            .rev()
+            .filter(|country| digits.starts_with(country.dial_code))
+            .max_by_key(|country| country.dial_code.len())
+            .ok_or(PhoneError::UnknownDialCode)
+    }
+
+    /// E.164 representation
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.country.dial_code, self.national)
+    }
+}
+
+impl std::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.e164())
+    }
+}
+
+/// PhoneInput component
+pub struct PhoneInput<'a> {
+    name: &'a str,
+    id: Option<&'a str>,
+    label: &'a str,
+    value: Option<&'a str>,
+    country: &'a str,
+    countries: &'a [PhoneCountry],
+    hint: Option<&'a str>,
+    error: Option<&'a str>,
+    required: bool,
+    disabled: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> PhoneInput<'a> {
+    /// Create a new PhoneInput
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            id: None,
+            label: "Phone number",
+            value: None,
+            country: "US",
+            countries: PHONE_COUNTRIES,
+            hint: None,
+            error: None,
+            required: false,
+            disabled: false,
+            class: None,
+        }
+    }
+
+    /// Set the input id (defaults to `sh-phone-{name}`)
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the label text
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Set the value
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Select a country by ISO code (default: "US")
+    pub fn country(mut self, code: &'a str) -> Self {
+        self.country = code;
+        self
+    }
+
+    /// Restrict the countries offered
+    pub fn countries(mut self, countries: &'a [PhoneCountry]) -> Self {
+        self.countries = countries;
+        self
+    }
+
+    /// Set hint text below the field
+    pub fn hint(mut self, hint: &'a str) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Set an error message
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    /// Set required state
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Add custom class
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Name of the country select for a phone field called `name`
+    pub fn country_field(name: &str) -> String {
+        format!("{}_country", name)
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = vec!["sh-phone".to_string()];
+        if self.error.is_some() {
+            classes.push("sh-phone--error".to_string());
+        }
+        if self.disabled {
+            classes.push("sh-phone--disabled".to_string());
+        }
+        if let Some(custom) = self.class {
+            classes.push(custom.to_string());
+        }
+        classes.join(" ")
+    }
+}
+
+impl<'a> Render for PhoneInput<'a> {
+    fn render(&self) -> Markup {
+        let classes = self.build_classes();
+        let input_id = self
+            .id
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("sh-phone-{}", self.name));
+        let country_id = format!("{}-country", input_id);
+        let hint_id = format!("{}-hint", input_id);
+        let error_id = format!("{}-error", input_id);
+        let described_by = match (self.hint, self.error) {
+            (Some(_), Some(_)) => Some(format!("{} {}", hint_id, error_id)),
+            (Some(_), None) => Some(hint_id.clone()),
+            (None, Some(_)) => Some(error_id.clone()),
+            (None, None) => None,
+        };
+        let selected = self
+            .countries
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(self.country))
+            .or(self.countries.first());
+
+        html! {
+            div class=(classes) {
+                label class="sh-phone__label" for=(input_id) { (self.label) }
+                div class="sh-phone__group" {
+                    select
+                        class="sh-phone__country"
+                        id=(country_id)
+                        name=(Self::country_field(self.name))
+                        autocomplete="tel-country-code"
+                        aria-label="Country calling code"
+                        disabled?[self.disabled] {
+                        @for country in self.countries {
+                            option
+                                value=(country.code)
+                                selected?[selected == Some(country)] {
+                                (country.flag()) " " (country.name) " +" (country.dial_code)
+                            }
+                        }
+                    }
+                    input
+                        type="tel"
+                        class="sh-phone__input"
+                        id=(input_id)
+                        name=(self.name)
+                        value=[self.value]
+                        placeholder=[selected.map(|country| country.example)]
+                        inputmode="tel"
+                        autocomplete="tel-national"
+                        pattern=(PHONE_PATTERN)
+                        title="Digits, spaces and + ( ) . - only"
+                        aria-invalid=[self.error.map(|_| "true")]
+                        aria-describedby=[described_by]
+                        required?[self.required]
+                        disabled?[self.disabled];
+                }
+                @if let Some(hint) = self.hint {
+                    p class="sh-phone__hint" id=(hint_id) { (hint) }
+                }
+                @if let Some(error) = self.error {
+                    p class="sh-phone__error" id=(error_id) role="alert" { (error) }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for PhoneInput component
+pub fn phone_input_css() -> String {
+    r#"
+.sh-phone {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+    width: 100%;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-phone__label {
+    font-size: 0.875rem;
+    font-weight: 500;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-phone__group {
+    display: flex;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    transition: border-color 0.2s ease, box-shadow 0.2s ease;
+}
+
+.sh-phone__group:focus-within {
+    border-color: var(--sh-primary, #3b82f6);
+    box-shadow: 0 0 0 3px rgba(59, 130, 246, 0.1);
+}
+
+.sh-phone__country {
+    flex: 0 0 auto;
+    max-width: 9rem;
+    padding: 0 0.5rem 0 0.75rem;
+    font-size: 0.9375rem;
+    color: var(--sh-text, #1f2937);
+    border: none;
+    border-right: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem) 0 0 var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface-alt, #f9fafb);
+    text-overflow: ellipsis;
+}
+
+.sh-phone__input {
+    flex: 1 1 auto;
+    min-width: 0;
+    height: 2.75rem;
+    padding: 0 0.875rem;
+    font-size: 1rem;
+    color: var(--sh-text, #1f2937);
+    border: none;
+    border-radius: 0 var(--sh-radius-md, 0.375rem) var(--sh-radius-md, 0.375rem) 0;
+    background: transparent;
+}
+
+.sh-phone__country:focus,
+.sh-phone__input:focus {
+    outline: none;
+}
+
+.sh-phone__input:not(:placeholder-shown):invalid {
+    color: var(--sh-error, #ef4444);
+}
+
+.sh-phone__hint {
+    margin: 0;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-phone__error {
+    margin: 0;
+    font-size: 0.8125rem;
+    color: var(--sh-error, #ef4444);
+}
+
+.sh-phone--error .sh-phone__group {
+    border-color: var(--sh-error, #ef4444);
+}
+
+/* Disabled state */
+.sh-phone--disabled {
+    opacity: 0.5;
+    pointer-events: none;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-phone__group {
+        transition: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag() {
+        assert_eq!(PhoneCountry::find("gb").unwrap().flag(), "🇬🇧");
+        assert_eq!(PhoneCountry::find("US").unwrap().flag(), "🇺🇸");
+        assert!(PhoneCountry::find("XX").is_none());
+    }
+
+    #[test]
+    fn test_parse_national() {
+        let number = PhoneNumber::parse("(201) 555-0123", "US").unwrap();
+        assert_eq!(number.country.code, "US");
+        assert_eq!(number.national, "2015550123");
+        assert_eq!(number.e164(), "+12015550123");
+
+        // Trunk prefix is dropped
+        assert_eq!(
+            PhoneNumber::normalize("07400 123456", "GB").unwrap(),
+            "+447400123456"
+        );
+        // Italian numbers keep their leading zero
+        assert_eq!(
+            PhoneNumber::normalize("06 1234 5678", "IT").unwrap(),
+            "+390612345678"
+        );
+    }
+
+    #[test]
+    fn test_parse_international() {
+        let number = PhoneNumber::parse("+33 6 12 34 56 78", "US").unwrap();
+        assert_eq!(number.country.code, "FR");
+        assert_eq!(number.to_string(), "+33612345678");
+
+        assert_eq!(
+            PhoneNumber::normalize("00353 85 012 3456", "GB").unwrap(),
+            "+353850123456"
+        );
+        // Shared +1 prefers the selected country
+        let canada = PhoneNumber::parse("+1 506 234 5678", "CA").unwrap();
+        assert_eq!(canada.country.code, "CA");
+        let us = PhoneNumber::parse("+1 506 234 5678", "GB").unwrap();
+        assert_eq!(us.country.code, "US");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(PhoneNumber::parse("  ", "US"), Err(PhoneError::Empty));
+        assert_eq!(
+            PhoneNumber::parse("555-CALL-NOW", "US"),
+            Err(PhoneError::InvalidCharacters)
+        );
+        assert_eq!(
+            PhoneNumber::parse("555 0123", "US"),
+            Err(PhoneError::TooShort)
+        );
+        assert_eq!(
+            PhoneNumber::parse("201 555 0123 99", "US"),
+            Err(PhoneError::TooLong)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+999 1234567", "US"),
+            Err(PhoneError::UnknownDialCode)
+        );
+        assert_eq!(
+            PhoneNumber::parse("2015550123", "ZZ"),
+            Err(PhoneError::UnknownCountry("ZZ".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_pattern_accepts_parseable_input() {
+        let pattern = regex::Regex::new(&format!("^(?:{})$", PHONE_PATTERN)).unwrap();
+        for input in ["(201) 555-0123", "+44 7400 123456", "06.12.34.56.78"] {
+            assert!(pattern.is_match(input), "{}", input);
+        }
+        assert!(!pattern.is_match("555-CALL-NOW"));
+        for country in PHONE_COUNTRIES {
+            assert!(pattern.is_match(country.example), "{}", country.code);
+            assert!(
+                PhoneNumber::parse(country.example, country.code).is_ok(),
+                "{}",
+                country.code
+            );
+        }
+    }
+
+    #[test]
+    fn test_phone_input_render() {
+        let html = PhoneInput::new("phone")
+            .country("GB")
+            .value("07400 123456")
+            .required(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="phone_country""#));
+        assert!(html.contains(r#"<option value="GB" selected>"#));
+        assert!(html.contains("United Kingdom +44"));
+        assert!(html.contains(r#"type="tel""#));
+        assert!(html.contains(r#"placeholder="07400 123456""#));
+        assert!(html.contains(r#"pattern="\+?[0-9\s\(\)\.\-]{6,24}""#));
+        assert!(html.contains("required"));
+    }
+
+    #[test]
+    fn test_phone_input_countries_and_error() {
+        let html = PhoneInput::new("mobile")
+            .countries(&PHONE_COUNTRIES[2..4])
+            .error("Enter a valid number")
+            .render()
+            .into_string();
+        assert_eq!(html.matches("<option").count(), 2);
+        // Falls back to the first offered country
+        assert!(html.contains(r#"<option value="GB" selected>"#));
+        assert!(html.contains(r#"aria-describedby="sh-phone-mobile-error""#));
+        assert!(html.contains("sh-phone--error"));
+    }
+
+    #[test]
+    fn test_phone_input_css() {
+        let css = phone_input_css();
+        assert!(css.contains(".sh-phone__country"));
+        assert!(css.contains(".sh-phone__group:focus-within"));
+    }
+}
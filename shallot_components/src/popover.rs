@@ -1,3 +1,4 @@
+use crate::component::OverlayEngine;
 use maud::{html, Markup, Render};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -37,6 +38,7 @@ pub struct Popover<'a> {
     pub align_end: bool,
     pub title: Option<&'a str>,
     pub id: Option<&'a str>,
+    pub engine: OverlayEngine,
 }
 
 impl<'a> Default for Popover<'a> {
@@ -50,6 +52,7 @@ impl<'a> Default for Popover<'a> {
             align_end: false,
             title: None,
             id: None,
+            engine: OverlayEngine::Css,
         }
     }
 }
@@ -93,6 +96,16 @@ impl<'a> Popover<'a> {
         self
     }
 
+    /// Choose between the `details` engine and the native `popover` attribute
+    pub fn engine(mut self, engine: OverlayEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    fn panel_id(&self) -> &'a str {
+        self.id.unwrap_or("popover-panel")
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-popover"];
 
@@ -121,14 +134,57 @@ impl<'a> Popover<'a> {
             classes.push("sh-popover--align-end");
         }
 
+        if let Some(class) = self.engine.class() {
+            classes.push(class);
+        }
+
         classes.join(" ")
     }
 }
 
+impl<'a> Popover<'a> {
+    fn render_panel_body(&self) -> Markup {
+        html! {
+            @if let Some(title) = self.title {
+                div class="sh-popover__header" { (title) }
+            }
+            div class="sh-popover__content" { (self.content) }
+        }
+    }
+
+    fn render_native(&self, classes: &str) -> Markup {
+        let id = self.panel_id();
+        let anchor = OverlayEngine::anchor_name(id);
+
+        html! {
+            div class=(classes) {
+                button
+                    type="button"
+                    class="sh-popover__trigger"
+                    popovertarget=(id)
+                    style={ "anchor-name: " (anchor) }
+                { (self.trigger) }
+                div
+                    class="sh-popover__panel"
+                    id=(id)
+                    popover="auto"
+                    role="dialog"
+                    aria-label=[self.title]
+                    style={ "position-anchor: " (anchor) }
+                { (self.render_panel_body()) }
+            }
+        }
+    }
+}
+
 impl<'a> Render for Popover<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
 
+        if self.engine.is_native() {
+            return self.render_native(&classes);
+        }
+
         html! {
             div class=(classes) {
                 details class="sh-popover__wrapper" {
@@ -142,13 +198,8 @@ impl<'a> Render for Popover<'a> {
                     div
                         class="sh-popover__panel"
                         role="tooltip"
-                        id=(self.id.unwrap_or("popover-panel"))
-                    {
-                        @if let Some(title) = self.title {
-                            div class="sh-popover__header" { (title) }
-                        }
-                        div class="sh-popover__content" { (self.content) }
-                    }
+                        id=(self.panel_id())
+                    { (self.render_panel_body()) }
                 }
             }
         }
@@ -161,7 +212,7 @@ pub fn popover_css() -> String {
     --popover-bg: var(--sh-surface, #fff);
     --popover-border: var(--sh-border, #e5e7eb);
     --popover-text: var(--sh-text, #1f2937);
-    --popover-shadow: 0 10px 15px -3px rgba(0, 0, 0, 0.1), 0 4px 6px -4px rgba(0, 0, 0, 0.1);
+    --popover-shadow: var(--sh-elevation-3);
     
     display: inline-block;
     position: relative;
@@ -230,7 +281,7 @@ pub fn popover_css() -> String {
 }
 
 .sh-popover--shadow .sh-popover__panel {
-    box-shadow: 0 20px 25px -5px rgba(0, 0, 0, 0.1), 0 8px 10px -6px rgba(0, 0, 0, 0.1);
+    box-shadow: var(--sh-elevation-4);
 }
 
 /* Sizes */
@@ -292,6 +343,62 @@ pub fn popover_css() -> String {
     color: var(--popover-text);
 }
 
+/* Native engine: popover attribute + anchor positioning */
+.sh-popover.sh-overlay--native .sh-popover__trigger {
+    font: inherit;
+    color: inherit;
+    background: none;
+    border: 0;
+    padding: 0;
+}
+
+.sh-popover.sh-overlay--native .sh-popover__panel {
+    color: var(--popover-text);
+    opacity: 1;
+    visibility: visible;
+    transform: none;
+}
+
+@supports (anchor-name: --sh-anchor) {
+    .sh-popover.sh-overlay--native .sh-popover__panel {
+        position: fixed;
+        inset: auto;
+        margin: 0.5rem;
+        position-area: block-end span-inline-end;
+        position-try-fallbacks: flip-block, flip-inline;
+    }
+
+    .sh-popover--top.sh-overlay--native .sh-popover__panel {
+        position-area: block-start span-inline-end;
+    }
+
+    .sh-popover--bottom.sh-popover--align-end.sh-overlay--native .sh-popover__panel {
+        position-area: block-end span-inline-start;
+    }
+
+    .sh-popover--top.sh-popover--align-end.sh-overlay--native .sh-popover__panel {
+        position-area: block-start span-inline-start;
+    }
+
+    .sh-popover--left.sh-overlay--native .sh-popover__panel {
+        position-area: center inline-start;
+    }
+
+    .sh-popover--right.sh-overlay--native .sh-popover__panel {
+        position-area: center inline-end;
+    }
+}
+
+/* Without anchor positioning the top-layer panel is docked to the viewport */
+@supports not (anchor-name: --sh-anchor) {
+    .sh-popover.sh-overlay--native .sh-popover__panel {
+        position: fixed;
+        inset: auto 1rem 1rem;
+        margin: 0 auto;
+        max-width: min(24rem, calc(100vw - 2rem));
+    }
+}
+
 /* Reduced motion */
 @media (prefers-reduced-motion: reduce) {
     .sh-popover__panel {
@@ -402,6 +509,34 @@ mod tests {
         assert!(html_str.contains("role=\"tooltip\""));
     }
 
+    #[test]
+    fn test_popover_native_engine() {
+        let html_str = Popover::new(html! { "Open" }, html! { "Body" })
+            .id("settings")
+            .title("Settings")
+            .engine(OverlayEngine::Native)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("sh-overlay--native"));
+        assert!(html_str.contains("<button type=\"button\" class=\"sh-popover__trigger\""));
+        assert!(html_str.contains("popovertarget=\"settings\""));
+        assert!(html_str.contains("id=\"settings\" popover=\"auto\""));
+        assert!(html_str.contains("anchor-name: --sh-anchor-settings"));
+        assert!(html_str.contains("position-anchor: --sh-anchor-settings"));
+        assert!(html_str.contains("aria-label=\"Settings\""));
+        assert!(!html_str.contains("<details"));
+    }
+
+    #[test]
+    fn test_popover_css_engine_is_default() {
+        let html_str = Popover::new(html! {}, html! {}).render().into_string();
+
+        assert!(html_str.contains("<details class=\"sh-popover__wrapper\""));
+        assert!(!html_str.contains("popover="));
+        assert!(!html_str.contains("sh-overlay--native"));
+    }
+
     #[test]
     fn test_popover_css() {
         let css = popover_css();
@@ -411,6 +546,7 @@ mod tests {
         assert!(css.contains(".sh-popover--light"));
         assert!(css.contains(".sh-popover--bordered"));
         assert!(css.contains(".sh-popover--shadow"));
+        assert!(css.contains("--popover-shadow: var(--sh-elevation-3);"));
         assert!(css.contains(".sh-popover--sm"));
         assert!(css.contains(".sh-popover--md"));
         assert!(css.contains(".sh-popover--lg"));
@@ -418,5 +554,7 @@ mod tests {
         assert!(css.contains(".sh-popover--top"));
         assert!(css.contains(".sh-popover--left"));
         assert!(css.contains(".sh-popover--right"));
+        assert!(css.contains("@supports (anchor-name: --sh-anchor)"));
+        assert!(css.contains("@supports not (anchor-name: --sh-anchor)"));
     }
 }
@@ -16,6 +16,7 @@
 //!     .quick_action(html! { "Add to Cart" });
 //! ```
 
+use crate::skeleton::{Skeleton, SkeletonText};
 use maud::{html, Markup};
 
 /// Product price with optional sale
@@ -430,6 +431,46 @@ impl ProductCard {
         }
     }
 
+    /// Render a loading placeholder with this card's layout, keeping the
+    /// image aspect ratio and the rating/description/price rows it will show
+    pub fn skeleton(&self) -> Markup {
+        let class = format!("{} sh-product-card--skeleton", self.container_class());
+
+        html! {
+            article class=(class) aria-busy="true" aria-label="Loading product" {
+                div class="sh-product-image-wrapper" {
+                    (Skeleton::fill())
+                }
+                div class="sh-product-content" {
+                    h3 class="sh-product-title" { (Skeleton::inline("70%")) }
+
+                    @if self.rating.is_some() {
+                        div class="sh-product-rating" { (Skeleton::inline("6rem")) }
+                    }
+
+                    @if self.description.is_some() {
+                        div class="sh-product-description" {
+                            (SkeletonText::new(2))
+                        }
+                    }
+
+                    div class="sh-product-footer" {
+                        @if self.price.is_some() {
+                            div class="sh-product-price-wrapper" {
+                                (Skeleton::inline("4rem"))
+                            }
+                        }
+                        @if self.quick_action.is_some() {
+                            div class="sh-product-action" {
+                                (Skeleton::inline("5rem"))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     pub fn render(self) -> Markup {
         let class = self.container_class();
 
@@ -449,20 +490,23 @@ pub struct CartSummary {
     subtotal: f64,
     shipping: Option<f64>,
     tax: Option<f64>,
+    discount: Option<f64>,
     currency: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CartItem {
-    name: String,
-    quantity: u32,
-    price: f64,
-    image: Option<String>,
+    pub(crate) id: Option<String>,
+    pub(crate) name: String,
+    pub(crate) quantity: u32,
+    pub(crate) price: f64,
+    pub(crate) image: Option<String>,
 }
 
 impl CartItem {
     pub fn new(name: impl Into<String>, quantity: u32, price: f64) -> Self {
         Self {
+            id: None,
             name: name.into(),
             quantity,
             price,
@@ -470,12 +514,32 @@ impl CartItem {
         }
     }
 
+    /// Stable identifier (SKU or line id) posted by cart update forms
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn image(mut self, src: impl Into<String>) -> Self {
         self.image = Some(src.into());
         self
     }
 
-    fn total(&self) -> f64 {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    /// Unit price
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    /// Unit price times quantity
+    pub fn total(&self) -> f64 {
         self.price * self.quantity as f64
     }
 }
@@ -488,6 +552,7 @@ impl CartSummary {
             subtotal,
             shipping: None,
             tax: None,
+            discount: None,
             currency: "$".to_string(),
         }
     }
@@ -502,13 +567,21 @@ impl CartSummary {
         self
     }
 
+    /// Amount taken off the subtotal
+    pub fn discount(mut self, amount: f64) -> Self {
+        self.discount = Some(amount);
+        self
+    }
+
     pub fn currency(mut self, symbol: impl Into<String>) -> Self {
         self.currency = symbol.into();
         self
     }
 
     fn total(&self) -> f64 {
-        self.subtotal + self.shipping.unwrap_or(0.0) + self.tax.unwrap_or(0.0)
+        self.subtotal - self.discount.unwrap_or(0.0)
+            + self.shipping.unwrap_or(0.0)
+            + self.tax.unwrap_or(0.0)
     }
 
     fn format_price(&self, amount: f64) -> String {
@@ -542,6 +615,12 @@ impl CartSummary {
                         span { "Subtotal" }
                         span { (self.format_price(self.subtotal)) }
                     }
+                    @if let Some(discount) = self.discount {
+                        div class="sh-cart-row sh-cart-row--discount" {
+                            span { "Discount" }
+                            span { "−" (self.format_price(discount)) }
+                        }
+                    }
                     @if let Some(shipping) = self.shipping {
                         div class="sh-cart-row" {
                             span { "Shipping" }
@@ -897,6 +976,10 @@ pub fn product_card_css() -> String {
     font-size: 0.875rem;
 }
 
+.sh-cart-row--discount {
+    color: var(--sh-success);
+}
+
 .sh-cart-row--total {
     margin-top: 0.5rem;
     padding-top: 0.5rem;
@@ -977,6 +1060,19 @@ pub fn product_card_css() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_product_card_skeleton() {
+        let card = ProductCard::new("Sneakers")
+            .description("Comfortable")
+            .variant(ProductCardVariant::Compact);
+        let html = card.skeleton().into_string();
+        assert!(html.contains("sh-product-card--compact"));
+        assert!(html.contains("sh-product-image-wrapper"));
+        assert!(html.contains("sh-product-description"));
+        assert!(!html.contains("sh-product-rating"));
+        assert!(!html.contains("Sneakers"));
+    }
+
     #[test]
     fn test_product_price() {
         let regular = ProductPrice::regular(99.99);
@@ -1043,6 +1139,17 @@ mod tests {
         assert_eq!(summary.total(), 27.0);
     }
 
+    #[test]
+    fn test_cart_summary_discount() {
+        let items = vec![CartItem::new("Item", 2, 10.0).id("sku-1")];
+        let summary = CartSummary::new(items).discount(5.0).shipping(4.0);
+
+        assert_eq!(summary.total(), 19.0);
+        let html = summary.render().into_string();
+        assert!(html.contains("sh-cart-row--discount"));
+        assert!(html.contains("−$5.00"));
+    }
+
     #[test]
     fn test_quantity_stepper() {
         let stepper = QuantityStepper::new("qty").value(5).min(1).max(10);
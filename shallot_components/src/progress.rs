@@ -1,22 +1,35 @@
-use maud::{html, Markup};
+use crate::live_region::LiveRegion;
+use maud::{html, Markup, Render};
 
 pub struct ProgressBar {
     pub value_0_100: u8,
+    pub announce: bool,
 }
 
 impl ProgressBar {
     pub fn new(value: u8) -> Self {
         Self {
             value_0_100: value.min(100),
+            announce: false,
         }
     }
 
+    /// Announce the percentage through a polite live region when the page
+    /// re-renders with a new value
+    pub fn announce(mut self, announce: bool) -> Self {
+        self.announce = announce;
+        self
+    }
+
     pub fn render(self) -> Markup {
         let v = self.value_0_100.min(100);
         html! {
             div class="sh-progress" role="progressbar" aria-valuemin="0" aria-valuemax="100" aria-valuenow=(v) {
                 div class="sh-progress__bar" style={(format!("width: {}%", v))} {}
             }
+            @if self.announce {
+                (LiveRegion::polite(html! { (v) "% complete" }).visually_hidden(true).render())
+            }
         }
     }
 }
@@ -58,6 +71,16 @@ mod tests {
         assert_eq!(progress.value_0_100, 100);
     }
 
+    #[test]
+    fn test_progress_announce() {
+        let quiet = ProgressBar::new(40).render().into_string();
+        assert!(!quiet.contains("aria-live"));
+
+        let html = ProgressBar::new(40).announce(true).render().into_string();
+        assert!(html.contains(r#"role="status" aria-live="polite""#));
+        assert!(html.contains("40% complete"));
+    }
+
     #[test]
     fn test_progress_css() {
         let css = progress_css();
@@ -1,6 +1,7 @@
 //! Progress Circle Component - Circular progress indicator
 //! CSS-only using conic-gradient
 
+use crate::charts::{arc_sweep, MeterFormat, Thresholds};
 use maud::{html, Markup, Render};
 
 /// Progress circle size
@@ -28,27 +29,77 @@ pub enum ProgressCircleVariant {
 #[derive(Debug, Clone)]
 pub struct ProgressCircle<'a> {
     pub value: u8,
+    pub min: u8,
     pub max: u8,
     pub size: ProgressCircleSize,
     pub variant: ProgressCircleVariant,
     pub label: Option<&'a str>,
     pub show_value: bool,
+    /// Degrees clockwise from 12 o'clock
+    pub start_angle: f64,
+    pub end_angle: f64,
+    pub thresholds: Option<Thresholds<ProgressCircleVariant>>,
+    pub format: MeterFormat,
+    pub meter: bool,
 }
 
 impl<'a> ProgressCircle<'a> {
     pub fn new(value: u8) -> Self {
         Self {
             value,
+            min: 0,
             max: 100,
             size: ProgressCircleSize::default(),
             variant: ProgressCircleVariant::default(),
             label: None,
             show_value: true,
+            start_angle: 0.0,
+            end_angle: 360.0,
+            thresholds: None,
+            format: MeterFormat::Value {
+                decimals: 0,
+                unit: "",
+            },
+            meter: false,
         }
     }
 
     pub fn max(mut self, max: u8) -> Self {
-        self.max = max.max(1);
+        self.max = max.max(self.min.saturating_add(1));
+        self
+    }
+
+    /// Set both ends of the range
+    pub fn range(mut self, min: u8, max: u8) -> Self {
+        self.min = min.min(254);
+        self.max = max.max(self.min + 1);
+        self
+    }
+
+    /// Draw an arc from `start` to `end`, in degrees clockwise from
+    /// 12 o'clock
+    pub fn angles(mut self, start: f64, end: f64) -> Self {
+        self.start_angle = start;
+        self.end_angle = end;
+        self
+    }
+
+    /// Pick the variant by value instead of [`ProgressCircle::variant`]
+    pub fn thresholds(mut self, thresholds: Thresholds<ProgressCircleVariant>) -> Self {
+        self.thresholds = Some(thresholds);
+        self
+    }
+
+    /// How the center label shows the value (default: the raw value)
+    pub fn format(mut self, format: MeterFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Render as `role="meter"`, for measurements such as disk usage
+    /// rather than task progress
+    pub fn meter(mut self, meter: bool) -> Self {
+        self.meter = meter;
         self
     }
 
@@ -82,7 +133,11 @@ impl<'a> ProgressCircle<'a> {
             ProgressCircleSize::Xl => classes.push("sh-progress-circle--xl"),
         }
 
-        match self.variant {
+        let variant = self
+            .thresholds
+            .as_ref()
+            .map_or(self.variant, |t| t.color_for(f64::from(self.value)));
+        match variant {
             ProgressCircleVariant::Primary => classes.push("sh-progress-circle--primary"),
             ProgressCircleVariant::Secondary => classes.push("sh-progress-circle--secondary"),
             ProgressCircleVariant::Success => classes.push("sh-progress-circle--success"),
@@ -94,7 +149,17 @@ impl<'a> ProgressCircle<'a> {
     }
 
     fn percentage(&self) -> f32 {
-        (self.value as f32 / self.max as f32 * 100.0).min(100.0)
+        let span = f32::from(self.max.saturating_sub(self.min).max(1));
+        (f32::from(self.value.saturating_sub(self.min)) / span * 100.0).min(100.0)
+    }
+
+    /// The center label text
+    pub fn value_text(&self) -> String {
+        self.format.format(
+            f64::from(self.value),
+            f64::from(self.min),
+            f64::from(self.max),
+        )
     }
 }
 
@@ -102,25 +167,35 @@ impl<'a> Render for ProgressCircle<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
         let percentage = self.percentage();
+        let value_text = self.value_text();
+        // Lengths are in percent of the circle thanks to pathLength="100"
+        let sweep = (arc_sweep(self.start_angle, self.end_angle) / 360.0 * 100.0) as f32;
+        let filled = percentage * sweep / 100.0;
+        let rotate =
+            (self.start_angle != 0.0).then(|| format!("rotate({} 18 18)", self.start_angle));
 
         html! {
             div
                 class=(classes)
-                role="progressbar"
+                role=(if self.meter { "meter" } else { "progressbar" })
                 aria-valuenow=(self.value)
-                aria-valuemin="0"
+                aria-valuemin=(self.min)
                 aria-valuemax=(self.max)
+                aria-valuetext=(value_text)
                 aria-label=[self.label]
                 style=(format!("--sh-progress: {}%", percentage))
             {
-                svg class="sh-progress-circle__svg" viewBox="0 0 36 36" {
+                svg class="sh-progress-circle__svg" viewBox="0 0 36 36" aria-hidden="true" {
                     circle
                         class="sh-progress-circle__track"
                         cx="18"
                         cy="18"
                         r="16"
                         fill="none"
-                        stroke-width="3";
+                        stroke-width="3"
+                        pathLength="100"
+                        stroke-dasharray=[(sweep < 100.0).then(|| format!("{} 100", sweep))]
+                        transform=[rotate.as_deref()];
                     circle
                         class="sh-progress-circle__fill"
                         cx="18"
@@ -128,11 +203,13 @@ impl<'a> Render for ProgressCircle<'a> {
                         r="16"
                         fill="none"
                         stroke-width="3"
-                        stroke-dasharray=(format!("{} {}", percentage, 100.0 - percentage));
+                        pathLength="100"
+                        stroke-dasharray=(format!("{} 100", filled))
+                        transform=[rotate.as_deref()];
                 }
                 @if self.show_value {
                     span class="sh-progress-circle__value" {
-                        (self.value)
+                        (value_text)
                     }
                 }
             }
@@ -180,7 +257,6 @@ pub fn progress_circle_css() -> String {
 .sh-progress-circle__fill {
     stroke-linecap: round;
     transition: stroke-dasharray 0.3s ease;
-    stroke-dasharray: var(--sh-progress, 0) 100;
 }
 
 .sh-progress-circle--primary .sh-progress-circle__fill {
@@ -260,6 +336,50 @@ mod tests {
         assert!(html.contains("sh-progress-circle--success"));
     }
 
+    #[test]
+    fn test_progress_circle_range_and_format() {
+        let progress = ProgressCircle::new(60)
+            .range(20, 120)
+            .format(MeterFormat::Percent)
+            .meter(true)
+            .label("Disk usage");
+        assert!((progress.percentage() - 40.0).abs() < f32::EPSILON);
+        assert_eq!(progress.value_text(), "40%");
+
+        let html = progress.render().into_string();
+        assert!(html.contains(r#"role="meter""#));
+        assert!(html.contains(r#"aria-valuemin="20""#));
+        assert!(html.contains(r#"aria-valuemax="120""#));
+        assert!(html.contains(r#"aria-valuetext="40%""#));
+        assert!(html.contains(">40%</span>"));
+        assert!(html.contains(r#"stroke-dasharray="40 100""#));
+    }
+
+    #[test]
+    fn test_progress_circle_thresholds_and_arc() {
+        let thresholds = Thresholds::new(ProgressCircleVariant::Danger)
+            .below(70.0, ProgressCircleVariant::Success)
+            .below(90.0, ProgressCircleVariant::Warning);
+        let html = ProgressCircle::new(80)
+            .thresholds(thresholds.clone())
+            .angles(-135.0, 135.0)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-progress-circle--warning"));
+        assert!(html.contains(r#"role="progressbar""#));
+        assert!(html.contains(r#"stroke-dasharray="75 100""#));
+        assert!(html.contains(r#"stroke-dasharray="60 100""#));
+        assert!(html.contains(r#"transform="rotate(-135 18 18)""#));
+
+        let html = ProgressCircle::new(95)
+            .thresholds(thresholds)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-progress-circle--danger"));
+        assert!(!html.contains("transform="));
+        assert!(html.contains(">95</span>"));
+    }
+
     #[test]
     fn test_css_generation() {
         let css = progress_circle_css();
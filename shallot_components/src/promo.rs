@@ -0,0 +1,742 @@
+//! Promo Components - Promotional banner and coupon code input
+//!
+//! [`PromoBanner`] is dismissed by following a plain link, e.g.
+//! `/promo/dismiss?promo=summer-sale`. The handler adds the id with
+//! [`DismissedPromos::dismiss`], stores the result with
+//! [`DismissedPromos::set_cookie`] and redirects back. On later requests,
+//! pass [`DismissedPromos::from_cookie_header`] to
+//! [`PromoBanner::dismissed_promos`] and the banner renders nothing. The
+//! countdown is text computed on the server when the page is rendered.
+//!
+//! [`CouponInput`] is an inline form with Apply and Remove submit buttons;
+//! read the submission with [`CouponSubmission::from_form`].
+
+use crate::form::{Form, FormData, FormVariant};
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Cookie holding the ids of dismissed promos
+pub const PROMO_COOKIE: &str = "sh_promo_dismissed";
+
+/// Query parameter carrying the promo id on dismiss links
+pub const PROMO_DISMISS_PARAM: &str = "promo";
+
+/// Keep ids cookie-safe: ASCII letters, digits, `-` and `_`
+pub(crate) fn sanitize_id(id: &str) -> String {
+    id.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect()
+}
+
+/// Read the `.`-separated ids stored in `cookie` from a `Cookie` header
+pub(crate) fn cookie_ids(header: &str, cookie: &str) -> Vec<String> {
+    header
+        .split(';')
+        .find_map(|pair| {
+            let (name, value) = pair.trim().split_once('=')?;
+            (name == cookie).then_some(value)
+        })
+        .map(|value| {
+            value
+                .split('.')
+                .map(sanitize_id)
+                .filter(|id| !id.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `Set-Cookie` header value storing `ids` in `cookie`
+pub(crate) fn ids_set_cookie(
+    cookie: &str,
+    ids: &[String],
+    max_age_days: u32,
+    secure: bool,
+) -> String {
+    format!(
+        "{}={}; Path=/; Max-Age={}; SameSite=Lax{}",
+        cookie,
+        ids.join("."),
+        u64::from(max_age_days) * 86_400,
+        if secure { "; Secure" } else { "" }
+    )
+}
+
+/// Promo ids a visitor has dismissed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DismissedPromos {
+    pub ids: Vec<String>,
+}
+
+impl DismissedPromos {
+    /// Read the ids from a `Cookie` request header
+    pub fn from_cookie_header(header: &str) -> Self {
+        Self {
+            ids: cookie_ids(header, PROMO_COOKIE),
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        let id = sanitize_id(id);
+        self.ids.contains(&id)
+    }
+
+    /// Add `id`, ignoring duplicates
+    pub fn dismiss(mut self, id: &str) -> Self {
+        let id = sanitize_id(id);
+        if !id.is_empty() && !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+        self
+    }
+
+    /// `Set-Cookie` header value storing the ids
+    pub fn set_cookie(&self, max_age_days: u32, secure: bool) -> String {
+        ids_set_cookie(PROMO_COOKIE, &self.ids, max_age_days, secure)
+    }
+}
+
+/// Remaining time as text, e.g. "2d 4h", "3h 15m" or "45s"
+pub fn format_countdown(seconds: u64) -> String {
+    let (days, hours) = (seconds / 86_400, seconds % 86_400 / 3_600);
+    let (minutes, secs) = (seconds % 3_600 / 60, seconds % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// ISO 8601 duration for the `<time datetime>` attribute, e.g. "P2DT4H"
+fn iso_duration(seconds: u64) -> String {
+    let (days, hours) = (seconds / 86_400, seconds % 86_400 / 3_600);
+    let (minutes, secs) = (seconds % 3_600 / 60, seconds % 60);
+    let mut out = String::from("P");
+    if days > 0 {
+        out.push_str(&format!("{}D", days));
+    }
+    out.push('T');
+    if hours > 0 {
+        out.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        out.push_str(&format!("{}M", minutes));
+    }
+    if secs > 0 || out.ends_with('T') {
+        out.push_str(&format!("{}S", secs));
+    }
+    out
+}
+
+/// Visual style of a promo banner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PromoVariant {
+    #[default]
+    Primary,
+    Sale,
+    Subtle,
+}
+
+impl PromoVariant {
+    fn modifier(&self) -> &'static str {
+        match self {
+            PromoVariant::Primary => "primary",
+            PromoVariant::Sale => "sale",
+            PromoVariant::Subtle => "subtle",
+        }
+    }
+}
+
+/// Site-wide promotional banner
+pub struct PromoBanner<'a> {
+    pub id: Cow<'a, str>,
+    pub message: Cow<'a, str>,
+    pub code: Option<Cow<'a, str>>,
+    pub link: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    /// Unix timestamps for the end of the promo and the render time
+    pub ends_at: Option<u64>,
+    pub now: u64,
+    pub dismiss_action: Option<Cow<'a, str>>,
+    pub dismissed: bool,
+    pub variant: PromoVariant,
+}
+
+impl<'a> PromoBanner<'a> {
+    /// `id` identifies the promo in the dismissal cookie
+    pub fn new(id: impl Into<Cow<'a, str>>, message: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            message: message.into(),
+            code: None,
+            link: None,
+            ends_at: None,
+            now: 0,
+            dismiss_action: None,
+            dismissed: false,
+            variant: PromoVariant::default(),
+        }
+    }
+
+    /// Show a coupon code to copy
+    pub fn code(mut self, code: impl Into<Cow<'a, str>>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Call-to-action link
+    pub fn link(mut self, label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        self.link = Some((label.into(), href.into()));
+        self
+    }
+
+    /// Show a countdown to `ends_at`, both as Unix timestamps. The banner
+    /// hides itself once the promo has ended.
+    pub fn countdown(mut self, ends_at: u64, now: u64) -> Self {
+        self.ends_at = Some(ends_at);
+        self.now = now;
+        self
+    }
+
+    /// Path the dismiss link points to; `?promo={id}` is appended
+    pub fn dismiss_action(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.dismiss_action = Some(path.into());
+        self
+    }
+
+    /// Hide the banner when `dismissed` contains its id
+    pub fn dismissed_promos(mut self, dismissed: &DismissedPromos) -> Self {
+        self.dismissed = dismissed.contains(&self.id);
+        self
+    }
+
+    pub fn variant(mut self, variant: PromoVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Seconds left, or `None` without a countdown
+    pub fn remaining(&self) -> Option<u64> {
+        self.ends_at.map(|end| end.saturating_sub(self.now))
+    }
+
+    /// Whether the banner renders nothing
+    pub fn is_hidden(&self) -> bool {
+        self.dismissed || self.remaining() == Some(0)
+    }
+
+    fn dismiss_href(&self) -> Option<String> {
+        let path = self.dismiss_action.as_ref()?;
+        let separator = if path.contains('?') { '&' } else { '?' };
+        Some(format!(
+            "{}{}{}={}",
+            path,
+            separator,
+            PROMO_DISMISS_PARAM,
+            sanitize_id(&self.id)
+        ))
+    }
+}
+
+impl<'a> Render for PromoBanner<'a> {
+    fn render(&self) -> Markup {
+        if self.is_hidden() {
+            return html! {};
+        }
+
+        html! {
+            aside
+                class={ "sh-promo sh-promo--" (self.variant.modifier()) }
+                id={ "sh-promo-" (sanitize_id(&self.id)) }
+                aria-label="Promotion" {
+                p class="sh-promo__message" {
+                    (self.message)
+                    @if let Some(code) = &self.code {
+                        " "
+                        span class="sh-promo__code-label" { "Use code " }
+                        code class="sh-promo__code" { (code) }
+                    }
+                }
+                @if let Some(remaining) = self.remaining() {
+                    p class="sh-promo__countdown" {
+                        "Ends in "
+                        time datetime=(iso_duration(remaining)) { (format_countdown(remaining)) }
+                    }
+                }
+                @if let Some((label, href)) = &self.link {
+                    a class="sh-promo__link" href=(href) { (label) }
+                }
+                @if let Some(href) = self.dismiss_href() {
+                    a class="sh-promo__dismiss" href=(href) rel="nofollow" {
+                        span aria-hidden="true" { "×" }
+                        span class="sh-visually-hidden" { "Dismiss promotion" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What a [`CouponInput`] submission asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CouponSubmission {
+    Apply(String),
+    Remove,
+}
+
+impl CouponSubmission {
+    /// Field set by the Apply / Remove buttons
+    pub const ACTION_FIELD: &'static str = "coupon_action";
+
+    /// Read a submission for the coupon field `name`. Codes are trimmed and
+    /// uppercased; an empty code is `None`.
+    pub fn from_form(data: &FormData, name: &str) -> Option<Self> {
+        if data.get(Self::ACTION_FIELD) == Some("remove") {
+            return Some(CouponSubmission::Remove);
+        }
+        let code = normalize_coupon_code(data.get(name)?);
+        (!code.is_empty()).then_some(CouponSubmission::Apply(code))
+    }
+}
+
+/// Trim, drop inner whitespace and uppercase a coupon code
+pub fn normalize_coupon_code(code: &str) -> String {
+    code.split_whitespace().collect::<String>().to_uppercase()
+}
+
+/// Result of the last coupon submission
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CouponState<'a> {
+    #[default]
+    Idle,
+    /// Code accepted, with a message such as "10% off your order"
+    Applied {
+        code: Cow<'a, str>,
+        message: Cow<'a, str>,
+    },
+    /// Code rejected, with the reason
+    Invalid(Cow<'a, str>),
+}
+
+/// Inline discount code form
+pub struct CouponInput<'a> {
+    pub action: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub label: Cow<'a, str>,
+    pub placeholder: Cow<'a, str>,
+    pub value: Option<Cow<'a, str>>,
+    pub state: CouponState<'a>,
+    pub csrf: Option<Cow<'a, str>>,
+}
+
+impl<'a> CouponInput<'a> {
+    /// `action` is the URL the code is posted to
+    pub fn new(action: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            action: action.into(),
+            name: Cow::Borrowed("coupon"),
+            label: Cow::Borrowed("Discount code"),
+            placeholder: Cow::Borrowed("Enter code"),
+            value: None,
+            state: CouponState::default(),
+            csrf: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'a, str>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Pre-fill the code, e.g. after a rejected attempt
+    pub fn value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn state(mut self, state: CouponState<'a>) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub fn csrf_token(mut self, token: impl Into<Cow<'a, str>>) -> Self {
+        self.csrf = Some(token.into());
+        self
+    }
+}
+
+impl<'a> Render for CouponInput<'a> {
+    fn render(&self) -> Markup {
+        let input_id = format!("sh-coupon-{}", self.name);
+        let message_id = format!("{}-message", input_id);
+        let (modifier, invalid) = match &self.state {
+            CouponState::Idle => ("", false),
+            CouponState::Applied { .. } => (" sh-coupon--applied", false),
+            CouponState::Invalid(_) => (" sh-coupon--invalid", true),
+        };
+
+        let fields = match &self.state {
+            CouponState::Applied { code, message } => html! {
+                span class="sh-coupon__applied" {
+                    span class="sh-coupon__label" { (self.label) }
+                    code class="sh-coupon__chip" { (code) }
+                    span class="sh-coupon__message" id=(message_id) role="status" { (message) }
+                }
+                button
+                    class="sh-btn sh-btn--ghost sh-btn--sm"
+                    type="submit"
+                    name=(CouponSubmission::ACTION_FIELD)
+                    value="remove" {
+                    "Remove"
+                    span class="sh-visually-hidden" { " code " (code) }
+                }
+            },
+            state => html! {
+                label class="sh-coupon__label" for=(input_id) { (self.label) }
+                span class="sh-coupon__row" {
+                    input
+                        class="sh-coupon__input"
+                        type="text"
+                        id=(input_id)
+                        name=(self.name)
+                        value=[self.value.as_deref()]
+                        placeholder=(self.placeholder)
+                        autocomplete="off"
+                        autocapitalize="characters"
+                        spellcheck="false"
+                        aria-invalid=[invalid.then_some("true")]
+                        aria-describedby=[invalid.then_some(message_id.as_str())];
+                    button
+                        class="sh-btn sh-btn--secondary sh-btn--md"
+                        type="submit"
+                        name=(CouponSubmission::ACTION_FIELD)
+                        value="apply" {
+                        "Apply"
+                    }
+                }
+                @if let CouponState::Invalid(reason) = state {
+                    span class="sh-coupon__message" id=(message_id) role="alert" { (reason) }
+                }
+            },
+        };
+
+        let class = format!("sh-coupon{}", modifier);
+        let mut form = Form::new()
+            .action(&self.action)
+            .method("post")
+            .variant(FormVariant::Inline)
+            .class(&class)
+            .fields(fields);
+        if let Some(token) = &self.csrf {
+            form = form.csrf_token(token);
+        }
+        form.render()
+    }
+}
+
+/// Generate CSS for promo components
+pub fn promo_css() -> String {
+    r#"
+.sh-promo {
+    position: relative;
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: center;
+    gap: 0.5rem 1rem;
+    padding: 0.625rem 3rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 0.875rem;
+    text-align: center;
+}
+
+.sh-promo--primary {
+    background: var(--sh-primary, #3b82f6);
+    color: #fff;
+}
+
+.sh-promo--sale {
+    background: var(--sh-error, #ef4444);
+    color: #fff;
+}
+
+.sh-promo--subtle {
+    background: var(--sh-surface-alt, #f3f4f6);
+    color: var(--sh-text, #1f2937);
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+}
+
+.sh-promo__message,
+.sh-promo__countdown {
+    margin: 0;
+}
+
+.sh-promo__code {
+    padding: 0.125rem 0.5rem;
+    border: 1px dashed currentColor;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font-family: var(--sh-font-mono, ui-monospace, monospace);
+    font-weight: 600;
+    letter-spacing: 0.05em;
+    user-select: all;
+}
+
+.sh-promo__countdown {
+    opacity: 0.85;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-promo__link {
+    color: inherit;
+    font-weight: 600;
+    text-decoration: underline;
+    text-underline-offset: 0.2em;
+}
+
+.sh-promo__dismiss {
+    position: absolute;
+    top: 50%;
+    right: 0.75rem;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    width: 2rem;
+    height: 2rem;
+    margin-top: -1rem;
+    border-radius: 9999px;
+    color: inherit;
+    font-size: 1.25rem;
+    line-height: 1;
+    text-decoration: none;
+}
+
+.sh-promo__dismiss:hover,
+.sh-promo__dismiss:focus-visible {
+    background: rgba(0, 0, 0, 0.1);
+}
+
+/* Coupon input */
+.sh-coupon {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-coupon__label {
+    font-size: 0.875rem;
+    font-weight: 500;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-coupon__row {
+    display: flex;
+    gap: 0.5rem;
+}
+
+.sh-coupon__input {
+    flex: 1;
+    min-width: 0;
+    height: 2.5rem;
+    padding: 0 0.75rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    color: var(--sh-text, #1f2937);
+    font-size: 0.9375rem;
+    text-transform: uppercase;
+    letter-spacing: 0.05em;
+}
+
+.sh-coupon__input::placeholder {
+    text-transform: none;
+    letter-spacing: normal;
+}
+
+.sh-coupon__input:focus {
+    outline: none;
+    border-color: var(--sh-primary, #3b82f6);
+    box-shadow: 0 0 0 3px rgba(59, 130, 246, 0.1);
+}
+
+.sh-coupon--invalid .sh-coupon__input {
+    border-color: var(--sh-error, #ef4444);
+}
+
+.sh-coupon__message {
+    font-size: 0.8125rem;
+}
+
+.sh-coupon--invalid .sh-coupon__message {
+    color: var(--sh-error, #ef4444);
+}
+
+.sh-coupon--applied {
+    flex-direction: row;
+    align-items: center;
+    justify-content: space-between;
+}
+
+.sh-coupon__applied {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-coupon__chip {
+    padding: 0.125rem 0.5rem;
+    border-radius: 9999px;
+    background: var(--sh-success, #10b981);
+    color: #fff;
+    font-size: 0.8125rem;
+    font-weight: 600;
+    letter-spacing: 0.05em;
+}
+
+.sh-coupon--applied .sh-coupon__message {
+    color: var(--sh-success, #10b981);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dismissed_promos_cookie() {
+        let dismissed = DismissedPromos::from_cookie_header(
+            "theme=dark; sh_promo_dismissed=spring.summer-sale",
+        );
+        assert!(dismissed.contains("summer-sale"));
+        assert!(!dismissed.contains("winter"));
+
+        let updated = dismissed.dismiss("winter").dismiss("spring");
+        assert_eq!(updated.ids, vec!["spring", "summer-sale", "winter"]);
+        assert_eq!(
+            updated.set_cookie(30, true),
+            "sh_promo_dismissed=spring.summer-sale.winter; Path=/; Max-Age=2592000; SameSite=Lax; Secure"
+        );
+        // Unsafe characters never reach the cookie
+        assert_eq!(DismissedPromos::default().dismiss("a;b=c").ids, vec!["abc"]);
+        assert!(DismissedPromos::from_cookie_header("other=1")
+            .ids
+            .is_empty());
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(2 * 86_400 + 4 * 3_600 + 59), "2d 4h");
+        assert_eq!(format_countdown(3 * 3_600 + 15 * 60), "3h 15m");
+        assert_eq!(format_countdown(600), "10m");
+        assert_eq!(format_countdown(45), "45s");
+        assert_eq!(iso_duration(2 * 86_400 + 4 * 3_600), "P2DT4H");
+        assert_eq!(iso_duration(0), "PT0S");
+    }
+
+    #[test]
+    fn test_promo_banner_render() {
+        let html = PromoBanner::new("summer-sale", "20% off everything")
+            .code("SUMMER20")
+            .link("Shop now", "/sale")
+            .countdown(10_000, 10_000 - 3_700)
+            .dismiss_action("/promo/dismiss")
+            .variant(PromoVariant::Sale)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-promo--sale"));
+        assert!(html.contains(r#"<code class="sh-promo__code">SUMMER20</code>"#));
+        assert!(html.contains(r#"<time datetime="PT1H1M40S">1h 1m</time>"#));
+        assert!(html.contains(r#"href="/promo/dismiss?promo=summer-sale""#));
+        assert!(html.contains(r#"href="/sale""#));
+    }
+
+    #[test]
+    fn test_promo_banner_hidden() {
+        let dismissed = DismissedPromos::default().dismiss("summer-sale");
+        let banner = PromoBanner::new("summer-sale", "Sale").dismissed_promos(&dismissed);
+        assert!(banner.is_hidden());
+        assert_eq!(banner.render().into_string(), "");
+
+        let expired = PromoBanner::new("flash", "Flash sale").countdown(100, 200);
+        assert!(expired.is_hidden());
+
+        let html = PromoBanner::new("x", "Hi")
+            .dismiss_action("/dismiss?return_to=/shop")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"href="/dismiss?return_to=/shop&amp;promo=x""#));
+    }
+
+    #[test]
+    fn test_coupon_submission() {
+        let apply = FormData::from_pairs([("coupon", " save 20 "), ("coupon_action", "apply")]);
+        assert_eq!(
+            CouponSubmission::from_form(&apply, "coupon"),
+            Some(CouponSubmission::Apply("SAVE20".to_string()))
+        );
+        let remove = FormData::from_pairs([("coupon_action", "remove")]);
+        assert_eq!(
+            CouponSubmission::from_form(&remove, "coupon"),
+            Some(CouponSubmission::Remove)
+        );
+        let empty = FormData::from_pairs([("coupon", "  ")]);
+        assert_eq!(CouponSubmission::from_form(&empty, "coupon"), None);
+    }
+
+    #[test]
+    fn test_coupon_input_states() {
+        let idle = CouponInput::new("/cart/coupon")
+            .csrf_token("tok")
+            .render()
+            .into_string();
+        assert!(idle.contains(r#"action="/cart/coupon""#));
+        assert!(idle.contains(r#"name="csrf_token" value="tok""#));
+        assert!(idle.contains(r#"name="coupon_action" value="apply""#));
+        assert!(!idle.contains("aria-invalid"));
+
+        let invalid = CouponInput::new("/cart/coupon")
+            .value("NOPE")
+            .state(CouponState::Invalid("This code has expired".into()))
+            .render()
+            .into_string();
+        assert!(invalid.contains("sh-coupon--invalid"));
+        assert!(invalid.contains(r#"value="NOPE""#));
+        assert!(invalid.contains(r#"aria-describedby="sh-coupon-coupon-message""#));
+        assert!(invalid.contains(r#"role="alert">This code has expired"#));
+
+        let applied = CouponInput::new("/cart/coupon")
+            .state(CouponState::Applied {
+                code: "SAVE20".into(),
+                message: "20% off your order".into(),
+            })
+            .render()
+            .into_string();
+        assert!(applied.contains("sh-coupon--applied"));
+        assert!(applied.contains(r#"<code class="sh-coupon__chip">SAVE20</code>"#));
+        assert!(applied.contains(r#"value="remove""#));
+        assert!(!applied.contains(r#"type="text""#));
+    }
+
+    #[test]
+    fn test_promo_css() {
+        let css = promo_css();
+        assert!(css.contains(".sh-promo__dismiss"));
+        assert!(css.contains(".sh-coupon--applied"));
+    }
+}
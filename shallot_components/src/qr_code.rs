@@ -0,0 +1,959 @@
+//! QR Code Component - Inline SVG QR codes
+//!
+//! Encodes text in byte mode (versions 1-40) with selectable Reed-Solomon
+//! error correction and draws the modules as a single SVG path. Renders
+//! server-side with no JavaScript, scales crisply and prints cleanly, which
+//! makes it suitable for payment links, tickets and printed handouts.
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Error correction level, from ~7% to ~30% recoverable damage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub enum QrEcc {
+    Low,
+    #[default]
+    Medium,
+    Quartile,
+    High,
+}
+
+impl QrEcc {
+    fn ordinal(self) -> usize {
+        match self {
+            QrEcc::Low => 0,
+            QrEcc::Medium => 1,
+            QrEcc::Quartile => 2,
+            QrEcc::High => 3,
+        }
+    }
+
+    fn format_bits(self) -> u32 {
+        match self {
+            QrEcc::Low => 1,
+            QrEcc::Medium => 0,
+            QrEcc::Quartile => 3,
+            QrEcc::High => 2,
+        }
+    }
+}
+
+/// Reason a payload could not be encoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QrError {
+    /// The payload exceeds the byte capacity of a version 40 symbol
+    DataTooLong { len: usize, max: usize },
+}
+
+impl std::fmt::Display for QrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DataTooLong { len, max } => {
+                write!(f, "QR payload is {} bytes, at most {} fit", len, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for QrError {}
+
+pub const MIN_VERSION: usize = 1;
+pub const MAX_VERSION: usize = 40;
+
+// Indexed by [ecc ordinal][version]; version 0 is unused
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// Modules available for data and ECC codewords in a version
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize, ecc: QrEcc) -> usize {
+    let e = ecc.ordinal();
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[e][version] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[e][version] as usize
+}
+
+fn char_count_bits(version: usize) -> usize {
+    if version <= 9 {
+        8
+    } else {
+        16
+    }
+}
+
+/// Largest byte-mode payload a version holds at a given level
+pub fn byte_capacity(version: usize, ecc: QrEcc) -> usize {
+    (num_data_codewords(version, ecc) * 8 - 4 - char_count_bits(version)) / 8
+}
+
+/// Multiply in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_multiply(d, factor);
+        }
+    }
+    result
+}
+
+/// Big-endian bit buffer for the data segment
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+    fn push(&mut self, value: u32, len: usize) {
+        for i in (0..len).rev() {
+            self.0.push((value >> i) & 1 != 0);
+        }
+    }
+}
+
+fn bit(value: u32, i: usize) -> bool {
+    (value >> i) & 1 != 0
+}
+
+/// An encoded QR symbol: a square grid of dark/light modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrMatrix {
+    version: usize,
+    size: usize,
+    ecc: QrEcc,
+    mask: u8,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl QrMatrix {
+    /// Encode bytes with the smallest version that fits at `ecc`
+    pub fn encode(data: &[u8], ecc: QrEcc) -> Result<Self, QrError> {
+        let version = (MIN_VERSION..=MAX_VERSION)
+            .find(|&v| data.len() <= byte_capacity(v, ecc))
+            .ok_or(QrError::DataTooLong {
+                len: data.len(),
+                max: byte_capacity(MAX_VERSION, ecc),
+            })?;
+
+        let mut bits = BitBuffer(Vec::new());
+        bits.push(0b0100, 4);
+        bits.push(data.len() as u32, char_count_bits(version));
+        for &byte in data {
+            bits.push(byte as u32, 8);
+        }
+
+        let capacity = num_data_codewords(version, ecc) * 8;
+        let terminator = (capacity - bits.0.len()).min(4);
+        bits.push(0, terminator);
+        bits.push(0, (8 - bits.0.len() % 8) % 8);
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if bits.0.len() >= capacity {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let codewords: Vec<u8> = bits
+            .0
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0u8, |acc, &b| (acc << 1) | b as u8))
+            .collect();
+
+        let size = version * 4 + 17;
+        let mut qr = Self {
+            version,
+            size,
+            ecc,
+            mask: 0,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        };
+        qr.draw_function_patterns();
+        let all_codewords = qr.add_ecc_and_interleave(&codewords);
+        qr.draw_codewords(&all_codewords);
+
+        let mut best = (0u8, i32::MAX);
+        for mask in 0..8 {
+            qr.apply_mask(mask);
+            qr.draw_format_bits(mask);
+            let penalty = qr.penalty_score();
+            if penalty < best.1 {
+                best = (mask, penalty);
+            }
+            qr.apply_mask(mask);
+        }
+        qr.mask = best.0;
+        qr.apply_mask(best.0);
+        qr.draw_format_bits(best.0);
+        Ok(qr)
+    }
+
+    /// Encode text as UTF-8 bytes
+    pub fn encode_text(text: &str, ecc: QrEcc) -> Result<Self, QrError> {
+        Self::encode(text.as_bytes(), ecc)
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Side length in modules, excluding the quiet zone
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn ecc(&self) -> QrEcc {
+        self.ecc
+    }
+
+    pub fn mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// Whether the module at column `x`, row `y` is dark
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let i = y * self.size + x;
+        self.modules[i] = dark;
+        self.is_function[i] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        for i in 0..self.size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        let far = self.size - 4;
+        self.draw_finder(3, 3);
+        self.draw_finder(far, 3);
+        self.draw_finder(3, far);
+
+        let positions = self.alignment_positions();
+        let n = positions.len();
+        for (i, &y) in positions.iter().enumerate() {
+            for (j, &x) in positions.iter().enumerate() {
+                let overlaps_finder = (i == 0 && (j == 0 || j == n - 1)) || (i == n - 1 && j == 0);
+                if !overlaps_finder {
+                    self.draw_alignment(x, y);
+                }
+            }
+        }
+
+        // Reserve the format areas; the real bits are drawn after masking
+        self.draw_format_bits(0);
+        self.draw_version();
+    }
+
+    fn draw_finder(&mut self, cx: usize, cy: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (x, y) = (cx as i32 + dx, cy as i32 + dy);
+                if (0..self.size as i32).contains(&x) && (0..self.size as i32).contains(&y) {
+                    let dist = dx.abs().max(dy.abs());
+                    self.set_function(x as usize, y as usize, dist != 2 && dist != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, cx: usize, cy: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function((cx as i32 + dx) as usize, (cy as i32 + dy) as usize, dark);
+            }
+        }
+    }
+
+    fn alignment_positions(&self) -> Vec<usize> {
+        if self.version == 1 {
+            return Vec::new();
+        }
+        let num_align = self.version / 7 + 2;
+        let step = (self.version * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+        let mut result: Vec<usize> = (0..num_align - 1)
+            .map(|i| self.size - 7 - i * step)
+            .collect();
+        result.push(6);
+        result.reverse();
+        result
+    }
+
+    fn draw_format_bits(&mut self, mask: u8) {
+        let data = (self.ecc.format_bits() << 3) | mask as u32;
+        let mut rem = data;
+        for _ in 0..10 {
+            rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | rem) ^ 0x5412;
+
+        for i in 0..=5 {
+            self.set_function(8, i, bit(bits, i));
+        }
+        self.set_function(8, 7, bit(bits, 6));
+        self.set_function(8, 8, bit(bits, 7));
+        self.set_function(7, 8, bit(bits, 8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(bits, i));
+        }
+
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(bits, i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(bits, i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let mut rem = self.version as u32;
+        for _ in 0..12 {
+            rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+        }
+        let bits = ((self.version as u32) << 12) | rem;
+        for i in 0..18 {
+            let (a, b) = (self.size - 11 + i % 3, i / 3);
+            self.set_function(a, b, bit(bits, i));
+            self.set_function(b, a, bit(bits, i));
+        }
+    }
+
+    fn add_ecc_and_interleave(&self, data: &[u8]) -> Vec<u8> {
+        let e = self.ecc.ordinal();
+        let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[e][self.version] as usize;
+        let block_ecc_len = ECC_CODEWORDS_PER_BLOCK[e][self.version] as usize;
+        let raw_codewords = num_raw_data_modules(self.version) / 8;
+        let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+        let short_block_len = raw_codewords / num_blocks;
+
+        let divisor = reed_solomon_divisor(block_ecc_len);
+        let mut blocks = Vec::with_capacity(num_blocks);
+        let mut k = 0;
+        for i in 0..num_blocks {
+            let len = short_block_len - block_ecc_len + usize::from(i >= num_short_blocks);
+            let mut block = data[k..k + len].to_vec();
+            k += len;
+            let ecc = reed_solomon_remainder(&block, &divisor);
+            if i < num_short_blocks {
+                block.push(0);
+            }
+            block.extend(ecc);
+            blocks.push(block);
+        }
+
+        let mut result = Vec::with_capacity(raw_codewords);
+        for i in 0..blocks[0].len() {
+            for (j, block) in blocks.iter().enumerate() {
+                // Skip the padding byte of short blocks
+                if i != short_block_len - block_ecc_len || j >= num_short_blocks {
+                    result.push(block[i]);
+                }
+            }
+        }
+        result
+    }
+
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let mut i = 0;
+        let mut right = self.size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..self.size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { self.size - 1 - vert } else { vert };
+                    let idx = y * self.size + x;
+                    if !self.is_function[idx] && i < data.len() * 8 {
+                        self.modules[idx] = (data[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let idx = y * self.size + x;
+                if invert && !self.is_function[idx] {
+                    self.modules[idx] = !self.modules[idx];
+                }
+            }
+        }
+    }
+
+    fn penalty_score(&self) -> i32 {
+        const FINDER_LIKE: [bool; 7] = [true, false, true, true, true, false, true];
+        let size = self.size;
+        let mut penalty = 0;
+
+        // Rows and columns: long runs and finder-like patterns
+        for transpose in [false, true] {
+            let at = |a: usize, b: usize| {
+                if transpose {
+                    self.get(a, b)
+                } else {
+                    self.get(b, a)
+                }
+            };
+            let light = |a: usize, b: Option<usize>| b.is_none_or(|b| b >= size || !at(a, b));
+            for line in 0..size {
+                let mut run = 1;
+                for i in 1..=size {
+                    if i < size && at(line, i) == at(line, i - 1) {
+                        run += 1;
+                        continue;
+                    }
+                    if run >= 5 {
+                        penalty += run - 2;
+                    }
+                    run = 1;
+                }
+
+                for start in 0..=size - 7 {
+                    if (0..7).any(|k| at(line, start + k) != FINDER_LIKE[k]) {
+                        continue;
+                    }
+                    let before = (1..=4).all(|k| light(line, start.checked_sub(k)));
+                    let after = (0..4).all(|k| light(line, Some(start + 7 + k)));
+                    penalty += 40 * (i32::from(before) + i32::from(after));
+                }
+            }
+        }
+
+        // 2x2 blocks of one color
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = self.get(x, y);
+                if c == self.get(x + 1, y) && c == self.get(x, y + 1) && c == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Balance of dark and light modules
+        let total = (size * size) as i32;
+        let dark = self.modules.iter().filter(|&&m| m).count() as i32;
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        penalty + k * 10
+    }
+}
+
+/// Inline SVG QR code with an optional centered logo
+#[derive(Debug, Clone)]
+pub struct QrCode<'a> {
+    data: Cow<'a, str>,
+    ecc: QrEcc,
+    size: Cow<'a, str>,
+    color: Cow<'a, str>,
+    background: Cow<'a, str>,
+    quiet_zone: usize,
+    logo: Option<Cow<'a, str>>,
+    label: Option<Cow<'a, str>>,
+    custom_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> QrCode<'a> {
+    /// Create a QR code for a URL or other text payload
+    pub fn new(data: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            data: data.into(),
+            ecc: QrEcc::default(),
+            size: Cow::Borrowed("12rem"),
+            color: Cow::Borrowed("#000000"),
+            background: Cow::Borrowed("#ffffff"),
+            quiet_zone: 4,
+            logo: None,
+            label: None,
+            custom_class: None,
+        }
+    }
+
+    pub fn ecc(mut self, ecc: QrEcc) -> Self {
+        self.ecc = ecc;
+        self
+    }
+
+    /// Rendered width as a CSS length
+    pub fn size(mut self, size: impl Into<Cow<'a, str>>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Dark module color
+    pub fn color(mut self, color: impl Into<Cow<'a, str>>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Light module and quiet zone color
+    pub fn background(mut self, background: impl Into<Cow<'a, str>>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Light border in modules (the spec asks for 4)
+    pub fn quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    /// Centered logo image; raises error correction to at least Quartile
+    pub fn logo(mut self, src: impl Into<Cow<'a, str>>) -> Self {
+        self.logo = Some(src.into());
+        self
+    }
+
+    /// Accessible name (defaults to the encoded text)
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn custom_class(mut self, class: impl Into<Cow<'a, str>>) -> Self {
+        self.custom_class = Some(class.into());
+        self
+    }
+
+    fn effective_ecc(&self) -> QrEcc {
+        if self.logo.is_some() {
+            self.ecc.max(QrEcc::Quartile)
+        } else {
+            self.ecc
+        }
+    }
+
+    /// Encode the payload
+    pub fn matrix(&self) -> Result<QrMatrix, QrError> {
+        QrMatrix::encode_text(&self.data, self.effective_ecc())
+    }
+
+    /// Logo box as (offset, side) in symbol modules, kept under ~5% of the area
+    fn logo_box(&self, size: usize) -> Option<(usize, usize)> {
+        self.logo.as_ref()?;
+        let mut side = (size * 2 / 9).max(3);
+        if side % 2 != size % 2 {
+            side += 1;
+        }
+        Some(((size - side) / 2, side))
+    }
+
+    fn path_data(&self, qr: &QrMatrix) -> String {
+        let q = self.quiet_zone;
+        let hole = self.logo_box(qr.size());
+        let covered = |x: usize, y: usize| {
+            hole.is_some_and(|(o, s)| (o..o + s).contains(&x) && (o..o + s).contains(&y))
+        };
+
+        let mut d = String::new();
+        for y in 0..qr.size() {
+            let mut x = 0;
+            while x < qr.size() {
+                if !qr.get(x, y) || covered(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < qr.size() && qr.get(x, y) && !covered(x, y) {
+                    x += 1;
+                }
+                let run = x - start;
+                let _ = write!(d, "M{} {}h{}v1h-{}z", start + q, y + q, run, run);
+            }
+        }
+        d
+    }
+
+    fn svg(&self, qr: &QrMatrix) -> Markup {
+        let q = self.quiet_zone;
+        let total = qr.size() + 2 * q;
+        let view_box = format!("0 0 {0} {0}", total);
+        let label = self.label.as_deref().unwrap_or(&self.data);
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                class="sh-qr__svg"
+                viewBox=(view_box)
+                role="img"
+                aria-label=(label)
+                shape-rendering="crispEdges"
+            {
+                rect width=(total) height=(total) fill=(self.background) {}
+                path class="sh-qr__modules" d=(self.path_data(qr)) fill=(self.color) {}
+                @if let (Some(src), Some((offset, side))) = (&self.logo, self.logo_box(qr.size())) {
+                    image
+                        class="sh-qr__logo"
+                        href=(src)
+                        x=(offset + q)
+                        y=(offset + q)
+                        width=(side)
+                        height=(side)
+                        preserveAspectRatio="xMidYMid meet"
+                    {}
+                }
+            }
+        }
+    }
+
+    /// Standalone SVG document for print or download
+    pub fn to_svg(&self) -> Result<String, QrError> {
+        Ok(self.svg(&self.matrix()?).into_string())
+    }
+}
+
+impl<'a> Render for QrCode<'a> {
+    fn render(&self) -> Markup {
+        let mut classes = vec!["sh-qr"];
+        if let Some(class) = &self.custom_class {
+            classes.push(class);
+        }
+        let style = format!("--sh-qr-size: {}", self.size);
+
+        match self.matrix() {
+            Ok(qr) => html! {
+                div class=(classes.join(" ")) style=(style) {
+                    (self.svg(&qr))
+                }
+            },
+            // Too long to encode: still hand the reader the payload
+            Err(_) => html! {
+                div class={ (classes.join(" ")) " sh-qr--fallback" } style=(style) {
+                    code class="sh-qr__text" { (self.data) }
+                }
+            },
+        }
+    }
+}
+
+pub fn qr_code_css() -> String {
+    r#"
+.sh-qr {
+    display: inline-block;
+    width: var(--sh-qr-size, 12rem);
+    max-width: 100%;
+    line-height: 0;
+}
+
+.sh-qr__svg {
+    display: block;
+    width: 100%;
+    height: auto;
+}
+
+.sh-qr__logo {
+    pointer-events: none;
+}
+
+.sh-qr--fallback {
+    line-height: 1.4;
+    padding: 0.75rem;
+    border: 1px dashed var(--sh-color-border, #d1d5db);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    overflow-wrap: anywhere;
+}
+
+.sh-qr__text {
+    font-family: var(--sh-font-mono, ui-monospace, monospace);
+    font-size: 0.75rem;
+}
+
+@media print {
+    .sh-qr {
+        break-inside: avoid;
+    }
+
+    .sh-qr__svg {
+        -webkit-print-color-adjust: exact;
+        print-color-adjust: exact;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Read the 15-bit format word back from the top-left copy
+    fn read_format(qr: &QrMatrix) -> u32 {
+        let mut bits = 0u32;
+        let mut set = |i: usize, dark: bool| bits |= (dark as u32) << i;
+        for i in 0..=5 {
+            set(i, qr.get(8, i));
+        }
+        set(6, qr.get(8, 7));
+        set(7, qr.get(8, 8));
+        set(8, qr.get(7, 8));
+        for i in 9..15 {
+            set(i, qr.get(14 - i, 8));
+        }
+        bits
+    }
+
+    #[test]
+    fn test_byte_capacities() {
+        assert_eq!(byte_capacity(1, QrEcc::Low), 17);
+        assert_eq!(byte_capacity(1, QrEcc::Medium), 14);
+        assert_eq!(byte_capacity(1, QrEcc::Quartile), 11);
+        assert_eq!(byte_capacity(1, QrEcc::High), 7);
+        assert_eq!(byte_capacity(40, QrEcc::Low), 2953);
+        assert_eq!(byte_capacity(40, QrEcc::Medium), 2331);
+        assert_eq!(byte_capacity(40, QrEcc::Quartile), 1663);
+        assert_eq!(byte_capacity(40, QrEcc::High), 1273);
+        assert_eq!(num_raw_data_modules(40) / 8, 3706);
+    }
+
+    #[test]
+    fn test_reed_solomon_codeword_has_zero_syndromes() {
+        let data = b"shallot";
+        let divisor = reed_solomon_divisor(10);
+        let mut codeword = data.to_vec();
+        codeword.extend(reed_solomon_remainder(data, &divisor));
+
+        // Evaluate at the generator roots 2^0..2^9 with Horner's rule
+        let mut root = 1u8;
+        for _ in 0..10 {
+            let value = codeword
+                .iter()
+                .fold(0u8, |acc, &c| gf_multiply(acc, root) ^ c);
+            assert_eq!(value, 0);
+            root = gf_multiply(root, 2);
+        }
+    }
+
+    #[test]
+    fn test_version_selection_and_size() {
+        let qr = QrMatrix::encode_text("HELLO WORLD", QrEcc::Medium).unwrap();
+        assert_eq!(qr.version(), 1);
+        assert_eq!(qr.size(), 21);
+
+        let long = "x".repeat(100);
+        let qr = QrMatrix::encode_text(&long, QrEcc::High).unwrap();
+        assert_eq!(qr.version(), 10);
+        assert_eq!(qr.size(), 57);
+    }
+
+    #[test]
+    fn test_data_too_long() {
+        assert!(QrMatrix::encode(&[b'a'; 2953], QrEcc::Low).is_ok());
+        let err = QrMatrix::encode(&[b'a'; 1274], QrEcc::High).unwrap_err();
+        assert_eq!(
+            err,
+            QrError::DataTooLong {
+                len: 1274,
+                max: 1273
+            }
+        );
+        assert!(err.to_string().contains("1273"));
+    }
+
+    #[test]
+    fn test_function_patterns() {
+        let qr = QrMatrix::encode_text("https://shallot.rs/pay?id=42", QrEcc::Quartile).unwrap();
+        let far = qr.size() - 7;
+        for (ox, oy) in [(0, 0), (far, 0), (0, far)] {
+            assert!(qr.get(ox, oy) && qr.get(ox + 6, oy + 6));
+            assert!(!qr.get(ox + 1, oy + 1));
+            assert!(qr.get(ox + 3, oy + 3));
+        }
+        // Timing pattern and the always-dark module
+        assert!(qr.get(8, 6) && !qr.get(9, 6) && qr.get(10, 6));
+        assert!(qr.get(8, qr.size() - 8));
+    }
+
+    #[test]
+    fn test_format_bits_encode_level_and_mask() {
+        for ecc in [QrEcc::Low, QrEcc::Medium, QrEcc::Quartile, QrEcc::High] {
+            let qr = QrMatrix::encode_text("format", ecc).unwrap();
+            let word = read_format(&qr) ^ 0x5412;
+            assert_eq!(word >> 13, ecc.format_bits());
+            assert_eq!((word >> 10) & 0b111, qr.mask() as u32);
+
+            let mut rem = word >> 10;
+            for _ in 0..10 {
+                rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+            }
+            assert_eq!(rem, word & 0x3FF);
+        }
+    }
+
+    #[test]
+    fn test_version_information_block() {
+        let qr = QrMatrix::encode(&[0u8; 200], QrEcc::Low).unwrap();
+        assert!(qr.version() >= 7);
+        let size = qr.size();
+        for i in 0..18 {
+            let (a, b) = (size - 11 + i % 3, i / 3);
+            assert_eq!(qr.get(a, b), qr.get(b, a));
+        }
+    }
+
+    #[test]
+    fn test_qr_code_render() {
+        let html = QrCode::new("https://shallot.rs")
+            .size("160px")
+            .color("#1e1b4b")
+            .background("#fefce8")
+            .label("Open shallot.rs")
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"class="sh-qr""#));
+        assert!(html.contains("--sh-qr-size: 160px"));
+        assert!(html.contains(r#"viewBox="0 0 33 33""#));
+        assert!(html.contains(r##"fill="#1e1b4b""##));
+        assert!(html.contains(r##"fill="#fefce8""##));
+        assert!(html.contains(r#"aria-label="Open shallot.rs""#));
+        assert!(html.contains(r#"shape-rendering="crispEdges""#));
+        assert!(html.contains("M4 4h7v1h-7z"));
+    }
+
+    #[test]
+    fn test_quiet_zone() {
+        let svg = QrCode::new("a").quiet_zone(0).to_svg().unwrap();
+        assert!(svg.contains(r#"viewBox="0 0 21 21""#));
+        assert!(svg.contains("M0 0h7v1h-7z"));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_logo_raises_ecc_and_clears_center() {
+        let code = QrCode::new("https://shallot.rs/tickets/123")
+            .ecc(QrEcc::Low)
+            .logo("/logo.svg");
+        let qr = code.matrix().unwrap();
+        assert_eq!(qr.ecc(), QrEcc::Quartile);
+
+        let (offset, side) = code.logo_box(qr.size()).unwrap();
+        assert_eq!(qr.size() % 2, side % 2);
+        let svg = code.to_svg().unwrap();
+        assert!(svg.contains(r#"href="/logo.svg""#));
+        assert!(svg.contains(&format!(r#"x="{}""#, offset + 4)));
+        assert!(!code.path_data(&qr).contains(&format!(
+            "M{} {}h",
+            qr.size() / 2 + 4,
+            qr.size() / 2 + 4
+        )));
+
+        let high = QrCode::new("x").ecc(QrEcc::High).logo("/l.png");
+        assert_eq!(high.matrix().unwrap().ecc(), QrEcc::High);
+    }
+
+    #[test]
+    fn test_fallback_when_too_long() {
+        let long = "a".repeat(3000);
+        let html = QrCode::new(long.as_str()).render().into_string();
+        assert!(html.contains("sh-qr--fallback"));
+        assert!(html.contains("<code"));
+        assert!(!html.contains("<svg"));
+    }
+
+    #[test]
+    fn test_css() {
+        let css = qr_code_css();
+        assert!(css.contains(".sh-qr"));
+        assert!(css.contains("print-color-adjust"));
+    }
+}
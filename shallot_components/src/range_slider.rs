@@ -1,7 +1,14 @@
 //! Range Slider Component - Dual-thumb slider for selecting a range of values
 //! Uses native HTML5 range inputs styled with CSS custom properties
 //! Zero JavaScript - CSS-only interaction
-
+//!
+//! Two full-width range inputs overlap on one track; only their thumbs take
+//! pointer events. They submit as `{name}_min` and `{name}_max`, e.g.
+//! `price_min` and `price_max`, which [`RangeSlider::selection_from_form`]
+//! reads back snapped and ordered. The value outputs show the values the
+//! page was rendered with.
+
+use crate::form::FormData;
 use maud::{html, Markup, Render};
 
 /// Size variants for the range slider
@@ -49,6 +56,26 @@ pub struct RangeSlider<'a> {
     pub show_values: bool,
     /// Disabled state
     pub disabled: bool,
+    /// Form field prefix; defaults to the id
+    pub name: Option<&'a str>,
+    /// Tick mark values, also offered through a `<datalist>`
+    pub ticks: Vec<i32>,
+    /// Show the tick values under the track
+    pub tick_labels: bool,
+    /// Snap submitted values to the nearest tick instead of the step
+    pub snap_to_ticks: bool,
+    /// Position the value outputs above the thumbs
+    pub bubbles: bool,
+    /// Text around displayed values, e.g. "$" or " kg"
+    pub prefix: &'a str,
+    pub suffix: &'a str,
+}
+
+/// A submitted low/high pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeSelection {
+    pub low: i32,
+    pub high: i32,
 }
 
 impl<'a> RangeSlider<'a> {
@@ -66,6 +93,13 @@ impl<'a> RangeSlider<'a> {
             label: None,
             show_values: true,
             disabled: false,
+            name: None,
+            ticks: Vec::new(),
+            tick_labels: false,
+            snap_to_ticks: false,
+            bubbles: false,
+            prefix: "",
+            suffix: "",
         }
     }
 
@@ -129,6 +163,97 @@ impl<'a> RangeSlider<'a> {
         self
     }
 
+    /// Set the form field prefix, e.g. "price" for `price_min`/`price_max`
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set tick mark values
+    pub fn ticks(mut self, ticks: Vec<i32>) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Put a tick every `interval` from min to max
+    pub fn tick_every(mut self, interval: i32) -> Self {
+        let interval = interval.max(1) as usize;
+        self.ticks = (self.min..=self.max).step_by(interval).collect();
+        self
+    }
+
+    /// Show tick values under the track
+    pub fn tick_labels(mut self, show: bool) -> Self {
+        self.tick_labels = show;
+        self
+    }
+
+    /// Snap submitted values to the nearest tick
+    pub fn snap_to_ticks(mut self, snap: bool) -> Self {
+        self.snap_to_ticks = snap;
+        self
+    }
+
+    /// Show the values as bubbles above the thumbs
+    pub fn bubbles(mut self, bubbles: bool) -> Self {
+        self.bubbles = bubbles;
+        self
+    }
+
+    /// Set text shown before and after displayed values
+    pub fn affixes(mut self, prefix: &'a str, suffix: &'a str) -> Self {
+        self.prefix = prefix;
+        self.suffix = suffix;
+        self
+    }
+
+    /// Field names of the two inputs
+    pub fn field_names(&self) -> (String, String) {
+        let name = self.name.unwrap_or(self.id);
+        (format!("{}_min", name), format!("{}_max", name))
+    }
+
+    /// Clamp `value` to the range and snap it to the step or nearest tick
+    pub fn snap(&self, value: i32) -> i32 {
+        let value = value.clamp(self.min, self.max);
+        if self.snap_to_ticks && !self.ticks.is_empty() {
+            return self
+                .ticks
+                .iter()
+                .copied()
+                .min_by_key(|tick| (i64::from(*tick) - i64::from(value)).abs())
+                .unwrap_or(value);
+        }
+        let step = self.step.max(1);
+        let snapped = self.min + ((value - self.min) as f64 / step as f64).round() as i32 * step;
+        if snapped > self.max {
+            snapped - step
+        } else {
+            snapped
+        }
+    }
+
+    /// Read both values from a submission, snapped and swapped if the
+    /// thumbs crossed; missing or invalid fields fall back to min and max
+    pub fn selection_from_form(&self, data: &FormData) -> RangeSelection {
+        let (min_name, max_name) = self.field_names();
+        let read = |name: &str, fallback: i32| {
+            data.get(name)
+                .and_then(|v| v.trim().parse::<i32>().ok())
+                .map_or(fallback, |v| self.snap(v))
+        };
+        let (a, b) = (read(&min_name, self.min), read(&max_name, self.max));
+        RangeSelection {
+            low: a.min(b),
+            high: a.max(b),
+        }
+    }
+
+    fn position(&self, value: i32) -> f64 {
+        let span = (self.max - self.min).max(1) as f64;
+        ((value - self.min) as f64 / span * 100.0).clamp(0.0, 100.0)
+    }
+
     /// Build CSS class string
     fn build_classes(&self) -> String {
         let size_class = match self.size {
@@ -151,6 +276,10 @@ impl<'a> RangeSlider<'a> {
             classes.push("sh-range-slider--disabled");
         }
 
+        if self.bubbles {
+            classes.push("sh-range-slider--bubbles");
+        }
+
         classes.join(" ")
     }
 }
@@ -158,58 +287,96 @@ impl<'a> RangeSlider<'a> {
 impl<'a> Render for RangeSlider<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
-        let low_id = format!("{}-low", self.id);
-        let high_id = format!("{}-high", self.id);
+        let low_id = format!("{}-min", self.id);
+        let high_id = format!("{}-max", self.id);
+        let label_id = format!("{}-label", self.id);
+        let ticks_id = format!("{}-ticks", self.id);
+        let (min_name, max_name) = self.field_names();
+        let (low, high) = (self.low.min(self.high), self.low.max(self.high));
+        let (low_pos, high_pos) = (self.position(low), self.position(high));
+        let list = (!self.ticks.is_empty()).then_some(ticks_id.as_str());
 
         html! {
-            div class=(classes) {
+            div
+                class=(classes)
+                id=(self.id)
+                role="group"
+                aria-labelledby=[self.label.map(|_| label_id.as_str())]
+                style=(format!("--sh-range-low: {}%; --sh-range-high: {}%;", low_pos, high_pos))
+            {
                 @if let Some(label) = self.label {
-                    label class="sh-range-slider__label" for=(self.id) {
+                    span class="sh-range-slider__label" id=(label_id) {
                         (label)
                     }
                 }
 
                 @if self.show_values {
                     div class="sh-range-slider__values" {
-                        span class="sh-range-slider__value sh-range-slider__value--low" {
-                            (self.low)
+                        output
+                            class="sh-range-slider__value sh-range-slider__value--low"
+                            for=(low_id)
+                            style=(format!("left: {}%", low_pos))
+                        {
+                            (self.prefix) (low) (self.suffix)
                         }
-                        span class="sh-range-slider__value sh-range-slider__value--high" {
-                            (self.high)
+                        output
+                            class="sh-range-slider__value sh-range-slider__value--high"
+                            for=(high_id)
+                            style=(format!("left: {}%", high_pos))
+                        {
+                            (self.prefix) (high) (self.suffix)
                         }
                     }
                 }
 
                 div class="sh-range-slider__track" {
-                    div class="sh-range-slider__fill" style=(format!("left: {}%; right: {}%",
-                        ((self.low - self.min) as f64 / (self.max - self.min) as f64 * 100.0),
-                        100.0 - ((self.high - self.min) as f64 / (self.max - self.min) as f64 * 100.0)
-                    )) {}
+                    div class="sh-range-slider__fill" style=(format!("left: {}%; right: {}%", low_pos, 100.0 - high_pos)) {}
 
                     input
                         type="range"
                         id=(low_id)
-                        name=(low_id)
+                        name=(min_name)
                         class="sh-range-slider__thumb sh-range-slider__thumb--low"
                         min=(self.min)
                         max=(self.max)
-                        value=(self.low)
-                        step=(self.step)
+                        value=(low)
+                        step=(self.step.max(1))
+                        list=[list]
                         disabled?[self.disabled]
                         aria-label="Minimum value";
 
                     input
                         type="range"
                         id=(high_id)
-                        name=(high_id)
+                        name=(max_name)
                         class="sh-range-slider__thumb sh-range-slider__thumb--high"
                         min=(self.min)
                         max=(self.max)
-                        value=(self.high)
-                        step=(self.step)
+                        value=(high)
+                        step=(self.step.max(1))
+                        list=[list]
                         disabled?[self.disabled]
                         aria-label="Maximum value";
                 }
+
+                @if !self.ticks.is_empty() {
+                    datalist id=(ticks_id) {
+                        @for tick in &self.ticks {
+                            option value=(tick) label=[self.tick_labels.then(|| tick.to_string())] {}
+                        }
+                    }
+                    div class="sh-range-slider__ticks" aria-hidden="true" {
+                        @for tick in &self.ticks {
+                            span class="sh-range-slider__tick" style=(format!("left: {}%", self.position(*tick))) {
+                                @if self.tick_labels {
+                                    span class="sh-range-slider__tick-label" {
+                                        (self.prefix) (tick) (self.suffix)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
     }
@@ -288,27 +455,37 @@ pub fn range_slider_css() -> String {
 
 .sh-range-slider__thumb {
     position: absolute;
-    top: 50%;
-    width: var(--sh-range-thumb-size);
-    height: var(--sh-range-thumb-size);
-    transform: translate(-50%, -50%);
+    inset: 0;
+    width: 100%;
+    height: 100%;
+    margin: 0;
     appearance: none;
-    background: white;
-    border: 2px solid var(--sh-color-primary, #3b82f6);
-    border-radius: 50%;
-    cursor: pointer;
-    pointer-events: auto;
-    z-index: 1;
+    -webkit-appearance: none;
+    background: transparent;
+    pointer-events: none;
+}
+
+.sh-range-slider__thumb::-webkit-slider-runnable-track {
+    height: 100%;
+    background: transparent;
+}
+
+.sh-range-slider__thumb::-moz-range-track {
+    height: 100%;
+    background: transparent;
 }
 
 .sh-range-slider__thumb::-webkit-slider-thumb {
     appearance: none;
+    -webkit-appearance: none;
     width: var(--sh-range-thumb-size);
     height: var(--sh-range-thumb-size);
+    margin-top: calc((var(--sh-range-track-height) - var(--sh-range-thumb-size)) / 2);
     background: white;
     border: 2px solid var(--sh-color-primary, #3b82f6);
     border-radius: 50%;
     cursor: pointer;
+    pointer-events: auto;
 }
 
 .sh-range-slider__thumb::-moz-range-thumb {
@@ -318,13 +495,76 @@ pub fn range_slider_css() -> String {
     border: 2px solid var(--sh-color-primary, #3b82f6);
     border-radius: 50%;
     cursor: pointer;
+    pointer-events: auto;
+}
+
+/* Keep the low thumb reachable when both sit at the maximum */
+.sh-range-slider__thumb--low {
+    z-index: 2;
+}
+
+.sh-range-slider__thumb--high {
+    z-index: 1;
 }
 
 .sh-range-slider__thumb:focus {
     outline: none;
+}
+
+.sh-range-slider__thumb:focus-visible::-webkit-slider-thumb {
     box-shadow: 0 0 0 3px var(--sh-color-primary-alpha, rgba(59, 130, 246, 0.2));
 }
 
+.sh-range-slider__thumb:focus-visible::-moz-range-thumb {
+    box-shadow: 0 0 0 3px var(--sh-color-primary-alpha, rgba(59, 130, 246, 0.2));
+}
+
+.sh-range-slider__ticks {
+    position: relative;
+    height: 0.5rem;
+    margin: 0 calc(var(--sh-range-thumb-size) / 2);
+}
+
+.sh-range-slider__tick {
+    position: absolute;
+    top: 0;
+    width: 1px;
+    height: 0.375rem;
+    background: var(--sh-color-border, #d4d4d4);
+}
+
+.sh-range-slider__tick-label {
+    position: absolute;
+    top: 0.5rem;
+    transform: translateX(-50%);
+    font-size: var(--sh-font-size-xs, 0.75rem);
+    color: var(--sh-color-text-muted, #666);
+    white-space: nowrap;
+}
+
+.sh-range-slider__ticks:has(.sh-range-slider__tick-label) {
+    height: 1.75rem;
+}
+
+.sh-range-slider--bubbles .sh-range-slider__values {
+    display: block;
+    position: relative;
+    height: 1.75rem;
+    margin: 0 calc(var(--sh-range-thumb-size) / 2);
+}
+
+.sh-range-slider--bubbles .sh-range-slider__value {
+    position: absolute;
+    bottom: 0.25rem;
+    transform: translateX(-50%);
+    padding: 0.125rem 0.5rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-color-text, #1a1a1a);
+    color: white;
+    font-size: var(--sh-font-size-xs, 0.75rem);
+    white-space: nowrap;
+}
+
 .sh-range-slider--disabled {
     opacity: 0.5;
     pointer-events: none;
@@ -394,6 +634,74 @@ mod tests {
         assert!(danger.build_classes().contains("sh-range-slider--danger"));
     }
 
+    #[test]
+    fn test_range_slider_form_names_and_outputs() {
+        let html = RangeSlider::new("price-range")
+            .name("price")
+            .max(500)
+            .low(100)
+            .high(400)
+            .label("Price")
+            .affixes("$", "")
+            .bubbles(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="price_min""#));
+        assert!(html.contains(r#"name="price_max""#));
+        assert!(html.contains(r#"id="price-range-min""#));
+        assert!(html.contains(r#"aria-labelledby="price-range-label""#));
+        assert!(html.contains(r#"for="price-range-min" style="left: 20%">$100</output>"#));
+        assert!(html.contains(r#"style="left: 80%">$400</output>"#));
+        assert!(html.contains("sh-range-slider--bubbles"));
+        assert!(!html.contains("datalist"));
+
+        let default_names = RangeSlider::new("age").field_names();
+        assert_eq!(
+            default_names,
+            ("age_min".to_string(), "age_max".to_string())
+        );
+    }
+
+    #[test]
+    fn test_range_slider_ticks() {
+        let html = RangeSlider::new("size")
+            .tick_every(25)
+            .tick_labels(true)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"list="size-ticks""#));
+        assert!(html.contains(r#"<datalist id="size-ticks">"#));
+        assert_eq!(html.matches("<option").count(), 5);
+        assert!(html.contains(r#"<option value="75" label="75">"#));
+        assert!(html.contains(r#"<span class="sh-range-slider__tick" style="left: 50%">"#));
+        assert!(html.contains("sh-range-slider__tick-label"));
+    }
+
+    #[test]
+    fn test_range_slider_snap_and_parse() {
+        let slider = RangeSlider::new("price").min(0).max(95).step(10);
+        assert_eq!(slider.snap(44), 40);
+        assert_eq!(slider.snap(46), 50);
+        assert_eq!(slider.snap(94), 90);
+        assert_eq!(slider.snap(-5), 0);
+
+        let data = FormData::from_pairs([("price_min", "73"), ("price_max", "18")]);
+        assert_eq!(
+            slider.selection_from_form(&data),
+            RangeSelection { low: 20, high: 70 }
+        );
+        assert_eq!(
+            slider.selection_from_form(&FormData::from_pairs([("price_min", "x")])),
+            RangeSelection { low: 0, high: 95 }
+        );
+
+        let ticks = RangeSlider::new("t")
+            .ticks(vec![0, 10, 50, 100])
+            .snap_to_ticks(true);
+        assert_eq!(ticks.snap(35), 50);
+        assert_eq!(ticks.snap(4), 0);
+    }
+
     #[test]
     fn test_css_generation() {
         let css = range_slider_css();
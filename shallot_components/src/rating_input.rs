@@ -1,5 +1,10 @@
 //! Rating Input Component - Star/number rating selector
 //! CSS-only using radio buttons styled as stars
+//!
+//! The same builder renders a form control or, with
+//! [`RatingInput::readonly`], a display of a fractional score whose partly
+//! filled symbols use gradient fills. Read the submitted value with
+//! [`parse_rating`].
 
 use maud::{html, Markup, Render};
 
@@ -12,16 +17,80 @@ pub enum RatingSize {
     Lg,
 }
 
+/// Shape drawn for each rating step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingSymbol {
+    #[default]
+    Star,
+    Heart,
+    Circle,
+}
+
+impl RatingSymbol {
+    fn path(&self) -> &'static str {
+        match self {
+            RatingSymbol::Star => "M12 2l3.09 6.26L22 9.27l-5 4.87 1.18 6.88L12 17.77l-6.18 3.25L7 14.14 2 9.27l6.91-1.01L12 2z",
+            RatingSymbol::Heart => "M12 21.35l-1.45-1.32C5.4 15.36 2 12.28 2 8.5 2 5.42 4.42 3 7.5 3c1.74 0 3.41.81 4.5 2.09C13.09 3.81 14.76 3 16.5 3 19.58 3 22 5.42 22 8.5c0 3.78-3.4 6.86-8.55 11.54L12 21.35z",
+            RatingSymbol::Circle => "M12 2a10 10 0 1 0 0 20a10 10 0 1 0 0-20z",
+        }
+    }
+
+    /// Noun used in labels, e.g. "3 hearts"
+    fn noun(&self, count: u8) -> &'static str {
+        match (self, count == 1) {
+            (RatingSymbol::Star, true) => "star",
+            (RatingSymbol::Star, false) => "stars",
+            (RatingSymbol::Heart, true) => "heart",
+            (RatingSymbol::Heart, false) => "hearts",
+            (RatingSymbol::Circle, true) => "circle",
+            (RatingSymbol::Circle, false) => "circles",
+        }
+    }
+}
+
+/// Steps a displayed score is rounded to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingPrecision {
+    Whole,
+    #[default]
+    Half,
+    Quarter,
+}
+
+impl RatingPrecision {
+    pub fn round(&self, score: f32) -> f32 {
+        let steps = match self {
+            RatingPrecision::Whole => 1.0,
+            RatingPrecision::Half => 2.0,
+            RatingPrecision::Quarter => 4.0,
+        };
+        (score * steps).round() / steps
+    }
+}
+
+/// Read a submitted rating, rejecting values outside `1..=max`
+pub fn parse_rating(raw: &str, max: u8) -> Option<u8> {
+    raw.trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|value| (1..=max).contains(value))
+}
+
 /// Rating input component
 #[derive(Debug, Clone)]
 pub struct RatingInput<'a> {
     pub name: &'a str,
     pub max: u8,
     pub value: u8,
+    /// Fractional score shown in readonly mode
+    pub score: Option<f32>,
     pub size: RatingSize,
     pub disabled: bool,
     pub readonly: bool,
+    pub required: bool,
     pub label: Option<&'a str>,
+    pub symbol: RatingSymbol,
+    pub precision: RatingPrecision,
 }
 
 impl<'a> RatingInput<'a> {
@@ -30,13 +99,22 @@ impl<'a> RatingInput<'a> {
             name,
             max: 5,
             value: 0,
+            score: None,
             size: RatingSize::default(),
             disabled: false,
             readonly: false,
+            required: false,
             label: None,
+            symbol: RatingSymbol::default(),
+            precision: RatingPrecision::default(),
         }
     }
 
+    /// Readonly display of a fractional score, e.g. an average of reviews
+    pub fn display(name: &'a str, score: f32) -> Self {
+        Self::new(name).score(score).readonly(true)
+    }
+
     pub fn max(mut self, max: u8) -> Self {
         self.max = max.max(1);
         self
@@ -47,6 +125,30 @@ impl<'a> RatingInput<'a> {
         self
     }
 
+    /// Set a fractional score; the submitted value is it rounded
+    pub fn score(mut self, score: f32) -> Self {
+        let score = score.clamp(0.0, f32::from(self.max));
+        self.score = Some(score);
+        self.value = score.round() as u8;
+        self
+    }
+
+    pub fn symbol(mut self, symbol: RatingSymbol) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Rounding of the displayed score in readonly mode
+    pub fn precision(mut self, precision: RatingPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
     pub fn size(mut self, size: RatingSize) -> Self {
         self.size = size;
         self
@@ -88,37 +190,122 @@ impl<'a> RatingInput<'a> {
     }
 }
 
-impl<'a> Render for RatingInput<'a> {
-    fn render(&self) -> Markup {
-        let classes = self.build_classes();
+impl<'a> RatingInput<'a> {
+    fn label_id(&self) -> String {
+        format!("{}-label", self.name)
+    }
+
+    /// Displayed score after rounding to the precision
+    pub fn displayed_score(&self) -> f32 {
+        self.precision
+            .round(self.score.unwrap_or(f32::from(self.value)))
+            .clamp(0.0, f32::from(self.max))
+    }
+
+    /// Symbol `index` (1-based) filled to `fill` (0.0-1.0)
+    fn render_symbol(&self, index: u8, fill: f32) -> Markup {
+        let percent = (fill * 100.0).round() as u8;
+        let gradient_id = format!("{}-fill-{}", self.name, index);
+        let state = match percent {
+            100 => "on",
+            0 => "off",
+            _ => "partial",
+        };
 
         html! {
-            div class=(classes) role="radiogroup" {
-                @if let Some(label) = self.label {
-                    span class="sh-rating__label" {
-                        (label)
+            span class={ "sh-rating__icon sh-rating__icon--" (state) } {
+                svg viewBox="0 0 24 24" aria-hidden="true" focusable="false" {
+                    @if state == "partial" {
+                        defs {
+                            linearGradient id=(gradient_id) x1="0" x2="1" y1="0" y2="0" {
+                                stop class="sh-rating__stop--on" offset={ (percent) "%" } {}
+                                stop class="sh-rating__stop--off" offset={ (percent) "%" } {}
+                            }
+                        }
+                        path fill=(format!("url(#{})", gradient_id)) d=(self.symbol.path()) {}
+                    } @else {
+                        path fill="currentColor" d=(self.symbol.path()) {}
                     }
                 }
+            }
+        }
+    }
 
-                div class="sh-rating__stars" {
-                    @for i in (1..=self.max).rev() {
-                        label class="sh-rating__star" {
-                            input
-                                type="radio"
-                                name=(self.name)
-                                value=(i)
-                                checked[i == self.value]
-                                disabled[self.disabled || self.readonly]
-                                class="sh-rating__input";
-                            span class="sh-rating__icon" {
-                                "★"
-                            }
-                        }
+    fn render_display(&self) -> Markup {
+        let score = self.displayed_score();
+        let summary = format!(
+            "Rated {} out of {} {}",
+            score,
+            self.max,
+            self.symbol.noun(self.max)
+        );
+
+        html! {
+            span class="sh-rating__symbols" role="img" aria-label=(summary) {
+                @for i in 1..=self.max {
+                    (self.render_symbol(i, (score - f32::from(i - 1)).clamp(0.0, 1.0)))
+                }
+            }
+            @if !self.disabled {
+                input type="hidden" name=(self.name) value=(self.value);
+            }
+            span class="sh-rating__value" aria-hidden="true" {
+                (score) " / " (self.max)
+            }
+        }
+    }
+
+    fn render_input(&self) -> Markup {
+        html! {
+            div class="sh-rating__stars" {
+                @for i in (1..=self.max).rev() {
+                    label class="sh-rating__star" {
+                        input
+                            type="radio"
+                            name=(self.name)
+                            value=(i)
+                            checked[i == self.value]
+                            disabled[self.disabled]
+                            required[self.required]
+                            class="sh-rating__input";
+                        (self.render_symbol(i, 1.0))
+                        span class="sh-visually-hidden" { (i) " " (self.symbol.noun(i)) }
                     }
                 }
+            }
 
-                span class="sh-rating__value" {
-                    (self.value) " / " (self.max)
+            span class="sh-rating__value" aria-hidden="true" {
+                (self.value) " / " (self.max)
+            }
+        }
+    }
+}
+
+impl<'a> Render for RatingInput<'a> {
+    fn render(&self) -> Markup {
+        let classes = self.build_classes();
+        let label_id = self.label_id();
+        let labelled_by = self.label.map(|_| label_id.as_str());
+
+        html! {
+            @if self.readonly {
+                div class=(classes) {
+                    @if let Some(label) = self.label {
+                        span class="sh-rating__label" id=(label_id) { (label) }
+                    }
+                    (self.render_display())
+                }
+            } @else {
+                div
+                    class=(classes)
+                    role="radiogroup"
+                    aria-labelledby=[labelled_by]
+                    aria-label=[self.label.is_none().then_some("Rating")]
+                    aria-required=[self.required.then_some("true")] {
+                    @if let Some(label) = self.label {
+                        span class="sh-rating__label" id=(label_id) { (label) }
+                    }
+                    (self.render_input())
                 }
             }
         }
@@ -158,10 +345,16 @@ pub fn rating_input_css() -> String {
 }
 
 .sh-rating__icon {
+    display: inline-flex;
     color: var(--sh-color-border, #e5e5e5);
     transition: color 0.15s ease;
 }
 
+.sh-rating__icon svg {
+    width: 1em;
+    height: 1em;
+}
+
 .sh-rating--md .sh-rating__icon {
     font-size: 1.25rem;
 }
@@ -174,8 +367,31 @@ pub fn rating_input_css() -> String {
     font-size: 1.5rem;
 }
 
+.sh-rating__symbols {
+    display: inline-flex;
+    gap: 0.125rem;
+}
+
+.sh-rating__symbols .sh-rating__icon--on {
+    color: var(--sh-color-warning, #f59e0b);
+}
+
+.sh-rating__stop--on {
+    stop-color: var(--sh-color-warning, #f59e0b);
+}
+
+.sh-rating__stop--off {
+    stop-color: var(--sh-color-border, #e5e5e5);
+}
+
+.sh-rating__input:focus-visible ~ .sh-rating__icon {
+    outline: 2px solid var(--sh-color-primary, #3b82f6);
+    outline-offset: 2px;
+    border-radius: 2px;
+}
+
 .sh-rating__input:checked ~ .sh-rating__icon,
-.sh-rating__input:checked ~ .sh-rating__star .sh-rating__icon {
+.sh-rating__star:has(.sh-rating__input:checked) ~ .sh-rating__star .sh-rating__icon {
     color: var(--sh-color-warning, #f59e0b);
 }
 
@@ -245,6 +461,64 @@ mod tests {
         assert!(html.contains("sh-rating--readonly"));
     }
 
+    #[test]
+    fn test_rating_radio_semantics() {
+        let html = RatingInput::new("quality")
+            .label("Quality")
+            .max(3)
+            .value(2)
+            .required(true)
+            .symbol(RatingSymbol::Heart)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"role="radiogroup" aria-labelledby="quality-label""#));
+        assert!(html.contains(r#"aria-required="true""#));
+        assert!(
+            html.contains(r#"<span class="sh-rating__label" id="quality-label">Quality</span>"#)
+        );
+        assert_eq!(html.matches(r#"type="radio" name="quality""#).count(), 3);
+        assert!(html.contains(r#"value="2" checked"#));
+        assert!(html.contains("2 hearts"));
+        assert!(html.contains("1 heart<"));
+        assert!(html.contains("M12 21.35"));
+
+        let unlabelled = RatingInput::new("r").render().into_string();
+        assert!(unlabelled.contains(r#"aria-label="Rating""#));
+    }
+
+    #[test]
+    fn test_rating_display_fractional() {
+        let rating = RatingInput::display("avg", 3.3).precision(RatingPrecision::Quarter);
+        assert_eq!(rating.displayed_score(), 3.25);
+        assert_eq!(rating.value, 3);
+
+        let html = rating.render().into_string();
+        assert!(!html.contains("radiogroup"));
+        assert!(html.contains(r#"aria-label="Rated 3.25 out of 5 stars""#));
+        assert_eq!(html.matches("sh-rating__icon--on").count(), 3);
+        assert_eq!(html.matches("sh-rating__icon--off").count(), 1);
+        assert!(html.contains(r#"<linearGradient id="avg-fill-4""#));
+        assert!(html.contains(r#"offset="25%""#));
+        assert!(html.contains(r#"fill="url(#avg-fill-4)""#));
+        assert!(html.contains(r#"<input type="hidden" name="avg" value="3">"#));
+
+        let whole = RatingInput::display("w", 3.3)
+            .precision(RatingPrecision::Whole)
+            .render()
+            .into_string();
+        assert!(!whole.contains("sh-rating__icon--partial"));
+        assert_eq!(RatingPrecision::Half.round(3.8), 4.0);
+    }
+
+    #[test]
+    fn test_parse_rating() {
+        assert_eq!(parse_rating("4", 5), Some(4));
+        assert_eq!(parse_rating(" 5 ", 5), Some(5));
+        assert_eq!(parse_rating("6", 5), None);
+        assert_eq!(parse_rating("0", 5), None);
+        assert_eq!(parse_rating("abc", 5), None);
+    }
+
     #[test]
     fn test_css_generation() {
         let css = rating_input_css();
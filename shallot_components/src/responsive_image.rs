@@ -1,6 +1,7 @@
 //! Responsive Image Component - Image with responsive sizing
 //! CSS-only responsive image with aspect ratio support
 
+use crate::view_transitions::view_transition_style;
 use maud::{html, Markup, Render};
 
 /// Image fit mode
@@ -34,6 +35,9 @@ pub struct ResponsiveImage<'a> {
     pub radius: ImageRadius,
     pub lazy: bool,
     pub sources: Vec<ImageSource<'a>>,
+    /// Rendered width for each viewport, needed by `w` srcset descriptors
+    pub sizes: Option<&'a str>,
+    pub view_transition_name: Option<&'a str>,
 }
 
 /// Image source for srcset
@@ -56,6 +60,8 @@ impl<'a> ResponsiveImage<'a> {
             radius: ImageRadius::default(),
             lazy: true,
             sources: Vec::new(),
+            sizes: None,
+            view_transition_name: None,
         }
     }
 
@@ -109,6 +115,30 @@ impl<'a> ResponsiveImage<'a> {
         self
     }
 
+    /// Add a source in another format, e.g. `"image/avif"`; browsers pick
+    /// the first type they support
+    pub fn source_with_type(mut self, srcset: &'a str, mime_type: &'a str) -> Self {
+        self.sources.push(ImageSource {
+            srcset,
+            media: None,
+            r#type: Some(mime_type),
+        });
+        self
+    }
+
+    /// Set the `sizes` attribute, e.g. `"(min-width: 64rem) 50vw, 100vw"`
+    pub fn sizes(mut self, sizes: &'a str) -> Self {
+        self.sizes = Some(sizes);
+        self
+    }
+
+    /// Name the image for cross-page view transitions, e.g. to match a
+    /// card thumbnail on the previous page
+    pub fn view_transition_name(mut self, name: &'a str) -> Self {
+        self.view_transition_name = Some(name);
+        self
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-image".to_string()];
 
@@ -145,17 +175,19 @@ impl<'a> Render for ResponsiveImage<'a> {
                 @for source in &self.sources {
                     source
                         srcset=(source.srcset)
-                        media?=(source.media)
-                        type?=(source.r#type);
+                        sizes=[self.sizes]
+                        media=[source.media]
+                        type=[source.r#type];
                 }
                 img
                     src=(self.src)
                     alt=(self.alt)
                     class=(classes)
-                    width?=(self.width)
-                    height?=(self.height)
-                    loading?={(if self.lazy { Some("lazy") } else { None })}
-                    decoding="async";
+                    width=[self.width]
+                    height=[self.height]
+                    loading=[self.lazy.then_some("lazy")]
+                    decoding="async"
+                    style=[self.view_transition_name.map(view_transition_style)];
             }
         }
     }
@@ -288,10 +320,37 @@ mod tests {
         assert!(html.contains("media"));
     }
 
+    #[test]
+    fn test_responsive_image_typed_sources() {
+        let html = ResponsiveImage::new("/hero.jpg", "Hero")
+            .source_with_type("/hero-480w.avif 480w, /hero-960w.avif 960w", "image/avif")
+            .sizes("100vw")
+            .render()
+            .into_string();
+
+        assert!(html.contains(
+            r#"<source srcset="/hero-480w.avif 480w, /hero-960w.avif 960w" sizes="100vw" type="image/avif">"#
+        ));
+    }
+
     #[test]
     fn test_responsive_image_css() {
         let css = responsive_image_css();
         assert!(css.contains(".sh-image"));
         assert!(css.contains("object-fit"));
     }
+
+    #[test]
+    fn test_responsive_image_view_transition_name() {
+        let html = ResponsiveImage::new("/lamp.jpg", "Lamp")
+            .view_transition_name("product-42")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"style="view-transition-name: product-42;""#));
+
+        let plain = ResponsiveImage::new("/lamp.jpg", "Lamp")
+            .render()
+            .into_string();
+        assert!(!plain.contains("style="));
+    }
 }
@@ -0,0 +1,856 @@
+//! Review Components - Review cards, rating summaries and testimonials
+//!
+//! [`ReviewCard`] votes post a small form with [`REVIEW_ID_FIELD`] and
+//! [`REVIEW_VOTE_FIELD`]; read them back with [`HelpfulVote::from_form`].
+//! [`ReviewSummary`] draws one [`ProgressBar`] per star, and
+//! [`TestimonialCarousel`] lays testimonials out as [`Carousel`] slides.
+
+use crate::avatar::Avatar;
+use crate::carousel::{Carousel, CarouselIndicatorStyle, CarouselSlide};
+use crate::component::ComponentSize;
+use crate::form::{Form, FormData, FormVariant};
+use crate::product_card::{Rating, RatingSize};
+use crate::progress::ProgressBar;
+use maud::{html, Markup, Render};
+use shallot_foundation::CalendarDate;
+use std::borrow::Cow;
+
+/// Hidden field carrying the review id on helpful-vote forms
+pub const REVIEW_ID_FIELD: &str = "review";
+
+/// Field set by the Yes / No vote buttons
+pub const REVIEW_VOTE_FIELD: &str = "vote";
+
+/// Format an ISO 8601 date ("2026-03-14", optionally followed by a time) as
+/// "Mar 14, 2026"; anything else is returned unchanged
+pub fn format_review_date(iso: &str) -> String {
+    match iso.get(..10).and_then(CalendarDate::parse) {
+        Some(date) => format!("{} {}, {}", date.month_abbreviation(), date.day, date.year),
+        None => iso.to_string(),
+    }
+}
+
+/// A visitor's answer to "Was this review helpful?"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpfulVote {
+    Helpful,
+    NotHelpful,
+}
+
+impl HelpfulVote {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HelpfulVote::Helpful => "helpful",
+            HelpfulVote::NotHelpful => "not_helpful",
+        }
+    }
+
+    /// Read the review id and vote from a submitted vote form
+    pub fn from_form(data: &FormData) -> Option<(String, Self)> {
+        let id = data.get(REVIEW_ID_FIELD)?.trim();
+        let vote = match data.get(REVIEW_VOTE_FIELD)? {
+            "helpful" => HelpfulVote::Helpful,
+            "not_helpful" => HelpfulVote::NotHelpful,
+            _ => return None,
+        };
+        (!id.is_empty()).then(|| (id.to_string(), vote))
+    }
+}
+
+/// A single customer review
+pub struct ReviewCard<'a> {
+    pub id: Cow<'a, str>,
+    pub author: Cow<'a, str>,
+    pub rating: u8,
+    pub title: Option<Cow<'a, str>>,
+    pub body: Cow<'a, str>,
+    /// ISO 8601 date, rendered in a `<time>` element
+    pub date: Option<Cow<'a, str>>,
+    pub avatar: Option<&'a str>,
+    pub verified: bool,
+    pub helpful_count: u32,
+    pub vote_action: Option<Cow<'a, str>>,
+    pub csrf: Option<Cow<'a, str>>,
+    pub voted: Option<HelpfulVote>,
+    pub response: Option<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> ReviewCard<'a> {
+    /// `rating` is clamped to 1–5 stars
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        author: impl Into<Cow<'a, str>>,
+        rating: u8,
+        body: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            author: author.into(),
+            rating: rating.clamp(1, 5),
+            title: None,
+            body: body.into(),
+            date: None,
+            avatar: None,
+            verified: false,
+            helpful_count: 0,
+            vote_action: None,
+            csrf: None,
+            voted: None,
+            response: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn date(mut self, iso: impl Into<Cow<'a, str>>) -> Self {
+        self.date = Some(iso.into());
+        self
+    }
+
+    pub fn avatar(mut self, src: &'a str) -> Self {
+        self.avatar = Some(src);
+        self
+    }
+
+    /// Mark the review as coming from a confirmed purchase
+    pub fn verified(mut self, verified: bool) -> Self {
+        self.verified = verified;
+        self
+    }
+
+    pub fn helpful_count(mut self, count: u32) -> Self {
+        self.helpful_count = count;
+        self
+    }
+
+    /// URL the helpful-vote form posts to; without it no form is rendered
+    pub fn vote_action(mut self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.vote_action = Some(action.into());
+        self
+    }
+
+    pub fn csrf_token(mut self, token: impl Into<Cow<'a, str>>) -> Self {
+        self.csrf = Some(token.into());
+        self
+    }
+
+    /// The visitor's recorded vote; replaces the form with a thank-you note
+    pub fn voted(mut self, vote: HelpfulVote) -> Self {
+        self.voted = Some(vote);
+        self
+    }
+
+    /// Reply from the seller, shown under the review
+    pub fn response(
+        mut self,
+        from: impl Into<Cow<'a, str>>,
+        body: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.response = Some((from.into(), body.into()));
+        self
+    }
+
+    fn helpful_text(&self) -> Option<String> {
+        match self.helpful_count {
+            0 => None,
+            1 => Some("1 person found this helpful".to_string()),
+            n => Some(format!("{} people found this helpful", n)),
+        }
+    }
+
+    fn render_vote(&self, action: &str) -> Markup {
+        if self.voted.is_some() {
+            return html! {
+                p class="sh-review__voted" role="status" { "Thanks for your feedback" }
+            };
+        }
+
+        let fields = html! {
+            span class="sh-review__vote-label" { "Was this review helpful?" }
+            button
+                class="sh-btn sh-btn--ghost sh-btn--sm"
+                type="submit"
+                name=(REVIEW_VOTE_FIELD)
+                value=(HelpfulVote::Helpful.as_str()) {
+                "Yes"
+            }
+            button
+                class="sh-btn sh-btn--ghost sh-btn--sm"
+                type="submit"
+                name=(REVIEW_VOTE_FIELD)
+                value=(HelpfulVote::NotHelpful.as_str()) {
+                "No"
+            }
+        };
+        let label = format!("Rate review by {}", self.author);
+        let mut form = Form::new()
+            .action(action)
+            .method("post")
+            .variant(FormVariant::Inline)
+            .class("sh-review__vote")
+            .aria_label(&label)
+            .hidden(REVIEW_ID_FIELD, &self.id)
+            .fields(fields);
+        if let Some(token) = &self.csrf {
+            form = form.csrf_token(token);
+        }
+        form.render()
+    }
+}
+
+impl<'a> Render for ReviewCard<'a> {
+    fn render(&self) -> Markup {
+        let mut avatar = Avatar::new(&self.author).size(ComponentSize::Sm);
+        if let Some(src) = self.avatar {
+            avatar = avatar.src(src);
+        }
+
+        html! {
+            article class="sh-review" id={ "sh-review-" (self.id) } {
+                header class="sh-review__header" {
+                    (avatar)
+                    div class="sh-review__meta" {
+                        span class="sh-review__author" { (self.author) }
+                        @if self.verified {
+                            span class="sh-review__verified" {
+                                span aria-hidden="true" { "✓ " }
+                                "Verified purchase"
+                            }
+                        }
+                        @if let Some(date) = &self.date {
+                            time class="sh-review__date" datetime=(date) { (format_review_date(date)) }
+                        }
+                    }
+                }
+                div class="sh-review__rating" {
+                    (Rating::without_count(f32::from(self.rating)).size(RatingSize::Sm).render())
+                    @if let Some(title) = &self.title {
+                        h3 class="sh-review__title" { (title) }
+                    }
+                }
+                p class="sh-review__body" { (self.body) }
+                @if let Some((from, body)) = &self.response {
+                    blockquote class="sh-review__response" {
+                        p class="sh-review__response-from" { "Response from " (from) }
+                        p { (body) }
+                    }
+                }
+                @if self.helpful_text().is_some() || self.vote_action.is_some() {
+                    footer class="sh-review__footer" {
+                        @if let Some(text) = self.helpful_text() {
+                            span class="sh-review__helpful" { (text) }
+                        }
+                        @if let Some(action) = &self.vote_action {
+                            (self.render_vote(action))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Average rating and per-star histogram
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewSummary<'a> {
+    /// Number of reviews per star, `counts[0]` being one star
+    pub counts: [u32; 5],
+    /// Path the star rows link to, with `?rating={n}` appended
+    pub filter_action: Option<Cow<'a, str>>,
+    pub selected: Option<u8>,
+}
+
+impl<'a> ReviewSummary<'a> {
+    pub fn new(counts: [u32; 5]) -> Self {
+        Self {
+            counts,
+            filter_action: None,
+            selected: None,
+        }
+    }
+
+    /// Tally individual ratings; values outside 1–5 are ignored
+    pub fn from_ratings(ratings: impl IntoIterator<Item = u8>) -> Self {
+        let mut counts = [0; 5];
+        for rating in ratings {
+            if (1..=5).contains(&rating) {
+                counts[usize::from(rating - 1)] += 1;
+            }
+        }
+        Self::new(counts)
+    }
+
+    /// Make each star row a link filtering the review list
+    pub fn filter_action(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.filter_action = Some(path.into());
+        self
+    }
+
+    /// Highlight the currently filtered star row
+    pub fn selected(mut self, stars: u8) -> Self {
+        self.selected = Some(stars);
+        self
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Mean rating rounded to one decimal, 0.0 without reviews
+    pub fn average(&self) -> f32 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        let sum: u32 = self
+            .counts
+            .iter()
+            .zip(1u32..)
+            .map(|(count, stars)| count * stars)
+            .sum();
+        (sum as f32 / total as f32 * 10.0).round() / 10.0
+    }
+
+    /// Share of reviews with `stars` stars, as a whole percentage
+    pub fn percentage(&self, stars: u8) -> u8 {
+        let total = self.total();
+        if total == 0 || !(1..=5).contains(&stars) {
+            return 0;
+        }
+        let count = self.counts[usize::from(stars - 1)];
+        (f64::from(count) * 100.0 / f64::from(total)).round() as u8
+    }
+
+    fn filter_href(&self, stars: u8) -> Option<String> {
+        let path = self.filter_action.as_ref()?;
+        let separator = if path.contains('?') { '&' } else { '?' };
+        Some(format!("{}{}rating={}", path, separator, stars))
+    }
+}
+
+impl<'a> Render for ReviewSummary<'a> {
+    fn render(&self) -> Markup {
+        let total = self.total();
+        let average = self.average();
+
+        html! {
+            section class="sh-review-summary" aria-label="Customer ratings" {
+                div class="sh-review-summary__overview" {
+                    p class="sh-review-summary__average" {
+                        (format!("{:.1}", average))
+                        span class="sh-visually-hidden" { " out of 5" }
+                    }
+                    (Rating::without_count(average).render())
+                    p class="sh-review-summary__total" {
+                        @if total == 1 { "Based on 1 review" } @else { "Based on " (total) " reviews" }
+                    }
+                }
+                ol class="sh-review-summary__histogram" {
+                    @for stars in (1..=5u8).rev() {
+                        @let count = self.counts[usize::from(stars - 1)];
+                        @let percentage = self.percentage(stars);
+                        @let row = html! {
+                            span class="sh-review-summary__label" { (stars) " star" @if stars > 1 { "s" } }
+                            (ProgressBar::new(percentage).render())
+                            span class="sh-review-summary__count" {
+                                (percentage) "%"
+                                span class="sh-visually-hidden" { " (" (count) ")" }
+                            }
+                        };
+                        li class=(if self.selected == Some(stars) { "sh-review-summary__row sh-review-summary__row--selected" } else { "sh-review-summary__row" }) {
+                            @match self.filter_href(stars) {
+                                Some(href) if count > 0 => {
+                                    a class="sh-review-summary__bar sh-review-summary__bar--link" href=(href)
+                                        aria-current=[(self.selected == Some(stars)).then_some("true")] {
+                                        (row)
+                                    }
+                                }
+                                _ => { div class="sh-review-summary__bar" { (row) } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A customer quote for landing pages
+#[derive(Debug, Clone)]
+pub struct Testimonial<'a> {
+    pub quote: Cow<'a, str>,
+    pub author: Cow<'a, str>,
+    pub role: Option<Cow<'a, str>>,
+    pub avatar: Option<&'a str>,
+    pub rating: Option<u8>,
+}
+
+impl<'a> Testimonial<'a> {
+    pub fn new(quote: impl Into<Cow<'a, str>>, author: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            quote: quote.into(),
+            author: author.into(),
+            role: None,
+            avatar: None,
+            rating: None,
+        }
+    }
+
+    /// Job title or company, e.g. "CTO, Acme"
+    pub fn role(mut self, role: impl Into<Cow<'a, str>>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    pub fn avatar(mut self, src: &'a str) -> Self {
+        self.avatar = Some(src);
+        self
+    }
+
+    pub fn rating(mut self, stars: u8) -> Self {
+        self.rating = Some(stars.clamp(1, 5));
+        self
+    }
+}
+
+impl<'a> Render for Testimonial<'a> {
+    fn render(&self) -> Markup {
+        let mut avatar = Avatar::new(&self.author);
+        if let Some(src) = self.avatar {
+            avatar = avatar.src(src);
+        }
+
+        html! {
+            figure class="sh-testimonial" {
+                @if let Some(stars) = self.rating {
+                    (Rating::without_count(f32::from(stars)).size(RatingSize::Sm).render())
+                }
+                blockquote class="sh-testimonial__quote" {
+                    p { (self.quote) }
+                }
+                figcaption class="sh-testimonial__author" {
+                    (avatar)
+                    span class="sh-testimonial__name" {
+                        cite { (self.author) }
+                        @if let Some(role) = &self.role {
+                            span class="sh-testimonial__role" { (role) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Testimonials shown one at a time in a [`Carousel`]
+pub struct TestimonialCarousel<'a> {
+    pub testimonials: Vec<Testimonial<'a>>,
+    pub id: Cow<'a, str>,
+    pub autoplay: bool,
+    pub interval_ms: u16,
+    pub indicator_style: CarouselIndicatorStyle,
+}
+
+impl<'a> TestimonialCarousel<'a> {
+    pub fn new(testimonials: Vec<Testimonial<'a>>) -> Self {
+        Self {
+            testimonials,
+            id: Cow::Borrowed("sh-testimonials"),
+            autoplay: false,
+            interval_ms: 8000,
+            indicator_style: CarouselIndicatorStyle::Dots,
+        }
+    }
+
+    /// Element id; must be unique when several carousels share a page
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Advance automatically every `interval_ms`; see [`Carousel::autoplay`]
+    pub fn autoplay(mut self, interval_ms: u16) -> Self {
+        self.autoplay = true;
+        self.interval_ms = interval_ms;
+        self
+    }
+
+    pub fn indicator_style(mut self, style: CarouselIndicatorStyle) -> Self {
+        self.indicator_style = style;
+        self
+    }
+}
+
+impl<'a> Render for TestimonialCarousel<'a> {
+    fn render(&self) -> Markup {
+        let slides = self
+            .testimonials
+            .iter()
+            .map(|testimonial| CarouselSlide::new(testimonial.render()))
+            .collect();
+        let carousel = Carousel::from_slides(slides)
+            .id(self.id.as_ref())
+            .indicator_style(self.indicator_style)
+            .autoplay(self.autoplay)
+            .autoplay_interval(self.interval_ms)
+            .aria_label("Testimonials");
+
+        html! {
+            div class="sh-testimonials" { (carousel) }
+        }
+    }
+}
+
+/// Generate CSS for review components
+pub fn reviews_css() -> String {
+    r#"
+.sh-review {
+    display: flex;
+    flex-direction: column;
+    gap: 0.75rem;
+    padding: 1.25rem 0;
+    border-bottom: 1px solid var(--sh-border, #e5e7eb);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-review__header {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+}
+
+.sh-review__meta {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: baseline;
+    gap: 0.25rem 0.75rem;
+    font-size: 0.875rem;
+}
+
+.sh-review__author {
+    font-weight: 600;
+}
+
+.sh-review__verified {
+    color: var(--sh-success, #10b981);
+    font-size: 0.8125rem;
+    font-weight: 500;
+}
+
+.sh-review__date {
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-review__rating {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-review__title {
+    margin: 0;
+    font-size: 1rem;
+    font-weight: 600;
+}
+
+.sh-review__body {
+    margin: 0;
+    line-height: 1.6;
+}
+
+.sh-review__response {
+    margin: 0;
+    padding: 0.75rem 1rem;
+    border-left: 3px solid var(--sh-primary, #3b82f6);
+    background: var(--sh-surface-alt, #f3f4f6);
+    border-radius: 0 var(--sh-radius-md, 0.375rem) var(--sh-radius-md, 0.375rem) 0;
+    font-size: 0.875rem;
+}
+
+.sh-review__response p {
+    margin: 0;
+}
+
+.sh-review__response-from {
+    margin-bottom: 0.25rem !important;
+    font-weight: 600;
+}
+
+.sh-review__footer {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem 1rem;
+    font-size: 0.8125rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-review__vote {
+    display: flex;
+    align-items: center;
+    gap: 0.25rem;
+}
+
+.sh-review__voted {
+    margin: 0;
+    color: var(--sh-success, #10b981);
+}
+
+/* Rating summary */
+.sh-review-summary {
+    display: grid;
+    grid-template-columns: auto 1fr;
+    align-items: center;
+    gap: 1.5rem 2rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+@media (max-width: 480px) {
+    .sh-review-summary {
+        grid-template-columns: 1fr;
+    }
+}
+
+.sh-review-summary__overview {
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    gap: 0.25rem;
+}
+
+.sh-review-summary__average {
+    margin: 0;
+    font-size: 3rem;
+    font-weight: 700;
+    line-height: 1;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-review-summary__total {
+    margin: 0;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-review-summary__histogram {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-review-summary__bar {
+    display: grid;
+    grid-template-columns: 4rem 1fr 2.75rem;
+    align-items: center;
+    gap: 0.75rem;
+    padding: 0.125rem 0.25rem;
+    margin: 0 -0.25rem;
+    border-radius: var(--sh-radius-md, 0.375rem);
+    font-size: 0.875rem;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-review-summary__bar--link {
+    text-decoration: none;
+}
+
+.sh-review-summary__bar--link:hover,
+.sh-review-summary__bar--link:focus-visible,
+.sh-review-summary__row--selected .sh-review-summary__bar {
+    background: var(--sh-surface-alt, #f3f4f6);
+}
+
+.sh-review-summary .sh-progress__bar {
+    background: var(--sh-warning, #f59e0b);
+}
+
+.sh-review-summary__count {
+    text-align: right;
+    color: var(--sh-text-muted, #6b7280);
+    font-variant-numeric: tabular-nums;
+}
+
+/* Testimonials */
+.sh-testimonial {
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    gap: 1.25rem;
+    max-width: 40rem;
+    margin: 0 auto;
+    padding: 2rem 3rem;
+    text-align: center;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+}
+
+.sh-testimonial__quote {
+    margin: 0;
+    font-size: 1.25rem;
+    line-height: 1.6;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-testimonial__quote p {
+    margin: 0;
+}
+
+.sh-testimonial__quote p::before {
+    content: "\201C";
+}
+
+.sh-testimonial__quote p::after {
+    content: "\201D";
+}
+
+.sh-testimonial__author {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    text-align: left;
+}
+
+.sh-testimonial__name {
+    display: flex;
+    flex-direction: column;
+}
+
+.sh-testimonial__name cite {
+    font-style: normal;
+    font-weight: 600;
+}
+
+.sh-testimonial__role {
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_review_date() {
+        assert_eq!(format_review_date("2026-03-14"), "Mar 14, 2026");
+        assert_eq!(format_review_date("2025-12-01T09:30:00Z"), "Dec 1, 2025");
+        assert_eq!(format_review_date("last week"), "last week");
+        assert_eq!(format_review_date("2026-13-01"), "2026-13-01");
+    }
+
+    #[test]
+    fn test_helpful_vote_from_form() {
+        let data = FormData::from_pairs([("review", "r-42"), ("vote", "helpful")]);
+        assert_eq!(
+            HelpfulVote::from_form(&data),
+            Some(("r-42".to_string(), HelpfulVote::Helpful))
+        );
+        let bad = FormData::from_pairs([("review", "r-42"), ("vote", "maybe")]);
+        assert_eq!(HelpfulVote::from_form(&bad), None);
+        let missing = FormData::from_pairs([("vote", "not_helpful")]);
+        assert_eq!(HelpfulVote::from_form(&missing), None);
+    }
+
+    #[test]
+    fn test_review_card_render() {
+        let html = ReviewCard::new("r-42", "Sam Lee", 4, "Comfortable and well made.")
+            .title("Great fit")
+            .date("2026-03-14")
+            .verified(true)
+            .helpful_count(12)
+            .vote_action("/reviews/vote")
+            .csrf_token("tok")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"id="sh-review-r-42""#));
+        assert!(html.contains("Verified purchase"));
+        assert!(html.contains(
+            r#"<time class="sh-review__date" datetime="2026-03-14">Mar 14, 2026</time>"#
+        ));
+        assert!(html.contains("Rated 4 out of 5 stars"));
+        assert!(html.contains("12 people found this helpful"));
+        assert!(html.contains(r#"name="review" value="r-42""#));
+        assert!(html.contains(r#"name="vote" value="not_helpful""#));
+        assert!(html.contains(r#"name="csrf_token" value="tok""#));
+    }
+
+    #[test]
+    fn test_review_card_voted_and_plain() {
+        let voted = ReviewCard::new("r-1", "Ana", 5, "Love it")
+            .vote_action("/reviews/vote")
+            .voted(HelpfulVote::Helpful)
+            .render()
+            .into_string();
+        assert!(voted.contains("Thanks for your feedback"));
+        assert!(!voted.contains("<form"));
+
+        let plain = ReviewCard::new("r-2", "Ana", 9, "Fine")
+            .render()
+            .into_string();
+        assert!(plain.contains("Rated 5 out of 5 stars"));
+        assert!(!plain.contains("sh-review__footer"));
+    }
+
+    #[test]
+    fn test_review_summary_math() {
+        let summary = ReviewSummary::from_ratings([5, 5, 5, 4, 3, 1, 0, 7]);
+        assert_eq!(summary.counts, [1, 0, 1, 1, 3]);
+        assert_eq!(summary.total(), 6);
+        assert_eq!(summary.average(), 3.8);
+        assert_eq!(summary.percentage(5), 50);
+        assert_eq!(summary.percentage(2), 0);
+        assert_eq!(ReviewSummary::new([0; 5]).average(), 0.0);
+    }
+
+    #[test]
+    fn test_review_summary_render() {
+        let html = ReviewSummary::new([1, 0, 1, 1, 3])
+            .filter_action("/product/1/reviews")
+            .selected(5)
+            .render()
+            .into_string();
+        assert!(html.contains("3.8"));
+        assert!(html.contains("Based on 6 reviews"));
+        assert!(html.contains(r#"aria-valuenow="50""#));
+        assert!(html.contains(r#"href="/product/1/reviews?rating=5" aria-current="true""#));
+        // Empty rows are not links
+        assert!(!html.contains("rating=2"));
+        assert!(html.contains("sh-review-summary__row--selected"));
+    }
+
+    #[test]
+    fn test_testimonial_carousel() {
+        let html = TestimonialCarousel::new(vec![
+            Testimonial::new("Shipped in a day.", "Kim")
+                .role("CTO, Acme")
+                .rating(5),
+            Testimonial::new("No JavaScript needed.", "Lou"),
+        ])
+        .id("quotes")
+        .autoplay(6000)
+        .render()
+        .into_string();
+        assert!(html.contains(r#"id="quotes""#));
+        assert!(html.contains(r#"id="quotes-slide-2""#));
+        assert!(html.contains(r#"aria-label="Testimonials""#));
+        assert!(html.contains("sh-carousel--autoplay-2"));
+        assert!(html.contains("<cite>Kim</cite>"));
+        assert!(html.contains("CTO, Acme"));
+    }
+
+    #[test]
+    fn test_reviews_css() {
+        let css = reviews_css();
+        assert!(css.contains(".sh-review__verified"));
+        assert!(css.contains(".sh-review-summary__histogram"));
+        assert!(css.contains(".sh-testimonial__quote"));
+    }
+}
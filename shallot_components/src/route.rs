@@ -0,0 +1,171 @@
+//! Route context - the current page as navigation components see it
+//!
+//! A server renders one page per request, so it always knows the path being
+//! served. Passing that to navigation components as a [`RouteContext`] lets
+//! them work out their own active states: [`crate::navbar::Navbar`] and
+//! [`crate::sidebar::SidebarNav`] mark the matching link with
+//! `aria-current="page"`, [`crate::breadcrumbs::Breadcrumbs`] builds its trail
+//! from the context's breadcrumbs, [`crate::tabs::Tabs`] selects the tab given
+//! by the parameter matching its name and [`crate::pagination::Pagination`]
+//! reads the current page from `page`.
+//!
+//! # Example
+//!
+//! ```
+//! use shallot_components::route::{RouteContext, RouteMatch};
+//!
+//! let route = RouteContext::new("/docs/components?tab=api")
+//!     .param("slug", "components")
+//!     .breadcrumb("Home", "/")
+//!     .breadcrumb("Docs", "/docs")
+//!     .breadcrumb("Components", "/docs/components");
+//! assert_eq!(route.path(), "/docs/components");
+//! assert_eq!(route.param_value("tab"), Some("api"));
+//! assert_eq!(route.matches("/docs"), RouteMatch::Ancestor);
+//! assert_eq!(route.aria_current("/docs/components/"), Some("page"));
+//! ```
+
+use std::borrow::Cow;
+
+/// How a link relates to the current path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteMatch {
+    /// The link points at the current page
+    Exact,
+    /// The current page lives below the link
+    Ancestor,
+    None,
+}
+
+/// The page being rendered: its path, parameters and breadcrumb trail
+#[derive(Debug, Clone)]
+pub struct RouteContext<'a> {
+    pub path: Cow<'a, str>,
+    /// Query parameters of the path followed by any added with [`RouteContext::param`]
+    pub params: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// `(label, href)` pairs from the site root to the current page;
+    /// [`crate::site::SiteBuilder`] derives them from its routes when empty
+    pub breadcrumbs: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> RouteContext<'a> {
+    /// Create a context for a request path; query parameters become params
+    /// as written, without percent-decoding
+    pub fn new(path: impl Into<Cow<'a, str>>) -> Self {
+        let path = path.into();
+        let params = path
+            .split_once('?')
+            .map(|(_, query)| query.split('#').next().unwrap_or_default())
+            .into_iter()
+            .flat_map(|query| query.split('&'))
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (Cow::Owned(name.to_string()), Cow::Owned(value.to_string()))
+            })
+            .collect();
+
+        Self {
+            path,
+            params,
+            breadcrumbs: Vec::new(),
+        }
+    }
+
+    /// Add a parameter, e.g. a path segment captured by the router
+    pub fn param(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Append a step to the breadcrumb trail
+    pub fn breadcrumb(
+        mut self,
+        label: impl Into<Cow<'a, str>>,
+        href: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.breadcrumbs.push((label.into(), href.into()));
+        self
+    }
+
+    /// The path without query string, fragment or trailing slash
+    pub fn path(&self) -> &str {
+        normalize_path(&self.path)
+    }
+
+    /// Value of the last parameter named `name`
+    pub fn param_value(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    /// How `href` relates to the current path
+    pub fn matches(&self, href: &str) -> RouteMatch {
+        route_match(href, &self.path)
+    }
+
+    /// Whether `href` points at the current page
+    pub fn is_current(&self, href: &str) -> bool {
+        self.matches(href) == RouteMatch::Exact
+    }
+
+    /// `aria-current` value for a link to `href`
+    pub fn aria_current(&self, href: &str) -> Option<&'static str> {
+        self.is_current(href).then_some("page")
+    }
+}
+
+fn normalize_path(path: &str) -> &str {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    }
+}
+
+pub(crate) fn route_match(href: &str, current: &str) -> RouteMatch {
+    let (href, current) = (normalize_path(href), normalize_path(current));
+    if href == current {
+        RouteMatch::Exact
+    } else if href != "/"
+        && current.starts_with(href)
+        && current.as_bytes().get(href.len()) == Some(&b'/')
+    {
+        RouteMatch::Ancestor
+    } else {
+        RouteMatch::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_match() {
+        assert_eq!(route_match("/billing", "/billing/"), RouteMatch::Exact);
+        assert_eq!(route_match("/billing", "/billing?tab=1"), RouteMatch::Exact);
+        assert_eq!(
+            route_match("/billing", "/billing/plans"),
+            RouteMatch::Ancestor
+        );
+        assert_eq!(route_match("/bill", "/billing"), RouteMatch::None);
+        assert_eq!(route_match("/", "/billing"), RouteMatch::None);
+        assert_eq!(route_match("/", ""), RouteMatch::Exact);
+    }
+
+    #[test]
+    fn test_route_params() {
+        let route = RouteContext::new("/search?q=rust&page=2&empty#results").param("page", "3");
+        assert_eq!(route.path(), "/search");
+        assert_eq!(route.param_value("q"), Some("rust"));
+        assert_eq!(route.param_value("empty"), Some(""));
+        assert_eq!(route.param_value("page"), Some("3"));
+        assert_eq!(route.param_value("missing"), None);
+        assert!(route.is_current("/search"));
+        assert_eq!(route.aria_current("/"), None);
+    }
+}
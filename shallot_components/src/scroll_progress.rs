@@ -0,0 +1,226 @@
+//! Scroll Progress Component - Reading progress bar driven by scrolling
+//!
+//! A thin bar pinned to the top or bottom of the viewport that fills as the
+//! page scrolls, using `animation-timeline` so no script is involved. With a
+//! [`RevealTimeline::View`] timeline the bar instead follows a single element
+//! marked with [`ScrollProgress::TRACK_CLASS`], such as an article body.
+//! Browsers without scroll-driven animation support hide the bar.
+
+use crate::scroll_reveal::RevealTimeline;
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Edge of the viewport the bar is pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollProgressPosition {
+    #[default]
+    Top,
+    Bottom,
+}
+
+/// Scroll Progress Component
+#[derive(Debug, Clone)]
+pub struct ScrollProgress<'a> {
+    pub timeline: RevealTimeline,
+    pub position: ScrollProgressPosition,
+    pub height_px: u8,
+    pub color: Option<Cow<'a, str>>,
+}
+
+impl<'a> Default for ScrollProgress<'a> {
+    fn default() -> Self {
+        Self {
+            timeline: RevealTimeline::scroll(),
+            position: ScrollProgressPosition::default(),
+            height_px: 3,
+            color: None,
+        }
+    }
+}
+
+impl<'a> ScrollProgress<'a> {
+    /// Class to put on the element a view-timeline progress bar follows
+    pub const TRACK_CLASS: &'static str = "sh-scroll-progress-track";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set what drives the bar. [`RevealTimeline::Time`] has no scroll
+    /// position to follow and is treated as a full-page scroll timeline.
+    pub fn timeline(mut self, timeline: RevealTimeline) -> Self {
+        self.timeline = match timeline {
+            RevealTimeline::Time => RevealTimeline::scroll(),
+            other => other,
+        };
+        self
+    }
+
+    pub fn position(mut self, position: ScrollProgressPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn height(mut self, height_px: u8) -> Self {
+        self.height_px = height_px.max(1);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Cow<'a, str>>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = vec!["sh-scroll-progress"];
+
+        match self.position {
+            ScrollProgressPosition::Top => classes.push("sh-scroll-progress--top"),
+            ScrollProgressPosition::Bottom => classes.push("sh-scroll-progress--bottom"),
+        }
+
+        match self.timeline {
+            RevealTimeline::View(_) => classes.push("sh-scroll-progress--view"),
+            _ => classes.push("sh-scroll-progress--scroll"),
+        }
+
+        classes.join(" ")
+    }
+
+    fn build_style(&self) -> String {
+        let mut style = format!("--sh-scroll-progress-h: {}px;", self.height_px);
+        if let Some(range) = self.timeline.range_css() {
+            style.push_str(&format!(" --sh-scroll-progress-range: {};", range));
+        }
+        if let Some(color) = &self.color {
+            style.push_str(&format!(" --sh-scroll-progress-color: {};", color));
+        }
+        style
+    }
+}
+
+impl<'a> Render for ScrollProgress<'a> {
+    fn render(&self) -> Markup {
+        // Purely decorative: the scrollbar already conveys position
+        html! {
+            div class=(self.build_classes()) style=(self.build_style()) aria-hidden="true" {
+                div class="sh-scroll-progress__bar" {}
+            }
+        }
+    }
+}
+
+/// Generate CSS for the scroll progress bar
+pub fn scroll_progress_css() -> String {
+    r#"
+/* Scroll Progress Styles */
+.sh-scroll-progress {
+    position: fixed;
+    left: 0;
+    right: 0;
+    z-index: 400;
+    height: var(--sh-scroll-progress-h, 3px);
+    pointer-events: none;
+}
+
+.sh-scroll-progress--top {
+    top: 0;
+}
+
+.sh-scroll-progress--bottom {
+    bottom: 0;
+}
+
+.sh-scroll-progress__bar {
+    height: 100%;
+    background: var(--sh-scroll-progress-color, var(--sh-accent));
+    transform-origin: 0 50%;
+    transform: scaleX(0);
+}
+
+@keyframes sh-scroll-progress-grow {
+    from { transform: scaleX(0); }
+    to { transform: scaleX(1); }
+}
+
+:root:has(.sh-scroll-progress-track) {
+    timeline-scope: --sh-scroll-progress;
+}
+
+.sh-scroll-progress-track {
+    view-timeline: --sh-scroll-progress block;
+}
+
+@supports (animation-timeline: scroll()) {
+    .sh-scroll-progress__bar {
+        animation: sh-scroll-progress-grow linear both;
+        animation-duration: auto;
+        animation-range: var(--sh-scroll-progress-range, 0% 100%);
+    }
+
+    .sh-scroll-progress--scroll .sh-scroll-progress__bar {
+        animation-timeline: scroll(root block);
+    }
+
+    .sh-scroll-progress--view .sh-scroll-progress__bar {
+        animation-timeline: --sh-scroll-progress;
+    }
+}
+
+/* Without scroll-driven animations the bar would never move */
+@supports not (animation-timeline: scroll()) {
+    .sh-scroll-progress {
+        display: none;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scroll_reveal::ViewRange;
+
+    #[test]
+    fn test_scroll_progress_defaults() {
+        let progress = ScrollProgress::new();
+        assert_eq!(progress.timeline, RevealTimeline::scroll());
+        assert_eq!(progress.position, ScrollProgressPosition::Top);
+        assert_eq!(progress.height_px, 3);
+    }
+
+    #[test]
+    fn test_scroll_progress_render() {
+        let html = ScrollProgress::new()
+            .position(ScrollProgressPosition::Bottom)
+            .height(4)
+            .color("hotpink")
+            .render()
+            .into_string();
+
+        assert!(html.contains("sh-scroll-progress--bottom"));
+        assert!(html.contains("sh-scroll-progress--scroll"));
+        assert!(html.contains("--sh-scroll-progress-h: 4px;"));
+        assert!(html.contains("--sh-scroll-progress-range: 0% 100%;"));
+        assert!(html.contains("--sh-scroll-progress-color: hotpink;"));
+        assert!(html.contains(r#"aria-hidden="true""#));
+    }
+
+    #[test]
+    fn test_scroll_progress_view_timeline() {
+        let progress = ScrollProgress::new().timeline(RevealTimeline::View(ViewRange::Contain));
+        let html = progress.render().into_string();
+
+        assert!(html.contains("sh-scroll-progress--view"));
+        assert!(html.contains("contain 0% contain 100%"));
+        assert!(scroll_progress_css().contains(ScrollProgress::TRACK_CLASS));
+    }
+
+    #[test]
+    fn test_scroll_progress_time_falls_back_to_scroll() {
+        let progress = ScrollProgress::new().timeline(RevealTimeline::Time);
+        assert_eq!(progress.timeline, RevealTimeline::scroll());
+        assert_eq!(ScrollProgress::new().height(0).height_px, 1);
+    }
+}
@@ -5,6 +5,11 @@
 //!
 //! Research Reference: Section 5.6.2 - Scroll & Parallax
 //!
+//! With a [`RevealTimeline`] other than `Time`, the animation is bound to
+//! scroll position through `animation-timeline: view()` or `scroll()`, so it
+//! plays without any script. Browsers without scroll-driven animation support
+//! show the content statically.
+//!
 //! # Example
 //! ```
 //! use shallot_components::scroll_reveal::{ScrollReveal, RevealAnimation};
@@ -18,6 +23,16 @@
 //! .duration(800)
 //! .delay(200);
 //! ```
+//!
+//! Scroll-driven, finishing once the element is fully in view:
+//! ```
+//! use shallot_components::scroll_reveal::{RevealTimeline, ScrollReveal, ViewRange};
+//! use maud::html;
+//!
+//! let reveal = ScrollReveal::new(html! { p { "Follows the scrollbar" } })
+//!     .timeline(RevealTimeline::View(ViewRange::Entry));
+//! assert!(reveal.render().into_string().contains("sh-reveal--timeline-view"));
+//! ```
 
 use maud::{html, Markup};
 
@@ -135,6 +150,79 @@ impl RevealThreshold {
     }
 }
 
+/// Portion of a view timeline an animation runs over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewRange {
+    /// While the element enters the viewport
+    Entry,
+    /// While the element leaves the viewport
+    Exit,
+    /// From the first pixel entering to the last pixel leaving
+    Cover,
+    /// While the element is entirely inside the viewport
+    Contain,
+    /// Start and end percentages of the `cover` range
+    Custom(u8, u8),
+}
+
+impl ViewRange {
+    pub fn css_value(&self) -> String {
+        match self {
+            Self::Entry => "entry 0% entry 100%".to_string(),
+            Self::Exit => "exit 0% exit 100%".to_string(),
+            Self::Cover => "cover 0% cover 100%".to_string(),
+            Self::Contain => "contain 0% contain 100%".to_string(),
+            Self::Custom(start, end) => {
+                format!("cover {}% cover {}%", (*start).min(100), (*end).min(100))
+            }
+        }
+    }
+}
+
+/// What drives an animation's progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevealTimeline {
+    /// Plays over `duration` once revealed (classic keyframes)
+    #[default]
+    Time,
+    /// Tracks the element's own position in the viewport (`view()`)
+    View(ViewRange),
+    /// Tracks the page's scroll position (`scroll()`), between two
+    /// percentages of the total scroll distance
+    Scroll { start: u8, end: u8 },
+}
+
+impl RevealTimeline {
+    /// Scroll timeline spanning the whole page
+    pub fn scroll() -> Self {
+        Self::Scroll { start: 0, end: 100 }
+    }
+
+    /// Short name used in modifier classes: `time`, `view` or `scroll`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Time => "time",
+            Self::View(_) => "view",
+            Self::Scroll { .. } => "scroll",
+        }
+    }
+
+    /// Value for `animation-range`, or `None` for time-based animations
+    pub fn range_css(&self) -> Option<String> {
+        match self {
+            Self::Time => None,
+            Self::View(range) => Some(range.css_value()),
+            Self::Scroll { start, end } => {
+                Some(format!("{}% {}%", (*start).min(100), (*end).min(100)))
+            }
+        }
+    }
+
+    pub fn is_scroll_driven(&self) -> bool {
+        !matches!(self, Self::Time)
+    }
+}
+
 /// Scroll reveal component
 #[derive(Debug, Clone)]
 pub struct ScrollReveal {
@@ -149,6 +237,7 @@ pub struct ScrollReveal {
     distance: Option<u16>,               // Override default distance (px)
     class: Option<String>,
     id: Option<String>,
+    timeline: RevealTimeline,
 }
 
 impl ScrollReveal {
@@ -165,6 +254,7 @@ impl ScrollReveal {
             distance: None,
             class: None,
             id: None,
+            timeline: RevealTimeline::Time,
         }
     }
 
@@ -183,6 +273,14 @@ impl ScrollReveal {
         self
     }
 
+    /// Drive the animation from scroll position instead of time.
+    ///
+    /// Duration, delay and `once` only apply to [`RevealTimeline::Time`].
+    pub fn timeline(mut self, timeline: RevealTimeline) -> Self {
+        self.timeline = timeline;
+        self
+    }
+
     pub fn easing(mut self, easing: Easing) -> Self {
         self.easing = easing;
         self
@@ -224,14 +322,20 @@ impl ScrollReveal {
     fn build_styles(&self) -> String {
         let distance = self.distance.unwrap_or(30);
 
-        format!(
+        let mut styles = format!(
             "--sh-reveal-duration:{}ms;--sh-reveal-delay:{}ms;--sh-reveal-easing:{};--sh-reveal-distance:{}px;--sh-reveal-threshold:{};",
             self.duration,
             self.delay,
             self.easing.css_value(),
             distance,
             self.threshold.value()
-        )
+        );
+
+        if let Some(range) = self.timeline.range_css() {
+            styles.push_str(&format!("--sh-reveal-range:{};", range));
+        }
+
+        styles
     }
 
     fn build_classes(&self) -> String {
@@ -248,6 +352,10 @@ impl ScrollReveal {
             classes.push("sh-reveal--stagger".to_string());
         }
 
+        if self.timeline.is_scroll_driven() {
+            classes.push(format!("sh-reveal--timeline-{}", self.timeline.kind()));
+        }
+
         if let Some(ref c) = self.class {
             classes.push(c.clone());
         }
@@ -699,6 +807,49 @@ pub fn scroll_reveal_css() -> String {
 .sh-reveal.sh-reveal--visible .sh-reveal-content {
     animation-play-state: running;
 }
+
+/* Scroll-driven timelines: progress follows scrolling, no script needed */
+.sh-reveal--timeline-view {
+    view-timeline: --sh-reveal block;
+}
+
+@media (prefers-reduced-motion: no-preference) {
+    @supports (animation-timeline: view()) {
+        .sh-reveal.sh-reveal--timeline-view .sh-reveal-content,
+        .sh-reveal.sh-reveal--timeline-scroll .sh-reveal-content {
+            animation-duration: auto;
+            animation-delay: 0s;
+            animation-fill-mode: both;
+            animation-play-state: running;
+            animation-range: var(--sh-reveal-range, entry 0% entry 100%);
+        }
+
+        .sh-reveal.sh-reveal--timeline-view .sh-reveal-content {
+            animation-timeline: --sh-reveal;
+        }
+
+        .sh-reveal.sh-reveal--timeline-scroll .sh-reveal-content {
+            animation-timeline: scroll(root block);
+        }
+    }
+}
+
+/* Fallback: without support (or with reduced motion) show content as-is */
+@supports not (animation-timeline: view()) {
+    .sh-reveal.sh-reveal--timeline-view .sh-reveal-content,
+    .sh-reveal.sh-reveal--timeline-scroll .sh-reveal-content {
+        opacity: 1;
+        animation: none;
+    }
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-reveal.sh-reveal--timeline-view .sh-reveal-content,
+    .sh-reveal.sh-reveal--timeline-scroll .sh-reveal-content {
+        opacity: 1;
+        animation: none;
+    }
+}
 "#
     .to_string()
 }
@@ -771,4 +922,49 @@ mod tests {
         assert_eq!(stagger.base_delay, 100);
         assert_eq!(stagger.stagger_delay, 50);
     }
+
+    #[test]
+    fn test_view_range_values() {
+        assert_eq!(ViewRange::Entry.css_value(), "entry 0% entry 100%");
+        assert_eq!(ViewRange::Contain.css_value(), "contain 0% contain 100%");
+        assert_eq!(
+            ViewRange::Custom(10, 150).css_value(),
+            "cover 10% cover 100%"
+        );
+    }
+
+    #[test]
+    fn test_reveal_timeline() {
+        assert_eq!(RevealTimeline::default(), RevealTimeline::Time);
+        assert_eq!(RevealTimeline::Time.range_css(), None);
+        assert_eq!(
+            RevealTimeline::scroll().range_css().as_deref(),
+            Some("0% 100%")
+        );
+        assert_eq!(RevealTimeline::View(ViewRange::Cover).kind(), "view");
+        assert!(!RevealTimeline::Time.is_scroll_driven());
+    }
+
+    #[test]
+    fn test_scroll_reveal_timeline_render() {
+        let html = ScrollReveal::new(html! { "Test" })
+            .timeline(RevealTimeline::View(ViewRange::Custom(0, 40)))
+            .render()
+            .into_string();
+
+        assert!(html.contains("sh-reveal--timeline-view"));
+        assert!(html.contains("--sh-reveal-range:cover 0% cover 40%;"));
+
+        let html = ScrollReveal::new(html! { "Test" }).render().into_string();
+        assert!(!html.contains("sh-reveal--timeline"));
+        assert!(!html.contains("--sh-reveal-range"));
+    }
+
+    #[test]
+    fn test_scroll_reveal_timeline_css() {
+        let css = scroll_reveal_css();
+        assert!(css.contains("@supports (animation-timeline: view())"));
+        assert!(css.contains("@supports not (animation-timeline: view())"));
+        assert!(css.contains("animation-timeline: scroll(root block)"));
+    }
 }
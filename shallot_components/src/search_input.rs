@@ -1,10 +1,40 @@
 //! Search Input Component
 //!
 //! Search input with clear button and keyboard navigation.
+//!
+//! By default the input renders inside its own `<form role="search"
+//! method="get">`, so pressing Enter loads `?q=...` (plus the scope, when
+//! [`SearchInput::scopes`] is set) without any script. Use
+//! [`SearchInput::embedded`] to drop the form when the input already lives in
+//! a larger one. [`SearchWithResults`] renders server-provided results with
+//! the query terms highlighted, and recent searches while the query is empty.
 
 use crate::component::ComponentSize;
+use crate::share_links::encode_uri_component;
 use maud::{html, Markup, Render};
 
+/// One option of the scope select, e.g. "Docs"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchScope<'a> {
+    pub value: &'a str,
+    pub label: &'a str,
+}
+
+impl<'a> SearchScope<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self { value, label }
+    }
+
+    /// All / Docs / Components, as used by the documentation site
+    pub fn site_defaults() -> Vec<Self> {
+        vec![
+            Self::new("all", "All"),
+            Self::new("docs", "Docs"),
+            Self::new("components", "Components"),
+        ]
+    }
+}
+
 #[derive(Clone)]
 pub struct SearchInput<'a> {
     name: &'a str,
@@ -14,6 +44,13 @@ pub struct SearchInput<'a> {
     variant: SearchVariant,
     disabled: bool,
     autofocus: bool,
+    action: Option<&'a str>,
+    embedded: bool,
+    scopes: Vec<SearchScope<'a>>,
+    scope_name: &'a str,
+    scope: Option<&'a str>,
+    suggestions: Vec<&'a str>,
+    shortcut: Option<&'a str>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -35,6 +72,13 @@ impl<'a> SearchInput<'a> {
             variant: SearchVariant::Default,
             disabled: false,
             autofocus: false,
+            action: None,
+            embedded: false,
+            scopes: Vec::new(),
+            scope_name: "scope",
+            scope: None,
+            suggestions: Vec::new(),
+            shortcut: None,
         }
     }
 
@@ -68,6 +112,49 @@ impl<'a> SearchInput<'a> {
         self
     }
 
+    /// Results page the form submits to (default: the current page)
+    pub fn action(mut self, action: &'a str) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Render without the surrounding `<form>`, for use inside another form
+    pub fn embedded(mut self, embedded: bool) -> Self {
+        self.embedded = embedded;
+        self
+    }
+
+    /// Add a select narrowing the search, submitted as `scope`
+    pub fn scopes(mut self, scopes: Vec<SearchScope<'a>>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// Rename the scope parameter
+    pub fn scope_name(mut self, name: &'a str) -> Self {
+        self.scope_name = name;
+        self
+    }
+
+    /// Currently selected scope value
+    pub fn scope(mut self, value: &'a str) -> Self {
+        self.scope = Some(value);
+        self
+    }
+
+    /// Offer completions through a `<datalist>`
+    pub fn suggestions(mut self, suggestions: Vec<&'a str>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Keyboard shortcut badge, e.g. `"/"`. A single character also becomes
+    /// the input's `accesskey`; longer chords need a script to bind them.
+    pub fn shortcut(mut self, shortcut: &'a str) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
     fn variant_class(&self) -> &'static str {
         match self.variant {
             SearchVariant::Default => "",
@@ -76,14 +163,46 @@ impl<'a> SearchInput<'a> {
             SearchVariant::Expandable => "sh-search--expandable",
         }
     }
-}
 
-impl<'a> Render for SearchInput<'a> {
-    fn render(&self) -> Markup {
+    /// URL running a search for `query`, keeping the current scope
+    fn search_href(&self, query: &str) -> String {
+        let mut href = format!(
+            "{}?{}={}",
+            self.action.unwrap_or(""),
+            self.name,
+            encode_uri_component(query)
+        );
+        if let Some(scope) = self.scope {
+            href.push_str(&format!(
+                "&{}={}",
+                self.scope_name,
+                encode_uri_component(scope)
+            ));
+        }
+        href
+    }
+
+    fn render_fields(&self) -> Markup {
         let input_id = format!("sh-search-{}", self.name);
+        let list_id = format!("{}-suggestions", input_id);
         let size_class = format!("sh-search--{}", self.size.class_suffix());
+        let has_value = self.value.is_some_and(|v| !v.is_empty());
+        let accesskey = self.shortcut.filter(|s| s.chars().count() == 1);
 
         html! {
+            @if !self.scopes.is_empty() {
+                select
+                    class="sh-search__scope"
+                    name=(self.scope_name)
+                    aria-label="Search in"
+                    disabled?[self.disabled]
+                {
+                    @for scope in &self.scopes {
+                        option value=(scope.value) selected?[self.scope == Some(scope.value)] { (scope.label) }
+                    }
+                }
+            }
+
             div class={(format!("sh-search {} {}", self.variant_class(), size_class))} {
                 span class="sh-search__icon" {
                     svg xmlns="http://www.w3.org/2000/svg" width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
@@ -99,27 +218,114 @@ impl<'a> Render for SearchInput<'a> {
                     id=(input_id)
                     placeholder=(self.placeholder.unwrap_or("Search..."))
                     value=[self.value]
+                    list=[(!self.suggestions.is_empty()).then_some(list_id.as_str())]
+                    accesskey=[accesskey]
                     disabled?[self.disabled]
                     autofocus?[self.autofocus]
                     autocomplete="off";
 
-                @if !self.disabled {
+                @if let Some(shortcut) = self.shortcut {
+                    kbd class="sh-search__shortcut" aria-hidden="true" { (shortcut) }
+                }
+
+                @if self.disabled {
+                } @else if self.embedded {
                     label for=(input_id) class="sh-search__clear" {
-                        svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                            line x1="18" y1="6" x2="6" y2="18";
-                            line x1="6" y1="6" x2="18" y2="18";
-                        }
+                        (clear_icon())
+                    }
+                } @else if has_value {
+                    // Clearing reloads the page without a query
+                    a href=(self.action.unwrap_or("?")) class="sh-search__clear" aria-label="Clear search" {
+                        (clear_icon())
+                    }
+                }
+            }
+
+            @if !self.suggestions.is_empty() {
+                datalist id=(list_id) {
+                    @for suggestion in &self.suggestions {
+                        option value=(suggestion) {}
                     }
                 }
             }
+
+            @if !self.embedded {
+                button type="submit" class="sh-visually-hidden" disabled?[self.disabled] { "Search" }
+            }
+        }
+    }
+}
+
+fn clear_icon() -> Markup {
+    html! {
+        svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
+            line x1="18" y1="6" x2="6" y2="18";
+            line x1="6" y1="6" x2="18" y2="18";
+        }
+    }
+}
+
+impl<'a> Render for SearchInput<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            @if self.embedded {
+                div class="sh-search-form" { (self.render_fields()) }
+            } @else {
+                form class="sh-search-form" role="search" method="get" action=[self.action] {
+                    (self.render_fields())
+                }
+            }
+        }
+    }
+}
+
+/// Wrap case-insensitive matches of the whitespace-separated `query` terms
+/// in `<mark>`
+pub fn highlight_matches(text: &str, query: &str) -> Markup {
+    // ASCII lowercasing keeps byte offsets aligned with `text`
+    let haystack = text.to_ascii_lowercase();
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for term in query.split_whitespace() {
+        let term = term.to_ascii_lowercase();
+        let mut from = 0;
+        while let Some(pos) = haystack[from..].find(&term) {
+            let start = from + pos;
+            ranges.push((start, start + term.len()));
+            from = start + term.len();
+        }
+    }
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
         }
     }
+
+    // (text before the match, the match) pairs, then the tail
+    let mut pieces = Vec::with_capacity(merged.len());
+    let mut cursor = 0;
+    for (start, end) in merged {
+        pieces.push((&text[cursor..start], &text[start..end]));
+        cursor = end;
+    }
+    html! {
+        @for (before, matched) in pieces {
+            (before)
+            mark class="sh-search-result__match" { (matched) }
+        }
+        (text[cursor..])
+    }
 }
 
 pub struct SearchWithResults<'a> {
     input: SearchInput<'a>,
     results: Vec<SearchResult<'a>>,
     show_results: bool,
+    recent: Vec<&'a str>,
+    highlight: bool,
 }
 
 pub struct SearchResult<'a> {
@@ -135,6 +341,8 @@ impl<'a> SearchWithResults<'a> {
             input,
             results: Vec::new(),
             show_results: false,
+            recent: Vec::new(),
+            highlight: true,
         }
     }
 
@@ -147,46 +355,79 @@ impl<'a> SearchWithResults<'a> {
         self.show_results = show;
         self
     }
+
+    /// The visitor's recent queries, newest first, linked as searches
+    /// while the input is empty
+    pub fn recent(mut self, queries: Vec<&'a str>) -> Self {
+        self.recent = queries;
+        self
+    }
+
+    /// Mark the query terms in result titles and descriptions (default: true)
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    fn query(&self) -> &str {
+        self.input.value.unwrap_or("").trim()
+    }
+
+    fn text(&self, text: &str) -> Markup {
+        if self.highlight {
+            highlight_matches(text, self.query())
+        } else {
+            html! { (text) }
+        }
+    }
+
+    fn render_result(&self, result: &SearchResult<'a>) -> Markup {
+        let body = html! {
+            @if let Some(icon) = result.icon {
+                span class="sh-search-result__icon" {
+                    (maud::PreEscaped(icon))
+                }
+            }
+            div class="sh-search-result__content" {
+                span class="sh-search-result__title" { (self.text(result.title)) }
+                @if let Some(desc) = result.description {
+                    span class="sh-search-result__desc" { (self.text(desc)) }
+                }
+            }
+        };
+        html! {
+            @if let Some(href) = result.href {
+                a href=(href) class="sh-search-result__link" { (body) }
+            } @else {
+                div class="sh-search-result__link" { (body) }
+            }
+        }
+    }
 }
 
 impl<'a> Render for SearchWithResults<'a> {
     fn render(&self) -> Markup {
+        let show_recent = self.query().is_empty() && !self.recent.is_empty();
+
         html! {
             div class="sh-search-wrapper" {
                 (self.input.clone())
 
                 @if self.show_results && !self.results.is_empty() {
-                    ul class="sh-search-results" role="listbox" {
+                    ul class="sh-search-results" aria-label="Search results" {
                         @for result in &self.results {
-                            li class="sh-search-result" role="option" {
-                                @if let Some(href) = result.href {
-                                    a href=(href) class="sh-search-result__link" {
-                                        @if let Some(icon) = result.icon {
-                                            span class="sh-search-result__icon" {
-                                                (maud::PreEscaped(icon))
-                                            }
-                                        }
-                                        div class="sh-search-result__content" {
-                                            span class="sh-search-result__title" { (result.title) }
-                                            @if let Some(desc) = result.description {
-                                                span class="sh-search-result__desc" { (desc) }
-                                            }
-                                        }
-                                    }
-                                } @else {
-                                    div class="sh-search-result__link" {
-                                        @if let Some(icon) = result.icon {
-                                            span class="sh-search-result__icon" {
-                                                (maud::PreEscaped(icon))
-                                            }
-                                        }
-                                        div class="sh-search-result__content" {
-                                            span class="sh-search-result__title" { (result.title) }
-                                            @if let Some(desc) = result.description {
-                                                span class="sh-search-result__desc" { (desc) }
-                                            }
-                                        }
-                                    }
+                            li class="sh-search-result" {
+                                (self.render_result(result))
+                            }
+                        }
+                    }
+                } @else if show_recent {
+                    nav class="sh-search-recent" aria-label="Recent searches" {
+                        span class="sh-search-recent__title" { "Recent searches" }
+                        ul class="sh-search-recent__list" {
+                            @for query in &self.recent {
+                                li {
+                                    a class="sh-search-recent__link" href=(self.input.search_href(query)) { (query) }
                                 }
                             }
                         }
@@ -204,6 +445,43 @@ pub fn search_input_css() -> String {
     width: 100%;
 }
 
+.sh-search-form {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    width: 100%;
+    margin: 0;
+}
+
+.sh-search__scope {
+    flex-shrink: 0;
+    padding: 0.625rem 0.75rem;
+    font-size: 0.875rem;
+    color: var(--sh-text, #1f2937);
+    background: var(--sh-surface-2, #f3f4f6);
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+}
+
+.sh-search__shortcut {
+    position: absolute;
+    right: 0.75rem;
+    padding: 0.125rem 0.375rem;
+    font-family: var(--sh-font-mono, ui-monospace, monospace);
+    font-size: 0.75rem;
+    line-height: 1.25;
+    color: var(--sh-text-muted, #6b7280);
+    background: var(--sh-surface-2, #f3f4f6);
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-sm, 0.25rem);
+    pointer-events: none;
+}
+
+.sh-search__input:focus ~ .sh-search__shortcut,
+.sh-search__input:not(:placeholder-shown) ~ .sh-search__shortcut {
+    display: none;
+}
+
 .sh-search {
     position: relative;
     display: flex;
@@ -363,6 +641,46 @@ a.sh-search-result__link:hover {
     color: var(--sh-text-muted, #6b7280);
     margin-top: 0.125rem;
 }
+
+.sh-search-result__match {
+    padding: 0;
+    color: inherit;
+    font-weight: 600;
+    background: color-mix(in srgb, var(--sh-warning, #f59e0b) 30%, transparent);
+    border-radius: 2px;
+}
+
+/* Recent searches */
+.sh-search-recent {
+    margin-top: 0.5rem;
+    padding: 0.5rem 0;
+    font-size: 0.875rem;
+}
+
+.sh-search-recent__title {
+    display: block;
+    padding: 0 1rem 0.25rem;
+    font-size: 0.75rem;
+    font-weight: 600;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-search-recent__list {
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-search-recent__link {
+    display: block;
+    padding: 0.375rem 1rem;
+    color: var(--sh-text, #1f2937);
+    text-decoration: none;
+}
+
+.sh-search-recent__link:hover {
+    background: var(--sh-surface-hover, #f3f4f6);
+}
 "#
     .to_string()
 }
@@ -397,4 +715,84 @@ mod tests {
 
         assert_eq!(search.results.len(), 1);
     }
+
+    #[test]
+    fn test_search_input_get_form() {
+        let html = SearchInput::new("q")
+            .action("/search")
+            .value("grid")
+            .scopes(SearchScope::site_defaults())
+            .scope("docs")
+            .suggestions(vec!["grid", "button"])
+            .shortcut("/")
+            .render()
+            .into_string();
+        assert!(html.starts_with(
+            r#"<form class="sh-search-form" role="search" method="get" action="/search">"#
+        ));
+        assert!(html
+            .contains(r#"<select class="sh-search__scope" name="scope" aria-label="Search in">"#));
+        assert!(html.contains(r#"<option value="docs" selected>Docs</option>"#));
+        assert!(html.contains(r#"list="sh-search-q-suggestions" accesskey="/""#));
+        assert!(html.contains(r#"<option value="button"></option>"#));
+        assert!(html.contains(r#"<kbd class="sh-search__shortcut" aria-hidden="true">/</kbd>"#));
+        assert!(html.contains(r#"<a href="/search" class="sh-search__clear""#));
+        assert!(
+            html.contains(r#"<button type="submit" class="sh-visually-hidden">Search</button>"#)
+        );
+    }
+
+    #[test]
+    fn test_search_input_embedded() {
+        let html = SearchInput::new("q")
+            .embedded(true)
+            .shortcut("Ctrl K")
+            .render()
+            .into_string();
+        assert!(html.starts_with(r#"<div class="sh-search-form">"#));
+        assert!(!html.contains("<form"));
+        assert!(!html.contains("accesskey"));
+        assert!(!html.contains("submit"));
+        assert!(html.contains(r#"<label for="sh-search-q" class="sh-search__clear">"#));
+    }
+
+    #[test]
+    fn test_highlight_matches() {
+        assert_eq!(
+            highlight_matches("Grid layout grid", "GRID").into_string(),
+            r#"<mark class="sh-search-result__match">Grid</mark> layout <mark class="sh-search-result__match">grid</mark>"#
+        );
+        assert_eq!(
+            highlight_matches("Button <b>", "butt ton").into_string(),
+            r#"<mark class="sh-search-result__match">Button</mark> &lt;b&gt;"#
+        );
+        assert_eq!(highlight_matches("Café", "").into_string(), "Café");
+    }
+
+    #[test]
+    fn test_search_results_and_recent() {
+        let results = || {
+            vec![SearchResult {
+                title: "Card grid",
+                description: None,
+                href: Some("/docs/grid"),
+                icon: None,
+            }]
+        };
+        let html = SearchWithResults::new(SearchInput::new("q").value("grid"))
+            .results(results())
+            .show_results(true)
+            .recent(vec!["button"])
+            .render()
+            .into_string();
+        assert!(html.contains(r#"Card <mark class="sh-search-result__match">grid</mark>"#));
+        assert!(!html.contains("sh-search-recent"));
+
+        let html = SearchWithResults::new(SearchInput::new("q").action("/search").scope("docs"))
+            .recent(vec!["date picker"])
+            .render()
+            .into_string();
+        assert!(html.contains(r#"href="/search?q=date%20picker&amp;scope=docs""#));
+        assert!(html.contains(r#"aria-label="Recent searches""#));
+    }
 }
@@ -0,0 +1,470 @@
+//! Search Engine Files
+//!
+//! `sitemap.xml` and `robots.txt` builders for static exports. Sitemaps
+//! larger than the protocol limit of 50,000 URLs are split into numbered
+//! parts with a `sitemap.xml` index pointing at them.
+
+use crate::feed::XML_DECLARATION;
+use maud::{html, Markup, PreEscaped};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// Maximum URLs allowed in a single sitemap file
+pub const MAX_SITEMAP_URLS: usize = 50_000;
+
+/// How often a page is expected to change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeFreq {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+
+impl ChangeFreq {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeFreq::Always => "always",
+            ChangeFreq::Hourly => "hourly",
+            ChangeFreq::Daily => "daily",
+            ChangeFreq::Weekly => "weekly",
+            ChangeFreq::Monthly => "monthly",
+            ChangeFreq::Yearly => "yearly",
+            ChangeFreq::Never => "never",
+        }
+    }
+}
+
+/// A sitemap entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<String>,
+    pub changefreq: Option<ChangeFreq>,
+    pub priority: Option<f32>,
+}
+
+impl SitemapUrl {
+    /// Create an entry; relative paths are resolved against the sitemap base URL
+    pub fn new(loc: impl Into<String>) -> Self {
+        Self {
+            loc: loc.into(),
+            lastmod: None,
+            changefreq: None,
+            priority: None,
+        }
+    }
+
+    /// Last modification date (`YYYY-MM-DD` or RFC 3339)
+    pub fn lastmod(mut self, lastmod: impl Into<String>) -> Self {
+        self.lastmod = Some(lastmod.into());
+        self
+    }
+
+    pub fn changefreq(mut self, changefreq: ChangeFreq) -> Self {
+        self.changefreq = Some(changefreq);
+        self
+    }
+
+    /// Relative priority, clamped to `0.0..=1.0`
+    pub fn priority(mut self, priority: f32) -> Self {
+        self.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+}
+
+/// A generated file: name relative to the output directory, and contents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeoFile {
+    pub name: String,
+    pub contents: String,
+}
+
+pub(crate) fn write_files(dir: &Path, files: Vec<SeoFile>) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    files
+        .into_iter()
+        .map(|file| {
+            let path = dir.join(&file.name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, file.contents)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// `sitemap.xml` builder
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sitemap {
+    base_url: String,
+    urls: Vec<SitemapUrl>,
+    max_urls_per_file: usize,
+}
+
+impl Sitemap {
+    /// Create a sitemap for the site at `base_url` (e.g. `https://shallot.rs`)
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            urls: Vec::new(),
+            max_urls_per_file: MAX_SITEMAP_URLS,
+        }
+    }
+
+    pub fn url(mut self, url: SitemapUrl) -> Self {
+        self.urls.push(url);
+        self
+    }
+
+    pub fn urls(mut self, urls: impl IntoIterator<Item = SitemapUrl>) -> Self {
+        self.urls.extend(urls);
+        self
+    }
+
+    /// Lower the per-file URL limit (capped at [`MAX_SITEMAP_URLS`])
+    pub fn max_urls_per_file(mut self, max: usize) -> Self {
+        self.max_urls_per_file = max.clamp(1, MAX_SITEMAP_URLS);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.urls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    /// Public URL of `sitemap.xml`, for `robots.txt`
+    pub fn index_url(&self) -> String {
+        self.absolute("sitemap.xml")
+    }
+
+    fn absolute(&self, loc: &str) -> String {
+        if loc.contains("://") {
+            loc.to_string()
+        } else {
+            format!("{}/{}", self.base_url, loc.trim_start_matches('/'))
+        }
+    }
+
+    fn render_urlset(&self, urls: &[SitemapUrl]) -> String {
+        let markup: Markup = html! {
+            (PreEscaped(XML_DECLARATION))
+            urlset xmlns=(SITEMAP_NS) {
+                @for url in urls {
+                    url {
+                        loc { (self.absolute(&url.loc)) }
+                        @if let Some(lastmod) = &url.lastmod {
+                            lastmod { (lastmod) }
+                        }
+                        @if let Some(changefreq) = url.changefreq {
+                            changefreq { (changefreq.as_str()) }
+                        }
+                        @if let Some(priority) = url.priority {
+                            priority { (format!("{:.1}", priority)) }
+                        }
+                    }
+                }
+            }
+        };
+        markup.into_string()
+    }
+
+    fn render_index(&self, parts: &[(String, Option<&str>)]) -> String {
+        let markup: Markup = html! {
+            (PreEscaped(XML_DECLARATION))
+            sitemapindex xmlns=(SITEMAP_NS) {
+                @for (name, lastmod) in parts {
+                    sitemap {
+                        loc { (self.absolute(name)) }
+                        @if let Some(lastmod) = lastmod {
+                            lastmod { (lastmod) }
+                        }
+                    }
+                }
+            }
+        };
+        markup.into_string()
+    }
+
+    /// Render `sitemap.xml`, or an index plus `sitemap-N.xml` parts when
+    /// the URL count exceeds the per-file limit
+    pub fn files(&self) -> Vec<SeoFile> {
+        if self.urls.len() <= self.max_urls_per_file {
+            return vec![SeoFile {
+                name: "sitemap.xml".to_string(),
+                contents: self.render_urlset(&self.urls),
+            }];
+        }
+
+        let mut files = Vec::new();
+        let mut parts = Vec::new();
+        for (i, chunk) in self.urls.chunks(self.max_urls_per_file).enumerate() {
+            let name = format!("sitemap-{}.xml", i + 1);
+            // ISO dates compare correctly as strings
+            let lastmod = chunk.iter().filter_map(|url| url.lastmod.as_deref()).max();
+            parts.push((name.clone(), lastmod));
+            files.push(SeoFile {
+                name,
+                contents: self.render_urlset(chunk),
+            });
+        }
+        files.insert(
+            0,
+            SeoFile {
+                name: "sitemap.xml".to_string(),
+                contents: self.render_index(&parts),
+            },
+        );
+        files
+    }
+
+    /// Write the sitemap file(s) into an output directory
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        write_files(dir.as_ref(), self.files())
+    }
+}
+
+/// Rules for one or more user agents
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobotsGroup {
+    pub user_agents: Vec<String>,
+    pub allow: Vec<String>,
+    pub disallow: Vec<String>,
+    pub crawl_delay: Option<u32>,
+}
+
+impl RobotsGroup {
+    pub fn new(user_agent: impl Into<String>) -> Self {
+        Self {
+            user_agents: vec![user_agent.into()],
+            allow: Vec::new(),
+            disallow: Vec::new(),
+            crawl_delay: None,
+        }
+    }
+
+    /// Apply the same rules to another user agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agents.push(user_agent.into());
+        self
+    }
+
+    pub fn allow(mut self, path: impl Into<String>) -> Self {
+        self.allow.push(path.into());
+        self
+    }
+
+    pub fn disallow(mut self, path: impl Into<String>) -> Self {
+        self.disallow.push(path.into());
+        self
+    }
+
+    pub fn crawl_delay(mut self, seconds: u32) -> Self {
+        self.crawl_delay = Some(seconds);
+        self
+    }
+
+    fn render(&self, out: &mut String) {
+        for agent in &self.user_agents {
+            out.push_str(&format!("User-agent: {}\n", agent));
+        }
+        for path in &self.allow {
+            out.push_str(&format!("Allow: {}\n", path));
+        }
+        for path in &self.disallow {
+            out.push_str(&format!("Disallow: {}\n", path));
+        }
+        // A group needs at least one rule; an empty Disallow allows everything
+        if self.allow.is_empty() && self.disallow.is_empty() {
+            out.push_str("Disallow:\n");
+        }
+        if let Some(delay) = self.crawl_delay {
+            out.push_str(&format!("Crawl-delay: {}\n", delay));
+        }
+    }
+}
+
+/// `robots.txt` builder
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Robots {
+    groups: Vec<RobotsGroup>,
+    sitemaps: Vec<String>,
+}
+
+impl Robots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow every crawler everywhere
+    pub fn allow_all() -> Self {
+        Self::new().group(RobotsGroup::new("*").allow("/"))
+    }
+
+    /// Keep every crawler out (staging and preview builds)
+    pub fn disallow_all() -> Self {
+        Self::new().group(RobotsGroup::new("*").disallow("/"))
+    }
+
+    pub fn group(mut self, group: RobotsGroup) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Advertise a sitemap by absolute URL
+    pub fn sitemap(mut self, url: impl Into<String>) -> Self {
+        self.sitemaps.push(url.into());
+        self
+    }
+
+    /// Advertise the index of a [`Sitemap`]
+    pub fn with_sitemap(self, sitemap: &Sitemap) -> Self {
+        self.sitemap(sitemap.index_url())
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let groups = if self.groups.is_empty() {
+            vec![RobotsGroup::new("*")]
+        } else {
+            self.groups.clone()
+        };
+
+        for (i, group) in groups.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            group.render(&mut out);
+        }
+
+        if !self.sitemaps.is_empty() {
+            out.push('\n');
+            for url in &self.sitemaps {
+                out.push_str(&format!("Sitemap: {}\n", url));
+            }
+        }
+        out
+    }
+
+    /// Write `robots.txt` into an output directory
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = dir.as_ref().join("robots.txt");
+        fs::create_dir_all(dir.as_ref())?;
+        fs::write(&path, self.render())?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sitemap_single_file() {
+        let sitemap = Sitemap::new("https://shallot.rs/")
+            .url(
+                SitemapUrl::new("/")
+                    .lastmod("2026-02-24")
+                    .changefreq(ChangeFreq::Weekly)
+                    .priority(1.5),
+            )
+            .url(SitemapUrl::new("docs?a=1&b=2"))
+            .url(SitemapUrl::new("https://cdn.shallot.rs/x"));
+        let files = sitemap.files();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "sitemap.xml");
+        let xml = &files[0].contents;
+        assert!(xml.starts_with(XML_DECLARATION));
+        assert!(xml.contains(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#));
+        assert!(xml.contains("<loc>https://shallot.rs/</loc><lastmod>2026-02-24</lastmod>"));
+        assert!(xml.contains("<changefreq>weekly</changefreq><priority>1.0</priority>"));
+        assert!(xml.contains("<loc>https://shallot.rs/docs?a=1&amp;b=2</loc>"));
+        assert!(xml.contains("<loc>https://cdn.shallot.rs/x</loc>"));
+    }
+
+    #[test]
+    fn test_sitemap_splits_into_index() {
+        let sitemap =
+            Sitemap::new("https://shallot.rs")
+                .max_urls_per_file(2)
+                .urls((1..=5).map(|i| {
+                    SitemapUrl::new(format!("/p{}", i)).lastmod(format!("2026-01-0{}", i))
+                }));
+        let files = sitemap.files();
+
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "sitemap.xml",
+                "sitemap-1.xml",
+                "sitemap-2.xml",
+                "sitemap-3.xml"
+            ]
+        );
+        assert!(files[0].contents.contains("<sitemapindex"));
+        assert!(files[0]
+            .contents
+            .contains("<loc>https://shallot.rs/sitemap-2.xml</loc><lastmod>2026-01-04</lastmod>"));
+        assert!(files[3].contents.contains("/p5"));
+        assert_eq!(sitemap.max_urls_per_file(0).max_urls_per_file, 1);
+    }
+
+    #[test]
+    fn test_robots_render() {
+        let sitemap = Sitemap::new("https://shallot.rs");
+        let robots = Robots::new()
+            .group(
+                RobotsGroup::new("*")
+                    .allow("/")
+                    .disallow("/drafts/")
+                    .crawl_delay(5),
+            )
+            .group(RobotsGroup::new("GPTBot").user_agent("CCBot").disallow("/"))
+            .with_sitemap(&sitemap);
+
+        assert_eq!(
+            robots.render(),
+            "User-agent: *\nAllow: /\nDisallow: /drafts/\nCrawl-delay: 5\n\n\
+             User-agent: GPTBot\nUser-agent: CCBot\nDisallow: /\n\n\
+             Sitemap: https://shallot.rs/sitemap.xml\n"
+        );
+    }
+
+    #[test]
+    fn test_robots_presets() {
+        assert_eq!(Robots::new().render(), "User-agent: *\nDisallow:\n");
+        assert_eq!(Robots::allow_all().render(), "User-agent: *\nAllow: /\n");
+        assert_eq!(
+            Robots::disallow_all().render(),
+            "User-agent: *\nDisallow: /\n"
+        );
+    }
+
+    #[test]
+    fn test_write_to_output_dir() {
+        let dir = std::env::temp_dir().join(format!("shallot-seo-{}", std::process::id()));
+        let sitemap = Sitemap::new("https://shallot.rs").url(SitemapUrl::new("/"));
+
+        let written = sitemap.write_to(&dir).unwrap();
+        let robots = Robots::allow_all()
+            .with_sitemap(&sitemap)
+            .write_to(&dir)
+            .unwrap();
+
+        assert_eq!(written, vec![dir.join("sitemap.xml")]);
+        assert!(fs::read_to_string(robots).unwrap().contains("Sitemap:"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
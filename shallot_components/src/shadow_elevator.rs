@@ -1,11 +1,13 @@
 //! ShadowElevator Component - Dynamic Depth Shadow System
 //!
 //! A component that creates dynamic elevation shadows using CSS.
-//! Simulates material design elevation levels.
+//! Levels map onto the shared [`Elevation`] scale, so an elevator matches
+//! cards, dialogs and menus at the same depth.
 
 use maud::{html, Markup, Render};
+use shallot_foundation::Elevation;
 
-/// Shadow elevation levels (0-5)
+/// Shadow elevation levels (0-5), numbered aliases for [`Elevation`] steps
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ShadowLevel {
     #[default]
@@ -17,15 +19,15 @@ pub enum ShadowLevel {
     Level5, // Maximum
 }
 
-impl ShadowLevel {
-    fn css_class(&self) -> &'static str {
-        match self {
-            ShadowLevel::Level0 => "sh-shadow--0",
-            ShadowLevel::Level1 => "sh-shadow--1",
-            ShadowLevel::Level2 => "sh-shadow--2",
-            ShadowLevel::Level3 => "sh-shadow--3",
-            ShadowLevel::Level4 => "sh-shadow--4",
-            ShadowLevel::Level5 => "sh-shadow--5",
+impl From<ShadowLevel> for Elevation {
+    fn from(level: ShadowLevel) -> Self {
+        match level {
+            ShadowLevel::Level0 => Elevation::None,
+            ShadowLevel::Level1 => Elevation::Low,
+            ShadowLevel::Level2 => Elevation::Default,
+            ShadowLevel::Level3 => Elevation::High,
+            ShadowLevel::Level4 => Elevation::Higher,
+            ShadowLevel::Level5 => Elevation::Highest,
         }
     }
 }
@@ -33,7 +35,7 @@ impl ShadowLevel {
 /// ShadowElevator component
 pub struct ShadowElevator<'a> {
     children: Markup,
-    level: ShadowLevel,
+    level: Elevation,
     interactive: bool,
     class: Option<&'a str>,
 }
@@ -43,15 +45,15 @@ impl<'a> ShadowElevator<'a> {
     pub fn new(children: Markup) -> Self {
         Self {
             children,
-            level: ShadowLevel::default(),
+            level: Elevation::None,
             interactive: false,
             class: None,
         }
     }
 
-    /// Set shadow level
-    pub fn level(mut self, level: ShadowLevel) -> Self {
-        self.level = level;
+    /// Set shadow level, as a [`ShadowLevel`] or an [`Elevation`] step
+    pub fn level(mut self, level: impl Into<Elevation>) -> Self {
+        self.level = level.into();
         self
     }
 
@@ -69,7 +71,7 @@ impl<'a> ShadowElevator<'a> {
 
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-shadow-elevator".to_string()];
-        classes.push(self.level.css_class().to_string());
+        classes.push(format!("sh-shadow--{}", self.level.level()));
         if self.interactive {
             classes.push("sh-shadow-elevator--interactive".to_string());
         }
@@ -107,75 +109,24 @@ pub fn shadow_elevator_css() -> String {
 }
 
 /* Elevation levels */
-.sh-shadow--0 {
-    box-shadow: none;
-}
-
-.sh-shadow--1 {
-    box-shadow:
-        0 1px 2px rgba(0, 0, 0, 0.05),
-        0 1px 3px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow--2 {
-    box-shadow:
-        0 4px 6px rgba(0, 0, 0, 0.05),
-        0 10px 15px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow--3 {
-    box-shadow:
-        0 10px 15px rgba(0, 0, 0, 0.05),
-        0 20px 25px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow--4 {
-    box-shadow:
-        0 15px 20px rgba(0, 0, 0, 0.05),
-        0 30px 40px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow--5 {
-    box-shadow:
-        0 20px 25px rgba(0, 0, 0, 0.05),
-        0 40px 60px rgba(0, 0, 0, 0.15);
-}
-
-/* Interactive hover - elevates on hover */
+.sh-shadow--0 { box-shadow: var(--sh-elevation-0); }
+.sh-shadow--1 { box-shadow: var(--sh-elevation-1); }
+.sh-shadow--2 { box-shadow: var(--sh-elevation-2); }
+.sh-shadow--3 { box-shadow: var(--sh-elevation-3); }
+.sh-shadow--4 { box-shadow: var(--sh-elevation-4); }
+.sh-shadow--5 { box-shadow: var(--sh-elevation-5); }
+
+/* Interactive hover - lifts one step up the scale */
 .sh-shadow-elevator--interactive:hover {
     transform: translateY(-4px);
 }
 
-.sh-shadow-elevator--interactive.sh-shadow--0:hover {
-    box-shadow:
-        0 4px 6px rgba(0, 0, 0, 0.05),
-        0 10px 15px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow-elevator--interactive.sh-shadow--1:hover {
-    box-shadow:
-        0 10px 15px rgba(0, 0, 0, 0.05),
-        0 20px 25px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow-elevator--interactive.sh-shadow--2:hover {
-    box-shadow:
-        0 15px 20px rgba(0, 0, 0, 0.05),
-        0 30px 40px rgba(0, 0, 0, 0.1);
-}
-
-.sh-shadow-elevator--interactive.sh-shadow--3:hover {
-    box-shadow:
-        0 20px 25px rgba(0, 0, 0, 0.05),
-        0 40px 60px rgba(0, 0, 0, 0.15);
-}
-
+.sh-shadow-elevator--interactive.sh-shadow--0:hover { box-shadow: var(--sh-elevation-1); }
+.sh-shadow-elevator--interactive.sh-shadow--1:hover { box-shadow: var(--sh-elevation-2); }
+.sh-shadow-elevator--interactive.sh-shadow--2:hover { box-shadow: var(--sh-elevation-3); }
+.sh-shadow-elevator--interactive.sh-shadow--3:hover { box-shadow: var(--sh-elevation-4); }
 .sh-shadow-elevator--interactive.sh-shadow--4:hover,
-.sh-shadow-elevator--interactive.sh-shadow--5:hover {
-    box-shadow:
-        0 25px 30px rgba(0, 0, 0, 0.05),
-        0 50px 80px rgba(0, 0, 0, 0.2);
-}
+.sh-shadow-elevator--interactive.sh-shadow--5:hover { box-shadow: var(--sh-elevation-5); }
 
 /* Active/pressed state */
 .sh-shadow-elevator--interactive:active {
@@ -187,39 +138,6 @@ pub fn shadow_elevator_css() -> String {
     outline: 2px solid var(--sh-primary, #3b82f6);
     outline-offset: 2px;
 }
-
-/* Dark mode adjustments */
-@media (prefers-color-scheme: dark) {
-    .sh-shadow--1 {
-        box-shadow:
-            0 1px 2px rgba(0, 0, 0, 0.1),
-            0 1px 3px rgba(0, 0, 0, 0.2);
-    }
-
-    .sh-shadow--2 {
-        box-shadow:
-            0 4px 6px rgba(0, 0, 0, 0.1),
-            0 10px 15px rgba(0, 0, 0, 0.2);
-    }
-
-    .sh-shadow--3 {
-        box-shadow:
-            0 10px 15px rgba(0, 0, 0, 0.1),
-            0 20px 25px rgba(0, 0, 0, 0.2);
-    }
-
-    .sh-shadow--4 {
-        box-shadow:
-            0 15px 20px rgba(0, 0, 0, 0.1),
-            0 30px 40px rgba(0, 0, 0, 0.25);
-    }
-
-    .sh-shadow--5 {
-        box-shadow:
-            0 20px 25px rgba(0, 0, 0, 0.1),
-            0 40px 60px rgba(0, 0, 0, 0.3);
-    }
-}
 "#
     .to_string()
 }
@@ -231,13 +149,17 @@ mod tests {
     #[test]
     fn test_shadow_creation() {
         let shadow = ShadowElevator::new(html! { "Content" });
-        assert_eq!(shadow.level, ShadowLevel::Level0);
+        assert_eq!(shadow.level, Elevation::None);
     }
 
     #[test]
     fn test_shadow_level() {
         let shadow = ShadowElevator::new(html! {}).level(ShadowLevel::Level5);
-        assert_eq!(shadow.level, ShadowLevel::Level5);
+        assert_eq!(shadow.level, Elevation::Highest);
+        assert!(shadow.build_classes().contains("sh-shadow--5"));
+
+        let shadow = ShadowElevator::new(html! {}).level(Elevation::High);
+        assert!(shadow.build_classes().contains("sh-shadow--3"));
     }
 
     #[test]
@@ -251,6 +173,6 @@ mod tests {
         let css = shadow_elevator_css();
         assert!(css.contains(".sh-shadow-elevator"));
         assert!(css.contains(".sh-shadow--1"));
-        assert!(css.contains(".sh-shadow--5"));
+        assert!(css.contains(".sh-shadow--5 { box-shadow: var(--sh-elevation-5); }"));
     }
 }
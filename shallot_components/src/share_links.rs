@@ -0,0 +1,406 @@
+//! Share Links Component - Social sharing without scripts or trackers
+//!
+//! Every network is a plain link to its share endpoint. Mastodon has no
+//! central endpoint, so its small form asks for the visitor's instance and
+//! submits it to a route of yours, which answers with a redirect to
+//! [`mastodon_share_url`]. "Copy link" is a read-only field whose text
+//! selects in one click.
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Field carrying the visitor's Mastodon instance
+pub const MASTODON_INSTANCE_FIELD: &str = "instance";
+
+/// Hidden field carrying the text to share on Mastodon
+pub const MASTODON_TEXT_FIELD: &str = "text";
+
+/// Percent-encode everything except RFC 3986 unreserved characters
+pub fn encode_uri_component(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Share URL on a Mastodon instance. Accepts "mastodon.social",
+/// "https://mastodon.social/" or "@me@mastodon.social"; returns `None`
+/// unless the instance is a plain host name.
+pub fn mastodon_share_url(instance: &str, text: &str) -> Option<String> {
+    let instance = instance.trim();
+    let instance = instance
+        .strip_prefix("https://")
+        .or_else(|| instance.strip_prefix("http://"))
+        .unwrap_or(instance);
+    let host = instance.rsplit('@').next()?.trim_end_matches('/');
+    let valid = host.contains('.')
+        && !host.starts_with(['.', '-'])
+        && !host.ends_with(['.', '-'])
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    valid.then(|| {
+        format!(
+            "https://{}/share?text={}",
+            host.to_ascii_lowercase(),
+            encode_uri_component(text)
+        )
+    })
+}
+
+/// Where a page can be shared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareTarget {
+    Email,
+    X,
+    Facebook,
+    LinkedIn,
+    Mastodon,
+    CopyLink,
+}
+
+impl ShareTarget {
+    /// Every target, in display order
+    pub const ALL: [ShareTarget; 6] = [
+        ShareTarget::Email,
+        ShareTarget::X,
+        ShareTarget::Facebook,
+        ShareTarget::LinkedIn,
+        ShareTarget::Mastodon,
+        ShareTarget::CopyLink,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShareTarget::Email => "Email",
+            ShareTarget::X => "X",
+            ShareTarget::Facebook => "Facebook",
+            ShareTarget::LinkedIn => "LinkedIn",
+            ShareTarget::Mastodon => "Mastodon",
+            ShareTarget::CopyLink => "Copy link",
+        }
+    }
+
+    fn modifier(&self) -> &'static str {
+        match self {
+            ShareTarget::Email => "email",
+            ShareTarget::X => "x",
+            ShareTarget::Facebook => "facebook",
+            ShareTarget::LinkedIn => "linkedin",
+            ShareTarget::Mastodon => "mastodon",
+            ShareTarget::CopyLink => "copy",
+        }
+    }
+
+    /// Share link for `url`, or `None` for targets that are not links
+    pub fn share_url(&self, url: &str, title: &str) -> Option<String> {
+        let url = encode_uri_component(url);
+        let title = encode_uri_component(title);
+        match self {
+            ShareTarget::Email => Some(format!("mailto:?subject={}&body={}", title, url)),
+            ShareTarget::X => Some(format!(
+                "https://x.com/intent/post?text={}&url={}",
+                title, url
+            )),
+            ShareTarget::Facebook => Some(format!(
+                "https://www.facebook.com/sharer/sharer.php?u={}",
+                url
+            )),
+            ShareTarget::LinkedIn => Some(format!(
+                "https://www.linkedin.com/sharing/share-offsite/?url={}",
+                url
+            )),
+            ShareTarget::Mastodon | ShareTarget::CopyLink => None,
+        }
+    }
+}
+
+/// Row of share links for one page
+pub struct ShareLinks<'a> {
+    pub url: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub targets: Vec<ShareTarget>,
+    pub mastodon_action: Option<Cow<'a, str>>,
+    pub label: Cow<'a, str>,
+}
+
+impl<'a> ShareLinks<'a> {
+    /// `url` should be the absolute, canonical page URL
+    pub fn new(url: impl Into<Cow<'a, str>>, title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            url: url.into(),
+            title: title.into(),
+            targets: ShareTarget::ALL.to_vec(),
+            mastodon_action: None,
+            label: Cow::Borrowed("Share"),
+        }
+    }
+
+    /// Limit and order the targets
+    pub fn targets(mut self, targets: impl IntoIterator<Item = ShareTarget>) -> Self {
+        self.targets = targets.into_iter().collect();
+        self
+    }
+
+    /// Route that redirects to [`mastodon_share_url`]; Mastodon is left out
+    /// until this is set
+    pub fn mastodon_action(mut self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.mastodon_action = Some(action.into());
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    fn render_target(&self, target: ShareTarget) -> Markup {
+        let class = format!("sh-share__link sh-share__link--{}", target.modifier());
+        match target {
+            ShareTarget::Mastodon => match &self.mastodon_action {
+                Some(action) => html! {
+                    form class="sh-share__mastodon" action=(action) method="get" target="_blank" {
+                        input type="hidden" name=(MASTODON_TEXT_FIELD) value=(format!("{} {}", self.title, self.url));
+                        label class="sh-visually-hidden" for="sh-share-mastodon-instance" { "Your Mastodon instance" }
+                        input
+                            class="sh-share__instance"
+                            id="sh-share-mastodon-instance"
+                            type="text"
+                            name=(MASTODON_INSTANCE_FIELD)
+                            placeholder="mastodon.social"
+                            inputmode="url"
+                            autocomplete="off"
+                            spellcheck="false"
+                            required;
+                        button class=(class) type="submit" { (target.label()) }
+                    }
+                },
+                None => html! {},
+            },
+            ShareTarget::CopyLink => html! {
+                label class="sh-share__copy" {
+                    span class="sh-share__copy-label" { (target.label()) }
+                    input class="sh-share__copy-input" type="text" value=(self.url) readonly;
+                }
+            },
+            _ => {
+                let href = target.share_url(&self.url, &self.title).unwrap_or_default();
+                let external = target != ShareTarget::Email;
+                html! {
+                    a
+                        class=(class)
+                        href=(href)
+                        target=[external.then_some("_blank")]
+                        rel=[external.then_some("noopener noreferrer")] {
+                        (target.label())
+                        @if external {
+                            span class="sh-visually-hidden" { " (opens in a new tab)" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for ShareLinks<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            nav class="sh-share" aria-label=(self.label) {
+                span class="sh-share__label" aria-hidden="true" { (self.label) }
+                ul class="sh-share__list" {
+                    @for target in &self.targets {
+                        @if *target != ShareTarget::Mastodon || self.mastodon_action.is_some() {
+                            li class="sh-share__item" { (self.render_target(*target)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for share links
+pub fn share_links_css() -> String {
+    r#"
+.sh-share {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.75rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 0.875rem;
+}
+
+.sh-share__label {
+    font-weight: 600;
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-share__list {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-share__link {
+    display: inline-flex;
+    align-items: center;
+    height: 2rem;
+    padding: 0 0.75rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: 9999px;
+    background: var(--sh-surface, #fff);
+    color: var(--sh-text, #1f2937);
+    font: inherit;
+    font-weight: 500;
+    text-decoration: none;
+    cursor: pointer;
+}
+
+.sh-share__link:hover,
+.sh-share__link:focus-visible {
+    border-color: var(--sh-primary, #3b82f6);
+    color: var(--sh-primary, #3b82f6);
+}
+
+.sh-share__mastodon {
+    display: flex;
+    align-items: center;
+    gap: 0.25rem;
+}
+
+.sh-share__instance,
+.sh-share__copy-input {
+    height: 2rem;
+    padding: 0 0.625rem;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.375rem);
+    background: var(--sh-surface, #fff);
+    color: var(--sh-text, #1f2937);
+    font: inherit;
+}
+
+.sh-share__instance {
+    width: 10rem;
+}
+
+.sh-share__copy {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+}
+
+.sh-share__copy-label {
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-share__copy-input {
+    width: 14rem;
+    color: var(--sh-text-muted, #6b7280);
+    user-select: all;
+    -webkit-user-select: all;
+}
+
+.sh-share__copy-input:focus {
+    outline: none;
+    border-color: var(--sh-primary, #3b82f6);
+    color: var(--sh-text, #1f2937);
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_uri_component() {
+        assert_eq!(
+            encode_uri_component("https://example.com/a b?x=1&y=é"),
+            "https%3A%2F%2Fexample.com%2Fa%20b%3Fx%3D1%26y%3D%C3%A9"
+        );
+        assert_eq!(encode_uri_component("safe-_.~"), "safe-_.~");
+    }
+
+    #[test]
+    fn test_share_urls() {
+        let url = "https://example.com/post";
+        assert_eq!(
+            ShareTarget::Email.share_url(url, "Hello world").unwrap(),
+            "mailto:?subject=Hello%20world&body=https%3A%2F%2Fexample.com%2Fpost"
+        );
+        assert_eq!(
+            ShareTarget::X.share_url(url, "Hi").unwrap(),
+            "https://x.com/intent/post?text=Hi&url=https%3A%2F%2Fexample.com%2Fpost"
+        );
+        assert!(ShareTarget::LinkedIn
+            .share_url(url, "Hi")
+            .unwrap()
+            .ends_with("?url=https%3A%2F%2Fexample.com%2Fpost"));
+        assert_eq!(ShareTarget::CopyLink.share_url(url, "Hi"), None);
+    }
+
+    #[test]
+    fn test_mastodon_share_url() {
+        let expected = Some("https://mastodon.social/share?text=Read%20this".to_string());
+        assert_eq!(mastodon_share_url("mastodon.social", "Read this"), expected);
+        assert_eq!(
+            mastodon_share_url(" https://Mastodon.Social/ ", "Read this"),
+            expected
+        );
+        assert_eq!(
+            mastodon_share_url("@me@mastodon.social", "Read this"),
+            expected
+        );
+        assert_eq!(mastodon_share_url("evil.com/path?x", "t"), None);
+        assert_eq!(mastodon_share_url("localhost", "t"), None);
+        assert_eq!(mastodon_share_url("", "t"), None);
+    }
+
+    #[test]
+    fn test_share_links_render() {
+        let html = ShareLinks::new("https://example.com/post", "My post")
+            .mastodon_action("/share/mastodon")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"aria-label="Share""#));
+        assert!(html.contains("sh-share__link--facebook"));
+        assert!(html.contains(r#"rel="noopener noreferrer""#));
+        assert!(html.contains(r#"action="/share/mastodon" method="get""#));
+        assert!(html.contains(r#"name="text" value="My post https://example.com/post""#));
+        assert!(html.contains(r#"value="https://example.com/post" readonly"#));
+        // mailto opens in the mail client, not a tab
+        assert!(html.contains(r#"<a class="sh-share__link sh-share__link--email" href="mailto:"#));
+    }
+
+    #[test]
+    fn test_share_links_targets() {
+        let html = ShareLinks::new("https://example.com", "Home")
+            .targets([ShareTarget::Mastodon, ShareTarget::X])
+            .render()
+            .into_string();
+        // Mastodon needs a redirect route
+        assert!(!html.contains("sh-share__mastodon"));
+        assert!(html.contains("sh-share__link--x"));
+        assert!(!html.contains("facebook"));
+    }
+
+    #[test]
+    fn test_share_links_css() {
+        let css = share_links_css();
+        assert!(css.contains(".sh-share__copy-input"));
+        assert!(css.contains("user-select: all"));
+    }
+}
@@ -1,6 +1,16 @@
 //! Sidebar Component - Side navigation panel with multiple variants
-
-use maud::{html, Markup, Render};
+//!
+//! Also provides [`SidebarNav`], a sectioned navigation tree that highlights
+//! the current route, and [`AppShell`], a sidebar + topbar + content layout
+//! whose sidebar turns into an off-canvas drawer on narrow screens. The
+//! drawer is driven by a checkbox toggle, or by linking to the sidebar's
+//! fragment (`#{id}-nav`), so it works without JavaScript.
+
+use crate::focus::SkipLinks;
+use crate::route::{route_match, RouteContext, RouteMatch};
+use maud::{html, Markup, PreEscaped, Render};
+use shallot_foundation::ClassList;
+use std::borrow::Cow;
 
 /// Sidebar variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -97,6 +107,12 @@ impl<'a> Sidebar<'a> {
         self
     }
 
+    /// Use a [`SidebarNav`] as the sidebar content
+    pub fn nav(mut self, nav: SidebarNav<'_>) -> Self {
+        self.content = nav.render();
+        self
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-sidebar"];
 
@@ -140,9 +156,297 @@ impl<'a> Render for Sidebar<'a> {
     }
 }
 
+/// Link in a [`SidebarNav`], optionally with nested children
+#[derive(Debug, Clone)]
+pub struct SidebarNavItem<'a> {
+    pub label: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+    /// Raw SVG markup shown before the label and on its own in rail mode
+    pub icon: Option<Cow<'a, str>>,
+    pub badge: Option<Cow<'a, str>>,
+    pub children: Vec<SidebarNavItem<'a>>,
+}
+
+impl<'a> SidebarNavItem<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+            icon: None,
+            badge: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Cow<'a, str>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn badge(mut self, badge: impl Into<Cow<'a, str>>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn children(mut self, children: Vec<SidebarNavItem<'a>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    fn route_match(&self, current: Option<&str>) -> RouteMatch {
+        let Some(current) = current else {
+            return RouteMatch::None;
+        };
+        match route_match(&self.href, current) {
+            RouteMatch::Exact => RouteMatch::Exact,
+            _ if self
+                .children
+                .iter()
+                .any(|child| child.route_match(Some(current)) != RouteMatch::None) =>
+            {
+                RouteMatch::Ancestor
+            }
+            other => other,
+        }
+    }
+}
+
+/// Titled group of nav items
+#[derive(Debug, Clone)]
+pub struct SidebarSection<'a> {
+    pub title: Option<Cow<'a, str>>,
+    pub items: Vec<SidebarNavItem<'a>>,
+}
+
+impl<'a> SidebarSection<'a> {
+    pub fn new(items: Vec<SidebarNavItem<'a>>) -> Self {
+        Self { title: None, items }
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// Sectioned sidebar navigation with current-route highlighting
+///
+/// The item matching [`SidebarNav::current_path`] (or [`SidebarNav::route`]) gets `aria-current="page"`;
+/// items above it in the tree are marked as part of the active trail and
+/// their child lists start expanded.
+#[derive(Debug, Clone)]
+pub struct SidebarNav<'a> {
+    pub sections: Vec<SidebarSection<'a>>,
+    pub current_path: Option<Cow<'a, str>>,
+    pub aria_label: Cow<'a, str>,
+}
+
+impl<'a> SidebarNav<'a> {
+    pub fn new(sections: Vec<SidebarSection<'a>>) -> Self {
+        Self {
+            sections,
+            current_path: None,
+            aria_label: Cow::Borrowed("Main"),
+        }
+    }
+
+    pub fn current_path(mut self, path: impl Into<Cow<'a, str>>) -> Self {
+        self.current_path = Some(path.into());
+        self
+    }
+
+    /// Take the current path from the page's route
+    pub fn route(self, route: &RouteContext<'a>) -> Self {
+        self.current_path(route.path.clone())
+    }
+
+    pub fn aria_label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.aria_label = label.into();
+        self
+    }
+
+    fn render_items(&self, items: &[SidebarNavItem<'a>], depth: usize) -> Markup {
+        let current = self.current_path.as_deref();
+
+        html! {
+            ul class="sh-sidebar-nav__list" data-depth=(depth) {
+                @for item in items {
+                    @let state = item.route_match(current);
+                    @let class = ClassList::new("sh-sidebar-nav__item")
+                        .push_if("sh-sidebar-nav__item--active", state == RouteMatch::Exact)
+                        .push_if("sh-sidebar-nav__item--trail", state == RouteMatch::Ancestor)
+                        .build();
+                    li class=(class) {
+                        @if item.children.is_empty() {
+                            (self.render_link(item, state))
+                        } @else {
+                            details class="sh-sidebar-nav__group" open?[state != RouteMatch::None] {
+                                summary class="sh-sidebar-nav__group-toggle" {
+                                    (self.render_link(item, state))
+                                }
+                                (self.render_items(&item.children, depth + 1))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_link(&self, item: &SidebarNavItem<'a>, state: RouteMatch) -> Markup {
+        html! {
+            a
+                class="sh-sidebar-nav__link"
+                href=(item.href)
+                title=(item.label)
+                aria-current=[(state == RouteMatch::Exact).then_some("page")]
+            {
+                @if let Some(icon) = &item.icon {
+                    span class="sh-sidebar-nav__icon" aria-hidden="true" { (PreEscaped(icon.as_ref())) }
+                }
+                span class="sh-sidebar-nav__label" { (item.label) }
+                @if let Some(badge) = &item.badge {
+                    span class="sh-sidebar-nav__badge" { (badge) }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for SidebarNav<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            nav class="sh-sidebar-nav" aria-label=(self.aria_label) {
+                @for section in &self.sections {
+                    div class="sh-sidebar-nav__section" {
+                        @if let Some(title) = &section.title {
+                            p class="sh-sidebar-nav__title" { (title) }
+                        }
+                        (self.render_items(&section.items, 0))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Application layout: sidebar, topbar and main content in one grid
+///
+/// Below 768px the sidebar slides in from the edge when the menu button's
+/// checkbox is checked or when `#{id}-nav` is the URL fragment. Skip links
+/// to `#{id}-main` and `#{id}-nav` come first, as in
+/// [`crate::landmark::PageLayout`].
+pub struct AppShell<'a> {
+    pub id: Cow<'a, str>,
+    pub sidebar: Sidebar<'a>,
+    pub topbar: Markup,
+    pub content: Markup,
+    pub rail: bool,
+    pub skip_links: bool,
+}
+
+impl<'a> AppShell<'a> {
+    pub fn new(sidebar: Sidebar<'a>, content: Markup) -> Self {
+        Self {
+            id: Cow::Borrowed("sh-app"),
+            sidebar,
+            topbar: html! {},
+            content,
+            rail: false,
+            skip_links: true,
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn topbar(mut self, topbar: Markup) -> Self {
+        self.topbar = topbar;
+        self
+    }
+
+    /// Collapse the sidebar to an icon rail on wide screens
+    pub fn rail(mut self, rail: bool) -> Self {
+        self.rail = rail;
+        self
+    }
+
+    /// Render skip links to the content and navigation (on by default)
+    pub fn skip_links(mut self, skip_links: bool) -> Self {
+        self.skip_links = skip_links;
+        self
+    }
+
+    fn build_classes(&self) -> String {
+        ClassList::new("sh-app-shell")
+            .push_if("sh-app-shell--rail", self.rail)
+            .push_if(
+                "sh-app-shell--right",
+                self.sidebar.position == SidebarPosition::Right,
+            )
+            .build()
+    }
+}
+
+impl<'a> Render for AppShell<'a> {
+    fn render(&self) -> Markup {
+        let toggle_id = format!("{}-toggle", self.id);
+        let nav_id = format!("{}-nav", self.id);
+        let main_id = format!("{}-main", self.id);
+        let style = format!("--sh-app-shell-sidebar-w: {}px;", self.sidebar.width_px);
+
+        html! {
+            div class=(self.build_classes()) id=(self.id) style=(style) {
+                @if self.skip_links {
+                    (SkipLinks::new()
+                        .link(&main_id, "Skip to main content")
+                        .link(&nav_id, "Skip to navigation"))
+                }
+
+                input
+                    type="checkbox"
+                    class="sh-app-shell__toggle"
+                    id=(toggle_id)
+                    aria-controls=(nav_id)
+                    aria-label="Toggle navigation";
+
+                header class="sh-app-shell__topbar" {
+                    label class="sh-app-shell__menu" for=(toggle_id) aria-hidden="true" {
+                        span class="sh-app-shell__menu-bar" {}
+                        span class="sh-app-shell__menu-bar" {}
+                        span class="sh-app-shell__menu-bar" {}
+                    }
+                    (self.topbar)
+                }
+
+                div class="sh-app-shell__sidebar" id=(nav_id) {
+                    (self.sidebar.render())
+                    a class="sh-app-shell__close" href={ "#" (main_id) } {
+                        span class="sh-visually-hidden" { "Close navigation" }
+                    }
+                }
+
+                label class="sh-app-shell__backdrop" for=(toggle_id) aria-hidden="true" {}
+
+                main class="sh-app-shell__main" id=(main_id) {
+                    (self.content)
+                }
+            }
+        }
+    }
+}
+
 /// Generate CSS for sidebar components
 pub fn sidebar_css() -> String {
-    r#"
+    let mut css = String::from(SIDEBAR_CSS);
+    css.push_str(SIDEBAR_NAV_CSS);
+    css.push_str(APP_SHELL_CSS);
+    css
+}
+
+const SIDEBAR_CSS: &str = r#"
 /* Sidebar Component Styles */
 .sh-sidebar {
     display: flex;
@@ -224,9 +528,300 @@ pub fn sidebar_css() -> String {
         transition: none;
     }
 }
-"#
-    .to_string()
+"#;
+
+const SIDEBAR_NAV_CSS: &str = r#"
+/* Sidebar navigation */
+.sh-sidebar-nav {
+    display: flex;
+    flex-direction: column;
+    gap: 1.25rem;
+    padding: 0 0.75rem;
+}
+
+.sh-sidebar-nav__title {
+    margin: 0 0 0.375rem;
+    padding: 0 0.75rem;
+    font-size: 0.75rem;
+    font-weight: 600;
+    letter-spacing: 0.05em;
+    text-transform: uppercase;
+    color: var(--sh-text-muted);
+}
+
+.sh-sidebar-nav__list {
+    display: flex;
+    flex-direction: column;
+    gap: 0.125rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-sidebar-nav__list .sh-sidebar-nav__list {
+    margin-top: 0.125rem;
+    padding-left: 1rem;
+}
+
+.sh-sidebar-nav__link {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    padding: 0.5rem 0.75rem;
+    border-radius: 0.375rem;
+    font-size: 0.875rem;
+    color: var(--sh-text-muted);
+    text-decoration: none;
+    transition: background 0.15s, color 0.15s;
+}
+
+.sh-sidebar-nav__link:hover {
+    background: var(--sh-surface-alt);
+    color: var(--sh-text);
+}
+
+.sh-sidebar-nav__link:focus-visible {
+    outline: 2px solid var(--sh-accent);
+    outline-offset: -2px;
+}
+
+.sh-sidebar-nav__item--trail > .sh-sidebar-nav__link,
+.sh-sidebar-nav__item--trail > .sh-sidebar-nav__group > summary .sh-sidebar-nav__link {
+    color: var(--sh-text);
+}
+
+.sh-sidebar-nav__link[aria-current="page"] {
+    background: color-mix(in srgb, var(--sh-accent) 12%, transparent);
+    color: var(--sh-accent);
+    font-weight: 500;
+}
+
+.sh-sidebar-nav__icon {
+    display: flex;
+    flex-shrink: 0;
+    width: 1.25rem;
+    height: 1.25rem;
+}
+
+.sh-sidebar-nav__label {
+    flex: 1;
+    min-width: 0;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+
+.sh-sidebar-nav__badge {
+    padding: 0.0625rem 0.5rem;
+    border-radius: 9999px;
+    background: var(--sh-surface-alt);
+    font-size: 0.75rem;
+}
+
+.sh-sidebar-nav__group-toggle {
+    list-style: none;
+    cursor: pointer;
+}
+
+.sh-sidebar-nav__group-toggle::-webkit-details-marker {
+    display: none;
+}
+
+.sh-sidebar-nav__group-toggle .sh-sidebar-nav__link::after {
+    content: '';
+    width: 0.375rem;
+    height: 0.375rem;
+    border-right: 2px solid currentColor;
+    border-bottom: 2px solid currentColor;
+    transform: rotate(-45deg);
+    transition: transform 0.2s;
+}
+
+.sh-sidebar-nav__group[open] > .sh-sidebar-nav__group-toggle .sh-sidebar-nav__link::after {
+    transform: rotate(45deg);
+}
+"#;
+
+const APP_SHELL_CSS: &str = r#"
+/* App shell layout */
+.sh-app-shell {
+    display: grid;
+    grid-template-columns: var(--sh-app-shell-sidebar-w, 280px) minmax(0, 1fr);
+    grid-template-rows: auto 1fr;
+    grid-template-areas:
+        "sidebar topbar"
+        "sidebar main";
+    min-height: 100vh;
+}
+
+.sh-app-shell--right {
+    grid-template-columns: minmax(0, 1fr) var(--sh-app-shell-sidebar-w, 280px);
+    grid-template-areas:
+        "topbar sidebar"
+        "main sidebar";
+}
+
+.sh-app-shell__toggle {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    opacity: 0;
+    pointer-events: none;
+}
+
+.sh-app-shell__topbar {
+    grid-area: topbar;
+    position: sticky;
+    top: 0;
+    z-index: 100;
+    display: flex;
+    align-items: center;
+    gap: 1rem;
+    min-height: 3.5rem;
+    padding: 0 1.5rem;
+    background: var(--sh-surface);
+    border-bottom: 1px solid var(--sh-border);
+}
+
+.sh-app-shell__sidebar {
+    grid-area: sidebar;
+    position: sticky;
+    top: 0;
+    height: 100vh;
+}
+
+.sh-app-shell__sidebar .sh-sidebar {
+    width: 100%;
+    height: 100%;
+}
+
+.sh-app-shell__main {
+    grid-area: main;
+    min-width: 0;
+    padding: 1.5rem;
+}
+
+.sh-app-shell__menu,
+.sh-app-shell__close,
+.sh-app-shell__backdrop {
+    display: none;
+}
+
+/* Rail mode: icons only on wide screens */
+.sh-app-shell--rail {
+    --sh-app-shell-sidebar-w: 64px;
+}
+
+.sh-app-shell--rail .sh-sidebar-nav {
+    padding: 0 0.5rem;
+}
+
+.sh-app-shell--rail .sh-sidebar-nav__title,
+.sh-app-shell--rail .sh-sidebar-nav__label,
+.sh-app-shell--rail .sh-sidebar-nav__badge,
+.sh-app-shell--rail .sh-sidebar-nav__link::after,
+.sh-app-shell--rail .sh-sidebar-nav__list .sh-sidebar-nav__list {
+    display: none;
+}
+
+.sh-app-shell--rail .sh-sidebar-nav__link {
+    justify-content: center;
+}
+
+/* Off-canvas drawer on narrow screens */
+@media (max-width: 768px) {
+    .sh-app-shell,
+    .sh-app-shell--right {
+        grid-template-columns: minmax(0, 1fr);
+        grid-template-areas:
+            "topbar"
+            "main";
+    }
+
+    .sh-app-shell__menu {
+        display: inline-flex;
+        flex-direction: column;
+        justify-content: center;
+        gap: 4px;
+        width: 2.5rem;
+        height: 2.5rem;
+        padding: 0.5rem;
+        border-radius: 0.375rem;
+        cursor: pointer;
+    }
+
+    .sh-app-shell__menu-bar {
+        height: 2px;
+        border-radius: 1px;
+        background: currentColor;
+    }
+
+    .sh-app-shell__toggle:focus-visible ~ .sh-app-shell__topbar .sh-app-shell__menu {
+        outline: 2px solid var(--sh-accent);
+    }
+
+    .sh-app-shell__sidebar {
+        position: fixed;
+        top: 0;
+        bottom: 0;
+        left: 0;
+        z-index: 300;
+        width: min(var(--sh-sidebar-w, 280px), 85vw);
+        transform: translateX(-100%);
+        transition: transform 0.25s ease;
+    }
+
+    .sh-app-shell--right .sh-app-shell__sidebar {
+        left: auto;
+        right: 0;
+        transform: translateX(100%);
+    }
+
+    .sh-app-shell__toggle:checked ~ .sh-app-shell__sidebar,
+    .sh-app-shell__sidebar:target {
+        transform: none;
+    }
+
+    .sh-app-shell__toggle:checked ~ .sh-app-shell__backdrop {
+        display: block;
+        position: fixed;
+        inset: 0;
+        z-index: 250;
+        background: rgb(0 0 0 / 0.4);
+    }
+
+    .sh-app-shell__sidebar:target .sh-app-shell__close {
+        display: block;
+        position: fixed;
+        inset: 0;
+        z-index: -1;
+    }
+
+    /* The drawer always shows full labels */
+    .sh-app-shell--rail .sh-sidebar-nav__title,
+    .sh-app-shell--rail .sh-sidebar-nav__label,
+    .sh-app-shell--rail .sh-sidebar-nav__badge,
+    .sh-app-shell--rail .sh-sidebar-nav__link::after {
+        display: revert;
+    }
+
+    .sh-app-shell--rail .sh-sidebar-nav__list .sh-sidebar-nav__list {
+        display: flex;
+    }
+
+    .sh-app-shell--rail .sh-sidebar-nav__link {
+        justify-content: flex-start;
+    }
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-app-shell__sidebar,
+    .sh-sidebar-nav__link,
+    .sh-sidebar-nav__link::after {
+        transition: none;
+    }
 }
+"#;
 
 #[cfg(test)]
 mod tests {
@@ -282,4 +877,93 @@ mod tests {
         assert!(css.contains(".sh-sidebar--mini"));
         assert!(css.contains(".sh-sidebar--collapsed"));
     }
+
+    fn dashboard_nav() -> SidebarNav<'static> {
+        SidebarNav::new(vec![
+            SidebarSection::new(vec![
+                SidebarNavItem::new("Overview", "/"),
+                SidebarNavItem::new("Billing", "/billing").children(vec![
+                    SidebarNavItem::new("Invoices", "/billing/invoices"),
+                    SidebarNavItem::new("Plans", "/billing/plans"),
+                ]),
+            ])
+            .title("Account"),
+            SidebarSection::new(vec![SidebarNavItem::new("Docs", "/docs").badge("New")]),
+        ])
+    }
+
+    #[test]
+    fn test_sidebar_nav_active_item() {
+        let html = dashboard_nav()
+            .current_path("/billing/plans")
+            .render()
+            .into_string();
+
+        assert_eq!(html.matches(r#"aria-current="page""#).count(), 1);
+        assert!(html.contains(r#"href="/billing/plans" title="Plans" aria-current="page""#));
+        assert!(html.contains("sh-sidebar-nav__item--trail"));
+        assert!(html.contains(r#"<details class="sh-sidebar-nav__group" open>"#));
+    }
+
+    #[test]
+    fn test_sidebar_nav_route() {
+        let route = RouteContext::new("/billing/plans?annual=1");
+        let html = dashboard_nav().route(&route).render().into_string();
+        assert_eq!(html.matches(r#"aria-current="page""#).count(), 1);
+        assert!(html.contains(r#"href="/billing/plans" title="Plans" aria-current="page""#));
+    }
+
+    #[test]
+    fn test_sidebar_nav_without_current_path() {
+        let html = dashboard_nav().render().into_string();
+
+        assert!(!html.contains("aria-current"));
+        assert!(!html.contains(" open"));
+        assert!(html.contains("Account"));
+        assert!(html.contains("sh-sidebar-nav__badge"));
+    }
+
+    #[test]
+    fn test_app_shell_structure() {
+        let sidebar = Sidebar::new().nav(dashboard_nav().current_path("/docs"));
+        let html = AppShell::new(sidebar, html! { h1 { "Docs" } })
+            .id("app")
+            .topbar(html! { "Search" })
+            .render()
+            .into_string();
+
+        assert!(html.contains(r#"id="app-toggle""#));
+        assert!(html.contains(r#"for="app-toggle""#));
+        assert!(html.contains(r#"class="sh-app-shell__sidebar" id="app-nav""#));
+        assert!(html.contains(r##"href="#app-main""##));
+        assert!(html.contains(r#"<main class="sh-app-shell__main" id="app-main">"#));
+        assert!(html.contains("--sh-app-shell-sidebar-w: 280px;"));
+        assert!(html.contains(r#"href="/docs" title="Docs" aria-current="page""#));
+        assert!(html.contains(r##"<a class="sh-skip-links__link" href="#app-main">"##));
+        assert!(html.contains(r##"<a class="sh-skip-links__link" href="#app-nav">"##));
+
+        let html = AppShell::new(Sidebar::new(), html! {})
+            .skip_links(false)
+            .render()
+            .into_string();
+        assert!(!html.contains("sh-skip-links"));
+    }
+
+    #[test]
+    fn test_app_shell_modes() {
+        let shell = AppShell::new(Sidebar::new(), html! {}).rail(true);
+        assert!(shell.build_classes().contains("sh-app-shell--rail"));
+
+        let shell = AppShell::new(Sidebar::new().position(SidebarPosition::Right), html! {});
+        assert_eq!(shell.build_classes(), "sh-app-shell sh-app-shell--right");
+    }
+
+    #[test]
+    fn test_app_shell_css() {
+        let css = sidebar_css();
+        assert!(css.contains(".sh-app-shell__toggle:checked ~ .sh-app-shell__sidebar"));
+        assert!(css.contains(".sh-app-shell__sidebar:target"));
+        assert!(css.contains(".sh-app-shell--rail"));
+        assert!(css.contains(r#".sh-sidebar-nav__link[aria-current="page"]"#));
+    }
 }
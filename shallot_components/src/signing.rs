@@ -0,0 +1,107 @@
+//! HMAC-SHA256 for tokens the server issues and later checks, such as the
+//! form time trap and form snapshots, plus the URL-safe base64 they travel
+//! in. Thin wrappers over the `hmac`, `sha2` and `base64` crates.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac(key: &[u8], message: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac
+}
+
+/// HMAC-SHA256 (RFC 2104) of `message` under `key`
+pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    mac(key, message).finalize().into_bytes().into()
+}
+
+/// Check a lowercase hex HMAC-SHA256 `signature` of `message` in constant
+/// time, so timing does not reveal how much of it matched
+pub(crate) fn verify_hex_signature(key: &[u8], message: &[u8], signature: &str) -> bool {
+    from_hex(signature).is_some_and(|tag| mac(key, message).verify_slice(&tag).is_ok())
+}
+
+/// Lowercase hex encoding
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    let lowercase = hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+    if !lowercase || !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Unpadded base64url (RFC 4648 section 5), safe in query strings and
+/// cookie values
+pub(crate) fn base64url_encode(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Decode unpadded base64url; `None` on any other character or a
+/// truncated final group
+pub(crate) fn base64url_decode(input: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(input).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256_rfc4231() {
+        assert_eq!(
+            to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Key longer than the block size is hashed first (test case 6)
+        assert_eq!(
+            to_hex(&hmac_sha256(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_verify_hex_signature() {
+        let signature = to_hex(&hmac_sha256(b"key", b"message"));
+        assert!(verify_hex_signature(b"key", b"message", &signature));
+        assert!(!verify_hex_signature(b"key", b"massage", &signature));
+        assert!(!verify_hex_signature(b"key", b"message", &signature[..62]));
+        assert!(!verify_hex_signature(
+            b"key",
+            b"message",
+            &signature.to_uppercase()
+        ));
+        assert!(!verify_hex_signature(b"key", b"message", "zz"));
+    }
+
+    #[test]
+    fn test_base64url_round_trip() {
+        assert_eq!(base64url_encode(b""), "");
+        assert_eq!(base64url_encode(b"f"), "Zg");
+        assert_eq!(base64url_encode(b"foob"), "Zm9vYg");
+        assert_eq!(base64url_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64url_encode(&[0xfb, 0xff]), "-_8");
+        for data in [&b""[..], b"a", b"ab", b"abc", &[0, 255, 128, 7]] {
+            assert_eq!(
+                base64url_decode(&base64url_encode(data)).as_deref(),
+                Some(data)
+            );
+        }
+        assert_eq!(base64url_decode("Zm9v+A"), None);
+        assert_eq!(base64url_decode("Zm9vY"), None);
+    }
+}
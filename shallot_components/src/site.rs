@@ -0,0 +1,1485 @@
+//! Site builder - routes, nested layouts and per-route head metadata
+//!
+//! [`SiteBuilder`] turns a set of routes into the pages of a static site. A
+//! route is either a single page at a fixed path or a collection: one page
+//! per item, with the item's parameters filling a pattern such as
+//! `/blog/{slug}`. Each page body is wrapped by every section layout whose
+//! prefix contains the page's path, deepest innermost, and then by the root
+//! layout, a [`Shell`] unless replaced, which renders the document around
+//! the route's [`Head`]. Pages and layouts see the route as a
+//! [`RouteContext`], so navigation components mark the current page
+//! themselves. File routes such as feeds are written verbatim, outside
+//! every layout.
+//!
+//! Before layouts run, each page's [`RouteContext`] gets a breadcrumb trail
+//! through every route above it, so a layout can render
+//! [`Breadcrumbs::from_route`](crate::breadcrumbs::Breadcrumbs::from_route)
+//! for any page. Steps are labelled with the route's title unless
+//! [`SiteBuilder::breadcrumb_label`] names it otherwise.
+//!
+//! Localized routes are generated once per registered [`Locale`], under
+//! its code: `/en/about`, `/de/about`. Versions of the same route are
+//! linked with `hreflang` alternates and, through [`PageContext`], are
+//! available to a [`LanguageSwitcher`](crate::i18n::LanguageSwitcher).
+//!
+//! Route closures run in [`SiteBuilder::build`], not when the route is
+//! added: page bodies are rendered and then wrapped in their layouts on a
+//! thread pool of [`SiteBuilder::jobs`] threads, every core by default.
+//! Pages come back in the order their routes were added either way.
+//!
+//! Redirects are written as stub pages that refresh to their target and
+//! name it as canonical, which works on any static host. Hosts that can
+//! answer with a real `301` also get their own rules file when one is
+//! requested with [`SiteBuilder::redirect_manifest`].
+//!
+//! # Example
+//!
+//! ```
+//! use maud::{html, Markup};
+//! use shallot_components::site::{Head, PageContext, Shell, SiteBuilder};
+//!
+//! let posts = [("hello-world", "Hello, world")];
+//! let pages = SiteBuilder::new()
+//!     .base_url("https://example.com")
+//!     .layout(Shell::new().stylesheet("/shallot.css"))
+//!     .section("/blog", |_: &PageContext, content: Markup| html! { article { (content) } })
+//!     .page("/", Head::new("Home"), |_| html! { h1 { "Home" } })
+//!     .collection(
+//!         "/blog/{slug}",
+//!         posts,
+//!         |(slug, _)| vec![("slug", slug.to_string())],
+//!         |(_, title), _| (Head::new(*title), html! { h1 { (title) } }),
+//!     )
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(pages[1].path, "/blog/hello-world");
+//! assert_eq!(pages[1].file.to_str(), Some("blog/hello-world/index.html"));
+//! assert!(pages[1].html.contains("<article><h1>Hello, world</h1></article>"));
+//! assert!(pages[1].html.contains(r#"href="https://example.com/blog/hello-world""#));
+//! ```
+
+use crate::i18n::{Alternate, Locale};
+use crate::route::{route_match, RouteContext, RouteMatch};
+use maud::{html, Markup, PreEscaped, Render, DOCTYPE};
+use rayon::prelude::*;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Why a site could not be built or written
+#[derive(Debug)]
+pub enum SiteError {
+    /// The route path is not absolute, or has an empty, `.` or `..`
+    /// segment, a query, a fragment or an unbalanced `{param}`
+    InvalidPath(String),
+    /// A collection item did not provide a parameter its pattern names
+    MissingParam {
+        route: String,
+        param: String,
+    },
+    /// A parameter value cannot be used as a path segment
+    InvalidParam {
+        route: String,
+        param: String,
+        value: String,
+    },
+    /// Two routes write the same output file
+    DuplicatePath(String),
+    /// A localized route was added before any [`SiteBuilder::locale`]
+    NoLocales(String),
+    /// A localized item names a locale the site does not have
+    UnknownLocale(String),
+    /// A redirect target is neither an absolute path nor an `http(s)` URL,
+    /// or contains whitespace
+    InvalidRedirect(String),
+    /// The render thread pool could not be started
+    ThreadPool(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SiteError::InvalidPath(path) => write!(f, "`{}` is not a valid route path", path),
+            SiteError::MissingParam { route, param } => {
+                write!(f, "route `{}` needs a value for `{{{}}}`", route, param)
+            }
+            SiteError::InvalidParam {
+                route,
+                param,
+                value,
+            } => write!(
+                f,
+                "`{}` is not a valid `{{{}}}` segment for route `{}`",
+                value, param, route
+            ),
+            SiteError::DuplicatePath(path) => write!(f, "more than one route renders `{}`", path),
+            SiteError::NoLocales(route) => {
+                write!(f, "localized route `{}` was added before any locale", route)
+            }
+            SiteError::UnknownLocale(code) => {
+                write!(f, "`{}` is not one of the site's locales", code)
+            }
+            SiteError::InvalidRedirect(to) => {
+                write!(f, "`{}` is not a valid redirect target", to)
+            }
+            SiteError::ThreadPool(err) => write!(f, "could not start render threads: {}", err),
+            SiteError::Io(err) => write!(f, "failed to write the site: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SiteError {}
+
+impl From<io::Error> for SiteError {
+    fn from(err: io::Error) -> Self {
+        SiteError::Io(err)
+    }
+}
+
+/// Per-route `<head>` metadata: title, description, canonical URL, sharing
+/// image and extra `<meta>` tags
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Head {
+    pub title: String,
+    pub description: Option<String>,
+    /// Absolute URL of the page; filled from [`SiteBuilder::base_url`]
+    /// when unset
+    pub canonical: Option<String>,
+    /// Open Graph image URL
+    pub image: Option<String>,
+    /// Ask search engines not to index the page
+    pub noindex: bool,
+    /// Extra `<meta name content>` pairs
+    pub meta: Vec<(String, String)>,
+    /// `(hreflang, href)` links to translations; filled for localized
+    /// routes when unset
+    pub alternates: Vec<(String, String)>,
+}
+
+impl Head {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn canonical(mut self, url: impl Into<String>) -> Self {
+        self.canonical = Some(url.into());
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image = Some(url.into());
+        self
+    }
+
+    pub fn noindex(mut self) -> Self {
+        self.noindex = true;
+        self
+    }
+
+    pub fn meta(mut self, name: impl Into<String>, content: impl Into<String>) -> Self {
+        self.meta.push((name.into(), content.into()));
+        self
+    }
+
+    pub fn alternate(mut self, hreflang: impl Into<String>, href: impl Into<String>) -> Self {
+        self.alternates.push((hreflang.into(), href.into()));
+        self
+    }
+}
+
+impl Render for Head {
+    fn render(&self) -> Markup {
+        html! {
+            title { (self.title) }
+            @if let Some(description) = &self.description {
+                meta name="description" content=(description);
+            }
+            @if let Some(canonical) = &self.canonical {
+                link rel="canonical" href=(canonical);
+            }
+            @for (hreflang, href) in &self.alternates {
+                link rel="alternate" hreflang=(hreflang) href=(href);
+            }
+            @if self.noindex {
+                meta name="robots" content="noindex";
+            }
+            @for (name, content) in &self.meta {
+                meta name=(name) content=(content);
+            }
+            meta property="og:title" content=(self.title);
+            @if let Some(description) = &self.description {
+                meta property="og:description" content=(description);
+            }
+            @if let Some(canonical) = &self.canonical {
+                meta property="og:url" content=(canonical);
+            }
+            @if let Some(image) = &self.image {
+                meta property="og:image" content=(image);
+            }
+        }
+    }
+}
+
+/// The page a layout is wrapping
+#[derive(Debug, Clone)]
+pub struct PageContext {
+    /// Path and route parameters, e.g. `slug` for `/blog/{slug}`
+    pub route: RouteContext<'static>,
+    pub head: Head,
+    /// Locale of a localized route
+    pub locale: Option<Locale>,
+    /// Every locale's version of a localized route, this one included
+    pub alternates: Vec<Alternate>,
+}
+
+impl PageContext {
+    fn new(route: RouteContext<'static>, head: Head) -> Self {
+        Self {
+            route,
+            head,
+            locale: None,
+            alternates: Vec::new(),
+        }
+    }
+}
+
+/// Wraps page content: the root document shell or a section's chrome
+///
+/// Closures taking `(&PageContext, Markup)` are layouts too. Pages are
+/// rendered in parallel, so layouts are shared between threads.
+pub trait Layout: Sync {
+    fn render(&self, page: &PageContext, content: Markup) -> Markup;
+}
+
+impl<F> Layout for F
+where
+    F: Fn(&PageContext, Markup) -> Markup + Sync,
+{
+    fn render(&self, page: &PageContext, content: Markup) -> Markup {
+        self(page, content)
+    }
+}
+
+/// Default root layout: the HTML document with the route's [`Head`],
+/// stylesheets and any extra head markup
+#[derive(Debug, Clone)]
+pub struct Shell {
+    pub lang: String,
+    pub stylesheets: Vec<String>,
+    /// Site-wide head content such as icon links or font preloads
+    pub head: Option<Markup>,
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        Self {
+            lang: "en".to_string(),
+            stylesheets: Vec::new(),
+            head: None,
+        }
+    }
+
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = lang.into();
+        self
+    }
+
+    pub fn stylesheet(mut self, href: impl Into<String>) -> Self {
+        self.stylesheets.push(href.into());
+        self
+    }
+
+    pub fn head(mut self, head: Markup) -> Self {
+        self.head = Some(head);
+        self
+    }
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layout for Shell {
+    fn render(&self, page: &PageContext, content: Markup) -> Markup {
+        html! {
+            (DOCTYPE)
+            html
+                lang=(page.locale.as_ref().map_or(self.lang.as_str(), |l| l.code.as_str()))
+                dir=[page.locale.as_ref().filter(|l| l.rtl).map(Locale::dir)] {
+                head {
+                    meta charset="utf-8";
+                    meta name="viewport" content="width=device-width, initial-scale=1";
+                    (page.head)
+                    @for href in &self.stylesheets {
+                        link rel="stylesheet" href=(href);
+                    }
+                    @if let Some(head) = &self.head {
+                        (head)
+                    }
+                }
+                body { (content) }
+            }
+        }
+    }
+}
+
+/// A rendered page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitePage {
+    /// URL path, e.g. `/blog/hello-world`
+    pub path: String,
+    /// Output file relative to the site root, e.g.
+    /// `blog/hello-world/index.html`
+    pub file: PathBuf,
+    /// The rendered document, or a file route's contents
+    pub html: String,
+}
+
+/// Renders a route's head and body on the build pool; `None` leaves the
+/// route out
+type RenderFn<'a> = Box<dyn FnOnce(&RouteContext<'static>) -> Option<(Head, Markup)> + Send + 'a>;
+
+/// A route as added, waiting for its closure to run
+struct Route<'a> {
+    route: RouteContext<'static>,
+    locale: Option<Locale>,
+    render: RenderFn<'a>,
+    raw: bool,
+    /// Path shared by every locale's version of a localized route
+    group: Option<String>,
+}
+
+/// A route with its head and body rendered
+struct Rendered {
+    page: PageContext,
+    content: Markup,
+    raw: bool,
+    group: Option<String>,
+}
+
+/// A route with its path resolved, waiting for its layouts
+struct Pending {
+    path: String,
+    file: PathBuf,
+    page: PageContext,
+    content: Markup,
+    raw: bool,
+}
+
+/// A permanent redirect from a site path to another path or URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+    pub from: String,
+    pub to: String,
+}
+
+/// Host-specific redirect rules written alongside the stub pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectManifest {
+    /// `_redirects`, read by Netlify and Cloudflare Pages
+    Redirects,
+    /// `[[redirects]]` tables in `netlify.toml`
+    NetlifyToml,
+    /// The `redirects` array of `vercel.json`
+    VercelJson,
+}
+
+impl RedirectManifest {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            RedirectManifest::Redirects => "_redirects",
+            RedirectManifest::NetlifyToml => "netlify.toml",
+            RedirectManifest::VercelJson => "vercel.json",
+        }
+    }
+
+    /// The rules for `redirects`, all answered with `301`
+    pub fn render(&self, redirects: &[Redirect]) -> String {
+        match self {
+            RedirectManifest::Redirects => redirects
+                .iter()
+                .map(|r| format!("{} {} 301\n", r.from, r.to))
+                .collect(),
+            RedirectManifest::NetlifyToml => redirects
+                .iter()
+                .map(|r| {
+                    // JSON string escapes are valid TOML basic strings
+                    format!(
+                        "[[redirects]]\nfrom = {}\nto = {}\nstatus = 301\n",
+                        Value::from(r.from.as_str()),
+                        Value::from(r.to.as_str())
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            RedirectManifest::VercelJson => {
+                let rules: Vec<Value> = redirects
+                    .iter()
+                    .map(|r| json!({ "source": r.from, "destination": r.to, "permanent": true }))
+                    .collect();
+                let mut json = serde_json::to_string_pretty(&json!({ "redirects": rules }))
+                    .unwrap_or_default();
+                json.push('\n');
+                json
+            }
+        }
+    }
+}
+
+/// Routes, layouts and head metadata for a static site
+pub struct SiteBuilder<'a> {
+    pub(crate) base_url: Option<String>,
+    root: Box<dyn Layout + 'a>,
+    sections: Vec<(String, Box<dyn Layout + 'a>)>,
+    routes: Vec<Route<'a>>,
+    breadcrumb_labels: HashMap<String, String>,
+    locales: Vec<Locale>,
+    redirects: Vec<Redirect>,
+    manifests: Vec<RedirectManifest>,
+    jobs: Option<usize>,
+    #[cfg(feature = "image")]
+    icons: Option<(PathBuf, String)>,
+    errors: Vec<SiteError>,
+}
+
+impl<'a> SiteBuilder<'a> {
+    pub fn new() -> Self {
+        Self {
+            base_url: None,
+            root: Box::new(Shell::new()),
+            sections: Vec::new(),
+            routes: Vec::new(),
+            breadcrumb_labels: HashMap::new(),
+            locales: Vec::new(),
+            redirects: Vec::new(),
+            manifests: Vec::new(),
+            jobs: None,
+            #[cfg(feature = "image")]
+            icons: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Origin used for canonical URLs, e.g. `https://example.com`
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Replace the root [`Shell`]
+    pub fn layout(mut self, layout: impl Layout + 'a) -> Self {
+        self.root = Box::new(layout);
+        self
+    }
+
+    /// Wrap every page at or below `prefix` in `layout`; nested sections
+    /// wrap inside their parents
+    pub fn section(mut self, prefix: &str, layout: impl Layout + 'a) -> Self {
+        match parse_path(prefix) {
+            Ok(_) => self.sections.push((prefix.to_string(), Box::new(layout))),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// A single page at `path`, rendered by `render` during the build
+    pub fn page<F>(mut self, path: &str, head: Head, render: F) -> Self
+    where
+        F: FnOnce(&RouteContext<'static>) -> Markup + Send + 'a,
+    {
+        match parse_path(path) {
+            Ok(segments) if segments.iter().all(|s| matches!(s, Segment::Fixed(_))) => {
+                self.routes.push(Route {
+                    route: RouteContext::new(path.to_string()),
+                    locale: None,
+                    render: Box::new(move |route| Some((head, render(route)))),
+                    raw: false,
+                    group: None,
+                });
+            }
+            Ok(_) => self.errors.push(SiteError::InvalidPath(path.to_string())),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// Label the route at `path` as `label` in breadcrumb trails instead
+    /// of its title, e.g. `Home` for `/`
+    pub fn breadcrumb_label(mut self, path: &str, label: impl Into<String>) -> Self {
+        let path = RouteContext::new(path).path().to_string();
+        self.breadcrumb_labels.insert(path, label.into());
+        self
+    }
+
+    /// Publish localized routes in `locale`; the first locale is the
+    /// default, linked as `x-default`. Register locales before adding
+    /// localized routes
+    pub fn locale(mut self, locale: Locale) -> Self {
+        if !is_valid_segment(&locale.code) {
+            self.errors
+                .push(SiteError::InvalidPath(format!("/{}", locale.code)));
+        } else if !self.locales.iter().any(|l| l.code == locale.code) {
+            self.locales.push(locale);
+        }
+        self
+    }
+
+    /// The page at `path` under every locale, e.g. `/about` as `/en/about`
+    /// and `/de/about`; `render` returns `None` for a locale the page is
+    /// not translated into. Localized routes carry a `locale` parameter
+    pub fn localized_page<F>(mut self, path: &str, render: F) -> Self
+    where
+        F: Fn(&Locale, &RouteContext<'static>) -> Option<(Head, Markup)> + Send + Sync + 'a,
+    {
+        match parse_path(path) {
+            Ok(segments) if segments.iter().all(|s| matches!(s, Segment::Fixed(_))) => {
+                if self.locales.is_empty() {
+                    self.errors.push(SiteError::NoLocales(path.to_string()));
+                }
+                let render = Arc::new(render);
+                for locale in self.locales.clone() {
+                    let route = RouteContext::new(localized_path(&locale, path))
+                        .param("locale", locale.code.clone());
+                    let render = Arc::clone(&render);
+                    let code = locale.clone();
+                    self.push_localized(
+                        locale,
+                        path.to_string(),
+                        route,
+                        Box::new(move |route| render(&code, route)),
+                    );
+                }
+            }
+            Ok(_) => self.errors.push(SiteError::InvalidPath(path.to_string())),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// One page per item under the item's locale; items filling `pattern`
+    /// with the same parameters are translations of each other, e.g. the
+    /// `en` and `de` entries with slug `hello` at `/en/blog/hello` and
+    /// `/de/blog/hello`
+    pub fn localized_collection<T, L, P, F>(
+        mut self,
+        pattern: &str,
+        items: impl IntoIterator<Item = T>,
+        locale: L,
+        params: P,
+        render: F,
+    ) -> Self
+    where
+        T: Send + 'a,
+        L: Fn(&T) -> String,
+        P: Fn(&T) -> Vec<(&'static str, String)>,
+        F: Fn(&T, &Locale, &RouteContext<'static>) -> (Head, Markup) + Send + Sync + 'a,
+    {
+        let segments = match parse_path(pattern) {
+            Ok(segments) => segments,
+            Err(err) => {
+                self.errors.push(err);
+                return self;
+            }
+        };
+        if self.locales.is_empty() {
+            self.errors.push(SiteError::NoLocales(pattern.to_string()));
+            return self;
+        }
+
+        let render = Arc::new(render);
+        for item in items {
+            let code = locale(&item);
+            let Some(locale) = self.locales.iter().find(|l| l.code == code).cloned() else {
+                self.errors.push(SiteError::UnknownLocale(code));
+                continue;
+            };
+            let values = params(&item);
+            match fill_pattern(pattern, &segments, &values) {
+                Ok(path) => {
+                    let mut route = RouteContext::new(localized_path(&locale, &path))
+                        .param("locale", locale.code.clone());
+                    for (name, value) in &values {
+                        route = route.param(*name, value.clone());
+                    }
+                    let render = Arc::clone(&render);
+                    let code = locale.clone();
+                    self.push_localized(
+                        locale,
+                        path,
+                        route,
+                        Box::new(move |route| Some(render(&item, &code, route))),
+                    );
+                }
+                Err(err) => self.errors.push(err),
+            }
+        }
+        self
+    }
+
+    fn push_localized(
+        &mut self,
+        locale: Locale,
+        group: String,
+        route: RouteContext<'static>,
+        render: RenderFn<'a>,
+    ) {
+        self.routes.push(Route {
+            route,
+            locale: Some(locale),
+            render,
+            raw: false,
+            group: Some(group),
+        });
+    }
+
+    /// Send `from` to `to`, an absolute path or an `http(s)` URL, with a
+    /// stub page at `from`
+    pub fn redirect(mut self, from: &str, to: &str) -> Self {
+        let valid_target = (to.starts_with('/') && !to.starts_with("//"))
+            || to.starts_with("https://")
+            || to.starts_with("http://");
+        match parse_path(from) {
+            Ok(segments) if segments.iter().any(|s| matches!(s, Segment::Param(_))) => {
+                self.errors.push(SiteError::InvalidPath(from.to_string()))
+            }
+            Ok(_) if !valid_target || to.contains(char::is_whitespace) => {
+                self.errors.push(SiteError::InvalidRedirect(to.to_string()))
+            }
+            Ok(_) => self.redirects.push(Redirect {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// Also write the redirects as `manifest` for hosts that serve them
+    /// with a real `301`
+    pub fn redirect_manifest(mut self, manifest: RedirectManifest) -> Self {
+        if !self.manifests.contains(&manifest) {
+            self.manifests.push(manifest);
+        }
+        self
+    }
+
+    /// Render pages on `jobs` threads instead of one per core; `1` renders
+    /// them serially
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs.max(1));
+        self
+    }
+
+    /// Resize the logo at `source` into the standard favicon sizes under
+    /// `dir` of the output, e.g. `/icons`, when the site is written
+    #[cfg(feature = "image")]
+    pub fn icons(mut self, source: impl Into<PathBuf>, dir: &str) -> Self {
+        match parse_path(dir) {
+            Ok(segments) if segments.iter().all(|s| matches!(s, Segment::Fixed(_))) => {
+                self.icons = Some((source.into(), dir.trim_matches('/').to_string()));
+            }
+            Ok(_) => self.errors.push(SiteError::InvalidPath(dir.to_string())),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// A file written verbatim at `path`, e.g. `/rss.xml`; no layout wraps it
+    pub fn file(mut self, path: &str, contents: impl Into<String>) -> Self {
+        match parse_path(path) {
+            Ok(segments) if segments.iter().all(|s| matches!(s, Segment::Fixed(_))) => {
+                let contents = PreEscaped(contents.into());
+                self.routes.push(Route {
+                    route: RouteContext::new(path.to_string()),
+                    locale: None,
+                    render: Box::new(move |_| Some((Head::default(), contents))),
+                    raw: true,
+                    group: None,
+                });
+            }
+            Ok(_) => self.errors.push(SiteError::InvalidPath(path.to_string())),
+            Err(err) => self.errors.push(err),
+        }
+        self
+    }
+
+    /// One page per item, at `pattern` with the `{param}` segments filled
+    /// from the item's `params`; items are rendered during the build
+    pub fn collection<T, P, F>(
+        mut self,
+        pattern: &str,
+        items: impl IntoIterator<Item = T>,
+        params: P,
+        render: F,
+    ) -> Self
+    where
+        T: Send + 'a,
+        P: Fn(&T) -> Vec<(&'static str, String)>,
+        F: Fn(&T, &RouteContext<'static>) -> (Head, Markup) + Send + Sync + 'a,
+    {
+        let segments = match parse_path(pattern) {
+            Ok(segments) => segments,
+            Err(err) => {
+                self.errors.push(err);
+                return self;
+            }
+        };
+
+        let render = Arc::new(render);
+        for item in items {
+            let values = params(&item);
+            match fill_pattern(pattern, &segments, &values) {
+                Ok(path) => {
+                    let mut route = RouteContext::new(path.clone());
+                    for (name, value) in &values {
+                        route = route.param(*name, value.clone());
+                    }
+                    let render = Arc::clone(&render);
+                    self.routes.push(Route {
+                        route,
+                        locale: None,
+                        render: Box::new(move |route| Some(render(&item, route))),
+                        raw: false,
+                        group: None,
+                    });
+                }
+                Err(err) => self.errors.push(err),
+            }
+        }
+        self
+    }
+
+    /// Render every route and wrap it in its layouts, in the order the
+    /// routes were added
+    pub fn build(self) -> Result<Vec<SitePage>, SiteError> {
+        if let Some(err) = self.errors.into_iter().next() {
+            return Err(err);
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs.unwrap_or(0))
+            .build()
+            .map_err(|err| SiteError::ThreadPool(err.to_string()))?;
+
+        // Heads are needed for breadcrumb labels and alternates before any
+        // layout runs, so bodies render in a pass of their own
+        let routes: Vec<Rendered> = pool.install(|| {
+            self.routes
+                .into_par_iter()
+                .filter_map(|route| {
+                    let (head, content) = (route.render)(&route.route)?;
+                    let mut page = PageContext::new(route.route, head);
+                    page.locale = route.locale;
+                    Some(Rendered {
+                        page,
+                        content,
+                        raw: route.raw,
+                        group: route.group,
+                    })
+                })
+                .collect()
+        });
+
+        let mut sections = self.sections;
+        sections.sort_by_key(|(prefix, _)| prefix.trim_end_matches('/').len());
+
+        let labels: HashMap<String, String> = routes
+            .iter()
+            .filter(|route| !route.raw)
+            .map(|route| {
+                let path = route.page.route.path().to_string();
+                let label = self
+                    .breadcrumb_labels
+                    .get(&path)
+                    .cloned()
+                    .unwrap_or_else(|| route.page.head.title.clone());
+                (path, label)
+            })
+            .collect();
+
+        let mut groups: HashMap<String, Vec<Alternate>> = HashMap::new();
+        for route in &routes {
+            if let (Some(group), Some(locale)) = (&route.group, &route.page.locale) {
+                groups.entry(group.clone()).or_default().push(Alternate {
+                    locale: locale.clone(),
+                    path: route.page.route.path().to_string(),
+                });
+            }
+        }
+        for alternates in groups.values_mut() {
+            alternates.sort_by_key(|alt| self.locales.iter().position(|l| *l == alt.locale));
+        }
+        let absolute = |path: &str| match &self.base_url {
+            Some(base) => format!("{}{}", base, path),
+            None => path.to_string(),
+        };
+
+        let mut files = HashSet::new();
+        let mut pending = Vec::with_capacity(routes.len());
+        for Rendered {
+            mut page,
+            content,
+            raw,
+            group,
+        } in routes
+        {
+            if page.route.breadcrumbs.is_empty() {
+                let trail = ancestors(page.route.path())
+                    .filter_map(|path| {
+                        let label = labels.get(&path)?.clone();
+                        Some((label.into(), path.into()))
+                    })
+                    .collect();
+                page.route.breadcrumbs = trail;
+            }
+            if let Some(alternates) = group.as_deref().and_then(|group| groups.get(group)) {
+                if page.head.alternates.is_empty() && alternates.len() > 1 {
+                    page.head.alternates = alternates
+                        .iter()
+                        .map(|alt| (alt.locale.code.clone(), absolute(&alt.path)))
+                        .collect();
+                    let default = self.locales.first().map(|l| l.code.as_str());
+                    if let Some(alt) = alternates
+                        .iter()
+                        .find(|alt| Some(alt.locale.code.as_str()) == default)
+                    {
+                        page.head
+                            .alternates
+                            .push(("x-default".to_string(), absolute(&alt.path)));
+                    }
+                }
+                page.alternates = alternates.clone();
+            }
+
+            let path = page.route.path().to_string();
+            let file = output_file(&path);
+            if !files.insert(file.clone()) {
+                return Err(SiteError::DuplicatePath(path));
+            }
+            if !raw && page.head.canonical.is_none() {
+                page.head.canonical = self
+                    .base_url
+                    .as_ref()
+                    .map(|base| format!("{}{}", base, path));
+            }
+            pending.push(Pending {
+                path,
+                file,
+                page,
+                content,
+                raw,
+            });
+        }
+
+        let root = &self.root;
+        let mut pages: Vec<SitePage> = pool.install(|| {
+            pending
+                .into_par_iter()
+                .map(
+                    |Pending {
+                         path,
+                         file,
+                         page,
+                         content,
+                         raw,
+                     }| {
+                        let html = if raw {
+                            content.into_string()
+                        } else {
+                            let html = sections
+                                .iter()
+                                .rev()
+                                .filter(|(prefix, _)| {
+                                    route_match(prefix, &path) != RouteMatch::None
+                                })
+                                .fold(content, |content, (_, layout)| {
+                                    layout.render(&page, content)
+                                });
+                            root.render(&page, html).into_string()
+                        };
+                        SitePage { path, file, html }
+                    },
+                )
+                .collect()
+        });
+
+        for Redirect { from, to } in &self.redirects {
+            let file = output_file(from);
+            if !files.insert(file.clone()) {
+                return Err(SiteError::DuplicatePath(from.clone()));
+            }
+            let canonical = match &self.base_url {
+                Some(base) if to.starts_with('/') => format!("{}{}", base, to),
+                _ => to.clone(),
+            };
+            pages.push(SitePage {
+                path: from.clone(),
+                file,
+                html: redirect_stub(to, &canonical),
+            });
+        }
+        for manifest in &self.manifests {
+            let file = PathBuf::from(manifest.file_name());
+            let path = format!("/{}", manifest.file_name());
+            if !files.insert(file.clone()) {
+                return Err(SiteError::DuplicatePath(path));
+            }
+            let html = manifest.render(&self.redirects);
+            pages.push(SitePage { path, file, html });
+        }
+        Ok(pages)
+    }
+
+    /// Build the site and write its pages into an output directory
+    pub fn write_to(self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, SiteError> {
+        let dir = dir.as_ref();
+        #[cfg(feature = "image")]
+        let icons = self.icons.clone();
+        let written = self
+            .build()?
+            .into_iter()
+            .map(|page| {
+                let path = dir.join(&page.file);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, page.html)?;
+                Ok(path)
+            })
+            .collect::<Result<Vec<_>, SiteError>>()?;
+        #[cfg(feature = "image")]
+        if let Some((source, icon_dir)) = icons {
+            let resizer = crate::assets::ImageResizer::new();
+            let icons = crate::assets::write_icons(source, dir.join(icon_dir), &resizer)?;
+            return Ok(written.into_iter().chain(icons).collect());
+        }
+        Ok(written)
+    }
+}
+
+impl Default for SiteBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `/`, then every prefix of `path` down to `path` itself
+fn ancestors(path: &str) -> impl Iterator<Item = String> + '_ {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    (0..=segments.len()).map(move |depth| format!("/{}", segments[..depth].join("/")))
+}
+
+/// `path` under the locale's prefix: `/about` is `/de/about`, `/` is `/de`
+fn localized_path(locale: &Locale, path: &str) -> String {
+    format!("/{}{}", locale.code, path.trim_end_matches('/'))
+}
+
+/// Standalone page that refreshes to `to` at once, for hosts without
+/// server-side redirects
+fn redirect_stub(to: &str, canonical: &str) -> String {
+    html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                title { "Redirecting…" }
+                meta http-equiv="refresh" content=(format!("0; url={}", to));
+                link rel="canonical" href=(canonical);
+                meta name="robots" content="noindex";
+            }
+            body {
+                p { "Redirecting to " a href=(to) { (to) } "." }
+            }
+        }
+    }
+    .into_string()
+}
+
+enum Segment<'p> {
+    Fixed(&'p str),
+    Param(&'p str),
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment<'_>>, SiteError> {
+    let invalid = || SiteError::InvalidPath(path.to_string());
+    let rest = path.strip_prefix('/').ok_or_else(invalid)?;
+    if path.contains(['?', '#']) {
+        return Err(invalid());
+    }
+
+    let rest = rest.strip_suffix('/').unwrap_or(rest);
+    if rest.is_empty() {
+        return Ok(Vec::new());
+    }
+    rest.split('/')
+        .map(|segment| match segment.strip_prefix('{') {
+            Some(name) => match name.strip_suffix('}') {
+                Some(name) if is_valid_segment(name) && !name.contains(['{', '}']) => {
+                    Ok(Segment::Param(name))
+                }
+                _ => Err(invalid()),
+            },
+            None if is_valid_segment(segment) && !segment.contains(['{', '}']) => {
+                Ok(Segment::Fixed(segment))
+            }
+            None => Err(invalid()),
+        })
+        .collect()
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+    !matches!(segment, "" | "." | "..") && !segment.contains(['/', '?', '#'])
+}
+
+fn fill_pattern(
+    pattern: &str,
+    segments: &[Segment<'_>],
+    values: &[(&'static str, String)],
+) -> Result<String, SiteError> {
+    if segments.is_empty() {
+        return Ok("/".to_string());
+    }
+
+    let mut path = String::new();
+    for segment in segments {
+        path.push('/');
+        match segment {
+            Segment::Fixed(fixed) => path.push_str(fixed),
+            Segment::Param(name) => {
+                let value = values
+                    .iter()
+                    .rev()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| SiteError::MissingParam {
+                        route: pattern.to_string(),
+                        param: name.to_string(),
+                    })?;
+                if !is_valid_segment(value) {
+                    return Err(SiteError::InvalidParam {
+                        route: pattern.to_string(),
+                        param: name.to_string(),
+                        value: value.clone(),
+                    });
+                }
+                path.push_str(value);
+            }
+        }
+    }
+    Ok(path)
+}
+
+/// `/` is `index.html`, paths whose last segment has an extension
+/// (`/404.html`, `/rss.xml`) are written as-is and any other path gets a
+/// directory with an `index.html`
+fn output_file(path: &str) -> PathBuf {
+    let relative = path.trim_matches('/');
+    if relative.is_empty() {
+        PathBuf::from("index.html")
+    } else if Path::new(relative).extension().is_some() {
+        PathBuf::from(relative)
+    } else {
+        Path::new(relative).join("index.html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::breadcrumbs::Breadcrumbs;
+    use crate::i18n::LanguageSwitcher;
+
+    struct Docs;
+
+    impl Layout for Docs {
+        fn render(&self, page: &PageContext, content: Markup) -> Markup {
+            html! {
+                nav {
+                    a href="/docs/intro" aria-current=[page.route.aria_current("/docs/intro")] { "Intro" }
+                }
+                main { (content) }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_layouts() {
+        let pages = SiteBuilder::new()
+            .section("/docs", Docs)
+            .section("/docs/api", |_: &PageContext, content: Markup| {
+                html! { div class="api" { (content) } }
+            })
+            .page("/docs/intro", Head::new("Intro"), |_| html! { p { "Hi" } })
+            .page(
+                "/docs/api/site",
+                Head::new("Site"),
+                |_| html! { p { "API" } },
+            )
+            .page("/404.html", Head::new("Not found").noindex(), |_| {
+                html! { p { "Missing" } }
+            })
+            .build()
+            .unwrap();
+
+        assert!(pages[0]
+            .html
+            .starts_with("<!DOCTYPE html><html lang=\"en\">"));
+        assert!(pages[0]
+            .html
+            .contains(r#"<a href="/docs/intro" aria-current="page">"#));
+        assert!(pages[0].html.contains("<main><p>Hi</p></main>"));
+        assert!(pages[1]
+            .html
+            .contains(r#"<main><div class="api"><p>API</p></div></main>"#));
+        assert!(!pages[2].html.contains("<main>"));
+        assert!(pages[2]
+            .html
+            .contains(r#"<meta name="robots" content="noindex">"#));
+        assert_eq!(pages[2].file, PathBuf::from("404.html"));
+        assert_eq!(pages[1].file, PathBuf::from("docs/api/site/index.html"));
+    }
+
+    #[test]
+    fn test_collection_params_and_head() {
+        let posts = vec![("first", "First post"), ("second", "Second post")];
+        let pages = SiteBuilder::new()
+            .base_url("https://example.com/")
+            .collection(
+                "/blog/{slug}",
+                &posts,
+                |post| vec![("slug", post.0.to_string())],
+                |post, route| {
+                    let head = Head::new(post.1)
+                        .description(format!("About {}", post.1))
+                        .image("/og.png");
+                    (
+                        head,
+                        html! { h1 { (route.param_value("slug").unwrap_or_default()) } },
+                    )
+                },
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].path, "/blog/second");
+        let html = &pages[1].html;
+        assert!(html.contains("<title>Second post</title>"));
+        assert!(html.contains(r#"<meta name="description" content="About Second post">"#));
+        assert!(html.contains(r#"<link rel="canonical" href="https://example.com/blog/second">"#));
+        assert!(html.contains(r#"<meta property="og:image" content="/og.png">"#));
+        assert!(html.contains("<body><h1>second</h1></body>"));
+    }
+
+    #[test]
+    fn test_parallel_build_keeps_route_order() {
+        let slugs: Vec<String> = (0..40).map(|i| format!("post-{}", i)).collect();
+        let build = |jobs: usize| {
+            SiteBuilder::new()
+                .jobs(jobs)
+                .section("/blog", |_: &PageContext, content: Markup| {
+                    html! { article { (content) } }
+                })
+                .collection(
+                    "/blog/{slug}",
+                    &slugs,
+                    |slug| vec![("slug", slug.to_string())],
+                    |slug, _| (Head::new(slug.as_str()), html! { h1 { (slug) } }),
+                )
+                .file("/rss.xml", "<rss/>")
+                .build()
+                .unwrap()
+        };
+
+        let parallel = build(4);
+        assert_eq!(parallel.len(), 41);
+        assert_eq!(parallel[7].path, "/blog/post-7");
+        assert!(parallel[7]
+            .html
+            .contains("<article><h1>post-7</h1></article>"));
+        assert_eq!(parallel[40].html, "<rss/>");
+        assert_eq!(parallel, build(1));
+    }
+
+    #[test]
+    fn test_pages_render_on_the_build_pool() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let rendered = AtomicUsize::new(0);
+        let on_pool = AtomicUsize::new(0);
+        let body = |label: &str| {
+            rendered.fetch_add(1, Ordering::SeqCst);
+            if rayon::current_thread_index().is_some() {
+                on_pool.fetch_add(1, Ordering::SeqCst);
+            }
+            html! { p { (label) } }
+        };
+
+        let site = SiteBuilder::new()
+            .jobs(2)
+            .page("/", Head::new("Home"), |_| body("Home"))
+            .collection(
+                "/posts/{n}",
+                0..8,
+                |n| vec![("n", n.to_string())],
+                |n, _| (Head::new(format!("Post {}", n)), body("Post")),
+            );
+        assert_eq!(rendered.load(Ordering::SeqCst), 0);
+
+        let pages = site.build().unwrap();
+        assert_eq!(pages.len(), 9);
+        assert_eq!(pages[3].path, "/posts/2");
+        assert_eq!(rendered.load(Ordering::SeqCst), 9);
+        assert_eq!(on_pool.load(Ordering::SeqCst), 9);
+    }
+
+    #[test]
+    fn test_route_errors() {
+        let missing = SiteBuilder::new()
+            .collection(
+                "/tags/{tag}",
+                ["rust"],
+                |_| Vec::new(),
+                |_, _| (Head::new("Tag"), html! {}),
+            )
+            .build();
+        assert!(matches!(missing, Err(SiteError::MissingParam { .. })));
+
+        let unsafe_value = SiteBuilder::new()
+            .collection(
+                "/tags/{tag}",
+                [".."],
+                |tag| vec![("tag", tag.to_string())],
+                |_, _| (Head::new("Tag"), html! {}),
+            )
+            .build();
+        assert!(matches!(unsafe_value, Err(SiteError::InvalidParam { .. })));
+
+        let duplicate = SiteBuilder::new()
+            .page("/about", Head::new("About"), |_| html! {})
+            .page("/about/", Head::new("About"), |_| html! {})
+            .build();
+        assert!(matches!(duplicate, Err(SiteError::DuplicatePath(path)) if path == "/about"));
+
+        for path in ["about", "/a/../b", "/a//b", "/search?q=1", "/blog/{slug"] {
+            let result = SiteBuilder::new()
+                .page(path, Head::new("x"), |_| html! {})
+                .build();
+            assert!(matches!(result, Err(SiteError::InvalidPath(_))), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_route_tree() {
+        let crumbs = |page: &PageContext, content: Markup| {
+            html! { (Breadcrumbs::from_route(&page.route).render()) (content) }
+        };
+        let pages = SiteBuilder::new()
+            .section("/docs", crumbs)
+            .breadcrumb_label("/", "Home")
+            .page("/", Head::new("Example"), |_| html! {})
+            .page("/docs", Head::new("Documentation"), |_| html! {})
+            .collection(
+                "/docs/guides/{slug}",
+                ["setup"],
+                |slug| vec![("slug", slug.to_string())],
+                |_, _| (Head::new("Setup"), html! {}),
+            )
+            .build()
+            .unwrap();
+
+        let setup = &pages[2].html;
+        assert!(setup.contains(r#"<a href="/">Home</a>"#));
+        assert!(setup.contains(r#"<a href="/docs">Documentation</a>"#));
+        assert!(setup.contains(r#"<span aria-current="page">Setup</span>"#));
+        assert!(!setup.contains("/docs/guides\""));
+        assert_eq!(
+            ancestors("/docs/guides/setup").collect::<Vec<_>>(),
+            ["/", "/docs", "/docs/guides", "/docs/guides/setup"]
+        );
+    }
+
+    #[test]
+    fn test_localized_routes() {
+        let posts = [
+            ("en", "hello", "Hello"),
+            ("de", "hello", "Hallo"),
+            ("en", "only", "Only"),
+        ];
+        let pages = SiteBuilder::new()
+            .base_url("https://example.com")
+            .locale(Locale::new("en", "English"))
+            .locale(Locale::new("de", "Deutsch"))
+            .layout(|page: &PageContext, content: Markup| {
+                let body = html! { (LanguageSwitcher::for_page(page)) (content) };
+                Shell::new().render(page, body)
+            })
+            .localized_page("/", |locale, _| {
+                Some((
+                    Head::new(locale.name.clone()),
+                    html! { h1 { (locale.name) } },
+                ))
+            })
+            .localized_page("/imprint", |locale, _| {
+                (locale.code == "de").then(|| (Head::new("Impressum"), html! {}))
+            })
+            .localized_collection(
+                "/blog/{slug}",
+                posts,
+                |(code, _, _)| code.to_string(),
+                |(_, slug, _)| vec![("slug", slug.to_string())],
+                |(_, _, title), _, route| {
+                    assert!(route.param_value("locale").is_some());
+                    (Head::new(*title), html! { h1 { (title) } })
+                },
+            )
+            .build()
+            .unwrap();
+
+        let paths: Vec<_> = pages.iter().map(|p| p.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/en",
+                "/de",
+                "/de/imprint",
+                "/en/blog/hello",
+                "/de/blog/hello",
+                "/en/blog/only"
+            ]
+        );
+        assert_eq!(pages[1].file, PathBuf::from("de/index.html"));
+
+        let hallo = &pages[4].html;
+        for link in [
+            r#"<link rel="alternate" hreflang="en" href="https://example.com/en/blog/hello">"#,
+            r#"<link rel="alternate" hreflang="de" href="https://example.com/de/blog/hello">"#,
+            r#"<link rel="alternate" hreflang="x-default" href="https://example.com/en/blog/hello">"#,
+        ] {
+            assert!(hallo.contains(link), "{}", link);
+        }
+        assert!(hallo.contains(r#"<html lang="de">"#));
+        assert!(hallo.contains(r#"href="/en/blog/hello" hreflang="en""#));
+        assert!(!pages[5].html.contains("hreflang"));
+        assert!(!pages[2].html.contains("sh-language-switcher"));
+
+        let unknown = SiteBuilder::new()
+            .locale(Locale::new("en", "English"))
+            .localized_collection(
+                "/{slug}",
+                [("fr", "a")],
+                |(code, _)| code.to_string(),
+                |(_, slug)| vec![("slug", slug.to_string())],
+                |_, _, _| (Head::new("A"), html! {}),
+            )
+            .build();
+        assert!(matches!(unknown, Err(SiteError::UnknownLocale(code)) if code == "fr"));
+        let no_locales = SiteBuilder::new().localized_page("/", |_, _| None).build();
+        assert!(matches!(no_locales, Err(SiteError::NoLocales(_))));
+    }
+
+    #[test]
+    fn test_redirects_and_manifests() {
+        let pages = SiteBuilder::new()
+            .base_url("https://example.com")
+            .page("/new", Head::new("New"), |_| html! { h1 { "New" } })
+            .redirect("/old", "/new")
+            .redirect("/docs.html", "https://docs.example.com/")
+            .redirect_manifest(RedirectManifest::Redirects)
+            .redirect_manifest(RedirectManifest::NetlifyToml)
+            .redirect_manifest(RedirectManifest::VercelJson)
+            .build()
+            .unwrap();
+
+        let stub = &pages[1];
+        assert_eq!(stub.file, PathBuf::from("old/index.html"));
+        assert!(stub
+            .html
+            .contains(r#"<meta http-equiv="refresh" content="0; url=/new">"#));
+        assert!(stub
+            .html
+            .contains(r#"<link rel="canonical" href="https://example.com/new">"#));
+        assert_eq!(pages[2].file, PathBuf::from("docs.html"));
+
+        assert_eq!(
+            pages[3].html,
+            "/old /new 301\n/docs.html https://docs.example.com/ 301\n"
+        );
+        assert_eq!(pages[4].file, PathBuf::from("netlify.toml"));
+        assert!(pages[4]
+            .html
+            .contains("[[redirects]]\nfrom = \"/old\"\nto = \"/new\"\nstatus = 301\n"));
+        let vercel: Value = serde_json::from_str(&pages[5].html).unwrap();
+        assert_eq!(
+            vercel["redirects"][1]["destination"],
+            "https://docs.example.com/"
+        );
+        assert_eq!(vercel["redirects"][0]["permanent"], true);
+
+        for (from, to) in [
+            ("/new", "/elsewhere"),
+            ("/a", "elsewhere"),
+            ("/b", "//evil.com"),
+        ] {
+            let result = SiteBuilder::new()
+                .page("/new", Head::new("New"), |_| html! {})
+                .redirect(from, to)
+                .build();
+            assert!(result.is_err(), "{} -> {}", from, to);
+        }
+    }
+
+    #[test]
+    fn test_write_to() {
+        let dir = std::env::temp_dir().join(format!("shallot-site-{}", std::process::id()));
+        let written = SiteBuilder::new()
+            .page("/", Head::new("Home"), |_| html! { h1 { "Home" } })
+            .page("/about", Head::new("About"), |_| html! { h1 { "About" } })
+            .file("/rss.xml", "<rss></rss>")
+            .write_to(&dir)
+            .unwrap();
+
+        assert_eq!(written[1], dir.join("about/index.html"));
+        assert_eq!(written[2], dir.join("rss.xml"));
+        let home = fs::read_to_string(dir.join("index.html")).unwrap();
+        assert!(home.contains("<title>Home</title>"));
+        let feed = fs::read_to_string(dir.join("rss.xml")).unwrap();
+        assert_eq!(feed, "<rss></rss>");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_write_to_icons() {
+        let dir = std::env::temp_dir().join(format!("shallot-site-icons-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let logo = dir.join("logo.png");
+        image::RgbaImage::new(48, 48).save(&logo).unwrap();
+
+        let written = SiteBuilder::new()
+            .page("/", Head::new("Home"), |_| html! {})
+            .icons(&logo, "/icons")
+            .write_to(dir.join("out"))
+            .unwrap();
+
+        assert_eq!(written.len(), 1 + crate::assets::STANDARD_ICONS.len());
+        let icon = image::open(dir.join("out/icons/apple-touch-icon.png")).unwrap();
+        assert_eq!((icon.width(), icon.height()), (180, 180));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -67,6 +67,20 @@ impl Skeleton {
         self
     }
 
+    /// Fill the parent box; the parent's own classes supply the dimensions
+    pub fn fill() -> Self {
+        Self::new().size("100%", "100%").border_radius("inherit")
+    }
+
+    /// An inline placeholder for one line of text. Placed inside the real
+    /// text element it keeps that element's font size and line height.
+    pub fn inline(width: impl Into<String>) -> Self {
+        Self::new()
+            .width(width)
+            .height("1em")
+            .custom_class("sh-skeleton--inline")
+    }
+
     /// Make it a circle (for avatars)
     pub fn circle(mut self, size: impl Into<String>) -> Self {
         let size = size.into();
@@ -365,6 +379,13 @@ pub fn skeleton_css() -> String {
   opacity: 0.7;
 }
 
+/* Inline text placeholder */
+.sh-skeleton--inline {
+  display: inline-block;
+  vertical-align: middle;
+  max-width: 100%;
+}
+
 /* Skeleton Text Container */
 .sh-skeleton-text {
   display: flex;
@@ -457,6 +478,18 @@ mod tests {
         assert_eq!(text.last_line_width, "40%");
     }
 
+    #[test]
+    fn test_skeleton_fill_and_inline() {
+        let fill = Skeleton::fill();
+        assert_eq!(fill.width, "100%");
+        assert_eq!(fill.height, "100%");
+        assert_eq!(fill.border_radius, "inherit");
+
+        let inline = Skeleton::inline("60%").render().into_string();
+        assert!(inline.contains("sh-skeleton--inline"));
+        assert!(inline.contains("width: 60%; height: 1em;"));
+    }
+
     #[test]
     fn test_skeleton_card() {
         let card = SkeletonCard::new()
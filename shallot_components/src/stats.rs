@@ -4,7 +4,10 @@
 //! Inspired by DaisyUI's stat component with rich variations.
 
 use crate::animated_text::NumberTicker;
+use crate::charts::{ChartColor, Sparkline};
 use crate::component::{Component, ComponentColor};
+use crate::counter::NumberFormat;
+use crate::skeleton::Skeleton;
 use maud::{html, Markup, Render};
 use shallot_foundation::Icon;
 
@@ -114,6 +117,27 @@ impl<'a> Stat<'a> {
 
         classes.join(" ")
     }
+
+    /// Render a loading placeholder with this stat's classes and rows
+    pub fn skeleton(&self) -> Markup {
+        html! {
+            div class=(self.build_classes()) role="group" aria-busy="true" aria-label="Loading statistic" {
+                @if self.figure.is_some() {
+                    div class="sh-stat__figure" aria-hidden="true" {
+                        (Skeleton::new().size("3rem", "3rem").render())
+                    }
+                }
+                div class="sh-stat__title" { (Skeleton::inline("50%").render()) }
+                div class="sh-stat__value" { (Skeleton::inline("70%").render()) }
+                @if self.trend.is_some() {
+                    div class="sh-stat__trend" { (Skeleton::inline("3rem").render()) }
+                }
+                @if self.description.is_some() {
+                    div class="sh-stat__desc" { (Skeleton::inline("60%").render()) }
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Render for Stat<'a> {
@@ -241,6 +265,17 @@ impl<'a> Stats<'a> {
         classes.join(" ")
     }
 
+    /// Render a loading placeholder for the whole group
+    pub fn skeleton(&self) -> Markup {
+        html! {
+            div class=(self.build_classes()) style=(self.build_style()) aria-busy="true" {
+                @for stat in &self.stats {
+                    (stat.skeleton())
+                }
+            }
+        }
+    }
+
     fn build_style(&self) -> String {
         if let Some(bg) = &self.bg_color {
             format!("background: {};", bg)
@@ -270,9 +305,13 @@ pub struct MetricCard<'a> {
     title: &'a str,
     value: Markup,
     change: Option<&'a str>,
+    /// `None` with a change set is a neutral trend
     change_positive: Option<bool>,
     icon: Option<Icon>,
     color: ComponentColor,
+    /// Past values, oldest first, drawn as a sparkline
+    history: Vec<f64>,
+    delta_bar: bool,
 }
 
 impl<'a> MetricCard<'a> {
@@ -284,6 +323,8 @@ impl<'a> MetricCard<'a> {
             change_positive: None,
             icon: None,
             color: ComponentColor::Primary,
+            history: Vec::new(),
+            delta_bar: false,
         }
     }
 
@@ -293,6 +334,29 @@ impl<'a> MetricCard<'a> {
         self
     }
 
+    /// Set the change text with an explicit direction, including neutral
+    pub fn trend(mut self, direction: TrendDirection, change: &'a str) -> Self {
+        self.change = Some(change);
+        self.change_positive = match direction {
+            TrendDirection::Up => Some(true),
+            TrendDirection::Down => Some(false),
+            TrendDirection::Neutral => None,
+        };
+        self
+    }
+
+    /// Show past values as a sparkline; needs at least two points
+    pub fn history(mut self, history: Vec<f64>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Show a mini-bar sized by the change across the history
+    pub fn delta_bar(mut self, show: bool) -> Self {
+        self.delta_bar = show;
+        self
+    }
+
     pub fn icon(mut self, icon: Icon) -> Self {
         self.icon = Some(icon);
         self
@@ -304,12 +368,119 @@ impl<'a> MetricCard<'a> {
     }
 }
 
-impl<'a> Render for MetricCard<'a> {
-    fn render(&self) -> Markup {
-        let class = format!(
+impl<'a> MetricCard<'a> {
+    /// Direction of the change if one is set, otherwise of the history
+    pub fn trend_direction(&self) -> Option<TrendDirection> {
+        if self.change.is_some() {
+            return Some(match self.change_positive {
+                Some(true) => TrendDirection::Up,
+                Some(false) => TrendDirection::Down,
+                None => TrendDirection::Neutral,
+            });
+        }
+        let (first, last) = self.history_bounds()?;
+        Some(if last > first {
+            TrendDirection::Up
+        } else if last < first {
+            TrendDirection::Down
+        } else {
+            TrendDirection::Neutral
+        })
+    }
+
+    /// Change from the first to the last history value in percent; `None`
+    /// without two points or when the first value is zero
+    pub fn history_delta(&self) -> Option<f64> {
+        let (first, last) = self.history_bounds()?;
+        (first != 0.0).then(|| (last - first) / first.abs() * 100.0)
+    }
+
+    fn history_bounds(&self) -> Option<(f64, f64)> {
+        match self.history.as_slice() {
+            [first, .., last] => Some((*first, *last)),
+            _ => None,
+        }
+    }
+
+    fn has_chart(&self) -> bool {
+        self.history.len() >= 2
+    }
+
+    fn render_chart(&self) -> Markup {
+        let color = match self.trend_direction() {
+            Some(TrendDirection::Up) => ChartColor::Success,
+            Some(TrendDirection::Down) => ChartColor::Error,
+            Some(TrendDirection::Neutral) | None => ChartColor::Custom("var(--sh-text-muted)"),
+        };
+        Sparkline::new(self.history.clone())
+            .width(96)
+            .height(32)
+            .color(color)
+            .aria_label(format!(
+                "{} over the last {} periods",
+                self.title,
+                self.history.len()
+            ))
+            .render()
+    }
+
+    fn render_delta_bar(&self) -> Markup {
+        let Some(delta) = self.history_delta() else {
+            return html! {};
+        };
+        html! {
+            div class="sh-metric-card__delta" aria-hidden="true" {
+                div
+                    class="sh-metric-card__delta-fill"
+                    style=(format!("width: {:.0}%;", delta.abs().min(100.0))) {}
+            }
+        }
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = format!(
             "sh-metric-card sh-metric-card--{}",
             self.color.class_suffix()
         );
+        if let Some(direction) = self.trend_direction() {
+            classes.push_str(match direction {
+                TrendDirection::Up => " sh-metric-card--up",
+                TrendDirection::Down => " sh-metric-card--down",
+                TrendDirection::Neutral => " sh-metric-card--neutral",
+            });
+        }
+        classes
+    }
+
+    /// Render a loading placeholder with this card's header, value and change rows
+    pub fn skeleton(&self) -> Markup {
+        html! {
+            div class=(self.build_classes()) aria-busy="true" aria-label="Loading metric" {
+                div class="sh-metric-card__header" {
+                    span class="sh-metric-card__title" { (Skeleton::inline("6rem").render()) }
+                    @if self.icon.is_some() {
+                        div class="sh-metric-card__icon" { (Skeleton::fill().render()) }
+                    }
+                }
+                div class="sh-metric-card__body" {
+                    div class="sh-metric-card__figures" {
+                        div class="sh-metric-card__value" { (Skeleton::inline("60%").render()) }
+                        @if self.change.is_some() {
+                            div class="sh-metric-card__change" { (Skeleton::inline("4rem").render()) }
+                        }
+                    }
+                    @if self.has_chart() {
+                        div class="sh-metric-card__chart" { (Skeleton::inline("6rem").render()) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for MetricCard<'a> {
+    fn render(&self) -> Markup {
+        let class = self.build_classes();
 
         html! {
             div class=(class) {
@@ -321,15 +492,386 @@ impl<'a> Render for MetricCard<'a> {
                         }
                     }
                 }
-                div class="sh-metric-card__value" { (self.value.clone()) }
-                @if let Some(change) = self.change {
-                    @if let Some(positive) = self.change_positive {
-                        div class=(format!("sh-metric-card__change {}",
-                            if positive { "sh-metric-card__change--positive" } else { "sh-metric-card__change--negative" }
-                        )) {
-                            span { (if positive { "↑" } else { "↓" }) " " (change) }
+                div class="sh-metric-card__body" {
+                    div class="sh-metric-card__figures" {
+                        div class="sh-metric-card__value" { (self.value.clone()) }
+                        @if let Some(change) = self.change {
+                            @match self.change_positive {
+                                Some(positive) => div class=(format!("sh-metric-card__change {}",
+                                    if positive { "sh-metric-card__change--positive" } else { "sh-metric-card__change--negative" }
+                                )) {
+                                    span { (if positive { "↑" } else { "↓" }) " " (change) }
+                                },
+                                None => div class="sh-metric-card__change sh-metric-card__change--neutral" {
+                                    span { "→ " (change) }
+                                },
+                            }
                         }
                     }
+                    @if self.has_chart() {
+                        div class="sh-metric-card__chart" { (self.render_chart()) }
+                    }
+                }
+                @if self.delta_bar {
+                    (self.render_delta_bar())
+                }
+            }
+        }
+    }
+}
+
+/// Change from `previous` to `current` in percent; `None` when `previous`
+/// is zero
+pub fn percent_change(current: f64, previous: f64) -> Option<f64> {
+    (previous != 0.0).then(|| (current - previous) / previous.abs() * 100.0)
+}
+
+/// Whether a rise is good news, for coloring a change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeltaSemantics {
+    /// Revenue, signups: up is green
+    #[default]
+    HigherIsBetter,
+    /// Churn, response time: down is green
+    LowerIsBetter,
+    /// Neither direction is colored
+    Neutral,
+}
+
+impl DeltaSemantics {
+    /// `good`, `bad` or `neutral` for a change in `direction`
+    pub fn tone(self, direction: TrendDirection) -> &'static str {
+        match (self, direction) {
+            (DeltaSemantics::Neutral, _) | (_, TrendDirection::Neutral) => "neutral",
+            (DeltaSemantics::HigherIsBetter, TrendDirection::Up)
+            | (DeltaSemantics::LowerIsBetter, TrendDirection::Down) => "good",
+            _ => "bad",
+        }
+    }
+}
+
+/// `+12.5%`, `-3%` or `0%`
+fn signed(value: f64, text: String) -> String {
+    if value > 0.0 {
+        format!("+{}", text)
+    } else if value < 0.0 && !text.starts_with('-') {
+        format!("-{}", text)
+    } else {
+        text
+    }
+}
+
+/// A value against the previous period: arrow, percent change and a
+/// color that follows [`DeltaSemantics`]
+pub struct StatDelta<'a> {
+    value: f64,
+    previous: f64,
+    label: Option<&'a str>,
+    /// Text after the previous value, like `last month`
+    period: Option<&'a str>,
+    format: NumberFormat,
+    prefix: &'a str,
+    suffix: &'a str,
+    semantics: DeltaSemantics,
+    /// Changes smaller than this many percent count as no change
+    neutral_within: f64,
+    percent_decimals: u8,
+    show_previous: bool,
+}
+
+impl<'a> StatDelta<'a> {
+    pub fn new(value: f64, previous: f64) -> Self {
+        Self {
+            value,
+            previous,
+            label: None,
+            period: None,
+            format: NumberFormat::default(),
+            prefix: "",
+            suffix: "",
+            semantics: DeltaSemantics::HigherIsBetter,
+            neutral_within: 0.0,
+            percent_decimals: 1,
+            show_previous: true,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Name the previous period, shown as `vs 1,200 last month`
+    pub fn period(mut self, period: &'a str) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// How both values are written
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn semantics(mut self, semantics: DeltaSemantics) -> Self {
+        self.semantics = semantics;
+        self
+    }
+
+    /// Shorthand for [`DeltaSemantics::LowerIsBetter`]
+    pub fn lower_is_better(self) -> Self {
+        self.semantics(DeltaSemantics::LowerIsBetter)
+    }
+
+    /// Treat changes within `percent` either way as flat
+    pub fn neutral_within(mut self, percent: f64) -> Self {
+        self.neutral_within = percent.abs();
+        self
+    }
+
+    /// Digits after the point of the percent change
+    pub fn percent_decimals(mut self, decimals: u8) -> Self {
+        self.percent_decimals = decimals;
+        self
+    }
+
+    pub fn show_previous(mut self, show: bool) -> Self {
+        self.show_previous = show;
+        self
+    }
+
+    /// Change in percent, see [`percent_change`]
+    pub fn percent_change(&self) -> Option<f64> {
+        percent_change(self.value, self.previous)
+    }
+
+    pub fn direction(&self) -> TrendDirection {
+        let change = self.value - self.previous;
+        let flat = match self.percent_change() {
+            Some(percent) => percent.abs() <= self.neutral_within,
+            None => change == 0.0,
+        };
+        if flat {
+            TrendDirection::Neutral
+        } else if change > 0.0 {
+            TrendDirection::Up
+        } else {
+            TrendDirection::Down
+        }
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        format!(
+            "{}{}{}",
+            self.prefix,
+            self.format.format(value),
+            self.suffix
+        )
+    }
+
+    /// `+12.5%`, or the signed difference when the previous value is zero
+    pub fn change_text(&self) -> String {
+        match self.percent_change() {
+            Some(percent) => {
+                let text = format!("{:.1$}%", percent.abs(), self.percent_decimals as usize);
+                signed(percent, text)
+            }
+            None => {
+                let change = self.value - self.previous;
+                signed(change, self.format_value(change.abs()))
+            }
+        }
+    }
+}
+
+impl<'a> Render for StatDelta<'a> {
+    fn render(&self) -> Markup {
+        let direction = self.direction();
+        let (class, arrow, spoken) = match direction {
+            TrendDirection::Up => ("up", "↑", "Up "),
+            TrendDirection::Down => ("down", "↓", "Down "),
+            TrendDirection::Neutral => ("neutral", "→", "No change, "),
+        };
+
+        html! {
+            div
+                class=(format!("sh-stat-delta sh-stat-delta--{} sh-stat-delta--{}", class, self.semantics.tone(direction)))
+                role="group"
+                aria-label=[self.label]
+            {
+                @if let Some(label) = self.label {
+                    span class="sh-stat-delta__label" { (label) }
+                }
+                span class="sh-stat-delta__value" { (self.format_value(self.value)) }
+                span class="sh-stat-delta__change" {
+                    span class="sh-stat-delta__arrow" aria-hidden="true" { (arrow) }
+                    span class="sh-visually-hidden" { (spoken) }
+                    (self.change_text())
+                }
+                @if self.show_previous {
+                    span class="sh-stat-delta__previous" {
+                        "vs " (self.format_value(self.previous))
+                        @if let Some(period) = self.period {
+                            " " (period)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Two values drawn on one bar, such as this period against the last or
+/// actual against target
+pub struct ComparisonBar<'a> {
+    current: f64,
+    previous: f64,
+    title: Option<&'a str>,
+    current_label: &'a str,
+    previous_label: &'a str,
+    /// Full width of the bar; defaults to the larger value
+    max: Option<f64>,
+    format: NumberFormat,
+    prefix: &'a str,
+    suffix: &'a str,
+    semantics: DeltaSemantics,
+    /// Draw the previous value as a tick instead of a fill
+    marker: bool,
+}
+
+impl<'a> ComparisonBar<'a> {
+    pub fn new(current: f64, previous: f64) -> Self {
+        Self {
+            current,
+            previous,
+            title: None,
+            current_label: "Current",
+            previous_label: "Previous",
+            max: None,
+            format: NumberFormat::default(),
+            prefix: "",
+            suffix: "",
+            semantics: DeltaSemantics::HigherIsBetter,
+            marker: false,
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Legend names of the two values
+    pub fn labels(mut self, current: &'a str, previous: &'a str) -> Self {
+        self.current_label = current;
+        self.previous_label = previous;
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn semantics(mut self, semantics: DeltaSemantics) -> Self {
+        self.semantics = semantics;
+        self
+    }
+
+    pub fn marker(mut self, marker: bool) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Share of the bar covered by `value`, in percent
+    fn width(&self, value: f64) -> f64 {
+        let max = self.max.unwrap_or(self.current.max(self.previous));
+        if max <= 0.0 {
+            return 0.0;
+        }
+        (value / max * 100.0).clamp(0.0, 100.0)
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        format!(
+            "{}{}{}",
+            self.prefix,
+            self.format.format(value),
+            self.suffix
+        )
+    }
+}
+
+impl<'a> Render for ComparisonBar<'a> {
+    fn render(&self) -> Markup {
+        let delta = StatDelta::new(self.current, self.previous);
+        let direction = delta.direction();
+        let tone = self.semantics.tone(direction);
+        let current = self.format_value(self.current);
+        let previous = self.format_value(self.previous);
+
+        html! {
+            div
+                class=(format!("sh-comparison-bar sh-comparison-bar--{}", tone))
+                role="group"
+                aria-label=[self.title]
+            {
+                @if let Some(title) = self.title {
+                    div class="sh-comparison-bar__header" {
+                        span class="sh-comparison-bar__title" { (title) }
+                        span class=(format!("sh-comparison-bar__change sh-comparison-bar__change--{}", tone)) {
+                            (delta.change_text())
+                        }
+                    }
+                }
+                div class="sh-comparison-bar__track" aria-hidden="true" {
+                    @if self.marker {
+                        div
+                            class="sh-comparison-bar__marker"
+                            style=(format!("left: {:.1}%;", self.width(self.previous))) {}
+                    } @else {
+                        div
+                            class="sh-comparison-bar__fill sh-comparison-bar__fill--previous"
+                            style=(format!("width: {:.1}%;", self.width(self.previous))) {}
+                    }
+                    div
+                        class="sh-comparison-bar__fill sh-comparison-bar__fill--current"
+                        style=(format!("width: {:.1}%;", self.width(self.current))) {}
+                }
+                dl class="sh-comparison-bar__legend" {
+                    div class="sh-comparison-bar__item sh-comparison-bar__item--current" {
+                        dt { (self.current_label) }
+                        dd { (current) }
+                    }
+                    div class="sh-comparison-bar__item sh-comparison-bar__item--previous" {
+                        dt { (self.previous_label) }
+                        dd { (previous) }
+                    }
                 }
             }
         }
@@ -529,6 +1071,53 @@ pub fn stats_css() -> String {
   color: var(--sh-error);
 }
 
+.sh-metric-card__change--neutral {
+  color: var(--sh-text-muted);
+}
+
+/* Value and sparkline share a bottom edge */
+.sh-metric-card__body {
+  display: flex;
+  align-items: flex-end;
+  justify-content: space-between;
+  gap: 1rem;
+}
+
+.sh-metric-card__figures {
+  min-width: 0;
+}
+
+.sh-metric-card__chart {
+  flex-shrink: 0;
+  margin-bottom: 0.5rem;
+}
+
+.sh-metric-card__chart svg {
+  display: block;
+}
+
+.sh-metric-card__delta {
+  height: 0.25rem;
+  margin-top: 0.75rem;
+  border-radius: 9999px;
+  background: var(--sh-border);
+  overflow: hidden;
+}
+
+.sh-metric-card__delta-fill {
+  height: 100%;
+  border-radius: inherit;
+  background: var(--sh-text-muted);
+}
+
+.sh-metric-card--up .sh-metric-card__delta-fill {
+  background: var(--sh-success);
+}
+
+.sh-metric-card--down .sh-metric-card__delta-fill {
+  background: var(--sh-error);
+}
+
 /* Color variants for metric cards */
 .sh-metric-card--primary .sh-metric-card__icon {
   background: color-mix(in srgb, var(--sh-accent) 10%, transparent);
@@ -550,6 +1139,174 @@ pub fn stats_css() -> String {
   color: var(--sh-error);
 }
 
+/* Stat Delta */
+.sh-stat-delta {
+  display: flex;
+  flex-direction: column;
+  align-items: flex-start;
+  gap: 0.375rem;
+}
+
+.sh-stat-delta__label {
+  font-size: 0.875rem;
+  font-weight: 500;
+  color: var(--sh-text-muted);
+}
+
+.sh-stat-delta__value {
+  font-size: 2rem;
+  font-weight: 700;
+  line-height: 1;
+  color: var(--sh-text);
+  font-variant-numeric: tabular-nums;
+}
+
+.sh-stat-delta__change {
+  display: inline-flex;
+  align-items: center;
+  gap: 0.25rem;
+  padding: 0.125rem 0.5rem;
+  font-size: 0.875rem;
+  font-weight: 600;
+  border-radius: 9999px;
+  font-variant-numeric: tabular-nums;
+}
+
+.sh-stat-delta__previous {
+  font-size: 0.75rem;
+  color: var(--sh-text-muted);
+}
+
+.sh-stat-delta--good .sh-stat-delta__change {
+  color: var(--sh-success);
+  background: color-mix(in srgb, var(--sh-success) 12%, transparent);
+}
+
+.sh-stat-delta--bad .sh-stat-delta__change {
+  color: var(--sh-error);
+  background: color-mix(in srgb, var(--sh-error) 12%, transparent);
+}
+
+.sh-stat-delta--neutral .sh-stat-delta__change {
+  color: var(--sh-text-muted);
+  background: color-mix(in srgb, var(--sh-text-muted) 12%, transparent);
+}
+
+/* Comparison Bar */
+.sh-comparison-bar {
+  display: flex;
+  flex-direction: column;
+  gap: 0.5rem;
+}
+
+.sh-comparison-bar__header {
+  display: flex;
+  align-items: baseline;
+  justify-content: space-between;
+  gap: 1rem;
+  font-size: 0.875rem;
+}
+
+.sh-comparison-bar__title {
+  font-weight: 500;
+  color: var(--sh-text);
+}
+
+.sh-comparison-bar__change {
+  font-weight: 600;
+  font-variant-numeric: tabular-nums;
+}
+
+.sh-comparison-bar__change--good { color: var(--sh-success); }
+.sh-comparison-bar__change--bad { color: var(--sh-error); }
+.sh-comparison-bar__change--neutral { color: var(--sh-text-muted); }
+
+.sh-comparison-bar__track {
+  position: relative;
+  height: 0.5rem;
+  border-radius: 9999px;
+  background: var(--sh-border);
+}
+
+.sh-comparison-bar__fill {
+  position: absolute;
+  inset-block: 0;
+  left: 0;
+  border-radius: inherit;
+}
+
+.sh-comparison-bar__fill--previous {
+  background: color-mix(in srgb, var(--sh-text-muted) 40%, transparent);
+}
+
+.sh-comparison-bar__fill--current {
+  background: var(--sh-accent);
+}
+
+.sh-comparison-bar--good .sh-comparison-bar__fill--current {
+  background: var(--sh-success);
+}
+
+.sh-comparison-bar--bad .sh-comparison-bar__fill--current {
+  background: var(--sh-error);
+}
+
+/* Above the current fill so it stays visible underneath it */
+.sh-comparison-bar__marker {
+  position: absolute;
+  z-index: 1;
+  top: -0.25rem;
+  bottom: -0.25rem;
+  width: 2px;
+  margin-left: -1px;
+  background: var(--sh-text);
+}
+
+.sh-comparison-bar__legend {
+  display: flex;
+  justify-content: space-between;
+  gap: 1rem;
+  margin: 0;
+  font-size: 0.75rem;
+}
+
+.sh-comparison-bar__item {
+  display: flex;
+  align-items: center;
+  gap: 0.375rem;
+}
+
+.sh-comparison-bar__item::before {
+  content: "";
+  width: 0.5rem;
+  height: 0.5rem;
+  border-radius: 9999px;
+  background: var(--sh-accent);
+}
+
+.sh-comparison-bar--good .sh-comparison-bar__item--current::before {
+  background: var(--sh-success);
+}
+
+.sh-comparison-bar--bad .sh-comparison-bar__item--current::before {
+  background: var(--sh-error);
+}
+
+.sh-comparison-bar__item--previous::before {
+  background: color-mix(in srgb, var(--sh-text-muted) 40%, transparent);
+}
+
+.sh-comparison-bar__item dt {
+  color: var(--sh-text-muted);
+}
+
+.sh-comparison-bar__item dd {
+  margin: 0;
+  font-weight: 600;
+  color: var(--sh-text);
+  font-variant-numeric: tabular-nums;
+}
+
 /* Responsive */
 @media (max-width: 640px) {
   .sh-stats--horizontal {
@@ -577,6 +1334,32 @@ pub fn stats_css() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stat_skeleton() {
+        let stat = Stat::new("Revenue", html! { "$1,000" })
+            .description("This month")
+            .trend(TrendDirection::Up, "12%");
+        let html = stat.skeleton().into_string();
+        assert!(html.contains(r#"class="sh-stat""#));
+        assert!(html.contains("aria-busy=\"true\""));
+        assert!(html.contains("sh-stat__trend"));
+        assert!(html.contains("sh-stat__desc"));
+        assert!(!html.contains("Revenue"));
+
+        let group = Stats::new(vec![stat]).skeleton().into_string();
+        assert!(group.contains("sh-stats--horizontal"));
+        assert_eq!(group.matches("sh-stat__value").count(), 1);
+    }
+
+    #[test]
+    fn test_metric_card_skeleton() {
+        let card = MetricCard::new("Users", html! { "42" }).change("+5%", true);
+        let html = card.skeleton().into_string();
+        assert!(html.contains("sh-metric-card sh-metric-card--primary"));
+        assert!(html.contains("sh-metric-card__change"));
+        assert!(!html.contains("sh-metric-card__icon"));
+    }
+
     #[test]
     fn test_stat_creation() {
         let stat = Stat::new("Users", html! { "1,234" })
@@ -619,4 +1402,117 @@ mod tests {
         assert_eq!(card.change, Some("+20%"));
         assert_eq!(card.change_positive, Some(true));
     }
+
+    #[test]
+    fn test_metric_card_history() {
+        let card = MetricCard::new("Signups", html! { "480" })
+            .history(vec![400.0, 380.0, 450.0, 480.0])
+            .delta_bar(true);
+        assert_eq!(card.trend_direction(), Some(TrendDirection::Up));
+        assert_eq!(card.history_delta(), Some(20.0));
+
+        let html = card.render().into_string();
+        assert!(html.contains("sh-metric-card--up"));
+        assert!(html.contains("sh-metric-card__chart"));
+        assert!(html.contains("var(--sh-success)"));
+        assert!(html.contains("Signups over the last 4 periods"));
+        assert!(html.contains("width: 20%;"));
+
+        // An explicit change wins over the history for the color
+        let html = MetricCard::new("Churn", html! { "3%" })
+            .history(vec![2.0, 3.0])
+            .change("+1pt", false)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-metric-card--down"));
+        assert!(html.contains("var(--sh-error)"));
+        assert!(!html.contains("sh-metric-card__delta"));
+    }
+
+    #[test]
+    fn test_stat_delta() {
+        let delta = StatDelta::new(1350.0, 1200.0)
+            .label("Revenue")
+            .prefix("$")
+            .period("last month");
+        assert_eq!(delta.percent_change(), Some(12.5));
+        assert_eq!(delta.direction(), TrendDirection::Up);
+        let html = delta.render().into_string();
+        assert!(html.contains("sh-stat-delta--up sh-stat-delta--good"));
+        assert!(html.contains(r#"<span class="sh-stat-delta__value">$1,350</span>"#));
+        assert!(html.contains(r#"<span class="sh-visually-hidden">Up </span>+12.5%"#));
+        assert!(html.contains("vs $1,200 last month"));
+
+        // A rise in churn is bad news
+        let churn = StatDelta::new(0.05, 0.04)
+            .format(NumberFormat {
+                decimals: 1,
+                separator: true,
+                percent: true,
+            })
+            .suffix("%")
+            .lower_is_better()
+            .percent_decimals(0);
+        assert_eq!(churn.change_text(), "+25%");
+        let html = churn.render().into_string();
+        assert!(html.contains("sh-stat-delta--bad"));
+        assert!(html.contains("vs 4.0%"));
+
+        let flat = StatDelta::new(100.4, 100.0).neutral_within(1.0);
+        assert_eq!(flat.direction(), TrendDirection::Neutral);
+        assert!(flat
+            .render()
+            .into_string()
+            .contains("sh-stat-delta--neutral sh-stat-delta--neutral"));
+
+        // From zero there is no percentage, so the difference is shown
+        let from_zero = StatDelta::new(40.0, 0.0).suffix(" users");
+        assert_eq!(from_zero.percent_change(), None);
+        assert_eq!(from_zero.change_text(), "+40 users");
+        assert_eq!(StatDelta::new(0.0, 8.0).change_text(), "-100.0%");
+        assert_eq!(StatDelta::new(-2.0, 0.0).change_text(), "-2");
+    }
+
+    #[test]
+    fn test_comparison_bar() {
+        let html = ComparisonBar::new(75.0, 100.0)
+            .title("Orders")
+            .labels("This week", "Last week")
+            .render()
+            .into_string();
+        assert!(html.contains("sh-comparison-bar sh-comparison-bar--bad"));
+        assert!(html.contains(r#"sh-comparison-bar__change--bad">-25.0%"#));
+        assert!(html.contains(r#"sh-comparison-bar__fill--previous" style="width: 100.0%;""#));
+        assert!(html.contains(r#"sh-comparison-bar__fill--current" style="width: 75.0%;""#));
+        assert!(html.contains("<dt>This week</dt><dd>75</dd>"));
+        assert!(html.contains("<dt>Last week</dt><dd>100</dd>"));
+
+        let target = ComparisonBar::new(30.0, 40.0)
+            .max(50.0)
+            .marker(true)
+            .semantics(DeltaSemantics::Neutral)
+            .render()
+            .into_string();
+        assert!(target.contains("sh-comparison-bar--neutral"));
+        assert!(target.contains(r#"sh-comparison-bar__marker" style="left: 80.0%;""#));
+        assert!(target.contains("width: 60.0%;"));
+        assert!(!target.contains("sh-comparison-bar__fill--previous"));
+        assert!(!target.contains("sh-comparison-bar__header"));
+    }
+
+    #[test]
+    fn test_metric_card_neutral_and_short_history() {
+        let card = MetricCard::new("Uptime", html! { "99.9%" })
+            .trend(TrendDirection::Neutral, "0%")
+            .history(vec![99.9]);
+        assert_eq!(card.trend_direction(), Some(TrendDirection::Neutral));
+        assert_eq!(card.history_delta(), None);
+
+        let html = card.render().into_string();
+        assert!(html.contains("sh-metric-card__change--neutral"));
+        assert!(!html.contains("sh-metric-card__chart"));
+        assert!(MetricCard::new("Empty", html! {})
+            .trend_direction()
+            .is_none());
+    }
 }
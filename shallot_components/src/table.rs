@@ -3,6 +3,7 @@
 //! Data tables with sorting, selection, and responsive design.
 
 use crate::component::ComponentSize;
+use crate::skeleton::Skeleton;
 use maud::{html, Markup, Render};
 
 pub struct TableColumn<'a> {
@@ -177,38 +178,7 @@ impl<'a> Render for Table<'a> {
                         caption class="sh-table__caption" { (caption) }
                     }
 
-                    thead class="sh-table__head" {
-                        tr {
-                            @for col in &self.columns {
-                                @let header_class = self.build_header_class(col);
-                                th
-                                    class=(header_class)
-                                    data-key=(col.key)
-                                    data-sortable=[if col.sortable { Some("true") } else { None }]
-                                    style=[col.width.map(|w| format!("width: {}", w))]
-                                    scope="col"
-                                    aria-sort=[if self.sort_key == Some(col.key) {
-                                        Some(match self.sort_dir {
-                                            Some(SortDir::Asc) => "ascending",
-                                            Some(SortDir::Desc) => "descending",
-                                            None => "none",
-                                        })
-                                    } else { None }]
-                                {
-                                    (col.label)
-                                    @if self.sort_key == Some(col.key) {
-                                        span class="sh-table__sort-icon" aria-hidden="true" {
-                                            @match self.sort_dir {
-                                                Some(SortDir::Asc) => " \u{2191}",
-                                                Some(SortDir::Desc) => " \u{2193}",
-                                                None => "",
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    (self.render_head())
 
                     tbody class="sh-table__body" {
                         @for row in &self.rows {
@@ -232,6 +202,74 @@ impl<'a> Render for Table<'a> {
 }
 
 impl<'a> Table<'a> {
+    fn render_head(&self) -> Markup {
+        html! {
+            thead class="sh-table__head" {
+                tr {
+                    @for col in &self.columns {
+                        @let header_class = self.build_header_class(col);
+                        th
+                            class=(header_class)
+                            data-key=(col.key)
+                            data-sortable=[if col.sortable { Some("true") } else { None }]
+                            style=[col.width.map(|w| format!("width: {}", w))]
+                            scope="col"
+                            aria-sort=[if self.sort_key == Some(col.key) {
+                                Some(match self.sort_dir {
+                                    Some(SortDir::Asc) => "ascending",
+                                    Some(SortDir::Desc) => "descending",
+                                    None => "none",
+                                })
+                            } else { None }]
+                        {
+                            (col.label)
+                            @if self.sort_key == Some(col.key) {
+                                span class="sh-table__sort-icon" aria-hidden="true" {
+                                    @match self.sort_dir {
+                                        Some(SortDir::Asc) => " \u{2191}",
+                                        Some(SortDir::Desc) => " \u{2193}",
+                                        None => "",
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Render a loading placeholder with this table's header and `rows`
+    /// placeholder rows, keeping column widths and row heights stable
+    pub fn skeleton(&self, rows: usize) -> Markup {
+        let class = self.build_classes();
+        let label = self.caption.unwrap_or("Data table");
+
+        html! {
+            div class="sh-table-wrapper" role="region" aria-label=(label) aria-busy="true" {
+                table class=(class) role="table" aria-label=(label) {
+                    @if let Some(caption) = self.caption {
+                        caption class="sh-table__caption" { (caption) }
+                    }
+
+                    (self.render_head())
+
+                    tbody class="sh-table__body" aria-hidden="true" {
+                        @for _ in 0..rows {
+                            tr class="sh-table__row" {
+                                @for _ in &self.columns {
+                                    td class="sh-table__cell" {
+                                        (Skeleton::inline("80%").render())
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn build_header_class(&self, col: &TableColumn<'a>) -> String {
         let mut cls = vec![
             "sh-table__header".to_string(),
@@ -430,6 +468,32 @@ pub fn table_css() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_table_skeleton() {
+        let table = Table::new(vec![
+            TableColumn {
+                key: "name",
+                label: "Name",
+                sortable: false,
+                width: Some("40%"),
+                align: ColumnAlign::Left,
+            },
+            TableColumn {
+                key: "email",
+                label: "Email",
+                sortable: false,
+                width: None,
+                align: ColumnAlign::Left,
+            },
+        ]);
+        let html = table.skeleton(3).into_string();
+        assert!(html.contains("aria-busy=\"true\""));
+        assert!(html.contains("Name"));
+        assert!(html.contains("width: 40%"));
+        assert_eq!(html.matches("<tr class=\"sh-table__row\">").count(), 3);
+        assert_eq!(html.matches("sh-skeleton--inline").count(), 6);
+    }
+
     #[test]
     fn test_table_creation() {
         let columns = vec![
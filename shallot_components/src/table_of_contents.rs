@@ -1,23 +1,29 @@
 //! TableOfContents Component - Auto-generated TOC from Headings
 //!
-//! Displays a table of contents based on page headings.
-//! Uses CSS scroll-linked highlighting for active section.
+//! Displays a table of contents based on page headings, either supplied as
+//! a flat list (nested automatically by level) or scraped from rendered
+//! markup. The current section is highlighted through `:target` rules
+//! generated per instance, and through `:target-current` scroll-spy where
+//! `scroll-target-group` is supported.
 
-use maud::{html, Markup, Render};
+use maud::{html, Markup, PreEscaped, Render};
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::OnceLock;
 
 /// TOC Item representing a heading
 pub struct TocItem<'a> {
-    pub label: &'a str,
-    pub href: &'a str,
+    pub label: Cow<'a, str>,
+    pub href: Cow<'a, str>,
     pub level: u8,
     pub children: Vec<TocItem<'a>>,
 }
 
 impl<'a> TocItem<'a> {
-    pub fn new(label: &'a str, href: &'a str, level: u8) -> Self {
+    pub fn new(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>, level: u8) -> Self {
         Self {
-            label,
-            href,
+            label: label.into(),
+            href: href.into(),
             level,
             children: Vec::new(),
         }
@@ -27,6 +33,77 @@ impl<'a> TocItem<'a> {
         self.children = children;
         self
     }
+
+    /// Fragment id this item links to, if the href is a same-page anchor
+    fn fragment(&self) -> Option<&str> {
+        self.href.strip_prefix('#').filter(|id| !id.is_empty())
+    }
+}
+
+/// TOC layout variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TocVariant {
+    /// Bordered card that sticks within its container
+    #[default]
+    Card,
+    /// Borderless rail pinned beside the content, offset by `--sh-toc-top`
+    Sidebar,
+}
+
+/// Nest a flat, document-ordered heading list by level
+fn nest_items(items: Vec<TocItem<'_>>) -> Vec<TocItem<'_>> {
+    fn insert<'a>(siblings: &mut Vec<TocItem<'a>>, item: TocItem<'a>) {
+        match siblings.last_mut() {
+            Some(last) if item.level > last.level => insert(&mut last.children, item),
+            _ => siblings.push(item),
+        }
+    }
+
+    let mut roots = Vec::new();
+    for item in items {
+        insert(&mut roots, item);
+    }
+    roots
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Extract `(level, id, text)` for every heading carrying an `id`
+fn scrape_headings(html: &str) -> Vec<TocItem<'static>> {
+    static HEADING: OnceLock<Regex> = OnceLock::new();
+    static ID_ATTR: OnceLock<Regex> = OnceLock::new();
+    static TAGS: OnceLock<Regex> = OnceLock::new();
+    let heading = HEADING
+        .get_or_init(|| Regex::new(r"(?s)<h([1-6])\b([^>]*)>(.*?)</h[1-6]>").expect("valid regex"));
+    let id_attr = ID_ATTR.get_or_init(|| Regex::new(r#"\bid="([^"]+)""#).expect("valid regex"));
+    let tags = TAGS.get_or_init(|| Regex::new(r"<[^>]*>").expect("valid regex"));
+
+    heading
+        .captures_iter(html)
+        .filter_map(|caps| {
+            let level = caps[1].parse().ok()?;
+            let id = id_attr.captures(&caps[2])?[1].to_string();
+            let text = decode_entities(tags.replace_all(&caps[3], "").trim());
+            Some(TocItem::new(
+                text,
+                format!("#{}", decode_entities(&id)),
+                level,
+            ))
+        })
+        .collect()
+}
+
+/// Whether an id can be embedded in a generated attribute selector
+fn is_selector_safe(id: &str) -> bool {
+    !id.chars()
+        .any(|c| matches!(c, '"' | '\\' | '<' | '>') || c.is_control())
 }
 
 /// TableOfContents component
@@ -35,6 +112,9 @@ pub struct TableOfContents<'a> {
     title: Option<&'a str>,
     max_depth: Option<u8>,
     highlight: bool,
+    numbered: bool,
+    variant: TocVariant,
+    id: &'a str,
     class: Option<&'a str>,
 }
 
@@ -46,10 +126,23 @@ impl<'a> TableOfContents<'a> {
             title: Some("On this page"),
             max_depth: Some(3),
             highlight: true,
+            numbered: false,
+            variant: TocVariant::Card,
+            id: "sh-toc",
             class: None,
         }
     }
 
+    /// Build from a flat heading list in document order, nesting by level
+    pub fn from_headings(headings: Vec<TocItem<'a>>) -> Self {
+        Self::new(nest_items(headings))
+    }
+
+    /// Build from rendered content, using every `h1`-`h6` that has an `id`
+    pub fn from_markup(markup: &Markup) -> Self {
+        Self::from_headings(scrape_headings(&markup.0))
+    }
+
     /// Set the title
     pub fn title(mut self, title: &'a str) -> Self {
         self.title = Some(title);
@@ -74,6 +167,24 @@ impl<'a> TableOfContents<'a> {
         self
     }
 
+    /// Number sections (1, 1.1, 1.2, ...) with CSS counters
+    pub fn numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    /// Set the layout variant
+    pub fn variant(mut self, variant: TocVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the nav id that scopes the generated highlight rules
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = id;
+        self
+    }
+
     /// Add custom class
     pub fn class(mut self, class: &'a str) -> Self {
         self.class = Some(class);
@@ -82,6 +193,12 @@ impl<'a> TableOfContents<'a> {
 
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-toc".to_string()];
+        if self.variant == TocVariant::Sidebar {
+            classes.push("sh-toc--sidebar".to_string());
+        }
+        if self.numbered {
+            classes.push("sh-toc--numbered".to_string());
+        }
         if !self.highlight {
             classes.push("sh-toc--no-highlight".to_string());
         }
@@ -91,8 +208,57 @@ impl<'a> TableOfContents<'a> {
         classes.join(" ")
     }
 
+    fn shows_children(&self, depth: u8) -> bool {
+        self.max_depth.is_none_or(|max| depth < max)
+    }
+
+    fn collect_fragments<'s>(
+        &'s self,
+        items: &'s [TocItem<'a>],
+        depth: u8,
+        out: &mut Vec<&'s str>,
+    ) {
+        for item in items {
+            if let Some(id) = item.fragment().filter(|id| is_selector_safe(id)) {
+                out.push(id);
+            }
+            if self.shows_children(depth) {
+                self.collect_fragments(&item.children, depth + 1, out);
+            }
+        }
+    }
+
+    /// `:target` rules highlighting the link whose section is targeted
+    pub fn highlight_css(&self) -> String {
+        let mut ids = Vec::new();
+        self.collect_fragments(&self.items, 1, &mut ids);
+        if ids.is_empty() || !is_selector_safe(self.id) {
+            return String::new();
+        }
+
+        let links: Vec<String> = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    ":root:has([id=\"{id}\"]:target) [id=\"{toc}\"] .sh-toc__link[href=\"#{id}\"]",
+                    toc = self.id
+                )
+            })
+            .collect();
+        let targets: Vec<String> = ids
+            .iter()
+            .map(|id| format!("[id=\"{}\"]:target", id))
+            .collect();
+
+        format!(
+            "{} {{ color: var(--sh-primary, #3b82f6); font-weight: 500; background: var(--sh-primary-bg, rgba(59, 130, 246, 0.1)); }}\n{} {{ scroll-margin-top: var(--sh-toc-top, 1rem); animation: sh-toc-target 1.2s ease; }}",
+            links.join(",\n"),
+            targets.join(",\n")
+        )
+    }
+
     fn render_item(&self, item: &TocItem<'a>, depth: u8) -> Markup {
-        let show_children = self.max_depth.map_or(true, |max| depth < max);
+        let show_children = self.shows_children(depth);
         let indent_class = match item.level {
             1 => "sh-toc__link--h1",
             2 => "sh-toc__link--h2",
@@ -124,13 +290,22 @@ impl<'a> TableOfContents<'a> {
 impl<'a> Render for TableOfContents<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
+        let highlight_css = if self.highlight {
+            self.highlight_css()
+        } else {
+            String::new()
+        };
 
         html! {
             nav
                 class=(classes)
+                id=(self.id)
                 aria-label=(self.title.unwrap_or("Table of contents"))
                 role="navigation"
             {
+                @if !highlight_css.is_empty() {
+                    style { (PreEscaped(highlight_css)) }
+                }
                 @if let Some(title) = self.title {
                     h2 class="sh-toc__title" {
                         (title)
@@ -196,13 +371,26 @@ pub fn table_of_contents_css() -> String {
     background: var(--sh-surface-2, #f3f4f6);
 }
 
-/* Active state via CSS scroll-driven or :target */
-.sh-toc__link:has(:target) {
+/* Active state: scroll-spy where supported; :target rules are generated per instance */
+.sh-toc {
+    scroll-target-group: auto;
+}
+
+.sh-toc__link:target-current {
     color: var(--sh-primary, #3b82f6);
     font-weight: 500;
     background: var(--sh-primary-bg, rgba(59, 130, 246, 0.1));
 }
 
+@keyframes sh-toc-target {
+    from {
+        background: var(--sh-primary-bg, rgba(59, 130, 246, 0.15));
+    }
+    to {
+        background: transparent;
+    }
+}
+
 /* Indentation by heading level */
 .sh-toc__link--h1 {
     font-weight: 500;
@@ -231,9 +419,52 @@ pub fn table_of_contents_css() -> String {
 }
 
 /* No highlight mode */
-.sh-toc--no-highlight .sh-toc__link:has(:target) {
+.sh-toc--no-highlight {
+    scroll-target-group: none;
+}
+
+/* Numbered sections */
+.sh-toc--numbered .sh-toc__list,
+.sh-toc--numbered .sh-toc__sub {
+    counter-reset: sh-toc-section;
+}
+
+.sh-toc--numbered .sh-toc__item {
+    counter-increment: sh-toc-section;
+}
+
+.sh-toc--numbered .sh-toc__link::before {
+    content: counters(sh-toc-section, ".") ". ";
+    font-variant-numeric: tabular-nums;
+    color: var(--sh-text-muted, #9ca3af);
+}
+
+/* Sidebar variant: borderless sticky rail */
+.sh-toc--sidebar {
+    top: var(--sh-toc-top, 1rem);
+    max-height: calc(100vh - var(--sh-toc-top, 1rem) - 1rem);
+    padding: 0 0 0 1rem;
+    background: transparent;
+    border: 0;
+    border-left: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: 0;
+}
+
+.sh-toc--sidebar .sh-toc__link {
+    margin-left: calc(-1rem - 1px);
+    border-left: 2px solid transparent;
+    border-radius: 0;
+}
+
+.sh-toc--sidebar .sh-toc__link:target-current {
+    border-left-color: var(--sh-primary, #3b82f6);
     background: transparent;
-    font-weight: normal;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .sh-toc__link {
+        transition: none;
+    }
 }
 
 /* Scrollbar styling */
@@ -260,6 +491,7 @@ pub fn table_of_contents_css() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use maud::html;
 
     #[test]
     fn test_toc_creation() {
@@ -290,10 +522,87 @@ mod tests {
         assert_eq!(toc.max_depth, Some(2));
     }
 
+    #[test]
+    fn test_toc_from_headings_nests_by_level() {
+        let toc = TableOfContents::from_headings(vec![
+            TocItem::new("Intro", "#intro", 2),
+            TocItem::new("Setup", "#setup", 3),
+            TocItem::new("Deep", "#deep", 4),
+            TocItem::new("Usage", "#usage", 2),
+            TocItem::new("Api", "#api", 3),
+        ]);
+
+        assert_eq!(toc.items.len(), 2);
+        assert_eq!(toc.items[0].children.len(), 1);
+        assert_eq!(toc.items[0].children[0].children[0].label, "Deep");
+        assert_eq!(toc.items[1].children[0].href, "#api");
+    }
+
+    #[test]
+    fn test_toc_from_markup() {
+        let content = html! {
+            h1 id="guide" { "Guide" }
+            p { "Text" }
+            h2 id="install" class="title" { "Install " code { "shallot" } }
+            h2 { "No id" }
+            h3 id="q-and-a" { "Q & A" }
+        };
+        let toc = TableOfContents::from_markup(&content);
+
+        assert_eq!(toc.items.len(), 1);
+        let install = &toc.items[0].children[0];
+        assert_eq!(install.label, "Install shallot");
+        assert_eq!(install.children[0].label, "Q & A");
+        assert_eq!(install.children[0].href, "#q-and-a");
+    }
+
+    #[test]
+    fn test_toc_highlight_css() {
+        let toc = TableOfContents::new(vec![TocItem::new("Intro", "#intro", 2)
+            .children(vec![TocItem::new("External", "/other", 3)])])
+        .id("docs-toc");
+        let css = toc.highlight_css();
+
+        assert!(css.contains(
+            ":root:has([id=\"intro\"]:target) [id=\"docs-toc\"] .sh-toc__link[href=\"#intro\"]"
+        ));
+        assert!(css.contains("[id=\"intro\"]:target { scroll-margin-top"));
+        assert!(!css.contains("other"));
+    }
+
+    #[test]
+    fn test_toc_highlight_skips_unsafe_ids() {
+        let toc = TableOfContents::new(vec![TocItem::new("Bad", "#a\"b", 2)]);
+        assert!(toc.highlight_css().is_empty());
+        assert!(!toc.render().into_string().contains("<style>"));
+    }
+
+    #[test]
+    fn test_toc_render_variants() {
+        let html_str = TableOfContents::new(vec![TocItem::new("Intro", "#intro", 2)])
+            .numbered(true)
+            .variant(TocVariant::Sidebar)
+            .render()
+            .into_string();
+
+        assert!(html_str.contains("class=\"sh-toc sh-toc--sidebar sh-toc--numbered\""));
+        assert!(html_str.contains("id=\"sh-toc\""));
+        assert!(html_str.contains("<style>"));
+
+        let plain = TableOfContents::new(vec![TocItem::new("Intro", "#intro", 2)])
+            .highlight(false)
+            .render()
+            .into_string();
+        assert!(!plain.contains("<style>"));
+    }
+
     #[test]
     fn test_toc_css() {
         let css = table_of_contents_css();
         assert!(css.contains(".sh-toc"));
         assert!(css.contains(".sh-toc__list"));
+        assert!(css.contains("counters(sh-toc-section, \".\")"));
+        assert!(css.contains(".sh-toc--sidebar"));
+        assert!(css.contains(".sh-toc__link:target-current"));
     }
 }
@@ -1,7 +1,23 @@
 //! Tabs Component with animated transitions
-
+//!
+//! Two markup strategies are available. [`TabStrategy::Button`] renders
+//! `<button role="tab">` triggers and relies on the server (or a script) to
+//! move the active panel. [`TabStrategy::Target`] renders anchor triggers
+//! pointing at panel ids so the URL fragment drives the open panel with
+//! `:target`: `/settings#tab-billing` opens the `billing` tab of
+//! `Tabs::new("tab", ..)` with no JavaScript. Without a fragment the
+//! server-selected tab (see [`Tabs::active_tab`] and [`Tabs::route`]) stays
+//! open.
+
+use crate::route::RouteContext;
 use maud::{html, Markup, Render};
 
+/// Number of tabs whose trigger highlight follows `:target` in CSS.
+///
+/// Panels beyond this index still open from a fragment; only the trigger
+/// styling falls back to the server-selected tab.
+pub const MAX_TARGET_TABS: usize = 12;
+
 /// Tab animation style
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TabAnimation {
@@ -31,6 +47,16 @@ pub enum TabSize {
     Lg,
 }
 
+/// How tab selection is expressed in markup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabStrategy {
+    /// Button triggers; only the server-selected panel is shown
+    #[default]
+    Button,
+    /// Anchor triggers with `:target` panels, so tabs can be deep-linked
+    Target,
+}
+
 /// Single tab item
 pub struct Tab<'a> {
     pub id: &'a str,
@@ -70,6 +96,7 @@ pub struct Tabs<'a> {
     pub animation: TabAnimation,
     pub variant: TabVariant,
     pub size: TabSize,
+    pub strategy: TabStrategy,
 }
 
 impl<'a> Tabs<'a> {
@@ -81,6 +108,7 @@ impl<'a> Tabs<'a> {
             animation: TabAnimation::default(),
             variant: TabVariant::default(),
             size: TabSize::default(),
+            strategy: TabStrategy::default(),
         }
     }
 
@@ -104,6 +132,40 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    pub fn strategy(mut self, strategy: TabStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Select the tab with the given id, e.g. from a query parameter or
+    /// session state. Unknown ids leave the current selection untouched.
+    pub fn active_tab(mut self, id: &str) -> Self {
+        if let Some(index) = self.tabs.iter().position(|tab| tab.id == id) {
+            self.selected = index;
+        }
+        self
+    }
+
+    /// Select the tab named by the route parameter matching the tabs'
+    /// `name`: `?tab=billing` for `Tabs::new("tab", ..)`
+    pub fn route(self, route: &RouteContext) -> Self {
+        match route.param_value(self.name) {
+            Some(id) => self.active_tab(id),
+            None => self,
+        }
+    }
+
+    /// Element id of the panel for `tab_id`.
+    ///
+    /// With [`TabStrategy::Target`] this is `{name}-{tab_id}`, the fragment
+    /// to link to; otherwise it is `{name}-panel-{tab_id}`.
+    pub fn panel_id(&self, tab_id: &str) -> String {
+        match self.strategy {
+            TabStrategy::Button => format!("{}-panel-{}", self.name, tab_id),
+            TabStrategy::Target => format!("{}-{}", self.name, tab_id),
+        }
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-tabs"];
 
@@ -127,13 +189,30 @@ impl<'a> Tabs<'a> {
             TabAnimation::Scale => classes.push("sh-tabs--scale"),
         }
 
+        if self.strategy == TabStrategy::Target {
+            classes.push("sh-tabs--target");
+        }
+
         classes.join(" ")
     }
 }
 
+impl<'a> Tabs<'a> {
+    fn render_label(&self, tab: &Tab<'a>) -> Markup {
+        html! {
+            @if let Some(icon) = tab.icon {
+                span class="sh-tabs__icon" { (maud::PreEscaped(icon)) }
+            }
+            span class="sh-tabs__label" { (tab.label) }
+        }
+    }
+}
+
 impl<'a> Render for Tabs<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
+        // Target panels stay in the layout so `:target` can reveal them
+        let target = self.strategy == TabStrategy::Target;
 
         // Pre-compute tab classes
         let tab_classes: Vec<String> = self.tabs.iter().enumerate().map(|(idx, tab)| {
@@ -159,20 +238,34 @@ impl<'a> Render for Tabs<'a> {
             div class=(classes) data-tabs=(self.name) {
                 div class="sh-tabs__list" role="tablist" {
                     @for (idx, tab) in self.tabs.iter().enumerate() {
-                        button
-                            class=(tab_classes[idx])
-                            type="button"
-                            role="tab"
-                            id={(format!("{}-tab-{}", self.name, tab.id))}
-                            aria-selected=(aria_selected[idx])
-                            aria-controls={(format!("{}-panel-{}", self.name, tab.id))}
-                            disabled?[tab.disabled]
-                            data-tab-index=(idx)
-                        {
-                            @if let Some(icon) = tab.icon {
-                                span class="sh-tabs__icon" { (maud::PreEscaped(icon)) }
+                        @let tab_id = format!("{}-tab-{}", self.name, tab.id);
+                        @let panel_id = self.panel_id(tab.id);
+                        @if target {
+                            a
+                                class=(tab_classes[idx])
+                                href=[(!tab.disabled).then(|| format!("#{}", panel_id))]
+                                role="tab"
+                                id=(tab_id)
+                                aria-selected=(aria_selected[idx])
+                                aria-controls=(panel_id)
+                                aria-disabled=[tab.disabled.then_some("true")]
+                                data-tab-index=(idx)
+                            {
+                                (self.render_label(tab))
+                            }
+                        } @else {
+                            button
+                                class=(tab_classes[idx])
+                                type="button"
+                                role="tab"
+                                id=(tab_id)
+                                aria-selected=(aria_selected[idx])
+                                aria-controls=(panel_id)
+                                disabled?[tab.disabled]
+                                data-tab-index=(idx)
+                            {
+                                (self.render_label(tab))
                             }
-                            span class="sh-tabs__label" { (tab.label) }
                         }
                     }
 
@@ -184,9 +277,9 @@ impl<'a> Render for Tabs<'a> {
                         div
                             class=(panel_classes[idx])
                             role="tabpanel"
-                            id={(format!("{}-panel-{}", self.name, tab.id))}
+                            id=(self.panel_id(tab.id))
                             aria-labelledby={(format!("{}-tab-{}", self.name, tab.id))}
-                            hidden?[idx != self.selected]
+                            hidden?[!target && idx != self.selected]
                             data-panel-index=(idx)
                         {
                             (tab.content.clone())
@@ -229,6 +322,21 @@ impl<'a> AnimatedTabs<'a> {
         self
     }
 
+    pub fn strategy(mut self, strategy: TabStrategy) -> Self {
+        self.tabs = self.tabs.strategy(strategy);
+        self
+    }
+
+    pub fn active_tab(mut self, id: &str) -> Self {
+        self.tabs = self.tabs.active_tab(id);
+        self
+    }
+
+    pub fn route(mut self, route: &RouteContext) -> Self {
+        self.tabs = self.tabs.route(route);
+        self
+    }
+
     pub fn transition_duration(mut self, duration: u16) -> Self {
         self.transition_duration = duration;
         self
@@ -250,6 +358,104 @@ impl<'a> Render for AnimatedTabs<'a> {
 
 /// Generate CSS for tabs components
 pub fn tabs_css() -> String {
+    let mut css = base_tabs_css().to_string();
+    css.push_str(&target_tabs_css());
+    css
+}
+
+/// `:target` rules for [`TabStrategy::Target`]: a targeted panel replaces the
+/// server-selected one, and the matching trigger takes over its highlight.
+fn target_tabs_css() -> String {
+    let matched = |suffix: &str| {
+        (1..=MAX_TARGET_TABS)
+            .map(|n| {
+                format!(
+                    ".sh-tabs--target:has(> .sh-tabs__panels > .sh-tabs__panel:nth-child({n}):target) \
+                     > .sh-tabs__list > .sh-tabs__tab:nth-child({n}){suffix}"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n")
+    };
+    let variant = |class: &str, suffix: &str| {
+        matched(suffix).replace(".sh-tabs--target:", &format!(".{class}.sh-tabs--target:"))
+    };
+    let pills = variant("sh-tabs--pills", "");
+    let underline = variant("sh-tabs--underline", "::after");
+    let bordered = variant("sh-tabs--bordered", "");
+
+    format!(
+        r#"
+/* Target strategy: the fragment overrides the server-selected tab */
+.sh-tabs--target > .sh-tabs__list > .sh-tabs__tab {{
+    text-decoration: none;
+}}
+
+.sh-tabs--target > .sh-tabs__panels > .sh-tabs__panel {{
+    scroll-margin-top: 4rem;
+}}
+
+.sh-tabs--target > .sh-tabs__panels > .sh-tabs__panel:target {{
+    display: block;
+    opacity: 1;
+    transform: none;
+}}
+
+.sh-tabs--target:has(> .sh-tabs__panels > .sh-tabs__panel:target)
+    > .sh-tabs__panels > .sh-tabs__panel--active:not(:target) {{
+    display: none;
+}}
+
+.sh-tabs--target:has(> .sh-tabs__panels > .sh-tabs__panel:target)
+    > .sh-tabs__list > .sh-tabs__tab--active {{
+    color: var(--sh-text-muted);
+    background: transparent;
+    border-color: transparent;
+}}
+
+.sh-tabs--target:has(> .sh-tabs__panels > .sh-tabs__panel:target)
+    > .sh-tabs__list > .sh-tabs__tab--active::after {{
+    background: transparent;
+}}
+
+{default} {{
+    color: var(--sh-accent);
+}}
+
+{pills} {{
+    background: var(--sh-primary);
+    color: white;
+}}
+
+{underline} {{
+    background: var(--sh-accent);
+}}
+
+{bordered} {{
+    border-color: var(--sh-border);
+    background: var(--sh-surface);
+}}
+
+@media (forced-colors: active) {{
+    {default} {{
+        color: Highlight;
+    }}
+
+    {pills} {{
+        background: Highlight;
+        color: HighlightText;
+    }}
+
+    {underline} {{
+        background: Highlight;
+    }}
+}}
+"#,
+        default = matched(""),
+    )
+}
+
+fn base_tabs_css() -> &'static str {
     r#"
 /* Tabs Component Styles */
 .sh-tabs {
@@ -423,7 +629,29 @@ pub fn tabs_css() -> String {
         transition: none;
     }
 }
-"#.to_string()
+
+/* Forced colors: the active tab is marked by colour and background only */
+@media (forced-colors: active) {
+    .sh-tabs__tab--active {
+        color: Highlight;
+    }
+
+    .sh-tabs__tab:focus-visible {
+        outline: 2px solid Highlight;
+        outline-offset: -2px;
+    }
+
+    .sh-tabs--pills .sh-tabs__tab--active {
+        background: Highlight;
+        color: HighlightText;
+    }
+
+    .sh-tabs--underline .sh-tabs__tab--active::after,
+    .sh-tabs__indicator {
+        background: Highlight;
+    }
+}
+"#
 }
 
 #[cfg(test)]
@@ -486,4 +714,96 @@ mod tests {
 
         assert!(tab.disabled);
     }
+
+    fn billing_tabs<'a>() -> Tabs<'a> {
+        Tabs::new(
+            "tab",
+            vec![
+                Tab::new("profile", "Profile", html! { "Profile" }),
+                Tab::new("billing", "Billing", html! { "Billing" }),
+                Tab::new("legacy", "Legacy", html! { "Legacy" }).disabled(true),
+            ],
+        )
+        .strategy(TabStrategy::Target)
+    }
+
+    #[test]
+    fn test_target_tabs_link_to_panels() {
+        let html = billing_tabs().render().into_string();
+
+        assert!(html.contains("sh-tabs--target"));
+        assert!(html.contains(r##"href="#tab-billing""##));
+        assert!(html.contains(r#"id="tab-billing""#));
+        assert!(!html.contains("<button"));
+        // Panels are left to CSS so a fragment can reveal any of them
+        assert!(!html.contains("hidden"));
+    }
+
+    #[test]
+    fn test_target_tabs_server_fallback() {
+        let html = billing_tabs().active_tab("billing").render().into_string();
+
+        assert!(html.contains(
+            r##"class="sh-tabs__tab sh-tabs__tab--active" href="#tab-billing" role="tab""##
+        ));
+        assert!(html.contains(r#"id="tab-tab-billing" aria-selected="true""#));
+        assert!(html.contains(r#"class="sh-tabs__panel sh-tabs__panel--active" role="tabpanel" id="tab-billing""#));
+        assert_eq!(html.matches(r#"aria-selected="true""#).count(), 1);
+    }
+
+    #[test]
+    fn test_target_tabs_disabled_has_no_href() {
+        let html = billing_tabs().render().into_string();
+
+        assert!(!html.contains(r##"href="#tab-legacy""##));
+        assert!(html.contains(r#"aria-disabled="true""#));
+    }
+
+    #[test]
+    fn test_active_tab_unknown_id_keeps_selection() {
+        let tabs = billing_tabs().selected(1).active_tab("missing");
+        assert_eq!(tabs.selected, 1);
+    }
+
+    #[test]
+    fn test_tabs_route() {
+        let route = RouteContext::new("/settings?tab=billing");
+        assert_eq!(billing_tabs().route(&route).selected, 1);
+
+        let route = RouteContext::new("/settings?other=billing");
+        assert_eq!(billing_tabs().route(&route).selected, 0);
+    }
+
+    #[test]
+    fn test_panel_id_by_strategy() {
+        let tabs = Tabs::new("settings", vec![]);
+        assert_eq!(tabs.panel_id("billing"), "settings-panel-billing");
+        let tabs = tabs.strategy(TabStrategy::Target);
+        assert_eq!(tabs.panel_id("billing"), "settings-billing");
+    }
+
+    #[test]
+    fn test_button_strategy_unchanged() {
+        let tabs = vec![Tab::new("a", "A", html! {}), Tab::new("b", "B", html! {})];
+        let html = Tabs::new("t", tabs).render().into_string();
+
+        assert!(html.contains("<button"));
+        assert!(html.contains(r#"id="t-panel-b""#));
+        assert!(html.contains("hidden"));
+        assert!(!html.contains("sh-tabs--target"));
+    }
+
+    #[test]
+    fn test_tabs_css_target_rules() {
+        let css = tabs_css();
+        assert!(css.contains(".sh-tabs__panel:target"));
+        assert!(css.contains(&format!("nth-child({MAX_TARGET_TABS}):target")));
+        assert!(css.contains(".sh-tabs--pills.sh-tabs--target:has("));
+    }
+
+    #[test]
+    fn test_tabs_css_forced_colors() {
+        let css = tabs_css();
+        assert_eq!(shallot_testing::accessibility::forced_colors_issues(&css), vec![]);
+    }
 }
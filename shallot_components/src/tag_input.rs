@@ -1,14 +1,155 @@
 //! Tag Input Component
 //!
 //! Input for managing multiple tags/chips.
+//!
+//! Tags round-trip through the server: each current tag is a hidden input
+//! named after the field, the text box submits `{name}_new`, and every
+//! remove button submits `{name}_remove={tag}`. On each post rebuild the list
+//! with [`TagSet::from_form`], apply the action with [`TagSet::apply_form`]
+//! and render the input again with [`TagSet::tags`].
+//!
+//! ```ignore
+//! let mut set = TagSet::from_form(&form, "topics").max_tags(5).allowed_chars("-");
+//! let error = set.apply_form(&form, "topics").err().map(|e| e.to_string());
+//! let tags: Vec<&str> = set.tags().iter().map(String::as_str).collect();
+//! let mut input = TagInput::new("topics").tags(tags).max_tags(5);
+//! if let Some(error) = &error {
+//!     input = input.error(error);
+//! }
+//! ```
 
 use crate::component::ComponentSize;
+use crate::form::FormData;
+use crate::form_validation::{split_tags, ValidationError, ValidationRule};
+use crate::share_links::encode_uri_component;
 use maud::{html, Markup, Render};
 
+fn new_field(name: &str) -> String {
+    format!("{}_new", name)
+}
+
+fn remove_field(name: &str) -> String {
+    format!("{}_remove", name)
+}
+
+/// Trim a tag and collapse runs of whitespace
+fn normalize_tag(tag: &str) -> String {
+    tag.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Server-side list of tags for a [`TagInput`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagSet {
+    tags: Vec<String>,
+    rules: Vec<ValidationRule>,
+}
+
+impl TagSet {
+    pub fn new<I, T>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut set = Self::default();
+        for tag in tags {
+            let tag = normalize_tag(tag.as_ref());
+            if !tag.is_empty() && !set.contains(&tag) {
+                set.tags.push(tag);
+            }
+        }
+        set
+    }
+
+    /// The tags currently held by a submitted [`TagInput`] named `name`
+    pub fn from_form(data: &FormData, name: &str) -> Self {
+        Self::new(data.get_all(name))
+    }
+
+    /// Reject additions that would exceed `max` tags
+    pub fn max_tags(self, max: usize) -> Self {
+        self.rule(ValidationRule::MaxTags(max))
+    }
+
+    /// Allow letters, digits, spaces and `extra` in tags
+    pub fn allowed_chars(self, extra: &str) -> Self {
+        self.rule(ValidationRule::TagCharset(extra.to_string()))
+    }
+
+    /// Any rule checked on additions; it sees the comma-separated list
+    pub fn rule(mut self, rule: ValidationRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Case-insensitive membership
+    pub fn contains(&self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag))
+    }
+
+    /// Add the comma-separated tags in `raw`, skipping ones already present.
+    /// Nothing is added when the result would break a rule. Returns whether
+    /// the set changed.
+    pub fn add(&mut self, raw: &str) -> Result<bool, ValidationError> {
+        let mut next = self.clone();
+        for tag in split_tags(raw).map(normalize_tag) {
+            if !next.contains(&tag) {
+                next.tags.push(tag);
+            }
+        }
+        if next.tags.len() == self.tags.len() {
+            return Ok(false);
+        }
+        let joined = next.tags.join(",");
+        for rule in &self.rules {
+            rule.validate(&joined)?;
+        }
+        self.tags = next.tags;
+        Ok(true)
+    }
+
+    /// Remove a tag, ignoring case. Returns whether it was present.
+    pub fn remove(&mut self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        let before = self.tags.len();
+        self.tags.retain(|t| !t.eq_ignore_ascii_case(&tag));
+        self.tags.len() != before
+    }
+
+    /// Apply the action submitted by a [`TagInput`] named `name`: a remove
+    /// button if one was pressed, otherwise the text box contents
+    pub fn apply_form(&mut self, data: &FormData, name: &str) -> Result<bool, ValidationError> {
+        if let Some(tag) = data.get(&remove_field(name)) {
+            return Ok(self.remove(tag));
+        }
+        match data.get(&new_field(name)) {
+            Some(raw) => self.add(raw),
+            None => Ok(false),
+        }
+    }
+}
+
 pub struct TagInput<'a> {
     name: &'a str,
     tags: Vec<&'a str>,
+    label: Option<&'a str>,
     placeholder: Option<&'a str>,
+    suggestions: Vec<&'a str>,
+    remove_href: Option<&'a str>,
+    helper_text: Option<&'a str>,
+    error: Option<&'a str>,
     size: ComponentSize,
     disabled: bool,
     max_tags: Option<usize>,
@@ -19,7 +160,12 @@ impl<'a> TagInput<'a> {
         Self {
             name,
             tags: Vec::new(),
+            label: None,
             placeholder: None,
+            suggestions: Vec::new(),
+            remove_href: None,
+            helper_text: None,
+            error: None,
             size: ComponentSize::Md,
             disabled: false,
             max_tags: None,
@@ -31,11 +177,39 @@ impl<'a> TagInput<'a> {
         self
     }
 
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
     pub fn placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
     }
 
+    /// Offer these tags in a `<datalist>`; ones already added are left out
+    pub fn suggestions(mut self, suggestions: Vec<&'a str>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Render remove controls as links to `path?{name}_remove={tag}` instead
+    /// of submit buttons, for tag lists kept in the URL or the session
+    pub fn remove_href(mut self, path: &'a str) -> Self {
+        self.remove_href = Some(path);
+        self
+    }
+
+    pub fn helper_text(mut self, text: &'a str) -> Self {
+        self.helper_text = Some(text);
+        self
+    }
+
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
     pub fn size(mut self, size: ComponentSize) -> Self {
         self.size = size;
         self
@@ -50,46 +224,121 @@ impl<'a> TagInput<'a> {
         self.max_tags = Some(max);
         self
     }
+
+    fn remove_link(&self, path: &str, tag: &str) -> String {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        format!(
+            "{}{}{}={}",
+            path,
+            separator,
+            remove_field(self.name),
+            encode_uri_component(tag)
+        )
+    }
+
+    fn helper(&self) -> Option<String> {
+        match (self.helper_text, self.max_tags) {
+            (Some(text), _) => Some(text.to_string()),
+            (None, Some(max)) => Some(format!("Up to {} tags", max)),
+            (None, None) => None,
+        }
+    }
+
+    fn remove_icon() -> Markup {
+        html! {
+            svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" aria-hidden="true" {
+                line x1="18" y1="6" x2="6" y2="18";
+                line x1="6" y1="6" x2="18" y2="18";
+            }
+        }
+    }
 }
 
 impl<'a> Render for TagInput<'a> {
     fn render(&self) -> Markup {
         let input_id = format!("sh-tag-input-{}", self.name);
+        let list_id = format!("{}-suggestions", input_id);
+        let message_id = format!("{}-message", input_id);
         let size_class = format!("sh-tag-input--{}", self.size.class_suffix());
-        let can_add_more = self.max_tags.map_or(true, |max| self.tags.len() < max);
+        let can_add_more = self.max_tags.is_none_or(|max| self.tags.len() < max);
+        let suggestions: Vec<&str> = self
+            .suggestions
+            .iter()
+            .copied()
+            .filter(|s| !self.tags.iter().any(|t| t.eq_ignore_ascii_case(s)))
+            .collect();
+        let helper = self.helper();
+        let has_message = self.error.is_some() || helper.is_some();
 
         html! {
-            div class={(format!("sh-tag-input {} {}", size_class, if self.disabled { "sh-tag-input--disabled" } else { "" }))} {
-                @for (i, tag) in self.tags.iter().enumerate() {
-                    @let _tag_id = format!("{}-tag-{}", self.name, i);
+            div class={
+                "sh-tag-input-wrapper"
+                @if self.error.is_some() { " sh-tag-input-wrapper--error" }
+            } {
+                @if let Some(label) = self.label {
+                    label class="sh-tag-input__label" for=(input_id) { (label) }
+                }
 
-                    span class="sh-tag" {
+                div class={(format!("sh-tag-input {} {}", size_class, if self.disabled { "sh-tag-input--disabled" } else { "" }))} {
+                    // The text box and its add button come first so Enter
+                    // submits "add" rather than the first remove button
+                    @if can_add_more && !self.disabled {
                         input
-                            type="hidden"
-                            name={(format!("{}[]", self.name))}
-                            value=(tag);
-                        span class="sh-tag__text" { (tag) }
-                        @if !self.disabled {
-                            button
-                                type="button"
-                                class="sh-tag__remove"
-                                aria-label=(format!("Remove {}", tag))
-                            {
-                                svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" {
-                                    line x1="18" y1="6" x2="6" y2="18";
-                                    line x1="6" y1="6" x2="18" y2="18";
+                            type="text"
+                            class="sh-tag-input__field"
+                            id=(input_id)
+                            name=(new_field(self.name))
+                            list=[(!suggestions.is_empty()).then_some(list_id.as_str())]
+                            autocomplete="off"
+                            placeholder=(self.placeholder.unwrap_or("Add tag..."))
+                            aria-invalid=[self.error.map(|_| "true")]
+                            aria-describedby=[has_message.then_some(message_id.as_str())];
+                        button type="submit" class="sh-tag-input__add" formnovalidate {
+                            "Add"
+                        }
+                        @if !suggestions.is_empty() {
+                            datalist id=(list_id) {
+                                @for suggestion in &suggestions {
+                                    option value=(suggestion) {}
+                                }
+                            }
+                        }
+                    }
+
+                    @if !self.tags.is_empty() {
+                        ul class="sh-tag-input__tags" aria-label="Tags" {
+                            @for tag in &self.tags {
+                                li class="sh-tag" {
+                                    input type="hidden" name=(self.name) value=(tag);
+                                    span class="sh-tag__text" { (tag) }
+                                    @if !self.disabled {
+                                        @if let Some(path) = self.remove_href {
+                                            a class="sh-tag__remove" href=(self.remove_link(path, tag)) aria-label=(format!("Remove {}", tag)) {
+                                                (Self::remove_icon())
+                                            }
+                                        } @else {
+                                            button
+                                                type="submit"
+                                                class="sh-tag__remove"
+                                                name=(remove_field(self.name))
+                                                value=(tag)
+                                                formnovalidate
+                                                aria-label=(format!("Remove {}", tag))
+                                            {
+                                                (Self::remove_icon())
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
                     }
                 }
 
-                @if can_add_more && !self.disabled {
-                    input
-                        type="text"
-                        class="sh-tag-input__field"
-                        id=(input_id)
-                        placeholder=(self.placeholder.unwrap_or("Add tag..."));
+                @if let Some(error) = self.error {
+                    div class="sh-tag-input__error" id=(message_id) { (error) }
+                } @else if let Some(helper) = helper {
+                    div class="sh-tag-input__helper" id=(message_id) { (helper) }
                 }
             }
         }
@@ -208,6 +457,18 @@ impl<'a> Render for TagList<'a> {
 
 pub fn tag_input_css() -> String {
     r#"
+.sh-tag-input-wrapper {
+    display: flex;
+    flex-direction: column;
+    gap: 0.375rem;
+}
+
+.sh-tag-input__label {
+    font-size: 0.875rem;
+    font-weight: 500;
+    color: var(--sh-text, #1f2937);
+}
+
 .sh-tag-input {
     display: flex;
     flex-wrap: wrap;
@@ -246,6 +507,47 @@ pub fn tag_input_css() -> String {
     color: var(--sh-text-muted, #6b7280);
 }
 
+/* Tags sit before the text box visually while following it in the DOM */
+.sh-tag-input__tags {
+    display: contents;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-tag-input__tags .sh-tag {
+    order: -1;
+}
+
+.sh-tag-input__add {
+    padding: 0.25rem 0.625rem;
+    font-size: 0.75rem;
+    font-weight: 500;
+    color: var(--sh-accent, #3b82f6);
+    background: transparent;
+    border: 1px solid currentColor;
+    border-radius: var(--sh-radius-sm, 0.25rem);
+    cursor: pointer;
+}
+
+.sh-tag-input__add:hover {
+    background: color-mix(in srgb, var(--sh-accent, #3b82f6) 10%, transparent);
+}
+
+.sh-tag-input-wrapper--error .sh-tag-input {
+    border-color: var(--sh-error, #ef4444);
+}
+
+.sh-tag-input__error,
+.sh-tag-input__helper {
+    font-size: 0.75rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-tag-input__error {
+    color: var(--sh-error, #ef4444);
+}
+
 /* Individual tag */
 .sh-tag {
     display: inline-flex;
@@ -362,6 +664,82 @@ mod tests {
         assert_eq!(input.tags.len(), 2);
     }
 
+    #[test]
+    fn test_tag_input_render() {
+        let html = TagInput::new("topics")
+            .label("Topics")
+            .tags(vec!["rust", "web dev"])
+            .suggestions(vec!["Rust", "css", "html"])
+            .max_tags(5)
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<label class="sh-tag-input__label" for="sh-tag-input-topics">"#));
+        assert!(html.contains(r#"name="topics_new" list="sh-tag-input-topics-suggestions""#));
+        assert!(html.contains(r#"<option value="css"></option>"#));
+        assert!(!html.contains(r#"<option value="Rust">"#));
+        assert!(html.contains(r#"<input type="hidden" name="topics" value="web dev">"#));
+        assert!(html.contains(
+            r#"name="topics_remove" value="rust" formnovalidate aria-label="Remove rust""#
+        ));
+        assert!(html.contains("Up to 5 tags"));
+        // The add button precedes every remove button
+        assert!(html.find("sh-tag-input__add") < html.find("topics_remove"));
+    }
+
+    #[test]
+    fn test_tag_input_full_and_links() {
+        let html = TagInput::new("f")
+            .tags(vec!["a b", "c"])
+            .max_tags(2)
+            .remove_href("/search?q=x")
+            .error("Use at most 2 tags")
+            .render()
+            .into_string();
+        assert!(!html.contains("f_new"));
+        assert!(html.contains(r#"href="/search?q=x&amp;f_remove=a%20b""#));
+        assert!(html.contains(r#"<div class="sh-tag-input__error" id="sh-tag-input-f-message">"#));
+        assert!(html.contains("sh-tag-input-wrapper--error"));
+    }
+
+    #[test]
+    fn test_tag_set_add_remove() {
+        let mut set = TagSet::new(["rust", " Web   Dev ", "RUST"]).max_tags(3);
+        assert_eq!(set.tags(), ["rust", "Web Dev"]);
+        assert_eq!(set.add("web dev, css"), Ok(true));
+        assert_eq!(set.add("css"), Ok(false));
+        assert_eq!(set.add("html"), Err(ValidationError::TooManyTags(3)));
+        assert_eq!(set.len(), 3);
+        assert!(set.remove("CSS"));
+        assert!(!set.remove("css"));
+
+        let mut set = TagSet::default().allowed_chars("-");
+        assert!(set.add("c++").is_err());
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_tag_set_apply_form() {
+        let form = FormData::from_pairs([
+            ("topics", "rust"),
+            ("topics", "web"),
+            ("topics_new", "css, html"),
+        ]);
+        let mut set = TagSet::from_form(&form, "topics");
+        assert_eq!(set.apply_form(&form, "topics"), Ok(true));
+        assert_eq!(set.tags(), ["rust", "web", "css", "html"]);
+
+        // A remove button wins over text left in the box
+        let form = FormData::from_pairs([
+            ("topics", "rust"),
+            ("topics", "web"),
+            ("topics_new", "css"),
+            ("topics_remove", "web"),
+        ]);
+        let mut set = TagSet::from_form(&form, "topics");
+        assert_eq!(set.apply_form(&form, "topics"), Ok(true));
+        assert_eq!(set.tags(), ["rust"]);
+    }
+
     #[test]
     fn test_tag_creation() {
         let tag = Tag::new("Featured")
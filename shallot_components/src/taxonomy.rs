@@ -0,0 +1,615 @@
+//! Taxonomy pages - tag and category indexes, tag clouds and per-term feeds
+//!
+//! A [`Taxonomy`] groups content items by the terms they carry, such as tags
+//! or categories. [`SiteBuilder::taxonomy`] adds its pages to a site: an
+//! index at the taxonomy path with a [`TagCloud`], a paginated listing per
+//! term at `{path}/{slug}` and `{path}/{slug}/page/{n}`, and an RSS feed per
+//! term at `{path}/{slug}/rss.xml`. Listings and feeds are newest first.
+//!
+//! # Example
+//!
+//! ```
+//! use shallot_components::site::SiteBuilder;
+//! use shallot_components::taxonomy::{Taxonomy, TaxonomyItem};
+//!
+//! let posts = [
+//!     ("Hello, world", "/blog/hello-world", "2026-01-05", ["rust", "intro"]),
+//!     ("Second post", "/blog/second-post", "2026-02-10", ["rust", "web"]),
+//! ];
+//! let items: Vec<TaxonomyItem> = posts
+//!     .iter()
+//!     .map(|(title, url, date, tags)| {
+//!         tags.iter()
+//!             .fold(TaxonomyItem::new(*title, *url, *date), |item, tag| item.term(*tag))
+//!     })
+//!     .collect();
+//!
+//! let pages = SiteBuilder::new()
+//!     .base_url("https://example.com")
+//!     .taxonomy(&Taxonomy::new("Tags", "/tags").items(items))
+//!     .build()
+//!     .unwrap();
+//!
+//! let rust = pages.iter().find(|page| page.path == "/tags/rust").unwrap();
+//! assert!(rust.html.contains("Second post"));
+//! let feed = pages.iter().find(|page| page.path == "/tags/rust/rss.xml").unwrap();
+//! assert!(feed.html.contains("<link>https://example.com/blog/hello-world</link>"));
+//! ```
+
+use crate::badge::Badge;
+use crate::blog::{RelatedPost, RelatedPosts};
+use crate::component::{ComponentColor, ComponentSize};
+use crate::feed::{Feed, FeedItem};
+use crate::pagination::Pagination;
+use crate::site::{Head, SiteBuilder};
+use maud::{html, Markup, Render};
+
+/// A content item listed under the terms it carries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxonomyItem {
+    pub title: String,
+    pub url: String,
+    /// RFC 3339 date, e.g. `2026-02-24` or `2026-02-24T09:00:00Z`
+    pub published: String,
+    pub summary: Option<String>,
+    pub terms: Vec<String>,
+}
+
+impl TaxonomyItem {
+    pub fn new(
+        title: impl Into<String>,
+        url: impl Into<String>,
+        published: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            url: url.into(),
+            published: published.into(),
+            summary: None,
+            terms: Vec::new(),
+        }
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    pub fn term(mut self, term: impl Into<String>) -> Self {
+        self.terms.push(term.into());
+        self
+    }
+
+    pub fn terms(mut self, terms: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.terms.extend(terms.into_iter().map(Into::into));
+        self
+    }
+
+    fn has_term(&self, slug: &str) -> bool {
+        self.terms.iter().any(|term| slugify(term) == slug)
+    }
+}
+
+/// A term and the number of items carrying it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    /// Name as first written, e.g. `Web Components`
+    pub name: String,
+    /// Path segment, e.g. `web-components`
+    pub slug: String,
+    pub count: usize,
+}
+
+/// A set of items grouped by terms, e.g. tags or categories
+#[derive(Debug, Clone)]
+pub struct Taxonomy {
+    title: String,
+    path: String,
+    per_page: usize,
+    feeds: bool,
+    items: Vec<TaxonomyItem>,
+}
+
+impl Taxonomy {
+    /// A taxonomy titled `title` whose pages live under `path`, e.g.
+    /// `Taxonomy::new("Categories", "/categories")`
+    pub fn new(title: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            path: path.into().trim_end_matches('/').to_string(),
+            per_page: 10,
+            feeds: true,
+            items: Vec::new(),
+        }
+    }
+
+    /// Items per term page (default 10)
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+
+    /// Write an RSS feed per term (default on)
+    pub fn feeds(mut self, feeds: bool) -> Self {
+        self.feeds = feeds;
+        self
+    }
+
+    pub fn item(mut self, item: TaxonomyItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = TaxonomyItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Path of the taxonomy index, e.g. `/tags`
+    pub fn index_path(&self) -> &str {
+        if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        }
+    }
+
+    /// Path of the first page listing `term`
+    pub fn term_path(&self, term: &Term) -> String {
+        format!("{}/{}", self.path, term.slug)
+    }
+
+    /// Path of the RSS feed for `term`
+    pub fn feed_path(&self, term: &Term) -> String {
+        format!("{}/rss.xml", self.term_path(term))
+    }
+
+    /// Every term in use, sorted by slug; terms are matched by slug, so
+    /// `Web Components` and `web-components` are the same term
+    pub fn terms(&self) -> Vec<Term> {
+        let mut terms: Vec<Term> = Vec::new();
+        for item in &self.items {
+            let mut seen = Vec::new();
+            for name in &item.terms {
+                let slug = slugify(name);
+                if slug.is_empty() || seen.contains(&slug) {
+                    continue;
+                }
+                match terms.iter_mut().find(|term| term.slug == slug) {
+                    Some(term) => term.count += 1,
+                    None => terms.push(Term {
+                        name: name.trim().to_string(),
+                        slug: slug.clone(),
+                        count: 1,
+                    }),
+                }
+                seen.push(slug);
+            }
+        }
+        terms.sort_by(|a, b| a.slug.cmp(&b.slug));
+        terms
+    }
+
+    /// Items carrying `term`, newest first
+    pub fn items_for(&self, term: &Term) -> Vec<&TaxonomyItem> {
+        let mut items: Vec<&TaxonomyItem> = self
+            .items
+            .iter()
+            .filter(|item| item.has_term(&term.slug))
+            .collect();
+        items.sort_by(|a, b| b.published.cmp(&a.published));
+        items
+    }
+
+    /// Every listing page of every term
+    pub fn term_pages(&self) -> Vec<TermPage<'_>> {
+        let mut pages = Vec::new();
+        for term in self.terms() {
+            let items = self.items_for(&term);
+            let total_pages = items.len().div_ceil(self.per_page).max(1) as u32;
+            for (index, chunk) in items.chunks(self.per_page).enumerate() {
+                pages.push(TermPage {
+                    taxonomy: self,
+                    term: term.clone(),
+                    page: index as u32 + 1,
+                    total_pages,
+                    items: chunk.to_vec(),
+                });
+            }
+        }
+        pages
+    }
+
+    /// RSS-ready feed of every item carrying `term`; relative item URLs
+    /// are resolved against `base_url`
+    pub fn feed(&self, term: &Term, base_url: Option<&str>) -> Feed {
+        let absolute = |url: &str| match base_url {
+            Some(base) if url.starts_with('/') => format!("{}{}", base, url),
+            _ => url.to_string(),
+        };
+        let items = self.items_for(term).into_iter().map(|item| {
+            let mut feed_item = FeedItem::new(&item.title, absolute(&item.url), &item.published);
+            if let Some(summary) = &item.summary {
+                feed_item = feed_item.summary(summary);
+            }
+            item.terms
+                .iter()
+                .fold(feed_item, |feed_item, term| feed_item.category(term))
+        });
+        Feed::new(
+            format!("{}: {}", self.title, term.name),
+            absolute(&self.term_path(term)),
+            absolute(&self.feed_path(term)),
+        )
+        .description(format!("Items filed under {}", term.name))
+        .items(items)
+    }
+}
+
+/// One page of the listing for a term
+#[derive(Debug, Clone)]
+pub struct TermPage<'a> {
+    pub taxonomy: &'a Taxonomy,
+    pub term: Term,
+    /// 1-based page number
+    pub page: u32,
+    pub total_pages: u32,
+    pub items: Vec<&'a TaxonomyItem>,
+}
+
+impl<'a> TermPage<'a> {
+    /// `{path}/{slug}` for the first page, `{path}/{slug}/page/{n}` after
+    pub fn path(&self) -> String {
+        if self.page == 1 {
+            self.taxonomy.term_path(&self.term)
+        } else {
+            self.numbered_path(self.page)
+        }
+    }
+
+    /// `{path}/{slug}/page/{n}`; page 1 is also served here so
+    /// [`Pagination`] links resolve for every page
+    pub fn numbered_path(&self, page: u32) -> String {
+        format!("{}/page/{}", self.taxonomy.term_path(&self.term), page)
+    }
+
+    pub fn head(&self) -> Head {
+        let title = if self.page == 1 {
+            format!("{}: {}", self.taxonomy.title, self.term.name)
+        } else {
+            format!(
+                "{}: {} (page {} of {})",
+                self.taxonomy.title, self.term.name, self.page, self.total_pages
+            )
+        };
+        Head::new(title).description(format!(
+            "{} {} filed under {}",
+            self.term.count,
+            if self.term.count == 1 {
+                "item"
+            } else {
+                "items"
+            },
+            self.term.name
+        ))
+    }
+}
+
+impl<'a> Render for TermPage<'a> {
+    fn render(&self) -> Markup {
+        let posts = self
+            .items
+            .iter()
+            .map(|item| {
+                let date = item.published.split('T').next().unwrap_or_default();
+                let post = RelatedPost::new(item.title.as_str(), item.url.as_str()).date(date);
+                match &item.summary {
+                    Some(summary) => post.excerpt(summary.as_str()),
+                    None => post,
+                }
+            })
+            .collect();
+        let listing_title = if self.total_pages > 1 {
+            format!("Page {} of {}", self.page, self.total_pages)
+        } else {
+            format!(
+                "{} {}",
+                self.term.count,
+                if self.term.count == 1 {
+                    "item"
+                } else {
+                    "items"
+                }
+            )
+        };
+        let page_url = format!("{}/page/{{page}}", self.taxonomy.term_path(&self.term));
+
+        html! {
+            section class="sh-taxonomy" {
+                header class="sh-taxonomy__header" {
+                    a class="sh-taxonomy__parent" href=(self.taxonomy.index_path()) {
+                        (self.taxonomy.title)
+                    }
+                    h1 class="sh-taxonomy__title" { (self.term.name) }
+                    @if self.taxonomy.feeds {
+                        a
+                            class="sh-taxonomy__feed"
+                            href=(self.taxonomy.feed_path(&self.term))
+                            type="application/rss+xml" {
+                            "RSS feed"
+                        }
+                    }
+                }
+                (RelatedPosts::new(posts).title(listing_title).columns(2))
+                @if self.total_pages > 1 {
+                    (Pagination::new(self.page, self.total_pages, &page_url))
+                }
+            }
+        }
+    }
+}
+
+/// Terms as links sized by how many items carry them
+pub struct TagCloud<'a> {
+    taxonomy: &'a Taxonomy,
+    terms: Vec<Term>,
+    color: ComponentColor,
+}
+
+impl<'a> TagCloud<'a> {
+    pub fn new(taxonomy: &'a Taxonomy) -> Self {
+        Self {
+            taxonomy,
+            terms: taxonomy.terms(),
+            color: ComponentColor::Primary,
+        }
+    }
+
+    pub fn color(mut self, color: ComponentColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Badge size for `count`, spread linearly from `Xs` for the rarest
+    /// term to `Xl` for the most common; all `Md` when counts are equal
+    fn size(&self, count: usize) -> ComponentSize {
+        const SIZES: [ComponentSize; 5] = [
+            ComponentSize::Xs,
+            ComponentSize::Sm,
+            ComponentSize::Md,
+            ComponentSize::Lg,
+            ComponentSize::Xl,
+        ];
+        let min = self.terms.iter().map(|t| t.count).min().unwrap_or(0);
+        let max = self.terms.iter().map(|t| t.count).max().unwrap_or(0);
+        if max == min {
+            return ComponentSize::Md;
+        }
+        let step = ((count - min) * (SIZES.len() - 1) + (max - min) / 2) / (max - min);
+        SIZES[step]
+    }
+}
+
+impl<'a> Render for TagCloud<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            ul class="sh-tag-cloud" aria-label=(self.taxonomy.title) {
+                @for term in &self.terms {
+                    li class="sh-tag-cloud__term" {
+                        a
+                            class="sh-tag-cloud__link"
+                            href=(self.taxonomy.term_path(term))
+                            aria-label=(format!(
+                                "{}, {} {}",
+                                term.name,
+                                term.count,
+                                if term.count == 1 { "item" } else { "items" }
+                            )) {
+                            (Badge::new(term.name.as_str())
+                                .size(self.size(term.count))
+                                .color(self.color))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> SiteBuilder<'a> {
+    /// Add the index, term listing pages and term feeds of `taxonomy`;
+    /// call after [`SiteBuilder::base_url`] so feeds link absolutely
+    pub fn taxonomy(mut self, taxonomy: &Taxonomy) -> Self {
+        let index = html! {
+            section class="sh-taxonomy" {
+                h1 class="sh-taxonomy__title" { (taxonomy.title) }
+                (TagCloud::new(taxonomy))
+            }
+        };
+        self = self.page(
+            taxonomy.index_path(),
+            Head::new(taxonomy.title.as_str()),
+            |_| index,
+        );
+
+        for page in taxonomy.term_pages() {
+            let content = page.render();
+            if page.page == 1 {
+                let mut head = page.head();
+                if let Some(base) = &self.base_url {
+                    head = head.canonical(format!("{}{}", base, page.path()));
+                }
+                let duplicate = content.clone();
+                self = self.page(&page.numbered_path(1), head, |_| duplicate);
+            }
+            self = self.page(&page.path(), page.head(), |_| content);
+        }
+
+        if taxonomy.feeds {
+            for term in taxonomy.terms() {
+                let feed = taxonomy.feed(&term, self.base_url.as_deref()).to_rss();
+                self = self.file(&taxonomy.feed_path(&term), feed);
+            }
+        }
+        self
+    }
+}
+
+/// Lowercase alphanumerics with runs of anything else collapsed to `-`
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Generate CSS for taxonomy pages and tag clouds
+pub fn taxonomy_css() -> String {
+    r#"
+.sh-taxonomy {
+    display: flex;
+    flex-direction: column;
+    gap: 1.5rem;
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    color: var(--sh-text, #1f2937);
+}
+
+.sh-taxonomy__header {
+    display: flex;
+    flex-direction: column;
+    gap: 0.25rem;
+}
+
+.sh-taxonomy__parent,
+.sh-taxonomy__feed {
+    font-size: 0.875rem;
+    color: var(--sh-text-muted, #6b7280);
+}
+
+.sh-taxonomy__title {
+    margin: 0;
+    font-size: 2rem;
+    line-height: 1.2;
+}
+
+.sh-tag-cloud {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sh-tag-cloud__link {
+    text-decoration: none;
+}
+
+.sh-tag-cloud__link:focus-visible {
+    outline: 2px solid var(--sh-primary, #3b82f6);
+    outline-offset: 2px;
+    border-radius: 9999px;
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxonomy() -> Taxonomy {
+        Taxonomy::new("Tags", "/tags").items([
+            TaxonomyItem::new("One", "/blog/one", "2026-01-01").terms(["Rust", "Web Components"]),
+            TaxonomyItem::new("Two", "/blog/two", "2026-02-01T09:00:00Z")
+                .summary("Second")
+                .terms(["rust", "rust"]),
+            TaxonomyItem::new("Three", "/blog/three", "2026-03-01").terms(["RUST", "wasm"]),
+        ])
+    }
+
+    #[test]
+    fn test_terms_group_by_slug() {
+        let terms = taxonomy().terms();
+        let slugs: Vec<_> = terms.iter().map(|t| (t.slug.as_str(), t.count)).collect();
+        assert_eq!(slugs, [("rust", 3), ("wasm", 1), ("web-components", 1)]);
+        assert_eq!(terms[0].name, "Rust");
+        assert_eq!(terms[2].name, "Web Components");
+        assert_eq!(slugify("  C++ / Café "), "c-café");
+    }
+
+    #[test]
+    fn test_term_pages_paginate_newest_first() {
+        let taxonomy = taxonomy().per_page(2);
+        let pages = taxonomy.term_pages();
+        let paths: Vec<_> = pages.iter().map(TermPage::path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/tags/rust",
+                "/tags/rust/page/2",
+                "/tags/wasm",
+                "/tags/web-components"
+            ]
+        );
+        let titles: Vec<_> = pages[0].items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, ["Three", "Two"]);
+
+        let html = pages[1].render().into_string();
+        assert!(html.contains("Page 2 of 2"));
+        assert!(html.contains(r#"href="/tags/rust/page/1""#));
+        assert!(html.contains(r#"href="/tags/rust/rss.xml""#));
+        assert!(!pages[2].render().into_string().contains("sh-pagination"));
+    }
+
+    #[test]
+    fn test_tag_cloud_weights() {
+        let html = TagCloud::new(&taxonomy()).render().into_string();
+        assert!(html.contains(r#"href="/tags/rust" aria-label="Rust, 3 items""#));
+        assert!(html.contains("sh-badge--xl"));
+        assert!(html.contains("sh-badge--xs"));
+
+        let even = Taxonomy::new("Tags", "/tags")
+            .item(TaxonomyItem::new("One", "/one", "2026-01-01").terms(["a", "b"]));
+        let html = TagCloud::new(&even).render().into_string();
+        assert_eq!(html.matches("sh-badge--md").count(), 2);
+    }
+
+    #[test]
+    fn test_site_builder_pages_and_feeds() {
+        let pages = SiteBuilder::new()
+            .base_url("https://example.com/")
+            .taxonomy(&taxonomy().per_page(2))
+            .build()
+            .unwrap();
+        let find = |path: &str| pages.iter().find(|page| page.path == path).unwrap();
+
+        assert!(find("/tags").html.contains("sh-tag-cloud"));
+        assert!(find("/tags/rust/page/1")
+            .html
+            .contains(r#"<link rel="canonical" href="https://example.com/tags/rust">"#));
+
+        let feed = find("/tags/rust/rss.xml");
+        assert_eq!(feed.file.to_str(), Some("tags/rust/rss.xml"));
+        assert!(feed.html.starts_with("<?xml"));
+        assert!(feed
+            .html
+            .contains("<link>https://example.com/blog/three</link>"));
+        assert!(feed.html.contains("<category>wasm</category>"));
+        assert_eq!(feed.html.matches("<item>").count(), 3);
+
+        let without_feeds = SiteBuilder::new()
+            .taxonomy(&taxonomy().feeds(false))
+            .build()
+            .unwrap();
+        assert!(without_feeds
+            .iter()
+            .all(|page| !page.path.ends_with(".xml")));
+    }
+}
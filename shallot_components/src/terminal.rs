@@ -0,0 +1,1082 @@
+//! Terminal Component - ANSI-colored log output rendered to HTML
+//!
+//! SGR escape sequences (`ESC[...m`) are parsed on the server into styled
+//! spans; every other control sequence is dropped. Level filters are hidden
+//! checkboxes toggled through their labels, so hiding debug noise needs no
+//! client runtime.
+
+use crate::copy_button::{CopyButton, CopyButtonSize, CopyButtonVariant};
+use maud::{html, Markup, PreEscaped, Render};
+use std::borrow::Cow;
+
+const ESC: char = '\x1b';
+
+const NAMED_COLORS: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// A terminal color as selected by an SGR sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// One of the 16 palette colors (0-7 normal, 8-15 bright)
+    Named(u8),
+    /// A color from the 256-color table (16-255)
+    Indexed(u8),
+    /// A 24-bit color
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    fn from_index(index: u8) -> Self {
+        if index < 16 {
+            AnsiColor::Named(index)
+        } else {
+            AnsiColor::Indexed(index)
+        }
+    }
+
+    /// Class suffix for palette colors, e.g. `red` or `bright-red`
+    fn class_name(&self) -> Option<String> {
+        match *self {
+            AnsiColor::Named(n) if n < 8 => Some(NAMED_COLORS[n as usize].to_string()),
+            AnsiColor::Named(n) => Some(format!("bright-{}", NAMED_COLORS[(n % 8) as usize])),
+            _ => None,
+        }
+    }
+
+    /// CSS color for colors outside the themeable palette
+    fn css_value(&self) -> Option<String> {
+        match *self {
+            AnsiColor::Named(_) => None,
+            AnsiColor::Indexed(n) => {
+                let (r, g, b) = indexed_rgb(n);
+                Some(format!("rgb({}, {}, {})", r, g, b))
+            }
+            AnsiColor::Rgb(r, g, b) => Some(format!("rgb({}, {}, {})", r, g, b)),
+        }
+    }
+}
+
+/// Map a 256-color table index (16-255) to RGB
+fn indexed_rgb(n: u8) -> (u8, u8, u8) {
+    const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    if n >= 232 {
+        let v = 8 + 10 * (n - 232);
+        (v, v, v)
+    } else {
+        let i = n.saturating_sub(16);
+        (
+            CUBE[(i / 36) as usize],
+            CUBE[((i / 6) % 6) as usize],
+            CUBE[(i % 6) as usize],
+        )
+    }
+}
+
+/// Text attributes accumulated from SGR sequences
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AnsiStyle {
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub fg: Option<AnsiColor>,
+    pub bg: Option<AnsiColor>,
+}
+
+impl AnsiStyle {
+    fn is_plain(&self) -> bool {
+        *self == AnsiStyle::default()
+    }
+
+    /// Apply the numeric parameters of one `ESC[...m` sequence
+    fn apply_sgr(&mut self, params: &str) {
+        let codes: Vec<u16> = if params.is_empty() {
+            vec![0]
+        } else {
+            params
+                .split([';', ':'])
+                .map(|p| p.parse().unwrap_or(0))
+                .collect()
+        };
+
+        let mut i = 0;
+        while i < codes.len() {
+            match codes[i] {
+                0 => *self = AnsiStyle::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                29 => self.strikethrough = false,
+                c @ 30..=37 => self.fg = Some(AnsiColor::Named((c - 30) as u8)),
+                39 => self.fg = None,
+                c @ 40..=47 => self.bg = Some(AnsiColor::Named((c - 40) as u8)),
+                49 => self.bg = None,
+                c @ 90..=97 => self.fg = Some(AnsiColor::Named((c - 90 + 8) as u8)),
+                c @ 100..=107 => self.bg = Some(AnsiColor::Named((c - 100 + 8) as u8)),
+                c @ (38 | 48) => {
+                    let (color, used) = extended_color(&codes[i + 1..]);
+                    if let Some(color) = color {
+                        if c == 38 {
+                            self.fg = Some(color);
+                        } else {
+                            self.bg = Some(color);
+                        }
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    fn classes(&self) -> String {
+        let mut classes = Vec::new();
+        if self.bold {
+            classes.push("sh-terminal__bold".to_string());
+        }
+        if self.dim {
+            classes.push("sh-terminal__dim".to_string());
+        }
+        if self.italic {
+            classes.push("sh-terminal__italic".to_string());
+        }
+        if self.underline {
+            classes.push("sh-terminal__underline".to_string());
+        }
+        if self.strikethrough {
+            classes.push("sh-terminal__strike".to_string());
+        }
+        if let Some(name) = self.fg.and_then(|c| c.class_name()) {
+            classes.push(format!("sh-terminal__fg-{}", name));
+        }
+        if let Some(name) = self.bg.and_then(|c| c.class_name()) {
+            classes.push(format!("sh-terminal__bg-{}", name));
+        }
+        classes.join(" ")
+    }
+
+    fn inline_style(&self) -> Option<String> {
+        let mut style = String::new();
+        if let Some(color) = self.fg.and_then(|c| c.css_value()) {
+            style.push_str(&format!("color: {};", color));
+        }
+        if let Some(color) = self.bg.and_then(|c| c.css_value()) {
+            style.push_str(&format!("background-color: {};", color));
+        }
+        (!style.is_empty()).then_some(style)
+    }
+}
+
+/// Parse the color following a `38`/`48` code; returns the color and how
+/// many extra parameters it consumed
+fn extended_color(rest: &[u16]) -> (Option<AnsiColor>, usize) {
+    match rest {
+        [5, n, ..] => (Some(AnsiColor::from_index((*n).min(255) as u8)), 2),
+        [2, r, g, b, ..] => (
+            Some(AnsiColor::Rgb(
+                (*r).min(255) as u8,
+                (*g).min(255) as u8,
+                (*b).min(255) as u8,
+            )),
+            4,
+        ),
+        [5] | [2, ..] => (None, rest.len()),
+        _ => (None, 0),
+    }
+}
+
+/// A run of text sharing one style
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnsiSpan {
+    pub text: String,
+    pub style: AnsiStyle,
+}
+
+/// Split `input` into styled spans, starting from `style` and leaving the
+/// style in effect at the end so it can carry over to the next line
+fn parse_with_state(input: &str, style: &mut AnsiStyle) -> Vec<AnsiSpan> {
+    let mut spans: Vec<AnsiSpan> = Vec::new();
+    let mut current = String::new();
+    let mut chars = input.chars().peekable();
+
+    let flush = |spans: &mut Vec<AnsiSpan>, current: &mut String, style: AnsiStyle| {
+        if !current.is_empty() {
+            spans.push(AnsiSpan {
+                text: std::mem::take(current),
+                style,
+            });
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ESC => match chars.peek() {
+                Some('[') => {
+                    chars.next();
+                    let mut params = String::new();
+                    let mut terminator = None;
+                    for c in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            terminator = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    if terminator == Some('m') {
+                        let mut next = *style;
+                        next.apply_sgr(&params);
+                        if next != *style {
+                            flush(&mut spans, &mut current, *style);
+                            *style = next;
+                        }
+                    }
+                }
+                Some(']') => {
+                    // OSC: skip to BEL or ESC \
+                    chars.next();
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == ESC {
+                            if chars.peek() == Some(&'\\') {
+                                chars.next();
+                            }
+                            break;
+                        }
+                    }
+                }
+                Some(_) => {
+                    chars.next();
+                }
+                None => {}
+            },
+            '\t' => current.push(ch),
+            c if c.is_control() => {}
+            c => current.push(c),
+        }
+    }
+    flush(&mut spans, &mut current, *style);
+    spans
+}
+
+/// Parse one line of ANSI text into styled spans
+pub fn parse_ansi(input: &str) -> Vec<AnsiSpan> {
+    parse_with_state(input, &mut AnsiStyle::default())
+}
+
+/// Remove all escape sequences and control characters from `input`
+pub fn strip_ansi(input: &str) -> String {
+    parse_ansi(input).into_iter().map(|s| s.text).collect()
+}
+
+/// Keep only what a terminal would show after carriage returns (progress bars)
+fn after_carriage_return(line: &str) -> &str {
+    line.rsplit('\r')
+        .find(|segment| !segment.is_empty())
+        .unwrap_or("")
+}
+
+/// Severity of a log line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    fn modifier(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "Trace",
+            LogLevel::Debug => "Debug",
+            LogLevel::Info => "Info",
+            LogLevel::Warn => "Warn",
+            LogLevel::Error => "Error",
+        }
+    }
+
+    /// Detect a level keyword among the first few words of a plain line
+    pub fn detect(text: &str) -> Option<Self> {
+        text.split_whitespace().take(3).find_map(|word| {
+            let word = word.trim_matches(|c: char| !c.is_ascii_alphabetic());
+            match word.to_ascii_uppercase().as_str() {
+                "ERROR" | "ERR" | "FATAL" | "CRITICAL" | "PANIC" => Some(LogLevel::Error),
+                "WARN" | "WARNING" => Some(LogLevel::Warn),
+                "INFO" => Some(LogLevel::Info),
+                "DEBUG" => Some(LogLevel::Debug),
+                "TRACE" => Some(LogLevel::Trace),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Split a leading timestamp off a plain line: `[12:00:01] ...`,
+/// `2024-05-01T12:00:01Z ...` or `2024-05-01 12:00:01 ...`
+fn split_timestamp(line: &str) -> Option<(&str, &str)> {
+    if let Some(rest) = line.strip_prefix('[') {
+        let end = rest.find(']')?;
+        let inner = &rest[..end];
+        let looks_like_time = inner.starts_with(|c: char| c.is_ascii_digit())
+            && inner.contains([':', '-'])
+            && inner
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || " :-.+TZ".contains(c));
+        return looks_like_time.then(|| (inner, rest[end + 1..].trim_start()));
+    }
+
+    let is_date = |s: &str| {
+        let b = s.as_bytes();
+        b.len() >= 10 && b[..4].iter().all(u8::is_ascii_digit) && b[4] == b'-' && b[7] == b'-'
+    };
+    let is_time = |s: &str| {
+        s.len() >= 5 && s.as_bytes()[0].is_ascii_digit() && s.as_bytes().get(2) == Some(&b':')
+    };
+
+    let first_end = line.find(char::is_whitespace).unwrap_or(line.len());
+    let first = &line[..first_end];
+    if is_date(first) {
+        let rest = line[first_end..].trim_start();
+        let second_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if first.len() == 10 && is_time(&rest[..second_end]) {
+            let ts_end = line.len() - rest.len() + second_end;
+            return Some((&line[..ts_end], line[ts_end..].trim_start()));
+        }
+        return Some((first, rest));
+    }
+    if is_time(first)
+        && first
+            .chars()
+            .all(|c| c.is_ascii_digit() || ":.".contains(c))
+    {
+        return Some((first, line[first_end..].trim_start()));
+    }
+    None
+}
+
+/// A single line of terminal output
+#[derive(Debug, Clone)]
+pub struct TerminalLine<'a> {
+    pub text: Cow<'a, str>,
+    pub timestamp: Option<Cow<'a, str>>,
+    pub level: Option<LogLevel>,
+}
+
+impl<'a> TerminalLine<'a> {
+    /// Create a line from raw (possibly ANSI-colored) text; the level is
+    /// detected from the text unless set explicitly
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        let text = text.into();
+        let level = LogLevel::detect(&strip_ansi(&text));
+        Self {
+            text,
+            timestamp: None,
+            level,
+        }
+    }
+
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn level(mut self, level: LogLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Text without escape sequences, as it is copied to the clipboard
+    pub fn plain_text(&self) -> String {
+        let text = strip_ansi(after_carriage_return(&self.text));
+        match &self.timestamp {
+            Some(ts) => format!("{} {}", ts, text),
+            None => text,
+        }
+    }
+}
+
+/// How long lines are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// Long lines scroll horizontally
+    #[default]
+    NoWrap,
+    /// Long lines wrap, keeping the gutter aligned
+    Wrap,
+}
+
+/// Terminal/log viewer
+#[derive(Debug, Clone)]
+pub struct Terminal<'a> {
+    pub id: Cow<'a, str>,
+    pub lines: Vec<TerminalLine<'a>>,
+    pub title: Option<Cow<'a, str>>,
+    pub line_numbers: bool,
+    pub start_line: usize,
+    pub timestamps: bool,
+    pub filters: bool,
+    pub wrap: WrapMode,
+    pub copy_button: bool,
+    pub max_height: Option<Cow<'a, str>>,
+}
+
+impl<'a> Terminal<'a> {
+    /// `id` prefixes the filter controls and must be unique on the page
+    pub fn new(id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            lines: Vec::new(),
+            title: None,
+            line_numbers: true,
+            start_line: 1,
+            timestamps: true,
+            filters: true,
+            wrap: WrapMode::default(),
+            copy_button: true,
+            max_height: None,
+        }
+    }
+
+    /// Build from captured output, one line per `\n`, splitting a leading
+    /// timestamp off each line when one is recognized
+    pub fn from_output(id: impl Into<Cow<'a, str>>, output: &str) -> Self {
+        let lines = output
+            .lines()
+            .map(|raw| {
+                let plain = strip_ansi(raw);
+                match split_timestamp(&plain) {
+                    // Only split when the timestamp is uncolored, so the
+                    // remaining escape sequences stay intact
+                    Some((ts, _)) if raw.starts_with(ts) => {
+                        let rest = raw[ts.len()..].trim_start_matches([']', ' ']);
+                        TerminalLine::new(rest.to_string()).timestamp(ts.to_string())
+                    }
+                    Some((ts, _)) if raw.starts_with('[') && raw[1..].starts_with(ts) => {
+                        let rest = raw[1 + ts.len()..].trim_start_matches([']', ' ']);
+                        TerminalLine::new(rest.to_string()).timestamp(ts.to_string())
+                    }
+                    _ => TerminalLine::new(raw.to_string()),
+                }
+            })
+            .collect();
+        Self::new(id).lines(lines)
+    }
+
+    pub fn lines(mut self, lines: Vec<TerminalLine<'a>>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    pub fn line(mut self, line: TerminalLine<'a>) -> Self {
+        self.lines.push(line);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Number of the first line, for excerpts of a longer log
+    pub fn start_line(mut self, start: usize) -> Self {
+        self.start_line = start;
+        self
+    }
+
+    pub fn timestamps(mut self, show: bool) -> Self {
+        self.timestamps = show;
+        self
+    }
+
+    /// Show checkbox toggles for each level present in the output
+    pub fn filters(mut self, show: bool) -> Self {
+        self.filters = show;
+        self
+    }
+
+    pub fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn copy_button(mut self, show: bool) -> Self {
+        self.copy_button = show;
+        self
+    }
+
+    /// Cap the body height (any CSS length); the body scrolls beyond it
+    pub fn max_height(mut self, height: impl Into<Cow<'a, str>>) -> Self {
+        self.max_height = Some(height.into());
+        self
+    }
+
+    /// The whole output without escape sequences
+    pub fn plain_text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn level_counts(&self) -> Vec<(LogLevel, usize)> {
+        LogLevel::ALL
+            .iter()
+            .map(|level| {
+                let count = self
+                    .lines
+                    .iter()
+                    .filter(|l| l.level == Some(*level))
+                    .count();
+                (*level, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    fn build_classes(&self) -> String {
+        let mut classes = vec!["sh-terminal"];
+        classes.push(match self.wrap {
+            WrapMode::NoWrap => "sh-terminal--nowrap",
+            WrapMode::Wrap => "sh-terminal--wrap",
+        });
+        if self.line_numbers {
+            classes.push("sh-terminal--numbered");
+        }
+        classes.join(" ")
+    }
+}
+
+fn render_spans(spans: &[AnsiSpan]) -> Markup {
+    html! {
+        @for span in spans {
+            @if span.style.is_plain() {
+                (span.text)
+            } @else {
+                span class=(span.style.classes()) style=[span.style.inline_style()] {
+                    (span.text)
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for Terminal<'a> {
+    fn render(&self) -> Markup {
+        let label = self.title.as_deref().unwrap_or("Terminal output");
+        let counts = if self.filters {
+            self.level_counts()
+        } else {
+            Vec::new()
+        };
+        let show_filters = counts.len() > 1;
+        let plain = self.plain_text();
+        let body_style = self
+            .max_height
+            .as_ref()
+            .map(|h| format!("max-height: {};", h));
+        let mut style = AnsiStyle::default();
+
+        html! {
+            section class=(self.build_classes()) aria-label=(label) {
+                @if show_filters {
+                    @for (level, _) in &counts {
+                        input
+                            type="checkbox"
+                            class={ "sh-terminal__filter-input sh-terminal__filter-input--" (level.modifier()) }
+                            id={ (self.id) "-level-" (level.modifier()) }
+                            checked;
+                    }
+                }
+                div class="sh-terminal__header" {
+                    @if let Some(title) = &self.title {
+                        span class="sh-terminal__title" { (title) }
+                    }
+                    @if show_filters {
+                        div class="sh-terminal__filters" role="group" aria-label="Show levels" {
+                            @for (level, count) in &counts {
+                                label
+                                    class={ "sh-terminal__filter sh-terminal__filter--" (level.modifier()) }
+                                    for={ (self.id) "-level-" (level.modifier()) } {
+                                    (level.label())
+                                    span class="sh-terminal__filter-count" { (count) }
+                                }
+                            }
+                        }
+                    }
+                    @if self.copy_button {
+                        span class="sh-terminal__copy" {
+                            (CopyButton::new(&plain)
+                                .size(CopyButtonSize::Sm)
+                                .variant(CopyButtonVariant::Ghost)
+                                .label("Copy output"))
+                        }
+                    }
+                }
+                div class="sh-terminal__body" tabindex="0" style=[body_style] {
+                    @for (i, line) in self.lines.iter().enumerate() {
+                        @let spans = parse_with_state(after_carriage_return(&line.text), &mut style);
+                        div class={
+                            "sh-terminal__line"
+                            @if let Some(level) = line.level { " sh-terminal__line--" (level.modifier()) }
+                        } data-level=[line.level.map(|l| l.modifier())] {
+                            @if self.line_numbers {
+                                span class="sh-terminal__number" aria-hidden="true" {
+                                    (self.start_line + i)
+                                }
+                            }
+                            @if self.timestamps {
+                                @if let Some(ts) = &line.timestamp {
+                                    time class="sh-terminal__timestamp" { (ts) }
+                                }
+                            }
+                            code class="sh-terminal__content" {
+                                @if spans.is_empty() {
+                                    (PreEscaped("\u{200b}"))
+                                } @else {
+                                    (render_spans(&spans))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate CSS for the terminal component
+pub fn terminal_css() -> String {
+    r#"
+.sh-terminal {
+    --sh-terminal-bg: #0f1117;
+    --sh-terminal-fg: #d6d9e0;
+    --sh-terminal-gutter: #6b7280;
+    --sh-terminal-black: #3f4451;
+    --sh-terminal-red: #e06c75;
+    --sh-terminal-green: #98c379;
+    --sh-terminal-yellow: #e5c07b;
+    --sh-terminal-blue: #61afef;
+    --sh-terminal-magenta: #c678dd;
+    --sh-terminal-cyan: #56b6c2;
+    --sh-terminal-white: #d6d9e0;
+    --sh-terminal-bright-black: #5c6370;
+    --sh-terminal-bright-red: #ff7b86;
+    --sh-terminal-bright-green: #b5e890;
+    --sh-terminal-bright-yellow: #ffd68a;
+    --sh-terminal-bright-blue: #7cc4ff;
+    --sh-terminal-bright-magenta: #de9bf0;
+    --sh-terminal-bright-cyan: #6fd3df;
+    --sh-terminal-bright-white: #ffffff;
+    overflow: hidden;
+    border: 1px solid var(--sh-border, #e5e7eb);
+    border-radius: var(--sh-radius-md, 0.5rem);
+    background: var(--sh-terminal-bg);
+    color: var(--sh-terminal-fg);
+}
+
+.sh-terminal__filter-input {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    margin: 0;
+    opacity: 0;
+    pointer-events: none;
+}
+
+.sh-terminal__header {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    padding: 0.375rem 0.75rem;
+    border-bottom: 1px solid rgba(255, 255, 255, 0.08);
+    font-family: var(--sh-font-sans, system-ui, sans-serif);
+    font-size: 0.8125rem;
+}
+
+.sh-terminal__title {
+    font-weight: 600;
+}
+
+.sh-terminal__filters {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 0.25rem;
+}
+
+.sh-terminal__filter {
+    display: inline-flex;
+    align-items: center;
+    gap: 0.375rem;
+    padding: 0.125rem 0.5rem;
+    border: 1px solid rgba(255, 255, 255, 0.15);
+    border-radius: 999px;
+    cursor: pointer;
+    opacity: 0.5;
+    text-decoration: line-through;
+    user-select: none;
+}
+
+.sh-terminal__filter-count {
+    font-variant-numeric: tabular-nums;
+    opacity: 0.75;
+}
+
+.sh-terminal__filter--error { color: var(--sh-terminal-red); }
+.sh-terminal__filter--warn { color: var(--sh-terminal-yellow); }
+.sh-terminal__filter--info { color: var(--sh-terminal-blue); }
+.sh-terminal__filter--debug { color: var(--sh-terminal-bright-black); }
+.sh-terminal__filter--trace { color: var(--sh-terminal-bright-black); }
+
+.sh-terminal__filter-input--error:checked ~ .sh-terminal__header .sh-terminal__filter--error,
+.sh-terminal__filter-input--warn:checked ~ .sh-terminal__header .sh-terminal__filter--warn,
+.sh-terminal__filter-input--info:checked ~ .sh-terminal__header .sh-terminal__filter--info,
+.sh-terminal__filter-input--debug:checked ~ .sh-terminal__header .sh-terminal__filter--debug,
+.sh-terminal__filter-input--trace:checked ~ .sh-terminal__header .sh-terminal__filter--trace {
+    opacity: 1;
+    text-decoration: none;
+    border-color: currentColor;
+}
+
+.sh-terminal__filter-input--error:focus-visible ~ .sh-terminal__header .sh-terminal__filter--error,
+.sh-terminal__filter-input--warn:focus-visible ~ .sh-terminal__header .sh-terminal__filter--warn,
+.sh-terminal__filter-input--info:focus-visible ~ .sh-terminal__header .sh-terminal__filter--info,
+.sh-terminal__filter-input--debug:focus-visible ~ .sh-terminal__header .sh-terminal__filter--debug,
+.sh-terminal__filter-input--trace:focus-visible ~ .sh-terminal__header .sh-terminal__filter--trace {
+    outline: 2px solid var(--sh-accent, #3b82f6);
+    outline-offset: 2px;
+}
+
+.sh-terminal__filter-input--error:not(:checked) ~ .sh-terminal__body .sh-terminal__line--error,
+.sh-terminal__filter-input--warn:not(:checked) ~ .sh-terminal__body .sh-terminal__line--warn,
+.sh-terminal__filter-input--info:not(:checked) ~ .sh-terminal__body .sh-terminal__line--info,
+.sh-terminal__filter-input--debug:not(:checked) ~ .sh-terminal__body .sh-terminal__line--debug,
+.sh-terminal__filter-input--trace:not(:checked) ~ .sh-terminal__body .sh-terminal__line--trace {
+    display: none;
+}
+
+.sh-terminal__copy {
+    margin-left: auto;
+}
+
+.sh-terminal__body {
+    overflow: auto;
+    padding: 0.5rem 0;
+    font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace;
+    font-size: 0.8125rem;
+    line-height: 1.5;
+}
+
+.sh-terminal__body:focus-visible {
+    outline: 2px solid var(--sh-accent, #3b82f6);
+    outline-offset: -2px;
+}
+
+.sh-terminal__line {
+    display: flex;
+    gap: 0.75rem;
+    padding: 0 0.75rem;
+}
+
+.sh-terminal--nowrap .sh-terminal__line {
+    width: max-content;
+    min-width: 100%;
+}
+
+.sh-terminal__line:hover {
+    background: rgba(255, 255, 255, 0.04);
+}
+
+.sh-terminal__line--error {
+    background: rgba(224, 108, 117, 0.08);
+}
+
+.sh-terminal__line--warn {
+    background: rgba(229, 192, 123, 0.06);
+}
+
+.sh-terminal__number {
+    flex: none;
+    min-width: 3ch;
+    color: var(--sh-terminal-gutter);
+    text-align: right;
+    user-select: none;
+    font-variant-numeric: tabular-nums;
+}
+
+.sh-terminal__timestamp {
+    flex: none;
+    color: var(--sh-terminal-gutter);
+}
+
+.sh-terminal__content {
+    flex: 1;
+    font: inherit;
+    white-space: pre;
+    tab-size: 4;
+}
+
+.sh-terminal--wrap .sh-terminal__content {
+    min-width: 0;
+    white-space: pre-wrap;
+    overflow-wrap: anywhere;
+}
+
+.sh-terminal__bold { font-weight: 700; }
+.sh-terminal__dim { opacity: 0.65; }
+.sh-terminal__italic { font-style: italic; }
+.sh-terminal__underline { text-decoration: underline; }
+.sh-terminal__strike { text-decoration: line-through; }
+.sh-terminal__underline.sh-terminal__strike { text-decoration: underline line-through; }
+
+.sh-terminal__fg-black { color: var(--sh-terminal-black); }
+.sh-terminal__fg-red { color: var(--sh-terminal-red); }
+.sh-terminal__fg-green { color: var(--sh-terminal-green); }
+.sh-terminal__fg-yellow { color: var(--sh-terminal-yellow); }
+.sh-terminal__fg-blue { color: var(--sh-terminal-blue); }
+.sh-terminal__fg-magenta { color: var(--sh-terminal-magenta); }
+.sh-terminal__fg-cyan { color: var(--sh-terminal-cyan); }
+.sh-terminal__fg-white { color: var(--sh-terminal-white); }
+.sh-terminal__fg-bright-black { color: var(--sh-terminal-bright-black); }
+.sh-terminal__fg-bright-red { color: var(--sh-terminal-bright-red); }
+.sh-terminal__fg-bright-green { color: var(--sh-terminal-bright-green); }
+.sh-terminal__fg-bright-yellow { color: var(--sh-terminal-bright-yellow); }
+.sh-terminal__fg-bright-blue { color: var(--sh-terminal-bright-blue); }
+.sh-terminal__fg-bright-magenta { color: var(--sh-terminal-bright-magenta); }
+.sh-terminal__fg-bright-cyan { color: var(--sh-terminal-bright-cyan); }
+.sh-terminal__fg-bright-white { color: var(--sh-terminal-bright-white); }
+
+.sh-terminal__bg-black { background-color: var(--sh-terminal-black); }
+.sh-terminal__bg-red { background-color: var(--sh-terminal-red); }
+.sh-terminal__bg-green { background-color: var(--sh-terminal-green); }
+.sh-terminal__bg-yellow { background-color: var(--sh-terminal-yellow); }
+.sh-terminal__bg-blue { background-color: var(--sh-terminal-blue); }
+.sh-terminal__bg-magenta { background-color: var(--sh-terminal-magenta); }
+.sh-terminal__bg-cyan { background-color: var(--sh-terminal-cyan); }
+.sh-terminal__bg-white { background-color: var(--sh-terminal-white); }
+.sh-terminal__bg-bright-black { background-color: var(--sh-terminal-bright-black); }
+.sh-terminal__bg-bright-red { background-color: var(--sh-terminal-bright-red); }
+.sh-terminal__bg-bright-green { background-color: var(--sh-terminal-bright-green); }
+.sh-terminal__bg-bright-yellow { background-color: var(--sh-terminal-bright-yellow); }
+.sh-terminal__bg-bright-blue { background-color: var(--sh-terminal-bright-blue); }
+.sh-terminal__bg-bright-magenta { background-color: var(--sh-terminal-bright-magenta); }
+.sh-terminal__bg-bright-cyan { background-color: var(--sh-terminal-bright-cyan); }
+.sh-terminal__bg-bright-white { background-color: var(--sh-terminal-bright-white); }
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_basic_colors() {
+        let spans = parse_ansi("\x1b[1;31mfail\x1b[0m ok");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text, "fail");
+        assert!(spans[0].style.bold);
+        assert_eq!(spans[0].style.fg, Some(AnsiColor::Named(1)));
+        assert_eq!(spans[1].text, " ok");
+        assert!(spans[1].style.is_plain());
+    }
+
+    #[test]
+    fn test_parse_extended_colors() {
+        let spans = parse_ansi("\x1b[38;5;196mA\x1b[48;2;10;20;30mB\x1b[38;5;3mC");
+        assert_eq!(spans[0].style.fg, Some(AnsiColor::Indexed(196)));
+        assert_eq!(spans[1].style.bg, Some(AnsiColor::Rgb(10, 20, 30)));
+        assert_eq!(spans[2].style.fg, Some(AnsiColor::Named(3)));
+        assert_eq!(indexed_rgb(196), (255, 0, 0));
+        assert_eq!(indexed_rgb(232), (8, 8, 8));
+    }
+
+    #[test]
+    fn test_partial_resets() {
+        let spans = parse_ansi("\x1b[1;4;32ma\x1b[22mb\x1b[39;24mc");
+        assert!(spans[0].style.bold && spans[0].style.underline);
+        assert!(!spans[1].style.bold && spans[1].style.underline);
+        assert_eq!(spans[1].style.fg, Some(AnsiColor::Named(2)));
+        assert!(spans[2].style.is_plain());
+    }
+
+    #[test]
+    fn test_strip_ansi_drops_other_sequences() {
+        assert_eq!(
+            strip_ansi("\x1b]0;title\x07\x1b[2K\x1b[32mdone\x1b[0m\x08"),
+            "done"
+        );
+    }
+
+    #[test]
+    fn test_level_detection() {
+        assert_eq!(LogLevel::detect("ERROR db: timeout"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::detect("[WARN] disk 91%"), Some(LogLevel::Warn));
+        assert_eq!(
+            LogLevel::detect("server: info: listening"),
+            Some(LogLevel::Info)
+        );
+        assert_eq!(LogLevel::detect("compiling crate"), None);
+    }
+
+    #[test]
+    fn test_timestamp_split() {
+        assert_eq!(
+            split_timestamp("[12:00:01] started"),
+            Some(("12:00:01", "started"))
+        );
+        assert_eq!(
+            split_timestamp("2024-05-01T12:00:01Z INFO up"),
+            Some(("2024-05-01T12:00:01Z", "INFO up"))
+        );
+        assert_eq!(
+            split_timestamp("2024-05-01 12:00:01 INFO up"),
+            Some(("2024-05-01 12:00:01", "INFO up"))
+        );
+        assert_eq!(split_timestamp("[build] step 1"), None);
+        assert_eq!(split_timestamp("100 files"), None);
+    }
+
+    #[test]
+    fn test_from_output() {
+        let term = Terminal::from_output(
+            "log",
+            "12:00:01 \x1b[33mWARN\x1b[0m slow\n[12:00:02] ERROR boom\nplain",
+        );
+        assert_eq!(term.lines.len(), 3);
+        assert_eq!(term.lines[0].timestamp.as_deref(), Some("12:00:01"));
+        assert_eq!(term.lines[0].level, Some(LogLevel::Warn));
+        assert_eq!(term.lines[1].timestamp.as_deref(), Some("12:00:02"));
+        assert_eq!(term.lines[1].text, "ERROR boom");
+        assert_eq!(term.lines[2].level, None);
+        assert_eq!(
+            term.plain_text(),
+            "12:00:01 WARN slow\n12:00:02 ERROR boom\nplain"
+        );
+    }
+
+    #[test]
+    fn test_render_spans_and_gutter() {
+        let html = Terminal::new("t")
+            .start_line(40)
+            .line(TerminalLine::new("\x1b[31mred <b>\x1b[0m").timestamp("09:00"))
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<span class="sh-terminal__fg-red">red &lt;b&gt;</span>"#));
+        assert!(html.contains(r#"<span class="sh-terminal__number" aria-hidden="true">40</span>"#));
+        assert!(html.contains(r#"<time class="sh-terminal__timestamp">09:00</time>"#));
+        assert!(!html.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_render_inline_rgb() {
+        let html = Terminal::new("t")
+            .line(TerminalLine::new("\x1b[38;2;1;2;3mx"))
+            .render()
+            .into_string();
+        assert!(html.contains(r#"style="color: rgb(1, 2, 3);""#));
+    }
+
+    #[test]
+    fn test_style_carries_across_lines() {
+        let html = Terminal::new("t")
+            .line(TerminalLine::new("\x1b[32mfirst"))
+            .line(TerminalLine::new("second\x1b[0m"))
+            .render()
+            .into_string();
+        assert!(html.contains(r#"<span class="sh-terminal__fg-green">second</span>"#));
+    }
+
+    #[test]
+    fn test_filters_only_for_present_levels() {
+        let term = Terminal::new("build")
+            .line(TerminalLine::new("INFO start"))
+            .line(TerminalLine::new("DEBUG cfg"))
+            .line(TerminalLine::new("INFO done"));
+        let html = term.render().into_string();
+        assert!(html.contains(r#"id="build-level-info" checked"#));
+        assert!(html.contains(r#"for="build-level-debug""#));
+        assert!(!html.contains("build-level-error"));
+        assert!(html.contains(r#"data-level="debug""#));
+
+        let single = Terminal::new("one")
+            .line(TerminalLine::new("INFO only"))
+            .render()
+            .into_string();
+        assert!(!single.contains("sh-terminal__filter-input"));
+
+        let off = term.filters(false).render().into_string();
+        assert!(!off.contains("sh-terminal__filter"));
+    }
+
+    #[test]
+    fn test_wrap_modes_and_copy() {
+        let html = Terminal::new("t")
+            .line(TerminalLine::new("\x1b[1mhi"))
+            .render()
+            .into_string();
+        assert!(html.contains("sh-terminal--nowrap"));
+        assert!(html.contains(r#"data-copy-text="hi""#));
+
+        let html = Terminal::new("t")
+            .wrap(WrapMode::Wrap)
+            .copy_button(false)
+            .line_numbers(false)
+            .render()
+            .into_string();
+        assert!(html.contains("sh-terminal--wrap"));
+        assert!(!html.contains("sh-copy-btn"));
+        assert!(!html.contains("sh-terminal--numbered"));
+    }
+
+    #[test]
+    fn test_carriage_return_keeps_last_frame() {
+        let line = TerminalLine::new("10%\r50%\r100%");
+        assert_eq!(line.plain_text(), "100%");
+    }
+
+    #[test]
+    fn test_terminal_css() {
+        let css = terminal_css();
+        assert!(css.contains(".sh-terminal__line--debug"));
+        assert!(css.contains(":not(:checked) ~ .sh-terminal__body"));
+        assert!(css.contains(".sh-terminal--wrap"));
+    }
+}
@@ -1,8 +1,85 @@
 //! Time Picker Component - CSS-only time selection
 //!
 //! A zero-JavaScript time picker using native input[type="time"] with enhanced styling.
+//!
+//! The native input shows 12 or 24 hours as the browser's locale decides.
+//! [`TimeDisplay::Selects`] renders hour, minute and AM/PM selects instead,
+//! in the [`TimeFormat`] of the page's locale, with minutes offered in the
+//! picker's step. An optional select next to it picks an IANA time zone.
+//!
+//! On the server, [`Time::from_form`] reads the value back from either
+//! display, [`Time`] and [`TimeRange`] parse and compare times of day, and
+//! [`TimeValidator`] checks bounds and steps.
 
+use crate::form::{FormData, ValidationError, Validator};
 use maud::{html, Markup, Render};
+use std::str::FromStr;
+
+/// Time zones offered by [`TimePicker::timezone`], as IANA names
+pub const TIMEZONES: &[&str] = &[
+    "UTC",
+    "Africa/Cairo",
+    "Africa/Johannesburg",
+    "Africa/Lagos",
+    "Africa/Nairobi",
+    "America/Anchorage",
+    "America/Argentina/Buenos_Aires",
+    "America/Bogota",
+    "America/Chicago",
+    "America/Denver",
+    "America/Halifax",
+    "America/Lima",
+    "America/Los_Angeles",
+    "America/Mexico_City",
+    "America/New_York",
+    "America/Phoenix",
+    "America/Santiago",
+    "America/Sao_Paulo",
+    "America/St_Johns",
+    "America/Toronto",
+    "America/Vancouver",
+    "Asia/Bangkok",
+    "Asia/Dhaka",
+    "Asia/Dubai",
+    "Asia/Hong_Kong",
+    "Asia/Jakarta",
+    "Asia/Jerusalem",
+    "Asia/Karachi",
+    "Asia/Kathmandu",
+    "Asia/Kolkata",
+    "Asia/Manila",
+    "Asia/Riyadh",
+    "Asia/Seoul",
+    "Asia/Shanghai",
+    "Asia/Singapore",
+    "Asia/Taipei",
+    "Asia/Tehran",
+    "Asia/Tokyo",
+    "Atlantic/Azores",
+    "Atlantic/Reykjavik",
+    "Australia/Adelaide",
+    "Australia/Brisbane",
+    "Australia/Perth",
+    "Australia/Sydney",
+    "Europe/Amsterdam",
+    "Europe/Athens",
+    "Europe/Berlin",
+    "Europe/Dublin",
+    "Europe/Helsinki",
+    "Europe/Istanbul",
+    "Europe/Lisbon",
+    "Europe/London",
+    "Europe/Madrid",
+    "Europe/Moscow",
+    "Europe/Paris",
+    "Europe/Rome",
+    "Europe/Stockholm",
+    "Europe/Warsaw",
+    "Europe/Zurich",
+    "Pacific/Auckland",
+    "Pacific/Fiji",
+    "Pacific/Honolulu",
+];
 
 /// Time picker size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -21,6 +98,30 @@ pub enum TimeFormat {
     Hour12,
 }
 
+impl TimeFormat {
+    /// Clock used in a BCP 47 locale, e.g. `en-US` or `de`
+    pub fn for_locale(locale: &str) -> Self {
+        let locale = locale.to_ascii_lowercase().replace('_', "-");
+        let language = locale.split('-').next().unwrap_or("");
+        match locale.as_str() {
+            "en" | "en-us" | "en-ca" | "en-au" | "en-nz" | "en-in" | "en-ph" | "es-mx"
+            | "es-us" | "fr-ca" => TimeFormat::Hour12,
+            _ if matches!(language, "ar" | "bn" | "fil" | "hi" | "ko" | "ur") => TimeFormat::Hour12,
+            _ => TimeFormat::Hour24,
+        }
+    }
+}
+
+/// How the picker is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeDisplay {
+    /// The browser's own time input
+    #[default]
+    Native,
+    /// Hour, minute and, for 12 hours, AM/PM selects
+    Selects,
+}
+
 /// Time Picker component
 #[derive(Debug, Clone)]
 pub struct TimePicker<'a> {
@@ -35,6 +136,13 @@ pub struct TimePicker<'a> {
     pub disabled: bool,
     pub required: bool,
     pub label: Option<&'a str>,
+    pub display: TimeDisplay,
+    /// Name of the time zone select, if shown
+    pub timezone: Option<&'a str>,
+    /// Selected time zone (defaults to `UTC`)
+    pub timezone_value: Option<&'a str>,
+    /// IANA names offered in the time zone select
+    pub timezones: &'a [&'a str],
 }
 
 impl<'a> TimePicker<'a> {
@@ -52,6 +160,10 @@ impl<'a> TimePicker<'a> {
             disabled: false,
             required: false,
             label: None,
+            display: TimeDisplay::Native,
+            timezone: None,
+            timezone_value: None,
+            timezones: TIMEZONES,
         }
     }
 
@@ -79,6 +191,12 @@ impl<'a> TimePicker<'a> {
         self
     }
 
+    /// Set step in minutes, e.g. 15 for quarter hours
+    pub fn minute_step(mut self, minutes: u32) -> Self {
+        self.step = Some(minutes.max(1) * 60);
+        self
+    }
+
     /// Set size variant
     pub fn size(mut self, size: TimePickerSize) -> Self {
         self.size = size;
@@ -91,6 +209,36 @@ impl<'a> TimePicker<'a> {
         self
     }
 
+    /// Use the clock of a BCP 47 locale, see [`TimeFormat::for_locale`]
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.format = TimeFormat::for_locale(locale);
+        self
+    }
+
+    /// Set how the picker is drawn
+    pub fn display(mut self, display: TimeDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Show a time zone select named `name`
+    pub fn timezone(mut self, name: &'a str) -> Self {
+        self.timezone = Some(name);
+        self
+    }
+
+    /// Set the selected time zone
+    pub fn timezone_value(mut self, zone: &'a str) -> Self {
+        self.timezone_value = Some(zone);
+        self
+    }
+
+    /// Offer these IANA names instead of [`TIMEZONES`]
+    pub fn timezones(mut self, zones: &'a [&'a str]) -> Self {
+        self.timezones = zones;
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -109,6 +257,20 @@ impl<'a> TimePicker<'a> {
         self
     }
 
+    /// A validator checking submissions against this picker's bounds and step
+    pub fn validator(&self) -> TimeValidator {
+        TimeValidator {
+            min: self.min_time.and_then(|t| t.parse().ok()),
+            max: self.max_time.and_then(|t| t.parse().ok()),
+            minute_step: self.minute_step_value(),
+            format: self.format,
+        }
+    }
+
+    fn minute_step_value(&self) -> Option<u32> {
+        self.step.map(|s| (s / 60).max(1)).filter(|&m| m > 1)
+    }
+
     fn build_classes(&self) -> String {
         let mut classes = vec!["sh-time-picker".to_string()];
 
@@ -124,40 +286,452 @@ impl<'a> TimePicker<'a> {
 
         classes.join(" ")
     }
+
+    fn render_required(&self) -> Markup {
+        html! {
+            @if self.required {
+                span class="sh-time-picker__required" { "*" }
+            }
+        }
+    }
+
+    fn render_native(&self) -> Markup {
+        let step_value = self.step.map(|s| s.to_string());
+        html! {
+            @if let Some(label) = self.label {
+                label class="sh-time-picker__label" for=(self.id) {
+                    (label)
+                    (self.render_required())
+                }
+            }
+            input
+                type="time"
+                id=(self.id)
+                name=(self.name)
+                class="sh-time-picker__input"
+                value=[self.value]
+                min=[self.min_time]
+                max=[self.max_time]
+                step=[step_value.as_deref()]
+                disabled?[self.disabled]
+                required?[self.required]
+                aria-label=[self.label.or(Some("Time picker"))];
+        }
+    }
+
+    fn render_selects(&self) -> Markup {
+        let selected: Option<Time> = self.value.and_then(|v| v.parse().ok());
+        let min: Option<Time> = self.min_time.and_then(|t| t.parse().ok());
+        let max: Option<Time> = self.max_time.and_then(|t| t.parse().ok());
+        let step = self.minute_step_value().unwrap_or(1) as usize;
+        let hour12 = self.format == TimeFormat::Hour12;
+
+        let hours: Vec<(u8, String)> = if hour12 {
+            [12, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+                .into_iter()
+                .map(|h| (h, h.to_string()))
+                .collect()
+        } else {
+            // Hours outside the bounds can go; minutes depend on the hour
+            let (first, last) = match (min, max) {
+                (Some(min), Some(max)) if min > max => (0, 23),
+                _ => (min.map_or(0, |t| t.hour), max.map_or(23, |t| t.hour)),
+            };
+            (first..=last).map(|h| (h, format!("{:02}", h))).collect()
+        };
+        let selected_hour = selected.map(|t| if hour12 { t.hour12() } else { t.hour });
+        let select = |part: &str| format!("{}[{}]", self.name, part);
+
+        html! {
+            fieldset class="sh-time-picker__fieldset" disabled?[self.disabled] {
+                legend class=(if self.label.is_some() { "sh-time-picker__label" } else { "sh-visually-hidden" }) {
+                    (self.label.unwrap_or("Time"))
+                    (self.render_required())
+                }
+                div class="sh-time-picker__selects" {
+                    select id=(self.id) name=(select("hour")) class="sh-time-picker__select" aria-label="Hour" required?[self.required] {
+                        option value="" selected?[selected.is_none()] { "--" }
+                        @for (hour, text) in &hours {
+                            option value=(text) selected?[selected_hour == Some(*hour)] { (text) }
+                        }
+                    }
+                    span class="sh-time-picker__colon" aria-hidden="true" { ":" }
+                    select id=(format!("{}-minute", self.id)) name=(select("minute")) class="sh-time-picker__select" aria-label="Minute" required?[self.required] {
+                        option value="" selected?[selected.is_none()] { "--" }
+                        @for minute in (0..60u8).step_by(step) {
+                            option value=(format!("{:02}", minute)) selected?[selected.map(|t| t.minute) == Some(minute)] {
+                                (format!("{:02}", minute))
+                            }
+                        }
+                    }
+                    @if hour12 {
+                        select id=(format!("{}-period", self.id)) name=(select("period")) class="sh-time-picker__select" aria-label="AM or PM" {
+                            option value="AM" selected?[selected.is_some_and(|t| t.hour < 12)] { "AM" }
+                            option value="PM" selected?[selected.is_some_and(|t| t.hour >= 12)] { "PM" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_timezone(&self, name: &str) -> Markup {
+        let current = self.timezone_value.unwrap_or("UTC");
+        let mut regions: Vec<(&str, Vec<&str>)> = Vec::new();
+        for zone in self.timezones {
+            let region = zone.split_once('/').map_or("", |(region, _)| region);
+            match regions.iter_mut().find(|(r, _)| *r == region) {
+                Some((_, zones)) => zones.push(zone),
+                None => regions.push((region, vec![zone])),
+            }
+        }
+        let zone_option = |zone: &str, text: &str| {
+            html! {
+                option value=(zone) selected?[zone == current] { (text.replace('_', " ")) }
+            }
+        };
+
+        html! {
+            select
+                id=(format!("{}-timezone", self.id))
+                name=(name)
+                class="sh-time-picker__timezone"
+                aria-label="Time zone"
+                disabled?[self.disabled] {
+                @for (region, zones) in &regions {
+                    @if region.is_empty() {
+                        @for zone in zones { (zone_option(zone, zone)) }
+                    } @else {
+                        optgroup label=(region) {
+                            @for zone in zones {
+                                (zone_option(zone, zone.split_once('/').map_or(*zone, |(_, city)| city)))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Render for TimePicker<'a> {
     fn render(&self) -> Markup {
         let classes = self.build_classes();
-        let step_value = self.step.map(|s| s.to_string());
 
         html! {
             div class=(classes) {
-                @if let Some(label) = self.label {
-                    label class="sh-time-picker__label" for=(self.id) {
-                        (label)
-                        @if self.required {
-                            span class="sh-time-picker__required" { "*" }
-                        }
-                    }
+                @match self.display {
+                    TimeDisplay::Native => (self.render_native()),
+                    TimeDisplay::Selects => (self.render_selects()),
+                }
+                @if let Some(name) = self.timezone {
+                    (self.render_timezone(name))
                 }
-                input
-                    type="time"
-                    id=(self.id)
-                    name=(self.name)
-                    class="sh-time-picker__input"
-                    value=[self.value]
-                    min=[self.min_time]
-                    max=[self.max_time]
-                    step=[step_value.as_deref()]
-                    disabled?[self.disabled]
-                    required?[self.required]
-                    aria-label=[self.label.or(Some("Time picker"))];
             }
         }
     }
 }
 
+/// Why a time could not be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeParseError {
+    /// Not a time of day
+    Invalid,
+    /// Only some of the hour and minute selects were chosen
+    Incomplete,
+}
+
+impl std::fmt::Display for TimeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeParseError::Invalid => write!(f, "not a valid time of day"),
+            TimeParseError::Incomplete => write!(f, "hour or minute is missing"),
+        }
+    }
+}
+
+impl std::error::Error for TimeParseError {}
+
+/// A time of day to the minute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl Time {
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Minutes since midnight
+    pub fn minutes(&self) -> u32 {
+        self.hour as u32 * 60 + self.minute as u32
+    }
+
+    /// The time `minutes` after midnight, wrapping past a day
+    pub fn from_minutes(minutes: u32) -> Self {
+        let minutes = minutes % (24 * 60);
+        Self {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        }
+    }
+
+    /// Hour on a 12-hour clock, 1 to 12
+    pub fn hour12(&self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+
+    /// `14:30` or `2:30 PM`
+    pub fn format(&self, format: TimeFormat) -> String {
+        match format {
+            TimeFormat::Hour24 => self.to_string(),
+            TimeFormat::Hour12 => {
+                let period = if self.hour < 12 { "AM" } else { "PM" };
+                format!("{}:{:02} {}", self.hour12(), self.minute, period)
+            }
+        }
+    }
+
+    /// The time submitted by a [`TimePicker`] named `name`, from the
+    /// native input or the hour, minute and AM/PM selects
+    pub fn from_form(data: &FormData, name: &str) -> Result<Option<Self>, TimeParseError> {
+        if let Some(value) = data.get(name) {
+            return match value.trim() {
+                "" => Ok(None),
+                value => value.parse().map(Some),
+            };
+        }
+        let part = |part: &str| {
+            data.get(&format!("{}[{}]", name, part))
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+        };
+        match (part("hour"), part("minute")) {
+            (None, None) => Ok(None),
+            (Some(hour), Some(minute)) => {
+                let time = match part("period") {
+                    Some(period) => format!("{}:{} {}", hour, minute, period),
+                    None => format!("{}:{}", hour, minute),
+                };
+                time.parse().map(Some)
+            }
+            _ => Err(TimeParseError::Incomplete),
+        }
+    }
+}
+
+impl std::fmt::Display for Time {
+    /// `HH:MM`, the value format of `input[type="time"]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for Time {
+    type Err = TimeParseError;
+
+    /// Read `14:30`, `14:30:15`, `2:30 pm` or `2pm`; seconds are dropped
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase().replace('.', "");
+        let (clock, pm) = match (lower.strip_suffix("am"), lower.strip_suffix("pm")) {
+            (Some(clock), _) => (clock.trim_end(), Some(false)),
+            (_, Some(clock)) => (clock.trim_end(), Some(true)),
+            _ => (lower.as_str(), None),
+        };
+        let number = |part: &str, max_len: usize| {
+            let valid = !part.is_empty()
+                && part.len() <= max_len
+                && part.bytes().all(|b| b.is_ascii_digit());
+            valid
+                .then(|| part.parse::<u8>().ok())
+                .flatten()
+                .ok_or(TimeParseError::Invalid)
+        };
+
+        let mut parts = clock.split(':');
+        let hour = number(parts.next().unwrap_or(""), 2)?;
+        let minute = match parts.next() {
+            Some(minute) if minute.len() == 2 => number(minute, 2)?,
+            None if pm.is_some() => 0,
+            _ => return Err(TimeParseError::Invalid),
+        };
+        if let Some(seconds) = parts.next() {
+            if seconds.len() != 2 || number(seconds, 2)? > 59 || pm.is_some() {
+                return Err(TimeParseError::Invalid);
+            }
+        }
+        if parts.next().is_some() {
+            return Err(TimeParseError::Invalid);
+        }
+        let hour = match pm {
+            Some(_) if !(1..=12).contains(&hour) => return Err(TimeParseError::Invalid),
+            Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+            None => hour,
+        };
+        Time::new(hour, minute).ok_or(TimeParseError::Invalid)
+    }
+}
+
+/// A span of the day from `start` up to `end`; a range whose end is
+/// earlier than its start runs past midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeRange {
+    pub start: Time,
+    pub end: Time,
+}
+
+impl TimeRange {
+    pub fn new(start: Time, end: Time) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether the range runs past midnight
+    pub fn is_overnight(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// Whether `time` falls in the range; the end is excluded
+    pub fn contains(&self, time: Time) -> bool {
+        if self.is_overnight() {
+            time >= self.start || time < self.end
+        } else {
+            self.start <= time && time < self.end
+        }
+    }
+
+    /// Length of the range in minutes
+    pub fn duration_minutes(&self) -> u32 {
+        (self.end.minutes() + 24 * 60 - self.start.minutes()) % (24 * 60)
+    }
+
+    /// Start times every `step` minutes that fit in the range, e.g. for
+    /// booking slots
+    pub fn slots(&self, step: u32) -> Vec<Time> {
+        let step = step.max(1);
+        (0..self.duration_minutes())
+            .step_by(step as usize)
+            .filter(|offset| offset + step <= self.duration_minutes())
+            .map(|offset| Time::from_minutes(self.start.minutes() + offset))
+            .collect()
+    }
+
+    /// The range submitted by two [`TimePicker`]s
+    pub fn from_form(
+        data: &FormData,
+        start_name: &str,
+        end_name: &str,
+    ) -> Result<Option<Self>, TimeParseError> {
+        match (
+            Time::from_form(data, start_name)?,
+            Time::from_form(data, end_name)?,
+        ) {
+            (Some(start), Some(end)) => Ok(Some(Self::new(start, end))),
+            (None, None) => Ok(None),
+            _ => Err(TimeParseError::Incomplete),
+        }
+    }
+}
+
+impl std::fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl FromStr for TimeRange {
+    type Err = TimeParseError;
+
+    /// Read `09:00-17:30` or `9am - 5pm`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once(['-', '–']).ok_or(TimeParseError::Invalid)?;
+        Ok(Self::new(start.parse()?, end.parse()?))
+    }
+}
+
+/// Checks a submitted time parses and respects bounds and a minute step;
+/// empty values are left to the required validator
+#[derive(Debug, Clone, Default)]
+pub struct TimeValidator {
+    pub min: Option<Time>,
+    pub max: Option<Time>,
+    /// Minutes the time must be a multiple of, counted from `min`
+    pub minute_step: Option<u32>,
+    /// Clock used in messages
+    pub format: TimeFormat,
+}
+
+impl TimeValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(mut self, min: Time) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: Time) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn minute_step(mut self, minutes: u32) -> Self {
+        self.minute_step = Some(minutes.max(1));
+        self
+    }
+}
+
+impl Validator for TimeValidator {
+    fn validate(&self, value: &str, field_name: &str) -> Option<ValidationError> {
+        if value.trim().is_empty() {
+            return None;
+        }
+        let error =
+            |message: String, code: &str| Some(ValidationError::new(field_name, message, code));
+        let Ok(time) = value.parse::<Time>() else {
+            let example = Time {
+                hour: 14,
+                minute: 30,
+            }
+            .format(self.format);
+            return error(format!("Enter a time like {}", example), "time");
+        };
+        // A min after max is a range past midnight
+        let in_bounds = match (self.min, self.max) {
+            (Some(min), Some(max)) if min > max => time >= min || time <= max,
+            (min, max) => min.is_none_or(|m| time >= m) && max.is_none_or(|m| time <= m),
+        };
+        if !in_bounds {
+            let message = match (self.min, self.max) {
+                (Some(min), Some(max)) => format!(
+                    "Choose a time between {} and {}",
+                    min.format(self.format),
+                    max.format(self.format)
+                ),
+                (Some(min), None) => format!("Choose a time from {}", min.format(self.format)),
+                (None, Some(max)) => format!("Choose a time until {}", max.format(self.format)),
+                (None, None) => unreachable!(),
+            };
+            return error(message, "time_range");
+        }
+        if let Some(step) = self.minute_step {
+            let base = self.min.map_or(0, |m| m.minutes());
+            if !(time.minutes() + 24 * 60 - base).is_multiple_of(step) {
+                return error(
+                    format!("Choose a time in {}-minute steps", step),
+                    "time_step",
+                );
+            }
+        }
+        None
+    }
+}
+
 /// Generate time picker CSS
 pub fn time_picker_css() -> String {
     r#"
@@ -203,6 +777,56 @@ pub fn time_picker_css() -> String {
     box-shadow: 0 0 0 3px color-mix(in srgb, var(--sh-accent) 20%, transparent);
 }
 
+/* Select display */
+.sh-time-picker__fieldset {
+    display: flex;
+    flex-direction: column;
+    gap: 0.5rem;
+    margin: 0;
+    padding: 0;
+    border: none;
+    min-width: 0;
+}
+
+.sh-time-picker__fieldset .sh-time-picker__label {
+    padding: 0;
+}
+
+.sh-time-picker__selects {
+    display: flex;
+    align-items: center;
+    gap: 0.375rem;
+}
+
+.sh-time-picker__select,
+.sh-time-picker__timezone {
+    padding: 0.625rem 0.75rem;
+    font-size: 1rem;
+    font-family: inherit;
+    font-variant-numeric: tabular-nums;
+    color: var(--sh-text);
+    background: var(--sh-surface);
+    border: 1px solid var(--sh-border);
+    border-radius: var(--sh-radius-md);
+    cursor: pointer;
+}
+
+.sh-time-picker__select:focus,
+.sh-time-picker__timezone:focus {
+    outline: none;
+    border-color: var(--sh-accent);
+    box-shadow: 0 0 0 3px color-mix(in srgb, var(--sh-accent) 20%, transparent);
+}
+
+.sh-time-picker__colon {
+    font-weight: 600;
+    color: var(--sh-text-muted);
+}
+
+.sh-time-picker__timezone {
+    width: 100%;
+}
+
 /* Size variants */
 .sh-time-picker--sm .sh-time-picker__input {
     padding: 0.5rem 0.75rem;
@@ -257,6 +881,110 @@ mod tests {
         assert_eq!(picker.value, Some("14:30"));
     }
 
+    #[test]
+    fn test_time_parse_and_format() {
+        let time: Time = "14:05".parse().unwrap();
+        assert_eq!(time, Time::new(14, 5).unwrap());
+        assert_eq!(time.format(TimeFormat::Hour12), "2:05 PM");
+        assert_eq!("2:05 p.m.".parse::<Time>(), Ok(time));
+        assert_eq!("12am".parse::<Time>().unwrap().to_string(), "00:00");
+        assert_eq!("12:30 PM".parse::<Time>().unwrap().to_string(), "12:30");
+        assert_eq!("09:15:59".parse::<Time>().unwrap().to_string(), "09:15");
+        for bad in ["24:00", "9:5", "13pm", "0am", "", "12:00:00:00", "ab:cd"] {
+            assert_eq!(bad.parse::<Time>(), Err(TimeParseError::Invalid), "{}", bad);
+        }
+        assert_eq!(TimeFormat::for_locale("en-US"), TimeFormat::Hour12);
+        assert_eq!(TimeFormat::for_locale("en-GB"), TimeFormat::Hour24);
+        assert_eq!(TimeFormat::for_locale("ko_KR"), TimeFormat::Hour12);
+    }
+
+    #[test]
+    fn test_time_range() {
+        let shift: TimeRange = "9am - 5:30pm".parse().unwrap();
+        assert_eq!(shift.to_string(), "09:00-17:30");
+        assert_eq!(shift.duration_minutes(), 510);
+        assert!(shift.contains("17:29".parse().unwrap()));
+        assert!(!shift.contains("17:30".parse().unwrap()));
+
+        let night: TimeRange = "22:00-02:00".parse().unwrap();
+        assert!(night.is_overnight() && night.contains("01:00".parse().unwrap()));
+        let slots: Vec<String> = night.slots(90).iter().map(Time::to_string).collect();
+        assert_eq!(slots, ["22:00", "23:30"]);
+    }
+
+    #[test]
+    fn test_time_from_form() {
+        let native = FormData::parse_urlencoded("at=08%3A45");
+        assert_eq!(Time::from_form(&native, "at"), Ok(Time::new(8, 45)));
+        let selects =
+            FormData::parse_urlencoded("at%5Bhour%5D=12&at%5Bminute%5D=15&at%5Bperiod%5D=AM");
+        assert_eq!(Time::from_form(&selects, "at"), Ok(Time::new(0, 15)));
+        let partial = FormData::parse_urlencoded("at%5Bhour%5D=3&at%5Bminute%5D=");
+        assert_eq!(
+            Time::from_form(&partial, "at"),
+            Err(TimeParseError::Incomplete)
+        );
+        assert_eq!(Time::from_form(&FormData::new(), "at"), Ok(None));
+    }
+
+    #[test]
+    fn test_time_picker_selects_and_timezone() {
+        let html = TimePicker::new("start", "start")
+            .locale("en-US")
+            .display(TimeDisplay::Selects)
+            .minute_step(15)
+            .value("13:30")
+            .timezone("tz")
+            .timezone_value("America/New_York")
+            .render()
+            .into_string();
+        assert!(html.contains(r#"name="start[hour]""#));
+        assert!(html.contains(r#"<option value="1" selected>1</option>"#));
+        assert!(html.contains(r#"<option value="30" selected>30</option>"#));
+        assert!(!html.contains(r#"value="05""#));
+        assert!(html.contains(r#"<option value="PM" selected>PM</option>"#));
+        assert!(html.contains(r#"<optgroup label="America">"#));
+        assert!(html.contains(r#"<option value="America/New_York" selected>New York</option>"#));
+
+        let html = TimePicker::new("t", "t")
+            .display(TimeDisplay::Selects)
+            .min_time("09:00")
+            .max_time("17:00")
+            .minute_step(30)
+            .render()
+            .into_string();
+        assert!(!html.contains(r#"name="t[period]""#));
+        assert!(!html.contains(r#"<option value="08">"#));
+        assert!(html.contains(r#"<option value="17">"#));
+    }
+
+    #[test]
+    fn test_time_validator() {
+        let validator = TimePicker::new("t", "t")
+            .min_time("09:00")
+            .max_time("17:00")
+            .minute_step(30)
+            .format(TimeFormat::Hour12)
+            .validator();
+        assert!(validator.validate("10:30", "t").is_none());
+        assert!(validator.validate("", "t").is_none());
+        assert_eq!(
+            validator.validate("soon", "t").unwrap().message,
+            "Enter a time like 2:30 PM"
+        );
+        assert_eq!(
+            validator.validate("18:00", "t").unwrap().message,
+            "Choose a time between 9:00 AM and 5:00 PM"
+        );
+        assert_eq!(validator.validate("10:15", "t").unwrap().code, "time_step");
+
+        let overnight = TimeValidator::new()
+            .min(Time::new(22, 0).unwrap())
+            .max(Time::new(2, 0).unwrap());
+        assert!(overnight.validate("23:00", "t").is_none());
+        assert!(overnight.validate("12:00", "t").is_some());
+    }
+
     #[test]
     fn test_time_picker_step() {
         let picker = TimePicker::new("time", "time").step(900); // 15 minutes
@@ -1,4 +1,6 @@
+use crate::component::AriaLive;
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToastVariant {
@@ -8,26 +10,40 @@ pub enum ToastVariant {
     Error,
 }
 
+impl ToastVariant {
+    /// Warnings and errors interrupt the screen reader, the rest wait
+    pub fn live(self) -> AriaLive {
+        match self {
+            ToastVariant::Info | ToastVariant::Success => AriaLive::Polite,
+            ToastVariant::Warning | ToastVariant::Error => AriaLive::Assertive,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Toast<'a> {
-    pub title: &'a str,
-    pub message: &'a str,
+    pub title: Cow<'a, str>,
+    pub message: Cow<'a, str>,
     pub variant: ToastVariant,
-    pub close_href: Option<&'a str>,
+    pub close_href: Option<Cow<'a, str>>,
 }
 
 impl<'a> Toast<'a> {
-    pub fn new(title: &'a str, message: &'a str, variant: ToastVariant) -> Self {
+    pub fn new(
+        title: impl Into<Cow<'a, str>>,
+        message: impl Into<Cow<'a, str>>,
+        variant: ToastVariant,
+    ) -> Self {
         Self {
-            title,
-            message,
+            title: title.into(),
+            message: message.into(),
             variant,
             close_href: None,
         }
     }
 
-    pub fn close_href(mut self, href: &'a str) -> Self {
-        self.close_href = Some(href);
+    pub fn close_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.close_href = Some(href.into());
         self
     }
 }
@@ -48,8 +64,10 @@ impl<'a> Render for Toast<'a> {
             ToastVariant::Error => "var(--sh-error)",
         };
 
+        let live = self.variant.live();
+
         html! {
-            div class="sh-toast" role="alert" aria-live="polite" {
+            div class="sh-toast" role=[live.role()] aria-live=(live.as_str()) aria-atomic="true" {
                 div style=(format!("color: {}; display: flex", color)) {
                     img class="sh-icon" src=(format!("/icons/{}.svg", icon)) alt="" loading="lazy";
                 }
@@ -57,7 +75,7 @@ impl<'a> Render for Toast<'a> {
                     div style="font-weight: 600; font-size: 13px" { (self.title) }
                     div style="color: var(--sh-text-muted); font-size: 12px" { (self.message) }
                 }
-                @if let Some(href) = self.close_href {
+                @if let Some(href) = &self.close_href {
                     a href=(href) aria-label="Close" style="margin-left: auto; color: var(--sh-text-muted); display: flex" {
                         img class="sh-icon" style="width: 14px; height: 14px" src="/icons/close.svg" alt="" loading="lazy";
                     }
@@ -114,7 +132,7 @@ pub fn toast_css() -> String {
     background: var(--sh-surface, #fff);
     border: 1px solid var(--sh-border, #e5e7eb);
     border-radius: var(--sh-radius-lg, 0.5rem);
-    box-shadow: var(--sh-shadow-lg, 0 10px 40px rgba(0, 0, 0, 0.1));
+    box-shadow: var(--sh-elevation-3);
     min-width: 20rem;
     max-width: 24rem;
     animation: sh-toast-slide 0.3s ease;
@@ -164,5 +182,19 @@ mod tests {
         let css = toast_css();
         assert!(css.contains(".sh-toast"));
         assert!(css.contains(".sh-toast-container"));
+        assert!(css.contains("box-shadow: var(--sh-elevation-3);"));
+    }
+
+    #[test]
+    fn test_toast_live_region() {
+        let info = Toast::new("Saved", "All changes saved", ToastVariant::Success)
+            .render()
+            .into_string();
+        assert!(info.contains(r#"role="status" aria-live="polite""#));
+
+        let error = Toast::new("Failed", "Could not save", ToastVariant::Error)
+            .render()
+            .into_string();
+        assert!(error.contains(r#"role="alert" aria-live="assertive""#));
     }
 }
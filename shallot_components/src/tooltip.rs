@@ -1,15 +1,30 @@
 //! Tooltip Component
 //!
-//! CSS-only tooltips using hover states.
+//! CSS-only tooltips using hover states. With [`OverlayEngine::Native`] the
+//! bubble is placed with anchor positioning, scoped per tooltip through
+//! `anchor-scope`, so it is no longer clipped by `overflow: hidden` ancestors.
+//!
+//! # Example
+//! ```
+//! use maud::{html, Render};
+//! use shallot_components::tooltip::{Tooltip, TooltipPosition};
+//!
+//! let tooltip = Tooltip::new(html! { button { "Save" } }, "Saves a draft")
+//!     .position(TooltipPosition::Bottom)
+//!     .render();
+//! ```
 
+use crate::component::OverlayEngine;
 use maud::{html, Markup, Render};
+use std::borrow::Cow;
 
 pub struct Tooltip<'a> {
     content: Markup,
-    text: &'a str,
+    text: Cow<'a, str>,
     position: TooltipPosition,
     variant: TooltipVariant,
     delay: TooltipDelay,
+    engine: OverlayEngine,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -73,13 +88,14 @@ impl TooltipDelay {
 }
 
 impl<'a> Tooltip<'a> {
-    pub fn new(content: Markup, text: &'a str) -> Self {
+    pub fn new(content: Markup, text: impl Into<Cow<'a, str>>) -> Self {
         Self {
             content,
-            text,
+            text: text.into(),
             position: TooltipPosition::Top,
             variant: TooltipVariant::Default,
             delay: TooltipDelay::Fast,
+            engine: OverlayEngine::Css,
         }
     }
 
@@ -97,15 +113,21 @@ impl<'a> Tooltip<'a> {
         self.delay = delay;
         self
     }
+
+    pub fn engine(mut self, engine: OverlayEngine) -> Self {
+        self.engine = engine;
+        self
+    }
 }
 
 impl<'a> Render for Tooltip<'a> {
     fn render(&self) -> Markup {
         let classes = format!(
-            "sh-tooltip {} {} {}",
+            "sh-tooltip {} {} {} {}",
             self.position.class(),
             self.variant.class(),
-            self.delay.class()
+            self.delay.class(),
+            self.engine.class().unwrap_or("")
         );
 
         html! {
@@ -122,17 +144,19 @@ impl<'a> Render for Tooltip<'a> {
 }
 
 pub struct IconTooltip<'a> {
-    icon: &'a str,
-    text: &'a str,
+    icon: Cow<'a, str>,
+    text: Cow<'a, str>,
     position: TooltipPosition,
+    engine: OverlayEngine,
 }
 
 impl<'a> IconTooltip<'a> {
-    pub fn new(icon: &'a str, text: &'a str) -> Self {
+    pub fn new(icon: impl Into<Cow<'a, str>>, text: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            icon,
-            text,
+            icon: icon.into(),
+            text: text.into(),
             position: TooltipPosition::Top,
+            engine: OverlayEngine::Css,
         }
     }
 
@@ -140,36 +164,48 @@ impl<'a> IconTooltip<'a> {
         self.position = position;
         self
     }
+
+    pub fn engine(mut self, engine: OverlayEngine) -> Self {
+        self.engine = engine;
+        self
+    }
 }
 
 impl<'a> Render for IconTooltip<'a> {
     fn render(&self) -> Markup {
         let icon_html = html! {
             span class="sh-tooltip__icon" {
-                (maud::PreEscaped(self.icon))
+                (maud::PreEscaped(self.icon.as_ref()))
             }
         };
 
-        Tooltip::new(icon_html, self.text)
+        Tooltip::new(icon_html, self.text.as_ref())
             .position(self.position)
+            .engine(self.engine)
             .render()
     }
 }
 
 pub struct RichTooltip<'a> {
     content: Markup,
-    title: &'a str,
-    description: &'a str,
+    title: Cow<'a, str>,
+    description: Cow<'a, str>,
     position: TooltipPosition,
+    engine: OverlayEngine,
 }
 
 impl<'a> RichTooltip<'a> {
-    pub fn new(content: Markup, title: &'a str, description: &'a str) -> Self {
+    pub fn new(
+        content: Markup,
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             content,
-            title,
-            description,
+            title: title.into(),
+            description: description.into(),
             position: TooltipPosition::Top,
+            engine: OverlayEngine::Css,
         }
     }
 
@@ -177,14 +213,23 @@ impl<'a> RichTooltip<'a> {
         self.position = position;
         self
     }
+
+    pub fn engine(mut self, engine: OverlayEngine) -> Self {
+        self.engine = engine;
+        self
+    }
 }
 
 impl<'a> Render for RichTooltip<'a> {
     fn render(&self) -> Markup {
-        let position_class = self.position.class();
+        let classes = format!(
+            "sh-tooltip sh-tooltip--rich {} {}",
+            self.position.class(),
+            self.engine.class().unwrap_or("")
+        );
 
         html! {
-            span class={(format!("sh-tooltip sh-tooltip--rich {}", position_class))} {
+            span class=(classes.trim_end()) {
                 span class="sh-tooltip__trigger" {
                     (self.content.clone())
                 }
@@ -364,6 +409,47 @@ pub fn tooltip_css() -> String {
     opacity: 0.9;
 }
 
+/* Native engine: anchor positioning, falls back to the rules above */
+@supports (anchor-name: --sh-anchor) and (anchor-scope: all) {
+    .sh-tooltip.sh-overlay--native {
+        anchor-scope: --sh-tooltip;
+    }
+
+    .sh-overlay--native .sh-tooltip__trigger {
+        anchor-name: --sh-tooltip;
+    }
+
+    .sh-overlay--native .sh-tooltip__content {
+        position: fixed;
+        position-anchor: --sh-tooltip;
+        inset: auto;
+        margin: 0.375rem;
+        transform: none;
+        position-try-fallbacks: flip-block, flip-inline;
+    }
+
+    .sh-tooltip--top.sh-overlay--native .sh-tooltip__content {
+        position-area: block-start;
+    }
+
+    .sh-tooltip--bottom.sh-overlay--native .sh-tooltip__content {
+        position-area: block-end;
+    }
+
+    .sh-tooltip--left.sh-overlay--native .sh-tooltip__content {
+        position-area: center inline-start;
+    }
+
+    .sh-tooltip--right.sh-overlay--native .sh-tooltip__content {
+        position-area: center inline-end;
+    }
+
+    /* Flip fallbacks would leave the arrow on the wrong side */
+    .sh-overlay--native .sh-tooltip__content::after {
+        display: none;
+    }
+}
+
 /* Icon in trigger */
 .sh-tooltip__icon {
     display: inline-flex;
@@ -400,4 +486,37 @@ mod tests {
         assert_eq!(tooltip.title, "Title");
         assert_eq!(tooltip.description, "Description");
     }
+
+    #[test]
+    fn test_tooltip_native_engine() {
+        let html_str = Tooltip::new(html! { "?" }, "Help")
+            .engine(OverlayEngine::Native)
+            .render()
+            .into_string();
+        assert!(html_str.contains("sh-overlay--native"));
+        assert!(html_str.contains("role=\"tooltip\""));
+
+        let icon = IconTooltip::new("<svg></svg>", "Info")
+            .engine(OverlayEngine::Native)
+            .render()
+            .into_string();
+        assert!(icon.contains("sh-overlay--native"));
+
+        let rich = RichTooltip::new(html! { "?" }, "Title", "Body")
+            .engine(OverlayEngine::Native)
+            .render()
+            .into_string();
+        assert!(rich.contains("sh-tooltip sh-tooltip--rich sh-tooltip--top sh-overlay--native"));
+
+        let css_engine = RichTooltip::new(html! {}, "T", "D").render().into_string();
+        assert!(css_engine.contains("class=\"sh-tooltip sh-tooltip--rich sh-tooltip--top\""));
+    }
+
+    #[test]
+    fn test_tooltip_css_native_fallback() {
+        let css = tooltip_css();
+        assert!(css.contains("@supports (anchor-name: --sh-anchor) and (anchor-scope: all)"));
+        assert!(css.contains("anchor-scope: --sh-tooltip"));
+        assert!(css.contains("position-anchor: --sh-tooltip"));
+    }
 }
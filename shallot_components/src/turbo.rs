@@ -0,0 +1,202 @@
+//! Turbo - frame and stream helpers for Hotwire stacks (`turbo` feature)
+//!
+//! [`TurboFrame`] wraps a region of the page in a `<turbo-frame>` so links
+//! and forms inside it replace only that region. [`TurboStream`] builds the
+//! `<turbo-stream>` actions a server sends back to append, replace or
+//! remove elements by id. With the feature, [`crate::button::Button`] and
+//! [`crate::form::Form`] also gain `turbo_frame` to aim a link or form at a
+//! frame elsewhere on the page.
+//!
+//! Everything degrades to plain links and forms when Turbo is not loaded.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let frame = TurboFrame::new("comments", html! { "Loading…" })
+//!     .src("/posts/1/comments")
+//!     .lazy(true);
+//! let update = TurboStream::append("comments", html! { li { "New comment" } });
+//! ```
+
+use maud::{html, Markup, Render};
+use std::borrow::Cow;
+
+/// Content type of a stream response
+pub const TURBO_STREAM_MIME: &str = "text/vnd.turbo-stream.html";
+
+/// A `<turbo-frame>` region replaced independently of the page
+#[derive(Debug, Clone)]
+pub struct TurboFrame<'a> {
+    pub id: Cow<'a, str>,
+    pub content: Markup,
+    pub src: Option<Cow<'a, str>>,
+    pub lazy: bool,
+    pub target: Option<Cow<'a, str>>,
+}
+
+impl<'a> TurboFrame<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self {
+            id: id.into(),
+            content,
+            src: None,
+            lazy: false,
+            target: None,
+        }
+    }
+
+    /// Load the frame's content from `url`; `content` shows until it arrives
+    pub fn src(mut self, url: impl Into<Cow<'a, str>>) -> Self {
+        self.src = Some(url.into());
+        self
+    }
+
+    /// Wait to load `src` until the frame scrolls into view
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Frame that links inside this one navigate; `_top` for the whole page
+    pub fn target(mut self, target: impl Into<Cow<'a, str>>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+}
+
+impl<'a> Render for TurboFrame<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            turbo-frame
+                id=(self.id)
+                src=[self.src.as_deref()]
+                loading=[self.lazy.then_some("lazy")]
+                target=[self.target.as_deref()]
+            {
+                (self.content)
+            }
+        }
+    }
+}
+
+/// What a stream does to its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurboStreamAction {
+    Append,
+    Prepend,
+    Replace,
+    Update,
+    Remove,
+    Before,
+    After,
+}
+
+impl TurboStreamAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TurboStreamAction::Append => "append",
+            TurboStreamAction::Prepend => "prepend",
+            TurboStreamAction::Replace => "replace",
+            TurboStreamAction::Update => "update",
+            TurboStreamAction::Remove => "remove",
+            TurboStreamAction::Before => "before",
+            TurboStreamAction::After => "after",
+        }
+    }
+}
+
+/// One `<turbo-stream>` action on the element with id `target`
+#[derive(Debug, Clone)]
+pub struct TurboStream<'a> {
+    pub action: TurboStreamAction,
+    pub target: Cow<'a, str>,
+    pub content: Markup,
+}
+
+impl<'a> TurboStream<'a> {
+    pub fn new(
+        action: TurboStreamAction,
+        target: impl Into<Cow<'a, str>>,
+        content: Markup,
+    ) -> Self {
+        Self {
+            action,
+            target: target.into(),
+            content,
+        }
+    }
+
+    /// Add `content` as the target's last child
+    pub fn append(target: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self::new(TurboStreamAction::Append, target, content)
+    }
+
+    /// Add `content` as the target's first child
+    pub fn prepend(target: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self::new(TurboStreamAction::Prepend, target, content)
+    }
+
+    /// Swap the whole target element for `content`
+    pub fn replace(target: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self::new(TurboStreamAction::Replace, target, content)
+    }
+
+    /// Replace the target's children with `content`
+    pub fn update(target: impl Into<Cow<'a, str>>, content: Markup) -> Self {
+        Self::new(TurboStreamAction::Update, target, content)
+    }
+
+    /// Delete the target element
+    pub fn remove(target: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(TurboStreamAction::Remove, target, html! {})
+    }
+}
+
+impl<'a> Render for TurboStream<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            turbo-stream action=(self.action.as_str()) target=(self.target) {
+                @if self.action != TurboStreamAction::Remove {
+                    template { (self.content) }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turbo_frame() {
+        let html = TurboFrame::new("comments", html! { "Loading" })
+            .src("/posts/1/comments")
+            .lazy(true)
+            .target("_top")
+            .render()
+            .into_string();
+        assert_eq!(
+            html,
+            r#"<turbo-frame id="comments" src="/posts/1/comments" loading="lazy" target="_top">Loading</turbo-frame>"#
+        );
+    }
+
+    #[test]
+    fn test_turbo_streams() {
+        let html = TurboStream::append("comments", html! { li { "Hi" } })
+            .render()
+            .into_string();
+        assert_eq!(
+            html,
+            r#"<turbo-stream action="append" target="comments"><template><li>Hi</li></template></turbo-stream>"#
+        );
+
+        let html = TurboStream::remove("comment-3").render().into_string();
+        assert_eq!(
+            html,
+            r#"<turbo-stream action="remove" target="comment-3"></turbo-stream>"#
+        );
+        assert_eq!(TurboStreamAction::Update.as_str(), "update");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::motion::{EffectCss, MotionConfig};
 use maud::{html, Markup, Render};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -116,7 +117,13 @@ impl Render for TypingAnimation {
 }
 
 pub fn typing_animation_css() -> String {
-    r#"
+    typing_animation_css_for(MotionConfig::default())
+}
+
+/// TypingAnimation CSS at the given motion level
+pub fn typing_animation_css_for(motion: MotionConfig) -> String {
+    motion.effect_css(EffectCss {
+        animated: r#"
 .sh-typing {
     --sh-typing-len: 10;
     --sh-typing-dur: 2s;
@@ -217,8 +224,27 @@ pub fn typing_animation_css() -> String {
         opacity: 0;
     }
 }
-"#
-    .to_string()
+"#,
+        still: r#"
+.sh-typing__text,
+.sh-typing--fade .sh-typing__text,
+.sh-typing--loop .sh-typing__text {
+    animation: none;
+    width: auto;
+}
+
+.sh-typing__cursor::after,
+.sh-typing--cursor .sh-typing__cursor::after,
+.sh-typing--blinking .sh-typing__cursor::after {
+    animation: none;
+}
+"#,
+        off: r#"
+.sh-typing__cursor {
+    display: none;
+}
+"#,
+    })
 }
 
 #[cfg(test)]
@@ -310,4 +336,15 @@ mod tests {
         assert!(css.contains(".sh-typing"));
         assert!(css.contains("@keyframes typing-text"));
     }
+
+    #[test]
+    fn test_typing_animation_motion() {
+        let css = typing_animation_css_for(MotionConfig::Reduced);
+        assert!(!css.contains("@keyframes"));
+        assert!(css.contains("width: auto;"));
+        assert!(!css.contains(".sh-typing__cursor {\n    display: none;"));
+
+        let css = typing_animation_css_for(MotionConfig::None);
+        assert!(css.contains(".sh-typing__cursor {\n    display: none;"));
+    }
 }
@@ -9,42 +9,88 @@
 //!
 //! All components ensure semantic HTML output for accessibility and SEO.
 
+use crate::fonts::FluidSize;
+use crate::view_transitions::view_transition_style;
 use maud::{html, Markup};
+use shallot_foundation::ClassList;
 
 /// Text size variants
+///
+/// Sizes resolve to the `--sh-font-size-*` / `--sh-line-height-*` custom
+/// properties of the design tokens' [`TypographyScale`], falling back to
+/// its default steps when the tokens are not loaded.
+///
+/// [`TypographyScale`]: shallot_foundation::TypographyScale
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextSize {
-    Xs,   // 12px
-    Sm,   // 14px
-    Md,   // 16px (base)
-    Lg,   // 18px
-    Xl,   // 20px
-    Xxl,  // 24px
-    Xxxl, // 30px
+    Xs,
+    Sm,
+    Md, // base
+    Lg,
+    Xl,
+    Xxl,
+    Xxxl,
 }
 
 impl TextSize {
+    /// Every size, smallest first
+    pub const ALL: [TextSize; 7] = [
+        Self::Xs,
+        Self::Sm,
+        Self::Md,
+        Self::Lg,
+        Self::Xl,
+        Self::Xxl,
+        Self::Xxxl,
+    ];
+
+    /// Steps away from `Md` on the type scale
+    pub const fn step(&self) -> i32 {
+        match self {
+            Self::Xs => -2,
+            Self::Sm => -1,
+            Self::Md => 0,
+            Self::Lg => 1,
+            Self::Xl => 2,
+            Self::Xxl => 3,
+            Self::Xxxl => 4,
+        }
+    }
+
+    /// Suffix of the size's custom properties
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Xs => "xs",
+            Self::Sm => "sm",
+            Self::Md => "md",
+            Self::Lg => "lg",
+            Self::Xl => "xl",
+            Self::Xxl => "2xl",
+            Self::Xxxl => "3xl",
+        }
+    }
+
     pub const fn css_value(&self) -> &'static str {
         match self {
-            Self::Xs => "0.75rem",
-            Self::Sm => "0.875rem",
-            Self::Md => "1rem",
-            Self::Lg => "1.125rem",
-            Self::Xl => "1.25rem",
-            Self::Xxl => "1.5rem",
-            Self::Xxxl => "1.875rem",
+            Self::Xs => "var(--sh-font-size-xs, 0.75rem)",
+            Self::Sm => "var(--sh-font-size-sm, 0.875rem)",
+            Self::Md => "var(--sh-font-size-md, 1rem)",
+            Self::Lg => "var(--sh-font-size-lg, 1.125rem)",
+            Self::Xl => "var(--sh-font-size-xl, 1.25rem)",
+            Self::Xxl => "var(--sh-font-size-2xl, 1.5rem)",
+            Self::Xxxl => "var(--sh-font-size-3xl, 1.875rem)",
         }
     }
 
     pub const fn line_height(&self) -> &'static str {
         match self {
-            Self::Xs => "1rem",
-            Self::Sm => "1.25rem",
-            Self::Md => "1.5rem",
-            Self::Lg => "1.75rem",
-            Self::Xl => "1.75rem",
-            Self::Xxl => "2rem",
-            Self::Xxxl => "2.25rem",
+            Self::Xs => "var(--sh-line-height-xs, 1rem)",
+            Self::Sm => "var(--sh-line-height-sm, 1.25rem)",
+            Self::Md => "var(--sh-line-height-md, 1.5rem)",
+            Self::Lg => "var(--sh-line-height-lg, 1.75rem)",
+            Self::Xl => "var(--sh-line-height-xl, 1.75rem)",
+            Self::Xxl => "var(--sh-line-height-2xl, 2rem)",
+            Self::Xxxl => "var(--sh-line-height-3xl, 2.25rem)",
         }
     }
 }
@@ -193,11 +239,20 @@ impl FontFamily {
     }
 }
 
+/// `font-size` and `line-height` for a step, or for a fluid size when set
+fn font_size_styles(size: TextSize, fluid: Option<&FluidSize>) -> (String, String) {
+    match fluid {
+        Some(fluid) => (fluid.css_value(), fluid.leading.to_string()),
+        None => (size.css_value().to_string(), size.line_height().to_string()),
+    }
+}
+
 /// Text component - Semantic text spans with comprehensive styling
 #[derive(Debug, Clone)]
 pub struct Text {
     content: String,
     size: TextSize,
+    fluid: Option<FluidSize>,
     weight: FontWeight,
     color: TextColor,
     align: TextAlign,
@@ -220,6 +275,7 @@ impl Text {
         Self {
             content: content.into(),
             size: TextSize::Md,
+            fluid: None,
             weight: FontWeight::Normal,
             color: TextColor::Default,
             align: TextAlign::Start,
@@ -243,6 +299,12 @@ impl Text {
         self
     }
 
+    /// Scale the font size with the viewport instead of using a fixed step
+    pub fn fluid(mut self, fluid: FluidSize) -> Self {
+        self.fluid = Some(fluid);
+        self
+    }
+
     pub fn weight(mut self, weight: FontWeight) -> Self {
         self.weight = weight;
         self
@@ -321,10 +383,11 @@ impl Text {
 
     /// Build CSS styles
     fn build_styles(&self) -> String {
+        let (font_size, line_height) = font_size_styles(self.size, self.fluid.as_ref());
         let mut styles = format!(
             "font-size:{};line-height:{};font-weight:{};color:{};text-align:{};text-transform:{};text-decoration:{};font-family:{};",
-            self.size.css_value(),
-            self.size.line_height(),
+            font_size,
+            line_height,
             self.weight.css_value(),
             self.color.css_value(),
             self.align.css_value(),
@@ -357,11 +420,9 @@ impl Text {
 
     /// Build class attribute
     fn build_class(&self) -> String {
-        let mut classes = vec!["sh-text".to_string()];
-        if let Some(ref c) = self.class {
-            classes.push(c.clone());
-        }
-        classes.join(" ")
+        ClassList::new("sh-text")
+            .push_opt(self.class.as_deref())
+            .build()
     }
 
     /// Render the text
@@ -404,6 +465,7 @@ pub struct Heading {
     level: u8, // 1-6
     content: String,
     visual_size: Option<TextSize>,
+    fluid: Option<FluidSize>,
     weight: FontWeight,
     color: TextColor,
     align: TextAlign,
@@ -412,6 +474,7 @@ pub struct Heading {
     class: Option<String>,
     margin_top: Option<&'static str>,
     margin_bottom: Option<&'static str>,
+    view_transition_name: Option<String>,
 }
 
 impl Heading {
@@ -421,6 +484,7 @@ impl Heading {
             level,
             content: content.into(),
             visual_size: None,
+            fluid: None,
             weight: FontWeight::Bold,
             color: TextColor::Default,
             align: TextAlign::Start,
@@ -429,6 +493,7 @@ impl Heading {
             class: None,
             margin_top: None,
             margin_bottom: None,
+            view_transition_name: None,
         }
     }
 
@@ -462,6 +527,12 @@ impl Heading {
         self
     }
 
+    /// Scale the font size with the viewport instead of using a fixed step
+    pub fn fluid(mut self, fluid: FluidSize) -> Self {
+        self.fluid = Some(fluid);
+        self
+    }
+
     pub fn weight(mut self, weight: FontWeight) -> Self {
         self.weight = weight;
         self
@@ -503,6 +574,12 @@ impl Heading {
         self
     }
 
+    /// Name the heading for cross-page view transitions
+    pub fn view_transition_name(mut self, name: impl Into<String>) -> Self {
+        self.view_transition_name = Some(name.into());
+        self
+    }
+
     /// Generate id from content if not provided
     fn generate_id(&self) -> String {
         self.id.clone().unwrap_or_else(|| {
@@ -530,10 +607,11 @@ impl Heading {
         let id = self.generate_id();
         let element = format!("h{}", self.level);
 
+        let (font_size, line_height) = font_size_styles(size, self.fluid.as_ref());
         let mut styles = format!(
             "font-size:{};line-height:{};font-weight:{};color:{};text-align:{};",
-            size.css_value(),
-            size.line_height(),
+            font_size,
+            line_height,
             self.weight.css_value(),
             self.color.css_value(),
             self.align.css_value()
@@ -545,12 +623,14 @@ impl Heading {
         if let Some(m) = self.margin_bottom {
             styles.push_str(&format!("margin-bottom:{};", m));
         }
-
-        let mut classes = vec![format!("sh-heading"), format!("sh-heading-{}", self.level)];
-        if let Some(ref c) = self.class {
-            classes.push(c.clone());
+        if let Some(name) = &self.view_transition_name {
+            styles.push_str(&view_transition_style(name));
         }
-        let class = classes.join(" ");
+
+        let class = ClassList::new("sh-heading")
+            .push_fmt(format_args!("sh-heading-{}", self.level))
+            .push_opt(self.class.as_deref())
+            .build();
 
         let anchor_link = if self.anchor {
             html! {
@@ -906,11 +986,9 @@ impl List {
             ListVariant::Description => ("dl", "sh-list sh-list--description"),
         };
 
-        let mut classes = vec![class.to_string()];
-        if let Some(ref c) = self.class {
-            classes.push(c.clone());
-        }
-        let class = classes.join(" ");
+        let class = ClassList::new(class)
+            .push_opt(self.class.as_deref())
+            .build();
 
         let styles = self.build_styles().unwrap_or_default();
 
@@ -1171,9 +1249,30 @@ mod tests {
 
     #[test]
     fn test_text_size_values() {
-        assert_eq!(TextSize::Xs.css_value(), "0.75rem");
-        assert_eq!(TextSize::Md.css_value(), "1rem");
-        assert_eq!(TextSize::Xxxl.css_value(), "1.875rem");
+        assert_eq!(TextSize::Xs.css_value(), "var(--sh-font-size-xs, 0.75rem)");
+        assert_eq!(
+            TextSize::Md.line_height(),
+            "var(--sh-line-height-md, 1.5rem)"
+        );
+        assert_eq!(TextSize::Xxxl.name(), "3xl");
+
+        // Fallbacks are the design tokens' default steps
+        let steps = shallot_foundation::DEFAULT_TEXT_STEPS;
+        for (size, step) in TextSize::ALL.iter().zip(steps) {
+            assert_eq!(
+                size.css_value(),
+                format!("var(--sh-font-size-{}, {}rem)", size.name(), step.size_rem)
+            );
+            assert_eq!(
+                size.line_height(),
+                format!(
+                    "var(--sh-line-height-{}, {}rem)",
+                    size.name(),
+                    step.line_height_rem
+                )
+            );
+        }
+        assert!(!typography_css().contains(":root"));
     }
 
     #[test]
@@ -1181,4 +1280,30 @@ mod tests {
         assert_eq!(FontWeight::Normal.css_value(), "400");
         assert_eq!(FontWeight::Bold.css_value(), "700");
     }
+
+    #[test]
+    fn test_fluid_heading() {
+        let html = Heading::h1("Fluid")
+            .fluid(FluidSize::new(2.0, 3.5))
+            .render()
+            .into_string();
+        assert!(html.contains("font-size:clamp(2rem, 1.5rem + 2.5vw, 3.5rem);line-height:1.25;"));
+
+        let html = Text::new("Lead")
+            .size(TextSize::Lg)
+            .fluid(FluidSize::new(1.0, 1.25).leading(1.5))
+            .render()
+            .into_string();
+        assert!(html.contains("line-height:1.5;"));
+        assert!(!html.contains("--sh-font-size-lg"));
+    }
+
+    #[test]
+    fn test_heading_view_transition_name() {
+        let html = Heading::h1("Lamp")
+            .view_transition_name("Page Title")
+            .render()
+            .into_string();
+        assert!(html.contains("view-transition-name: page-title;"));
+    }
 }
@@ -0,0 +1,222 @@
+//! View Transitions - Animated cross-page navigation without JavaScript
+//!
+//! Multi-page apps opt in to the cross-document View Transitions API with a
+//! single `@view-transition { navigation: auto; }` rule. When both the old and
+//! the new page include it, same-origin navigations crossfade instead of
+//! flashing, and elements that carry the same `view-transition-name` on both
+//! pages morph from one position to the other.
+//!
+//! The rules are not part of [`crate::all_component_css`], because turning
+//! transitions on is a site-wide decision. Include [`view_transitions_css`]
+//! (or a configured [`ViewTransitions`]) in every page that should take part.
+//!
+//! # Patterns
+//!
+//! - **List to detail**: give the product card on the list page and the hero
+//!   image on the detail page the same name, e.g. `product-42`.
+//! - **Persistent chrome**: name the navbar or sidebar so it stays in place
+//!   while the page content crossfades around it.
+//! - **Page titles**: name the main heading so it glides into its new spot.
+//!
+//! A name may appear on at most one element per page; duplicates make the
+//! browser skip the transition. Visitors who prefer reduced motion get plain
+//! navigations.
+//!
+//! ```
+//! use shallot_components::view_transitions::{transition_name, view_transitions_css};
+//! use shallot_components::Card;
+//! use maud::{html, Render};
+//!
+//! let css = view_transitions_css();
+//! assert!(css.contains("@view-transition"));
+//!
+//! let card = Card::new(html! { "Lamp" }).view_transition_name(transition_name("product 42"));
+//! assert!(card.render().into_string().contains("view-transition-name: product-42;"));
+//! ```
+
+/// Animation applied to the page as a whole (the `root` transition group)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RootTransition {
+    /// Browser default crossfade
+    #[default]
+    Crossfade,
+    /// Old page slides out to the left while the new page slides in
+    Slide,
+    /// Page swaps instantly; only named elements animate
+    None,
+}
+
+/// Configurable cross-document view transition stylesheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewTransitions {
+    pub root: RootTransition,
+    pub duration_ms: u16,
+}
+
+impl Default for ViewTransitions {
+    fn default() -> Self {
+        Self {
+            root: RootTransition::default(),
+            duration_ms: 250,
+        }
+    }
+}
+
+impl ViewTransitions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn root(mut self, root: RootTransition) -> Self {
+        self.root = root;
+        self
+    }
+
+    pub fn duration(mut self, duration_ms: u16) -> Self {
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    /// Build the stylesheet
+    pub fn css(&self) -> String {
+        let root = match self.root {
+            RootTransition::Crossfade => "",
+            RootTransition::Slide => {
+                r#"
+    ::view-transition-old(root) {
+        animation: sh-vt-slide-out var(--sh-vt-duration) ease both;
+    }
+
+    ::view-transition-new(root) {
+        animation: sh-vt-slide-in var(--sh-vt-duration) ease both;
+    }
+
+    @keyframes sh-vt-slide-out {
+        to { opacity: 0; transform: translateX(-2rem); }
+    }
+
+    @keyframes sh-vt-slide-in {
+        from { opacity: 0; transform: translateX(2rem); }
+    }
+"#
+            }
+            RootTransition::None => {
+                r#"
+    ::view-transition-old(root),
+    ::view-transition-new(root) {
+        animation: none;
+    }
+"#
+            }
+        };
+
+        format!(
+            r#"
+/* View Transitions */
+@media (prefers-reduced-motion: no-preference) {{
+    @view-transition {{
+        navigation: auto;
+    }}
+
+    :root {{
+        --sh-vt-duration: {duration}ms;
+    }}
+
+    ::view-transition-group(*) {{
+        animation-duration: var(--sh-vt-duration);
+    }}
+{root}}}
+"#,
+            duration = self.duration_ms,
+            root = root,
+        )
+    }
+}
+
+/// Stylesheet enabling cross-document view transitions with default settings
+pub fn view_transitions_css() -> String {
+    ViewTransitions::new().css()
+}
+
+/// Turn arbitrary text into a valid `view-transition-name` identifier.
+///
+/// Letters and digits are lowercased and kept, everything else collapses
+/// into single dashes, and names that would start with a digit or clash with
+/// the `none`/`auto` keywords get a `vt-` prefix.
+pub fn transition_name(raw: &str) -> String {
+    let mut name = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('-') {
+            name.push('-');
+        }
+    }
+    let name = name.trim_end_matches('-');
+
+    let needs_prefix = name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || matches!(name, "none" | "auto" | "match-element");
+    if needs_prefix {
+        format!("vt-{}", name).trim_end_matches('-').to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Inline style declaring an element's `view-transition-name`
+pub fn view_transition_style(name: &str) -> String {
+    format!("view-transition-name: {};", transition_name(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_css() {
+        let css = view_transitions_css();
+        assert!(css.contains("@view-transition {\n        navigation: auto;"));
+        assert!(css.contains("--sh-vt-duration: 250ms;"));
+        assert!(css.contains("prefers-reduced-motion: no-preference"));
+        assert!(!css.contains("sh-vt-slide"));
+    }
+
+    #[test]
+    fn test_root_transitions() {
+        let slide = ViewTransitions::new()
+            .root(RootTransition::Slide)
+            .duration(400)
+            .css();
+        assert!(slide.contains("@keyframes sh-vt-slide-in"));
+        assert!(slide.contains("--sh-vt-duration: 400ms;"));
+
+        let none = ViewTransitions::new().root(RootTransition::None).css();
+        assert!(none.contains("::view-transition-new(root) {\n        animation: none;"));
+    }
+
+    #[test]
+    fn test_css_braces_balanced() {
+        for root in [
+            RootTransition::Crossfade,
+            RootTransition::Slide,
+            RootTransition::None,
+        ] {
+            let css = ViewTransitions::new().root(root).css();
+            assert_eq!(css.matches('{').count(), css.matches('}').count());
+        }
+    }
+
+    #[test]
+    fn test_transition_name() {
+        assert_eq!(transition_name("Product 42"), "product-42");
+        assert_eq!(transition_name("  hero--image! "), "hero-image");
+        assert_eq!(transition_name("42"), "vt-42");
+        assert_eq!(transition_name("none"), "vt-none");
+        assert_eq!(transition_name("!!"), "vt");
+        assert_eq!(
+            view_transition_style("Main Title"),
+            "view-transition-name: main-title;"
+        );
+    }
+}
@@ -19,7 +19,7 @@ impl<'a> VisuallyHidden<'a> {
         }
     }
 
-    /// Make the element visible when focused (for skip links)
+    /// Make the element visible while it or its contents have focus
     pub fn focusable(mut self, focusable: bool) -> Self {
         self.focusable = focusable;
         self
@@ -35,10 +35,7 @@ impl<'a> Render for VisuallyHidden<'a> {
         };
 
         html! {
-            span
-                class=(class)
-                role="text"
-            {
+            span class=(class) {
                 (self.content)
             }
         }
@@ -46,6 +43,9 @@ impl<'a> Render for VisuallyHidden<'a> {
 }
 
 /// Skip link component for keyboard navigation
+///
+/// A single link; [`SkipLinks`](crate::focus::SkipLinks) renders a
+/// labelled list of them.
 #[derive(Debug, Clone)]
 pub struct SkipLink<'a> {
     pub target: &'a str,
@@ -87,7 +87,8 @@ pub fn visually_hidden_css() -> String {
 }
 
 .sh-visually-hidden--focusable:active,
-.sh-visually-hidden--focusable:focus {
+.sh-visually-hidden--focusable:focus,
+.sh-visually-hidden--focusable:focus-within {
     position: static;
     width: auto;
     height: auto;
@@ -0,0 +1,83 @@
+//! Class building benchmarks
+//!
+//! Compares the `Vec<String>` + `format!` + `join` pattern most components used
+//! to build their class attribute against [`ClassList`], which writes every
+//! class into a single buffer.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use shallot_foundation::{ClassBuilder, ClassList};
+
+struct Props {
+    variant: &'static str,
+    size: &'static str,
+    elevation: &'static str,
+    clickable: bool,
+    divided: bool,
+    custom: Option<&'static str>,
+}
+
+const PROPS: Props = Props {
+    variant: "outlined",
+    size: "lg",
+    elevation: "high",
+    clickable: true,
+    divided: false,
+    custom: Some("my-card"),
+};
+
+fn vec_join(p: &Props) -> String {
+    let mut classes = vec!["sh-card".to_string()];
+    classes.push(format!("sh-card--{}", p.variant));
+    classes.push(format!("sh-card--{}", p.size));
+    classes.push(format!("sh-card--elevation-{}", p.elevation));
+    if p.clickable {
+        classes.push("sh-card--clickable".to_string());
+    }
+    if p.divided {
+        classes.push("sh-card--divided".to_string());
+    }
+    if let Some(custom) = p.custom {
+        classes.push(custom.to_string());
+    }
+    classes.join(" ")
+}
+
+fn class_builder(p: &Props) -> String {
+    ClassBuilder::new()
+        .add("sh-card")
+        .add_with_value("sh-card", Some(p.variant))
+        .add_with_value("sh-card", Some(p.size))
+        .add(format!("sh-card--elevation-{}", p.elevation))
+        .add_if("sh-card--clickable", p.clickable)
+        .add_if("sh-card--divided", p.divided)
+        .add_many(p.custom.into_iter().collect())
+        .build()
+}
+
+fn class_list(p: &Props) -> String {
+    ClassList::new("sh-card")
+        .modifier("sh-card", p.variant)
+        .modifier("sh-card", p.size)
+        .modifier("sh-card", format_args!("elevation-{}", p.elevation))
+        .push_if("sh-card--clickable", p.clickable)
+        .push_if("sh-card--divided", p.divided)
+        .push_opt(p.custom)
+        .build()
+}
+
+fn bench_class_building(c: &mut Criterion) {
+    assert_eq!(vec_join(&PROPS), class_list(&PROPS));
+
+    let mut group = c.benchmark_group("card_classes");
+    group.bench_function("vec_format_join", |b| {
+        b.iter(|| vec_join(black_box(&PROPS)))
+    });
+    group.bench_function("class_builder", |b| {
+        b.iter(|| class_builder(black_box(&PROPS)))
+    });
+    group.bench_function("class_list", |b| b.iter(|| class_list(black_box(&PROPS))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_class_building);
+criterion_main!(benches);
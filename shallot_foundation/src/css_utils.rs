@@ -5,8 +5,10 @@
 //! - Class name utilities
 //! - Style merging helpers
 //! - CSS validation utilities
+//! - Content hashing for fingerprinted asset names
 
 use std::collections::HashMap;
+use std::fmt::{self, Write};
 
 /// A utility for building CSS class names conditionally
 #[derive(Debug, Clone, Default)]
@@ -17,7 +19,9 @@ pub struct ClassBuilder {
 impl ClassBuilder {
     /// Create a new class builder
     pub fn new() -> Self {
-        Self { classes: Vec::new() }
+        Self {
+            classes: Vec::new(),
+        }
     }
 
     /// Add a class if the condition is true
@@ -66,6 +70,125 @@ impl ClassBuilder {
     }
 }
 
+/// Default buffer size for a [`ClassList`]; fits a typical block class plus a
+/// handful of BEM modifiers without reallocating.
+const CLASS_LIST_CAPACITY: usize = 96;
+
+/// An allocation-light class list that writes every class straight into a
+/// single buffer.
+///
+/// Unlike [`ClassBuilder`], no intermediate `Vec<String>` is built and modifiers
+/// are formatted in place with `write!`, so a component's class attribute costs
+/// one allocation instead of one per class plus the final `join`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassList {
+    buf: String,
+}
+
+impl ClassList {
+    /// Create a class list starting with the component's base class
+    pub fn new(base: &str) -> Self {
+        Self::with_capacity(CLASS_LIST_CAPACITY).push(base)
+    }
+
+    /// Create an empty class list with the given buffer capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    fn separate(&mut self) {
+        if !self.buf.is_empty() {
+            self.buf.push(' ');
+        }
+    }
+
+    /// Add a class unconditionally; empty strings are ignored
+    pub fn push(mut self, class: &str) -> Self {
+        let class = class.trim();
+        if !class.is_empty() {
+            self.separate();
+            self.buf.push_str(class);
+        }
+        self
+    }
+
+    /// Add a class if the condition is true
+    pub fn push_if(self, class: &str, condition: bool) -> Self {
+        if condition {
+            self.push(class)
+        } else {
+            self
+        }
+    }
+
+    /// Add an optional class (typically a user-supplied custom class)
+    pub fn push_opt(self, class: Option<&str>) -> Self {
+        match class {
+            Some(class) => self.push(class),
+            None => self,
+        }
+    }
+
+    /// Add a class formatted in place, e.g. `format_args!("sh-heading-{}", level)`
+    pub fn push_fmt(mut self, class: impl fmt::Display) -> Self {
+        self.separate();
+        // Writing into a String cannot fail
+        let _ = write!(self.buf, "{}", class);
+        self
+    }
+
+    /// Add a BEM modifier (`block--modifier`), formatted in place
+    pub fn modifier(mut self, block: &str, modifier: impl fmt::Display) -> Self {
+        self.separate();
+        // Writing into a String cannot fail
+        let _ = write!(self.buf, "{}--{}", block, modifier);
+        self
+    }
+
+    /// Add a BEM modifier if the condition is true
+    pub fn modifier_if(self, block: &str, modifier: impl fmt::Display, condition: bool) -> Self {
+        if condition {
+            self.modifier(block, modifier)
+        } else {
+            self
+        }
+    }
+
+    /// Borrow the class string built so far
+    pub fn as_str(&self) -> &str {
+        &self.buf
+    }
+
+    /// Check if any classes have been added
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Get the number of classes
+    pub fn len(&self) -> usize {
+        self.buf.split(' ').filter(|c| !c.is_empty()).count()
+    }
+
+    /// Build the final class string without copying the buffer
+    pub fn build(self) -> String {
+        self.buf
+    }
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.buf)
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(list: ClassList) -> Self {
+        list.buf
+    }
+}
+
 /// A utility for building inline style attributes
 #[derive(Debug, Clone, Default)]
 pub struct StyleBuilder {
@@ -185,7 +308,7 @@ pub mod color {
     /// Convert hex to RGB
     pub fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
         let hex = hex.trim_start_matches('#');
-        
+
         match hex.len() {
             3 => {
                 let r = u8::from_str_radix(&hex[0..1].repeat(2), 16).ok()?;
@@ -448,7 +571,8 @@ pub fn utility_classes() -> String {
 .sh-opacity-100 {
   opacity: 1;
 }
-"#.to_string()
+"#
+    .to_string()
 }
 
 /// Escape a string for use in CSS
@@ -471,13 +595,32 @@ pub fn is_valid_css_identifier(ident: &str) -> bool {
         return false;
     }
 
-    ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    ident
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// FNV-1a 64-bit hash; stable across builds and platforms
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_content_hash() {
+        // Reference values for FNV-1a 64
+        assert_eq!(content_hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(content_hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
     #[test]
     fn test_class_builder() {
         let classes = ClassBuilder::new()
@@ -501,6 +644,38 @@ mod tests {
         assert_eq!(classes, "btn btn--lg");
     }
 
+    #[test]
+    fn test_class_list() {
+        let classes = ClassList::new("sh-card")
+            .modifier("sh-card", "outlined")
+            .modifier("sh-card", format_args!("elevation-{}", 2))
+            .push_if("sh-card--clickable", true)
+            .push_if("sh-card--divided", false)
+            .push_opt(Some("custom"))
+            .push_opt(None)
+            .push("")
+            .build();
+
+        assert_eq!(
+            classes,
+            "sh-card sh-card--outlined sh-card--elevation-2 sh-card--clickable custom"
+        );
+    }
+
+    #[test]
+    fn test_class_list_single_allocation() {
+        let list = ClassList::new("sh-btn").modifier("sh-btn", "primary");
+        let ptr = list.as_str().as_ptr();
+        let list = list.modifier("sh-btn", "md").push("sh-btn--block");
+        assert_eq!(list.as_str().as_ptr(), ptr);
+        assert_eq!(list.len(), 4);
+        assert!(!list.is_empty());
+        assert_eq!(
+            list.to_string(),
+            "sh-btn sh-btn--primary sh-btn--md sh-btn--block"
+        );
+    }
+
     #[test]
     fn test_style_builder() {
         let style = StyleBuilder::new()
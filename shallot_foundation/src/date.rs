@@ -0,0 +1,150 @@
+//! Calendar Dates - proleptic Gregorian dates without a time zone
+//!
+//! [`CalendarDate`] is the one date type shared by the components and the
+//! build tools: chart axes, heatmaps, chat day separators, form date
+//! fields and content publish dates. Days are counted from 1970-01-01 with
+//! Howard Hinnant's `days_from_civil` / `civil_from_days`, so any date
+//! converts to and from a day number and Unix timestamps divide into days.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// English month abbreviations, January first
+pub const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A calendar day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDate {
+    /// A date from trusted parts; use [`CalendarDate::try_new`] or
+    /// [`CalendarDate::parse`] for input
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    /// A date, or `None` when the month or day does not exist
+    pub fn try_new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && (1..=Self::days_in_month(year, month)).contains(&day))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Parse `YYYY-MM-DD`
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parts = input.trim().splitn(3, '-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        let digits =
+            |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+        if !digits(year, 4) || !digits(month, 2) || !digits(day, 2) {
+            return None;
+        }
+        Self::try_new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Current UTC date
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self::from_days((secs / 86_400) as i64)
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Days since 1970-01-01 (`days_from_civil`)
+    pub fn days_since_epoch(&self) -> i64 {
+        let y = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = self.month as i64;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Inverse of [`CalendarDate::days_since_epoch`] (`civil_from_days`)
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400) as i32 + i32::from(month <= 2);
+        Self { year, month, day }
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.days_since_epoch() + days)
+    }
+
+    /// Day of the week, Monday = 0
+    pub fn weekday(&self) -> i64 {
+        (self.days_since_epoch() + 3).rem_euclid(7)
+    }
+
+    /// English abbreviation of the month, e.g. `Mar`
+    pub fn month_abbreviation(&self) -> &'static str {
+        MONTH_ABBREVIATIONS[self.month as usize - 1]
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            CalendarDate::parse("2024-02-29"),
+            Some(CalendarDate::new(2024, 2, 29))
+        );
+        assert_eq!(CalendarDate::parse("2023-02-29"), None);
+        assert_eq!(CalendarDate::parse("2026-1-5"), None);
+        assert_eq!(CalendarDate::parse("2026-13-01"), None);
+        assert_eq!(CalendarDate::parse("2026-01-+1"), None);
+        assert_eq!(CalendarDate::try_new(2026, 4, 31), None);
+    }
+
+    #[test]
+    fn test_day_numbers() {
+        assert_eq!(CalendarDate::from_days(0).to_string(), "1970-01-01");
+        assert_eq!(CalendarDate::from_days(20_741).to_string(), "2026-10-15");
+        for days in [-800_000, -1, 0, 59, 11_016, 20_741, 3_000_000] {
+            assert_eq!(CalendarDate::from_days(days).days_since_epoch(), days);
+        }
+        let leap = CalendarDate::new(2024, 3, 1);
+        assert_eq!(leap.add_days(-1), CalendarDate::new(2024, 2, 29));
+        assert_eq!(CalendarDate::new(2024, 3, 13).weekday(), 2);
+        assert_eq!(leap.month_abbreviation(), "Mar");
+    }
+}
@@ -185,6 +185,33 @@ impl ColorPalette {
     }
 }
 
+/// Names of the text steps, smallest first; `md` is the body size
+pub const TEXT_STEP_NAMES: [&str; 7] = ["xs", "sm", "md", "lg", "xl", "2xl", "3xl"];
+
+/// Font size and line height of one text step, in rem
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStep {
+    pub size_rem: f32,
+    pub line_height_rem: f32,
+}
+
+impl TextStep {
+    pub const fn new(size_rem: f32, line_height_rem: f32) -> Self {
+        Self { size_rem, line_height_rem }
+    }
+}
+
+/// The default text steps, `xs` (12px) to `3xl` (30px)
+pub const DEFAULT_TEXT_STEPS: [TextStep; 7] = [
+    TextStep::new(0.75, 1.0),
+    TextStep::new(0.875, 1.25),
+    TextStep::new(1.0, 1.5),
+    TextStep::new(1.125, 1.75),
+    TextStep::new(1.25, 1.75),
+    TextStep::new(1.5, 2.0),
+    TextStep::new(1.875, 2.25),
+];
+
 #[derive(Debug, Clone)]
 pub struct TypographyScale {
     pub font_family_base: String,
@@ -193,6 +220,8 @@ pub struct TypographyScale {
     pub font_size_base: f32,
     pub line_height_base: f32,
     pub scale_ratio: f32,
+    /// The [`TEXT_STEP_NAMES`] sizes text components use
+    pub text_steps: [TextStep; 7],
 }
 
 impl Default for TypographyScale {
@@ -204,28 +233,53 @@ impl Default for TypographyScale {
             font_size_base: 16.0,
             line_height_base: 1.5,
             scale_ratio: 1.25,
+            text_steps: DEFAULT_TEXT_STEPS,
         }
     }
 }
 
 impl TypographyScale {
+    /// Replace the text steps, e.g. with a generated modular scale
+    pub fn text_steps(mut self, steps: [TextStep; 7]) -> Self {
+        self.text_steps = steps;
+        self
+    }
+
+    /// Font sizes in px: the text steps, `base` for `md`, and `4xl`/`5xl`
+    /// continuing past `3xl` by `scale_ratio`
     pub fn calculate_sizes(&self) -> HashMap<String, f32> {
         let mut sizes = HashMap::new();
-        
-        // Major scale (1.25 ratio)
-        sizes.insert("xs".to_string(), self.font_size_base / (self.scale_ratio.powi(3)));
-        sizes.insert("sm".to_string(), self.font_size_base / (self.scale_ratio.powi(2)));
-        sizes.insert("base".to_string(), self.font_size_base);
-        sizes.insert("lg".to_string(), self.font_size_base * self.scale_ratio);
-        sizes.insert("xl".to_string(), self.font_size_base * (self.scale_ratio.powi(2)));
-        sizes.insert("2xl".to_string(), self.font_size_base * (self.scale_ratio.powi(3)));
-        sizes.insert("3xl".to_string(), self.font_size_base * (self.scale_ratio.powi(4)));
-        sizes.insert("4xl".to_string(), self.font_size_base * (self.scale_ratio.powi(5)));
-        sizes.insert("5xl".to_string(), self.font_size_base * (self.scale_ratio.powi(6)));
-        
+        let px = |rem: f32| rem * 16.0;
+
+        for (name, step) in TEXT_STEP_NAMES.iter().zip(&self.text_steps) {
+            sizes.insert(name.to_string(), px(step.size_rem));
+        }
+        let largest = self.text_steps[6].size_rem;
+        sizes.insert("base".to_string(), px(self.text_steps[2].size_rem));
+        sizes.insert("4xl".to_string(), px(largest * self.scale_ratio));
+        sizes.insert("5xl".to_string(), px(largest * self.scale_ratio.powi(2)));
+
         sizes
     }
 
+    /// `--sh-font-size-*` and `--sh-line-height-*` for the text steps, as
+    /// a rule for `selector`
+    pub fn text_css(&self, selector: &str) -> String {
+        let round = |value: f32| (value * 1000.0).round() / 1000.0;
+        let mut css = format!("{} {{\n", selector);
+        for (name, step) in TEXT_STEP_NAMES.iter().zip(&self.text_steps) {
+            css.push_str(&format!(
+                "  --sh-font-size-{}: {}rem;\n  --sh-line-height-{}: {}rem;\n",
+                name,
+                round(step.size_rem),
+                name,
+                round(step.line_height_rem)
+            ));
+        }
+        css.push_str("  --sh-font-size-base: var(--sh-font-size-md);\n}\n");
+        css
+    }
+
     pub fn to_css_variables(&self) -> HashMap<String, String> {
         let mut vars = HashMap::new();
         let sizes = self.calculate_sizes();
@@ -241,6 +295,9 @@ impl TypographyScale {
         }
         
         // Line heights
+        for (name, step) in TEXT_STEP_NAMES.iter().zip(&self.text_steps) {
+            vars.insert(format!("--sh-line-height-{}", name), format!("{}rem", step.line_height_rem));
+        }
         vars.insert("--sh-line-height-tight".to_string(), "1.25".to_string());
         vars.insert("--sh-line-height-normal".to_string(), self.line_height_base.to_string());
         vars.insert("--sh-line-height-relaxed".to_string(), "1.75".to_string());
@@ -254,6 +311,9 @@ impl TypographyScale {
     }
 }
 
+/// Number of `--sh-space-N` tokens
+pub const SPACING_TOKEN_COUNT: u8 = 12;
+
 #[derive(Debug, Clone)]
 pub struct SpacingScale {
     pub base_unit: f32,
@@ -292,6 +352,22 @@ impl SpacingScale {
         
         vars
     }
+
+    /// Spacing tokens `--sh-space-1` to `--sh-space-12`, each a multiple of
+    /// `--sh-space-unit` (`base_unit` px, written in rem). Overriding the
+    /// unit resizes every token at once.
+    pub fn token_css(&self, selector: &str) -> String {
+        let unit_rem = (self.base_unit / 16.0 * 10000.0).round() / 10000.0;
+        let mut css = format!("{} {{\n  --sh-space-unit: {}rem;\n", selector, unit_rem);
+        for n in 1..=SPACING_TOKEN_COUNT {
+            css.push_str(&format!(
+                "  --sh-space-{}: calc(var(--sh-space-unit) * {});\n",
+                n, n
+            ));
+        }
+        css.push_str("}\n");
+        css
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -412,6 +488,128 @@ impl ShadowScale {
     }
 }
 
+/// Shared elevation scale, resolving to `var(--sh-elevation-N)`.
+///
+/// Surfaces that float above the page (cards, dialogs, popovers, menus,
+/// toasts) pick a step here instead of writing their own `box-shadow`, so
+/// depth reads the same across the library and follows dark mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Elevation {
+    /// Flat, no shadow
+    None,
+    /// Resting surfaces barely lifted off the page
+    Low,
+    /// Cards and panels
+    #[default]
+    Default,
+    /// Menus, popovers and toasts
+    High,
+    /// Surfaces lifted on hover
+    Higher,
+    /// Modal dialogs
+    Highest,
+}
+
+/// Per-step shadows as `(light, dark)`. Dark surfaces need denser shadows
+/// for the same perceived depth.
+const ELEVATION_SHADOWS: [(&str, &str); 6] = [
+    ("none", "none"),
+    (
+        "0 1px 2px rgba(0, 0, 0, 0.05), 0 1px 3px rgba(0, 0, 0, 0.1)",
+        "0 1px 2px rgba(0, 0, 0, 0.1), 0 1px 3px rgba(0, 0, 0, 0.2)",
+    ),
+    (
+        "0 4px 6px rgba(0, 0, 0, 0.05), 0 10px 15px rgba(0, 0, 0, 0.1)",
+        "0 4px 6px rgba(0, 0, 0, 0.1), 0 10px 15px rgba(0, 0, 0, 0.2)",
+    ),
+    (
+        "0 10px 15px rgba(0, 0, 0, 0.05), 0 20px 25px rgba(0, 0, 0, 0.1)",
+        "0 10px 15px rgba(0, 0, 0, 0.1), 0 20px 25px rgba(0, 0, 0, 0.2)",
+    ),
+    (
+        "0 15px 20px rgba(0, 0, 0, 0.05), 0 30px 40px rgba(0, 0, 0, 0.1)",
+        "0 15px 20px rgba(0, 0, 0, 0.1), 0 30px 40px rgba(0, 0, 0, 0.25)",
+    ),
+    (
+        "0 20px 25px rgba(0, 0, 0, 0.05), 0 40px 60px rgba(0, 0, 0, 0.15)",
+        "0 20px 25px rgba(0, 0, 0, 0.1), 0 40px 60px rgba(0, 0, 0, 0.3)",
+    ),
+];
+
+impl Elevation {
+    /// Every step, lowest first
+    pub const ALL: [Elevation; 6] = [
+        Elevation::None,
+        Elevation::Low,
+        Elevation::Default,
+        Elevation::High,
+        Elevation::Higher,
+        Elevation::Highest,
+    ];
+
+    /// Step index, 0 (flat) to 5
+    pub const fn level(self) -> u8 {
+        match self {
+            Elevation::None => 0,
+            Elevation::Low => 1,
+            Elevation::Default => 2,
+            Elevation::High => 3,
+            Elevation::Higher => 4,
+            Elevation::Highest => 5,
+        }
+    }
+
+    /// Lowercase name used in class suffixes
+    pub const fn name(self) -> &'static str {
+        match self {
+            Elevation::None => "none",
+            Elevation::Low => "low",
+            Elevation::Default => "default",
+            Elevation::High => "high",
+            Elevation::Higher => "higher",
+            Elevation::Highest => "highest",
+        }
+    }
+
+    /// The step's custom property, e.g. `var(--sh-elevation-2)`
+    pub const fn css_value(self) -> &'static str {
+        match self {
+            Elevation::None => "var(--sh-elevation-0)",
+            Elevation::Low => "var(--sh-elevation-1)",
+            Elevation::Default => "var(--sh-elevation-2)",
+            Elevation::High => "var(--sh-elevation-3)",
+            Elevation::Higher => "var(--sh-elevation-4)",
+            Elevation::Highest => "var(--sh-elevation-5)",
+        }
+    }
+
+    /// One step up, for hover lift; `Highest` stays put
+    pub const fn raised(self) -> Elevation {
+        match self {
+            Elevation::None => Elevation::Low,
+            Elevation::Low => Elevation::Default,
+            Elevation::Default => Elevation::High,
+            Elevation::High => Elevation::Higher,
+            Elevation::Higher | Elevation::Highest => Elevation::Highest,
+        }
+    }
+
+    /// `--sh-elevation-0` to `--sh-elevation-5` on `selector`, with denser
+    /// values under `prefers-color-scheme: dark`
+    pub fn css(selector: &str) -> String {
+        let mut light = format!("{} {{\n", selector);
+        let mut dark = format!("@media (prefers-color-scheme: dark) {{\n  {} {{\n", selector);
+        for (level, (light_shadow, dark_shadow)) in ELEVATION_SHADOWS.iter().enumerate() {
+            light.push_str(&format!("  --sh-elevation-{}: {};\n", level, light_shadow));
+            dark.push_str(&format!("    --sh-elevation-{}: {};\n", level, dark_shadow));
+        }
+        light.push_str("}\n");
+        dark.push_str("  }\n}\n");
+        light.push_str(&dark);
+        light
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DesignTokens {
     pub color_palette: ColorPalette,
@@ -474,6 +672,25 @@ impl DesignTokens {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_elevation_scale() {
+        for (index, elevation) in Elevation::ALL.iter().enumerate() {
+            assert_eq!(elevation.level() as usize, index);
+            assert_eq!(
+                elevation.css_value(),
+                format!("var(--sh-elevation-{})", index)
+            );
+        }
+        assert_eq!(Elevation::default(), Elevation::Default);
+        assert_eq!(Elevation::Low.raised(), Elevation::Default);
+        assert_eq!(Elevation::Highest.raised(), Elevation::Highest);
+
+        let css = Elevation::css(":root");
+        assert!(css.starts_with(":root {\n  --sh-elevation-0: none;\n"));
+        assert!(css.contains("@media (prefers-color-scheme: dark) {\n  :root {\n"));
+        assert_eq!(css.matches("--sh-elevation-5:").count(), 2);
+    }
+
     #[test]
     fn test_hsl_color_creation() {
         let color = HSLColor::new(180.0, 50.0, 50.0);
@@ -513,6 +730,32 @@ mod tests {
         assert_ne!(palette.secondary, primary);
     }
 
+    #[test]
+    fn test_typography_text_steps() {
+        let typography = TypographyScale::default();
+        let vars = typography.to_css_variables();
+        assert_eq!(vars["--sh-font-size-xs"], "0.75rem");
+        assert_eq!(vars["--sh-font-size-base"], "1rem");
+        assert_eq!(vars["--sh-font-size-3xl"], "1.875rem");
+        assert_eq!(vars["--sh-line-height-2xl"], "2rem");
+
+        let css = typography.text_css(":root");
+        assert!(css.starts_with(":root {\n  --sh-font-size-xs: 0.75rem;\n  --sh-line-height-xs: 1rem;\n"));
+        assert!(css.contains("  --sh-font-size-base: var(--sh-font-size-md);\n"));
+
+        let custom = typography.text_steps([TextStep::new(2.0, 3.0); 7]);
+        assert_eq!(custom.calculate_sizes()["sm"], 32.0);
+    }
+
+    #[test]
+    fn test_spacing_token_css() {
+        let css = SpacingScale::default().token_css(":root");
+        assert!(css.starts_with(":root {\n  --sh-space-unit: 0.25rem;\n"));
+        assert!(css.contains("  --sh-space-1: calc(var(--sh-space-unit) * 1);\n"));
+        assert!(css.contains("  --sh-space-12: calc(var(--sh-space-unit) * 12);\n"));
+        assert!(!css.contains("--sh-space-13"));
+    }
+
     #[test]
     fn test_css_variable_generation() {
         let primary = HSLColor::new(200.0, 60.0, 50.0);
@@ -2,6 +2,8 @@
 //!
 //! This crate provides the foundational building blocks for the Shallot UI library:
 //! - Design tokens (colors, typography, spacing, shadows)
+//! - Calendar dates shared by date-aware components and tools
+//! - Shareable theme packages
 //! - Animation and transition systems
 //! - Responsive design utilities
 //! - Icon system
@@ -14,12 +16,20 @@ mod animations;
 mod icon;
 mod css_utils;
 mod transitions;
+mod theme_package;
+mod date;
 
 // Re-export core types
 pub use theme::{ColorMode, Theme};
+pub use theme_package::{
+    ThemePackage, ThemePackageError,
+    THEME_FONT_KEYS, THEME_RADIUS_KEYS, THEME_SHADOW_KEYS
+};
+pub use date::{CalendarDate, MONTH_ABBREVIATIONS};
 pub use design_tokens::{
     HSLColor, ColorPalette, ColorScheme, 
-    TypographyScale, SpacingScale, BorderRadiusScale, ShadowScale, DesignTokens
+    TypographyScale, SpacingScale, BorderRadiusScale, ShadowScale, DesignTokens,
+    Elevation, TextStep, DEFAULT_TEXT_STEPS, SPACING_TOKEN_COUNT, TEXT_STEP_NAMES
 };
 pub use responsive::{
     Breakpoint, ResponsiveValue, ResponsiveProperty,
@@ -43,9 +53,9 @@ pub use icon::{
 
 // Re-export CSS utilities
 pub use css_utils::{
-    ClassBuilder, StyleBuilder,
+    ClassBuilder, ClassList, StyleBuilder,
     css_vars, color, units,
-    utility_classes, css_escape, is_valid_css_identifier
+    utility_classes, css_escape, is_valid_css_identifier, content_hash
 };
 
 // Re-export transitions
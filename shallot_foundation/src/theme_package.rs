@@ -0,0 +1,524 @@
+//! Theme Packages - Shareable theme files
+//!
+//! A theme package is a small TOML file that overrides design tokens. It
+//! can be loaded, validated and turned into CSS custom properties scoped
+//! to any selector, so several themes can live on one page:
+//!
+//! ```toml
+//! name = "Nordic"
+//! author = "@viking_coder"
+//! version = "1.0.0"
+//!
+//! [tokens]        # --sh-{name}
+//! accent = "#5e81ac"
+//! bg = "#eceff4"
+//!
+//! [fonts]         # --sh-font-{sans|serif|mono|heading}
+//! sans = "'Inter', system-ui, sans-serif"
+//!
+//! [radii]         # --sh-radius-{size}; bare numbers are pixels
+//! md = 6
+//!
+//! [shadows]       # --sh-shadow-{size}
+//! sm = "0 1px 2px rgb(0 0 0 / 0.1)"
+//! ```
+//!
+//! Any valid TOML is accepted as long as it only has the top-level metadata
+//! and the four tables, with string and number values.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::css_utils::is_valid_css_identifier;
+
+/// Font roles a package can set
+pub const THEME_FONT_KEYS: &[&str] = &["sans", "serif", "mono", "heading"];
+
+/// Radius steps a package can set
+pub const THEME_RADIUS_KEYS: &[&str] = &["none", "sm", "md", "lg", "xl", "2xl", "full"];
+
+/// Shadow steps a package can set
+pub const THEME_SHADOW_KEYS: &[&str] = &["xs", "sm", "md", "lg", "xl", "2xl", "glow"];
+
+/// Why a theme package could not be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemePackageError {
+    /// The file could not be read
+    Io(String),
+    /// The file is not valid TOML
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    UnknownSection {
+        section: String,
+    },
+    /// `section` is empty for top-level keys
+    UnknownKey {
+        section: String,
+        key: String,
+    },
+    MissingName,
+    InvalidValue {
+        key: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for ThemePackageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(message) => write!(f, "Could not read theme: {}", message),
+            Self::Syntax { line, message } => write!(f, "Line {}: {}", line, message),
+            Self::UnknownSection { section } => write!(f, "Unknown section [{}]", section),
+            Self::UnknownKey { section, key } if section.is_empty() => {
+                write!(f, "Unknown key `{}`", key)
+            }
+            Self::UnknownKey { section, key } => {
+                write!(f, "Unknown key `{}` in [{}]", key, section)
+            }
+            Self::MissingName => write!(f, "A theme needs a `name`"),
+            Self::InvalidValue { key, message } => write!(f, "`{}`: {}", key, message),
+        }
+    }
+}
+
+impl std::error::Error for ThemePackageError {}
+
+/// A shareable theme: metadata plus design token overrides
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemePackage {
+    pub name: String,
+    pub author: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// Token name (without `--sh-`) to CSS value
+    pub tokens: BTreeMap<String, String>,
+    pub fonts: BTreeMap<String, String>,
+    pub radii: BTreeMap<String, String>,
+    pub shadows: BTreeMap<String, String>,
+}
+
+impl ThemePackage {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Override `--sh-{name}`
+    pub fn token(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tokens.insert(name.into(), value.into());
+        self
+    }
+
+    /// Override `--sh-font-{role}`
+    pub fn font(mut self, role: impl Into<String>, stack: impl Into<String>) -> Self {
+        self.fonts.insert(role.into(), stack.into());
+        self
+    }
+
+    /// Override `--sh-radius-{size}`
+    pub fn radius(mut self, size: impl Into<String>, value: impl Into<String>) -> Self {
+        self.radii.insert(size.into(), value.into());
+        self
+    }
+
+    /// Override `--sh-shadow-{size}`
+    pub fn shadow(mut self, size: impl Into<String>, value: impl Into<String>) -> Self {
+        self.shadows.insert(size.into(), value.into());
+        self
+    }
+
+    /// Parse and validate a package
+    pub fn from_toml(source: &str) -> Result<Self, ThemePackageError> {
+        let table: toml::Table = source.parse().map_err(|error: toml::de::Error| {
+            let offset = error.span().map_or(0, |span| span.start);
+            ThemePackageError::Syntax {
+                line: source[..offset].matches('\n').count() + 1,
+                message: error.message().to_string(),
+            }
+        })?;
+
+        let mut package = Self::default();
+        for (key, value) in table {
+            match value {
+                toml::Value::Table(values) => {
+                    if !matches!(key.as_str(), "tokens" | "fonts" | "radii" | "shadows") {
+                        return Err(ThemePackageError::UnknownSection { section: key });
+                    }
+                    for (name, value) in values {
+                        package.set(&key, name, value)?;
+                    }
+                }
+                value => package.set("", key, value)?,
+            }
+        }
+
+        package.validate()?;
+        Ok(package)
+    }
+
+    /// Read and parse a `.toml` file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemePackageError> {
+        let source = fs::read_to_string(path.as_ref())
+            .map_err(|error| ThemePackageError::Io(error.to_string()))?;
+        Self::from_toml(&source)
+    }
+
+    /// Every `.toml` package in `dir`, sorted by name
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Vec<Self>, ThemePackageError> {
+        let entries =
+            fs::read_dir(dir.as_ref()).map_err(|error| ThemePackageError::Io(error.to_string()))?;
+        let mut packages = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|error| ThemePackageError::Io(error.to_string()))?
+                .path();
+            if path.extension().is_some_and(|ext| ext == "toml") {
+                packages.push(Self::load(&path)?);
+            }
+        }
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(packages)
+    }
+
+    fn set(
+        &mut self,
+        section: &str,
+        key: String,
+        value: toml::Value,
+    ) -> Result<(), ThemePackageError> {
+        let unknown = |key: String| ThemePackageError::UnknownKey {
+            section: section.to_string(),
+            key,
+        };
+        if section.is_empty() {
+            let toml::Value::String(text) = value else {
+                return Err(ThemePackageError::InvalidValue {
+                    key,
+                    message: "expected a string".to_string(),
+                });
+            };
+            match key.as_str() {
+                "name" => self.name = text,
+                "author" => self.author = Some(text),
+                "version" => self.version = Some(text),
+                "description" => self.description = Some(text),
+                _ => return Err(unknown(key)),
+            }
+            return Ok(());
+        }
+
+        let (values, allowed) = match section {
+            "tokens" => (&mut self.tokens, None),
+            "fonts" => (&mut self.fonts, Some(THEME_FONT_KEYS)),
+            "radii" => (&mut self.radii, Some(THEME_RADIUS_KEYS)),
+            _ => (&mut self.shadows, Some(THEME_SHADOW_KEYS)),
+        };
+        if allowed.is_some_and(|keys| !keys.contains(&key.as_str())) {
+            return Err(unknown(key));
+        }
+        let css = match value {
+            toml::Value::String(text) => text,
+            toml::Value::Integer(0) => "0".to_string(),
+            toml::Value::Integer(n) if section == "radii" => format!("{}px", n),
+            toml::Value::Integer(n) => n.to_string(),
+            toml::Value::Float(n) if n.is_finite() && section == "radii" && n != 0.0 => {
+                format!("{}px", n)
+            }
+            toml::Value::Float(n) if n.is_finite() => n.to_string(),
+            _ => {
+                return Err(ThemePackageError::InvalidValue {
+                    key,
+                    message: "expected a quoted string or a number".to_string(),
+                })
+            }
+        };
+        values.insert(key, css);
+        Ok(())
+    }
+
+    /// Check the name and that every override is a safe CSS value
+    pub fn validate(&self) -> Result<(), ThemePackageError> {
+        if self.name.trim().is_empty() || self.slug().is_empty() {
+            return Err(ThemePackageError::MissingName);
+        }
+        for (name, value) in self.properties() {
+            let check = if is_valid_css_identifier(name.trim_start_matches("--")) {
+                check_css_value(value)
+            } else {
+                Err("not a valid CSS custom property name")
+            };
+            if let Err(message) = check {
+                return Err(ThemePackageError::InvalidValue {
+                    key: name.trim_start_matches("--sh-").to_string(),
+                    message: message.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Lowercase, dash-separated name, used in [`ThemePackage::selector`]
+    pub fn slug(&self) -> String {
+        let mut slug = String::new();
+        for c in self.name.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        slug.trim_end_matches('-').to_string()
+    }
+
+    /// Default scope: `[data-sh-theme="{slug}"]`
+    pub fn selector(&self) -> String {
+        format!("[data-sh-theme=\"{}\"]", self.slug())
+    }
+
+    /// Custom properties in output order: tokens, fonts, radii, shadows
+    pub fn properties(&self) -> Vec<(String, &str)> {
+        let groups: [(&str, &BTreeMap<String, String>); 4] = [
+            ("--sh-", &self.tokens),
+            ("--sh-font-", &self.fonts),
+            ("--sh-radius-", &self.radii),
+            ("--sh-shadow-", &self.shadows),
+        ];
+        groups
+            .iter()
+            .flat_map(|(prefix, values)| {
+                values
+                    .iter()
+                    .map(move |(key, value)| (format!("{}{}", prefix, key), value.as_str()))
+            })
+            .collect()
+    }
+
+    /// The overrides as a rule for `selector`, e.g. `:root` or `.brand`
+    pub fn css(&self, selector: &str) -> String {
+        let mut css = format!("{} {{\n", selector);
+        for (name, value) in self.properties() {
+            css.push_str(&format!("  {}: {};\n", name, value));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// The overrides scoped to [`ThemePackage::selector`]
+    pub fn scoped_css(&self) -> String {
+        self.css(&self.selector())
+    }
+
+    /// Serialize back to the package format
+    pub fn to_toml(&self) -> String {
+        let mut toml = format!("name = {}\n", quote(&self.name));
+        for (key, value) in [
+            ("author", &self.author),
+            ("version", &self.version),
+            ("description", &self.description),
+        ] {
+            if let Some(value) = value {
+                toml.push_str(&format!("{} = {}\n", key, quote(value)));
+            }
+        }
+        for (section, values) in [
+            ("tokens", &self.tokens),
+            ("fonts", &self.fonts),
+            ("radii", &self.radii),
+            ("shadows", &self.shadows),
+        ] {
+            if values.is_empty() {
+                continue;
+            }
+            toml.push_str(&format!("\n[{}]\n", section));
+            for (key, value) in values {
+                toml.push_str(&format!("{} = {}\n", key, quote(value)));
+            }
+        }
+        toml
+    }
+}
+
+/// Reject values that could end the declaration or the rule
+fn check_css_value(value: &str) -> Result<(), &'static str> {
+    if value.trim().is_empty() {
+        return Err("value is empty");
+    }
+    if value
+        .chars()
+        .any(|c| matches!(c, ';' | '{' | '}' | '<' | '>' | '\\') || c.is_control())
+    {
+        return Err("value may not contain `;`, braces, angle brackets or backslashes");
+    }
+    if value.contains("/*") {
+        return Err("value may not contain comments");
+    }
+    Ok(())
+}
+
+/// A TOML basic string
+fn quote(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORDIC: &str = r##"
+# A community theme
+name = "Nordic"
+author = "@viking_coder"
+version = '1.0.0'
+
+[tokens]
+accent = "#5e81ac"   # frost
+"accent-2" = "#81a1c1"
+
+[fonts]
+sans = "'Inter', system-ui, sans-serif"
+
+[radii]
+md = 6
+full = "9999px"
+
+[shadows]
+sm = "0 1px 2px rgb(0 0 0 / 0.1)"
+"##;
+
+    #[test]
+    fn test_parse_package() {
+        let theme = ThemePackage::from_toml(NORDIC).expect("valid theme");
+        assert_eq!(theme.name, "Nordic");
+        assert_eq!(theme.author.as_deref(), Some("@viking_coder"));
+        assert_eq!(theme.version.as_deref(), Some("1.0.0"));
+        assert_eq!(theme.tokens["accent"], "#5e81ac");
+        assert_eq!(theme.tokens["accent-2"], "#81a1c1");
+        assert_eq!(theme.fonts["sans"], "'Inter', system-ui, sans-serif");
+        assert_eq!(theme.radii["md"], "6px");
+        assert_eq!(theme.shadows["sm"], "0 1px 2px rgb(0 0 0 / 0.1)");
+    }
+
+    #[test]
+    fn test_scoped_css() {
+        let theme = ThemePackage::from_toml(NORDIC).unwrap();
+        assert_eq!(theme.selector(), "[data-sh-theme=\"nordic\"]");
+        let css = theme.scoped_css();
+        assert!(css.starts_with("[data-sh-theme=\"nordic\"] {\n"));
+        assert!(css.contains("  --sh-accent: #5e81ac;\n"));
+        assert!(css.contains("  --sh-font-sans: 'Inter', system-ui, sans-serif;\n"));
+        assert!(css.contains("  --sh-radius-md: 6px;\n"));
+        assert!(css.contains("  --sh-shadow-sm: 0 1px 2px rgb(0 0 0 / 0.1);\n"));
+        assert!(theme.css(":root").starts_with(":root {"));
+    }
+
+    #[test]
+    fn test_full_toml_syntax() {
+        let theme = ThemePackage::from_toml(
+            "name = \"\"\"Multi\nline\"\"\"\ntokens = { accent = \"#000\" }\nradii.sm = 2.5\nradii.none = 0",
+        )
+        .unwrap();
+        assert_eq!(theme.name, "Multi\nline");
+        assert_eq!(theme.tokens["accent"], "#000");
+        assert_eq!(theme.radii["sm"], "2.5px");
+        assert_eq!(theme.radii["none"], "0");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let theme = ThemePackage::new("Say \"hi\"")
+            .author("me")
+            .token("accent", "#fff")
+            .radius("sm", "2px");
+        let parsed = ThemePackage::from_toml(&theme.to_toml()).unwrap();
+        assert_eq!(parsed, theme);
+        assert_eq!(parsed.slug(), "say-hi");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            ThemePackage::from_toml("[tokens]\naccent = \"#fff\""),
+            Err(ThemePackageError::MissingName)
+        );
+        assert_eq!(
+            ThemePackage::from_toml("name = \"x\"\n[colors]"),
+            Err(ThemePackageError::UnknownSection {
+                section: "colors".to_string()
+            })
+        );
+        assert_eq!(
+            ThemePackage::from_toml("name = \"x\"\n[fonts]\ncursive = \"x\""),
+            Err(ThemePackageError::UnknownKey {
+                section: "fonts".to_string(),
+                key: "cursive".to_string()
+            })
+        );
+        assert!(matches!(
+            ThemePackage::from_toml("name = \"x\"\nname = \"y\""),
+            Err(ThemePackageError::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(
+            ThemePackage::from_toml("name = \"x\"\naccent"),
+            Err(ThemePackageError::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(
+            ThemePackage::from_toml("name = \"x\"\n[tokens]\nbg = \"red\" extra"),
+            Err(ThemePackageError::Syntax { line: 3, .. })
+        ));
+        assert!(matches!(
+            ThemePackage::from_toml("name = \"x\"\n[tokens]\nbg = true"),
+            Err(ThemePackageError::InvalidValue { ref key, .. }) if key == "bg"
+        ));
+    }
+
+    #[test]
+    fn test_rejects_unsafe_values() {
+        let error =
+            ThemePackage::from_toml("name = \"x\"\n[tokens]\nbg = \"red; } body { display: none\"")
+                .unwrap_err();
+        assert!(matches!(error, ThemePackageError::InvalidValue { ref key, .. } if key == "bg"));
+        assert!(error.to_string().starts_with("`bg`: "));
+
+        let theme = ThemePackage::new("x").token("bad name", "red");
+        assert!(theme.validate().is_err());
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = std::env::temp_dir().join(format!("shallot-themes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("nordic.toml"), NORDIC).unwrap();
+        fs::write(dir.join("amber.toml"), "name = \"Amber\"").unwrap();
+        fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let themes = ThemePackage::load_dir(&dir).unwrap();
+        let names: Vec<&str> = themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Amber", "Nordic"]);
+        assert!(matches!(
+            ThemePackage::load(dir.join("missing.toml")),
+            Err(ThemePackageError::Io(_))
+        ));
+        fs::remove_dir_all(&dir).ok();
+    }
+}
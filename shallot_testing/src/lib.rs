@@ -295,6 +295,199 @@ pub mod accessibility {
         }
     }
 
+    /// Why a rule's styling disappears under `forced-colors: active`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ForcedColorsReason {
+        /// `outline: none`, so the focus ring is gone
+        OutlineRemoved,
+        /// A `box-shadow` (dropped in forced colors) with no border or outline
+        ShadowOnly,
+        /// A background colour (replaced in forced colors) with no border or outline
+        BackgroundOnly,
+    }
+
+    /// A rule that needs a `@media (forced-colors: active)` override
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ForcedColorsIssue {
+        pub selector: String,
+        pub reason: ForcedColorsReason,
+    }
+
+    struct CssRule {
+        selectors: Vec<String>,
+        declarations: Vec<(String, String)>,
+        forced: bool,
+    }
+
+    /// Flag rules whose visible styling a high-contrast theme would strip.
+    ///
+    /// Forced colors drops `box-shadow` and swaps every colour for a system
+    /// colour, so a control drawn only with a shadow or a background, or a
+    /// focus state that removes the outline, vanishes. A rule is fine when
+    /// each of its selectors, or the BEM block of a modifier-only selector
+    /// (`.sh-btn` for `.sh-btn--primary:hover`), is restyled inside
+    /// `@media (forced-colors: active)`.
+    pub fn forced_colors_issues(css: &str) -> Vec<ForcedColorsIssue> {
+        let mut rules = Vec::new();
+        collect_rules(&strip_comments(css), false, &mut rules);
+
+        let covered: Vec<&String> = rules
+            .iter()
+            .filter(|rule| rule.forced)
+            .flat_map(|rule| rule.selectors.iter())
+            .collect();
+
+        let mut issues = Vec::new();
+        for rule in rules.iter().filter(|rule| !rule.forced) {
+            let is_covered = |selector: &String| {
+                covered.contains(&selector)
+                    || bem_block(selector).is_some_and(|block| covered.contains(&&block))
+            };
+            if rule.selectors.iter().all(is_covered) {
+                continue;
+            }
+            let value = |name: &str| {
+                rule.declarations
+                    .iter()
+                    .find(|(property, _)| property == name)
+                    .map(|(_, value)| value.as_str())
+            };
+            let has_edge = rule.declarations.iter().any(|(property, _)| {
+                (property.starts_with("border") && !property.starts_with("border-radius"))
+                    || property.starts_with("outline")
+            });
+            let background = value("background").or_else(|| value("background-color"));
+
+            let reason = if matches!(value("outline"), Some("none") | Some("0")) {
+                Some(ForcedColorsReason::OutlineRemoved)
+            } else if !has_edge && value("box-shadow").is_some_and(|shadow| shadow != "none") {
+                Some(ForcedColorsReason::ShadowOnly)
+            } else if !has_edge && background.is_some_and(is_background_colour) {
+                Some(ForcedColorsReason::BackgroundOnly)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                issues.push(ForcedColorsIssue {
+                    selector: rule.selectors.join(", "),
+                    reason,
+                });
+            }
+        }
+        issues
+    }
+
+    /// [`forced_colors_issues`] as a test result
+    pub fn test_forced_colors(css: &str) -> TestResult {
+        let issues = forced_colors_issues(css);
+        if issues.is_empty() {
+            TestResult::Pass
+        } else {
+            TestResult::Fail(format!(
+                "{} rule(s) disappear in forced colors: {}",
+                issues.len(),
+                issues
+                    .iter()
+                    .map(|issue| format!("{} ({:?})", issue.selector, issue.reason))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ))
+        }
+    }
+
+    /// `.block` for a selector made only of `.block--modifier` classes
+    fn bem_block(selector: &str) -> Option<String> {
+        let compound = selector.split(':').next()?;
+        if compound.contains([' ', '>', '+', '~', '[']) {
+            return None;
+        }
+        let mut block = None;
+        for class in compound.split('.').skip(1) {
+            let (name, _) = class.split_once("--")?;
+            if block.is_some_and(|block| block != name) {
+                return None;
+            }
+            block = Some(name);
+        }
+        block.map(|name| format!(".{}", name))
+    }
+
+    fn is_background_colour(value: &str) -> bool {
+        !matches!(
+            value,
+            "transparent" | "none" | "inherit" | "initial" | "unset" | "currentColor"
+        ) && !value.starts_with("url(")
+    }
+
+    fn strip_comments(css: &str) -> String {
+        let mut out = String::with_capacity(css.len());
+        let mut rest = css;
+        while let Some(start) = rest.find("/*") {
+            out.push_str(&rest[..start]);
+            rest = match rest[start + 2..].find("*/") {
+                Some(end) => &rest[start + 2 + end + 2..],
+                None => "",
+            };
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Byte offset of the `}` closing the block opened just before `text`
+    fn block_end(text: &str) -> usize {
+        let mut depth = 1usize;
+        for (i, c) in text.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i;
+                    }
+                }
+                _ => {}
+            }
+        }
+        text.len()
+    }
+
+    fn collect_rules(css: &str, forced: bool, rules: &mut Vec<CssRule>) {
+        let mut rest = css;
+        while let Some(open) = rest.find('{') {
+            let prelude = rest[..open].rsplit([';', '}']).next().unwrap_or("").trim();
+            let body_start = open + 1;
+            let end = body_start + block_end(&rest[body_start..]);
+            let body = &rest[body_start..end];
+
+            if let Some(at_rule) = prelude.strip_prefix('@') {
+                let grouping = ["media", "supports", "layer", "container"]
+                    .iter()
+                    .any(|name| at_rule.starts_with(name));
+                if grouping {
+                    let forced_block = at_rule.starts_with("media")
+                        && at_rule.replace(' ', "").contains("forced-colors:active");
+                    collect_rules(body, forced || forced_block, rules);
+                }
+            } else if !prelude.is_empty() {
+                rules.push(CssRule {
+                    selectors: prelude
+                        .split(',')
+                        .map(|selector| selector.split_whitespace().collect::<Vec<_>>().join(" "))
+                        .collect(),
+                    declarations: body
+                        .split(';')
+                        .filter_map(|declaration| {
+                            let (property, value) = declaration.split_once(':')?;
+                            Some((property.trim().to_string(), value.trim().to_string()))
+                        })
+                        .collect(),
+                    forced,
+                });
+            }
+            rest = rest.get(end + 1..).unwrap_or("");
+        }
+    }
+
     fn calculate_contrast_ratio(_foreground: &str, _background: &str) -> f32 {
         // Simplified contrast calculation
         // Real implementation would parse colors and calculate luminance
@@ -0,0 +1,79 @@
+//! Collect the `# Example` blocks from each component's module docs
+//!
+//! Writes `$OUT_DIR/doc_examples.rs`, a table of `(module, code)` pairs that
+//! `doc_examples.rs` includes. The examples are doc tests, so the code shown
+//! in the showcase is the code `cargo test` compiles.
+
+use shallot_components::gallery::extract_code_examples;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const COMPONENTS_SRC: &str = "../shallot_components/src";
+
+/// Markdown under the module docs' `# Example` / `# Examples` headings
+fn example_sections(source: &str) -> String {
+    let mut section = String::new();
+    let mut in_example = false;
+    let mut in_fence = false;
+
+    for line in source.lines() {
+        let Some(text) = line.trim_start().strip_prefix("//!") else {
+            // Module docs end at the first non-`//!` line after they start
+            if line.trim().is_empty() {
+                continue;
+            }
+            break;
+        };
+        let text = text.strip_prefix(' ').unwrap_or(text);
+
+        if text.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            if let Some(heading) = text.strip_prefix("# ") {
+                in_example = matches!(heading.trim(), "Example" | "Examples");
+                continue;
+            }
+        }
+        if in_example {
+            section.push_str(text);
+            section.push('\n');
+        }
+    }
+    section
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", COMPONENTS_SRC);
+
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for file in fs::read_dir(COMPONENTS_SRC).expect("read shallot_components/src") {
+        let path = file.expect("read directory entry").path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(&path).expect("read component source");
+        if let Some(code) = extract_code_examples(&example_sections(&source))
+            .into_iter()
+            .next()
+        {
+            let module = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .expect("UTF-8 file name")
+                .to_string();
+            entries.push((module, code));
+        }
+    }
+    entries.sort();
+
+    let mut table = String::from("pub const DOC_EXAMPLES: &[(&str, &str)] = &[\n");
+    for (module, code) in &entries {
+        table.push_str(&format!("    ({:?}, {:?}),\n", module, code));
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR")).join("doc_examples.rs");
+    fs::write(out, table).expect("write doc_examples.rs");
+}
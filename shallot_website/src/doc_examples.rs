@@ -0,0 +1,32 @@
+//! Doc Examples - Real usage code for the showcase
+//!
+//! `build.rs` pulls the first code block under `# Example` in each
+//! component's module docs. Those blocks run as doc tests, so the samples
+//! shown here cannot drift from the component API.
+
+include!(concat!(env!("OUT_DIR"), "/doc_examples.rs"));
+
+/// Module name of a component: `BorderBeam` lives in `border_beam.rs`
+pub fn module_name(component: &str) -> String {
+    let mut module = String::new();
+    for (i, c) in component.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                module.push('_');
+            }
+            module.push(c.to_ascii_lowercase());
+        } else {
+            module.push(c);
+        }
+    }
+    module
+}
+
+/// The doc example for a component, by type name
+pub fn example_for(component: &str) -> Option<&'static str> {
+    let module = module_name(component);
+    DOC_EXAMPLES
+        .iter()
+        .find(|(name, _)| *name == module)
+        .map(|(_, code)| *code)
+}
@@ -13,8 +13,10 @@
 //!   The theme_panel floating widget has been removed; theming lives in navbar.
 
 pub mod component_docs;
+pub mod doc_examples;
 pub mod retro_hero;
 pub mod rss;
+pub mod search_index;
 pub mod showcase;
 pub mod theme_marketplace; // kept for compilation, not rendered
 pub mod theme_panel; // kept for compilation, not rendered
@@ -3,7 +3,12 @@
 //! Generates the static website HTML and CSS files.
 
 use shallot_components::all_component_css;
-use shallot_website::{homepage, main_css, retro_hero, rss, showcase_css};
+use shallot_components::assets::{DisplayMode, Manifest};
+use shallot_components::seo::{Robots, Sitemap, SitemapUrl};
+use shallot_website::search_index::{SearchDocument, SearchIndex};
+use shallot_website::{
+    homepage, main_css, retro_hero, rss, showcase, showcase_css, theme_marketplace,
+};
 use std::fs;
 use std::path::Path;
 
@@ -15,14 +20,50 @@ fn main() {
     fs::create_dir_all(&styles_dir).expect("Failed to create output directories");
 
     // Generate main HTML
-    let html = homepage();
-    fs::write(Path::new(out_dir).join("index.html"), html.into_string())
-        .expect("Failed to write index.html");
+    let html = homepage().into_string();
+    fs::write(Path::new(out_dir).join("index.html"), &html).expect("Failed to write index.html");
+
+    // Generate static search pages
+    let search_pages = SearchIndex::new()
+        .page(SearchDocument::from_html("../index.html", &html))
+        .write_to(out_dir)
+        .expect("Failed to write search index");
+
+    // Generate component gallery
+    let gallery_pages = showcase::gallery()
+        .write_to(Path::new(out_dir).join("gallery"))
+        .expect("Failed to write component gallery");
+
+    // Generate community theme packages
+    let themes_dir = Path::new(out_dir).join("themes");
+    fs::create_dir_all(&themes_dir).expect("Failed to create themes directory");
+    for (slug, source) in theme_marketplace::THEME_FILES {
+        fs::write(themes_dir.join(format!("{}.toml", slug)), source)
+            .expect("Failed to write theme package");
+    }
 
     // Generate RSS feed
     fs::write(Path::new(out_dir).join("feed.xml"), rss::rss_string())
         .expect("Failed to write feed.xml");
 
+    // Generate sitemap and robots.txt
+    let sitemap = Sitemap::new("https://shallot.rs").url(SitemapUrl::new("/").priority(1.0));
+    sitemap
+        .write_to(out_dir)
+        .expect("Failed to write sitemap.xml");
+    Robots::allow_all()
+        .with_sitemap(&sitemap)
+        .write_to(out_dir)
+        .expect("Failed to write robots.txt");
+
+    // Generate web app manifest
+    Manifest::new("Shallot")
+        .description("Zero-JS UI components for Rust")
+        .display(DisplayMode::Standalone)
+        .theme_color("#8b5cf6")
+        .write_to(out_dir)
+        .expect("Failed to write site.webmanifest");
+
     // Generate CSS files
     fs::write(styles_dir.join("main.css"), main_css()).expect("Failed to write main.css");
 
@@ -35,14 +76,31 @@ fn main() {
     fs::write(styles_dir.join("components.css"), all_component_css())
         .expect("Failed to write components.css");
 
+    fs::write(
+        styles_dir.join("themes.css"),
+        theme_marketplace::themes_css(),
+    )
+    .expect("Failed to write themes.css");
+
     println!("✅ Website generated successfully in {}/", out_dir);
     println!("📄 Files created:");
     println!("   - {}/index.html", out_dir);
     println!("   - {}/feed.xml", out_dir);
+    println!("   - {}/search/ ({} files)", out_dir, search_pages.len());
+    println!("   - {}/gallery/ ({} files)", out_dir, gallery_pages.len());
+    println!(
+        "   - {}/themes/ ({} files)",
+        out_dir,
+        theme_marketplace::THEME_FILES.len()
+    );
+    println!("   - {}/sitemap.xml", out_dir);
+    println!("   - {}/robots.txt", out_dir);
+    println!("   - {}/site.webmanifest", out_dir);
     println!("   - {}/styles/main.css", out_dir);
     println!("   - {}/styles/retro.css", out_dir);
     println!("   - {}/styles/showcase.css", out_dir);
     println!("   - {}/styles/components.css", out_dir);
+    println!("   - {}/styles/themes.css", out_dir);
     println!(
         "\n🚀 Open {}/index.html in your browser to view the website!",
         out_dir
@@ -1,8 +1,8 @@
 //! RSS Feed Generator
 //!
-//! Generates an Atom/RSS feed for component updates.
+//! Generates the RSS feed for component updates.
 
-use maud::{html, Markup};
+use shallot_components::feed::{Feed, FeedAuthor, FeedFormat, FeedItem};
 
 /// RSS feed entry
 pub struct FeedEntry {
@@ -12,9 +12,8 @@ pub struct FeedEntry {
     pub summary: String,
 }
 
-/// Generate RSS feed
-pub fn generate_rss() -> Markup {
-    let entries = vec![
+fn entries() -> Vec<FeedEntry> {
+    vec![
         FeedEntry {
             title: "Shallot v1.0 Released - 129 COMPLETE Components!".to_string(),
             link: "https://shallot.rs/#showcase".to_string(),
@@ -45,35 +44,25 @@ pub fn generate_rss() -> Markup {
             published: "2026-02-22T00:00:00Z".to_string(),
             summary: "Official website launched with interactive component showcase.".to_string(),
         },
-    ];
-
-    html! {
-        (maud::PreEscaped(r#"<?xml version="1.0" encoding="UTF-8"?>"#))
-        feed xmlns="http://www.w3.org/2005/Atom" {
-            title { "Shallot Component Library - Updates" }
-            link href="https://shallot.rs/" rel="alternate";
-            link href="https://shallot.rs/feed.xml" rel="self";
-            id { "urn:uuid:shallot-rs" }
-            updated { "2026-02-24T00:00:00Z" }
-            author {
-                name { "Shallot Team" }
-            }
-            subtitle { "Zero-JS Rust UI Component Library" }
+    ]
+}
 
-            @for entry in entries {
-                entry {
-                    title { (entry.title) }
-                    link href=(entry.link) rel="alternate";
-                    id { (entry.link) }
-                    published { (entry.published) }
-                    summary { (entry.summary) }
-                }
-            }
-        }
-    }
+/// Build the site feed
+pub fn site_feed() -> Feed {
+    Feed::new(
+        "Shallot Component Library - Updates",
+        "https://shallot.rs/",
+        "https://shallot.rs/feed.xml",
+    )
+    .description("Zero-JS Rust UI Component Library")
+    .language("en")
+    .author(FeedAuthor::new("Shallot Team"))
+    .items(entries().into_iter().map(|entry| {
+        FeedItem::new(entry.title, entry.link, entry.published).summary(entry.summary)
+    }))
 }
 
 /// Generate RSS feed as string
 pub fn rss_string() -> String {
-    generate_rss().into_string()
+    site_feed().render(FeedFormat::Rss)
 }
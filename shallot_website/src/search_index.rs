@@ -0,0 +1,430 @@
+//! Static Search Index
+//!
+//! Builds a search index from rendered pages at build time, so a static
+//! shallot site can offer search without a backend. [`SearchIndex::write_to`]
+//! emits, under `search/`:
+//!
+//! - `index.html`: an A–Z directory of every indexed term
+//! - `{term}.html`: one result page per term, with matches highlighted
+//! - `index.json`: the compact index, for an optional script-driven search box
+//!
+//! Tokens are lowercased runs of letters and digits; common English stop
+//! words are dropped. Words in a page title count five times.
+
+use maud::{html, Markup, DOCTYPE};
+use shallot_components::highlight_matches;
+use shallot_components::share_links::encode_uri_component;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const STOPWORDS: &[&str] = &[
+    "a", "all", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "in", "is", "it",
+    "its", "of", "on", "or", "that", "the", "this", "to", "was", "with", "you", "your",
+];
+
+/// Weight of a word in the page title relative to one in the body
+const TITLE_WEIGHT: u32 = 5;
+
+/// Longest token kept; longer runs are usually hashes or encoded data
+const MAX_TOKEN_LEN: usize = 32;
+
+/// Characters of context shown around the first match in a snippet
+const SNIPPET_CHARS: usize = 160;
+
+/// Split text into lowercased index terms
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|token| {
+            let len = token.chars().count();
+            (2..=MAX_TOKEN_LEN).contains(&len) && !STOPWORDS.contains(&token.as_str())
+        })
+        .collect()
+}
+
+/// Visible text of an HTML document: tags removed, `<script>`, `<style>`
+/// and `<head>` contents skipped, common entities decoded and whitespace
+/// collapsed
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    let mut skip_until: Option<&str> = None;
+    let mut rest = html;
+
+    while let Some(open) = rest.find('<') {
+        if skip_until.is_none() {
+            text.push_str(&rest[..open]);
+        }
+        let Some(close) = rest[open..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = rest[open + 1..open + close].to_ascii_lowercase();
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+        match skip_until {
+            Some(end) if tag.starts_with('/') && name == end => skip_until = None,
+            Some(_) => {}
+            None if matches!(name, "script" | "style" | "head") && !tag.starts_with('/') => {
+                skip_until = Some(match name {
+                    "script" => "script",
+                    "style" => "style",
+                    _ => "head",
+                })
+            }
+            // Tags separate words, e.g. `<li>One</li><li>Two</li>`
+            None => text.push(' '),
+        }
+        rest = &rest[open + close + 1..];
+    }
+    if skip_until.is_none() {
+        text.push_str(rest);
+    }
+
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Contents of the first `<tag>...</tag>` in `html`, as text
+fn first_element_text(html: &str, tag: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let start = lower.find(&format!("<{}", tag))?;
+    let content = start + lower[start..].find('>')? + 1;
+    let end = content + lower[content..].find(&format!("</{}", tag))?;
+    let text = html_to_text(&html[content..end]);
+    (!text.is_empty()).then_some(text)
+}
+
+/// A rendered page to index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchDocument {
+    pub url: String,
+    pub title: String,
+    pub text: String,
+}
+
+impl SearchDocument {
+    pub fn new(url: impl Into<String>, title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Read the title from `<title>` (or the first `<h1>`) and the text from
+    /// the page body
+    pub fn from_html(url: impl Into<String>, html: &str) -> Self {
+        let url = url.into();
+        let title = first_element_text(html, "title")
+            .or_else(|| first_element_text(html, "h1"))
+            .unwrap_or_else(|| url.clone());
+        Self {
+            url,
+            title,
+            text: html_to_text(html),
+        }
+    }
+
+    /// Up to [`SNIPPET_CHARS`] characters of text around the first match of
+    /// any `query` term
+    pub fn snippet(&self, query: &str) -> String {
+        let haystack = self.text.to_lowercase();
+        let first = query
+            .split_whitespace()
+            .filter_map(|term| haystack.find(&term.to_lowercase()))
+            .min();
+        // Lowercasing can change byte lengths outside ASCII; fall back to
+        // the start of the text when the offset does not line up
+        let pos = first
+            .filter(|&pos| haystack.len() == self.text.len() && self.text.is_char_boundary(pos))
+            .unwrap_or(0);
+
+        let start = self.text[..pos]
+            .char_indices()
+            .rev()
+            .nth(SNIPPET_CHARS / 3)
+            .map_or(0, |(i, _)| i);
+        let end = self.text[start..]
+            .char_indices()
+            .nth(SNIPPET_CHARS)
+            .map_or(self.text.len(), |(i, _)| start + i);
+
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push('…');
+        }
+        snippet.push_str(self.text[start..end].trim());
+        if end < self.text.len() {
+            snippet.push('…');
+        }
+        snippet
+    }
+}
+
+/// A document matching a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit<'a> {
+    pub document: &'a SearchDocument,
+    pub score: u32,
+}
+
+/// Inverted index over [`SearchDocument`]s
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    documents: Vec<SearchDocument>,
+    /// Term to (document index, weighted count), in document order
+    postings: BTreeMap<String, Vec<(usize, u32)>>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn page(mut self, document: SearchDocument) -> Self {
+        self.add(document);
+        self
+    }
+
+    pub fn add(&mut self, document: SearchDocument) {
+        let id = self.documents.len();
+        let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+        for term in tokenize(&document.title) {
+            *counts.entry(term).or_default() += TITLE_WEIGHT;
+        }
+        for term in tokenize(&document.text) {
+            *counts.entry(term).or_default() += 1;
+        }
+        for (term, count) in counts {
+            self.postings.entry(term).or_default().push((id, count));
+        }
+        self.documents.push(document);
+    }
+
+    pub fn documents(&self) -> &[SearchDocument] {
+        &self.documents
+    }
+
+    /// Indexed terms in alphabetical order
+    pub fn terms(&self) -> impl Iterator<Item = &str> {
+        self.postings.keys().map(String::as_str)
+    }
+
+    /// Documents containing every term of `query`, best first
+    pub fn search(&self, query: &str) -> Vec<SearchHit<'_>> {
+        let terms = tokenize(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: BTreeMap<usize, (usize, u32)> = BTreeMap::new();
+        for term in &terms {
+            for &(id, count) in self.postings.get(term).into_iter().flatten() {
+                let entry = scores.entry(id).or_default();
+                entry.0 += 1;
+                entry.1 += count;
+            }
+        }
+
+        let mut hits: Vec<SearchHit<'_>> = scores
+            .into_iter()
+            .filter(|(_, (matched, _))| *matched == terms.len())
+            .map(|(id, (_, score))| SearchHit {
+                document: &self.documents[id],
+                score,
+            })
+            .collect();
+        // Stable sort keeps document order among equal scores
+        hits.sort_by_key(|hit| Reverse(hit.score));
+        hits
+    }
+
+    /// Compact JSON: `{"documents":[{"url","title"}],"terms":{"term":[[doc,score]]}}`
+    pub fn to_json(&self) -> String {
+        let documents = self
+            .documents
+            .iter()
+            .map(|doc| {
+                format!(
+                    r#"{{"url":{},"title":{}}}"#,
+                    json_string(&doc.url),
+                    json_string(&doc.title)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let terms = self
+            .postings
+            .iter()
+            .map(|(term, postings)| {
+                let postings = postings
+                    .iter()
+                    .map(|(id, count)| format!("[{},{}]", id, count))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("{}:[{}]", json_string(term), postings)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(r#"{{"documents":[{}],"terms":{{{}}}}}"#, documents, terms)
+    }
+
+    /// File name of the result page for `term`, relative to `search/`
+    pub fn term_href(term: &str) -> String {
+        format!("{}.html", encode_uri_component(term))
+    }
+
+    /// Static result page for one term
+    pub fn result_page(&self, term: &str) -> Markup {
+        let hits = self.search(term);
+        page(
+            &format!("Search: {}", term),
+            html! {
+                p class="sh-search-page__back" { a href="index.html" { "All terms" } }
+                h1 { "Results for “" (term) "”" }
+                p class="sh-search-page__count" {
+                    (hits.len()) @if hits.len() == 1 { " page" } @else { " pages" }
+                }
+                ul class="sh-search-page__results" {
+                    @for hit in &hits {
+                        li class="sh-search-result" {
+                            a class="sh-search-result__link" href=(hit.document.url) {
+                                div class="sh-search-result__content" {
+                                    span class="sh-search-result__title" { (highlight_matches(&hit.document.title, term)) }
+                                    span class="sh-search-result__desc" { (highlight_matches(&hit.document.snippet(term), term)) }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// A–Z directory of every term, linking to its result page
+    pub fn directory_page(&self) -> Markup {
+        let mut groups: BTreeMap<char, Vec<&str>> = BTreeMap::new();
+        for term in self.terms() {
+            let initial = term.chars().next().unwrap_or('#');
+            let initial = if initial.is_ascii_alphabetic() {
+                initial.to_ascii_uppercase()
+            } else {
+                '#'
+            };
+            groups.entry(initial).or_default().push(term);
+        }
+
+        page(
+            "Search",
+            html! {
+                h1 { "Search" }
+                nav class="sh-search-page__letters" aria-label="Jump to letter" {
+                    @for initial in groups.keys() {
+                        a href=(format!("#letter-{}", letter_id(*initial))) { (initial) }
+                    }
+                }
+                @for (initial, terms) in &groups {
+                    section class="sh-search-page__group" id=(format!("letter-{}", letter_id(*initial))) {
+                        h2 { (initial) }
+                        ul class="sh-search-page__terms" {
+                            @for term in terms {
+                                li { a href=(Self::term_href(term)) { (term) } }
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Write the directory, one page per term and the JSON index into
+    /// `dir/search/`, returning the written paths
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let search_dir = dir.as_ref().join("search");
+        fs::create_dir_all(&search_dir)?;
+
+        let mut written = Vec::with_capacity(self.postings.len() + 2);
+        let mut write = |name: String, contents: String| -> io::Result<()> {
+            let path = search_dir.join(name);
+            fs::write(&path, contents)?;
+            written.push(path);
+            Ok(())
+        };
+
+        write(
+            "index.html".to_string(),
+            self.directory_page().into_string(),
+        )?;
+        write("index.json".to_string(), self.to_json())?;
+        for term in self.terms() {
+            write(
+                format!("{}.html", term),
+                self.result_page(term).into_string(),
+            )?;
+        }
+        Ok(written)
+    }
+}
+
+fn letter_id(initial: char) -> String {
+    if initial == '#' {
+        "other".to_string()
+    } else {
+        initial.to_ascii_lowercase().to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Shell shared by the search pages, which live one level below the site root
+fn page(title: &str, body: Markup) -> Markup {
+    html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                meta name="robots" content="noindex";
+                title { (title) " · Shallot" }
+                link rel="stylesheet" href="../styles/main.css";
+                link rel="stylesheet" href="../styles/components.css";
+            }
+            body {
+                main class="sh-search-page" { (body) }
+            }
+        }
+    }
+}
@@ -3,10 +3,11 @@
 //! Displays all 129 components organized by category.
 //! Each component has a preview, description, and expandable code view.
 
-use crate::theme_marketplace;
+use crate::{doc_examples, theme_marketplace};
 use maud::{html, Markup, Render};
 use shallot_components::{
     alert::{Alert, AlertKind},
+    all_component_css,
     avatar::Avatar,
     badge::Badge,
     border_beam::BorderBeam,
@@ -15,6 +16,8 @@ use shallot_components::{
     capdrop::CapDrop,
     card::Card,
     confetti::Confetti,
+    encode_uri_component,
+    gallery::{Gallery, GalleryCategory, GalleryEntry},
     input::Input,
     liquid_button::LiquidButton,
     progress::ProgressBar,
@@ -354,8 +357,8 @@ fn render_preview(name: &str) -> Markup {
         "Breadcrumbs" => html! {
             div aria-label=(format!("{} component preview: Path navigation links", name)) {
                 (Breadcrumbs::new(vec![
-                    BreadcrumbItem { label: "Home", href: Some("/") },
-                    BreadcrumbItem { label: "Components", href: None },
+                    BreadcrumbItem::new("Home").href("/"),
+                    BreadcrumbItem::new("Components"),
                 ]).render())
             }
         },
@@ -380,6 +383,62 @@ fn render_preview(name: &str) -> Markup {
     }
 }
 
+/// A component name and its live preview
+type GalleryPreview = (&'static str, fn() -> Markup);
+
+/// Showcase components that have a live preview, for the standalone gallery
+const GALLERY_PREVIEWS: &[GalleryPreview] = &[
+    ("Input", || render_preview("Input")),
+    ("Card", || render_preview("Card")),
+    ("Avatar", || render_preview("Avatar")),
+    ("BorderBeam", || render_preview("BorderBeam")),
+    ("Confetti", || render_preview("Confetti")),
+    ("LiquidButton", || render_preview("LiquidButton")),
+    ("RefractiveGauge", || render_preview("RefractiveGauge")),
+    ("ShadowElevator", || render_preview("ShadowElevator")),
+    ("Timeline", || render_preview("Timeline")),
+    ("CapDrop", || render_preview("CapDrop")),
+    ("Breadcrumbs", || render_preview("Breadcrumbs")),
+];
+
+/// The showcase as a standalone styleguide site, one page per previewed
+/// component
+pub fn gallery() -> Gallery {
+    let mut gallery = Gallery::new("Shallot Components")
+        .description("Zero-JS UI components for Rust")
+        .css(all_component_css());
+    for category in CATEGORIES {
+        gallery = gallery.category(
+            GalleryCategory::new(category.id, category.name).description(category.description),
+        );
+    }
+    for (name, category, description, _tagline) in SAMPLE_COMPONENTS {
+        if let Some((_, preview)) = GALLERY_PREVIEWS.iter().find(|(n, _)| n == name) {
+            gallery = gallery.entry(
+                GalleryEntry::new(*name, *category)
+                    .description(*description)
+                    .example("Preview", *preview),
+            );
+        }
+    }
+    gallery
+}
+
+/// Code for the "Full Code" tab: the `# Example` from the component's docs
+fn full_code(name: &str) -> String {
+    let module = doc_examples::module_name(name);
+    match doc_examples::example_for(name) {
+        Some(example) => format!(
+            "// Source: shallot_components/src/{}.rs\n\n{}",
+            module, example
+        ),
+        None => format!(
+            "// Source: shallot_components/src/{}.rs\n// No `# Example` in the module docs yet",
+            module
+        ),
+    }
+}
+
 /// Render the showcase section
 pub fn render() -> Markup {
     html! {
@@ -485,7 +544,7 @@ pub fn render() -> Markup {
                                                 div class="sh-code-dropdown__content" {
                                                     /* Download button */
                                                     a
-                                                        href=(format!("data:text/plain;charset=utf-8,{}example%20code%20for%20{}", "%5B%20Example%20code%20%5D", name.to_lowercase()))
+                                                        href=(format!("data:text/plain;charset=utf-8,{}", encode_uri_component(&full_code(name))))
                                                         download=(format!("{}_example.rs", name.to_lowercase()))
                                                         class="sh-code-download"
                                                     {
@@ -533,42 +592,7 @@ pub fn render() -> Markup {
 
                                                     /* Code blocks */
                                                     pre class="sh-code-block sh-code-block--full" {
-                                                        code {
-                                                            "// " (name) " Component\n"
-                                                            "// Source: shallot_components/src/" (name.to_lowercase()) ".rs\n"
-                                                            "\n"
-                                                            "use maud::{html, Markup, Render};\n"
-                                                            "use shallot_foundation::theme::Theme;\n"
-                                                            "\n"
-                                                            "pub struct " (name) " {\n"
-                                                            "    pub text: String,\n"
-                                                            "    pub variant: Option<String>,\n"
-                                                            "}\n"
-                                                            "\n"
-                                                            "impl " (name) " {\n"
-                                                            "    pub fn new(text: impl Into<String>) -> Self {\n"
-                                                            "        Self {\n"
-                                                            "            text: text.into(),\n"
-                                                            "            variant: None,\n"
-                                                            "        }\n"
-                                                            "    }\n"
-                                                            "\n"
-                                                            "    pub fn variant(mut self, variant: impl Into<String>) -> Self {\n"
-                                                            "        self.variant = Some(variant.into());\n"
-                                                            "        self\n"
-                                                            "    }\n"
-                                                            "}\n"
-                                                            "\n"
-                                                            "impl Render for " (name) " {\n"
-                                                            "    fn render(&self) -> Markup {\n"
-                                                            "        html! {\n"
-                                                            "            div class=(format!(\"sh-{}\", stringify!(" (name.to_lowercase()) ")) {\n"
-                                                            "                (self.text)\n"
-                                                            "            }\n"
-                                                            "        }\n"
-                                                            "    }\n"
-                                                            "}"
-                                                        }
+                                                        code { (full_code(name)) }
                                                     }
 
                                                     pre class="sh-code-block sh-code-block--library" {
@@ -578,7 +602,7 @@ pub fn render() -> Markup {
                                                             "// shallot_components = \"0.1\"\n"
                                                             "\n"
                                                             "// 2. Import the component:\n"
-                                                            "use shallot_components::" (name.to_lowercase()) "::" (name) ";\n"
+                                                            "use shallot_components::" (doc_examples::module_name(name)) "::" (name) ";\n"
                                                             "\n"
                                                             "// 3. Use in your Maud template:\n"
                                                             "html! {\n"
@@ -1,9 +1,12 @@
 //! Theme Marketplace
 //!
 //! Community-submitted theme presets.
-//! Users can submit themes via GitHub PRs.
+//! Users can submit themes via GitHub PRs as theme package files in
+//! `shallot_website/themes/`, in the format read by
+//! [`ThemePackage::from_toml`].
 
 use maud::{html, Markup};
+use shallot_foundation::ThemePackage;
 
 /// Community theme
 pub struct CommunityTheme {
@@ -67,6 +70,41 @@ pub const COMMUNITY_THEMES: &[CommunityTheme] = &[
     },
 ];
 
+/// Theme package files shipped with the site, by slug
+pub const THEME_FILES: &[(&str, &str)] = &[
+    ("cyberpunk", include_str!("../themes/cyberpunk.toml")),
+    ("dracula", include_str!("../themes/dracula.toml")),
+    ("gruvbox", include_str!("../themes/gruvbox.toml")),
+    ("nordic", include_str!("../themes/nordic.toml")),
+    ("solarized", include_str!("../themes/solarized.toml")),
+    ("synthwave", include_str!("../themes/synthwave.toml")),
+];
+
+/// The shipped theme packages, parsed and validated
+pub fn packages() -> Vec<ThemePackage> {
+    THEME_FILES
+        .iter()
+        .map(|(slug, source)| {
+            ThemePackage::from_toml(source)
+                .unwrap_or_else(|error| panic!("themes/{}.toml: {}", slug, error))
+        })
+        .collect()
+}
+
+/// Every shipped theme scoped to its `[data-sh-theme="…"]` selector
+pub fn themes_css() -> String {
+    packages()
+        .iter()
+        .map(ThemePackage::scoped_css)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Download link for a theme's package file
+fn theme_file_href(name: &str) -> String {
+    format!("themes/{}.toml", ThemePackage::new(name).slug())
+}
+
 /// Render theme marketplace
 pub fn render() -> Markup {
     html! {
@@ -103,14 +141,12 @@ pub fn render() -> Markup {
                                         "📥 " (theme.downloads)
                                     }
                                 }
-                                button
+                                a
                                     class="sh-theme-card__install"
-                                    type="button"
-                                    data-primary=(theme.primary)
-                                    data-secondary=(theme.secondary)
-                                    data-accent=(theme.accent)
+                                    href=(theme_file_href(theme.name))
+                                    download
                                 {
-                                    "Use Theme"
+                                    "Download Theme"
                                 }
                             }
                         }
@@ -204,6 +240,9 @@ pub fn theme_marketplace_css() -> String {
     font-weight: 600;
     cursor: pointer;
     transition: background 0.2s ease;
+    display: block;
+    text-align: center;
+    text-decoration: none;
 }
 
 .sh-theme-card__install:hover {
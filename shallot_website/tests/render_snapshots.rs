@@ -8,7 +8,9 @@
 
 #[cfg(test)]
 mod tests {
-    use shallot_website::{homepage, retro_hero, showcase, theme_panel};
+    use shallot_website::{
+        doc_examples, homepage, retro_hero, showcase, theme_marketplace, theme_panel,
+    };
 
     #[test]
     fn test_hero_renders_without_debug_strings() {
@@ -116,6 +118,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_showcase_code_comes_from_doc_examples() {
+        assert_eq!(doc_examples::module_name("BorderBeam"), "border_beam");
+        let example = doc_examples::example_for("Avatar").expect("Avatar doc example");
+        assert!(example.contains("Avatar::new(\"Ada Lovelace\")"));
+
+        let showcase_markup = showcase::render().into_string();
+        assert!(showcase_markup.contains("Avatar::new(&quot;Ada Lovelace&quot;)"));
+        assert!(
+            !showcase_markup.contains("pub struct Avatar"),
+            "Placeholder source still rendered"
+        );
+    }
+
+    #[test]
+    fn test_showcase_gallery_pages() {
+        let gallery = showcase::gallery();
+        let files = gallery.files();
+
+        // Index, stylesheet and one page per previewed component
+        assert_eq!(files.len(), gallery.entries.len() + 2);
+        let card = files
+            .iter()
+            .find(|file| file.name == "components/card.html")
+            .expect("Card page missing");
+        assert!(card.contents.contains("sh-gallery__preview"));
+        assert!(card.contents.contains("Card Title"));
+    }
+
+    #[test]
+    fn test_theme_packages_load() {
+        let packages = theme_marketplace::packages();
+        assert_eq!(packages.len(), theme_marketplace::THEME_FILES.len());
+        for (package, (slug, _)) in packages.iter().zip(theme_marketplace::THEME_FILES) {
+            assert_eq!(&package.slug(), slug, "file name must match theme name");
+        }
+
+        let css = theme_marketplace::themes_css();
+        assert!(css.contains("[data-sh-theme=\"dracula\"] {"));
+        assert!(css.contains("--sh-primary: #bd93f9;"));
+        assert!(theme_marketplace::render()
+            .into_string()
+            .contains(r#"href="themes/nordic.toml""#));
+    }
+
     #[test]
     fn test_theme_panel_renders() {
         let panel_markup = theme_panel::render().into_string();
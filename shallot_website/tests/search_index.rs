@@ -0,0 +1,111 @@
+//! Tests for the static search index builder
+//!
+//! Run with: cargo test --test search_index
+
+#[cfg(test)]
+mod tests {
+    use shallot_website::search_index::{html_to_text, tokenize, SearchDocument, SearchIndex};
+
+    fn index() -> SearchIndex {
+        SearchIndex::new()
+            .page(SearchDocument::new(
+                "/docs/grid",
+                "Grid layout",
+                "Responsive grid with gap and columns.",
+            ))
+            .page(SearchDocument::new(
+                "/docs/button",
+                "Button",
+                "Buttons can sit in a grid or stack.",
+            ))
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("The Zero-JS grid, and a 3D card!"),
+            vec!["zero", "js", "grid", "3d", "card"]
+        );
+        assert!(tokenize("a an the").is_empty());
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = r#"<!DOCTYPE html><html><head><title>Docs</title><style>.a{}</style></head>
+            <body><ul><li>One</li><li>Two &amp; three</li></ul><script>var x = 1;</script>End</body></html>"#;
+        assert_eq!(html_to_text(html), "One Two & three End");
+    }
+
+    #[test]
+    fn test_document_from_html() {
+        let doc = SearchDocument::from_html(
+            "/",
+            "<html><head><title>Shallot &amp; Co</title></head><body><h1>Hi</h1>Body</body></html>",
+        );
+        assert_eq!(doc.title, "Shallot & Co");
+        assert_eq!(doc.text, "Hi Body");
+
+        let doc = SearchDocument::from_html("/x", "<main><h1>Heading</h1></main>");
+        assert_eq!(doc.title, "Heading");
+    }
+
+    #[test]
+    fn test_search_ranks_title_matches_first() {
+        let index = index();
+        let hits = index.search("grid");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].document.url, "/docs/grid");
+        assert!(hits[0].score > hits[1].score);
+
+        // Every term must match
+        let hits = index.search("grid stack");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].document.url, "/docs/button");
+        assert!(index.search("the").is_empty());
+    }
+
+    #[test]
+    fn test_snippet() {
+        let long = format!("{} needle {}", "word ".repeat(100), "tail ".repeat(100));
+        let doc = SearchDocument::new("/", "Long", long);
+        let snippet = doc.snippet("needle");
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("needle"));
+        assert!(snippet.chars().count() <= 162);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let index = SearchIndex::new().page(SearchDocument::new("/a", "Say \"hi\"", "hello hello"));
+        assert_eq!(
+            index.to_json(),
+            r#"{"documents":[{"url":"/a","title":"Say \"hi\""}],"terms":{"hello":[[0,2]],"hi":[[0,5]],"say":[[0,5]]}}"#
+        );
+    }
+
+    #[test]
+    fn test_result_and_directory_pages() {
+        let index = index();
+        let page = index.result_page("grid").into_string();
+        assert!(page.contains("Results for “grid”"));
+        assert!(page.contains("2 pages"));
+        assert!(page.contains(r#"<mark class="sh-search-result__match">Grid</mark> layout"#));
+        assert!(page.contains(r#"href="/docs/button""#));
+
+        let directory = index.directory_page().into_string();
+        assert!(directory.contains(r##"<a href="#letter-g">G</a>"##));
+        assert!(directory.contains(r#"<a href="grid.html">grid</a>"#));
+    }
+
+    #[test]
+    fn test_write_to() {
+        let dir = std::env::temp_dir().join(format!("shallot-search-{}", std::process::id()));
+        let written = index().write_to(&dir).expect("write search pages");
+        assert!(dir.join("search/index.html").exists());
+        assert!(dir.join("search/index.json").exists());
+        assert!(dir.join("search/grid.html").exists());
+        assert_eq!(written.len(), index().terms().count() + 2);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}